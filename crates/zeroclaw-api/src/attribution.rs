@@ -101,6 +101,7 @@ pub enum ChannelKind {
     Wechat,
     WhatsappBusiness,
     WhatsappWeb,
+    Zulip,
     Plugin,
 }
 
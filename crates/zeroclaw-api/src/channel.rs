@@ -223,6 +223,23 @@ pub enum ChannelConversationScope {
     ReplyTarget,
 }
 
+/// Inbound media stored on local disk for the duration of one turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InboundMediaFile {
+    pub path: std::path::PathBuf,
+    pub mime_type: String,
+}
+
+/// An emoji reaction a user added to an earlier message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InboundReaction {
+    /// Platform id of the reacted-to message, in the same form the channel
+    /// reports in its [`DeliveryReceipt`].
+    pub message_id: String,
+    /// Emoji the user added with this update.
+    pub emoji: Vec<String>,
+}
+
 /// A message received from or sent to a channel
 #[derive(Debug, Clone, Default)]
 pub struct ChannelMessage {
@@ -250,6 +267,10 @@ pub struct ChannelMessage {
     /// Channels populate this when they receive media alongside a text message.
     /// Defaults to empty — existing channels are unaffected.
     pub attachments: Vec<MediaAttachment>,
+    /// Files the channel downloaded for this message, e.g. photos referenced
+    /// by `[IMAGE:<path>]` markers in `content`. The orchestrator deletes
+    /// them once the message's turn ends, including on error or cancellation.
+    pub media_files: Vec<InboundMediaFile>,
     /// Email subject for reply threading.
     pub subject: Option<String>,
     /// Internal SOP-ingress marker carrying the event topic, set ONLY by the
@@ -266,6 +287,13 @@ pub struct ChannelMessage {
     pub explicitly_addressed: bool,
     /// Controls whether conversation history is sender-scoped or room-scoped.
     pub conversation_scope: ChannelConversationScope,
+    /// Set when this message is a user edit of an earlier one: the `id` of
+    /// the message it replaces. The orchestrator interrupts the original's
+    /// in-flight turn before answering the edit.
+    pub edit_of: Option<String>,
+    /// Set when this event is a reaction rather than a message. The
+    /// orchestrator records it as reply feedback and never starts a turn.
+    pub reaction: Option<InboundReaction>,
 }
 
 /// Message to send through a channel
@@ -463,6 +491,28 @@ impl SendMessage {
     }
 }
 
+/// Platform confirmation that a send was accepted, returned by
+/// [`Channel::send_with_receipt`]. `message_id` is the platform's id for the
+/// delivered message (Telegram `message_id`, Slack `ts`, Discord message id,
+/// Matrix `event_id`). When a reply is split across several messages the
+/// channel reports the part that anchors it: the first on Discord, the last
+/// on Telegram.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryReceipt {
+    pub message_id: String,
+    pub accepted_at: std::time::SystemTime,
+}
+
+impl DeliveryReceipt {
+    /// Receipt for a message the platform accepted just now.
+    pub fn new(message_id: impl Into<String>) -> Self {
+        Self {
+            message_id: message_id.into(),
+            accepted_at: std::time::SystemTime::now(),
+        }
+    }
+}
+
 /// A low-level, provider-relative forge API request routed through a
 /// forge-backed channel. Channel-neutral so the `Channel` trait carries no
 /// forge-specific types; the git channel maps this onto its provider's
@@ -498,6 +548,17 @@ pub trait Channel: Send + Sync + crate::attribution::Attributable {
     /// Send a message through this channel
     async fn send(&self, message: &SendMessage) -> anyhow::Result<()>;
 
+    /// Send a message and report the platform id it was delivered under.
+    /// `Ok(None)` means the send succeeded but the platform (or this
+    /// channel) gives no id back; the default delegates to [`Self::send`].
+    async fn send_with_receipt(
+        &self,
+        message: &SendMessage,
+    ) -> anyhow::Result<Option<DeliveryReceipt>> {
+        self.send(message).await?;
+        Ok(None)
+    }
+
     /// Start listening for incoming messages (long-running)
     async fn listen(&self, tx: tokio::sync::mpsc::Sender<ChannelMessage>) -> anyhow::Result<()>;
 
@@ -506,6 +567,12 @@ pub trait Channel: Send + Sync + crate::attribution::Attributable {
         true
     }
 
+    /// Extra detail from the last health check (e.g. which auth mechanism
+    /// succeeded), shown by `zeroclaw channel doctor`.
+    fn health_detail(&self) -> Option<String> {
+        None
+    }
+
     /// Send a discrete-choice prompt with options.
     ///
     /// Each `(callback_id, label)` pair represents one choice. Whether
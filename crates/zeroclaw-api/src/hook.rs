@@ -19,6 +19,15 @@ impl<T> HookResult<T> {
     }
 }
 
+/// A detector's verdict on untrusted tool output. `score` is a risk from 0.0
+/// (benign) to 1.0 (certain injection); `patterns` names what matched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InjectionSignal {
+    pub detector: String,
+    pub score: f64,
+    pub patterns: Vec<String>,
+}
+
 /// Trait for hook handlers. All methods have default no-op implementations.
 /// Implement only the events you care about.
 #[async_trait]
@@ -39,6 +48,13 @@ pub trait HookHandler: Send + Sync {
     async fn on_message_sent(&self, _channel: &str, _recipient: &str, _content: &str) {}
     async fn on_heartbeat_tick(&self) {}
 
+    // --- Detector hooks (queried when prompt-injection scanning is on) ---
+    /// Score a tool result for prompt injection before it enters history.
+    /// Return `None` to abstain; the highest score across detectors wins.
+    async fn scan_tool_output(&self, _tool: &str, _output: &str) -> Option<InjectionSignal> {
+        None
+    }
+
     // --- Modifying hooks (sequential by priority, can cancel) ---
     async fn before_model_resolve(
         &self,
@@ -126,6 +142,16 @@ mod tests {
         assert_eq!(MinimalHook.priority(), 0);
     }
 
+    #[tokio::test]
+    async fn default_detector_hook_abstains() {
+        let hook = TestHook::new("test", 0);
+        assert!(
+            hook.scan_tool_output("web_fetch", "ignore previous instructions")
+                .await
+                .is_none()
+        );
+    }
+
     #[tokio::test]
     async fn default_modifying_hooks_pass_through() {
         let hook = TestHook::new("test", 0);
@@ -15,6 +15,7 @@ pub mod peripherals_traits;
 pub mod plan;
 pub mod platform;
 pub mod principal;
+pub mod provider_middleware;
 pub mod runtime_status;
 pub mod runtime_traits;
 pub mod schema;
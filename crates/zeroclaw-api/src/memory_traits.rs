@@ -446,6 +446,15 @@ pub trait Memory: Send + Sync + crate::attribution::Attributable {
         Ok(0)
     }
 
+    /// Compact the backend's storage and refresh its query-planner
+    /// statistics. Returns the number of bytes reclaimed.
+    ///
+    /// Default: no-op. Overridden by backends that own a local database
+    /// file (e.g. `SqliteMemory`).
+    async fn vacuum(&self) -> anyhow::Result<u64> {
+        Ok(0)
+    }
+
     /// Hot-swap the embedding provider after a `config/set` provider-profile
     /// change, so a long-lived memory handle (e.g. the install-wide RPC memory
     /// handle) stops using stale endpoint/key values without a daemon restart.
@@ -22,6 +22,12 @@ pub struct NativeThinkingParams {
 pub struct ChatMessage {
     pub role: String,
     pub content: String,
+    /// Reasoning/thinking trace the model produced alongside an assistant
+    /// message. Kept apart from `content` so it never reaches channel
+    /// output or outbound provider requests; only written to session
+    /// transcripts when `runtime.reasoning_enabled` is on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reasoning: Option<String>,
 }
 
 pub const PRUNED_TOOL_EXCHANGE_SUMMARY_PREFIX: &str = "[Tool exchange:";
@@ -33,6 +39,7 @@ impl ChatMessage {
         Self {
             role: "system".into(),
             content: content.into(),
+            reasoning: None,
         }
     }
 
@@ -40,6 +47,7 @@ impl ChatMessage {
         Self {
             role: "user".into(),
             content: content.into(),
+            reasoning: None,
         }
     }
 
@@ -47,6 +55,7 @@ impl ChatMessage {
         Self {
             role: "assistant".into(),
             content: content.into(),
+            reasoning: None,
         }
     }
 
@@ -54,9 +63,16 @@ impl ChatMessage {
         Self {
             role: "tool".into(),
             content: content.into(),
+            reasoning: None,
         }
     }
 
+    /// Attach a reasoning trace. Empty or whitespace-only traces are dropped.
+    pub fn with_reasoning(mut self, reasoning: Option<String>) -> Self {
+        self.reasoning = reasoning.filter(|r| !r.trim().is_empty());
+        self
+    }
+
     pub fn pruned_tool_exchange_summary(tool_count: usize) -> String {
         format!(
             "{PRUNED_TOOL_EXCHANGE_SUMMARY_PREFIX} {tool_count} tool call(s) — {PRUNED_TOOL_EXCHANGE_SUMMARY_SUFFIX}"
@@ -48,6 +48,14 @@ pub struct TurnTokenUsage {
     pub output_tokens: u64,
 }
 
+/// Timing and outcome of one tool call inside a tool-loop iteration.
+#[derive(Debug, Clone)]
+pub struct ToolIterationStat {
+    pub tool: String,
+    pub duration: Duration,
+    pub success: bool,
+}
+
 /// Discrete events emitted by the agent runtime for observability.
 ///
 /// Each variant represents a lifecycle event that observers can record,
@@ -314,6 +322,103 @@ pub enum ObserverEvent {
         agent_alias: Option<String>,
         turn_id: Option<String>,
     },
+    /// One provider round-trip of the tool-call loop and the tools it ran.
+    ///
+    /// Emitted once per iteration, after the iteration's tools finished (or
+    /// when the loop ends), so observers can answer "which tool is slow" and
+    /// "how many iterations did this message take" without correlating
+    /// individual `LlmResponse` / `ToolCall` events themselves.
+    ToolLoopIteration {
+        /// 1-based iteration index within the current message.
+        iteration: usize,
+        model_provider: String,
+        model: String,
+        llm_duration: Duration,
+        llm_success: bool,
+        input_tokens: Option<u64>,
+        output_tokens: Option<u64>,
+        cost_usd: Option<f64>,
+        /// Tools executed in this iteration, in dispatch order. Empty for the
+        /// final (answer-only) iteration.
+        tools: Vec<ToolIterationStat>,
+        channel: Option<String>,
+        agent_alias: Option<String>,
+        turn_id: Option<String>,
+    },
+    /// The tool-call loop detector caught a repeated or alternating tool call
+    /// and intervened.
+    ToolLoopDetected {
+        tool: String,
+        /// `nudge` (a note was injected), `block` (the model was told to stop
+        /// calling the tool), or `abort` (the turn was ended).
+        action: String,
+        /// The detector's description of the pattern it saw.
+        reason: String,
+        channel: Option<String>,
+        agent_alias: Option<String>,
+        turn_id: Option<String>,
+    },
+    /// Aggregate of every [`Self::ToolLoopIteration`] for one message.
+    ///
+    /// Emitted exactly once when the tool-call loop returns, on success and
+    /// on error alike.
+    ToolLoopSummary {
+        model_provider: String,
+        model: String,
+        iterations: usize,
+        /// Wall-clock time spent in the loop.
+        duration: Duration,
+        /// Sum of provider call latencies across iterations.
+        llm_duration: Duration,
+        /// Sum of tool execution latencies across iterations.
+        tool_duration: Duration,
+        tool_calls: usize,
+        tool_failures: usize,
+        input_tokens: u64,
+        output_tokens: u64,
+        /// Summed per-call cost; `None` when no call had pricing.
+        cost_usd: Option<f64>,
+        success: bool,
+        channel: Option<String>,
+        agent_alias: Option<String>,
+        turn_id: Option<String>,
+    },
+    /// An inbound channel message entered processing.
+    ///
+    /// Opens the per-message trace: the [`Self::AgentStart`] and
+    /// [`Self::ChannelSend`] carrying the same `turn_id` nest under it until
+    /// the matching [`Self::ChannelMessageEnd`].
+    ChannelReceive {
+        /// Channel name, including the alias when one is configured.
+        channel: String,
+        /// Truncated SHA-256 of the sender id. Correlates messages from one
+        /// sender without exporting the raw identifier.
+        sender_hash: String,
+        turn_id: String,
+    },
+    /// The reply to a channel message was handed to the channel.
+    ChannelSend {
+        channel: String,
+        duration: Duration,
+        success: bool,
+        turn_id: String,
+        /// Platform message id from the channel's delivery receipt
+        /// (Telegram `message_id`, Slack `ts`, Discord message id, Matrix
+        /// `event_id`). `None` when the channel does not report one.
+        message_id: Option<String>,
+    },
+    /// Processing of a channel message finished, on every exit path.
+    ChannelMessageEnd {
+        channel: String,
+        duration: Duration,
+        turn_id: String,
+    },
+    /// A user reacted to a delivered reply with a thumbs-up or thumbs-down.
+    ReplyFeedback {
+        channel: String,
+        /// `true` for 👍, `false` for 👎.
+        positive: bool,
+    },
 }
 
 /// Numeric metrics emitted by the agent runtime.
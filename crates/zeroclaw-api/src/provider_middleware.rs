@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+
+use crate::hook::HookResult;
+use crate::model_provider::ChatMessage;
+
+/// Request/response middleware applied around every chat call made through
+/// the resilient provider wrapper.
+///
+/// Middleware runs sequentially by descending `priority()` on the way in and
+/// in the same order on the way out. Returning [`HookResult::Cancel`] aborts
+/// the call; the reason is shown to the sender as a policy notice, so keep it
+/// free of internal detail. Both methods default to pass-through.
+#[async_trait]
+pub trait ProviderMiddleware: Send + Sync {
+    fn name(&self) -> &str;
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// Inspect or rewrite the conversation before it is sent to the model.
+    async fn before_request(&self, messages: Vec<ChatMessage>) -> HookResult<Vec<ChatMessage>> {
+        HookResult::Continue(messages)
+    }
+
+    /// Inspect or rewrite the model's text reply before it reaches the caller.
+    async fn after_response(&self, text: String) -> HookResult<String> {
+        HookResult::Continue(text)
+    }
+}
+
+/// Error surfaced when a middleware aborts a provider call. Callers match on
+/// it to deliver `reason` as a policy message instead of a generic failure.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("request blocked by policy ({middleware}): {reason}")]
+pub struct MiddlewareRejection {
+    pub middleware: String,
+    pub reason: String,
+}
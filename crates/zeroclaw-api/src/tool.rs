@@ -342,6 +342,23 @@ pub trait Tool: Send + Sync + crate::attribution::Attributable {
         Vec::new()
     }
 
+    /// Whether executing the tool has no side effects. Read-only calls are
+    /// always re-run when a failed request is retried; every other call
+    /// that already succeeded replays its recorded result instead.
+    /// Default: `false`, so unknown tools are treated as mutating.
+    fn is_read_only(&self) -> bool {
+        false
+    }
+
+    /// Whether this particular call changes state (files, schedules,
+    /// memory, remote systems). The dispatcher refuses mutating calls in
+    /// read-only autonomy before the tool runs. Tools whose effect depends
+    /// on the arguments (a shell command, a `list` vs `create` action)
+    /// override this. Default: anything not declared read-only mutates.
+    fn mutates(&self, _args: &serde_json::Value) -> bool {
+        !self.is_read_only()
+    }
+
     /// Execute the tool with given arguments
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult>;
 
@@ -6,9 +6,18 @@ use tokio::sync::RwLock;
 use tokio_tungstenite::tungstenite::Message;
 use uuid::Uuid;
 use zeroclaw_api::channel::{Channel, ChannelMessage, SendMessage};
+use zeroclaw_config::schema::RichTextMode;
+
+use crate::rich_text::{TextLimit, chunk_markdown, map_prose_lines, plain_title, use_rich_format};
 
 const DINGTALK_BOT_CALLBACK_TOPIC: &str = "/v1.0/im/bot/messages/get";
 
+/// Session-webhook replies over 2000 characters are rejected outright.
+const DINGTALK_REPLY_MAX_CHARS: usize = 2000;
+
+/// Markdown message titles show up in notification previews; keep them short.
+const DINGTALK_TITLE_MAX_CHARS: usize = 32;
+
 /// DingTalk channel — connects via Stream Mode WebSocket for real-time messages.
 /// Replies are sent through per-message session webhook URLs.
 pub struct DingTalkChannel {
@@ -25,6 +34,58 @@ pub struct DingTalkChannel {
     session_webhooks: Arc<RwLock<HashMap<String, String>>>,
     /// Per-channel proxy URL override.
     proxy_url: Option<String>,
+    /// Plain text vs markdown message selection for replies.
+    rich_text: RichTextMode,
+}
+
+/// DingTalk markdown collapses single newlines; turn line breaks inside a
+/// paragraph into markdown hard breaks. Headings and code blocks are left alone.
+fn to_dingtalk_markdown(text: &str) -> String {
+    map_prose_lines(text, |line, next| {
+        let continues = next.is_some_and(|next| !next.trim().is_empty());
+        if continues
+            && !line.trim().is_empty()
+            && !line.trim_start().starts_with('#')
+            && !line.ends_with("  ")
+        {
+            format!("{line}  ")
+        } else {
+            line.to_string()
+        }
+    })
+}
+
+/// Webhook payloads for one reply: a `markdown` message per chunk when the
+/// reply is sent rich, a `text` message per chunk otherwise.
+fn build_reply_bodies(
+    content: &str,
+    subject: Option<&str>,
+    rich_text: RichTextMode,
+) -> Vec<serde_json::Value> {
+    let limit = TextLimit::Chars(DINGTALK_REPLY_MAX_CHARS);
+    if !use_rich_format(rich_text, content) {
+        return chunk_markdown(content, limit)
+            .into_iter()
+            .map(|chunk| serde_json::json!({ "msgtype": "text", "text": { "content": chunk } }))
+            .collect();
+    }
+
+    let title = subject
+        .map(str::to_string)
+        .or_else(|| plain_title(content, DINGTALK_TITLE_MAX_CHARS))
+        .unwrap_or_else(|| "ZeroClaw".to_string());
+    chunk_markdown(&to_dingtalk_markdown(content), limit)
+        .into_iter()
+        .map(|chunk| {
+            serde_json::json!({
+                "msgtype": "markdown",
+                "markdown": {
+                    "title": title,
+                    "text": chunk,
+                }
+            })
+        })
+        .collect()
 }
 
 /// Response from DingTalk gateway connection registration.
@@ -48,6 +109,7 @@ impl DingTalkChannel {
             peer_resolver,
             session_webhooks: Arc::new(RwLock::new(HashMap::new())),
             proxy_url: None,
+            rich_text: RichTextMode::default(),
         }
     }
 
@@ -63,6 +125,12 @@ impl DingTalkChannel {
         self
     }
 
+    /// Set the reply format from `[channels.dingtalk.<alias>].rich_text`.
+    pub fn with_rich_text(mut self, rich_text: RichTextMode) -> Self {
+        self.rich_text = rich_text;
+        self
+    }
+
     fn http_client(&self) -> reqwest::Client {
         zeroclaw_config::schema::build_channel_proxy_client(
             "channel.dingtalk",
@@ -172,26 +240,16 @@ impl Channel for DingTalkChannel {
             ))
         })?;
 
-        let title = message.subject.as_deref().unwrap_or("ZeroClaw");
-        let body = serde_json::json!({
-            "msgtype": "markdown",
-            "markdown": {
-                "title": title,
-                "text": message.content,
-            }
-        });
+        let client = self.http_client();
+        for body in build_reply_bodies(&message.content, message.subject.as_deref(), self.rich_text)
+        {
+            let resp = client.post(webhook_url).json(&body).send().await?;
 
-        let resp = self
-            .http_client()
-            .post(webhook_url)
-            .json(&body)
-            .send()
-            .await?;
-
-        if !resp.status().is_success() {
-            let status = resp.status();
-            let err = resp.text().await.unwrap_or_default();
-            anyhow::bail!("webhook reply failed ({status}): {err}");
+            if !resp.status().is_success() {
+                let status = resp.status();
+                let err = resp.text().await.unwrap_or_default();
+                anyhow::bail!("webhook reply failed ({status}): {err}");
+            }
         }
 
         Ok(())
@@ -541,6 +599,44 @@ client_secret = "secret"
         );
     }
 
+    #[test]
+    fn markdown_reply_matches_fixture_payload() {
+        let reply = include_str!("../tests/fixtures/rich_text/reply.md").trim_end();
+        let expected: serde_json::Value = serde_json::from_str(include_str!(
+            "../tests/fixtures/rich_text/dingtalk_markdown.json"
+        ))
+        .unwrap();
+        let bodies = build_reply_bodies(reply, None, RichTextMode::Auto);
+        assert_eq!(serde_json::Value::Array(bodies), expected);
+    }
+
+    #[test]
+    fn plain_reply_is_sent_as_text_message() {
+        let bodies = build_reply_bodies("Done, see you tomorrow.", None, RichTextMode::Auto);
+        assert_eq!(
+            bodies,
+            vec![serde_json::json!({
+                "msgtype": "text",
+                "text": { "content": "Done, see you tomorrow." }
+            })]
+        );
+        let forced = build_reply_bodies("Done.", Some("Status"), RichTextMode::Always);
+        assert_eq!(forced[0]["msgtype"], "markdown");
+        assert_eq!(forced[0]["markdown"]["title"], "Status");
+    }
+
+    #[test]
+    fn long_reply_is_chunked_under_the_limit() {
+        let reply = include_str!("../tests/fixtures/rich_text/reply.md").repeat(20);
+        let bodies = build_reply_bodies(&reply, None, RichTextMode::Auto);
+        assert!(bodies.len() > 1);
+        for body in &bodies {
+            let text = body["markdown"]["text"].as_str().unwrap();
+            assert!(text.chars().count() <= DINGTALK_REPLY_MAX_CHARS);
+            assert_eq!(text.matches("```").count() % 2, 0, "unbalanced fence");
+        }
+    }
+
     #[test]
     fn resolve_chat_id_handles_numeric_group_conversation_type() {
         let data = serde_json::json!({
@@ -11,7 +11,7 @@ use tokio_tungstenite::tungstenite::Message;
 use uuid::Uuid;
 use zeroclaw_api::channel::{
     Channel, ChannelApprovalRequest, ChannelApprovalResponse, ChannelGatePrompt, ChannelMessage,
-    GateChoiceEmphasis, SendMessage,
+    DeliveryReceipt, GateChoiceEmphasis, InboundMediaFile, SendMessage,
 };
 use zeroclaw_api::media::MediaAttachment;
 use zeroclaw_runtime::i18n;
@@ -98,6 +98,8 @@ pub struct DiscordChannel {
     transcription_manager: Option<std::sync::Arc<super::transcription::TranscriptionManager>>,
     /// Workspace directory for saving downloaded inbound media attachments.
     workspace_dir: Option<PathBuf>,
+    /// Downscale and per-message limits for inbound image attachments.
+    image_limits: crate::inbound_media::InboundImageLimits,
     /// Streaming mode: Off, Partial (draft edits), or MultiMessage (paragraph splits).
     stream_mode: zeroclaw_config::schema::StreamMode,
     /// Minimum interval (ms) between draft message edits (Partial mode only).
@@ -142,6 +144,13 @@ pub struct DiscordChannel {
     /// Resolves skill-derived commands to register alongside `/ask`.
     /// `None` (or an empty resolution) = `/ask` only.
     slash_command_resolver: Option<DiscordSlashCommandResolver>,
+    /// Re-dispatch user edits (MESSAGE_UPDATE) to the agent. Config
+    /// `respond_to_edits`.
+    respond_to_edits: bool,
+    /// Oldest edited message, in seconds, that is re-dispatched (0 = any).
+    edit_window_secs: u64,
+    /// Quote the referenced message into replies. Config `reply_context`.
+    reply_context: bool,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -176,6 +185,7 @@ impl DiscordChannel {
             transcription: None,
             transcription_manager: None,
             workspace_dir: None,
+            image_limits: crate::inbound_media::InboundImageLimits::default(),
             stream_mode: zeroclaw_config::schema::StreamMode::Off,
             draft_update_interval_ms: 1000,
             multi_message_delay_ms: 800,
@@ -192,6 +202,9 @@ impl DiscordChannel {
             pending_interactions: Arc::new(Mutex::new(HashMap::new())),
             pending_components: Arc::new(Mutex::new(pending::PendingComponents::default())),
             slash_command_resolver: None,
+            respond_to_edits: false,
+            edit_window_secs: 0,
+            reply_context: false,
         }
     }
 
@@ -257,6 +270,19 @@ impl DiscordChannel {
         mask
     }
 
+    /// Re-dispatch edited messages no older than `window_secs` (0 = any age).
+    pub fn with_edit_redispatch(mut self, enabled: bool, window_secs: u64) -> Self {
+        self.respond_to_edits = enabled;
+        self.edit_window_secs = window_secs;
+        self
+    }
+
+    /// Prepend the message a reply refers to as quoted context.
+    pub fn with_reply_context(mut self, enabled: bool) -> Self {
+        self.reply_context = enabled;
+        self
+    }
+
     pub fn with_approval_timeout_secs(mut self, secs: u64) -> Self {
         self.approval_timeout_secs = secs;
         self
@@ -268,6 +294,12 @@ impl DiscordChannel {
         self
     }
 
+    /// Configure limits for inbound image attachments (see `[multimodal]`).
+    pub fn with_image_limits(mut self, limits: crate::inbound_media::InboundImageLimits) -> Self {
+        self.image_limits = limits;
+        self
+    }
+
     /// Configure voice transcription for audio attachments.
     pub fn with_transcription(
         mut self,
@@ -770,6 +802,42 @@ impl DiscordChannel {
         discord_thread_parent(client, &self.bot_token, &self.thread_channels, channel_id).await
     }
 
+    /// Quoted context for a reply, or `None` when `d` is not a reply or the
+    /// referenced message is gone. Uses the inline `referenced_message` and
+    /// falls back to a REST fetch when the gateway left it out.
+    async fn reply_context_for(
+        &self,
+        client: &reqwest::Client,
+        d: &serde_json::Value,
+    ) -> Option<String> {
+        let reference = d.get("message_reference")?;
+        let referenced = match d.get("referenced_message") {
+            // Explicit null: the referenced message was deleted.
+            Some(serde_json::Value::Null) => return None,
+            Some(inline) => inline.clone(),
+            None => {
+                let message_id = reference.get("message_id")?.as_str()?;
+                let channel_id = reference
+                    .get("channel_id")
+                    .or_else(|| d.get("channel_id"))
+                    .and_then(serde_json::Value::as_str)?;
+                match fetch_discord_message(client, &self.bot_token, channel_id, message_id).await {
+                    Ok(message) => message,
+                    Err(e) => {
+                        ::zeroclaw_log::record!(
+                            DEBUG,
+                            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                                .with_attrs(::serde_json::json!({"message_id": message_id, "error": e.to_string()})),
+                            "discord reply context lookup failed"
+                        );
+                        return None;
+                    }
+                }
+            }
+        };
+        discord_reply_quote(&referenced)
+    }
+
     /// Apply the trust-boundary / delivery-failure emoji reactions to the
     /// bot's just-sent message. Best-effort: reaction failures are debug
     /// logged but never propagated. `message_id` being `None` (e.g. when
@@ -1116,14 +1184,19 @@ fn reaction_sweep_matches(key: &str, message_id: &str, emoji_key: Option<&str>)
     }
 }
 
+/// Download a message's attachments. Returns the `[Attachments]` text, the
+/// media-pipeline attachments, and the image files stored in the temp media
+/// dir (which the orchestrator removes when the turn ends).
 async fn process_attachments(
     attachments: &[serde_json::Value],
     client: &reqwest::Client,
     workspace_dir: Option<&Path>,
+    image_limits: crate::inbound_media::InboundImageLimits,
     transcription_manager: Option<&super::transcription::TranscriptionManager>,
-) -> (String, Vec<MediaAttachment>) {
+) -> (String, Vec<MediaAttachment>, Vec<InboundMediaFile>) {
     let mut text_parts: Vec<String> = Vec::new();
     let mut media: Vec<MediaAttachment> = Vec::new();
+    let mut media_files: Vec<InboundMediaFile> = Vec::new();
 
     for att in attachments {
         let ct = att
@@ -1218,6 +1291,53 @@ async fn process_attachments(
 
         let marker_kind = marker_kind_for(ct, is_audio);
 
+        if marker_kind == "IMAGE" {
+            if media_files.len() >= image_limits.max_images {
+                text_parts.push(format!(
+                    "[Image skipped: {name} — limit of {} images per message]",
+                    image_limits.max_images
+                ));
+                continue;
+            }
+            let Some(bytes) = download_attachment_bytes(client, url, name).await else {
+                continue;
+            };
+            match crate::inbound_media::store_inbound_image(
+                "discord",
+                name,
+                bytes.clone(),
+                ct,
+                image_limits,
+            )
+            .await
+            {
+                Ok(stored) => {
+                    text_parts.push(format!("[IMAGE:{}]", stored.path.display()));
+                    media.push(MediaAttachment {
+                        file_name: name.to_string(),
+                        data: bytes,
+                        mime_type: Some(ct.to_string()),
+                    });
+                    media_files.push(stored);
+                }
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                            .with_attrs(
+                                ::serde_json::json!({"name": name, "error": format!("{}", e)})
+                            ),
+                        "skipping image attachment that exceeds image limits"
+                    );
+                    text_parts.push(format!(
+                        "[Image skipped: {name} exceeds the image size limit]"
+                    ));
+                }
+            }
+            continue;
+        }
+
         let bytes = match downloaded_audio_bytes {
             Some(b) => b,
             None => match download_attachment_bytes(client, url, name).await {
@@ -1249,7 +1369,7 @@ async fn process_attachments(
         });
     }
 
-    (text_parts.join("\n---\n"), media)
+    (text_parts.join("\n---\n"), media, media_files)
 }
 
 /// Download an attachment URL into memory, with structured warn-logging on
@@ -1442,6 +1562,95 @@ fn admit_discord_message(
     Some(normalized)
 }
 
+/// Discord's snowflake epoch (2015-01-01T00:00:00Z) in Unix milliseconds.
+const DISCORD_EPOCH_MS: u64 = 1_420_070_400_000;
+
+/// Creation time of a snowflake id in Unix milliseconds.
+fn snowflake_created_ms(id: &str) -> Option<u64> {
+    Some((id.parse::<u64>().ok()? >> 22) + DISCORD_EPOCH_MS)
+}
+
+/// Whether a MESSAGE_UPDATE payload is a user edit worth re-dispatching.
+/// Discord also sends updates when link embeds unfurl; those carry no
+/// `edited_timestamp` and no content change, so they are skipped.
+fn is_redispatchable_edit(d: &serde_json::Value, window_secs: u64, now_ms: u64) -> bool {
+    let edited = d
+        .get("edited_timestamp")
+        .is_some_and(serde_json::Value::is_string);
+    if !edited || !d.get("content").is_some_and(serde_json::Value::is_string) {
+        return false;
+    }
+    if window_secs == 0 {
+        return true;
+    }
+    d.get("id")
+        .and_then(serde_json::Value::as_str)
+        .and_then(snowflake_created_ms)
+        .is_some_and(|created| now_ms.saturating_sub(created) <= window_secs * 1000)
+}
+
+/// Quote a referenced message the way Telegram reply context is quoted:
+/// `> @author:` followed by the message's lines.
+fn discord_reply_quote(referenced: &serde_json::Value) -> Option<String> {
+    let author = referenced
+        .get("author")
+        .and_then(|a| a.get("username"))
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or("unknown");
+    let content = referenced
+        .get("content")
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or("")
+        .trim();
+    let text = if !content.is_empty() {
+        content.to_string()
+    } else if referenced
+        .get("attachments")
+        .and_then(serde_json::Value::as_array)
+        .is_some_and(|a| !a.is_empty())
+    {
+        "[Attachment]".to_string()
+    } else if referenced
+        .get("embeds")
+        .and_then(serde_json::Value::as_array)
+        .is_some_and(|e| !e.is_empty())
+    {
+        "[Embed]".to_string()
+    } else {
+        return None;
+    };
+    let quoted_lines = text
+        .lines()
+        .map(|line| format!("> {line}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Some(format!("> @{author}:\n{quoted_lines}"))
+}
+
+/// Fetch one message by id. Used for reply context when the gateway
+/// payload omits `referenced_message`.
+async fn fetch_discord_message(
+    client: &reqwest::Client,
+    bot_token: &str,
+    channel_id: &str,
+    message_id: &str,
+) -> anyhow::Result<serde_json::Value> {
+    let url = format!("https://discord.com/api/v10/channels/{channel_id}/messages/{message_id}");
+    let resp = tokio::time::timeout(
+        THREAD_LOOKUP_TIMEOUT,
+        client
+            .get(&url)
+            .header("Authorization", format!("Bot {bot_token}"))
+            .send(),
+    )
+    .await
+    .map_err(|_| anyhow::anyhow!("message lookup timed out"))??;
+    if !resp.status().is_success() {
+        anyhow::bail!("message lookup failed ({})", resp.status());
+    }
+    Ok(resp.json().await?)
+}
+
 /// Minimal base64 decode (no extra dep) — only needs to decode the user ID portion
 #[allow(clippy::cast_possible_truncation)]
 fn base64_decode(input: &str) -> Option<String> {
@@ -1683,6 +1892,13 @@ impl Channel for DiscordChannel {
     }
 
     async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
+        self.send_with_receipt(message).await.map(|_| ())
+    }
+
+    async fn send_with_receipt(
+        &self,
+        message: &SendMessage,
+    ) -> anyhow::Result<Option<DeliveryReceipt>> {
         if let Some(interaction_id) = parse_discord_interaction_target(&message.recipient) {
             let pending = {
                 let guard = self.pending_interactions.lock();
@@ -1704,7 +1920,7 @@ impl Channel for DiscordChannel {
                 self.build_marker_components(&component_rows)
             };
             let client = self.http_client();
-            return deliver_interaction_answer(
+            deliver_interaction_answer(
                 &client,
                 &pending.app_id,
                 &pending.token,
@@ -1713,7 +1929,8 @@ impl Channel for DiscordChannel {
                 &embeds,
                 &component_action_rows,
             )
-            .await;
+            .await?;
+            return Ok(None);
         }
 
         let raw_content = crate::util::strip_tool_call_tags(&message.content);
@@ -1861,7 +2078,9 @@ impl Channel for DiscordChannel {
         self.apply_failure_reactions(&message.recipient, first_message_id.as_deref(), &reactions)
             .await;
 
-        Ok(())
+        Ok(first_message_id
+            .filter(|id| !id.is_empty())
+            .map(DeliveryReceipt::new))
     }
 
     #[allow(clippy::too_many_lines)]
@@ -3007,7 +3226,8 @@ impl Channel for DiscordChannel {
                     // MESSAGE_UPDATE / MESSAGE_DELETE / MESSAGE_DELETE_BULK
                     // keep the archive in sync. All three already arrive
                     // under the GUILD_MESSAGES / DIRECT_MESSAGES intents;
-                    // agent routing stays MESSAGE_CREATE-only.
+                    // agent routing is MESSAGE_CREATE-only, plus user edits
+                    // when `respond_to_edits` is on.
                     if event_type == "MESSAGE_UPDATE"
                         || event_type == "MESSAGE_DELETE"
                         || event_type == "MESSAGE_DELETE_BULK"
@@ -3016,7 +3236,15 @@ impl Channel for DiscordChannel {
                             self.sync_archive_for_message_event(event_type, d, &bot_user_id)
                                 .await;
                         }
-                        continue;
+                        let now_ms = u64::try_from(chrono::Utc::now().timestamp_millis()).unwrap_or(0);
+                        let redispatch = event_type == "MESSAGE_UPDATE"
+                            && self.respond_to_edits
+                            && event
+                                .get("d")
+                                .is_some_and(|d| is_redispatchable_edit(d, self.edit_window_secs, now_ms));
+                        if !redispatch {
+                            continue;
+                        }
                     }
 
                     if event_type == "MESSAGE_REACTION_ADD"
@@ -3044,7 +3272,9 @@ impl Channel for DiscordChannel {
                     }
 
                     // Only handle MESSAGE_CREATE (opcode 0, type "MESSAGE_CREATE")
-                    if event_type != "MESSAGE_CREATE" {
+                    // and the user edits admitted above.
+                    let is_edit = event_type == "MESSAGE_UPDATE";
+                    if event_type != "MESSAGE_CREATE" && !is_edit {
                         continue;
                     }
 
@@ -3111,7 +3341,10 @@ impl Channel for DiscordChannel {
                     }
 
                     // Archive every non-bot message to discord.db when enabled.
-                    if let Some(ref archive_mem) = archive_memory {
+                    // Edits were already applied by the archive sync above.
+                    if let Some(ref archive_mem) = archive_memory
+                        && !is_edit
+                    {
                         let archive_channel_id =
                             d.get("channel_id").and_then(|c| c.as_str()).unwrap_or("");
                         let is_dm_event = d.get("guild_id").is_none();
@@ -3192,10 +3425,11 @@ impl Channel for DiscordChannel {
                     };
 
                     let client = self.http_client();
-                    let (attachment_text, media_attachments) = process_attachments(
+                    let (attachment_text, media_attachments, media_files) = process_attachments(
                         &atts,
                         &client,
                         self.workspace_dir.as_deref(),
+                        self.image_limits,
                         self.transcription_manager.as_deref(),
                     )
                     .await;
@@ -3216,6 +3450,14 @@ impl Channel for DiscordChannel {
                         }
                     }
 
+                    let final_content = if self.reply_context
+                        && let Some(quote) = self.reply_context_for(&client, d).await
+                    {
+                        format!("{quote}\n\n{final_content}")
+                    } else {
+                        final_content
+                    };
+
                     let message_id = d.get("id").and_then(|i| i.as_str()).unwrap_or("");
                     let channel_id = d
                         .get("channel_id")
@@ -3223,7 +3465,8 @@ impl Channel for DiscordChannel {
                         .unwrap_or("")
                         .to_string();
 
-                    if !message_id.is_empty() && !channel_id.is_empty() {
+                    // The original message already carries the ACK reaction.
+                    if !is_edit && !message_id.is_empty() && !channel_id.is_empty() {
                         let reaction_channel = DiscordChannel::new(
                             self.bot_token.clone(),
                             self.guild_ids.clone(),
@@ -3264,6 +3507,8 @@ impl Channel for DiscordChannel {
                         } else {
                             format!("discord_{message_id}")
                         },
+                        edit_of: (is_edit && !message_id.is_empty())
+                            .then(|| format!("discord_{message_id}")),
                         sender: author_id.to_string(),
                         reply_target: if channel_id.is_empty() {
                             author_id.to_string()
@@ -3280,6 +3525,7 @@ impl Channel for DiscordChannel {
                         interruption_scope_id: thread_ts.clone(),
                         thread_ts,
                         attachments: media_attachments,
+                        media_files,
                         subject: None,
 
                         ..Default::default()};
@@ -4022,6 +4268,7 @@ impl Channel for DiscordChannel {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::inbound_media::{InboundImageLimits, InboundMediaCleanup};
 
     #[test]
     fn effective_recipient_prefers_per_message_target() {
@@ -4426,6 +4673,7 @@ mod tests {
             tools: vec![],
             prompts: vec![],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         }
     }
@@ -6575,9 +6823,62 @@ mod tests {
     #[tokio::test]
     async fn process_attachments_empty_list_returns_empty() {
         let client = reqwest::Client::new();
-        let (text, media) = process_attachments(&[], &client, None, None).await;
+        let (text, media, media_files) =
+            process_attachments(&[], &client, None, InboundImageLimits::default(), None).await;
         assert!(text.is_empty());
         assert!(media.is_empty());
+        assert!(media_files.is_empty());
+    }
+
+    #[tokio::test]
+    async fn process_attachments_stores_images_up_to_per_message_cap() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let png = {
+            let img = image::DynamicImage::ImageRgb8(image::RgbImage::new(8, 8));
+            let mut cursor = std::io::Cursor::new(Vec::new());
+            img.write_to(&mut cursor, image::ImageFormat::Png).unwrap();
+            cursor.into_inner()
+        };
+        let media_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/a.png"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(png))
+            .expect(1)
+            .mount(&media_server)
+            .await;
+
+        let attachments = vec![
+            serde_json::json!({
+                "content_type": "image/png",
+                "filename": "a.png",
+                "url": format!("{}/a.png", media_server.uri()),
+            }),
+            serde_json::json!({
+                "content_type": "image/png",
+                "filename": "b.png",
+                "url": format!("{}/b.png", media_server.uri()),
+            }),
+        ];
+        let limits = InboundImageLimits {
+            max_images: 1,
+            ..InboundImageLimits::default()
+        };
+
+        let client = reqwest::Client::new();
+        let (text, media, media_files) =
+            process_attachments(&attachments, &client, None, limits, None).await;
+
+        assert_eq!(media.len(), 1);
+        assert_eq!(media_files.len(), 1);
+        let stored = media_files[0].path.clone();
+        assert!(stored.starts_with(crate::inbound_media::media_dir("discord")));
+        assert!(text.contains(&format!("[IMAGE:{}]", stored.display())));
+        assert!(text.contains("[Image skipped: b.png"));
+
+        drop(InboundMediaCleanup::new(&media_files));
+        assert!(!stored.exists());
     }
 
     #[tokio::test]
@@ -6617,8 +6918,14 @@ mod tests {
                 .with_agent_transcription_provider("local_whisper");
 
         let client = reqwest::Client::new();
-        let (text, media) =
-            process_attachments(&attachments, &client, None, Some(&transcription)).await;
+        let (text, media, _) = process_attachments(
+            &attachments,
+            &client,
+            None,
+            InboundImageLimits::default(),
+            Some(&transcription),
+        )
+        .await;
 
         assert_eq!(
             text,
@@ -6664,8 +6971,14 @@ mod tests {
                 .with_agent_transcription_provider("local_whisper");
 
         let client = reqwest::Client::new();
-        let (text, media) =
-            process_attachments(&attachments, &client, None, Some(&transcription)).await;
+        let (text, media, _) = process_attachments(
+            &attachments,
+            &client,
+            None,
+            InboundImageLimits::default(),
+            Some(&transcription),
+        )
+        .await;
 
         assert_eq!(
             text,
@@ -8063,4 +8376,60 @@ mod tests {
         assert!(resp.status().is_success());
         // wiremock verifies expect(1) on drop.
     }
+
+    fn snowflake_at(created_ms: u64) -> String {
+        ((created_ms - DISCORD_EPOCH_MS) << 22).to_string()
+    }
+
+    #[test]
+    fn embed_unfurl_update_is_not_redispatched() {
+        let now = 1_800_000_000_000;
+        let d = serde_json::json!({
+            "id": snowflake_at(now - 1_000),
+            "edited_timestamp": null,
+            "embeds": [{ "url": "https://example.com" }]
+        });
+        assert!(!is_redispatchable_edit(&d, 300, now));
+    }
+
+    #[test]
+    fn edit_inside_window_is_redispatched_and_stale_edit_is_not() {
+        let now = 1_800_000_000_000;
+        let fresh = serde_json::json!({
+            "id": snowflake_at(now - 60_000),
+            "content": "fixed typo",
+            "edited_timestamp": "2027-01-15T08:00:00+00:00"
+        });
+        let stale = serde_json::json!({
+            "id": snowflake_at(now - 600_000),
+            "content": "fixed typo",
+            "edited_timestamp": "2027-01-15T08:00:00+00:00"
+        });
+        assert!(is_redispatchable_edit(&fresh, 300, now));
+        assert!(!is_redispatchable_edit(&stale, 300, now));
+        assert!(is_redispatchable_edit(&stale, 0, now));
+    }
+
+    #[test]
+    fn reply_quote_names_author_and_quotes_each_line() {
+        let referenced = serde_json::json!({
+            "author": { "username": "alice" },
+            "content": "first line\nsecond line"
+        });
+        assert_eq!(
+            discord_reply_quote(&referenced).as_deref(),
+            Some("> @alice:\n> first line\n> second line")
+        );
+
+        let attachment_only = serde_json::json!({
+            "author": { "username": "bob" },
+            "content": "",
+            "attachments": [{ "id": "1" }]
+        });
+        assert_eq!(
+            discord_reply_quote(&attachment_only).as_deref(),
+            Some("> @bob:\n> [Attachment]")
+        );
+        assert!(discord_reply_quote(&serde_json::json!({ "content": "" })).is_none());
+    }
 }
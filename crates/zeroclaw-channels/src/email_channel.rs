@@ -18,11 +18,12 @@ use lettre::message::{Attachment, MultiPart, SinglePart};
 use lettre::transport::smtp::authentication::Credentials;
 use lettre::{Message, SmtpTransport, Transport};
 use mail_parser::{MessageParser, MimeHeaders};
-use pulldown_cmark::{Options, Parser, html};
+use pulldown_cmark::{Event, Options, Parser, html};
 use rustls::{ClientConfig, RootCertStore};
 use rustls_pki_types::DnsName;
 use sha2::{Digest, Sha256};
 use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::net::TcpStream;
@@ -30,6 +31,7 @@ use tokio::sync::{Mutex, mpsc};
 use tokio::time::{sleep, timeout};
 use tokio_rustls::TlsConnector;
 
+use crate::email_seen_state::SeenMessageIds;
 use zeroclaw_api::channel::{Channel, ChannelMessage, SendMessage};
 use zeroclaw_tools::email_imap::{ImapSession, TlsStreamTolerant};
 
@@ -48,8 +50,15 @@ pub struct EmailChannel {
     /// Resolves inbound external peers from canonical state at message-time.
     /// No cache (see AGENTS.md "ABSOLUTE RULE — SINGLE SOURCE OF TRUTH").
     pub peer_resolver: Arc<dyn Fn() -> Vec<String> + Send + Sync>,
-    seen_messages: Arc<Mutex<HashSet<String>>>,
+    /// Message-IDs already dispatched. Persisted under the workspace once
+    /// one is set, so restarts don't redeliver.
+    seen_messages: Arc<Mutex<SeenMessageIds>>,
     auth_service: Option<Arc<zeroclaw_providers::auth::AuthService>>,
+    /// Owning agent's workspace, resolved by the orchestrator from
+    /// `Config::channel_workspace_dir("email.<alias>")`. Inbound attachments
+    /// are saved under it and outbound `[DOCUMENT:<path>]` markers must
+    /// resolve inside it.
+    workspace_dir: Option<PathBuf>,
 }
 
 impl EmailChannel {
@@ -62,11 +71,20 @@ impl EmailChannel {
             config,
             alias: alias.into(),
             peer_resolver,
-            seen_messages: Arc::new(Mutex::new(HashSet::new())),
+            seen_messages: Arc::new(Mutex::new(SeenMessageIds::default())),
             auth_service: None,
+            workspace_dir: None,
         }
     }
 
+    /// Set the workspace directory used for inbound attachment storage and
+    /// outbound attachment marker resolution.
+    pub fn with_workspace_dir(mut self, dir: PathBuf) -> Self {
+        self.seen_messages = Arc::new(Mutex::new(SeenMessageIds::load(Some(&dir), &self.alias)));
+        self.workspace_dir = Some(dir);
+        self
+    }
+
     /// Wire in the auth service so XOAUTH2 token refresh works for
     /// channels configured with `[channels.email.<alias>.oauth2]`.
     pub fn with_auth_service(
@@ -98,28 +116,6 @@ impl EmailChannel {
         })
     }
 
-    /// Strip HTML tags from content (basic)
-    pub fn strip_html(html: &str) -> String {
-        let mut result = String::new();
-        let mut in_tag = false;
-        for ch in html.chars() {
-            match ch {
-                '<' => in_tag = true,
-                '>' => in_tag = false,
-                _ if !in_tag => result.push(ch),
-                _ => {}
-            }
-        }
-        let mut normalized = String::with_capacity(result.len());
-        for word in result.split_whitespace() {
-            if !normalized.is_empty() {
-                normalized.push(' ');
-            }
-            normalized.push_str(word);
-        }
-        normalized
-    }
-
     /// Extract the sender address from a parsed email
     fn extract_sender(parsed: &mail_parser::Message) -> String {
         parsed
@@ -130,13 +126,33 @@ impl EmailChannel {
             .unwrap_or_else(|| "unknown".into())
     }
 
-    /// Extract readable text from a parsed email
+    /// Extract readable text from a parsed email.
+    ///
+    /// Inline `text/plain` body parts are used verbatim. HTML-only mail is
+    /// converted to text with block structure and link targets preserved,
+    /// so the agent does not see raw markup.
     fn extract_text(parsed: &mail_parser::Message) -> String {
-        if let Some(text) = parsed.body_text(0) {
-            return text.to_string();
+        let sections: Vec<String> = parsed
+            .text_bodies()
+            .filter(|part| part.is_text())
+            .filter_map(|part| {
+                let text = part.text_contents()?;
+                let text = if part.is_text_html() {
+                    html_to_text(text)
+                } else {
+                    text.trim().to_string()
+                };
+                (!text.is_empty()).then_some(text)
+            })
+            .collect();
+        if !sections.is_empty() {
+            return sections.join("\n\n");
         }
-        if let Some(html) = parsed.body_html(0) {
-            return Self::strip_html(html.as_ref());
+        if let Some(html) = parsed.html_part(0).and_then(|part| part.text_contents()) {
+            let text = html_to_text(html);
+            if !text.is_empty() {
+                return text;
+            }
         }
         for part in parsed.attachments() {
             let part: &mail_parser::MessagePart = part;
@@ -165,9 +181,11 @@ impl EmailChannel {
             let mime_str =
                 ct.map(|c| format!("{}/{}", c.ctype(), c.subtype().unwrap_or("octet-stream")));
 
-            // Skip text parts — already handled by extract_text
+            // Skip unnamed text parts — already handled by extract_text.
+            // Named text files (CSV, logs, source) are real attachments.
             if let Some(ref m) = mime_str
                 && m.starts_with("text/")
+                && MimeHeaders::attachment_name(part).is_none()
             {
                 continue;
             }
@@ -375,8 +393,14 @@ impl EmailChannel {
                     .unwrap_or(0)
             });
         let attachments = self.extract_attachments(parsed);
+        let thread_root = thread_root(
+            &msg_id,
+            &header_message_ids(parsed.in_reply_to()),
+            &header_message_ids(parsed.references()),
+        );
         ParsedEmail {
             msg_id,
+            thread_root,
             sender,
             subject,
             content,
@@ -763,15 +787,23 @@ impl EmailChannel {
         if !is_new {
             return Ok(true);
         }
+        let mut content = email.content;
+        let references = self
+            .persist_attachments(&email.msg_id, &email.attachments)
+            .await;
+        if !references.is_empty() {
+            content.push_str("\n\n");
+            content.push_str(&references.join("\n"));
+        }
         let msg = ChannelMessage {
             id: email.msg_id,
             reply_target: email.sender.clone(),
             sender: email.sender,
-            content: email.content,
+            content,
             channel: "email".to_string(),
             channel_alias: Some(self.alias.clone()),
             timestamp: email.timestamp,
-            thread_ts: None,
+            thread_ts: Some(email.thread_root),
             interruption_scope_id: None,
             attachments: email.attachments,
             subject: Some(email.subject),
@@ -781,6 +813,122 @@ impl EmailChannel {
         Ok(tx.send(msg).await.is_ok())
     }
 
+    /// Directory inbound attachments are written to. Prefers the owning
+    /// agent's workspace so file tools can reach the files; falls back to a
+    /// per-alias temp dir when the channel was built without one.
+    fn attachments_root(&self) -> PathBuf {
+        match self.workspace_dir {
+            Some(ref ws) => ws.join("attachments"),
+            None => std::env::temp_dir()
+                .join("zeroclaw-email")
+                .join(sanitize_attachment_name(&self.alias)),
+        }
+    }
+
+    /// Save inbound attachments to disk and return one
+    /// `[Attachment: <name>] <path>` reference line per saved file.
+    /// Failures are logged and the file is skipped; the message is still
+    /// delivered.
+    async fn persist_attachments(
+        &self,
+        msg_id: &str,
+        attachments: &[zeroclaw_api::media::MediaAttachment],
+    ) -> Vec<String> {
+        if attachments.is_empty() {
+            return Vec::new();
+        }
+        let digest = Sha256::digest(msg_id.as_bytes());
+        let dir = self
+            .attachments_root()
+            .join(format!("email-{}", hex::encode(&digest[..8])));
+        if let Err(e) = tokio::fs::create_dir_all(&dir).await {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure),
+                &format!(
+                    "Failed to create email attachment dir {}: {e}",
+                    dir.display()
+                )
+            );
+            return Vec::new();
+        }
+
+        let mut used_names = HashSet::new();
+        let mut references = Vec::with_capacity(attachments.len());
+        for (index, att) in attachments.iter().enumerate() {
+            let mut name = sanitize_attachment_name(&att.file_name);
+            if !used_names.insert(name.clone()) {
+                // Prefix with the 1-based position so same-named parts don't overwrite each other.
+                name = format!("{}-{name}", index + 1);
+                used_names.insert(name.clone());
+            }
+            let path = dir.join(&name);
+            match tokio::fs::write(&path, &att.data).await {
+                Ok(()) => references.push(format!("[Attachment: {name}] {}", path.display())),
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure),
+                        &format!("Failed to save email attachment {}: {e}", path.display())
+                    );
+                }
+            }
+        }
+        references
+    }
+
+    /// Resolve outbound `[DOCUMENT:<path>]`-style markers into MIME parts.
+    ///
+    /// Local targets must resolve inside the workspace and fit within the
+    /// remaining `max_attachment_bytes` budget. URLs are kept as links in the
+    /// body. Anything rejected is reported back as a short body note so the
+    /// recipient knows a file was meant to be there.
+    fn resolve_outbound_markers(
+        &self,
+        markers: Vec<(String, String)>,
+        mut budget: usize,
+    ) -> (Vec<AttachmentPart>, Vec<String>) {
+        let mut parts = Vec::new();
+        let mut notes = Vec::new();
+        for (_kind, target) in markers {
+            if target.starts_with("http://") || target.starts_with("https://") {
+                notes.push(target);
+                continue;
+            }
+            let display_name = Path::new(&target)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or(&target)
+                .to_string();
+            match read_workspace_attachment(&target, self.workspace_dir.as_deref(), budget) {
+                Ok(data) => {
+                    budget -= data.len();
+                    let content_type = ContentType::parse(
+                        mime_guess::from_path(&display_name)
+                            .first_or_octet_stream()
+                            .essence_str(),
+                    )
+                    .unwrap_or_else(|_| {
+                        ContentType::parse("application/octet-stream").expect("hardcoded MIME type")
+                    });
+                    parts.push((display_name, data, content_type));
+                }
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure),
+                        &format!("Dropping email attachment marker {target}: {e}")
+                    );
+                    notes.push(format!("[attachment omitted: {display_name}]"));
+                }
+            }
+        }
+        (parts, notes)
+    }
+
     /// Process newly arrived messages (UID >= uid_threshold). Returns updated threshold.
     async fn process_new(
         &self,
@@ -801,6 +949,103 @@ impl EmailChannel {
         Ok(new_threshold)
     }
 
+    /// Build the outbound MIME message for `message`: plain or
+    /// `multipart/alternative` body, plus explicit and marker attachments.
+    /// Returns the message and the number of attached files.
+    fn build_email(&self, message: &SendMessage) -> Result<(Message, usize)> {
+        // Use explicit subject if provided, otherwise fall back to legacy parsing or default
+        let default_subject = self.config.default_subject.as_str();
+        let (subject, body) = if let Some(ref subj) = message.subject {
+            (subj.as_str(), message.content.as_str())
+        } else if message.content.starts_with("Subject: ") {
+            if let Some(pos) = message.content.find('\n') {
+                (&message.content[9..pos], message.content[pos + 1..].trim())
+            } else {
+                (default_subject, message.content.as_str())
+            }
+        } else {
+            (default_subject, message.content.as_str())
+        };
+
+        let threading =
+            reply_threading_headers(message.in_reply_to.as_deref(), message.thread_ts.as_deref());
+        let subject = if threading.is_some() {
+            reply_subject(subject)
+        } else {
+            subject.to_string()
+        };
+
+        let mut builder = Message::builder()
+            .from(self.config.from_address.parse()?)
+            .to(message.recipient.parse()?)
+            .subject(subject);
+        if let Some((in_reply_to, references)) = threading {
+            builder = builder.in_reply_to(in_reply_to).references(references);
+        }
+        let mut att_parts: Vec<AttachmentPart> = Vec::new();
+        for att in &message.attachments {
+            let content_type = att
+                .mime_type
+                .as_deref()
+                .and_then(|m| ContentType::parse(m).ok())
+                .unwrap_or_else(|| {
+                    ContentType::parse("application/octet-stream").expect("hardcoded MIME type")
+                });
+            let att_data = resolve_attachment_data(&att.file_name, &att.data)?;
+            let att_name = std::path::Path::new(&att.file_name)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or(&att.file_name)
+                .to_string();
+            att_parts.push((att_name, att_data, content_type));
+        }
+
+        let (cleaned, markers) =
+            crate::util::parse_attachment_markers_of_kinds(body, OUTBOUND_MARKER_KINDS);
+        let body = if markers.is_empty() {
+            body.to_string()
+        } else {
+            let used: usize = att_parts.iter().map(|(_, data, _)| data.len()).sum();
+            let budget = self.config.max_attachment_bytes.saturating_sub(used);
+            let (marker_parts, notes) = self.resolve_outbound_markers(markers, budget);
+            att_parts.extend(marker_parts);
+            let mut cleaned = cleaned.trim().to_string();
+            if !notes.is_empty() {
+                cleaned.push_str("\n\n");
+                cleaned.push_str(&notes.join("\n"));
+            }
+            cleaned
+        };
+        let attachment_count = att_parts.len();
+
+        let email = if self.config.html_body {
+            let alt = MultiPart::alternative()
+                .singlepart(SinglePart::plain(body.clone()))
+                .singlepart(SinglePart::html(markdown_to_html(&body)));
+            if att_parts.is_empty() {
+                builder.multipart(alt)?
+            } else {
+                let mut mixed = MultiPart::mixed().multipart(alt);
+                for (name, data, ct) in att_parts {
+                    mixed = mixed.singlepart(Attachment::new(name).body(data, ct));
+                }
+                builder.multipart(mixed)?
+            }
+        } else {
+            let plain = SinglePart::plain(body);
+            if att_parts.is_empty() {
+                builder.singlepart(plain)?
+            } else {
+                let mut mixed = MultiPart::mixed().singlepart(plain);
+                for (name, data, ct) in att_parts {
+                    mixed = mixed.singlepart(Attachment::new(name).body(data, ct));
+                }
+                builder.multipart(mixed)?
+            }
+        };
+        Ok((email, attachment_count))
+    }
+
     fn smtp_credentials(&self) -> Credentials {
         let user = smtp_credential_override(self.config.smtp_username.as_deref())
             .unwrap_or(&self.config.username)
@@ -831,6 +1076,9 @@ impl EmailChannel {
 /// Internal struct for parsed email data
 struct ParsedEmail {
     msg_id: String,
+    /// Message-ID of the thread's first message; becomes `thread_ts` so
+    /// every reply in the thread shares one conversation history.
+    thread_root: String,
     sender: String,
     subject: String,
     content: String,
@@ -854,14 +1102,96 @@ impl ::zeroclaw_api::attribution::Attributable for EmailChannel {
     }
 }
 
+/// Outbound attachment as `(file name, bytes, content type)`.
+type AttachmentPart = (String, Vec<u8>, ContentType);
+
+/// Marker kinds that become MIME attachments on outbound mail.
+const OUTBOUND_MARKER_KINDS: &[&str] = &[
+    "DOCUMENT", "FILE", "IMAGE", "PHOTO", "VIDEO", "AUDIO", "VOICE",
+];
+
+/// Render the agent's Markdown reply as a complete HTML document.
+///
+/// Raw HTML in the Markdown source is escaped rather than passed through, so
+/// model output can never inject markup (scripts, tracking pixels, forms)
+/// into the recipient's mail client.
 fn markdown_to_html(md: &str) -> String {
     let mut options = Options::empty();
     options.insert(Options::ENABLE_TABLES);
     options.insert(Options::ENABLE_STRIKETHROUGH);
-    let parser = Parser::new_ext(md, options);
-    let mut html_output = String::new();
-    html::push_html(&mut html_output, parser);
-    html_output
+    let parser = Parser::new_ext(md, options).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        other => other,
+    });
+    let mut fragment = String::new();
+    html::push_html(&mut fragment, parser);
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         </head>\n<body style=\"font-family: -apple-system, 'Segoe UI', Helvetica, Arial, \
+         sans-serif; font-size: 14px; line-height: 1.5;\">\n{fragment}</body>\n</html>\n"
+    )
+}
+
+/// Convert an HTML body to readable plain text.
+fn html_to_text(html: &str) -> String {
+    nanohtml2text::html2text(html).trim().to_string()
+}
+
+/// Reduce an attachment filename to a safe single path component.
+fn sanitize_attachment_name(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or(name);
+    let cleaned: String = base
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let cleaned = cleaned.trim_start_matches('.');
+    if cleaned.is_empty() {
+        "attachment".to_string()
+    } else {
+        cleaned.chars().take(128).collect()
+    }
+}
+
+/// Read a marker target as attachment bytes, confined to `workspace_dir`
+/// and capped at `budget` bytes.
+fn read_workspace_attachment(
+    target: &str,
+    workspace_dir: Option<&Path>,
+    budget: usize,
+) -> anyhow::Result<Vec<u8>> {
+    let workspace = workspace_dir
+        .ok_or_else(|| anyhow::Error::msg("email channel was started without a workspace_dir"))?;
+    let workspace = std::fs::canonicalize(workspace)
+        .map_err(|e| anyhow::Error::msg(format!("canonicalize workspace_dir failed: {e}")))?;
+    // Remap the Docker container workspace path (/workspace/...) to the host.
+    let target = target.strip_prefix("/workspace/").unwrap_or(target);
+    let candidate = Path::new(target);
+    let candidate = if candidate.is_absolute() {
+        candidate.to_path_buf()
+    } else {
+        workspace.join(candidate)
+    };
+    let candidate = std::fs::canonicalize(&candidate)
+        .map_err(|e| anyhow::Error::msg(format!("attachment not found: {e}")))?;
+    if !candidate.starts_with(&workspace) {
+        anyhow::bail!("attachment resolves outside workspace_dir");
+    }
+    let metadata = std::fs::metadata(&candidate)?;
+    if !metadata.is_file() {
+        anyhow::bail!("attachment is not a regular file");
+    }
+    let len = usize::try_from(metadata.len()).unwrap_or(usize::MAX);
+    if len > budget {
+        anyhow::bail!("attachment is {len} bytes, exceeds remaining budget of {budget} bytes");
+    }
+    Ok(std::fs::read(&candidate)?)
 }
 
 fn smtp_credential_override(value: Option<&str>) -> Option<&str> {
@@ -872,6 +1202,75 @@ fn is_synthetic_email_message_id(value: &str) -> bool {
     value.starts_with("email-imap-") || value.starts_with("email-fallback-")
 }
 
+/// Message-IDs from an `In-Reply-To` or `References` header, without angle
+/// brackets (mail-parser strips them).
+fn header_message_ids(value: &mail_parser::HeaderValue) -> Vec<String> {
+    match value {
+        mail_parser::HeaderValue::Text(id) => vec![id.to_string()],
+        mail_parser::HeaderValue::TextList(ids) => ids.iter().map(|id| id.to_string()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// The thread an inbound message belongs to. `References` lists ancestors
+/// oldest first, so its head is the root; clients that only send
+/// `In-Reply-To` thread under the parent instead; a message with neither
+/// starts its own thread.
+fn thread_root(message_id: &str, in_reply_to: &[String], references: &[String]) -> String {
+    references
+        .iter()
+        .chain(in_reply_to)
+        .map(|id| id.trim())
+        .find(|id| !id.is_empty())
+        .unwrap_or(message_id)
+        .to_string()
+}
+
+fn angle_bracketed(id: &str) -> String {
+    let id = id.trim();
+    if id.starts_with('<') && id.ends_with('>') {
+        id.to_string()
+    } else {
+        format!("<{id}>")
+    }
+}
+
+/// `In-Reply-To` and `References` values for an outbound reply. The parent
+/// is the message being answered, falling back to the thread root.
+/// `References` carries the root and the parent, the trimmed form RFC 5322
+/// §3.6.4 allows. Synthetic ids for mail that arrived without a Message-ID
+/// are never sent.
+fn reply_threading_headers(
+    in_reply_to: Option<&str>,
+    thread_root: Option<&str>,
+) -> Option<(String, String)> {
+    let real = |id: Option<&str>| {
+        id.map(str::trim)
+            .filter(|id| !id.is_empty() && !is_synthetic_email_message_id(id))
+    };
+    let root = real(thread_root);
+    let parent = real(in_reply_to).or(root)?;
+    let mut references = Vec::with_capacity(2);
+    if let Some(root) = root
+        && root != parent
+    {
+        references.push(angle_bracketed(root));
+    }
+    references.push(angle_bracketed(parent));
+    Some((angle_bracketed(parent), references.join(" ")))
+}
+
+fn reply_subject(subject: &str) -> String {
+    if subject
+        .get(..3)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("re:"))
+    {
+        subject.to_string()
+    } else {
+        format!("Re: {subject}")
+    }
+}
+
 #[async_trait]
 
 impl Channel for EmailChannel {
@@ -890,73 +1289,7 @@ impl Channel for EmailChannel {
     }
 
     async fn send(&self, message: &SendMessage) -> Result<()> {
-        // Use explicit subject if provided, otherwise fall back to legacy parsing or default
-        let default_subject = self.config.default_subject.as_str();
-        let (subject, body) = if let Some(ref subj) = message.subject {
-            (subj.as_str(), message.content.as_str())
-        } else if message.content.starts_with("Subject: ") {
-            if let Some(pos) = message.content.find('\n') {
-                (&message.content[9..pos], message.content[pos + 1..].trim())
-            } else {
-                (default_subject, message.content.as_str())
-            }
-        } else {
-            (default_subject, message.content.as_str())
-        };
-
-        let mut builder = Message::builder()
-            .from(self.config.from_address.parse()?)
-            .to(message.recipient.parse()?)
-            .subject(subject);
-        if let Some(ref reply_id) = message.in_reply_to
-            && !is_synthetic_email_message_id(reply_id)
-        {
-            builder = builder.in_reply_to(reply_id.clone());
-        }
-        let mut att_parts: Vec<(String, Vec<u8>, ContentType)> = Vec::new();
-        for att in &message.attachments {
-            let content_type = att
-                .mime_type
-                .as_deref()
-                .and_then(|m| ContentType::parse(m).ok())
-                .unwrap_or_else(|| {
-                    ContentType::parse("application/octet-stream").expect("hardcoded MIME type")
-                });
-            let att_data = resolve_attachment_data(&att.file_name, &att.data)?;
-            let att_name = std::path::Path::new(&att.file_name)
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or(&att.file_name)
-                .to_string();
-            att_parts.push((att_name, att_data, content_type));
-        }
-
-        let email = if self.config.html_body {
-            let alt = MultiPart::alternative()
-                .singlepart(SinglePart::plain(body.to_string()))
-                .singlepart(SinglePart::html(markdown_to_html(body)));
-            if att_parts.is_empty() {
-                builder.multipart(alt)?
-            } else {
-                let mut mixed = MultiPart::mixed().multipart(alt);
-                for (name, data, ct) in att_parts {
-                    mixed = mixed.singlepart(Attachment::new(name).body(data, ct));
-                }
-                builder.multipart(mixed)?
-            }
-        } else {
-            let plain = SinglePart::plain(body.to_string());
-            if att_parts.is_empty() {
-                builder.singlepart(plain)?
-            } else {
-                let mut mixed = MultiPart::mixed().singlepart(plain);
-                for (name, data, ct) in att_parts {
-                    mixed = mixed.singlepart(Attachment::new(name).body(data, ct));
-                }
-                builder.multipart(mixed)?
-            }
-        };
-
+        let (email, attachment_count) = self.build_email(message)?;
         let transport = self.create_smtp_transport()?;
         transport.send(&email)?;
         ::zeroclaw_log::record!(
@@ -964,8 +1297,7 @@ impl Channel for EmailChannel {
             ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
             &format!(
                 "Email sent to {} ({} attachments)",
-                message.recipient,
-                message.attachments.len()
+                message.recipient, attachment_count
             )
         );
         Ok(())
@@ -1601,75 +1933,6 @@ mod tests {
         assert!(channel.is_sender_allowed("@example.com"));
     }
 
-    // strip_html tests
-
-    #[test]
-    fn strip_html_basic() {
-        assert_eq!(EmailChannel::strip_html("<p>Hello</p>"), "Hello");
-        assert_eq!(EmailChannel::strip_html("<div>World</div>"), "World");
-    }
-
-    #[test]
-    fn strip_html_nested_tags() {
-        assert_eq!(
-            EmailChannel::strip_html("<div><p>Hello <strong>World</strong></p></div>"),
-            "Hello World"
-        );
-    }
-
-    #[test]
-    fn strip_html_multiple_lines() {
-        let html = "<div>\n  <p>Line 1</p>\n  <p>Line 2</p>\n</div>";
-        assert_eq!(EmailChannel::strip_html(html), "Line 1 Line 2");
-    }
-
-    #[test]
-    fn strip_html_preserves_text() {
-        assert_eq!(EmailChannel::strip_html("No tags here"), "No tags here");
-        assert_eq!(EmailChannel::strip_html(""), "");
-    }
-
-    #[test]
-    fn strip_html_handles_malformed() {
-        assert_eq!(EmailChannel::strip_html("<p>Unclosed"), "Unclosed");
-        // The function removes everything between < and >, so "Text>with>brackets" becomes "Textwithbrackets"
-        assert_eq!(
-            EmailChannel::strip_html("Text>with>brackets"),
-            "Textwithbrackets"
-        );
-    }
-
-    #[test]
-    fn strip_html_self_closing_tags() {
-        // Self-closing tags are removed but don't add spaces
-        assert_eq!(EmailChannel::strip_html("Hello<br/>World"), "HelloWorld");
-        assert_eq!(EmailChannel::strip_html("Text<hr/>More"), "TextMore");
-    }
-
-    #[test]
-    fn strip_html_attributes_preserved() {
-        assert_eq!(
-            EmailChannel::strip_html("<a href=\"http://example.com\">Link</a>"),
-            "Link"
-        );
-    }
-
-    #[test]
-    fn strip_html_multiple_spaces_collapsed() {
-        assert_eq!(
-            EmailChannel::strip_html("<p>Word</p>  <p>Word</p>"),
-            "Word Word"
-        );
-    }
-
-    #[test]
-    fn strip_html_special_characters() {
-        assert_eq!(
-            EmailChannel::strip_html("<span>&lt;tag&gt;</span>"),
-            "&lt;tag&gt;"
-        );
-    }
-
     // Default function tests
 
     #[test]
@@ -1886,4 +2149,329 @@ mod tests {
         );
         assert_eq!(creds, expected);
     }
+
+    const HTML_ONLY_EML: &[u8] = include_bytes!("../tests/fixtures/email/html_only.eml");
+    const MIXED_EML: &[u8] = include_bytes!("../tests/fixtures/email/mixed_with_attachments.eml");
+
+    fn send_message(content: &str) -> SendMessage {
+        SendMessage::new(content, "alice@example.com").subject("Re: report")
+    }
+
+    #[test]
+    fn extract_text_prefers_plain_alternative() {
+        let parsed = parse_test_email(MIXED_EML);
+        let text = EmailChannel::extract_text(&parsed);
+        assert_eq!(text, "Please review the attached invoice and usage export.");
+    }
+
+    #[test]
+    fn extract_text_converts_html_only_body() {
+        let parsed = parse_test_email(HTML_ONLY_EML);
+        let text = EmailChannel::extract_text(&parsed);
+        assert!(text.contains("Hi there,"), "{text}");
+        assert!(text.contains("12%"), "{text}");
+        assert!(text.contains("Retention"), "{text}");
+        assert!(text.contains("report"), "{text}");
+        assert!(!text.contains('<'), "markup leaked: {text}");
+        assert!(!text.contains("color: red"), "style leaked: {text}");
+    }
+
+    #[test]
+    fn extract_attachments_keeps_named_text_files() {
+        let channel = EmailChannel::new(mailbox_identity_config(), "default", empty_resolver());
+        let parsed = parse_test_email(MIXED_EML);
+        let attachments = channel.extract_attachments(&parsed);
+        let names: Vec<&str> = attachments.iter().map(|a| a.file_name.as_str()).collect();
+        assert_eq!(names, ["invoice.pdf", "usage.csv"]);
+        assert!(attachments[0].data.starts_with(b"%PDF-1.4"));
+        assert_eq!(attachments[1].mime_type.as_deref(), Some("text/csv"));
+    }
+
+    #[tokio::test]
+    async fn persist_attachments_writes_into_workspace() {
+        let ws = tempfile::tempdir().unwrap();
+        let channel = EmailChannel::new(mailbox_identity_config(), "default", empty_resolver())
+            .with_workspace_dir(ws.path().to_path_buf());
+        let attachments = vec![
+            zeroclaw_api::media::MediaAttachment {
+                file_name: "../../etc/passwd".into(),
+                data: b"one".to_vec(),
+                mime_type: None,
+            },
+            zeroclaw_api::media::MediaAttachment {
+                file_name: "passwd".into(),
+                data: b"two".to_vec(),
+                mime_type: None,
+            },
+        ];
+        let refs = channel
+            .persist_attachments("<mixed-1@example.com>", &attachments)
+            .await;
+        assert_eq!(refs.len(), 2);
+
+        let dir = std::fs::read_dir(ws.path().join("attachments"))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        assert_eq!(std::fs::read(dir.join("passwd")).unwrap(), b"one");
+        assert_eq!(std::fs::read(dir.join("2-passwd")).unwrap(), b"two");
+        assert_eq!(
+            refs[0],
+            format!("[Attachment: passwd] {}", dir.join("passwd").display())
+        );
+    }
+
+    #[test]
+    fn sanitize_attachment_name_strips_paths_and_unsafe_chars() {
+        assert_eq!(sanitize_attachment_name("report.pdf"), "report.pdf");
+        assert_eq!(sanitize_attachment_name("../../etc/passwd"), "passwd");
+        assert_eq!(sanitize_attachment_name("C:\\tmp\\a b.txt"), "a_b.txt");
+        assert_eq!(sanitize_attachment_name(".hidden"), "hidden");
+        assert_eq!(sanitize_attachment_name(""), "attachment");
+    }
+
+    #[test]
+    fn markdown_to_html_escapes_raw_html() {
+        let html = markdown_to_html("**hi** <script>alert(1)</script>");
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<meta charset=\"utf-8\">"));
+        assert!(html.contains("<strong>hi</strong>"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;script&gt;"));
+    }
+
+    #[test]
+    fn read_workspace_attachment_confines_to_workspace() {
+        let ws = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        std::fs::write(ws.path().join("notes.txt"), b"hello").unwrap();
+        std::fs::write(outside.path().join("secret.txt"), b"nope").unwrap();
+
+        let data = read_workspace_attachment("notes.txt", Some(ws.path()), 1024).unwrap();
+        assert_eq!(data, b"hello");
+        let data =
+            read_workspace_attachment("/workspace/notes.txt", Some(ws.path()), 1024).unwrap();
+        assert_eq!(data, b"hello");
+
+        let escape = outside.path().join("secret.txt");
+        assert!(
+            read_workspace_attachment(escape.to_str().unwrap(), Some(ws.path()), 1024).is_err()
+        );
+        assert!(read_workspace_attachment("../secret.txt", Some(ws.path()), 1024).is_err());
+        assert!(read_workspace_attachment("notes.txt", Some(ws.path()), 4).is_err());
+        assert!(read_workspace_attachment("notes.txt", None, 1024).is_err());
+    }
+
+    #[test]
+    fn build_email_round_trips_html_and_document_marker() {
+        let ws = tempfile::tempdir().unwrap();
+        std::fs::write(ws.path().join("summary.csv"), b"a,b\n1,2\n").unwrap();
+        let channel = EmailChannel::new(mailbox_identity_config(), "default", empty_resolver())
+            .with_workspace_dir(ws.path().to_path_buf());
+
+        let (email, count) = channel
+            .build_email(&send_message(
+                "Here is the **summary**.\n\n[DOCUMENT:summary.csv]",
+            ))
+            .unwrap();
+        assert_eq!(count, 1);
+
+        let raw = email.formatted();
+        let parsed = MessageParser::default().parse(&raw).unwrap();
+        assert_eq!(parsed.subject(), Some("Re: report"));
+        let text = parsed.body_text(0).unwrap();
+        assert_eq!(text.trim(), "Here is the **summary**.");
+        let html = parsed.body_html(0).unwrap();
+        assert!(html.contains("<strong>summary</strong>"), "{html}");
+        assert!(!html.contains("DOCUMENT"), "{html}");
+
+        let att = parsed.attachment(0).unwrap();
+        assert_eq!(MimeHeaders::attachment_name(att), Some("summary.csv"));
+        assert_eq!(att.contents(), b"a,b\n1,2\n");
+        assert_eq!(
+            MimeHeaders::content_type(att).map(|ct| ct.subtype()),
+            Some(Some("csv"))
+        );
+    }
+
+    #[test]
+    fn build_email_notes_omitted_marker_attachments() {
+        let ws = tempfile::tempdir().unwrap();
+        std::fs::write(ws.path().join("big.bin"), vec![0u8; 64]).unwrap();
+        let mut config = mailbox_identity_config();
+        config.max_attachment_bytes = 16;
+        config.html_body = false;
+        let channel = EmailChannel::new(config, "default", empty_resolver())
+            .with_workspace_dir(ws.path().to_path_buf());
+
+        let (email, count) = channel
+            .build_email(&send_message(
+                "See attached.\n[DOCUMENT:big.bin]\n[FILE:missing.txt]\n[DOCUMENT:https://example.com/a.pdf]",
+            ))
+            .unwrap();
+        assert_eq!(count, 0);
+
+        let raw = email.formatted();
+        let parsed = MessageParser::default().parse(&raw).unwrap();
+        assert_eq!(parsed.attachment_count(), 0);
+        let text = parsed.body_text(0).unwrap();
+        assert!(text.starts_with("See attached."), "{text}");
+        assert!(text.contains("[attachment omitted: big.bin]"), "{text}");
+        assert!(text.contains("[attachment omitted: missing.txt]"), "{text}");
+        assert!(text.contains("https://example.com/a.pdf"), "{text}");
+    }
+
+    fn thread_root_of(raw: &'static [u8]) -> String {
+        let channel = EmailChannel::new(
+            mailbox_identity_config(),
+            "email_test_alias",
+            empty_resolver(),
+        );
+        channel
+            .build_parsed_email(&parse_test_email(raw), 7, Some(1))
+            .thread_root
+    }
+
+    #[test]
+    fn thread_root_is_own_message_id_for_a_new_thread() {
+        let root = thread_root_of(
+            b"From: Sender <sender@example.invalid>\r\n\
+              Subject: Quarterly numbers\r\n\
+              Message-ID: <root@example.invalid>\r\n\
+              \r\n\
+              hello",
+        );
+        assert_eq!(root, "root@example.invalid");
+    }
+
+    #[test]
+    fn thread_root_is_first_reference_for_replies() {
+        let root = thread_root_of(
+            b"From: Sender <sender@example.invalid>\r\n\
+              Subject: Re: Quarterly numbers\r\n\
+              Message-ID: <third@example.invalid>\r\n\
+              In-Reply-To: <second@example.invalid>\r\n\
+              References: <root@example.invalid>\r\n <second@example.invalid>\r\n\
+              \r\n\
+              and another thing",
+        );
+        assert_eq!(root, "root@example.invalid");
+    }
+
+    #[test]
+    fn thread_root_falls_back_to_in_reply_to_without_references() {
+        let root = thread_root_of(
+            b"From: Sender <sender@example.invalid>\r\n\
+              Subject: Re: Quarterly numbers\r\n\
+              Message-ID: <second@example.invalid>\r\n\
+              In-Reply-To: <root@example.invalid>\r\n\
+              \r\n\
+              reply",
+        );
+        assert_eq!(root, "root@example.invalid");
+    }
+
+    #[test]
+    fn replies_in_one_thread_share_a_history_key() {
+        let channel = EmailChannel::new(
+            mailbox_identity_config(),
+            "email_test_alias",
+            empty_resolver(),
+        );
+        let message = |raw: &'static [u8]| {
+            let email = channel.build_parsed_email(&parse_test_email(raw), 7, Some(1));
+            ChannelMessage {
+                id: email.msg_id,
+                sender: email.sender.clone(),
+                reply_target: email.sender,
+                channel: "email".into(),
+                channel_alias: Some("email_test_alias".into()),
+                thread_ts: Some(email.thread_root),
+                ..Default::default()
+            }
+        };
+        let first = message(
+            b"From: sender@example.invalid\r\n\
+              Message-ID: <root@example.invalid>\r\n\
+              \r\n\
+              one",
+        );
+        let reply = message(
+            b"From: sender@example.invalid\r\n\
+              Message-ID: <two@example.invalid>\r\n\
+              In-Reply-To: <bot-reply@example.invalid>\r\n\
+              References: <root@example.invalid> <bot-reply@example.invalid>\r\n\
+              \r\n\
+              two",
+        );
+        let unrelated = message(
+            b"From: sender@example.invalid\r\n\
+              Message-ID: <other@example.invalid>\r\n\
+              \r\n\
+              three",
+        );
+        let key = crate::orchestrator::conversation_history_key;
+        assert_eq!(key(&first), key(&reply));
+        assert_ne!(key(&first), key(&unrelated));
+    }
+
+    #[test]
+    fn reply_threading_headers_reference_root_and_parent() {
+        assert_eq!(
+            reply_threading_headers(Some("two@example.invalid"), Some("root@example.invalid")),
+            Some((
+                "<two@example.invalid>".to_string(),
+                "<root@example.invalid> <two@example.invalid>".to_string()
+            ))
+        );
+        assert_eq!(
+            reply_threading_headers(Some("root@example.invalid"), Some("root@example.invalid")),
+            Some((
+                "<root@example.invalid>".to_string(),
+                "<root@example.invalid>".to_string()
+            ))
+        );
+        assert_eq!(
+            reply_threading_headers(None, Some("root@example.invalid")),
+            Some((
+                "<root@example.invalid>".to_string(),
+                "<root@example.invalid>".to_string()
+            ))
+        );
+        assert_eq!(
+            reply_threading_headers(Some("email-imap-abc-7"), Some("email-imap-abc-7")),
+            None
+        );
+    }
+
+    #[test]
+    fn build_email_threads_replies_and_prefixes_subject() {
+        let channel = EmailChannel::new(mailbox_identity_config(), "default", empty_resolver());
+        let message = SendMessage::new("Thanks!", "sender@example.invalid")
+            .subject("Quarterly numbers")
+            .in_thread(Some("root@example.invalid".into()))
+            .in_reply_to(Some("two@example.invalid".into()));
+
+        let (email, _) = channel.build_email(&message).unwrap();
+        let raw = email.formatted();
+        let parsed = MessageParser::default().parse(&raw).unwrap();
+        assert_eq!(parsed.subject(), Some("Re: Quarterly numbers"));
+        assert_eq!(
+            header_message_ids(parsed.in_reply_to()),
+            ["two@example.invalid"]
+        );
+        assert_eq!(
+            header_message_ids(parsed.references()),
+            ["root@example.invalid", "two@example.invalid"]
+        );
+    }
+
+    #[test]
+    fn reply_subject_keeps_existing_prefix() {
+        assert_eq!(reply_subject("RE: hi"), "RE: hi");
+        assert_eq!(reply_subject("hi"), "Re: hi");
+        assert_eq!(reply_subject("é"), "Re: é");
+    }
 }
@@ -0,0 +1,161 @@
+//! Persisted Message-ID seen-set for the email channel, so a restart does
+//! not hand the agent mail it already processed.
+
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Oldest ids are dropped past this many; a mailbox rarely redelivers
+/// anything older than the last couple of thousand messages.
+const MAX_SEEN: usize = 2_000;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SeenFile {
+    ids: Vec<String>,
+}
+
+/// Bounded, insertion-ordered set of Message-IDs, mirrored to
+/// `<workspace>/state/email-seen-<alias>.json` when a workspace is known.
+#[derive(Debug, Default)]
+pub struct SeenMessageIds {
+    path: Option<PathBuf>,
+    order: VecDeque<String>,
+    ids: HashSet<String>,
+}
+
+impl SeenMessageIds {
+    fn path(workspace_dir: &Path, alias: &str) -> PathBuf {
+        let alias: String = alias
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        workspace_dir
+            .join("state")
+            .join(format!("email-seen-{alias}.json"))
+    }
+
+    /// Load the seen-set for `alias`. An absent, unreadable or corrupt file
+    /// yields an empty set; the worst case is one redelivery per message.
+    pub fn load(workspace_dir: Option<&Path>, alias: &str) -> Self {
+        let Some(ws) = workspace_dir else {
+            return Self::default();
+        };
+        let path = Self::path(ws, alias);
+        let file = std::fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<SeenFile>(&bytes).ok())
+            .unwrap_or_default();
+        let mut seen = Self {
+            path: Some(path),
+            ..Self::default()
+        };
+        for id in file.ids {
+            seen.remember(id);
+        }
+        seen
+    }
+
+    /// Record `id`. Returns `false` when it was already seen. New ids are
+    /// written through to disk (best-effort).
+    pub fn insert(&mut self, id: String) -> bool {
+        if !self.remember(id) {
+            return false;
+        }
+        self.write();
+        true
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.contains(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    fn remember(&mut self, id: String) -> bool {
+        if !self.ids.insert(id.clone()) {
+            return false;
+        }
+        self.order.push_back(id);
+        while self.order.len() > MAX_SEEN {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        true
+    }
+
+    fn write(&self) {
+        let Some(path) = self.path.as_deref() else {
+            return;
+        };
+        let Some(dir) = path.parent() else {
+            return;
+        };
+        if let Err(e) = std::fs::create_dir_all(dir) {
+            ::zeroclaw_log::record!(
+                DEBUG,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                    .with_attrs(::serde_json::json!({"error": e.to_string()})),
+                "could not create email state dir; seen message ids not persisted"
+            );
+            return;
+        }
+        let file = SeenFile {
+            ids: self.order.iter().cloned().collect(),
+        };
+        let Ok(bytes) = serde_json::to_vec(&file) else {
+            return;
+        };
+        let tmp = path.with_extension(format!("json.{}.tmp", std::process::id()));
+        if let Err(e) = std::fs::write(&tmp, &bytes).and_then(|()| std::fs::rename(&tmp, path)) {
+            let _ = std::fs::remove_file(&tmp);
+            ::zeroclaw_log::record!(
+                DEBUG,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                    .with_attrs(::serde_json::json!({"error": e.to_string()})),
+                "could not persist email seen message ids"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seen_ids_survive_a_reload() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut seen = SeenMessageIds::load(Some(tmp.path()), "work");
+        assert!(seen.insert("a@example.com".into()));
+        assert!(!seen.insert("a@example.com".into()));
+
+        let reloaded = SeenMessageIds::load(Some(tmp.path()), "work");
+        assert!(reloaded.contains("a@example.com"));
+        assert!(SeenMessageIds::load(Some(tmp.path()), "other").is_empty());
+    }
+
+    #[test]
+    fn oldest_ids_are_evicted_past_the_cap() {
+        let mut seen = SeenMessageIds::default();
+        for i in 0..=MAX_SEEN {
+            seen.insert(format!("id-{i}"));
+        }
+        assert_eq!(seen.len(), MAX_SEEN);
+        assert!(!seen.contains("id-0"));
+        assert!(seen.contains(&format!("id-{MAX_SEEN}")));
+    }
+}
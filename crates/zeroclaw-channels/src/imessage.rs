@@ -1,9 +1,11 @@
 use async_trait::async_trait;
 use directories::UserDirs;
+use parking_lot::Mutex as SyncMutex;
 use rusqlite::{Connection, OpenFlags};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::mpsc;
-use zeroclaw_api::channel::{Channel, ChannelMessage, SendMessage};
+use zeroclaw_api::channel::{Channel, ChannelMessage, InboundMediaFile, SendMessage};
 
 fn extract_text_from_attributed_body(blob: &[u8]) -> Option<String> {
     // Find the start-of-text marker: [0x01, 0x2B]
@@ -50,6 +52,123 @@ fn resolve_message_content(rowid: i64, text: Option<String>, body: Option<Vec<u8
         .unwrap_or_default()
 }
 
+/// Recipient prefix that routes a reply to a group chat by its GUID.
+const GROUP_TARGET_PREFIX: &str = "group:";
+
+/// `chat.style` value Messages uses for group chats (45 is a 1:1 chat).
+const GROUP_CHAT_STYLE: i64 = 43;
+
+/// Attachment markers sent as files.
+const OUTBOUND_MARKER_KINDS: &[&str] = &[
+    "IMAGE", "PHOTO", "DOCUMENT", "FILE", "VIDEO", "AUDIO", "VOICE",
+];
+
+/// Largest file iMessage will send.
+const MAX_ATTACHMENT_BYTES: u64 = 100 * 1024 * 1024;
+
+/// One inbound row from `chat.db`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct InboundRow {
+    rowid: i64,
+    sender: String,
+    text: String,
+    /// GUID of the group chat the message was posted in; `None` for 1:1 chats.
+    chat_guid: Option<String>,
+    attachments: Vec<InboundAttachment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct InboundAttachment {
+    path: PathBuf,
+    name: String,
+    mime_type: Option<String>,
+}
+
+/// Read up to 20 incoming messages after `since`, with their group chat and
+/// attachments.
+fn query_new_messages(conn: &Connection, since: i64) -> anyhow::Result<Vec<InboundRow>> {
+    let mut stmt = conn.prepare(
+        "SELECT m.ROWID, h.id, m.text, m.attributedBody, \
+         (SELECT c.guid FROM chat_message_join cmj \
+          JOIN chat c ON c.ROWID = cmj.chat_id \
+          WHERE cmj.message_id = m.ROWID AND c.style = ?2 LIMIT 1) \
+         FROM message m \
+         JOIN handle h ON m.handle_id = h.ROWID \
+         WHERE m.ROWID > ?1 \
+         AND m.is_from_me = 0 \
+         AND (m.text IS NOT NULL OR m.attributedBody IS NOT NULL) \
+         ORDER BY m.ROWID ASC \
+         LIMIT 20",
+    )?;
+    let rows = stmt
+        .query_map([since, GROUP_CHAT_STYLE], |row| {
+            let rowid = row.get::<_, i64>(0)?;
+            let sender = row.get::<_, String>(1)?;
+            let text: Option<String> = row.get(2)?;
+            let body: Option<Vec<u8>> = row.get(3)?;
+            let chat_guid: Option<String> = row.get(4)?;
+            // Each attachment leaves an object-replacement character in the text.
+            let text = resolve_message_content(rowid, text, body).replace('\u{FFFC}', "");
+            Ok(InboundRow {
+                rowid,
+                sender,
+                text,
+                chat_guid,
+                attachments: Vec::new(),
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let home = UserDirs::new().map(|u| u.home_dir().to_path_buf());
+    let mut attachment_stmt = conn.prepare(
+        "SELECT a.filename, a.mime_type, a.transfer_name \
+         FROM attachment a \
+         JOIN message_attachment_join maj ON maj.attachment_id = a.ROWID \
+         WHERE maj.message_id = ?1 \
+         ORDER BY a.ROWID ASC",
+    )?;
+    let mut results = Vec::with_capacity(rows.len());
+    for mut row in rows {
+        row.attachments = attachment_stmt
+            .query_map([row.rowid], |r| {
+                Ok((
+                    r.get::<_, Option<String>>(0)?,
+                    r.get::<_, Option<String>>(1)?,
+                    r.get::<_, Option<String>>(2)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            // No filename yet means the attachment is still downloading.
+            .filter_map(|(filename, mime_type, transfer_name)| {
+                let path = expand_home(&filename?, home.as_deref());
+                let name = transfer_name
+                    .filter(|n| !n.trim().is_empty())
+                    .or_else(|| {
+                        path.file_name()
+                            .map(|n| n.to_string_lossy().into_owned())
+                    })
+                    .unwrap_or_else(|| "attachment".to_string());
+                Some(InboundAttachment {
+                    path,
+                    name,
+                    mime_type,
+                })
+            })
+            .collect();
+        results.push(row);
+    }
+    Ok(results)
+}
+
+/// `attachment.filename` is stored as `~/Library/Messages/Attachments/...`.
+fn expand_home(path: &str, home: Option<&Path>) -> PathBuf {
+    match (path.strip_prefix("~/"), home) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
 /// iMessage channel using macOS `AppleScript` bridge.
 /// Polls the Messages database for new messages and sends replies via `osascript`.
 #[derive(Clone)]
@@ -60,7 +179,12 @@ pub struct IMessageChannel {
     /// Resolves inbound external peers from canonical state at message-time.
     /// No cache (see AGENTS.md "ABSOLUTE RULE — SINGLE SOURCE OF TRUTH").
     peer_resolver: Arc<dyn Fn() -> Vec<String> + Send + Sync>,
+    /// Group chat GUIDs answered for every participant (`"*"` = all groups).
+    group_ids: Vec<String>,
+    image_limits: crate::inbound_media::InboundImageLimits,
     poll_interval_secs: u64,
+    /// The missing permission found by the last health check, if any.
+    last_health: Arc<SyncMutex<Option<String>>>,
 }
 
 impl IMessageChannel {
@@ -71,10 +195,25 @@ impl IMessageChannel {
         Self {
             alias: alias.into(),
             peer_resolver,
+            group_ids: Vec::new(),
+            image_limits: crate::inbound_media::InboundImageLimits::default(),
             poll_interval_secs: 3,
+            last_health: Arc::new(SyncMutex::new(None)),
         }
     }
 
+    /// Accept every participant in these group chats (`"*"` = all groups).
+    pub fn with_group_ids(mut self, group_ids: Vec<String>) -> Self {
+        self.group_ids = group_ids;
+        self
+    }
+
+    /// Configure limits for inbound images.
+    pub fn with_image_limits(mut self, limits: crate::inbound_media::InboundImageLimits) -> Self {
+        self.image_limits = limits;
+        self
+    }
+
     /// Return the alias under `[channels.imessage.<alias>]` that this
     /// channel handle is bound to.
     pub fn alias(&self) -> &str {
@@ -85,6 +224,207 @@ impl IMessageChannel {
         let peers = (self.peer_resolver)();
         crate::allowlist::is_user_allowed(&peers, sender, crate::allowlist::Match::CaseInsensitive)
     }
+
+    /// A listed group admits all of its participants; anywhere else the
+    /// sender must be an allowed peer.
+    fn is_message_allowed(&self, sender: &str, chat_guid: Option<&str>) -> bool {
+        if let Some(guid) = chat_guid
+            && self.group_ids.iter().any(|g| g == "*" || g == guid)
+        {
+            return true;
+        }
+        self.is_contact_allowed(sender)
+    }
+
+    /// Render inbound attachments as content lines. Images are copied into
+    /// the temp media dir and reported for cleanup; other files are
+    /// referenced in place, since they belong to the Messages library.
+    async fn render_attachments(
+        &self,
+        attachments: &[InboundAttachment],
+    ) -> (Vec<String>, Vec<InboundMediaFile>) {
+        let mut lines = Vec::new();
+        let mut media_files = Vec::new();
+        for att in attachments {
+            let name = att.name.as_str();
+            let is_image = att
+                .mime_type
+                .as_deref()
+                .is_some_and(|m| m.starts_with("image/"));
+            if !is_image {
+                lines.push(format!("[Document: {name}] {}", att.path.display()));
+                continue;
+            }
+            if media_files.len() >= self.image_limits.max_images {
+                lines.push(format!(
+                    "[Image skipped: {name} — limit of {} images per message]",
+                    self.image_limits.max_images
+                ));
+                continue;
+            }
+            let stored = match tokio::fs::read(&att.path).await {
+                Ok(bytes) => {
+                    crate::inbound_media::store_inbound_image(
+                        "imessage",
+                        name,
+                        bytes,
+                        att.mime_type.as_deref().unwrap_or("image/jpeg"),
+                        self.image_limits,
+                    )
+                    .await
+                }
+                Err(e) => Err(e.into()),
+            };
+            match stored {
+                Ok(stored) => {
+                    lines.push(format!("[IMAGE:{}]", stored.path.display()));
+                    media_files.push(stored);
+                }
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                            .with_attrs(
+                                ::serde_json::json!({"name": name, "error": format!("{}", e)})
+                            ),
+                        "skipping iMessage image attachment"
+                    );
+                    lines.push(format!("[Image skipped: {name} could not be read]"));
+                }
+            }
+        }
+        (lines, media_files)
+    }
+
+    /// Split `[IMAGE:]`/`[DOCUMENT:]` markers out of `message` and stage
+    /// them, together with `message.attachments`, where Messages can read
+    /// them. Markers that fail to stage are replaced by a short note (or
+    /// the URL) in the text.
+    async fn collect_outbound_attachments(&self, message: &SendMessage) -> (String, Vec<PathBuf>) {
+        let (mut text, markers) =
+            crate::util::parse_attachment_markers_of_kinds(&message.content, OUTBOUND_MARKER_KINDS);
+        let mut files = Vec::new();
+        let mut notes = Vec::new();
+        for att in message.attachments.iter().filter(|a| !a.data.is_empty()) {
+            match stage_outbound_bytes(&att.file_name, &att.data).await {
+                Ok(path) => files.push(path),
+                Err(e) => {
+                    log_dropped_attachment(&att.file_name, &e);
+                    notes.push(format!("[attachment omitted: {}]", att.file_name));
+                }
+            }
+        }
+        for (_kind, target) in markers {
+            // Messages turns links into previews on its own.
+            if target.starts_with("http://") || target.starts_with("https://") {
+                notes.push(target);
+                continue;
+            }
+            match stage_outbound_file(Path::new(&target)).await {
+                Ok(path) => files.push(path),
+                Err(e) => {
+                    log_dropped_attachment(&target, &e);
+                    let name = Path::new(&target)
+                        .file_name()
+                        .and_then(|n| n.to_str())
+                        .unwrap_or(&target);
+                    notes.push(format!("[attachment omitted: {name}]"));
+                }
+            }
+        }
+        if !notes.is_empty() {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+            }
+            text.push_str(&notes.join("\n"));
+        }
+        (text, files)
+    }
+}
+
+fn log_dropped_attachment(target: &str, e: &anyhow::Error) {
+    ::zeroclaw_log::record!(
+        WARN,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+            .with_attrs(::serde_json::json!({
+                "target": target,
+                "error": format!("{e:#}"),
+            })),
+        "Dropping iMessage attachment"
+    );
+}
+
+/// Messages is sandboxed and can't send files from the temp dir or most of
+/// the workspace, so outbound files are copied under `~/Pictures` first.
+/// Sends are asynchronous, so copies are left for the stale-file sweep.
+fn outbound_staging_dir() -> anyhow::Result<PathBuf> {
+    UserDirs::new()
+        .map(|u| u.home_dir().join("Pictures/ZeroClaw"))
+        .ok_or_else(|| anyhow::Error::msg("Cannot find home directory"))
+}
+
+fn staged_file_name(file_name: &str) -> String {
+    let safe: String = file_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "._-".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let safe = safe.trim_start_matches('.');
+    format!(
+        "{}-{}",
+        uuid::Uuid::new_v4(),
+        if safe.is_empty() { "attachment" } else { safe }
+    )
+}
+
+async fn prepare_staging_dir() -> anyhow::Result<PathBuf> {
+    let dir = outbound_staging_dir()?;
+    tokio::fs::create_dir_all(&dir).await?;
+    let sweep_dir = dir.clone();
+    tokio::task::spawn_blocking(move || {
+        crate::inbound_media::sweep_stale_media(&sweep_dir, crate::inbound_media::STALE_MEDIA_AGE);
+    })
+    .await?;
+    Ok(dir)
+}
+
+async fn stage_outbound_bytes(file_name: &str, data: &[u8]) -> anyhow::Result<PathBuf> {
+    if data.len() as u64 > MAX_ATTACHMENT_BYTES {
+        anyhow::bail!("attachment exceeds iMessage's {MAX_ATTACHMENT_BYTES} byte limit");
+    }
+    let path = prepare_staging_dir()
+        .await?
+        .join(staged_file_name(file_name));
+    tokio::fs::write(&path, data).await?;
+    Ok(path)
+}
+
+async fn stage_outbound_file(source: &Path) -> anyhow::Result<PathBuf> {
+    let metadata = tokio::fs::metadata(source)
+        .await
+        .map_err(|e| anyhow::Error::msg(format!("attachment not found: {e}")))?;
+    if !metadata.is_file() {
+        anyhow::bail!("attachment is not a regular file");
+    }
+    if metadata.len() > MAX_ATTACHMENT_BYTES {
+        anyhow::bail!("attachment exceeds iMessage's {MAX_ATTACHMENT_BYTES} byte limit");
+    }
+    let file_name = source
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("attachment");
+    let path = prepare_staging_dir()
+        .await?
+        .join(staged_file_name(file_name));
+    tokio::fs::copy(source, &path).await?;
+    Ok(path)
 }
 
 fn escape_applescript(s: &str) -> String {
@@ -131,6 +471,161 @@ fn is_valid_imessage_target(target: &str) -> bool {
     false
 }
 
+/// Chat GUIDs look like `iMessage;+;chat123456789` or `SMS;-;+15551234567`.
+fn is_valid_chat_guid(guid: &str) -> bool {
+    !guid.is_empty()
+        && guid.len() <= 128
+        && guid
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || ";+-_.@:".contains(c))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum IMessageTarget {
+    /// A phone number or email address.
+    Buddy(String),
+    /// A chat GUID, from a `group:<guid>` recipient.
+    Chat(String),
+}
+
+impl IMessageTarget {
+    fn parse(recipient: &str) -> anyhow::Result<Self> {
+        let recipient = recipient.trim();
+        if let Some(guid) = recipient.strip_prefix(GROUP_TARGET_PREFIX) {
+            if !is_valid_chat_guid(guid) {
+                anyhow::bail!(
+                    "Invalid iMessage group target: chat GUID has unsupported characters"
+                );
+            }
+            return Ok(Self::Chat(guid.to_string()));
+        }
+        if !is_valid_imessage_target(recipient) {
+            anyhow::bail!(
+                "Invalid iMessage target: must be a phone number (+1234567890), email (user@example.com), or group:<chat GUID>"
+            );
+        }
+        Ok(Self::Buddy(recipient.to_string()))
+    }
+}
+
+enum Outbound<'a> {
+    Text(&'a str),
+    File(&'a Path),
+}
+
+fn build_send_script(target: &IMessageTarget, outbound: Outbound<'_>) -> String {
+    // SECURITY: Escape every interpolated value to prevent AppleScript injection
+    // See: CWE-78 (OS Command Injection)
+    let recipient = match target {
+        IMessageTarget::Buddy(handle) => format!(
+            "set targetRecipient to participant \"{}\" of targetService",
+            escape_applescript(handle)
+        ),
+        IMessageTarget::Chat(guid) => {
+            format!(
+                "set targetRecipient to chat id \"{}\"",
+                escape_applescript(guid)
+            )
+        }
+    };
+    let payload = match outbound {
+        Outbound::Text(text) => format!("\"{}\"", escape_applescript(text)),
+        Outbound::File(path) => format!(
+            "(POSIX file \"{}\")",
+            escape_applescript(&path.to_string_lossy())
+        ),
+    };
+    format!(
+        r#"tell application "Messages"
+    set targetService to 1st account whose service type = iMessage
+    {recipient}
+    send {payload} to targetRecipient
+end tell"#
+    )
+}
+
+async fn run_osascript(script: &str) -> anyhow::Result<()> {
+    let output = tokio::process::Command::new("osascript")
+        .arg("-e")
+        .arg(script)
+        .output()
+        .await?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("iMessage send failed: {stderr}");
+    }
+
+    Ok(())
+}
+
+fn chat_db_path() -> Option<PathBuf> {
+    UserDirs::new().map(|u| u.home_dir().join("Library/Messages/chat.db"))
+}
+
+/// Name of the binary the user has to grant permissions to.
+fn current_binary() -> String {
+    std::env::current_exe()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|_| "zeroclaw".to_string())
+}
+
+/// Check that `chat.db` exists and is readable. Returns the problem, if any.
+fn probe_chat_db(db_path: &Path) -> Option<String> {
+    if !db_path.exists() {
+        return Some(format!(
+            "Messages database not found at {}; sign in to Messages.app first",
+            db_path.display()
+        ));
+    }
+    if let Err(e) = std::fs::File::open(db_path) {
+        return Some(if e.kind() == std::io::ErrorKind::PermissionDenied {
+            format!(
+                "Full Disk Access not granted to {}: enable it in System Settings → Privacy & Security → Full Disk Access",
+                current_binary()
+            )
+        } else {
+            format!("cannot open {}: {e}", db_path.display())
+        });
+    }
+    let readable = Connection::open_with_flags(
+        db_path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .and_then(|conn| conn.query_row("SELECT COUNT(*) FROM message LIMIT 1", [], |_| Ok(())));
+    readable
+        .err()
+        .map(|e| format!("cannot read {}: {e}", db_path.display()))
+}
+
+/// Turn a failed `osascript` probe into the permission that's missing.
+fn describe_automation_failure(stderr: &str) -> String {
+    // -1743: "Not authorized to send Apple events to Messages."
+    if stderr.contains("-1743") {
+        format!(
+            "Automation permission for Messages not granted to {}: enable it in System Settings → Privacy & Security → Automation",
+            current_binary()
+        )
+    } else {
+        format!("cannot control Messages via AppleScript: {}", stderr.trim())
+    }
+}
+
+async fn probe_automation() -> Option<String> {
+    let output = tokio::process::Command::new("osascript")
+        .arg("-e")
+        .arg(r#"tell application "Messages" to get name"#)
+        .output()
+        .await;
+    match output {
+        Ok(output) if output.status.success() => None,
+        Ok(output) => Some(describe_automation_failure(&String::from_utf8_lossy(
+            &output.stderr,
+        ))),
+        Err(e) => Some(format!("cannot run osascript: {e}")),
+    }
+}
+
 impl ::zeroclaw_api::attribution::Attributable for IMessageChannel {
     fn role(&self) -> ::zeroclaw_api::attribution::Role {
         ::zeroclaw_api::attribution::Role::Channel(
@@ -150,34 +645,14 @@ impl Channel for IMessageChannel {
 
     async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
         // Defense-in-depth: validate target format before any interpolation
-        if !is_valid_imessage_target(&message.recipient) {
-            anyhow::bail!(
-                "Invalid iMessage target: must be a phone number (+1234567890) or email (user@example.com)"
-            );
-        }
-
-        // SECURITY: Escape both message AND target to prevent AppleScript injection
-        // See: CWE-78 (OS Command Injection)
-        let escaped_msg = escape_applescript(&message.content);
-        let escaped_target = escape_applescript(&message.recipient);
-
-        let script = format!(
-            r#"tell application "Messages"
-    set targetService to 1st account whose service type = iMessage
-    set targetBuddy to participant "{escaped_target}" of targetService
-    send "{escaped_msg}" to targetBuddy
-end tell"#
-        );
-
-        let output = tokio::process::Command::new("osascript")
-            .arg("-e")
-            .arg(&script)
-            .output()
-            .await?;
+        let target = IMessageTarget::parse(&message.recipient)?;
+        let (text, files) = self.collect_outbound_attachments(message).await;
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            anyhow::bail!("iMessage send failed: {stderr}");
+        if !text.trim().is_empty() {
+            run_osascript(&build_send_script(&target, Outbound::Text(&text))).await?;
+        }
+        for file in &files {
+            run_osascript(&build_send_script(&target, Outbound::File(file))).await?;
         }
 
         Ok(())
@@ -192,17 +667,15 @@ end tell"#
 
         // Query the Messages SQLite database for new messages
         // The database is at ~/Library/Messages/chat.db
-        let db_path = UserDirs::new()
-            .map(|u| u.home_dir().join("Library/Messages/chat.db"))
-            .ok_or_else(|| {
-                ::zeroclaw_log::record!(
-                    ERROR,
-                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
-                        .with_outcome(::zeroclaw_log::EventOutcome::Failure),
-                    "Cannot find home directory"
-                );
-                anyhow::Error::msg("Cannot find home directory")
-            })?;
+        let db_path = chat_db_path().ok_or_else(|| {
+            ::zeroclaw_log::record!(
+                ERROR,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure),
+                "Cannot find home directory"
+            );
+            anyhow::Error::msg("Cannot find home directory")
+        })?;
 
         if !db_path.exists() {
             anyhow::bail!(
@@ -241,29 +714,8 @@ end tell"#
 
             let since = last_rowid;
             let (returned_conn, poll_result) = tokio::task::spawn_blocking(
-                move || -> (Connection, anyhow::Result<Vec<(i64, String, String)>>) {
-                    let result = (|| -> anyhow::Result<Vec<(i64, String, String)>> {
-                        let mut stmt = conn.prepare(
-                            "SELECT m.ROWID, h.id, m.text, m.attributedBody \
-                     FROM message m \
-                     JOIN handle h ON m.handle_id = h.ROWID \
-                     WHERE m.ROWID > ?1 \
-                     AND m.is_from_me = 0 \
-                     AND (m.text IS NOT NULL OR m.attributedBody IS NOT NULL) \
-                     ORDER BY m.ROWID ASC \
-                     LIMIT 20",
-                        )?;
-                        let rows = stmt.query_map([since], |row| {
-                            let rowid = row.get::<_, i64>(0)?;
-                            let sender = row.get::<_, String>(1)?;
-                            let text: Option<String> = row.get(2)?;
-                            let body: Option<Vec<u8>> = row.get(3)?;
-                            Ok((rowid, sender, resolve_message_content(rowid, text, body)))
-                        })?;
-                        let results = rows.collect::<Result<Vec<_>, _>>()?;
-                        Ok(results)
-                    })();
-
+                move || -> (Connection, anyhow::Result<Vec<InboundRow>>) {
+                    let result = query_new_messages(&conn, since);
                     (conn, result)
                 },
             )
@@ -282,24 +734,37 @@ end tell"#
 
             match poll_result {
                 Ok(messages) => {
-                    for (rowid, sender, text) in messages {
-                        if rowid > last_rowid {
-                            last_rowid = rowid;
+                    for row in messages {
+                        if row.rowid > last_rowid {
+                            last_rowid = row.rowid;
                         }
 
-                        if !self.is_contact_allowed(&sender) {
+                        if !self.is_message_allowed(&row.sender, row.chat_guid.as_deref()) {
                             continue;
                         }
 
-                        if text.trim().is_empty() {
+                        if row.text.trim().is_empty() && row.attachments.is_empty() {
                             continue;
                         }
 
+                        let (attachment_lines, media_files) =
+                            self.render_attachments(&row.attachments).await;
+                        let content = std::iter::once(row.text.trim().to_string())
+                            .filter(|t| !t.is_empty())
+                            .chain(attachment_lines)
+                            .collect::<Vec<_>>()
+                            .join("\n");
+
+                        let reply_target = match &row.chat_guid {
+                            Some(guid) => format!("{GROUP_TARGET_PREFIX}{guid}"),
+                            None => row.sender.clone(),
+                        };
+
                         let msg = ChannelMessage {
-                            id: rowid.to_string(),
-                            sender: sender.clone(),
-                            reply_target: sender.clone(),
-                            content: text,
+                            id: row.rowid.to_string(),
+                            sender: row.sender,
+                            reply_target,
+                            content,
                             channel: "imessage".to_string(),
                             channel_alias: Some(self.alias.clone()),
                             timestamp: std::time::SystemTime::now()
@@ -310,6 +775,7 @@ end tell"#
                             interruption_scope_id: None,
                             attachments: vec![],
                             subject: None,
+                            media_files,
 
                             ..Default::default()
                         };
@@ -333,15 +799,27 @@ end tell"#
     }
 
     async fn health_check(&self) -> bool {
-        if !cfg!(target_os = "macos") {
-            return false;
-        }
-
-        let db_path = UserDirs::new()
-            .map(|u| u.home_dir().join("Library/Messages/chat.db"))
-            .unwrap_or_default();
+        let problem = if !cfg!(target_os = "macos") {
+            Some("iMessage requires macOS".to_string())
+        } else {
+            match chat_db_path() {
+                None => Some("Cannot find home directory".to_string()),
+                Some(db_path) => {
+                    match tokio::task::spawn_blocking(move || probe_chat_db(&db_path)).await {
+                        Ok(None) => probe_automation().await,
+                        Ok(problem) => problem,
+                        Err(e) => Some(format!("health probe failed: {e}")),
+                    }
+                }
+            }
+        };
+        let healthy = problem.is_none();
+        *self.last_health.lock() = problem;
+        healthy
+    }
 
-        db_path.exists()
+    fn health_detail(&self) -> Option<String> {
+        self.last_health.lock().clone()
     }
 
     async fn start_typing(&self, _recipient: &str) -> anyhow::Result<()> {
@@ -382,27 +860,10 @@ async fn fetch_new_messages(
                 &path,
                 OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
             )?;
-            let mut stmt = conn.prepare(
-                "SELECT m.ROWID, h.id, m.text, m.attributedBody \
-             FROM message m \
-             JOIN handle h ON m.handle_id = h.ROWID \
-             WHERE m.ROWID > ?1 \
-             AND m.is_from_me = 0 \
-             AND (m.text IS NOT NULL OR m.attributedBody IS NOT NULL) \
-             ORDER BY m.ROWID ASC \
-             LIMIT 20",
-            )?;
-            let rows = stmt.query_map([since_rowid], |row| {
-                let rowid = row.get::<_, i64>(0)?;
-                let sender = row.get::<_, String>(1)?;
-                let text: Option<String> = row.get(2)?;
-                let body: Option<Vec<u8>> = row.get(3)?;
-                Ok((rowid, sender, resolve_message_content(rowid, text, body)))
-            })?;
-            let results: Vec<_> = rows
-                .collect::<Result<Vec<_>, _>>()?
+            let results: Vec<_> = query_new_messages(&conn, since_rowid)?
                 .into_iter()
-                .filter(|(_, _, content)| !content.trim().is_empty())
+                .filter(|row| !row.text.trim().is_empty())
+                .map(|row| (row.rowid, row.sender, row.text))
                 .collect();
             Ok(results)
         })
@@ -726,6 +1187,25 @@ mod tests {
                 attributedBody BLOB,
                 is_from_me INTEGER DEFAULT 0,
                 FOREIGN KEY (handle_id) REFERENCES handle(ROWID)
+            );
+            CREATE TABLE chat (
+                ROWID INTEGER PRIMARY KEY,
+                guid TEXT NOT NULL,
+                style INTEGER
+            );
+            CREATE TABLE chat_message_join (
+                chat_id INTEGER,
+                message_id INTEGER
+            );
+            CREATE TABLE attachment (
+                ROWID INTEGER PRIMARY KEY,
+                filename TEXT,
+                mime_type TEXT,
+                transfer_name TEXT
+            );
+            CREATE TABLE message_attachment_join (
+                message_id INTEGER,
+                attachment_id INTEGER
             );",
         )
         .unwrap();
@@ -1361,4 +1841,246 @@ mod tests {
         assert_eq!(result[0].2, "Legacy message");
         assert_eq!(result[1].2, "1");
     }
+
+    // ══════════════════════════════════════════════════════════
+    // Group Chats and Attachments
+    // ══════════════════════════════════════════════════════════
+
+    fn query(db_path: &std::path::Path, since: i64) -> Vec<InboundRow> {
+        let conn = Connection::open(db_path).unwrap();
+        query_new_messages(&conn, since).unwrap()
+    }
+
+    fn insert_group_fixture(db_path: &std::path::Path) {
+        let conn = Connection::open(db_path).unwrap();
+        conn.execute_batch(
+            "INSERT INTO handle (ROWID, id) VALUES (1, '+1234567890');
+             INSERT INTO chat (ROWID, guid, style) VALUES (1, 'iMessage;-;+1234567890', 45);
+             INSERT INTO chat (ROWID, guid, style) VALUES (2, 'iMessage;+;chat42', 43);
+             INSERT INTO message (ROWID, handle_id, text, is_from_me) VALUES (10, 1, 'direct', 0);
+             INSERT INTO message (ROWID, handle_id, text, is_from_me) VALUES (20, 1, 'in group', 0);
+             INSERT INTO chat_message_join (chat_id, message_id) VALUES (1, 10);
+             INSERT INTO chat_message_join (chat_id, message_id) VALUES (2, 20);",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn query_resolves_group_chat_guid() {
+        let (_dir, db_path) = create_test_db();
+        insert_group_fixture(&db_path);
+
+        let rows = query(&db_path, 0);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].chat_guid, None);
+        assert_eq!(rows[1].chat_guid.as_deref(), Some("iMessage;+;chat42"));
+        assert_eq!(rows[1].sender, "+1234567890");
+    }
+
+    #[test]
+    fn query_collects_attachments_and_strips_placeholders() {
+        let (_dir, db_path) = create_test_db();
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            conn.execute_batch(
+                "INSERT INTO handle (ROWID, id) VALUES (1, '+1234567890');
+                 INSERT INTO message (ROWID, handle_id, text, is_from_me) VALUES (10, 1, 'look \u{FFFC}', 0);
+                 INSERT INTO attachment (ROWID, filename, mime_type, transfer_name)
+                     VALUES (1, '/tmp/a/IMG_0001.HEIC', 'image/heic', 'IMG_0001.HEIC');
+                 INSERT INTO attachment (ROWID, filename, mime_type, transfer_name)
+                     VALUES (2, NULL, 'application/pdf', 'pending.pdf');
+                 INSERT INTO message_attachment_join (message_id, attachment_id) VALUES (10, 1);
+                 INSERT INTO message_attachment_join (message_id, attachment_id) VALUES (10, 2);",
+            )
+            .unwrap();
+        }
+
+        let rows = query(&db_path, 0);
+        assert_eq!(rows[0].text, "look ");
+        // The attachment without a filename hasn't downloaded yet.
+        assert_eq!(
+            rows[0].attachments,
+            vec![InboundAttachment {
+                path: PathBuf::from("/tmp/a/IMG_0001.HEIC"),
+                name: "IMG_0001.HEIC".into(),
+                mime_type: Some("image/heic".into()),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn attachment_only_message_is_not_filtered_by_query() {
+        let (_dir, db_path) = create_test_db();
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            conn.execute_batch(
+                "INSERT INTO handle (ROWID, id) VALUES (1, '+1234567890');
+                 INSERT INTO message (ROWID, handle_id, text, is_from_me) VALUES (10, 1, '\u{FFFC}', 0);
+                 INSERT INTO attachment (ROWID, filename, mime_type, transfer_name)
+                     VALUES (1, '/tmp/a/report.pdf', 'application/pdf', 'report.pdf');
+                 INSERT INTO message_attachment_join (message_id, attachment_id) VALUES (10, 1);",
+            )
+            .unwrap();
+        }
+
+        let rows = query(&db_path, 0);
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0].text.is_empty());
+        assert_eq!(rows[0].attachments.len(), 1);
+        // The text-only helper still drops it.
+        assert!(fetch_new_messages(&db_path, 0).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn expand_home_only_rewrites_tilde_prefix() {
+        let home = std::path::Path::new("/Users/me");
+        assert_eq!(
+            expand_home("~/Library/Messages/Attachments/a.jpg", Some(home)),
+            PathBuf::from("/Users/me/Library/Messages/Attachments/a.jpg")
+        );
+        assert_eq!(
+            expand_home("/abs/a.jpg", Some(home)),
+            PathBuf::from("/abs/a.jpg")
+        );
+        assert_eq!(expand_home("~/a.jpg", None), PathBuf::from("~/a.jpg"));
+    }
+
+    #[test]
+    fn listed_group_admits_every_participant() {
+        let ch = IMessageChannel::new("imessage_test_alias", Arc::new(Vec::new))
+            .with_group_ids(vec!["iMessage;+;chat42".into()]);
+        assert!(ch.is_message_allowed("+1555000111", Some("iMessage;+;chat42")));
+        assert!(!ch.is_message_allowed("+1555000111", Some("iMessage;+;chat7")));
+        // Listing a group doesn't open up direct messages.
+        assert!(!ch.is_message_allowed("+1555000111", None));
+    }
+
+    #[test]
+    fn unlisted_group_requires_allowed_participant() {
+        let ch = IMessageChannel::new(
+            "imessage_test_alias",
+            Arc::new(|| vec!["+1234567890".into()]),
+        );
+        assert!(ch.is_message_allowed("+1234567890", Some("iMessage;+;chat42")));
+        assert!(!ch.is_message_allowed("+1555000111", Some("iMessage;+;chat42")));
+        assert!(ch.is_message_allowed("+1234567890", None));
+    }
+
+    #[test]
+    fn wildcard_group_id_admits_all_groups() {
+        let ch = IMessageChannel::new("imessage_test_alias", Arc::new(Vec::new))
+            .with_group_ids(vec!["*".into()]);
+        assert!(ch.is_message_allowed("+1555000111", Some("iMessage;+;chat7")));
+        assert!(!ch.is_message_allowed("+1555000111", None));
+    }
+
+    #[test]
+    fn target_parses_group_and_buddy() {
+        assert_eq!(
+            IMessageTarget::parse("group:iMessage;+;chat42").unwrap(),
+            IMessageTarget::Chat("iMessage;+;chat42".into())
+        );
+        assert_eq!(
+            IMessageTarget::parse(" +1234567890 ").unwrap(),
+            IMessageTarget::Buddy("+1234567890".into())
+        );
+        assert!(IMessageTarget::parse("group:").is_err());
+        assert!(IMessageTarget::parse(r#"group:chat" & do shell script "id"#).is_err());
+        assert!(IMessageTarget::parse("group:chat42\nend tell").is_err());
+        assert!(IMessageTarget::parse("not-a-target").is_err());
+    }
+
+    #[test]
+    fn send_script_targets_chat_by_id() {
+        let script = build_send_script(
+            &IMessageTarget::Chat("iMessage;+;chat42".into()),
+            Outbound::Text("hi \"all\""),
+        );
+        assert!(script.contains(r#"set targetRecipient to chat id "iMessage;+;chat42""#));
+        assert!(script.contains(r#"send "hi \"all\"" to targetRecipient"#));
+    }
+
+    #[test]
+    fn send_script_attaches_posix_file() {
+        let script = build_send_script(
+            &IMessageTarget::Buddy("+1234567890".into()),
+            Outbound::File(std::path::Path::new(
+                "/Users/me/Pictures/ZeroClaw/x \"y\".png",
+            )),
+        );
+        assert!(script.contains(r#"participant "+1234567890" of targetService"#));
+        assert!(script.contains(
+            r#"send (POSIX file "/Users/me/Pictures/ZeroClaw/x \"y\".png") to targetRecipient"#
+        ));
+    }
+
+    #[test]
+    fn staged_file_name_is_sanitized_and_unique() {
+        let a = staged_file_name("../../etc/pass wd");
+        let b = staged_file_name("../../etc/pass wd");
+        assert_ne!(a, b);
+        assert!(a.ends_with("-_.._etc_pass_wd"));
+        assert!(!a.contains('/'));
+        assert!(staged_file_name("").ends_with("-attachment"));
+    }
+
+    #[test]
+    fn probe_reports_missing_database() {
+        let detail = probe_chat_db(std::path::Path::new("/nonexistent/Messages/chat.db")).unwrap();
+        assert!(detail.contains("Messages database not found"));
+    }
+
+    #[test]
+    fn probe_passes_readable_database() {
+        let (_dir, db_path) = create_test_db();
+        assert_eq!(probe_chat_db(&db_path), None);
+    }
+
+    #[test]
+    fn automation_denial_names_the_permission() {
+        let detail = describe_automation_failure(
+            "execution error: Not authorized to send Apple events to Messages. (-1743)",
+        );
+        assert!(detail.contains("Privacy & Security → Automation"));
+        let other = describe_automation_failure("syntax error");
+        assert!(other.contains("syntax error"));
+    }
+
+    #[tokio::test]
+    async fn non_image_attachment_is_referenced_in_place() {
+        let ch = IMessageChannel::new("imessage_test_alias", Arc::new(Vec::new));
+        let (lines, media) = ch
+            .render_attachments(&[InboundAttachment {
+                path: PathBuf::from("/Users/me/Library/Messages/Attachments/report.pdf"),
+                name: "report.pdf".into(),
+                mime_type: Some("application/pdf".into()),
+            }])
+            .await;
+        assert_eq!(
+            lines,
+            vec!["[Document: report.pdf] /Users/me/Library/Messages/Attachments/report.pdf"]
+        );
+        // Never scheduled for deletion: the file belongs to Messages.
+        assert!(media.is_empty());
+    }
+
+    #[tokio::test]
+    async fn image_attachment_is_copied_to_media_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("photo.png");
+        std::fs::write(&source, b"\x89PNG\r\n\x1a\nnot really").unwrap();
+        let ch = IMessageChannel::new("imessage_test_alias", Arc::new(Vec::new));
+        let (lines, media) = ch
+            .render_attachments(&[InboundAttachment {
+                path: source.clone(),
+                name: "photo.png".into(),
+                mime_type: Some("image/png".into()),
+            }])
+            .await;
+        assert_eq!(media.len(), 1);
+        assert_ne!(media[0].path, source);
+        assert_eq!(lines, vec![format!("[IMAGE:{}]", media[0].path.display())]);
+        assert!(source.exists());
+        let _ = std::fs::remove_file(&media[0].path);
+    }
 }
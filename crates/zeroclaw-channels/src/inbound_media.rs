@@ -0,0 +1,405 @@
+//! Temporary storage for photos received on channels.
+//!
+//! Channels download inbound images into a per-channel directory under the
+//! system temp dir rather than the workspace, and report each stored file on
+//! `ChannelMessage::media_files`. The orchestrator holds an
+//! [`InboundMediaCleanup`] for every message it processes, so the files are
+//! removed when the turn ends — whether it succeeds, fails, or is cancelled.
+//! Anything left behind by a crash or a superseded message is swept the next
+//! time the channel stores an image.
+
+use anyhow::Result;
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use zeroclaw_api::channel::InboundMediaFile;
+use zeroclaw_config::schema::MultimodalConfig;
+
+/// Files older than this are removed by the sweep in [`store_inbound_image`].
+pub const STALE_MEDIA_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Smallest edge length the downscaler will shrink to while trying to fit
+/// the byte limit. Below this the image is rejected instead.
+const MIN_DOWNSCALE_DIMENSION: u32 = 256;
+
+/// Root of the inbound media directories.
+pub fn media_root() -> PathBuf {
+    std::env::temp_dir().join("zeroclaw-media")
+}
+
+/// Directory holding inbound media for one channel.
+pub fn media_dir(channel: &str) -> PathBuf {
+    media_dir_in(&media_root(), channel)
+}
+
+fn media_dir_in(root: &Path, channel: &str) -> PathBuf {
+    let safe: String = channel
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    root.join(if safe.is_empty() { "unknown" } else { &safe })
+}
+
+/// Limits applied to inbound photos, derived from `[multimodal]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InboundImageLimits {
+    /// Images accepted per message; the rest are skipped with a note.
+    pub max_images: usize,
+    /// Largest stored image, in bytes, after downscaling.
+    pub max_bytes: usize,
+    /// Longest edge, in pixels; larger images are downscaled.
+    pub max_dimension: u32,
+}
+
+impl InboundImageLimits {
+    pub fn from_config(config: &MultimodalConfig) -> Self {
+        let (max_images, max_image_size_mb) = config.effective_limits();
+        Self {
+            max_images,
+            max_bytes: max_image_size_mb * 1024 * 1024,
+            max_dimension: config
+                .max_image_dimension
+                .clamp(MIN_DOWNSCALE_DIMENSION, 8192),
+        }
+    }
+}
+
+impl Default for InboundImageLimits {
+    fn default() -> Self {
+        Self::from_config(&MultimodalConfig::default())
+    }
+}
+
+/// Downscale `data` to fit `limits` and store it in the channel's media
+/// directory. Fails when the image cannot be brought under the byte limit.
+pub async fn store_inbound_image(
+    channel: &str,
+    file_name: &str,
+    data: Vec<u8>,
+    mime_type: &str,
+    limits: InboundImageLimits,
+) -> Result<InboundMediaFile> {
+    let dir = media_dir(channel);
+    let file_name = file_name.to_string();
+    let mime_type = mime_type.to_string();
+    tokio::task::spawn_blocking(move || {
+        write_inbound_image(&dir, &file_name, data, &mime_type, limits)
+    })
+    .await?
+}
+
+fn write_inbound_image(
+    dir: &Path,
+    file_name: &str,
+    data: Vec<u8>,
+    mime_type: &str,
+    limits: InboundImageLimits,
+) -> Result<InboundMediaFile> {
+    std::fs::create_dir_all(dir)?;
+    sweep_stale_media(dir, STALE_MEDIA_AGE);
+
+    let (data, mime_type) = fit_image(data, mime_type, limits)?;
+    let ext = extension_for(&mime_type, file_name);
+    let path = dir.join(format!("{}.{ext}", uuid::Uuid::new_v4()));
+    std::fs::write(&path, &data)?;
+    Ok(InboundMediaFile { path, mime_type })
+}
+
+#[cfg(feature = "image-normalization")]
+fn fit_image(
+    data: Vec<u8>,
+    mime_type: &str,
+    limits: InboundImageLimits,
+) -> Result<(Vec<u8>, String)> {
+    use image::{GenericImageView, ImageFormat, imageops::FilterType};
+
+    // Formats the decoder doesn't cover (GIF, HEIC) pass through untouched
+    // and are only held to the byte limit.
+    let Ok(decoded) = image::load_from_memory(&data) else {
+        return ensure_within_bytes(data, mime_type.to_string(), limits);
+    };
+    let (width, height) = decoded.dimensions();
+    if width.max(height) <= limits.max_dimension && data.len() <= limits.max_bytes {
+        return Ok((data, mime_type.to_string()));
+    }
+
+    let (format, mime) = if mime_type.eq_ignore_ascii_case("image/png") {
+        (ImageFormat::Png, "image/png")
+    } else {
+        (ImageFormat::Jpeg, "image/jpeg")
+    };
+    let mut dimension = limits.max_dimension.min(width.max(height));
+    loop {
+        let resized = decoded.resize(dimension, dimension, FilterType::Triangle);
+        let resized = if format == ImageFormat::Jpeg {
+            image::DynamicImage::ImageRgb8(resized.to_rgb8())
+        } else {
+            resized
+        };
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        resized.write_to(&mut cursor, format)?;
+        let encoded = cursor.into_inner();
+        if encoded.len() <= limits.max_bytes || dimension / 2 < MIN_DOWNSCALE_DIMENSION {
+            return ensure_within_bytes(encoded, mime.to_string(), limits);
+        }
+        dimension /= 2;
+    }
+}
+
+#[cfg(not(feature = "image-normalization"))]
+fn fit_image(
+    data: Vec<u8>,
+    mime_type: &str,
+    limits: InboundImageLimits,
+) -> Result<(Vec<u8>, String)> {
+    ensure_within_bytes(data, mime_type.to_string(), limits)
+}
+
+fn ensure_within_bytes(
+    data: Vec<u8>,
+    mime_type: String,
+    limits: InboundImageLimits,
+) -> Result<(Vec<u8>, String)> {
+    if data.len() > limits.max_bytes {
+        anyhow::bail!(
+            "image is {} bytes, over the {} byte limit",
+            data.len(),
+            limits.max_bytes
+        );
+    }
+    Ok((data, mime_type))
+}
+
+/// Image MIME type implied by a file name's extension.
+pub fn image_mime_from_name(file_name: &str) -> &'static str {
+    let ext = Path::new(file_name)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase);
+    match ext.as_deref() {
+        Some("png") => "image/png",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("bmp") => "image/bmp",
+        _ => "image/jpeg",
+    }
+}
+
+fn extension_for(mime_type: &str, file_name: &str) -> String {
+    match mime_type.to_ascii_lowercase().as_str() {
+        "image/png" => "png".into(),
+        "image/jpeg" | "image/jpg" => "jpg".into(),
+        "image/gif" => "gif".into(),
+        "image/webp" => "webp".into(),
+        _ => Path::new(file_name)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .filter(|ext| ext.chars().all(|c| c.is_ascii_alphanumeric()))
+            .map(str::to_ascii_lowercase)
+            .unwrap_or_else(|| "bin".into()),
+    }
+}
+
+/// Remove files in `dir` last modified more than `max_age` ago.
+pub(crate) fn sweep_stale_media(dir: &Path, max_age: Duration) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let now = SystemTime::now();
+    for entry in entries.flatten() {
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        let stale = meta.is_file()
+            && meta
+                .modified()
+                .ok()
+                .and_then(|modified| now.duration_since(modified).ok())
+                .is_some_and(|age| age > max_age);
+        if stale {
+            let _ = std::fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// Deletes a message's inbound media files when dropped. Only paths under
+/// the media root are touched, so a channel can't use `media_files` to
+/// delete arbitrary files.
+#[derive(Debug)]
+pub struct InboundMediaCleanup {
+    root: PathBuf,
+    paths: Vec<PathBuf>,
+}
+
+impl InboundMediaCleanup {
+    pub fn new(files: &[InboundMediaFile]) -> Self {
+        Self::with_root(media_root(), files)
+    }
+
+    fn with_root(root: PathBuf, files: &[InboundMediaFile]) -> Self {
+        Self {
+            root,
+            paths: files.iter().map(|f| f.path.clone()).collect(),
+        }
+    }
+}
+
+impl Drop for InboundMediaCleanup {
+    fn drop(&mut self) {
+        for path in &self.paths {
+            let contained = path.starts_with(&self.root)
+                && !path.components().any(|c| matches!(c, Component::ParentDir));
+            if !contained {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                        .with_attrs(::serde_json::json!({"path": path.display().to_string()})),
+                    "not removing inbound media outside the media directory"
+                );
+                continue;
+            }
+            if let Err(e) = std::fs::remove_file(path)
+                && e.kind() != std::io::ErrorKind::NotFound
+            {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "path": path.display().to_string(),
+                            "error": format!("{}", e),
+                        })),
+                    "failed to remove inbound media"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(max_bytes: usize, max_dimension: u32) -> InboundImageLimits {
+        InboundImageLimits {
+            max_images: 4,
+            max_bytes,
+            max_dimension,
+        }
+    }
+
+    #[cfg(feature = "image-normalization")]
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let img =
+            image::DynamicImage::ImageRgba8(image::RgbaImage::from_fn(width, height, |x, y| {
+                image::Rgba([(x % 256) as u8, (y % 256) as u8, ((x + y) % 256) as u8, 255])
+            }));
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        img.write_to(&mut cursor, image::ImageFormat::Png).unwrap();
+        cursor.into_inner()
+    }
+
+    #[test]
+    fn limits_follow_multimodal_config() {
+        let config = MultimodalConfig {
+            max_images: 40,
+            max_image_size_mb: 2,
+            max_image_dimension: 10,
+            ..Default::default()
+        };
+        let limits = InboundImageLimits::from_config(&config);
+        assert_eq!(limits.max_images, 16);
+        assert_eq!(limits.max_bytes, 2 * 1024 * 1024);
+        assert_eq!(limits.max_dimension, MIN_DOWNSCALE_DIMENSION);
+    }
+
+    #[cfg(feature = "image-normalization")]
+    #[test]
+    fn oversized_photo_is_downscaled_to_max_dimension() {
+        use image::GenericImageView;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let stored = write_inbound_image(
+            tmp.path(),
+            "big.png",
+            png(1200, 600),
+            "image/png",
+            limits(usize::MAX, 400),
+        )
+        .unwrap();
+
+        assert!(stored.path.starts_with(tmp.path()));
+        assert_eq!(stored.mime_type, "image/png");
+        let decoded = image::open(&stored.path).unwrap();
+        assert_eq!(decoded.dimensions(), (400, 200));
+    }
+
+    #[test]
+    fn image_over_byte_limit_is_rejected() {
+        let tmp = tempfile::tempdir().unwrap();
+        let err = write_inbound_image(
+            tmp.path(),
+            "anim.gif",
+            vec![0u8; 2048],
+            "image/gif",
+            limits(1024, 2048),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("byte limit"));
+    }
+
+    #[test]
+    fn cleanup_removes_only_files_under_root() {
+        let root = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        let inside_path = root.path().join("telegram").join("a.jpg");
+        std::fs::create_dir_all(inside_path.parent().unwrap()).unwrap();
+        std::fs::write(&inside_path, b"x").unwrap();
+        let outside_path = outside.path().join("keep.jpg");
+        std::fs::write(&outside_path, b"x").unwrap();
+
+        let files = [&inside_path, &outside_path].map(|path| InboundMediaFile {
+            path: path.clone(),
+            mime_type: "image/jpeg".into(),
+        });
+        drop(InboundMediaCleanup::with_root(
+            root.path().to_path_buf(),
+            &files,
+        ));
+
+        assert!(!inside_path.exists());
+        assert!(outside_path.exists());
+    }
+
+    #[test]
+    fn sweep_removes_stale_files_only() {
+        let tmp = tempfile::tempdir().unwrap();
+        let stale = tmp.path().join("old.jpg");
+        let fresh = tmp.path().join("new.jpg");
+        std::fs::write(&stale, b"x").unwrap();
+        std::fs::write(&fresh, b"x").unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&stale)
+            .unwrap()
+            .set_modified(SystemTime::now() - 2 * STALE_MEDIA_AGE)
+            .unwrap();
+
+        sweep_stale_media(tmp.path(), STALE_MEDIA_AGE);
+
+        assert!(!stale.exists());
+        assert!(fresh.exists());
+    }
+
+    #[test]
+    fn media_dir_sanitizes_channel_name() {
+        let root = Path::new("/tmp/zeroclaw-media");
+        assert_eq!(media_dir_in(root, "../telegram"), root.join("___telegram"));
+    }
+}
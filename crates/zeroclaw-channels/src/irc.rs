@@ -1,5 +1,9 @@
+use anyhow::Context;
 use async_trait::async_trait;
+use parking_lot::Mutex as SyncMutex;
 use portable_atomic::{AtomicU64, Ordering};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::sync::{Mutex, mpsc};
@@ -32,6 +36,14 @@ pub struct IrcChannel {
     sasl_password: Option<String>,
     verify_tls: bool,
     mention_only: bool,
+    /// PEM client certificate presented in the TLS handshake (CertFP).
+    /// Enables SASL EXTERNAL.
+    client_cert_path: Option<PathBuf>,
+    /// PEM private key for `client_cert_path`; `None` reads the key from
+    /// the certificate file.
+    client_key_path: Option<PathBuf>,
+    /// How the most recent registration authenticated, for `channel doctor`.
+    last_auth: Arc<SyncMutex<Option<AuthOutcome>>>,
     /// Shared write half of the TLS stream for sending messages.
     writer: Arc<Mutex<Option<WriteHalf>>>,
 }
@@ -148,6 +160,320 @@ fn encode_sasl_plain(nick: &str, password: &str) -> String {
     out
 }
 
+/// SASL mechanisms the client negotiates, in preference order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SaslMechanism {
+    /// Authenticate with the TLS client certificate (CertFP).
+    External,
+    /// Account name + `sasl_password`.
+    Plain,
+}
+
+impl SaslMechanism {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::External => "EXTERNAL",
+            Self::Plain => "PLAIN",
+        }
+    }
+}
+
+/// How a registration ended up authenticated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AuthOutcome {
+    Sasl(SaslMechanism),
+    NickServ,
+    ServerPassword,
+    Unauthenticated,
+}
+
+impl std::fmt::Display for AuthOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sasl(mechanism) => write!(f, "SASL {}", mechanism.as_str()),
+            Self::NickServ => f.write_str("NickServ IDENTIFY"),
+            Self::ServerPassword => f.write_str("server password"),
+            Self::Unauthenticated => f.write_str("none"),
+        }
+    }
+}
+
+/// Client side of IRC registration: CAP/SASL negotiation with fallback
+/// between mechanisms, nick collisions, and NickServ identification.
+/// Feed it every server message and write back the lines it returns; it
+/// does no I/O itself.
+struct Registration {
+    /// Configured nickname — also the SASL / NickServ account name.
+    account: String,
+    /// Nick the server currently knows us by.
+    nick: String,
+    username: String,
+    server_password: Option<String>,
+    nickserv_password: Option<String>,
+    sasl_password: Option<String>,
+    /// Mechanisms not yet attempted.
+    pending_mechanisms: VecDeque<SaslMechanism>,
+    /// Mechanism currently being negotiated.
+    attempting: Option<SaslMechanism>,
+    /// CAP negotiation is open and must be closed with `CAP END`.
+    cap_open: bool,
+    sasl_succeeded: Option<SaslMechanism>,
+    registered: bool,
+}
+
+impl Registration {
+    fn new(channel: &IrcChannel) -> Self {
+        let mut pending_mechanisms = VecDeque::new();
+        if channel.client_cert_path.is_some() {
+            pending_mechanisms.push_back(SaslMechanism::External);
+        }
+        if channel.sasl_password.is_some() {
+            pending_mechanisms.push_back(SaslMechanism::Plain);
+        }
+        Self {
+            account: channel.nickname.clone(),
+            nick: channel.nickname.clone(),
+            username: channel.username.clone(),
+            server_password: channel.server_password.clone(),
+            nickserv_password: channel.nickserv_password.clone(),
+            sasl_password: channel.sasl_password.clone(),
+            pending_mechanisms,
+            attempting: None,
+            cap_open: false,
+            sasl_succeeded: None,
+            registered: false,
+        }
+    }
+
+    /// Lines that open the connection: capability request, PASS, NICK, USER.
+    fn opening_lines(&mut self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if !self.pending_mechanisms.is_empty() {
+            self.cap_open = true;
+            lines.push("CAP REQ :sasl".to_string());
+        }
+        if let Some(pass) = &self.server_password {
+            lines.push(format!("PASS {pass}"));
+        }
+        lines.push(format!("NICK {}", self.nick));
+        lines.push(format!("USER {} 0 * :ZeroClaw", self.username));
+        lines
+    }
+
+    fn auth_outcome(&self) -> AuthOutcome {
+        if let Some(mechanism) = self.sasl_succeeded {
+            AuthOutcome::Sasl(mechanism)
+        } else if self.nickserv_password.is_some() {
+            AuthOutcome::NickServ
+        } else if self.server_password.is_some() {
+            AuthOutcome::ServerPassword
+        } else {
+            AuthOutcome::Unauthenticated
+        }
+    }
+
+    fn end_cap(&mut self) -> Vec<String> {
+        self.attempting = None;
+        self.pending_mechanisms.clear();
+        if std::mem::take(&mut self.cap_open) {
+            vec!["CAP END".to_string()]
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Start the next untried mechanism, or give up on SASL.
+    fn next_mechanism(&mut self) -> Vec<String> {
+        match self.pending_mechanisms.pop_front() {
+            Some(mechanism) => {
+                self.attempting = Some(mechanism);
+                vec![format!("AUTHENTICATE {}", mechanism.as_str())]
+            }
+            None => self.end_cap(),
+        }
+    }
+
+    /// NickServ identification once registered. When a collision left us on
+    /// a suffixed nick, identify to the account explicitly and reclaim it.
+    fn identify_lines(&self) -> Vec<String> {
+        let on_account_nick = self.nick.eq_ignore_ascii_case(&self.account);
+        let mut lines = Vec::new();
+        if self.sasl_succeeded.is_none()
+            && let Some(pass) = &self.nickserv_password
+        {
+            if on_account_nick {
+                lines.push(format!("PRIVMSG NickServ :IDENTIFY {pass}"));
+            } else {
+                lines.push(format!(
+                    "PRIVMSG NickServ :IDENTIFY {} {pass}",
+                    self.account
+                ));
+            }
+        }
+        let identified = self.sasl_succeeded.is_some() || self.nickserv_password.is_some();
+        if identified && !on_account_nick {
+            lines.push(format!("PRIVMSG NickServ :REGAIN {}", self.account));
+        }
+        lines
+    }
+
+    /// Advance on one server message; returns the lines to send back.
+    fn handle(&mut self, msg: &IrcMessage) -> Vec<String> {
+        match msg.command.as_str() {
+            "CAP" if self.cap_open => {
+                let subcommand = msg.params.get(1).map_or("", String::as_str);
+                let acks_sasl = msg
+                    .params
+                    .last()
+                    .is_some_and(|caps| caps.split_whitespace().any(|cap| cap == "sasl"));
+                match subcommand {
+                    "ACK" if acks_sasl && self.attempting.is_none() => self.next_mechanism(),
+                    "NAK" => {
+                        ::zeroclaw_log::record!(
+                            WARN,
+                            ::zeroclaw_log::Event::new(
+                                module_path!(),
+                                ::zeroclaw_log::Action::Note
+                            )
+                            .with_outcome(::zeroclaw_log::EventOutcome::Unknown),
+                            "server does not support SASL, continuing without it"
+                        );
+                        self.end_cap()
+                    }
+                    _ => Vec::new(),
+                }
+            }
+
+            // Server asks for credentials
+            "AUTHENTICATE" if msg.params.first().is_some_and(|p| p == "+") => {
+                match (self.attempting, self.sasl_password.as_deref()) {
+                    // EXTERNAL: the certificate is the credential; send an
+                    // empty response so the server derives the account.
+                    (Some(SaslMechanism::External), _) => vec!["AUTHENTICATE +".to_string()],
+                    (Some(SaslMechanism::Plain), Some(password)) => vec![format!(
+                        "AUTHENTICATE {}",
+                        encode_sasl_plain(&self.account, password)
+                    )],
+                    _ => Vec::new(),
+                }
+            }
+
+            // RPL_SASLSUCCESS
+            "903" => {
+                self.sasl_succeeded = self.attempting;
+                if let Some(mechanism) = self.sasl_succeeded {
+                    ::zeroclaw_log::record!(
+                        INFO,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_attrs(::serde_json::json!({"mechanism": mechanism.as_str()})),
+                        "IRC SASL authentication succeeded"
+                    );
+                }
+                self.end_cap()
+            }
+
+            // ERR_SASLFAIL / ERR_SASLTOOLONG — fall back to the next mechanism
+            "904" | "905" => {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "numeric": msg.command,
+                            "mechanism": self.attempting.map(SaslMechanism::as_str),
+                        })),
+                    "IRC SASL authentication failed"
+                );
+                self.attempting = None;
+                self.next_mechanism()
+            }
+
+            // ERR_NICKLOCKED / ERR_SASLABORTED / ERR_SASLALREADY
+            "902" | "906" | "907" => self.end_cap(),
+
+            // ERR_NICKNAMEINUSE / ERR_NICKCOLLISION during registration
+            "433" | "436" if !self.registered => {
+                let alt = format!("{}_", self.nick);
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                        .with_attrs(::serde_json::json!({"current_nick": self.nick, "alt": alt})),
+                    "nickname is in use, trying"
+                );
+                self.nick.clone_from(&alt);
+                vec![format!("NICK {alt}")]
+            }
+
+            // Our nick changed — a REGAIN landing, or the server renaming us.
+            "NICK"
+                if msg
+                    .nick()
+                    .is_some_and(|n| n.eq_ignore_ascii_case(&self.nick)) =>
+            {
+                let Some(new_nick) = msg.params.first() else {
+                    return Vec::new();
+                };
+                self.nick.clone_from(new_nick);
+                if self.registered && !self.nick.eq_ignore_ascii_case(&self.account) {
+                    self.identify_lines()
+                } else {
+                    Vec::new()
+                }
+            }
+
+            // RPL_WELCOME — registration complete
+            "001" => {
+                self.registered = true;
+                if let Some(nick) = msg.params.first() {
+                    self.nick.clone_from(nick);
+                }
+                let mut lines = self.end_cap();
+                lines.extend(self.identify_lines());
+                lines
+            }
+
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Load a PEM client certificate chain and its private key. The key is
+/// read from `key_path`, or from the certificate file itself when unset
+/// (the combined `.pem` most CertFP guides produce).
+fn load_client_identity(
+    cert_path: &Path,
+    key_path: Option<&Path>,
+) -> anyhow::Result<(
+    Vec<rustls::pki_types::CertificateDer<'static>>,
+    rustls::pki_types::PrivateKeyDer<'static>,
+)> {
+    use rustls::pki_types::pem::PemObject;
+
+    let certs = rustls::pki_types::CertificateDer::pem_file_iter(cert_path)
+        .with_context(|| {
+            format!(
+                "failed to read IRC client certificate {}",
+                cert_path.display()
+            )
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| {
+            format!(
+                "invalid PEM in IRC client certificate {}",
+                cert_path.display()
+            )
+        })?;
+    if certs.is_empty() {
+        anyhow::bail!("no certificate found in {}", cert_path.display());
+    }
+
+    let key_path = key_path.unwrap_or(cert_path);
+    let key = rustls::pki_types::PrivateKeyDer::from_pem_file(key_path)
+        .with_context(|| format!("failed to read IRC client key {}", key_path.display()))?;
+    Ok((certs, key))
+}
+
 fn split_message(message: &str, max_bytes: usize) -> Vec<String> {
     let mut chunks = Vec::new();
 
@@ -251,10 +577,26 @@ impl IrcChannel {
             sasl_password: cfg.sasl_password,
             verify_tls: cfg.verify_tls,
             mention_only: cfg.mention_only,
+            client_cert_path: None,
+            client_key_path: None,
+            last_auth: Arc::new(SyncMutex::new(None)),
             writer: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Present a client certificate during the TLS handshake and try SASL
+    /// EXTERNAL before PLAIN. `key_path = None` reads the key from the
+    /// certificate file.
+    pub fn with_client_cert(
+        mut self,
+        cert_path: Option<PathBuf>,
+        key_path: Option<PathBuf>,
+    ) -> Self {
+        self.client_cert_path = cert_path;
+        self.client_key_path = key_path;
+        self
+    }
+
     /// Return the alias under `[channels.irc.<alias>]` that this
     /// channel handle is bound to.
     pub fn alias(&self) -> &str {
@@ -294,17 +636,24 @@ impl IrcChannel {
         let addr = format!("{}:{}", self.server, self.port);
         let tcp = tokio::net::TcpStream::connect(&addr).await?;
 
-        let tls_config = if self.verify_tls {
+        let builder = if self.verify_tls {
             let root_store: rustls::RootCertStore =
                 webpki_roots::TLS_SERVER_ROOTS.iter().cloned().collect();
-            rustls::ClientConfig::builder()
-                .with_root_certificates(root_store)
-                .with_no_client_auth()
+            rustls::ClientConfig::builder().with_root_certificates(root_store)
         } else {
             rustls::ClientConfig::builder()
                 .dangerous()
                 .with_custom_certificate_verifier(Arc::new(NoVerify))
-                .with_no_client_auth()
+        };
+        let tls_config = match &self.client_cert_path {
+            Some(cert_path) => {
+                let (certs, key) =
+                    load_client_identity(cert_path, self.client_key_path.as_deref())?;
+                builder
+                    .with_client_auth_cert(certs, key)
+                    .context("IRC client certificate and key do not match")?
+            }
+            None => builder.with_no_client_auth(),
         };
 
         let connector = tokio_rustls::TlsConnector::from(Arc::new(tls_config));
@@ -321,6 +670,51 @@ impl IrcChannel {
         writer.flush().await?;
         Ok(())
     }
+
+    /// Connect and register without joining anything, then QUIT. Proves
+    /// the credentials work and reports which mechanism authenticated.
+    /// Stops at RPL_WELCOME, before NickServ identification, so a probe
+    /// never reclaims the nick from a running listener.
+    async fn probe_registration(&self) -> anyhow::Result<AuthOutcome> {
+        let tls = self.connect().await?;
+        let (reader, mut writer) = tokio::io::split(tls);
+        let mut registration = Registration::new(self);
+        for line in registration.opening_lines() {
+            Self::send_raw(&mut writer, &line).await?;
+        }
+
+        let mut buf_reader = BufReader::new(reader);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if buf_reader.read_line(&mut line).await? == 0 {
+                anyhow::bail!("IRC connection closed during registration");
+            }
+            let Some(msg) = IrcMessage::parse(&line) else {
+                continue;
+            };
+            match msg.command.as_str() {
+                "001" => {
+                    let _ = Self::send_raw(&mut writer, "QUIT :health check").await;
+                    return Ok(registration.auth_outcome());
+                }
+                "PING" => {
+                    let token = msg.params.first().map_or("", String::as_str);
+                    Self::send_raw(&mut writer, &format!("PONG :{token}")).await?;
+                }
+                "464" => anyhow::bail!("IRC password mismatch"),
+                "ERROR" => anyhow::bail!(
+                    "IRC server closed the link: {}",
+                    msg.params.last().map_or("", String::as_str)
+                ),
+                _ => {
+                    for reply in registration.handle(&msg) {
+                        Self::send_raw(&mut writer, &reply).await?;
+                    }
+                }
+            }
+        }
+    }
 }
 
 /// Certificate verifier that accepts any certificate (for `verify_tls=false`).
@@ -417,37 +811,24 @@ impl Channel for IrcChannel {
     }
 
     async fn listen(&self, tx: mpsc::Sender<ChannelMessage>) -> anyhow::Result<()> {
-        let mut current_nick = self.nickname.clone();
         ::zeroclaw_log::record!(
             INFO,
             ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
             &format!(
                 "IRC channel connecting to {}:{} as {}...",
-                self.server, self.port, current_nick
+                self.server, self.port, self.nickname
             )
         );
 
         let tls = self.connect().await?;
         let (reader, mut writer) = tokio::io::split(tls);
 
-        // --- SASL negotiation ---
-        if self.sasl_password.is_some() {
-            Self::send_raw(&mut writer, "CAP REQ :sasl").await?;
-        }
-
-        // --- Server password ---
-        if let Some(ref pass) = self.server_password {
-            Self::send_raw(&mut writer, &format!("PASS {pass}")).await?;
+        // --- CAP/SASL request, server password, NICK/USER registration ---
+        let mut registration = Registration::new(self);
+        for line in registration.opening_lines() {
+            Self::send_raw(&mut writer, &line).await?;
         }
 
-        // --- Nick/User registration ---
-        Self::send_raw(&mut writer, &format!("NICK {current_nick}")).await?;
-        Self::send_raw(
-            &mut writer,
-            &format!("USER {} 0 * :ZeroClaw", self.username),
-        )
-        .await?;
-
         // Store writer for send
         {
             let mut guard = self.writer.lock().await;
@@ -456,8 +837,6 @@ impl Channel for IrcChannel {
 
         let mut buf_reader = BufReader::new(reader);
         let mut line = String::new();
-        let mut registered = false;
-        let mut sasl_pending = self.sasl_password.is_some();
 
         loop {
             line.clear();
@@ -483,6 +862,17 @@ impl Channel for IrcChannel {
                 continue;
             };
 
+            // CAP/SASL, nick collisions and NickServ identification
+            let replies = registration.handle(&msg);
+            if !replies.is_empty() {
+                let mut guard = self.writer.lock().await;
+                if let Some(ref mut w) = *guard {
+                    for reply in &replies {
+                        Self::send_raw(w, reply).await?;
+                    }
+                }
+            }
+
             match msg.command.as_str() {
                 "PING" => {
                     let token = msg.params.first().map_or("", String::as_str);
@@ -492,103 +882,17 @@ impl Channel for IrcChannel {
                     }
                 }
 
-                // CAP responses for SASL
-                "CAP" if sasl_pending && msg.params.iter().any(|p| p.contains("sasl")) => {
-                    if msg.params.iter().any(|p| p.contains("ACK")) {
-                        // CAP * ACK :sasl — server accepted, start SASL auth
-                        let mut guard = self.writer.lock().await;
-                        if let Some(ref mut w) = *guard {
-                            Self::send_raw(w, "AUTHENTICATE PLAIN").await?;
-                        }
-                    } else if msg.params.iter().any(|p| p.contains("NAK")) {
-                        // CAP * NAK :sasl — server rejected SASL, proceed without it
-                        ::zeroclaw_log::record!(
-                            WARN,
-                            ::zeroclaw_log::Event::new(
-                                module_path!(),
-                                ::zeroclaw_log::Action::Note
-                            )
-                            .with_outcome(::zeroclaw_log::EventOutcome::Unknown),
-                            "server does not support SASL, continuing without it"
-                        );
-                        sasl_pending = false;
-                        let mut guard = self.writer.lock().await;
-                        if let Some(ref mut w) = *guard {
-                            Self::send_raw(w, "CAP END").await?;
-                        }
-                    }
-                }
-
-                "AUTHENTICATE" if sasl_pending && msg.params.first().is_some_and(|p| p == "+") => {
-                    // Server sends "AUTHENTICATE +" to request credentials
-                    // sasl_password is loaded from runtime config, not hard-coded
-                    if let Some(password) = self.sasl_password.as_deref() {
-                        let encoded = encode_sasl_plain(&current_nick, password);
-                        let mut guard = self.writer.lock().await;
-                        if let Some(ref mut w) = *guard {
-                            Self::send_raw(w, &format!("AUTHENTICATE {encoded}")).await?;
-                        }
-                    } else {
-                        // SASL was requested but no password is configured; abort SASL
-                        ::zeroclaw_log::record!(
-                            WARN,
-                            ::zeroclaw_log::Event::new(
-                                module_path!(),
-                                ::zeroclaw_log::Action::Note
-                            )
-                            .with_outcome(::zeroclaw_log::EventOutcome::Unknown),
-                            "SASL authentication requested but no SASL password is configured; aborting SASL"
-                        );
-                        sasl_pending = false;
-                        let mut guard = self.writer.lock().await;
-                        if let Some(ref mut w) = *guard {
-                            Self::send_raw(w, "CAP END").await?;
-                        }
-                    }
-                }
-
-                // RPL_SASLSUCCESS (903) — SASL done, end CAP
-                "903" => {
-                    sasl_pending = false;
-                    let mut guard = self.writer.lock().await;
-                    if let Some(ref mut w) = *guard {
-                        Self::send_raw(w, "CAP END").await?;
-                    }
-                }
-
-                // SASL failure (904, 905, 906, 907)
-                "904" | "905" | "906" | "907" => {
-                    ::zeroclaw_log::record!(
-                        WARN,
-                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                            .with_outcome(::zeroclaw_log::EventOutcome::Unknown),
-                        &format!("SASL authentication failed ({})", msg.command)
-                    );
-                    sasl_pending = false;
-                    let mut guard = self.writer.lock().await;
-                    if let Some(ref mut w) = *guard {
-                        Self::send_raw(w, "CAP END").await?;
-                    }
-                }
-
                 // RPL_WELCOME — registration complete
                 "001" => {
-                    registered = true;
+                    let auth = registration.auth_outcome();
+                    *self.last_auth.lock() = Some(auth);
                     ::zeroclaw_log::record!(
                         INFO,
-                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
-                        &format!("registered as {}", current_nick)
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_attrs(::serde_json::json!({"auth": auth.to_string()})),
+                        &format!("registered as {}", registration.nick)
                     );
 
-                    // NickServ authentication
-                    if let Some(ref pass) = self.nickserv_password {
-                        let mut guard = self.writer.lock().await;
-                        if let Some(ref mut w) = *guard {
-                            Self::send_raw(w, &format!("PRIVMSG NickServ :IDENTIFY {pass}"))
-                                .await?;
-                        }
-                    }
-
                     // Join channels
                     for chan in &self.channels {
                         let mut guard = self.writer.lock().await;
@@ -598,27 +902,8 @@ impl Channel for IrcChannel {
                     }
                 }
 
-                // ERR_NICKNAMEINUSE (433)
-                "433" => {
-                    let alt = format!("{current_nick}_");
-                    ::zeroclaw_log::record!(
-                        WARN,
-                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                            .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                            .with_attrs(
-                                ::serde_json::json!({"current_nick": current_nick, "alt": alt})
-                            ),
-                        "nickname is in use, trying"
-                    );
-                    let mut guard = self.writer.lock().await;
-                    if let Some(ref mut w) = *guard {
-                        Self::send_raw(w, &format!("NICK {alt}")).await?;
-                    }
-                    current_nick = alt;
-                }
-
                 "PRIVMSG" => {
-                    if !registered {
+                    if !registration.registered {
                         continue;
                     }
 
@@ -638,7 +923,10 @@ impl Channel for IrcChannel {
                         continue;
                     }
 
-                    if self.mention_only && is_channel && !Self::is_mentioned(&current_nick, text) {
+                    if self.mention_only
+                        && is_channel
+                        && !Self::is_mentioned(&registration.nick, text)
+                    {
                         continue;
                     }
 
@@ -691,17 +979,30 @@ impl Channel for IrcChannel {
     }
 
     async fn health_check(&self) -> bool {
-        // Lightweight connectivity check: TLS connect + QUIT
-        match self.connect().await {
-            Ok(tls) => {
-                let (_, mut writer) = tokio::io::split(tls);
-                let _ = Self::send_raw(&mut writer, "QUIT :health check").await;
+        // Register for real so bad credentials or certificates show up,
+        // then QUIT before joining anything.
+        match self.probe_registration().await {
+            Ok(auth) => {
+                *self.last_auth.lock() = Some(auth);
                 true
             }
-            Err(_) => false,
+            Err(e) => {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                    "irc: health check registration failed"
+                );
+                false
+            }
         }
     }
 
+    fn health_detail(&self) -> Option<String> {
+        self.last_auth.lock().map(|auth| format!("auth: {auth}"))
+    }
+
     async fn start_typing(&self, _recipient: &str) -> anyhow::Result<()> {
         // No reliable server-supported typing indicator on IRC.
         Ok(())
@@ -988,7 +1289,7 @@ mod tests {
             username: None,
             channels: vec![],
             alias: "irc_test_alias".into(),
-            peer_resolver: Arc::new(Vec::new),
+            peer_resolver: Arc::new(Vec::<String>::new),
             server_password: None,
             nickserv_password: None,
             sasl_password: None,
@@ -1057,7 +1358,7 @@ mod tests {
             username: None,
             channels: vec![],
             alias: "irc_test_alias".into(),
-            peer_resolver: Arc::new(Vec::new),
+            peer_resolver: Arc::new(Vec::<String>::new),
             server_password: None,
             nickserv_password: None,
             sasl_password: None,
@@ -1078,7 +1379,7 @@ mod tests {
             username: Some("customuser".into()),
             channels: vec![],
             alias: "irc_test_alias".into(),
-            peer_resolver: Arc::new(Vec::new),
+            peer_resolver: Arc::new(Vec::<String>::new),
             server_password: None,
             nickserv_password: None,
             sasl_password: None,
@@ -1142,6 +1443,223 @@ mod tests {
         assert!(!ch.mention_only);
     }
 
+    // ── CAP/SASL registration state machine ─────────────────
+
+    fn registration(
+        sasl_password: Option<&str>,
+        nickserv_password: Option<&str>,
+        client_cert: bool,
+    ) -> Registration {
+        let ch = IrcChannel::new(IrcChannelConfig {
+            server: "irc.example.com".into(),
+            port: 6697,
+            nickname: "zcbot".into(),
+            username: None,
+            channels: vec!["#test".into()],
+            alias: "default".into(),
+            peer_resolver: Arc::new(Vec::<String>::new),
+            server_password: None,
+            nickserv_password: nickserv_password.map(Into::into),
+            sasl_password: sasl_password.map(Into::into),
+            verify_tls: true,
+            mention_only: false,
+        })
+        .with_client_cert(client_cert.then(|| "/tmp/irc.pem".into()), None);
+        Registration::new(&ch)
+    }
+
+    /// Play a scripted server against `registration`, asserting the client's
+    /// reply to every line.
+    fn run_script(registration: &mut Registration, script: &[(&str, &[&str])]) {
+        for (server_line, expected) in script {
+            let msg = IrcMessage::parse(server_line).unwrap();
+            assert_eq!(
+                registration.handle(&msg),
+                expected.to_vec(),
+                "client reply to {server_line:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn sasl_external_with_client_cert() {
+        let mut reg = registration(None, None, true);
+        assert_eq!(
+            reg.opening_lines(),
+            vec!["CAP REQ :sasl", "NICK zcbot", "USER zcbot 0 * :ZeroClaw"]
+        );
+        run_script(
+            &mut reg,
+            &[
+                (":srv CAP * ACK :sasl", &["AUTHENTICATE EXTERNAL"]),
+                ("AUTHENTICATE +", &["AUTHENTICATE +"]),
+                (
+                    ":srv 900 zcbot zcbot!zcbot@host zcbot :You are now logged in as zcbot",
+                    &[],
+                ),
+                (
+                    ":srv 903 zcbot :SASL authentication successful",
+                    &["CAP END"],
+                ),
+                (":srv 001 zcbot :Welcome", &[]),
+            ],
+        );
+        assert!(reg.registered);
+        assert_eq!(
+            reg.auth_outcome(),
+            AuthOutcome::Sasl(SaslMechanism::External)
+        );
+        assert_eq!(reg.auth_outcome().to_string(), "SASL EXTERNAL");
+    }
+
+    #[test]
+    fn sasl_external_failure_falls_back_to_plain() {
+        let mut reg = registration(Some("hunter2"), None, true);
+        reg.opening_lines();
+        let plain = format!("AUTHENTICATE {}", encode_sasl_plain("zcbot", "hunter2"));
+        run_script(
+            &mut reg,
+            &[
+                (":srv CAP * ACK :sasl", &["AUTHENTICATE EXTERNAL"]),
+                ("AUTHENTICATE +", &["AUTHENTICATE +"]),
+                (
+                    ":srv 904 zcbot :SASL authentication failed",
+                    &["AUTHENTICATE PLAIN"],
+                ),
+                ("AUTHENTICATE +", &[plain.as_str()]),
+                (
+                    ":srv 903 zcbot :SASL authentication successful",
+                    &["CAP END"],
+                ),
+            ],
+        );
+        assert_eq!(reg.auth_outcome(), AuthOutcome::Sasl(SaslMechanism::Plain));
+    }
+
+    #[test]
+    fn sasl_gives_up_after_last_mechanism_fails() {
+        let mut reg = registration(Some("wrong"), Some("nspass"), false);
+        assert_eq!(reg.opening_lines()[0], "CAP REQ :sasl");
+        run_script(
+            &mut reg,
+            &[
+                (":srv CAP * ACK :sasl", &["AUTHENTICATE PLAIN"]),
+                (":srv 904 zcbot :SASL authentication failed", &["CAP END"]),
+                (
+                    ":srv 001 zcbot :Welcome",
+                    &["PRIVMSG NickServ :IDENTIFY nspass"],
+                ),
+            ],
+        );
+        assert_eq!(reg.auth_outcome(), AuthOutcome::NickServ);
+    }
+
+    #[test]
+    fn sasl_nak_ends_negotiation() {
+        let mut reg = registration(Some("pw"), None, false);
+        reg.opening_lines();
+        run_script(&mut reg, &[(":srv CAP * NAK :sasl", &["CAP END"])]);
+        assert_eq!(reg.auth_outcome(), AuthOutcome::Unauthenticated);
+    }
+
+    #[test]
+    fn no_sasl_configured_skips_cap() {
+        let mut reg = registration(None, None, false);
+        assert_eq!(
+            reg.opening_lines(),
+            vec!["NICK zcbot", "USER zcbot 0 * :ZeroClaw"]
+        );
+        run_script(&mut reg, &[(":srv CAP * ACK :sasl", &[])]);
+    }
+
+    #[test]
+    fn nick_collision_suffixes_then_reclaims_with_nickserv() {
+        let mut reg = registration(None, Some("nspass"), false);
+        reg.opening_lines();
+        run_script(
+            &mut reg,
+            &[
+                (
+                    ":srv 433 * zcbot :Nickname is already in use",
+                    &["NICK zcbot_"],
+                ),
+                (
+                    ":srv 001 zcbot_ :Welcome",
+                    &[
+                        "PRIVMSG NickServ :IDENTIFY zcbot nspass",
+                        "PRIVMSG NickServ :REGAIN zcbot",
+                    ],
+                ),
+                // REGAIN lands: back on the account nick, nothing more to do.
+                (":zcbot_!zcbot@host NICK :zcbot", &[]),
+                // A later collision on someone else's NICK attempt is ignored.
+                (":srv 433 zcbot other :Nickname is already in use", &[]),
+            ],
+        );
+        assert_eq!(reg.nick, "zcbot");
+    }
+
+    #[test]
+    fn server_forced_rename_reidentifies() {
+        let mut reg = registration(None, Some("nspass"), false);
+        reg.opening_lines();
+        run_script(
+            &mut reg,
+            &[
+                (
+                    ":srv 001 zcbot :Welcome",
+                    &["PRIVMSG NickServ :IDENTIFY nspass"],
+                ),
+                (
+                    ":zcbot!zcbot@host NICK :Guest4821",
+                    &[
+                        "PRIVMSG NickServ :IDENTIFY zcbot nspass",
+                        "PRIVMSG NickServ :REGAIN zcbot",
+                    ],
+                ),
+                // Someone else's nick change is not ours to track.
+                (":alice!a@host NICK :alice2", &[]),
+            ],
+        );
+        assert_eq!(reg.nick, "Guest4821");
+    }
+
+    #[test]
+    fn sasl_success_skips_nickserv_identify_but_reclaims() {
+        let mut reg = registration(Some("pw"), Some("nspass"), false);
+        reg.opening_lines();
+        run_script(
+            &mut reg,
+            &[
+                (
+                    ":srv 433 * zcbot :Nickname is already in use",
+                    &["NICK zcbot_"],
+                ),
+                (":srv CAP * ACK :sasl", &["AUTHENTICATE PLAIN"]),
+                (
+                    "AUTHENTICATE +",
+                    &[format!("AUTHENTICATE {}", encode_sasl_plain("zcbot", "pw")).as_str()],
+                ),
+                (
+                    ":srv 903 zcbot_ :SASL authentication successful",
+                    &["CAP END"],
+                ),
+                (
+                    ":srv 001 zcbot_ :Welcome",
+                    &["PRIVMSG NickServ :REGAIN zcbot"],
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn load_client_identity_reports_missing_file() {
+        let err = load_client_identity(Path::new("/nonexistent/irc.pem"), None)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("/nonexistent/irc.pem"), "{err}");
+    }
+
     // ── Config serde ────────────────────────────────────────
 
     #[test]
@@ -1158,6 +1676,8 @@ mod tests {
             server_password: None,
             nickserv_password: Some("secret".into()),
             sasl_password: None,
+            client_cert_path: Some("/etc/zeroclaw/irc.pem".into()),
+            client_key_path: None,
             verify_tls: Some(true),
             mention_only: false,
             excluded_tools: vec![],
@@ -1173,6 +1693,11 @@ mod tests {
         assert!(parsed.server_password.is_none());
         assert_eq!(parsed.nickserv_password.as_deref(), Some("secret"));
         assert!(parsed.sasl_password.is_none());
+        assert_eq!(
+            parsed.client_cert_path.as_deref(),
+            Some(std::path::Path::new("/etc/zeroclaw/irc.pem"))
+        );
+        assert!(parsed.client_key_path.is_none());
         assert_eq!(parsed.verify_tls, Some(true));
         assert!(!parsed.mention_only);
     }
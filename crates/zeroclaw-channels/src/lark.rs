@@ -1,11 +1,14 @@
+use aes::Aes256;
 use async_trait::async_trait;
 use base64::Engine as _;
+use cbc::cipher::{BlockDecryptMut, KeyIvInit, block_padding::Pkcs7};
 use futures_util::{SinkExt, StreamExt};
 use prost::Message as ProstMessage;
 use reqwest::multipart::{Form, Part};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock as StdRwLock};
 use std::time::{Duration, Instant};
 use tokio::fs;
@@ -13,7 +16,9 @@ use tokio::sync::RwLock;
 use tokio_tungstenite::tungstenite::Message as WsMsg;
 use uuid::Uuid;
 use zeroclaw_api::channel::{Channel, ChannelMessage, SendMessage};
-use zeroclaw_config::schema::StreamMode;
+use zeroclaw_config::schema::{RichTextMode, StreamMode};
+
+use crate::rich_text::{TextLimit, chunk_markdown, use_rich_format};
 
 const FEISHU_BASE_URL: &str = "https://open.feishu.cn/open-apis";
 const FEISHU_WS_BASE_URL: &str = "https://open.feishu.cn";
@@ -154,7 +159,7 @@ struct LarkEvent {
 #[derive(Debug, serde::Deserialize)]
 struct LarkEventHeader {
     event_type: String,
-    #[allow(dead_code)]
+    #[serde(default)]
     event_id: String,
 }
 
@@ -191,6 +196,16 @@ struct LarkMessage {
 /// Heartbeat timeout for WS connection — must be larger than ping_interval (default 120 s).
 /// If no binary frame (pong or event) is received within this window, reconnect.
 const WS_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(300);
+/// First delay before reconnecting a dropped WS session; doubles per
+/// consecutive failure up to [`WS_RECONNECT_MAX_BACKOFF`].
+const WS_RECONNECT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const WS_RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+/// Consecutive failed connection attempts (no session established) before
+/// the error is handed to the channel supervisor.
+const WS_MAX_CONNECT_FAILURES: u32 = 5;
+/// How long WS message/event ids are remembered for dedup. Lark redelivers
+/// unacknowledged events, including after a reconnect.
+const WS_DEDUP_TTL: Duration = Duration::from_secs(30 * 60);
 /// Refresh tenant token this many seconds before the announced expiry.
 const LARK_TOKEN_REFRESH_SKEW: Duration = Duration::from_secs(120);
 /// Fallback tenant token TTL when `expire`/`expires_in` is absent.
@@ -392,6 +407,36 @@ fn build_interactive_card_body(recipient: &str, markdown: &str) -> serde_json::V
     })
 }
 
+/// Build the full message body for sending a plain text message.
+fn build_text_message_body(recipient: &str, text: &str) -> serde_json::Value {
+    serde_json::json!({
+        "receive_id": recipient,
+        "msg_type": "text",
+        "content": serde_json::json!({ "text": text }).to_string(),
+    })
+}
+
+/// Message bodies for one reply: interactive cards when the reply is sent
+/// rich, text messages otherwise. Chunks split on paragraph boundaries and
+/// keep fenced code blocks intact.
+fn build_reply_bodies(
+    recipient: &str,
+    text: &str,
+    rich_text: RichTextMode,
+) -> Vec<serde_json::Value> {
+    let rich = use_rich_format(rich_text, text);
+    chunk_markdown(text, TextLimit::Bytes(LARK_CARD_MARKDOWN_MAX_BYTES))
+        .iter()
+        .map(|chunk| {
+            if rich {
+                build_interactive_card_body(recipient, chunk)
+            } else {
+                build_text_message_body(recipient, chunk)
+            }
+        })
+        .collect()
+}
+
 fn truncate_card_markdown(text: &str, max_bytes: usize) -> String {
     if text.len() <= max_bytes {
         return text.to_string();
@@ -734,8 +779,14 @@ pub struct LarkChannel {
     receive_mode: zeroclaw_config::schema::LarkReceiveMode,
     /// Cached tenant access token
     tenant_token: Arc<RwLock<Option<CachedTenantToken>>>,
-    /// Dedup set: WS message_ids seen in last ~30 min to prevent double-dispatch
+    /// Dedup set: WS event and message ids seen in the last
+    /// [`WS_DEDUP_TTL`]. Shared across reconnects of this handle.
     ws_seen_ids: Arc<RwLock<HashMap<String, Instant>>>,
+    /// Whether a WS long-connection session is currently established.
+    ws_connected: Arc<AtomicBool>,
+    /// `encrypt_key` from config; when set, inbound events arrive as
+    /// `{"encrypt": "..."}` and are decrypted before parsing.
+    encrypt_key: Option<String>,
     /// Per-channel proxy URL override.
     proxy_url: Option<String>,
     /// Workspace root that bounds outbound media marker reads. Resolved by
@@ -761,6 +812,9 @@ pub struct LarkChannel {
     /// via [`Self::with_streaming`].
     draft_update_interval_ms: u64,
     last_draft_edit: Arc<tokio::sync::Mutex<HashMap<String, Instant>>>,
+    /// Card vs plain text selection for `send()`, from
+    /// `[channels.lark.<alias>].rich_text`.
+    rich_text: RichTextMode,
     #[cfg(test)]
     api_base_override: Option<String>,
 }
@@ -816,6 +870,8 @@ impl LarkChannel {
             receive_mode: zeroclaw_config::schema::LarkReceiveMode::default(),
             tenant_token: Arc::new(RwLock::new(None)),
             ws_seen_ids: Arc::new(RwLock::new(HashMap::new())),
+            ws_connected: Arc::new(AtomicBool::new(false)),
+            encrypt_key: None,
             proxy_url: None,
             workspace_dir: None,
             transcription: None,
@@ -828,6 +884,7 @@ impl LarkChannel {
             stream_mode: StreamMode::Off,
             draft_update_interval_ms: 1000,
             last_draft_edit: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
+            rich_text: RichTextMode::default(),
             #[cfg(test)]
             api_base_override: None,
         }
@@ -856,7 +913,9 @@ impl LarkChannel {
             platform,
         );
         ch.receive_mode = config.receive_mode.clone();
+        ch.encrypt_key = config.encrypt_key.clone().filter(|k| !k.is_empty());
         ch.proxy_url = config.proxy_url.clone();
+        ch.rich_text = config.rich_text;
         ch
     }
 
@@ -1090,6 +1149,57 @@ impl LarkChannel {
         Ok((ep.url, ep.client_config.unwrap_or_default()))
     }
 
+    /// Keep a WS long-connection up until `tx` closes. Dropped sessions are
+    /// reconnected here with exponential backoff, so a routine disconnect
+    /// never surfaces as a listener exit; only [`WS_MAX_CONNECT_FAILURES`]
+    /// consecutive attempts that never connect return the error to the
+    /// supervisor.
+    async fn listen_ws_with_reconnect(
+        &self,
+        tx: tokio::sync::mpsc::Sender<ChannelMessage>,
+    ) -> anyhow::Result<()> {
+        let mut backoff = WS_RECONNECT_INITIAL_BACKOFF;
+        let mut connect_failures = 0u32;
+        loop {
+            let result = self.listen_ws(tx.clone()).await;
+            let was_connected = self.ws_connected.swap(false, Ordering::Relaxed);
+            if tx.is_closed() {
+                return result;
+            }
+            zeroclaw_runtime::health::mark_component_error(
+                &self.ws_health_component(),
+                match &result {
+                    Ok(()) => "long connection closed; reconnecting".to_string(),
+                    Err(e) => format!("{e:#}"),
+                },
+            );
+            if was_connected {
+                backoff = WS_RECONNECT_INITIAL_BACKOFF;
+                connect_failures = 0;
+            } else {
+                connect_failures += 1;
+            }
+            if let Err(e) = &result {
+                if connect_failures >= WS_MAX_CONNECT_FAILURES {
+                    return result;
+                }
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "error": format!("{e:#}"),
+                            "attempt": connect_failures,
+                            "retry_in_secs": backoff.as_secs(),
+                        })),
+                    "Lark WS session failed; reconnecting"
+                );
+            }
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(WS_RECONNECT_MAX_BACKOFF);
+        }
+    }
+
     /// WS long-connection event loop.  Returns Ok(()) when the connection closes
     /// (the caller reconnects).
     #[allow(clippy::too_many_lines)]
@@ -1120,6 +1230,8 @@ impl LarkChannel {
         )
         .await?;
         let (mut write, mut read) = ws_stream.split();
+        self.ws_connected.store(true, Ordering::Relaxed);
+        zeroclaw_runtime::health::mark_component_ok(&self.ws_health_component());
         ::zeroclaw_log::record!(
             INFO,
             ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
@@ -1259,10 +1371,20 @@ impl LarkChannel {
 
                     if msg_type != "event" { continue; }
 
-                    let event: LarkEvent = match serde_json::from_slice(&payload) {
+                    let event: LarkEvent = match serde_json::from_slice(&payload)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|raw| self.open_event_payload(raw))
+                        .and_then(|opened| serde_json::from_value(opened).map_err(anyhow::Error::from))
+                    {
                         Ok(e) => e,
                         Err(e) => { ::zeroclaw_log::record!(ERROR, ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail).with_outcome(::zeroclaw_log::EventOutcome::Failure).with_attrs(::serde_json::json!({"error": format!("{}", e)})), "event JSON"); continue; }
                     };
+                    if !event.header.event_id.is_empty()
+                        && !self.remember_ws_id(&format!("event:{}", event.header.event_id)).await
+                    {
+                        ::zeroclaw_log::record!(DEBUG, ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note), &format!("WS: dup event {}", event.header.event_id));
+                        continue;
+                    }
                     match event.header.event_type.as_str() {
                         "im.message.receive_v1" => {}
                         "card.action.trigger" => {
@@ -1301,16 +1423,9 @@ impl LarkChannel {
                     let lark_msg = &recv.message;
 
                     // Dedup
-                    {
-                        let now = Instant::now();
-                        let mut seen = self.ws_seen_ids.write().await;
-                        // GC
-                        seen.retain(|_, t| now.duration_since(*t) < Duration::from_secs(30 * 60));
-                        if seen.contains_key(&lark_msg.message_id) {
-                            ::zeroclaw_log::record!(DEBUG, ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note), &format!("WS: dup {}", lark_msg.message_id));
-                            continue;
-                        }
-                        seen.insert(lark_msg.message_id.clone(), now);
+                    if !self.remember_ws_id(&lark_msg.message_id).await {
+                        ::zeroclaw_log::record!(DEBUG, ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note), &format!("WS: dup {}", lark_msg.message_id));
+                        continue;
                     }
 
                     // Decode content by type (mirrors clawdbot-feishu parsing)
@@ -1462,6 +1577,55 @@ impl LarkChannel {
     }
 
     /// Check if a user open_id is allowed
+    /// Health registry component tracking the WS long connection, separate
+    /// from the supervisor's `channel:` entry so a reconnect loop shows up
+    /// while the listener itself keeps running.
+    fn ws_health_component(&self) -> String {
+        format!("{}_ws:{}", self.channel_name(), self.alias)
+    }
+
+    /// Record a WS id; `false` when it was already seen within
+    /// [`WS_DEDUP_TTL`].
+    async fn remember_ws_id(&self, id: &str) -> bool {
+        let now = Instant::now();
+        let mut seen = self.ws_seen_ids.write().await;
+        seen.retain(|_, t| now.duration_since(*t) < WS_DEDUP_TTL);
+        if seen.contains_key(id) {
+            return false;
+        }
+        seen.insert(id.to_string(), now);
+        true
+    }
+
+    /// Decrypt and verify an inbound event envelope, shared by the WS and
+    /// webhook paths. An `{"encrypt": ...}` body is decrypted with
+    /// `encrypt_key`; a `token` (v2 `header.token` or v1 top-level) that
+    /// differs from the configured `verification_token` is rejected.
+    fn open_event_payload(&self, payload: serde_json::Value) -> anyhow::Result<serde_json::Value> {
+        let payload = match payload.get("encrypt").and_then(|v| v.as_str()) {
+            Some(ciphertext) => {
+                let key = self.encrypt_key.as_deref().ok_or_else(|| {
+                    anyhow::Error::msg(
+                        "received an encrypted event but no encrypt_key is configured",
+                    )
+                })?;
+                serde_json::from_str(&decrypt_lark_event(key, ciphertext)?)?
+            }
+            None => payload,
+        };
+        let token = payload
+            .pointer("/header/token")
+            .or_else(|| payload.get("token"))
+            .and_then(|t| t.as_str());
+        if let Some(token) = token
+            && !self.verification_token.is_empty()
+            && token != self.verification_token
+        {
+            anyhow::bail!("event verification token mismatch");
+        }
+        Ok(payload)
+    }
+
     fn is_user_allowed(&self, open_id: &str) -> bool {
         let peers = (self.peer_resolver)();
         crate::allowlist::is_user_allowed(&peers, open_id, crate::allowlist::Match::Sensitive)
@@ -2587,9 +2751,7 @@ impl Channel for LarkChannel {
         }
 
         if !text_content.is_empty() || markers.is_empty() {
-            let chunks = split_markdown_chunks(&text_content, LARK_CARD_MARKDOWN_MAX_BYTES);
-            for chunk in &chunks {
-                let body = build_interactive_card_body(&message.recipient, chunk);
+            for body in build_reply_bodies(&message.recipient, &text_content, self.rich_text) {
                 self.send_json_with_token_refresh(&url, &mut token, &body, "text send")
                     .await?;
             }
@@ -2606,7 +2768,7 @@ impl Channel for LarkChannel {
     async fn listen(&self, tx: tokio::sync::mpsc::Sender<ChannelMessage>) -> anyhow::Result<()> {
         use zeroclaw_config::schema::LarkReceiveMode;
         match self.receive_mode {
-            LarkReceiveMode::Websocket => self.listen_ws(tx).await,
+            LarkReceiveMode::Websocket => self.listen_ws_with_reconnect(tx).await,
             LarkReceiveMode::Webhook => self.listen_http(tx).await,
         }
     }
@@ -2615,6 +2777,20 @@ impl Channel for LarkChannel {
         self.get_tenant_access_token().await.is_ok()
     }
 
+    fn health_detail(&self) -> Option<String> {
+        use zeroclaw_config::schema::LarkReceiveMode;
+        Some(match self.receive_mode {
+            LarkReceiveMode::Websocket if self.ws_connected.load(Ordering::Relaxed) => {
+                "mode: websocket, connected".to_string()
+            }
+            LarkReceiveMode::Websocket => "mode: websocket, not connected".to_string(),
+            LarkReceiveMode::Webhook => match self.port {
+                Some(port) => format!("mode: webhook, port {port}"),
+                None => "mode: webhook, no port configured".to_string(),
+            },
+        })
+    }
+
     async fn start_typing(&self, _recipient: &str) -> anyhow::Result<()> {
         // No typing-indicator API on the Lark/Feishu Open Platform.
         Ok(())
@@ -3468,7 +3644,6 @@ impl LarkChannel {
 
         #[derive(Clone)]
         struct AppState {
-            verification_token: String,
             channel: Arc<LarkChannel>,
             tx: tokio::sync::mpsc::Sender<ChannelMessage>,
         }
@@ -3480,18 +3655,22 @@ impl LarkChannel {
             use axum::http::StatusCode;
             use axum::response::IntoResponse;
 
-            // URL verification challenge
-            if let Some(challenge) = payload.get("challenge").and_then(|c| c.as_str()) {
-                // Verify token if present
-                let token_ok = payload
-                    .get("token")
-                    .and_then(|t| t.as_str())
-                    .is_none_or(|t| t == state.verification_token);
-
-                if !token_ok {
-                    return (StatusCode::FORBIDDEN, "invalid token").into_response();
+            let payload = match state.channel.open_event_payload(payload) {
+                Ok(payload) => payload,
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                            .with_attrs(::serde_json::json!({"error": e.to_string()})),
+                        "Lark webhook: rejected event"
+                    );
+                    return (StatusCode::FORBIDDEN, "invalid event").into_response();
                 }
+            };
 
+            // URL verification challenge (token already checked above)
+            if let Some(challenge) = payload.get("challenge").and_then(|c| c.as_str()) {
                 let resp = serde_json::json!({ "challenge": challenge });
                 return (StatusCode::OK, Json(resp)).into_response();
             }
@@ -3587,7 +3766,6 @@ impl LarkChannel {
         })?;
 
         let state = AppState {
-            verification_token: self.verification_token.clone(),
             channel: Arc::new(self.clone()),
             tx,
         };
@@ -3615,6 +3793,27 @@ impl LarkChannel {
 // WS helper functions
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Decrypt an `encrypt` event field: base64 of a 16-byte IV followed by
+/// AES-256-CBC/PKCS7 ciphertext, keyed by SHA-256 of the `encrypt_key`.
+fn decrypt_lark_event(encrypt_key: &str, ciphertext_b64: &str) -> anyhow::Result<String> {
+    use sha2::{Digest, Sha256};
+
+    let raw = base64::engine::general_purpose::STANDARD.decode(ciphertext_b64.trim())?;
+    if raw.len() <= 16 || !(raw.len() - 16).is_multiple_of(16) {
+        anyhow::bail!(
+            "encrypted event has an invalid length ({} bytes)",
+            raw.len()
+        );
+    }
+    let key = Sha256::digest(encrypt_key.as_bytes());
+    let (iv, body) = raw.split_at(16);
+    let mut buf = body.to_vec();
+    let plaintext = cbc::Decryptor::<Aes256>::new(key.as_slice().into(), iv.into())
+        .decrypt_padded_mut::<Pkcs7>(&mut buf)
+        .map_err(|_| anyhow::Error::msg("failed to decrypt event; check encrypt_key"))?;
+    Ok(String::from_utf8(plaintext.to_vec())?)
+}
+
 fn inferred_audio_filename(file_key: &str) -> String {
     const SUPPORTED_EXTENSIONS: &[&str] = &[".m4a", ".ogg", ".mp3", ".aac", ".wav"];
     let file_key_lower = file_key.to_lowercase();
@@ -4532,6 +4731,7 @@ mod tests {
             ack_reactions: None,
             stream_mode: StreamMode::default(),
             draft_update_interval_ms: 1000,
+            rich_text: Default::default(),
         };
         let json = serde_json::to_string(&lc).unwrap();
         let parsed: LarkConfig = serde_json::from_str(&json).unwrap();
@@ -4560,6 +4760,7 @@ mod tests {
             ack_reactions: None,
             stream_mode: StreamMode::default(),
             draft_update_interval_ms: 1000,
+            rich_text: Default::default(),
         };
         let toml_str = toml::to_string(&lc).unwrap();
         let parsed: LarkConfig = toml::from_str(&toml_str).unwrap();
@@ -4599,6 +4800,7 @@ mod tests {
             ack_reactions: None,
             stream_mode: StreamMode::default(),
             draft_update_interval_ms: 1000,
+            rich_text: Default::default(),
         };
 
         let ch = LarkChannel::from_config(&cfg, "lark_test_alias", resolver_from(vec!["*".into()]));
@@ -4630,6 +4832,7 @@ mod tests {
             ack_reactions: None,
             stream_mode: StreamMode::default(),
             draft_update_interval_ms: 1000,
+            rich_text: Default::default(),
         };
 
         let ch =
@@ -4661,6 +4864,7 @@ mod tests {
             ack_reactions: None,
             stream_mode: StreamMode::default(),
             draft_update_interval_ms: 1000,
+            rich_text: Default::default(),
         };
 
         let ch = LarkChannel::from_config(&cfg, "lark_test_alias", resolver_from(vec!["*".into()]))
@@ -4981,6 +5185,7 @@ mod tests {
             ack_reactions: None,
             stream_mode: StreamMode::default(),
             draft_update_interval_ms: 1000,
+            rich_text: Default::default(),
         };
         let ch_feishu = LarkChannel::from_config(
             &feishu_cfg,
@@ -5061,6 +5266,37 @@ mod tests {
         assert_eq!(preview, format!("{prefix}...\n[truncated]"));
     }
 
+    #[test]
+    fn markdown_reply_matches_fixture_card() {
+        let reply = include_str!("../tests/fixtures/rich_text/reply.md").trim_end();
+        let expected: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/fixtures/rich_text/lark_card.json"))
+                .unwrap();
+        let bodies: Vec<serde_json::Value> =
+            build_reply_bodies("oc_chat123", reply, RichTextMode::Auto)
+                .into_iter()
+                .map(|mut body| {
+                    let content = body["content"].as_str().unwrap().to_string();
+                    body["content"] = serde_json::from_str(&content).unwrap();
+                    body
+                })
+                .collect();
+        assert_eq!(serde_json::Value::Array(bodies), expected);
+    }
+
+    #[test]
+    fn plain_reply_is_sent_as_text_message() {
+        let bodies = build_reply_bodies("oc_chat123", "On it.", RichTextMode::Auto);
+        assert_eq!(bodies.len(), 1);
+        assert_eq!(bodies[0]["msg_type"], "text");
+        assert_eq!(bodies[0]["content"], r#"{"text":"On it."}"#);
+
+        let forced = build_reply_bodies("oc_chat123", "On it.", RichTextMode::Always);
+        assert_eq!(forced[0]["msg_type"], "interactive");
+        let plain = build_reply_bodies("oc_chat123", "**On it.**", RichTextMode::Never);
+        assert_eq!(plain[0]["msg_type"], "text");
+    }
+
     #[test]
     fn build_interactive_card_body_produces_correct_structure() {
         let body = build_interactive_card_body("oc_chat123", "**Hello** world");
@@ -6679,4 +6915,86 @@ mod tests {
         drop(post_glance_mock);
         drop(delete_glance_mock);
     }
+
+    fn encrypt_event_for_test(encrypt_key: &str, plaintext: &str) -> String {
+        use cbc::cipher::BlockEncryptMut;
+        use sha2::{Digest, Sha256};
+
+        let key = Sha256::digest(encrypt_key.as_bytes());
+        let iv = [7u8; 16];
+        let mut buf = plaintext.as_bytes().to_vec();
+        buf.resize(plaintext.len() + 16, 0);
+        let ciphertext = cbc::Encryptor::<Aes256>::new(key.as_slice().into(), iv.as_slice().into())
+            .encrypt_padded_mut::<Pkcs7>(&mut buf, plaintext.len())
+            .unwrap();
+        let mut raw = iv.to_vec();
+        raw.extend_from_slice(ciphertext);
+        base64::engine::general_purpose::STANDARD.encode(raw)
+    }
+
+    #[test]
+    fn open_event_payload_decrypts_and_verifies_token() {
+        let mut ch = make_channel();
+        ch.encrypt_key = Some("test encrypt key".into());
+        let inner = serde_json::json!({
+            "schema": "2.0",
+            "header": {
+                "event_id": "ev_1",
+                "event_type": "im.message.receive_v1",
+                "token": "test_verification_token"
+            },
+            "event": {}
+        });
+        let sealed = serde_json::json!({
+            "encrypt": encrypt_event_for_test("test encrypt key", &inner.to_string())
+        });
+
+        assert_eq!(ch.open_event_payload(sealed.clone()).unwrap(), inner);
+
+        let forged = serde_json::json!({"header": {"token": "other"}, "event": {}});
+        assert!(ch.open_event_payload(forged).is_err());
+
+        ch.encrypt_key = Some("wrong key".into());
+        assert!(ch.open_event_payload(sealed.clone()).is_err());
+
+        ch.encrypt_key = None;
+        assert!(
+            ch.open_event_payload(sealed).is_err(),
+            "an encrypted event without encrypt_key must be rejected, not parsed"
+        );
+    }
+
+    #[tokio::test]
+    async fn ws_ids_are_deduplicated_across_sessions() {
+        let ch = make_channel();
+        assert!(ch.remember_ws_id("event:ev_1").await);
+        // A reconnect reuses the same handle, so a redelivered event is
+        // still recognised.
+        let reconnected = ch.clone();
+        assert!(!reconnected.remember_ws_id("event:ev_1").await);
+        assert!(reconnected.remember_ws_id("event:ev_2").await);
+    }
+
+    #[test]
+    fn health_detail_reports_mode_and_connection() {
+        use zeroclaw_config::schema::LarkReceiveMode;
+
+        let mut ch = make_channel();
+        assert_eq!(
+            ch.health_detail().as_deref(),
+            Some("mode: websocket, not connected")
+        );
+        ch.ws_connected.store(true, Ordering::Relaxed);
+        assert_eq!(
+            ch.health_detail().as_deref(),
+            Some("mode: websocket, connected")
+        );
+
+        ch.receive_mode = LarkReceiveMode::Webhook;
+        ch.port = Some(9898);
+        assert_eq!(
+            ch.health_detail().as_deref(),
+            Some("mode: webhook, port 9898")
+        );
+    }
 }
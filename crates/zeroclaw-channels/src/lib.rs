@@ -10,12 +10,15 @@
 pub mod allowlist;
 #[cfg(any(feature = "channel-wechat", feature = "whatsapp-web"))]
 pub(crate) mod identity_persist;
+pub mod inbound_media;
 pub mod listing;
 pub mod login_events;
 pub mod login_probe;
 pub mod login_relink;
 pub mod orchestrator;
 pub mod paced_channel;
+#[cfg(any(feature = "channel-dingtalk", feature = "channel-lark"))]
+pub(crate) mod rich_text;
 pub mod util;
 
 // Always-compiled channels and utilities (no feature gate)
@@ -24,6 +27,7 @@ pub mod acp_channel;
 pub mod cli;
 pub mod link_enricher;
 pub mod transcription;
+pub mod translation;
 pub mod tts;
 pub mod voice;
 
@@ -42,6 +46,8 @@ pub mod discord;
 pub mod discord_slash_state;
 #[cfg(feature = "channel-email")]
 pub mod email_channel;
+#[cfg(feature = "channel-email")]
+pub mod email_seen_state;
 #[cfg(feature = "channel-filesystem")]
 pub mod filesystem;
 #[cfg(feature = "channel-git")]
@@ -104,3 +110,5 @@ pub mod whatsapp;
 pub mod whatsapp_storage;
 #[cfg(feature = "whatsapp-web")]
 pub mod whatsapp_web;
+#[cfg(feature = "channel-zulip")]
+pub mod zulip;
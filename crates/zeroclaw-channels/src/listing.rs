@@ -163,6 +163,11 @@ const CHANNEL_COMPILE_SPECS: &[ChannelCompileSpec] = &[
         type_keys: &["mochat"],
         compiled: cfg!(feature = "channel-mochat"),
     },
+    ChannelCompileSpec {
+        schema_name: Some("Zulip"),
+        type_keys: &["zulip"],
+        compiled: cfg!(feature = "channel-zulip"),
+    },
     ChannelCompileSpec {
         schema_name: Some("LINE"),
         type_keys: &["line"],
@@ -29,8 +29,8 @@ use matrix_sdk::{
 };
 
 use zeroclaw_api::channel::{
-    Channel, ChannelApprovalRequest, ChannelApprovalResponse, ChannelMessage, RoomCreationOptions,
-    RoomVisibility, SendMessage,
+    Channel, ChannelApprovalRequest, ChannelApprovalResponse, ChannelMessage, DeliveryReceipt,
+    RoomCreationOptions, RoomVisibility, SendMessage,
 };
 use zeroclaw_config::schema::{MatrixConfig, StreamMode, TranscriptionConfig};
 
@@ -3362,9 +3362,13 @@ impl Channel for MatrixChannel {
     }
 
     async fn send(&self, message: &SendMessage) -> Result<()> {
+        self.send_with_receipt(message).await.map(|_| ())
+    }
+
+    async fn send_with_receipt(&self, message: &SendMessage) -> Result<Option<DeliveryReceipt>> {
         let client = self.ensure_client().await?;
-        let _ = outbound::send(&self.outbox(client), message).await?;
-        Ok(())
+        let event_id = outbound::send(&self.outbox(client), message).await?;
+        Ok(Some(DeliveryReceipt::new(event_id.to_string())))
     }
 
     async fn listen(&self, tx: mpsc::Sender<ChannelMessage>) -> Result<()> {
@@ -3,13 +3,15 @@ use async_trait::async_trait;
 use futures_util::{SinkExt, StreamExt, stream::SplitSink, stream::SplitStream};
 use parking_lot::Mutex;
 use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::OnceCell;
 use tokio::{io::AsyncRead, io::AsyncWrite};
 use tokio_tungstenite::WebSocketStream;
 use tokio_tungstenite::tungstenite::Message as WsMessage;
-use zeroclaw_api::channel::{Channel, ChannelMessage, SendMessage};
+use zeroclaw_api::channel::{Channel, ChannelMessage, DeliveryReceipt, SendMessage};
+use zeroclaw_api::media::MediaAttachment;
 pub(crate) use zeroclaw_config::schema::MattermostListenMode;
 
 const MAX_MATTERMOST_AUDIO_BYTES: u64 = 25 * 1024 * 1024;
@@ -30,6 +32,35 @@ const WS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(30);
 /// the server can miss two pings before we declare it dead.
 const WS_READ_TIMEOUT: Duration = Duration::from_secs(90);
 
+/// Default minimum interval between draft post edits.
+const DRAFT_UPDATE_INTERVAL_MS: u64 = 1000;
+/// Floor for the configured draft edit interval; faster edits mostly earn
+/// 429s from the server's per-user rate limiter.
+const DRAFT_MIN_UPDATE_INTERVAL_MS: u64 = 250;
+/// Wait assumed when a 429 carries no usable `Retry-After`.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+/// Cap on a single `Retry-After` wait.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
+/// Tries for a post, edit or upload that keeps answering 429.
+const RATE_LIMIT_ATTEMPTS: u32 = 3;
+/// Mattermost attaches at most this many files to one post.
+const MAX_FILES_PER_POST: usize = 5;
+/// Largest file uploaded from an `[IMAGE:]`/`[DOCUMENT:]` marker.
+const MAX_MATTERMOST_UPLOAD_BYTES: u64 = 50 * 1024 * 1024;
+/// Marker kinds delivered as uploaded files.
+const OUTBOUND_MARKER_KINDS: &[&str] = &[
+    "IMAGE", "PHOTO", "DOCUMENT", "FILE", "VIDEO", "AUDIO", "VOICE",
+];
+
+/// Edit throttle for one draft post.
+#[derive(Debug, Default, Clone, Copy)]
+struct DraftEditState {
+    last_edit: Option<Instant>,
+    /// Set after a 429; edits are skipped until then. Drafts are cumulative,
+    /// so the next edit after the pause carries everything skipped.
+    paused_until: Option<Instant>,
+}
+
 /// One channel the bot will poll. `is_direct` flags DM (`type=D`) and group DM
 /// (`type=G`) channels so the receive path can bypass `mention_only` for them.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -121,6 +152,14 @@ pub struct MattermostChannel {
     transcription_manager: Option<Arc<super::transcription::TranscriptionManager>>,
     /// How this channel receives inbound messages. Defaults to `Polling`.
     listen_mode: MattermostListenMode,
+    /// Stream replies by creating a post and editing it as text arrives.
+    stream_drafts: bool,
+    /// Minimum interval between edits of the same draft post.
+    draft_update_interval: Duration,
+    /// Edit throttle and 429 backoff per draft post id.
+    draft_edits: Mutex<HashMap<String, DraftEditState>>,
+    /// Resolves relative `[IMAGE:]`/`[DOCUMENT:]` paths (and `/workspace/`).
+    workspace_dir: Option<PathBuf>,
 }
 
 impl MattermostChannel {
@@ -156,6 +195,10 @@ impl MattermostChannel {
             transcription: None,
             transcription_manager: None,
             listen_mode: MattermostListenMode::default(),
+            stream_drafts: false,
+            draft_update_interval: Duration::from_millis(DRAFT_UPDATE_INTERVAL_MS),
+            draft_edits: Mutex::new(HashMap::new()),
+            workspace_dir: None,
         }
     }
 
@@ -383,6 +426,23 @@ impl MattermostChannel {
         self
     }
 
+    /// Enable progressive draft streaming (create a post, then edit it).
+    /// Intervals below `DRAFT_MIN_UPDATE_INTERVAL_MS` are raised to it.
+    pub fn with_streaming(mut self, enabled: bool, interval_ms: u64) -> Self {
+        self.stream_drafts = enabled;
+        if interval_ms > 0 {
+            self.draft_update_interval =
+                Duration::from_millis(interval_ms.max(DRAFT_MIN_UPDATE_INTERVAL_MS));
+        }
+        self
+    }
+
+    /// Directory that relative attachment marker paths resolve against.
+    pub fn with_workspace_dir(mut self, dir: PathBuf) -> Self {
+        self.workspace_dir = Some(dir);
+        self
+    }
+
     fn http_client(&self) -> reqwest::Client {
         zeroclaw_config::schema::build_channel_proxy_client_with_timeouts(
             "channel.mattermost",
@@ -714,47 +774,140 @@ impl Channel for MattermostChannel {
     }
 
     async fn send(&self, message: &SendMessage) -> Result<()> {
-        // Mattermost supports threading via 'root_id'.
-        // We pack 'channel_id:root_id' into recipient if it's a thread.
-        let (channel_id, root_id) = if let Some((c, r)) = message.recipient.split_once(':') {
-            (c, Some(r))
-        } else {
-            (message.recipient.as_str(), None)
-        };
+        self.send_with_receipt(message).await.map(|_| ())
+    }
 
-        let mut body_map = serde_json::json!({
-            "channel_id": channel_id,
-            "message": message.content
-        });
+    async fn send_with_receipt(&self, message: &SendMessage) -> Result<Option<DeliveryReceipt>> {
+        let (channel_id, root_id) = split_recipient(&message.recipient);
+        let (text, file_ids) = self
+            .prepare_outbound(channel_id, &message.content, &message.attachments)
+            .await;
+        let post_id = self
+            .create_post(channel_id, root_id, &text, &file_ids)
+            .await?;
+        Ok(Some(DeliveryReceipt::new(post_id)))
+    }
 
-        if let Some(root) = root_id {
-            body_map.as_object_mut().unwrap().insert(
-                "root_id".to_string(),
-                serde_json::Value::String(root.to_string()),
-            );
+    fn supports_draft_updates(&self) -> bool {
+        self.stream_drafts
+    }
+
+    async fn send_draft(&self, message: &SendMessage) -> Result<Option<String>> {
+        if !self.stream_drafts {
+            return Ok(None);
+        }
+        let (channel_id, root_id) = split_recipient(&message.recipient);
+        let post_id = self
+            .create_post(channel_id, root_id, &message.content, &[])
+            .await?;
+        self.draft_edits
+            .lock()
+            .insert(post_id.clone(), DraftEditState::default());
+        Ok(Some(post_id))
+    }
+
+    async fn update_draft(&self, _recipient: &str, message_id: &str, text: &str) -> Result<()> {
+        let now = Instant::now();
+        {
+            let mut edits = self.draft_edits.lock();
+            let state = edits.entry(message_id.to_string()).or_default();
+            if state.paused_until.is_some_and(|until| now < until)
+                || state
+                    .last_edit
+                    .is_some_and(|last| now.duration_since(last) < self.draft_update_interval)
+            {
+                return Ok(());
+            }
+            // Claimed before the request so a slow edit isn't overlapped.
+            state.last_edit = Some(now);
+            state.paused_until = None;
         }
 
+        // Markers become uploads on finalize; keep them out of the preview.
+        let (visible, _) =
+            crate::util::parse_attachment_markers_of_kinds(text, OUTBOUND_MARKER_KINDS);
         let token = self.token().await?;
         let resp = self
             .http_client()
-            .post(format!("{}/api/v4/posts", self.base_url))
+            .put(format!("{}/api/v4/posts/{message_id}/patch", self.base_url))
             .bearer_auth(token)
-            .json(&body_map)
+            .json(&serde_json::json!({ "message": visible }))
             .send()
             .await?;
-
         let status = resp.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let wait = retry_after(resp.headers());
+            if let Some(state) = self.draft_edits.lock().get_mut(message_id) {
+                state.paused_until = Some(Instant::now() + wait);
+            }
+            ::zeroclaw_log::record!(
+                DEBUG,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Defer)
+                    .with_attrs(::serde_json::json!({
+                        "post_id": message_id,
+                        "backoff_ms": u64::try_from(wait.as_millis()).unwrap_or(u64::MAX),
+                    })),
+                "draft edit rate limited; backing off"
+            );
+            return Ok(());
+        }
         if !status.is_success() {
-            let body = resp
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("<failed to read response: {e}>"));
-            bail!("post failed ({status}): {body}");
+            let body = resp.text().await.unwrap_or_default();
+            bail!("draft edit failed ({status}): {body}");
+        }
+        Ok(())
+    }
+
+    async fn finalize_draft(
+        &self,
+        recipient: &str,
+        message_id: &str,
+        text: &str,
+        _suppress_voice: bool,
+    ) -> Result<()> {
+        let paused_until = self
+            .draft_edits
+            .lock()
+            .remove(message_id)
+            .and_then(|state| state.paused_until);
+        if let Some(until) = paused_until {
+            tokio::time::sleep_until(until.into()).await;
+        }
+
+        let (channel_id, root_id) = split_recipient(recipient);
+        let (text, file_ids) = self.prepare_outbound(channel_id, text, &[]).await;
+        if file_ids.is_empty() {
+            let result = self.patch_post(message_id, &text).await;
+            if result.is_err() {
+                // The caller falls back to a fresh post; don't leave the draft behind.
+                let _ = self.delete_post(message_id).await;
+            }
+            return result;
         }
 
+        // Files can only be attached when a post is created, so the final
+        // reply replaces the draft in the same thread.
+        self.create_post(channel_id, root_id, &text, &file_ids)
+            .await?;
+        if let Err(e) = self.delete_post(message_id).await {
+            ::zeroclaw_log::record!(
+                DEBUG,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Delete)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(
+                        ::serde_json::json!({"post_id": message_id, "error": format!("{e:#}")})
+                    ),
+                "failed to remove draft after posting reply with files"
+            );
+        }
         Ok(())
     }
 
+    async fn cancel_draft(&self, _recipient: &str, message_id: &str) -> Result<()> {
+        self.draft_edits.lock().remove(message_id);
+        self.delete_post(message_id).await
+    }
+
     async fn listen(&self, tx: tokio::sync::mpsc::Sender<ChannelMessage>) -> Result<()> {
         match self.listen_mode {
             MattermostListenMode::Polling => self.listen_polling(tx).await,
@@ -835,6 +988,278 @@ impl Channel for MattermostChannel {
     }
 }
 
+/// Split a reply target into `(channel_id, root_id)`. Thread replies are
+/// addressed as `channel_id:root_id`.
+fn split_recipient(recipient: &str) -> (&str, Option<&str>) {
+    match recipient.split_once(':') {
+        Some((channel, root)) if !root.is_empty() => (channel, Some(root)),
+        Some((channel, _)) => (channel, None),
+        None => (recipient, None),
+    }
+}
+
+/// How long to wait after a 429: `Retry-After` (seconds), else Mattermost's
+/// `X-Ratelimit-Reset`, else one second, capped at `MAX_RETRY_AFTER`.
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Duration {
+    ["retry-after", "x-ratelimit-reset"]
+        .iter()
+        .find_map(|name| {
+            headers
+                .get(*name)?
+                .to_str()
+                .ok()?
+                .trim()
+                .parse::<u64>()
+                .ok()
+        })
+        .map_or(DEFAULT_RETRY_AFTER, Duration::from_secs)
+        .min(MAX_RETRY_AFTER)
+}
+
+impl MattermostChannel {
+    /// Send a request built by `build`, waiting out 429 responses per
+    /// `Retry-After` for up to `RATE_LIMIT_ATTEMPTS` tries.
+    async fn send_rate_limited(
+        &self,
+        what: &str,
+        build: impl Fn(&reqwest::Client, &str) -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response> {
+        let token = self.token().await?;
+        let client = self.http_client();
+        let mut attempt = 1;
+        loop {
+            let resp = build(&client, token).send().await?;
+            let status = resp.status();
+            if status == reqwest::StatusCode::TOO_MANY_REQUESTS && attempt < RATE_LIMIT_ATTEMPTS {
+                let wait = retry_after(resp.headers());
+                ::zeroclaw_log::record!(
+                    DEBUG,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Retry)
+                        .with_attrs(::serde_json::json!({
+                            "request": what,
+                            "attempt": attempt,
+                            "backoff_ms": u64::try_from(wait.as_millis()).unwrap_or(u64::MAX),
+                        })),
+                    "mattermost request rate limited; retrying"
+                );
+                tokio::time::sleep(wait).await;
+                attempt += 1;
+                continue;
+            }
+            if !status.is_success() {
+                let body = resp
+                    .text()
+                    .await
+                    .unwrap_or_else(|e| format!("<failed to read response: {e}>"));
+                bail!("{what} failed ({status}): {body}");
+            }
+            return Ok(resp);
+        }
+    }
+
+    /// Create a post and return its id.
+    async fn create_post(
+        &self,
+        channel_id: &str,
+        root_id: Option<&str>,
+        message: &str,
+        file_ids: &[String],
+    ) -> Result<String> {
+        let mut body = serde_json::json!({
+            "channel_id": channel_id,
+            "message": message,
+        });
+        if let Some(root) = root_id {
+            body["root_id"] = serde_json::json!(root);
+        }
+        if !file_ids.is_empty() {
+            body["file_ids"] = serde_json::json!(file_ids);
+        }
+        let url = format!("{}/api/v4/posts", self.base_url);
+        let resp = self
+            .send_rate_limited("post", |client, token| {
+                client.post(&url).bearer_auth(token).json(&body)
+            })
+            .await?;
+        let post: serde_json::Value = resp.json().await.unwrap_or_default();
+        Ok(post
+            .get("id")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string())
+    }
+
+    async fn patch_post(&self, post_id: &str, message: &str) -> Result<()> {
+        let url = format!("{}/api/v4/posts/{post_id}/patch", self.base_url);
+        let body = serde_json::json!({ "message": message });
+        self.send_rate_limited("post edit", |client, token| {
+            client.put(&url).bearer_auth(token).json(&body)
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_post(&self, post_id: &str) -> Result<()> {
+        let url = format!("{}/api/v4/posts/{post_id}", self.base_url);
+        self.send_rate_limited("post delete", |client, token| {
+            client.delete(&url).bearer_auth(token)
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Upload one file to `channel_id` and return its file id.
+    async fn upload_file(
+        &self,
+        channel_id: &str,
+        file_name: &str,
+        data: Vec<u8>,
+    ) -> Result<String> {
+        let url = format!("{}/api/v4/files", self.base_url);
+        let resp = self
+            .send_rate_limited("file upload", |client, token| {
+                let part =
+                    reqwest::multipart::Part::bytes(data.clone()).file_name(file_name.to_string());
+                let form = reqwest::multipart::Form::new()
+                    .text("channel_id", channel_id.to_string())
+                    .part("files", part);
+                client.post(&url).bearer_auth(token).multipart(form)
+            })
+            .await?;
+        let body: serde_json::Value = resp.json().await?;
+        body.pointer("/file_infos/0/id")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .context("file upload response has no file id")
+    }
+
+    fn resolve_attachment_path(&self, target: &str) -> PathBuf {
+        match (&self.workspace_dir, target.strip_prefix("/workspace/")) {
+            (Some(ws), Some(rel)) => ws.join(rel),
+            (Some(ws), None) if Path::new(target).is_relative() => ws.join(target),
+            _ => PathBuf::from(target),
+        }
+    }
+
+    /// Load a marker target (local path or http(s) URL) as `(file_name, bytes)`.
+    async fn load_marker_file(&self, target: &str) -> Result<(String, Vec<u8>)> {
+        if target.starts_with("http://") || target.starts_with("https://") {
+            let resp = self
+                .http_client()
+                .get(target)
+                .send()
+                .await?
+                .error_for_status()?;
+            if resp
+                .content_length()
+                .is_some_and(|len| len > MAX_MATTERMOST_UPLOAD_BYTES)
+            {
+                bail!("file exceeds the {MAX_MATTERMOST_UPLOAD_BYTES} byte upload limit");
+            }
+            let file_name = resp
+                .url()
+                .path_segments()
+                .and_then(|mut segments| segments.next_back())
+                .filter(|name| !name.is_empty())
+                .unwrap_or("attachment")
+                .to_string();
+            let data = resp.bytes().await?;
+            if data.len() as u64 > MAX_MATTERMOST_UPLOAD_BYTES {
+                bail!("file exceeds the {MAX_MATTERMOST_UPLOAD_BYTES} byte upload limit");
+            }
+            return Ok((file_name, data.to_vec()));
+        }
+
+        let path = self.resolve_attachment_path(target);
+        let metadata = tokio::fs::metadata(&path)
+            .await
+            .with_context(|| format!("attachment not found: {}", path.display()))?;
+        if !metadata.is_file() {
+            bail!("attachment is not a regular file");
+        }
+        if metadata.len() > MAX_MATTERMOST_UPLOAD_BYTES {
+            bail!("file exceeds the {MAX_MATTERMOST_UPLOAD_BYTES} byte upload limit");
+        }
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("attachment")
+            .to_string();
+        Ok((file_name, tokio::fs::read(&path).await?))
+    }
+
+    /// Split `[IMAGE:]`/`[DOCUMENT:]` markers out of `content`, upload them
+    /// and any in-memory `attachments` to `channel_id`, and return the text
+    /// with the file ids to attach. Files that fail to upload, or exceed the
+    /// per-post limit, are logged and noted in the text instead.
+    async fn prepare_outbound(
+        &self,
+        channel_id: &str,
+        content: &str,
+        attachments: &[MediaAttachment],
+    ) -> (String, Vec<String>) {
+        let (mut text, markers) =
+            crate::util::parse_attachment_markers_of_kinds(content, OUTBOUND_MARKER_KINDS);
+        if markers.is_empty() && attachments.is_empty() {
+            return (text, Vec::new());
+        }
+
+        let mut file_ids = Vec::new();
+        let mut notes = Vec::new();
+        let in_memory = attachments
+            .iter()
+            .filter(|att| !att.data.is_empty())
+            .map(|att| (att.file_name.clone(), Some(att.data.clone())));
+        let from_markers = markers.into_iter().map(|(_kind, target)| (target, None));
+        for (target, data) in in_memory.chain(from_markers) {
+            let name = Path::new(&target)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or(&target)
+                .to_string();
+            if file_ids.len() == MAX_FILES_PER_POST {
+                notes.push(format!("[attachment omitted: {name}]"));
+                continue;
+            }
+            let loaded = match data {
+                Some(data) => Ok((name.clone(), data)),
+                None => self.load_marker_file(&target).await,
+            };
+            let uploaded = match loaded {
+                Ok((file_name, data)) => self.upload_file(channel_id, &file_name, data).await,
+                Err(e) => Err(e),
+            };
+            match uploaded {
+                Ok(file_id) => file_ids.push(file_id),
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                            .with_attrs(::serde_json::json!({
+                                "target": target,
+                                "error": format!("{e:#}"),
+                            })),
+                        "Dropping Mattermost attachment"
+                    );
+                    if target.starts_with("http://") || target.starts_with("https://") {
+                        notes.push(target);
+                    } else {
+                        notes.push(format!("[attachment omitted: {name}]"));
+                    }
+                }
+            }
+        }
+        if !notes.is_empty() {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+            }
+            text.push_str(&notes.join("\n"));
+        }
+        (text, file_ids)
+    }
+}
+
 impl MattermostChannel {
     async fn listen_polling(&self, tx: tokio::sync::mpsc::Sender<ChannelMessage>) -> Result<()> {
         // Resolve auth up front so misconfiguration fails fast at listen-time.
@@ -2991,6 +3416,205 @@ mod tests {
         }
     }
 
+    mod draft_http_tests {
+        use super::*;
+        use wiremock::matchers::{body_partial_json, body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        fn channel(server: &MockServer) -> MattermostChannel {
+            MattermostChannel::new(
+                server.uri(),
+                Some("token".into()),
+                None,
+                None,
+                Vec::new(),
+                "mattermost_draft_alias",
+                Arc::new(|| vec!["*".into()]),
+                true,
+                false,
+            )
+        }
+
+        async fn mount_draft_post(server: &MockServer) {
+            Mock::given(method("POST"))
+                .and(path("/api/v4/posts"))
+                .respond_with(ResponseTemplate::new(201).set_body_json(json!({"id": "draft1"})))
+                .mount(server)
+                .await;
+        }
+
+        #[tokio::test]
+        async fn draft_edits_are_throttled_and_finalize_edits_in_place() {
+            let server = MockServer::start().await;
+            mount_draft_post(&server).await;
+            Mock::given(method("PUT"))
+                .and(path("/api/v4/posts/draft1/patch"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "draft1"})))
+                .expect(2)
+                .mount(&server)
+                .await;
+
+            let ch = channel(&server).with_streaming(true, 60_000);
+            assert!(ch.supports_draft_updates());
+            let draft_id = ch
+                .send_draft(&SendMessage::new("...", "chan1:root1"))
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(draft_id, "draft1");
+
+            for text in ["a", "ab", "abc"] {
+                ch.update_draft("chan1:root1", &draft_id, text)
+                    .await
+                    .unwrap();
+            }
+            ch.finalize_draft("chan1:root1", &draft_id, "final answer", false)
+                .await
+                .unwrap();
+
+            let requests = server.received_requests().await.unwrap();
+            let draft: serde_json::Value = requests[0].body_json().unwrap();
+            assert_eq!(draft["root_id"], "root1");
+            let last: serde_json::Value = requests.last().unwrap().body_json().unwrap();
+            assert_eq!(last["message"], "final answer");
+        }
+
+        #[tokio::test]
+        async fn rate_limited_draft_edit_backs_off_instead_of_dropping() {
+            let server = MockServer::start().await;
+            mount_draft_post(&server).await;
+            Mock::given(method("PUT"))
+                .and(path("/api/v4/posts/draft1/patch"))
+                .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+                .up_to_n_times(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("PUT"))
+                .and(path("/api/v4/posts/draft1/patch"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "draft1"})))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let ch = channel(&server).with_streaming(true, 250);
+            let draft_id = ch
+                .send_draft(&SendMessage::new("...", "chan1"))
+                .await
+                .unwrap()
+                .unwrap();
+
+            // The 429 is absorbed, and edits inside the Retry-After window are skipped.
+            ch.update_draft("chan1", &draft_id, "partial")
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            ch.update_draft("chan1", &draft_id, "partial more")
+                .await
+                .unwrap();
+
+            // Finalize waits out the window and still lands the full text.
+            ch.finalize_draft("chan1", &draft_id, "complete", false)
+                .await
+                .unwrap();
+            let requests = server.received_requests().await.unwrap();
+            let last: serde_json::Value = requests.last().unwrap().body_json().unwrap();
+            assert_eq!(last["message"], "complete");
+        }
+
+        #[tokio::test]
+        async fn send_uploads_marker_files_and_attaches_them_in_thread() {
+            let server = MockServer::start().await;
+            let workspace = tempfile::tempdir().unwrap();
+            std::fs::write(workspace.path().join("report.txt"), b"quarterly").unwrap();
+
+            Mock::given(method("POST"))
+                .and(path("/api/v4/files"))
+                .and(body_string_contains("report.txt"))
+                .and(body_string_contains("chan1"))
+                .respond_with(
+                    ResponseTemplate::new(201)
+                        .set_body_json(json!({"file_infos": [{"id": "file1"}]})),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("POST"))
+                .and(path("/api/v4/posts"))
+                .and(body_partial_json(json!({
+                    "channel_id": "chan1",
+                    "root_id": "root1",
+                    "message": "Here it is",
+                    "file_ids": ["file1"],
+                })))
+                .respond_with(ResponseTemplate::new(201).set_body_json(json!({"id": "post1"})))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let ch = channel(&server).with_workspace_dir(workspace.path().to_path_buf());
+            let receipt = ch
+                .send_with_receipt(&SendMessage::new(
+                    "Here it is [DOCUMENT:report.txt]",
+                    "chan1:root1",
+                ))
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(receipt.message_id, "post1");
+        }
+
+        #[tokio::test]
+        async fn finalize_with_files_replaces_draft_in_thread() {
+            let server = MockServer::start().await;
+            let workspace = tempfile::tempdir().unwrap();
+            std::fs::write(workspace.path().join("chart.png"), b"png").unwrap();
+
+            Mock::given(method("POST"))
+                .and(path("/api/v4/files"))
+                .respond_with(
+                    ResponseTemplate::new(201)
+                        .set_body_json(json!({"file_infos": [{"id": "file1"}]})),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("POST"))
+                .and(path("/api/v4/posts"))
+                .and(body_partial_json(json!({
+                    "root_id": "root1",
+                    "file_ids": ["file1"],
+                })))
+                .respond_with(ResponseTemplate::new(201).set_body_json(json!({"id": "final1"})))
+                .expect(1)
+                .mount(&server)
+                .await;
+            mount_draft_post(&server).await;
+            Mock::given(method("DELETE"))
+                .and(path("/api/v4/posts/draft1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({"status": "OK"})))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let ch = channel(&server)
+                .with_streaming(true, 1000)
+                .with_workspace_dir(workspace.path().to_path_buf());
+            let draft_id = ch
+                .send_draft(&SendMessage::new("...", "chan1:root1"))
+                .await
+                .unwrap()
+                .unwrap();
+            ch.finalize_draft(
+                "chan1:root1",
+                &draft_id,
+                "Chart: [IMAGE:/workspace/chart.png]",
+                false,
+            )
+            .await
+            .unwrap();
+        }
+    }
+
     #[test]
     fn test_ws_url_conversion() {
         let ch = MattermostChannel::new(
@@ -2120,6 +2120,7 @@ fn map_tool_kind(name: &str) -> &'static str {
         | "read_skill"
         | "sessions_history"
         | "sessions_list"
+        | "skill_invoke"
         | "sop_list"
         | "sop_status"
         | "text_browser"
@@ -0,0 +1,174 @@
+//! Reply feedback from user reactions (`[channels.feedback]`).
+//!
+//! A 👍 or 👎 on a reply the bot delivered is matched to its stored turn
+//! through the delivery id recorded with the reply, reported to the observer
+//! (which feeds the daily digest and Prometheus), and optionally kept in
+//! memory together with the question and answer it rates.
+
+use std::sync::Arc;
+
+use zeroclaw_api::channel::{ChannelMessage, InboundReaction, SendMessage};
+use zeroclaw_infra::session_backend::SessionBackend;
+use zeroclaw_memory::MemoryCategory;
+use zeroclaw_runtime::observability::traits::ObserverEvent;
+
+use super::{
+    ChannelRuntimeContext, channel_scope, conversation_history_key, find_channel_for_message,
+};
+
+/// Memory category of stored feedback entries.
+const FEEDBACK_CATEGORY: &str = "feedback";
+
+/// `Some(true)` for 👍, `Some(false)` for 👎, `None` when the reaction
+/// carries neither.
+fn sentiment(emoji: &[String]) -> Option<bool> {
+    emoji.iter().find_map(|emoji| match emoji.as_str() {
+        "👍" => Some(true),
+        "👎" => Some(false),
+        _ => None,
+    })
+}
+
+/// The user message and the reply delivered as `delivery_id` in the
+/// session `history_key`.
+fn rated_turn(
+    store: &dyn SessionBackend,
+    history_key: &str,
+    delivery_id: &str,
+) -> Option<(String, String)> {
+    let rows = store.load_with_timestamps(history_key);
+    let reply = rows.iter().rposition(|row| {
+        row.message.role == "assistant" && row.delivery_id.as_deref() == Some(delivery_id)
+    })?;
+    let question = rows[..reply]
+        .iter()
+        .rev()
+        .find(|row| row.message.role == "user")
+        .map(|row| row.message.content.clone())
+        .unwrap_or_default();
+    Some((question, rows[reply].message.content.clone()))
+}
+
+/// Record a reaction on one of the bot's replies. Reactions to other
+/// messages, and emoji other than 👍 and 👎, are ignored.
+pub(super) async fn record_reaction_feedback(
+    ctx: Arc<ChannelRuntimeContext>,
+    msg: ChannelMessage,
+    reaction: InboundReaction,
+) {
+    let Some(positive) = sentiment(&reaction.emoji) else {
+        return;
+    };
+    let history_key = conversation_history_key(&msg);
+    let Some((question, answer)) = ctx
+        .session_store
+        .as_deref()
+        .and_then(|store| rated_turn(store, &history_key, &reaction.message_id))
+    else {
+        ::zeroclaw_log::record!(
+            DEBUG,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Skip).with_attrs(
+                ::serde_json::json!({
+                    "history_key": history_key,
+                    "delivery_id": reaction.message_id,
+                })
+            ),
+            "reaction is not on a recorded reply; ignoring"
+        );
+        return;
+    };
+
+    let channel = channel_scope(&msg);
+    ctx.observer.record_event(&ObserverEvent::ReplyFeedback {
+        channel: channel.clone(),
+        positive,
+    });
+
+    let feedback = &ctx.prompt_config.channels.feedback;
+    if feedback.store_in_memory {
+        let verdict = if positive { "helpful" } else { "not helpful" };
+        let content = format!(
+            "User rated a reply on {channel} as {verdict} ({}).\nQuestion: {question}\nAnswer: {answer}",
+            reaction.emoji.join(" ")
+        );
+        let key = format!("feedback_{channel}_{}", reaction.message_id);
+        if let Err(e) = ctx
+            .memory
+            .store(
+                &key,
+                &content,
+                MemoryCategory::Custom(FEEDBACK_CATEGORY.into()),
+                Some(&history_key),
+            )
+            .await
+        {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Write)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                "failed to store reply feedback in memory"
+            );
+        }
+    }
+
+    if !positive
+        && feedback.follow_up_on_negative
+        && let Some(target) = find_channel_for_message(&ctx.channels_by_name, &msg)
+    {
+        let text =
+            zeroclaw_runtime::i18n::get_required_cli_string("channel-runtime-feedback-follow-up");
+        let follow_up = SendMessage::new(text, &msg.reply_target).in_thread(msg.thread_ts.clone());
+        if let Err(e) = target.send(&follow_up).await {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Outbound)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(
+                        ::serde_json::json!({"channel": channel, "error": format!("{e:#}")})
+                    ),
+                "failed to send negative-feedback follow-up"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use zeroclaw_infra::session_sqlite::SqliteSessionBackend;
+    use zeroclaw_providers::ChatMessage;
+
+    #[test]
+    fn only_thumbs_carry_a_sentiment() {
+        assert_eq!(sentiment(&["❤".into(), "👍".into()]), Some(true));
+        assert_eq!(sentiment(&["👎".into()]), Some(false));
+        assert_eq!(sentiment(&["🔥".into()]), None);
+    }
+
+    #[test]
+    fn reaction_resolves_to_the_turn_that_was_delivered() {
+        let tmp = TempDir::new().unwrap();
+        let store = SqliteSessionBackend::new(tmp.path()).unwrap();
+        store
+            .append("s", &ChatMessage::user("what is 2+2?"))
+            .unwrap();
+        store.append("s", &ChatMessage::assistant("4")).unwrap();
+        store.set_last_delivery_id("s", "10").unwrap();
+        store.append("s", &ChatMessage::user("and 3+3?")).unwrap();
+        store.append("s", &ChatMessage::assistant("6")).unwrap();
+        store.set_last_delivery_id("s", "12").unwrap();
+
+        assert_eq!(
+            rated_turn(&store, "s", "10"),
+            Some(("what is 2+2?".into(), "4".into()))
+        );
+        assert_eq!(
+            rated_turn(&store, "s", "12"),
+            Some(("and 3+3?".into(), "6".into()))
+        );
+        // A message the bot never delivered (e.g. the user's own) is not rated.
+        assert_eq!(rated_turn(&store, "s", "11"), None);
+    }
+}
@@ -2,9 +2,11 @@
 
 #[cfg(feature = "channel-acp-server")]
 pub mod acp_server;
+mod feedback;
 pub mod media_pipeline;
 #[cfg(feature = "channel-mqtt")]
 pub mod mqtt;
+mod outbox;
 
 // Channel types imported directly from source crates (no shim files)
 #[cfg(feature = "channel-amqp")]
@@ -77,7 +79,9 @@ pub use crate::wecom_ws::WeComWsChannel;
 use crate::wecom_ws::WeComWsRuntimePolicy;
 #[cfg(feature = "channel-whatsapp-cloud")]
 pub use crate::whatsapp::WhatsAppChannel;
-pub use zeroclaw_api::channel::{Channel, ChannelMessage, SendMessage};
+#[cfg(feature = "channel-zulip")]
+pub use crate::zulip::ZulipChannel;
+pub use zeroclaw_api::channel::{Channel, ChannelMessage, DeliveryReceipt, SendMessage};
 // Local channel types (in misc, not zeroclaw-channels)
 pub use crate::cli::CliChannel;
 pub use crate::link_enricher;
@@ -96,7 +100,7 @@ use anyhow::{Context, Result};
 use parking_lot::RwLock;
 use portable_atomic::{AtomicU64, Ordering};
 use pulldown_cmark::{Event, Options as MarkdownOptions, Parser as MarkdownParser, Tag};
-use serde::Deserialize;
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 use std::ops::Range;
@@ -108,6 +112,7 @@ use std::time::{Duration, Instant, SystemTime};
 use tokio_util::sync::CancellationToken;
 use url::Url;
 
+use crate::translation::{ChannelTranslation, Translator};
 use zeroclaw_api::memory_traits::MemoryStrategy;
 use zeroclaw_api::session_keys::sanitize_session_key;
 use zeroclaw_config::scattered_types::{ThinkingConfig, ThinkingLevel};
@@ -124,6 +129,7 @@ use zeroclaw_runtime::agent::loop_::{
     is_model_switch_requested, run_tool_call_loop, scope_session_key, scope_thread_id,
     scrub_credentials,
 };
+use zeroclaw_runtime::agent::prompt_cache::PromptSectionCache;
 use zeroclaw_runtime::approval::ApprovalManager;
 use zeroclaw_runtime::observability::traits::{ObserverEvent, ObserverMetric};
 use zeroclaw_runtime::observability::{self, Observer};
@@ -218,8 +224,8 @@ const WHATSAPP_CURRENT_GROUP_MESSAGE_LABEL: &str = "Current WhatsApp group messa
 // System prompt functions live in `zeroclaw_runtime::agent::system_prompt`.
 #[allow(unused_imports)]
 pub use zeroclaw_runtime::agent::system_prompt::{
-    BOOTSTRAP_MAX_CHARS, build_system_prompt, build_system_prompt_with_mode,
-    build_system_prompt_with_mode_and_autonomy,
+    BOOTSTRAP_MAX_CHARS, build_system_prompt, build_system_prompt_cached,
+    build_system_prompt_with_mode, build_system_prompt_with_mode_and_autonomy,
 };
 
 const DEFAULT_CHANNEL_INITIAL_BACKOFF_SECS: u64 = 2;
@@ -233,10 +239,9 @@ const CHANNEL_MIN_IN_FLIGHT_MESSAGES: usize = 8;
 const CHANNEL_MAX_IN_FLIGHT_MESSAGES: usize = 64;
 const CHANNEL_TYPING_REFRESH_INTERVAL_SECS: u64 = 4;
 const CHANNEL_HEALTH_HEARTBEAT_SECS: u64 = 30;
-const MODEL_CACHE_FILE: &str = "models_cache.json";
 const MODEL_CACHE_PREVIEW_LIMIT: usize = 10;
 const CHANNEL_HISTORY_COMPACT_KEEP_MESSAGES: usize = 12;
-const CHANNEL_HISTORY_COMPACT_CONTENT_CHARS: usize = 600;
+const CHANNEL_HISTORY_COMPACT_CONTENT_TOKENS: usize = 150;
 /// Proactive context-window budget in estimated characters (~4 chars/token).
 /// Guardrail for hook-modified outbound channel content.
 const CHANNEL_HOOK_MAX_OUTBOUND_CHARS: usize = 20_000;
@@ -282,6 +287,12 @@ struct ChannelRouteSelection {
     /// Route-specific API key override. When set, this credential is passed
     /// directly to the requested provider instead of the alias entry's key.
     api_key: Option<String>,
+    /// Persona picked with `/persona`, naming a `personas/<name>/`
+    /// directory in the workspace. Only the per-sender entry carries one.
+    persona: Option<String>,
+    /// `/voice on`: deliver this sender's replies as voice notes. Like
+    /// `persona`, only the per-sender entry carries it.
+    voice_replies: bool,
 }
 
 /// Selectable scope for a session-only `/model` override. The absence of any
@@ -323,17 +334,11 @@ enum ChannelRuntimeCommand {
     NewSession,
     SetThinking(Option<ThinkingLevel>),
     InvalidThinking(String),
-}
-
-#[derive(Debug, Clone, Default, Deserialize)]
-struct ModelCacheState {
-    entries: Vec<ModelCacheEntry>,
-}
-
-#[derive(Debug, Clone, Default, Deserialize)]
-struct ModelCacheEntry {
-    model_provider: String,
-    models: Vec<String>,
+    ShowPersonas,
+    /// `/persona <name>`; `None` is `/persona default`.
+    SetPersona(Option<String>),
+    ShowVoiceReplies,
+    SetVoiceReplies(bool),
 }
 
 #[derive(Debug, Clone)]
@@ -518,6 +523,12 @@ struct ChannelRuntimeContext {
     persist_locks: Arc<std::sync::Mutex<HashMap<String, Arc<std::sync::Mutex<()>>>>>,
     sop_engine: Option<Arc<std::sync::Mutex<zeroclaw_runtime::sop::SopEngine>>>,
     sop_audit: Option<Arc<zeroclaw_runtime::sop::SopAuditLogger>>,
+    /// Rendered workspace-file and skills prompt sections, rebuilt only when
+    /// a backing file changes. Shared by every message on this context.
+    prompt_cache: Arc<PromptSectionCache>,
+    /// Translation backend override and per-sender language cache for
+    /// channels with a `translate` setting.
+    translation: Arc<ChannelTranslation>,
 }
 
 /// Acquire the per-conversation-history-key persistence lock so that
@@ -533,6 +544,9 @@ fn acquire_persist_lock(ctx: &ChannelRuntimeContext, key: &str) -> Arc<std::sync
 #[derive(Clone)]
 struct InFlightSenderTaskState {
     task_id: u64,
+    /// `ChannelMessage::id` being answered, so an edit of that message can
+    /// interrupt it even when `interrupt_on_new_message` is off.
+    message_id: String,
     cancellation: CancellationToken,
     completion: Arc<InFlightTaskCompletion>,
 }
@@ -1304,6 +1318,11 @@ fn supports_runtime_model_switch(channel_name: &str) -> bool {
     )
 }
 
+/// Channels that can deliver a reply as a synthesized voice note.
+fn supports_voice_replies(channel_name: &str) -> bool {
+    channel_name == "telegram"
+}
+
 fn should_bypass_reply_intent_precheck(
     msg: &zeroclaw_api::channel::ChannelMessage,
     direct_message: bool,
@@ -1408,6 +1427,23 @@ fn parse_runtime_command(channel_name: &str, content: &str) -> Option<ChannelRun
                 }
             }
         }
+        "/persona" => {
+            let name = parts.collect::<Vec<_>>().join(" ");
+            if name.is_empty() {
+                Some(ChannelRuntimeCommand::ShowPersonas)
+            } else if name.eq_ignore_ascii_case("default") {
+                Some(ChannelRuntimeCommand::SetPersona(None))
+            } else {
+                Some(ChannelRuntimeCommand::SetPersona(Some(name)))
+            }
+        }
+        "/voice" if supports_voice_replies(channel_name) => {
+            match parts.next().map(str::to_ascii_lowercase).as_deref() {
+                Some("on") => Some(ChannelRuntimeCommand::SetVoiceReplies(true)),
+                Some("off") => Some(ChannelRuntimeCommand::SetVoiceReplies(false)),
+                _ => Some(ChannelRuntimeCommand::ShowVoiceReplies),
+            }
+        }
         // Model/model_provider switching is channel-gated.
         "/models" if supports_runtime_model_switch(channel_name) => {
             if let Some(model_provider) = parts.next() {
@@ -1776,6 +1812,8 @@ fn default_route_selection_from_snapshot(
         model_provider: defaults.default_model_provider,
         model: defaults.model,
         api_key: None,
+        persona: None,
+        voice_replies: false,
     }
 }
 
@@ -1824,14 +1862,47 @@ fn get_route_selection(
 fn set_route_selection(
     ctx: &ChannelRuntimeContext,
     sender_key: &str,
-    next: ChannelRouteSelection,
+    mut next: ChannelRouteSelection,
+    defaults_snapshot: &ChannelRuntimeDefaultsSnapshot,
+) {
+    let default_route = default_route_selection_from_snapshot(defaults_snapshot);
+    let mut routes = ctx
+        .route_overrides
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    // Only `/persona` and `/voice` change these; a model switch keeps them.
+    if let Some(existing) = routes.get(sender_key) {
+        next.persona = existing.persona.clone();
+        next.voice_replies = existing.voice_replies;
+    } else {
+        next.persona = None;
+        next.voice_replies = false;
+    }
+    if next == default_route {
+        routes.remove(sender_key);
+    } else {
+        routes.insert(sender_key.to_string(), next);
+    }
+}
+
+/// Change the sender's `/persona` or `/voice` preference, leaving the model
+/// half of their route as it is.
+fn update_sender_preferences(
+    ctx: &ChannelRuntimeContext,
+    sender_key: &str,
     defaults_snapshot: &ChannelRuntimeDefaultsSnapshot,
+    apply: impl FnOnce(&mut ChannelRouteSelection),
 ) {
     let default_route = default_route_selection_from_snapshot(defaults_snapshot);
     let mut routes = ctx
         .route_overrides
         .lock()
         .unwrap_or_else(|e| e.into_inner());
+    let mut next = routes
+        .get(sender_key)
+        .cloned()
+        .unwrap_or_else(|| default_route.clone());
+    apply(&mut next);
     if next == default_route {
         routes.remove(sender_key);
     } else {
@@ -1839,6 +1910,28 @@ fn set_route_selection(
     }
 }
 
+fn route_persona(ctx: &ChannelRuntimeContext, sender_key: &str) -> Option<String> {
+    ctx.route_overrides
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(sender_key)
+        .and_then(|route| route.persona.clone())
+}
+
+fn route_voice_replies(ctx: &ChannelRuntimeContext, sender_key: &str) -> bool {
+    ctx.route_overrides
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(sender_key)
+        .is_some_and(|route| route.voice_replies)
+}
+
+/// Whether `/voice on` can take effect for this agent: TTS is enabled and
+/// the agent names a `tts_provider`.
+fn voice_replies_configured(ctx: &ChannelRuntimeContext) -> bool {
+    ctx.prompt_config.tts.enabled && !ctx.agent_cfg.tts_provider.as_str().is_empty()
+}
+
 fn apply_model_ref(
     sel: &mut ChannelRouteSelection,
     model_routes: &[zeroclaw_config::schema::ModelRouteConfig],
@@ -1967,6 +2060,8 @@ fn clear_sender_history(ctx: &ChannelRuntimeContext, sender_key: &str) {
         .lock()
         .unwrap_or_else(|e| e.into_inner())
         .pop(sender_key);
+    // The shell tool's cwd and exported vars live exactly as long as the history.
+    zeroclaw_runtime::tools::shell_session::forget(sender_key);
 }
 
 fn mark_sender_for_new_session(ctx: &ChannelRuntimeContext, sender_key: &str) {
@@ -2026,18 +2121,58 @@ fn replace_available_skills_section(base_prompt: &str, refreshed_skills: &str) -
     format!("{base_prompt}\n\n{refreshed_skills}")
 }
 
+/// Splice freshly rendered workspace files into the Project Context section.
+/// Returns `None` when the section is absent (AIEOS identity, truncated
+/// prompt, custom prefix) so the caller keeps the prompt as built.
+fn replace_project_context_files(base_prompt: &str, refreshed_files: &str) -> Option<String> {
+    const PROJECT_CONTEXT_HEADER: &str = "## Project Context\n\n";
+
+    let content_start = base_prompt.find(PROJECT_CONTEXT_HEADER)? + PROJECT_CONTEXT_HEADER.len();
+    let runtime_start = base_prompt
+        .find("\n## Runtime")
+        .map(|i| i + 1)
+        .unwrap_or(base_prompt.len());
+    let (date_start, _) = find_latest_date_heading_before(base_prompt, runtime_start)?;
+    if date_start < content_start {
+        return None;
+    }
+    let mut refreshed = String::with_capacity(base_prompt.len() + refreshed_files.len());
+    refreshed.push_str(&base_prompt[..content_start]);
+    refreshed.push_str(refreshed_files);
+    refreshed.push_str(&base_prompt[date_start..]);
+    Some(refreshed)
+}
+
 fn refreshed_new_session_system_prompt(ctx: &ChannelRuntimeContext) -> String {
-    let refreshed_skills = zeroclaw_runtime::skills::skills_to_prompt_with_mode(
-        &zeroclaw_runtime::skills::load_skills_for_agent(
-            ctx.workspace_dir.as_ref(),
-            ctx.prompt_config.as_ref(),
-            ctx.agent_alias.as_ref(),
-        ),
+    refreshed_system_prompt(ctx, None)
+}
+
+/// The startup prompt with skills and workspace files re-read, taking
+/// SOUL/IDENTITY/TOOLS from `persona_dir` when a persona is selected.
+fn refreshed_system_prompt(ctx: &ChannelRuntimeContext, persona_dir: Option<&Path>) -> String {
+    let refreshed_skills = ctx.prompt_cache.skills_section(
         ctx.workspace_dir.as_ref(),
-        ctx.prompt_config
-            .effective_skills_prompt_mode(ctx.agent_alias.as_str()),
+        ctx.prompt_config.as_ref(),
+        ctx.agent_alias.as_str(),
     );
-    replace_available_skills_section(ctx.system_prompt.as_str(), &refreshed_skills)
+    let prompt = replace_available_skills_section(ctx.system_prompt.as_str(), &refreshed_skills);
+
+    // A budgeted prompt was truncated at build time; splicing in longer
+    // files here would overrun it, so it keeps the startup snapshot.
+    if zeroclaw_runtime::identity::is_aieos_configured(&ctx.agent_cfg.identity)
+        || ctx.agent_cfg.resolved.max_system_prompt_chars > 0
+    {
+        return prompt;
+    }
+    let max_chars = if ctx.agent_cfg.resolved.compact_context {
+        6000
+    } else {
+        BOOTSTRAP_MAX_CHARS
+    };
+    let files =
+        ctx.prompt_cache
+            .project_context(ctx.workspace_dir.as_ref(), persona_dir, max_chars, true);
+    replace_project_context_files(&prompt, &files).unwrap_or(prompt)
 }
 
 fn compact_sender_history(ctx: &ChannelRuntimeContext, sender_key: &str) -> bool {
@@ -2060,9 +2195,14 @@ fn compact_sender_history(ctx: &ChannelRuntimeContext, sender_key: &str) -> bool
     let mut compacted = normalize_cached_channel_turns(turns[keep_from..].to_vec());
 
     for turn in &mut compacted {
-        if turn.content.chars().count() > CHANNEL_HISTORY_COMPACT_CONTENT_CHARS {
-            turn.content =
-                truncate_with_ellipsis(&turn.content, CHANNEL_HISTORY_COMPACT_CONTENT_CHARS);
+        if zeroclaw_providers::tokens::estimate_tokens(&turn.content)
+            > CHANNEL_HISTORY_COMPACT_CONTENT_TOKENS
+        {
+            let kept = zeroclaw_providers::tokens::truncate_to_tokens(
+                &turn.content,
+                CHANNEL_HISTORY_COMPACT_CONTENT_TOKENS,
+            );
+            turn.content = format!("{}...", kept.trim_end());
         }
     }
 
@@ -2119,6 +2259,44 @@ fn append_sender_turn(ctx: &ChannelRuntimeContext, sender_key: &str, turn: ChatM
     }
 }
 
+/// Log a reply's delivery receipt and attach its platform message id to the
+/// assistant turn persisted for `sender_key`, so a conversation can be traced
+/// back to the message the user actually saw.
+fn record_delivery_receipt(
+    ctx: &ChannelRuntimeContext,
+    sender_key: &str,
+    channel: &str,
+    receipt: &DeliveryReceipt,
+) {
+    let accepted_at = chrono::DateTime::<chrono::Utc>::from(receipt.accepted_at).to_rfc3339();
+    ::zeroclaw_log::record!(
+        INFO,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Outbound)
+            .with_outcome(::zeroclaw_log::EventOutcome::Success)
+            .with_attrs(::serde_json::json!({
+                "channel": channel,
+                "delivery_id": receipt.message_id,
+                "accepted_at": accepted_at,
+            })),
+        "reply delivery confirmed"
+    );
+
+    let Some(ref store) = ctx.session_store else {
+        return;
+    };
+    let persist_lock = acquire_persist_lock(ctx, sender_key);
+    let _lock = persist_lock.lock().unwrap_or_else(|e| e.into_inner());
+    if let Err(e) = store.set_last_delivery_id(sender_key, &receipt.message_id) {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                .with_attrs(::serde_json::json!({"error": format!("{}", e)})),
+            "Failed to persist reply delivery id"
+        );
+    }
+}
+
 /// Extract tool-call (assistant with tool_call content) and tool-result
 /// messages from the current turn in the LLM history, excluding the final
 /// assistant text response.  "Current turn" = everything after the last
@@ -2148,6 +2326,22 @@ fn extract_current_turn_tool_messages(history: &[ChatMessage]) -> Vec<ChatMessag
         .collect()
 }
 
+/// Reasoning trace to keep on the persisted final reply. Only kept when
+/// `runtime.reasoning_enabled = true`; the trace is never part of the text
+/// delivered to the channel.
+fn final_turn_reasoning(
+    history: &[ChatMessage],
+    reasoning_enabled: Option<bool>,
+) -> Option<String> {
+    if reasoning_enabled != Some(true) {
+        return None;
+    }
+    history
+        .last()
+        .filter(|m| m.role == "assistant")
+        .and_then(|m| m.reasoning.clone())
+}
+
 fn rollback_orphan_user_turn(
     ctx: &ChannelRuntimeContext,
     sender_key: &str,
@@ -2206,6 +2400,17 @@ fn should_rollback_failed_user_turn(error: &anyhow::Error) -> bool {
     zeroclaw_providers::reliable::is_non_retryable(error)
 }
 
+/// Whether a failed tool loop is worth re-running the whole message for
+/// (`[reliability] message_retries`): transient model_provider failures only,
+/// never model switches, cancellations, context overflows, or errors the
+/// provider layer already classifies as permanent.
+fn is_retryable_message_error(error: &anyhow::Error) -> bool {
+    is_model_switch_requested(error).is_none()
+        && !zeroclaw_runtime::agent::loop_::is_tool_loop_cancelled(error)
+        && !is_context_window_overflow_error(error)
+        && !zeroclaw_providers::reliable::is_non_retryable(error)
+}
+
 fn is_context_window_overflow_error(err: &anyhow::Error) -> bool {
     let lower = err.to_string().to_lowercase();
     [
@@ -2223,26 +2428,9 @@ fn is_context_window_overflow_error(err: &anyhow::Error) -> bool {
 }
 
 fn load_cached_model_preview(workspace_dir: &Path, provider_name: &str) -> Vec<String> {
-    let cache_path = workspace_dir.join("state").join(MODEL_CACHE_FILE);
-    let Ok(raw) = std::fs::read_to_string(cache_path) else {
-        return Vec::new();
-    };
-    let Ok(state) = serde_json::from_str::<ModelCacheState>(&raw) else {
-        return Vec::new();
-    };
-
-    state
-        .entries
-        .into_iter()
-        .find(|entry| entry.model_provider == provider_name)
-        .map(|entry| {
-            entry
-                .models
-                .into_iter()
-                .take(MODEL_CACHE_PREVIEW_LIMIT)
-                .collect::<Vec<_>>()
-        })
-        .unwrap_or_default()
+    let mut models = zeroclaw_runtime::model_cache::cached_models(workspace_dir, provider_name);
+    models.truncate(MODEL_CACHE_PREVIEW_LIMIT);
+    models
 }
 
 /// Build a cache key that includes the runtime-defaults generation, the
@@ -2957,6 +3145,75 @@ async fn handle_runtime_command_if_needed(
             "channel-runtime-thinking-invalid",
             &[("raw", raw.as_str())],
         ),
+        ChannelRuntimeCommand::ShowPersonas => {
+            let personas =
+                zeroclaw_runtime::agent::system_prompt::list_personas(ctx.workspace_dir.as_path());
+            if personas.is_empty() {
+                channel_runtime_cli_string("channel-runtime-persona-none-available")
+            } else {
+                let current =
+                    route_persona(ctx, &sender_key).unwrap_or_else(|| "default".to_string());
+                let list = personas
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                channel_runtime_cli_string_with_args(
+                    "channel-runtime-persona-list",
+                    &[("current", current.as_str()), ("list", list.as_str())],
+                )
+            }
+        }
+        ChannelRuntimeCommand::ShowVoiceReplies => {
+            let key = if !voice_replies_configured(ctx) {
+                "channel-runtime-voice-unavailable"
+            } else if route_voice_replies(ctx, &sender_key) {
+                "channel-runtime-voice-status-on"
+            } else {
+                "channel-runtime-voice-status-off"
+            };
+            channel_runtime_cli_string(key)
+        }
+        ChannelRuntimeCommand::SetVoiceReplies(true) if !voice_replies_configured(ctx) => {
+            channel_runtime_cli_string("channel-runtime-voice-unavailable")
+        }
+        ChannelRuntimeCommand::SetVoiceReplies(enabled) => {
+            update_sender_preferences(ctx, &sender_key, &defaults_snapshot, |route| {
+                route.voice_replies = enabled;
+            });
+            channel_runtime_cli_string(if enabled {
+                "channel-runtime-voice-on"
+            } else {
+                "channel-runtime-voice-off"
+            })
+        }
+        ChannelRuntimeCommand::SetPersona(None) => {
+            update_sender_preferences(ctx, &sender_key, &defaults_snapshot, |route| {
+                route.persona = None;
+            });
+            channel_runtime_cli_string("channel-runtime-persona-reset")
+        }
+        ChannelRuntimeCommand::SetPersona(Some(name)) => {
+            if zeroclaw_runtime::agent::system_prompt::persona_dir(
+                ctx.workspace_dir.as_path(),
+                &name,
+            )
+            .is_some()
+            {
+                update_sender_preferences(ctx, &sender_key, &defaults_snapshot, |route| {
+                    route.persona = Some(name.clone());
+                });
+                channel_runtime_cli_string_with_args(
+                    "channel-runtime-persona-switched",
+                    &[("persona", name.as_str())],
+                )
+            } else {
+                channel_runtime_cli_string_with_args(
+                    "channel-runtime-persona-unknown",
+                    &[("persona", name.as_str())],
+                )
+            }
+        }
     };
 
     if let Err(err) = channel
@@ -3234,8 +3491,12 @@ fn parse_reply_intent(response: &str) -> AssistantChannelOutcome {
     AssistantChannelOutcome::Reply(String::new())
 }
 
-async fn resolve_classifier_route(
+/// Resolve an auxiliary `<type>.<alias>` provider override such as the
+/// agent's `classifier_provider` or `[translation] model_provider`. `setting`
+/// names the config key in logs. `None` means use the main agent provider.
+async fn resolve_auxiliary_route(
     ctx: &ChannelRuntimeContext,
+    setting: &str,
     provider_ref: &zeroclaw_config::providers::ModelProviderRef,
     defaults_snapshot: &ChannelRuntimeDefaultsSnapshot,
 ) -> Option<(Arc<dyn ModelProvider>, String, Option<f64>)> {
@@ -3251,8 +3512,10 @@ async fn resolve_classifier_route(
                 WARN,
                 ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
                     .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                    .with_attrs(::serde_json::json!({"provider": provider_str})),
-                "classifier_provider must be dotted `<type>.<alias>`; falling back to main agent"
+                    .with_attrs(
+                        ::serde_json::json!({"setting": setting, "provider": provider_str})
+                    ),
+                "provider override must be dotted `<type>.<alias>`; falling back to main agent"
             );
             return None;
         }
@@ -3270,8 +3533,10 @@ async fn resolve_classifier_route(
                 WARN,
                 ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
                     .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                    .with_attrs(::serde_json::json!({"provider": provider_str})),
-                "classifier_provider references an unknown [providers.models.<type>.<alias>] entry; falling back to main agent"
+                    .with_attrs(
+                        ::serde_json::json!({"setting": setting, "provider": provider_str})
+                    ),
+                "provider override references an unknown [providers.models.<type>.<alias>] entry; falling back to main agent"
             );
             return None;
         }
@@ -3284,8 +3549,8 @@ async fn resolve_classifier_route(
             WARN,
             ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
                 .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                .with_attrs(::serde_json::json!({"provider": provider_str})),
-            "classifier_provider points to a [providers.models] entry without a `model` field; falling back to main agent"
+                .with_attrs(::serde_json::json!({"setting": setting, "provider": provider_str})),
+            "provider override points to a [providers.models] entry without a `model` field; falling back to main agent"
         );
         return None;
     }
@@ -3305,8 +3570,8 @@ async fn resolve_classifier_route(
                 WARN,
                 ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
                     .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                    .with_attrs(::serde_json::json!({"provider": provider_str, "error": safe_err})),
-                "Failed to initialize classifier_provider; falling back to main agent provider"
+                    .with_attrs(::serde_json::json!({"setting": setting, "provider": provider_str, "error": safe_err})),
+                "Failed to initialize provider override; falling back to main agent provider"
             );
             return None;
         }
@@ -3315,8 +3580,8 @@ async fn resolve_classifier_route(
     ::zeroclaw_log::record!(
         INFO,
         ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-            .with_attrs(::serde_json::json!({"provider": provider_str, "model": model.as_str()})),
-        "classifier_provider override active"
+            .with_attrs(::serde_json::json!({"setting": setting, "provider": provider_str, "model": model.as_str()})),
+        "provider override active"
     );
 
     Some((provider, model, temperature))
@@ -4331,6 +4596,9 @@ async fn process_channel_message(
     msg: zeroclaw_api::channel::ChannelMessage,
     cancellation_token: CancellationToken,
 ) {
+    // Removes the message's downloaded media when this future completes or
+    // is dropped, so errors and cancellation don't leave files behind.
+    let _media_cleanup = crate::inbound_media::InboundMediaCleanup::new(&msg.media_files);
     if cancellation_token.is_cancelled() {
         return;
     }
@@ -4389,6 +4657,83 @@ fn resolve_channel_ack_reactions(
     }
 }
 
+/// The `translate` setting of the message's channel alias, if any.
+fn resolve_channel_translate<'a>(
+    ctx: &'a ChannelRuntimeContext,
+    msg: &ChannelMessage,
+) -> Option<&'a zeroclaw_config::schema::ChannelTranslateConfig> {
+    let alias = msg.channel_alias.as_deref()?;
+    let channels = &ctx.prompt_config.channels;
+    match msg.channel.as_str() {
+        "telegram" => channels.telegram.get(alias)?.translate.as_ref(),
+        "discord" => channels.discord.get(alias)?.translate.as_ref(),
+        "slack" => channels.slack.get(alias)?.translate.as_ref(),
+        _ => None,
+    }
+}
+
+/// Backend built once at startup. Only DeepL is fixed; the `model` backend
+/// is resolved per message because it can follow the active provider.
+fn fixed_channel_translator(config: &Config) -> Option<Arc<dyn Translator>> {
+    let translation = &config.translation;
+    if translation.backend != zeroclaw_config::schema::TranslationBackend::Deepl {
+        return None;
+    }
+    let key = translation.deepl_api_key.as_deref()?.trim();
+    if key.is_empty() {
+        return None;
+    }
+    Some(Arc::new(crate::translation::DeeplTranslator::new(
+        key,
+        translation.deepl_api_url.clone(),
+    )))
+}
+
+/// The translator for this message: the fixed backend when there is one,
+/// otherwise a model translator on `[translation] model_provider` or the
+/// turn's own provider and model.
+async fn resolve_channel_translator(
+    ctx: &ChannelRuntimeContext,
+    runtime_defaults: &ChannelRuntimeDefaultsSnapshot,
+    active_model_provider: &Arc<dyn ModelProvider>,
+    active_model: &str,
+) -> Option<Arc<dyn Translator>> {
+    if let Some(translator) = ctx.translation.translator() {
+        return Some(translator);
+    }
+    let translation = &runtime_defaults.config.translation;
+    if translation.backend == zeroclaw_config::schema::TranslationBackend::Deepl {
+        return fixed_channel_translator(&runtime_defaults.config);
+    }
+    let (provider, model, temperature) = resolve_auxiliary_route(
+        ctx,
+        "translation.model_provider",
+        &translation.model_provider,
+        runtime_defaults,
+    )
+    .await
+    .unwrap_or_else(|| {
+        (
+            Arc::clone(active_model_provider),
+            active_model.to_string(),
+            None,
+        )
+    });
+    Some(Arc::new(crate::translation::ModelTranslator::new(
+        provider,
+        model,
+        temperature,
+    )))
+}
+
+/// Cache key for a sender's detected language.
+fn translation_sender_key(msg: &ChannelMessage) -> String {
+    match &msg.channel_alias {
+        Some(alias) => format!("{}.{}:{}", msg.channel, alias, msg.sender),
+        None => format!("{}:{}", msg.channel, msg.sender),
+    }
+}
+
 async fn reconcile_early_ack(
     ctx: &ChannelRuntimeContext,
     msg: &ChannelMessage,
@@ -4496,6 +4841,16 @@ async fn process_channel_message_body(
         "channel inbound message"
     );
 
+    // Root of this message's trace. Its turn id is threaded through the agent
+    // turn and tool loop below so their spans nest under the receive.
+    let message_observer = Arc::clone(&ctx.observer);
+    let message_trace = zeroclaw_runtime::observability::ChannelMessageGuard::start(
+        message_observer.as_ref(),
+        channel_composite.clone(),
+        &msg.sender,
+        uuid::Uuid::new_v4().to_string(),
+    );
+
     // ── Hook: on_message_received (modifying) ────────────
     let mut msg = if let Some(hooks) = &ctx.hooks {
         match hooks.run_on_message_received(msg).await {
@@ -4635,8 +4990,14 @@ async fn process_channel_message_body(
 
     // ── Media pipeline: enrich inbound message with media annotations ──
     if ctx.media_pipeline.enabled && !msg.attachments.is_empty() {
-        let vision =
-            ctx.model_provider.supports_vision() || ctx.multimodal.vision_model_provider.is_some();
+        let route = get_route_selection(
+            ctx.as_ref(),
+            &msg,
+            &history_key,
+            &runtime_defaults_snapshot(ctx.as_ref()),
+        );
+        let vision = channel_model_accepts_images(ctx.as_ref(), &route)
+            || ctx.multimodal.vision_model_provider.is_some();
         // Build from legacy config; if that fails (e.g. no legacy api_key
         // but typed providers are configured), fall back to an empty shell
         // so with_typed_providers() can still populate the registry.
@@ -4716,6 +5077,8 @@ async fn process_channel_message_body(
             model_provider: matched_route.model_provider.clone(),
             model: matched_route.model.clone(),
             api_key: matched_route.api_key.clone(),
+            persona: None,
+            voice_replies: false,
         };
     }
 
@@ -4751,12 +5114,64 @@ async fn process_channel_message_body(
             return;
         }
     };
+
+    // ── Translation: inbound text into the agent's language, before
+    // memory autosave and recall see it ──
+    let channel_translate = resolve_channel_translate(ctx.as_ref(), &msg).cloned();
+    let channel_translator = match channel_translate {
+        Some(_) => {
+            resolve_channel_translator(
+                ctx.as_ref(),
+                &runtime_defaults,
+                &active_model_provider,
+                &route.model,
+            )
+            .await
+        }
+        None => None,
+    };
+    let translation_sender = translation_sender_key(&msg);
+    let sender_original_content = msg.content.clone();
+    if let (Some(settings), Some(translator)) = (&channel_translate, &channel_translator)
+        && !settings.inbound_to.trim().is_empty()
+    {
+        match ctx
+            .translation
+            .translate_inbound(
+                translator.as_ref(),
+                &translation_sender,
+                &msg.content,
+                &settings.inbound_to,
+            )
+            .await
+        {
+            Ok(translated) => msg.content = translated,
+            Err(e) => {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"error": e.to_string()})),
+                    "inbound translation failed; using the original text"
+                );
+            }
+        }
+    }
+
     let history_user_content = msg.content.clone();
     // Autosave must not persist heavy/private inline `data:` image bytes into
     // durable memory. Strip them here (path/markers are preserved) before the
     // store; the channel-history cache still keeps the re-loadable markers via
     // collapse_inline_image_payloads downstream.
     let autosave_content = strip_inline_data_image_markers(&history_user_content);
+    // `[memory] scope`: per-sender / per-channel namespace for this turn's
+    // writes and recall. `None` under the global scope.
+    let memory_namespace = zeroclaw_memory::scope::channel_namespace(
+        &ctx.prompt_config.memory,
+        &channel_scope(&msg),
+        &msg.sender,
+        &msg.reply_target,
+    );
     if ctx.auto_save_memory
         && autosave_content.chars().count() >= AUTOSAVE_MIN_MESSAGE_CHARS
         && !zeroclaw_memory::should_skip_autosave_content(&autosave_content)
@@ -4764,11 +5179,13 @@ async fn process_channel_message_body(
         let autosave_key = conversation_memory_key(&msg);
         let _ = ctx
             .memory
-            .store(
+            .store_with_metadata(
                 &autosave_key,
                 &autosave_content,
                 zeroclaw_memory::MemoryCategory::Conversation,
                 Some(&history_key),
+                memory_namespace.as_ref().map(|ns| ns.namespace.as_str()),
+                None,
             )
             .await;
     }
@@ -4857,7 +5274,14 @@ async fn process_channel_message_body(
         memory_sessions.push(Some(history_key.clone()));
     }
 
-    let base_system_prompt = if had_prior_history {
+    let persona_dir = route_persona(ctx.as_ref(), &history_key).and_then(|name| {
+        zeroclaw_runtime::agent::system_prompt::persona_dir(ctx.workspace_dir.as_ref(), &name)
+    });
+    let base_system_prompt = if let Some(dir) = persona_dir.as_deref() {
+        // Rebuilt every turn so a switch applies mid-conversation; the
+        // prompt cache keeps it byte-stable while the files are unchanged.
+        refreshed_system_prompt(ctx.as_ref(), Some(dir))
+    } else if had_prior_history {
         ctx.system_prompt.as_str().to_string()
     } else {
         refreshed_new_session_system_prompt(ctx.as_ref())
@@ -4943,8 +5367,9 @@ async fn process_channel_message_body(
                     Arc<dyn ModelProvider>,
                     String,
                     Option<f64>,
-                ) = resolve_classifier_route(
+                ) = resolve_auxiliary_route(
                     ctx.as_ref(),
+                    "classifier_provider",
                     &ctx.agent_cfg.classifier_provider,
                     &runtime_defaults,
                 )
@@ -5318,7 +5743,7 @@ async fn process_channel_message_body(
         }
     });
     let loop_knobs = LoopKnobs::default();
-    let turn_id = uuid::Uuid::new_v4().to_string();
+    let turn_id = message_trace.turn_id().to_string();
     // Bracket the channel turn so lifecycle events
     // reach observers (and, via the broadcast hook, /api/events and
     // /api/events/history) for channel-originated turns — mirroring the CLI
@@ -5335,6 +5760,15 @@ async fn process_channel_message_body(
         Some(ctx.agent_alias.to_string()),
         Some(turn_id.clone()),
     );
+    // One execution ledger per inbound message, shared by every retry of
+    // it below: a re-run replays mutating tool calls that already succeeded
+    // instead of executing them again.
+    let message_retries = ctx.reliability.message_retries;
+    let tool_ledger = (message_retries > 0).then(|| {
+        Arc::new(zeroclaw_runtime::agent::tool_ledger::ToolExecutionLedger::new(msg.id.clone()))
+    });
+    let history_before_llm = (message_retries > 0).then(|| history.clone());
+    let mut message_attempt: u32 = 0;
     let (llm_result, fallback_info) = scope_provider_fallback(async {
         let llm_result = loop {
             let thread_scope_id = msg
@@ -5408,6 +5842,10 @@ async fn process_channel_message_body(
                     handle: ctx.memory.as_ref(),
                     query: msg.content.clone(),
                     sessions: memory_sessions.clone(),
+                    namespaces: memory_namespace
+                        .as_ref()
+                        .map(zeroclaw_memory::scope::ScopedNamespace::recall_namespaces)
+                        .unwrap_or_default(),
                     suppress: false,
                     // The relevance floor stays the context's resolved copy;
                     // the rerank stage settings thread from the live config.
@@ -5443,6 +5881,10 @@ async fn process_channel_message_body(
                 .scope(cost_tracking_context.clone(), tool_loop);
             let tool_loop = scope_session_key(Some(history_key.clone()), tool_loop);
             let tool_loop = scope_thread_id(thread_scope_id, tool_loop);
+            let tool_loop = zeroclaw_memory::scope::MEMORY_NAMESPACE
+                .scope(memory_namespace.clone(), tool_loop);
+            let tool_loop = zeroclaw_runtime::agent::tool_ledger::TOOL_EXECUTION_LEDGER
+                .scope(tool_ledger.clone(), tool_loop);
             let timed_tool_loop =
                 tokio::time::timeout(Duration::from_secs(timeout_budget_secs), tool_loop);
 
@@ -5522,6 +5964,8 @@ async fn process_channel_message_body(
                                 model_provider: route.model_provider.clone(),
                                 model: route.model.clone(),
                                 api_key: route.api_key.clone(),
+                                persona: None,
+                                voice_replies: false,
                             },
                             &runtime_defaults,
                         );
@@ -5545,6 +5989,28 @@ async fn process_channel_message_body(
                 }
             }
 
+            if let LlmExecutionResult::Completed(Ok(Err(ref e))) = loop_result
+                && message_attempt < message_retries
+                && is_retryable_message_error(e)
+                && let Some(snapshot) = history_before_llm.as_ref()
+            {
+                message_attempt += 1;
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Retry)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "message_id": msg.id,
+                            "attempt": message_attempt,
+                            "max_retries": message_retries,
+                            "err": e.to_string(),
+                        })),
+                    "Retrying channel message after model_provider failure"
+                );
+                history.clone_from(snapshot);
+                continue;
+            }
+
             break loop_result;
         };
         let fb = take_last_provider_fallback();
@@ -5564,6 +6030,18 @@ async fn process_channel_message_body(
             },
         )
     });
+    if let Some(ledger) = tool_ledger.as_ref().filter(|l| !l.is_empty()) {
+        ::zeroclaw_log::record!(
+            INFO,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_category(::zeroclaw_log::EventCategory::Tool)
+                .with_attrs(::serde_json::json!({
+                    "attempts": message_attempt + 1,
+                    "ledger": ledger.to_transcript_json(),
+                })),
+            "tool execution ledger"
+        );
+    }
     turn_guard.set_model_route(route.model_provider.clone(), route.model.clone());
     turn_guard.set_usage(turn_tokens_used, None);
     turn_guard.finish();
@@ -5728,6 +6206,42 @@ async fn process_channel_message_body(
                 &msg.reply_target,
             );
 
+            // History and memory keep the reply in the agent's language; only
+            // the delivered copy is translated.
+            let mut untranslated_response = None;
+            if let (Some(settings), Some(translator)) = (&channel_translate, &channel_translator)
+                && !settings.outbound_to.trim().is_empty()
+            {
+                match ctx
+                    .translation
+                    .translate_outbound(
+                        translator.as_ref(),
+                        &translation_sender,
+                        &sender_original_content,
+                        &delivered_response,
+                        &settings.outbound_to,
+                    )
+                    .await
+                {
+                    Ok(translated) => {
+                        untranslated_response =
+                            Some(std::mem::replace(&mut delivered_response, translated));
+                    }
+                    Err(e) => {
+                        ::zeroclaw_log::record!(
+                            WARN,
+                            ::zeroclaw_log::Event::new(
+                                module_path!(),
+                                ::zeroclaw_log::Action::Note
+                            )
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                            .with_attrs(::serde_json::json!({"error": e.to_string()})),
+                            "outbound translation failed; sending the original reply"
+                        );
+                    }
+                }
+            }
+
             // Append a footer when the response was served by a different model_provider family.
             // Intra-family fallbacks (e.g. minimax → minimax-cn) are suppressed.
             if let Some(fb) = fallback_info.as_ref() {
@@ -5779,11 +6293,14 @@ async fn process_channel_message_body(
                 }
             }
 
-            let history_response = delivered_response.clone();
+            let history_response =
+                untranslated_response.unwrap_or_else(|| delivered_response.clone());
+            let history_reasoning =
+                final_turn_reasoning(&history, ctx.prompt_config.runtime.reasoning_enabled);
             append_sender_turn(
                 ctx.as_ref(),
                 &history_key,
-                ChatMessage::assistant(&history_response),
+                ChatMessage::assistant(&history_response).with_reasoning(history_reasoning),
             );
 
             // Fire-and-forget LLM-driven memory consolidation. Passes the
@@ -5796,7 +6313,7 @@ async fn process_channel_message_body(
                 let model = ctx.model.to_string();
                 let temperature = ctx.temperature;
                 let user_msg = msg.content.clone();
-                let assistant_resp = delivered_response.clone();
+                let assistant_resp = history_response.clone();
                 zeroclaw_spawn::spawn!(async move {
                     if let Err(e) = memory_strategy
                         .consolidate_turn(
@@ -5881,11 +6398,14 @@ async fn process_channel_message_body(
                 );
                 (ch, recipient, suppress, force_voice)
             } else {
+                // `/voice on` asks for this sender's replies as voice notes.
+                let voice_replies = voice_replies_configured(ctx.as_ref())
+                    && route_voice_replies(ctx.as_ref(), &history_key);
                 (
                     target_channel.clone(),
                     msg.reply_target.clone(),
                     None,
-                    false,
+                    voice_replies,
                 )
             };
 
@@ -5894,9 +6414,29 @@ async fn process_channel_message_body(
                     .as_ref()
                     .and_then(|r| r.channel.as_deref())
                     .is_some();
-                // Whether the agent's reply reached a channel — gates the
-                // `fire_message_sent` observer hook below.
-                let reply_delivered = if is_redirect {
+                // Replies for a recipient with an outbox backlog queue behind
+                // it so they still arrive in order.
+                let channel_outbox = zeroclaw_runtime::outbox::active();
+                let outbox_key = match turn_route.as_ref().and_then(|r| r.channel.as_deref()) {
+                    Some(key) if !key.is_empty() => key.to_string(),
+                    _ => channel_key_for_message(&msg),
+                };
+                let queued_behind = channel_outbox.as_ref().is_some_and(|outbox| {
+                    outbox
+                        .has_backlog(&outbox_key, &delivery_recipient)
+                        .unwrap_or(false)
+                });
+                let send_started = Instant::now();
+                let send_result = if queued_behind {
+                    if let (Some(orig_ch), Some(draft_id)) =
+                        (target_channel.as_ref(), draft_message_id.as_deref())
+                    {
+                        let _ = orig_ch.cancel_draft(&msg.reply_target, draft_id).await;
+                    }
+                    Err(anyhow::anyhow!(
+                        "earlier replies to this recipient are still queued"
+                    ))
+                } else if is_redirect {
                     // Routing redirects to a different channel: cancel any in-progress
                     // draft on the originating channel before delivering elsewhere.
                     if let (Some(orig_ch), Some(draft_id)) =
@@ -5912,7 +6452,7 @@ async fn process_channel_message_body(
                     } else if force_voice_override {
                         send_msg = send_msg.force_voice();
                     }
-                    channel.send(&send_msg).await.is_ok()
+                    channel.send_with_receipt(&send_msg).await
                 } else if let Some(ref draft_id) = draft_message_id {
                     // Same channel with draft. For force-voice routing: cancel the
                     // draft placeholder and deliver via send() so force_voice
@@ -5921,13 +6461,12 @@ async fn process_channel_message_body(
                     if force_voice_override {
                         let _ = channel.cancel_draft(&delivery_recipient, draft_id).await;
                         channel
-                            .send(
+                            .send_with_receipt(
                                 &SendMessage::new(&delivered_response, &delivery_recipient)
                                     .force_voice()
                                     .in_thread(msg.thread_ts.clone()),
                             )
                             .await
-                            .is_ok()
                     } else {
                         let suppress = suppress_voice_override.unwrap_or(false);
                         match channel
@@ -5939,7 +6478,7 @@ async fn process_channel_message_body(
                             )
                             .await
                         {
-                            Ok(()) => true,
+                            Ok(()) => Ok(None),
                             Err(e) => {
                                 ::zeroclaw_log::record!(
                                     WARN,
@@ -5955,7 +6494,7 @@ async fn process_channel_message_body(
                                 if suppress {
                                     fallback = fallback.suppress_voice();
                                 }
-                                channel.send(&fallback).await.is_ok()
+                                channel.send_with_receipt(&fallback).await
                             }
                         }
                     }
@@ -5969,9 +6508,17 @@ async fn process_channel_message_body(
                     } else if force_voice_override {
                         send_msg = send_msg.force_voice();
                     }
-                    match channel.send(&send_msg).await {
-                        Ok(()) => true,
-                        Err(e) => {
+                    channel.send_with_receipt(&send_msg).await
+                };
+                // Whether the agent's reply reached a channel — gates the
+                // `fire_message_sent` observer hook below — and the platform
+                // message id when the channel returned a delivery receipt.
+                // Undelivered replies go to the outbox unless the turn was
+                // cancelled.
+                let (reply_delivered, delivery_receipt) = match send_result {
+                    Ok(receipt) => (true, receipt),
+                    Err(e) => {
+                        if !queued_behind {
                             ::zeroclaw_log::record!(
                                 ERROR,
                                 ::zeroclaw_log::Event::new(
@@ -5982,10 +6529,27 @@ async fn process_channel_message_body(
                                 .with_attrs(::serde_json::json!({"error": format!("{}", e)})),
                                 "failed to reply"
                             );
-                            false
                         }
+                        if let Some(channel_outbox) = channel_outbox.as_deref()
+                            && !cancellation_token.is_cancelled()
+                        {
+                            outbox::park_reply(
+                                channel_outbox,
+                                &outbox_key,
+                                &delivery_recipient,
+                                msg.thread_ts.clone(),
+                                &delivered_response,
+                                &format!("{e:#}"),
+                            );
+                        }
+                        (false, None)
                     }
                 };
+                let delivery_id = delivery_receipt.as_ref().map(|r| r.message_id.as_str());
+                message_trace.record_send(send_started.elapsed(), reply_delivered, delivery_id);
+                if let Some(receipt) = delivery_receipt.as_ref() {
+                    record_delivery_receipt(ctx.as_ref(), &history_key, &msg.channel, receipt);
+                }
                 if reply_delivered && let Some(hooks) = ctx.hooks.as_ref() {
                     hooks
                         .fire_message_sent(&msg.channel, &msg.reply_target, &delivered_response)
@@ -6148,8 +6712,11 @@ async fn process_channel_message_body(
                     );
                 }
                 if let Some(channel) = target_channel.as_ref() {
-                    let user_msg = zeroclaw_providers::reliable::transient_error_hint(&e)
-                        .map(str::to_string)
+                    let user_msg = zeroclaw_providers::middleware::policy_rejection_message(&e)
+                        .or_else(|| {
+                            zeroclaw_providers::reliable::transient_error_hint(&e)
+                                .map(str::to_string)
+                        })
                         .unwrap_or_else(|| format!("⚠️ Error: {safe_error}"));
                     // Cancel any in-progress draft (don't finalize it with the
                     // error text, which would trigger TTS on the error message)
@@ -6264,17 +6831,22 @@ async fn dispatch_worker(
                 sender_scope_key.clone(),
                 InFlightSenderTaskState {
                     task_id,
+                    message_id: msg.id.clone(),
                     cancellation: cancellation_token.clone(),
                     completion: Arc::clone(&completion),
                 },
             )
         };
 
-        if interrupt_enabled && let Some(previous) = previous {
+        if let Some(previous) = previous
+            && (interrupt_enabled || msg.edit_of.as_deref() == Some(previous.message_id.as_str()))
+        {
             ::zeroclaw_log::record!(
                 INFO,
                 ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                    .with_attrs(::serde_json::json!({"sender": msg.sender})),
+                    .with_attrs(
+                        ::serde_json::json!({"sender": msg.sender, "edit": msg.edit_of.is_some()})
+                    ),
                 "interrupting previous in-flight request for sender"
             );
             previous.cancellation.cancel();
@@ -6838,10 +7410,32 @@ fn resolve_effective_debounce_window(
     std::time::Duration::from_millis(per_channel_ms.unwrap_or(global_ms))
 }
 
+/// Messages held for paused channels in `queue` mode before the oldest are
+/// dropped.
+const PAUSED_QUEUE_CAPACITY: usize = 1024;
+
+/// Next held message whose channel is no longer paused. Nothing is released
+/// while draining.
+fn take_released_message(
+    held: &mut std::collections::VecDeque<zeroclaw_api::channel::ChannelMessage>,
+    admission: &zeroclaw_runtime::admission::ChannelAdmission,
+) -> Option<zeroclaw_api::channel::ChannelMessage> {
+    if held.is_empty() || admission.is_draining() {
+        return None;
+    }
+    let pos = held
+        .iter()
+        .position(|msg| !admission.is_paused(&channel_key_for_message(msg)))?;
+    let msg = held.remove(pos);
+    admission.set_queued(held.len());
+    msg
+}
+
 async fn run_message_dispatch_loop(
     mut rx: tokio::sync::mpsc::Receiver<zeroclaw_api::channel::ChannelMessage>,
     router: AgentRouter,
     max_in_flight_messages: usize,
+    admission: Arc<zeroclaw_runtime::admission::ChannelAdmission>,
 ) {
     let semaphore = Arc::new(tokio::sync::Semaphore::new(max_in_flight_messages));
     let mut workers = tokio::task::JoinSet::new();
@@ -6850,10 +7444,33 @@ async fn run_message_dispatch_loop(
         InFlightSenderTaskState,
     >::new()));
     let task_sequence = Arc::new(AtomicU64::new(1));
+    let mut admission_rx = admission.subscribe();
+    let mut held = std::collections::VecDeque::new();
 
-    while let Some(msg) = rx.recv().await {
-        // Gate answers (button-click markers / `approve <ref>` text replies)
-        // resolve a PARKED run and must never start one, so they are consumed
+    loop {
+        // Drain mode stops reading from `rx` (senders back up on the bounded
+        // channel) while workers already spawned keep running and are joined
+        // here. Every pause/resume/drain change wakes the select so released
+        // messages and the receiver guard are re-evaluated.
+        let msg = if let Some(msg) = take_released_message(&mut held, &admission) {
+            msg
+        } else {
+            tokio::select! {
+                received = rx.recv(), if !admission.is_draining() => match received {
+                    Some(msg) => msg,
+                    None => break,
+                },
+                _ = admission_rx.changed() => continue,
+                Some(result) = workers.join_next(), if !workers.is_empty() => {
+                    log_worker_join_result(result);
+                    admission.set_in_flight(workers.len());
+                    continue;
+                }
+            }
+        };
+
+        // Gate answers (button-click markers / `approve <ref>` text replies)
+        // resolve a PARKED run and must never start one, so they are consumed
         // BEFORE agent ownership lookup. A configured approval route may be
         // intentionally unowned by an agent; it can present gate prompts but
         // must never receive ordinary agent traffic. All live contexts share
@@ -6908,6 +7525,11 @@ async fn run_message_dispatch_loop(
         if dispatch_channel_sop_event(&router, &msg).await {
             continue;
         }
+        // Reactions rate an earlier reply; they never start a turn.
+        if let Some(reaction) = msg.reaction.clone() {
+            zeroclaw_spawn::spawn!(feedback::record_reaction_feedback(ctx, msg, reaction));
+            continue;
+        }
         // Fast path: /stop cancels the in-flight task for this sender scope without
         // spawning a worker or registering a new task. Handled here — before semaphore
         // acquisition — so the target task is still in the store and is never replaced.
@@ -6943,6 +7565,39 @@ async fn run_message_dispatch_loop(
             continue;
         }
 
+        // Paused channels (gateway admin API) never reach a worker: the
+        // sender gets the configured auto-reply, or the message is held until
+        // the channel is resumed.
+        if msg.channel != "cli" && admission.is_paused(&channel_key_for_message(&msg)) {
+            match ctx.prompt_config.channels.paused_mode {
+                zeroclaw_config::schema::PausedChannelMode::Reply => {
+                    let reply = ctx.prompt_config.channels.paused_reply.clone();
+                    if let Some(channel) =
+                        find_channel_for_message(&ctx.channels_by_name, &msg).cloned()
+                        && !reply.trim().is_empty()
+                    {
+                        let reply_target = msg.reply_target.clone();
+                        let thread_ts = msg.thread_ts.clone();
+                        zeroclaw_spawn::spawn!(async move {
+                            let _ = channel
+                                .send(&SendMessage::new(reply, &reply_target).in_thread(thread_ts))
+                                .await;
+                        });
+                    }
+                }
+                zeroclaw_config::schema::PausedChannelMode::Queue => {
+                    if held.len() >= PAUSED_QUEUE_CAPACITY
+                        && let Some(dropped) = held.pop_front()
+                    {
+                        ::zeroclaw_log::record!(WARN, ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note).with_outcome(::zeroclaw_log::EventOutcome::Unknown).with_attrs(::serde_json::json!({"channel": dropped.channel, "sender": dropped.sender, "capacity": PAUSED_QUEUE_CAPACITY})), "paused channel queue full; dropping oldest held message");
+                    }
+                    held.push_back(msg);
+                    admission.set_queued(held.len());
+                }
+            }
+            continue;
+        }
+
         // ── Debounce: accumulate rapid messages per sender ──────────
         // CLI messages bypass debouncing so the interactive loop stays responsive.
         let msg = if msg.channel != "cli" {
@@ -7024,11 +7679,23 @@ async fn run_message_dispatch_loop(
         while let Some(result) = workers.try_join_next() {
             log_worker_join_result(result);
         }
+        admission.set_in_flight(workers.len());
     }
 
+    if !held.is_empty() {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                .with_attrs(::serde_json::json!({"held": held.len()})),
+            "channel dispatch loop stopped with messages still held for paused channels"
+        );
+        admission.set_queued(0);
+    }
     while let Some(result) = workers.join_next().await {
         log_worker_join_result(result);
     }
+    admission.set_in_flight(0);
 }
 
 fn normalize_telegram_identity(value: &str) -> String {
@@ -7172,6 +7839,164 @@ pub async fn bind_telegram_identity(config: &Config, identity: &str, alias: &str
     Ok(())
 }
 
+/// Options for `zeroclaw channel pair`.
+#[derive(Debug, Clone)]
+pub struct ChannelPairOptions {
+    /// Write each QR code to this PNG file instead of drawing it in the
+    /// terminal.
+    pub png: Option<PathBuf>,
+    /// Clear any stored session first and link a new device.
+    pub force: bool,
+    /// How long to wait for the phone to scan the QR code.
+    pub timeout: Duration,
+}
+
+impl Default for ChannelPairOptions {
+    fn default() -> Self {
+        Self {
+            png: None,
+            force: false,
+            timeout: Duration::from_secs(180),
+        }
+    }
+}
+
+/// Link a QR-paired channel alias to an account from the CLI, without
+/// starting the channel. Only WhatsApp Web pairs this way today.
+pub async fn pair_channel(
+    config: &Config,
+    channel: &str,
+    alias: &str,
+    options: ChannelPairOptions,
+) -> Result<()> {
+    match channel.trim().to_ascii_lowercase().as_str() {
+        "whatsapp" | "whatsapp-web" | "whatsapp_web" => {
+            Box::pin(pair_whatsapp_web(config, alias, options)).await
+        }
+        other => anyhow::bail!(
+            "Channel '{other}' does not pair by QR code. Supported: whatsapp (WhatsApp Web backend)"
+        ),
+    }
+}
+
+#[cfg(feature = "whatsapp-web")]
+async fn pair_whatsapp_web(
+    config: &Config,
+    alias: &str,
+    options: ChannelPairOptions,
+) -> Result<()> {
+    use crate::whatsapp_web::{PairingOutcome, PairingProgress};
+
+    let whatsapp = config.channels.whatsapp.get(alias).with_context(|| {
+        format!(
+            "No [channels.whatsapp.{alias}] section. Configure it first: \
+             zeroclaw config set channels.whatsapp.{alias}.session_path=~/.zeroclaw/state/whatsapp-web/{alias}/session.db"
+        )
+    })?;
+    let Some(session_path) = whatsapp
+        .session_path
+        .as_deref()
+        .filter(|path| !path.trim().is_empty())
+    else {
+        anyhow::bail!(
+            "channels.whatsapp.{alias} uses the Cloud API backend, which has no QR pairing. \
+             Set `session_path` to use WhatsApp Web."
+        );
+    };
+
+    if options.force {
+        let removed = WhatsAppWebChannel::clear_persisted_session(session_path)?;
+        if !removed.is_empty() {
+            println!("🧹 Cleared the stored session for whatsapp.{alias}");
+        }
+    } else if WhatsAppWebChannel::has_persisted_session(session_path) {
+        println!("🔎 Checking the stored session for whatsapp.{alias}...");
+    }
+
+    let png = options.png.clone();
+    let progress_alias = alias.to_string();
+    let outcome = WhatsAppWebChannel::pair_session(
+        session_path,
+        whatsapp.ws_url.as_deref(),
+        options.timeout,
+        move |progress| match progress {
+            PairingProgress::Qr(code) => print_whatsapp_pairing_qr(code, png.as_deref()),
+            PairingProgress::SessionExpired => println!(
+                "⚠️ WhatsApp rejected the stored session for whatsapp.{progress_alias} \
+                 (unlinked from the phone or expired). Starting a new pairing."
+            ),
+        },
+    )
+    .await?;
+
+    match outcome {
+        PairingOutcome::AlreadyLinked(device) => {
+            println!(
+                "✅ whatsapp.{alias} is already linked to {}. Use --force to link a different phone.",
+                device.describe()
+            );
+        }
+        PairingOutcome::Linked(device) => {
+            println!("✅ Linked whatsapp.{alias} to {}", device.describe());
+            println!("   Session saved to {session_path} (owner-only permissions)");
+            match maybe_restart_managed_daemon_service() {
+                Ok(true) => {
+                    println!("🔄 Detected running managed daemon service; reloaded automatically.");
+                }
+                Ok(false) => {
+                    println!(
+                        "ℹ️ Start the channel with `zeroclaw channel start` or `zeroclaw daemon`."
+                    );
+                }
+                Err(e) => {
+                    eprintln!(
+                        "⚠️ Session saved, but failed to reload daemon service automatically: {e}\n\
+                         Restart service manually with `zeroclaw service stop && zeroclaw service start`."
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "whatsapp-web")]
+fn print_whatsapp_pairing_qr(code: &str, png: Option<&Path>) {
+    if let Some(path) = png {
+        match WhatsAppWebChannel::write_pairing_qr_png(code, path) {
+            Ok(()) => {
+                println!(
+                    "📷 QR code written to {} — open it and scan with WhatsApp > Linked Devices. \
+                     It refreshes about every 20 seconds.",
+                    path.display()
+                );
+                return;
+            }
+            Err(e) => eprintln!("⚠️ {e:#}; showing the QR code here instead."),
+        }
+    }
+    match WhatsAppWebChannel::render_pairing_qr(code) {
+        Ok(rendered) => {
+            println!();
+            println!("Scan with WhatsApp > Linked Devices > Link a device:");
+            println!("{rendered}");
+        }
+        Err(_) => println!("WhatsApp Web QR payload: {code}"),
+    }
+}
+
+#[cfg(not(feature = "whatsapp-web"))]
+async fn pair_whatsapp_web(
+    _config: &Config,
+    _alias: &str,
+    _options: ChannelPairOptions,
+) -> Result<()> {
+    anyhow::bail!(
+        "WhatsApp Web pairing requires the `whatsapp-web` feature. \
+         Rebuild with `--features whatsapp-web`."
+    )
+}
+
 fn maybe_restart_managed_daemon_service() -> Result<bool> {
     if cfg!(target_os = "macos") {
         let home = directories::UserDirs::new()
@@ -7319,11 +8144,16 @@ fn build_channel_by_id(
                 .with_persistence(config_arc.clone())
                 .with_api_base(tg.api_base_url.clone())
                 .with_ack_reactions(ack)
+                .with_reaction_feedback(config.channels.feedback.enabled)
                 .with_streaming(tg.stream_mode, tg.draft_update_interval_ms)
                 .with_transcription(config.transcription.clone())
                 .with_tts(&config)
                 .with_workspace_dir(workspace_dir)
-                .with_approval_timeout_secs(tg.approval_timeout_secs),
+                .with_image_limits(crate::inbound_media::InboundImageLimits::from_config(
+                    &config.multimodal,
+                ))
+                .with_approval_timeout_secs(tg.approval_timeout_secs)
+                .with_allowed_topics(tg.allowed_topics.clone()),
             ))
         }
         #[cfg(not(feature = "channel-telegram"))]
@@ -7355,6 +8185,9 @@ fn build_channel_by_id(
                 )
                 .with_channel_ids(dc.channel_ids.clone())
                 .with_workspace_dir(workspace_dir)
+                .with_image_limits(crate::inbound_media::InboundImageLimits::from_config(
+                    &config.multimodal,
+                ))
                 .with_streaming(
                     dc.stream_mode,
                     dc.draft_update_interval_ms,
@@ -7364,7 +8197,9 @@ fn build_channel_by_id(
                 .with_stall_timeout(dc.stall_timeout_secs)
                 .with_approval_timeout_secs(dc.approval_timeout_secs)
                 .with_intents_mask(dc.intents_mask)
-                .with_reaction_notifications(dc.reaction_notifications),
+                .with_reaction_notifications(dc.reaction_notifications)
+                .with_edit_redispatch(dc.respond_to_edits, dc.edit_window_secs)
+                .with_reply_context(dc.reply_context),
             ))
         }
         #[cfg(not(feature = "channel-discord"))]
@@ -7425,6 +8260,7 @@ fn build_channel_by_id(
                 let alias = alias.clone();
                 Arc::new(move || cfg_arc.read().channel_external_peers("mattermost", &alias))
             };
+            let workspace_dir = one_shot_channel_workspace_dir(&config, "mattermost", &alias);
             Ok(Arc::new(
                 MattermostChannel::new(
                     mm.url.clone(),
@@ -7439,7 +8275,9 @@ fn build_channel_by_id(
                 )
                 .with_team_ids(mm.team_ids.clone())
                 .with_discover_dms(mm.discover_dms.unwrap_or(true))
-                .with_listen_mode(mm.listen_mode),
+                .with_listen_mode(mm.listen_mode)
+                .with_streaming(mm.stream_drafts, mm.draft_update_interval_ms)
+                .with_workspace_dir(workspace_dir),
             ))
         }
         #[cfg(not(feature = "channel-mattermost"))]
@@ -7459,6 +8297,7 @@ fn build_channel_by_id(
                 let alias = alias.clone();
                 Arc::new(move || cfg_arc.read().channel_external_peers("signal", &alias))
             };
+            let workspace_dir = one_shot_channel_workspace_dir(&config, "signal", &alias);
             Ok(Arc::new(
                 SignalChannel::new(
                     sg.http_url.clone(),
@@ -7470,6 +8309,8 @@ fn build_channel_by_id(
                     sg.ignore_attachments,
                     sg.ignore_stories,
                 )
+                .with_mention_only(sg.mention_only)
+                .with_workspace_dir(workspace_dir)
                 .with_approval_timeout_secs(sg.approval_timeout_secs),
             ))
         }
@@ -7626,7 +8467,8 @@ fn build_channel_by_id(
                     alias,
                     peer_resolver,
                 )
-                .with_proxy_url(dt.proxy_url.clone()),
+                .with_proxy_url(dt.proxy_url.clone())
+                .with_rich_text(dt.rich_text),
             ))
         }
         #[cfg(not(feature = "channel-dingtalk"))]
@@ -7850,11 +8692,11 @@ fn build_channel_by_id(
                 let alias = alias.clone();
                 Arc::new(move || cfg_arc.read().channel_external_peers("email", &alias))
             };
-            Ok(Arc::new(EmailChannel::new(
-                em.clone(),
-                alias,
-                peer_resolver,
-            )))
+            Ok(Arc::new(
+                EmailChannel::new(em.clone(), alias, peer_resolver).with_workspace_dir(
+                    one_shot_channel_workspace_dir(&config, "email", "default"),
+                ),
+            ))
         }
         #[cfg(not(feature = "channel-email"))]
         "email" => {
@@ -7896,20 +8738,26 @@ fn build_channel_by_id(
                 let alias = alias.clone();
                 Arc::new(move || cfg_arc.read().channel_external_peers("irc", &alias))
             };
-            Ok(Arc::new(IrcChannel::new(crate::irc::IrcChannelConfig {
-                server: irc_cfg.server.clone(),
-                port: irc_cfg.port,
-                nickname: irc_cfg.nickname.clone(),
-                username: irc_cfg.username.clone(),
-                channels: irc_cfg.channels.clone(),
-                alias,
-                peer_resolver,
-                server_password: irc_cfg.server_password.clone(),
-                nickserv_password: irc_cfg.nickserv_password.clone(),
-                sasl_password: irc_cfg.sasl_password.clone(),
-                verify_tls: irc_cfg.verify_tls.unwrap_or(true),
-                mention_only: irc_cfg.mention_only,
-            })))
+            Ok(Arc::new(
+                IrcChannel::new(crate::irc::IrcChannelConfig {
+                    server: irc_cfg.server.clone(),
+                    port: irc_cfg.port,
+                    nickname: irc_cfg.nickname.clone(),
+                    username: irc_cfg.username.clone(),
+                    channels: irc_cfg.channels.clone(),
+                    alias,
+                    peer_resolver,
+                    server_password: irc_cfg.server_password.clone(),
+                    nickserv_password: irc_cfg.nickserv_password.clone(),
+                    sasl_password: irc_cfg.sasl_password.clone(),
+                    verify_tls: irc_cfg.verify_tls.unwrap_or(true),
+                    mention_only: irc_cfg.mention_only,
+                })
+                .with_client_cert(
+                    irc_cfg.client_cert_path.clone(),
+                    irc_cfg.client_key_path.clone(),
+                ),
+            ))
         }
         #[cfg(not(feature = "channel-irc"))]
         "irc" => {
@@ -8008,6 +8856,32 @@ fn build_channel_by_id(
         "mochat" => {
             anyhow::bail!("Mochat channel requires the `channel-mochat` feature");
         }
+        #[cfg(feature = "channel-zulip")]
+        "zulip" => {
+            let zc = config
+                .channels
+                .zulip
+                .get("default")
+                .context("Zulip channel is not configured")?;
+            let alias = "default".to_string();
+            let peer_resolver: Arc<dyn Fn() -> Vec<String> + Send + Sync> = {
+                let cfg_arc = config_arc.clone();
+                let alias = alias.clone();
+                Arc::new(move || cfg_arc.read().channel_external_peers("zulip", &alias))
+            };
+            Ok(Arc::new(ZulipChannel::new(
+                zc.site_url.clone(),
+                zc.bot_email.clone(),
+                zc.api_key.clone(),
+                zc.streams.clone(),
+                alias,
+                peer_resolver,
+            )))
+        }
+        #[cfg(not(feature = "channel-zulip"))]
+        "zulip" => {
+            anyhow::bail!("Zulip channel requires the `channel-zulip` feature");
+        }
         #[cfg(feature = "channel-imessage")]
         "imessage" => {
             if !config.channels.imessage.contains_key("default") {
@@ -8019,7 +8893,14 @@ fn build_channel_by_id(
                 let alias = alias.clone();
                 Arc::new(move || cfg_arc.read().channel_external_peers("imessage", &alias))
             };
-            Ok(Arc::new(IMessageChannel::new(alias, peer_resolver)))
+            let im = &config.channels.imessage["default"];
+            Ok(Arc::new(
+                IMessageChannel::new(alias, peer_resolver)
+                    .with_group_ids(im.group_ids.clone())
+                    .with_image_limits(crate::inbound_media::InboundImageLimits::from_config(
+                        &config.multimodal,
+                    )),
+            ))
         }
         #[cfg(not(feature = "channel-imessage"))]
         "imessage" => {
@@ -8081,7 +8962,7 @@ fn build_channel_by_id(
         other => anyhow::bail!(
             "Unknown channel '{other}'. Supported: telegram, discord, slack, mattermost, signal, \
             matrix, whatsapp, qq, lark, feishu, dingtalk, wecom, wecom_ws, nextcloud_talk, wati, linq, \
-            email, gmail_push, git, irc, twitter, mochat, imessage, line, voice-call"
+            email, gmail_push, git, irc, twitter, mochat, zulip, imessage, line, voice-call"
         ),
     }
 }
@@ -8106,13 +8987,67 @@ pub async fn send_channel_message(
     Ok(())
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ChannelHealthState {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChannelHealthState {
     Healthy,
     Unhealthy,
     Timeout,
 }
 
+/// Options for `zeroclaw channel doctor`.
+#[derive(Debug, Clone)]
+pub struct ChannelDoctorOptions {
+    /// Print a JSON array of [`ChannelHealthEntry`] instead of the human report.
+    pub json: bool,
+    /// Probe only the channel matching this name (`telegram`, `telegram.alerts`,
+    /// or the display name), case-insensitively.
+    pub channel: Option<String>,
+    /// Per-channel health-check timeout.
+    pub timeout: Duration,
+}
+
+impl Default for ChannelDoctorOptions {
+    fn default() -> Self {
+        Self {
+            json: false,
+            channel: None,
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// One probed channel in the `channel doctor --json` output.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChannelHealthEntry {
+    /// Registry key: `<name>.<alias>`, or the bare name for singletons.
+    pub channel: String,
+    pub state: ChannelHealthState,
+    pub latency_ms: u64,
+    pub hint: Option<String>,
+}
+
+/// Result of a `channel doctor` run.
+#[derive(Debug, Clone, Default)]
+pub struct ChannelDoctorReport {
+    pub entries: Vec<ChannelHealthEntry>,
+}
+
+impl ChannelDoctorReport {
+    /// Process exit code for CI/cron: 0 when every channel is healthy, 2 when
+    /// any is unhealthy, otherwise 3 when any timed out.
+    pub fn exit_code(&self) -> i32 {
+        let any = |state| self.entries.iter().any(|e| e.state == state);
+        if any(ChannelHealthState::Unhealthy) {
+            2
+        } else if any(ChannelHealthState::Timeout) {
+            3
+        } else {
+            0
+        }
+    }
+}
+
 fn classify_health_result(
     result: &std::result::Result<bool, tokio::time::error::Elapsed>,
 ) -> ChannelHealthState {
@@ -8462,6 +9397,7 @@ fn collect_configured_channels(
                     .with_persistence(config_arc.clone())
                     .with_api_base(tg.api_base_url.clone())
                     .with_ack_reactions(ack)
+                    .with_reaction_feedback(config.channels.feedback.enabled)
                     .with_streaming(tg.stream_mode, tg.draft_update_interval_ms)
                     .with_transcription(config.transcription.clone())
                     .with_agent_transcription_provider(agent_transcription_provider.clone())
@@ -8471,9 +9407,13 @@ fn collect_configured_channels(
                     )
                     .with_tts(&config)
                     .with_workspace_dir(config.channel_workspace_dir(&format!("telegram.{alias}")))
+                    .with_image_limits(crate::inbound_media::InboundImageLimits::from_config(
+                        &config.multimodal,
+                    ))
                     .with_proxy_url(tg.proxy_url.clone())
                     .with_tool_command_specs(tool_specs.to_vec())
-                    .with_approval_timeout_secs(tg.approval_timeout_secs),
+                    .with_approval_timeout_secs(tg.approval_timeout_secs)
+                    .with_allowed_topics(tg.allowed_topics.clone()),
                 ),
                 tg,
             ),
@@ -8514,6 +9454,9 @@ fn collect_configured_channels(
         )
         .with_channel_ids(dc.channel_ids.clone())
         .with_workspace_dir(config.channel_workspace_dir(&format!("discord.{alias}")))
+        .with_image_limits(crate::inbound_media::InboundImageLimits::from_config(
+            &config.multimodal,
+        ))
         .with_streaming(
             dc.stream_mode,
             dc.draft_update_interval_ms,
@@ -8526,7 +9469,9 @@ fn collect_configured_channels(
         .with_slash_commands(dc.slash_commands)
         .with_slash_command_scope(dc.slash_command_scope)
         .with_intents_mask(dc.intents_mask)
-        .with_reaction_notifications(dc.reaction_notifications);
+        .with_reaction_notifications(dc.reaction_notifications)
+        .with_edit_redispatch(dc.respond_to_edits, dc.edit_window_secs)
+        .with_reply_context(dc.reply_context);
         if dc.slash_commands {
             let cfg_arc_for_slash = config_arc.clone();
             let channel_ref = format!("discord.{alias}");
@@ -8677,7 +9622,11 @@ fn collect_configured_channels(
                     .with_discover_dms(mm.discover_dms.unwrap_or(true))
                     .with_proxy_url(mm.proxy_url.clone())
                     .with_transcription(config.transcription.clone())
-                    .with_listen_mode(mm.listen_mode),
+                    .with_listen_mode(mm.listen_mode)
+                    .with_streaming(mm.stream_drafts, mm.draft_update_interval_ms)
+                    .with_workspace_dir(
+                        config.channel_workspace_dir(&format!("mattermost.{alias}")),
+                    ),
                 ),
                 mm,
             ),
@@ -8703,7 +9652,6 @@ fn collect_configured_channels(
         if !im.enabled {
             continue;
         }
-        let _ = im;
         let peer_resolver: Arc<dyn Fn() -> Vec<String> + Send + Sync> = {
             let cfg_arc = config_arc.clone();
             let alias = alias.clone();
@@ -8713,7 +9661,13 @@ fn collect_configured_channels(
             display_name: "iMessage",
             alias: Some(alias.clone()),
             channel: crate::paced_channel::PacedChannel::wrap(
-                Arc::new(IMessageChannel::new(alias.clone(), peer_resolver)),
+                Arc::new(
+                    IMessageChannel::new(alias.clone(), peer_resolver)
+                        .with_group_ids(im.group_ids.clone())
+                        .with_image_limits(crate::inbound_media::InboundImageLimits::from_config(
+                            &config.multimodal,
+                        )),
+                ),
                 im,
             ),
         });
@@ -8811,6 +9765,8 @@ fn collect_configured_channels(
                         sig.ignore_stories,
                     )
                     .with_proxy_url(sig.proxy_url.clone())
+                    .with_mention_only(sig.mention_only)
+                    .with_workspace_dir(config.channel_workspace_dir(&format!("signal.{alias}")))
                     .with_approval_timeout_secs(sig.approval_timeout_secs),
                 ),
                 sig,
@@ -9115,7 +10071,8 @@ fn collect_configured_channels(
                 let alias = alias.clone();
                 Arc::new(move || cfg_arc.read().channel_external_peers("email", &alias))
             };
-            let mut channel = EmailChannel::new(email_cfg.clone(), alias.clone(), peer_resolver);
+            let mut channel = EmailChannel::new(email_cfg.clone(), alias.clone(), peer_resolver)
+                .with_workspace_dir(config.channel_workspace_dir(&format!("email.{alias}")));
             if email_cfg.oauth2.is_some() {
                 channel = channel.with_auth_service(auth_service.clone());
             }
@@ -9178,20 +10135,23 @@ fn collect_configured_channels(
         channels.push(ConfiguredChannel {
             display_name: "IRC",
             alias: Some(alias.clone()),
-            channel: Arc::new(IrcChannel::new(crate::irc::IrcChannelConfig {
-                server: irc.server.clone(),
-                port: irc.port,
-                nickname: irc.nickname.clone(),
-                username: irc.username.clone(),
-                channels: irc.channels.clone(),
-                alias: alias.clone(),
-                peer_resolver,
-                server_password: irc.server_password.clone(),
-                nickserv_password: irc.nickserv_password.clone(),
-                sasl_password: irc.sasl_password.clone(),
-                verify_tls: irc.verify_tls.unwrap_or(true),
-                mention_only: irc.mention_only,
-            })),
+            channel: Arc::new(
+                IrcChannel::new(crate::irc::IrcChannelConfig {
+                    server: irc.server.clone(),
+                    port: irc.port,
+                    nickname: irc.nickname.clone(),
+                    username: irc.username.clone(),
+                    channels: irc.channels.clone(),
+                    alias: alias.clone(),
+                    peer_resolver,
+                    server_password: irc.server_password.clone(),
+                    nickserv_password: irc.nickserv_password.clone(),
+                    sasl_password: irc.sasl_password.clone(),
+                    verify_tls: irc.verify_tls.unwrap_or(true),
+                    mention_only: irc.mention_only,
+                })
+                .with_client_cert(irc.client_cert_path.clone(), irc.client_key_path.clone()),
+            ),
         });
     }
 
@@ -9417,7 +10377,8 @@ fn collect_configured_channels(
                     alias.clone(),
                     peer_resolver,
                 )
-                .with_proxy_url(dt.proxy_url.clone()),
+                .with_proxy_url(dt.proxy_url.clone())
+                .with_rich_text(dt.rich_text),
             ),
         });
     }
@@ -9591,6 +10552,44 @@ fn collect_configured_channels(
         );
     }
 
+    #[cfg(feature = "channel-zulip")]
+    for (alias, zc) in &config.channels.zulip {
+        if !active_channel_aliases.contains(&format!("zulip.{alias}")) {
+            continue;
+        }
+        if !zc.enabled {
+            continue;
+        }
+        let peer_resolver: Arc<dyn Fn() -> Vec<String> + Send + Sync> = {
+            let cfg_arc = config_arc.clone();
+            let alias = alias.clone();
+            Arc::new(move || cfg_arc.read().channel_external_peers("zulip", &alias))
+        };
+        channels.push(ConfiguredChannel {
+            display_name: "Zulip",
+            alias: Some(alias.clone()),
+            channel: Arc::new(ZulipChannel::new(
+                zc.site_url.clone(),
+                zc.bot_email.clone(),
+                zc.api_key.clone(),
+                zc.streams.clone(),
+                alias.clone(),
+                peer_resolver,
+            )),
+        });
+    }
+
+    #[cfg(not(feature = "channel-zulip"))]
+    if !config.channels.zulip.is_empty() {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Unknown),
+            "Zulip channel is configured but this build was compiled without \
+             `channel-zulip`; skipping Zulip."
+        );
+    }
+
     #[cfg(feature = "channel-wecom")]
     for (alias, wc) in &config.channels.wecom {
         if !active_channel_aliases.contains(&format!("wecom.{alias}")) {
@@ -9984,12 +10983,99 @@ fn collect_configured_channels(
     channels
 }
 
+/// Whether images can go to the routed model inline. The provider's own
+/// vision capability is the ceiling; the capability registry lowers it for a
+/// model it knows is text-only unless config forces `vision = true`.
+fn channel_model_accepts_images(
+    ctx: &ChannelRuntimeContext,
+    route: &ChannelRouteSelection,
+) -> bool {
+    if !ctx.model_provider.supports_vision() {
+        return false;
+    }
+    zeroclaw_providers::capabilities::resolve(
+        &ctx.prompt_config,
+        &route.model_provider,
+        &route.model,
+    )
+    .supports_vision
+    .is_none_or(|vision| vision.value)
+}
+
+/// Proactive history-trim budget for a channel agent: the runtime profile's
+/// `max_context_tokens`, lowered to fit the model's real context window when
+/// one is known (a configured `context_window`, the `n_ctx` a llama.cpp
+/// server reports, or the model capability registry) minus the profile's
+/// `response_reserve_tokens`, so history is compacted before the provider
+/// rejects it.
+async fn channel_context_budget(
+    config: &Config,
+    agent_alias: &str,
+    max_context_tokens: usize,
+) -> usize {
+    let Some((provider_type, provider_alias, provider)) =
+        config.resolved_model_provider_for_agent(agent_alias)
+    else {
+        return max_context_tokens;
+    };
+    let window = match provider.context_window {
+        Some(window) => Some(window),
+        None if provider_type == "llamacpp" => {
+            let probed = zeroclaw_providers::fetch_context_window(provider_type, provider).await;
+            if let Some(n_ctx) = probed {
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                        .with_attrs(::serde_json::json!({"agent": agent_alias, "n_ctx": n_ctx})),
+                    "using llama.cpp server context window for history budget"
+                );
+            }
+            probed
+        }
+        None => provider.model.as_deref().and_then(|model| {
+            zeroclaw_providers::capabilities::known(
+                &format!("{provider_type}.{provider_alias}"),
+                model,
+            )
+            .context_window
+        }),
+    };
+    clamp_context_budget(
+        max_context_tokens,
+        window,
+        config.effective_response_reserve_tokens(agent_alias),
+    )
+}
+
+/// Cap `max_context_tokens` at `window` minus `reserve` tokens for the reply.
+/// Without a reserve, 90% of `window` — the same headroom the runtime's
+/// overflow recovery trims to.
+fn clamp_context_budget(
+    max_context_tokens: usize,
+    window: Option<usize>,
+    reserve: Option<usize>,
+) -> usize {
+    match window {
+        Some(window) if window > 0 => {
+            let reserve = reserve.unwrap_or(window - window * 9 / 10);
+            // A zero budget disables trimming; a reserve that eats the
+            // whole window should trim hardest instead.
+            max_context_tokens.min(window.saturating_sub(reserve).max(1))
+        }
+        _ => max_context_tokens,
+    }
+}
+
 fn no_real_time_channels_message() -> &'static str {
     "No real-time channels configured. Run `zeroclaw quickstart` to set one up."
 }
 
 /// Run health checks for configured channels.
-pub async fn doctor_channels(config: Config) -> Result<()> {
+pub async fn doctor_channels(
+    config: Config,
+    options: ChannelDoctorOptions,
+) -> Result<ChannelDoctorReport> {
     let config_arc = Arc::new(RwLock::new(config));
     #[allow(unused_mut)]
     let mut channels = collect_configured_channels(&config_arc, "health check", &[], None, None);
@@ -10045,49 +11131,117 @@ pub async fn doctor_channels(config: Config) -> Result<()> {
         }
     }
 
+    if let Some(wanted) = options.channel.as_deref() {
+        channels.retain(|configured| channel_matches_filter(configured, wanted));
+        if channels.is_empty() {
+            anyhow::bail!(
+                "No configured channel matches `{wanted}`. Run `zeroclaw channel list` to see configured channels."
+            );
+        }
+    }
+
     if channels.is_empty() {
-        println!("{}", no_real_time_channels_message());
-        return Ok(());
+        if options.json {
+            println!("[]");
+        } else {
+            println!("{}", no_real_time_channels_message());
+        }
+        return Ok(ChannelDoctorReport::default());
     }
 
-    println!("🩺 ZeroClaw Channel Doctor");
-    println!();
+    if !options.json {
+        println!("🩺 ZeroClaw Channel Doctor");
+        println!();
+    }
 
+    let timeout_secs = options.timeout.as_secs();
+    let mut report = ChannelDoctorReport::default();
     let mut healthy = 0_u32;
     let mut unhealthy = 0_u32;
     let mut timeout = 0_u32;
 
     for configured in channels {
-        let result =
-            tokio::time::timeout(Duration::from_secs(10), configured.channel.health_check()).await;
+        let started = Instant::now();
+        let result = tokio::time::timeout(options.timeout, configured.channel.health_check()).await;
+        let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
         let state = classify_health_result(&result);
 
-        match state {
+        let hint = match state {
             ChannelHealthState::Healthy => {
                 healthy += 1;
-                println!("  ✅ {:<9} healthy", configured.display_name);
+                let detail = configured.channel.health_detail();
+                if !options.json {
+                    match &detail {
+                        Some(detail) => {
+                            println!("  ✅ {:<9} healthy ({detail})", configured.display_name);
+                        }
+                        None => println!("  ✅ {:<9} healthy", configured.display_name),
+                    }
+                }
+                detail
             }
             ChannelHealthState::Unhealthy => {
                 unhealthy += 1;
-                println!(
-                    "  ❌ {:<9} unhealthy (auth/config/network)",
-                    configured.display_name
-                );
+                let detail = configured.channel.health_detail();
+                if !options.json {
+                    match &detail {
+                        Some(detail) => {
+                            println!("  ❌ {:<9} unhealthy ({detail})", configured.display_name);
+                        }
+                        None => println!(
+                            "  ❌ {:<9} unhealthy (auth/config/network)",
+                            configured.display_name
+                        ),
+                    }
+                }
+                Some(detail.unwrap_or_else(|| {
+                    "check credentials, channel config, and network reachability".to_string()
+                }))
             }
             ChannelHealthState::Timeout => {
                 timeout += 1;
-                println!("  ⏱️  {:<9} timed out (>10s)", configured.display_name);
+                if !options.json {
+                    println!(
+                        "  ⏱️  {:<9} timed out (>{timeout_secs}s)",
+                        configured.display_name
+                    );
+                }
+                Some(format!(
+                    "no response within {timeout_secs}s; retry with a longer --timeout"
+                ))
             }
-        }
+        };
+
+        report.entries.push(ChannelHealthEntry {
+            channel: composite_channel_key(configured.channel.name(), configured.alias.as_deref()),
+            state,
+            latency_ms,
+            hint,
+        });
+    }
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&report.entries)?);
+        return Ok(report);
     }
 
-    if !config_arc.read().channels.webhook.is_empty() {
+    if options.channel.is_none() && !config_arc.read().channels.webhook.is_empty() {
         println!("  ℹ️  Webhook   check via `zeroclaw gateway` then GET /health");
     }
 
     println!();
     println!("Summary: {healthy} healthy, {unhealthy} unhealthy, {timeout} timed out");
-    Ok(())
+    Ok(report)
+}
+
+/// `--channel` filter: matches the channel's `name()`, its `<name>.<alias>`
+/// registry key, or its display name.
+fn channel_matches_filter(configured: &ConfiguredChannel, wanted: &str) -> bool {
+    let name = configured.channel.name();
+    let key = composite_channel_key(name, configured.alias.as_deref());
+    [name, key.as_str(), configured.display_name]
+        .iter()
+        .any(|candidate| candidate.eq_ignore_ascii_case(wanted.trim()))
 }
 
 fn build_owner_by_channel_key(
@@ -10349,6 +11503,7 @@ pub async fn start_channels(
     }
 
     zeroclaw_providers::pricing::spawn_refresher(config_arc.clone());
+    zeroclaw_providers::capabilities::load_catalog(&config.data_dir);
 
     let enabled_agents: Vec<String> = {
         let mut v: Vec<String> = config
@@ -10368,6 +11523,7 @@ pub async fn start_channels(
         Arc::from(observability::create_observer(&config.observability));
     let runtime: Arc<dyn platform::RuntimeAdapter> =
         Arc::from(platform::create_runtime(&config.runtime)?);
+    platform::prepare_runtime(&config.runtime).await?;
 
     // i18n is process-global; initialize once before the per-agent loop
     // touches tool descriptions.
@@ -10473,6 +11629,8 @@ pub async fn start_channels(
                 "ModelProvider warmup failed (non-fatal)"
             );
         }
+        let context_token_budget =
+            channel_context_budget(&config, agent_alias, agent.resolved.max_context_tokens).await;
 
         let security = Arc::new(SecurityPolicy::for_agent(&config, agent_alias)?);
         let mem: Arc<dyn Memory> = zeroclaw_memory::create_memory_for_agent(
@@ -10588,6 +11746,10 @@ pub async fn start_channels(
                 "Load the full source for an available skill by name. Use when: compact mode only shows a summary and you need the complete skill instructions.",
             ));
         }
+        tool_descs.push((
+            "skill_invoke",
+            "Run an available skill with arguments. Use when: a skill declares <parameters>.",
+        ));
         if config.browser.enabled {
             tool_descs.push((
                 "browser_open",
@@ -10655,7 +11817,9 @@ pub async fn start_channels(
             &mut deferred_section,
             &pinned_section,
         );
-        let mut system_prompt = build_system_prompt_with_mode_and_autonomy(
+        let prompt_cache = Arc::new(PromptSectionCache::new());
+        let mut system_prompt = build_system_prompt_cached(
+            &prompt_cache,
             &workspace,
             &model,
             &tool_descs,
@@ -10845,8 +12009,23 @@ pub async fn start_channels(
                 .write()
                 .unwrap_or_else(|e| e.into_inner()) = Some(Arc::clone(&cbn));
 
-            let in_flight = max_in_flight_messages_for_config(channels.len(), &config.channels);
-            println!("  🚦 In-flight message limit: {in_flight}");
+            // Replies a channel fails to deliver wait in the outbox and are
+            // retried in the background (`[channels.outbox]`).
+            if config.channels.outbox.enabled {
+                let channel_outbox =
+                    Arc::new(zeroclaw_runtime::outbox::ChannelOutbox::new(&config));
+                zeroclaw_runtime::outbox::install(Some(Arc::clone(&channel_outbox)));
+                listener_handles.push(outbox::spawn_outbox_worker(
+                    channel_outbox,
+                    Arc::clone(&cbn),
+                    cancel.clone(),
+                ));
+            } else {
+                zeroclaw_runtime::outbox::install(None);
+            }
+
+            let in_flight = max_in_flight_messages_for_config(channels.len(), &config.channels);
+            println!("  🚦 In-flight message limit: {in_flight}");
 
             max_in_flight_messages = Some(in_flight);
             channels_by_name_shared = Some(cbn);
@@ -10972,7 +12151,7 @@ pub async fn start_channels(
             }),
             pacing: config.pacing.clone(),
             max_tool_result_chars: agent.resolved.max_tool_result_chars,
-            context_token_budget: agent.resolved.max_context_tokens,
+            context_token_budget,
             debouncer: Arc::new(zeroclaw_infra::debounce::MessageDebouncer::new(
                 Duration::from_millis(config.channels.debounce_ms),
             )),
@@ -10987,6 +12166,8 @@ pub async fn start_channels(
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: sop_engine.clone(),
             sop_audit: sop_audit.clone(),
+            prompt_cache,
+            translation: Arc::new(ChannelTranslation::new(fixed_channel_translator(&config))),
         });
 
         agent_ctxs.insert(agent_alias.clone(), runtime_ctx);
@@ -11085,7 +12266,13 @@ pub async fn start_channels(
     let rx = rx_holder.expect("rx initialized by first agent's channel setup");
     let max_in_flight =
         max_in_flight_messages.expect("max_in_flight initialized by first agent's channel setup");
-    run_message_dispatch_loop(rx, router, max_in_flight).await;
+    run_message_dispatch_loop(
+        rx,
+        router,
+        max_in_flight,
+        zeroclaw_runtime::admission::global(),
+    )
+    .await;
 
     for h in listener_handles {
         let _ = h.await;
@@ -11238,7 +12425,8 @@ pub async fn deliver_announcement(
                 peer_resolver,
                 sg.ignore_attachments,
                 sg.ignore_stories,
-            );
+            )
+            .with_workspace_dir(config.channel_workspace_dir(channel));
             zeroclaw_api::channel::Channel::send(&ch, &make_msg(&safe_output)).await?;
         }
         #[cfg(not(feature = "channel-signal"))]
@@ -11367,7 +12555,8 @@ pub async fn deliver_announcement(
             let peers = config.channel_external_peers("email", alias);
             let peer_resolver: Arc<dyn Fn() -> Vec<String> + Send + Sync> =
                 Arc::new(move || peers.clone());
-            let ch = EmailChannel::new(em.clone(), alias.to_string(), peer_resolver);
+            let ch = EmailChannel::new(em.clone(), alias.to_string(), peer_resolver)
+                .with_workspace_dir(config.channel_workspace_dir(&format!("email.{alias}")));
             zeroclaw_api::channel::Channel::send(&ch, &make_msg(&safe_output)).await?;
         }
         #[cfg(not(feature = "channel-email"))]
@@ -11535,6 +12724,8 @@ fn concurrent_persist_lock_serialization() {
         persist_locks: Arc::new(Mutex::new(HashMap::new())),
         sop_engine: None,
         sop_audit: None,
+        prompt_cache: Arc::new(PromptSectionCache::new()),
+        translation: Arc::new(ChannelTranslation::default()),
     });
     ctx.conversation_histories
         .lock()
@@ -12042,6 +13233,8 @@ temperature = 0.3
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         })
     }
 
@@ -12138,11 +13331,12 @@ temperature = 0.3
     }
 
     #[tokio::test]
-    async fn resolve_classifier_route_returns_none_for_empty_ref() {
+    async fn resolve_auxiliary_route_returns_none_for_empty_ref() {
         let ctx = router_test_ctx();
         let empty = zeroclaw_config::providers::ModelProviderRef::default();
-        let result = resolve_classifier_route(
+        let result = resolve_auxiliary_route(
             ctx.as_ref(),
+            "classifier_provider",
             &empty,
             &runtime_defaults_snapshot(ctx.as_ref()),
         )
@@ -12151,11 +13345,12 @@ temperature = 0.3
     }
 
     #[tokio::test]
-    async fn resolve_classifier_route_returns_none_for_unresolvable_ref() {
+    async fn resolve_auxiliary_route_returns_none_for_unresolvable_ref() {
         let ctx = router_test_ctx();
         let bogus = zeroclaw_config::providers::ModelProviderRef::from("custom.does-not-exist");
-        let result = resolve_classifier_route(
+        let result = resolve_auxiliary_route(
             ctx.as_ref(),
+            "classifier_provider",
             &bogus,
             &runtime_defaults_snapshot(ctx.as_ref()),
         )
@@ -12164,7 +13359,7 @@ temperature = 0.3
     }
 
     #[tokio::test]
-    async fn resolve_classifier_route_returns_alias_temperature() {
+    async fn resolve_auxiliary_route_returns_alias_temperature() {
         // Build a config where `openai.my-classifier` has `temperature = 0.0`.
         let mut cfg = zeroclaw_config::schema::Config::default();
         cfg.providers.models.openai.insert(
@@ -12185,8 +13380,9 @@ temperature = 0.3
         });
 
         let alias_ref = zeroclaw_config::providers::ModelProviderRef::from("openai.my-classifier");
-        let result = resolve_classifier_route(
+        let result = resolve_auxiliary_route(
             ctx.as_ref(),
+            "classifier_provider",
             &alias_ref,
             &runtime_defaults_snapshot(ctx.as_ref()),
         )
@@ -12669,6 +13865,8 @@ temperature = 0.3
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         }
     }
 
@@ -13059,11 +14257,11 @@ api_key = "anthropic-key"
             sender.clone(),
             (0..20)
                 .map(|idx| {
-                    let content = format!("msg-{idx}-{}", "x".repeat(700));
                     if idx % 2 == 0 {
-                        ChatMessage::user(content)
+                        ChatMessage::user(format!("msg-{idx}-{}", "x".repeat(700)))
                     } else {
-                        ChatMessage::assistant(content)
+                        // CJK replies: the old 600-char cap kept ~600 tokens.
+                        ChatMessage::assistant(format!("msg-{idx}-{}", "回复".repeat(400)))
                     }
                 })
                 .collect::<Vec<_>>(),
@@ -13141,6 +14339,8 @@ api_key = "anthropic-key"
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         };
 
         assert!(compact_sender_history(&ctx, &sender));
@@ -13154,10 +14354,8 @@ api_key = "anthropic-key"
             .expect("sender history should remain");
         assert_eq!(kept.len(), CHANNEL_HISTORY_COMPACT_KEEP_MESSAGES);
         assert!(kept.iter().all(|turn| {
-            let len = turn.content.chars().count();
-            len <= CHANNEL_HISTORY_COMPACT_CONTENT_CHARS
-                || (len <= CHANNEL_HISTORY_COMPACT_CONTENT_CHARS + 3
-                    && turn.content.ends_with("..."))
+            let tokens = zeroclaw_providers::tokens::estimate_tokens(&turn.content);
+            turn.content.ends_with("...") && tokens <= CHANNEL_HISTORY_COMPACT_CONTENT_TOKENS + 1
         }));
     }
 
@@ -13238,6 +14436,8 @@ api_key = "anthropic-key"
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         };
 
         append_sender_turn(&ctx, &sender, ChatMessage::user("hello"));
@@ -13353,6 +14553,8 @@ api_key = "anthropic-key"
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         };
 
         assert!(rollback_orphan_user_turn(&ctx, &sender, "pending"));
@@ -13472,6 +14674,8 @@ api_key = "anthropic-key"
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         };
 
         assert!(rollback_orphan_user_turn(
@@ -13626,6 +14830,11 @@ api_key = "anthropic-key"
         sent_messages: tokio::sync::Mutex<Vec<String>>,
     }
 
+    #[derive(Default)]
+    struct DiscordRecordingChannel {
+        sent_messages: tokio::sync::Mutex<Vec<String>>,
+    }
+
     impl ::zeroclaw_api::attribution::Attributable for TelegramRecordingChannel {
         fn role(&self) -> ::zeroclaw_api::attribution::Role {
             ::zeroclaw_api::attribution::Role::Channel(
@@ -13749,6 +14958,47 @@ api_key = "anthropic-key"
         }
     }
 
+    impl ::zeroclaw_api::attribution::Attributable for DiscordRecordingChannel {
+        fn role(&self) -> ::zeroclaw_api::attribution::Role {
+            ::zeroclaw_api::attribution::Role::Channel(
+                ::zeroclaw_api::attribution::ChannelKind::Webhook,
+            )
+        }
+        fn alias(&self) -> &str {
+            "test"
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Channel for DiscordRecordingChannel {
+        fn name(&self) -> &str {
+            "discord"
+        }
+
+        async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
+            self.sent_messages
+                .lock()
+                .await
+                .push(format!("{}:{}", message.recipient, message.content));
+            Ok(())
+        }
+
+        async fn listen(
+            &self,
+            _tx: tokio::sync::mpsc::Sender<zeroclaw_api::channel::ChannelMessage>,
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        async fn start_typing(&self, _recipient: &str) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        async fn stop_typing(&self, _recipient: &str) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
     impl ::zeroclaw_api::attribution::Attributable for RecordingChannel {
         fn role(&self) -> ::zeroclaw_api::attribution::Role {
             ::zeroclaw_api::attribution::Role::Channel(
@@ -14047,6 +15297,8 @@ api_key = "anthropic-key"
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         })
     }
 
@@ -14115,6 +15367,106 @@ api_key = "anthropic-key"
         );
     }
 
+    /// Records every call and tags text with its target language. Anything
+    /// containing "Hallo" is reported as German.
+    #[derive(Default)]
+    struct RecordingTranslator {
+        calls: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Translator for RecordingTranslator {
+        async fn translate(
+            &self,
+            text: &str,
+            target: &str,
+        ) -> anyhow::Result<crate::translation::Translated> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("translate {target}: {text}"));
+            let source = if text.contains("Hallo") { "de" } else { "en" };
+            Ok(crate::translation::Translated {
+                text: format!("[{target}] {text}"),
+                source_lang: Some(source.to_string()),
+            })
+        }
+
+        async fn detect(&self, text: &str) -> anyhow::Result<String> {
+            self.calls.lock().unwrap().push(format!("detect: {text}"));
+            Ok("de".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn process_channel_message_translates_inbound_before_the_model_and_reply_after() {
+        let channel_impl = Arc::new(RecordingChannel::default());
+        let channel: Arc<dyn Channel> = channel_impl.clone();
+        let provider_impl = Arc::new(HistoryCaptureModelProvider::default());
+        let translator = Arc::new(RecordingTranslator::default());
+
+        let mut config = zeroclaw_config::schema::Config::default();
+        config.channels.telegram.insert(
+            "main".to_string(),
+            zeroclaw_config::schema::TelegramConfig {
+                translate: Some(zeroclaw_config::schema::ChannelTranslateConfig {
+                    inbound_to: "en".to_string(),
+                    outbound_to: crate::translation::AUTO_DETECT_SENDER.to_string(),
+                }),
+                ..Default::default()
+            },
+        );
+        let base_ctx = test_runtime_ctx_with_config_agent_and_provider_ref(
+            Arc::clone(&channel),
+            provider_impl.clone(),
+            config,
+            zeroclaw_config::schema::AliasedAgentConfig::default(),
+            "test-provider",
+            None,
+        );
+        let runtime_ctx = Arc::new(ChannelRuntimeContext {
+            channels_by_name: Arc::new(HashMap::from([("telegram.main".to_string(), channel)])),
+            translation: Arc::new(ChannelTranslation::new(Some(translator.clone()))),
+            ..(*base_ctx).clone()
+        });
+
+        process_channel_message(
+            runtime_ctx,
+            zeroclaw_api::channel::ChannelMessage {
+                id: "msg-1".to_string(),
+                sender: "hans".to_string(),
+                reply_target: "chat-7".to_string(),
+                content: "Hallo zusammen".to_string(),
+                channel: "telegram".into(),
+                channel_alias: Some("main".to_string()),
+                timestamp: 1,
+                ..Default::default()
+            },
+            CancellationToken::new(),
+        )
+        .await;
+
+        // Inbound first, then the reply; the cached sender language means
+        // no separate detect call.
+        assert_eq!(
+            translator.calls.lock().unwrap().as_slice(),
+            [
+                "translate en: Hallo zusammen".to_string(),
+                "translate de: response-1".to_string(),
+            ]
+        );
+        let calls = provider_impl
+            .calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let user_turn = &calls[0].last().unwrap().1;
+        assert!(user_turn.contains("[en] Hallo zusammen"), "{user_turn}");
+        drop(calls);
+
+        let sent_messages = channel_impl.sent_messages.lock().await;
+        assert_eq!(sent_messages.as_slice(), ["chat-7:[de] response-1"]);
+    }
+
     /// Serializes tests that interact with the process-wide model-switch
     /// request (`MODEL_SWITCH_REQUEST` in zeroclaw-runtime's agent loop).
     /// While a pending request exists, ANY concurrently running
@@ -14236,8 +15588,9 @@ api_key = "anthropic-key"
     /// Discord, ...) never emitted `AgentStart`/`AgentEnd`, so
     /// `/api/events/history` showed `llm_request` frames but no turn
     /// lifecycle brackets. A successful turn must emit exactly one
-    /// `AgentStart` (before the LLM request) and one `AgentEnd` (last),
-    /// all sharing one `turn_id` and carrying the channel + agent alias.
+    /// `AgentStart` (before the LLM request) and one `AgentEnd` (last of the
+    /// turn, inside the channel message trace), all sharing one `turn_id` and
+    /// carrying the channel + agent alias.
     #[tokio::test]
     async fn process_channel_message_brackets_turn_with_agent_start_and_agent_end() {
         // The exactly-one-AgentStart assertion is sensitive to a leaked
@@ -14282,9 +15635,27 @@ api_key = "anthropic-key"
             start_pos < llm_request_pos,
             "AgentStart must precede the LlmRequest: {events:?}"
         );
+        let end_pos = events
+            .iter()
+            .position(|e| matches!(e, ObserverEvent::AgentEnd { .. }))
+            .unwrap();
         assert!(
-            matches!(events.last(), Some(ObserverEvent::AgentEnd { .. })),
-            "AgentEnd must be the last event: {events:?}"
+            events[end_pos + 1..].iter().all(|e| matches!(
+                e,
+                ObserverEvent::ChannelSend { .. } | ObserverEvent::ChannelMessageEnd { .. }
+            )),
+            "AgentEnd must be the last turn event: {events:?}"
+        );
+        let Some(ObserverEvent::ChannelReceive {
+            turn_id: message_turn_id,
+            ..
+        }) = events.first()
+        else {
+            panic!("ChannelReceive must open the message trace: {events:?}");
+        };
+        assert!(
+            matches!(events.last(), Some(ObserverEvent::ChannelMessageEnd { .. })),
+            "ChannelMessageEnd must close the message trace: {events:?}"
         );
 
         let (start_channel, start_alias, start_turn_id) = starts[0].clone();
@@ -14295,6 +15666,11 @@ api_key = "anthropic-key"
         assert_eq!(end_alias.as_deref(), Some("test-agent"));
         assert!(start_turn_id.is_some(), "AgentStart must carry a turn_id");
         assert_eq!(start_turn_id, end_turn_id, "brackets must share a turn_id");
+        assert_eq!(
+            start_turn_id.as_deref(),
+            Some(message_turn_id.as_str()),
+            "the turn must carry the message trace's turn_id"
+        );
 
         let llm_request_turn_id = events.iter().find_map(|e| match e {
             ObserverEvent::LlmRequest { turn_id, .. } => Some(turn_id.clone()),
@@ -14883,6 +16259,59 @@ BTC is currently around $65,000 based on latest tool output."#
         }
     }
 
+    #[test]
+    fn channel_image_gate_consults_the_capability_registry() {
+        let channel: Arc<dyn Channel> = Arc::new(RecordingChannel::default());
+        let provider: Arc<dyn ModelProvider> = Arc::new(HistoryCaptureModelProvider {
+            vision: true,
+            ..Default::default()
+        });
+        let mut config = zeroclaw_config::schema::Config::default();
+        config.providers.models.openai.insert(
+            "forced".to_string(),
+            zeroclaw_config::schema::OpenAIModelProviderConfig {
+                base: zeroclaw_config::schema::ModelProviderConfig {
+                    vision: Some(true),
+                    ..Default::default()
+                },
+            },
+        );
+        let ctx = test_runtime_ctx_with_config_agent_and_provider_ref(
+            channel,
+            provider,
+            config,
+            zeroclaw_config::schema::AliasedAgentConfig::default(),
+            "test-provider",
+            None,
+        );
+        let route = |model_provider: &str, model: &str| ChannelRouteSelection {
+            model_provider: model_provider.to_string(),
+            model: model.to_string(),
+            api_key: None,
+            persona: None,
+            voice_replies: false,
+        };
+
+        assert!(channel_model_accepts_images(
+            &ctx,
+            &route("openai.default", "gpt-4o")
+        ));
+        assert!(channel_model_accepts_images(
+            &ctx,
+            &route("custom.default", "my-finetune")
+        ));
+        // Known text-only model behind a vision-capable provider family.
+        assert!(!channel_model_accepts_images(
+            &ctx,
+            &route("openai.default", "o3-mini")
+        ));
+        // An explicit `vision = true` on the provider entry wins.
+        assert!(channel_model_accepts_images(
+            &ctx,
+            &route("openai.forced", "o3-mini")
+        ));
+    }
+
     #[tokio::test]
     async fn passive_context_records_history_without_channel_or_model_side_effects() {
         let channel_impl = Arc::new(RecordingChannel::default());
@@ -15771,6 +17200,7 @@ BTC is currently around $65,000 based on latest tool output."#
             }],
             prompts: vec![],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         }];
         let assembled = assemble_channel_agent_tools(
@@ -15885,6 +17315,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         })
     }
 
@@ -15970,6 +17402,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -16090,6 +17524,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
             agent_cfg: Arc::new(zeroclaw_config::schema::AliasedAgentConfig::default()),
             agent_transcription_provider: String::new(),
         });
@@ -16208,6 +17644,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
             agent_cfg: Arc::new(zeroclaw_config::schema::AliasedAgentConfig::default()),
             agent_transcription_provider: String::new(),
         });
@@ -16363,6 +17801,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
             agent_cfg: Arc::new(zeroclaw_config::schema::AliasedAgentConfig::default()),
             agent_transcription_provider: String::new(),
         });
@@ -16480,6 +17920,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
             agent_cfg: Arc::new(zeroclaw_config::schema::AliasedAgentConfig::default()),
             agent_transcription_provider: String::new(),
         });
@@ -16622,6 +18064,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -16750,6 +18194,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -16863,6 +18309,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -16996,6 +18444,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -17076,6 +18526,8 @@ BTC is currently around $65,000 based on latest tool output."#
                 model_provider: "openrouter".into(),
                 model: "route-model".to_string(),
                 api_key: None,
+                persona: None,
+                voice_replies: false,
             },
         );
 
@@ -17153,6 +18605,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -17339,6 +18793,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -17821,6 +19277,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -17932,6 +19390,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -18050,6 +19510,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -18415,6 +19877,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<zeroclaw_api::channel::ChannelMessage>(4);
@@ -18454,7 +19918,7 @@ BTC is currently around $65,000 based on latest tool output."#
         .unwrap();
         drop(tx);
 
-        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 2).await;
+        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 2, Arc::default()).await;
 
         let peak = peak_in_flight.load(Ordering::SeqCst);
         assert!(
@@ -18472,6 +19936,148 @@ BTC is currently around $65,000 based on latest tool output."#
         assert_eq!(sent_messages.len(), 2);
     }
 
+    fn admission_test_ctx(
+        channel: Arc<dyn Channel>,
+        config: zeroclaw_config::schema::Config,
+    ) -> Arc<ChannelRuntimeContext> {
+        let mut channels_by_name = HashMap::new();
+        channels_by_name.insert(channel.name().to_string(), channel);
+        Arc::new(ChannelRuntimeContext {
+            channels_by_name: Arc::new(channels_by_name),
+            prompt_config: Arc::new(config),
+            max_tool_iterations: 10,
+            ..(*router_test_ctx()).clone()
+        })
+    }
+
+    fn admission_test_message(id: &str, sender: &str) -> zeroclaw_api::channel::ChannelMessage {
+        zeroclaw_api::channel::ChannelMessage {
+            id: id.to_string(),
+            sender: sender.to_string(),
+            reply_target: sender.to_string(),
+            content: format!("hello from {sender}"),
+            channel: "test-channel".into(),
+            ..Default::default()
+        }
+    }
+
+    async fn wait_for_sent(channel: &RecordingChannel, count: usize) -> Vec<String> {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                {
+                    let sent = channel.sent_messages.lock().await;
+                    if sent.len() >= count {
+                        return sent.clone();
+                    }
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timed out waiting for channel sends")
+    }
+
+    #[tokio::test]
+    async fn message_dispatch_auto_replies_while_a_channel_is_paused() {
+        let channel_impl = Arc::new(RecordingChannel::default());
+        let mut config = zeroclaw_config::schema::Config::default();
+        config.channels.paused_reply = "Paused for maintenance.".into();
+        let runtime_ctx = admission_test_ctx(channel_impl.clone(), config);
+        let admission = Arc::new(zeroclaw_runtime::admission::ChannelAdmission::new());
+        let (tx, rx) = tokio::sync::mpsc::channel(4);
+
+        let driver = {
+            let channel_impl = Arc::clone(&channel_impl);
+            let admission = Arc::clone(&admission);
+            async move {
+                tx.send(admission_test_message("1", "alice")).await.unwrap();
+                wait_for_sent(&channel_impl, 1).await;
+
+                admission.pause("test-channel");
+                tx.send(admission_test_message("2", "bob")).await.unwrap();
+                let sent = wait_for_sent(&channel_impl, 2).await;
+                assert_eq!(sent[1], "bob:Paused for maintenance.");
+
+                admission.resume("test-channel");
+                tx.send(admission_test_message("3", "carol")).await.unwrap();
+                wait_for_sent(&channel_impl, 3).await;
+            }
+        };
+        tokio::join!(
+            run_message_dispatch_loop(
+                rx,
+                AgentRouter::single(runtime_ctx),
+                4,
+                Arc::clone(&admission)
+            ),
+            driver
+        );
+
+        let sent = channel_impl.sent_messages.lock().await;
+        assert_eq!(sent.len(), 3, "{sent:?}");
+        assert!(sent[0].starts_with("alice:"));
+        assert!(!sent[0].contains("Paused"));
+        assert!(sent[2].starts_with("carol:"));
+        assert!(!sent[2].contains("Paused"));
+        assert_eq!(admission.snapshot().in_flight, 0);
+    }
+
+    #[tokio::test]
+    async fn message_dispatch_holds_paused_messages_and_stops_reading_while_draining() {
+        let channel_impl = Arc::new(RecordingChannel::default());
+        let mut config = zeroclaw_config::schema::Config::default();
+        config.channels.paused_mode = zeroclaw_config::schema::PausedChannelMode::Queue;
+        let runtime_ctx = admission_test_ctx(channel_impl.clone(), config);
+        let admission = Arc::new(zeroclaw_runtime::admission::ChannelAdmission::new());
+        let (tx, rx) = tokio::sync::mpsc::channel(4);
+
+        let driver = {
+            let channel_impl = Arc::clone(&channel_impl);
+            let admission = Arc::clone(&admission);
+            async move {
+                admission.pause("test-channel.default");
+                let mut held = admission_test_message("1", "alice");
+                held.channel_alias = Some("default".into());
+                tx.send(held).await.unwrap();
+                tokio::time::timeout(Duration::from_secs(5), async {
+                    while admission.snapshot().queued == 0 {
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                    }
+                })
+                .await
+                .expect("message was never held");
+                assert!(channel_impl.sent_messages.lock().await.is_empty());
+
+                // Draining keeps the held message and the next inbound one
+                // waiting even after the channel is resumed.
+                admission.set_draining(true);
+                admission.resume("test-channel.default");
+                tx.send(admission_test_message("2", "bob")).await.unwrap();
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                assert!(channel_impl.sent_messages.lock().await.is_empty());
+                assert_eq!(admission.snapshot().queued, 1);
+
+                admission.set_draining(false);
+                wait_for_sent(&channel_impl, 2).await;
+                assert_eq!(admission.snapshot().queued, 0);
+            }
+        };
+        tokio::join!(
+            run_message_dispatch_loop(
+                rx,
+                AgentRouter::single(runtime_ctx),
+                4,
+                Arc::clone(&admission)
+            ),
+            driver
+        );
+
+        let sent = channel_impl.sent_messages.lock().await;
+        assert_eq!(sent.len(), 2, "{sent:?}");
+        assert!(sent.iter().any(|line| line.starts_with("alice:")));
+        assert!(sent.iter().any(|line| line.starts_with("bob:")));
+    }
+
     #[tokio::test]
     async fn message_dispatch_interrupts_in_flight_telegram_request_and_preserves_context() {
         let channel_impl = Arc::new(TelegramRecordingChannel::default());
@@ -18559,6 +20165,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<zeroclaw_api::channel::ChannelMessage>(8);
@@ -18600,7 +20208,7 @@ BTC is currently around $65,000 based on latest tool output."#
             .unwrap();
         });
 
-        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4).await;
+        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4, Arc::default()).await;
         send_task.await.unwrap();
 
         let sent_messages = channel_impl.sent_messages.lock().await;
@@ -18718,6 +20326,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<zeroclaw_api::channel::ChannelMessage>(8);
@@ -18759,7 +20369,7 @@ BTC is currently around $65,000 based on latest tool output."#
             .unwrap();
         });
 
-        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4).await;
+        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4, Arc::default()).await;
         send_task.await.unwrap();
 
         let sent_messages = channel_impl.sent_messages.lock().await;
@@ -18790,6 +20400,300 @@ BTC is currently around $65,000 based on latest tool output."#
         );
     }
 
+    #[tokio::test]
+    async fn message_dispatch_interrupts_in_flight_discord_request_and_preserves_context() {
+        let channel_impl = Arc::new(DiscordRecordingChannel::default());
+        let channel: Arc<dyn Channel> = channel_impl.clone();
+
+        let mut channels_by_name = HashMap::new();
+        channels_by_name.insert(channel.name().to_string(), channel);
+
+        let provider_impl = Arc::new(DelayedHistoryCaptureModelProvider {
+            delay: Duration::from_millis(250),
+            calls: std::sync::Mutex::new(Vec::new()),
+        });
+
+        let runtime_ctx = Arc::new(ChannelRuntimeContext {
+            channels_by_name: Arc::new(channels_by_name),
+            model_provider: provider_impl.clone(),
+            model_provider_ref: Arc::new("test-provider".to_string()),
+            agent_alias: Arc::new("test-agent".to_string()),
+            agent_cfg: Arc::new(zeroclaw_config::schema::AliasedAgentConfig::default()),
+            memory: Arc::new(NoopMemory),
+            memory_strategy: Arc::new(
+                zeroclaw_runtime::agent::memory_strategy::DefaultMemoryStrategy::with_config(
+                    Arc::new(NoopMemory),
+                    zeroclaw_config::schema::MemoryConfig::default(),
+                    std::path::PathBuf::new(),
+                ),
+            ),
+            tools_registry: Arc::new(vec![]),
+            observer: Arc::new(NoopObserver),
+            system_prompt: Arc::new("test-system-prompt".to_string()),
+            model: Arc::new("test-model".to_string()),
+            temperature: Some(0.0),
+            auto_save_memory: false,
+            max_tool_iterations: 10,
+            min_relevance_score: 0.0,
+            conversation_histories: Arc::new(Mutex::new(lru::LruCache::new(
+                std::num::NonZeroUsize::new(MAX_CONVERSATION_SENDERS).unwrap(),
+            ))),
+            pending_new_sessions: Arc::new(Mutex::new(HashSet::new())),
+            provider_cache: Arc::new(Mutex::new(HashMap::new())),
+            route_overrides: Arc::new(Mutex::new(HashMap::new())),
+            thinking_overrides: Arc::new(Mutex::new(HashMap::new())),
+            scope_overrides: Arc::new(Mutex::new(HashMap::new())),
+            reliability: Arc::new(zeroclaw_config::schema::ReliabilityConfig::default()),
+            provider_runtime_options: zeroclaw_providers::ModelProviderRuntimeOptions::default(),
+            workspace_dir: Arc::new(std::env::temp_dir()),
+            prompt_config: Arc::new(zeroclaw_config::schema::Config::default()),
+            message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            interrupt_on_new_message: InterruptOnNewMessageConfig {
+                telegram: false,
+                slack: false,
+                discord: true,
+                mattermost: false,
+                matrix: false,
+                whatsapp: false,
+            },
+            ack_reactions: true,
+            show_tool_calls: true,
+            session_store: None,
+            multimodal: zeroclaw_config::schema::MultimodalConfig::default(),
+            media_pipeline: zeroclaw_config::schema::MediaPipelineConfig::default(),
+            transcription_config: zeroclaw_config::schema::TranscriptionConfig::default(),
+            agent_transcription_provider: String::new(),
+            hooks: None,
+            non_cli_excluded_tools: Arc::new(Vec::new()),
+            autonomy_level: AutonomyLevel::default(),
+            tool_call_dedup_exempt: Arc::new(Vec::new()),
+            model_routes: Arc::new(Vec::new()),
+            approval_manager: Arc::new(ApprovalManager::for_non_interactive(
+                &zeroclaw_config::schema::RiskProfileConfig::default(),
+            )),
+            activated_tools: None,
+            cost_tracking: None,
+            query_classification: zeroclaw_config::schema::QueryClassificationConfig::default(),
+            pacing: zeroclaw_config::schema::PacingConfig::default(),
+            max_tool_result_chars: 0,
+            context_token_budget: 0,
+            debouncer: Arc::new(zeroclaw_infra::debounce::MessageDebouncer::new(
+                Duration::ZERO,
+            )),
+            receipt_generator: None,
+            show_receipts_in_response: false,
+            last_applied_config_stamp: Arc::new(Mutex::new(None)),
+            runtime_defaults_override: Arc::new(Mutex::new(None)),
+            persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            sop_engine: None,
+            sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
+        });
+
+        let (tx, rx) = tokio::sync::mpsc::channel::<zeroclaw_api::channel::ChannelMessage>(8);
+        let send_task = zeroclaw_spawn::spawn!(async move {
+            tx.send(zeroclaw_api::channel::ChannelMessage {
+                id: "msg-1".to_string(),
+                sender: "987654321".to_string(),
+                reply_target: "112233445566".to_string(),
+                content: "first question".to_string(),
+                channel: "discord".into(),
+                channel_alias: None,
+                timestamp: 1,
+                thread_ts: None,
+                interruption_scope_id: None,
+                attachments: vec![],
+                subject: None,
+
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+            tokio::time::sleep(Duration::from_millis(40)).await;
+            tx.send(zeroclaw_api::channel::ChannelMessage {
+                id: "msg-2".to_string(),
+                sender: "987654321".to_string(),
+                reply_target: "112233445566".to_string(),
+                content: "second question".to_string(),
+                channel: "discord".into(),
+                channel_alias: None,
+                timestamp: 2,
+                thread_ts: None,
+                interruption_scope_id: None,
+                attachments: vec![],
+                subject: None,
+
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        });
+
+        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4, Arc::default()).await;
+        send_task.await.unwrap();
+
+        let sent_messages = channel_impl.sent_messages.lock().await;
+        assert_eq!(sent_messages.len(), 1);
+        assert!(sent_messages[0].starts_with("112233445566:"));
+        assert!(sent_messages[0].contains("response-2"));
+        drop(sent_messages);
+
+        let calls = provider_impl
+            .calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        assert_eq!(calls.len(), 2);
+        let second_call = &calls[1];
+        assert!(
+            second_call
+                .iter()
+                .any(|(role, content)| { role == "user" && content.contains("first question") })
+        );
+        assert!(
+            second_call
+                .iter()
+                .any(|(role, content)| { role == "user" && content.contains("second question") })
+        );
+        assert!(
+            !second_call.iter().any(|(role, _)| role == "assistant"),
+            "cancelled turn should not persist an assistant response"
+        );
+    }
+
+    #[tokio::test]
+    async fn message_dispatch_slack_interrupt_does_not_cross_threads() {
+        let channel_impl = Arc::new(SlackRecordingChannel::default());
+        let channel: Arc<dyn Channel> = channel_impl.clone();
+
+        let mut channels_by_name = HashMap::new();
+        channels_by_name.insert(channel.name().to_string(), channel);
+
+        let provider_impl = Arc::new(DelayedHistoryCaptureModelProvider {
+            delay: Duration::from_millis(250),
+            calls: std::sync::Mutex::new(Vec::new()),
+        });
+
+        let runtime_ctx = Arc::new(ChannelRuntimeContext {
+            channels_by_name: Arc::new(channels_by_name),
+            model_provider: provider_impl.clone(),
+            model_provider_ref: Arc::new("test-provider".to_string()),
+            agent_alias: Arc::new("test-agent".to_string()),
+            agent_cfg: Arc::new(zeroclaw_config::schema::AliasedAgentConfig::default()),
+            memory: Arc::new(NoopMemory),
+            memory_strategy: Arc::new(
+                zeroclaw_runtime::agent::memory_strategy::DefaultMemoryStrategy::with_config(
+                    Arc::new(NoopMemory),
+                    zeroclaw_config::schema::MemoryConfig::default(),
+                    std::path::PathBuf::new(),
+                ),
+            ),
+            tools_registry: Arc::new(vec![]),
+            observer: Arc::new(NoopObserver),
+            system_prompt: Arc::new("test-system-prompt".to_string()),
+            model: Arc::new("test-model".to_string()),
+            temperature: Some(0.0),
+            auto_save_memory: false,
+            max_tool_iterations: 10,
+            min_relevance_score: 0.0,
+            conversation_histories: Arc::new(Mutex::new(lru::LruCache::new(
+                std::num::NonZeroUsize::new(MAX_CONVERSATION_SENDERS).unwrap(),
+            ))),
+            pending_new_sessions: Arc::new(Mutex::new(HashSet::new())),
+            provider_cache: Arc::new(Mutex::new(HashMap::new())),
+            route_overrides: Arc::new(Mutex::new(HashMap::new())),
+            thinking_overrides: Arc::new(Mutex::new(HashMap::new())),
+            scope_overrides: Arc::new(Mutex::new(HashMap::new())),
+            reliability: Arc::new(zeroclaw_config::schema::ReliabilityConfig::default()),
+            provider_runtime_options: zeroclaw_providers::ModelProviderRuntimeOptions::default(),
+            workspace_dir: Arc::new(std::env::temp_dir()),
+            prompt_config: Arc::new(zeroclaw_config::schema::Config::default()),
+            message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            interrupt_on_new_message: InterruptOnNewMessageConfig {
+                telegram: false,
+                slack: true,
+                discord: false,
+                mattermost: false,
+                matrix: false,
+                whatsapp: false,
+            },
+            ack_reactions: true,
+            show_tool_calls: true,
+            session_store: None,
+            multimodal: zeroclaw_config::schema::MultimodalConfig::default(),
+            media_pipeline: zeroclaw_config::schema::MediaPipelineConfig::default(),
+            transcription_config: zeroclaw_config::schema::TranscriptionConfig::default(),
+            agent_transcription_provider: String::new(),
+            hooks: None,
+            non_cli_excluded_tools: Arc::new(Vec::new()),
+            autonomy_level: AutonomyLevel::default(),
+            tool_call_dedup_exempt: Arc::new(Vec::new()),
+            model_routes: Arc::new(Vec::new()),
+            approval_manager: Arc::new(ApprovalManager::for_non_interactive(
+                &zeroclaw_config::schema::RiskProfileConfig::default(),
+            )),
+            activated_tools: None,
+            cost_tracking: None,
+            query_classification: zeroclaw_config::schema::QueryClassificationConfig::default(),
+            pacing: zeroclaw_config::schema::PacingConfig::default(),
+            max_tool_result_chars: 0,
+            context_token_budget: 0,
+            debouncer: Arc::new(zeroclaw_infra::debounce::MessageDebouncer::new(
+                Duration::ZERO,
+            )),
+            receipt_generator: None,
+            show_receipts_in_response: false,
+            last_applied_config_stamp: Arc::new(Mutex::new(None)),
+            runtime_defaults_override: Arc::new(Mutex::new(None)),
+            persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            sop_engine: None,
+            sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
+        });
+
+        let (tx, rx) = tokio::sync::mpsc::channel::<zeroclaw_api::channel::ChannelMessage>(8);
+        let send_task = zeroclaw_spawn::spawn!(async move {
+            for (id, thread, content) in [
+                ("msg-1", "1741234567.100001", "first thread"),
+                ("msg-2", "1741234567.200002", "second thread"),
+            ] {
+                tx.send(zeroclaw_api::channel::ChannelMessage {
+                    id: id.to_string(),
+                    sender: "U123".to_string(),
+                    reply_target: "C123".to_string(),
+                    content: content.to_string(),
+                    channel: "slack".into(),
+                    timestamp: 1,
+                    thread_ts: Some(thread.to_string()),
+                    interruption_scope_id: Some(thread.to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+                tokio::time::sleep(Duration::from_millis(40)).await;
+            }
+        });
+
+        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4, Arc::default()).await;
+        send_task.await.unwrap();
+
+        let sent_messages = channel_impl.sent_messages.lock().await;
+        assert_eq!(
+            sent_messages.len(),
+            2,
+            "a message in another thread must not cancel the in-flight reply"
+        );
+        assert_eq!(
+            provider_impl
+                .calls
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .len(),
+            2
+        );
+    }
+
     #[tokio::test]
     async fn message_dispatch_interrupts_in_flight_whatsapp_request_and_preserves_context() {
         let channel_impl = Arc::new(WhatsAppRecordingChannel::default());
@@ -18880,6 +20784,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<zeroclaw_api::channel::ChannelMessage>(8);
@@ -18921,7 +20827,7 @@ BTC is currently around $65,000 based on latest tool output."#
             .unwrap();
         });
 
-        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4).await;
+        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4, Arc::default()).await;
         send_task.await.unwrap();
 
         let sent_messages = channel_impl.sent_messages.lock().await;
@@ -19032,6 +20938,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<zeroclaw_api::channel::ChannelMessage>(8);
@@ -19073,7 +20981,7 @@ BTC is currently around $65,000 based on latest tool output."#
             .unwrap();
         });
 
-        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4).await;
+        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4, Arc::default()).await;
         send_task.await.unwrap();
 
         let sent_messages = channel_impl.sent_messages.lock().await;
@@ -19166,6 +21074,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -19279,6 +21189,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -19406,6 +21318,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -19581,6 +21495,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -19842,6 +21758,7 @@ BTC is currently around $65,000 based on latest tool output."#
             }],
             prompts: vec!["Always run cargo test before final response.".into()],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         }];
 
@@ -19885,6 +21802,7 @@ BTC is currently around $65,000 based on latest tool output."#
             }],
             prompts: vec!["Always run cargo test before final response.".into()],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         }];
 
@@ -19938,6 +21856,7 @@ BTC is currently around $65,000 based on latest tool output."#
             }],
             prompts: vec!["Use <tool_call> and & keep output \"safe\"".into()],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         }];
 
@@ -20458,6 +22377,31 @@ BTC is currently around $65,000 based on latest tool output."#
         assert!(!root_key.contains("$reply:server"));
     }
 
+    #[test]
+    fn telegram_forum_topics_get_separate_history_keys() {
+        let topic = zeroclaw_api::channel::ChannelMessage {
+            id: "telegram_-100200300_42".into(),
+            sender: "alice".into(),
+            reply_target: "-100200300:789".into(),
+            content: "hello from topic".into(),
+            channel: "telegram".into(),
+            channel_alias: Some("default".into()),
+            timestamp: 1,
+            thread_ts: Some("789".into()),
+            ..Default::default()
+        };
+        let other_topic = zeroclaw_api::channel::ChannelMessage {
+            reply_target: "-100200300:790".into(),
+            thread_ts: Some("790".into()),
+            ..topic.clone()
+        };
+
+        assert_ne!(
+            conversation_history_key(&topic),
+            conversation_history_key(&other_topic)
+        );
+    }
+
     #[test]
     fn reply_target_conversation_scope_omits_sender_from_history_key() {
         let first = zeroclaw_api::channel::ChannelMessage {
@@ -20624,6 +22568,8 @@ BTC is currently around $65,000 based on latest tool output."#
             model_provider: "openrouter.default".into(),
             model: m.into(),
             api_key: None,
+            persona: None,
+            voice_replies: false,
         };
 
         // Nothing set → config default (whatever the snapshot resolves to).
@@ -20662,6 +22608,39 @@ BTC is currently around $65,000 based on latest tool output."#
         );
     }
 
+    #[test]
+    fn voice_and_persona_preferences_survive_model_switches() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let ctx = channel_runtime_context_for_defaults_test(
+            tmp.path(),
+            "agentX",
+            "openrouter.default",
+            "default-model",
+        );
+        let msg = scope_test_msg("alice", "chan", None);
+        let snapshot = runtime_defaults_snapshot(&ctx);
+        let sender_key = conversation_history_key(&msg);
+
+        update_sender_preferences(&ctx, &sender_key, &snapshot, |route| {
+            route.voice_replies = true;
+        });
+        assert!(route_voice_replies(&ctx, &sender_key));
+
+        let mut next = get_route_selection(&ctx, &msg, &sender_key, &snapshot);
+        next.model = "other-model".into();
+        next.voice_replies = false;
+        set_route_selection(&ctx, &sender_key, next, &snapshot);
+        assert!(route_voice_replies(&ctx, &sender_key));
+
+        // Switching voice back off with the default model drops the entry.
+        let default = default_route_selection_from_snapshot(&snapshot);
+        set_route_selection(&ctx, &sender_key, default, &snapshot);
+        update_sender_preferences(&ctx, &sender_key, &snapshot, |route| {
+            route.voice_replies = false;
+        });
+        assert!(ctx.route_overrides.lock().unwrap().is_empty());
+    }
+
     #[test]
     fn set_scope_override_clears_when_equal_to_default() {
         let tmp = tempfile::TempDir::new().unwrap();
@@ -20682,6 +22661,8 @@ BTC is currently around $65,000 based on latest tool output."#
                 model_provider: "openrouter.default".into(),
                 model: "other".into(),
                 api_key: None,
+                persona: None,
+                voice_replies: false,
             },
             &snapshot,
         );
@@ -20691,6 +22672,39 @@ BTC is currently around $65,000 based on latest tool output."#
         assert!(ctx.scope_overrides.lock().unwrap().is_empty());
     }
 
+    #[test]
+    fn parse_runtime_command_maps_persona_arguments() {
+        assert_eq!(
+            parse_runtime_command("telegram", "/persona"),
+            Some(ChannelRuntimeCommand::ShowPersonas)
+        );
+        assert_eq!(
+            parse_runtime_command("telegram", "/persona pirate"),
+            Some(ChannelRuntimeCommand::SetPersona(Some("pirate".into())))
+        );
+        assert_eq!(
+            parse_runtime_command("telegram", "/persona Default"),
+            Some(ChannelRuntimeCommand::SetPersona(None))
+        );
+    }
+
+    #[test]
+    fn parse_runtime_command_maps_voice_toggle_on_telegram_only() {
+        assert_eq!(
+            parse_runtime_command("telegram", "/voice on"),
+            Some(ChannelRuntimeCommand::SetVoiceReplies(true))
+        );
+        assert_eq!(
+            parse_runtime_command("telegram", "/voice OFF"),
+            Some(ChannelRuntimeCommand::SetVoiceReplies(false))
+        );
+        assert_eq!(
+            parse_runtime_command("telegram", "/voice"),
+            Some(ChannelRuntimeCommand::ShowVoiceReplies)
+        );
+        assert_eq!(parse_runtime_command("slack", "/voice on"), None);
+    }
+
     #[test]
     fn parse_runtime_command_maps_clear_to_new_session() {
         assert_eq!(
@@ -21801,6 +23815,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -21976,6 +23992,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -22016,6 +24034,25 @@ BTC is currently around $65,000 based on latest tool output."#
             "fresh-session prompt should pick up skills added after startup"
         );
 
+        std::fs::write(
+            workspace.path().join("SOUL.md"),
+            "# Soul\nBe brief and warm.",
+        )
+        .unwrap();
+        let refreshed = refreshed_new_session_system_prompt(runtime_ctx.as_ref());
+        assert!(
+            refreshed.contains("Be brief and warm.") && !refreshed.contains("Be helpful."),
+            "fresh-session prompt should pick up SOUL.md edits made after startup"
+        );
+        assert!(refreshed.contains("## Current Date"));
+        let misses = runtime_ctx.prompt_cache.stats().misses;
+        refreshed_new_session_system_prompt(runtime_ctx.as_ref());
+        assert_eq!(
+            runtime_ctx.prompt_cache.stats().misses,
+            misses,
+            "an unchanged workspace should be served from the prompt cache"
+        );
+
         process_channel_message(
             runtime_ctx.clone(),
             zeroclaw_api::channel::ChannelMessage {
@@ -22112,6 +24149,176 @@ BTC is currently around $65,000 based on latest tool output."#
         );
     }
 
+    #[tokio::test]
+    async fn persona_command_swaps_soul_in_the_built_system_prompt() {
+        let workspace = make_workspace();
+        let pirate = workspace.path().join("personas").join("pirate");
+        std::fs::create_dir_all(&pirate).unwrap();
+        std::fs::write(pirate.join("SOUL.md"), "# Soul\nTalk like a pirate.").unwrap();
+        std::fs::write(pirate.join("IDENTITY.md"), "# Identity\nName: Redbeard").unwrap();
+        let mut config = Config {
+            data_dir: workspace.path().to_path_buf(),
+            ..Default::default()
+        };
+        config.skills.open_skills_enabled = false;
+
+        let default_identity = zeroclaw_config::schema::IdentityConfig::default();
+        let initial_system_prompt = build_system_prompt_with_mode(
+            workspace.path(),
+            "test-model",
+            &[],
+            &[],
+            Some(&default_identity),
+            None,
+            false,
+            config.skills.prompt_injection_mode,
+            AutonomyLevel::default(),
+        );
+
+        let channel_impl = Arc::new(TelegramRecordingChannel::default());
+        let channel: Arc<dyn Channel> = channel_impl.clone();
+
+        let mut channels_by_name = HashMap::new();
+        channels_by_name.insert(channel.name().to_string(), channel);
+
+        let provider_impl = Arc::new(HistoryCaptureModelProvider::default());
+        let runtime_ctx = Arc::new(ChannelRuntimeContext {
+            channels_by_name: Arc::new(channels_by_name),
+            model_provider: provider_impl.clone(),
+            model_provider_ref: Arc::new("test-provider".to_string()),
+            agent_alias: Arc::new("test-agent".to_string()),
+            agent_cfg: Arc::new(zeroclaw_config::schema::AliasedAgentConfig::default()),
+            memory: Arc::new(NoopMemory),
+            memory_strategy: Arc::new(
+                zeroclaw_runtime::agent::memory_strategy::DefaultMemoryStrategy::with_config(
+                    Arc::new(NoopMemory),
+                    zeroclaw_config::schema::MemoryConfig::default(),
+                    std::path::PathBuf::new(),
+                ),
+            ),
+            tools_registry: Arc::new(vec![]),
+            observer: Arc::new(NoopObserver),
+            system_prompt: Arc::new(initial_system_prompt),
+            model: Arc::new("test-model".to_string()),
+            temperature: Some(0.0),
+            auto_save_memory: false,
+            max_tool_iterations: 5,
+            min_relevance_score: 0.0,
+            conversation_histories: Arc::new(Mutex::new(lru::LruCache::new(
+                std::num::NonZeroUsize::new(MAX_CONVERSATION_SENDERS).unwrap(),
+            ))),
+            pending_new_sessions: Arc::new(Mutex::new(HashSet::new())),
+            provider_cache: Arc::new(Mutex::new(HashMap::new())),
+            route_overrides: Arc::new(Mutex::new(HashMap::new())),
+            thinking_overrides: Arc::new(Mutex::new(HashMap::new())),
+            scope_overrides: Arc::new(Mutex::new(HashMap::new())),
+            reliability: Arc::new(zeroclaw_config::schema::ReliabilityConfig::default()),
+            provider_runtime_options: zeroclaw_providers::ModelProviderRuntimeOptions::default(),
+            workspace_dir: Arc::new(config.data_dir.clone()),
+            prompt_config: Arc::new(config.clone()),
+            message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            interrupt_on_new_message: InterruptOnNewMessageConfig {
+                telegram: false,
+                slack: false,
+                discord: false,
+                mattermost: false,
+                matrix: false,
+                whatsapp: false,
+            },
+            multimodal: zeroclaw_config::schema::MultimodalConfig::default(),
+            media_pipeline: zeroclaw_config::schema::MediaPipelineConfig::default(),
+            transcription_config: zeroclaw_config::schema::TranscriptionConfig::default(),
+            agent_transcription_provider: String::new(),
+            hooks: None,
+            non_cli_excluded_tools: Arc::new(Vec::new()),
+            autonomy_level: AutonomyLevel::default(),
+            tool_call_dedup_exempt: Arc::new(Vec::new()),
+            model_routes: Arc::new(Vec::new()),
+            query_classification: zeroclaw_config::schema::QueryClassificationConfig::default(),
+            ack_reactions: true,
+            show_tool_calls: true,
+            session_store: None,
+            approval_manager: Arc::new(ApprovalManager::for_non_interactive(
+                &zeroclaw_config::schema::RiskProfileConfig::default(),
+            )),
+            activated_tools: None,
+            cost_tracking: None,
+            pacing: zeroclaw_config::schema::PacingConfig::default(),
+            max_tool_result_chars: 0,
+            context_token_budget: 0,
+            debouncer: Arc::new(zeroclaw_infra::debounce::MessageDebouncer::new(
+                Duration::ZERO,
+            )),
+            receipt_generator: None,
+            show_receipts_in_response: false,
+            last_applied_config_stamp: Arc::new(Mutex::new(None)),
+            runtime_defaults_override: Arc::new(Mutex::new(None)),
+            persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            sop_engine: None,
+            sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
+        });
+
+        let send = |id: &str, content: &str| {
+            process_channel_message(
+                runtime_ctx.clone(),
+                zeroclaw_api::channel::ChannelMessage {
+                    id: id.to_string(),
+                    sender: "alice".to_string(),
+                    reply_target: "chat-persona".to_string(),
+                    content: content.to_string(),
+                    channel: "telegram".into(),
+                    ..Default::default()
+                },
+                CancellationToken::new(),
+            )
+        };
+
+        send("msg-1", "hello").await;
+        send("msg-2", "/persona pirate").await;
+        assert_eq!(
+            route_persona(&runtime_ctx, "telegram_chat-persona_alice").as_deref(),
+            Some("pirate")
+        );
+        send("msg-3", "ahoy").await;
+        send("msg-4", "/persona default").await;
+        assert_eq!(
+            route_persona(&runtime_ctx, "telegram_chat-persona_alice"),
+            None
+        );
+        send("msg-5", "back to normal").await;
+
+        let calls = provider_impl
+            .calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        assert_eq!(calls.len(), 3);
+        assert!(calls[0][0].1.contains("Be helpful."));
+        let persona_prompt = &calls[1][0].1;
+        assert!(persona_prompt.contains("Talk like a pirate."));
+        assert!(persona_prompt.contains("Name: Redbeard"));
+        assert!(!persona_prompt.contains("Be helpful."));
+        assert!(
+            persona_prompt.contains("Follow instructions.")
+                && persona_prompt.contains("Use shell carefully."),
+            "AGENTS.md and the workspace TOOLS.md stay in the persona prompt"
+        );
+        assert!(calls[2][0].1.contains("Be helpful."));
+        assert!(!calls[2][0].1.contains("Talk like a pirate."));
+
+        let switched = zeroclaw_runtime::i18n::get_required_cli_string_with_args(
+            "channel-runtime-persona-switched",
+            &[("persona", "pirate")],
+        );
+        let sent_messages = channel_impl.sent_messages.lock().await;
+        assert!(
+            sent_messages
+                .iter()
+                .any(|message| message.contains(&switched))
+        );
+    }
+
     #[tokio::test]
     async fn process_channel_message_enriches_current_turn_without_persisting_context() {
         let channel_impl = Arc::new(RecordingChannel::default());
@@ -22364,6 +24571,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         })
     }
 
@@ -22857,6 +25066,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -22879,8 +25090,11 @@ BTC is currently around $65,000 based on latest tool output."#
                     data: vec![1, 2, 3, 4],
                     mime_type: Some("image/png".to_string()),
                 }],
+                media_files: Vec::new(),
                 subject: None,
                 internal_sop_event: None,
+                edit_of: None,
+                reaction: None,
             },
             CancellationToken::new(),
         )
@@ -23007,6 +25221,8 @@ BTC is currently around $65,000 based on latest tool output."#
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -23461,6 +25677,88 @@ This is an example JSON object for profile settings."#;
         assert_eq!(state, ChannelHealthState::Unhealthy);
     }
 
+    #[test]
+    fn context_budget_fits_known_model_window() {
+        assert_eq!(clamp_context_budget(32_000, None, None), 32_000);
+        assert_eq!(clamp_context_budget(32_000, Some(8_192), None), 7_372);
+        assert_eq!(clamp_context_budget(32_000, Some(131_072), None), 32_000);
+        assert_eq!(clamp_context_budget(32_000, Some(0), None), 32_000);
+        assert_eq!(
+            clamp_context_budget(32_000, Some(8_192), Some(2_048)),
+            6_144
+        );
+        assert_eq!(clamp_context_budget(32_000, Some(1_000), Some(4_096)), 1);
+    }
+
+    #[tokio::test]
+    async fn context_budget_uses_the_capability_registry_window() {
+        let mut config = Config::default();
+        config.providers.models.ollama.insert(
+            "local".to_string(),
+            zeroclaw_config::schema::OllamaModelProviderConfig {
+                base: zeroclaw_config::schema::ModelProviderConfig {
+                    model: Some("qwen2.5:7b".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        config.agents.insert(
+            "local".to_string(),
+            zeroclaw_config::schema::AliasedAgentConfig {
+                model_provider: "ollama.local".into(),
+                ..Default::default()
+            },
+        );
+
+        // qwen2.5 is a built-in 32K-window model; nothing is configured.
+        assert_eq!(
+            channel_context_budget(&config, "local", 64_000).await,
+            32_768 * 9 / 10
+        );
+        assert_eq!(channel_context_budget(&config, "local", 8_000).await, 8_000);
+    }
+
+    fn health_entry(state: ChannelHealthState) -> ChannelHealthEntry {
+        ChannelHealthEntry {
+            channel: "telegram.default".into(),
+            state,
+            latency_ms: 12,
+            hint: None,
+        }
+    }
+
+    #[test]
+    fn channel_doctor_exit_codes() {
+        let report = |states: &[ChannelHealthState]| ChannelDoctorReport {
+            entries: states.iter().copied().map(health_entry).collect(),
+        };
+        assert_eq!(report(&[]).exit_code(), 0);
+        assert_eq!(report(&[ChannelHealthState::Healthy]).exit_code(), 0);
+        assert_eq!(
+            report(&[ChannelHealthState::Healthy, ChannelHealthState::Timeout]).exit_code(),
+            3
+        );
+        assert_eq!(
+            report(&[ChannelHealthState::Timeout, ChannelHealthState::Unhealthy]).exit_code(),
+            2
+        );
+    }
+
+    #[test]
+    fn channel_health_entry_serializes_lowercase_state() {
+        let json = serde_json::to_value(health_entry(ChannelHealthState::Timeout)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "channel": "telegram.default",
+                "state": "timeout",
+                "latency_ms": 12,
+                "hint": null,
+            })
+        );
+    }
+
     #[tokio::test]
     async fn classify_health_timeout() {
         let result = tokio::time::timeout(Duration::from_millis(1), async {
@@ -23516,6 +25814,8 @@ This is an example JSON object for profile settings."#;
                 interrupt_on_new_message: false,
                 proxy_url: None,
                 listen_mode: zeroclaw_config::schema::MattermostListenMode::default(),
+                stream_drafts: false,
+                draft_update_interval_ms: 1000,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -23565,6 +25865,8 @@ This is an example JSON object for profile settings."#;
                 interrupt_on_new_message: false,
                 proxy_url: None,
                 listen_mode: zeroclaw_config::schema::MattermostListenMode::default(),
+                stream_drafts: false,
+                draft_update_interval_ms: 1000,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -24626,8 +26928,11 @@ This is an example JSON object for profile settings."#;
                     data: vec![1, 2, 3, 4],
                     mime_type: Some("image/png".to_string()),
                 }],
+                media_files: Vec::new(),
                 subject: None,
                 internal_sop_event: None,
+                edit_of: None,
+                reaction: None,
             },
             CancellationToken::new(),
         )
@@ -24757,6 +27062,8 @@ This is an example JSON object for profile settings."#;
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         // Simulate a photo attachment message with [IMAGE:] marker.
@@ -24877,6 +27184,8 @@ This is an example JSON object for profile settings."#;
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -25036,6 +27345,8 @@ This is an example JSON object for profile settings."#;
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
             media_pipeline: zeroclaw_config::schema::MediaPipelineConfig::default(),
             transcription_config: zeroclaw_config::schema::TranscriptionConfig::default(),
             agent_transcription_provider: String::new(),
@@ -25274,6 +27585,8 @@ This is an example JSON object for profile settings."#;
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -25427,6 +27740,8 @@ This is an example JSON object for profile settings."#;
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -25572,6 +27887,8 @@ This is an example JSON object for profile settings."#;
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -25737,6 +28054,8 @@ This is an example JSON object for profile settings."#;
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         process_channel_message(
@@ -25814,9 +28133,11 @@ This is an example JSON object for profile settings."#;
                 draft_update_interval_ms: 1000,
                 interrupt_on_new_message: false,
                 mention_only: false,
+                allowed_topics: Vec::new(),
                 ack_reactions: None,
                 proxy_url: None,
                 approval_timeout_secs: 120,
+                translate: None,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -25843,9 +28164,11 @@ This is an example JSON object for profile settings."#;
                 draft_update_interval_ms: 1000,
                 interrupt_on_new_message: false,
                 mention_only: false,
+                allowed_topics: Vec::new(),
                 ack_reactions: None,
                 proxy_url: None,
                 approval_timeout_secs: 120,
+                translate: None,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -25934,6 +28257,54 @@ This is an example JSON object for profile settings."#;
         );
     }
 
+    #[tokio::test]
+    async fn pair_channel_rejects_channels_without_qr_pairing() {
+        let err = pair_channel(
+            &Config::default(),
+            "telegram",
+            "default",
+            ChannelPairOptions::default(),
+        )
+        .await
+        .expect_err("token channels do not pair");
+        assert!(
+            err.to_string().contains("does not pair by QR code"),
+            "{err}"
+        );
+    }
+
+    #[cfg(feature = "whatsapp-web")]
+    #[tokio::test]
+    async fn pair_whatsapp_requires_a_web_session_path() {
+        let mut config = Config::default();
+        config.channels.whatsapp.insert(
+            "cloud".to_string(),
+            zeroclaw_config::schema::WhatsAppConfig {
+                enabled: true,
+                phone_number_id: Some("123".into()),
+                ..Default::default()
+            },
+        );
+
+        let err = pair_channel(
+            &config,
+            "whatsapp",
+            "missing",
+            ChannelPairOptions::default(),
+        )
+        .await
+        .expect_err("unconfigured alias");
+        assert!(
+            err.to_string().contains("channels.whatsapp.missing"),
+            "{err}"
+        );
+
+        let err = pair_channel(&config, "whatsapp", "cloud", ChannelPairOptions::default())
+            .await
+            .expect_err("Cloud API alias");
+        assert!(err.to_string().contains("Cloud API backend"), "{err}");
+    }
+
     /// The generic bind must keep the SCOPED dotted `<type>.<alias>` channel
     /// ref — never a bare type, which would broaden the peer across every
     /// alias of that type (the bug the alias-aware fix closed).
@@ -26302,6 +28673,8 @@ This is an example JSON object for profile settings."#;
             persist_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             sop_engine: None,
             sop_audit: None,
+            prompt_cache: Arc::new(PromptSectionCache::new()),
+            translation: Arc::new(ChannelTranslation::default()),
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<zeroclaw_api::channel::ChannelMessage>(8);
@@ -26345,7 +28718,7 @@ This is an example JSON object for profile settings."#;
             .unwrap();
         });
 
-        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4).await;
+        run_message_dispatch_loop(rx, AgentRouter::single(runtime_ctx), 4, Arc::default()).await;
         send_task.await.unwrap();
 
         // Both tasks should have completed — different threads, no cancellation.
@@ -27150,6 +29523,30 @@ Done."#;
         assert_eq!(tool_msgs.len(), 4);
     }
 
+    #[test]
+    fn final_turn_reasoning_is_kept_only_when_enabled() {
+        let history = vec![
+            ChatMessage::user("what day is it"),
+            ChatMessage::assistant("It is Friday.")
+                .with_reasoning(Some("Check the calendar.".to_string())),
+        ];
+        assert_eq!(
+            final_turn_reasoning(&history, Some(true)).as_deref(),
+            Some("Check the calendar.")
+        );
+        assert_eq!(final_turn_reasoning(&history, None), None);
+        assert_eq!(final_turn_reasoning(&history, Some(false)), None);
+
+        // The delivered reply and its JSONL record never mix the two.
+        let persisted = ChatMessage::assistant("It is Friday.")
+            .with_reasoning(final_turn_reasoning(&history, Some(true)));
+        let json = serde_json::to_value(&persisted).unwrap();
+        assert_eq!(json["content"], "It is Friday.");
+        assert_eq!(json["reasoning"], "Check the calendar.");
+        let plain = serde_json::to_value(ChatMessage::assistant("It is Friday.")).unwrap();
+        assert!(plain.get("reasoning").is_none());
+    }
+
     #[test]
     fn normalize_cached_channel_turns_passes_through_tool_messages() {
         let turns = vec![
@@ -28214,7 +30611,7 @@ Done."#;
         let (tx, rx) = tokio::sync::mpsc::channel(1);
         tx.send(msg).await.expect("queue gate reply");
         drop(tx);
-        run_message_dispatch_loop(rx, router, 1).await;
+        run_message_dispatch_loop(rx, router, 1, Arc::default()).await;
 
         tokio::time::timeout(Duration::from_secs(1), async {
             loop {
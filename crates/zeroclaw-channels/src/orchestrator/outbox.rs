@@ -0,0 +1,327 @@
+//! Retry worker for replies parked in the channel outbox
+//! (see [`zeroclaw_runtime::outbox`]).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+use zeroclaw_api::channel::{Channel, SendMessage};
+use zeroclaw_runtime::outbox::{ChannelOutbox, NewOutboxEntry, OutboxEntry, OutboxStatus};
+
+/// How often the worker looks for entries whose backoff has elapsed.
+const OUTBOX_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Retry due outbox entries until `cancel` fires, keeping the per-channel
+/// depth in `/health/details` current.
+pub(super) fn spawn_outbox_worker(
+    outbox: Arc<ChannelOutbox>,
+    channels: Arc<HashMap<String, Arc<dyn Channel>>>,
+    cancel: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    zeroclaw_spawn::spawn!(async move {
+        let mut poll = tokio::time::interval(OUTBOX_POLL_INTERVAL);
+        poll.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                () = cancel.cancelled() => return,
+                _ = poll.tick() => {}
+            }
+            // A delivery makes the recipient's next entry due at once, so
+            // keep going while sends land instead of waiting a tick each.
+            while retry_due(&outbox, &channels).await > 0 {}
+            if let Err(e) = outbox.prune_delivered() {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Delete)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                    "failed to prune delivered outbox entries"
+                );
+            }
+            report_depth(&outbox);
+        }
+    })
+}
+
+/// Park a reply the channel did not deliver. Returns `false` when the
+/// outbox could not store it either.
+pub(super) fn park_reply(
+    outbox: &ChannelOutbox,
+    channel_key: &str,
+    recipient: &str,
+    thread_ts: Option<String>,
+    content: &str,
+    error: &str,
+) -> bool {
+    let entry = NewOutboxEntry {
+        channel: channel_key.to_string(),
+        recipient: recipient.to_string(),
+        thread_ts,
+        content: content.to_string(),
+    };
+    let parked = match outbox.enqueue(entry, error) {
+        Ok(entry) => {
+            ::zeroclaw_log::record!(
+                INFO,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Defer)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Success)
+                    .with_attrs(::serde_json::json!({
+                        "channel": channel_key,
+                        "outbox_id": entry.id,
+                        "next_attempt_at": entry.next_attempt_at.to_rfc3339(),
+                    })),
+                "reply queued in the outbox for retry"
+            );
+            true
+        }
+        Err(e) => {
+            ::zeroclaw_log::record!(
+                ERROR,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Write)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({
+                        "channel": channel_key,
+                        "error": format!("{e:#}"),
+                    })),
+                "failed to queue reply in the outbox; reply dropped"
+            );
+            false
+        }
+    };
+    report_depth(outbox);
+    parked
+}
+
+/// Send every due entry once. Returns how many were delivered.
+async fn retry_due(outbox: &ChannelOutbox, channels: &HashMap<String, Arc<dyn Channel>>) -> usize {
+    let due = match outbox.due() {
+        Ok(due) => due,
+        Err(e) => {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Query)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                "failed to read the outbox"
+            );
+            return 0;
+        }
+    };
+    let mut delivered = 0;
+    for entry in due {
+        let Some(channel) = channel_for_entry(channels, &entry.channel) else {
+            record_failure(outbox, &entry, "channel is not running");
+            continue;
+        };
+        let message = SendMessage::new(entry.content.as_str(), entry.recipient.as_str())
+            .in_thread(entry.thread_ts.clone());
+        match channel.send_with_receipt(&message).await {
+            Ok(receipt) => {
+                let delivery_id = receipt.as_ref().map(|r| r.message_id.as_str());
+                match outbox.mark_delivered(entry.id, delivery_id) {
+                    Ok(true) => {
+                        delivered += 1;
+                        ::zeroclaw_log::record!(
+                            INFO,
+                            ::zeroclaw_log::Event::new(
+                                module_path!(),
+                                ::zeroclaw_log::Action::Retry
+                            )
+                            .with_outcome(::zeroclaw_log::EventOutcome::Success)
+                            .with_attrs(::serde_json::json!({
+                                "channel": entry.channel,
+                                "outbox_id": entry.id,
+                                "attempts": entry.attempts + 1,
+                                "delivery_id": delivery_id,
+                            })),
+                            "queued reply delivered"
+                        );
+                    }
+                    // Dropped from the CLI while the send was in flight.
+                    Ok(false) => {}
+                    Err(e) => {
+                        ::zeroclaw_log::record!(
+                            WARN,
+                            ::zeroclaw_log::Event::new(
+                                module_path!(),
+                                ::zeroclaw_log::Action::Write
+                            )
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                            .with_attrs(::serde_json::json!({
+                                "outbox_id": entry.id,
+                                "error": format!("{e:#}"),
+                            })),
+                            "queued reply sent but its receipt was not recorded"
+                        );
+                    }
+                }
+            }
+            Err(e) => record_failure(outbox, &entry, &format!("{e:#}")),
+        }
+    }
+    delivered
+}
+
+fn record_failure(outbox: &ChannelOutbox, entry: &OutboxEntry, error: &str) {
+    match outbox.mark_failed(entry.id, error) {
+        Ok(Some(OutboxStatus::Dead)) => {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({
+                        "channel": entry.channel,
+                        "outbox_id": entry.id,
+                        "error": error,
+                    })),
+                "queued reply expired undelivered; moved to the dead-letter list"
+            );
+        }
+        Ok(_) => {
+            ::zeroclaw_log::record!(
+                DEBUG,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Retry)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({
+                        "channel": entry.channel,
+                        "outbox_id": entry.id,
+                        "error": error,
+                    })),
+                "queued reply still undeliverable"
+            );
+        }
+        Err(e) => {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Write)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({
+                        "outbox_id": entry.id,
+                        "error": format!("{e:#}"),
+                    })),
+                "failed to record outbox attempt"
+            );
+        }
+    }
+}
+
+fn report_depth(outbox: &ChannelOutbox) {
+    if let Ok(depth) = outbox.depth() {
+        zeroclaw_runtime::admission::global().set_outbox_depth(depth);
+    }
+}
+
+/// Resolve an entry's channel key the way replies are routed: the exact
+/// key, then its bare channel type.
+fn channel_for_entry<'a>(
+    channels: &'a HashMap<String, Arc<dyn Channel>>,
+    key: &str,
+) -> Option<&'a Arc<dyn Channel>> {
+    channels.get(key).or_else(|| {
+        key.split_once(['.', ':'])
+            .and_then(|(base, _)| channels.get(base))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tempfile::TempDir;
+    use zeroclaw_api::attribution::{Attributable, ChannelKind, Role};
+    use zeroclaw_api::channel::{ChannelMessage, DeliveryReceipt};
+    use zeroclaw_config::schema::Config;
+
+    /// Fails every send until `online` is set, then records what it sent.
+    #[derive(Default)]
+    struct FlakyChannel {
+        online: AtomicBool,
+        sent: parking_lot::Mutex<Vec<String>>,
+    }
+
+    impl Attributable for FlakyChannel {
+        fn role(&self) -> Role {
+            Role::Channel(ChannelKind::Cli)
+        }
+        fn alias(&self) -> &str {
+            "flaky"
+        }
+    }
+
+    #[async_trait]
+    impl Channel for FlakyChannel {
+        fn name(&self) -> &str {
+            "flaky"
+        }
+
+        async fn send(&self, _message: &SendMessage) -> anyhow::Result<()> {
+            unreachable!("the worker sends with receipts")
+        }
+
+        async fn send_with_receipt(
+            &self,
+            message: &SendMessage,
+        ) -> anyhow::Result<Option<DeliveryReceipt>> {
+            if !self.online.load(Ordering::SeqCst) {
+                anyhow::bail!("platform unreachable");
+            }
+            let mut sent = self.sent.lock();
+            sent.push(message.content.clone());
+            Ok(Some(DeliveryReceipt::new(format!("m{}", sent.len()))))
+        }
+
+        async fn listen(
+            &self,
+            _tx: tokio::sync::mpsc::Sender<ChannelMessage>,
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn queued_replies_are_delivered_in_order_once_the_channel_recovers() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = Config {
+            data_dir: tmp.path().to_path_buf(),
+            ..Config::default()
+        };
+        config.channels.outbox.base_retry_secs = 0;
+        let outbox = ChannelOutbox::new(&config);
+        let flaky = Arc::new(FlakyChannel::default());
+        let channels: HashMap<String, Arc<dyn Channel>> =
+            HashMap::from([("flaky".to_string(), flaky.clone() as Arc<dyn Channel>)]);
+
+        assert!(park_reply(
+            &outbox,
+            "flaky.default",
+            "alice",
+            None,
+            "first",
+            "timeout"
+        ));
+        assert!(park_reply(
+            &outbox,
+            "flaky.default",
+            "alice",
+            None,
+            "second",
+            "timeout"
+        ));
+        assert_eq!(retry_due(&outbox, &channels).await, 0);
+        assert_eq!(outbox.get(1).unwrap().unwrap().attempts, 2);
+
+        flaky.online.store(true, Ordering::SeqCst);
+        while retry_due(&outbox, &channels).await > 0 {}
+        assert_eq!(*flaky.sent.lock(), ["first", "second"]);
+        let first = outbox.get(1).unwrap().unwrap();
+        assert_eq!(first.status, OutboxStatus::Delivered);
+        assert_eq!(first.delivery_id.as_deref(), Some("m1"));
+
+        // Delivered entries are never sent again.
+        assert_eq!(retry_due(&outbox, &channels).await, 0);
+        assert_eq!(flaky.sent.lock().len(), 2);
+        assert!(outbox.depth().unwrap().is_empty());
+    }
+}
@@ -9,13 +9,13 @@ use async_trait::async_trait;
 use tokio::sync::{Mutex, oneshot};
 use zeroclaw_api::attribution::{Attributable, Role};
 use zeroclaw_api::channel::{
-    Channel, ChannelApprovalRequest, ChannelApprovalResponse, ChannelMessage, RoomCreationOptions,
-    SendMessage,
+    Channel, ChannelApprovalRequest, ChannelApprovalResponse, ChannelMessage, DeliveryReceipt,
+    RoomCreationOptions, SendMessage,
 };
 use zeroclaw_config::schema::{DEFAULT_REPLY_QUEUE_DEPTH, HasReplyPacing, PACING_RECIPIENT_CAP};
 
 enum PacedOp {
-    /// A final outbound message. Dispatches to `inner.send`.
+    /// A final outbound message. Dispatches to `inner.send_with_receipt`.
     Send(SendMessage),
     /// A terminal draft write. Dispatches to `inner.finalize_draft` so the
     /// channel edits the existing draft rather than posting a new message.
@@ -44,10 +44,12 @@ impl PacedOp {
         }
     }
 
-    /// Dispatch to the correct inner-channel method for this op.
-    async fn dispatch(self, inner: &Arc<dyn Channel>) -> Result<()> {
+    /// Dispatch to the correct inner-channel method for this op. Only
+    /// final sends carry a delivery receipt; draft ids are channel-local
+    /// handles (Slack's are lazy placeholders), not platform message ids.
+    async fn dispatch(self, inner: &Arc<dyn Channel>) -> SendOutcome {
         match self {
-            Self::Send(message) => inner.send(&message).await,
+            Self::Send(message) => inner.send_with_receipt(&message).await,
             Self::FinalizeDraft {
                 recipient,
                 message_id,
@@ -56,19 +58,24 @@ impl PacedOp {
             } => {
                 inner
                     .finalize_draft(&recipient, &message_id, &text, suppress_voice)
-                    .await
+                    .await?;
+                Ok(None)
             }
         }
     }
 }
 
+/// Result of a paced dispatch: the inner channel's delivery receipt, when it
+/// reports one.
+type SendOutcome = Result<Option<DeliveryReceipt>>;
+
 /// Per-recipient queued operation waiting on its turn through the pacing floor.
 struct PendingSend {
     op: PacedOp,
     /// One-shot back-channel for delivering the eventual send result to
     /// the caller. The caller awaits this so a paced `send()` still
     /// returns the inner channel's result rather than swallowing it.
-    reply: oneshot::Sender<Result<()>>,
+    reply: oneshot::Sender<SendOutcome>,
 }
 
 /// Per-recipient pacing state.
@@ -169,10 +176,14 @@ impl PacedChannel {
         })
     }
 
-    async fn paced_dispatch(&self, op: PacedOp) -> Result<()> {
+    async fn paced_dispatch(&self, op: PacedOp) -> SendOutcome {
         let recipient_key = op.recipient().to_string();
 
-        let decision: (Option<PacedOp>, Option<oneshot::Receiver<Result<()>>>, bool) = {
+        let decision: (
+            Option<PacedOp>,
+            Option<oneshot::Receiver<SendOutcome>>,
+            bool,
+        ) = {
             let mut map = self.recipients.lock().await;
             map.evict_if_over_cap();
             let now = Instant::now();
@@ -257,7 +268,7 @@ impl PacedChannel {
                 ))
             });
         }
-        Ok(())
+        Ok(None)
     }
 
     /// Spawn the worker that drains a recipient's queue at the floor rate.
@@ -328,6 +339,10 @@ impl Channel for PacedChannel {
     }
 
     async fn send(&self, message: &SendMessage) -> Result<()> {
+        self.send_with_receipt(message).await.map(|_| ())
+    }
+
+    async fn send_with_receipt(&self, message: &SendMessage) -> Result<Option<DeliveryReceipt>> {
         self.paced_dispatch(PacedOp::Send(message.clone())).await
     }
 
@@ -339,6 +354,10 @@ impl Channel for PacedChannel {
         self.inner.health_check().await
     }
 
+    fn health_detail(&self) -> Option<String> {
+        self.inner.health_detail()
+    }
+
     async fn start_typing(&self, recipient: &str) -> Result<()> {
         self.inner.start_typing(recipient).await
     }
@@ -394,6 +413,7 @@ impl Channel for PacedChannel {
             suppress_voice,
         })
         .await
+        .map(|_| ())
     }
 
     async fn cancel_draft(&self, recipient: &str, message_id: &str) -> Result<()> {
@@ -797,6 +817,58 @@ mod tests {
         );
     }
 
+    struct ReceiptChannel;
+
+    impl Attributable for ReceiptChannel {
+        fn role(&self) -> Role {
+            Role::Channel(zeroclaw_api::attribution::ChannelKind::Cli)
+        }
+        fn alias(&self) -> &str {
+            "receipt"
+        }
+    }
+
+    #[async_trait]
+    impl Channel for ReceiptChannel {
+        fn name(&self) -> &str {
+            "receipt"
+        }
+        async fn send(&self, _message: &SendMessage) -> Result<()> {
+            Ok(())
+        }
+        async fn send_with_receipt(&self, message: &SendMessage) -> SendOutcome {
+            Ok(Some(DeliveryReceipt::new(format!(
+                "id-{}",
+                message.content
+            ))))
+        }
+        async fn listen(&self, _tx: tokio::sync::mpsc::Sender<ChannelMessage>) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn receipts_pass_through_immediate_and_queued_sends() {
+        let inner: Arc<dyn Channel> = Arc::new(ReceiptChannel);
+        let cfg = PacingFixture {
+            interval_secs: 1,
+            depth: 4,
+        };
+        let paced = PacedChannel::wrap(inner, &cfg);
+        let first = paced
+            .send_with_receipt(&SendMessage::new("a", "alice"))
+            .await
+            .unwrap();
+        assert_eq!(first.map(|r| r.message_id).as_deref(), Some("id-a"));
+        // The second send waits behind the floor and is drained by the
+        // worker; its receipt must survive the oneshot hand-off.
+        let second = paced
+            .send_with_receipt(&SendMessage::new("b", "alice"))
+            .await
+            .unwrap();
+        assert_eq!(second.map(|r| r.message_id).as_deref(), Some("id-b"));
+    }
+
     #[tokio::test]
     async fn room_management_forwards_to_inner_channel() {
         let counting = Arc::new(RoomManagementChannel {
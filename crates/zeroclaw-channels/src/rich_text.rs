@@ -0,0 +1,398 @@
+//! Reply shaping for channels that render markdown as a rich message type
+//! (DingTalk markdown messages, Lark interactive cards): the plain-vs-rich
+//! decision and size-limited chunking that keeps fenced code blocks whole.
+
+use zeroclaw_config::schema::RichTextMode;
+
+/// A platform's hard limit on one message body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TextLimit {
+    /// Limit counted in Unicode scalar values.
+    Chars(usize),
+    /// Limit counted in UTF-8 bytes.
+    Bytes(usize),
+}
+
+impl TextLimit {
+    fn max(self) -> usize {
+        match self {
+            Self::Chars(n) | Self::Bytes(n) => n,
+        }
+    }
+
+    fn measure(self, text: &str) -> usize {
+        match self {
+            Self::Chars(_) => text.chars().count(),
+            Self::Bytes(_) => text.len(),
+        }
+    }
+
+    fn fits(self, text: &str) -> bool {
+        self.measure(text) <= self.max()
+    }
+
+    fn shrink(self, by: usize) -> Self {
+        match self {
+            Self::Chars(n) => Self::Chars(n.saturating_sub(by).max(1)),
+            Self::Bytes(n) => Self::Bytes(n.saturating_sub(by).max(4)),
+        }
+    }
+}
+
+/// Whether `text` carries markdown worth rendering: fenced code, headings,
+/// lists, quotes, tables, emphasis or links.
+pub(crate) fn looks_like_markdown(text: &str) -> bool {
+    if text.contains("**") || text.contains("](") || text.contains('`') {
+        return true;
+    }
+    text.lines().any(|line| {
+        let line = line.trim_start();
+        line.starts_with('#')
+            || line.starts_with("> ")
+            || line.starts_with("- ")
+            || line.starts_with("* ")
+            || (line.starts_with('|') && line.ends_with('|'))
+            || is_ordered_list_item(line)
+    })
+}
+
+fn is_ordered_list_item(line: &str) -> bool {
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    digits > 0 && line[digits..].starts_with(". ")
+}
+
+/// Resolve the per-channel switch against the reply content.
+pub(crate) fn use_rich_format(mode: RichTextMode, text: &str) -> bool {
+    match mode {
+        RichTextMode::Always => true,
+        RichTextMode::Never => false,
+        RichTextMode::Auto => looks_like_markdown(text),
+    }
+}
+
+/// Opening fence marker (three or more backticks or tildes), if `line` opens one.
+fn fence_marker(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    let ch = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = trimmed.chars().take_while(|c| *c == ch).count();
+    (len >= 3).then(|| &trimmed[..len])
+}
+
+fn closes_fence(line: &str, marker: &str) -> bool {
+    let trimmed = line.trim();
+    let ch = marker.chars().next().unwrap_or('`');
+    trimmed.len() >= marker.len() && trimmed.chars().all(|c| c == ch)
+}
+
+enum Block {
+    Text(String),
+    /// Fenced code block: opening line, body lines, closing marker.
+    Code {
+        open: String,
+        body: Vec<String>,
+        close: String,
+        closed: bool,
+    },
+}
+
+impl Block {
+    fn render(&self) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            Self::Code {
+                open,
+                body,
+                close,
+                closed,
+            } => {
+                let mut out = open.clone();
+                for line in body {
+                    out.push('\n');
+                    out.push_str(line);
+                }
+                if *closed {
+                    out.push('\n');
+                    out.push_str(close);
+                }
+                out
+            }
+        }
+    }
+}
+
+/// Split `text` into paragraphs (separated by blank lines) and fenced code
+/// blocks. Blank lines inside a code block stay with the block.
+fn parse_blocks(text: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut lines = text.lines();
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>| {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Text(paragraph.join("\n")));
+            paragraph.clear();
+        }
+    };
+
+    while let Some(line) = lines.next() {
+        if let Some(marker) = fence_marker(line) {
+            flush(&mut paragraph, &mut blocks);
+            let marker = marker.to_string();
+            let mut body = Vec::new();
+            let mut closed = false;
+            for inner in lines.by_ref() {
+                if closes_fence(inner, &marker) {
+                    closed = true;
+                    break;
+                }
+                body.push(inner.to_string());
+            }
+            blocks.push(Block::Code {
+                open: line.to_string(),
+                body,
+                close: marker,
+                closed,
+            });
+        } else if line.trim().is_empty() {
+            flush(&mut paragraph, &mut blocks);
+        } else {
+            paragraph.push(line);
+        }
+    }
+    flush(&mut paragraph, &mut blocks);
+    blocks
+}
+
+/// Greedily pack lines under `limit`; a single line over the limit is cut
+/// at character boundaries.
+fn split_lines(text: &str, limit: TextLimit) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+    for line in text.lines() {
+        let candidate = if current.is_empty() {
+            line.to_string()
+        } else {
+            format!("{current}\n{line}")
+        };
+        if limit.fits(&candidate) {
+            current = candidate;
+            continue;
+        }
+        if !current.is_empty() {
+            pieces.push(std::mem::take(&mut current));
+        }
+        if limit.fits(line) {
+            current = line.to_string();
+            continue;
+        }
+        let mut buf = String::new();
+        for ch in line.chars() {
+            buf.push(ch);
+            if !limit.fits(&buf) {
+                buf.pop();
+                pieces.push(std::mem::take(&mut buf));
+                buf.push(ch);
+            }
+        }
+        current = buf;
+    }
+    if !current.is_empty() {
+        pieces.push(current);
+    }
+    pieces
+}
+
+/// Split a block that does not fit on its own. Code blocks are split by
+/// line and every piece is re-fenced so each chunk renders on its own.
+fn split_block(block: &Block, limit: TextLimit) -> Vec<String> {
+    match block {
+        Block::Text(text) => split_lines(text, limit),
+        Block::Code {
+            open, body, close, ..
+        } => {
+            // Two newlines join the fences to the body.
+            let overhead = limit.measure(open) + limit.measure(close) + 2;
+            split_lines(&body.join("\n"), limit.shrink(overhead))
+                .into_iter()
+                .map(|piece| format!("{open}\n{piece}\n{close}"))
+                .collect()
+        }
+    }
+}
+
+/// Chunk a reply under `limit`, splitting on paragraph boundaries first,
+/// then lines, then characters. Fenced code blocks are kept in one chunk
+/// when they fit and re-fenced per chunk when they do not.
+pub(crate) fn chunk_markdown(text: &str, limit: TextLimit) -> Vec<String> {
+    if limit.fits(text) {
+        return vec![text.to_string()];
+    }
+
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    for block in parse_blocks(text) {
+        let rendered = block.render();
+        if !current.is_empty() {
+            let candidate = format!("{current}\n\n{rendered}");
+            if limit.fits(&candidate) {
+                current = candidate;
+                continue;
+            }
+            chunks.push(std::mem::take(&mut current));
+        }
+        if limit.fits(&rendered) {
+            current = rendered;
+        } else {
+            let mut pieces = split_block(&block, limit);
+            current = pieces.pop().unwrap_or_default();
+            chunks.extend(pieces);
+        }
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Apply `f` to every line outside fenced code blocks. `f` also receives
+/// the following line, if any, so callers can tell paragraph ends apart.
+pub(crate) fn map_prose_lines(text: &str, f: impl Fn(&str, Option<&str>) -> String) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut out = Vec::with_capacity(lines.len());
+    let mut fence: Option<&str> = None;
+    for (idx, line) in lines.iter().enumerate() {
+        match fence {
+            Some(marker) => {
+                if closes_fence(line, marker) {
+                    fence = None;
+                }
+                out.push(line.to_string());
+            }
+            None => {
+                if let Some(marker) = fence_marker(line) {
+                    fence = Some(marker);
+                    out.push(line.to_string());
+                } else {
+                    out.push(f(line, lines.get(idx + 1).copied()));
+                }
+            }
+        }
+    }
+    out.join("\n")
+}
+
+/// Short plain-text summary of a reply for notification titles: the first
+/// non-empty line with markdown markers stripped.
+pub(crate) fn plain_title(text: &str, max_chars: usize) -> Option<String> {
+    let line = text
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && fence_marker(line).is_none())?;
+    let stripped: String = line
+        .trim_start_matches(['#', '>', '-', '*', ' '])
+        .chars()
+        .filter(|c| !matches!(c, '*' | '`' | '_'))
+        .collect();
+    let stripped = stripped.trim();
+    if stripped.is_empty() {
+        return None;
+    }
+    Some(crate::util::truncate_with_ellipsis(stripped, max_chars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_mode_detects_markdown() {
+        assert!(looks_like_markdown("## Summary\nAll good"));
+        assert!(looks_like_markdown("Run `cargo test` first"));
+        assert!(looks_like_markdown("1. first\n2. second"));
+        assert!(!looks_like_markdown("Sure, I will check tomorrow."));
+        assert!(!use_rich_format(RichTextMode::Auto, "plain reply"));
+        assert!(use_rich_format(RichTextMode::Always, "plain reply"));
+        assert!(!use_rich_format(RichTextMode::Never, "**bold**"));
+    }
+
+    #[test]
+    fn chunking_splits_on_paragraph_boundaries() {
+        let text = format!(
+            "{}\n\n{}\n\n{}",
+            "a".repeat(40),
+            "b".repeat(40),
+            "c".repeat(40)
+        );
+        let chunks = chunk_markdown(&text, TextLimit::Chars(90));
+        assert_eq!(
+            chunks,
+            vec![
+                format!("{}\n\n{}", "a".repeat(40), "b".repeat(40)),
+                "c".repeat(40),
+            ]
+        );
+    }
+
+    #[test]
+    fn chunking_keeps_code_blocks_whole_when_they_fit() {
+        let text = format!(
+            "{}\n\n```rust\nfn main() {{}}\n\nfn other() {{}}\n```\n\ntail",
+            "p".repeat(30)
+        );
+        let chunks = chunk_markdown(&text, TextLimit::Chars(50));
+        assert_eq!(chunks[0], "p".repeat(30));
+        assert_eq!(
+            chunks[1],
+            "```rust\nfn main() {}\n\nfn other() {}\n```\n\ntail"
+        );
+    }
+
+    #[test]
+    fn oversized_code_blocks_are_refenced_per_chunk() {
+        let body: Vec<String> = (0..20).map(|i| format!("line {i:02}")).collect();
+        let text = format!("```py\n{}\n```", body.join("\n"));
+        let chunks = chunk_markdown(&text, TextLimit::Bytes(60));
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert!(chunk.len() <= 60, "chunk over limit: {chunk:?}");
+            assert!(chunk.starts_with("```py\n"));
+            assert!(chunk.ends_with("\n```"));
+        }
+        let joined: Vec<&str> = chunks
+            .iter()
+            .flat_map(|c| c.lines().filter(|l| !l.starts_with("```")))
+            .collect();
+        assert_eq!(joined, body);
+    }
+
+    #[test]
+    fn chunking_counts_chars_not_bytes_when_asked() {
+        let text = "你好".repeat(30);
+        assert_eq!(
+            chunk_markdown(&text, TextLimit::Chars(60)),
+            vec![text.clone()]
+        );
+        let chunks = chunk_markdown(&text, TextLimit::Chars(25));
+        assert!(chunks.iter().all(|c| c.chars().count() <= 25));
+        assert_eq!(chunks.concat(), text);
+    }
+
+    #[test]
+    fn prose_mapping_skips_code_blocks() {
+        let text = "a\nb\n```\nx\ny\n```\nc";
+        let mapped = map_prose_lines(text, |line, next| {
+            format!("{line}{}", next.map_or("$", |_| "!"))
+        });
+        assert_eq!(mapped, "a!\nb!\n```\nx\ny\n```\nc$");
+    }
+
+    #[test]
+    fn title_strips_markdown_markers() {
+        assert_eq!(
+            plain_title("## **Deploy** report\nbody", 40).as_deref(),
+            Some("Deploy report")
+        );
+        assert_eq!(plain_title("```\ncode\n```", 40).as_deref(), Some("code"));
+        assert_eq!(plain_title("   \n", 40), None);
+    }
+}
@@ -6,6 +6,7 @@ use reqwest::Client;
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{Mutex, mpsc, oneshot};
@@ -18,6 +19,14 @@ const GROUP_TARGET_PREFIX: &str = "group:";
 
 const RECENT_TARGETS_CAPACITY: usize = 1024;
 
+/// Marker kinds sent as Signal attachments; `[LOCATION:]` stays as text.
+const OUTBOUND_MARKER_KINDS: &[&str] = &[
+    "IMAGE", "PHOTO", "DOCUMENT", "FILE", "VIDEO", "AUDIO", "VOICE",
+];
+
+/// Signal's per-attachment size limit.
+const MAX_ATTACHMENT_BYTES: u64 = 100 * 1024 * 1024;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum RecipientTarget {
     Direct(String),
@@ -40,6 +49,14 @@ pub struct SignalChannel {
     group_ids: Vec<String>,
     /// When true, accept only DMs and reject all group traffic.
     dm_only: bool,
+    /// When true, group messages are only accepted if they mention this
+    /// account or quote one of its messages. DMs are unaffected.
+    mention_only: bool,
+    /// UUID of `account`, resolved via `getUserStatus` when listening starts
+    /// and on health checks. Mentions and quotes may carry only the UUID.
+    account_uuid: Arc<SyncMutex<Option<String>>>,
+    /// Resolves relative and `/workspace/...` attachment marker paths.
+    workspace_dir: Option<PathBuf>,
     /// The alias key under `[channels.signal.<alias>]` this handle is
     /// bound to. Used to scope peer-group writes and resolver lookups.
     alias: String,
@@ -79,6 +96,12 @@ struct Envelope {
     data_message: Option<DataMessage>,
     #[serde(rename = "storyMessage", default)]
     story_message: Option<serde_json::Value>,
+    /// Delivery/read receipt. Carries no content for the agent.
+    #[serde(rename = "receiptMessage", default)]
+    receipt_message: Option<serde_json::Value>,
+    /// Typing start/stop indicator. Carries no content for the agent.
+    #[serde(rename = "typingMessage", default)]
+    typing_message: Option<serde_json::Value>,
     #[serde(default)]
     timestamp: Option<u64>,
 }
@@ -102,6 +125,31 @@ struct DataMessage {
     /// Native signal-cli daemon 0.14.x emits poll responses as `pollVote`.
     #[serde(rename = "pollVote", default)]
     poll_vote: Option<PollAnswer>,
+    #[serde(default)]
+    mentions: Option<Vec<Mention>>,
+    #[serde(default)]
+    quote: Option<Quote>,
+}
+
+/// An @-mention inside a group message body.
+#[derive(Debug, Deserialize)]
+struct Mention {
+    #[serde(default)]
+    number: Option<String>,
+    #[serde(default)]
+    uuid: Option<String>,
+}
+
+/// The message a reply quotes. `author` is the number when known,
+/// otherwise the UUID.
+#[derive(Debug, Deserialize)]
+struct Quote {
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(rename = "authorNumber", default)]
+    author_number: Option<String>,
+    #[serde(rename = "authorUuid", default)]
+    author_uuid: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -151,6 +199,9 @@ impl SignalChannel {
             account,
             group_ids,
             dm_only,
+            mention_only: false,
+            account_uuid: Arc::new(SyncMutex::new(None)),
+            workspace_dir: None,
             alias: alias.into(),
             peer_resolver,
             ignore_attachments,
@@ -182,6 +233,18 @@ impl SignalChannel {
         self
     }
 
+    /// Only respond in groups when mentioned or replied to.
+    pub fn with_mention_only(mut self, mention_only: bool) -> Self {
+        self.mention_only = mention_only;
+        self
+    }
+
+    /// Workspace used to resolve `[IMAGE:]`/`[DOCUMENT:]` marker paths.
+    pub fn with_workspace_dir(mut self, dir: PathBuf) -> Self {
+        self.workspace_dir = Some(dir);
+        self
+    }
+
     fn http_client(&self) -> Client {
         let builder = Client::builder().connect_timeout(Duration::from_secs(10));
         let builder = zeroclaw_config::schema::apply_channel_proxy_to_builder(
@@ -318,6 +381,32 @@ impl SignalChannel {
         }
     }
 
+    /// Whether `number`/`uuid` identify this channel's own account.
+    fn is_own_identity(&self, number: Option<&str>, uuid: Option<&str>) -> bool {
+        if number.is_some_and(|n| n == self.account) {
+            return true;
+        }
+        let own_uuid = self.account_uuid.lock();
+        matches!((uuid, own_uuid.as_deref()), (Some(a), Some(b)) if a.eq_ignore_ascii_case(b))
+    }
+
+    /// True when a group message mentions this account or quotes one of
+    /// its messages.
+    fn addresses_bot(&self, data_msg: &DataMessage) -> bool {
+        let mentioned = data_msg
+            .mentions
+            .iter()
+            .flatten()
+            .any(|m| self.is_own_identity(m.number.as_deref(), m.uuid.as_deref()));
+        let quoted = data_msg.quote.as_ref().is_some_and(|q| {
+            self.is_own_identity(
+                q.author_number.as_deref().or(q.author.as_deref()),
+                q.author_uuid.as_deref().or(q.author.as_deref()),
+            )
+        });
+        mentioned || quoted
+    }
+
     /// Determine the send target: group id or the sender's number.
     fn reply_target(&self, data_msg: &DataMessage, sender: &str) -> String {
         if let Some(group_id) = data_msg
@@ -398,6 +487,11 @@ impl SignalChannel {
     /// treat any vec from this method as "the user's reply set" and
     /// dispatch each entry through their normal inbound pipeline.
     fn process_envelope(&self, envelope: &Envelope) -> Vec<ChannelMessage> {
+        // Receipts and typing indicators never carry content for the agent.
+        if envelope.receipt_message.is_some() || envelope.typing_message.is_some() {
+            return Vec::new();
+        }
+
         // Skip story messages when configured
         if self.ignore_stories && envelope.story_message.is_some() {
             return Vec::new();
@@ -431,6 +525,16 @@ impl SignalChannel {
             return Vec::new();
         }
 
+        // Poll votes answer the bot's own poll, so they bypass mention gating.
+        let is_group = data_msg
+            .group_info
+            .as_ref()
+            .is_some_and(|g| g.group_id.is_some());
+        let is_poll_vote = data_msg.poll_answer.is_some() || data_msg.poll_vote.is_some();
+        if self.mention_only && is_group && !is_poll_vote && !self.addresses_bot(data_msg) {
+            return Vec::new();
+        }
+
         let target = self.reply_target(data_msg, &sender);
 
         let timestamp = data_msg
@@ -469,11 +573,13 @@ impl SignalChannel {
                 Vec::new()
             }
         } else {
+            // signal-cli puts U+FFFC where each @-mention sits in the body.
             data_msg
                 .message
                 .as_deref()
+                .map(|t| t.replace('\u{FFFC}', "").trim().to_string())
                 .filter(|t| !t.is_empty())
-                .map(|t| vec![t.to_string()])
+                .map(|t| vec![t])
                 .unwrap_or_default()
         };
         contents
@@ -511,6 +617,133 @@ impl SignalChannel {
             })
             .collect()
     }
+
+    /// Ask signal-cli whether `account` is registered and learn its UUID.
+    async fn account_status(&self) -> anyhow::Result<(bool, Option<String>)> {
+        let params = serde_json::json!({
+            "recipient": [&self.account],
+            "account": &self.account,
+        });
+        let result = self.rpc_request("getUserStatus", params).await?;
+        let status = parse_user_status(result.as_ref(), &self.account);
+        if let Some(uuid) = &status.1 {
+            *self.account_uuid.lock() = Some(uuid.clone());
+        }
+        Ok(status)
+    }
+
+    /// Map a `/workspace/...` or relative marker path onto the host workspace.
+    fn resolve_attachment_path(&self, target: &str) -> PathBuf {
+        match (&self.workspace_dir, target.strip_prefix("/workspace/")) {
+            (Some(ws), Some(rel)) => ws.join(rel),
+            (Some(ws), None) if Path::new(target).is_relative() => ws.join(target),
+            _ => PathBuf::from(target),
+        }
+    }
+
+    /// Load an `[IMAGE:]`/`[DOCUMENT:]` marker target (local path or URL)
+    /// as a signal-cli data URI.
+    async fn load_marker_attachment(&self, target: &str) -> anyhow::Result<String> {
+        if target.starts_with("http://") || target.starts_with("https://") {
+            let resp = self
+                .http_client()
+                .get(target)
+                .timeout(Duration::from_secs(60))
+                .send()
+                .await?
+                .error_for_status()?;
+            if resp
+                .content_length()
+                .is_some_and(|len| len > MAX_ATTACHMENT_BYTES)
+            {
+                anyhow::bail!("attachment exceeds Signal's {MAX_ATTACHMENT_BYTES} byte limit");
+            }
+            let mime = resp
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.split(';').next())
+                .map(|v| v.trim().to_string());
+            let file_name = resp
+                .url()
+                .path_segments()
+                .and_then(|mut segments| segments.next_back())
+                .filter(|name| !name.is_empty())
+                .unwrap_or("attachment")
+                .to_string();
+            let data = resp.bytes().await?;
+            if data.len() as u64 > MAX_ATTACHMENT_BYTES {
+                anyhow::bail!("attachment exceeds Signal's {MAX_ATTACHMENT_BYTES} byte limit");
+            }
+            return Ok(attachment_data_uri(&file_name, mime.as_deref(), &data));
+        }
+
+        let path = self.resolve_attachment_path(target);
+        let metadata = tokio::fs::metadata(&path)
+            .await
+            .map_err(|e| anyhow::Error::msg(format!("attachment not found: {e}")))?;
+        if !metadata.is_file() {
+            anyhow::bail!("attachment is not a regular file");
+        }
+        if metadata.len() > MAX_ATTACHMENT_BYTES {
+            anyhow::bail!("attachment exceeds Signal's {MAX_ATTACHMENT_BYTES} byte limit");
+        }
+        let data = tokio::fs::read(&path).await?;
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("attachment");
+        Ok(attachment_data_uri(file_name, None, &data))
+    }
+
+    /// Split `[IMAGE:]`/`[DOCUMENT:]` markers out of `message` and load them
+    /// together with `message.attachments`. Markers that fail to load are
+    /// logged and replaced by a short note (or the URL) in the text.
+    async fn collect_outbound_attachments(&self, message: &SendMessage) -> (String, Vec<String>) {
+        let (mut text, markers) =
+            crate::util::parse_attachment_markers_of_kinds(&message.content, OUTBOUND_MARKER_KINDS);
+        let mut attachments: Vec<String> = message
+            .attachments
+            .iter()
+            .filter(|att| !att.data.is_empty())
+            .map(|att| attachment_data_uri(&att.file_name, att.mime_type.as_deref(), &att.data))
+            .collect();
+        let mut notes = Vec::new();
+        for (_kind, target) in markers {
+            match self.load_marker_attachment(&target).await {
+                Ok(uri) => attachments.push(uri),
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                            .with_attrs(::serde_json::json!({
+                                "target": target,
+                                "error": format!("{e:#}"),
+                            })),
+                        "Dropping Signal attachment marker"
+                    );
+                    if target.starts_with("http://") || target.starts_with("https://") {
+                        notes.push(target);
+                    } else {
+                        let name = Path::new(&target)
+                            .file_name()
+                            .and_then(|n| n.to_str())
+                            .unwrap_or(&target);
+                        notes.push(format!("[attachment omitted: {name}]"));
+                    }
+                }
+            }
+        }
+        if !notes.is_empty() {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+            }
+            text.push_str(&notes.join("\n"));
+        }
+        (text, attachments)
+    }
+
     fn random_id_suffix() -> String {
         use rand::RngExt;
         const CHARSET: &[u8] = b"0123456789abcdef";
@@ -552,6 +785,60 @@ impl SignalChannel {
     }
 }
 
+/// Build a signal-cli attachment data URI
+/// (`data:<mime>;filename=<name>;base64,<data>`).
+fn attachment_data_uri(file_name: &str, mime: Option<&str>, data: &[u8]) -> String {
+    use base64::Engine as _;
+    let file_name: String = Path::new(file_name)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("attachment")
+        .chars()
+        .map(|c| if matches!(c, ';' | ',') { '_' } else { c })
+        .collect();
+    let mime = mime
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| {
+            mime_guess::from_path(&file_name)
+                .first_or_octet_stream()
+                .essence_str()
+                .to_string()
+        });
+    format!(
+        "data:{mime};filename={file_name};base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(data)
+    )
+}
+
+/// Read `(isRegistered, uuid)` for `account` from a `getUserStatus` result.
+fn parse_user_status(result: Option<&serde_json::Value>, account: &str) -> (bool, Option<String>) {
+    let Some(entries) = result.and_then(serde_json::Value::as_array) else {
+        return (false, None);
+    };
+    let entry = entries
+        .iter()
+        .find(|e| {
+            e.get("number").and_then(serde_json::Value::as_str) == Some(account)
+                || e.get("recipient").and_then(serde_json::Value::as_str) == Some(account)
+        })
+        .or_else(|| entries.first());
+    let Some(entry) = entry else {
+        return (false, None);
+    };
+    let registered = entry
+        .get("isRegistered")
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false);
+    let uuid = entry
+        .get("uuid")
+        .and_then(serde_json::Value::as_str)
+        .filter(|u| !u.is_empty())
+        .map(str::to_string);
+    (registered, uuid)
+}
+
 impl ::zeroclaw_api::attribution::Attributable for SignalChannel {
     fn role(&self) -> ::zeroclaw_api::attribution::Role {
         ::zeroclaw_api::attribution::Role::Channel(::zeroclaw_api::attribution::ChannelKind::Signal)
@@ -568,18 +855,22 @@ impl Channel for SignalChannel {
     }
 
     async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
-        let params = match Self::parse_recipient_target(&message.recipient) {
+        let (text, attachments) = self.collect_outbound_attachments(message).await;
+        let mut params = match Self::parse_recipient_target(&message.recipient) {
             RecipientTarget::Direct(number) => serde_json::json!({
                 "recipient": [number],
-                "message": &message.content,
+                "message": text,
                 "account": &self.account,
             }),
             RecipientTarget::Group(group_id) => serde_json::json!({
                 "groupId": group_id,
-                "message": &message.content,
+                "message": text,
                 "account": &self.account,
             }),
         };
+        if !attachments.is_empty() {
+            params["attachments"] = serde_json::json!(attachments);
+        }
 
         self.rpc_request("send", params).await?;
         Ok(())
@@ -644,6 +935,20 @@ impl Channel for SignalChannel {
             &format!("channel listening via SSE on {}...", self.http_url)
         );
 
+        // Mentions and quotes can identify the bot by UUID only; learn it
+        // up front so `mention_only` recognises them.
+        if self.mention_only
+            && let Err(e) = self.account_status().await
+        {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Query)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                "Could not resolve Signal account UUID; mention_only matches by number only"
+            );
+        }
+
         let mut retry_delay_secs = 2u64;
         let max_delay_secs = 60u64;
 
@@ -844,7 +1149,25 @@ impl Channel for SignalChannel {
         else {
             return false;
         };
-        resp.status().is_success()
+        if !resp.status().is_success() {
+            return false;
+        }
+
+        // A reachable daemon can still have lost its linked device; confirm
+        // the account itself is registered.
+        match self.account_status().await {
+            Ok((registered, _)) => registered,
+            Err(e) => {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Query)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                    "Signal account status check failed"
+                );
+                false
+            }
+        }
     }
 
     async fn start_typing(&self, recipient: &str) -> anyhow::Result<()> {
@@ -939,8 +1262,12 @@ mod tests {
                 attachments: None,
                 poll_answer: None,
                 poll_vote: None,
+                mentions: None,
+                quote: None,
             }),
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: Some(1_700_000_000_000),
         }
     }
@@ -1112,6 +1439,8 @@ mod tests {
             attachments: None,
             poll_answer: None,
             poll_vote: None,
+            mentions: None,
+            quote: None,
         };
         assert!(ch.matches_group(&dm));
 
@@ -1124,6 +1453,8 @@ mod tests {
             attachments: None,
             poll_answer: None,
             poll_vote: None,
+            mentions: None,
+            quote: None,
         };
         assert!(ch.matches_group(&group));
     }
@@ -1152,6 +1483,8 @@ mod tests {
             attachments: None,
             poll_answer: None,
             poll_vote: None,
+            mentions: None,
+            quote: None,
         };
         assert!(ch.matches_group(&matching));
 
@@ -1164,6 +1497,8 @@ mod tests {
             attachments: None,
             poll_answer: None,
             poll_vote: None,
+            mentions: None,
+            quote: None,
         };
         assert!(!ch.matches_group(&non_matching));
     }
@@ -1190,6 +1525,8 @@ mod tests {
             attachments: None,
             poll_answer: None,
             poll_vote: None,
+            mentions: None,
+            quote: None,
         };
         assert!(ch.matches_group(&dm));
 
@@ -1202,6 +1539,8 @@ mod tests {
             attachments: None,
             poll_answer: None,
             poll_vote: None,
+            mentions: None,
+            quote: None,
         };
         assert!(!ch.matches_group(&group));
     }
@@ -1228,6 +1567,8 @@ mod tests {
             attachments: None,
             poll_answer: None,
             poll_vote: None,
+            mentions: None,
+            quote: None,
         };
         assert_eq!(ch.reply_target(&dm, "+1111111111"), "+1111111111");
     }
@@ -1256,6 +1597,8 @@ mod tests {
             attachments: None,
             poll_answer: None,
             poll_vote: None,
+            mentions: None,
+            quote: None,
         };
         assert_eq!(ch.reply_target(&group, "+1111111111"), "group:group123");
     }
@@ -1318,6 +1661,8 @@ mod tests {
             source_number: Some("+1111111111".to_string()),
             data_message: None,
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: Some(1000),
         };
         assert_eq!(SignalChannel::sender(&env), Some("+1111111111".to_string()));
@@ -1330,6 +1675,8 @@ mod tests {
             source_number: None,
             data_message: None,
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: Some(1000),
         };
         assert_eq!(SignalChannel::sender(&env), Some("uuid-123".to_string()));
@@ -1361,8 +1708,12 @@ mod tests {
                 attachments: None,
                 poll_answer: None,
                 poll_vote: None,
+                mentions: None,
+                quote: None,
             }),
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: Some(1_700_000_000_000),
         };
         let mut msgs = ch.process_envelope(&env);
@@ -1420,8 +1771,12 @@ mod tests {
                 attachments: None,
                 poll_answer: None,
                 poll_vote: None,
+                mentions: None,
+                quote: None,
             }),
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: Some(1_700_000_000_000),
         };
         let mut msgs = ch.process_envelope(&env);
@@ -1442,6 +1797,8 @@ mod tests {
             source_number: None,
             data_message: None,
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: None,
         };
         assert_eq!(SignalChannel::sender(&env), None);
@@ -1588,8 +1945,12 @@ mod tests {
                 attachments: Some(vec![serde_json::json!({"contentType": "image/png"})]),
                 poll_answer: None,
                 poll_vote: None,
+                mentions: None,
+                quote: None,
             }),
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: Some(1_700_000_000_000),
         };
         assert!(ch.process_envelope(&env).is_empty());
@@ -1622,8 +1983,12 @@ mod tests {
                 attachments: None,
                 poll_answer: None,
                 poll_vote: None,
+                mentions: None,
+                quote: None,
             }),
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: Some(1_700_000_000_000),
         };
         let mut msgs = ch.process_envelope(&env);
@@ -1678,8 +2043,12 @@ mod tests {
                 attachments: None,
                 poll_answer: None,
                 poll_vote: None,
+                mentions: None,
+                quote: None,
             }),
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: Some(1_700_000_000_000),
         };
         let mut msgs = ch.process_envelope(&env);
@@ -1908,8 +2277,12 @@ mod tests {
                 attachments: None,
                 poll_answer: None,
                 poll_vote: None,
+                mentions: None,
+                quote: None,
             }),
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: Some(1_700_000_000_000),
         };
         let mut msgs = ch.process_envelope(&env);
@@ -1989,8 +2362,12 @@ mod tests {
                     selected_titles: selected_titles.iter().map(|s| s.to_string()).collect(),
                 }),
                 poll_vote: None,
+                mentions: None,
+                quote: None,
             }),
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: Some(1_700_000_000_000),
         }
     }
@@ -2005,6 +2382,8 @@ mod tests {
                 group_info: None,
                 attachments: None,
                 poll_answer: None,
+                mentions: None,
+                quote: None,
                 poll_vote: Some(PollAnswer {
                     poll_id: Some(1),
                     selected_indices: option_indexes,
@@ -2012,6 +2391,8 @@ mod tests {
                 }),
             }),
             story_message: None,
+            receipt_message: None,
+            typing_message: None,
             timestamp: Some(1_700_000_000_000),
         }
     }
@@ -2106,4 +2487,123 @@ mod tests {
         let env = poll_envelope(Some("+1111111111"), vec![], vec![]);
         assert!(ch.process_envelope(&env).is_empty());
     }
+
+    fn sse_envelope(json: &str) -> Envelope {
+        serde_json::from_str::<SseEnvelope>(json)
+            .unwrap()
+            .envelope
+            .unwrap()
+    }
+
+    #[test]
+    fn process_envelope_skips_receipts_and_typing() {
+        let ch = make_channel();
+        let receipt = sse_envelope(
+            r#"{"envelope":{"sourceNumber":"+1111111111","timestamp":1700000000000,
+                "receiptMessage":{"when":1700000000000,"isDelivery":true,"isRead":false,"timestamps":[1]}}}"#,
+        );
+        let typing = sse_envelope(
+            r#"{"envelope":{"sourceNumber":"+1111111111","timestamp":1700000000000,
+                "typingMessage":{"action":"STARTED","timestamp":1700000000000}}}"#,
+        );
+        assert!(ch.process_envelope(&receipt).is_empty());
+        assert!(ch.process_envelope(&typing).is_empty());
+    }
+
+    fn group_message_json(extra: &str) -> String {
+        format!(
+            r#"{{"envelope":{{"sourceNumber":"+1111111111","timestamp":1700000000000,
+                "dataMessage":{{"message":"\uFFFC what's the weather?","timestamp":1700000000000,
+                "groupInfo":{{"groupId":"group_xyz"}}{extra}}}}}}}"#
+        )
+    }
+
+    #[test]
+    fn mention_only_drops_unaddressed_group_messages() {
+        let ch = make_channel().with_mention_only(true);
+        let env = sse_envelope(&group_message_json(""));
+        assert!(ch.process_envelope(&env).is_empty());
+
+        let other = sse_envelope(&group_message_json(
+            r#","mentions":[{"number":"+2222222222","start":0,"length":1}]"#,
+        ));
+        assert!(ch.process_envelope(&other).is_empty());
+    }
+
+    #[test]
+    fn mention_only_accepts_number_mention_and_strips_placeholder() {
+        let ch = make_channel().with_mention_only(true);
+        let env = sse_envelope(&group_message_json(
+            r#","mentions":[{"number":"+1234567890","start":0,"length":1}]"#,
+        ));
+        let msgs = ch.process_envelope(&env);
+        assert_eq!(msgs.len(), 1);
+        assert_eq!(msgs[0].content, "what's the weather?");
+    }
+
+    #[test]
+    fn mention_only_accepts_uuid_mention_and_quote_reply() {
+        let ch = make_channel().with_mention_only(true);
+        *ch.account_uuid.lock() = Some("A1B2C3D4-0000-4000-8000-000000000001".into());
+
+        let mention = sse_envelope(&group_message_json(
+            r#","mentions":[{"uuid":"a1b2c3d4-0000-4000-8000-000000000001","start":0,"length":1}]"#,
+        ));
+        assert_eq!(ch.process_envelope(&mention).len(), 1);
+
+        let reply = sse_envelope(&group_message_json(
+            r#","quote":{"id":1699999999000,"author":"+1234567890","authorNumber":"+1234567890","text":"hi"}"#,
+        ));
+        assert_eq!(ch.process_envelope(&reply).len(), 1);
+    }
+
+    #[test]
+    fn mention_only_leaves_dms_alone() {
+        let ch = make_channel().with_mention_only(true);
+        let env = make_envelope(Some("+1111111111"), Some("hello"));
+        assert_eq!(ch.process_envelope(&env).len(), 1);
+    }
+
+    #[test]
+    fn attachment_data_uri_guesses_mime_and_sanitizes_name() {
+        let uri = attachment_data_uri("/tmp/chart;v2.png", None, b"png");
+        assert_eq!(uri, "data:image/png;filename=chart_v2.png;base64,cG5n");
+
+        let uri = attachment_data_uri("report", Some("application/pdf"), b"");
+        assert!(uri.starts_with("data:application/pdf;filename=report;base64,"));
+    }
+
+    #[test]
+    fn parse_user_status_reads_registration_and_uuid() {
+        let result = serde_json::json!([{
+            "recipient": "+1234567890",
+            "number": "+1234567890",
+            "uuid": "a1b2c3d4-0000-4000-8000-000000000001",
+            "isRegistered": true
+        }]);
+        assert_eq!(
+            parse_user_status(Some(&result), "+1234567890"),
+            (
+                true,
+                Some("a1b2c3d4-0000-4000-8000-000000000001".to_string())
+            )
+        );
+        assert_eq!(parse_user_status(None, "+1234567890"), (false, None));
+    }
+
+    #[tokio::test]
+    async fn collect_outbound_attachments_loads_workspace_markers() {
+        let ws = tempfile::tempdir().unwrap();
+        std::fs::write(ws.path().join("chart.png"), b"png").unwrap();
+        let ch = make_channel().with_workspace_dir(ws.path().to_path_buf());
+
+        let msg = SendMessage::new(
+            "Here you go [IMAGE:/workspace/chart.png] [DOCUMENT:missing.pdf]",
+            "+1111111111",
+        );
+        let (text, attachments) = ch.collect_outbound_attachments(&msg).await;
+        assert_eq!(attachments.len(), 1);
+        assert!(attachments[0].starts_with("data:image/png;filename=chart.png;base64,"));
+        assert_eq!(text, "Here you go\n\n[attachment omitted: missing.pdf]");
+    }
 }
@@ -12,10 +12,19 @@ use tokio::io::AsyncWriteExt;
 use tokio::sync::{Mutex as AsyncMutex, oneshot};
 use tokio_tungstenite::tungstenite::Message as WsMessage;
 use zeroclaw_api::channel::{
-    Channel, ChannelApprovalRequest, ChannelApprovalResponse, ChannelMessage, SendMessage,
+    Channel, ChannelApprovalRequest, ChannelApprovalResponse, ChannelMessage, DeliveryReceipt,
+    SendMessage,
 };
 use zeroclaw_api::media::MediaAttachment;
 
+#[derive(Debug, Clone, Copy)]
+struct SlackDraftBackoff {
+    /// Draft edits for the channel are skipped until this instant.
+    until: Instant,
+    /// Consecutive 429 responses, used as the exponential backoff exponent.
+    attempt: u32,
+}
+
 #[derive(Clone)]
 struct CachedSlackDisplayName {
     display_name: String,
@@ -59,6 +68,9 @@ pub struct SlackChannel {
     draft_update_interval_ms: u64,
     /// Per-channel rate-limit tracker for draft edits.
     last_draft_edit: Mutex<HashMap<String, Instant>>,
+    /// Per-channel backoff state after Slack rejects a draft edit with 429.
+    /// Shared with the spawned `chat.update` tasks that observe the response.
+    draft_backoff: Arc<Mutex<HashMap<String, SlackDraftBackoff>>>,
     /// Maps lazy placeholder IDs to real Slack message timestamps.
     /// `send_draft` returns a placeholder without posting; the real message
     /// is created on the first `update_draft` call.
@@ -218,6 +230,13 @@ fn unicode_emoji_to_slack_name(emoji: &str) -> &str {
 /// Slack's `chat.update` is rate-limited to ~1 req/sec per channel.
 const SLACK_DRAFT_UPDATE_INTERVAL_MS: u64 = 1200;
 
+/// Floor for the configured draft edit interval; anything faster trips
+/// Slack's per-channel `chat.update` limit.
+const SLACK_DRAFT_MIN_UPDATE_INTERVAL_MS: u64 = 1000;
+
+/// Upper bound for the draft edit backoff after Slack answers HTTP 429.
+const SLACK_DRAFT_MAX_BACKOFF_SECS: u64 = 30;
+
 /// Maximum text length for a single Slack message (approx 40k chars).
 const SLACK_MESSAGE_MAX_CHARS: usize = 40_000;
 
@@ -269,6 +288,7 @@ impl SlackChannel {
             stream_drafts: false,
             draft_update_interval_ms: SLACK_DRAFT_UPDATE_INTERVAL_MS,
             last_draft_edit: Mutex::new(HashMap::new()),
+            draft_backoff: Arc::new(Mutex::new(HashMap::new())),
             lazy_draft_ts: tokio::sync::Mutex::new(HashMap::new()),
             cancel_reaction: None,
             pending_approvals: Arc::new(AsyncMutex::new(HashMap::new())),
@@ -392,10 +412,11 @@ impl SlackChannel {
     }
 
     /// Enable progressive draft message streaming via `chat.update`.
+    /// Intervals below Slack's ~1 edit/sec budget are raised to that floor.
     pub fn with_streaming(mut self, enabled: bool, interval_ms: u64) -> Self {
         self.stream_drafts = enabled;
         if interval_ms > 0 {
-            self.draft_update_interval_ms = interval_ms;
+            self.draft_update_interval_ms = interval_ms.max(SLACK_DRAFT_MIN_UPDATE_INTERVAL_MS);
         }
         self
     }
@@ -415,7 +436,7 @@ impl SlackChannel {
 
         let resp = self
             .http_client()
-            .post("https://slack.com/api/chat.delete")
+            .post(self.slack_api_url("chat.delete"))
             .bearer_auth(&self.bot_token)
             .json(&body)
             .send()
@@ -449,6 +470,50 @@ impl SlackChannel {
         self.lazy_draft_ts.lock().await.get(message_id).cloned()
     }
 
+    /// Whether draft edits for `channel_id` are paused after a 429.
+    fn draft_backoff_active(&self, channel_id: &str) -> bool {
+        self.draft_backoff
+            .lock()
+            .ok()
+            .and_then(|map| map.get(channel_id).map(|b| Instant::now() < b.until))
+            .unwrap_or(false)
+    }
+
+    fn clear_draft_backoff(&self, channel_id: &str) {
+        if let Ok(mut map) = self.draft_backoff.lock() {
+            map.remove(channel_id);
+        }
+    }
+
+    /// Record a 429 for a draft edit and return how long edits stay paused.
+    /// Consecutive 429s double the wait, starting from Slack's `Retry-After`.
+    fn record_draft_rate_limit(
+        backoff: &Mutex<HashMap<String, SlackDraftBackoff>>,
+        channel_id: &str,
+        retry_after_secs: Option<u64>,
+    ) -> Duration {
+        let Ok(mut map) = backoff.lock() else {
+            return Duration::ZERO;
+        };
+        let attempt = map
+            .get(channel_id)
+            .map_or(0, |b| b.attempt.saturating_add(1));
+        let wait = Self::compute_exponential_backoff_delay(
+            retry_after_secs.unwrap_or(1).max(1),
+            attempt,
+            SLACK_DRAFT_MAX_BACKOFF_SECS,
+            Self::jitter_ms(SLACK_HISTORY_MAX_JITTER_MS),
+        );
+        map.insert(
+            channel_id.to_string(),
+            SlackDraftBackoff {
+                until: Instant::now() + wait,
+                attempt,
+            },
+        );
+        wait
+    }
+
     /// Post the initial draft message and store the mapping from
     /// lazy placeholder ID to real Slack ts.
     async fn materialize_lazy_draft(
@@ -482,7 +547,7 @@ impl SlackChannel {
 
         let resp = self
             .http_client()
-            .post("https://slack.com/api/chat.postMessage")
+            .post(self.slack_api_url("chat.postMessage"))
             .bearer_auth(&self.bot_token)
             .json(&body)
             .send()
@@ -4278,6 +4343,13 @@ impl Channel for SlackChannel {
     }
 
     async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
+        self.send_with_receipt(message).await.map(|_| ())
+    }
+
+    async fn send_with_receipt(
+        &self,
+        message: &SendMessage,
+    ) -> anyhow::Result<Option<DeliveryReceipt>> {
         let thread_ts = self.outbound_thread_ts(message);
         let mut outbound_attachments = message.attachments.clone();
 
@@ -4309,7 +4381,7 @@ impl Channel for SlackChannel {
                     &outbound_attachments,
                 )
                 .await?;
-                return Ok(());
+                return Ok(None);
             }
 
             let mut body = serde_json::json!({
@@ -4388,13 +4460,18 @@ impl Channel for SlackChannel {
                 .unwrap_or("unknown");
             anyhow::bail!("chat.postMessage failed: {err}");
         }
+        // The message `ts` is Slack's message id within the channel.
+        let receipt = parsed
+            .get("ts")
+            .and_then(serde_json::Value::as_str)
+            .map(DeliveryReceipt::new);
 
         if !outbound_attachments.is_empty() {
             self.upload_outbound_attachments(&message.recipient, thread_ts, &outbound_attachments)
                 .await?;
         }
 
-        Ok(())
+        Ok(receipt)
     }
 
     fn supports_draft_updates(&self) -> bool {
@@ -4439,6 +4516,11 @@ impl Channel for SlackChannel {
             None => return Ok(()),
         };
 
+        // Back off entirely while Slack is answering this channel with 429.
+        if self.draft_backoff_active(recipient) {
+            return Ok(());
+        }
+
         // Rate-limit edits per channel
         {
             let last_edits = self.last_draft_edit.lock().expect("last_draft_edit lock");
@@ -4473,6 +4555,8 @@ impl Channel for SlackChannel {
         let client = self.http_client();
         let token = self.bot_token.clone();
         let channel = recipient.to_string();
+        let update_url = self.slack_api_url("chat.update");
+        let backoff = Arc::clone(&self.draft_backoff);
         zeroclaw_spawn::spawn!(async move {
             let mut body = serde_json::json!({
                 "channel": channel,
@@ -4486,13 +4570,30 @@ impl Channel for SlackChannel {
                 }]);
             }
             match client
-                .post("https://slack.com/api/chat.update")
+                .post(&update_url)
                 .bearer_auth(&token)
                 .json(&body)
                 .send()
                 .await
             {
+                Ok(resp) if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                    let retry_after = SlackChannel::parse_retry_after_secs(resp.headers());
+                    let wait =
+                        SlackChannel::record_draft_rate_limit(&backoff, &channel, retry_after);
+                    ::zeroclaw_log::record!(
+                        DEBUG,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_attrs(::serde_json::json!({
+                                "channel": channel,
+                                "backoff_ms": u64::try_from(wait.as_millis()).unwrap_or(u64::MAX),
+                            })),
+                        "chat.update (draft) rate limited; backing off"
+                    );
+                }
                 Ok(resp) => {
+                    if let Ok(mut map) = backoff.lock() {
+                        map.remove(&channel);
+                    }
                     if let Ok(resp_body) = resp.json::<serde_json::Value>().await
                         && resp_body.get("ok") != Some(&serde_json::Value::Bool(true))
                     {
@@ -4553,6 +4654,7 @@ impl Channel for SlackChannel {
             .lock()
             .expect("last_draft_edit lock")
             .remove(recipient);
+        self.clear_draft_backoff(recipient);
 
         // Extract thread_ts from the lazy draft ID ("lazy:{channel}:{thread_ts}")
         // so fallback sends preserve thread context.
@@ -4596,13 +4698,13 @@ impl Channel for SlackChannel {
 
         let resp = self
             .http_client()
-            .post("https://slack.com/api/chat.update")
+            .post(self.slack_api_url("chat.update"))
             .bearer_auth(&self.bot_token)
             .json(&body)
             .send()
             .await?;
 
-        let resp_body: serde_json::Value = resp.json().await?;
+        let resp_body: serde_json::Value = resp.json().await.unwrap_or_default();
         if resp_body.get("ok") == Some(&serde_json::Value::Bool(true)) {
             return Ok(());
         }
@@ -4629,6 +4731,7 @@ impl Channel for SlackChannel {
             .lock()
             .expect("last_draft_edit lock")
             .remove(recipient);
+        self.clear_draft_backoff(recipient);
         let real_ts = self.resolve_draft_ts(message_id).await;
         self.lazy_draft_ts.lock().await.remove(message_id);
         if let Some(ts) = real_ts {
@@ -6072,6 +6175,142 @@ mod tests {
         );
     }
 
+    fn streaming_slack_channel(server: &wiremock::MockServer) -> SlackChannel {
+        SlackChannel::new(
+            "xoxb-fake".into(),
+            None,
+            vec![],
+            "slack_test_alias",
+            Arc::new(Vec::new),
+        )
+        .with_streaming(true, SLACK_DRAFT_UPDATE_INTERVAL_MS)
+        .with_api_base_url(server.uri())
+    }
+
+    #[test]
+    fn with_streaming_clamps_interval_to_slack_rate_limit() {
+        let ch = SlackChannel::new("xoxb-fake".into(), None, vec![], "a", Arc::new(Vec::new))
+            .with_streaming(true, 100);
+        assert_eq!(
+            ch.draft_update_interval_ms,
+            SLACK_DRAFT_MIN_UPDATE_INTERVAL_MS
+        );
+    }
+
+    #[tokio::test]
+    async fn draft_updates_are_throttled_and_finalize_replaces_draft_in_thread() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "ts": "1710000000.000200",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat.update"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"ok": true})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let ch = streaming_slack_channel(&server);
+        let msg = SendMessage::new("", "C123").in_thread(Some("1709999999.000001".into()));
+        let draft_id = ch.send_draft(&msg).await.unwrap().expect("draft id");
+
+        // First update posts the draft; the second lands inside the
+        // throttle window and must not reach Slack.
+        ch.update_draft("C123", &draft_id, "Hel").await.unwrap();
+        ch.update_draft("C123", &draft_id, "Hello").await.unwrap();
+        ch.finalize_draft("C123", &draft_id, "Hello, world", false)
+            .await
+            .unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let post = requests
+            .iter()
+            .find(|req| req.url.path() == "/chat.postMessage")
+            .expect("draft should be posted");
+        let post_body: serde_json::Value = serde_json::from_slice(&post.body).unwrap();
+        assert_eq!(post_body["text"], "Hel");
+        assert_eq!(post_body["thread_ts"], "1709999999.000001");
+
+        let updates: Vec<serde_json::Value> = requests
+            .iter()
+            .filter(|req| req.url.path() == "/chat.update")
+            .map(|req| serde_json::from_slice(&req.body).unwrap())
+            .collect();
+        assert_eq!(updates.len(), 1, "only the finalize edit should be sent");
+        assert_eq!(updates[0]["ts"], "1710000000.000200");
+        assert_eq!(updates[0]["text"], "Hello, world");
+        assert!(ch.lazy_draft_ts.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn draft_updates_back_off_after_rate_limit() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat.update"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "5"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut ch = streaming_slack_channel(&server);
+        // Disable the interval throttle so only the 429 backoff gates edits.
+        ch.draft_update_interval_ms = 0;
+
+        ch.update_draft("C123", "1710000000.000300", "partial")
+            .await
+            .unwrap();
+        for _ in 0..50 {
+            if ch.draft_backoff_active("C123") {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(
+            ch.draft_backoff_active("C123"),
+            "429 should start a backoff"
+        );
+
+        ch.update_draft("C123", "1710000000.000300", "partial more")
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let updates = server
+            .received_requests()
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|req| req.url.path() == "/chat.update")
+            .count();
+        assert_eq!(updates, 1, "edits during backoff must not reach Slack");
+    }
+
+    #[test]
+    fn draft_rate_limit_backoff_grows_exponentially() {
+        let backoff = Mutex::new(HashMap::new());
+        let first = SlackChannel::record_draft_rate_limit(&backoff, "C1", Some(2));
+        let second = SlackChannel::record_draft_rate_limit(&backoff, "C1", Some(2));
+        assert!(first >= Duration::from_secs(2) && first < Duration::from_secs(3));
+        assert!(second >= Duration::from_secs(4) && second < Duration::from_secs(5));
+        for _ in 0..10 {
+            SlackChannel::record_draft_rate_limit(&backoff, "C1", Some(2));
+        }
+        let capped = SlackChannel::record_draft_rate_limit(&backoff, "C1", Some(2));
+        assert!(capped < Duration::from_secs(SLACK_DRAFT_MAX_BACKOFF_SECS + 1));
+    }
+
     #[test]
     fn ensure_file_extension_appends_when_missing() {
         assert_eq!(
@@ -6,7 +6,9 @@ use std::fmt::Write as _;
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
-use zeroclaw_api::channel::{Channel, ChannelMessage, SendMessage};
+use zeroclaw_api::channel::{
+    Channel, ChannelMessage, DeliveryReceipt, InboundReaction, SendMessage,
+};
 use zeroclaw_config::schema::{Config, StreamMode, TELEGRAM_OFFICIAL_API_BASE_URL};
 use zeroclaw_runtime::security::pairing::PairingGuard;
 
@@ -35,6 +37,8 @@ enum IncomingAttachmentKind {
     Photo,
 }
 const TELEGRAM_BIND_COMMAND: &str = "/bind";
+/// Forum General topic; its messages carry no `message_thread_id`.
+const TELEGRAM_GENERAL_TOPIC_ID: &str = "1";
 /// Telegram Bot API allows at most 100 commands via setMyCommands.
 const TELEGRAM_MAX_BOT_COMMANDS: usize = 100;
 /// Telegram command names: 1-32 lowercase a-z, 0-9, and underscore.
@@ -84,6 +88,14 @@ fn truncate_telegram_command_description(raw: &str) -> String {
     truncated
 }
 
+/// `message_id` from a Bot API `sendMessage` response
+/// (`{"ok": true, "result": {"message_id": 42, ...}}`).
+fn sent_message_id(body: &serde_json::Value) -> Option<String> {
+    body.pointer("/result/message_id")
+        .and_then(serde_json::Value::as_i64)
+        .map(|id| id.to_string())
+}
+
 /// Split a message into chunks that respect Telegram's 4096 character limit.
 /// Tries to split at word boundaries when possible, and handles continuation.
 /// The split budget includes continuation markers and synthetic code fences
@@ -410,6 +422,27 @@ fn is_http_url(target: &str) -> bool {
     target.starts_with("http://") || target.starts_with("https://")
 }
 
+/// A `[VOICE:...]` marker carries text to speak rather than a file when its
+/// target is neither a URL nor an existing path and does not look like one.
+fn is_spoken_voice_marker(target: &str) -> bool {
+    if is_http_url(target) || Path::new(target).exists() {
+        return false;
+    }
+    target.chars().any(char::is_whitespace) || infer_attachment_kind_from_target(target).is_none()
+}
+
+/// Whether a reply reads naturally as speech: no URLs, JSON, code blocks,
+/// tool markup, or error dumps.
+fn is_speakable_reply(content: &str) -> bool {
+    !content.starts_with("http")
+        && !content.starts_with('{')
+        && !content.starts_with('[')
+        && !content.starts_with("Error")
+        && !content.contains("```")
+        && !content.contains("tool_call")
+        && !content.contains("wttr.in")
+}
+
 fn infer_attachment_kind_from_target(target: &str) -> Option<TelegramAttachmentKind> {
     let normalized = target
         .split('?')
@@ -559,7 +592,11 @@ pub struct TelegramChannel {
     transcription_manager: Option<std::sync::Arc<super::transcription::TranscriptionManager>>,
     voice_transcriptions: Mutex<std::collections::HashMap<String, String>>,
     workspace_dir: Option<std::path::PathBuf>,
+    /// Downscale and per-message limits for inbound photos.
+    image_limits: crate::inbound_media::InboundImageLimits,
     ack_reactions: bool,
+    /// Subscribe to `message_reaction` updates (`[channels.feedback]`).
+    reaction_feedback: bool,
     tts_manager: Option<Arc<super::tts::TtsManager>>,
     voice_chats: Arc<std::sync::Mutex<std::collections::HashSet<String>>>,
     /// Resolves voice peers from canonical config at call-time.
@@ -585,6 +622,8 @@ pub struct TelegramChannel {
     /// tool approval prompt before auto-denying. Configurable via
     /// `channels.telegram.approval_timeout_secs`. Default: 120.
     approval_timeout_secs: u64,
+    /// `"<chat_id>:<topic_id>"` entries from `channels.telegram.allowed_topics`.
+    allowed_topics: Vec<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -655,7 +694,9 @@ impl TelegramChannel {
             transcription_manager: None,
             voice_transcriptions: Mutex::new(std::collections::HashMap::new()),
             workspace_dir: None,
+            image_limits: crate::inbound_media::InboundImageLimits::default(),
             ack_reactions: true,
+            reaction_feedback: false,
             tts_manager: None,
             voice_chats: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
             voice_peer_resolver: Arc::new(Vec::new) as Arc<dyn Fn() -> Vec<String> + Send + Sync>,
@@ -664,6 +705,7 @@ impl TelegramChannel {
             tool_command_specs: Vec::new(),
             pending_approvals: Arc::new(tokio::sync::Mutex::new(std::collections::HashMap::new())),
             approval_timeout_secs: 120,
+            allowed_topics: Vec::new(),
         }
     }
 
@@ -682,12 +724,34 @@ impl TelegramChannel {
         self
     }
 
+    /// Restrict forum chats to the listed `"<chat_id>:<topic_id>"` topics.
+    pub fn with_allowed_topics(mut self, allowed_topics: Vec<String>) -> Self {
+        self.allowed_topics = allowed_topics;
+        self
+    }
+
     /// Configure whether Telegram-native acknowledgement reactions are sent.
     pub fn with_ack_reactions(mut self, enabled: bool) -> Self {
         self.ack_reactions = enabled;
         self
     }
 
+    /// Configure whether reactions to the bot's replies are forwarded as
+    /// feedback events.
+    pub fn with_reaction_feedback(mut self, enabled: bool) -> Self {
+        self.reaction_feedback = enabled;
+        self
+    }
+
+    /// Update kinds requested from `getUpdates`.
+    fn allowed_updates(&self) -> Vec<&'static str> {
+        let mut updates = vec!["message", "callback_query"];
+        if self.reaction_feedback {
+            updates.push("message_reaction");
+        }
+        updates
+    }
+
     /// Returns `true` if `recipient` is in a peer group configured with
     /// `output_modality = "voice"` for this channel. Resolved live from config
     /// via `voice_peer_resolver` so it stays correct across hot-reloads.
@@ -713,6 +777,12 @@ impl TelegramChannel {
         self
     }
 
+    /// Configure limits for inbound photos (see `[multimodal]`).
+    pub fn with_image_limits(mut self, limits: crate::inbound_media::InboundImageLimits) -> Self {
+        self.image_limits = limits;
+        self
+    }
+
     /// Configure streaming mode for progressive draft updates.
     pub fn with_streaming(
         mut self,
@@ -845,6 +915,38 @@ impl TelegramChannel {
         }
     }
 
+    /// Whether the bot may answer in `reply_target`'s topic. A chat with no
+    /// `allowed_topics` entry is unrestricted; messages without a thread id
+    /// belong to the General topic.
+    fn is_topic_allowed(&self, reply_target: &str) -> bool {
+        let (chat_id, thread_id) = Self::parse_reply_target(reply_target);
+        let topic = thread_id.as_deref().unwrap_or(TELEGRAM_GENERAL_TOPIC_ID);
+        let prefix = format!("{chat_id}:");
+        let mut restricted = false;
+        for entry in &self.allowed_topics {
+            if let Some(allowed) = entry.trim().strip_prefix(&prefix) {
+                if allowed == topic {
+                    return true;
+                }
+                restricted = true;
+            }
+        }
+        !restricted
+    }
+
+    /// `sendChatAction` typing body for `recipient`, kept inside its topic.
+    fn typing_action_body(recipient: &str) -> serde_json::Value {
+        let (chat_id, thread_id) = Self::parse_reply_target(recipient);
+        let mut body = serde_json::json!({
+            "chat_id": chat_id,
+            "action": "typing"
+        });
+        if let Some(tid) = thread_id {
+            body["message_thread_id"] = serde_json::Value::String(tid);
+        }
+        body
+    }
+
     fn extract_update_message_target(update: &serde_json::Value) -> Option<(String, i64)> {
         let message = update.get("message")?;
         let chat_id = message
@@ -1113,21 +1215,14 @@ impl TelegramChannel {
             || (self.voice_peer_resolver)().iter().any(|p| p == recipient)
     }
 
-    fn try_queue_voice_reply(&self, recipient: &str, content: &str, immediate: bool, force: bool) {
-        if (!force && !self.is_voice_chat(recipient)) || self.tts_manager.is_none() {
+    fn try_queue_voice_reply(&self, recipient: &str, content: &str, immediate: bool) {
+        if !self.is_voice_chat(recipient) || self.tts_manager.is_none() {
             return;
         }
 
         // Only queue substantive natural-language replies for voice.
         // Skip tool outputs: URLs, JSON, code blocks, errors, short status.
-        let is_substantive = content.len() > 40
-            && !content.starts_with("http")
-            && !content.starts_with('{')
-            && !content.starts_with('[')
-            && !content.starts_with("Error")
-            && !content.contains("```")
-            && !content.contains("tool_call")
-            && !content.contains("wttr.in");
+        let is_substantive = content.len() > 40 && is_speakable_reply(content);
 
         if !is_substantive {
             return;
@@ -1159,7 +1254,7 @@ impl TelegramChannel {
                 )
                 .await
                 {
-                    Ok(()) => {
+                    Ok(_) => {
                         ::zeroclaw_log::record!(
                             INFO,
                             ::zeroclaw_log::Event::new(
@@ -1226,7 +1321,7 @@ impl TelegramChannel {
                 )
                 .await
                 {
-                    Ok(()) => {
+                    Ok(_) => {
                         ::zeroclaw_log::record!(
                             INFO,
                             ::zeroclaw_log::Event::new(
@@ -1253,7 +1348,33 @@ impl TelegramChannel {
         });
     }
 
-    /// Synthesize text to speech and send as a Telegram voice note (static version for spawned tasks).
+    /// Speak `text` as a voice note now. Returns `true` when the reply ran
+    /// past `[tts].max_voice_duration_secs` and was only voiced in part, so
+    /// the caller should also send it as text. Fails when TTS is not
+    /// configured for this channel.
+    async fn speak(
+        &self,
+        chat_id: &str,
+        thread_id: Option<&str>,
+        text: &str,
+    ) -> anyhow::Result<bool> {
+        let Some(tts_manager) = self.tts_manager.as_deref() else {
+            anyhow::bail!("TTS is not configured for this channel");
+        };
+        Self::synthesize_and_send_voice(
+            &self.api_base,
+            &self.bot_token,
+            chat_id,
+            thread_id,
+            text,
+            tts_manager,
+        )
+        .await
+    }
+
+    /// Synthesize text to speech and send as a Telegram voice note (static
+    /// version for spawned tasks). Returns `true` when the text was cut to
+    /// the voice duration cap.
     async fn synthesize_and_send_voice(
         api_base: &str,
         bot_token: &str,
@@ -1261,8 +1382,9 @@ impl TelegramChannel {
         thread_id: Option<&str>,
         text: &str,
         tts_manager: &crate::tts::TtsManager,
-    ) -> anyhow::Result<()> {
-        let audio_bytes = tts_manager.synthesize_opus(text).await?;
+    ) -> anyhow::Result<bool> {
+        let (spoken, truncated) = tts_manager.voice_excerpt(text);
+        let audio_bytes = tts_manager.synthesize_opus(spoken).await?;
         let audio_len = audio_bytes.len();
         ::zeroclaw_log::record!(
             INFO,
@@ -1307,7 +1429,7 @@ impl TelegramChannel {
                 .with_attrs(::serde_json::json!({"audio_len": audio_len})),
             "sent voice note ( bytes)"
         );
-        Ok(())
+        Ok(truncated)
     }
 
     async fn classify_edit_message_response(resp: reqwest::Response) -> EditMessageResult {
@@ -1838,29 +1960,6 @@ Allowlist Telegram username (without '@') or numeric user ID.",
             chat_id.clone()
         };
 
-        // Ensure workspace directory is configured
-        let workspace = self.workspace_dir.as_ref().or_else(|| {
-            ::zeroclaw_log::record!(
-                WARN,
-                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                    .with_outcome(::zeroclaw_log::EventOutcome::Unknown),
-                "Cannot save attachment: workspace_dir not configured"
-            );
-            None
-        })?;
-
-        let save_dir = workspace.join("telegram_files");
-        if let Err(e) = tokio::fs::create_dir_all(&save_dir).await {
-            ::zeroclaw_log::record!(
-                WARN,
-                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                    .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                    .with_attrs(::serde_json::json!({"error": zeroclaw_runtime::security::scrub(&format!("{}", e))})),
-                "Failed to create telegram_files directory"
-            );
-            return None;
-        }
-
         // Download file from Telegram
         let tg_file_path = match self.get_file_path(&attachment.file_id).await {
             Ok(p) => p,
@@ -1900,23 +1999,74 @@ Allowlist Telegram username (without '@') or numeric user ID.",
             }
         };
 
-        let local_path = save_dir.join(&local_filename);
-        if let Err(e) = tokio::fs::write(&local_path, &file_data).await {
-            ::zeroclaw_log::record!(
-                WARN,
-                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                    .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                    .with_attrs(::serde_json::json!({"error": zeroclaw_runtime::security::scrub(&format!("{}", e))})),
-                &format!("Failed to save attachment to {}", local_path.display())
-            );
-            return None;
-        }
+        // Photos with image extensions use the [IMAGE:] marker so the
+        // multimodal pipeline validates vision capability. They go to the
+        // temp media dir (removed when the turn ends) and don't need a
+        // workspace. Non-image files always get [Document:] format
+        // regardless of Telegram's classification.
+        let mut media_files = Vec::new();
+        let mut content = if is_image_extension(Path::new(&local_filename)) {
+            match crate::inbound_media::store_inbound_image(
+                "telegram",
+                &local_filename,
+                file_data,
+                crate::inbound_media::image_mime_from_name(&local_filename),
+                self.image_limits,
+            )
+            .await
+            {
+                Ok(stored) => {
+                    let marker = format!("[IMAGE:{}]", stored.path.display());
+                    media_files.push(stored);
+                    marker
+                }
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                            .with_attrs(::serde_json::json!({"name": local_filename, "error": format!("{}", e)})),
+                        "Skipping photo that exceeds image limits"
+                    );
+                    format!("[Photo skipped: {local_filename} exceeds the image size limit]")
+                }
+            }
+        } else {
+            let workspace = self.workspace_dir.as_ref().or_else(|| {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Unknown),
+                    "Cannot save attachment: workspace_dir not configured"
+                );
+                None
+            })?;
 
-        // Build message content.
-        // Photos with image extensions use [IMAGE:] marker so the multimodal
-        // pipeline validates vision capability. Non-image files always get
-        // [Document:] format regardless of Telegram's classification.
-        let mut content = format_attachment_content(attachment.kind, &local_filename, &local_path);
+            let save_dir = workspace.join("telegram_files");
+            if let Err(e) = tokio::fs::create_dir_all(&save_dir).await {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                        .with_attrs(::serde_json::json!({"error": zeroclaw_runtime::security::scrub(&format!("{}", e))})),
+                    "Failed to create telegram_files directory"
+                );
+                return None;
+            }
+
+            let local_path = save_dir.join(&local_filename);
+            if let Err(e) = tokio::fs::write(&local_path, &file_data).await {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                        .with_attrs(::serde_json::json!({"error": zeroclaw_runtime::security::scrub(&format!("{}", e))})),
+                    &format!("Failed to save attachment to {}", local_path.display())
+                );
+                return None;
+            }
+            format_attachment_content(attachment.kind, &local_filename, &local_path)
+        };
         // `gated_caption` is the trimmed caption when the `mention_only`
         // gate admits it; otherwise the raw caption (or None).
         if let Some(caption) = gated_caption.as_deref()
@@ -1950,6 +2100,7 @@ Allowlist Telegram username (without '@') or numeric user ID.",
             thread_ts: thread_id,
             interruption_scope_id: None,
             attachments: vec![],
+            media_files,
             subject: None,
 
             ..Default::default()
@@ -2121,15 +2272,19 @@ Allowlist Telegram username (without '@') or numeric user ID.",
 
     /// Extract sender username and display identity from a Telegram message object.
     fn extract_sender_info(message: &serde_json::Value) -> (String, Option<String>, String) {
-        let username = message
-            .get("from")
-            .and_then(|from| from.get("username"))
+        Self::extract_user_info(message.get("from"))
+    }
+
+    /// Username, numeric id, and the identity used as `sender` for a
+    /// Telegram `User` object.
+    fn extract_user_info(user: Option<&serde_json::Value>) -> (String, Option<String>, String) {
+        let username = user
+            .and_then(|user| user.get("username"))
             .and_then(serde_json::Value::as_str)
             .unwrap_or("unknown")
             .to_string();
-        let sender_id = message
-            .get("from")
-            .and_then(|from| from.get("id"))
+        let sender_id = user
+            .and_then(|user| user.get("id"))
             .and_then(serde_json::Value::as_i64)
             .map(|id| id.to_string());
         let sender_identity = if username == "unknown" {
@@ -2390,6 +2545,71 @@ Allowlist Telegram username (without '@') or numeric user ID.",
         })
     }
 
+    /// Turn a `message_reaction` update into a reaction event. Only emoji the
+    /// user just added count; removals and custom emoji are ignored.
+    /// Telegram omits the forum topic from reaction updates, so the event
+    /// targets the chat itself.
+    fn parse_reaction_update(&self, update: &serde_json::Value) -> Option<ChannelMessage> {
+        let reaction = update.get("message_reaction")?;
+        let (username, sender_id, sender_identity) =
+            Self::extract_user_info(Some(reaction.get("user")?));
+
+        let mut identities = vec![username.as_str()];
+        if let Some(id) = sender_id.as_deref() {
+            identities.push(id);
+        }
+        if !self.is_any_user_allowed(identities.iter().copied()) {
+            return None;
+        }
+
+        let emoji_of = |key: &str| -> Vec<String> {
+            reaction
+                .get(key)
+                .and_then(serde_json::Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter(|r| r.get("type").and_then(serde_json::Value::as_str) == Some("emoji"))
+                .filter_map(|r| r.get("emoji").and_then(serde_json::Value::as_str))
+                .map(str::to_string)
+                .collect()
+        };
+        let old = emoji_of("old_reaction");
+        let added: Vec<String> = emoji_of("new_reaction")
+            .into_iter()
+            .filter(|emoji| !old.contains(emoji))
+            .collect();
+        if added.is_empty() {
+            return None;
+        }
+
+        let chat_id = reaction
+            .get("chat")
+            .and_then(|chat| chat.get("id"))
+            .and_then(serde_json::Value::as_i64)?
+            .to_string();
+        let message_id = reaction
+            .get("message_id")
+            .and_then(serde_json::Value::as_i64)?
+            .to_string();
+
+        Some(ChannelMessage {
+            id: format!("telegram_{chat_id}_{message_id}_reaction"),
+            sender: sender_identity,
+            reply_target: chat_id,
+            channel: "telegram".into(),
+            channel_alias: Some(self.alias.clone()),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            reaction: Some(InboundReaction {
+                message_id,
+                emoji: added,
+            }),
+            ..Default::default()
+        })
+    }
+
     /// Convert Markdown to Telegram HTML format.
     /// Telegram HTML supports: <b>, <i>, <u>, <s>, <code>, <pre>, <a href="...">
     /// This mirrors OpenClaw's markdownToTelegramHtml approach.
@@ -2559,7 +2779,21 @@ Allowlist Telegram username (without '@') or numeric user ID.",
         chat_id: &str,
         thread_id: Option<&str>,
     ) -> anyhow::Result<()> {
+        self.send_text_chunks_with_id(message, chat_id, thread_id)
+            .await
+            .map(|_| ())
+    }
+
+    /// Send `message` as one or more `sendMessage` calls and return the
+    /// `message_id` of the last chunk Telegram accepted.
+    async fn send_text_chunks_with_id(
+        &self,
+        message: &str,
+        chat_id: &str,
+        thread_id: Option<&str>,
+    ) -> anyhow::Result<Option<String>> {
         let chunks = split_message_for_telegram(message);
+        let mut last_message_id = None;
 
         for (index, chunk) in chunks.iter().enumerate() {
             let text = format_telegram_text_chunk(chunk, index, chunks.len());
@@ -2583,6 +2817,11 @@ Allowlist Telegram username (without '@') or numeric user ID.",
                 .await?;
 
             if markdown_resp.status().is_success() {
+                last_message_id = markdown_resp
+                    .json::<serde_json::Value>()
+                    .await
+                    .ok()
+                    .and_then(|body| sent_message_id(&body));
                 if index < chunks.len() - 1 {
                     tokio::time::sleep(Duration::from_millis(100)).await;
                 }
@@ -2626,13 +2865,18 @@ Allowlist Telegram username (without '@') or numeric user ID.",
                     plain_err
                 );
             }
+            last_message_id = plain_resp
+                .json::<serde_json::Value>()
+                .await
+                .ok()
+                .and_then(|body| sent_message_id(&body));
 
             if index < chunks.len() - 1 {
                 tokio::time::sleep(Duration::from_millis(100)).await;
             }
         }
 
-        Ok(())
+        Ok(last_message_id)
     }
 
     async fn send_media_by_url(
@@ -2687,6 +2931,23 @@ Allowlist Telegram username (without '@') or numeric user ID.",
     ) -> anyhow::Result<()> {
         let target = attachment.target.trim();
 
+        if attachment.kind == TelegramAttachmentKind::Voice && is_spoken_voice_marker(target) {
+            return match self.speak(chat_id, thread_id, target).await {
+                Ok(false) => Ok(()),
+                Ok(true) => self.send_text_chunks(target, chat_id, thread_id).await,
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Send)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                            .with_attrs(::serde_json::json!({"error": zeroclaw_runtime::security::scrub(&format!("{e}"))})),
+                        "[VOICE:] marker could not be voiced; sending it as text"
+                    );
+                    self.send_text_chunks(target, chat_id, thread_id).await
+                }
+            };
+        }
+
         if is_http_url(target) {
             let result = match attachment.kind {
                 TelegramAttachmentKind::Image => {
@@ -3394,7 +3655,7 @@ impl Channel for TelegramChannel {
         // Queue TTS voice reply — immediate mode since text is already final.
         // Skipped when suppress_voice is set (explicit text-only routing override).
         if !suppress_voice {
-            self.try_queue_voice_reply(recipient, text, true, false);
+            self.try_queue_voice_reply(recipient, text, true);
         }
 
         // Clean up rate-limit tracking for this chat
@@ -3630,33 +3891,61 @@ impl Channel for TelegramChannel {
     }
 
     async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
+        self.send_with_receipt(message).await.map(|_| ())
+    }
+
+    async fn send_with_receipt(
+        &self,
+        message: &SendMessage,
+    ) -> anyhow::Result<Option<DeliveryReceipt>> {
         // Strip tool_call tags before processing to prevent Markdown parsing failures
         let content = strip_tool_call_tags(&message.content);
 
         // Parse recipient: "chat_id" or "chat_id:thread_id" format
         let (chat_id, thread_id) = match message.recipient.split_once(':') {
             Some((chat, thread)) => (chat, Some(thread)),
-            None => (message.recipient.as_str(), None),
+            None => (message.recipient.as_str(), message.thread_ts.as_deref()),
         };
 
-        // Voice chat mode: queue a voice note. Suppressed messages (errors,
-        // system notices) are never voiced.
-        if !message.suppress_voice {
-            self.try_queue_voice_reply(&message.recipient, &content, false, message.force_voice);
-        }
+        let (text_without_markers, attachments) = parse_attachment_markers(&content);
 
-        // Voice-only peers (or explicit force_voice): the voice note is the sole reply — skip text.
-        if !message.suppress_voice
-            && (self.is_voice_peer(&message.recipient) || message.force_voice)
-        {
-            return Ok(());
+        // Suppressed messages (errors, system notices) are never voiced.
+        if message.force_voice && !message.suppress_voice && attachments.is_empty() {
+            // Forced voice (`/voice on`, `send_via`): speak it now and fall
+            // back to text when it cannot be voiced.
+            let spoken = if is_speakable_reply(&content) {
+                self.speak(chat_id, thread_id, &content).await
+            } else {
+                Err(anyhow::anyhow!("reply is not suitable for speech"))
+            };
+            match spoken {
+                Ok(false) => return Ok(None),
+                // Voiced in part; the full reply follows as text.
+                Ok(true) => {}
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Send)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                            .with_attrs(::serde_json::json!({"error": zeroclaw_runtime::security::scrub(&format!("{e}"))})),
+                        "voice reply failed; sending it as text"
+                    );
+                }
+            }
+        } else if !message.suppress_voice && !message.force_voice {
+            // Voice chat mode: queue a voice note.
+            self.try_queue_voice_reply(&message.recipient, &content, false);
+            // Voice-only peers: the voice note is the sole reply — skip text.
+            if self.is_voice_peer(&message.recipient) {
+                return Ok(None);
+            }
         }
 
-        let (text_without_markers, attachments) = parse_attachment_markers(&content);
-
         if !attachments.is_empty() {
+            let mut message_id = None;
             if !text_without_markers.is_empty() {
-                self.send_text_chunks(&text_without_markers, chat_id, thread_id)
+                message_id = self
+                    .send_text_chunks_with_id(&text_without_markers, chat_id, thread_id)
                     .await?;
             }
 
@@ -3664,16 +3953,19 @@ impl Channel for TelegramChannel {
                 self.send_attachment(chat_id, thread_id, attachment).await?;
             }
 
-            return Ok(());
+            return Ok(message_id.map(DeliveryReceipt::new));
         }
 
         if let Some(attachment) = parse_path_only_attachment(&content) {
             self.send_attachment(chat_id, thread_id, &attachment)
                 .await?;
-            return Ok(());
+            return Ok(None);
         }
 
-        self.send_text_chunks(&content, chat_id, thread_id).await
+        Ok(self
+            .send_text_chunks_with_id(&content, chat_id, thread_id)
+            .await?
+            .map(DeliveryReceipt::new))
     }
 
     async fn listen(&self, tx: tokio::sync::mpsc::Sender<ChannelMessage>) -> anyhow::Result<()> {
@@ -3694,7 +3986,7 @@ impl Channel for TelegramChannel {
             let probe = serde_json::json!({
                 "offset": offset,
                 "timeout": 0,
-                "allowed_updates": ["message", "callback_query"]
+                "allowed_updates": self.allowed_updates()
             });
             match self.http_client().post(&url).json(&probe).send().await {
                 Err(e) => {
@@ -3793,7 +4085,7 @@ impl Channel for TelegramChannel {
             let body = serde_json::json!({
                 "offset": offset,
                 "timeout": 30,
-                "allowed_updates": ["message", "callback_query"]
+                "allowed_updates": self.allowed_updates()
             });
 
             let resp = match self.http_client().post(&url).json(&body).send().await {
@@ -3959,6 +4251,15 @@ Ensure only one `zeroclaw` process is using this bot token."
                         continue; // callback_query is not a regular message
                     }
 
+                    if update.get("message_reaction").is_some() {
+                        if let Some(msg) = self.parse_reaction_update(update)
+                            && tx.send(msg).await.is_err()
+                        {
+                            return Ok(());
+                        }
+                        continue;
+                    }
+
                     let msg = if let Some(m) = self.parse_update_message(update) {
                         m
                     } else if let Some(m) = self.try_parse_voice_message(update).await {
@@ -3970,6 +4271,19 @@ Ensure only one `zeroclaw` process is using this bot token."
                         continue;
                     };
 
+                    if !self.is_topic_allowed(&msg.reply_target) {
+                        ::zeroclaw_log::record!(
+                            DEBUG,
+                            ::zeroclaw_log::Event::new(
+                                module_path!(),
+                                ::zeroclaw_log::Action::Skip
+                            )
+                            .with_attrs(::serde_json::json!({"reply_target": msg.reply_target})),
+                            "Ignoring Telegram message outside allowed_topics"
+                        );
+                        continue;
+                    }
+
                     if self.ack_reactions
                         && let Some((reaction_chat_id, reaction_message_id)) =
                             Self::extract_update_message_target(update)
@@ -3981,10 +4295,7 @@ Ensure only one `zeroclaw` process is using this bot token."
                     }
 
                     // Send "typing" indicator immediately when we receive a message
-                    let typing_body = serde_json::json!({
-                        "chat_id": &msg.reply_target,
-                        "action": "typing"
-                    });
+                    let typing_body = Self::typing_action_body(&msg.reply_target);
                     let _ = self
                         .http_client()
                         .post(self.api_url("sendChatAction"))
@@ -4035,14 +4346,10 @@ Ensure only one `zeroclaw` process is using this bot token."
 
         let client = self.http_client();
         let url = self.api_url("sendChatAction");
-        let chat_id = recipient.to_string();
+        let body = Self::typing_action_body(recipient);
 
         let handle = zeroclaw_spawn::spawn!(async move {
             loop {
-                let body = serde_json::json!({
-                    "chat_id": &chat_id,
-                    "action": "typing"
-                });
                 let _ = client.post(&url).json(&body).send().await;
                 // Telegram typing indicator expires after 5s; refresh at 4s
                 tokio::time::sleep(Duration::from_secs(4)).await;
@@ -4920,6 +5227,50 @@ mod tests {
         assert_eq!(attachments[1].target, "https://example.com/a.pdf");
     }
 
+    #[test]
+    fn voice_marker_with_prose_is_spoken_text() {
+        assert!(is_spoken_voice_marker("Good morning, captain"));
+        assert!(is_spoken_voice_marker("Ahoy"));
+        assert!(!is_spoken_voice_marker("/tmp/reply.ogg"));
+        assert!(!is_spoken_voice_marker("https://example.com/reply.ogg"));
+    }
+
+    #[tokio::test]
+    async fn forced_voice_without_tts_falls_back_to_text() {
+        use wiremock::matchers::{method, path_regex};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"/bot[^/]+/sendMessage$"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(
+                    serde_json::json!({ "ok": true, "result": { "message_id": 7 } }),
+                ),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"/bot[^/]+/sendVoice$"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let ch = TelegramChannel::new(
+            "fake-token".into(),
+            "telegram_test_alias",
+            Arc::new(|| vec!["*".into()]),
+            false,
+        )
+        .with_api_base(mock_server.uri());
+        let message = SendMessage::new("The weather is calm today.", "123456").force_voice();
+
+        let receipt = ch.send_with_receipt(&message).await.unwrap();
+        assert_eq!(receipt.map(|r| r.message_id).as_deref(), Some("7"));
+    }
+
     #[test]
     fn parse_attachment_markers_keeps_invalid_markers_in_text() {
         let message = "Report [UNKNOWN:/tmp/a.bin]";
@@ -4989,6 +5340,56 @@ mod tests {
         assert_eq!(msg.id, "telegram_-100200300_33");
     }
 
+    #[test]
+    fn parse_reaction_update_reports_only_newly_added_emoji() {
+        let ch = TelegramChannel::new(
+            "token".into(),
+            "telegram_test_alias",
+            Arc::new(|| vec!["alice".into()]),
+            false,
+        )
+        .with_reaction_feedback(true);
+        assert!(ch.allowed_updates().contains(&"message_reaction"));
+
+        let reaction_update = |user: &str, old: &[&str], new: &[&str]| {
+            let emoji = |list: &[&str]| -> Vec<serde_json::Value> {
+                list.iter()
+                    .map(|e| serde_json::json!({ "type": "emoji", "emoji": e }))
+                    .collect()
+            };
+            serde_json::json!({
+                "update_id": 2,
+                "message_reaction": {
+                    "chat": { "id": 42 },
+                    "message_id": 77,
+                    "user": { "id": 555, "username": user },
+                    "date": 1_760_000_000,
+                    "old_reaction": emoji(old),
+                    "new_reaction": emoji(new)
+                }
+            })
+        };
+
+        let msg = ch
+            .parse_reaction_update(&reaction_update("alice", &["❤"], &["❤", "👍"]))
+            .expect("added reaction should parse");
+        assert_eq!(msg.sender, "alice");
+        assert_eq!(msg.reply_target, "42");
+        let reaction = msg.reaction.expect("reaction event");
+        assert_eq!(reaction.message_id, "77");
+        assert_eq!(reaction.emoji, vec!["👍".to_string()]);
+
+        // Removing a reaction and reactions from strangers are not feedback.
+        assert!(
+            ch.parse_reaction_update(&reaction_update("alice", &["👍"], &[]))
+                .is_none()
+        );
+        assert!(
+            ch.parse_reaction_update(&reaction_update("mallory", &[], &["👎"]))
+                .is_none()
+        );
+    }
+
     #[test]
     fn parse_update_message_allows_numeric_id_without_username() {
         let mention_only = false;
@@ -5055,6 +5456,39 @@ mod tests {
         assert_eq!(msg.id, "telegram_-100200300_42");
     }
 
+    #[test]
+    fn allowed_topics_restrict_only_listed_chats() {
+        let ch = TelegramChannel::new(
+            "token".into(),
+            "telegram_test_alias",
+            Arc::new(|| vec!["*".into()]),
+            false,
+        )
+        .with_allowed_topics(vec!["-100200300:789".into(), "-100200300:1".into()]);
+
+        assert!(ch.is_topic_allowed("-100200300:789"));
+        assert!(ch.is_topic_allowed("-100200300"));
+        assert!(!ch.is_topic_allowed("-100200300:790"));
+        assert!(ch.is_topic_allowed("-100999:5"));
+        assert!(ch.is_topic_allowed("12345"));
+    }
+
+    #[test]
+    fn typing_action_stays_in_topic() {
+        assert_eq!(
+            TelegramChannel::typing_action_body("-100200300:789"),
+            serde_json::json!({
+                "chat_id": "-100200300",
+                "action": "typing",
+                "message_thread_id": "789"
+            })
+        );
+        assert_eq!(
+            TelegramChannel::typing_action_body("12345"),
+            serde_json::json!({"chat_id": "12345", "action": "typing"})
+        );
+    }
+
     // ── File sending API URL tests ──────────────────────────────────
 
     #[test]
@@ -6336,6 +6770,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn telegram_sent_message_id_reads_result() {
+        let body = serde_json::json!({"ok": true, "result": {"message_id": 42, "chat": {"id": 1}}});
+        assert_eq!(sent_message_id(&body).as_deref(), Some("42"));
+        assert_eq!(sent_message_id(&serde_json::json!({"ok": false})), None);
+    }
+
     #[test]
     fn telegram_split_only_whitespace() {
         let msg = "   \n\n\t  ";
@@ -7800,6 +8241,75 @@ mod tests {
         ch.register_bot_commands().await;
     }
 
+    #[tokio::test]
+    async fn photo_is_stored_in_media_dir_without_workspace() {
+        use wiremock::matchers::{method, path, path_regex};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let png = {
+            let img = image::DynamicImage::ImageRgb8(image::RgbImage::new(64, 32));
+            let mut cursor = std::io::Cursor::new(Vec::new());
+            img.write_to(&mut cursor, image::ImageFormat::Png).unwrap();
+            cursor.into_inner()
+        };
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"/bot[^/]+/getFile$"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({"ok": true, "result": {"file_path": "photos/file_1.png"}}),
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/file/botfake-token/photos/file_1.png"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(png))
+            .mount(&mock_server)
+            .await;
+
+        let ch = TelegramChannel::new(
+            "fake-token".into(),
+            "telegram_test_alias",
+            Arc::new(|| vec!["*".into()]),
+            false,
+        )
+        .with_api_base(mock_server.uri())
+        .with_image_limits(crate::inbound_media::InboundImageLimits {
+            max_images: 1,
+            max_bytes: 1024 * 1024,
+            max_dimension: 256,
+        });
+        let update = serde_json::json!({
+            "message": {
+                "message_id": 7,
+                "chat": {"id": 42},
+                "from": {"id": 1, "username": "alice"},
+                "photo": [{"file_id": "p1", "file_size": 100}],
+                "caption": "what is this?"
+            }
+        });
+
+        let msg = ch.try_parse_attachment_message(&update).await.unwrap();
+        assert_eq!(msg.media_files.len(), 1);
+        let stored = &msg.media_files[0];
+        assert_eq!(stored.mime_type, "image/png");
+        assert!(
+            stored
+                .path
+                .starts_with(crate::inbound_media::media_dir("telegram"))
+        );
+        assert!(
+            msg.content
+                .starts_with(&format!("[IMAGE:{}]", stored.path.display()))
+        );
+        assert!(msg.content.ends_with("what is this?"));
+
+        let path = stored.path.clone();
+        drop(crate::inbound_media::InboundMediaCleanup::new(
+            &msg.media_files,
+        ));
+        assert!(!path.exists());
+    }
+
     #[tokio::test]
     async fn register_bot_commands_includes_tools_from_config() {
         use wiremock::matchers::{body_json, method, path_regex};
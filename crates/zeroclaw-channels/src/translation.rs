@@ -0,0 +1,441 @@
+//! Translation layer for cross-language channels.
+//!
+//! A channel alias with `translate = { inbound_to, outbound_to }` has inbound
+//! messages translated into the agent's language before memory recall, and
+//! the final reply translated back before delivery. Fenced code blocks pass
+//! through untouched. The detected language of each sender is cached so
+//! `outbound_to = "auto-detect-sender"` does not re-detect on every reply.
+
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use lru::LruCache;
+use parking_lot::Mutex;
+use zeroclaw_providers::{ModelProvider, ProviderDispatch};
+
+/// `outbound_to` value that answers each sender in their own language.
+pub const AUTO_DETECT_SENDER: &str = "auto-detect-sender";
+
+/// Senders whose detected language is remembered.
+const SENDER_LANGUAGE_CACHE_SIZE: usize = 1024;
+
+/// Result of one translation call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Translated {
+    pub text: String,
+    /// Language of the input as reported by the backend (ISO 639-1,
+    /// lowercase), when it reports one.
+    pub source_lang: Option<String>,
+}
+
+/// A translation backend.
+#[async_trait]
+pub trait Translator: Send + Sync {
+    /// Translate `text` into `target` (ISO 639-1, e.g. `en`).
+    async fn translate(&self, text: &str, target: &str) -> anyhow::Result<Translated>;
+
+    /// Detect the language of `text` (ISO 639-1, lowercase).
+    async fn detect(&self, text: &str) -> anyhow::Result<String>;
+}
+
+/// Lowercase base language of a code: `EN-US` → `en`.
+pub fn base_lang(code: &str) -> String {
+    code.trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// DeepL REST API backend.
+pub struct DeeplTranslator {
+    client: reqwest::Client,
+    api_url: String,
+    api_key: String,
+}
+
+impl DeeplTranslator {
+    pub fn new(api_key: impl Into<String>, api_url: impl Into<String>) -> Self {
+        Self {
+            client: zeroclaw_config::schema::build_runtime_proxy_client("translation.deepl"),
+            api_url: api_url.into(),
+            api_key: api_key.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Translator for DeeplTranslator {
+    async fn translate(&self, text: &str, target: &str) -> anyhow::Result<Translated> {
+        let target_lang = target.trim().to_ascii_uppercase();
+        let resp = self
+            .client
+            .post(&self.api_url)
+            .header("Authorization", format!("DeepL-Auth-Key {}", self.api_key))
+            .form(&[("text", text), ("target_lang", target_lang.as_str())])
+            .send()
+            .await
+            .context("DeepL request failed")?;
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!("DeepL returned {status}: {body}");
+        }
+        let body: serde_json::Value = resp.json().await.context("DeepL response")?;
+        let first = body
+            .get("translations")
+            .and_then(|t| t.get(0))
+            .context("DeepL response has no translations")?;
+        Ok(Translated {
+            text: first
+                .get("text")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            source_lang: first
+                .get("detected_source_language")
+                .and_then(serde_json::Value::as_str)
+                .map(base_lang),
+        })
+    }
+
+    async fn detect(&self, text: &str) -> anyhow::Result<String> {
+        // DeepL has no detection endpoint; every translation reports the
+        // source language, so translate and keep only that.
+        self.translate(text, "EN")
+            .await?
+            .source_lang
+            .context("DeepL did not report a source language")
+    }
+}
+
+/// Backend that asks a language model, usually a small, cheap one.
+pub struct ModelTranslator {
+    provider: Arc<dyn ModelProvider>,
+    model: String,
+    temperature: Option<f64>,
+}
+
+impl ModelTranslator {
+    pub fn new(provider: Arc<dyn ModelProvider>, model: String, temperature: Option<f64>) -> Self {
+        Self {
+            provider,
+            model,
+            temperature,
+        }
+    }
+}
+
+#[async_trait]
+impl Translator for ModelTranslator {
+    async fn translate(&self, text: &str, target: &str) -> anyhow::Result<Translated> {
+        let system = format!(
+            "You are a translation engine. Translate the user's message into the language \
+             with ISO 639-1 code `{target}`. Keep names, URLs, inline code, and markdown \
+             formatting unchanged. Answer with a first line `LANG: <ISO 639-1 code of the \
+             original message>`, then the translation. Output nothing else."
+        );
+        let response = ProviderDispatch::from_ref(self.provider.as_ref())
+            .chat_with_system(Some(&system), text, &self.model, self.temperature)
+            .await?;
+        Ok(parse_model_translation(&response))
+    }
+
+    async fn detect(&self, text: &str) -> anyhow::Result<String> {
+        let response = ProviderDispatch::from_ref(self.provider.as_ref())
+            .chat_with_system(
+                Some(
+                    "Identify the language of the user's message. Answer with its ISO 639-1 \
+                     code only.",
+                ),
+                text,
+                &self.model,
+                self.temperature,
+            )
+            .await?;
+        let code = base_lang(response.trim().trim_matches('`'));
+        if code.len() < 2 || code.len() > 3 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            anyhow::bail!("model returned no language code: {response}");
+        }
+        Ok(code)
+    }
+}
+
+/// Split a `LANG: xx` header line off a model translation.
+fn parse_model_translation(response: &str) -> Translated {
+    let response = response.trim();
+    let (first, rest) = response.split_once('\n').unwrap_or((response, ""));
+    match first.trim().strip_prefix("LANG:") {
+        Some(code) => Translated {
+            text: rest.trim().to_string(),
+            source_lang: Some(base_lang(code)).filter(|c| !c.is_empty()),
+        },
+        None => Translated {
+            text: response.to_string(),
+            source_lang: None,
+        },
+    }
+}
+
+/// One run of lines, either inside a fenced code block or outside one.
+#[derive(Debug, PartialEq, Eq)]
+struct Segment<'a> {
+    code: bool,
+    text: &'a str,
+}
+
+/// Split `text` into prose and fenced-code segments. Fence lines belong to
+/// the code segment; an unclosed fence runs to the end of the text.
+fn split_code_fences(text: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut in_code = false;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let is_fence = trimmed.starts_with("```") || trimmed.starts_with("~~~");
+        if is_fence && !in_code {
+            if offset > start {
+                segments.push(Segment {
+                    code: false,
+                    text: &text[start..offset],
+                });
+            }
+            start = offset;
+            in_code = true;
+        } else if is_fence && in_code {
+            let end = offset + line.len();
+            segments.push(Segment {
+                code: true,
+                text: &text[start..end],
+            });
+            start = end;
+            in_code = false;
+        }
+        offset += line.len();
+    }
+    if start < text.len() {
+        segments.push(Segment {
+            code: in_code,
+            text: &text[start..],
+        });
+    }
+    segments
+}
+
+/// Translate the prose of `text` into `target`, passing fenced code blocks
+/// and surrounding whitespace through unchanged.
+pub async fn translate_preserving_code(
+    translator: &dyn Translator,
+    text: &str,
+    target: &str,
+) -> anyhow::Result<Translated> {
+    let mut out = String::with_capacity(text.len());
+    let mut source_lang = None;
+    for segment in split_code_fences(text) {
+        let core = segment.text.trim();
+        if segment.code || core.is_empty() {
+            out.push_str(segment.text);
+            continue;
+        }
+        let lead = &segment.text[..segment.text.len() - segment.text.trim_start().len()];
+        let trail = &segment.text[segment.text.trim_end().len()..];
+        let translated = translator.translate(core, target).await?;
+        if source_lang.is_none() {
+            source_lang = translated.source_lang;
+        }
+        out.push_str(lead);
+        out.push_str(translated.text.trim());
+        out.push_str(trail);
+    }
+    Ok(Translated {
+        text: out,
+        source_lang,
+    })
+}
+
+/// Translation state shared by one runtime context: an optional fixed
+/// backend and the per-sender language cache.
+pub struct ChannelTranslation {
+    translator: Option<Arc<dyn Translator>>,
+    sender_languages: Mutex<LruCache<String, String>>,
+}
+
+impl Default for ChannelTranslation {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl ChannelTranslation {
+    /// `translator` fixes the backend; `None` lets the caller resolve one
+    /// per message (the `model` backend follows the active provider).
+    pub fn new(translator: Option<Arc<dyn Translator>>) -> Self {
+        Self {
+            translator,
+            sender_languages: Mutex::new(LruCache::new(
+                NonZeroUsize::new(SENDER_LANGUAGE_CACHE_SIZE).expect("non-zero cache size"),
+            )),
+        }
+    }
+
+    pub fn translator(&self) -> Option<Arc<dyn Translator>> {
+        self.translator.clone()
+    }
+
+    /// Cached language for `sender_key`, if one has been detected.
+    pub fn sender_language(&self, sender_key: &str) -> Option<String> {
+        self.sender_languages.lock().get(sender_key).cloned()
+    }
+
+    fn remember(&self, sender_key: &str, lang: &str) {
+        if !lang.is_empty() {
+            self.sender_languages
+                .lock()
+                .put(sender_key.to_string(), lang.to_string());
+        }
+    }
+
+    /// Translate an inbound message into `target`. Text already in `target`
+    /// is returned as written. Records the sender's language.
+    pub async fn translate_inbound(
+        &self,
+        translator: &dyn Translator,
+        sender_key: &str,
+        text: &str,
+        target: &str,
+    ) -> anyhow::Result<String> {
+        if target.trim().is_empty() || text.trim().is_empty() {
+            return Ok(text.to_string());
+        }
+        let translated = translate_preserving_code(translator, text, target).await?;
+        if let Some(lang) = translated.source_lang.as_deref() {
+            self.remember(sender_key, lang);
+            if lang == base_lang(target) {
+                return Ok(text.to_string());
+            }
+        }
+        Ok(translated.text)
+    }
+
+    /// Translate a reply according to `outbound_to`. For
+    /// [`AUTO_DETECT_SENDER`] the sender's cached language is used; on a
+    /// cache miss it is detected once from `sender_text`.
+    pub async fn translate_outbound(
+        &self,
+        translator: &dyn Translator,
+        sender_key: &str,
+        sender_text: &str,
+        reply: &str,
+        outbound_to: &str,
+    ) -> anyhow::Result<String> {
+        let outbound_to = outbound_to.trim();
+        if outbound_to.is_empty() || reply.trim().is_empty() {
+            return Ok(reply.to_string());
+        }
+        let target = if outbound_to == AUTO_DETECT_SENDER {
+            match self.sender_language(sender_key) {
+                Some(lang) => lang,
+                None => {
+                    let lang = translator.detect(sender_text).await?;
+                    self.remember(sender_key, &lang);
+                    lang
+                }
+            }
+        } else {
+            base_lang(outbound_to)
+        };
+        let translated = translate_preserving_code(translator, reply, &target).await?;
+        if translated.source_lang.as_deref() == Some(target.as_str()) {
+            return Ok(reply.to_string());
+        }
+        Ok(translated.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Tags text with the target language and reports German for anything
+    /// containing "Hallo".
+    #[derive(Default)]
+    struct TagTranslator {
+        detects: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Translator for TagTranslator {
+        async fn translate(&self, text: &str, target: &str) -> anyhow::Result<Translated> {
+            let source = if text.contains("Hallo") { "de" } else { "en" };
+            Ok(Translated {
+                text: format!("[{target}] {text}"),
+                source_lang: Some(source.into()),
+            })
+        }
+
+        async fn detect(&self, text: &str) -> anyhow::Result<String> {
+            self.detects.fetch_add(1, Ordering::SeqCst);
+            Ok(if text.contains("Hallo") { "de" } else { "en" }.into())
+        }
+    }
+
+    #[tokio::test]
+    async fn code_blocks_pass_through_untranslated() {
+        let text = "Hallo, run this:\n```sh\necho Hallo\n```\nDanke.";
+        let out = translate_preserving_code(&TagTranslator::default(), text, "en")
+            .await
+            .unwrap();
+        assert_eq!(
+            out.text,
+            "[en] Hallo, run this:\n```sh\necho Hallo\n```\n[en] Danke."
+        );
+        assert_eq!(out.source_lang.as_deref(), Some("de"));
+    }
+
+    #[tokio::test]
+    async fn inbound_in_target_language_is_left_as_written() {
+        let translation = ChannelTranslation::default();
+        let translator = TagTranslator::default();
+        let out = translation
+            .translate_inbound(&translator, "telegram:alice", "good morning", "en")
+            .await
+            .unwrap();
+        assert_eq!(out, "good morning");
+        assert_eq!(
+            translation.sender_language("telegram:alice").as_deref(),
+            Some("en")
+        );
+    }
+
+    #[tokio::test]
+    async fn auto_detect_sender_detects_once_per_sender() {
+        let translation = ChannelTranslation::default();
+        let translator = TagTranslator::default();
+        for _ in 0..3 {
+            let out = translation
+                .translate_outbound(
+                    &translator,
+                    "telegram:hans",
+                    "Hallo zusammen",
+                    "Hello",
+                    AUTO_DETECT_SENDER,
+                )
+                .await
+                .unwrap();
+            assert_eq!(out, "[de] Hello");
+        }
+        assert_eq!(translator.detects.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn model_translation_header_is_split_off() {
+        let parsed = parse_model_translation("LANG: DE\nGood morning");
+        assert_eq!(parsed.text, "Good morning");
+        assert_eq!(parsed.source_lang.as_deref(), Some("de"));
+        assert_eq!(parse_model_translation("Good morning").source_lang, None);
+    }
+}
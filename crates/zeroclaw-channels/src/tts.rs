@@ -9,6 +9,10 @@ use zeroclaw_config::schema::{Config, TtsProviderConfig};
 /// Maximum text length before synthesis is rejected (default: 4096 chars).
 const DEFAULT_MAX_TEXT_LENGTH: usize = 4096;
 
+/// Rough speaking rate (about 150 words a minute) used to turn
+/// `[tts].max_voice_duration_secs` into a text budget.
+const SPOKEN_CHARS_PER_SEC: u64 = 15;
+
 /// Default HTTP request timeout for TTS API calls.
 const TTS_HTTP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
 
@@ -673,6 +677,7 @@ pub struct TtsManager {
     agent_tts_provider: String,
     default_voice: String,
     max_text_length: usize,
+    max_voice_duration_secs: u64,
 }
 
 impl TtsManager {
@@ -747,9 +752,25 @@ impl TtsManager {
             agent_tts_provider,
             default_voice: config.tts.default_voice.clone(),
             max_text_length,
+            max_voice_duration_secs: config.tts.max_voice_duration_secs,
         })
     }
 
+    /// The part of `text` to voice under `[tts].max_voice_duration_secs`,
+    /// and whether it was cut short.
+    pub fn voice_excerpt<'a>(&self, text: &'a str) -> (&'a str, bool) {
+        let budget = self
+            .max_voice_duration_secs
+            .saturating_mul(SPOKEN_CHARS_PER_SEC);
+        let budget = usize::try_from(budget).unwrap_or(usize::MAX);
+        let budget = if budget == 0 {
+            self.max_text_length
+        } else {
+            budget.min(self.max_text_length)
+        };
+        truncate_for_speech(text, budget)
+    }
+
     pub async fn synthesize_opus(&self, text: &str) -> Result<Vec<u8>> {
         let audio = self.synthesize(text).await?;
         let provider_alias = self.agent_tts_provider.as_str();
@@ -856,6 +877,25 @@ impl TtsManager {
     }
 }
 
+/// Cut `text` to at most `max_chars` characters, ending on the last
+/// sentence (or failing that, word) boundary so the voice note does not stop
+/// mid-word.
+fn truncate_for_speech(text: &str, max_chars: usize) -> (&str, bool) {
+    let text = text.trim();
+    let Some((cut, _)) = text.char_indices().nth(max_chars) else {
+        return (text, false);
+    };
+    let head = &text[..cut];
+    let sentence_end = head
+        .rfind(['.', '!', '?', '。', '！', '？', '\n'])
+        .map(|i| i + head[i..].chars().next().map_or(1, char::len_utf8));
+    let end = sentence_end
+        .filter(|&end| end > head.len() / 2)
+        .or_else(|| head.rfind(char::is_whitespace))
+        .unwrap_or(head.len());
+    (head[..end].trim_end(), true)
+}
+
 // ── Tests ────────────────────────────────────────────────────────
 
 impl ::zeroclaw_api::attribution::Attributable for OpenAiTtsProvider {
@@ -1033,6 +1073,26 @@ mod tests {
         cfg
     }
 
+    #[test]
+    fn voice_excerpt_stops_at_a_sentence_within_the_duration_cap() {
+        let mut cfg = Config::default();
+        cfg.tts.max_voice_duration_secs = 2;
+        let manager = TtsManager::from_config(&cfg).unwrap();
+
+        let (short, cut) = manager.voice_excerpt("Ahoy there.");
+        assert_eq!((short, cut), ("Ahoy there.", false));
+
+        let (excerpt, cut) =
+            manager.voice_excerpt("The tide is high. The wind is strong and the crew is tired.");
+        assert!(cut);
+        assert_eq!(excerpt, "The tide is high.");
+
+        cfg.tts.max_voice_duration_secs = 0;
+        let manager = TtsManager::from_config(&cfg).unwrap();
+        let long = "word ".repeat(500);
+        assert!(!manager.voice_excerpt(&long).1);
+    }
+
     #[test]
     fn tts_manager_creation_with_defaults() {
         let config = Config::default();
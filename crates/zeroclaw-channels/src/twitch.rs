@@ -102,6 +102,10 @@ impl Channel for TwitchChannel {
         self.inner.health_check().await
     }
 
+    fn health_detail(&self) -> Option<String> {
+        self.inner.health_detail()
+    }
+
     async fn start_typing(&self, _recipient: &str) -> anyhow::Result<()> {
         // No typing concept in the Twitch IRC or EventSub APIs.
         Ok(())
@@ -70,13 +70,49 @@ const DEFAULT_DEVICE_ID: i32 = 1;
 #[cfg(feature = "whatsapp-web")]
 const DEVICE_EXISTS_SQL: &str = "SELECT COUNT(*) FROM device WHERE id = ?1";
 
-/// Used by [`persisted_device_exists`]: "does the store hold a *linked*
+/// Used by [`persisted_linked_device`]: "does the store hold a *linked*
 /// device?" — `pn` (the account JID) is NULL until pairing completes and is
 /// only written by a successful login, so it distinguishes a linked session
 /// from the unregistered row a starting channel persists pre-pairing.
 #[cfg(feature = "whatsapp-web")]
-const LINKED_DEVICE_EXISTS_SQL: &str =
-    "SELECT COUNT(*) FROM device WHERE id = ?1 AND pn IS NOT NULL";
+const LINKED_DEVICE_SQL: &str = "SELECT pn, push_name FROM device WHERE id = ?1 AND pn IS NOT NULL";
+
+/// The WhatsApp account a session database is linked to.
+#[cfg(feature = "whatsapp-web")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedDevice {
+    /// Account JID of this linked device (`<phone>:<device>@s.whatsapp.net`).
+    pub jid: String,
+    /// Display name the account reported; empty until the first sync.
+    pub push_name: String,
+}
+
+#[cfg(feature = "whatsapp-web")]
+impl LinkedDevice {
+    /// Phone number of the linked account, digits only.
+    pub fn phone(&self) -> &str {
+        let user = self.jid.split('@').next().unwrap_or_default();
+        user.split(':').next().unwrap_or_default()
+    }
+
+    /// One-line description for operator output, e.g.
+    /// `+15551234567 (device 12, "Ops phone")`.
+    pub fn describe(&self) -> String {
+        let device = self
+            .jid
+            .split('@')
+            .next()
+            .and_then(|user| user.split_once(':'))
+            .map(|(_, device)| format!("device {device}"));
+        let name = (!self.push_name.is_empty()).then(|| format!("\"{}\"", self.push_name));
+        let detail: Vec<String> = device.into_iter().chain(name).collect();
+        if detail.is_empty() {
+            format!("+{}", self.phone())
+        } else {
+            format!("+{} ({})", self.phone(), detail.join(", "))
+        }
+    }
+}
 
 /// Channel-owned persisted-login probe for readiness reporting.
 ///
@@ -92,23 +128,30 @@ const LINKED_DEVICE_EXISTS_SQL: &str =
 /// reports `false`.
 #[cfg(feature = "whatsapp-web")]
 pub fn persisted_device_exists<P: AsRef<Path>>(db_path: P) -> bool {
+    persisted_linked_device(db_path).is_some()
+}
+
+/// The linked account recorded in a session database, read the same
+/// read-only way as [`persisted_device_exists`]. `None` for every case that
+/// probe reports `false`.
+#[cfg(feature = "whatsapp-web")]
+pub fn persisted_linked_device<P: AsRef<Path>>(db_path: P) -> Option<LinkedDevice> {
     let path = db_path.as_ref();
     if !path.is_file() {
-        return false;
+        return None;
     }
-    let Ok(conn) = Connection::open_with_flags(
+    let conn = Connection::open_with_flags(
         path,
         rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX,
-    ) else {
-        return false;
-    };
-    conn.query_row(
-        LINKED_DEVICE_EXISTS_SQL,
-        params![DEFAULT_DEVICE_ID],
-        |row| row.get::<_, i64>(0),
     )
-    .map(|count| count > 0)
-    .unwrap_or(false)
+    .ok()?;
+    conn.query_row(LINKED_DEVICE_SQL, params![DEFAULT_DEVICE_ID], |row| {
+        Ok(LinkedDevice {
+            jid: row.get(0)?,
+            push_name: row.get(1)?,
+        })
+    })
+    .ok()
 }
 
 #[cfg(feature = "whatsapp-web")]
@@ -1577,6 +1620,35 @@ mod tests {
         assert!(persisted_device_exists(&path));
     }
 
+    #[cfg(feature = "whatsapp-web")]
+    #[tokio::test]
+    async fn persisted_linked_device_reports_account_and_name() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("session.db");
+        let store = RusqliteStore::new(&path).unwrap();
+        assert_eq!(persisted_linked_device(&path), None);
+
+        let mut device = CoreDevice::new();
+        device.pn = Some(wacore_binary::jid::Jid::pn("15551234567"));
+        device.push_name = "Ops phone".to_string();
+        DeviceStoreTrait::save(&store, &device).await.unwrap();
+
+        let linked = persisted_linked_device(&path).expect("linked device");
+        assert_eq!(linked.phone(), "15551234567");
+        assert_eq!(linked.describe(), "+15551234567 (\"Ops phone\")");
+    }
+
+    #[cfg(feature = "whatsapp-web")]
+    #[test]
+    fn linked_device_describe_includes_device_index() {
+        let linked = LinkedDevice {
+            jid: "15551234567:12@s.whatsapp.net".to_string(),
+            push_name: String::new(),
+        };
+        assert_eq!(linked.phone(), "15551234567");
+        assert_eq!(linked.describe(), "+15551234567 (device 12)");
+    }
+
     #[cfg(feature = "whatsapp-web")]
     #[tokio::test]
     async fn mutation_macs_round_trip_raw_bytes() {
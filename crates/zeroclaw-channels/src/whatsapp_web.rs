@@ -91,6 +91,32 @@ pub struct WhatsAppWebChannel {
     persist: Option<Arc<parking_lot::RwLock<zeroclaw_config::schema::Config>>>,
 }
 
+/// Progress reported while [`WhatsAppWebChannel::pair_session`] runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairingProgress<'a> {
+    /// A QR payload to show the operator; WhatsApp rotates it every ~20s
+    /// until it is scanned.
+    Qr(&'a str),
+    /// The stored session was rejected by WhatsApp (unlinked from the phone
+    /// or expired). It has been cleared and a fresh pairing follows.
+    SessionExpired,
+}
+
+/// How a pairing run ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PairingOutcome {
+    /// A QR code was scanned and the new link is persisted.
+    Linked(super::whatsapp_storage::LinkedDevice),
+    /// The stored session connected as-is; nothing was re-paired.
+    AlreadyLinked(super::whatsapp_storage::LinkedDevice),
+}
+
+/// How a single connection attempt inside a pairing run ended.
+enum PairingAttempt {
+    Connected,
+    LoggedOut,
+}
+
 impl WhatsAppWebChannel {
     #[cfg(feature = "whatsapp-web")]
     pub fn new(
@@ -387,7 +413,7 @@ impl WhatsAppWebChannel {
 
     /// Render a WhatsApp pairing QR payload into terminal-friendly text.
     #[cfg(feature = "whatsapp-web")]
-    fn render_pairing_qr(code: &str) -> Result<String> {
+    pub fn render_pairing_qr(code: &str) -> Result<String> {
         let payload = code.trim();
         if payload.is_empty() {
             anyhow::bail!("QR payload is empty");
@@ -532,6 +558,198 @@ impl WhatsAppWebChannel {
         Ok(removed)
     }
 
+    /// Restrict the session database and its sidecars to the owner. The
+    /// files hold the device's identity keys, so any other local user who
+    /// can read them can impersonate the linked account.
+    fn restrict_session_permissions(expanded_session_path: &str) -> std::io::Result<()> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            for path in Self::session_file_paths(expanded_session_path) {
+                match std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)) {
+                    Ok(()) => {}
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        #[cfg(not(unix))]
+        let _ = expanded_session_path;
+        Ok(())
+    }
+
+    /// Write a WhatsApp pairing QR payload as a PNG image, for terminals
+    /// that cannot render the block-character QR legibly.
+    pub fn write_pairing_qr_png(code: &str, path: &Path) -> Result<()> {
+        let payload = code.trim();
+        if payload.is_empty() {
+            anyhow::bail!("QR payload is empty");
+        }
+        let qr = qrcode::QrCode::new(payload.as_bytes())
+            .map_err(|err| anyhow::anyhow!("Failed to encode WhatsApp Web QR payload: {err}"))?;
+        qr.render::<image::Luma<u8>>()
+            .quiet_zone(true)
+            .min_dimensions(320, 320)
+            .build()
+            .save(path)
+            .with_context(|| format!("Failed to write QR image to {}", path.display()))
+    }
+
+    /// Link a WhatsApp account to the session at `session_path` without
+    /// starting the channel.
+    ///
+    /// With a linked session already on disk this only connects to confirm
+    /// WhatsApp still accepts it, and returns [`PairingOutcome::AlreadyLinked`].
+    /// A session WhatsApp rejects is cleared (reported as
+    /// [`PairingProgress::SessionExpired`]) and pairing starts over. Each QR
+    /// payload is handed to `on_progress` for the caller to display; the run
+    /// ends once the phone completes the link and the device is persisted,
+    /// or fails after `timeout` without one. On success the session files
+    /// are restricted to the owner.
+    pub async fn pair_session(
+        session_path: &str,
+        ws_url: Option<&str>,
+        timeout: std::time::Duration,
+        on_progress: impl Fn(PairingProgress<'_>) + Send + Sync + 'static,
+    ) -> Result<PairingOutcome> {
+        if session_path.trim().is_empty() {
+            anyhow::bail!("WhatsApp Web pairing requires `session_path` in the channel config");
+        }
+        let expanded = Self::expand_session_path(session_path);
+        let on_progress: Arc<dyn Fn(PairingProgress<'_>) + Send + Sync> = Arc::new(on_progress);
+        let mut resuming = Self::has_persisted_session(session_path);
+
+        loop {
+            match Self::run_pairing_attempt(&expanded, ws_url, timeout, Arc::clone(&on_progress))
+                .await?
+            {
+                PairingAttempt::Connected => {
+                    // The link is written by the store's background saver;
+                    // wait for it so the next channel start resumes it.
+                    let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(10);
+                    let device = loop {
+                        if let Some(device) =
+                            super::whatsapp_storage::persisted_linked_device(&expanded)
+                        {
+                            break device;
+                        }
+                        if tokio::time::Instant::now() >= deadline {
+                            anyhow::bail!(
+                                "WhatsApp reported the device as linked but the session was not saved to {expanded}"
+                            );
+                        }
+                        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+                    };
+                    Self::restrict_session_permissions(&expanded)
+                        .with_context(|| format!("Failed to restrict permissions on {expanded}"))?;
+                    ::zeroclaw_log::record!(
+                        INFO,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Success)
+                            .with_attrs(::serde_json::json!({"resumed": resuming})),
+                        "WhatsApp Web session linked"
+                    );
+                    return Ok(if resuming {
+                        PairingOutcome::AlreadyLinked(device)
+                    } else {
+                        PairingOutcome::Linked(device)
+                    });
+                }
+                PairingAttempt::LoggedOut if resuming => {
+                    on_progress(PairingProgress::SessionExpired);
+                    Self::clear_persisted_session(session_path).with_context(|| {
+                        format!("Failed to clear expired session at {expanded}")
+                    })?;
+                    resuming = false;
+                }
+                PairingAttempt::LoggedOut => {
+                    anyhow::bail!(
+                        "WhatsApp ended the pairing before the device was linked; run the command again for a fresh QR code"
+                    );
+                }
+            }
+        }
+    }
+
+    /// One connection for [`Self::pair_session`]: runs a bot with no message
+    /// handling until it connects, is logged out, or `timeout` passes.
+    async fn run_pairing_attempt(
+        expanded_session_path: &str,
+        ws_url: Option<&str>,
+        timeout: std::time::Duration,
+        on_progress: Arc<dyn Fn(PairingProgress<'_>) + Send + Sync>,
+    ) -> Result<PairingAttempt> {
+        use wacore::store::DevicePropsOverride;
+        use wacore::types::events::Event;
+        use whatsapp_rust::TokioRuntime;
+        use whatsapp_rust::bot::Bot;
+        use whatsapp_rust_tokio_transport::TokioWebSocketTransportFactory;
+        use whatsapp_rust_ureq_http_client::UreqHttpClient;
+
+        let backend = Arc::new(RusqliteStore::new(expanded_session_path)?);
+        let mut transport_factory = TokioWebSocketTransportFactory::new();
+        if let Some(ws_url) = ws_url {
+            transport_factory = transport_factory.with_url(ws_url.to_string());
+        }
+        let (done_tx, mut done_rx) = tokio::sync::mpsc::channel::<PairingAttempt>(1);
+
+        let mut bot = Bot::builder()
+            .with_backend(backend)
+            .with_transport_factory(transport_factory)
+            .with_http_client(UreqHttpClient::new())
+            .with_runtime(TokioRuntime)
+            .with_device_props(
+                DevicePropsOverride::new()
+                    .with_os("ZeroClaw")
+                    .with_platform_type(PlatformType::Desktop),
+            )
+            .on_event(move |event, _client| {
+                let done_tx = done_tx.clone();
+                let on_progress = Arc::clone(&on_progress);
+                async move {
+                    match &*event {
+                        Event::PairingQrCode { code, .. } => {
+                            on_progress(PairingProgress::Qr(code.as_str()));
+                        }
+                        Event::Connected(_) => {
+                            let _ = done_tx.send(PairingAttempt::Connected).await;
+                        }
+                        Event::LoggedOut(_) => {
+                            let _ = done_tx.send(PairingAttempt::LoggedOut).await;
+                        }
+                        _ => {}
+                    }
+                }
+            })
+            .build()
+            .await?;
+        let bot_handle = bot.run().await?;
+
+        let ended = select! {
+            ended = tokio::time::timeout(timeout, done_rx.recv()) => ended,
+            _ = tokio::signal::ctrl_c() => {
+                bot_handle.abort();
+                let _ = bot_handle.await;
+                anyhow::bail!("WhatsApp Web pairing cancelled");
+            }
+        };
+
+        bot_handle.abort();
+        let _ = bot_handle.await;
+        // Close the SQLite connection before the caller inspects or clears
+        // the session files.
+        drop(bot);
+
+        match ended {
+            Ok(Some(attempt)) => Ok(attempt),
+            Ok(None) => anyhow::bail!("WhatsApp Web connection closed before pairing finished"),
+            Err(_) => anyhow::bail!(
+                "No device linked within {}s; run the command again for a fresh QR code",
+                timeout.as_secs()
+            ),
+        }
+    }
+
     /// Attempt to download and transcribe a WhatsApp voice note.
     /// Returns `None` if transcription is disabled, download fails, or
     /// transcription fails (all logged as warnings).
@@ -904,11 +1122,14 @@ impl WhatsAppWebChannel {
                 thread_ts: None,
                 interruption_scope_id: None,
                 attachments,
+                media_files: Vec::new(),
                 subject: None,
                 internal_sop_event: None,
                 passive_context,
                 explicitly_addressed: false,
                 conversation_scope,
+                edit_of: None,
+                reaction: None,
             })
             .await
         {
@@ -2495,8 +2716,23 @@ impl Channel for WhatsAppWebChannel {
     }
 
     async fn health_check(&self) -> bool {
-        let bot_handle_guard = self.bot_handle.lock();
-        bot_handle_guard.is_some()
+        // A running channel is healthy while its bot is up. An idle handle
+        // (`channel doctor`) is healthy when the next start can resume a
+        // linked session instead of waiting for a QR scan.
+        self.bot_handle.lock().is_some() || Self::has_persisted_session(&self.session_path)
+    }
+
+    fn health_detail(&self) -> Option<String> {
+        let expanded = Self::expand_session_path(&self.session_path);
+        Some(
+            match super::whatsapp_storage::persisted_linked_device(&expanded) {
+                Some(device) => format!("session linked as {}", device.describe()),
+                None => format!(
+                    "no linked session at {expanded}; run `zeroclaw channel pair whatsapp --alias {}`",
+                    self.alias
+                ),
+            },
+        )
     }
 
     async fn start_typing(&self, recipient: &str) -> Result<()> {
@@ -3231,6 +3467,58 @@ mod tests {
         assert!(!ch.health_check().await);
     }
 
+    #[tokio::test]
+    #[cfg(feature = "whatsapp-web")]
+    async fn idle_channel_health_reports_linked_session() {
+        use wacore::store::Device as CoreDevice;
+        use wacore::store::traits::DeviceStore as DeviceStoreTrait;
+
+        let temp = tempfile::tempdir().unwrap();
+        let session_path = temp.path().join("session.db");
+        let cfg = zeroclaw_config::schema::WhatsAppConfig {
+            enabled: true,
+            session_path: Some(session_path.to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        let ch = WhatsAppWebChannel::new(&cfg, "ops", Arc::new(Vec::new), Arc::new(Vec::new));
+        assert!(!ch.health_check().await);
+        let detail = ch.health_detail().unwrap();
+        assert!(
+            detail.contains("zeroclaw channel pair whatsapp --alias ops"),
+            "unpaired detail must say how to pair; got {detail:?}"
+        );
+
+        let store = RusqliteStore::new(&session_path).unwrap();
+        let mut device = CoreDevice::new();
+        device.pn = Some(Jid::pn("15551234567"));
+        device.push_name = "Ops phone".to_string();
+        DeviceStoreTrait::save(&store, &device).await.unwrap();
+
+        assert!(ch.health_check().await);
+        assert_eq!(
+            ch.health_detail().as_deref(),
+            Some("session linked as +15551234567 (\"Ops phone\")")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "whatsapp-web", unix))]
+    fn restrict_session_permissions_makes_files_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempfile::tempdir().unwrap();
+        let db = temp.path().join("session.db");
+        std::fs::write(&db, b"db").unwrap();
+        std::fs::write(temp.path().join("session.db-wal"), b"wal").unwrap();
+        let expanded = db.to_string_lossy().into_owned();
+
+        WhatsAppWebChannel::restrict_session_permissions(&expanded).unwrap();
+        for path in [db.clone(), temp.path().join("session.db-wal")] {
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600, "{}", path.display());
+        }
+    }
+
     // ── Reconnect retry state machine tests (exercise production helpers) ──
 
     #[test]
@@ -0,0 +1,667 @@
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+use zeroclaw_api::channel::{Channel, ChannelMessage, SendMessage};
+
+/// Prefix marking a stream reply target: `stream:<stream_id>:<topic>`.
+/// Anything without this prefix is a comma-separated list of direct-message
+/// recipient emails.
+const STREAM_TARGET_PREFIX: &str = "stream:";
+
+/// Topic used when a stream send arrives without one (e.g. cron delivery
+/// addressed to a bare `stream:<id>:` target). Zulip rejects empty topics.
+const FALLBACK_TOPIC: &str = "(no topic)";
+
+/// Upper bound for one long-poll round-trip. Zulip sends a heartbeat event
+/// roughly every minute, so a silent connection past this is dead.
+const EVENTS_POLL_TIMEOUT_SECS: u64 = 90;
+const EVENTS_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Backoff after a failed register or poll before retrying.
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Zulip bot channel.
+///
+/// Registers an event queue through the real-time events API, long-polls
+/// `/api/v1/events` for `message` events, and replies through
+/// `/api/v1/messages`. Stream messages reply into the same stream and topic;
+/// direct messages reply to the original participants.
+pub struct ZulipChannel {
+    site_url: String,
+    bot_email: String,
+    api_key: String,
+    /// Stream names to accept. Empty accepts every subscribed stream.
+    streams: Vec<String>,
+    /// The alias key under `[channels.zulip.<alias>]` this handle is
+    /// bound to. Used to scope peer-group writes and resolver lookups.
+    alias: String,
+    /// Resolves inbound external peers from canonical state at message-time.
+    /// No cache (see AGENTS.md "ABSOLUTE RULE — SINGLE SOURCE OF TRUTH").
+    peer_resolver: Arc<dyn Fn() -> Vec<String> + Send + Sync>,
+}
+
+/// An event queue registered with the Zulip server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct EventQueue {
+    queue_id: String,
+    last_event_id: i64,
+}
+
+/// Outcome of one `/api/v1/events` long-poll.
+#[derive(Debug)]
+enum PollOutcome {
+    Events(Vec<Value>),
+    /// The server garbage-collected the queue (`BAD_EVENT_QUEUE_ID`).
+    QueueExpired,
+}
+
+/// Where an outbound message goes, decoded from a `reply_target`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ZulipTarget {
+    Stream { stream_id: u64, topic: String },
+    Direct { emails: Vec<String> },
+}
+
+impl ZulipTarget {
+    fn parse(reply_target: &str) -> anyhow::Result<Self> {
+        if let Some(rest) = reply_target.strip_prefix(STREAM_TARGET_PREFIX) {
+            let (id, topic) = rest.split_once(':').unwrap_or((rest, ""));
+            let stream_id = id.trim().parse::<u64>().map_err(|_| {
+                anyhow::Error::msg(format!("invalid Zulip stream target: {reply_target}"))
+            })?;
+            let topic = if topic.trim().is_empty() {
+                FALLBACK_TOPIC.to_string()
+            } else {
+                topic.to_string()
+            };
+            return Ok(Self::Stream { stream_id, topic });
+        }
+        let emails: Vec<String> = reply_target
+            .split(',')
+            .map(str::trim)
+            .filter(|e| !e.is_empty())
+            .map(str::to_string)
+            .collect();
+        if emails.is_empty() {
+            anyhow::bail!("Zulip reply target is empty");
+        }
+        Ok(Self::Direct { emails })
+    }
+
+    fn form(&self, content: &str) -> Vec<(&'static str, String)> {
+        match self {
+            Self::Stream { stream_id, topic } => vec![
+                ("type", "stream".to_string()),
+                ("to", stream_id.to_string()),
+                ("topic", topic.clone()),
+                ("content", content.to_string()),
+            ],
+            Self::Direct { emails } => vec![
+                ("type", "private".to_string()),
+                (
+                    "to",
+                    serde_json::to_string(emails).unwrap_or_else(|_| "[]".into()),
+                ),
+                ("content", content.to_string()),
+            ],
+        }
+    }
+}
+
+impl ZulipChannel {
+    pub fn new(
+        site_url: String,
+        bot_email: String,
+        api_key: String,
+        streams: Vec<String>,
+        alias: impl Into<String>,
+        peer_resolver: Arc<dyn Fn() -> Vec<String> + Send + Sync>,
+    ) -> Self {
+        Self {
+            site_url: site_url.trim_end_matches('/').to_string(),
+            bot_email,
+            api_key,
+            streams,
+            alias: alias.into(),
+            peer_resolver,
+        }
+    }
+
+    /// Return the alias under `[channels.zulip.<alias>]` that this
+    /// channel handle is bound to.
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    fn http_client(&self) -> reqwest::Client {
+        zeroclaw_config::schema::build_runtime_proxy_client("channel.zulip")
+    }
+
+    fn events_client(&self) -> reqwest::Client {
+        zeroclaw_config::schema::build_runtime_proxy_client_with_timeouts(
+            "channel.zulip",
+            EVENTS_POLL_TIMEOUT_SECS,
+            EVENTS_CONNECT_TIMEOUT_SECS,
+        )
+    }
+
+    fn api_url(&self, path: &str) -> String {
+        format!("{}/api/v1/{path}", self.site_url)
+    }
+
+    /// Zulip identifies users by email; addresses are case-insensitive.
+    fn is_user_allowed(&self, email: &str) -> bool {
+        let peers = (self.peer_resolver)();
+        crate::allowlist::is_user_allowed(&peers, email, crate::allowlist::Match::CaseInsensitive)
+    }
+
+    fn is_stream_allowed(&self, stream: &str) -> bool {
+        self.streams.is_empty() || self.streams.iter().any(|s| s.eq_ignore_ascii_case(stream))
+    }
+
+    async fn register_queue(&self) -> anyhow::Result<EventQueue> {
+        let form = [
+            ("event_types", r#"["message"]"#),
+            // Raw markdown in `content` instead of server-rendered HTML.
+            ("apply_markdown", "false"),
+        ];
+        let resp = self
+            .http_client()
+            .post(self.api_url("register"))
+            .basic_auth(&self.bot_email, Some(&self.api_key))
+            .form(&form)
+            .send()
+            .await?;
+        let status = resp.status();
+        let body: Value = resp.json().await.unwrap_or(Value::Null);
+        if !status.is_success() || body.get("result").and_then(Value::as_str) != Some("success") {
+            let msg = body.get("msg").and_then(Value::as_str).unwrap_or("");
+            anyhow::bail!("Zulip register failed ({status}): {msg}");
+        }
+        parse_register_response(&body)
+    }
+
+    async fn poll_events(&self, queue: &EventQueue) -> anyhow::Result<PollOutcome> {
+        let resp = self
+            .events_client()
+            .get(self.api_url("events"))
+            .basic_auth(&self.bot_email, Some(&self.api_key))
+            .query(&[
+                ("queue_id", queue.queue_id.as_str()),
+                ("last_event_id", &queue.last_event_id.to_string()),
+            ])
+            .send()
+            .await?;
+        let status = resp.status();
+        let body: Value = resp.json().await.unwrap_or(Value::Null);
+        if is_queue_expired(&body) {
+            return Ok(PollOutcome::QueueExpired);
+        }
+        if !status.is_success() || body.get("result").and_then(Value::as_str) != Some("success") {
+            let msg = body.get("msg").and_then(Value::as_str).unwrap_or("");
+            anyhow::bail!("Zulip events poll failed ({status}): {msg}");
+        }
+        Ok(PollOutcome::Events(
+            body.get("events")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default(),
+        ))
+    }
+
+    /// Convert one `message` event into a `ChannelMessage`, applying the
+    /// self-message, stream, and allowlist filters.
+    fn message_from_event(&self, event: &Value) -> Option<ChannelMessage> {
+        if event.get("type").and_then(Value::as_str) != Some("message") {
+            return None;
+        }
+        let message = event.get("message")?;
+        let sender = message.get("sender_email").and_then(Value::as_str)?;
+        if sender.eq_ignore_ascii_case(&self.bot_email) {
+            return None;
+        }
+
+        let reply_target = match message.get("type").and_then(Value::as_str)? {
+            "stream" => {
+                let stream = message.get("display_recipient").and_then(Value::as_str)?;
+                if !self.is_stream_allowed(stream) {
+                    return None;
+                }
+                let stream_id = message.get("stream_id").and_then(Value::as_u64)?;
+                let topic = message
+                    .get("subject")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                format!("{STREAM_TARGET_PREFIX}{stream_id}:{topic}")
+            }
+            "private" => {
+                let recipients = message.get("display_recipient").and_then(Value::as_array)?;
+                let emails: Vec<&str> = recipients
+                    .iter()
+                    .filter_map(|r| r.get("email").and_then(Value::as_str))
+                    .filter(|e| !e.eq_ignore_ascii_case(&self.bot_email))
+                    .collect();
+                if emails.is_empty() {
+                    sender.to_string()
+                } else {
+                    emails.join(",")
+                }
+            }
+            _ => return None,
+        };
+
+        if !self.is_user_allowed(sender) {
+            ::zeroclaw_log::record!(
+                DEBUG,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                    .with_attrs(::serde_json::json!({"sender": sender})),
+                "ignoring message from unauthorized user"
+            );
+            return None;
+        }
+
+        let content = message
+            .get("content")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .trim();
+        if content.is_empty() {
+            return None;
+        }
+
+        let id = message
+            .get("id")
+            .and_then(Value::as_u64)
+            .map(|id| id.to_string())
+            .unwrap_or_default();
+        let timestamp = message
+            .get("timestamp")
+            .and_then(Value::as_u64)
+            .unwrap_or_else(|| {
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs()
+            });
+
+        Some(ChannelMessage {
+            id,
+            sender: sender.to_string(),
+            reply_target,
+            content: content.to_string(),
+            channel: "zulip".to_string(),
+            channel_alias: Some(self.alias.clone()),
+            timestamp,
+            thread_ts: None,
+            interruption_scope_id: None,
+            attachments: vec![],
+            subject: None,
+
+            ..Default::default()
+        })
+    }
+}
+
+fn parse_register_response(body: &Value) -> anyhow::Result<EventQueue> {
+    let queue_id = body
+        .get("queue_id")
+        .and_then(Value::as_str)
+        .filter(|q| !q.is_empty())
+        .ok_or_else(|| anyhow::Error::msg("Zulip register response missing queue_id"))?;
+    let last_event_id = body
+        .get("last_event_id")
+        .and_then(Value::as_i64)
+        .unwrap_or(-1);
+    Ok(EventQueue {
+        queue_id: queue_id.to_string(),
+        last_event_id,
+    })
+}
+
+fn is_queue_expired(body: &Value) -> bool {
+    body.get("code").and_then(Value::as_str) == Some("BAD_EVENT_QUEUE_ID")
+}
+
+impl ::zeroclaw_api::attribution::Attributable for ZulipChannel {
+    fn role(&self) -> ::zeroclaw_api::attribution::Role {
+        ::zeroclaw_api::attribution::Role::Channel(::zeroclaw_api::attribution::ChannelKind::Zulip)
+    }
+    fn alias(&self) -> &str {
+        &self.alias
+    }
+}
+
+#[async_trait]
+impl Channel for ZulipChannel {
+    fn name(&self) -> &str {
+        "zulip"
+    }
+
+    async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
+        // Zulip renders markdown natively, so content passes through as-is.
+        let target = ZulipTarget::parse(&message.recipient)?;
+        let resp = self
+            .http_client()
+            .post(self.api_url("messages"))
+            .basic_auth(&self.bot_email, Some(&self.api_key))
+            .form(&target.form(&message.content))
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let err = resp.text().await.unwrap_or_default();
+            anyhow::bail!("Zulip send message failed ({status}): {err}");
+        }
+        Ok(())
+    }
+
+    async fn listen(&self, tx: tokio::sync::mpsc::Sender<ChannelMessage>) -> anyhow::Result<()> {
+        ::zeroclaw_log::record!(
+            INFO,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
+            "starting event queue listener"
+        );
+
+        let mut queue: Option<EventQueue> = None;
+        loop {
+            let current = match queue.take() {
+                Some(q) => q,
+                None => match self.register_queue().await {
+                    Ok(q) => q,
+                    Err(e) => {
+                        ::zeroclaw_log::record!(
+                            WARN,
+                            ::zeroclaw_log::Event::new(
+                                module_path!(),
+                                ::zeroclaw_log::Action::Note
+                            )
+                            .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                            .with_attrs(::serde_json::json!({"error": format!("{}", e)})),
+                            "event queue registration failed"
+                        );
+                        tokio::time::sleep(RETRY_DELAY).await;
+                        continue;
+                    }
+                },
+            };
+
+            match self.poll_events(&current).await {
+                Ok(PollOutcome::QueueExpired) => {
+                    ::zeroclaw_log::record!(
+                        INFO,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
+                        "event queue expired; re-registering"
+                    );
+                }
+                Ok(PollOutcome::Events(events)) => {
+                    let mut next = current;
+                    for event in &events {
+                        if let Some(id) = event.get("id").and_then(Value::as_i64) {
+                            next.last_event_id = next.last_event_id.max(id);
+                        }
+                        let Some(msg) = self.message_from_event(event) else {
+                            continue;
+                        };
+                        if tx.send(msg).await.is_err() {
+                            ::zeroclaw_log::record!(
+                                WARN,
+                                ::zeroclaw_log::Event::new(
+                                    module_path!(),
+                                    ::zeroclaw_log::Action::Note
+                                )
+                                .with_outcome(::zeroclaw_log::EventOutcome::Unknown),
+                                "message channel closed"
+                            );
+                            return Ok(());
+                        }
+                    }
+                    queue = Some(next);
+                }
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                            .with_attrs(::serde_json::json!({"error": format!("{}", e)})),
+                        "event poll error"
+                    );
+                    // Keep the queue: transient network errors don't
+                    // invalidate it, and expiry is reported explicitly.
+                    queue = Some(current);
+                    tokio::time::sleep(RETRY_DELAY).await;
+                }
+            }
+        }
+    }
+
+    async fn health_check(&self) -> bool {
+        let resp = self
+            .http_client()
+            .get(self.api_url("users/me"))
+            .basic_auth(&self.bot_email, Some(&self.api_key))
+            .send()
+            .await;
+
+        match resp {
+            Ok(r) => r.status().is_success(),
+            Err(_) => false,
+        }
+    }
+
+    async fn start_typing(&self, _recipient: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn stop_typing(&self, _recipient: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn self_handle(&self) -> Option<String> {
+        Some(self.bot_email.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn channel(streams: Vec<String>, peers: Vec<String>) -> ZulipChannel {
+        ZulipChannel::new(
+            "https://chat.example.com/".into(),
+            "bot@chat.example.com".into(),
+            "key".into(),
+            streams,
+            "zulip_test_alias",
+            Arc::new(move || peers.clone()),
+        )
+    }
+
+    fn stream_event(stream: &str, topic: &str, sender: &str) -> Value {
+        json!({
+            "type": "message",
+            "id": 7,
+            "message": {
+                "id": 42,
+                "type": "stream",
+                "sender_email": sender,
+                "display_recipient": stream,
+                "stream_id": 12,
+                "subject": topic,
+                "content": "**hello** there",
+                "timestamp": 1_700_000_000_u64
+            }
+        })
+    }
+
+    #[test]
+    fn site_url_trailing_slash_stripped() {
+        let ch = channel(vec![], vec![]);
+        assert_eq!(
+            ch.api_url("register"),
+            "https://chat.example.com/api/v1/register"
+        );
+    }
+
+    #[test]
+    fn stream_message_replies_into_same_topic() {
+        let ch = channel(vec![], vec!["*".into()]);
+        let msg = ch
+            .message_from_event(&stream_event("general", "deploys: v2", "alice@example.com"))
+            .expect("stream message accepted");
+        assert_eq!(msg.reply_target, "stream:12:deploys: v2");
+        assert_eq!(msg.sender, "alice@example.com");
+        assert_eq!(msg.content, "**hello** there");
+        assert_eq!(msg.id, "42");
+        assert_eq!(msg.channel, "zulip");
+
+        let target = ZulipTarget::parse(&msg.reply_target).unwrap();
+        assert_eq!(
+            target,
+            ZulipTarget::Stream {
+                stream_id: 12,
+                topic: "deploys: v2".into()
+            }
+        );
+    }
+
+    #[test]
+    fn stream_filter_is_case_insensitive_and_empty_accepts_all() {
+        let ch = channel(vec!["General".into()], vec!["*".into()]);
+        assert!(
+            ch.message_from_event(&stream_event("general", "t", "a@example.com"))
+                .is_some()
+        );
+        assert!(
+            ch.message_from_event(&stream_event("random", "t", "a@example.com"))
+                .is_none()
+        );
+
+        let all = channel(vec![], vec!["*".into()]);
+        assert!(
+            all.message_from_event(&stream_event("random", "t", "a@example.com"))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn allowed_users_match_email_case_insensitively() {
+        let ch = channel(vec![], vec!["Alice@Example.com".into()]);
+        assert!(
+            ch.message_from_event(&stream_event("general", "t", "alice@example.com"))
+                .is_some()
+        );
+        assert!(
+            ch.message_from_event(&stream_event("general", "t", "mallory@example.com"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn empty_allowlist_denies_everyone() {
+        let ch = channel(vec![], vec![]);
+        assert!(
+            ch.message_from_event(&stream_event("general", "t", "alice@example.com"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn own_messages_are_dropped() {
+        let ch = channel(vec![], vec!["*".into()]);
+        assert!(
+            ch.message_from_event(&stream_event("general", "t", "BOT@chat.example.com"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn direct_message_replies_to_other_participants() {
+        let ch = channel(vec![], vec!["*".into()]);
+        let event = json!({
+            "type": "message",
+            "id": 3,
+            "message": {
+                "id": 5,
+                "type": "private",
+                "sender_email": "alice@example.com",
+                "display_recipient": [
+                    {"email": "alice@example.com"},
+                    {"email": "bob@example.com"},
+                    {"email": "bot@chat.example.com"}
+                ],
+                "content": "hi"
+            }
+        });
+        let msg = ch.message_from_event(&event).expect("dm accepted");
+        assert_eq!(msg.reply_target, "alice@example.com,bob@example.com");
+        assert_eq!(
+            ZulipTarget::parse(&msg.reply_target).unwrap(),
+            ZulipTarget::Direct {
+                emails: vec!["alice@example.com".into(), "bob@example.com".into()]
+            }
+        );
+    }
+
+    #[test]
+    fn heartbeat_events_are_ignored() {
+        let ch = channel(vec![], vec!["*".into()]);
+        assert!(
+            ch.message_from_event(&json!({"type": "heartbeat", "id": 9}))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn stream_target_without_topic_uses_fallback() {
+        assert_eq!(
+            ZulipTarget::parse("stream:12:").unwrap(),
+            ZulipTarget::Stream {
+                stream_id: 12,
+                topic: FALLBACK_TOPIC.into()
+            }
+        );
+        assert!(ZulipTarget::parse("stream:general:topic").is_err());
+        assert!(ZulipTarget::parse(" , ").is_err());
+    }
+
+    #[test]
+    fn stream_form_carries_markdown_verbatim() {
+        let target = ZulipTarget::Stream {
+            stream_id: 12,
+            topic: "t".into(),
+        };
+        let form = target.form("```rust\nfn main() {}\n```");
+        assert!(form.contains(&("type", "stream".to_string())));
+        assert!(form.contains(&("to", "12".to_string())));
+        assert!(form.contains(&("content", "```rust\nfn main() {}\n```".to_string())));
+    }
+
+    #[test]
+    fn register_response_parses_queue() {
+        let body = json!({"result": "success", "queue_id": "q-1", "last_event_id": -1});
+        assert_eq!(
+            parse_register_response(&body).unwrap(),
+            EventQueue {
+                queue_id: "q-1".into(),
+                last_event_id: -1
+            }
+        );
+        assert!(parse_register_response(&json!({"result": "success"})).is_err());
+    }
+
+    #[test]
+    fn bad_event_queue_id_is_detected_as_expiry() {
+        let body = json!({
+            "result": "error",
+            "code": "BAD_EVENT_QUEUE_ID",
+            "msg": "Bad event queue ID: q-1"
+        });
+        assert!(is_queue_expired(&body));
+        assert!(!is_queue_expired(
+            &json!({"result": "error", "code": "UNAUTHORIZED"})
+        ));
+    }
+}
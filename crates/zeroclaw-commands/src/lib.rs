@@ -36,6 +36,10 @@ pub enum BuiltinCommandId {
     Config,
     /// Show or change model thinking/reasoning effort.
     Thinking,
+    /// Show or change the persona for this conversation.
+    Persona,
+    /// Turn voice-note replies on or off for this conversation.
+    Voice,
     /// Manage durable goal-mode work.
     Goal,
 }
@@ -173,6 +177,24 @@ static BUILTIN_COMMANDS: &[CommandSpec] = &[
         surfaces: CHANNEL_ONLY,
         execution: CommandExecution::RuntimeCommand,
     },
+    CommandSpec {
+        id: BuiltinCommandId::Persona,
+        name: "persona",
+        aliases: &[],
+        usage: "/persona [name|default]",
+        description_key: "command-persona-description",
+        surfaces: CHANNEL_ONLY,
+        execution: CommandExecution::RuntimeCommand,
+    },
+    CommandSpec {
+        id: BuiltinCommandId::Voice,
+        name: "voice",
+        aliases: &[],
+        usage: "/voice [on|off]",
+        description_key: "command-voice-description",
+        surfaces: CHANNEL_ONLY,
+        execution: CommandExecution::RuntimeCommand,
+    },
     CommandSpec {
         id: BuiltinCommandId::Goal,
         name: "goal",
@@ -415,6 +415,11 @@ fn scrub_agent_refs(cfg: &mut Config, alias: &str) {
     if cfg.heartbeat.agent.trim() == alias {
         cfg.heartbeat.agent.clear();
     }
+    for watcher in cfg.watchers.iter_mut() {
+        if watcher.agent.trim() == alias {
+            watcher.agent.clear();
+        }
+    }
     // Compute the match first so the immutable borrow ends before the assignment.
     let clear_acp = cfg
         .acp
@@ -699,6 +704,16 @@ fn rewrite_agent_refs(cfg: &mut Config, old: &str, new: &str) -> Vec<String> {
         cfg.heartbeat.agent = new.to_string();
         dirty.push("heartbeat.agent".to_string());
     }
+    let mut watchers_touched = false;
+    for watcher in cfg.watchers.iter_mut() {
+        if watcher.agent.trim() == old {
+            watcher.agent = new.to_string();
+            watchers_touched = true;
+        }
+    }
+    if watchers_touched {
+        dirty.push("watchers".to_string());
+    }
     let hit_acp = cfg
         .acp
         .default_agent
@@ -1192,6 +1207,22 @@ fn collect_agent_refs(cfg: &Config, alias: &str, sites: &mut Vec<RefSite>) {
             ));
         }
     }
+    // watchers[].agent — validate() trims; only enabled watchers require it.
+    for (i, watcher) in cfg.watchers.iter().enumerate() {
+        if watcher.agent.trim() != alias {
+            continue;
+        }
+        let path = format!("watchers[{i}].agent");
+        if watcher.enabled {
+            sites.push(RefSite::hard(path, ScrubAction::Refuse, &watcher.agent));
+        } else {
+            sites.push(RefSite::soft(
+                path,
+                ScrubAction::ClearOptional,
+                &watcher.agent,
+            ));
+        }
+    }
     // acp.default_agent — Option<String>, not load-validated (schema.rs:10889).
     if let Some(da) = cfg.acp.default_agent.as_deref()
         && da.trim() == alias
@@ -1271,6 +1302,7 @@ mod tests {
     use crate::multi_agent::{AccessMode, AgentAlias, PeerGroupConfig};
     use crate::schema::{
         AliasedAgentConfig, Config, DelegateTargetConfig, EmbeddingRouteConfig, ModelRouteConfig,
+        WatcherConfig,
     };
 
     /// Empty config with the alias-keyed containers cleared so Config::default
@@ -1285,6 +1317,7 @@ mod tests {
         c.heartbeat.enabled = false;
         c.heartbeat.agent.clear();
         c.acp.default_agent = None;
+        c.watchers.clear();
         c
     }
 
@@ -1453,6 +1486,25 @@ mod tests {
         assert_eq!(report.scrubs[0].action, ScrubAction::ClearOptional);
     }
 
+    #[test]
+    fn agent_refs_watchers_hard_only_when_enabled() {
+        let mut cfg = empty_config();
+        for (name, enabled) in [("inbox", true), ("archive", false)] {
+            cfg.watchers.push(WatcherConfig {
+                name: name.into(),
+                enabled,
+                agent: "bot".into(),
+                ..Default::default()
+            });
+        }
+        let report = plan_delete(&cfg, &AliasKind::Agent, "bot");
+        assert_eq!(report.blockers.len(), 1);
+        assert_eq!(report.blockers[0].path, "watchers[0].agent");
+        assert_eq!(report.scrubs.len(), 1);
+        assert_eq!(report.scrubs[0].path, "watchers[1].agent");
+        assert!(!report.allowed);
+    }
+
     #[test]
     fn no_references_is_allowed_and_empty() {
         let cfg = empty_config();
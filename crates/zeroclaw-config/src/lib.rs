@@ -15,8 +15,10 @@ pub mod domain_matcher;
 pub mod env_overrides;
 pub mod field_visibility;
 pub mod helpers;
+pub mod lint;
 pub mod migration;
 pub mod multi_agent;
+pub mod network_policy;
 pub mod pairing;
 pub mod paths;
 pub mod platform;
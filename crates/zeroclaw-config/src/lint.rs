@@ -0,0 +1,509 @@
+//! Config file lint for `zeroclaw config validate` and daemon startup.
+//!
+//! The loader is deliberately forgiving: serde ignores keys it does not know
+//! and the resilient path resets sections that fail to deserialize. Both
+//! turn a typo like `mention_onlyy = true` into a setting that silently does
+//! nothing. This module reports those cases with the dotted key path and,
+//! where it can be found, the line in the file.
+
+use crate::migration::{CURRENT_SCHEMA_VERSION, detect_version, migrate_value};
+use crate::schema::Config;
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigIssueKind {
+    /// A key no config struct declares; serde drops it.
+    UnknownKey,
+    /// A value that fails to parse, deserialize, or pass `Config::validate`.
+    InvalidValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigIssue {
+    pub kind: ConfigIssueKind,
+    /// Dotted key path (`channels.telegram.default.mention_onlyy`); empty
+    /// when the problem is not tied to one key.
+    pub path: String,
+    /// 1-based line in the file. Only reported for files already at the
+    /// current schema version, since migration moves keys around.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSeg {
+    Key(String),
+    Index(usize),
+}
+
+fn render_path(path: &[PathSeg]) -> String {
+    let mut out = String::new();
+    for seg in path {
+        match seg {
+            PathSeg::Key(key) => {
+                if !out.is_empty() {
+                    out.push('.');
+                }
+                out.push_str(key);
+            }
+            PathSeg::Index(i) => out.push_str(&format!("[{i}]")),
+        }
+    }
+    out
+}
+
+/// Lint a raw `config.toml`. An empty result means every key is recognised
+/// and the file deserializes strictly.
+pub fn lint_config_toml(raw: &str) -> Vec<ConfigIssue> {
+    let current = match toml::from_str::<toml::Value>(raw) {
+        Ok(value) => detect_version(&value).is_ok_and(|v| v == CURRENT_SCHEMA_VERSION),
+        Err(e) => {
+            return vec![ConfigIssue {
+                kind: ConfigIssueKind::InvalidValue,
+                path: String::new(),
+                line: None,
+                message: e.to_string(),
+            }];
+        }
+    };
+    let migrated = match migrate_value(raw) {
+        Ok(value) => value,
+        Err(e) => {
+            return vec![ConfigIssue {
+                kind: ConfigIssueKind::InvalidValue,
+                path: "schema_version".into(),
+                line: None,
+                message: format!("{e:#}"),
+            }];
+        }
+    };
+
+    let mut issues = Vec::new();
+    let strict = migrated.clone().try_into::<Config>();
+    let baseline = strict
+        .as_ref()
+        .ok()
+        .and_then(|config| serde_json::to_value(config).ok());
+    for path in unknown_key_paths(&migrated) {
+        // Keys the schema does not list can still be consumed through
+        // `#[serde(alias)]`; dropping one of those changes the result.
+        if let Some(baseline) = &baseline
+            && consumed(&migrated, &path, baseline)
+        {
+            continue;
+        }
+        issues.push(ConfigIssue {
+            kind: ConfigIssueKind::UnknownKey,
+            line: current.then(|| key_line(raw, &path)).flatten(),
+            path: render_path(&path),
+            message: "unknown key; ignored".into(),
+        });
+    }
+
+    match strict {
+        Ok(config) => {
+            if let Err(e) = config.validate() {
+                issues.push(ConfigIssue {
+                    kind: ConfigIssueKind::InvalidValue,
+                    path: String::new(),
+                    line: None,
+                    message: format!("{e:#}"),
+                });
+            }
+        }
+        Err(_) => issues.extend(section_errors(raw, &migrated, current)),
+    }
+    issues
+}
+
+/// One deserialize error per failing top-level section, so a broken
+/// `[heartbeat]` does not hide a broken `[gateway]`.
+fn section_errors(raw: &str, migrated: &toml::Value, current: bool) -> Vec<ConfigIssue> {
+    let Some(table) = migrated.as_table() else {
+        return Vec::new();
+    };
+    let mut issues = Vec::new();
+    for (key, value) in table {
+        if key == "schema_version" {
+            continue;
+        }
+        let mut section = toml::Table::new();
+        section.insert(
+            "schema_version".into(),
+            toml::Value::Integer(i64::from(CURRENT_SCHEMA_VERSION)),
+        );
+        section.insert(key.clone(), value.clone());
+        if let Err(e) = toml::Value::Table(section).try_into::<Config>() {
+            let path = [PathSeg::Key(key.clone())];
+            issues.push(ConfigIssue {
+                kind: ConfigIssueKind::InvalidValue,
+                path: key.clone(),
+                line: current.then(|| key_line(raw, &path)).flatten(),
+                message: e.to_string().trim().to_string(),
+            });
+        }
+    }
+    if issues.is_empty() {
+        // Sections only fail in combination (cross-section references);
+        // report the whole-file error instead.
+        if let Err(e) = migrated.clone().try_into::<Config>() {
+            issues.push(ConfigIssue {
+                kind: ConfigIssueKind::InvalidValue,
+                path: String::new(),
+                line: None,
+                message: e.to_string().trim().to_string(),
+            });
+        }
+    }
+    issues
+}
+
+/// Whether removing `path` changes what `Config` deserializes to.
+fn consumed(migrated: &toml::Value, path: &[PathSeg], baseline: &serde_json::Value) -> bool {
+    let mut pruned = migrated.clone();
+    if !remove_path(&mut pruned, path) {
+        return false;
+    }
+    pruned
+        .try_into::<Config>()
+        .ok()
+        .and_then(|config| serde_json::to_value(config).ok())
+        .is_some_and(|value| value != *baseline)
+}
+
+fn remove_path(value: &mut toml::Value, path: &[PathSeg]) -> bool {
+    let Some((last, parents)) = path.split_last() else {
+        return false;
+    };
+    let mut cursor = value;
+    for seg in parents {
+        let next = match (seg, cursor) {
+            (PathSeg::Key(key), toml::Value::Table(table)) => table.get_mut(key),
+            (PathSeg::Index(i), toml::Value::Array(items)) => items.get_mut(*i),
+            _ => None,
+        };
+        match next {
+            Some(next) => cursor = next,
+            None => return false,
+        }
+    }
+    match (last, cursor) {
+        (PathSeg::Key(key), toml::Value::Table(table)) => table.remove(key).is_some(),
+        _ => false,
+    }
+}
+
+/// Paths of keys the `Config` JSON Schema does not declare. Maps
+/// (`additionalProperties`) and free-form values accept anything.
+#[cfg(feature = "schema-export")]
+fn unknown_key_paths(value: &toml::Value) -> Vec<Vec<PathSeg>> {
+    let Ok(schema) = serde_json::to_value(schemars::schema_for!(Config)) else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    walk_schema(&schema, &[&schema], value, &mut Vec::new(), &mut out);
+    out
+}
+
+/// Without the schema, only top-level keys can be checked.
+#[cfg(not(feature = "schema-export"))]
+fn unknown_key_paths(value: &toml::Value) -> Vec<Vec<PathSeg>> {
+    let Ok(raw) = toml::to_string(value) else {
+        return Vec::new();
+    };
+    Config::unknown_keys(&raw)
+        .into_iter()
+        .map(|key| vec![PathSeg::Key(key)])
+        .collect()
+}
+
+#[cfg(feature = "schema-export")]
+fn resolve_ref<'a>(
+    root: &'a serde_json::Value,
+    schema: &'a serde_json::Value,
+) -> &'a serde_json::Value {
+    let mut current = schema;
+    for _ in 0..32 {
+        let Some(target) = current
+            .get("$ref")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|r| r.strip_prefix('#'))
+            .and_then(|pointer| root.pointer(pointer))
+        else {
+            break;
+        };
+        current = target;
+    }
+    current
+}
+
+/// Flatten `anyOf`/`oneOf`/`allOf` into the concrete schemas a value may
+/// match. Known keys are the union across all of them.
+#[cfg(feature = "schema-export")]
+fn schema_variants<'a>(
+    root: &'a serde_json::Value,
+    schema: &'a serde_json::Value,
+    depth: usize,
+    out: &mut Vec<&'a serde_json::Value>,
+) {
+    let schema = resolve_ref(root, schema);
+    if depth > 16 {
+        out.push(schema);
+        return;
+    }
+    let mut composed = false;
+    for key in ["anyOf", "oneOf", "allOf"] {
+        if let Some(list) = schema.get(key).and_then(serde_json::Value::as_array) {
+            composed = true;
+            for sub in list {
+                schema_variants(root, sub, depth + 1, out);
+            }
+        }
+    }
+    if !composed
+        || schema.get("properties").is_some()
+        || schema.get("additionalProperties").is_some()
+    {
+        out.push(schema);
+    }
+}
+
+#[cfg(feature = "schema-export")]
+fn is_object_schema(schema: &serde_json::Value) -> bool {
+    if schema.as_bool() == Some(true) {
+        return true;
+    }
+    match schema.get("type") {
+        None => true,
+        Some(serde_json::Value::String(t)) => t == "object",
+        Some(serde_json::Value::Array(types)) => types.iter().any(|t| t == "object"),
+        Some(_) => false,
+    }
+}
+
+#[cfg(feature = "schema-export")]
+fn is_open_schema(schema: &serde_json::Value) -> bool {
+    schema.as_bool() == Some(true)
+        || schema.get("patternProperties").is_some()
+        || (schema.get("properties").is_none() && schema.get("additionalProperties").is_none())
+}
+
+#[cfg(feature = "schema-export")]
+fn walk_schema(
+    root: &serde_json::Value,
+    candidates: &[&serde_json::Value],
+    value: &toml::Value,
+    path: &mut Vec<PathSeg>,
+    out: &mut Vec<Vec<PathSeg>>,
+) {
+    let mut variants = Vec::new();
+    for candidate in candidates {
+        schema_variants(root, candidate, 0, &mut variants);
+    }
+    match value {
+        toml::Value::Table(table) => {
+            let objects: Vec<_> = variants
+                .into_iter()
+                .filter(|v| is_object_schema(v))
+                .collect();
+            if objects.is_empty() || objects.iter().any(|v| is_open_schema(v)) {
+                return;
+            }
+            for (key, child) in table {
+                let mut known = false;
+                let mut open = false;
+                let mut children = Vec::new();
+                for schema in &objects {
+                    if let Some(sub) = schema.get("properties").and_then(|p| p.get(key)) {
+                        known = true;
+                        children.push(sub);
+                        continue;
+                    }
+                    match schema.get("additionalProperties") {
+                        Some(serde_json::Value::Bool(true)) => {
+                            known = true;
+                            open = true;
+                        }
+                        Some(sub @ serde_json::Value::Object(_)) => {
+                            known = true;
+                            children.push(sub);
+                        }
+                        _ => {}
+                    }
+                }
+                path.push(PathSeg::Key(key.clone()));
+                if !known {
+                    out.push(path.clone());
+                } else if !open {
+                    walk_schema(root, &children, child, path, out);
+                }
+                path.pop();
+            }
+        }
+        toml::Value::Array(items) => {
+            let item_schemas: Vec<_> = variants
+                .iter()
+                .filter_map(|v| v.get("items"))
+                .filter(|items| items.is_object() || items.is_boolean())
+                .collect();
+            if item_schemas.is_empty() {
+                return;
+            }
+            for (i, item) in items.iter().enumerate() {
+                path.push(PathSeg::Index(i));
+                walk_schema(root, &item_schemas, item, path, out);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Best-effort line lookup: tracks `[table]` / `[[array]]` headers and
+/// matches `key = value` lines against the path. Keys inside inline tables
+/// and multi-line values are not located.
+fn key_line(raw: &str, path: &[PathSeg]) -> Option<usize> {
+    let target: Vec<&str> = path
+        .iter()
+        .filter_map(|seg| match seg {
+            PathSeg::Key(key) => Some(key.as_str()),
+            PathSeg::Index(_) => None,
+        })
+        .collect();
+    let mut table: Vec<String> = Vec::new();
+    for (idx, line) in raw.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if let Some(header) = trimmed.strip_prefix('[') {
+            let header = header.trim_start_matches('[');
+            let Some(end) = header.find(']') else {
+                continue;
+            };
+            let header = &header[..end];
+            if header.contains(',') {
+                // An array element on its own line, not a header.
+                continue;
+            }
+            table = split_dotted(header);
+            if table == target {
+                return Some(idx + 1);
+            }
+            continue;
+        }
+        let Some((key, _)) = trimmed.split_once('=') else {
+            continue;
+        };
+        let mut full = table.clone();
+        full.extend(split_dotted(key));
+        if full == target {
+            return Some(idx + 1);
+        }
+    }
+    None
+}
+
+fn split_dotted(key: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+    for c in key.chars() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (None, '.') => parts.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    parts.push(current);
+    parts.into_iter().map(|p| p.trim().to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unknown(issues: &[ConfigIssue]) -> Vec<(&str, Option<usize>)> {
+        issues
+            .iter()
+            .filter(|i| i.kind == ConfigIssueKind::UnknownKey)
+            .map(|i| (i.path.as_str(), i.line))
+            .collect()
+    }
+
+    #[test]
+    fn clean_config_has_no_issues() {
+        let raw = toml::to_string(&Config::default()).unwrap();
+        assert_eq!(lint_config_toml(&raw), Vec::new());
+    }
+
+    #[cfg(feature = "schema-export")]
+    #[test]
+    fn nested_typo_is_reported_with_line() {
+        let raw = format!(
+            "schema_version = {CURRENT_SCHEMA_VERSION}\n\
+             \n\
+             [heartbeat]\n\
+             enabled = true\n\
+             interval_minutez = 5\n\
+             \n\
+             [gateway]\n\
+             port = 42617\n"
+        );
+        let issues = lint_config_toml(&raw);
+        assert_eq!(
+            unknown(&issues),
+            vec![("heartbeat.interval_minutez", Some(5))]
+        );
+    }
+
+    #[test]
+    fn top_level_typo_is_reported() {
+        let raw = format!("schema_version = {CURRENT_SCHEMA_VERSION}\nstrict_confg = true\n");
+        let issues = lint_config_toml(&raw);
+        assert_eq!(unknown(&issues), vec![("strict_confg", Some(2))]);
+    }
+
+    #[test]
+    fn type_error_names_the_section() {
+        let raw = format!(
+            "schema_version = {CURRENT_SCHEMA_VERSION}\n\n[heartbeat]\nenabled = \"yes\"\n"
+        );
+        let issues = lint_config_toml(&raw);
+        let invalid: Vec<_> = issues
+            .iter()
+            .filter(|i| i.kind == ConfigIssueKind::InvalidValue)
+            .collect();
+        assert_eq!(invalid.len(), 1, "{issues:?}");
+        assert_eq!(invalid[0].path, "heartbeat");
+        assert_eq!(invalid[0].line, Some(3));
+    }
+
+    #[test]
+    fn key_line_handles_headers_and_dotted_keys() {
+        let raw = "[a]\nx = 1\n[a.\"b.c\"]\ny = 2\n[[list]]\nz.w = 3\n";
+        let path = |keys: &[&str]| -> Vec<PathSeg> {
+            keys.iter().map(|k| PathSeg::Key((*k).into())).collect()
+        };
+        assert_eq!(key_line(raw, &path(&["a", "x"])), Some(2));
+        assert_eq!(key_line(raw, &path(&["a", "b.c", "y"])), Some(4));
+        assert_eq!(key_line(raw, &path(&["list", "z", "w"])), Some(6));
+        assert_eq!(key_line(raw, &path(&["a", "missing"])), None);
+    }
+}
@@ -279,7 +279,7 @@ pub fn migrate_to_current_salvaged(input: &str) -> ResilientLoad {
 
 /// Parse + migrate to the current schema version as a `toml::Value`, without
 /// the final typed deserialize. Shared by the strict and resilient entries.
-fn migrate_value(input: &str) -> Result<toml::Value> {
+pub(crate) fn migrate_value(input: &str) -> Result<toml::Value> {
     let value: toml::Value = toml::from_str(input).context("failed to parse config TOML")?;
     let from = detect_version(&value)?;
     if from == CURRENT_SCHEMA_VERSION {
@@ -0,0 +1,312 @@
+//! Outbound network policy resolved from `[risk_profiles.<alias>.network]`.
+//!
+//! `SecurityPolicy` carries one [`NetworkPolicy`]; network-capable tools take
+//! a [`NetworkGuard`] (or a client built by
+//! `SecurityPolicy::network_client_builder`) from it so every host they
+//! contact, redirect hops included, is checked against one set of rules.
+
+use std::net::IpAddr;
+
+use crate::domain_matcher::DomainMatcher;
+use crate::schema::{NetworkMode, NetworkPolicyConfig};
+
+/// Host names that resolve to an instance-metadata service on the major
+/// clouds. Refused like the metadata IPs themselves.
+const METADATA_HOSTS: &[&str] = &["metadata.google.internal", "metadata.azure.internal"];
+
+/// Redirect hops a policy-built client follows before giving up.
+const MAX_REDIRECTS: usize = 10;
+
+/// The rule that refused an outbound host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkRule {
+    /// Cloud metadata endpoint. Never reachable.
+    Metadata,
+    /// Private, loopback or link-local host not admitted by
+    /// `allowed_private_hosts` or the calling tool.
+    PrivateHosts,
+    /// Host is outside `allowed_domains` in `allowlist` mode.
+    AllowedDomains,
+    /// Host matches `blocked_domains`.
+    BlockedDomains,
+}
+
+impl NetworkRule {
+    /// Config key under `[risk_profiles.<alias>.network]` that governs this
+    /// rule, or `None` for the built-in metadata block.
+    pub fn config_key(self) -> Option<&'static str> {
+        match self {
+            Self::Metadata => None,
+            Self::PrivateHosts => Some("allowed_private_hosts"),
+            Self::AllowedDomains => Some("allowed_domains"),
+            Self::BlockedDomains => Some("blocked_domains"),
+        }
+    }
+
+    fn reason(self) -> &'static str {
+        match self {
+            Self::Metadata => "cloud metadata endpoint",
+            Self::PrivateHosts => "private or local address",
+            Self::AllowedDomains => "not in the network allowlist",
+            Self::BlockedDomains => "in the network blocklist",
+        }
+    }
+}
+
+/// Structured refusal returned to the tool, naming the policy entry and the
+/// offending host.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Host '{host}' is blocked by security policy [{policy}] ({})", rule.reason())]
+pub struct NetworkPolicyViolation {
+    /// Dotted config path of the rule, e.g.
+    /// `risk_profiles.default.network.blocked_domains`.
+    pub policy: String,
+    pub rule: NetworkRule,
+    pub host: String,
+}
+
+/// Resolved form of [`NetworkPolicyConfig`]. Patterns are validated at config
+/// load, so any that still fail to parse here are dropped rather than
+/// failing policy construction.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkPolicy {
+    pub mode: NetworkMode,
+    allowed: DomainMatcher,
+    blocked: DomainMatcher,
+    allowed_private: DomainMatcher,
+    allowed_private_ips: Vec<IpAddr>,
+}
+
+impl NetworkPolicy {
+    pub fn from_config(config: &NetworkPolicyConfig) -> Self {
+        let (ips, names): (Vec<&String>, Vec<&String>) = config
+            .allowed_private_hosts
+            .iter()
+            .partition(|entry| bare_host(entry).parse::<IpAddr>().is_ok());
+        Self {
+            mode: config.mode,
+            allowed: matcher(config.allowed_domains.iter()),
+            blocked: matcher(config.blocked_domains.iter()),
+            allowed_private: matcher(names.into_iter()),
+            allowed_private_ips: ips
+                .into_iter()
+                .filter_map(|ip| bare_host(ip).parse().ok())
+                .collect(),
+        }
+    }
+
+    pub fn allowed_domains(&self) -> &[String] {
+        self.allowed.patterns()
+    }
+
+    pub fn blocked_domains(&self) -> &[String] {
+        self.blocked.patterns()
+    }
+
+    /// Check `host` against the policy. `private_allowed` is the calling
+    /// tool's own opt-in for private hosts (e.g.
+    /// `http_request.allow_private_hosts`); metadata endpoints are refused
+    /// regardless.
+    pub fn check_host(&self, host: &str, private_allowed: bool) -> Result<(), NetworkRule> {
+        let host = bare_host(host);
+        let ip = host.parse::<IpAddr>().ok();
+
+        if ip.is_some_and(zeroclaw_infra::net_guard::is_cloud_metadata_ip)
+            || METADATA_HOSTS.contains(&host.as_str())
+        {
+            return Err(NetworkRule::Metadata);
+        }
+        if zeroclaw_infra::net_guard::is_private_or_local_host(&host)
+            && !private_allowed
+            && !self.private_host_allowed(&host, ip)
+        {
+            return Err(NetworkRule::PrivateHosts);
+        }
+        match self.mode {
+            NetworkMode::Open => Ok(()),
+            NetworkMode::Blocklist | NetworkMode::Allowlist if self.blocked.is_gated(&host) => {
+                Err(NetworkRule::BlockedDomains)
+            }
+            NetworkMode::Allowlist if !self.allowed.is_gated(&host) => {
+                Err(NetworkRule::AllowedDomains)
+            }
+            NetworkMode::Blocklist | NetworkMode::Allowlist => Ok(()),
+        }
+    }
+
+    fn private_host_allowed(&self, host: &str, ip: Option<IpAddr>) -> bool {
+        match ip {
+            Some(ip) => self.allowed_private_ips.contains(&ip),
+            None => self.allowed_private.is_gated(host),
+        }
+    }
+}
+
+/// A [`NetworkPolicy`] bound to the risk profile it came from, so refusals
+/// can name the config entry. Cheap to clone into redirect policies and
+/// plugin hooks.
+#[derive(Debug, Clone)]
+pub struct NetworkGuard {
+    policy: NetworkPolicy,
+    profile: String,
+}
+
+impl NetworkGuard {
+    pub fn new(policy: NetworkPolicy, profile: &str) -> Self {
+        let profile = if profile.is_empty() {
+            "<profile>"
+        } else {
+            profile
+        };
+        Self {
+            policy,
+            profile: profile.to_string(),
+        }
+    }
+
+    pub fn check_host(
+        &self,
+        host: &str,
+        private_allowed: bool,
+    ) -> Result<(), NetworkPolicyViolation> {
+        self.policy
+            .check_host(host, private_allowed)
+            .map_err(|rule| NetworkPolicyViolation {
+                policy: match rule.config_key() {
+                    Some(key) => format!("risk_profiles.{}.network.{key}", self.profile),
+                    None => format!("risk_profiles.{}.network", self.profile),
+                },
+                rule,
+                host: bare_host(host),
+            })
+    }
+
+    /// Check the host of `url`. A URL without a host (e.g. `data:`) has
+    /// nothing to reach and passes.
+    pub fn check_url(
+        &self,
+        url: &reqwest::Url,
+        private_allowed: bool,
+    ) -> Result<(), NetworkPolicyViolation> {
+        match url.host_str() {
+            Some(host) => self.check_host(host, private_allowed),
+            None => Ok(()),
+        }
+    }
+
+    /// Redirect policy that re-checks every hop, so an allowed host cannot
+    /// bounce the client onto a refused one.
+    pub fn redirect_policy(&self) -> reqwest::redirect::Policy {
+        let guard = self.clone();
+        reqwest::redirect::Policy::custom(move |attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                return attempt.error("too many redirects");
+            }
+            match guard.check_url(attempt.url(), false) {
+                Ok(()) => attempt.follow(),
+                Err(violation) => attempt.error(violation),
+            }
+        })
+    }
+}
+
+fn bare_host(host: &str) -> String {
+    let host = host.trim();
+    host.strip_prefix('[')
+        .and_then(|h| h.strip_suffix(']'))
+        .unwrap_or(host)
+        .trim_end_matches('.')
+        .to_ascii_lowercase()
+}
+
+fn matcher<'a>(patterns: impl Iterator<Item = &'a String>) -> DomainMatcher {
+    let valid: Vec<String> = patterns
+        .filter(|p| DomainMatcher::validate_pattern(p).is_ok())
+        .cloned()
+        .collect();
+    DomainMatcher::new(&valid, &[]).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard(config: NetworkPolicyConfig) -> NetworkGuard {
+        NetworkGuard::new(NetworkPolicy::from_config(&config), "default")
+    }
+
+    #[test]
+    fn metadata_endpoint_is_refused_even_when_private_hosts_are_allowed() {
+        let guard = guard(NetworkPolicyConfig {
+            allowed_private_hosts: vec!["169.254.169.254".into()],
+            ..Default::default()
+        });
+        let err = guard.check_host("169.254.169.254", true).unwrap_err();
+        assert_eq!(err.rule, NetworkRule::Metadata);
+        assert_eq!(err.host, "169.254.169.254");
+        assert!(err.to_string().contains("[risk_profiles.default.network]"));
+        assert!(guard.check_host("metadata.google.internal", true).is_err());
+    }
+
+    #[test]
+    fn private_hosts_need_an_explicit_allowance() {
+        let guard = guard(NetworkPolicyConfig {
+            allowed_private_hosts: vec!["nas.local".into(), "10.0.0.5".into()],
+            ..Default::default()
+        });
+        let err = guard.check_host("192.168.1.1", false).unwrap_err();
+        assert_eq!(err.rule, NetworkRule::PrivateHosts);
+        assert_eq!(
+            err.policy,
+            "risk_profiles.default.network.allowed_private_hosts"
+        );
+        assert!(guard.check_host("192.168.1.1", true).is_ok());
+        assert!(guard.check_host("nas.local", false).is_ok());
+        assert!(guard.check_host("10.0.0.5", false).is_ok());
+        assert!(guard.check_host("[::1]", false).is_err());
+    }
+
+    #[test]
+    fn blocklist_refuses_matching_domains() {
+        let guard = guard(NetworkPolicyConfig {
+            mode: NetworkMode::Blocklist,
+            blocked_domains: vec!["*.evil.example".into()],
+            ..Default::default()
+        });
+        let err = guard.check_host("api.evil.example", false).unwrap_err();
+        assert_eq!(err.rule, NetworkRule::BlockedDomains);
+        assert_eq!(err.host, "api.evil.example");
+        assert_eq!(err.policy, "risk_profiles.default.network.blocked_domains");
+        assert!(guard.check_host("docs.rs", false).is_ok());
+    }
+
+    #[test]
+    fn allowlist_admits_only_listed_domains_minus_blocked() {
+        let guard = guard(NetworkPolicyConfig {
+            mode: NetworkMode::Allowlist,
+            allowed_domains: vec!["*.github.com".into(), "docs.rs".into()],
+            blocked_domains: vec!["gist.github.com".into()],
+            ..Default::default()
+        });
+        assert!(guard.check_host("api.github.com", false).is_ok());
+        assert!(guard.check_host("DOCS.RS.", false).is_ok());
+        assert_eq!(
+            guard.check_host("example.com", false).unwrap_err().rule,
+            NetworkRule::AllowedDomains
+        );
+        assert_eq!(
+            guard.check_host("gist.github.com", false).unwrap_err().rule,
+            NetworkRule::BlockedDomains
+        );
+    }
+
+    #[test]
+    fn open_mode_ignores_domain_lists() {
+        let guard = guard(NetworkPolicyConfig {
+            blocked_domains: vec!["example.com".into()],
+            ..Default::default()
+        });
+        assert!(guard.check_host("example.com", false).is_ok());
+        assert!(guard.check_host("127.0.0.1", false).is_err());
+    }
+}
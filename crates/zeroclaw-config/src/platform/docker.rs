@@ -1,19 +1,415 @@
 use crate::schema::DockerRuntimeConfig;
 use anyhow::{Context, Result};
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 use zeroclaw_api::runtime_traits::RuntimeAdapter;
 
+/// Host-side driver for one shell invocation: start the session container if
+/// it is not running yet, then `docker exec` the command inside it.
+///
+/// Positional args: `$1` container name, `$2` command, the rest is the
+/// `docker run` argument list (flags, image, keep-alive command). A losing
+/// racer's `docker run` fails on the name conflict and falls through to the
+/// second `docker start`, which succeeds against the winner's container.
+const SESSION_SCRIPT: &str = r#"name=$1; cmd=$2; shift 2
+docker start "$name" >/dev/null 2>&1 || docker run --detach --rm --name "$name" "$@" >/dev/null || docker start "$name" >/dev/null || exit 125
+exec docker exec --interactive __WORKDIR__"$name" sh -c "$cmd""#;
+
+/// Label on every session container; the value is the owning process id so
+/// a later start can reap containers a crashed process left behind.
+const OWNER_LABEL: &str = "zeroclaw.session-owner";
+
+/// Label carrying the session key a container serves.
+const SESSION_LABEL: &str = "zeroclaw.session";
+
+/// Session key for shell calls made outside a gateway or channel session
+/// (the interactive CLI, cron).
+const DEFAULT_SESSION: &str = "default";
+
+type SessionKey = (String, PathBuf);
+
+/// A session container started by this process.
+struct SessionContainer {
+    name: String,
+    /// When a shell command was last sent to it; drives idle and LRU removal.
+    last_used: Instant,
+}
+
+/// Session containers started by this process, keyed by session key and host
+/// workspace. Process-wide so every runtime handle serving a session reuses
+/// the same container.
+static SESSION_CONTAINERS: LazyLock<Mutex<BTreeMap<SessionKey, SessionContainer>>> =
+    LazyLock::new(|| Mutex::new(BTreeMap::new()));
+
+/// Suffix for container names, so a session that comes back after its
+/// container was evicted never races the pending `docker rm` of the old one.
+static CONTAINER_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Images [`DockerRuntime::ensure_image`] has already verified in this process.
+static READY_IMAGES: LazyLock<Mutex<HashSet<String>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Set once the startup sweep for orphaned session containers has run.
+static STALE_SWEEP_DONE: AtomicBool = AtomicBool::new(false);
+
 /// Docker runtime with lightweight container isolation.
+///
+/// Shell commands run in a long-lived container per session (one per session
+/// key and workspace) so state such as installed packages or background files
+/// survives between tool calls. A container idle for longer than
+/// `session_idle_timeout_secs`, or the least recently used one once
+/// `max_session_containers` are running, is removed when another session
+/// runs a command. The rest are removed by [`remove_session_containers`] on
+/// shutdown; containers left behind by a process that died are reaped by
+/// [`remove_stale_session_containers`] at the next start.
 #[derive(Debug, Clone)]
 pub struct DockerRuntime {
     config: DockerRuntimeConfig,
 }
 
+/// Container name for a session and workspace, registering it for removal on
+/// shutdown.
+///
+/// Marks the container as just used and unregisters other sessions'
+/// containers that went idle for `idle_timeout` or fall outside the
+/// `max_live` most recently used; their names are returned for removal.
+fn claim_session_container(
+    session: &str,
+    workspace: &Path,
+    idle_timeout: Option<Duration>,
+    max_live: usize,
+) -> (String, Vec<String>) {
+    let now = Instant::now();
+    let key = (session.to_string(), workspace.to_path_buf());
+    let mut containers = SESSION_CONTAINERS.lock();
+    let container = containers
+        .entry(key.clone())
+        .or_insert_with(|| SessionContainer {
+            name: new_container_name(session, workspace),
+            last_used: now,
+        });
+    container.last_used = now;
+    let name = container.name.clone();
+    let evicted = evict_session_containers(&mut containers, &key, now, idle_timeout, max_live);
+    (name, evicted)
+}
+
+fn new_container_name(session: &str, workspace: &Path) -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    session.hash(&mut hasher);
+    workspace.hash(&mut hasher);
+    format!(
+        "zeroclaw-{}-{:08x}-{}",
+        std::process::id(),
+        hasher.finish() as u32,
+        CONTAINER_SEQ.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Drop idle entries, then the least recently used ones beyond `max_live`
+/// (`0` = no cap). `keep` is the entry being claimed and always survives.
+fn evict_session_containers(
+    containers: &mut BTreeMap<SessionKey, SessionContainer>,
+    keep: &SessionKey,
+    now: Instant,
+    idle_timeout: Option<Duration>,
+    max_live: usize,
+) -> Vec<String> {
+    let mut doomed: Vec<SessionKey> = Vec::new();
+    if let Some(idle_timeout) = idle_timeout {
+        doomed.extend(
+            containers
+                .iter()
+                .filter(|(key, c)| *key != keep && now.duration_since(c.last_used) >= idle_timeout)
+                .map(|(key, _)| key.clone()),
+        );
+    }
+    let live = containers.len() - doomed.len();
+    if max_live > 0 && live > max_live {
+        let mut by_age: Vec<(Instant, &SessionKey)> = containers
+            .iter()
+            .filter(|(key, _)| *key != keep && !doomed.contains(*key))
+            .map(|(key, c)| (c.last_used, key))
+            .collect();
+        by_age.sort();
+        let lru: Vec<SessionKey> = by_age
+            .into_iter()
+            .take(live - max_live)
+            .map(|(_, key)| key.clone())
+            .collect();
+        doomed.extend(lru);
+    }
+    doomed
+        .iter()
+        .filter_map(|key| containers.remove(key))
+        .map(|c| c.name)
+        .collect()
+}
+
+/// `docker rm --force` evicted containers on a background thread so the
+/// shell call that triggered the eviction is not held up.
+fn remove_evicted_containers(names: Vec<String>) {
+    if names.is_empty() {
+        return;
+    }
+    ::zeroclaw_log::record!(
+        INFO,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Delete)
+            .with_attrs(::serde_json::json!({"containers": names})),
+        "removing idle docker session containers"
+    );
+    std::thread::spawn(move || {
+        let _ = std::process::Command::new("docker")
+            .args(["rm", "--force"])
+            .args(&names)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status();
+    });
+}
+
+/// The session key scoped around the current tool call.
+fn current_session() -> String {
+    zeroclaw_api::TOOL_LOOP_SESSION_KEY
+        .try_with(Clone::clone)
+        .ok()
+        .flatten()
+        .filter(|key| !key.is_empty())
+        .unwrap_or_else(|| DEFAULT_SESSION.to_string())
+}
+
+/// Remove every session container this process started. Blocks until
+/// `docker rm` returns; call on shutdown once no more shell commands run.
+pub fn remove_session_containers() {
+    let names: Vec<String> = std::mem::take(&mut *SESSION_CONTAINERS.lock())
+        .into_values()
+        .map(|c| c.name)
+        .collect();
+    if names.is_empty() {
+        return;
+    }
+    let _ = std::process::Command::new("docker")
+        .args(["rm", "--force"])
+        .args(&names)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status();
+}
+
+/// Remove labelled session containers whose owning process is gone (killed
+/// before [`remove_session_containers`] could run). Runs once per process.
+pub async fn remove_stale_session_containers() -> Result<()> {
+    if STALE_SWEEP_DONE.swap(true, Ordering::SeqCst) {
+        return Ok(());
+    }
+    let filter = format!("label={OWNER_LABEL}");
+    let format = format!("{{{{.Names}}}}\t{{{{.Label \"{OWNER_LABEL}\"}}}}");
+    let listing = tokio::process::Command::new("docker")
+        .args(["ps", "--all", "--filter", &filter, "--format", &format])
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .output()
+        .await
+        .context("Failed to run `docker ps` for the session container sweep")?;
+    if !listing.status.success() {
+        anyhow::bail!(
+            "`docker ps` failed: {}",
+            String::from_utf8_lossy(&listing.stderr).trim()
+        );
+    }
+
+    let stale = stale_container_names(&String::from_utf8_lossy(&listing.stdout));
+    if stale.is_empty() {
+        return Ok(());
+    }
+    let removed = tokio::process::Command::new("docker")
+        .args(["rm", "--force"])
+        .args(&stale)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::piped())
+        .output()
+        .await
+        .context("Failed to run `docker rm` for stale session containers")?;
+    if !removed.status.success() {
+        anyhow::bail!(
+            "`docker rm` failed: {}",
+            String::from_utf8_lossy(&removed.stderr).trim()
+        );
+    }
+    ::zeroclaw_log::record!(
+        INFO,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Delete)
+            .with_attrs(::serde_json::json!({"containers": stale})),
+        "removed stale docker session containers"
+    );
+    Ok(())
+}
+
+/// Names from `docker ps` lines (`<name>\t<owner pid>`) whose owner is
+/// neither this process nor still alive.
+fn stale_container_names(listing: &str) -> Vec<String> {
+    let own_pid = std::process::id();
+    listing
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .filter(|(_, owner)| {
+            owner
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .is_none_or(|pid| pid != own_pid && !pid_is_alive(pid))
+        })
+        .map(|(name, _)| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Best-effort liveness check for `pid`. On Linux we consult `/proc/<pid>`;
+/// elsewhere we conservatively assume the process is alive so a live
+/// process's containers are never reaped.
+fn pid_is_alive(pid: u32) -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        Path::new(&format!("/proc/{pid}")).exists()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = pid;
+        true
+    }
+}
+
 impl DockerRuntime {
     pub fn new(config: DockerRuntimeConfig) -> Self {
         Self { config }
     }
 
+    /// Docker `--network` value. `true`/`false` (as written by
+    /// `zeroclaw config set`) map to `bridge`/`none`.
+    /// This session's container name; evicts idle and surplus containers of
+    /// other sessions on the way.
+    fn session_container(&self, session: &str, workspace_dir: &Path) -> String {
+        let idle_timeout = (self.config.session_idle_timeout_secs > 0)
+            .then(|| Duration::from_secs(self.config.session_idle_timeout_secs));
+        let (name, evicted) = claim_session_container(
+            session,
+            workspace_dir,
+            idle_timeout,
+            self.config.max_session_containers,
+        );
+        remove_evicted_containers(evicted);
+        name
+    }
+
+    fn network_mode(&self) -> &str {
+        match self.config.network.trim() {
+            "true" => "bridge",
+            "false" => "none",
+            other => other,
+        }
+    }
+
+    /// Verify the configured image is available locally, pulling it when it
+    /// is not. Called at startup so a missing image or daemon fails loudly
+    /// instead of on the first shell tool call; an image verified once is
+    /// not inspected again for the life of the process.
+    pub async fn ensure_image(&self) -> Result<()> {
+        let image = self.config.image.trim();
+        if image.is_empty() {
+            anyhow::bail!("runtime.docker.image must not be empty");
+        }
+        if READY_IMAGES.lock().contains(image) {
+            return Ok(());
+        }
+
+        let inspect = tokio::process::Command::new("docker")
+            .args(["image", "inspect", "--format", "{{.Id}}", image])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::piped())
+            .output()
+            .await
+            .context(
+                "runtime.kind = \"docker\" but the `docker` CLI could not be run; install Docker or set runtime.kind = \"native\"",
+            )?;
+        if inspect.status.success() {
+            READY_IMAGES.lock().insert(image.to_string());
+            return Ok(());
+        }
+
+        let pull = tokio::process::Command::new("docker")
+            .args(["pull", "--quiet", image])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::piped())
+            .output()
+            .await
+            .with_context(|| format!("Failed to run `docker pull {image}`"))?;
+        if !pull.status.success() {
+            anyhow::bail!(
+                "Docker image {image} is not available locally and `docker pull` failed: {}. \
+                 Pull it manually or set runtime.docker.image",
+                String::from_utf8_lossy(&pull.stderr).trim()
+            );
+        }
+        READY_IMAGES.lock().insert(image.to_string());
+        Ok(())
+    }
+
+    /// `docker run` arguments for a session container: labels, limits,
+    /// mounts, image and a keep-alive command.
+    fn session_run_args(&self, session: &str, workspace_dir: &Path) -> Result<Vec<String>> {
+        let mut args: Vec<String> = vec![
+            "--init".into(),
+            "--label".into(),
+            format!("{OWNER_LABEL}={}", std::process::id()),
+            "--label".into(),
+            format!("{SESSION_LABEL}={session}"),
+        ];
+
+        let network = self.network_mode();
+        if !network.is_empty() {
+            args.extend(["--network".into(), network.to_string()]);
+        }
+
+        if let Some(memory_limit_mb) = self.config.memory_limit_mb.filter(|mb| *mb > 0) {
+            args.extend(["--memory".into(), format!("{memory_limit_mb}m")]);
+        }
+
+        if let Some(cpu_limit) = self.config.cpu_limit.filter(|cpus| *cpus > 0.0) {
+            args.extend(["--cpus".into(), cpu_limit.to_string()]);
+        }
+
+        if self.config.read_only_rootfs {
+            // Writable scratch space for tools that need it; everything
+            // outside /workspace and /tmp stays read-only.
+            args.extend(["--read-only".into(), "--tmpfs".into(), "/tmp".into()]);
+        }
+
+        if self.config.mount_workspace {
+            let host_workspace = self.workspace_mount_path(workspace_dir).with_context(|| {
+                format!(
+                    "Failed to validate workspace mount path {}",
+                    workspace_dir.display()
+                )
+            })?;
+            args.extend([
+                "--volume".into(),
+                format!("{}:/workspace:rw", host_workspace.display()),
+            ]);
+        }
+
+        args.extend([
+            self.config.image.trim().to_string(),
+            "sleep".into(),
+            "infinity".into(),
+        ]);
+        Ok(args)
+    }
+
     fn workspace_mount_path(&self, workspace_dir: &Path) -> Result<PathBuf> {
         let resolved = workspace_dir
             .canonicalize()
@@ -88,50 +484,22 @@ impl RuntimeAdapter for DockerRuntime {
         command: &str,
         workspace_dir: &Path,
     ) -> anyhow::Result<tokio::process::Command> {
-        let mut process = tokio::process::Command::new("docker");
-        process
-            .arg("run")
-            .arg("--rm")
-            .arg("--init")
-            .arg("--interactive");
-
-        let network = self.config.network.trim();
-        if !network.is_empty() {
-            process.arg("--network").arg(network);
-        }
-
-        if let Some(memory_limit_mb) = self.config.memory_limit_mb.filter(|mb| *mb > 0) {
-            process.arg("--memory").arg(format!("{memory_limit_mb}m"));
-        }
-
-        if let Some(cpu_limit) = self.config.cpu_limit.filter(|cpus| *cpus > 0.0) {
-            process.arg("--cpus").arg(cpu_limit.to_string());
-        }
-
-        if self.config.read_only_rootfs {
-            process.arg("--read-only");
-        }
-
-        if self.config.mount_workspace {
-            let host_workspace = self.workspace_mount_path(workspace_dir).with_context(|| {
-                format!(
-                    "Failed to validate workspace mount path {}",
-                    workspace_dir.display()
-                )
-            })?;
-
-            process
-                .arg("--volume")
-                .arg(format!("{}:/workspace:rw", host_workspace.display()))
-                .arg("--workdir")
-                .arg("/workspace");
-        }
+        let session = current_session();
+        let run_args = self.session_run_args(&session, workspace_dir)?;
+        let workdir = if self.config.mount_workspace {
+            "--workdir /workspace "
+        } else {
+            ""
+        };
 
+        let mut process = tokio::process::Command::new("sh");
         process
-            .arg(self.config.image.trim())
-            .arg("sh")
             .arg("-c")
-            .arg(command);
+            .arg(SESSION_SCRIPT.replace("__WORKDIR__", workdir))
+            .arg("zeroclaw-docker")
+            .arg(self.session_container(&session, workspace_dir))
+            .arg(command)
+            .args(run_args);
 
         Ok(process)
     }
@@ -141,6 +509,10 @@ impl RuntimeAdapter for DockerRuntime {
 mod tests {
     use super::*;
 
+    fn session_container_name(session: &str, workspace: &Path) -> String {
+        claim_session_container(session, workspace, None, 0).0
+    }
+
     #[test]
     fn docker_runtime_name() {
         let runtime = DockerRuntime::new(DockerRuntimeConfig::default());
@@ -257,6 +629,134 @@ mod tests {
         );
     }
 
+    #[test]
+    fn docker_reuses_one_container_per_session_and_workspace() {
+        let a = std::env::temp_dir();
+        let b = a.join("other-workspace");
+
+        let first = session_container_name("gw:alice", &a);
+        assert_eq!(session_container_name("gw:alice", &a), first);
+        assert_ne!(session_container_name("gw:alice", &b), first);
+        assert_ne!(session_container_name("gw:bob", &a), first);
+        assert!(first.starts_with(&format!("zeroclaw-{}-", std::process::id())));
+    }
+
+    #[test]
+    fn docker_evicts_idle_then_least_recently_used_containers() {
+        let start = Instant::now();
+        let key = |session: &str| (session.to_string(), PathBuf::from("/ws"));
+        let mut containers: BTreeMap<SessionKey, SessionContainer> =
+            [("idle", 0), ("old", 500), ("recent", 900), ("current", 0)]
+                .into_iter()
+                .map(|(session, secs)| {
+                    (
+                        key(session),
+                        SessionContainer {
+                            name: session.to_string(),
+                            last_used: start + Duration::from_secs(secs),
+                        },
+                    )
+                })
+                .collect();
+        let now = start + Duration::from_secs(1000);
+        let current = key("current");
+
+        let evicted = evict_session_containers(
+            &mut containers,
+            &current,
+            now,
+            Some(Duration::from_secs(600)),
+            2,
+        );
+        assert_eq!(evicted, ["idle", "old"]);
+        assert_eq!(
+            containers.keys().collect::<Vec<_>>(),
+            [&current, &key("recent")]
+        );
+
+        // No timeout and no cap keeps everything.
+        assert!(evict_session_containers(&mut containers, &current, now, None, 0).is_empty());
+        assert_eq!(containers.len(), 2);
+    }
+
+    #[test]
+    fn docker_session_follows_the_scoped_session_key() {
+        let runtime = DockerRuntime::new(DockerRuntimeConfig::default());
+        let workspace = std::env::temp_dir();
+        let container = |key: Option<&str>| {
+            zeroclaw_api::TOOL_LOOP_SESSION_KEY.sync_scope(key.map(str::to_string), || {
+                let cmd = runtime.build_shell_command("true", &workspace).unwrap();
+                let name = cmd.as_std().get_args().nth(3).unwrap();
+                name.to_string_lossy().into_owned()
+            })
+        };
+
+        assert_eq!(
+            container(Some("chan:alice")),
+            session_container_name("chan:alice", &workspace)
+        );
+        assert_ne!(container(Some("chan:bob")), container(Some("chan:alice")));
+        assert_eq!(
+            container(None),
+            session_container_name(DEFAULT_SESSION, &workspace)
+        );
+    }
+
+    #[test]
+    fn docker_stale_sweep_spares_live_owners() {
+        let own = std::process::id();
+        let listing = format!("mine\t{own}\nunowned\t\nlive-elsewhere\t1\n");
+        let stale = stale_container_names(&listing);
+        assert!(!stale.contains(&"mine".to_string()));
+        assert!(stale.contains(&"unowned".to_string()));
+        #[cfg(target_os = "linux")]
+        assert!(!stale.contains(&"live-elsewhere".to_string()));
+
+        #[cfg(target_os = "linux")]
+        assert_eq!(
+            stale_container_names(&format!("gone\t{}\n", u32::MAX)),
+            vec!["gone".to_string()]
+        );
+    }
+
+    #[test]
+    fn docker_shell_command_execs_into_session_container() {
+        let runtime = DockerRuntime::new(DockerRuntimeConfig::default());
+        let workspace = std::env::temp_dir();
+        let cmd = runtime
+            .build_shell_command("echo hello", &workspace)
+            .unwrap();
+        let args: Vec<String> = cmd
+            .as_std()
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+
+        assert_eq!(cmd.as_std().get_program(), "sh");
+        assert!(args[1].contains("docker exec --interactive --workdir /workspace"));
+        assert_eq!(args[3], session_container_name(DEFAULT_SESSION, &workspace));
+        assert_eq!(args[4], "echo hello");
+        assert!(args.contains(&"--read-only".to_string()));
+        assert!(args.contains(&format!("{OWNER_LABEL}={}", std::process::id())));
+        assert!(args.contains(&format!("{SESSION_LABEL}={DEFAULT_SESSION}")));
+        assert!(args.contains(&format!(
+            "{}:/workspace:rw",
+            workspace.canonicalize().unwrap().display()
+        )));
+        assert_eq!(args[args.len() - 3..], ["alpine:3.20", "sleep", "infinity"]);
+    }
+
+    #[test]
+    fn docker_network_bool_shorthand() {
+        for (value, expected) in [("true", "bridge"), ("false", "none"), ("host", "host")] {
+            let runtime = DockerRuntime::new(DockerRuntimeConfig {
+                network: value.into(),
+                ..DockerRuntimeConfig::default()
+            });
+            assert_eq!(runtime.network_mode(), expected);
+        }
+    }
+
     #[test]
     fn docker_no_memory_flag_when_not_configured() {
         let cfg = DockerRuntimeConfig {
@@ -1,7 +1,7 @@
 pub mod docker;
 pub mod native;
 
-pub use docker::DockerRuntime;
+pub use docker::{DockerRuntime, remove_session_containers};
 pub use native::NativeRuntime;
 pub use zeroclaw_api::runtime_traits::RuntimeAdapter;
 
@@ -22,6 +22,30 @@ pub fn create_runtime(config: &RuntimeConfig) -> anyhow::Result<Box<dyn RuntimeA
     }
 }
 
+/// Startup checks that need I/O and so cannot run inside [`create_runtime`]:
+/// for `kind = "docker"`, verify the image is present (pulling it if not)
+/// and reap session containers orphaned by a process that died.
+pub async fn prepare_runtime(config: &RuntimeConfig) -> anyhow::Result<()> {
+    match config.kind {
+        RuntimeKind::Docker => {
+            DockerRuntime::new(config.docker.clone())
+                .ensure_image()
+                .await?;
+            if let Err(e) = docker::remove_stale_session_containers().await {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Delete)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                    "stale docker session container sweep failed"
+                );
+            }
+            Ok(())
+        }
+        RuntimeKind::Native | RuntimeKind::Cloudflare => Ok(()),
+    }
+}
+
 #[cfg(unix)]
 fn validate_shell(shell: &str) -> anyhow::Result<()> {
     use std::os::unix::fs::PermissionsExt;
@@ -147,6 +171,25 @@ mod tests {
         assert_eq!(empty.kind, RuntimeKind::Native);
     }
 
+    #[test]
+    fn docker_network_accepts_bool_shorthand() {
+        let parse = |toml: &str| {
+            toml::from_str::<RuntimeConfig>(toml)
+                .unwrap()
+                .docker
+                .network
+        };
+        assert_eq!(parse("[docker]\nnetwork = true"), "bridge");
+        assert_eq!(parse("[docker]\nnetwork = false"), "none");
+        assert_eq!(parse("[docker]\nnetwork = \"host\""), "host");
+        assert_eq!(parse(""), "none");
+    }
+
+    #[tokio::test]
+    async fn prepare_native_runtime_is_a_no_op() {
+        prepare_runtime(&RuntimeConfig::default()).await.unwrap();
+    }
+
     #[test]
     #[cfg(not(target_os = "windows"))]
     fn factory_native_default_shell_is_sh() {
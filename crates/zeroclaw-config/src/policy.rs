@@ -8,13 +8,197 @@ use std::time::Instant;
 pub use crate::autonomy::AutonomyLevel;
 
 /// Risk score for shell command execution.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum CommandRiskLevel {
     Low,
     Medium,
     High,
 }
 
+impl CommandRiskLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+}
+
+/// Which classifier rule set a command's risk level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandRiskRule {
+    /// The executable is on the built-in high-risk list.
+    HighRiskCommand,
+    /// The segment contains a known destructive pattern (`rm -rf /`, ...).
+    DestructivePattern,
+    /// A state-changing subcommand of git or a package manager.
+    MediumRiskSubcommand,
+    /// The executable is on the built-in medium-risk list.
+    MediumRiskCommand,
+}
+
+impl CommandRiskRule {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::HighRiskCommand => "high-risk command list",
+            Self::DestructivePattern => "destructive pattern list",
+            Self::MediumRiskSubcommand => "state-changing subcommand list",
+            Self::MediumRiskCommand => "medium-risk command list",
+        }
+    }
+}
+
+/// What kind of operation a risk rule matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandRiskCategory {
+    Destructive,
+    PrivilegeEscalation,
+    Permissions,
+    AccountManagement,
+    SystemAdministration,
+    Network,
+    ScriptingHost,
+    PackageInstall,
+    VersionControl,
+    FileChange,
+}
+
+impl CommandRiskCategory {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Destructive => "destructive",
+            Self::PrivilegeEscalation => "privilege escalation",
+            Self::Permissions => "permission change",
+            Self::AccountManagement => "account management",
+            Self::SystemAdministration => "system administration",
+            Self::Network => "network",
+            Self::ScriptingHost => "scripting host",
+            Self::PackageInstall => "package install",
+            Self::VersionControl => "version control",
+            Self::FileChange => "file change",
+        }
+    }
+}
+
+/// Why [`SecurityPolicy::command_risk_verdict`] rated a command as it did.
+/// `rule`, `category`, `trigger` and `segment` are all `None` for a
+/// low-risk command.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct CommandRiskVerdict {
+    pub level: CommandRiskLevel,
+    pub rule: Option<CommandRiskRule>,
+    pub category: Option<CommandRiskCategory>,
+    /// The token or pattern that matched, e.g. `curl`, `git push`, `rm -rf /`.
+    pub trigger: Option<String>,
+    /// The shell segment (split on `&&`, `|`, `;`, ...) the trigger is in.
+    /// Not serialized, so log records carrying a verdict never repeat
+    /// unscrubbed command text.
+    #[serde(skip)]
+    pub segment: Option<String>,
+}
+
+impl CommandRiskVerdict {
+    fn low() -> Self {
+        Self {
+            level: CommandRiskLevel::Low,
+            rule: None,
+            category: None,
+            trigger: None,
+            segment: None,
+        }
+    }
+
+    fn matched(
+        level: CommandRiskLevel,
+        rule: CommandRiskRule,
+        category: CommandRiskCategory,
+        trigger: impl Into<String>,
+        segment: &str,
+    ) -> Self {
+        Self {
+            level,
+            rule: Some(rule),
+            category: Some(category),
+            trigger: Some(trigger.into()),
+            segment: Some(segment.trim().to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for CommandRiskVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.rule, self.category, self.trigger.as_deref()) {
+            (Some(rule), Some(category), Some(trigger)) => write!(
+                f,
+                "{} risk ({}): `{trigger}` is on the {}",
+                self.level.as_str(),
+                category.label(),
+                rule.label()
+            ),
+            _ => write!(f, "{} risk: no risk rule matched", self.level.as_str()),
+        }
+    }
+}
+
+/// Final outcome of [`SecurityPolicy::explain_command`] for an unapproved call.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case", tag = "decision", content = "reason")]
+pub enum CommandDecision {
+    Allowed,
+    RequiresApproval(String),
+    Blocked(String),
+}
+
+/// Everything the shell policy decides about one command: the allowlist
+/// gate, the risk verdict, and what happens when the agent runs it without
+/// prior approval.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct CommandExplanation {
+    pub command: String,
+    /// Why the allowlist refuses the command; `None` when it passes.
+    pub allowlist_violation: Option<String>,
+    pub risk: CommandRiskVerdict,
+    pub decision: CommandDecision,
+}
+
+/// Category of a command on the built-in high-risk list.
+fn high_risk_command_category(base: &str) -> Option<CommandRiskCategory> {
+    use CommandRiskCategory as C;
+    let category = match base {
+        "rm" | "mkfs" | "dd" => C::Destructive,
+        "shutdown" | "reboot" | "halt" | "poweroff" | "mount" | "umount" | "iptables" | "ufw"
+        | "firewall-cmd" => C::SystemAdministration,
+        "sudo" | "su" => C::PrivilegeEscalation,
+        "chown" | "chmod" => C::Permissions,
+        "useradd" | "userdel" | "usermod" | "passwd" => C::AccountManagement,
+        "curl" | "wget" | "nc" | "ncat" | "netcat" | "scp" | "ssh" | "ftp" | "telnet" => C::Network,
+        // Windows-specific high-risk commands
+        "del" | "rmdir" | "format" => C::Destructive,
+        "reg" | "sc" | "netsh" => C::SystemAdministration,
+        "net" => C::AccountManagement,
+        "runas" => C::PrivilegeEscalation,
+        "icacls" | "takeown" => C::Permissions,
+        "powershell" | "pwsh" | "wmic" => C::ScriptingHost,
+        _ => return None,
+    };
+    Some(category)
+}
+
+/// Destructive patterns that make any segment high risk.
+const DESTRUCTIVE_PATTERNS: &[&str] = &[
+    "rm -rf /",
+    "rm -fr /",
+    ":(){:|:&};:",
+    // Windows destructive patterns
+    "del /s /q",
+    "rmdir /s /q",
+    "format c:",
+];
+
 /// Classifies whether a tool operation is read-only or side-effecting.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ToolOperation {
@@ -22,6 +206,42 @@ pub enum ToolOperation {
     Act,
 }
 
+/// Outcome of [`SecurityPolicy::check_tool`] for one tool call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolPolicyDecision {
+    /// No tool policy applies; the usual approval rules decide.
+    Allow,
+    /// The call must go through the approval flow before it runs.
+    RequireApproval,
+    /// The call is refused; the message explains which policy denied it.
+    Deny(String),
+}
+
+/// Risk level of one `browser` tool call. Actions that act on the page
+/// (clicking, filling or typing, key presses, OS-level input through the
+/// computer-use sidecar) are medium: they can submit forms and change state
+/// on the remote site. Navigation, reads and screenshots are low; where the
+/// browser may go is bounded by `browser.allowed_domains`.
+pub fn browser_action_risk(args: &serde_json::Value) -> CommandRiskLevel {
+    let action = args.get("action").and_then(|a| a.as_str());
+    let acts_on_page = match action {
+        Some(
+            "click" | "fill" | "type" | "press" | "mouse_click" | "mouse_drag" | "key_type"
+            | "key_press",
+        ) => true,
+        Some("find") => matches!(
+            args.get("find_action").and_then(|a| a.as_str()),
+            Some("click" | "fill" | "check")
+        ),
+        _ => false,
+    };
+    if acts_on_page {
+        CommandRiskLevel::Medium
+    } else {
+        CommandRiskLevel::Low
+    }
+}
+
 /// Sliding-window action tracker for rate limiting.
 #[derive(Debug)]
 pub struct ActionTracker {
@@ -207,6 +427,12 @@ pub struct SecurityPolicy {
     /// Extra arguments forwarded to firejail when `sandbox_backend`
     /// resolves to `"firejail"`.
     pub firejail_args: Vec<String>,
+    /// Per-tool permission entries. Mirrors `RiskProfileConfig.tools`;
+    /// evaluated by [`SecurityPolicy::check_tool`].
+    pub tool_policies: HashMap<String, crate::schema::ToolPolicyConfig>,
+    /// Outbound network policy. Mirrors `RiskProfileConfig.network`;
+    /// enforced through [`SecurityPolicy::network_guard`].
+    pub network: crate::network_policy::NetworkPolicy,
     pub tracker: PerSenderTracker,
 }
 
@@ -227,6 +453,135 @@ impl SecurityPolicy {
             .as_ref()
             .is_some_and(|list| list.iter().any(|t| t == name))
     }
+
+    /// Evaluate the per-tool permission entry for `name` against the call's
+    /// arguments. Called at tool dispatch, before `Tool::execute`. Calls the
+    /// entry's `except_paths` / `except_commands` exempt fall through to
+    /// [`ToolPolicyDecision::Allow`]. Without an entry, medium-risk `browser`
+    /// calls need approval in supervised mode, the same rule that applies to
+    /// medium-risk shell commands.
+    pub fn check_tool(&self, name: &str, args: &serde_json::Value) -> ToolPolicyDecision {
+        use crate::schema::ToolPermission;
+
+        let Some(entry) = self.tool_policies.get(name) else {
+            if name == "browser"
+                && browser_action_risk(args) == CommandRiskLevel::Medium
+                && self.autonomy == AutonomyLevel::Supervised
+                && self.require_approval_for_medium_risk
+            {
+                return ToolPolicyDecision::RequireApproval;
+            }
+            return ToolPolicyDecision::Allow;
+        };
+        if entry.permission == ToolPermission::Allow || self.is_tool_call_exempt(entry, args) {
+            return ToolPolicyDecision::Allow;
+        }
+        match entry.permission {
+            ToolPermission::Allow => ToolPolicyDecision::Allow,
+            ToolPermission::RequireApproval => ToolPolicyDecision::RequireApproval,
+            ToolPermission::Deny => {
+                let profile = if self.risk_profile_name.is_empty() {
+                    "<profile>"
+                } else {
+                    self.risk_profile_name.as_str()
+                };
+                ToolPolicyDecision::Deny(format!(
+                    "Tool '{name}' is denied by security policy [risk_profiles.{profile}.tools.{name}]"
+                ))
+            }
+        }
+    }
+
+    /// The outbound network policy bound to this profile's name, for tools
+    /// and plugin hooks that check hosts themselves.
+    pub fn network_guard(&self) -> crate::network_policy::NetworkGuard {
+        crate::network_policy::NetworkGuard::new(self.network.clone(), &self.risk_profile_name)
+    }
+
+    /// Check one outbound host against the network policy. See
+    /// [`crate::network_policy::NetworkPolicy::check_host`] for
+    /// `private_allowed`.
+    pub fn check_network_host(
+        &self,
+        host: &str,
+        private_allowed: bool,
+    ) -> Result<(), crate::network_policy::NetworkPolicyViolation> {
+        self.network_guard().check_host(host, private_allowed)
+    }
+
+    /// Shared HTTP client factory for network-capable tools: applies the
+    /// runtime proxy for `service_key` and re-checks every redirect hop
+    /// against the network policy. Tools still check the initial URL with
+    /// [`Self::check_network_host`] before sending.
+    pub fn network_client_builder(&self, service_key: &str) -> reqwest::ClientBuilder {
+        let builder = reqwest::Client::builder().redirect(self.network_guard().redirect_policy());
+        crate::schema::apply_runtime_proxy_to_builder(builder, service_key)
+    }
+
+    fn is_tool_call_exempt(
+        &self,
+        entry: &crate::schema::ToolPolicyConfig,
+        args: &serde_json::Value,
+    ) -> bool {
+        let path_exempt = !entry.except_paths.is_empty()
+            && args
+                .get("path")
+                .and_then(serde_json::Value::as_str)
+                .is_some_and(|path| self.is_path_under_any(path, &entry.except_paths));
+        let command_exempt = !entry.except_commands.is_empty()
+            && args
+                .get("command")
+                .and_then(serde_json::Value::as_str)
+                .is_some_and(|command| command_matches_prefix(command, &entry.except_commands));
+        path_exempt || command_exempt
+    }
+
+    /// True when `path` (tool-supplied, workspace-relative or absolute)
+    /// resolves under one of `roots`. Traversal and unresolvable paths never
+    /// match, so an exemption can only narrow a policy, not be escaped.
+    fn is_path_under_any(&self, path: &str, roots: &[String]) -> bool {
+        if path.contains('\0')
+            || Path::new(path)
+                .components()
+                .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            return false;
+        }
+        let Ok(resolved) = canonicalize_existing_prefix(&self.resolve_tool_path(path)) else {
+            return false;
+        };
+        roots.iter().any(|root| {
+            let root = self.resolve_tool_path(root.trim());
+            let root = canonicalize_existing_prefix(&root).unwrap_or(root);
+            resolved.starts_with(&root)
+        })
+    }
+}
+
+fn read_only_violation_message(operation_name: &str) -> String {
+    format!("Security policy: read-only mode, cannot perform '{operation_name}'")
+}
+
+/// True when `command` starts with one of `prefixes` on a word boundary and
+/// chains nothing after it, so `git status; rm -rf ~` never matches
+/// `"git status"`.
+fn command_matches_prefix(command: &str, prefixes: &[String]) -> bool {
+    let command = command.trim();
+    if command
+        .chars()
+        .any(|c| matches!(c, ';' | '&' | '|' | '`' | '$' | '>' | '<' | '\n' | '\r'))
+    {
+        return false;
+    }
+    prefixes.iter().any(|prefix| {
+        let prefix = prefix.trim();
+        !prefix.is_empty()
+            && command.starts_with(prefix)
+            && command[prefix.len()..]
+                .chars()
+                .next()
+                .is_none_or(char::is_whitespace)
+    })
 }
 
 /// Default allowed commands for Unix platforms.
@@ -350,6 +705,38 @@ fn roots_contain(roots: &[PathBuf], expanded: &Path) -> bool {
     })
 }
 
+/// Canonicalize the longest existing prefix of `path` and re-append the
+/// missing components. An existing component that cannot be resolved because
+/// it is a dangling symlink is rejected: its target could be created later,
+/// outside every root.
+fn canonicalize_existing_prefix(path: &Path) -> Result<PathBuf, String> {
+    let mut missing = Vec::new();
+    let mut current = path;
+    loop {
+        match current.canonicalize() {
+            Ok(mut resolved) => {
+                resolved.extend(missing.iter().rev());
+                return Ok(resolved);
+            }
+            Err(e) => match current.symlink_metadata() {
+                Ok(meta) if meta.file_type().is_symlink() => {
+                    return Err(format!(
+                        "Refusing to follow dangling symlink: {}",
+                        current.display()
+                    ));
+                }
+                Ok(_) => return Err(format!("Failed to resolve file path: {e}")),
+                Err(_) => {}
+            },
+        }
+        let (Some(parent), Some(name)) = (current.parent(), current.file_name()) else {
+            return Err(format!("Failed to resolve file path: {}", path.display()));
+        };
+        missing.push(name);
+        current = parent;
+    }
+}
+
 fn path_contains(parent: &Path, child: &Path) -> bool {
     let canonical_parent = parent
         .canonicalize()
@@ -502,6 +889,8 @@ impl Default for SecurityPolicy {
             sandbox_enabled: None,
             sandbox_backend: None,
             firejail_args: vec![],
+            tool_policies: HashMap::new(),
+            network: crate::network_policy::NetworkPolicy::default(),
             tracker: PerSenderTracker::new(),
         }
     }
@@ -1194,7 +1583,14 @@ impl SecurityPolicy {
 
     /// Classify command risk. Any high-risk segment marks the whole command high.
     pub fn command_risk_level(&self, command: &str) -> CommandRiskLevel {
-        let mut saw_medium = false;
+        self.command_risk_verdict(command).level
+    }
+
+    /// Classify command risk and report the rule, category and token that
+    /// decided it. The first high-risk match wins; otherwise the first
+    /// medium-risk match.
+    pub fn command_risk_verdict(&self, command: &str) -> CommandRiskVerdict {
+        let mut medium: Option<CommandRiskVerdict> = None;
 
         for segment in split_unquoted_segments(command) {
             let cmd_part = skip_env_assignments(&segment);
@@ -1210,72 +1606,42 @@ impl SecurityPolicy {
             let joined_segment = cmd_part.to_ascii_lowercase();
 
             // High-risk commands (Unix and Windows)
-            if matches!(
-                base,
-                "rm" | "mkfs"
-                    | "dd"
-                    | "shutdown"
-                    | "reboot"
-                    | "halt"
-                    | "poweroff"
-                    | "sudo"
-                    | "su"
-                    | "chown"
-                    | "chmod"
-                    | "useradd"
-                    | "userdel"
-                    | "usermod"
-                    | "passwd"
-                    | "mount"
-                    | "umount"
-                    | "iptables"
-                    | "ufw"
-                    | "firewall-cmd"
-                    | "curl"
-                    | "wget"
-                    | "nc"
-                    | "ncat"
-                    | "netcat"
-                    | "scp"
-                    | "ssh"
-                    | "ftp"
-                    | "telnet"
-                    // Windows-specific high-risk commands
-                    | "del"
-                    | "rmdir"
-                    | "format"
-                    | "reg"
-                    | "net"
-                    | "runas"
-                    | "icacls"
-                    | "takeown"
-                    | "powershell"
-                    | "pwsh"
-                    | "wmic"
-                    | "sc"
-                    | "netsh"
-            ) {
-                return CommandRiskLevel::High;
+            if let Some(category) = high_risk_command_category(base) {
+                return CommandRiskVerdict::matched(
+                    CommandRiskLevel::High,
+                    CommandRiskRule::HighRiskCommand,
+                    category,
+                    base,
+                    cmd_part,
+                );
             }
 
-            if joined_segment.contains("rm -rf /")
-                || joined_segment.contains("rm -fr /")
-                || joined_segment.contains(":(){:|:&};:")
-                // Windows destructive patterns
-                || joined_segment.contains("del /s /q")
-                || joined_segment.contains("rmdir /s /q")
-                || joined_segment.contains("format c:")
+            if let Some(pattern) = DESTRUCTIVE_PATTERNS
+                .iter()
+                .find(|pattern| joined_segment.contains(*pattern))
             {
-                return CommandRiskLevel::High;
+                return CommandRiskVerdict::matched(
+                    CommandRiskLevel::High,
+                    CommandRiskRule::DestructivePattern,
+                    CommandRiskCategory::Destructive,
+                    *pattern,
+                    cmd_part,
+                );
+            }
+
+            if medium.is_some() {
+                continue;
             }
 
             // Medium-risk commands (state-changing, but not inherently destructive)
-            let medium = match base {
-                "git" => args.first().is_some_and(|verb| {
-                    matches!(
-                        verb.as_str(),
+            let verb = args.first().map(String::as_str).unwrap_or_default();
+            let subcommand_category = match base {
+                "git"
+                    if matches!(
+                        verb,
                         "commit"
                             | "push"
+                            | "pull"
                             | "reset"
                             | "clean"
                             | "rebase"
@@ -1286,34 +1652,48 @@ impl SecurityPolicy {
                             | "checkout"
                             | "switch"
                             | "tag"
-                    )
-                }),
-                "npm" | "pnpm" | "yarn" => args.first().is_some_and(|verb| {
-                    matches!(
-                        verb.as_str(),
+                    ) =>
+                {
+                    Some(CommandRiskCategory::VersionControl)
+                }
+                "npm" | "pnpm" | "yarn"
+                    if matches!(
+                        verb,
                         "install" | "add" | "remove" | "uninstall" | "update" | "publish"
-                    )
-                }),
-                "cargo" => args.first().is_some_and(|verb| {
-                    matches!(
-                        verb.as_str(),
-                        "add" | "remove" | "install" | "clean" | "publish"
-                    )
-                }),
+                    ) =>
+                {
+                    Some(CommandRiskCategory::PackageInstall)
+                }
+                "cargo" if matches!(verb, "add" | "remove" | "install" | "clean" | "publish") => {
+                    Some(CommandRiskCategory::PackageInstall)
+                }
+                _ => None,
+            };
+            if let Some(category) = subcommand_category {
+                medium = Some(CommandRiskVerdict::matched(
+                    CommandRiskLevel::Medium,
+                    CommandRiskRule::MediumRiskSubcommand,
+                    category,
+                    format!("{base} {verb}"),
+                    cmd_part,
+                ));
+            } else if matches!(
+                base,
                 "touch" | "mkdir" | "mv" | "cp" | "ln"
                 // Windows medium-risk equivalents
-                | "copy" | "xcopy" | "robocopy" | "move" | "ren" | "rename" | "mklink" => true,
-                _ => false,
-            };
-
-            saw_medium |= medium;
+                | "copy" | "xcopy" | "robocopy" | "move" | "ren" | "rename" | "mklink"
+            ) {
+                medium = Some(CommandRiskVerdict::matched(
+                    CommandRiskLevel::Medium,
+                    CommandRiskRule::MediumRiskCommand,
+                    CommandRiskCategory::FileChange,
+                    base,
+                    cmd_part,
+                ));
+            }
         }
 
-        if saw_medium {
-            CommandRiskLevel::Medium
-        } else {
-            CommandRiskLevel::Low
-        }
+        medium.unwrap_or_else(CommandRiskVerdict::low)
     }
 
     /// Validate full command execution policy (allowlist + risk gate).
@@ -1322,21 +1702,25 @@ impl SecurityPolicy {
         command: &str,
         approved: bool,
     ) -> Result<CommandRiskLevel, String> {
-        if !self.is_command_allowed(command) {
-            return Err(format!("Command not allowed by security policy: {command}"));
+        if let Some(violation) = self.command_allowlist_violation(command) {
+            return Err(format!(
+                "Command not allowed by security policy: {command} ({violation})"
+            ));
         }
 
-        let risk = self.command_risk_level(command);
+        let verdict = self.command_risk_verdict(command);
+        let risk = verdict.level;
 
         if risk == CommandRiskLevel::High {
             if self.block_high_risk_commands && !self.is_command_explicitly_allowed(command) {
-                return Err("Command blocked: high-risk command is disallowed by policy".into());
+                return Err(format!(
+                    "Command blocked: high-risk command is disallowed by policy ({verdict})"
+                ));
             }
             if self.autonomy == AutonomyLevel::Supervised && !approved {
-                return Err(
-                    "Command requires explicit approval (approved=true): high-risk operation"
-                        .into(),
-                );
+                return Err(format!(
+                    "Command requires explicit approval (approved=true): high-risk operation ({verdict})"
+                ));
             }
         }
 
@@ -1345,14 +1729,45 @@ impl SecurityPolicy {
             && self.require_approval_for_medium_risk
             && !approved
         {
-            return Err(
-                "Command requires explicit approval (approved=true): medium-risk operation".into(),
-            );
+            return Err(format!(
+                "Command requires explicit approval (approved=true): medium-risk operation ({verdict})"
+            ));
         }
 
         Ok(risk)
     }
 
+    /// Explain how the shell policy treats `command` when the agent runs it
+    /// without prior approval: the allowlist gate, the risk verdict, and the
+    /// resulting decision. Backs `zeroclaw security explain`.
+    pub fn explain_command(&self, command: &str) -> CommandExplanation {
+        let allowlist_violation = self.command_allowlist_violation(command);
+        let risk = self.command_risk_verdict(command);
+        let decision = match (
+            &allowlist_violation,
+            self.validate_command_execution(command, false),
+        ) {
+            (Some(violation), _) => CommandDecision::Blocked(format!("not allowed: {violation}")),
+            (None, Ok(_)) => CommandDecision::Allowed,
+            (None, Err(reason)) if reason.starts_with("Command blocked") => {
+                CommandDecision::Blocked(
+                    "block_high_risk_commands is on and the command is not in allowed_commands"
+                        .into(),
+                )
+            }
+            (None, Err(_)) => CommandDecision::RequiresApproval(format!(
+                "{} risk commands need approval in supervised mode",
+                risk.level.as_str()
+            )),
+        };
+        CommandExplanation {
+            command: command.to_string(),
+            allowlist_violation,
+            risk,
+            decision,
+        }
+    }
+
     fn is_command_explicitly_allowed(&self, command: &str) -> bool {
         let segments = split_unquoted_segments(command);
         for segment in &segments {
@@ -1398,8 +1813,13 @@ impl SecurityPolicy {
     // technique. If any gate rejects, the whole command is blocked.
 
     pub fn is_command_allowed(&self, command: &str) -> bool {
+        self.command_allowlist_violation(command).is_none()
+    }
+
+    /// Why the layered allowlist refuses `command`, or `None` when it passes.
+    pub fn command_allowlist_violation(&self, command: &str) -> Option<String> {
         if self.autonomy == AutonomyLevel::ReadOnly {
-            return false;
+            return Some("autonomy is read_only".into());
         }
 
         // When the operator has explicitly opted out of all command-level
@@ -1408,7 +1828,7 @@ impl SecurityPolicy {
         // $(), heredocs, etc. in trusted environments.
         let has_wildcard = self.allowed_commands.iter().any(|c| c.trim() == "*");
         if has_wildcard && !self.block_high_risk_commands {
-            return true;
+            return None;
         }
 
         if command.contains('`')
@@ -1416,7 +1836,7 @@ impl SecurityPolicy {
             || command.contains("<(")
             || command.contains(">(")
         {
-            return false;
+            return Some("command substitution, variable expansion or process substitution".into());
         }
 
         // Block shell redirections that target files. Allow safe forms:
@@ -1424,10 +1844,10 @@ impl SecurityPolicy {
         //   - `2>&1`, `1>&2` (fd merging)
         //   - `<<` heredocs, `<<<` here-strings (input literals)
         if contains_unsafe_output_redirect(command) {
-            return false;
+            return Some("output redirection to a file".into());
         }
         if contains_unquoted_input_redirect(command) {
-            return false;
+            return Some("input redirection from a file".into());
         }
 
         // Block `tee` — it can write to arbitrary files, bypassing the
@@ -1436,7 +1856,7 @@ impl SecurityPolicy {
             .split_whitespace()
             .any(|w| w == "tee" || w.ends_with("/tee"))
         {
-            return false;
+            return Some("`tee` can write arbitrary files".into());
         }
 
         // Block background command chaining (`&`), which can hide extra
@@ -1445,7 +1865,7 @@ impl SecurityPolicy {
         // flagged as background chaining.
         let ampersand_check = strip_fd_merge_redirects(command);
         if contains_unquoted_single_ampersand(&ampersand_check) {
-            return false;
+            return Some("background `&` chaining".into());
         }
 
         // Split on unquoted command separators and validate each sub-command.
@@ -1476,7 +1896,7 @@ impl SecurityPolicy {
                 .iter()
                 .any(|allowed| is_allowlist_entry_match(allowed, executable, base_cmd))
             {
-                return false;
+                return Some(format!("`{base_cmd}` is not in allowed_commands"));
             }
 
             // Validate arguments for the command.
@@ -1485,43 +1905,50 @@ impl SecurityPolicy {
             //   - `args` (lowercased) for case-insensitive matches (e.g. subcommand names)
             let args_cased: Vec<String> = words.map(|w| w.to_string()).collect();
             let args: Vec<String> = args_cased.iter().map(|w| w.to_ascii_lowercase()).collect();
-            if !self.is_args_safe(base_cmd, &args, &args_cased) {
-                return false;
+            if let Some(arg) = self.unsafe_argument(base_cmd, &args, &args_cased) {
+                return Some(format!("argument `{arg}` is not allowed for `{base_cmd}`"));
             }
         }
 
         // At least one command must be present
-        segments.iter().any(|s| {
+        let has_command = segments.iter().any(|s| {
             let s = skip_env_assignments(s.trim());
             s.split_whitespace().next().is_some_and(|w| !w.is_empty())
-        })
+        });
+        (!has_command).then(|| "no command to run".into())
     }
 
-    fn is_args_safe(&self, base: &str, args: &[String], args_cased: &[String]) -> bool {
+    /// First argument that makes `base` unsafe to run (code execution or
+    /// package fetches), if any.
+    fn unsafe_argument(
+        &self,
+        base: &str,
+        args: &[String],
+        args_cased: &[String],
+    ) -> Option<String> {
         let base = base.to_ascii_lowercase();
-        match base.as_str() {
+        let found = match base.as_str() {
             "find" => {
                 // find -exec and find -ok allow arbitrary command execution
-                !args.iter().any(|arg| arg == "-exec" || arg == "-ok")
+                args.iter().find(|arg| *arg == "-exec" || *arg == "-ok")
             }
-            "git" => {
-                !args_cased.iter().any(|arg| arg == "-c")
-                    && !args.iter().any(|arg| {
-                        arg == "config"
-                            || arg.starts_with("config.")
-                            || arg == "alias"
-                            || arg.starts_with("alias.")
-                    })
-            }
-            "python" | "python3" => !args
+            "git" => args_cased.iter().find(|arg| *arg == "-c").or_else(|| {
+                args.iter().find(|arg| {
+                    *arg == "config"
+                        || arg.starts_with("config.")
+                        || *arg == "alias"
+                        || arg.starts_with("alias.")
+                })
+            }),
+            "python" | "python3" => args
                 .iter()
-                .any(|arg| arg.starts_with("-c") || arg.starts_with("-m")),
+                .find(|arg| arg.starts_with("-c") || arg.starts_with("-m")),
             "node" => {
                 // -e/--eval evaluates argument as JavaScript
                 // -p/--print same as --eval but prints the result
                 // starts_with covers glued form: node -e'code' (one whitespace token)
                 // Ref: https://nodejs.org/api/cli.html
-                !args.iter().any(|arg| {
+                args.iter().find(|arg| {
                     arg.starts_with("-e")
                         || arg.starts_with("--eval")
                         || arg.starts_with("-p")
@@ -1531,23 +1958,29 @@ impl SecurityPolicy {
             "pip" | "pip3" => {
                 // install/download fetch external packages; setup.py runs arbitrary code
                 // Ref: https://blog.phylum.io/python-package-installation-attacks/
-                !args.iter().any(|arg| arg == "install" || arg == "download")
+                args.iter()
+                    .find(|arg| *arg == "install" || *arg == "download")
             }
             "npm" => {
                 // exec can fetch+run remote packages (npx behavior)
                 // install fetches external packages; lifecycle scripts run arbitrary code
                 // Ref: https://cheatsheetseries.owasp.org/cheatsheets/NPM_Security_Cheat_Sheet.html
-                !args.iter().any(|arg| {
-                    arg == "exec" || arg == "install" || arg == "i" || arg == "add" || arg == "ci"
+                args.iter().find(|arg| {
+                    *arg == "exec"
+                        || *arg == "install"
+                        || *arg == "i"
+                        || *arg == "add"
+                        || *arg == "ci"
                 })
             }
             "cargo" => {
                 // install fetches+builds external crate; build.rs executes arbitrary code
                 // Ref: https://shnatsel.medium.com/do-not-run-any-cargo-commands-on-untrusted-projects
-                !args.iter().any(|arg| arg == "install")
+                args.iter().find(|arg| *arg == "install")
             }
-            _ => true,
-        }
+            _ => None,
+        };
+        found.cloned()
     }
 
     /// Return the first path-like argument blocked by path policy.
@@ -1941,9 +2374,7 @@ impl SecurityPolicy {
             ToolOperation::Read => Ok(()),
             ToolOperation::Act => {
                 if !self.can_act() {
-                    return Err(format!(
-                        "Security policy: read-only mode, cannot perform '{operation_name}'"
-                    ));
+                    return Err(read_only_violation_message(operation_name));
                 }
 
                 if !self.record_action() {
@@ -1955,6 +2386,19 @@ impl SecurityPolicy {
         }
     }
 
+    /// Capability mask derived from the autonomy level, applied at tool
+    /// dispatch to every call its tool reports as mutating (`Tool::mutates`).
+    /// `ReadOnly` refuses such calls before they run, which covers plugin and
+    /// MCP tools that never consult the policy themselves. `Supervised`
+    /// leaves them to the approval manager's prompts; `Full` runs them.
+    pub fn check_mutation(&self, name: &str, mutates: bool) -> ToolPolicyDecision {
+        if mutates && self.autonomy == AutonomyLevel::ReadOnly {
+            ToolPolicyDecision::Deny(read_only_violation_message(name))
+        } else {
+            ToolPolicyDecision::Allow
+        }
+    }
+
     /// Record an action for the current sender and check if rate-limited.
     /// Returns `true` if allowed, `false` if budget exhausted.
     pub fn record_action(&self) -> bool {
@@ -1994,6 +2438,52 @@ impl SecurityPolicy {
         }
     }
 
+    /// Resolve a tool-supplied `path` and confine it to the policy's roots.
+    ///
+    /// The deepest existing ancestor is canonicalized, so a symlink anywhere
+    /// on the path is judged by its target, and the not-yet-existing tail is
+    /// re-appended (new files are judged by where their parent really lives).
+    /// `..` components are rejected rather than normalized. `Read` follows a
+    /// symlinked final component and checks the readable roots; `Act` leaves
+    /// the final component unfollowed and checks the writable roots — the
+    /// caller must still refuse to write through an existing symlink there.
+    pub fn resolve_confined_path(
+        &self,
+        path: &str,
+        operation: ToolOperation,
+    ) -> Result<PathBuf, String> {
+        if path.contains('\0') {
+            return Err("Path not allowed: contains null byte".into());
+        }
+        let traverses = Path::new(path)
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir));
+        let tilde_user = path.starts_with('~') && path != "~" && !path.starts_with("~/");
+        if traverses || tilde_user {
+            return Err(format!("Path not allowed by security policy: {path}"));
+        }
+
+        let full = self.resolve_tool_path(path);
+        let resolved = match operation {
+            ToolOperation::Read => canonicalize_existing_prefix(&full)?,
+            ToolOperation::Act => {
+                let (Some(parent), Some(name)) = (full.parent(), full.file_name()) else {
+                    return Err("Invalid path: missing parent directory".into());
+                };
+                canonicalize_existing_prefix(parent)?.join(name)
+            }
+        };
+
+        let allowed = match operation {
+            ToolOperation::Read => self.is_resolved_path_readable(&resolved),
+            ToolOperation::Act => self.is_resolved_path_allowed(&resolved),
+        };
+        if !allowed {
+            return Err(self.resolved_path_violation_message(&resolved));
+        }
+        Ok(resolved)
+    }
+
     pub fn is_under_allowed_root(&self, path: &str) -> bool {
         let expanded = expand_user_path(path);
         if !expanded.is_absolute() {
@@ -2194,6 +2684,8 @@ impl SecurityPolicy {
             sandbox_enabled: risk_profile.sandbox_enabled,
             sandbox_backend: risk_profile.sandbox_backend.clone(),
             firejail_args: risk_profile.firejail_args.clone(),
+            tool_policies: risk_profile.tools.clone(),
+            network: crate::network_policy::NetworkPolicy::from_config(&risk_profile.network),
             tracker: PerSenderTracker::new(),
         }
     }
@@ -2555,6 +3047,13 @@ mod tests {
             approval_route: None,
             allowed_tools: vec!["shell".into(), "memory_recall".into()],
             excluded_tools: vec!["spawn_subagent".into()],
+            tools: HashMap::from([(
+                "browser".to_string(),
+                crate::schema::ToolPolicyConfig {
+                    permission: crate::schema::ToolPermission::Deny,
+                    ..Default::default()
+                },
+            )]),
             sandbox_enabled: Some(true),
             sandbox_backend: Some("firejail".into()),
             firejail_args: vec!["--net=none".into()],
@@ -2607,6 +3106,194 @@ mod tests {
             vec!["--net=none".to_string()],
             "firejail_args"
         );
+        assert!(
+            policy.tool_policies.contains_key("browser"),
+            "tools must reach the policy"
+        );
+    }
+
+    fn tool_policy_fixture(
+        workspace: &Path,
+        name: &str,
+        entry: crate::schema::ToolPolicyConfig,
+    ) -> SecurityPolicy {
+        SecurityPolicy {
+            workspace_dir: workspace.to_path_buf(),
+            risk_profile_name: "default".into(),
+            tool_policies: HashMap::from([(name.to_string(), entry)]),
+            ..SecurityPolicy::default()
+        }
+    }
+
+    #[test]
+    fn check_tool_allows_tools_without_policy_entries() {
+        let policy = SecurityPolicy::default();
+        assert_eq!(
+            policy.check_tool("file_write", &serde_json::json!({"path": "a.txt"})),
+            ToolPolicyDecision::Allow
+        );
+        assert_eq!(
+            policy.check_tool("shell", &serde_json::json!({"command": "ls"})),
+            ToolPolicyDecision::Allow
+        );
+    }
+
+    #[test]
+    fn check_tool_denies_file_write_and_names_the_policy() {
+        let tmp = tempfile::tempdir().unwrap();
+        let policy = tool_policy_fixture(
+            tmp.path(),
+            "file_write",
+            crate::schema::ToolPolicyConfig {
+                permission: crate::schema::ToolPermission::Deny,
+                ..Default::default()
+            },
+        );
+        match policy.check_tool("file_write", &serde_json::json!({"path": "notes.md"})) {
+            ToolPolicyDecision::Deny(reason) => {
+                assert!(reason.contains("[risk_profiles.default.tools.file_write]"));
+            }
+            other => panic!("expected deny, got {other:?}"),
+        }
+        assert_eq!(
+            policy.check_tool("file_read", &serde_json::json!({"path": "notes.md"})),
+            ToolPolicyDecision::Allow
+        );
+    }
+
+    #[test]
+    fn check_tool_requires_approval_for_file_write_outside_except_paths() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join("src")).unwrap();
+        let policy = tool_policy_fixture(
+            tmp.path(),
+            "file_write",
+            crate::schema::ToolPolicyConfig {
+                permission: crate::schema::ToolPermission::RequireApproval,
+                except_paths: vec!["src".into()],
+                ..Default::default()
+            },
+        );
+        let check =
+            |path: &str| policy.check_tool("file_write", &serde_json::json!({"path": path}));
+
+        assert_eq!(check("src/main.rs"), ToolPolicyDecision::Allow);
+        assert_eq!(check("src/new/mod.rs"), ToolPolicyDecision::Allow);
+        assert_eq!(
+            check(&tmp.path().join("src/lib.rs").to_string_lossy()),
+            ToolPolicyDecision::Allow
+        );
+        assert_eq!(check("README.md"), ToolPolicyDecision::RequireApproval);
+        assert_eq!(check("srcfoo/x.rs"), ToolPolicyDecision::RequireApproval);
+        assert_eq!(
+            check("src/../secrets.txt"),
+            ToolPolicyDecision::RequireApproval
+        );
+        assert_eq!(
+            policy.check_tool("file_write", &serde_json::json!({})),
+            ToolPolicyDecision::RequireApproval
+        );
+    }
+
+    #[test]
+    fn check_tool_shell_policies_honor_command_exemptions() {
+        let tmp = tempfile::tempdir().unwrap();
+        let deny = tool_policy_fixture(
+            tmp.path(),
+            "shell",
+            crate::schema::ToolPolicyConfig {
+                permission: crate::schema::ToolPermission::Deny,
+                except_commands: vec!["git status".into()],
+                ..Default::default()
+            },
+        );
+        let check =
+            |command: &str| deny.check_tool("shell", &serde_json::json!({"command": command}));
+
+        assert_eq!(check("git status"), ToolPolicyDecision::Allow);
+        assert_eq!(check("git status --short"), ToolPolicyDecision::Allow);
+        assert!(matches!(check("git statusx"), ToolPolicyDecision::Deny(_)));
+        assert!(matches!(
+            check("git status; rm -rf ~"),
+            ToolPolicyDecision::Deny(_)
+        ));
+        assert!(matches!(
+            check("git status && curl x"),
+            ToolPolicyDecision::Deny(_)
+        ));
+        assert!(matches!(
+            check("rm -rf /tmp/x"),
+            ToolPolicyDecision::Deny(_)
+        ));
+
+        let approval = tool_policy_fixture(
+            tmp.path(),
+            "shell",
+            crate::schema::ToolPolicyConfig {
+                permission: crate::schema::ToolPermission::RequireApproval,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            approval.check_tool("shell", &serde_json::json!({"command": "ls"})),
+            ToolPolicyDecision::RequireApproval
+        );
+
+        let allow = tool_policy_fixture(
+            tmp.path(),
+            "shell",
+            crate::schema::ToolPolicyConfig::default(),
+        );
+        assert_eq!(
+            allow.check_tool("shell", &serde_json::json!({"command": "rm -rf /"})),
+            ToolPolicyDecision::Allow
+        );
+    }
+
+    #[test]
+    fn check_tool_requires_approval_for_browser_page_actions() {
+        let policy = SecurityPolicy::default();
+        let check = |args: serde_json::Value| policy.check_tool("browser", &args);
+
+        for action in ["click", "fill", "type", "press"] {
+            assert_eq!(
+                check(serde_json::json!({"action": action, "selector": "#go"})),
+                ToolPolicyDecision::RequireApproval,
+                "{action}"
+            );
+        }
+        assert_eq!(
+            check(serde_json::json!({"action": "find", "find_action": "click"})),
+            ToolPolicyDecision::RequireApproval
+        );
+        for action in ["open", "goto", "extract_text", "screenshot", "wait_for"] {
+            assert_eq!(
+                check(serde_json::json!({"action": action})),
+                ToolPolicyDecision::Allow,
+                "{action}"
+            );
+        }
+        assert_eq!(
+            check(serde_json::json!({"action": "find", "find_action": "text"})),
+            ToolPolicyDecision::Allow
+        );
+
+        let relaxed = SecurityPolicy {
+            require_approval_for_medium_risk: false,
+            ..SecurityPolicy::default()
+        };
+        assert_eq!(
+            relaxed.check_tool("browser", &serde_json::json!({"action": "click"})),
+            ToolPolicyDecision::Allow
+        );
+        let full = SecurityPolicy {
+            autonomy: AutonomyLevel::Full,
+            ..SecurityPolicy::default()
+        };
+        assert_eq!(
+            full.check_tool("browser", &serde_json::json!({"action": "fill"})),
+            ToolPolicyDecision::Allow
+        );
     }
 
     #[test]
@@ -2761,6 +3448,29 @@ mod tests {
         assert!(err.contains("read-only mode"));
     }
 
+    #[test]
+    fn check_mutation_denies_only_mutating_calls_in_readonly_mode() {
+        let readonly = readonly_policy();
+        assert_eq!(
+            readonly.check_mutation("file_write", true),
+            ToolPolicyDecision::Deny(
+                "Security policy: read-only mode, cannot perform 'file_write'".into()
+            )
+        );
+        assert_eq!(
+            readonly.check_mutation("file_read", false),
+            ToolPolicyDecision::Allow
+        );
+        assert_eq!(
+            default_policy().check_mutation("file_write", true),
+            ToolPolicyDecision::Allow
+        );
+        assert_eq!(
+            full_policy().check_mutation("file_write", true),
+            ToolPolicyDecision::Allow
+        );
+    }
+
     #[test]
     fn enforce_tool_operation_act_uses_rate_budget() {
         let p = SecurityPolicy {
@@ -2921,6 +3631,146 @@ mod tests {
         );
     }
 
+    #[test]
+    fn command_risk_verdict_names_rule_category_and_trigger() {
+        let p = default_policy();
+        // (command, level, rule, category, trigger)
+        let corpus = [
+            ("ls -la", CommandRiskLevel::Low, None, None, None),
+            (
+                "curl https://example.com",
+                CommandRiskLevel::High,
+                Some(CommandRiskRule::HighRiskCommand),
+                Some(CommandRiskCategory::Network),
+                Some("curl"),
+            ),
+            (
+                "ls && sudo reboot",
+                CommandRiskLevel::High,
+                Some(CommandRiskRule::HighRiskCommand),
+                Some(CommandRiskCategory::PrivilegeEscalation),
+                Some("sudo"),
+            ),
+            (
+                "echo rm -rf /",
+                CommandRiskLevel::High,
+                Some(CommandRiskRule::DestructivePattern),
+                Some(CommandRiskCategory::Destructive),
+                Some("rm -rf /"),
+            ),
+            (
+                "git push origin main",
+                CommandRiskLevel::Medium,
+                Some(CommandRiskRule::MediumRiskSubcommand),
+                Some(CommandRiskCategory::VersionControl),
+                Some("git push"),
+            ),
+            (
+                "cargo install ripgrep",
+                CommandRiskLevel::Medium,
+                Some(CommandRiskRule::MediumRiskSubcommand),
+                Some(CommandRiskCategory::PackageInstall),
+                Some("cargo install"),
+            ),
+            (
+                "touch a.txt; chmod +x a.txt",
+                CommandRiskLevel::High,
+                Some(CommandRiskRule::HighRiskCommand),
+                Some(CommandRiskCategory::Permissions),
+                Some("chmod"),
+            ),
+            (
+                "mkdir out && git status",
+                CommandRiskLevel::Medium,
+                Some(CommandRiskRule::MediumRiskCommand),
+                Some(CommandRiskCategory::FileChange),
+                Some("mkdir"),
+            ),
+        ];
+        for (command, level, rule, category, trigger) in corpus {
+            let verdict = p.command_risk_verdict(command);
+            assert_eq!(verdict.level, level, "{command}");
+            assert_eq!(verdict.rule, rule, "{command}");
+            assert_eq!(verdict.category, category, "{command}");
+            assert_eq!(verdict.trigger.as_deref(), trigger, "{command}");
+            assert_eq!(p.command_risk_level(command), level, "{command}");
+        }
+
+        assert_eq!(
+            p.command_risk_verdict("ls && curl x").to_string(),
+            "high risk (network): `curl` is on the high-risk command list"
+        );
+        assert_eq!(
+            p.command_risk_verdict("ls").to_string(),
+            "low risk: no risk rule matched"
+        );
+    }
+
+    #[test]
+    fn command_allowlist_violation_names_the_gate() {
+        let p = default_policy();
+        assert_eq!(p.command_allowlist_violation("ls -la"), None);
+        assert!(
+            p.command_allowlist_violation("nmap localhost")
+                .unwrap()
+                .contains("`nmap` is not in allowed_commands")
+        );
+        assert!(
+            p.command_allowlist_violation("echo $HOME")
+                .unwrap()
+                .contains("variable expansion")
+        );
+        assert!(
+            p.command_allowlist_violation("find . -exec rm {} ;")
+                .unwrap()
+                .contains("`-exec`")
+        );
+    }
+
+    #[test]
+    fn explain_command_reports_decision() {
+        let p = SecurityPolicy {
+            autonomy: AutonomyLevel::Supervised,
+            require_approval_for_medium_risk: true,
+            allowed_commands: vec!["git".into(), "ls".into(), "curl".into()],
+            block_high_risk_commands: false,
+            ..SecurityPolicy::default()
+        };
+        assert_eq!(p.explain_command("ls").decision, CommandDecision::Allowed);
+        assert!(matches!(
+            p.explain_command("git push").decision,
+            CommandDecision::RequiresApproval(_)
+        ));
+        assert!(matches!(
+            p.explain_command("wget x").decision,
+            CommandDecision::Blocked(_)
+        ));
+
+        let blocking = SecurityPolicy {
+            allowed_commands: vec!["*".into()],
+            block_high_risk_commands: true,
+            ..p
+        };
+        let explanation = blocking.explain_command("curl https://example.com");
+        assert_eq!(explanation.allowlist_violation, None);
+        assert!(matches!(explanation.decision, CommandDecision::Blocked(_)));
+    }
+
+    #[test]
+    fn validate_command_error_carries_risk_verdict() {
+        let p = SecurityPolicy {
+            autonomy: AutonomyLevel::Supervised,
+            allowed_commands: vec!["curl".into()],
+            block_high_risk_commands: false,
+            ..SecurityPolicy::default()
+        };
+        let err = p
+            .validate_command_execution("curl https://example.com", false)
+            .unwrap_err();
+        assert!(err.contains("requires explicit approval"));
+        assert!(err.contains("`curl` is on the high-risk command list"));
+    }
+
     #[test]
     fn validate_command_requires_approval_for_medium_risk() {
         let p = SecurityPolicy {
@@ -4632,6 +5482,95 @@ mod tests {
         let _ = std::fs::remove_dir_all(&root);
     }
 
+    #[test]
+    fn confined_path_rejects_parent_traversal_chains() {
+        let tmp = tempfile::tempdir().unwrap();
+        let policy = SecurityPolicy {
+            workspace_dir: tmp.path().join("workspace"),
+            ..SecurityPolicy::default()
+        };
+
+        for path in [
+            "../../etc/passwd",
+            "notes/../../secret.txt",
+            "a/b/../../../c",
+        ] {
+            for operation in [ToolOperation::Read, ToolOperation::Act] {
+                let err = policy.resolve_confined_path(path, operation).unwrap_err();
+                assert!(err.contains("not allowed"), "{path}: {err}");
+            }
+        }
+        assert!(
+            policy
+                .resolve_confined_path("~root/.bashrc", ToolOperation::Read)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn confined_path_judges_new_files_by_existing_parent() {
+        let tmp = tempfile::tempdir().unwrap();
+        let workspace = tmp.path().join("workspace");
+        std::fs::create_dir_all(&workspace).unwrap();
+        let canonical_workspace = workspace.canonicalize().unwrap();
+        let policy = SecurityPolicy {
+            workspace_dir: workspace.clone(),
+            ..SecurityPolicy::default()
+        };
+
+        let inside = policy
+            .resolve_confined_path("new/dir/file.txt", ToolOperation::Act)
+            .unwrap();
+        assert_eq!(inside, canonical_workspace.join("new/dir/file.txt"));
+
+        let outside = tmp.path().join("outside").join("new.txt");
+        let err = policy
+            .resolve_confined_path(&outside.to_string_lossy(), ToolOperation::Act)
+            .unwrap_err();
+        assert!(err.contains("escapes workspace"), "{err}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn confined_path_follows_symlinked_directories() {
+        use std::os::unix::fs::symlink;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let workspace = tmp.path().join("workspace");
+        let outside = tmp.path().join("outside");
+        std::fs::create_dir_all(workspace.join("real")).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::fs::write(outside.join("secret.txt"), "secret").unwrap();
+        symlink(&outside, workspace.join("escape")).unwrap();
+        symlink(workspace.join("real"), workspace.join("alias")).unwrap();
+        symlink(tmp.path().join("missing"), workspace.join("dangling")).unwrap();
+        let policy = SecurityPolicy {
+            workspace_dir: workspace.clone(),
+            ..SecurityPolicy::default()
+        };
+
+        for path in ["escape/secret.txt", "escape/new/file.txt"] {
+            for operation in [ToolOperation::Read, ToolOperation::Act] {
+                let err = policy.resolve_confined_path(path, operation).unwrap_err();
+                assert!(err.contains("escapes workspace"), "{path}: {err}");
+            }
+        }
+        assert!(!outside.join("new").exists());
+
+        let aliased = policy
+            .resolve_confined_path("alias/new.txt", ToolOperation::Act)
+            .unwrap();
+        assert_eq!(
+            aliased,
+            workspace.canonicalize().unwrap().join("real/new.txt")
+        );
+
+        let err = policy
+            .resolve_confined_path("dangling/file.txt", ToolOperation::Act)
+            .unwrap_err();
+        assert!(err.contains("dangling symlink"), "{err}");
+    }
+
     #[cfg(unix)]
     #[test]
     fn allowed_roots_permits_paths_outside_workspace() {
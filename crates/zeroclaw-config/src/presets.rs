@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::autonomy::AutonomyLevel;
 use crate::autonomy::{DelegationMode, DelegationPolicy};
 use crate::policy::{default_allowed_commands, default_forbidden_paths};
-use crate::schema::{RiskProfileConfig, RuntimeProfileConfig};
+use crate::schema::{NetworkPolicyConfig, RiskProfileConfig, RuntimeProfileConfig};
 
 // ─────────────────────────────────────────────────────────────────────
 // Risk presets
@@ -85,6 +85,8 @@ fn locked_down_risk() -> RiskProfileConfig {
         approval_route: None,
         allowed_tools: vec![],
         excluded_tools: vec![],
+        tools: std::collections::HashMap::new(),
+        network: NetworkPolicyConfig::default(),
         sandbox_enabled: Some(true),
         sandbox_backend: None,
         firejail_args: vec![],
@@ -109,6 +111,8 @@ fn balanced_risk() -> RiskProfileConfig {
         approval_route: None,
         allowed_tools: vec![],
         excluded_tools: vec![],
+        tools: std::collections::HashMap::new(),
+        network: NetworkPolicyConfig::default(),
         sandbox_enabled: Some(true),
         sandbox_backend: None,
         firejail_args: vec![],
@@ -133,6 +137,8 @@ fn yolo_risk() -> RiskProfileConfig {
         approval_route: None,
         allowed_tools: vec![],
         excluded_tools: vec![],
+        tools: std::collections::HashMap::new(),
+        network: NetworkPolicyConfig::default(),
         sandbox_enabled: Some(false),
         sandbox_backend: None,
         firejail_args: vec![],
@@ -413,10 +419,28 @@ pub struct BuilderSubmission {
     /// channel ref. Empty list = no peer-group rows written.
     #[serde(default)]
     pub peer_groups: Vec<QuickstartPeerGroup>,
+    /// "Expose gateway publicly" from the Channels step. `None` leaves
+    /// `[tunnel]` untouched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_tunnel: Option<PublicTunnelChoice>,
     /// Agent identity (always create-new — there's no reuse path).
     pub agent: AgentIdentity,
 }
 
+/// Cloudflare Tunnel staged by the Channels step's "expose gateway
+/// publicly" option. Written to `[tunnel]` at apply time; the daemon's
+/// gateway then runs and supervises `cloudflared`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+pub struct PublicTunnelChoice {
+    /// Tunnel token from the Cloudflare Zero Trust dashboard.
+    pub token: String,
+    /// Public URL the surface learned by provisioning the tunnel, persisted
+    /// as `tunnel.public_url`. `None` when the probe was skipped or failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_url: Option<String>,
+}
+
 /// Peer-group entry staged in the Quickstart. Maps 1:1 to a
 /// `[peer_groups.<name>]` table written at apply time. The `channel`
 /// field carries a `<type>.<alias>` ref pointing at either a staged
@@ -759,6 +783,10 @@ mod tests {
                 fields: std::collections::HashMap::new(),
             })],
             peer_groups: vec![],
+            public_tunnel: Some(PublicTunnelChoice {
+                token: "cf-token".into(),
+                public_url: Some("https://bot.example.com".into()),
+            }),
             agent: AgentIdentity {
                 name: "my-bot".into(),
                 system_prompt: "You are a helpful assistant.".into(),
@@ -45,14 +45,17 @@ const SUPPORTED_PROXY_SERVICE_KEYS: &[&str] = &[
     "channel.wati",
     "channel.wechat",
     "channel.whatsapp",
+    "channel.zulip",
     "tool.browser",
     "tool.composio",
     "tool.http_request",
+    "tool.notify",
     "tool.pushover",
     "tool.web_search",
     "memory.embeddings",
     "tunnel.custom",
     "transcription.groq",
+    "translation.deepl",
 ];
 
 const SUPPORTED_PROXY_SERVICE_SELECTORS: &[&str] = &[
@@ -62,6 +65,7 @@ const SUPPORTED_PROXY_SERVICE_SELECTORS: &[&str] = &[
     "memory.*",
     "tunnel.*",
     "transcription.*",
+    "translation.*",
 ];
 
 static RUNTIME_PROXY_CONFIG: OnceLock<RwLock<ProxyConfig>> = OnceLock::new();
@@ -223,6 +227,19 @@ pub struct Config {
     #[group = "Agent"]
     pub scheduler: SchedulerConfig,
 
+    /// Background task queue for long-running agent work (`[tasks]`).
+    #[serde(default)]
+    #[nested]
+    #[group = "Agent"]
+    pub tasks: TasksConfig,
+
+    /// Persistent queue for tool approvals no channel could answer
+    /// (`[approvals]`).
+    #[serde(default)]
+    #[nested]
+    #[group = "Agent"]
+    pub approvals: ApprovalQueueConfig,
+
     /// Agent evaluation harness (`[eval]`) — surfaced via `zeroclaw eval`.
     /// Distinct from `[agent.eval]`, which is the in-loop response-quality scorer.
     #[serde(default)]
@@ -259,6 +276,25 @@ pub struct Config {
     #[group = "Agent"]
     pub heartbeat: HeartbeatConfig,
 
+    /// Component health and daemon self-healing (`[health]`).
+    #[serde(default)]
+    #[nested]
+    #[group = "Operations"]
+    pub health: HealthConfig,
+
+    /// Quiet hours for heartbeat and scheduled deliveries (`[quiet_hours]`).
+    #[serde(default)]
+    #[nested]
+    #[group = "Agent"]
+    pub quiet_hours: QuietHoursConfig,
+
+    /// File watchers that prompt the agent when files change (`[[watchers]]`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[nested]
+    #[natural_key = "name"]
+    #[group = "Agent"]
+    pub watchers: Vec<WatcherConfig>,
+
     /// ZeroCode live task tracker (`[todotracker]`), the read-only
     /// TodoWrite visual tracker in the Code pane.
     #[serde(default)]
@@ -510,6 +546,12 @@ pub struct Config {
     #[group = "Tools"]
     pub transcription: TranscriptionConfig,
 
+    /// Translation backend for channels with a `translate` setting (`[translation]`).
+    #[serde(default)]
+    #[nested]
+    #[group = "Tools"]
+    pub translation: TranslationConfig,
+
     /// Text-to-Speech configuration (`[tts]`).
     #[serde(default)]
     #[nested]
@@ -545,6 +587,12 @@ pub struct Config {
     #[group = "Integrations"]
     pub jira: JiraConfig,
 
+    /// Push notification targets for the `notify` tool (`[notifications]`).
+    #[serde(default)]
+    #[nested]
+    #[group = "Integrations"]
+    pub notifications: NotificationsConfig,
+
     /// Secure inter-node transport configuration (`[node_transport]`).
     #[serde(default)]
     #[nested]
@@ -602,6 +650,12 @@ pub struct Config {
     #[serde(default)]
     pub locale: Option<String>,
 
+    /// Treat config lint findings (unknown keys, values that fail to
+    /// deserialize) as fatal at daemon startup instead of logging warnings.
+    /// See `zeroclaw config validate`.
+    #[serde(default)]
+    pub strict_config: bool,
+
     /// Verifiable Intent (VI) credential verification and issuance (`[verifiable_intent]`).
     #[serde(default)]
     #[nested]
@@ -650,10 +704,22 @@ pub struct Config {
     #[group = "Tools"]
     pub shell_tool: ShellToolConfig,
 
+    /// Git tool configuration (`[git_tool]`).
+    #[serde(default)]
+    #[nested]
+    #[group = "Tools"]
+    pub git_tool: GitToolConfig,
+
     /// Escalation routing configuration (`[escalation]`).
     #[serde(default)]
     #[nested]
     pub escalation: EscalationConfig,
+
+    /// Self-update configuration (`[updater]`) — surfaced via `zeroclaw update`.
+    #[serde(default)]
+    #[nested]
+    #[group = "Operations"]
+    pub updater: UpdaterConfig,
 }
 
 /// Multi-client workspace isolation configuration.
@@ -815,10 +881,22 @@ pub struct ModelProviderConfig {
     #[tab(Model)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f64>,
-    /// HTTP request timeout in seconds. Bump this for slow local model_providers (Ollama on CPU, big local models) or high-latency networks; leave unset otherwise.
+    /// HTTP request timeout in seconds. Bump this for slow local model_providers (Ollama on CPU, big local models) or high-latency networks; leave unset otherwise. Also accepted as `request_timeout_secs`.
     #[tab(Model)]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        alias = "request_timeout_secs",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub timeout_secs: Option<u64>,
+    /// TCP/TLS connect timeout in seconds. Leave unset for the 10-second default; raise it when the provider sits behind a slow proxy or VPN.
+    #[tab(Connection)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_secs: Option<u64>,
+    /// Proxy URL for this provider only (http, https, socks5, socks5h). Overrides the global `[proxy]` section; `NO_PROXY` from the environment still applies. When neither is set, `HTTPS_PROXY` / `NO_PROXY` from the environment are used. Also accepted as `proxy`.
+    #[tab(Connection)]
+    #[serde(default, alias = "proxy", skip_serializing_if = "Option::is_none")]
+    pub proxy_url: Option<String>,
     /// Extra HTTP headers sent with every request. Niche: used for auth bridges, corporate proxies, or custom gateways that demand a tracing header. Most users never touch this; edit `config.toml` directly if you need it.
     #[tab(Connection)]
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
@@ -932,6 +1010,26 @@ pub struct ModelProviderConfig {
     #[tab(Connection)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tls_ca_cert_path: Option<String>,
+    /// Reasoning knobs for this provider (`[providers.models.<family>.<alias>.reasoning]`).
+    /// Overrides `runtime.reasoning_effort` for this alias only.
+    #[tab(Advanced)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[nested]
+    pub reasoning: Option<ModelProviderReasoningConfig>,
+}
+
+/// Per-provider reasoning controls. Each knob is forwarded only by provider
+/// families that expose the matching API parameter; others ignore it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "providers.models.reasoning"]
+pub struct ModelProviderReasoningConfig {
+    /// Reasoning effort for OpenAI o-series / GPT-5 style models (`minimal`, `low`, `medium`, `high`, `xhigh`). Sent as `reasoning_effort` (chat completions) or `reasoning.effort` (Responses API). Unset falls back to `runtime.reasoning_effort`.
+    #[serde(default, deserialize_with = "deserialize_reasoning_effort_opt")]
+    pub reasoning_effort: Option<String>,
+    /// Default extended-thinking budget in tokens for Anthropic models, used when the turn does not pick a thinking level of its own. Must be between 1024 and 128000.
+    #[serde(default)]
+    pub thinking_budget_tokens: Option<u32>,
 }
 
 // ── Per-family model model_provider configs ────────────────────────────
@@ -1426,6 +1524,10 @@ pub struct MistralModelProviderConfig {
     #[nested]
     #[serde(flatten)]
     pub base: ModelProviderConfig,
+    /// Send `safe_prompt = true` so Mistral prepends its guardrail system
+    /// prompt to every request. Off by default, matching the API.
+    #[serde(default)]
+    pub safe_prompt: bool,
 }
 
 // ── Atomic Chat (local OpenAI-compatible runtime, e.g. Jan) ──
@@ -1533,13 +1635,40 @@ impl ModelEndpoint for PerplexityEndpoint {
     }
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize, Configurable)]
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
 #[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
 #[prefix = "providers.models.perplexity"]
 pub struct PerplexityModelProviderConfig {
     #[nested]
     #[serde(flatten)]
     pub base: ModelProviderConfig,
+    /// Append the sources Perplexity cites as a numbered list under each
+    /// reply. Replies are not streamed while this is on. Default: `true`.
+    #[serde(default = "default_true")]
+    pub citations: bool,
+    /// Limit web search to these domains; prefix one with `-` to exclude it.
+    #[serde(default)]
+    pub search_domain_filter: Vec<String>,
+    /// Only use sources published within the last `hour`, `day`, `week`,
+    /// or `month`.
+    #[serde(default)]
+    pub search_recency_filter: Option<String>,
+    /// How much web context to retrieve per query: `low`, `medium`, or
+    /// `high`.
+    #[serde(default)]
+    pub search_context_size: Option<String>,
+}
+
+impl Default for PerplexityModelProviderConfig {
+    fn default() -> Self {
+        Self {
+            base: ModelProviderConfig::default(),
+            citations: true,
+            search_domain_filter: Vec::new(),
+            search_recency_filter: None,
+            search_context_size: None,
+        }
+    }
 }
 
 // ── xAI (Grok) ──
@@ -3685,6 +3814,14 @@ pub struct AliasedAgentConfig {
     #[serde(default)]
     pub delegates: Vec<DelegateTargetConfig>,
 
+    /// Number of interactive `zeroclaw agent` sessions checkpointed under
+    /// `<agent workspace>/state/sessions/` for `--resume`. Older sessions
+    /// are pruned after each save. `0` disables checkpointing and pruning;
+    /// `--resume` still saves the resumed session. Default: `20`.
+    #[tab(General)]
+    #[serde(default = "default_cli_session_retention")]
+    pub cli_session_retention: usize,
+
     // ── Resolved runtime tunables (populated by `resolved_agent_config`
     // from the runtime profile; not config-settable on the agent). ──
     #[serde(skip)]
@@ -3730,6 +3867,10 @@ pub struct AliasedAgentConfig {
     pub a2a: crate::multi_agent::AgentA2aConfig,
 }
 
+fn default_cli_session_retention() -> usize {
+    20
+}
+
 impl Default for AliasedAgentConfig {
     fn default() -> Self {
         Self {
@@ -3750,6 +3891,7 @@ impl Default for AliasedAgentConfig {
             summary_provider: crate::providers::ModelProviderRef::default(),
             delegate_same_risk_profile: true,
             delegates: Vec::new(),
+            cli_session_retention: default_cli_session_retention(),
             resolved: ResolvedRuntime::default(),
             workspace: crate::multi_agent::AgentWorkspaceConfig::default(),
             memory: crate::multi_agent::AgentMemoryConfig::default(),
@@ -4036,6 +4178,14 @@ impl Config {
             .unwrap_or(32_000)
     }
 
+    /// Tokens held back for the reply from a known context window
+    /// (runtime profile `response_reserve_tokens`). `None` when unset.
+    #[must_use]
+    pub fn effective_response_reserve_tokens(&self, agent_alias: &str) -> Option<usize> {
+        self.runtime_profile_for_agent(agent_alias)
+            .and_then(|p| p.response_reserve_tokens)
+    }
+
     /// Returns the model's context window size (max input tokens).
     /// Source: provider config `context_window` → fallback 32,000.
     /// Does NOT check runtime profile (that's for output budget).
@@ -4843,6 +4993,83 @@ impl Default for TranscriptionConfig {
     }
 }
 
+// ── Translation ─────────────────────────────────────────────────
+
+fn default_deepl_api_url() -> String {
+    "https://api-free.deepl.com/v2/translate".into()
+}
+
+/// Service that performs channel translation.
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum TranslationBackend {
+    /// Ask a language model. Uses `model_provider`, or the agent's own
+    /// provider and model when that is empty.
+    #[default]
+    Model,
+    /// DeepL REST API.
+    Deepl,
+}
+
+/// Translation backend settings (`[translation]` section).
+///
+/// Translation itself is switched on per channel through the channel's
+/// `translate` setting; this section only picks how it is done.
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "translation"]
+pub struct TranslationConfig {
+    /// `model` (default) or `deepl`.
+    #[serde(default)]
+    pub backend: TranslationBackend,
+    /// Provider for the `model` backend, as `<type>.<alias>` referencing
+    /// `[providers.models.<type>.<alias>]`. A small, cheap model is enough.
+    /// Empty uses the agent's provider and model.
+    #[serde(default)]
+    pub model_provider: crate::providers::ModelProviderRef,
+    /// DeepL authentication key. Encrypted at rest.
+    #[serde(default)]
+    #[secret]
+    #[credential_class = "encrypted_secret"]
+    #[cfg_attr(feature = "schema-export", schemars(extend("x-secret" = true)))]
+    pub deepl_api_key: Option<String>,
+    /// DeepL translate endpoint. Pro accounts use
+    /// `https://api.deepl.com/v2/translate`.
+    #[serde(default = "default_deepl_api_url")]
+    pub deepl_api_url: String,
+}
+
+impl Default for TranslationConfig {
+    fn default() -> Self {
+        Self {
+            backend: TranslationBackend::default(),
+            model_provider: crate::providers::ModelProviderRef::default(),
+            deepl_api_key: None,
+            deepl_api_url: default_deepl_api_url(),
+        }
+    }
+}
+
+/// Per-channel translation (`translate = { ... }` on a channel alias).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "channels.translate"]
+pub struct ChannelTranslateConfig {
+    /// Language the agent reads (ISO 639-1, e.g. `en`). Inbound messages
+    /// in another language are translated before memory recall. Empty
+    /// leaves inbound messages untouched.
+    #[serde(default)]
+    pub inbound_to: String,
+    /// Language replies are sent in: an ISO 639-1 code, or
+    /// `auto-detect-sender` to answer each sender in the language they
+    /// write. Empty leaves replies untouched.
+    #[serde(default)]
+    pub outbound_to: String,
+}
+
 // ── MCP ─────────────────────────────────────────────────────────
 
 /// Transport type for MCP server connections.
@@ -5175,6 +5402,10 @@ fn default_tts_max_text_length() -> usize {
     4096
 }
 
+fn default_tts_max_voice_duration_secs() -> u64 {
+    120
+}
+
 /// Text-to-Speech subsystem configuration (`[tts]`).
 ///
 /// Per-instance TTS configs live under `[tts_providers.<type>.<alias>]`
@@ -5196,6 +5427,11 @@ pub struct TtsConfig {
     /// Maximum input text length in characters (default 4096).
     #[serde(default = "default_tts_max_text_length")]
     pub max_text_length: usize,
+    /// Longest voice note to send, in seconds of estimated speech (default
+    /// 120). Longer replies are voiced up to this point and also delivered
+    /// in full as text. `0` disables the cap.
+    #[serde(default = "default_tts_max_voice_duration_secs")]
+    pub max_voice_duration_secs: u64,
 }
 
 impl Default for TtsConfig {
@@ -5205,6 +5441,7 @@ impl Default for TtsConfig {
             default_voice: default_tts_voice(),
             default_format: default_tts_format(),
             max_text_length: default_tts_max_text_length(),
+            max_voice_duration_secs: default_tts_max_voice_duration_secs(),
         }
     }
 }
@@ -6299,6 +6536,11 @@ pub struct MultimodalConfig {
     /// Maximum image payload size in MiB before base64 encoding.
     #[serde(default = "default_multimodal_max_image_size_mb")]
     pub max_image_size_mb: usize,
+    /// Longest edge, in pixels, for photos received on channels. Larger
+    /// photos are downscaled (aspect ratio kept) before they reach the
+    /// provider; photos that still exceed `max_image_size_mb` are dropped.
+    #[serde(default = "default_multimodal_max_image_dimension")]
+    pub max_image_dimension: u32,
     /// Maximum age of images in conversation turns.
     ///
     /// When non-zero, images in user messages that are more than this many
@@ -6334,6 +6576,10 @@ fn default_multimodal_max_image_size_mb() -> usize {
     5
 }
 
+fn default_multimodal_max_image_dimension() -> u32 {
+    2048
+}
+
 impl MultimodalConfig {
     /// Clamp configured values to safe runtime bounds.
     pub fn effective_limits(&self) -> (usize, usize) {
@@ -6348,6 +6594,7 @@ impl Default for MultimodalConfig {
         Self {
             max_images: default_multimodal_max_images(),
             max_image_size_mb: default_multimodal_max_image_size_mb(),
+            max_image_dimension: default_multimodal_max_image_dimension(),
             max_image_turns: 0,
             allow_remote_fetch: false,
             vision_model_provider: None,
@@ -6752,6 +6999,29 @@ pub struct PeripheralBoardConfig {
     /// Baud rate for serial (default: 115200)
     #[serde(default = "default_peripheral_baud")]
     pub baud: u32,
+    /// Name tools use to address this board when several are attached
+    /// (the `board` tool argument). Default: `board:path`, or `board` for
+    /// boards without a path.
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+impl PeripheralBoardConfig {
+    /// Stable name for this board: `name` when set, otherwise `board:path`.
+    pub fn session_name(&self) -> String {
+        if let Some(name) = self
+            .name
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+        {
+            return name.to_string();
+        }
+        match self.path.as_deref() {
+            Some(path) => format!("{}:{path}", self.board),
+            None => self.board.clone(),
+        }
+    }
 }
 
 fn default_peripheral_transport() -> String {
@@ -6769,6 +7039,7 @@ impl Default for PeripheralBoardConfig {
             transport: default_peripheral_transport(),
             path: None,
             baud: default_peripheral_baud(),
+            name: None,
         }
     }
 }
@@ -6796,7 +7067,8 @@ pub struct GatewayConfig {
     #[serde(default)]
     pub allow_public_bind: bool,
     /// Allow authenticated remote callers to use admin endpoints that are
-    /// otherwise localhost-only. Currently this gates `POST /admin/reload`.
+    /// otherwise localhost-only. Currently this gates `POST /admin/reload`;
+    /// the channel pause/resume and drain endpoints use `admin_api_key`.
     /// When false (default), those endpoints reject any non-loopback peer.
     /// When true, a non-loopback request is accepted only if it also passes
     /// pairing authentication — which requires `require_pairing = true`; with
@@ -6806,6 +7078,15 @@ pub struct GatewayConfig {
     /// (default: false)
     #[serde(default)]
     pub allow_remote_admin: bool,
+    /// Admin-scoped API key for `/admin/channels/{name}/pause`, `/resume`
+    /// and `/admin/drain`. Remote callers must send it as
+    /// `Authorization: Bearer <key>`; pairing tokens are not accepted there.
+    /// Unset (default) keeps those endpoints localhost-only.
+    #[serde(default)]
+    #[secret]
+    #[credential_class = "encrypted_secret"]
+    #[cfg_attr(feature = "schema-export", schemars(extend("x-secret" = true)))]
+    pub admin_api_key: Option<String>,
     /// Paired bearer tokens (managed automatically, not user-edited)
     #[serde(default)]
     #[secret]
@@ -6894,16 +7175,46 @@ pub struct GatewayConfig {
     /// unless you trust every paired client. (default: false)
     #[serde(default)]
     pub allow_self_upgrade: bool,
+
+    /// Seconds a runtime component may stay in error before `GET /health`
+    /// answers 503 instead of 200. `/health/details` marks such components
+    /// as `stuck`. (default: 300)
+    #[serde(default = "default_gateway_health_error_threshold_secs")]
+    pub health_error_threshold_secs: u64,
+
+    /// Browser origins allowed to call the gateway cross-origin (CORS), e.g.
+    /// `https://app.example.com`. `"*"` allows any origin. Empty (default)
+    /// sends no CORS headers, so browsers enforce the same-origin policy.
+    #[serde(default)]
+    pub cors_allowed_origins: Vec<String>,
+
+    /// Maximum request body size in bytes. Larger requests are rejected
+    /// with 413 Payload Too Large. (default: 65536)
+    #[serde(default = "default_gateway_max_body_size_bytes")]
+    pub max_body_size_bytes: usize,
+
+    /// JSON access log file (`[gateway.access_log]`).
+    #[serde(default)]
+    #[nested]
+    pub access_log: Option<GatewayAccessLogConfig>,
 }
 
 fn default_gateway_port() -> u16 {
     42617
 }
 
+fn default_gateway_health_error_threshold_secs() -> u64 {
+    300
+}
+
 fn default_gateway_request_timeout_secs() -> u64 {
     30
 }
 
+fn default_gateway_max_body_size_bytes() -> usize {
+    65_536
+}
+
 fn default_gateway_long_running_request_timeout_secs() -> u64 {
     600
 }
@@ -6956,6 +7267,7 @@ impl Default for GatewayConfig {
             require_pairing: true,
             allow_public_bind: false,
             allow_remote_admin: false,
+            admin_api_key: None,
             paired_tokens: Vec::new(),
             pair_rate_limit_per_minute: default_pair_rate_limit(),
             webhook_rate_limit_per_minute: default_webhook_rate_limit(),
@@ -6973,6 +7285,10 @@ impl Default for GatewayConfig {
             long_running_request_timeout_secs: default_gateway_long_running_request_timeout_secs(),
             check_updates: true,
             allow_self_upgrade: false,
+            health_error_threshold_secs: default_gateway_health_error_threshold_secs(),
+            cors_allowed_origins: Vec::new(),
+            max_body_size_bytes: default_gateway_max_body_size_bytes(),
+            access_log: None,
         }
     }
 }
@@ -7027,6 +7343,50 @@ impl Default for PairingDashboardConfig {
     }
 }
 
+/// JSON access log for the gateway (`[gateway.access_log]`). One line per
+/// request; the file is rotated by size.
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "gateway.access_log"]
+pub struct GatewayAccessLogConfig {
+    /// Write the access log (default: false).
+    #[serde(default)]
+    pub enabled: bool,
+    /// Log file path. Relative paths resolve against the data directory.
+    /// (default: "logs/gateway-access.jsonl")
+    #[serde(default = "default_gateway_access_log_path")]
+    pub path: String,
+    /// Rotate once the file reaches this size in MB (default: 10).
+    #[serde(default = "default_gateway_access_log_max_size_mb")]
+    pub max_size_mb: u32,
+    /// Rotated files to keep, `<path>.1` being the newest (default: 5).
+    #[serde(default = "default_gateway_access_log_max_files")]
+    pub max_files: u32,
+}
+
+fn default_gateway_access_log_path() -> String {
+    "logs/gateway-access.jsonl".into()
+}
+
+fn default_gateway_access_log_max_size_mb() -> u32 {
+    10
+}
+
+fn default_gateway_access_log_max_files() -> u32 {
+    5
+}
+
+impl Default for GatewayAccessLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: default_gateway_access_log_path(),
+            max_size_mb: default_gateway_access_log_max_size_mb(),
+            max_files: default_gateway_access_log_max_files(),
+        }
+    }
+}
+
 /// TLS configuration for the gateway server (`[gateway.tls]`).
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Configurable)]
 #[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
@@ -7209,6 +7569,10 @@ pub struct ComposioConfig {
     /// Default entity ID for multi-user setups
     #[serde(default = "default_entity_id")]
     pub entity_id: String,
+    /// Toolkits set up with `zeroclaw integrations setup` (`github`,
+    /// `googlecalendar`). `zeroclaw doctor` checks each has a connected account.
+    #[serde(default)]
+    pub toolkits: Vec<String>,
 }
 
 fn default_entity_id() -> String {
@@ -7221,6 +7585,7 @@ impl Default for ComposioConfig {
             enabled: false,
             api_key: None,
             entity_id: default_entity_id(),
+            toolkits: Vec::new(),
         }
     }
 }
@@ -7429,6 +7794,11 @@ pub struct BrowserConfig {
     /// endpoint (`169.254.169.254`) — list specific hosts unless you accept that exposure.
     #[serde(default)]
     pub allowed_private_hosts: Vec<String>,
+    /// Domains where the `browser` tool may type into credential fields
+    /// (password, one-time code, token or API-key inputs). Exact and subdomain
+    /// matches are supported. Default: empty, so credentials are never typed.
+    #[serde(default)]
+    pub credential_domains: Vec<String>,
 }
 
 fn default_browser_allowed_domains() -> Vec<String> {
@@ -7456,6 +7826,7 @@ impl Default for BrowserConfig {
             native_chrome_path: None,
             computer_use: BrowserComputerUseConfig::default(),
             allowed_private_hosts: vec![],
+            credential_domains: vec![],
         }
     }
 }
@@ -7497,6 +7868,11 @@ pub struct HttpRequestConfig {
     #[credential_class = "encrypted_secret"]
     #[cfg_attr(feature = "schema-export", schemars(extend("x-secret" = true)))]
     pub secrets: HashMap<String, String>,
+    /// Named request profiles (`[http_request.profiles.<name>]`) the tool
+    /// accepts via its `profile` argument.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[nested]
+    pub profiles: HashMap<String, HttpRequestProfileConfig>,
 }
 
 impl Default for HttpRequestConfig {
@@ -7509,10 +7885,51 @@ impl Default for HttpRequestConfig {
             allow_private_hosts: false,
             allowed_private_hosts: vec![],
             secrets: HashMap::new(),
+            profiles: HashMap::new(),
         }
     }
 }
 
+/// Authorization scheme for an HTTP request profile.
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum HttpRequestAuth {
+    /// No Authorization header.
+    #[default]
+    None,
+    /// `Authorization: Bearer <token>`, token read from `secret_env`.
+    Bearer,
+    /// `Authorization: Basic ...` from `username` and the password in `secret_env`.
+    Basic,
+}
+
+/// Named HTTP request profile (`[http_request.profiles.<name>]`).
+///
+/// Calls naming the profile resolve a relative `url` against `base_url` and
+/// carry its headers and credentials, so the model does not have to repeat
+/// them. Absolute URLs must stay on the `base_url` origin. The resolved URL
+/// is still checked against `allowed_domains` and the SSRF guards.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "http_request_profile"]
+#[serde(default)]
+pub struct HttpRequestProfileConfig {
+    /// Base URL relative `url` arguments resolve against (e.g. `https://api.github.com/v3`).
+    pub base_url: String,
+    /// Headers sent with every request through this profile. Per-call headers win.
+    pub headers: HashMap<String, String>,
+    /// Authorization scheme: `none`, `bearer`, or `basic`.
+    pub auth: HttpRequestAuth,
+    /// Environment variable holding the bearer token or basic-auth password.
+    /// Read at call time; never stored in config.
+    pub secret_env: Option<String>,
+    /// Username for `auth = "basic"`.
+    pub username: Option<String>,
+}
+
 fn default_http_max_response_size() -> usize {
     1_000_000 // 1MB
 }
@@ -7764,6 +8181,57 @@ impl Default for ShellToolConfig {
     }
 }
 
+// ── Git tool ─────────────────────────────────────────────────────
+
+/// Git tool configuration (`[git_tool]` section).
+///
+/// Controls the `git_operations` tool. Remote operations are off by
+/// default; when enabled, `push` and `pull` are medium-risk and go through
+/// the same approval gate as medium-risk shell commands.
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "git_tool"]
+pub struct GitToolConfig {
+    /// Expose `push` and `pull` (default: false).
+    #[serde(default)]
+    pub allow_remote: bool,
+    /// Author and committer name forced on every commit the tool makes.
+    /// Unset keeps the repository's own `user.name`.
+    #[serde(default)]
+    pub author_name: Option<String>,
+    /// Author and committer email forced on every commit the tool makes.
+    /// Unset keeps the repository's own `user.email`.
+    #[serde(default)]
+    pub author_email: Option<String>,
+    /// Absolute paths of repositories outside the workspace the tool may
+    /// operate in.
+    /// Committing there still requires the staged files to be writable under
+    /// the security policy (e.g. via `[autonomy] allowed_roots`).
+    #[serde(default)]
+    pub allowed_repos: Vec<String>,
+    /// Cap on raw git output parsed into a single result, in bytes
+    /// (default: 65536). Larger diffs and status listings are cut at a line
+    /// boundary and flagged `truncated`.
+    #[serde(default = "default_git_tool_max_output_bytes")]
+    pub max_output_bytes: usize,
+}
+
+fn default_git_tool_max_output_bytes() -> usize {
+    65_536
+}
+
+impl Default for GitToolConfig {
+    fn default() -> Self {
+        Self {
+            allow_remote: false,
+            author_name: None,
+            author_email: None,
+            allowed_repos: Vec::new(),
+            max_output_bytes: default_git_tool_max_output_bytes(),
+        }
+    }
+}
+
 // ── Escalation routing ───────────────────────────────────────────
 
 /// Escalation routing configuration (`[escalation]` section).
@@ -7784,6 +8252,45 @@ pub struct EscalationConfig {
     pub alert_channels: Vec<String>,
 }
 
+// ── Updater ──────────────────────────────────────────────────────
+
+/// Release track followed by `zeroclaw update`.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateChannel {
+    /// Tagged stable releases only (`vX.Y.Z`).
+    #[default]
+    Stable,
+    /// Stable releases plus `-beta.N` / `-rc.N` prereleases.
+    Beta,
+    /// The newest published release of any kind, including nightly builds.
+    Nightly,
+}
+
+impl UpdateChannel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Beta => "beta",
+            Self::Nightly => "nightly",
+        }
+    }
+}
+
+/// Self-update configuration (`[updater]` section).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "updater"]
+pub struct UpdaterConfig {
+    /// Release track `zeroclaw update` checks: `stable` (default), `beta`, or `nightly`.
+    /// An explicit `--version` always wins over the channel.
+    #[serde(default)]
+    pub channel: UpdateChannel,
+}
+
 // ── Web search ───────────────────────────────────────────────────
 
 /// Web search tool configuration (`[web_search]` section).
@@ -9924,7 +10431,40 @@ fn build_explicit_proxy_client(
     client
 }
 
-/// Apply a single explicit proxy URL to a builder via `Proxy::all`.
+/// Build an HTTP client for a model provider with its own timeouts. An
+/// explicit per-provider `proxy_url` wins; otherwise the global runtime proxy
+/// applies, and with neither configured reqwest reads `HTTPS_PROXY` /
+/// `NO_PROXY` from the environment.
+pub fn build_provider_proxy_client_with_timeouts(
+    service_key: &str,
+    proxy_url: Option<&str>,
+    timeout_secs: u64,
+    connect_timeout_secs: u64,
+) -> reqwest::Client {
+    build_channel_proxy_client_with_timeouts(
+        service_key,
+        proxy_url,
+        timeout_secs,
+        connect_timeout_secs,
+    )
+}
+
+/// Builder counterpart of [`build_provider_proxy_client_with_timeouts`] for
+/// providers that need extra builder settings (headers, TLS roots, streaming
+/// read timeouts).
+pub fn apply_provider_proxy_to_builder(
+    builder: reqwest::ClientBuilder,
+    service_key: &str,
+    proxy_url: Option<&str>,
+) -> reqwest::ClientBuilder {
+    match normalize_proxy_url_option(proxy_url) {
+        Some(url) => apply_explicit_proxy_to_builder(builder, service_key, &url),
+        None => apply_runtime_proxy_to_builder(builder, service_key),
+    }
+}
+
+/// Apply a single explicit proxy URL to a builder via `Proxy::all`. Hosts
+/// listed in the environment's `NO_PROXY` bypass it.
 fn apply_explicit_proxy_to_builder(
     mut builder: reqwest::ClientBuilder,
     service_key: &str,
@@ -9932,7 +10472,7 @@ fn apply_explicit_proxy_to_builder(
 ) -> reqwest::ClientBuilder {
     match reqwest::Proxy::all(proxy_url) {
         Ok(proxy) => {
-            builder = builder.proxy(proxy);
+            builder = builder.proxy(proxy.no_proxy(reqwest::NoProxy::from_env()));
         }
         Err(error) => {
             ::zeroclaw_log::record!(WARN, ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note).with_outcome(::zeroclaw_log::EventOutcome::Unknown).with_attrs(::serde_json::json!({"proxy_url": proxy_url, "service_key": service_key, "error": format!("{}", error)})), "Ignoring invalid channel proxy_url: ");
@@ -10560,7 +11100,7 @@ pub struct MemoryConfig {
     /// Delete core memory rows older than this many days from the DB. Age is measured by `created_at` (first-write time). Neither recall nor ordinary rewrites refresh `created_at` under the current SQLite upsert, so core retention is an absolute age limit from first write. Set this to a generously large window for durable core memories, or keep 0 = keep forever.
     #[serde(default = "default_zero_retention")]
     pub core_retention_days: u32,
-    /// Source of embedding vectors for semantic search. `none` = keyword-only retrieval (no API calls, no vector cost); `openai` = OpenAI's embedding API; `custom:URL` = any OpenAI-compatible embedding endpoint (LiteLLM, local gateway, etc.).
+    /// Source of embedding vectors for semantic search. `none` = keyword-only retrieval (no API calls, no vector cost); `openai` = OpenAI's embedding API; `custom:URL` = any OpenAI-compatible embedding endpoint (LiteLLM, local gateway, etc.); `ollama` = a local Ollama server at `http://localhost:11434` (no API key, e.g. `nomic-embed-text` with 768 dimensions), or `ollama:URL` for one elsewhere. Independent of the chat provider, so embeddings can come from OpenAI or Ollama while chatting through any other provider.
     #[serde(default = "default_embedding_provider")]
     pub embedding_provider: String,
     /// Embedding model identifier — must match a model your chosen embedding model_provider serves (e.g. `text-embedding-3-small` for OpenAI). Changing this invalidates existing embeddings: the change is detected at startup and stale vectors are cleared automatically; run `zeroclaw memory reindex` to re-embed (or set `auto_reindex_on_identity_change`).
@@ -10595,6 +11135,12 @@ pub struct MemoryConfig {
     /// Max embedding cache entries before LRU eviction
     #[serde(default = "default_cache_size")]
     pub embedding_cache_size: usize,
+    /// Max texts sent to the embedding endpoint in one request. Larger batches (reindex, bulk imports) are split into chunks of this size; lower it for local servers like Ollama or endpoints with tight request limits.
+    #[serde(default = "default_embedding_batch_size")]
+    pub embedding_batch_size: usize,
+    /// Embed new memories in batches from a background writer instead of inline on every store (sqlite backend). Stores, including auto-saves, return as soon as the row is written; recall waits for any queued vectors before its semantic stage. Has no effect while `dedup` is on, which needs the vector at store time.
+    #[serde(default = "default_true")]
+    pub deferred_embeddings: bool,
     /// Max tokens per chunk for document splitting
     #[serde(default = "default_chunk_size")]
     pub chunk_max_tokens: usize,
@@ -10668,6 +11214,18 @@ pub struct MemoryConfig {
     /// Default namespace for memory entries.
     #[serde(default = "default_namespace")]
     pub default_namespace: String,
+    /// Namespace policy for channel turns. `"global"` (default) keeps every
+    /// write and recall in the default namespace; `"per-sender"` stores
+    /// channel auto-saves and `memory_store` writes under
+    /// `channel:<channel-key>:<sender>`; `"per-channel"` under
+    /// `channel:<channel-key>:<reply-target>`. Scoped recall only sees the
+    /// requesting scope plus `shared_namespace`. CLI turns are unaffected.
+    #[serde(default)]
+    pub scope: MemoryScope,
+    /// Namespace every scope may recall from. Nothing is written here
+    /// automatically; it holds entries operators want visible to everyone.
+    #[serde(default = "default_shared_namespace")]
+    pub shared_namespace: String,
 
     // ── Conflict Resolution ─────────────────────────────────────
     /// Cosine similarity threshold for conflict detection (0.0–1.0).
@@ -10685,6 +11243,16 @@ pub struct MemoryConfig {
     /// Action to take when a duplicate is detected.
     #[serde(default)]
     pub dedup_action: MemoryDedupAction,
+    /// Duplicate handling on every store (SQLite backend): `skip` keeps the
+    /// existing entry and bumps its seen count, `merge` also folds the new
+    /// text into it, `off` writes every entry. Compares against recent
+    /// entries in the same category and namespace.
+    #[serde(default)]
+    pub dedup: MemoryStoreDedup,
+    /// Embedding cosine similarity (0.0–1.0) at or above which a stored entry
+    /// counts as a near-duplicate. Exact matches are caught without an embedder.
+    #[serde(default = "default_dedup_similarity_threshold")]
+    pub dedup_similarity_threshold: f64,
 
     // ── Memory Budget / Pinning ────────────────────────────────
     /// Maximum Core rows before budget compaction. 0 = unbounded.
@@ -10867,6 +11435,9 @@ fn validate_memory_rerank_config(memory: &MemoryConfig) -> Result<()> {
 fn default_namespace() -> String {
     "default".into()
 }
+fn default_shared_namespace() -> String {
+    "shared".into()
+}
 fn default_conflict_threshold() -> f64 {
     0.85
 }
@@ -10876,6 +11447,9 @@ fn default_conflict_supersede_enabled() -> bool {
 fn default_dedup_jaccard_threshold() -> f64 {
     0.80
 }
+fn default_dedup_similarity_threshold() -> f64 {
+    0.95
+}
 fn default_pin_min_importance() -> f64 {
     1.01
 }
@@ -10939,6 +11513,22 @@ pub fn validate_memory_semantics(
         .collect()
 }
 
+/// Memory namespace policy for channel turns (`[memory] scope`).
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum MemoryScope {
+    /// One namespace for every sender and channel.
+    #[default]
+    Global,
+    /// One namespace per channel sender (`channel:<channel-key>:<sender>`).
+    PerSender,
+    /// One namespace per channel conversation (`channel:<channel-key>:<reply-target>`).
+    PerChannel,
+}
+
 /// Write-time duplicate handling policy for memory entries.
 #[derive(
     Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, zeroclaw_macros::ConfigEnum,
@@ -10953,6 +11543,22 @@ pub enum MemoryDedupAction {
     Merge,
 }
 
+/// Duplicate handling for every memory store.
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MemoryStoreDedup {
+    /// Write every entry.
+    #[default]
+    Off,
+    /// Keep the existing entry and bump its seen count.
+    Skip,
+    /// Fold the new text into the existing entry and bump its seen count.
+    Merge,
+}
+
 /// Memory budget eviction order.
 #[derive(
     Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, zeroclaw_macros::ConfigEnum,
@@ -11014,6 +11620,9 @@ fn default_min_relevance_score() -> f64 {
 fn default_cache_size() -> usize {
     10_000
 }
+fn default_embedding_batch_size() -> usize {
+    64
+}
 fn default_chunk_size() -> usize {
     512
 }
@@ -11050,6 +11659,8 @@ impl Default for MemoryConfig {
             search_mode: SearchMode::default(),
             min_relevance_score: default_min_relevance_score(),
             embedding_cache_size: default_cache_size(),
+            embedding_batch_size: default_embedding_batch_size(),
+            deferred_embeddings: true,
             chunk_max_tokens: default_chunk_size(),
             response_cache_enabled: false,
             response_cache_ttl_minutes: default_response_cache_ttl(),
@@ -11068,11 +11679,15 @@ impl Default for MemoryConfig {
             recency_weight: default_recency_weight(),
             fts_early_return_score: default_fts_early_return_score(),
             default_namespace: default_namespace(),
+            scope: MemoryScope::default(),
+            shared_namespace: default_shared_namespace(),
             conflict_threshold: default_conflict_threshold(),
             conflict_supersede_enabled: default_conflict_supersede_enabled(),
             dedup_on_write: false,
             dedup_jaccard_threshold: default_dedup_jaccard_threshold(),
             dedup_action: MemoryDedupAction::default(),
+            dedup: MemoryStoreDedup::default(),
+            dedup_similarity_threshold: default_dedup_similarity_threshold(),
             core_max_rows: 0,
             core_max_bytes: 0,
             daily_max_rows: 0,
@@ -11375,6 +11990,49 @@ pub struct ObservabilityConfig {
     /// independently. `0` is treated as `off`.
     #[serde(default = "default_otel_tool_io_max_chars")]
     pub otel_tool_io_max_chars: usize,
+
+    /// Daily activity digest (`[observability.digest]`).
+    #[serde(default)]
+    #[nested]
+    pub digest: DigestConfig,
+}
+
+/// Daily activity digest (`[observability.digest]` section). The daemon
+/// counts channel messages, tool calls, errors, and tokens per day, and on
+/// `cron` sends a summary of the previous day to `channel` / `recipient`.
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "observability.digest"]
+pub struct DigestConfig {
+    /// Record daily activity and send the digest.
+    #[serde(default)]
+    pub enabled: bool,
+    /// When to send, as a cron expression in the host's local time.
+    /// Default: 08:00 every day.
+    #[serde(default = "default_digest_cron")]
+    pub cron: String,
+    /// Channel to deliver through: a channel type (`"telegram"`) or a
+    /// single alias (`"telegram.ops"`).
+    #[serde(default)]
+    pub channel: String,
+    /// Recipient on that channel (chat id, user id, or room).
+    #[serde(default)]
+    pub recipient: String,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            cron: default_digest_cron(),
+            channel: String::new(),
+            recipient: String::new(),
+        }
+    }
+}
+
+fn default_digest_cron() -> String {
+    "0 8 * * *".into()
 }
 
 impl Default for ObservabilityConfig {
@@ -11400,6 +12058,7 @@ impl Default for ObservabilityConfig {
             otel_genai_content_max_chars: default_otel_genai_content_max_chars(),
             otel_tool_io: OtelContentPolicy::Off,
             otel_tool_io_max_chars: default_otel_tool_io_max_chars(),
+            digest: DigestConfig::default(),
         }
     }
 }
@@ -11721,6 +12380,18 @@ pub struct RiskProfileConfig {
     /// `<server>__<tool>` MCP names that would otherwise be auto-admitted
     /// by the `allowed_tools` MCP exception described above.
     pub excluded_tools: Vec<String>,
+    /// Per-tool permission policy (`[risk_profiles.<alias>.tools.<tool>]`).
+    /// Checked by `SecurityPolicy::check_tool` at tool dispatch, before the
+    /// tool runs: `deny` refuses the call, `require_approval` sends it through
+    /// the approval flow, `allow` defers to the rules above.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[nested]
+    pub tools: HashMap<String, ToolPolicyConfig>,
+    /// Outbound network policy (`[risk_profiles.<alias>.network]`) for
+    /// network-capable tools. A legacy `[autonomy.network]` table migrates
+    /// here with the rest of `[autonomy]`.
+    #[nested]
+    pub network: NetworkPolicyConfig,
     // ── Sandbox (from security.sandbox) ─────────────────────────────
     /// Whether the sandbox is enabled for this profile. `None` inherits global.
     pub sandbox_enabled: Option<bool>,
@@ -11747,6 +12418,8 @@ impl Default for RiskProfileConfig {
             approval_route: None,
             allowed_tools: Vec::new(),
             excluded_tools: Vec::new(),
+            tools: HashMap::new(),
+            network: NetworkPolicyConfig::default(),
             sandbox_enabled: None,
             sandbox_backend: None,
             firejail_args: Vec::new(),
@@ -11754,6 +12427,115 @@ impl Default for RiskProfileConfig {
     }
 }
 
+/// What a `[risk_profiles.<alias>.tools.<tool>]` entry does to matching calls.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ToolPermission {
+    /// No extra gate; the profile's usual approval rules apply.
+    #[default]
+    Allow,
+    /// Every matching call goes through the approval flow, even when the
+    /// tool is in `auto_approve`. Paths without an approver deny it.
+    RequireApproval,
+    /// Matching calls are refused with an error naming the policy.
+    Deny,
+}
+
+impl ToolPermission {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::RequireApproval => "require_approval",
+            Self::Deny => "deny",
+        }
+    }
+}
+
+/// Permission policy for one tool (`[risk_profiles.<alias>.tools.<tool>]`).
+///
+/// ```toml
+/// [risk_profiles.default.tools.browser]
+/// permission = "deny"
+///
+/// [risk_profiles.default.tools.file_write]
+/// permission = "require_approval"
+/// except_paths = ["src"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "tool_policy"]
+#[serde(default)]
+pub struct ToolPolicyConfig {
+    /// `allow` (default), `require_approval`, or `deny`.
+    pub permission: ToolPermission,
+    /// Calls whose `path` argument resolves under one of these roots are
+    /// exempt from `permission`. Relative entries are workspace-relative.
+    pub except_paths: Vec<String>,
+    /// Calls whose `command` argument starts with one of these prefixes are
+    /// exempt from `permission` (e.g. `"git status"` for `shell`).
+    pub except_commands: Vec<String>,
+}
+
+/// Which hosts outbound network tools may reach under a risk profile.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkMode {
+    /// Only hosts matching `allowed_domains` are reachable.
+    Allowlist,
+    /// Every host except those matching `blocked_domains` is reachable.
+    Blocklist,
+    /// No domain filtering. The private-address block still applies.
+    #[default]
+    Open,
+}
+
+impl NetworkMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Allowlist => "allowlist",
+            Self::Blocklist => "blocklist",
+            Self::Open => "open",
+        }
+    }
+}
+
+/// Outbound network policy (`[risk_profiles.<alias>.network]`).
+///
+/// Applied by `SecurityPolicy::check_network_host` to every host that
+/// `http_request`, `web_fetch`, `web_search`, `text_browser` and plugin
+/// tools contact, including redirect hops. Cloud metadata endpoints are
+/// always refused; private, loopback and link-local hosts are refused unless
+/// listed in `allowed_private_hosts` or the tool's own private-host setting
+/// admits them.
+///
+/// ```toml
+/// [risk_profiles.default.network]
+/// mode = "allowlist"
+/// allowed_domains = ["*.github.com", "docs.rs"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "network_policy"]
+#[serde(default)]
+pub struct NetworkPolicyConfig {
+    /// `open` (default), `allowlist`, or `blocklist`.
+    pub mode: NetworkMode,
+    /// Host globs reachable in `allowlist` mode (e.g. `"*.example.com"`).
+    pub allowed_domains: Vec<String>,
+    /// Host globs refused in `blocklist` mode. Also refused in `allowlist`
+    /// mode, so a narrow block can carve out of a wide allow.
+    pub blocked_domains: Vec<String>,
+    /// Private or local hosts (globs or IPs) admitted despite the
+    /// private-address block, e.g. a LAN service.
+    pub allowed_private_hosts: Vec<String>,
+}
+
 /// Named runtime/LLM execution profile (`[runtime_profiles.<alias>]`).
 ///
 /// Reusable operational tuning: agentic mode, iteration caps, context
@@ -11799,6 +12581,10 @@ pub struct RuntimeProfileConfig {
     pub max_history_messages: Option<usize>,
     /// Maximum estimated tokens for context before compaction. `None` inherits.
     pub max_context_tokens: Option<usize>,
+    /// Tokens of the model's context window held back for the reply when the
+    /// window is known. The history budget is capped at the window minus
+    /// this. `None` reserves 10% of the window.
+    pub response_reserve_tokens: Option<usize>,
     /// Use compact bootstrap (6000 chars / 2 RAG chunks). `None` inherits.
     pub compact_context: Option<bool>,
     /// Enable parallel tool execution per iteration. `None` inherits.
@@ -11809,7 +12595,7 @@ pub struct RuntimeProfileConfig {
     pub tool_call_dedup_exempt: Vec<String>,
     /// Maximum characters for the assembled system prompt. `None` inherits.
     pub max_system_prompt_chars: Option<usize>,
-    /// Maximum characters for a single tool result. `None` inherits.
+    /// Maximum characters for a single tool result; larger results spill to `state/tool-output/` in the agent workspace and are replaced by a preview. `None` inherits.
     pub max_tool_result_chars: Option<usize>,
     /// Number of recent turns whose full tool context is preserved. `None` inherits.
     pub keep_tool_context_turns: Option<usize>,
@@ -11853,6 +12639,7 @@ impl Default for RuntimeProfileConfig {
             agentic_timeout_secs: None,
             max_history_messages: None,
             max_context_tokens: None,
+            response_reserve_tokens: None,
             compact_context: None,
             parallel_tools: None,
             tool_dispatcher: None,
@@ -12028,8 +12815,12 @@ pub struct DockerRuntimeConfig {
     #[serde(default = "default_docker_image")]
     pub image: String,
 
-    /// Docker network mode (`none`, `bridge`, etc.).
-    #[serde(default = "default_docker_network")]
+    /// Docker network mode (`none`, `bridge`, etc.). Defaults to `none`;
+    /// `network = true` is shorthand for `bridge`, `false` for `none`.
+    #[serde(
+        default = "default_docker_network",
+        deserialize_with = "deserialize_docker_network"
+    )]
     pub network: String,
 
     /// Optional memory limit in MB (`None` = no explicit limit).
@@ -12051,6 +12842,16 @@ pub struct DockerRuntimeConfig {
     /// Optional workspace root allowlist for Docker mount validation.
     #[serde(default)]
     pub allowed_workspace_roots: Vec<String>,
+
+    /// Remove a session's container once it has run no command for this many
+    /// seconds (`0` = keep it until shutdown).
+    #[serde(default = "default_docker_session_idle_timeout_secs")]
+    pub session_idle_timeout_secs: u64,
+
+    /// Most session containers kept running at once; the least recently used
+    /// one is removed to make room (`0` = no limit).
+    #[serde(default = "default_docker_max_session_containers")]
+    pub max_session_containers: usize,
 }
 
 fn default_docker_image() -> String {
@@ -12061,6 +12862,24 @@ fn default_docker_network() -> String {
     "none".into()
 }
 
+fn deserialize_docker_network<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Network {
+        Enabled(bool),
+        Mode(String),
+    }
+
+    Ok(match Network::deserialize(deserializer)? {
+        Network::Enabled(true) => "bridge".into(),
+        Network::Enabled(false) => default_docker_network(),
+        Network::Mode(mode) => mode,
+    })
+}
+
 fn default_docker_memory_limit_mb() -> Option<u64> {
     Some(512)
 }
@@ -12069,6 +12888,14 @@ fn default_docker_cpu_limit() -> Option<f64> {
     Some(1.0)
 }
 
+fn default_docker_session_idle_timeout_secs() -> u64 {
+    1800
+}
+
+fn default_docker_max_session_containers() -> usize {
+    16
+}
+
 impl Default for DockerRuntimeConfig {
     fn default() -> Self {
         Self {
@@ -12079,6 +12906,8 @@ impl Default for DockerRuntimeConfig {
             read_only_rootfs: true,
             mount_workspace: true,
             allowed_workspace_roots: Vec::new(),
+            session_idle_timeout_secs: default_docker_session_idle_timeout_secs(),
+            max_session_containers: default_docker_max_session_containers(),
         }
     }
 }
@@ -12117,6 +12946,12 @@ pub struct ReliabilityConfig {
     /// Max retries for cron job execution attempts.
     #[serde(default = "default_scheduler_retries")]
     pub scheduler_retries: u32,
+    /// Times a channel message is re-run from the start when its tool loop
+    /// fails with a retryable model_provider error. Tool calls that already
+    /// succeeded replay their recorded result instead of running again;
+    /// read-only tools always re-run. `0` disables the retry.
+    #[serde(default)]
+    pub message_retries: u32,
 }
 
 fn default_provider_retries() -> u32 {
@@ -12153,6 +12988,7 @@ impl Default for ReliabilityConfig {
             channel_max_backoff_secs: default_channel_backoff_max_secs(),
             scheduler_poll_secs: default_scheduler_poll_secs(),
             scheduler_retries: default_scheduler_retries(),
+            message_retries: 0,
         }
     }
 }
@@ -12184,6 +13020,20 @@ pub struct SchedulerConfig {
     /// next scheduled occurrence instead.
     #[serde(default = "default_true")]
     pub catch_up_on_startup: bool,
+    /// Maximum catch-up runs started at scheduler startup, across all jobs.
+    /// Default: `20`.
+    ///
+    /// Guards against a run storm after a long outage: `catch_up = "all"`
+    /// jobs replay their most recent missed occurrences within this budget,
+    /// and jobs past it skip to their next occurrence.
+    #[serde(default = "default_scheduler_max_catchup_runs")]
+    pub max_catchup_runs: u32,
+    /// Maximum occurrences a `concurrency = "queue"` job holds while its
+    /// previous run is still going. Default: `3`.
+    ///
+    /// Occurrences past the cap are recorded as skipped.
+    #[serde(default = "default_scheduler_max_queued_runs")]
+    pub max_queued_runs: u32,
     /// Maximum number of historical cron run records to retain. Default: `50`.
     #[serde(default = "default_max_run_history")]
     pub max_run_history: u32,
@@ -12201,6 +13051,14 @@ fn default_scheduler_max_concurrent() -> usize {
     4
 }
 
+fn default_scheduler_max_catchup_runs() -> u32 {
+    20
+}
+
+fn default_scheduler_max_queued_runs() -> u32 {
+    3
+}
+
 impl Default for SchedulerConfig {
     fn default() -> Self {
         Self {
@@ -12208,11 +13066,101 @@ impl Default for SchedulerConfig {
             max_tasks: default_scheduler_max_tasks(),
             max_concurrent: default_scheduler_max_concurrent(),
             catch_up_on_startup: true,
+            max_catchup_runs: default_scheduler_max_catchup_runs(),
+            max_queued_runs: default_scheduler_max_queued_runs(),
             max_run_history: default_max_run_history(),
         }
     }
 }
 
+/// Background task queue configuration (`[tasks]` section).
+///
+/// Tasks queued with the `task_spawn` tool run in the daemon's `tasks`
+/// worker, outside the channel `message_timeout_secs` budget, and report
+/// progress and completion back to the channel they were spawned from.
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "tasks"]
+pub struct TasksConfig {
+    /// Enable the background task worker and the `task_*` tools.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Maximum background tasks running at once. Default: `2`.
+    #[serde(default = "default_tasks_max_concurrent")]
+    pub max_concurrent: usize,
+    /// Seconds between "still running" progress messages. `0` disables them.
+    /// Default: `600`.
+    #[serde(default = "default_tasks_progress_interval_secs")]
+    pub progress_interval_secs: u64,
+    /// Hard limit on a single task's runtime in seconds. `0` means no limit.
+    /// Default: `14400` (4 hours).
+    #[serde(default = "default_tasks_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Re-queue tasks that were running when the daemon stopped. When
+    /// `false` (default) they are marked `interrupted` and the spawning
+    /// channel is told.
+    #[serde(default)]
+    pub resume_interrupted: bool,
+}
+
+fn default_tasks_max_concurrent() -> usize {
+    2
+}
+
+fn default_tasks_progress_interval_secs() -> u64 {
+    600
+}
+
+fn default_tasks_timeout_secs() -> u64 {
+    14_400
+}
+
+impl Default for TasksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_concurrent: default_tasks_max_concurrent(),
+            progress_interval_secs: default_tasks_progress_interval_secs(),
+            timeout_secs: default_tasks_timeout_secs(),
+            resume_interrupted: false,
+        }
+    }
+}
+
+/// Persistent tool-approval queue (`[approvals]` section).
+///
+/// When a supervised tool call needs approval and the originating channel
+/// cannot answer it (no inline approval support, or nobody responded), the
+/// request is persisted under `<data_dir>/approvals/` and the call waits for
+/// `zeroclaw approvals approve|deny` instead of being denied outright.
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "approvals"]
+pub struct ApprovalQueueConfig {
+    /// Queue unanswered approvals in the daemon. Default: `false` (they are
+    /// denied immediately).
+    #[serde(default)]
+    pub enabled: bool,
+    /// Seconds a queued approval waits before it is denied as expired and
+    /// the originating channel is told. A channel turn can end sooner when
+    /// it hits `channels.message_timeout_secs`. Default: `3600`.
+    #[serde(default = "default_approval_queue_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_approval_queue_timeout_secs() -> u64 {
+    3600
+}
+
+impl Default for ApprovalQueueConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            timeout_secs: default_approval_queue_timeout_secs(),
+        }
+    }
+}
+
 // ── Model routing ────────────────────────────────────────────────
 
 /// Route a task hint to a specific model_provider + model.
@@ -12342,6 +13290,151 @@ pub struct ClassificationRule {
     pub priority: i32,
 }
 
+// ── Quiet hours ──────────────────────────────────────────────────
+
+/// Quiet hours (`[quiet_hours]` section). While the window is open the
+/// heartbeat defers non-critical tasks, and announcements produced by
+/// scheduled or heartbeat work are queued and delivered once it closes.
+/// Direct replies to a user message are never held back.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "quiet_hours"]
+pub struct QuietHoursConfig {
+    /// Window start as `HH:MM` (24h), e.g. `"23:00"`. Quiet hours are off
+    /// unless both `start` and `end` are set and differ.
+    #[serde(default)]
+    pub start: String,
+    /// Window end as `HH:MM` (24h), e.g. `"07:00"`. An end earlier than the
+    /// start means the window crosses midnight.
+    #[serde(default)]
+    pub end: String,
+    /// IANA timezone the window is expressed in, e.g. `"Europe/Berlin"`.
+    /// `None` uses the host's local time.
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// Channels whose scheduled deliveries are held. An entry matches a
+    /// channel type (`"telegram"`) or a single alias (`"telegram.ops"`).
+    /// Empty holds deliveries on every channel.
+    #[serde(default)]
+    pub channels: Vec<String>,
+}
+
+impl QuietHoursConfig {
+    /// Whether a quiet window is configured (both ends set and distinct).
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        let (start, end) = (self.start.trim(), self.end.trim());
+        !start.is_empty() && !end.is_empty() && start != end
+    }
+}
+
+// ── File watchers ────────────────────────────────────────────────
+
+/// What a watcher does when a matching file changes.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum WatcherAction {
+    /// Render `prompt_template` with the changed path and run it through the
+    /// agent loop (default).
+    #[default]
+    Prompt,
+}
+
+/// A daemon-managed file watcher (`[[watchers]]`). Changes under `path` whose
+/// file name matches `glob` are coalesced over `debounce_ms`, then the
+/// rendered `prompt_template` runs as `agent` and the reply is delivered to
+/// `channel`/`recipient`.
+///
+/// ```toml
+/// [[watchers]]
+/// name = "inbox"
+/// agent = "default"
+/// path = "inbox"
+/// glob = "*.pdf"
+/// prompt_template = "Summarise the new document at {path}."
+/// channel = "telegram"
+/// recipient = "123456789"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "watchers"]
+pub struct WatcherConfig {
+    /// Watcher name, unique across `[[watchers]]`. Shown in logs and in the
+    /// `watchers` health component.
+    #[serde(default)]
+    pub name: String,
+    /// Enable this watcher. Default: `true`.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Configured agent alias the prompt runs as; refers to an
+    /// `[agents.<alias>]` entry. Its risk profile decides which paths the
+    /// watcher may observe.
+    #[serde(default)]
+    pub agent: String,
+    /// Directory (or single file) to watch, recursively. Relative paths
+    /// resolve against the agent's workspace.
+    #[serde(default)]
+    pub path: String,
+    /// Pattern a changed file must match, e.g. `"*.md"`. Matched against
+    /// the file name, or against the path relative to `path` when the
+    /// pattern contains `/`. Default: `"*"`.
+    #[serde(default = "default_watcher_glob")]
+    pub glob: String,
+    /// Action taken for a change. Default: `prompt`.
+    #[serde(default)]
+    pub action: WatcherAction,
+    /// Prompt sent to the agent. `{path}` expands to the changed file and
+    /// the prompt runs once per file; a template using `{paths}` runs once
+    /// per burst with every changed file, one per line.
+    #[serde(default)]
+    pub prompt_template: String,
+    /// Delivery channel for the agent's reply (e.g. `telegram` or
+    /// `telegram.ops`). When omitted the reply is only logged.
+    #[serde(default)]
+    pub channel: Option<String>,
+    /// Delivery recipient/chat identifier. Required when `channel` is set.
+    #[serde(default)]
+    pub recipient: Option<String>,
+    /// Quiet period in milliseconds: a burst of events is handled once no
+    /// further matching change has arrived for this long. Default: `2000`.
+    #[serde(default = "default_watcher_debounce_ms")]
+    pub debounce_ms: u64,
+    /// Watch `path` even when the agent's risk profile would not let it read
+    /// there (outside the workspace under `workspace_only`, or forbidden).
+    /// The agent run itself stays under its policy. Default: `false`.
+    #[serde(default)]
+    pub allow_outside_workspace: bool,
+}
+
+fn default_watcher_glob() -> String {
+    "*".into()
+}
+
+fn default_watcher_debounce_ms() -> u64 {
+    2000
+}
+
+impl Default for WatcherConfig {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            enabled: true,
+            agent: String::new(),
+            path: String::new(),
+            glob: default_watcher_glob(),
+            action: WatcherAction::default(),
+            prompt_template: String::new(),
+            channel: None,
+            recipient: None,
+            debounce_ms: default_watcher_debounce_ms(),
+            allow_outside_workspace: false,
+        }
+    }
+}
+
 // ── Heartbeat ────────────────────────────────────────────────────
 
 /// Heartbeat configuration for periodic health pings (`[heartbeat]` section).
@@ -12464,6 +13557,89 @@ impl Default for HeartbeatConfig {
     }
 }
 
+// ── Health ───────────────────────────────────────────────────────
+
+/// Component health settings (`[health]` section).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "health"]
+pub struct HealthConfig {
+    /// Daemon watchdog that restarts stuck components
+    /// (`[health.selfheal]`).
+    #[serde(default)]
+    #[nested]
+    pub selfheal: SelfHealConfig,
+}
+
+/// Daemon self-healing (`[health.selfheal]` section). On every check the
+/// watchdog restarts the channel supervisor when a listener has been in
+/// error past `listener_error_threshold_secs`, restarts a scheduler that has
+/// not ticked for `scheduler_stall_secs`, and notifies `channel` /
+/// `recipient` once a component needs `escalate_after` restarts without
+/// recovering.
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "health.selfheal"]
+pub struct SelfHealConfig {
+    /// Run the watchdog in the daemon. Default: `false`.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Seconds between watchdog checks. Default: `60`.
+    #[serde(default = "default_selfheal_interval_secs")]
+    pub interval_secs: u64,
+    /// Seconds a channel listener may stay in error before the channel
+    /// supervisor is restarted with channels rebuilt from the config on
+    /// disk. Default: `1800` (30 minutes).
+    #[serde(default = "default_selfheal_listener_error_threshold_secs")]
+    pub listener_error_threshold_secs: u64,
+    /// Seconds without a scheduler tick before the scheduler counts as
+    /// wedged and is restarted. Default: `600`.
+    #[serde(default = "default_selfheal_scheduler_stall_secs")]
+    pub scheduler_stall_secs: u64,
+    /// Self-heal restarts of one component, without it recovering in
+    /// between, before an escalation notice is sent. Default: `3`.
+    #[serde(default = "default_selfheal_escalate_after")]
+    pub escalate_after: u32,
+    /// Channel escalation notices go through: a channel type
+    /// (`"telegram"`) or a single alias (`"telegram.ops"`). Empty logs the
+    /// escalation only.
+    #[serde(default)]
+    pub channel: String,
+    /// Recipient on that channel (chat id, user id, or room).
+    #[serde(default)]
+    pub recipient: String,
+}
+
+fn default_selfheal_interval_secs() -> u64 {
+    60
+}
+
+fn default_selfheal_listener_error_threshold_secs() -> u64 {
+    1800
+}
+
+fn default_selfheal_scheduler_stall_secs() -> u64 {
+    600
+}
+
+fn default_selfheal_escalate_after() -> u32 {
+    3
+}
+
+impl Default for SelfHealConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: default_selfheal_interval_secs(),
+            listener_error_threshold_secs: default_selfheal_listener_error_threshold_secs(),
+            scheduler_stall_secs: default_selfheal_scheduler_stall_secs(),
+            escalate_after: default_selfheal_escalate_after(),
+            channel: String::new(),
+            recipient: String::new(),
+        }
+    }
+}
+
 // ── TodoTracker ──────────────────────────────────────────────────
 
 /// Location of the ZeroCode TodoWrite tracker panel.
@@ -12581,6 +13757,14 @@ pub struct CronJobDecl {
     #[serde(default)]
     #[nested]
     pub delivery: Option<DeliveryConfigDecl>,
+    /// Missed-run policy after daemon downtime: `"none"`, `"once"`, or
+    /// `"all"`. Unset follows `[scheduler] catch_up_on_startup`.
+    #[serde(default)]
+    pub catch_up: Option<String>,
+    /// What to do when the job comes due while its previous run is still
+    /// going: `"allow"`, `"skip"` (default), or `"queue"`.
+    #[serde(default)]
+    pub concurrency: Option<String>,
 }
 
 impl Default for CronJobDecl {
@@ -12597,6 +13781,8 @@ impl Default for CronJobDecl {
             uses_memory: true,
             session_target: None,
             delivery: None,
+            catch_up: None,
+            concurrency: None,
         }
     }
 }
@@ -12759,6 +13945,10 @@ pub struct TunnelConfig {
     #[serde(default)]
     #[nested]
     pub pinggy: Option<PinggyTunnelConfig>,
+
+    /// Public base URL the tunnel serves the gateway on (e.g. `https://bot.example.com`). Written by Quickstart's "expose gateway publicly" step; webhook URLs (`<public_url>/whatsapp/<alias>`, `<public_url>/webhook`) derive from it and `zeroclaw doctor` probes `<public_url>/health` to confirm it reaches this gateway.
+    #[serde(default)]
+    pub public_url: Option<String>,
 }
 
 impl Default for TunnelConfig {
@@ -12771,6 +13961,7 @@ impl Default for TunnelConfig {
             openvpn: None,
             custom: None,
             pinggy: None,
+            public_url: None,
         }
     }
 }
@@ -13005,6 +14196,10 @@ pub struct ChannelsConfig {
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     #[nested]
     pub mochat: HashMap<String, MochatConfig>,
+    /// Zulip bot channel instances (`[channels.zulip.<alias>]`).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[nested]
+    pub zulip: HashMap<String, ZulipConfig>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     #[nested]
     pub nostr: HashMap<String, NostrConfig>,
@@ -13087,6 +14282,125 @@ pub struct ChannelsConfig {
     /// as a single concatenated message. `0` disables debouncing. Default: `0`.
     #[serde(default)]
     pub debounce_ms: u64,
+    /// What happens to messages for a channel paused through the gateway
+    /// admin API: `reply` (default) answers with `paused_reply`, `queue`
+    /// holds them silently and handles them on resume.
+    #[serde(default)]
+    pub paused_mode: PausedChannelMode,
+    /// Auto-reply sent to senders on a paused channel when `paused_mode` is
+    /// `reply`.
+    #[serde(default = "default_paused_reply")]
+    pub paused_reply: String,
+    /// Persistent queue for replies a channel failed to deliver
+    /// (`[channels.outbox]`).
+    #[serde(default)]
+    #[nested]
+    pub outbox: ChannelOutboxConfig,
+    /// Reactions to delivered replies recorded as feedback
+    /// (`[channels.feedback]`).
+    #[serde(default)]
+    #[nested]
+    pub feedback: ChannelFeedbackConfig,
+}
+
+fn default_paused_reply() -> String {
+    "The bot is temporarily unavailable. Please try again shortly.".into()
+}
+
+/// Outbound reply queue (`[channels.outbox]` section).
+///
+/// A reply the channel could not deliver is stored in
+/// `<data_dir>/state/channel_outbox.db` and retried by the daemon with
+/// exponential backoff. Entries still undelivered after `max_age_secs` move
+/// to the dead-letter list shown by `zeroclaw channel outbox list`.
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "channels.outbox"]
+pub struct ChannelOutboxConfig {
+    /// Queue failed replies for retry. Default: `true`. When `false` a
+    /// failed send is logged and dropped.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Seconds a queued reply keeps being retried before it is
+    /// dead-lettered. Default: `86400` (one day).
+    #[serde(default = "default_outbox_max_age_secs")]
+    pub max_age_secs: u64,
+    /// Delay before the first retry; doubles after every failed attempt.
+    /// Default: `30`.
+    #[serde(default = "default_outbox_base_retry_secs")]
+    pub base_retry_secs: u64,
+    /// Upper bound for the retry delay. Default: `3600`.
+    #[serde(default = "default_outbox_max_retry_secs")]
+    pub max_retry_secs: u64,
+}
+
+fn default_outbox_max_age_secs() -> u64 {
+    86_400
+}
+
+fn default_outbox_base_retry_secs() -> u64 {
+    30
+}
+
+fn default_outbox_max_retry_secs() -> u64 {
+    3600
+}
+
+impl Default for ChannelOutboxConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_age_secs: default_outbox_max_age_secs(),
+            base_retry_secs: default_outbox_base_retry_secs(),
+            max_retry_secs: default_outbox_max_retry_secs(),
+        }
+    }
+}
+
+/// Reply feedback from reactions (`[channels.feedback]` section).
+///
+/// When enabled, channels that support it (Telegram) subscribe to reaction
+/// updates. A 👍 or 👎 on a reply the bot delivered is counted in the daily
+/// digest and `zeroclaw status`, exported as `zeroclaw_reply_feedback_total`,
+/// and optionally stored in memory under the `feedback` category.
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "channels.feedback"]
+pub struct ChannelFeedbackConfig {
+    /// Record reactions to delivered replies. Default: `false`.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Store each reaction with the question and answer it rates as a
+    /// `feedback` memory. Default: `true`.
+    #[serde(default = "default_true")]
+    pub store_in_memory: bool,
+    /// Answer a 👎 with an offer to retry in more detail. Default: `false`.
+    #[serde(default)]
+    pub follow_up_on_negative: bool,
+}
+
+impl Default for ChannelFeedbackConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            store_in_memory: true,
+            follow_up_on_negative: false,
+        }
+    }
+}
+
+/// Handling of inbound messages on a paused channel.
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum PausedChannelMode {
+    /// Answer with `[channels].paused_reply` and drop the message.
+    #[default]
+    Reply,
+    /// Hold the message without replying; it is handled on resume.
+    Queue,
 }
 
 impl ChannelsConfig {
@@ -13274,6 +14588,12 @@ impl ChannelsConfig {
                 desc: "Mochat Customer Service",
                 configured: !self.mochat.is_empty(),
             },
+            ChannelInfo {
+                kind: "zulip",
+                name: "Zulip",
+                desc: "stream + topic bot via the events API",
+                configured: !self.zulip.is_empty(),
+            },
             ChannelInfo {
                 kind: "line",
                 name: "LINE",
@@ -13350,6 +14670,7 @@ impl ChannelsConfig {
             || self.qq.values().any(|c| c.enabled)
             || self.twitter.values().any(|c| c.enabled)
             || self.mochat.values().any(|c| c.enabled)
+            || self.zulip.values().any(|c| c.enabled)
             || self.nostr.values().any(|c| c.enabled)
             || self.clawdtalk.values().any(|c| c.enabled)
             || self.reddit.values().any(|c| c.enabled)
@@ -13370,7 +14691,7 @@ impl ChannelsConfig {
     /// amqp are fan-in listeners; voice_wake is input-only), so a name-addressed
     /// outbound surface such as `heartbeat.target` can refuse them at validation
     /// instead of accepting a target the delivery layer silently drops.
-    pub fn channel_presence(&self) -> [(&'static str, bool, bool); 36] {
+    pub fn channel_presence(&self) -> [(&'static str, bool, bool); 37] {
         [
             ("telegram", !self.telegram.is_empty(), true),
             ("discord", !self.discord.is_empty(), true),
@@ -13397,6 +14718,7 @@ impl ChannelsConfig {
             ("qq", !self.qq.is_empty(), true),
             ("twitter", !self.twitter.is_empty(), true),
             ("mochat", !self.mochat.is_empty(), true),
+            ("zulip", !self.zulip.is_empty(), true),
             ("nostr", !self.nostr.is_empty(), true),
             ("clawdtalk", !self.clawdtalk.is_empty(), true),
             ("reddit", !self.reddit.is_empty(), true),
@@ -13483,6 +14805,7 @@ impl Default for ChannelsConfig {
             qq: HashMap::new(),
             twitter: HashMap::new(),
             mochat: HashMap::new(),
+            zulip: HashMap::new(),
             nostr: HashMap::new(),
             clawdtalk: HashMap::new(),
             reddit: HashMap::new(),
@@ -13502,6 +14825,10 @@ impl Default for ChannelsConfig {
             session_backend: default_session_backend(),
             session_ttl_hours: 0,
             debounce_ms: 0,
+            paused_mode: PausedChannelMode::default(),
+            paused_reply: default_paused_reply(),
+            outbox: ChannelOutboxConfig::default(),
+            feedback: ChannelFeedbackConfig::default(),
         }
     }
 }
@@ -13523,6 +14850,23 @@ pub enum StreamMode {
     MultiMessage,
 }
 
+/// Reply formatting for channels that can render either plain text or a
+/// rich markdown payload (DingTalk markdown messages, Lark interactive cards).
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum RichTextMode {
+    /// Use the rich format when the reply contains markdown (default).
+    #[default]
+    Auto,
+    /// Always send the rich format.
+    Always,
+    /// Always send plain text.
+    Never,
+}
+
 /// Where a channel registers its skill slash commands. `global` (default)
 /// registers application-wide - the commands work everywhere the bot is, but
 /// Discord takes up to ~1h to propagate changes. `guild` registers to each
@@ -13621,6 +14965,12 @@ pub struct TelegramConfig {
     #[tab(Behavior)]
     #[serde(default)]
     pub mention_only: bool,
+    /// Forum topics the bot answers in, as `"<chat_id>:<topic_id>"` entries
+    /// (e.g. `"-1001234567890:42"`; the General topic is `1`). Chats without
+    /// an entry are unrestricted; in a listed chat, other topics are ignored.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub allowed_topics: Vec<String>,
     /// Override for the top-level `ack_reactions` setting. When `None`, the
     /// channel falls back to `[channels].ack_reactions`. When set
     /// explicitly, it takes precedence.
@@ -13638,6 +14988,14 @@ pub struct TelegramConfig {
     #[serde(default = "default_telegram_approval_timeout_secs")]
     pub approval_timeout_secs: u64,
 
+    /// Translate messages on this channel (`translate = { inbound_to = "en",
+    /// outbound_to = "auto-detect-sender" }`). Unset disables translation.
+    /// The backend is chosen under `[translation]`.
+    #[tab(Behavior)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[nested]
+    pub translate: Option<ChannelTranslateConfig>,
+
     /// Tools excluded from this channel's tool spec. When set, these tools
     /// are not exposed to the model when responding via this channel.
     #[tab(Behavior)]
@@ -13666,9 +15024,11 @@ impl Default for TelegramConfig {
             draft_update_interval_ms: default_draft_update_interval_ms(),
             interrupt_on_new_message: false,
             mention_only: false,
+            allowed_topics: Vec::new(),
             ack_reactions: None,
             proxy_url: None,
             approval_timeout_secs: default_telegram_approval_timeout_secs(),
+            translate: None,
             excluded_tools: Vec::new(),
             reply_min_interval_secs: 0,
             reply_queue_depth_max: 0,
@@ -13770,6 +15130,23 @@ pub struct DiscordConfig {
     #[tab(Behavior)]
     #[serde(default)]
     pub interrupt_on_new_message: bool,
+    /// When true, editing a message re-dispatches the edited text to the
+    /// agent. If the original is still being answered, that turn is
+    /// interrupted first. Edits to messages older than `edit_window_secs`
+    /// are ignored.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub respond_to_edits: bool,
+    /// Maximum age in seconds of an edited message for `respond_to_edits`
+    /// to re-dispatch it. 0 = any age. Default 300.
+    #[tab(Behavior)]
+    #[serde(default = "default_discord_edit_window_secs")]
+    pub edit_window_secs: u64,
+    /// When true, a reply to an earlier message carries that message as
+    /// quoted context, so the agent knows what the reply refers to.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub reply_context: bool,
     /// When true, only respond to messages that @-mention the bot.
     /// Other messages in the guild are silently ignored.
     #[tab(Behavior)]
@@ -13844,6 +15221,14 @@ pub struct DiscordConfig {
     #[serde(default = "default_channel_approval_timeout_secs")]
     pub approval_timeout_secs: u64,
 
+    /// Translate messages on this channel (`translate = { inbound_to = "en",
+    /// outbound_to = "auto-detect-sender" }`). Unset disables translation.
+    /// The backend is chosen under `[translation]`.
+    #[tab(Behavior)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[nested]
+    pub translate: Option<ChannelTranslateConfig>,
+
     /// Tools excluded from this channel's tool spec. When set, these tools
     /// are not exposed to the model when responding via this channel.
     #[tab(Behavior)]
@@ -13862,6 +15247,10 @@ pub struct DiscordConfig {
     pub reply_queue_depth_max: u16,
 }
 
+fn default_discord_edit_window_secs() -> u64 {
+    300
+}
+
 impl DiscordConfig {
     /// Validate this alias's bot-token placeholder and enabled-state rules.
     /// Mirrors `TelegramConfig::validate_bot_token`.
@@ -13977,6 +15366,14 @@ pub struct SlackConfig {
     #[serde(default = "default_channel_approval_timeout_secs")]
     pub approval_timeout_secs: u64,
 
+    /// Translate messages on this channel (`translate = { inbound_to = "en",
+    /// outbound_to = "auto-detect-sender" }`). Unset disables translation.
+    /// The backend is chosen under `[translation]`.
+    #[tab(Behavior)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[nested]
+    pub translate: Option<ChannelTranslateConfig>,
+
     /// Tools excluded from this channel's tool spec. When set, these tools
     /// are not exposed to the model when responding via this channel.
     #[tab(Behavior)]
@@ -14155,6 +15552,14 @@ pub struct MattermostConfig {
     /// faster but requires a WebSocket-capable Mattermost server (v4.0+).
     #[serde(default)]
     pub listen_mode: MattermostListenMode,
+    /// Stream replies by creating a post and editing it as text arrives.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub stream_drafts: bool,
+    /// Minimum interval (ms) between draft post edits to avoid Mattermost rate limits.
+    #[tab(Behavior)]
+    #[serde(default = "default_draft_update_interval_ms")]
+    pub draft_update_interval_ms: u64,
 
     /// Tools excluded from this channel's tool spec. When set, these tools
     /// are not exposed to the model when responding via this channel.
@@ -14227,6 +15632,20 @@ pub struct WebhookConfig {
     #[tab(Connection)]
     #[cfg_attr(feature = "schema-export", schemars(extend("x-secret" = true)))]
     pub secret: Option<String>,
+    /// Template that turns the JSON payload posted to the gateway's
+    /// `/webhook/<alias>` route into the message text. `{{ path }}` inserts a
+    /// value (`{{ repository.full_name }}`) and `[]` maps over an array,
+    /// one line per item (`{{ commits[].message }}`). Unset: the payload's
+    /// `message` or `content` field is used, else the raw JSON.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub template: Option<String>,
+    /// Return the agent's reply as the body of the gateway's
+    /// `/webhook/<alias>` response. Default `false`: the request is answered
+    /// with `202 Accepted` and the turn runs in the background.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub sync_response: bool,
 
     /// Tools excluded from this channel's tool spec. When set, these tools
     /// are not exposed to the model when responding via this channel.
@@ -14289,6 +15708,13 @@ pub struct IMessageConfig {
     #[tab(Behavior)]
     #[serde(default)]
     pub excluded_tools: Vec<String>,
+    /// Group chat GUIDs (the `chat.guid` column in the Messages database,
+    /// e.g. `iMessage;+;chat123456789`) whose messages are accepted from any
+    /// participant. `"*"` accepts every group. In groups not listed here,
+    /// only participants on the peer allowlist are answered.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub group_ids: Vec<String>,
     /// Per-(channel, recipient) outbound pacing floor in seconds.
     /// Range: `0..=REPLY_MIN_INTERVAL_MAX_SECS` (0 disables).
     #[serde(default)]
@@ -14462,6 +15888,12 @@ pub struct SignalConfig {
     #[tab(Advanced)]
     #[serde(default)]
     pub dm_only: bool,
+    /// When true, only respond in groups when a message mentions this
+    /// account (by number or UUID) or replies to one of its messages.
+    /// Direct messages are always processed.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub mention_only: bool,
     /// Skip messages that are attachment-only (no text body).
     #[tab(Advanced)]
     #[serde(default)]
@@ -15438,6 +16870,15 @@ pub struct IrcConfig {
     #[tab(Connection)]
     #[cfg_attr(feature = "schema-export", schemars(extend("x-secret" = true)))]
     pub sasl_password: Option<String>,
+    /// PEM client certificate presented during the TLS handshake (CertFP).
+    /// When set, SASL EXTERNAL is tried first, falling back to SASL PLAIN
+    /// if `sasl_password` is also set.
+    #[tab(Connection)]
+    pub client_cert_path: Option<PathBuf>,
+    /// PEM private key for `client_cert_path`. Leave unset when the key is
+    /// in the same file as the certificate.
+    #[tab(Connection)]
+    pub client_key_path: Option<PathBuf>,
     /// Verify TLS certificate (default: true)
     #[tab(Advanced)]
     pub verify_tls: Option<bool>,
@@ -15545,13 +16986,15 @@ pub struct LarkConfig {
     #[tab(Connection)]
     #[cfg_attr(feature = "schema-export", schemars(extend("x-secret" = true)))]
     pub app_secret: String,
-    /// Encrypt key for webhook message decryption (optional)
+    /// Encrypt key for event decryption (optional). When set in the
+    /// developer console, events arrive encrypted in both receive modes.
     #[serde(default)]
     #[secret]
     #[tab(Connection)]
     #[cfg_attr(feature = "schema-export", schemars(extend("x-secret" = true)))]
     pub encrypt_key: Option<String>,
-    /// Verification token for webhook validation (optional)
+    /// Verification token checked against inbound events in both receive
+    /// modes (optional)
     #[serde(default)]
     #[secret]
     #[tab(Connection)]
@@ -15566,9 +17009,10 @@ pub struct LarkConfig {
     #[tab(Advanced)]
     #[serde(default)]
     pub use_feishu: bool,
-    /// Event receive mode: "websocket" (default) or "webhook"
+    /// Event receive mode: "websocket" (default) or "webhook". Also
+    /// accepted as `connection_mode`.
     #[tab(Advanced)]
-    #[serde(default)]
+    #[serde(default, alias = "connection_mode")]
     pub receive_mode: LarkReceiveMode,
     /// HTTP port for webhook mode only. Must be set when receive_mode = "webhook".
     /// Not required (and ignored) for websocket mode.
@@ -15623,6 +17067,13 @@ pub struct LarkConfig {
     #[tab(Behavior)]
     #[serde(default = "default_draft_update_interval_ms")]
     pub draft_update_interval_ms: u64,
+
+    /// Reply format: `auto` (default) sends an interactive card when the
+    /// reply contains markdown and a plain text message otherwise;
+    /// `always` / `never` force one or the other.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub rich_text: RichTextMode,
 }
 
 impl ChannelConfig for LarkConfig {
@@ -15769,6 +17220,12 @@ pub struct SecurityConfig {
     #[nested]
     pub leak_detection: LeakDetectionConfig,
 
+    /// Prompt-injection scanning of tool output before it enters history.
+    /// See `[security.prompt_injection]`.
+    #[serde(default)]
+    #[nested]
+    pub prompt_injection: PromptInjectionConfig,
+
     /// OTP gating configuration for sensitive actions/domains.
     #[serde(default)]
     #[nested]
@@ -15836,6 +17293,81 @@ impl Default for LeakDetectionConfig {
     }
 }
 
+/// What to do with a tool result whose injection risk score reaches
+/// `[security.prompt_injection].threshold`.
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum PromptInjectionAction {
+    /// Keep the content, wrapped in an untrusted-content frame that tells the
+    /// model not to follow instructions inside it.
+    #[default]
+    Warn,
+    /// Replace the content with a notice; the model never sees it.
+    Block,
+}
+
+/// Prompt-injection scanning of tool results (`[security.prompt_injection]`).
+///
+/// Web pages, emails, and files fetched by tools are attacker-controlled
+/// text. When enabled, every tool result is scored by a heuristic rule set,
+/// by any plugin detectors registered as hooks, and optionally by a cheap
+/// classifier model before it is appended to history. Detections are
+/// written to the audit log.
+#[derive(Debug, Clone, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "security.prompt_injection"]
+pub struct PromptInjectionConfig {
+    /// Scan tool results for prompt injection. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// `warn` frames risky content as untrusted; `block` withholds it.
+    #[serde(default)]
+    pub action: PromptInjectionAction,
+
+    /// Risk score from 0.0 to 1.0 at which `action` applies. Lower scores
+    /// are logged but the content passes through unchanged.
+    #[serde(default = "default_prompt_injection_threshold")]
+    pub threshold: f64,
+
+    /// Tools whose output is scanned. Empty = every tool.
+    #[serde(default)]
+    pub tools: Vec<String>,
+
+    /// Optional classifier model, as a `providers.models.<type>.<alias>`
+    /// reference, asked to score each result. Empty = heuristics only.
+    #[serde(default)]
+    pub classifier_provider: crate::providers::ModelProviderRef,
+
+    /// Characters of each result sent to the classifier.
+    #[serde(default = "default_prompt_injection_classifier_max_chars")]
+    pub classifier_max_chars: usize,
+}
+
+fn default_prompt_injection_threshold() -> f64 {
+    0.6
+}
+
+fn default_prompt_injection_classifier_max_chars() -> usize {
+    4_000
+}
+
+impl Default for PromptInjectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            action: PromptInjectionAction::default(),
+            threshold: default_prompt_injection_threshold(),
+            tools: Vec::new(),
+            classifier_provider: crate::providers::ModelProviderRef::default(),
+            classifier_max_chars: default_prompt_injection_classifier_max_chars(),
+        }
+    }
+}
+
 /// WebAuthn / FIDO2 hardware key authentication configuration (`[security.webauthn]`).
 ///
 /// Enables registration and authentication via hardware security keys
@@ -16298,6 +17830,13 @@ pub struct DingTalkConfig {
     #[tab(Behavior)]
     #[serde(default)]
     pub excluded_tools: Vec<String>,
+
+    /// Reply format: `auto` (default) sends a markdown message when the
+    /// reply contains markdown and a plain text message otherwise;
+    /// `always` / `never` force one or the other.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub rich_text: RichTextMode,
 }
 
 impl ChannelConfig for DingTalkConfig {
@@ -16600,6 +18139,52 @@ impl ChannelConfig for MochatConfig {
     }
 }
 
+/// Zulip bot channel configuration (REST + real-time events API).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "channels.zulip"]
+pub struct ZulipConfig {
+    /// Whether this channel is active. The runtime only loads channels whose
+    /// `enabled = true`. Default: `false` so an operator who pastes a partial
+    /// `[channels.<type>.<alias>]` block doesn't accidentally bring a channel
+    /// live before the rest of its config is filled in.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub enabled: bool,
+    /// Zulip organization URL (e.g. `"https://chat.example.com"`).
+    #[tab(Connection)]
+    pub site_url: String,
+    /// Email address of the Zulip bot user. Used as the HTTP basic-auth
+    /// username and to drop the bot's own messages.
+    #[tab(Connection)]
+    pub bot_email: String,
+    /// Zulip bot API key.
+    #[secret]
+    #[tab(Connection)]
+    #[cfg_attr(feature = "schema-export", schemars(extend("x-secret" = true)))]
+    pub api_key: String,
+    /// Stream names to listen on. Empty means every stream the bot is
+    /// subscribed to. Direct messages to the bot are always received.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub streams: Vec<String>,
+
+    /// Tools excluded from this channel's tool spec. When set, these tools
+    /// are not exposed to the model when responding via this channel.
+    #[tab(Behavior)]
+    #[serde(default)]
+    pub excluded_tools: Vec<String>,
+}
+
+impl ChannelConfig for ZulipConfig {
+    fn name() -> &'static str {
+        "Zulip"
+    }
+    fn desc() -> &'static str {
+        "stream + topic bot via the events API"
+    }
+}
+
 /// Reddit channel configuration (OAuth2 bot).
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Configurable)]
 #[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
@@ -17190,6 +18775,65 @@ impl Default for JiraConfig {
     }
 }
 
+// ── Notifications ────────────────────────────────────────────────
+
+/// Push notification targets (`[notifications]` section).
+///
+/// The `notify` tool registers only when at least one target is configured;
+/// the model picks a target by name through the tool's `target` argument.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "notifications"]
+pub struct NotificationsConfig {
+    /// Named targets (`[notifications.targets.<name>]`).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[nested]
+    pub targets: HashMap<String, NotificationTargetConfig>,
+}
+
+/// Push service a notification target delivers through.
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, zeroclaw_macros::ConfigEnum,
+)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum NotificationBackend {
+    /// ntfy topic (`https://ntfy.sh/<topic>` or self-hosted).
+    #[default]
+    Ntfy,
+    /// Gotify server.
+    Gotify,
+    /// Pushover application.
+    Pushover,
+}
+
+/// One notification target (`[notifications.targets.<name>]`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Configurable)]
+#[cfg_attr(feature = "schema-export", derive(schemars::JsonSchema))]
+#[prefix = "notification_target"]
+#[serde(default)]
+pub struct NotificationTargetConfig {
+    /// Push service: `ntfy`, `gotify`, or `pushover`.
+    pub backend: NotificationBackend,
+    /// ntfy: full topic URL (`https://ntfy.sh/alerts`). gotify: server base
+    /// URL. Unused for pushover.
+    pub url: String,
+    /// ntfy access token (optional), gotify application token, or pushover
+    /// application token. Encrypted at rest.
+    #[secret]
+    #[credential_class = "encrypted_secret"]
+    #[cfg_attr(feature = "schema-export", schemars(extend("x-secret" = true)))]
+    pub token: String,
+    /// Pushover user or group key. Encrypted at rest. Unused for other
+    /// backends.
+    #[secret]
+    #[credential_class = "encrypted_secret"]
+    #[cfg_attr(feature = "schema-export", schemars(extend("x-secret" = true)))]
+    pub user_key: String,
+    /// ntfy tags added to every message from this target.
+    pub tags: Vec<String>,
+}
+
 ///
 /// Controls the read-only cloud transformation analysis tools:
 /// IaC review, migration assessment, cost analysis, and architecture review.
@@ -17472,11 +19116,16 @@ impl Default for Config {
             runtime: RuntimeConfig::default(),
             reliability: ReliabilityConfig::default(),
             scheduler: SchedulerConfig::default(),
+            tasks: TasksConfig::default(),
             eval: crate::scattered_types::EvalHarnessConfig::default(),
             pacing: PacingConfig::default(),
             skills: SkillsConfig::default(),
             pipeline: PipelineConfig::default(),
             heartbeat: HeartbeatConfig::default(),
+            health: HealthConfig::default(),
+            quiet_hours: QuietHoursConfig::default(),
+            watchers: Vec::new(),
+            approvals: ApprovalQueueConfig::default(),
             todotracker: TodoTrackerConfig::default(),
             cron: HashMap::new(),
             acp: AcpConfig::default(),
@@ -17516,12 +19165,14 @@ impl Default for Config {
             hardware: HardwareConfig::default(),
             query_classification: QueryClassificationConfig::default(),
             transcription: TranscriptionConfig::default(),
+            translation: TranslationConfig::default(),
             tts: TtsConfig::default(),
             mcp: McpConfig::default(),
             nodes: NodesConfig::default(),
             onboard_state: OnboardStateConfig::default(),
             notion: NotionConfig::default(),
             jira: JiraConfig::default(),
+            notifications: NotificationsConfig::default(),
             node_transport: NodeTransportConfig::default(),
             knowledge: KnowledgeConfig::default(),
             linkedin: LinkedInConfig::default(),
@@ -17531,6 +19182,7 @@ impl Default for Config {
             file_download: FileDownloadConfig::default(),
             plugins: PluginsConfig::default(),
             locale: None,
+            strict_config: false,
             verifiable_intent: VerifiableIntentConfig::default(),
             claude_code: ClaudeCodeConfig::default(),
             claude_code_runner: ClaudeCodeRunnerConfig::default(),
@@ -17539,7 +19191,9 @@ impl Default for Config {
             opencode_cli: OpenCodeCliConfig::default(),
             sop: SopConfig::default(),
             shell_tool: ShellToolConfig::default(),
+            git_tool: GitToolConfig::default(),
             escalation: EscalationConfig::default(),
+            updater: UpdaterConfig::default(),
         }
     }
 }
@@ -18607,17 +20261,20 @@ impl Config {
                 default_profile.ensure_default_auto_approve();
             }
 
-            // Detect unknown top-level config keys by comparing the raw
-            // TOML table keys against what Config actually deserializes.
-            // This replaces the previous serde_ignored-based approach which
-            // had false-positive issues with #[serde(default)] nested structs.
-            for key in Self::unknown_keys(&contents) {
+            // Detect unknown config keys at any depth by walking the raw
+            // TOML against the schema; keys consumed through serde aliases
+            // are filtered out (see `lint`). Findings are warnings here;
+            // `strict_config` makes the daemon refuse to start on them.
+            for issue in crate::lint::lint_config_toml(&contents)
+                .into_iter()
+                .filter(|issue| issue.kind == crate::lint::ConfigIssueKind::UnknownKey)
+            {
                 ::zeroclaw_log::record!(
                     WARN,
                     ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
                         .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                        .with_attrs(::serde_json::json!({"key": key})),
-                    "Unknown config key ignored: \"\". Check config.toml for typos or deprecated options."
+                        .with_attrs(::serde_json::json!({"key": issue.path, "line": issue.line})),
+                    "Unknown config key ignored. Check config.toml for typos or deprecated options."
                 );
             }
             // Unknown provider families are dropped by serde, so an alias
@@ -19331,6 +20988,45 @@ impl Config {
                 "gateway.host must not be empty"
             );
         }
+        if self.gateway.max_body_size_bytes == 0 {
+            validation_bail!(
+                InvalidNumericRange,
+                "gateway.max_body_size_bytes",
+                "gateway.max_body_size_bytes must be greater than 0"
+            );
+        }
+        for origin in &self.gateway.cors_allowed_origins {
+            let host = origin
+                .strip_prefix("https://")
+                .or_else(|| origin.strip_prefix("http://"));
+            let valid = origin == "*"
+                || host.is_some_and(|h| !h.is_empty() && !h.contains('/'));
+            if !valid {
+                validation_bail!(
+                    InvalidFormat,
+                    "gateway.cors_allowed_origins",
+                    "gateway.cors_allowed_origins entry {origin:?} must be \"*\" or an origin like https://app.example.com (scheme and host, no path or trailing '/')"
+                );
+            }
+        }
+        if let Some(log) = &self.gateway.access_log
+            && log.enabled
+        {
+            if log.path.trim().is_empty() {
+                validation_bail!(
+                    RequiredFieldEmpty,
+                    "gateway.access_log.path",
+                    "gateway.access_log.path must not be empty when the access log is enabled"
+                );
+            }
+            if log.max_size_mb == 0 {
+                validation_bail!(
+                    InvalidNumericRange,
+                    "gateway.access_log.max_size_mb",
+                    "gateway.access_log.max_size_mb must be greater than 0"
+                );
+            }
+        }
         if self.nodes.mdns.max_peers == 0 {
             validation_bail!(
                 InvalidNumericRange,
@@ -19443,6 +21139,105 @@ impl Config {
                 );
             }
         }
+        // File watchers
+        let mut watcher_names = std::collections::HashSet::new();
+        for (i, watcher) in self.watchers.iter().enumerate() {
+            let name = watcher.name.trim();
+            if name.is_empty() {
+                validation_bail!(
+                    RequiredFieldEmpty,
+                    format!("watchers[{i}].name"),
+                    "watchers[{i}].name must not be empty"
+                );
+            }
+            if !watcher_names.insert(name) {
+                validation_bail!(
+                    InvalidFormat,
+                    format!("watchers[{i}].name"),
+                    "watchers[{i}].name = {name:?} is used by more than one watcher"
+                );
+            }
+            if !watcher.enabled {
+                continue;
+            }
+            let agent = watcher.agent.trim();
+            if agent.is_empty() {
+                validation_bail!(
+                    RequiredFieldEmpty,
+                    format!("watchers[{i}].agent"),
+                    "watchers[{i}].agent must reference a configured agent"
+                );
+            }
+            if !self.agents.contains_key(agent) {
+                validation_bail!(
+                    DanglingReference,
+                    format!("watchers[{i}].agent"),
+                    "watchers[{i}].agent = {agent:?} but no [agents.{agent}] entry is configured"
+                );
+            }
+            if watcher.path.trim().is_empty() {
+                validation_bail!(
+                    RequiredFieldEmpty,
+                    format!("watchers[{i}].path"),
+                    "watchers[{i}].path must not be empty"
+                );
+            }
+            if watcher.prompt_template.trim().is_empty() {
+                validation_bail!(
+                    RequiredFieldEmpty,
+                    format!("watchers[{i}].prompt_template"),
+                    "watchers[{i}].prompt_template must not be empty when action = \"prompt\""
+                );
+            }
+            let has_channel = watcher
+                .channel
+                .as_deref()
+                .is_some_and(|c| !c.trim().is_empty());
+            let has_recipient = watcher
+                .recipient
+                .as_deref()
+                .is_some_and(|r| !r.trim().is_empty());
+            if has_channel && !has_recipient {
+                validation_bail!(
+                    RequiredFieldEmpty,
+                    format!("watchers[{i}].recipient"),
+                    "watchers[{i}].recipient is required when watchers[{i}].channel is set"
+                );
+            }
+        }
+        let selfheal = &self.health.selfheal;
+        if selfheal.enabled {
+            for (key, value) in [
+                ("interval_secs", selfheal.interval_secs),
+                (
+                    "listener_error_threshold_secs",
+                    selfheal.listener_error_threshold_secs,
+                ),
+                ("scheduler_stall_secs", selfheal.scheduler_stall_secs),
+            ] {
+                if value == 0 {
+                    validation_bail!(
+                        InvalidNumericRange,
+                        format!("health.selfheal.{key}"),
+                        "health.selfheal.{key} must be greater than 0 when health.selfheal.enabled = true"
+                    );
+                }
+            }
+        }
+        if !selfheal.channel.trim().is_empty() && selfheal.recipient.trim().is_empty() {
+            validation_bail!(
+                RequiredFieldEmpty,
+                "health.selfheal.recipient",
+                "health.selfheal.recipient is required when health.selfheal.channel is set"
+            );
+        }
+        if self.approvals.enabled && self.approvals.timeout_secs == 0 {
+            validation_bail!(
+                InvalidFormat,
+                "approvals.timeout_secs",
+                "approvals.timeout_secs must be greater than 0 when approvals.enabled = true"
+            );
+        }
         if let Some(ref prefix) = self.gateway.path_prefix {
             // Validate the raw value — no silent trimming so the stored
             // value is exactly what was validated.
@@ -19600,6 +21395,23 @@ impl Config {
                     );
                 }
             }
+            let network = &profile.network;
+            for (field, patterns) in [
+                ("allowed_domains", &network.allowed_domains),
+                ("blocked_domains", &network.blocked_domains),
+                ("allowed_private_hosts", &network.allowed_private_hosts),
+            ] {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if field == "allowed_private_hosts"
+                        && pattern.trim().parse::<std::net::IpAddr>().is_ok()
+                    {
+                        continue;
+                    }
+                    if let Err(e) = DomainMatcher::validate_pattern(pattern) {
+                        anyhow::bail!("risk_profiles.{profile_alias}.network.{field}[{i}]: {e}");
+                    }
+                }
+            }
         }
 
         // Security OTP / estop
@@ -19690,6 +21502,21 @@ impl Config {
                 "security.leak_detection.sensitivity must be between 0.0 and 1.0"
             );
         }
+        if !(0.0..=1.0).contains(&self.security.prompt_injection.threshold) {
+            validation_bail!(
+                InvalidNumericRange,
+                "security.prompt_injection.threshold",
+                "security.prompt_injection.threshold must be between 0.0 and 1.0"
+            );
+        }
+        let classifier = self.security.prompt_injection.classifier_provider.trim();
+        if !classifier.is_empty() && self.providers.models.find_by_name(classifier).is_none() {
+            validation_bail!(
+                DanglingReference,
+                "security.prompt_injection.classifier_provider",
+                "security.prompt_injection.classifier_provider = {classifier:?} but no providers.models entry by that name is configured"
+            );
+        }
 
         // Scheduler
         if self.scheduler.max_concurrent == 0 {
@@ -19842,6 +21669,29 @@ impl Config {
                 }
             }
 
+            if profile.connect_timeout_secs == Some(0) {
+                anyhow::bail!(
+                    "providers.models.{profile_name}.connect_timeout_secs must be greater than 0"
+                );
+            }
+            if let Some(url) = normalize_proxy_url_option(profile.proxy_url.as_deref()) {
+                validate_proxy_url(&format!("providers.models.{profile_name}.proxy_url"), &url)?;
+            }
+            if let Some(budget) = profile
+                .reasoning
+                .as_ref()
+                .and_then(|r| r.thinking_budget_tokens)
+                && !(crate::scattered_types::MIN_BUDGET_TOKENS
+                    ..=crate::scattered_types::MAX_BUDGET_TOKENS)
+                    .contains(&budget)
+            {
+                anyhow::bail!(
+                    "providers.models.{profile_name}.reasoning.thinking_budget_tokens must be between {} and {}",
+                    crate::scattered_types::MIN_BUDGET_TOKENS,
+                    crate::scattered_types::MAX_BUDGET_TOKENS
+                );
+            }
+
             if let Some(temp) = profile.temperature {
                 validate_temperature(temp).map_err(|e| {
                     ::zeroclaw_log::record!(
@@ -20322,6 +22172,54 @@ impl Config {
             }
         }
 
+        // Notification targets
+        for (name, target) in &self.notifications.targets {
+            match target.backend {
+                NotificationBackend::Ntfy | NotificationBackend::Gotify => {
+                    let url = target.url.trim();
+                    if !(url.starts_with("https://") || url.starts_with("http://")) {
+                        anyhow::bail!("notifications.targets.{name}.url must be an http(s) URL");
+                    }
+                    if target.backend == NotificationBackend::Gotify
+                        && target.token.trim().is_empty()
+                    {
+                        anyhow::bail!(
+                            "notifications.targets.{name}.token must be set to a Gotify application token"
+                        );
+                    }
+                }
+                NotificationBackend::Pushover => {
+                    if target.token.trim().is_empty() || target.user_key.trim().is_empty() {
+                        anyhow::bail!(
+                            "notifications.targets.{name} needs both token and user_key for the pushover backend"
+                        );
+                    }
+                }
+            }
+        }
+
+        // Translation
+        let translating = self
+            .channels
+            .telegram
+            .values()
+            .map(|c| c.translate.as_ref())
+            .chain(self.channels.discord.values().map(|c| c.translate.as_ref()))
+            .chain(self.channels.slack.values().map(|c| c.translate.as_ref()))
+            .any(|t| t.is_some());
+        if translating
+            && self.translation.backend == TranslationBackend::Deepl
+            && self
+                .translation
+                .deepl_api_key
+                .as_deref()
+                .is_none_or(|k| k.trim().is_empty())
+        {
+            anyhow::bail!(
+                "translation.deepl_api_key must be set when translation.backend = \"deepl\""
+            );
+        }
+
         // Nevis IAM — delegate to NevisConfig::validate() for field-level checks
         if let Err(msg) = self.security.nevis.validate() {
             anyhow::bail!("security.nevis: {msg}");
@@ -23708,6 +25606,31 @@ enabled = true
         );
     }
 
+    #[test]
+    async fn validate_requires_deepl_key_when_a_channel_translates() {
+        let mut config = Config::default();
+        config.translation.backend = TranslationBackend::Deepl;
+        config.channels.telegram.insert(
+            "default".to_string(),
+            TelegramConfig {
+                bot_token: "tok".into(),
+                translate: Some(ChannelTranslateConfig {
+                    inbound_to: "en".into(),
+                    outbound_to: "auto-detect-sender".into(),
+                }),
+                ..Default::default()
+            },
+        );
+        let err = config.validate().expect_err("missing DeepL key");
+        assert!(
+            err.to_string().contains("translation.deepl_api_key"),
+            "{err}"
+        );
+
+        config.translation.deepl_api_key = Some("key:fx".into());
+        config.validate().unwrap();
+    }
+
     #[test]
     async fn validate_rejects_zero_plugin_call_fuel() {
         let mut config = Config::default();
@@ -23721,6 +25644,48 @@ enabled = true
         );
     }
 
+    #[test]
+    async fn validate_rejects_malformed_network_domain_glob() {
+        let mut config = Config::default();
+        let mut profile = RiskProfileConfig::default();
+        profile.network.blocked_domains = vec!["bad domain".into()];
+        profile.network.allowed_private_hosts = vec!["10.0.0.5".into(), "::1".into()];
+        config.risk_profiles.insert("default".into(), profile);
+        let err = config
+            .validate()
+            .expect_err("malformed network glob must be rejected");
+        assert!(
+            err.to_string()
+                .contains("risk_profiles.default.network.blocked_domains[0]"),
+            "error must name the offending path; got: {err}"
+        );
+    }
+
+    #[test]
+    async fn validate_checks_selfheal_thresholds_and_escalation_target() {
+        let mut config = Config::default();
+        config.health.selfheal.enabled = true;
+        config.health.selfheal.scheduler_stall_secs = 0;
+        let err = config
+            .validate()
+            .expect_err("zero stall threshold must be rejected");
+        assert!(
+            err.to_string()
+                .contains("health.selfheal.scheduler_stall_secs"),
+            "error must name the offending path; got: {err}"
+        );
+
+        config.health.selfheal.scheduler_stall_secs = 600;
+        config.health.selfheal.channel = "telegram.ops".into();
+        let err = config
+            .validate()
+            .expect_err("escalation channel without recipient must be rejected");
+        assert!(
+            err.to_string().contains("health.selfheal.recipient"),
+            "error must name the offending path; got: {err}"
+        );
+    }
+
     #[test]
     async fn validate_rejects_zero_plugin_max_memory() {
         let mut config = Config::default();
@@ -24394,6 +26359,8 @@ recipient = "42"
             max_tasks: 16,
             max_concurrent: 2,
             catch_up_on_startup: false,
+            max_catchup_runs: 5,
+            max_queued_runs: 1,
             max_run_history: 100,
         };
         let json = serde_json::to_string(&s).unwrap();
@@ -24611,6 +26578,95 @@ auto_save = true
         assert_eq!(reparsed.temperature_override, Some(0.5));
     }
 
+    #[test]
+    async fn provider_transport_fields_accept_request_aliases() {
+        let toml = r#"
+            request_timeout_secs = 900
+            connect_timeout_secs = 30
+            proxy = "http://proxy.corp:3128"
+        "#;
+        let parsed: OllamaModelProviderConfig = toml::from_str(toml).unwrap();
+        assert_eq!(parsed.base.timeout_secs, Some(900));
+        assert_eq!(parsed.base.connect_timeout_secs, Some(30));
+        assert_eq!(
+            parsed.base.proxy_url.as_deref(),
+            Some("http://proxy.corp:3128")
+        );
+
+        // Serialized under the canonical names.
+        let serialized = toml::to_string(&parsed).unwrap();
+        assert!(serialized.contains("timeout_secs = 900"));
+        assert!(serialized.contains("proxy_url = "));
+    }
+
+    #[test]
+    async fn validate_rejects_bad_provider_proxy_and_zero_connect_timeout() {
+        let _env_guard = env_override_lock().await;
+        let mut config = Config::default();
+        config.providers.models.ollama.insert(
+            "local".to_string(),
+            OllamaModelProviderConfig {
+                base: ModelProviderConfig {
+                    model: Some("llama3".to_string()),
+                    proxy_url: Some("ftp://proxy.corp".to_string()),
+                    ..Default::default()
+                },
+                ..OllamaModelProviderConfig::default()
+            },
+        );
+        let error = config.validate().expect_err("ftp proxy must be rejected");
+        assert!(
+            error
+                .to_string()
+                .contains("providers.models.ollama.local.proxy_url")
+        );
+
+        let entry = config.providers.models.ollama.get_mut("local").unwrap();
+        entry.base.proxy_url = Some("socks5h://proxy.corp:1080".to_string());
+        entry.base.connect_timeout_secs = Some(0);
+        let error = config.validate().expect_err("zero connect timeout");
+        assert!(error.to_string().contains("connect_timeout_secs"));
+
+        let entry = config.providers.models.ollama.get_mut("local").unwrap();
+        entry.base.connect_timeout_secs = Some(20);
+        config.validate().expect("valid transport overrides");
+    }
+
+    #[test]
+    async fn provider_reasoning_table_parses_and_validates_budget() {
+        let _env_guard = env_override_lock().await;
+        let toml = r#"
+            model = "claude-sonnet-4-5"
+
+            [reasoning]
+            reasoning_effort = "HIGH"
+            thinking_budget_tokens = 500
+        "#;
+        let parsed: AnthropicModelProviderConfig = toml::from_str(toml).unwrap();
+        let reasoning = parsed.base.reasoning.clone().expect("reasoning table");
+        assert_eq!(reasoning.reasoning_effort.as_deref(), Some("high"));
+        assert_eq!(reasoning.thinking_budget_tokens, Some(500));
+
+        let mut config = Config::default();
+        config
+            .providers
+            .models
+            .anthropic
+            .insert("default".to_string(), parsed);
+        let error = config.validate().expect_err("budget below minimum");
+        assert!(
+            error
+                .to_string()
+                .contains("providers.models.anthropic.default.reasoning.thinking_budget_tokens")
+        );
+
+        let entry = config.providers.models.anthropic.get_mut("default");
+        if let Some(reasoning) = entry.and_then(|e| e.base.reasoning.as_mut()) {
+            reasoning.thinking_budget_tokens = Some(8_000);
+        }
+        config.validate().expect("budget within range");
+    }
+
     #[test]
     async fn ollama_alias_tuning_fields_default_to_none() {
         let toml = r#"
@@ -24793,6 +26849,7 @@ auto_save = true
             },
             reliability: ReliabilityConfig::default(),
             scheduler: SchedulerConfig::default(),
+            tasks: TasksConfig::default(),
             skills: SkillsConfig::default(),
             pipeline: PipelineConfig::default(),
             query_classification: QueryClassificationConfig::default(),
@@ -24805,6 +26862,10 @@ auto_save = true
                 to: Some("123456".into()),
                 ..HeartbeatConfig::default()
             },
+            health: HealthConfig::default(),
+            quiet_hours: QuietHoursConfig::default(),
+            watchers: Vec::new(),
+            approvals: ApprovalQueueConfig::default(),
             todotracker: TodoTrackerConfig::default(),
             cron: HashMap::new(),
             acp: AcpConfig::default(),
@@ -24821,9 +26882,11 @@ auto_save = true
                         debounce_ms: None,
                         interrupt_on_new_message: false,
                         mention_only: false,
+                        allowed_topics: Vec::new(),
                         ack_reactions: None,
                         proxy_url: None,
                         approval_timeout_secs: default_telegram_approval_timeout_secs(),
+                        translate: None,
                         excluded_tools: vec![],
                         reply_min_interval_secs: 0,
                         reply_queue_depth_max: 0,
@@ -24853,6 +26916,7 @@ auto_save = true
                 qq: HashMap::new(),
                 twitter: HashMap::new(),
                 mochat: HashMap::new(),
+                zulip: HashMap::new(),
                 nostr: HashMap::new(),
                 clawdtalk: HashMap::new(),
                 reddit: HashMap::new(),
@@ -24872,6 +26936,10 @@ auto_save = true
                 session_backend: default_session_backend(),
                 session_ttl_hours: 0,
                 debounce_ms: 0,
+                paused_mode: PausedChannelMode::default(),
+                paused_reply: default_paused_reply(),
+                outbox: ChannelOutboxConfig::default(),
+                feedback: ChannelFeedbackConfig::default(),
             },
             memory: MemoryConfig::default(),
             storage: StorageConfig::default(),
@@ -24907,12 +26975,14 @@ auto_save = true
             hooks: HooksConfig::default(),
             hardware: HardwareConfig::default(),
             transcription: TranscriptionConfig::default(),
+            translation: TranslationConfig::default(),
             tts: TtsConfig::default(),
             mcp: McpConfig::default(),
             nodes: NodesConfig::default(),
             onboard_state: OnboardStateConfig::default(),
             notion: NotionConfig::default(),
             jira: JiraConfig::default(),
+            notifications: NotificationsConfig::default(),
             node_transport: NodeTransportConfig::default(),
             knowledge: KnowledgeConfig::default(),
             linkedin: LinkedInConfig::default(),
@@ -24922,6 +26992,7 @@ auto_save = true
             file_download: FileDownloadConfig::default(),
             plugins: PluginsConfig::default(),
             locale: None,
+            strict_config: false,
             verifiable_intent: VerifiableIntentConfig::default(),
             claude_code: ClaudeCodeConfig::default(),
             claude_code_runner: ClaudeCodeRunnerConfig::default(),
@@ -24930,7 +27001,9 @@ auto_save = true
             opencode_cli: OpenCodeCliConfig::default(),
             sop: SopConfig::default(),
             shell_tool: ShellToolConfig::default(),
+            git_tool: GitToolConfig::default(),
             escalation: EscalationConfig::default(),
+            updater: UpdaterConfig::default(),
             env_overridden_paths: std::collections::HashSet::new(),
             pre_override_snapshots: std::collections::HashMap::new(),
             onepassword_reference_snapshots: std::collections::HashMap::new(),
@@ -25062,6 +27135,27 @@ auto_approve = ["file_read", "memory_recall", "http_request"]
         assert_eq!(runtime.max_actions_per_hour, 99);
     }
 
+    /// `[autonomy.network]` rides along with the rest of `[autonomy]` onto
+    /// `[risk_profiles.default.network]`.
+    #[test]
+    async fn v2_autonomy_network_migrates_onto_risk_profiles_default() {
+        let raw = r#"
+schema_version = 2
+default_temperature = 0.7
+
+[autonomy.network]
+mode = "blocklist"
+blocked_domains = ["*.tracker.example"]
+"#;
+        let parsed = crate::migration::migrate_to_current(raw).unwrap();
+        let profile = parsed
+            .risk_profiles
+            .get("default")
+            .expect("default profile");
+        assert_eq!(profile.network.mode, NetworkMode::Blocklist);
+        assert_eq!(profile.network.blocked_domains, vec!["*.tracker.example"]);
+    }
+
     /// Regression test for: when a user provides a custom auto_approve
     /// list, the built-in defaults must still be present.
     #[test]
@@ -25736,10 +27830,15 @@ default_temperature = 0.7
             runtime: RuntimeConfig::default(),
             reliability: ReliabilityConfig::default(),
             scheduler: SchedulerConfig::default(),
+            tasks: TasksConfig::default(),
             skills: SkillsConfig::default(),
             pipeline: PipelineConfig::default(),
             query_classification: QueryClassificationConfig::default(),
             heartbeat: HeartbeatConfig::default(),
+            health: HealthConfig::default(),
+            quiet_hours: QuietHoursConfig::default(),
+            watchers: Vec::new(),
+            approvals: ApprovalQueueConfig::default(),
             todotracker: TodoTrackerConfig::default(),
             cron: HashMap::new(),
             acp: AcpConfig::default(),
@@ -25779,12 +27878,14 @@ default_temperature = 0.7
             hooks: HooksConfig::default(),
             hardware: HardwareConfig::default(),
             transcription: TranscriptionConfig::default(),
+            translation: TranslationConfig::default(),
             tts: TtsConfig::default(),
             mcp: McpConfig::default(),
             nodes: NodesConfig::default(),
             onboard_state: OnboardStateConfig::default(),
             notion: NotionConfig::default(),
             jira: JiraConfig::default(),
+            notifications: NotificationsConfig::default(),
             node_transport: NodeTransportConfig::default(),
             knowledge: KnowledgeConfig::default(),
             linkedin: LinkedInConfig::default(),
@@ -25794,6 +27895,7 @@ default_temperature = 0.7
             file_download: FileDownloadConfig::default(),
             plugins: PluginsConfig::default(),
             locale: None,
+            strict_config: false,
             verifiable_intent: VerifiableIntentConfig::default(),
             claude_code: ClaudeCodeConfig::default(),
             claude_code_runner: ClaudeCodeRunnerConfig::default(),
@@ -25802,7 +27904,9 @@ default_temperature = 0.7
             opencode_cli: OpenCodeCliConfig::default(),
             sop: SopConfig::default(),
             shell_tool: ShellToolConfig::default(),
+            git_tool: GitToolConfig::default(),
             escalation: EscalationConfig::default(),
+            updater: UpdaterConfig::default(),
             env_overridden_paths: std::collections::HashSet::new(),
             pre_override_snapshots: std::collections::HashMap::new(),
             onepassword_reference_snapshots: std::collections::HashMap::new(),
@@ -25922,6 +28026,7 @@ default_temperature = 0.7
                 ack_reactions: None,
                 stream_mode: StreamMode::default(),
                 draft_update_interval_ms: default_draft_update_interval_ms(),
+                rich_text: Default::default(),
             },
         );
 
@@ -26267,9 +28372,11 @@ default_temperature = 0.7
             draft_update_interval_ms: 500,
             interrupt_on_new_message: true,
             mention_only: false,
+            allowed_topics: Vec::new(),
             ack_reactions: None,
             proxy_url: None,
             approval_timeout_secs: 120,
+            translate: None,
             excluded_tools: vec![],
             reply_min_interval_secs: 0,
             reply_queue_depth_max: 0,
@@ -26303,6 +28410,9 @@ default_temperature = 0.7
             archive: false,
             listen_to_bots: false,
             interrupt_on_new_message: false,
+            respond_to_edits: false,
+            edit_window_secs: 300,
+            reply_context: false,
             mention_only: false,
             slash_commands: false,
             slash_command_scope: SlashCommandScope::default(),
@@ -26314,6 +28424,7 @@ default_temperature = 0.7
             intents_mask: None,
             reaction_notifications: DiscordReactionScope::Off,
             approval_timeout_secs: 300,
+            translate: None,
             excluded_tools: vec![],
             reply_min_interval_secs: 0,
             reply_queue_depth_max: 0,
@@ -26334,6 +28445,9 @@ default_temperature = 0.7
             archive: false,
             listen_to_bots: false,
             interrupt_on_new_message: false,
+            respond_to_edits: false,
+            edit_window_secs: 300,
+            reply_context: false,
             mention_only: false,
             slash_commands: false,
             slash_command_scope: SlashCommandScope::default(),
@@ -26345,6 +28459,7 @@ default_temperature = 0.7
             intents_mask: None,
             reaction_notifications: DiscordReactionScope::Off,
             approval_timeout_secs: 300,
+            translate: None,
             excluded_tools: vec![],
             reply_min_interval_secs: 0,
             reply_queue_depth_max: 0,
@@ -26485,6 +28600,7 @@ allowed_users = ["@u:matrix.org"]
             account: "+1234567890".into(),
             group_ids: vec!["group123".into()],
             dm_only: false,
+            mention_only: false,
             ignore_attachments: true,
             ignore_stories: false,
             proxy_url: None,
@@ -26511,6 +28627,7 @@ allowed_users = ["@u:matrix.org"]
             account: "+9876543210".into(),
             group_ids: Vec::new(),
             dm_only: true,
+            mention_only: false,
             ignore_attachments: false,
             ignore_stories: true,
             proxy_url: None,
@@ -26534,6 +28651,7 @@ allowed_users = ["@u:matrix.org"]
         let parsed: SignalConfig = serde_json::from_str(json).unwrap();
         assert!(parsed.group_ids.is_empty());
         assert!(!parsed.dm_only);
+        assert!(!parsed.mention_only);
         assert!(!parsed.ignore_attachments);
         assert!(!parsed.ignore_stories);
     }
@@ -26552,6 +28670,7 @@ allowed_users = ["@u:matrix.org"]
                 IMessageConfig {
                     enabled: true,
                     excluded_tools: vec![],
+                    group_ids: vec![],
                     reply_min_interval_secs: 0,
                     reply_queue_depth_max: 0,
                 },
@@ -26598,6 +28717,7 @@ allowed_users = ["@u:matrix.org"]
             qq: HashMap::new(),
             twitter: HashMap::new(),
             mochat: HashMap::new(),
+            zulip: HashMap::new(),
             nostr: HashMap::new(),
             clawdtalk: HashMap::new(),
             reddit: HashMap::new(),
@@ -26617,6 +28737,10 @@ allowed_users = ["@u:matrix.org"]
             session_backend: default_session_backend(),
             session_ttl_hours: 0,
             debounce_ms: 0,
+            paused_mode: PausedChannelMode::default(),
+            paused_reply: default_paused_reply(),
+            outbox: ChannelOutboxConfig::default(),
+            feedback: ChannelFeedbackConfig::default(),
         };
         let toml_str = toml::to_string_pretty(&c).unwrap();
         let parsed: ChannelsConfig = toml::from_str(&toml_str).unwrap();
@@ -26835,6 +28959,8 @@ bot_token = "xoxb-tok"
             send_method: None,
             auth_header: None,
             secret: None,
+            template: None,
+            sync_response: false,
             excluded_tools: vec![],
             reply_min_interval_secs: 0,
             reply_queue_depth_max: 0,
@@ -27120,6 +29246,7 @@ allowed_numbers = ["+1", "+2"]
             qq: HashMap::new(),
             twitter: HashMap::new(),
             mochat: HashMap::new(),
+            zulip: HashMap::new(),
             nostr: HashMap::new(),
             clawdtalk: HashMap::new(),
             reddit: HashMap::new(),
@@ -27139,6 +29266,10 @@ allowed_numbers = ["+1", "+2"]
             session_backend: default_session_backend(),
             session_ttl_hours: 0,
             debounce_ms: 0,
+            paused_mode: PausedChannelMode::default(),
+            paused_reply: default_paused_reply(),
+            outbox: ChannelOutboxConfig::default(),
+            feedback: ChannelFeedbackConfig::default(),
         };
         let toml_str = toml::to_string_pretty(&c).unwrap();
         let parsed: ChannelsConfig = toml::from_str(&toml_str).unwrap();
@@ -27216,6 +29347,7 @@ allowed_numbers = ["+1", "+2"]
             require_pairing: true,
             allow_public_bind: false,
             allow_remote_admin: false,
+            admin_api_key: None,
             paired_tokens: vec!["zc_test_token".into()],
             pair_rate_limit_per_minute: 12,
             webhook_rate_limit_per_minute: 80,
@@ -27233,6 +29365,10 @@ allowed_numbers = ["+1", "+2"]
             long_running_request_timeout_secs: 600,
             check_updates: true,
             allow_self_upgrade: false,
+            health_error_threshold_secs: 300,
+            cors_allowed_origins: vec!["https://app.example.com".into()],
+            max_body_size_bytes: 1_048_576,
+            access_log: None,
         };
         let toml_str = toml::to_string(&g).unwrap();
         let parsed: GatewayConfig = toml::from_str(&toml_str).unwrap();
@@ -27250,6 +29386,42 @@ allowed_numbers = ["+1", "+2"]
         assert_eq!(parsed.idempotency_max_keys, 4096);
         assert!(parsed.check_updates);
         assert!(!parsed.allow_self_upgrade);
+        assert_eq!(parsed.cors_allowed_origins, vec!["https://app.example.com"]);
+        assert_eq!(parsed.max_body_size_bytes, 1_048_576);
+    }
+
+    #[test]
+    async fn gateway_cors_body_limit_and_access_log_validate() {
+        let _env_guard = env_override_lock().await;
+        let parsed: GatewayConfig = toml::from_str(
+            r#"
+            cors_allowed_origins = ["https://app.example.com", "*"]
+
+            [access_log]
+            enabled = true
+        "#,
+        )
+        .unwrap();
+        assert_eq!(parsed.max_body_size_bytes, 65_536);
+        let access_log = parsed.access_log.clone().expect("access_log table");
+        assert_eq!(access_log.path, "logs/gateway-access.jsonl");
+        assert_eq!(access_log.max_size_mb, 10);
+        assert_eq!(access_log.max_files, 5);
+
+        let mut config = Config {
+            gateway: parsed,
+            ..Config::default()
+        };
+        config.validate().expect("valid gateway config");
+
+        config.gateway.cors_allowed_origins = vec!["https://app.example.com/".into()];
+        let error = config.validate().expect_err("origin with trailing slash");
+        assert!(error.to_string().contains("gateway.cors_allowed_origins"));
+        config.gateway.cors_allowed_origins.clear();
+
+        config.gateway.max_body_size_bytes = 0;
+        let error = config.validate().expect_err("zero body limit");
+        assert!(error.to_string().contains("gateway.max_body_size_bytes"));
     }
 
     #[test]
@@ -27325,6 +29497,7 @@ default_temperature = 0.7
             enabled: true,
             api_key: Some("comp-key-123".into()),
             entity_id: "user42".into(),
+            toolkits: vec!["github".into()],
         };
         let toml_str = toml::to_string(&c).unwrap();
         let parsed: ComposioConfig = toml::from_str(&toml_str).unwrap();
@@ -27451,6 +29624,7 @@ default_temperature = 0.7
                 max_coordinate_y: Some(2160),
             },
             allowed_private_hosts: vec![],
+            credential_domains: vec!["intranet.example.com".into()],
         };
         let toml_str = toml::to_string(&b).unwrap();
         let parsed: BrowserConfig = toml::from_str(&toml_str).unwrap();
@@ -27475,6 +29649,7 @@ default_temperature = 0.7
         assert_eq!(parsed.computer_use.window_allowlist.len(), 2);
         assert_eq!(parsed.computer_use.max_coordinate_x, Some(3840));
         assert_eq!(parsed.computer_use.max_coordinate_y, Some(2160));
+        assert_eq!(parsed.credential_domains, vec!["intranet.example.com"]);
     }
 
     #[test]
@@ -28332,6 +30507,7 @@ default_model = "legacy-model"
                 ack_reactions: None,
                 stream_mode: StreamMode::default(),
                 draft_update_interval_ms: default_draft_update_interval_ms(),
+                rich_text: Default::default(),
             },
         );
         config.save().await.unwrap();
@@ -29201,6 +31377,7 @@ api_token = "tok"
                 transport: "serial".into(),
                 path: Some("/dev/ttyACM0".into()),
                 baud: 115_200,
+                name: None,
             }],
             datasheet_dir: None,
         };
@@ -29212,6 +31389,21 @@ api_token = "tok"
         assert_eq!(parsed.boards[0].path.as_deref(), Some("/dev/ttyACM0"));
     }
 
+    #[test]
+    async fn peripheral_session_name_defaults_to_board_and_path() {
+        let mut board = PeripheralBoardConfig {
+            board: "nucleo-f401re".into(),
+            path: Some("/dev/ttyACM0".into()),
+            ..PeripheralBoardConfig::default()
+        };
+        assert_eq!(board.session_name(), "nucleo-f401re:/dev/ttyACM0");
+        board.name = Some(" bench ".into());
+        assert_eq!(board.session_name(), "bench");
+        board.name = None;
+        board.path = None;
+        assert_eq!(board.session_name(), "nucleo-f401re");
+    }
+
     #[test]
     async fn lark_config_serde() {
         let lc = LarkConfig {
@@ -29231,6 +31423,7 @@ api_token = "tok"
             ack_reactions: None,
             stream_mode: StreamMode::default(),
             draft_update_interval_ms: default_draft_update_interval_ms(),
+            rich_text: Default::default(),
         };
         let json = serde_json::to_string(&lc).unwrap();
         let parsed: LarkConfig = serde_json::from_str(&json).unwrap();
@@ -29260,6 +31453,7 @@ api_token = "tok"
             ack_reactions: None,
             stream_mode: StreamMode::default(),
             draft_update_interval_ms: default_draft_update_interval_ms(),
+            rich_text: Default::default(),
         };
         let toml_str = toml::to_string(&lc).unwrap();
         let parsed: LarkConfig = toml::from_str(&toml_str).unwrap();
@@ -29288,6 +31482,17 @@ api_token = "tok"
         );
     }
 
+    #[test]
+    async fn lark_config_accepts_connection_mode_alias() {
+        let toml_str = r#"
+app_id = "cli_123"
+app_secret = "secret"
+connection_mode = "webhook"
+"#;
+        let parsed: LarkConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(parsed.receive_mode, LarkReceiveMode::Webhook);
+    }
+
     #[test]
     async fn lark_v2_allowed_users_fold_into_peer_groups() {
         // V2 `allowed_users` on a Lark channel migrates to a synthesized
@@ -30836,9 +33041,11 @@ high_entropy_tokens = false
                 draft_update_interval_ms: default_draft_update_interval_ms(),
                 interrupt_on_new_message: false,
                 mention_only: false,
+                allowed_topics: Vec::new(),
                 ack_reactions: None,
                 proxy_url: None,
                 approval_timeout_secs: default_telegram_approval_timeout_secs(),
+                translate: None,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -35039,6 +37246,51 @@ allowed_users = []
         );
     }
 
+    #[tokio::test]
+    async fn config_validate_checks_watchers() {
+        let base = r#"
+            [providers.models.custom.default]
+            api_key = "k"
+            model = "qwen3.6-plus"
+            uri = "https://example.com/v1"
+            wire_api = "chat_completions"
+
+            [risk_profiles.default]
+            level = "supervised"
+
+            [agents.default]
+            enabled = true
+            model_provider = "custom.default"
+            risk_profile = "default"
+
+            [[watchers]]
+            name = "inbox"
+            path = "inbox"
+            glob = "*.md"
+            prompt_template = "Summarise {path}"
+        "#;
+        let valid: Config = toml::from_str(&format!("{base}agent = \"default\"\n")).unwrap();
+        valid
+            .validate()
+            .expect("watcher with a configured agent is valid");
+        assert_eq!(valid.watchers[0].debounce_ms, 2000);
+        assert_eq!(valid.watchers[0].action, WatcherAction::Prompt);
+
+        let dangling: Config = toml::from_str(&format!("{base}agent = \"ghost\"\n")).unwrap();
+        let msg = format!("{:#}", dangling.validate().expect_err("unknown agent"));
+        assert!(msg.contains("watchers[0].agent"), "{msg}");
+
+        let no_recipient: Config = toml::from_str(&format!(
+            "{base}agent = \"default\"\nchannel = \"telegram\"\n"
+        ))
+        .unwrap();
+        let msg = format!(
+            "{:#}",
+            no_recipient.validate().expect_err("missing recipient")
+        );
+        assert!(msg.contains("watchers[0].recipient"), "{msg}");
+    }
+
     // profile-level summary_provider validated by the new profile loop.
     #[tokio::test]
     async fn config_validate_rejects_profile_summary_provider_missing_alias() {
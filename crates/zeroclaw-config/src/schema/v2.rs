@@ -92,6 +92,7 @@ pub const V3_CHANNEL_TYPES: &[&str] = &[
     "qq",
     "twitter",
     "mochat",
+    "zulip",
     "nostr",
     "clawdtalk",
     "reddit",
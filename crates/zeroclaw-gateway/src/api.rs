@@ -761,6 +761,8 @@ pub async fn handle_api_cron_settings_get(
     Json(serde_json::json!({
         "enabled": config.scheduler.enabled,
         "catch_up_on_startup": config.scheduler.catch_up_on_startup,
+        "max_catchup_runs": config.scheduler.max_catchup_runs,
+        "max_queued_runs": config.scheduler.max_queued_runs,
         "max_run_history": config.scheduler.max_run_history,
     }))
     .into_response()
@@ -786,6 +788,14 @@ pub async fn handle_api_cron_settings_patch(
         config.scheduler.catch_up_on_startup = v;
         config.mark_dirty("scheduler.catch-up-on-startup");
     }
+    if let Some(v) = body.get("max_catchup_runs").and_then(|v| v.as_u64()) {
+        config.scheduler.max_catchup_runs = u32::try_from(v).unwrap_or(u32::MAX);
+        config.mark_dirty("scheduler.max-catchup-runs");
+    }
+    if let Some(v) = body.get("max_queued_runs").and_then(|v| v.as_u64()) {
+        config.scheduler.max_queued_runs = u32::try_from(v).unwrap_or(u32::MAX);
+        config.mark_dirty("scheduler.max-queued-runs");
+    }
     if let Some(v) = body.get("max_run_history").and_then(|v| v.as_u64()) {
         config.scheduler.max_run_history = u32::try_from(v).unwrap_or(u32::MAX);
         config.mark_dirty("scheduler.max-run-history");
@@ -805,6 +815,8 @@ pub async fn handle_api_cron_settings_patch(
         "status": "ok",
         "enabled": config.scheduler.enabled,
         "catch_up_on_startup": config.scheduler.catch_up_on_startup,
+        "max_catchup_runs": config.scheduler.max_catchup_runs,
+        "max_queued_runs": config.scheduler.max_queued_runs,
         "max_run_history": config.scheduler.max_run_history,
     }))
     .into_response()
@@ -1189,6 +1201,12 @@ pub async fn handle_api_channels(
 
     let config = state.config.read().clone();
     let health = zeroclaw_runtime::health::snapshot();
+    let sessions = state
+        .session_backend
+        .as_ref()
+        .map(|backend| backend.list_sessions_with_metadata())
+        .unwrap_or_default();
+    let mut activity = crate::api_dashboard::channel_activity(&sessions);
     // One entry per `[channels.<type>.<alias>]` block. Owning
     // agent comes from the agents.<alias>.channels reverse lookup.
     let channels: Vec<serde_json::Value> = config
@@ -1196,14 +1214,9 @@ pub async fn handle_api_channels(
         .into_iter()
         .map(|info| {
             let composite = format!("{}.{}", info.channel_type, info.alias);
-            let compiled_key = compiled_readiness_key_for_alias(&config, &info);
-            let compiled = zeroclaw_channels::listing::is_channel_type_compiled(compiled_key);
-            let readiness = channel_readiness(&config, &info, &health, &state);
-            let (status, health_status) = if compiled {
-                channel_readiness_summary(&readiness)
-            } else {
-                ("not_compiled", "unavailable")
-            };
+            let (compiled, readiness, status, health_status) =
+                channel_status(&config, &info, &health, &state);
+            let counters = activity.remove(&composite).unwrap_or_default();
             serde_json::json!({
                 "name": composite,
                 "type": info.channel_type,
@@ -1212,8 +1225,8 @@ pub async fn handle_api_channels(
                 "enabled": info.enabled,
                 "compiled": compiled,
                 "status": status,
-                "message_count": 0,
-                "last_message_at": null,
+                "message_count": counters.message_count,
+                "last_message_at": counters.last_message_at,
                 "health": health_status,
                 "readiness": readiness,
             })
@@ -1383,7 +1396,7 @@ enum ChannelReadinessState {
 const CHANNEL_LISTENER_HEALTH_MAX_AGE_SECS: i64 = 30;
 
 #[derive(Debug, Clone, Serialize)]
-struct ChannelReadiness {
+pub(crate) struct ChannelReadiness {
     enabled: ChannelReadinessState,
     bound_to_agent: ChannelReadinessState,
     authenticated: ChannelReadinessState,
@@ -1481,6 +1494,25 @@ fn apply_persisted_login_readiness(
     }
 }
 
+/// Whether the channel type is compiled in, its readiness breakdown, and the
+/// `(status, health)` labels `/api/channels` and `/api/dashboard` report.
+pub(crate) fn channel_status(
+    config: &Config,
+    info: &ChannelAliasInfo,
+    health: &zeroclaw_runtime::health::HealthSnapshot,
+    state: &AppState,
+) -> (bool, ChannelReadiness, &'static str, &'static str) {
+    let compiled_key = compiled_readiness_key_for_alias(config, info);
+    let compiled = zeroclaw_channels::listing::is_channel_type_compiled(compiled_key);
+    let readiness = channel_readiness(config, info, health, state);
+    let (status, health_status) = if compiled {
+        channel_readiness_summary(&readiness)
+    } else {
+        ("not_compiled", "unavailable")
+    };
+    (compiled, readiness, status, health_status)
+}
+
 fn channel_readiness_summary(readiness: &ChannelReadiness) -> (&'static str, &'static str) {
     if readiness.enabled == ChannelReadinessState::Missing
         || readiness.bound_to_agent == ChannelReadinessState::Missing
@@ -1692,6 +1724,7 @@ pub async fn handle_api_session_messages(
                 "role": m.message.role,
                 "content": m.message.content,
                 "created_at": m.created_at.map(|dt| dt.to_rfc3339()),
+                "delivery_id": m.delivery_id,
             })
         })
         .collect();
@@ -3055,9 +3088,12 @@ pub(crate) mod tests {
                     updated_at: old,
                     last_ok: None,
                     last_error: None,
+                    error_since: None,
                     restart_count: 0,
+                    last_selfheal_at: None,
                 },
             )]),
+            channels: Default::default(),
         };
         let state = test_state(config.clone());
         let info = first_channel_info(&config);
@@ -0,0 +1,151 @@
+//! Out-of-band surface for the `[approvals]` tool-call queue.
+//!
+//! `GET /admin/approvals`, `POST /admin/approvals/approve`,
+//! `POST /admin/approvals/deny`. Auth and the transport-bound principal come
+//! from the SOP approval gate (`api_sop::authorize`). The optional `operator`
+//! body field (the CLI sends the OS user) is recorded next to the principal
+//! but never trusted for authorization. Resolving wakes the waiting tool call
+//! when this process runs the daemon's queue.
+
+use std::net::SocketAddr;
+
+use axum::Json;
+use axum::extract::{ConnectInfo, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use serde::Deserialize;
+
+use crate::AppState;
+use crate::api_sop::authorize;
+use zeroclaw_runtime::approval::queue::{self, ApprovalDecision, ResolveOutcome};
+use zeroclaw_runtime::sop::approval::ApprovalPrincipal;
+
+type JsonErr = (StatusCode, Json<serde_json::Value>);
+
+/// Body for approve/deny.
+#[derive(Deserialize)]
+pub struct ApprovalResolveBody {
+    id: String,
+    #[serde(default)]
+    reason: Option<String>,
+    /// Self-reported operator name, recorded for the audit trail.
+    #[serde(default)]
+    operator: Option<String>,
+}
+
+fn internal(e: anyhow::Error) -> JsonErr {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(serde_json::json!({ "error": format!("{e:#}") })),
+    )
+}
+
+/// `cli`, `http:<token-hash>`, … plus the claimed operator, if any.
+fn decided_by(principal: &ApprovalPrincipal, operator: Option<&str>) -> String {
+    let mut label = principal.source_label().to_string();
+    if let Some(identity) = &principal.identity {
+        label = format!("{label}:{identity}");
+    }
+    match operator.map(str::trim).filter(|o| !o.is_empty()) {
+        Some(operator) => format!("{label} ({operator})"),
+        None => label,
+    }
+}
+
+/// Map a queue `ResolveOutcome` to its HTTP status + wire label. Pure.
+fn outcome_response(outcome: &ResolveOutcome) -> (StatusCode, &'static str) {
+    match outcome {
+        ResolveOutcome::Resolved(_) => (StatusCode::OK, "resolved"),
+        ResolveOutcome::AlreadyResolved(_) => (StatusCode::CONFLICT, "already_resolved"),
+        ResolveOutcome::NotFound => (StatusCode::NOT_FOUND, "not_found"),
+    }
+}
+
+/// GET /admin/approvals - tool calls waiting for an operator.
+pub async fn handle_approvals_pending(
+    State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, JsonErr> {
+    authorize(&state, &peer, &headers)?;
+    let config = state.config.read().clone();
+    let pending = queue::list_approvals(&config, true, 100).map_err(internal)?;
+    Ok((
+        StatusCode::OK,
+        Json(serde_json::json!({ "pending": pending })),
+    ))
+}
+
+/// POST /admin/approvals/approve - let a queued tool call run.
+pub async fn handle_approvals_approve(
+    State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Json(body): Json<ApprovalResolveBody>,
+) -> Result<impl IntoResponse, JsonErr> {
+    let principal = authorize(&state, &peer, &headers)?;
+    resolve(&state, &peer, principal, body, true)
+}
+
+/// POST /admin/approvals/deny - deny a queued tool call.
+pub async fn handle_approvals_deny(
+    State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Json(body): Json<ApprovalResolveBody>,
+) -> Result<impl IntoResponse, JsonErr> {
+    let principal = authorize(&state, &peer, &headers)?;
+    resolve(&state, &peer, principal, body, false)
+}
+
+fn resolve(
+    state: &AppState,
+    peer: &SocketAddr,
+    principal: ApprovalPrincipal,
+    body: ApprovalResolveBody,
+    approved: bool,
+) -> Result<(StatusCode, Json<serde_json::Value>), JsonErr> {
+    let config = state.config.read().clone();
+    let decision = ApprovalDecision {
+        approved,
+        decided_by: decided_by(&principal, body.operator.as_deref()),
+        decided_via: format!("gateway {}", peer.ip()),
+        reason: body.reason.filter(|r| !r.trim().is_empty()),
+    };
+    let outcome = queue::resolve(&config, &body.id, decision).map_err(internal)?;
+    let (code, label) = outcome_response(&outcome);
+    let status = match &outcome {
+        ResolveOutcome::Resolved(a) | ResolveOutcome::AlreadyResolved(a) => Some(a.status.as_str()),
+        ResolveOutcome::NotFound => None,
+    };
+    Ok((
+        code,
+        Json(serde_json::json!({ "outcome": label, "id": body.id, "status": status })),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decided_by_combines_principal_and_claimed_operator() {
+        assert_eq!(
+            decided_by(&ApprovalPrincipal::cli(None), Some("alice")),
+            "cli (alice)"
+        );
+        assert_eq!(
+            decided_by(&ApprovalPrincipal::http(Some("ab12".into())), None),
+            "http:ab12"
+        );
+        assert_eq!(decided_by(&ApprovalPrincipal::cli(None), Some("  ")), "cli");
+    }
+
+    #[test]
+    fn outcome_response_maps_status_codes() {
+        assert_eq!(
+            outcome_response(&ResolveOutcome::NotFound),
+            (StatusCode::NOT_FOUND, "not_found")
+        );
+    }
+}
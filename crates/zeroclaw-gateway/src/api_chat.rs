@@ -0,0 +1,397 @@
+//! `POST /api/chat` — streaming chat over server-sent events.
+//!
+//! A lighter alternative to `/ws/chat` for custom web frontends: one request
+//! runs one agent turn (memory context, tool loop) and streams `delta`,
+//! `tool_call`, and a final `done` (or `error` / `aborted`) event. Sessions
+//! share the `gw_<session_id>` history and cancel-token keys with the
+//! WebSocket chat, so `POST /api/chat/{session_id}/abort` (an alias of
+//! `/api/sessions/{id}/abort`) interrupts a running stream the same way.
+
+use super::AppState;
+use crate::api::require_auth;
+use crate::ws::{GW_SESSION_PREFIX, persist_conversation_messages, resolve_ws_session_cwd};
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use serde::Deserialize;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use zeroclaw_runtime::agent::TurnEvent;
+
+/// Request body for `POST /api/chat`.
+#[derive(Debug, Deserialize)]
+pub struct ChatRequest {
+    /// Client-chosen session id. Omit to start a new session; the generated
+    /// id is returned in the `session` event.
+    #[serde(default)]
+    pub session_id: Option<String>,
+    pub message: String,
+    /// Configured agent alias. Defaults to the gateway's runtime agent.
+    #[serde(default, alias = "agent_alias")]
+    pub agent: Option<String>,
+}
+
+fn json_error(status: StatusCode, message: impl Into<String>) -> Response {
+    (status, Json(serde_json::json!({ "error": message.into() }))).into_response()
+}
+
+fn sse_event(name: &str, data: serde_json::Value) -> Result<Event, Infallible> {
+    Ok(Event::default().event(name).data(data.to_string()))
+}
+
+/// Map one runtime turn event to its SSE frame. `None` for events the SSE
+/// contract does not expose (thinking, plans, approval prompts).
+fn turn_event_frame(event: &TurnEvent) -> Option<(&'static str, serde_json::Value)> {
+    match event {
+        TurnEvent::Chunk { delta } => Some(("delta", serde_json::json!({ "content": delta }))),
+        TurnEvent::ToolCall { id, name, .. } => Some((
+            "tool_call",
+            serde_json::json!({ "id": id, "name": name, "status": "running" }),
+        )),
+        TurnEvent::ToolResult { id, name, .. } => Some((
+            "tool_call",
+            serde_json::json!({ "id": id, "name": name, "status": "done" }),
+        )),
+        _ => None,
+    }
+}
+
+/// POST /api/chat — run one agent turn and stream it as server-sent events.
+pub async fn handle_api_chat(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<ChatRequest>,
+) -> Response {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let message = body.message.trim().to_string();
+    if message.is_empty() {
+        return json_error(StatusCode::BAD_REQUEST, "message is required");
+    }
+
+    let config = state.config.read().clone();
+    let Some(agent_alias) = crate::resolve_gateway_chat_agent_alias(&config, body.agent.as_deref())
+    else {
+        return json_error(
+            StatusCode::BAD_REQUEST,
+            "No agent configured — pass `agent` matching a configured [agents.<alias>] entry",
+        );
+    };
+    if config.agent(&agent_alias).is_none() {
+        return json_error(
+            StatusCode::BAD_REQUEST,
+            format!("Unknown agent `{agent_alias}` — no [agents.{agent_alias}] entry configured."),
+        );
+    }
+    if let Some(err) = crate::needs_quickstart_for(&state.model) {
+        return json_error(StatusCode::SERVICE_UNAVAILABLE, err.to_string());
+    }
+
+    let session_id = body
+        .session_id
+        .filter(|id| !id.trim().is_empty())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let session_key = format!("{GW_SESSION_PREFIX}{session_id}");
+
+    let session_guard = match state.session_queue.acquire(&session_key).await {
+        Ok(guard) => guard,
+        Err(crate::session_queue::SessionQueueError::QueueFull { .. }) => {
+            return json_error(StatusCode::TOO_MANY_REQUESTS, "Session queue is full");
+        }
+        Err(crate::session_queue::SessionQueueError::Timeout { .. }) => {
+            return json_error(
+                StatusCode::REQUEST_TIMEOUT,
+                "Timed out waiting for session queue",
+            );
+        }
+    };
+
+    let session_cwd = match resolve_ws_session_cwd(None, &config, &agent_alias) {
+        Ok(cwd) => cwd,
+        Err(e) => return json_error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    };
+    let mut agent =
+        match zeroclaw_runtime::agent::Agent::from_live_config_with_session_cwd_and_mcp_backchannel(
+            Arc::clone(&state.config),
+            &agent_alias,
+            Some(&session_cwd),
+            true,
+            false,
+            state.sop_engine.clone(),
+            state.sop_audit.clone(),
+            Some(state.canvas_store.clone()),
+        )
+        .await
+        {
+            Ok(agent) => agent,
+            Err(e) => {
+                return json_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Failed to initialise agent: {e}"),
+                );
+            }
+        };
+    agent.set_channel_name("api_chat".to_string());
+    agent.set_memory_session_id(Some(zeroclaw_api::session_keys::sanitize_session_key(
+        &session_id,
+    )));
+    if let Some(ref backend) = state.session_backend {
+        let stored = backend.load(&session_key);
+        if !stored.is_empty() {
+            let _ = agent.seed_history_with_event(&stored);
+        }
+        let _ = backend.set_session_agent_alias(&session_key, &agent_alias);
+    }
+
+    let (frame_tx, frame_rx) = mpsc::channel::<Result<Event, Infallible>>(64);
+    let _ = frame_tx
+        .send(sse_event(
+            "session",
+            serde_json::json!({ "session_id": session_id, "agent": agent_alias }),
+        ))
+        .await;
+
+    zeroclaw_spawn::spawn!(async move {
+        let _session_guard = session_guard;
+        run_chat_turn(state, agent, message, session_key, frame_tx).await;
+    });
+
+    Sse::new(ReceiverStream::new(frame_rx))
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// Drive one turn, forwarding its events to the SSE stream. A closed stream
+/// (client went away) cancels the turn through the shared cancel token.
+async fn run_chat_turn(
+    state: AppState,
+    mut agent: zeroclaw_runtime::agent::Agent,
+    message: String,
+    session_key: String,
+    frame_tx: mpsc::Sender<Result<Event, Infallible>>,
+) {
+    let (turn_alias, turn_provider, turn_model) = agent.attribution_fields();
+    let cost_tracking_context = state.cost_tracker.as_ref().map(|tracker| {
+        let pricing =
+            zeroclaw_runtime::agent::cost::build_model_provider_pricing(&state.config.read());
+        zeroclaw_runtime::agent::cost::ToolLoopCostTrackingContext::new(
+            tracker.clone(),
+            Arc::new(pricing),
+        )
+        .with_agent_alias(&turn_alias)
+    });
+
+    let turn_id = uuid::Uuid::new_v4().to_string();
+    if let Some(ref backend) = state.session_backend {
+        let _ = backend.set_session_state(&session_key, "running", Some(&turn_id));
+    }
+    let cancel_token = tokio_util::sync::CancellationToken::new();
+    state
+        .cancel_tokens
+        .lock()
+        .expect("cancel_tokens lock poisoned")
+        .insert(session_key.clone(), cancel_token.clone());
+
+    let (event_tx, mut event_rx) = mpsc::channel::<TurnEvent>(64);
+    let turn_fut = zeroclaw_runtime::agent::loop_::scope_session_key(
+        Some(session_key.clone()),
+        zeroclaw_runtime::agent::cost::TOOL_LOOP_COST_TRACKING_CONTEXT.scope(
+            cost_tracking_context,
+            agent.turn_streamed_with_steering_state(
+                &message,
+                event_tx,
+                Some(cancel_token.clone()),
+                None,
+            ),
+        ),
+    );
+
+    let mut input_tokens: Option<u64> = None;
+    let mut output_tokens: Option<u64> = None;
+    let forward_fut = async {
+        while let Some(event) = event_rx.recv().await {
+            if let TurnEvent::Usage {
+                input_tokens: it,
+                output_tokens: ot,
+                ..
+            } = event
+            {
+                if let Some(it) = it {
+                    input_tokens = Some(input_tokens.unwrap_or(0) + it);
+                }
+                if let Some(ot) = ot {
+                    output_tokens = Some(output_tokens.unwrap_or(0) + ot);
+                }
+                continue;
+            }
+            let Some((name, data)) = turn_event_frame(&event) else {
+                continue;
+            };
+            if frame_tx.send(sse_event(name, data)).await.is_err() {
+                cancel_token.cancel();
+            }
+        }
+    };
+    let (result, ()) = tokio::join!(turn_fut, forward_fut);
+
+    state
+        .cancel_tokens
+        .lock()
+        .expect("cancel_tokens lock poisoned")
+        .remove(&session_key);
+
+    let new_messages = match &result {
+        Ok(outcome) => &outcome.new_messages,
+        Err(e) => &e.new_messages,
+    };
+    if let Some(ref backend) = state.session_backend {
+        persist_conversation_messages(backend.as_ref(), &session_key, new_messages);
+    }
+
+    let (frame, state_label, outcome) = match result {
+        Ok(outcome) => (
+            sse_event(
+                "done",
+                serde_json::json!({
+                    "reply": outcome.response,
+                    "usage": {
+                        "input_tokens": input_tokens,
+                        "output_tokens": output_tokens,
+                    },
+                    "model": turn_model,
+                    "provider": turn_provider,
+                }),
+            ),
+            "idle",
+            ::zeroclaw_log::EventOutcome::Success,
+        ),
+        Err(e) if zeroclaw_runtime::agent::loop_::is_tool_loop_cancelled(&e.error) => (
+            sse_event(
+                "aborted",
+                serde_json::json!({ "partial_reply": e.committed_response }),
+            ),
+            "idle",
+            ::zeroclaw_log::EventOutcome::Failure,
+        ),
+        Err(e) => (
+            sse_event(
+                "error",
+                serde_json::json!({
+                    "message": zeroclaw_providers::sanitize_api_error(&e.error.to_string()),
+                }),
+            ),
+            "error",
+            ::zeroclaw_log::EventOutcome::Failure,
+        ),
+    };
+    let _ = frame_tx.send(frame).await;
+
+    if let Some(ref backend) = state.session_backend
+        && backend.session_exists(&session_key)
+    {
+        let _ = backend.set_session_state(&session_key, state_label, None);
+    }
+
+    ::zeroclaw_log::record!(
+        INFO,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Complete)
+            .with_outcome(outcome)
+            .with_attrs(::serde_json::json!({
+                "model_provider": turn_provider,
+                "model": turn_model,
+                "session_key": session_key,
+                "input_tokens": input_tokens,
+                "output_tokens": output_tokens,
+                "trace_id": turn_id,
+            })),
+        "gateway_api_chat_turn"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_state;
+    use zeroclaw_runtime::security::pairing::PairingGuard;
+
+    #[test]
+    fn turn_events_map_to_sse_contract() {
+        let (name, data) = turn_event_frame(&TurnEvent::Chunk {
+            delta: "hel".into(),
+        })
+        .unwrap();
+        assert_eq!(name, "delta");
+        assert_eq!(data["content"], "hel");
+
+        let (name, data) = turn_event_frame(&TurnEvent::ToolCall {
+            id: "c1".into(),
+            name: "shell".into(),
+            args: serde_json::json!({"command": "ls"}),
+        })
+        .unwrap();
+        assert_eq!(name, "tool_call");
+        assert_eq!(data["status"], "running");
+        assert!(data.get("args").is_none(), "tool args stay server-side");
+
+        let (_, data) = turn_event_frame(&TurnEvent::ToolResult {
+            id: "c1".into(),
+            name: "shell".into(),
+            output: "secret output".into(),
+        })
+        .unwrap();
+        assert_eq!(data["status"], "done");
+        assert!(data.get("output").is_none());
+
+        assert!(
+            turn_event_frame(&TurnEvent::Thinking {
+                delta: "hmm".into()
+            })
+            .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn chat_requires_pairing_token() {
+        let mut state = test_state(zeroclaw_config::schema::Config::default());
+        state.pairing = Arc::new(PairingGuard::new(true, &[]));
+
+        let response = handle_api_chat(
+            State(state),
+            HeaderMap::new(),
+            Json(ChatRequest {
+                session_id: None,
+                message: "hi".into(),
+                agent: None,
+            }),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn chat_rejects_empty_message() {
+        let state = test_state(zeroclaw_config::schema::Config::default());
+
+        let response = handle_api_chat(
+            State(state),
+            HeaderMap::new(),
+            Json(ChatRequest {
+                session_id: Some("web-1".into()),
+                message: "   ".into(),
+                agent: None,
+            }),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
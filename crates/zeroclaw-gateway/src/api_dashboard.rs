@@ -0,0 +1,283 @@
+//! `GET /api/dashboard` — one-shot summary behind the dashboard's Activity tab.
+//!
+//! Each section reads the same source as the matching CLI command: the health
+//! registry and cost tracker (`zeroclaw status`), the approval queue
+//! (`zeroclaw approvals list`), the cron store (`zeroclaw cron list`) and the
+//! session backend. Nothing here keeps state of its own.
+
+use std::collections::BTreeMap;
+
+use axum::{
+    Json,
+    extract::State,
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use zeroclaw_infra::session_backend::{SessionBackend, SessionMetadata};
+
+use super::AppState;
+use super::api::{channel_status, require_auth};
+
+/// Sessions per channel whose last message is shown.
+const RECENT_SESSIONS_PER_CHANNEL: usize = 3;
+/// Characters of a message kept in the preview.
+const PREVIEW_MAX_CHARS: usize = 160;
+/// Newest pending approvals listed; `zeroclaw approvals list` shows the rest.
+const PENDING_APPROVALS_LIMIT: usize = 20;
+
+/// Message counters for one `<type>.<alias>` channel, summed over its sessions.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct ChannelActivity {
+    pub session_count: usize,
+    pub message_count: usize,
+    pub last_message_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecentMessage {
+    pub session_id: String,
+    pub sender: Option<String>,
+    pub role: String,
+    pub preview: String,
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChannelSummary {
+    pub name: String,
+    /// Same labels as `/api/channels`: `active`, `error`, `inactive`, ...
+    pub status: String,
+    /// `healthy`, `degraded`, `down` or `unavailable`.
+    pub health: String,
+    pub last_error: Option<String>,
+    #[serde(flatten)]
+    pub activity: ChannelActivity,
+    pub recent: Vec<RecentMessage>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DashboardSummary {
+    pub generated_at: DateTime<Utc>,
+    pub health: zeroclaw_runtime::health::HealthSnapshot,
+    pub channels: Vec<ChannelSummary>,
+    /// `None` when cost tracking is off.
+    pub usage_today: Option<UsageToday>,
+    /// `None` when `[approvals]` is disabled.
+    pub pending_approvals: Option<Vec<zeroclaw_runtime::approval::queue::QueuedApproval>>,
+    pub cron_jobs: Vec<zeroclaw_runtime::cron::CronJob>,
+    /// Sections that failed to load, so one broken store doesn't blank the page.
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UsageToday {
+    pub cost_usd: f64,
+    pub month_cost_usd: f64,
+    pub total_tokens: u64,
+    pub request_count: usize,
+}
+
+/// Per-channel counters from session metadata, keyed by `<type>.<alias>`.
+/// Sessions without a `channel_id` (CLI, gateway chat, cron) are skipped.
+pub fn channel_activity(sessions: &[SessionMetadata]) -> BTreeMap<String, ChannelActivity> {
+    let mut out: BTreeMap<String, ChannelActivity> = BTreeMap::new();
+    for meta in sessions {
+        let Some(channel) = meta.channel_id.as_deref() else {
+            continue;
+        };
+        let entry = out.entry(channel.to_string()).or_default();
+        entry.session_count += 1;
+        entry.message_count += meta.message_count;
+        if meta.message_count > 0 && entry.last_message_at < Some(meta.last_activity) {
+            entry.last_message_at = Some(meta.last_activity);
+        }
+    }
+    out
+}
+
+/// Last message of the most recently active sessions on `channel`.
+fn recent_messages(
+    backend: &dyn SessionBackend,
+    sessions: &[SessionMetadata],
+    channel: &str,
+) -> Vec<RecentMessage> {
+    let mut on_channel: Vec<&SessionMetadata> = sessions
+        .iter()
+        .filter(|meta| meta.channel_id.as_deref() == Some(channel) && meta.message_count > 0)
+        .collect();
+    on_channel.sort_by(|a, b| b.last_activity.cmp(&a.last_activity));
+    on_channel
+        .into_iter()
+        .take(RECENT_SESSIONS_PER_CHANNEL)
+        .filter_map(|meta| {
+            let last = backend.load(&meta.key).pop()?;
+            Some(RecentMessage {
+                session_id: meta.key.clone(),
+                sender: meta.sender_id.clone(),
+                role: last.role,
+                preview: preview(&last.content),
+                at: meta.last_activity,
+            })
+        })
+        .collect()
+}
+
+fn preview(content: &str) -> String {
+    let flat = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    if flat.chars().count() <= PREVIEW_MAX_CHARS {
+        return flat;
+    }
+    let mut cut: String = flat.chars().take(PREVIEW_MAX_CHARS).collect();
+    cut.push('…');
+    cut
+}
+
+/// GET /api/dashboard
+pub async fn handle_dashboard(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let config = state.config.read().clone();
+    let health = zeroclaw_runtime::health::snapshot();
+    let mut errors = Vec::new();
+
+    let sessions = state
+        .session_backend
+        .as_ref()
+        .map(|backend| backend.list_sessions_with_metadata())
+        .unwrap_or_default();
+    let mut activity = channel_activity(&sessions);
+    let channels = config
+        .channels_by_alias()
+        .into_iter()
+        .map(|info| {
+            let name = format!("{}.{}", info.channel_type, info.alias);
+            let (_, _, status, health_label) = channel_status(&config, &info, &health, &state);
+            let last_error = health
+                .components
+                .get(&format!("channel:{name}"))
+                .filter(|c| c.status != "ok")
+                .and_then(|c| c.last_error.clone());
+            ChannelSummary {
+                status: status.into(),
+                health: health_label.into(),
+                last_error,
+                activity: activity.remove(&name).unwrap_or_default(),
+                recent: state
+                    .session_backend
+                    .as_deref()
+                    .map(|backend| recent_messages(backend, &sessions, &name))
+                    .unwrap_or_default(),
+                name,
+            }
+        })
+        .collect();
+
+    // Same UTC day boundary the tracker uses for `daily_cost_usd`.
+    let midnight = Utc::now()
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .map(|t| t.and_utc());
+    let usage_today = state.cost_tracker.as_ref().and_then(|tracker| {
+        match tracker.get_summary_in_bounds(midnight, None) {
+            Ok(summary) => Some(UsageToday {
+                cost_usd: summary.daily_cost_usd,
+                month_cost_usd: summary.monthly_cost_usd,
+                total_tokens: summary.total_tokens,
+                request_count: summary.request_count,
+            }),
+            Err(e) => {
+                errors.push(format!("usage: {e}"));
+                None
+            }
+        }
+    });
+
+    let pending_approvals = if config.approvals.enabled {
+        match zeroclaw_runtime::approval::queue::list_approvals(
+            &config,
+            true,
+            PENDING_APPROVALS_LIMIT,
+        ) {
+            Ok(pending) => Some(pending),
+            Err(e) => {
+                errors.push(format!("approvals: {e:#}"));
+                Some(Vec::new())
+            }
+        }
+    } else {
+        None
+    };
+
+    let cron_jobs = match zeroclaw_runtime::cron::list_jobs(&config) {
+        Ok(jobs) => jobs.into_iter().filter(|job| job.enabled).collect(),
+        Err(e) => {
+            errors.push(format!("cron: {e:#}"));
+            Vec::new()
+        }
+    };
+
+    Json(DashboardSummary {
+        generated_at: Utc::now(),
+        health,
+        channels,
+        usage_today,
+        pending_approvals,
+        cron_jobs,
+        errors,
+    })
+    .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(key: &str, channel: Option<&str>, count: usize, minute: u32) -> SessionMetadata {
+        let at = DateTime::parse_from_rfc3339(&format!("2026-01-01T10:{minute:02}:00Z"))
+            .unwrap()
+            .with_timezone(&Utc);
+        SessionMetadata {
+            key: key.into(),
+            name: None,
+            created_at: at,
+            last_activity: at,
+            message_count: count,
+            agent_alias: None,
+            channel_id: channel.map(str::to_string),
+            room_id: None,
+            sender_id: None,
+        }
+    }
+
+    #[test]
+    fn channel_activity_sums_sessions_per_channel() {
+        let sessions = vec![
+            meta("a", Some("telegram.default"), 4, 5),
+            meta("b", Some("telegram.default"), 2, 9),
+            meta("c", Some("discord.ops"), 0, 30),
+            meta("gw_d", None, 7, 40),
+        ];
+        let activity = channel_activity(&sessions);
+
+        assert_eq!(activity.len(), 2);
+        let telegram = &activity["telegram.default"];
+        assert_eq!(telegram.session_count, 2);
+        assert_eq!(telegram.message_count, 6);
+        assert_eq!(telegram.last_message_at, Some(sessions[1].last_activity));
+        // An empty session counts but has no last message.
+        assert_eq!(activity["discord.ops"].last_message_at, None);
+    }
+
+    #[test]
+    fn preview_flattens_and_truncates() {
+        assert_eq!(preview("hello\n  world"), "hello world");
+        let long = "x".repeat(PREVIEW_MAX_CHARS + 10);
+        let cut = preview(&long);
+        assert_eq!(cut.chars().count(), PREVIEW_MAX_CHARS + 1);
+        assert!(cut.ends_with('…'));
+    }
+}
@@ -0,0 +1,547 @@
+//! `POST /v1/messages` — Anthropic Messages API emulation.
+//!
+//! Lets clients written against Anthropic's API talk to a configured agent.
+//! The request's `messages` become the turn's history (the last one must be a
+//! user message), and the reply comes back as a `message` object with text
+//! `content` blocks and `usage`, or, with `stream: true`, as the
+//! `message_start` / `content_block_delta` / `message_stop` event sequence.
+//! Requests are stateless: clients resend the whole conversation each time,
+//! so nothing is written to the gateway session store.
+//!
+//! The turn runs the agent's own system prompt and tools. Client-defined
+//! tools and `tool_use` / `tool_result` blocks are rejected for now.
+
+use super::AppState;
+use crate::ws::resolve_ws_session_cwd;
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use zeroclaw_providers::ChatMessage;
+use zeroclaw_runtime::agent::TurnEvent;
+
+/// Request body for `POST /v1/messages`.
+#[derive(Debug, Deserialize)]
+pub struct MessagesRequest {
+    /// Echoed back in the response. When it names a configured agent alias,
+    /// that agent answers; otherwise the gateway's runtime agent does.
+    #[serde(default)]
+    pub model: String,
+    /// A string or an array of text blocks.
+    #[serde(default)]
+    pub system: Option<Value>,
+    pub messages: Vec<InputMessage>,
+    /// Required by the API shape. The agent's own token limits apply.
+    pub max_tokens: u32,
+    #[serde(default)]
+    pub stream: bool,
+    #[serde(default)]
+    pub tools: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InputMessage {
+    pub role: String,
+    /// A string or an array of content blocks.
+    pub content: Value,
+}
+
+/// Anthropic-shaped error body.
+fn api_error(status: StatusCode, kind: &str, message: impl Into<String>) -> Response {
+    (
+        status,
+        Json(json!({
+            "type": "error",
+            "error": { "type": kind, "message": message.into() },
+        })),
+    )
+        .into_response()
+}
+
+fn invalid_request(message: impl Into<String>) -> Response {
+    api_error(StatusCode::BAD_REQUEST, "invalid_request_error", message)
+}
+
+/// Accept a paired gateway token as `x-api-key` (what Anthropic clients send)
+/// or as a bearer token.
+fn require_api_key(state: &AppState, headers: &HeaderMap) -> Result<(), Response> {
+    if !state.pairing.require_pairing() {
+        return Ok(());
+    }
+    let token = headers
+        .get("x-api-key")
+        .and_then(|v| v.to_str().ok())
+        .or_else(|| crate::api::extract_bearer_token(headers))
+        .unwrap_or("");
+    if !token.is_empty() && state.pairing.is_authenticated(token) {
+        return Ok(());
+    }
+    Err(api_error(
+        StatusCode::UNAUTHORIZED,
+        "authentication_error",
+        "Invalid API key — pair via POST /pair and send the token as x-api-key",
+    ))
+}
+
+/// Flatten a string or an array of text blocks into plain text.
+fn content_text(content: &Value) -> Result<String, String> {
+    match content {
+        Value::String(text) => Ok(text.clone()),
+        Value::Array(blocks) => {
+            let mut parts = Vec::with_capacity(blocks.len());
+            for block in blocks {
+                match block.get("type").and_then(Value::as_str) {
+                    Some("text") => parts.push(
+                        block
+                            .get("text")
+                            .and_then(Value::as_str)
+                            .ok_or("text block is missing `text`")?,
+                    ),
+                    Some(kind @ ("tool_use" | "tool_result")) => {
+                        return Err(format!(
+                            "`{kind}` blocks are not supported yet; this endpoint runs the \
+                             agent's own tools, so send text content only"
+                        ));
+                    }
+                    Some(kind) => return Err(format!("`{kind}` blocks are not supported")),
+                    None => return Err("content block is missing `type`".into()),
+                }
+            }
+            Ok(parts.join("\n"))
+        }
+        _ => Err("content must be a string or an array of content blocks".into()),
+    }
+}
+
+/// The history to seed and the user message that starts the turn.
+#[derive(Debug)]
+struct TurnInput {
+    history: Vec<ChatMessage>,
+    message: String,
+}
+
+fn turn_input(request: &MessagesRequest) -> Result<TurnInput, String> {
+    if !request.tools.is_empty() {
+        return Err(
+            "client-defined tools are not supported yet; the agent uses its configured tools"
+                .into(),
+        );
+    }
+    if request.max_tokens == 0 {
+        return Err("max_tokens must be at least 1".into());
+    }
+    let Some((last, earlier)) = request.messages.split_last() else {
+        return Err("messages must not be empty".into());
+    };
+    if last.role != "user" {
+        return Err("the last message must have role `user`".into());
+    }
+
+    let mut history = Vec::with_capacity(earlier.len());
+    for (index, msg) in earlier.iter().enumerate() {
+        let text = content_text(&msg.content).map_err(|e| format!("messages.{index}: {e}"))?;
+        history.push(match msg.role.as_str() {
+            "user" => ChatMessage::user(text),
+            "assistant" => ChatMessage::assistant(text),
+            other => return Err(format!("messages.{index}: unknown role `{other}`")),
+        });
+    }
+    let mut message =
+        content_text(&last.content).map_err(|e| format!("messages.{}: {e}", earlier.len()))?;
+    // The agent keeps its own system prompt; the client's rides along with
+    // the turn so it still shapes the reply.
+    if let Some(system) = &request.system {
+        let system = content_text(system).map_err(|e| format!("system: {e}"))?;
+        if !system.trim().is_empty() {
+            message = format!("[Client instructions]\n{}\n\n{message}", system.trim());
+        }
+    }
+    if message.trim().is_empty() {
+        return Err("the last user message is empty".into());
+    }
+    Ok(TurnInput { history, message })
+}
+
+/// How a turn ended, with the token totals the provider reported.
+struct TurnReport {
+    reply: Result<String, String>,
+    input_tokens: u64,
+    output_tokens: u64,
+}
+
+fn usage(report: &TurnReport) -> Value {
+    json!({
+        "input_tokens": report.input_tokens,
+        "output_tokens": report.output_tokens,
+    })
+}
+
+fn message_id() -> String {
+    format!("msg_{}", uuid::Uuid::new_v4().simple())
+}
+
+fn message_body(
+    id: &str,
+    model: &str,
+    content: Value,
+    stop_reason: Option<&str>,
+    usage: Value,
+) -> Value {
+    json!({
+        "id": id,
+        "type": "message",
+        "role": "assistant",
+        "model": model,
+        "content": content,
+        "stop_reason": stop_reason,
+        "stop_sequence": null,
+        "usage": usage,
+    })
+}
+
+fn sse_frame(data: Value) -> Result<Event, Infallible> {
+    let name = data["type"].as_str().unwrap_or("message").to_string();
+    Ok(Event::default().event(name).data(data.to_string()))
+}
+
+/// POST /v1/messages — run one agent turn in Anthropic's Messages API shape.
+pub async fn handle_messages(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<MessagesRequest>,
+) -> Response {
+    if let Err(e) = require_api_key(&state, &headers) {
+        return e;
+    }
+    let input = match turn_input(&body) {
+        Ok(input) => input,
+        Err(e) => return invalid_request(e),
+    };
+
+    let config = state.config.read().clone();
+    let requested = (!body.model.is_empty() && config.agent(&body.model).is_some())
+        .then_some(body.model.as_str());
+    let Some(agent_alias) = crate::resolve_gateway_chat_agent_alias(&config, requested) else {
+        return invalid_request(
+            "No agent configured — name a configured [agents.<alias>] entry as `model`",
+        );
+    };
+    if config.agent(&agent_alias).is_none() {
+        return invalid_request(format!(
+            "Unknown agent `{agent_alias}` — no [agents.{agent_alias}] entry configured."
+        ));
+    }
+    if let Some(err) = crate::needs_quickstart_for(&state.model) {
+        return api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "overloaded_error",
+            err.to_string(),
+        );
+    }
+
+    let session_cwd = match resolve_ws_session_cwd(None, &config, &agent_alias) {
+        Ok(cwd) => cwd,
+        Err(e) => {
+            return api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "api_error",
+                e.to_string(),
+            );
+        }
+    };
+    let mut agent =
+        match zeroclaw_runtime::agent::Agent::from_live_config_with_session_cwd_and_mcp_backchannel(
+            Arc::clone(&state.config),
+            &agent_alias,
+            Some(&session_cwd),
+            true,
+            false,
+            state.sop_engine.clone(),
+            state.sop_audit.clone(),
+            Some(state.canvas_store.clone()),
+        )
+        .await
+        {
+            Ok(agent) => agent,
+            Err(e) => {
+                return api_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "api_error",
+                    format!("Failed to initialise agent: {e}"),
+                );
+            }
+        };
+    agent.set_channel_name("api_messages".to_string());
+    agent.seed_history(&input.history);
+
+    let id = message_id();
+    let model = body.model.clone();
+    if !body.stream {
+        let report = run_turn(&state, &mut agent, &input.message, None).await;
+        return match &report.reply {
+            Ok(text) => Json(message_body(
+                &id,
+                &model,
+                json!([{ "type": "text", "text": text }]),
+                Some("end_turn"),
+                usage(&report),
+            ))
+            .into_response(),
+            Err(e) => api_error(StatusCode::BAD_GATEWAY, "api_error", e.clone()),
+        };
+    }
+
+    let (frame_tx, frame_rx) = mpsc::channel::<Result<Event, Infallible>>(64);
+    zeroclaw_spawn::spawn!(async move {
+        stream_turn(state, agent, input.message, id, model, frame_tx).await;
+    });
+    Sse::new(ReceiverStream::new(frame_rx))
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// Emit the Messages API event sequence around one turn.
+async fn stream_turn(
+    state: AppState,
+    mut agent: zeroclaw_runtime::agent::Agent,
+    message: String,
+    id: String,
+    model: String,
+    frame_tx: mpsc::Sender<Result<Event, Infallible>>,
+) {
+    let start = message_body(
+        &id,
+        &model,
+        json!([]),
+        None,
+        json!({ "input_tokens": 0, "output_tokens": 0 }),
+    );
+    for frame in [
+        json!({ "type": "message_start", "message": start }),
+        json!({
+            "type": "content_block_start",
+            "index": 0,
+            "content_block": { "type": "text", "text": "" },
+        }),
+    ] {
+        if frame_tx.send(sse_frame(frame)).await.is_err() {
+            return;
+        }
+    }
+
+    let (delta_tx, mut delta_rx) = mpsc::channel::<String>(64);
+    let forward = async {
+        while let Some(text) = delta_rx.recv().await {
+            let frame = json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "text_delta", "text": text },
+            });
+            if frame_tx.send(sse_frame(frame)).await.is_err() {
+                return false;
+            }
+        }
+        true
+    };
+    let (report, connected) = tokio::join!(
+        run_turn(&state, &mut agent, &message, Some(delta_tx)),
+        forward
+    );
+    if !connected {
+        return;
+    }
+
+    let frames = match &report.reply {
+        Ok(_) => vec![
+            json!({ "type": "content_block_stop", "index": 0 }),
+            json!({
+                "type": "message_delta",
+                "delta": { "stop_reason": "end_turn", "stop_sequence": null },
+                "usage": usage(&report),
+            }),
+            json!({ "type": "message_stop" }),
+        ],
+        Err(e) => vec![json!({
+            "type": "error",
+            "error": { "type": "api_error", "message": e },
+        })],
+    };
+    for frame in frames {
+        if frame_tx.send(sse_frame(frame)).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Run the turn, forwarding text deltas to `delta_tx` when streaming. The
+/// turn is cancelled once `delta_tx` has no receiver (client went away).
+async fn run_turn(
+    state: &AppState,
+    agent: &mut zeroclaw_runtime::agent::Agent,
+    message: &str,
+    delta_tx: Option<mpsc::Sender<String>>,
+) -> TurnReport {
+    let (turn_alias, turn_provider, turn_model) = agent.attribution_fields();
+    let cost_tracking_context = state.cost_tracker.as_ref().map(|tracker| {
+        let pricing =
+            zeroclaw_runtime::agent::cost::build_model_provider_pricing(&state.config.read());
+        zeroclaw_runtime::agent::cost::ToolLoopCostTrackingContext::new(
+            tracker.clone(),
+            Arc::new(pricing),
+        )
+        .with_agent_alias(&turn_alias)
+    });
+    let cancel_token = tokio_util::sync::CancellationToken::new();
+
+    let (event_tx, mut event_rx) = mpsc::channel::<TurnEvent>(64);
+    let turn_fut = zeroclaw_runtime::agent::cost::TOOL_LOOP_COST_TRACKING_CONTEXT.scope(
+        cost_tracking_context,
+        agent.turn_streamed_with_steering_state(
+            message,
+            event_tx,
+            Some(cancel_token.clone()),
+            None,
+        ),
+    );
+
+    let mut input_tokens = 0;
+    let mut output_tokens = 0;
+    let forward_fut = async {
+        while let Some(event) = event_rx.recv().await {
+            match event {
+                TurnEvent::Usage {
+                    input_tokens: it,
+                    output_tokens: ot,
+                    ..
+                } => {
+                    input_tokens += it.unwrap_or(0);
+                    output_tokens += ot.unwrap_or(0);
+                }
+                TurnEvent::Chunk { delta } => {
+                    if let Some(tx) = &delta_tx
+                        && tx.send(delta).await.is_err()
+                    {
+                        cancel_token.cancel();
+                    }
+                }
+                _ => {}
+            }
+        }
+    };
+    let (result, ()) = tokio::join!(turn_fut, forward_fut);
+
+    let (reply, outcome) = match result {
+        Ok(outcome) => (Ok(outcome.response), ::zeroclaw_log::EventOutcome::Success),
+        Err(e) => (
+            Err(zeroclaw_providers::sanitize_api_error(&e.error.to_string())),
+            ::zeroclaw_log::EventOutcome::Failure,
+        ),
+    };
+    ::zeroclaw_log::record!(
+        INFO,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Complete)
+            .with_outcome(outcome)
+            .with_attrs(::serde_json::json!({
+                "model_provider": turn_provider,
+                "model": turn_model,
+                "streamed": delta_tx.is_some(),
+                "input_tokens": input_tokens,
+                "output_tokens": output_tokens,
+            })),
+        "gateway_api_messages_turn"
+    );
+    TurnReport {
+        reply,
+        input_tokens,
+        output_tokens,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_state;
+    use zeroclaw_runtime::security::pairing::PairingGuard;
+
+    fn request(body: Value) -> MessagesRequest {
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn request_shape_becomes_history_and_turn_message() {
+        let input = turn_input(&request(json!({
+            "model": "assistant",
+            "max_tokens": 256,
+            "system": [{ "type": "text", "text": "Answer tersely." }],
+            "messages": [
+                { "role": "user", "content": "hi" },
+                { "role": "assistant", "content": [{ "type": "text", "text": "hello" }] },
+                { "role": "user", "content": [
+                    { "type": "text", "text": "what is" },
+                    { "type": "text", "text": "2 + 2?" },
+                ] },
+            ],
+        })))
+        .unwrap();
+        assert_eq!(input.history.len(), 2);
+        assert_eq!(input.history[0].role, "user");
+        assert_eq!(input.history[1].role, "assistant");
+        assert_eq!(input.history[1].content, "hello");
+        assert_eq!(
+            input.message,
+            "[Client instructions]\nAnswer tersely.\n\nwhat is\n2 + 2?"
+        );
+    }
+
+    #[test]
+    fn tool_blocks_and_client_tools_are_rejected() {
+        let err = turn_input(&request(json!({
+            "max_tokens": 64,
+            "messages": [{ "role": "user", "content": [
+                { "type": "tool_result", "tool_use_id": "t1", "content": "42" },
+            ] }],
+        })))
+        .unwrap_err();
+        assert!(err.starts_with("messages.0: `tool_result` blocks are not supported yet"));
+
+        let err = turn_input(&request(json!({
+            "max_tokens": 64,
+            "tools": [{ "name": "lookup", "input_schema": {} }],
+            "messages": [{ "role": "user", "content": "hi" }],
+        })))
+        .unwrap_err();
+        assert!(err.contains("client-defined tools"));
+
+        let err = turn_input(&request(json!({
+            "max_tokens": 64,
+            "messages": [{ "role": "assistant", "content": "hi" }],
+        })))
+        .unwrap_err();
+        assert!(err.contains("role `user`"));
+    }
+
+    #[tokio::test]
+    async fn messages_accept_paired_token_as_x_api_key() {
+        let mut state = test_state(zeroclaw_config::schema::Config::default());
+        state.pairing = Arc::new(PairingGuard::new(true, &["zc_token".to_string()]));
+
+        let mut headers = HeaderMap::new();
+        assert!(require_api_key(&state, &headers).is_err());
+        headers.insert("x-api-key", "wrong".parse().unwrap());
+        let rejected = require_api_key(&state, &headers).unwrap_err();
+        assert_eq!(rejected.status(), StatusCode::UNAUTHORIZED);
+        headers.insert("x-api-key", "zc_token".parse().unwrap());
+        assert!(require_api_key(&state, &headers).is_ok());
+    }
+}
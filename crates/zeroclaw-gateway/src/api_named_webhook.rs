@@ -0,0 +1,432 @@
+//! `POST /webhook/{alias}` — named webhooks.
+//!
+//! Each enabled `[channels.webhook.<alias>]` entry gets its own route on the
+//! gateway. The JSON payload is turned into the turn's message by the entry's
+//! `template` (see [`render_template`]), and the turn goes to the agent that
+//! lists `webhook.<alias>` in its `channels`. With `sync_response = true` the
+//! agent's reply is returned as the response body; otherwise the request is
+//! answered with `202 Accepted` and the turn runs in the background.
+
+use super::{
+    AppState, GatewayChatOutcome, client_key_from_request, is_needs_quickstart_err,
+    run_gateway_chat_with_tools, verify_whatsapp_signature, webhook_session_id,
+};
+use crate::api_webhook;
+use axum::{
+    Json,
+    body::Bytes,
+    extract::{ConnectInfo, Path, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde_json::{Value, json};
+use std::net::SocketAddr;
+
+/// Signature headers checked, in order, when the entry has a `secret`.
+/// `X-Hub-Signature-256` is what GitHub sends.
+const SIGNATURE_HEADERS: [&str; 2] = ["X-Webhook-Signature", "X-Hub-Signature-256"];
+
+/// Render `template` against `payload`.
+///
+/// `{{ path }}` is replaced by the value at a dot-separated path
+/// (`repository.full_name`, `commits.0.id`). A segment ending in `[]` maps
+/// the rest of the path over an array, and the results are joined one per
+/// line (`commits[].message`). Strings are inserted as-is, missing values and
+/// `null` render as nothing, and anything else as compact JSON.
+pub fn render_template(template: &str, payload: &Value) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        let expr = rest[start + 2..start + 2 + len].trim();
+        let rendered: Vec<String> = lookup(payload, expr)
+            .into_iter()
+            .filter_map(value_text)
+            .collect();
+        out.push_str(&rendered.join("\n"));
+        rest = &rest[start + 2 + len + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn lookup<'a>(payload: &'a Value, path: &str) -> Vec<&'a Value> {
+    let mut current = vec![payload];
+    for segment in path.split('.').filter(|s| !s.is_empty()) {
+        let (name, spread) = match segment.strip_suffix("[]") {
+            Some(name) => (name, true),
+            None => (segment, false),
+        };
+        current = current
+            .into_iter()
+            .filter_map(|value| {
+                if name.is_empty() {
+                    Some(value)
+                } else if let Ok(index) = name.parse::<usize>()
+                    && value.is_array()
+                {
+                    value.get(index)
+                } else {
+                    value.get(name)
+                }
+            })
+            .flat_map(|value| match (spread, value) {
+                (true, Value::Array(items)) => items.iter().collect(),
+                (true, _) => Vec::new(),
+                (false, value) => vec![value],
+            })
+            .collect();
+    }
+    current
+}
+
+fn value_text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Message text for a payload: the rendered `template` when one is set,
+/// otherwise the payload's `message` or `content` string, otherwise the raw
+/// JSON.
+pub fn webhook_content(template: Option<&str>, payload: &Value) -> String {
+    if let Some(template) = template {
+        return render_template(template, payload).trim().to_string();
+    }
+    ["message", "content"]
+        .iter()
+        .find_map(|key| payload.get(key).and_then(Value::as_str))
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| payload.to_string())
+}
+
+fn error(status: StatusCode, message: &str) -> Response {
+    (status, Json(json!({ "error": message }))).into_response()
+}
+
+/// POST /webhook/{alias} — named webhook entry
+pub async fn handle_named_webhook(
+    State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    Path(alias): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let rate_key =
+        client_key_from_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
+    if !state.rate_limiter.allow_webhook(&rate_key) {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                .with_attrs(json!({"alias": alias})),
+            "named webhook rate limit exceeded"
+        );
+        return error(
+            StatusCode::TOO_MANY_REQUESTS,
+            "Too many webhook requests. Please retry later.",
+        );
+    }
+
+    let (entry, agent) = {
+        let config = state.config.read();
+        let Some(entry) = config
+            .channels
+            .webhook
+            .get(&alias)
+            .filter(|entry| entry.enabled)
+            .cloned()
+        else {
+            return api_webhook::not_found("webhook");
+        };
+        let agent = config
+            .agent_for_channel(&format!("webhook.{alias}"))
+            .map(str::to_owned);
+        (entry, agent)
+    };
+
+    // ── Auth: the entry's HMAC secret, else gateway pairing ──
+    if let Some(secret) = entry.secret.as_deref().filter(|s| !s.is_empty()) {
+        let valid = SIGNATURE_HEADERS
+            .iter()
+            .find_map(|name| headers.get(*name).and_then(|v| v.to_str().ok()))
+            .is_some_and(|sig| {
+                let sig = sig.trim();
+                let sig = if sig.starts_with("sha256=") {
+                    sig.to_string()
+                } else {
+                    format!("sha256={sig}")
+                };
+                verify_whatsapp_signature(secret, &body, &sig)
+            });
+        if !valid {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(json!({"alias": alias})),
+                "named webhook: rejected — invalid or missing signature"
+            );
+            return error(
+                StatusCode::UNAUTHORIZED,
+                "Unauthorized — invalid or missing X-Webhook-Signature header",
+            );
+        }
+    } else if state.pairing.require_pairing() {
+        let token = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|auth| auth.strip_prefix("Bearer "))
+            .unwrap_or("");
+        if !state.pairing.is_authenticated(token) {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(json!({"alias": alias})),
+                "named webhook: rejected — not paired / invalid bearer token"
+            );
+            return error(
+                StatusCode::UNAUTHORIZED,
+                "Unauthorized — pair first via POST /pair, then send Authorization: Bearer <token>",
+            );
+        }
+    }
+
+    let payload: Value = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(e) => {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                    .with_attrs(json!({"alias": alias, "error": e.to_string()})),
+                "named webhook JSON parse error"
+            );
+            return error(StatusCode::BAD_REQUEST, "Invalid JSON body");
+        }
+    };
+    let message = webhook_content(entry.template.as_deref(), &payload);
+    if message.is_empty() {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "Payload rendered to an empty message",
+        );
+    }
+    let session_id = webhook_session_id(&headers);
+
+    if entry.sync_response {
+        return match run_gateway_chat_with_tools(
+            &state,
+            &message,
+            session_id.as_deref(),
+            agent.as_deref(),
+        )
+        .await
+        {
+            Ok(GatewayChatOutcome { response }) => {
+                (StatusCode::OK, Json(json!({ "response": response }))).into_response()
+            }
+            Err(e) if is_needs_quickstart_err(&e) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(json!({ "error": "needs_quickstart", "url": "/quickstart" })),
+            )
+                .into_response(),
+            Err(e) => {
+                ::zeroclaw_log::record!(
+                    ERROR,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(json!({
+                            "alias": alias,
+                            "error": zeroclaw_providers::sanitize_api_error(&e.to_string()),
+                        })),
+                    "named webhook model_provider error"
+                );
+                error(StatusCode::INTERNAL_SERVER_ERROR, "LLM request failed")
+            }
+        };
+    }
+
+    zeroclaw_spawn::spawn!(async move {
+        if let Err(e) =
+            run_gateway_chat_with_tools(&state, &message, session_id.as_deref(), agent.as_deref())
+                .await
+        {
+            ::zeroclaw_log::record!(
+                ERROR,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(json!({
+                        "alias": alias,
+                        "error": zeroclaw_providers::sanitize_api_error(&e.to_string()),
+                    })),
+                "named webhook background turn failed"
+            );
+        }
+    });
+    (StatusCode::ACCEPTED, Json(json!({ "status": "accepted" }))).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_state;
+    use zeroclaw_config::schema::{Config, WebhookConfig};
+
+    fn push_payload() -> Value {
+        json!({
+            "ref": "refs/heads/main",
+            "repository": { "full_name": "acme/widgets" },
+            "commits": [
+                { "id": "a1", "message": "Fix parser" },
+                { "id": "b2", "message": "Add tests" },
+            ],
+            "forced": false,
+        })
+    }
+
+    #[test]
+    fn template_renders_paths_and_array_maps() {
+        let payload = push_payload();
+        assert_eq!(
+            render_template(
+                "Push to {{ repository.full_name }} ({{ref}}):\n{{ commits[].message }}",
+                &payload,
+            ),
+            "Push to acme/widgets (refs/heads/main):\nFix parser\nAdd tests"
+        );
+        assert_eq!(render_template("{{ commits.1.id }}", &payload), "b2");
+        assert_eq!(
+            render_template("forced={{ forced }}", &payload),
+            "forced=false"
+        );
+        assert_eq!(render_template("[{{ pusher.name }}]", &payload), "[]");
+        assert_eq!(
+            render_template("literal {{ open", &payload),
+            "literal {{ open"
+        );
+    }
+
+    #[test]
+    fn content_without_template_prefers_message_field() {
+        assert_eq!(
+            webhook_content(None, &json!({ "message": " deploy done " })),
+            "deploy done"
+        );
+        assert_eq!(webhook_content(None, &json!({ "n": 1 })), r#"{"n":1}"#);
+    }
+
+    fn config_with(alias: &str, entry: WebhookConfig) -> Config {
+        let mut config = Config::default();
+        config.channels.webhook.insert(alias.to_string(), entry);
+        config
+    }
+
+    fn connect_info() -> ConnectInfo<SocketAddr> {
+        ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 30_300)))
+    }
+
+    #[tokio::test]
+    async fn sync_response_returns_the_agent_reply() {
+        let state = test_state(config_with(
+            "github",
+            WebhookConfig {
+                enabled: true,
+                template: Some("{{ commits[].message }}".into()),
+                sync_response: true,
+                ..WebhookConfig::default()
+            },
+        ));
+        let response = handle_named_webhook(
+            State(state),
+            connect_info(),
+            Path("github".into()),
+            HeaderMap::new(),
+            Bytes::from(push_payload().to_string()),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["response"], "ok");
+    }
+
+    #[tokio::test]
+    async fn async_entry_is_accepted_and_unknown_alias_is_not_found() {
+        let state = test_state(config_with(
+            "alerts",
+            WebhookConfig {
+                enabled: true,
+                ..WebhookConfig::default()
+            },
+        ));
+        let response = handle_named_webhook(
+            State(state.clone()),
+            connect_info(),
+            Path("alerts".into()),
+            HeaderMap::new(),
+            Bytes::from(r#"{"message":"disk full"}"#),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+        let response = handle_named_webhook(
+            State(state),
+            connect_info(),
+            Path("missing".into()),
+            HeaderMap::new(),
+            Bytes::from(r#"{"message":"hi"}"#),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn secret_requires_a_valid_signature() {
+        use hmac::{Hmac, Mac};
+        use sha2::Sha256;
+
+        let state = test_state(config_with(
+            "signed",
+            WebhookConfig {
+                enabled: true,
+                secret: Some("s3cret".into()),
+                sync_response: true,
+                ..WebhookConfig::default()
+            },
+        ));
+        let body = br#"{"message":"hello"}"#;
+        let response = handle_named_webhook(
+            State(state.clone()),
+            connect_info(),
+            Path("signed".into()),
+            HeaderMap::new(),
+            Bytes::from_static(body),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"s3cret").unwrap();
+        mac.update(body);
+        let signature = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Hub-Signature-256", signature.parse().unwrap());
+        let response = handle_named_webhook(
+            State(state),
+            connect_info(),
+            Path("signed".into()),
+            headers,
+            Bytes::from_static(body),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -50,9 +50,10 @@ fn lock_poisoned() -> JsonErr {
     )
 }
 
-/// Authorize an admin SOP call and derive the transport-bound principal. Mirrors
+/// Authorize an admin approval call (SOP gates and the `[approvals]` tool
+/// queue) and derive the transport-bound principal. Mirrors
 /// `handle_admin_reload`'s gate so the two never diverge.
-fn authorize(
+pub(crate) fn authorize(
     state: &AppState,
     peer: &SocketAddr,
     headers: &HeaderMap,
@@ -101,7 +102,7 @@ fn authorize(
         AdminReloadGate::Forbidden | AdminReloadGate::ForbiddenNoPairing => Err((
             StatusCode::FORBIDDEN,
             Json(serde_json::json!({
-                "error": "Remote SOP approval is disabled. Call from localhost, or set \
+                "error": "Remote approval is disabled. Call from localhost, or set \
                           gateway.allow_remote_admin = true with pairing enabled, then pair."
             })),
         )),
@@ -0,0 +1,218 @@
+//! CORS for browser frontends served from another origin
+//! (`[gateway] cors_allowed_origins`).
+//!
+//! Preflights from an allowed origin are answered here, before routing, so
+//! every route (chat, webhook, API) gets the same answer regardless of
+//! whether it registers an `OPTIONS` handler. Requests from other origins
+//! pass through untouched and get no CORS headers, leaving the browser's
+//! same-origin policy in force.
+
+use axum::extract::{Request, State};
+use axum::http::header::{self, HeaderMap, HeaderValue};
+use axum::http::{Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+const ALLOW_METHODS: &str = "GET, POST, PUT, PATCH, DELETE, OPTIONS";
+/// Used when a preflight does not list the headers it wants to send.
+const DEFAULT_ALLOW_HEADERS: &str = "Authorization, Content-Type, X-Webhook-Secret, \
+     X-Webhook-Signature, X-Webhook-Timestamp, X-Idempotency-Key, X-Session-Id";
+const PREFLIGHT_MAX_AGE_SECS: &str = "600";
+
+#[derive(Debug, Clone, Default)]
+pub struct CorsPolicy {
+    any_origin: bool,
+    origins: Vec<String>,
+}
+
+impl CorsPolicy {
+    pub fn new(allowed_origins: &[String]) -> Self {
+        Self {
+            any_origin: allowed_origins.iter().any(|o| o == "*"),
+            origins: allowed_origins
+                .iter()
+                .filter(|o| *o != "*")
+                .map(|o| o.to_ascii_lowercase())
+                .collect(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.any_origin || !self.origins.is_empty()
+    }
+
+    /// `Access-Control-Allow-Origin` value for `origin`, or `None` when the
+    /// origin is not allowed.
+    fn allow_origin(&self, origin: &str) -> Option<HeaderValue> {
+        if self.any_origin {
+            return Some(HeaderValue::from_static("*"));
+        }
+        self.origins
+            .iter()
+            .any(|o| o.eq_ignore_ascii_case(origin))
+            .then(|| HeaderValue::from_str(origin).ok())
+            .flatten()
+    }
+}
+
+pub async fn apply(
+    State(policy): State<Arc<CorsPolicy>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(allow_origin) = request
+        .headers()
+        .get(header::ORIGIN)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|origin| policy.allow_origin(origin))
+    else {
+        return next.run(request).await;
+    };
+
+    if request.method() == Method::OPTIONS
+        && request
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_REQUEST_METHOD)
+    {
+        let mut response = StatusCode::NO_CONTENT.into_response();
+        let headers = response.headers_mut();
+        insert_origin(headers, allow_origin);
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_METHODS,
+            HeaderValue::from_static(ALLOW_METHODS),
+        );
+        let requested = request
+            .headers()
+            .get(header::ACCESS_CONTROL_REQUEST_HEADERS)
+            .cloned()
+            .unwrap_or_else(|| HeaderValue::from_static(DEFAULT_ALLOW_HEADERS));
+        headers.insert(header::ACCESS_CONTROL_ALLOW_HEADERS, requested);
+        headers.insert(
+            header::ACCESS_CONTROL_MAX_AGE,
+            HeaderValue::from_static(PREFLIGHT_MAX_AGE_SECS),
+        );
+        return response;
+    }
+
+    let mut response = next.run(request).await;
+    insert_origin(response.headers_mut(), allow_origin);
+    response
+}
+
+fn insert_origin(headers: &mut HeaderMap, allow_origin: HeaderValue) {
+    let wildcard = allow_origin == "*";
+    headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, allow_origin);
+    if !wildcard {
+        // The response differs per origin; keep shared caches from serving
+        // one origin's answer to another.
+        headers.append(header::VARY, HeaderValue::from_static("origin"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::routing::post;
+    use tower::ServiceExt;
+
+    fn app(origins: &[&str]) -> Router {
+        let origins: Vec<String> = origins.iter().map(|o| o.to_string()).collect();
+        Router::new()
+            .route("/webhook", post(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(
+                Arc::new(CorsPolicy::new(&origins)),
+                apply,
+            ))
+    }
+
+    fn preflight(origin: &str) -> axum::http::Request<Body> {
+        axum::http::Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/webhook")
+            .header(header::ORIGIN, origin)
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .header(
+                header::ACCESS_CONTROL_REQUEST_HEADERS,
+                "authorization, content-type",
+            )
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn preflight_from_allowed_origin_is_answered() {
+        let response = app(&["https://app.example.com"])
+            .oneshot(preflight("https://app.example.com"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        let headers = response.headers();
+        assert_eq!(
+            headers[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://app.example.com"
+        );
+        assert!(
+            headers[header::ACCESS_CONTROL_ALLOW_METHODS]
+                .to_str()
+                .unwrap()
+                .contains("POST")
+        );
+        assert_eq!(
+            headers[header::ACCESS_CONTROL_ALLOW_HEADERS],
+            "authorization, content-type"
+        );
+        assert_eq!(headers[header::VARY], "origin");
+    }
+
+    #[tokio::test]
+    async fn simple_request_gets_allow_origin() {
+        let request = axum::http::Request::builder()
+            .method(Method::POST)
+            .uri("/webhook")
+            .header(header::ORIGIN, "https://APP.example.com")
+            .body(Body::empty())
+            .unwrap();
+        let response = app(&["https://app.example.com"])
+            .oneshot(request)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://APP.example.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn other_origins_get_no_cors_headers() {
+        let response = app(&["https://app.example.com"])
+            .oneshot(preflight("https://evil.example"))
+            .await
+            .unwrap();
+
+        assert_ne!(response.status(), StatusCode::NO_CONTENT);
+        assert!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn wildcard_allows_any_origin_without_vary() {
+        let response = app(&["*"])
+            .oneshot(preflight("https://anything.example"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+        assert!(response.headers().get(header::VARY).is_none());
+    }
+}
@@ -9,9 +9,14 @@ pub mod a2a;
 pub mod acp;
 pub mod agent_owned_state;
 pub mod api;
+pub mod api_approvals;
 pub mod api_browse;
+pub mod api_chat;
 pub mod api_config;
+pub mod api_dashboard;
 pub mod api_logs;
+pub mod api_messages;
+pub mod api_named_webhook;
 pub mod api_pairing;
 pub mod api_personality;
 #[cfg(feature = "plugins-wasm")]
@@ -32,10 +37,12 @@ pub mod api_webauthn;
 pub mod api_webhook;
 pub mod auth_rate_limit;
 pub mod canvas;
+pub mod cors;
 pub mod hardware_context;
 pub mod node_tool;
 pub mod nodes;
 pub mod openapi;
+pub mod request_log;
 pub mod security_headers;
 pub mod session_queue;
 pub mod sse;
@@ -145,7 +152,7 @@ use zeroclaw_runtime::tools;
 use zeroclaw_runtime::tools::CanvasStore;
 use zeroclaw_runtime::tools::scoped;
 
-/// Maximum request body size (64KB) — prevents memory exhaustion
+/// Default maximum request body size (64KB) — prevents memory exhaustion
 pub const MAX_BODY_SIZE: usize = 65_536;
 /// Default request timeout (30s) — prevents slow-loris attacks.
 pub const REQUEST_TIMEOUT_SECS: u64 = 30;
@@ -158,6 +165,12 @@ pub fn gateway_request_timeout_secs(cfg: &zeroclaw_config::schema::GatewayConfig
     cfg.request_timeout_secs
 }
 
+/// Request body limit (bytes); larger bodies are rejected with 413 before
+/// reaching a handler. Reads from typed config.
+pub fn gateway_max_body_size_bytes(cfg: &zeroclaw_config::schema::GatewayConfig) -> usize {
+    cfg.max_body_size_bytes
+}
+
 /// Manual cron-trigger request timeout (seconds), exempt from the
 /// gateway-wide [`gateway_request_timeout_secs`] limit so synchronous agent
 /// jobs can run to completion. Reads from typed config.
@@ -741,6 +754,19 @@ pub async fn run_gateway(
             Arc::new(platform::NativeRuntime::new())
         }
     };
+    if let Err(e) = platform::prepare_runtime(&config.runtime).await {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note,)
+                .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                .with_attrs(::serde_json::json!({
+                    "runtime_kind": config.runtime.kind,
+                    "error": format!("{e:#}"),
+                })),
+            "Gateway: runtime startup check failed; shell tool calls will fail \
+                 until [runtime] is fixed."
+        );
+    }
     let memory_strategy: Arc<dyn MemoryStrategy> = Arc::new(DefaultMemoryStrategy::with_config(
         mem.clone(),
         config.memory.clone(),
@@ -1255,8 +1281,8 @@ pub async fn run_gateway(
         .filter(|p| !p.is_empty());
 
     // ── Tunnel ────────────────────────────────────────────────
-    let tunnel = match zeroclaw_runtime::tunnel::create_tunnel(&config.tunnel) {
-        Ok(t) => t,
+    let tunnel = match zeroclaw_runtime::tunnel::create_gateway_tunnel(&config) {
+        Ok(t) => t.map(Arc::<dyn zeroclaw_runtime::tunnel::Tunnel>::from),
         Err(e) => {
             ::zeroclaw_log::record!(
                 WARN,
@@ -1279,11 +1305,18 @@ pub async fn run_gateway(
         match tun.start(host, actual_port).await {
             Ok(url) => {
                 println!("🌐 Tunnel active: {url}");
+                zeroclaw_runtime::health::mark_component_ok(
+                    zeroclaw_runtime::tunnel::TUNNEL_COMPONENT,
+                );
                 tunnel_url = Some(url);
             }
             Err(e) => {
                 println!("⚠️  Tunnel failed to start: {e}");
                 println!("   Falling back to local-only mode.");
+                zeroclaw_runtime::health::mark_component_error(
+                    zeroclaw_runtime::tunnel::TUNNEL_COMPONENT,
+                    format!("{e:#}"),
+                );
             }
         }
     }
@@ -1389,6 +1422,9 @@ pub async fn run_gateway(
     }
     println!("  POST {pfx}/pair      — pair a new client (X-Pairing-Code header)");
     println!("  POST {pfx}/webhook   — {{\"message\": \"your prompt\"}}");
+    if config.channels.webhook.values().any(|entry| entry.enabled) {
+        println!("  POST {pfx}/webhook/<alias> — named webhook (channels.webhook.<alias>)");
+    }
     #[cfg(feature = "channel-whatsapp-cloud")]
     if !whatsapp_channel.is_empty() {
         println!("  GET  {pfx}/whatsapp[/<alias>]  — Meta webhook verification");
@@ -1413,6 +1449,7 @@ pub async fn run_gateway(
         println!("  GET  {pfx}/ws/nodes  — WebSocket node discovery");
     }
     println!("  GET  {pfx}/health    — health check");
+    println!("  GET  {pfx}/health/details — per-component health");
     println!("  GET  {pfx}/metrics   — Prometheus metrics");
     println!("  Press Ctrl+C to stop.\n");
 
@@ -1445,6 +1482,30 @@ pub async fn run_gateway(
         .unwrap_or((owned_shutdown_tx, None));
     let mut shutdown_rx = shutdown_tx.subscribe();
 
+    // Supervise a tunnel that came up: health-check it and restart it with
+    // backoff until the gateway shuts down.
+    let tunnel_task = match (&tunnel, &tunnel_url) {
+        (Some(tun), Some(_)) => {
+            let tun = Arc::clone(tun);
+            let host = host.to_string();
+            let initial_backoff = config.reliability.channel_initial_backoff_secs;
+            let max_backoff = config.reliability.channel_max_backoff_secs;
+            let tunnel_shutdown_rx = shutdown_tx.subscribe();
+            Some(zeroclaw_spawn::spawn!(async move {
+                zeroclaw_runtime::tunnel::supervise_tunnel(
+                    tun,
+                    host,
+                    actual_port,
+                    initial_backoff,
+                    max_backoff,
+                    tunnel_shutdown_rx,
+                )
+                .await;
+            }))
+        }
+        _ => None,
+    };
+
     // Node registry for dynamic node discovery
     let node_registry = Arc::new(nodes::NodeRegistry::new(config.nodes.max_nodes));
     let mdns_config_state = Arc::clone(&config_state);
@@ -1604,17 +1665,27 @@ pub async fn run_gateway(
         // ── Admin routes (for CLI management) ──
         .route("/admin/shutdown", post(handle_admin_shutdown))
         .route("/admin/reload", post(handle_admin_reload))
+        .route("/admin/approvals", get(api_approvals::handle_approvals_pending))
+        .route("/admin/approvals/approve", post(api_approvals::handle_approvals_approve))
+        .route("/admin/approvals/deny", post(api_approvals::handle_approvals_deny))
         .route("/admin/sop/pending", get(api_sop::handle_sop_pending))
         .route("/admin/sop/approve", post(api_sop::handle_sop_approve))
         .route("/admin/sop/deny", post(api_sop::handle_sop_deny))
         .route("/admin/paircode", get(handle_admin_paircode))
         .route("/admin/paircode/new", post(handle_admin_paircode_new))
+        .route("/admin/channels/{name}/pause", post(handle_admin_channel_pause))
+        .route("/admin/channels/{name}/resume", post(handle_admin_channel_resume))
+        .route("/admin/drain", post(handle_admin_drain_start).delete(handle_admin_drain_stop))
         // ── Existing routes ──
         .route("/health", get(handle_health))
+        .route("/health/details", get(handle_health_details))
         .route("/metrics", get(handle_metrics))
         .route("/pair", post(handle_pair))
         .route("/pair/code", get(handle_pair_code))
         .route("/webhook", post(handle_webhook))
+        .route("/webhook/{alias}", post(api_named_webhook::handle_named_webhook))
+        // ── Anthropic Messages API emulation ──
+        .route("/v1/messages", post(api_messages::handle_messages))
         .merge(optional_channel_routes())
         // ── Claude Code runner hooks ──
         .route("/hooks/claude-code", post(api::handle_claude_code_hook))
@@ -1844,6 +1915,7 @@ pub async fn run_gateway(
             post(api::handle_api_channel_relink),
         )
         .route("/api/health", get(api::handle_api_health))
+        .route("/api/dashboard", get(api_dashboard::handle_dashboard))
         .route("/api/tuis", get(api::handle_api_tuis))
         .route("/api/sessions", get(api::handle_api_sessions_list))
         .route("/api/sessions/running", get(api::handle_api_sessions_running))
@@ -1854,6 +1926,8 @@ pub async fn run_gateway(
         .route("/api/sessions/{id}", delete(api::handle_api_session_delete).put(api::handle_api_session_rename))
         .route("/api/sessions/{id}/state", get(api::handle_api_session_state))
         .route("/api/sessions/{id}/abort", post(api::handle_api_session_abort))
+        .route("/api/chat", post(api_chat::handle_api_chat))
+        .route("/api/chat/{id}/abort", post(api::handle_api_session_abort))
         // ── Pairing + Device management API ──
         .route("/api/pairing/initiate", post(api_pairing::initiate_pairing))
         .route("/api/pair", post(api_pairing::submit_pairing_enhanced))
@@ -1939,7 +2013,9 @@ pub async fn run_gateway(
         // ── SPA fallback: non-API GET requests serve index.html ──
         .fallback(get(static_files::handle_spa_fallback))
         .with_state(state.clone())
-        .layer(RequestBodyLimitLayer::new(MAX_BODY_SIZE))
+        .layer(RequestBodyLimitLayer::new(gateway_max_body_size_bytes(
+            &config.gateway,
+        )))
         .layer(TimeoutLayer::with_status_code(
             StatusCode::REQUEST_TIMEOUT,
             Duration::from_secs(gateway_request_timeout_secs(&config.gateway)),
@@ -1953,9 +2029,16 @@ pub async fn run_gateway(
         Router::new().route("/api/cron/{id}/run", post(api::handle_api_cron_run));
     #[cfg(feature = "a2a")]
     let long_running_router = long_running_router.merge(a2a::a2a_task_route());
+    let request_log_state = Arc::new(request_log::RequestLogState {
+        trust_forwarded_headers: state.trust_forwarded_headers,
+        pairing: Arc::clone(&state.pairing),
+        access_log: request_log::AccessLogWriter::from_config(&config).map(Arc::new),
+    });
     let long_running_router: Router = long_running_router
         .with_state(state)
-        .layer(RequestBodyLimitLayer::new(MAX_BODY_SIZE))
+        .layer(RequestBodyLimitLayer::new(gateway_max_body_size_bytes(
+            &config.gateway,
+        )))
         .layer(TimeoutLayer::with_status_code(
             StatusCode::REQUEST_TIMEOUT,
             Duration::from_secs(gateway_long_running_request_timeout_secs(&config.gateway)),
@@ -1987,6 +2070,23 @@ pub async fn run_gateway(
         app.layer(axum::middleware::from_fn(security_headers::apply))
     };
 
+    // CORS sits outside routing so preflights are answered for every path;
+    // request logging is outermost so it sees the final status, including
+    // 413s from the body limit and answered preflights.
+    let cors = cors::CorsPolicy::new(&config.gateway.cors_allowed_origins);
+    let app = if cors.is_enabled() {
+        app.layer(axum::middleware::from_fn_with_state(
+            Arc::new(cors),
+            cors::apply,
+        ))
+    } else {
+        app
+    };
+    let app = app.layer(axum::middleware::from_fn_with_state(
+        request_log_state,
+        request_log::log_request,
+    ));
+
     // ── TLS / mTLS setup ───────────────────────────────────────────
     let tls_acceptor = match &config.gateway.tls {
         Some(tls_cfg) if tls_cfg.enabled => {
@@ -2109,6 +2209,13 @@ pub async fn run_gateway(
         }
     }
 
+    if let Some(task) = tunnel_task {
+        task.abort();
+    }
+    if let Some(tun) = tunnel {
+        tun.stop().await.ok();
+    }
+
     drop(broadcast_hook_guard);
     Ok(())
 }
@@ -2153,15 +2260,52 @@ fn paircode_recovery_curl_host(host: &str) -> &str {
 // AXUM HANDLERS
 // ══════════════════════════════════════════════════════════════════════════════
 
-/// GET /health — always public (no secrets leaked)
+/// Component health with the configured stuck-in-error threshold applied.
+/// Probes the memory backend first so its entry reflects the live state.
+async fn runtime_health_details(state: &AppState) -> zeroclaw_runtime::health::HealthDetails {
+    if state.mem.health_check().await {
+        zeroclaw_runtime::health::mark_component_ok("memory");
+    } else {
+        zeroclaw_runtime::health::mark_component_error(
+            "memory",
+            format!("{} backend health check failed", state.mem.name()),
+        );
+    }
+    let threshold_secs = state.config.read().gateway.health_error_threshold_secs;
+    zeroclaw_runtime::health::details(Duration::from_secs(threshold_secs))
+}
+
+/// GET /health — always public (no secrets leaked). Answers 503 while any
+/// component has been in error longer than
+/// `[gateway].health_error_threshold_secs`.
 async fn handle_health(State(state): State<AppState>) -> impl IntoResponse {
+    let details = runtime_health_details(&state).await;
+    let degraded = details.is_degraded();
     let body = serde_json::json!({
-        "status": "ok",
+        "status": details.status,
+        "stuck_components": details.stuck_components(),
         "paired": state.pairing.is_paired(),
         "require_pairing": state.pairing.require_pairing(),
         "runtime": zeroclaw_runtime::health::snapshot_json(),
     });
-    Json(body)
+    let code = if degraded {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+    (code, Json(body))
+}
+
+/// GET /health/details — per-component state, last error, restart count and
+/// last ok timestamp. Same status code rule as `/health`.
+async fn handle_health_details(State(state): State<AppState>) -> impl IntoResponse {
+    let details = runtime_health_details(&state).await;
+    let code = if details.is_degraded() {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+    (code, Json(details))
 }
 
 /// Prometheus content type for text exposition format.
@@ -3905,6 +4049,174 @@ async fn handle_admin_reload(
     ))
 }
 
+/// Authorize a channel admission endpoint (`/admin/channels/*`,
+/// `/admin/drain`). Loopback callers pass. Remote callers must send the
+/// admin-scoped `gateway.admin_api_key` as a bearer token; pairing tokens are
+/// not enough, and with no key configured the endpoints stay localhost-only.
+fn require_admin_key(
+    is_loopback: bool,
+    admin_api_key: Option<&str>,
+    headers: &HeaderMap,
+) -> Result<(), (StatusCode, Json<serde_json::Value>)> {
+    if is_loopback {
+        return Ok(());
+    }
+    let Some(expected) = admin_api_key.filter(|key| !key.is_empty()) else {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!({
+                "error": "Remote channel admin is disabled. Call from localhost, \
+                          or set gateway.admin_api_key and send it as \
+                          Authorization: Bearer <key>."
+            })),
+        ));
+    };
+    match api::extract_bearer_token(headers) {
+        Some(token) if constant_time_eq(token, expected) => Ok(()),
+        _ => Err((
+            StatusCode::UNAUTHORIZED,
+            Json(serde_json::json!({
+                "error": "Unauthorized — send the gateway admin API key as Authorization: Bearer <key>"
+            })),
+        )),
+    }
+}
+
+fn authorize_channel_admin(
+    state: &AppState,
+    peer: &SocketAddr,
+    headers: &HeaderMap,
+) -> Result<(), (StatusCode, Json<serde_json::Value>)> {
+    let admin_api_key = state.config.read().gateway.admin_api_key.clone();
+    require_admin_key(peer.ip().is_loopback(), admin_api_key.as_deref(), headers)
+}
+
+/// A channel type (`telegram`) or `<type>.<alias>` key (`telegram.support`).
+fn valid_admission_channel_name(name: &str) -> bool {
+    let valid_part = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    };
+    match name.split_once('.') {
+        Some((channel_type, alias)) => valid_part(channel_type) && valid_part(alias),
+        None => valid_part(name),
+    }
+}
+
+fn admission_response(message: String) -> (StatusCode, Json<serde_json::Value>) {
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "message": message,
+            "channels": zeroclaw_runtime::admission::global().snapshot(),
+        })),
+    )
+}
+
+fn set_channel_paused(
+    state: &AppState,
+    peer: &SocketAddr,
+    headers: &HeaderMap,
+    name: &str,
+    paused: bool,
+) -> Result<(StatusCode, Json<serde_json::Value>), (StatusCode, Json<serde_json::Value>)> {
+    authorize_channel_admin(state, peer, headers)?;
+    if !valid_admission_channel_name(name) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "Channel name must be a channel type (telegram) or <type>.<alias> (telegram.support)"
+            })),
+        ));
+    }
+    let admission = zeroclaw_runtime::admission::global();
+    let changed = if paused {
+        admission.pause(name)
+    } else {
+        admission.resume(name)
+    };
+    ::zeroclaw_log::record!(
+        INFO,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note).with_attrs(
+            ::serde_json::json!({"channel": name, "paused": paused, "changed": changed})
+        ),
+        "admin channel admission change"
+    );
+    let message = match (paused, changed) {
+        (true, true) => format!("Channel {name} paused"),
+        (true, false) => format!("Channel {name} was already paused"),
+        (false, true) => format!("Channel {name} resumed"),
+        (false, false) => format!("Channel {name} was not paused"),
+    };
+    Ok(admission_response(message))
+}
+
+/// POST /admin/channels/{name}/pause — stop dispatching messages for a
+/// channel type or `<type>.<alias>`
+async fn handle_admin_channel_pause(
+    State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    set_channel_paused(&state, &peer, &headers, &name, true)
+}
+
+/// POST /admin/channels/{name}/resume — undo a pause
+async fn handle_admin_channel_resume(
+    State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    set_channel_paused(&state, &peer, &headers, &name, false)
+}
+
+/// POST /admin/drain — stop taking new channel messages and let in-flight
+/// workers finish
+async fn handle_admin_drain_start(
+    State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    set_draining(&state, &peer, &headers, true)
+}
+
+/// DELETE /admin/drain — leave drain mode
+async fn handle_admin_drain_stop(
+    State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    set_draining(&state, &peer, &headers, false)
+}
+
+fn set_draining(
+    state: &AppState,
+    peer: &SocketAddr,
+    headers: &HeaderMap,
+    draining: bool,
+) -> Result<(StatusCode, Json<serde_json::Value>), (StatusCode, Json<serde_json::Value>)> {
+    authorize_channel_admin(state, peer, headers)?;
+    let changed = zeroclaw_runtime::admission::global().set_draining(draining);
+    ::zeroclaw_log::record!(
+        INFO,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+            .with_attrs(::serde_json::json!({"draining": draining, "changed": changed})),
+        "admin drain mode change"
+    );
+    let message = match (draining, changed) {
+        (true, true) => "Drain mode on; in-flight messages will finish",
+        (true, false) => "Drain mode was already on",
+        (false, true) => "Drain mode off",
+        (false, false) => "Drain mode was not on",
+    };
+    Ok(admission_response(message.to_string()))
+}
+
 /// GET /admin/paircode — fetch current pairing code (localhost only)
 async fn handle_admin_paircode(
     State(state): State<AppState>,
@@ -4218,6 +4530,43 @@ mod tests {
         assert_eq!(MAX_BODY_SIZE, 65_536);
     }
 
+    #[tokio::test]
+    async fn configured_body_limit_rejects_oversized_requests() {
+        use tower::ServiceExt;
+
+        let gateway = zeroclaw_config::schema::GatewayConfig {
+            max_body_size_bytes: 16,
+            ..Default::default()
+        };
+        assert_eq!(
+            zeroclaw_config::schema::GatewayConfig::default().max_body_size_bytes,
+            MAX_BODY_SIZE
+        );
+        let app = Router::new()
+            .route(
+                "/webhook",
+                post(|body: axum::body::Bytes| async move { body.len().to_string() }),
+            )
+            .layer(RequestBodyLimitLayer::new(gateway_max_body_size_bytes(
+                &gateway,
+            )));
+        let request = |body: &'static str| {
+            axum::http::Request::builder()
+                .method("POST")
+                .uri("/webhook")
+                .body(axum::body::Body::from(body))
+                .unwrap()
+        };
+
+        let ok = app.clone().oneshot(request("small")).await.unwrap();
+        assert_eq!(ok.status(), StatusCode::OK);
+        let rejected = app
+            .oneshot(request("this body is longer than sixteen bytes"))
+            .await
+            .unwrap();
+        assert_eq!(rejected.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
     #[test]
     fn security_timeout_default_is_30_seconds() {
         assert_eq!(REQUEST_TIMEOUT_SECS, 30);
@@ -7220,6 +7569,41 @@ mod tests {
         assert!(!zeroclaw_config::schema::GatewayConfig::default().allow_remote_admin);
     }
 
+    #[test]
+    fn channel_admin_requires_the_admin_key_remotely() {
+        let bearer = |token: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::AUTHORIZATION,
+                format!("Bearer {token}").parse().unwrap(),
+            );
+            headers
+        };
+
+        assert!(require_admin_key(true, None, &HeaderMap::new()).is_ok());
+        let (status, _) = require_admin_key(false, None, &bearer("anything")).unwrap_err();
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = require_admin_key(false, Some(""), &bearer("")).unwrap_err();
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = require_admin_key(false, Some("s3cret"), &HeaderMap::new()).unwrap_err();
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let (status, _) = require_admin_key(false, Some("s3cret"), &bearer("wrong")).unwrap_err();
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(require_admin_key(false, Some("s3cret"), &bearer("s3cret")).is_ok());
+    }
+
+    #[test]
+    fn admission_channel_names_are_a_type_or_type_and_alias() {
+        assert!(valid_admission_channel_name("telegram"));
+        assert!(valid_admission_channel_name("telegram.support"));
+        assert!(valid_admission_channel_name("voice_call.front-desk"));
+        assert!(!valid_admission_channel_name(""));
+        assert!(!valid_admission_channel_name("telegram."));
+        assert!(!valid_admission_channel_name(".support"));
+        assert!(!valid_admission_channel_name("telegram.a.b"));
+        assert!(!valid_admission_channel_name("tele gram"));
+    }
+
     /// Build an `AppState` for `handle_admin_reload`: controls
     /// `gateway.allow_remote_admin`, pairing (and its tokens), and wires a
     /// live reload channel so the allowed path reaches `200` rather than the
@@ -0,0 +1,249 @@
+//! Structured per-request logging for the gateway.
+//!
+//! Every request produces one `record!` event (method, path, status,
+//! latency, client IP, API key id) and, when `[gateway.access_log]` is
+//! enabled, one JSON line in a size-rotated access log file. Successful
+//! requests log at debug level so dashboard polling does not flood the
+//! default log; 4xx responses log at info and 5xx at warn.
+//!
+//! Only the path is recorded, never the query string: WebSocket and SSE
+//! clients may carry their bearer token there.
+
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::HeaderMap;
+use axum::middleware::Next;
+use axum::response::Response;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+use zeroclaw_config::schema::{Config, GatewayAccessLogConfig};
+use zeroclaw_runtime::security::pairing::PairingGuard;
+
+/// Hex characters of the token hash used as the API key id: enough to tell
+/// paired clients apart, too short to be useful as a credential.
+const KEY_ID_LEN: usize = 12;
+
+pub struct RequestLogState {
+    pub trust_forwarded_headers: bool,
+    pub pairing: Arc<PairingGuard>,
+    pub access_log: Option<Arc<AccessLogWriter>>,
+}
+
+#[derive(Debug, Serialize)]
+struct AccessLogEntry {
+    ts: String,
+    method: String,
+    path: String,
+    status: u16,
+    latency_ms: u64,
+    client_ip: String,
+    key_id: Option<String>,
+}
+
+/// Short id for the paired bearer token on the request, if any. Unpaired or
+/// malformed tokens get no id.
+fn api_key_id(headers: &HeaderMap, pairing: &PairingGuard) -> Option<String> {
+    let token = headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|t| !t.is_empty())?;
+    pairing
+        .authenticate_and_hash(token)
+        .map(|hash| hash[..KEY_ID_LEN.min(hash.len())].to_string())
+}
+
+pub async fn log_request(
+    State(state): State<Arc<RequestLogState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let started = Instant::now();
+    let method = request.method().to_string();
+    let path = request.uri().path().to_string();
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| *addr);
+    let client_ip =
+        super::client_key_from_request(peer, request.headers(), state.trust_forwarded_headers);
+    let key_id = api_key_id(request.headers(), &state.pairing);
+
+    let response = next.run(request).await;
+
+    let status = response.status().as_u16();
+    let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+    let event = ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Receive)
+        .with_outcome(if status < 400 {
+            ::zeroclaw_log::EventOutcome::Success
+        } else {
+            ::zeroclaw_log::EventOutcome::Failure
+        })
+        .with_duration(latency_ms)
+        .with_attrs(::serde_json::json!({
+            "method": method,
+            "path": path,
+            "status": status,
+            "client_ip": client_ip,
+            "key_id": key_id,
+        }));
+    match status {
+        500.. => ::zeroclaw_log::record!(WARN, event, "gateway request"),
+        400..=499 => ::zeroclaw_log::record!(INFO, event, "gateway request"),
+        _ => ::zeroclaw_log::record!(DEBUG, event, "gateway request"),
+    }
+
+    if let Some(writer) = state.access_log.clone() {
+        let entry = AccessLogEntry {
+            ts: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            method,
+            path,
+            status,
+            latency_ms,
+            client_ip,
+            key_id,
+        };
+        // File I/O and rotation stay off the async workers.
+        tokio::task::spawn_blocking(move || writer.append(&entry));
+    }
+
+    response
+}
+
+/// Appends JSON lines to the access log, rotating `<path>` to `<path>.1`
+/// (shifting older files up to `max_files`) once it reaches `max_bytes`.
+pub struct AccessLogWriter {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: u32,
+    lock: Mutex<()>,
+}
+
+impl AccessLogWriter {
+    /// Writer for an enabled `[gateway.access_log]`, with relative paths
+    /// resolved against the data directory.
+    pub fn from_config(config: &Config) -> Option<Self> {
+        let log: &GatewayAccessLogConfig = config.gateway.access_log.as_ref()?;
+        if !log.enabled {
+            return None;
+        }
+        let path = Path::new(&log.path);
+        let path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            config.data_dir.join(path)
+        };
+        Some(Self::new(
+            path,
+            u64::from(log.max_size_mb) * 1024 * 1024,
+            log.max_files,
+        ))
+    }
+
+    pub fn new(path: PathBuf, max_bytes: u64, max_files: u32) -> Self {
+        Self {
+            path,
+            max_bytes,
+            max_files,
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn append(&self, entry: &AccessLogEntry) {
+        let Ok(line) = serde_json::to_string(entry) else {
+            return;
+        };
+        let _guard = self.lock.lock();
+        if let Err(e) = self
+            .rotate_if_needed()
+            .and_then(|()| self.write_line(&line))
+        {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Write)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({
+                        "path": self.path.display().to_string(),
+                        "error": e.to_string(),
+                    })),
+                "gateway access log write failed"
+            );
+        }
+    }
+
+    fn write_line(&self, line: &str) -> std::io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{line}")
+    }
+
+    fn rotate_if_needed(&self) -> std::io::Result<()> {
+        let Ok(metadata) = std::fs::metadata(&self.path) else {
+            return Ok(());
+        };
+        if metadata.len() < self.max_bytes {
+            return Ok(());
+        }
+        if self.max_files == 0 {
+            return std::fs::remove_file(&self.path);
+        }
+        let numbered = |n: u32| PathBuf::from(format!("{}.{n}", self.path.display()));
+        let _ = std::fs::remove_file(numbered(self.max_files));
+        for n in (1..self.max_files).rev() {
+            let _ = std::fs::rename(numbered(n), numbered(n + 1));
+        }
+        std::fs::rename(&self.path, numbered(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(status: u16) -> AccessLogEntry {
+        AccessLogEntry {
+            ts: "2026-01-01T00:00:00.000Z".into(),
+            method: "POST".into(),
+            path: "/webhook".into(),
+            status,
+            latency_ms: 3,
+            client_ip: "127.0.0.1".into(),
+            key_id: Some("abc123def456".into()),
+        }
+    }
+
+    #[test]
+    fn access_log_writes_json_lines_and_rotates() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("logs/access.jsonl");
+        let writer = AccessLogWriter::new(path.clone(), 1, 2);
+
+        writer.append(&entry(200));
+        let first: serde_json::Value =
+            serde_json::from_str(std::fs::read_to_string(&path).unwrap().trim()).unwrap();
+        assert_eq!(first["status"], 200);
+        assert_eq!(first["path"], "/webhook");
+        assert_eq!(first["key_id"], "abc123def456");
+
+        writer.append(&entry(413));
+        writer.append(&entry(500));
+        writer.append(&entry(201));
+        let current = std::fs::read_to_string(&path).unwrap();
+        assert!(current.contains("\"status\":201"));
+        let rotated = std::fs::read_to_string(tmp.path().join("logs/access.jsonl.1")).unwrap();
+        assert!(rotated.contains("\"status\":500"));
+        assert!(tmp.path().join("logs/access.jsonl.2").exists());
+        assert!(!tmp.path().join("logs/access.jsonl.3").exists());
+    }
+}
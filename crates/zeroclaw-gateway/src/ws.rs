@@ -185,7 +185,7 @@ pub async fn handle_ws_chat(
 }
 
 /// Gateway session key prefix to avoid collisions with channel sessions.
-const GW_SESSION_PREFIX: &str = "gw_";
+pub(crate) const GW_SESSION_PREFIX: &str = "gw_";
 
 async fn resolve_ws_memory_handle(
     config: &zeroclaw_config::schema::Config,
@@ -788,7 +788,7 @@ fn resolve_session_cwd(
     })
 }
 
-fn resolve_ws_session_cwd(
+pub(crate) fn resolve_ws_session_cwd(
     requested_cwd: Option<&str>,
     config: &zeroclaw_config::schema::Config,
     agent_alias: &str,
@@ -823,7 +823,7 @@ fn session_queue_ws_error_code(error: &crate::session_queue::SessionQueueError)
     }
 }
 
-fn persist_conversation_messages(
+pub(crate) fn persist_conversation_messages(
     backend: &dyn zeroclaw_infra::session_backend::SessionBackend,
     session_key: &str,
     messages: &[zeroclaw_providers::ConversationMessage],
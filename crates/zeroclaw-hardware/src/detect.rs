@@ -0,0 +1,348 @@
+//! Peripheral auto-detection — match serial ports to board profiles by USB
+//! VID/PID and confirm each match with the firmware `capabilities` command.
+
+#![cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+
+use super::{discover, registry};
+use crate::peripherals::serial::SerialPeripheral;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use zeroclaw_config::schema::PeripheralBoardConfig;
+
+/// File under `<data_dir>/state/` holding the last detection table.
+pub const DETECTION_CACHE_FILE: &str = "peripheral_detection.json";
+
+/// Baud rate used for the probe; matches the firmware default.
+const PROBE_BAUD: u32 = 115_200;
+
+/// A serial port matched against the board registry and probed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DetectedPeripheral {
+    pub port_path: String,
+    pub vid: u16,
+    pub pid: u16,
+    /// Board profiles this VID/PID could be (see `registry::board_candidates`).
+    pub candidates: Vec<String>,
+    /// Resolved board profile, when the match is unambiguous.
+    pub board: Option<String>,
+    /// Firmware-reported capabilities (`gpio`, `led_pin`, ...).
+    #[serde(default)]
+    pub capabilities: Option<Value>,
+    /// Why the probe failed (port busy, no ZeroClaw firmware, timeout).
+    #[serde(default)]
+    pub probe_error: Option<String>,
+}
+
+impl DetectedPeripheral {
+    /// Firmware answered the `capabilities` probe.
+    pub fn probed(&self) -> bool {
+        self.capabilities.is_some()
+    }
+
+    /// Probed, but more than one board profile still fits.
+    pub fn is_ambiguous(&self) -> bool {
+        self.probed() && self.board.is_none() && self.candidates.len() > 1
+    }
+
+    /// Board config to attach, if probed and resolved.
+    pub fn board_config(&self) -> Option<PeripheralBoardConfig> {
+        if !self.probed() {
+            return None;
+        }
+        self.board.as_ref().map(|board| self.config_for(board))
+    }
+
+    fn config_for(&self, board: &str) -> PeripheralBoardConfig {
+        PeripheralBoardConfig {
+            board: board.to_string(),
+            transport: "serial".into(),
+            path: Some(self.port_path.clone()),
+            baud: PROBE_BAUD,
+            name: None,
+        }
+    }
+}
+
+/// Detection table persisted between runs so `zeroclaw peripheral list`
+/// can show firmware capabilities without reopening the ports.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DetectionCache {
+    /// Unix seconds of the scan that produced this table.
+    pub scanned_at: u64,
+    pub peripherals: Vec<DetectedPeripheral>,
+}
+
+/// Pick a board profile from the candidates. A `board` field in the
+/// firmware capabilities wins when it names one of the candidates;
+/// otherwise a single candidate is taken as-is and several stay unresolved.
+pub fn resolve_board(candidates: &[String], capabilities: Option<&Value>) -> Option<String> {
+    let reported = capabilities
+        .and_then(|c| c.get("board"))
+        .and_then(Value::as_str);
+    if let Some(reported) = reported
+        && let Some(found) = candidates.iter().find(|c| c.as_str() == reported)
+    {
+        return Some(found.clone());
+    }
+    match candidates {
+        [only] => Some(only.clone()),
+        _ => None,
+    }
+}
+
+/// Enumerate serial ports with a known VID/PID and probe each one.
+pub async fn detect_peripherals() -> Vec<DetectedPeripheral> {
+    let mut detected = Vec::new();
+    for dev in discover::scan_serial_devices() {
+        if dev.vid == 0 {
+            continue;
+        }
+        let candidates: Vec<String> = registry::board_candidates(dev.vid, dev.pid)
+            .into_iter()
+            .map(String::from)
+            .collect();
+        if candidates.is_empty() {
+            continue;
+        }
+
+        let (capabilities, probe_error) = match probe_capabilities(&dev.port_path).await {
+            Ok(caps) => (Some(caps), None),
+            Err(e) => {
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "path": dev.port_path,
+                            "error": format!("{}", e),
+                        })),
+                    "peripheral capabilities probe failed"
+                );
+                (None, Some(e.to_string()))
+            }
+        };
+        let board = if capabilities.is_some() {
+            resolve_board(&candidates, capabilities.as_ref())
+        } else {
+            None
+        };
+
+        detected.push(DetectedPeripheral {
+            port_path: dev.port_path,
+            vid: dev.vid,
+            pid: dev.pid,
+            candidates,
+            board,
+            capabilities,
+            probe_error,
+        });
+    }
+    detected
+}
+
+/// Open the port and send `capabilities`. The serial transport applies its
+/// own request timeout, so a silent port fails instead of hanging.
+async fn probe_capabilities(path: &str) -> Result<Value> {
+    let config = PeripheralBoardConfig {
+        board: "probe".into(),
+        transport: "serial".into(),
+        path: Some(path.to_string()),
+        baud: PROBE_BAUD,
+        name: None,
+    };
+    let peripheral = SerialPeripheral::connect(&config).await?;
+    let result = peripheral.transport().capabilities().await?;
+    if !result.success {
+        anyhow::bail!(
+            "capabilities probe rejected: {}",
+            result.error.as_deref().unwrap_or("unknown error")
+        );
+    }
+    let output = result.output.as_str();
+    Ok(serde_json::from_str(output).unwrap_or_else(|_| Value::String(output.to_string())))
+}
+
+/// Board configs for every probed device. Ambiguous devices are resolved
+/// with `choose` (which receives the device and returns the picked profile);
+/// without a chooser they fail with the list of ports and candidates.
+pub fn resolve_auto_peripherals(
+    detected: &[DetectedPeripheral],
+    mut choose: Option<&mut dyn FnMut(&DetectedPeripheral) -> Result<String>>,
+) -> Result<Vec<PeripheralBoardConfig>> {
+    let mut boards = Vec::new();
+    let mut unresolved = Vec::new();
+    for device in detected {
+        if let Some(config) = device.board_config() {
+            boards.push(config);
+        } else if device.is_ambiguous() {
+            match choose.as_mut() {
+                Some(choose) => {
+                    let board = choose(device)?;
+                    boards.push(device.config_for(&board));
+                }
+                None => unresolved.push(format!(
+                    "  {} ({:04x}:{:04x}): {}",
+                    device.port_path,
+                    device.vid,
+                    device.pid,
+                    device.candidates.join(", ")
+                )),
+            }
+        }
+    }
+    if !unresolved.is_empty() {
+        anyhow::bail!(
+            "Ambiguous boards detected; attach them explicitly with --peripheral <board>:<path>\n{}",
+            unresolved.join("\n")
+        );
+    }
+    Ok(boards)
+}
+
+/// Interactive chooser for `resolve_auto_peripherals`.
+pub fn prompt_for_board(device: &DetectedPeripheral) -> Result<String> {
+    let idx = dialoguer::Select::new()
+        .with_prompt(format!(
+            "Which board is on {} ({:04x}:{:04x})?",
+            device.port_path, device.vid, device.pid
+        ))
+        .items(&device.candidates)
+        .default(0)
+        .interact()?;
+    Ok(device.candidates[idx].clone())
+}
+
+fn cache_path(state_dir: &Path) -> PathBuf {
+    state_dir.join(DETECTION_CACHE_FILE)
+}
+
+/// Persist the detection table under `state_dir`.
+pub fn save_detection_cache(state_dir: &Path, detected: &[DetectedPeripheral]) -> Result<()> {
+    std::fs::create_dir_all(state_dir)?;
+    let cache = DetectionCache {
+        scanned_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        peripherals: detected.to_vec(),
+    };
+    std::fs::write(cache_path(state_dir), serde_json::to_vec_pretty(&cache)?)?;
+    Ok(())
+}
+
+/// Load the last detection table, if a scan has been run.
+pub fn load_detection_cache(state_dir: &Path) -> Result<Option<DetectionCache>> {
+    let path = cache_path(state_dir);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let raw = std::fs::read(path)?;
+    Ok(Some(serde_json::from_slice(&raw)?))
+}
+
+/// Print the detection table.
+pub fn print_detection_table(detected: &[DetectedPeripheral]) {
+    if detected.is_empty() {
+        println!("No known boards detected on serial ports.");
+        return;
+    }
+    println!("Detected boards:");
+    println!();
+    for d in detected {
+        let board = match (&d.board, d.is_ambiguous()) {
+            (Some(board), _) => board.clone(),
+            (None, true) => format!("ambiguous: {}", d.candidates.join(" | ")),
+            (None, false) => d.candidates.join(" | "),
+        };
+        let status = match (&d.capabilities, &d.probe_error) {
+            (Some(caps), _) => caps.to_string(),
+            (None, Some(err)) => format!("probe failed: {err}"),
+            (None, None) => "not probed".to_string(),
+        };
+        println!(
+            "  {}  {:04x}:{:04x}  {}  {}",
+            d.port_path, d.vid, d.pid, board, status
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn device(candidates: &[&str], board: Option<&str>, probed: bool) -> DetectedPeripheral {
+        DetectedPeripheral {
+            port_path: "/dev/ttyUSB0".into(),
+            vid: 0x1a86,
+            pid: 0x7523,
+            candidates: candidates.iter().map(|c| c.to_string()).collect(),
+            board: board.map(String::from),
+            capabilities: probed.then(|| json!({"gpio": [2, 13]})),
+            probe_error: (!probed).then(|| "timed out".to_string()),
+        }
+    }
+
+    #[test]
+    fn resolve_board_prefers_firmware_reported_board() {
+        let candidates = vec!["esp32".to_string(), "arduino-uno".to_string()];
+        let caps = json!({"gpio": [13], "board": "arduino-uno"});
+        assert_eq!(
+            resolve_board(&candidates, Some(&caps)).as_deref(),
+            Some("arduino-uno")
+        );
+        assert_eq!(
+            resolve_board(&candidates, Some(&json!({"gpio": [13]}))),
+            None
+        );
+    }
+
+    #[test]
+    fn resolve_board_takes_single_candidate() {
+        let candidates = vec!["nucleo-f401re".to_string()];
+        assert_eq!(
+            resolve_board(&candidates, Some(&json!({"board": "other"}))).as_deref(),
+            Some("nucleo-f401re")
+        );
+    }
+
+    #[test]
+    fn auto_attach_skips_unprobed_and_fails_on_ambiguous_without_chooser() {
+        let resolved = device(&["esp32"], Some("esp32"), true);
+        let unprobed = device(&["esp32"], None, false);
+        let boards = resolve_auto_peripherals(&[resolved.clone(), unprobed.clone()], None).unwrap();
+        assert_eq!(boards.len(), 1);
+        assert_eq!(boards[0].board, "esp32");
+        assert_eq!(boards[0].path.as_deref(), Some("/dev/ttyUSB0"));
+
+        let ambiguous = device(&["esp32", "arduino-uno"], None, true);
+        let err = resolve_auto_peripherals(&[resolved, ambiguous], None)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("/dev/ttyUSB0"));
+        assert!(err.contains("esp32, arduino-uno"));
+    }
+
+    #[test]
+    fn auto_attach_uses_chooser_for_ambiguous() {
+        let ambiguous = device(&["esp32", "arduino-uno"], None, true);
+        let mut choose = |d: &DetectedPeripheral| -> Result<String> { Ok(d.candidates[1].clone()) };
+        let boards = resolve_auto_peripherals(&[ambiguous], Some(&mut choose)).unwrap();
+        assert_eq!(boards[0].board, "arduino-uno");
+    }
+
+    #[test]
+    fn detection_cache_round_trips() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state_dir = tmp.path().join("state");
+        assert!(load_detection_cache(&state_dir).unwrap().is_none());
+
+        let detected = vec![device(&["esp32"], Some("esp32"), true)];
+        save_detection_cache(&state_dir, &detected).unwrap();
+        let cache = load_detection_cache(&state_dir).unwrap().unwrap();
+        assert_eq!(cache.peripherals, detected);
+        assert!(cache.scanned_at > 0);
+    }
+}
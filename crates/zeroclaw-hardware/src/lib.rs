@@ -16,6 +16,12 @@ pub mod transport;
 ))]
 pub mod discover;
 
+#[cfg(all(
+    feature = "hardware",
+    any(target_os = "linux", target_os = "macos", target_os = "windows")
+))]
+pub mod detect;
+
 #[cfg(all(
     feature = "hardware",
     any(target_os = "linux", target_os = "macos", target_os = "windows")
@@ -539,6 +545,28 @@ pub fn run_discover() -> Result<()> {
     Ok(())
 }
 
+/// Match serial ports to board profiles, probe them, print the detection
+/// table and cache it under `state_dir` for `zeroclaw peripheral list`.
+#[cfg(all(
+    feature = "hardware",
+    any(target_os = "linux", target_os = "macos", target_os = "windows")
+))]
+pub async fn run_scan(state_dir: &std::path::Path) -> Result<()> {
+    let detected = detect::detect_peripherals().await;
+    detect::print_detection_table(&detected);
+    detect::save_detection_cache(state_dir, &detected)?;
+
+    if detected
+        .iter()
+        .any(detect::DetectedPeripheral::is_ambiguous)
+    {
+        println!();
+        println!("Ambiguous matches need a choice: run `zeroclaw agent --peripheral auto`");
+        println!("interactively, or pass --peripheral <board>:<path> explicitly.");
+    }
+    Ok(())
+}
+
 #[cfg(all(
     feature = "hardware",
     any(target_os = "linux", target_os = "macos", target_os = "windows")
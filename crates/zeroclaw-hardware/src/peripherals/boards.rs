@@ -0,0 +1,147 @@
+//! Attached serial boards, addressed by name.
+//!
+//! Each board keeps its own [`SerialTransport`] (writer, reader task, and
+//! id-correlated pending requests), so requests to different boards are in
+//! flight at the same time. Tools take a `board` argument naming the target;
+//! it may be omitted only while a single board is attached.
+
+use super::serial::SerialTransport;
+use serde_json::{Value, json};
+use std::sync::{Arc, OnceLock};
+use zeroclaw_api::tool::ToolResult;
+
+/// Called when a board connects (`None`) or detaches (`Some(reason)`).
+pub type ConnectionObserver = fn(board: &str, error: Option<&str>);
+
+static CONNECTION_OBSERVER: OnceLock<ConnectionObserver> = OnceLock::new();
+
+/// Register the hook that reports board connection state. The binary wires
+/// it to the daemon health registry (`peripheral:<name>` components), which
+/// is what `zeroclaw peripheral list` reads back.
+pub fn set_connection_observer(observer: ConnectionObserver) {
+    let _ = CONNECTION_OBSERVER.set(observer);
+}
+
+pub(crate) fn report_connection(board: &str, error: Option<&str>) {
+    if let Some(observer) = CONNECTION_OBSERVER.get() {
+        observer(board, error);
+    }
+}
+
+/// Serial boards shared by the peripheral tools.
+pub struct SerialBoards {
+    boards: Vec<(String, Arc<SerialTransport>)>,
+}
+
+impl SerialBoards {
+    pub fn new(boards: Vec<(String, Arc<SerialTransport>)>) -> Self {
+        Self { boards }
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.boards.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// The transport named by the `board` argument. Without one, the only
+    /// attached board. Unknown or ambiguous targets come back as a failed
+    /// [`ToolResult`] listing the attached boards.
+    pub fn resolve(&self, args: &Value) -> Result<&Arc<SerialTransport>, ToolResult> {
+        let requested = args
+            .get("board")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|board| !board.is_empty());
+        let found = match requested {
+            Some(board) => self
+                .boards
+                .iter()
+                .find(|(name, _)| name == board)
+                .map(|(_, transport)| transport),
+            None if self.boards.len() == 1 => Some(&self.boards[0].1),
+            None => None,
+        };
+        found.ok_or_else(|| {
+            let attached = self.names().join(", ");
+            let error = match requested {
+                Some(board) => format!("Unknown board '{board}'. Attached boards: {attached}"),
+                None => format!("Several boards are attached; pass 'board' as one of: {attached}"),
+            };
+            ToolResult {
+                success: false,
+                output: String::new().into(),
+                error: Some(error),
+            }
+        })
+    }
+
+    /// Add the `board` property to a tool's parameter schema, required when
+    /// more than one board is attached.
+    pub fn with_board_param(&self, mut schema: Value) -> Value {
+        let names = self.names();
+        schema["properties"]["board"] = json!({
+            "type": "string",
+            "enum": names,
+            "description": "Attached board to use. Optional when only one board is attached."
+        });
+        if names.len() > 1 {
+            match schema.get_mut("required").and_then(Value::as_array_mut) {
+                Some(required) => required.push(json!("board")),
+                None => schema["required"] = json!(["board"]),
+            }
+        }
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boards(names: &[&str]) -> SerialBoards {
+        SerialBoards::new(
+            names
+                .iter()
+                .map(|name| {
+                    let (host, _device) = tokio::io::duplex(64);
+                    (
+                        (*name).to_string(),
+                        Arc::new(SerialTransport::spawn(name, host)),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn board_argument_is_validated_against_attached_boards() {
+        let single = boards(&["nucleo"]);
+        assert!(single.resolve(&json!({})).is_ok());
+        assert!(single.resolve(&json!({"board": "nucleo"})).is_ok());
+
+        let pair = boards(&["nucleo", "esp32:/dev/ttyUSB0"]);
+        let ambiguous = pair.resolve(&json!({"pin": 13})).unwrap_err();
+        assert!(
+            ambiguous
+                .error
+                .unwrap()
+                .contains("nucleo, esp32:/dev/ttyUSB0")
+        );
+        let unknown = pair.resolve(&json!({"board": "uno"})).unwrap_err();
+        assert!(unknown.error.unwrap().starts_with("Unknown board 'uno'"));
+        let esp = pair
+            .resolve(&json!({"board": "esp32:/dev/ttyUSB0"}))
+            .unwrap();
+        assert_eq!(esp.name(), "esp32:/dev/ttyUSB0");
+    }
+
+    #[tokio::test]
+    async fn board_param_is_required_only_with_several_boards() {
+        let schema = json!({"type": "object", "properties": {}, "required": ["pin"]});
+        let single = boards(&["nucleo"]).with_board_param(schema.clone());
+        assert_eq!(single["required"], json!(["pin"]));
+        assert_eq!(single["properties"]["board"]["enum"], json!(["nucleo"]));
+
+        let pair = boards(&["a", "b"]).with_board_param(schema);
+        assert_eq!(pair["required"], json!(["pin", "board"]));
+    }
+}
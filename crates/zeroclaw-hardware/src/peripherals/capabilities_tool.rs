@@ -29,7 +29,7 @@ impl Tool for HardwareCapabilitiesTool {
     }
 
     fn description(&self) -> &str {
-        "Query connected hardware for reported GPIO pins and LED pin. Use when: user asks what pins are available."
+        "Query connected hardware for reported GPIO pins, LED pin and streamable pins. Use when: user asks what pins are available."
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
@@ -77,6 +77,9 @@ impl Tool for HardwareCapabilitiesTool {
                                     .unwrap_or_else(|_| devices.to_string());
                                 s.push_str(&format!("\n  pin_devices: {pretty}"));
                             }
+                            if let Some(stream) = parsed.get("stream") {
+                                s.push_str(&format!("\n  stream: {stream}"));
+                            }
                             s
                         } else {
                             format!("{}: {}", board_name, result.output)
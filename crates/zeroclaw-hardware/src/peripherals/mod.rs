@@ -12,12 +12,16 @@ pub mod arduino_flash;
 #[cfg(feature = "hardware")]
 pub mod arduino_upload;
 #[cfg(feature = "hardware")]
+pub mod boards;
+#[cfg(feature = "hardware")]
 pub mod capabilities_tool;
 #[cfg(feature = "hardware")]
 pub mod nucleo_flash;
 #[cfg(feature = "hardware")]
 pub mod smartroom;
 #[cfg(feature = "hardware")]
+pub mod stream;
+#[cfg(feature = "hardware")]
 pub mod uno_q_bridge;
 #[cfg(feature = "hardware")]
 pub mod uno_q_setup;
@@ -44,6 +48,11 @@ pub fn list_configured_boards(config: &PeripheralsConfig) -> Vec<&PeripheralBoar
 
 /// Create and connect peripherals from config, returning their tools.
 /// Returns empty vec if peripherals disabled or hardware feature off.
+///
+/// Serial boards share one set of GPIO and stream tools whose `board`
+/// argument names the target (see [`boards::SerialBoards`]); each board
+/// keeps its own connection, so requests to different boards run
+/// concurrently.
 #[cfg(feature = "hardware")]
 pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<Box<dyn Tool>>> {
     if !config.enabled || config.boards.is_empty() {
@@ -52,6 +61,8 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
 
     let mut tools: Vec<Box<dyn Tool>> = Vec::new();
     let mut serial_transports: Vec<(String, std::sync::Arc<serial::SerialTransport>)> = Vec::new();
+    let mut smartroom_transports: Vec<(String, std::sync::Arc<serial::SerialTransport>)> =
+        Vec::new();
 
     for board in &config.boards {
         // Arduino Uno Q: Bridge transport (socket to local Bridge app)
@@ -108,6 +119,22 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
             );
             continue;
         }
+        let session_name = board.session_name();
+        if serial_transports
+            .iter()
+            .any(|(name, _)| *name == session_name)
+        {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Unknown),
+                &format!(
+                    "Skipping serial board {}: name '{session_name}' is already attached; set a distinct `name`",
+                    board.board
+                )
+            );
+            continue;
+        }
 
         match serial::SerialPeripheral::connect(board).await {
             Ok(peripheral) => {
@@ -120,8 +147,7 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
                         &format!("Peripheral {} connect warning (continuing)", p.name())
                     );
                 }
-                serial_transports.push((board.board.clone(), p.transport()));
-                tools.extend(p.tools());
+                serial_transports.push((session_name.clone(), p.transport()));
                 if board.board == "arduino-uno"
                     && let Some(ref path) = board.path
                 {
@@ -138,23 +164,15 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
                 // Smart-room named device tools (ESP32 / ESP32 simulator).
                 // Lets the model use device names instead of guessing pin numbers.
                 if board.board == "esp32" || board.board == "esp32-sim" {
-                    let transport = p.transport();
-                    tools.push(Box::new(smartroom::SetDeviceTool {
-                        transport: transport.clone(),
-                    }));
-                    tools.push(Box::new(smartroom::ReadDeviceTool { transport }));
-                    ::zeroclaw_log::record!(
-                        INFO,
-                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                            .with_attrs(::serde_json::json!({"board": board.board})),
-                        "Smart-room device tools added (set_device, read_device)"
-                    );
+                    smartroom_transports.push((session_name.clone(), p.transport()));
                 }
 
                 ::zeroclaw_log::record!(
                     INFO,
                     ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                        .with_attrs(::serde_json::json!({"board": board.board})),
+                        .with_attrs(
+                            ::serde_json::json!({"board": board.board, "name": session_name})
+                        ),
                     "Serial peripheral connected"
                 );
             }
@@ -169,6 +187,23 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
         }
     }
 
+    if !serial_transports.is_empty() {
+        let boards = std::sync::Arc::new(boards::SerialBoards::new(serial_transports.clone()));
+        tools.extend(serial::board_tools(&boards));
+    }
+    if !smartroom_transports.is_empty() {
+        let boards = std::sync::Arc::new(boards::SerialBoards::new(smartroom_transports));
+        tools.push(Box::new(smartroom::SetDeviceTool {
+            boards: boards.clone(),
+        }));
+        tools.push(Box::new(smartroom::ReadDeviceTool { boards }));
+        ::zeroclaw_log::record!(
+            INFO,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
+            "Smart-room device tools added (set_device, read_device)"
+        );
+    }
+
     // Phase B: Add hardware tools when any boards configured
     if !tools.is_empty() {
         let board_names: Vec<String> = config.boards.iter().map(|b| b.board.clone()).collect();
@@ -236,6 +271,7 @@ mod tests {
                 transport: "serial".into(),
                 path: Some("/dev/ttyACM0".into()),
                 baud: 115_200,
+                name: None,
             }],
             datasheet_dir: None,
         };
@@ -256,12 +292,14 @@ mod tests {
                     transport: "serial".into(),
                     path: Some("/dev/ttyACM0".into()),
                     baud: 115_200,
+                    name: None,
                 },
                 PeripheralBoardConfig {
                     board: "rpi-gpio".into(),
                     transport: "native".into(),
                     path: None,
                     baud: 115_200,
+                    name: None,
                 },
             ],
             datasheet_dir: None,
@@ -1,16 +1,19 @@
 //! Serial peripheral — STM32 and similar boards over USB CDC/serial.
 
+use super::boards::SerialBoards;
 use crate::peripherals::Peripheral;
 #[cfg(unix)]
 use crate::util::should_open_serial_nonexclusive;
 use crate::util::{is_serial_path_allowed, serial_open_baud, serial_path_allowlist_hint};
 use async_trait::async_trait;
-use portable_atomic::{AtomicU64, Ordering};
+use portable_atomic::{AtomicBool, AtomicU64, Ordering};
 use serde_json::{Value, json};
-use std::sync::Arc;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex as StdMutex};
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
-use tokio::sync::Mutex;
-use tokio_serial::{SerialPortBuilderExt, SerialStream};
+use tokio::sync::{Mutex, oneshot};
+use tokio::task::JoinHandle;
+use tokio_serial::SerialPortBuilderExt;
 use zeroclaw_api::attribution::ToolKind;
 use zeroclaw_api::tool::{Tool, ToolResult};
 use zeroclaw_api::tool_attribution;
@@ -22,71 +25,307 @@ tool_attribution!(GpioWriteTool, ToolKind::Plugin);
 /// Timeout for serial request/response (seconds).
 const SERIAL_TIMEOUT_SECS: u64 = 5;
 
-/// Maximum malformed or non-matching response frames skipped per request.
-const MAX_SKIPPED_RESPONSE_FRAMES: usize = 16;
+/// Longest frame the reader accepts; longer lines are dropped whole.
+const MAX_FRAME_BYTES: usize = 4096;
 
-/// JSON request/response over serial.
-async fn send_request<S>(port: &mut S, cmd: &str, args: Value) -> anyhow::Result<Value>
-where
-    S: AsyncRead + AsyncWrite + Unpin,
-{
-    static ID: AtomicU64 = AtomicU64::new(0);
-    let id = ID.fetch_add(1, Ordering::Relaxed);
-    let id_str = id.to_string();
+/// Samples kept per subscription; older ones are discarded first.
+const MAX_BUFFERED_SAMPLES: usize = 256;
 
-    let req = json!({
-        "id": id_str,
-        "cmd": cmd,
-        "args": args
-    });
-    let line = format!("{}\n", req);
+type PendingResponses = Arc<StdMutex<HashMap<String, oneshot::Sender<Value>>>>;
 
-    port.write_all(line.as_bytes()).await?;
-    port.flush().await?;
+/// One `{"event":"sample",...}` line pushed by the firmware.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamSample {
+    pub value: Value,
+    /// Firmware uptime when the sample was taken, if reported.
+    pub t_ms: Option<u64>,
+    /// Host wall-clock time the line was read (Unix milliseconds).
+    pub received_at_ms: u64,
+}
 
-    let mut skipped_frames = 0;
-    loop {
-        let mut buf = Vec::new();
-        let mut byte = [0u8; 1];
-        loop {
-            port.read_exact(&mut byte).await?;
-            if byte[0] == b'\n' {
-                break;
+/// Recent samples per subscription id, filled by the serial reader.
+#[derive(Default)]
+pub struct StreamBuffer {
+    samples: StdMutex<HashMap<String, VecDeque<StreamSample>>>,
+}
+
+impl StreamBuffer {
+    /// Start buffering for `sub_id` (a subscription the host created).
+    pub(crate) fn open(&self, sub_id: &str) {
+        self.lock().entry(sub_id.to_string()).or_default();
+    }
+
+    pub(crate) fn close(&self, sub_id: &str) -> bool {
+        self.lock().remove(sub_id).is_some()
+    }
+
+    fn push(&self, sub_id: &str, sample: StreamSample) {
+        let mut samples = self.lock();
+        // Only subscriptions this host opened are buffered; samples for ids
+        // left over from an earlier session are dropped.
+        if let Some(buffer) = samples.get_mut(sub_id) {
+            if buffer.len() == MAX_BUFFERED_SAMPLES {
+                buffer.pop_front();
             }
-            buf.push(byte[0]);
+            buffer.push_back(sample);
         }
+    }
 
-        if let Ok(resp) = serde_json::from_slice::<Value>(&buf)
-            && resp.get("id").and_then(Value::as_str) == Some(id_str.as_str())
-        {
-            return Ok(resp);
+    /// The newest `n` samples for `sub_id`, oldest first. `None` when the
+    /// subscription is unknown.
+    pub(crate) fn latest(&self, sub_id: &str, n: usize) -> Option<Vec<StreamSample>> {
+        let samples = self.lock();
+        let buffer = samples.get(sub_id)?;
+        Some(
+            buffer
+                .iter()
+                .skip(buffer.len().saturating_sub(n))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, VecDeque<StreamSample>>> {
+        self.samples.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Route one frame from the device: sample events go to `streams`, responses
+/// to the request waiting on their `id`. Anything else is dropped.
+fn dispatch_frame(frame: &[u8], pending: &PendingResponses, streams: &StreamBuffer) {
+    let Ok(frame) = serde_json::from_slice::<Value>(frame) else {
+        return;
+    };
+    if let Some(event) = frame.get("event").and_then(Value::as_str) {
+        let sub_id = match frame.get("id") {
+            Some(Value::String(id)) => id.clone(),
+            Some(Value::Number(id)) => id.to_string(),
+            _ => return,
+        };
+        if event == "sample" {
+            let received_at_ms = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX));
+            streams.push(
+                &sub_id,
+                StreamSample {
+                    value: frame.get("value").cloned().unwrap_or(Value::Null),
+                    t_ms: frame.get("t_ms").and_then(Value::as_u64),
+                    received_at_ms,
+                },
+            );
         }
+        return;
+    }
+    let waiter = frame
+        .get("id")
+        .and_then(Value::as_str)
+        .and_then(|id| pending.lock().unwrap_or_else(|e| e.into_inner()).remove(id));
+    if let Some(waiter) = waiter {
+        let _ = waiter.send(frame);
+    }
+}
 
-        if skipped_frames == MAX_SKIPPED_RESPONSE_FRAMES {
-            anyhow::bail!(
-                "Serial response skip limit exceeded (maximum {MAX_SKIPPED_RESPONSE_FRAMES} frames)"
+/// Connection state shared by a transport and its reader task.
+struct Link {
+    name: String,
+    connected: AtomicBool,
+}
+
+impl Link {
+    /// Mark the board detached; only the first call logs and reports.
+    fn detach(&self, reason: &str) {
+        if self.connected.swap(false, Ordering::AcqRel) {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Disconnect)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({"board": self.name, "reason": reason})),
+                "serial peripheral detached"
             );
+            super::boards::report_connection(&self.name, Some(reason));
         }
-        skipped_frames += 1;
+    }
+}
+
+/// Read newline-delimited frames until the port closes, demultiplexing
+/// unsolicited events from responses.
+async fn read_frames<R>(
+    mut reader: R,
+    pending: PendingResponses,
+    streams: Arc<StreamBuffer>,
+    link: Arc<Link>,
+) where
+    R: AsyncRead + Unpin,
+{
+    let mut chunk = [0u8; 512];
+    let mut frame = Vec::new();
+    let mut oversized = false;
+    loop {
+        let n = match reader.read(&mut chunk).await {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) => {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Disconnect)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"error": format!("{}", e)})),
+                    "serial peripheral read failed"
+                );
+                break;
+            }
+        };
+        for &byte in &chunk[..n] {
+            if byte == b'\n' {
+                if !oversized {
+                    dispatch_frame(&frame, &pending, &streams);
+                }
+                frame.clear();
+                oversized = false;
+            } else if frame.len() < MAX_FRAME_BYTES {
+                frame.push(byte);
+            } else {
+                oversized = true;
+            }
+        }
+    }
+    // Detach first so requests failed by dropping their senders below
+    // report a detached board rather than a generic closed connection.
+    link.detach("serial connection closed");
+    pending.lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// Removes a request's response slot when the request finishes or is
+/// abandoned (timeout), so late responses are dropped.
+struct PendingSlot<'a> {
+    pending: &'a PendingResponses,
+    id: String,
+}
+
+impl Drop for PendingSlot<'_> {
+    fn drop(&mut self) {
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&self.id);
     }
 }
 
 /// Shared serial transport for tools. Pub(crate) for capabilities tool.
+///
+/// A background reader owns the receive side: responses are matched to
+/// requests by `id`, and `{"event":"sample"}` lines from `subscribe` streams
+/// land in [`StreamBuffer`]. Once the port closes the transport is detached
+/// and every request fails fast with a tool error naming the board.
 pub struct SerialTransport {
-    port: Mutex<SerialStream>,
+    writer: Mutex<Box<dyn AsyncWrite + Send + Unpin>>,
+    pending: PendingResponses,
+    streams: Arc<StreamBuffer>,
+    link: Arc<Link>,
+    reader: JoinHandle<()>,
 }
 
 impl SerialTransport {
+    pub(crate) fn spawn<S>(name: &str, stream: S) -> Self
+    where
+        S: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let (reader, writer) = tokio::io::split(stream);
+        let pending = PendingResponses::default();
+        let streams = Arc::new(StreamBuffer::default());
+        let link = Arc::new(Link {
+            name: name.to_string(),
+            connected: AtomicBool::new(true),
+        });
+        super::boards::report_connection(name, None);
+        let reader = tokio::spawn(read_frames(
+            reader,
+            pending.clone(),
+            streams.clone(),
+            link.clone(),
+        ));
+        Self {
+            writer: Mutex::new(Box::new(writer)),
+            pending,
+            streams,
+            link,
+            reader,
+        }
+    }
+
+    /// The board's session name (`name` from config, or `board:path`).
+    pub fn name(&self) -> &str {
+        &self.link.name
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.link.connected.load(Ordering::Acquire)
+    }
+
+    fn detached_result(&self) -> ToolResult {
+        ToolResult {
+            success: false,
+            output: String::new().into(),
+            error: Some(format!(
+                "Peripheral '{}' is detached; reconnect it and restart the daemon",
+                self.link.name
+            )),
+        }
+    }
+
+    /// JSON request/response over serial.
+    async fn send(&self, cmd: &str, args: Value) -> anyhow::Result<Value> {
+        static ID: AtomicU64 = AtomicU64::new(0);
+        let id = ID.fetch_add(1, Ordering::Relaxed).to_string();
+
+        let (tx, rx) = oneshot::channel();
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(id.clone(), tx);
+        let _slot = PendingSlot {
+            pending: &self.pending,
+            id: id.clone(),
+        };
+
+        let req = json!({
+            "id": id,
+            "cmd": cmd,
+            "args": args
+        });
+        let line = format!("{}\n", req);
+        {
+            let mut writer = self.writer.lock().await;
+            let written = match writer.write_all(line.as_bytes()).await {
+                Ok(()) => writer.flush().await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = written {
+                self.link.detach(&format!("serial write failed: {e}"));
+                return Err(e.into());
+            }
+        }
+
+        rx.await
+            .map_err(|_| anyhow::Error::msg("Serial connection closed"))
+    }
+
     pub(crate) async fn request(&self, cmd: &str, args: Value) -> anyhow::Result<ToolResult> {
-        let mut port = self.port.lock().await;
-        // One timeout covers the request and every skipped frame, so stale or
+        if !self.is_connected() {
+            return Ok(self.detached_result());
+        }
+        // One timeout covers the write and the wait, so unsolicited or
         // malformed input cannot restart the deadline.
-        let resp = tokio::time::timeout(
+        let sent = tokio::time::timeout(
             std::time::Duration::from_secs(SERIAL_TIMEOUT_SECS),
-            send_request(&mut *port, cmd, args),
+            self.send(cmd, args),
         )
-        .await
-        .map_err(|_| {
+        .await;
+        if matches!(sent, Ok(Err(_))) && !self.is_connected() {
+            return Ok(self.detached_result());
+        }
+        let resp = sent.map_err(|_| {
             ::zeroclaw_log::record!(
                 WARN,
                 ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Timeout)
@@ -120,6 +359,17 @@ impl SerialTransport {
     pub async fn capabilities(&self) -> anyhow::Result<ToolResult> {
         self.request("capabilities", json!({})).await
     }
+
+    /// Samples pushed by `subscribe` streams on this port.
+    pub fn streams(&self) -> &StreamBuffer {
+        &self.streams
+    }
+}
+
+impl Drop for SerialTransport {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
 }
 
 /// Serial peripheral for STM32, Arduino, etc. over USB CDC.
@@ -174,10 +424,8 @@ impl SerialPeripheral {
             anyhow::Error::msg(format!("Failed to open {path}: {e}"))
         })?;
 
-        let name = format!("{}-{}", config.board, path.replace('/', "_"));
-        let transport = Arc::new(SerialTransport {
-            port: Mutex::new(port),
-        });
+        let name = config.session_name();
+        let transport = Arc::new(SerialTransport::spawn(&name, port));
 
         Ok(Self {
             name: name.clone(),
@@ -214,17 +462,35 @@ impl Peripheral for SerialPeripheral {
     }
 
     fn tools(&self) -> Vec<Box<dyn Tool>> {
-        vec![
-            Box::new(GpioReadTool {
-                transport: self.transport.clone(),
-            }),
-            Box::new(GpioWriteTool {
-                transport: self.transport.clone(),
-            }),
-        ]
+        let boards = Arc::new(SerialBoards::new(vec![(
+            self.name.clone(),
+            self.transport.clone(),
+        )]));
+        board_tools(&boards)
     }
 }
 
+/// GPIO and sample-stream tools over every board in `boards`.
+pub fn board_tools(boards: &Arc<SerialBoards>) -> Vec<Box<dyn Tool>> {
+    vec![
+        Box::new(GpioReadTool {
+            boards: boards.clone(),
+        }),
+        Box::new(GpioWriteTool {
+            boards: boards.clone(),
+        }),
+        Box::new(super::stream::PeripheralSubscribeTool {
+            boards: boards.clone(),
+        }),
+        Box::new(super::stream::PeripheralUnsubscribeTool {
+            boards: boards.clone(),
+        }),
+        Box::new(super::stream::PeripheralReadStreamTool {
+            boards: boards.clone(),
+        }),
+    ]
+}
+
 impl SerialPeripheral {
     /// Expose transport for capabilities tool (Phase C).
     pub fn transport(&self) -> Arc<SerialTransport> {
@@ -234,7 +500,7 @@ impl SerialPeripheral {
 
 /// Tool: read GPIO pin value.
 struct GpioReadTool {
-    transport: Arc<SerialTransport>,
+    boards: Arc<SerialBoards>,
 }
 
 #[async_trait]
@@ -248,7 +514,7 @@ impl Tool for GpioReadTool {
     }
 
     fn parameters_schema(&self) -> Value {
-        json!({
+        self.boards.with_board_param(json!({
             "type": "object",
             "properties": {
                 "pin": {
@@ -257,10 +523,14 @@ impl Tool for GpioReadTool {
                 }
             },
             "required": ["pin"]
-        })
+        }))
     }
 
     async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let transport = match self.boards.resolve(&args) {
+            Ok(transport) => transport,
+            Err(result) => return Ok(result),
+        };
         let pin = args.get("pin").and_then(|v| v.as_u64()).ok_or_else(|| {
             ::zeroclaw_log::record!(
                 WARN,
@@ -271,15 +541,13 @@ impl Tool for GpioReadTool {
             );
             anyhow::Error::msg("Missing 'pin' parameter")
         })?;
-        self.transport
-            .request("gpio_read", json!({ "pin": pin }))
-            .await
+        transport.request("gpio_read", json!({ "pin": pin })).await
     }
 }
 
 /// Tool: write GPIO pin value.
 struct GpioWriteTool {
-    transport: Arc<SerialTransport>,
+    boards: Arc<SerialBoards>,
 }
 
 #[async_trait]
@@ -293,7 +561,7 @@ impl Tool for GpioWriteTool {
     }
 
     fn parameters_schema(&self) -> Value {
-        json!({
+        self.boards.with_board_param(json!({
             "type": "object",
             "properties": {
                 "pin": {
@@ -306,10 +574,14 @@ impl Tool for GpioWriteTool {
                 }
             },
             "required": ["pin", "value"]
-        })
+        }))
     }
 
     async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let transport = match self.boards.resolve(&args) {
+            Ok(transport) => transport,
+            Err(result) => return Ok(result),
+        };
         let pin = args.get("pin").and_then(|v| v.as_u64()).ok_or_else(|| {
             ::zeroclaw_log::record!(
                 WARN,
@@ -330,7 +602,7 @@ impl Tool for GpioWriteTool {
             );
             anyhow::Error::msg("Missing 'value' parameter")
         })?;
-        self.transport
+        transport
             .request("gpio_write", json!({ "pin": pin, "value": value }))
             .await
     }
@@ -386,9 +658,10 @@ mod tests {
 
     #[tokio::test]
     async fn wrong_id_then_matching_response_succeeds() {
-        let (mut host, mut device) = tokio::io::duplex(4096);
+        let (host, mut device) = tokio::io::duplex(4096);
+        let transport = SerialTransport::spawn("test-board", host);
 
-        let host_request = send_request(&mut host, "ping", json!({}));
+        let host_request = transport.send("ping", json!({}));
         let device_response = async {
             let request_id = read_request_id(&mut device).await;
             write_json_frame(
@@ -410,9 +683,10 @@ mod tests {
 
     #[tokio::test]
     async fn malformed_frame_then_matching_response_succeeds() {
-        let (mut host, mut device) = tokio::io::duplex(4096);
+        let (host, mut device) = tokio::io::duplex(4096);
+        let transport = SerialTransport::spawn("test-board", host);
 
-        let host_request = send_request(&mut host, "ping", json!({}));
+        let host_request = transport.send("ping", json!({}));
         let device_response = async {
             let request_id = read_request_id(&mut device).await;
             write_frame(&mut device, b"not-json").await;
@@ -430,12 +704,13 @@ mod tests {
 
     #[tokio::test]
     async fn unsolicited_frame_flood_does_not_extend_deadline_and_stream_recovers() {
-        let (mut host, mut device) = tokio::io::duplex(4096);
+        let (host, mut device) = tokio::io::duplex(4096);
+        let transport = SerialTransport::spawn("test-board", host);
 
         let host_requests = async {
             let first = timeout(
                 Duration::from_millis(200),
-                send_request(&mut host, "first", json!({})),
+                transport.send("first", json!({})),
             )
             .await;
             assert!(
@@ -443,13 +718,10 @@ mod tests {
                 "unsolicited frames must not extend the original request deadline"
             );
 
-            let second = timeout(
-                Duration::from_secs(2),
-                send_request(&mut host, "second", json!({})),
-            )
-            .await
-            .expect("subsequent request should finish")
-            .expect("subsequent request should recover on the same stream");
+            let second = timeout(Duration::from_secs(2), transport.send("second", json!({})))
+                .await
+                .expect("subsequent request should finish")
+                .expect("subsequent request should recover on the same stream");
             assert_eq!(second["result"], "recovered");
         };
 
@@ -480,26 +752,137 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn unsolicited_frame_skip_limit_is_bounded() {
-        let (mut host, mut device) = tokio::io::duplex(4096);
+    async fn sample_events_are_buffered_apart_from_responses() {
+        let (host, mut device) = tokio::io::duplex(4096);
+        let transport = SerialTransport::spawn("test-board", host);
+        transport.streams().open("1");
 
-        let host_request = send_request(&mut host, "ping", json!({}));
+        let host_request = transport.send("ping", json!({}));
         let device_response = async {
-            let _request_id = read_request_id(&mut device).await;
-            for sequence in 0..=MAX_SKIPPED_RESPONSE_FRAMES {
+            let request_id = read_request_id(&mut device).await;
+            for value in 0..3 {
                 write_json_frame(
                     &mut device,
-                    json!({"id": format!("unsolicited-{sequence}"), "ok": true}),
+                    json!({"event": "sample", "id": "1", "value": value, "t_ms": 100 * value}),
                 )
                 .await;
             }
+            // Samples for a subscription the host never opened are dropped.
+            write_json_frame(
+                &mut device,
+                json!({"event": "sample", "id": "9", "value": 42}),
+            )
+            .await;
+            write_json_frame(
+                &mut device,
+                json!({"id": request_id, "ok": true, "result": "pong"}),
+            )
+            .await;
         };
 
         let (response, ()) = tokio::join!(host_request, device_response);
-        let error = response.expect_err("skip limit should reject an unsolicited-frame flood");
-        assert!(
-            error.to_string().contains("skip limit exceeded"),
-            "unexpected error: {error:#}"
-        );
+        assert_eq!(response.expect("response should arrive")["result"], "pong");
+
+        let latest = transport.streams().latest("1", 2).expect("open stream");
+        let values: Vec<_> = latest.iter().map(|s| s.value.clone()).collect();
+        assert_eq!(values, vec![json!(1), json!(2)]);
+        assert_eq!(latest[1].t_ms, Some(200));
+        assert!(transport.streams().latest("9", 10).is_none());
+    }
+
+    #[tokio::test]
+    async fn requests_to_different_boards_are_in_flight_together() {
+        let (host_a, mut device_a) = tokio::io::duplex(4096);
+        let (host_b, mut device_b) = tokio::io::duplex(4096);
+        let boards = Arc::new(SerialBoards::new(vec![
+            ("a".into(), Arc::new(SerialTransport::spawn("a", host_a))),
+            ("b".into(), Arc::new(SerialTransport::spawn("b", host_b))),
+        ]));
+        let tools = board_tools(&boards);
+        let gpio_write = tools.iter().find(|t| t.name() == "gpio_write").unwrap();
+
+        let to_a = gpio_write.execute(json!({"board": "a", "pin": 1, "value": 1}));
+        let to_b = gpio_write.execute(json!({"board": "b", "pin": 2, "value": 0}));
+        let devices = async {
+            let id_a = read_request_id(&mut device_a).await;
+            let id_b = read_request_id(&mut device_b).await;
+            // Board b answers while board a's request is still pending.
+            write_json_frame(
+                &mut device_b,
+                json!({"id": id_b, "ok": true, "result": "b done"}),
+            )
+            .await;
+            write_json_frame(
+                &mut device_a,
+                json!({"id": id_a, "ok": true, "result": "a done"}),
+            )
+            .await;
+        };
+
+        let (a, b, ()) = timeout(Duration::from_secs(2), async {
+            tokio::join!(to_a, to_b, devices)
+        })
+        .await
+        .expect("both boards should answer");
+        assert_eq!(a.unwrap().output, "a done");
+        assert_eq!(b.unwrap().output, "b done");
+
+        let missing = gpio_write
+            .execute(json!({"pin": 1, "value": 1}))
+            .await
+            .unwrap();
+        assert!(!missing.success);
+        assert!(missing.error.unwrap().contains("pass 'board'"));
+    }
+
+    #[tokio::test]
+    async fn detached_board_returns_a_tool_error_instead_of_hanging() {
+        let (host, mut device) = tokio::io::duplex(4096);
+        let transport = SerialTransport::spawn("nucleo", host);
+
+        let in_flight = transport.request("ping", json!({}));
+        let unplug = async move {
+            let _ = read_request_id(&mut device).await;
+            drop(device);
+        };
+        let (result, ()) = timeout(Duration::from_secs(2), async {
+            tokio::join!(in_flight, unplug)
+        })
+        .await
+        .expect("request should end when the board detaches");
+        let result = result.expect("detach is a tool error, not a transport error");
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("'nucleo' is detached"));
+        assert!(!transport.is_connected());
+
+        let next = timeout(
+            Duration::from_millis(200),
+            transport.request("gpio_write", json!({"pin": 13, "value": 1})),
+        )
+        .await
+        .expect("requests to a detached board must fail fast")
+        .unwrap();
+        assert!(!next.success);
+    }
+
+    #[test]
+    fn stream_buffer_keeps_only_recent_samples() {
+        let buffer = StreamBuffer::default();
+        buffer.open("1");
+        for value in 0..(MAX_BUFFERED_SAMPLES + 10) {
+            buffer.push(
+                "1",
+                StreamSample {
+                    value: json!(value),
+                    t_ms: None,
+                    received_at_ms: 0,
+                },
+            );
+        }
+        let all = buffer.latest("1", usize::MAX).expect("open stream");
+        assert_eq!(all.len(), MAX_BUFFERED_SAMPLES);
+        assert_eq!(all[0].value, json!(10));
+        assert!(buffer.close("1"));
+        assert!(buffer.latest("1", 1).is_none());
     }
 }
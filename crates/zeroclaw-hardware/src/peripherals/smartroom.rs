@@ -1,6 +1,6 @@
 //! High-level smart-room device tools for ESP32 boards.
 
-use super::serial::SerialTransport;
+use super::boards::SerialBoards;
 use async_trait::async_trait;
 use serde_json::{Value, json};
 use std::sync::Arc;
@@ -26,7 +26,8 @@ fn input_pin(device: &str) -> Option<u8> {
 
 /// Tool: set a smart-room device on or off by name.
 pub struct SetDeviceTool {
-    pub transport: Arc<SerialTransport>,
+    /// ESP32 boards wired as smart rooms.
+    pub boards: Arc<SerialBoards>,
 }
 
 #[async_trait]
@@ -46,7 +47,7 @@ impl Tool for SetDeviceTool {
     }
 
     fn parameters_schema(&self) -> Value {
-        json!({
+        self.boards.with_board_param(json!({
             "type": "object",
             "properties": {
                 "device": {
@@ -61,10 +62,14 @@ impl Tool for SetDeviceTool {
                 }
             },
             "required": ["device", "state"]
-        })
+        }))
     }
 
     async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let transport = match self.boards.resolve(&args) {
+            Ok(transport) => transport,
+            Err(result) => return Ok(result),
+        };
         let device = args
             .get("device")
             .and_then(|v| v.as_str())
@@ -84,8 +89,7 @@ impl Tool for SetDeviceTool {
             _ => anyhow::bail!("state must be 'on' or 'off'"),
         };
 
-        let result = self
-            .transport
+        let result = transport
             .request("gpio_write", json!({ "pin": pin, "value": value }))
             .await?;
 
@@ -95,7 +99,8 @@ impl Tool for SetDeviceTool {
 
 /// Tool: read a smart-room input device (currently only motion_sensor).
 pub struct ReadDeviceTool {
-    pub transport: Arc<SerialTransport>,
+    /// ESP32 boards wired as smart rooms.
+    pub boards: Arc<SerialBoards>,
 }
 
 #[async_trait]
@@ -110,7 +115,7 @@ impl Tool for ReadDeviceTool {
     }
 
     fn parameters_schema(&self) -> Value {
-        json!({
+        self.boards.with_board_param(json!({
             "type": "object",
             "properties": {
                 "device": {
@@ -120,10 +125,14 @@ impl Tool for ReadDeviceTool {
                 }
             },
             "required": ["device"]
-        })
+        }))
     }
 
     async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let transport = match self.boards.resolve(&args) {
+            Ok(transport) => transport,
+            Err(result) => return Ok(result),
+        };
         let device = args
             .get("device")
             .and_then(|v| v.as_str())
@@ -132,8 +141,7 @@ impl Tool for ReadDeviceTool {
         let pin = input_pin(device)
             .ok_or_else(|| anyhow::Error::msg(format!("unknown input device: {}", device)))?;
 
-        let result = self
-            .transport
+        let result = transport
             .request("gpio_read", json!({ "pin": pin }))
             .await?;
 
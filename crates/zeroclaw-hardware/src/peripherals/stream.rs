@@ -0,0 +1,214 @@
+//! Sensor streaming tools — subscribe to periodic samples pushed by serial
+//! firmware and read back the most recent ones.
+
+use super::boards::SerialBoards;
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use zeroclaw_api::attribution::ToolKind;
+use zeroclaw_api::tool::{Tool, ToolResult};
+use zeroclaw_api::tool_attribution;
+
+tool_attribution!(PeripheralSubscribeTool, ToolKind::Plugin);
+tool_attribution!(PeripheralUnsubscribeTool, ToolKind::Plugin);
+tool_attribution!(PeripheralReadStreamTool, ToolKind::Plugin);
+
+/// Default sampling interval when the caller omits `interval_ms`.
+const DEFAULT_INTERVAL_MS: u64 = 1000;
+
+/// Default number of samples returned by `peripheral_read_stream`.
+const DEFAULT_READ_LAST: usize = 10;
+
+fn missing_param(tool: &str, param: &str) -> anyhow::Error {
+    ::zeroclaw_log::record!(
+        WARN,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+            .with_attrs(::serde_json::json!({"tool": tool, "param": param})),
+        "tool argument validation failed: missing parameter"
+    );
+    anyhow::Error::msg(format!("Missing '{param}' parameter"))
+}
+
+fn subscription_arg(tool: &str, args: &Value) -> anyhow::Result<u64> {
+    args.get("subscription")
+        .and_then(|v| {
+            v.as_u64()
+                .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+        })
+        .ok_or_else(|| missing_param(tool, "subscription"))
+}
+
+/// Tool: start a periodic sample stream from a pin or named sensor.
+pub struct PeripheralSubscribeTool {
+    pub boards: Arc<SerialBoards>,
+}
+
+#[async_trait]
+impl Tool for PeripheralSubscribeTool {
+    fn name(&self) -> &str {
+        "peripheral_subscribe"
+    }
+
+    fn description(&self) -> &str {
+        "Start streaming periodic samples from an analog pin or named sensor on a connected peripheral. Returns a subscription id for peripheral_read_stream. Check hardware_capabilities for streamable pins."
+    }
+
+    fn parameters_schema(&self) -> Value {
+        self.boards.with_board_param(json!({
+            "type": "object",
+            "properties": {
+                "pin": {
+                    "type": "integer",
+                    "description": "Analog pin to sample"
+                },
+                "sensor": {
+                    "type": "string",
+                    "description": "Named sensor to sample (instead of pin)"
+                },
+                "interval_ms": {
+                    "type": "integer",
+                    "description": "Sampling interval in milliseconds (default 1000)"
+                }
+            }
+        }))
+    }
+
+    async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let transport = match self.boards.resolve(&args) {
+            Ok(transport) => transport,
+            Err(result) => return Ok(result),
+        };
+        let interval_ms = args
+            .get("interval_ms")
+            .and_then(Value::as_u64)
+            .unwrap_or(DEFAULT_INTERVAL_MS);
+        let request = if let Some(pin) = args.get("pin").and_then(Value::as_u64) {
+            json!({ "pin": pin, "interval_ms": interval_ms })
+        } else if let Some(sensor) = args.get("sensor").and_then(Value::as_str) {
+            json!({ "sensor": sensor, "interval_ms": interval_ms })
+        } else {
+            return Err(missing_param("peripheral_subscribe", "pin"));
+        };
+
+        let result = transport.request("subscribe", request).await?;
+        if result.success {
+            transport.streams().open(&result.output);
+        }
+        Ok(result)
+    }
+}
+
+/// Tool: stop a sample stream and discard its buffered samples.
+pub struct PeripheralUnsubscribeTool {
+    pub boards: Arc<SerialBoards>,
+}
+
+#[async_trait]
+impl Tool for PeripheralUnsubscribeTool {
+    fn name(&self) -> &str {
+        "peripheral_unsubscribe"
+    }
+
+    fn description(&self) -> &str {
+        "Stop a sample stream started with peripheral_subscribe"
+    }
+
+    fn parameters_schema(&self) -> Value {
+        self.boards.with_board_param(json!({
+            "type": "object",
+            "properties": {
+                "subscription": {
+                    "type": "integer",
+                    "description": "Subscription id returned by peripheral_subscribe"
+                }
+            },
+            "required": ["subscription"]
+        }))
+    }
+
+    async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let transport = match self.boards.resolve(&args) {
+            Ok(transport) => transport,
+            Err(result) => return Ok(result),
+        };
+        let subscription = subscription_arg("peripheral_unsubscribe", &args)?;
+        let result = transport
+            .request("unsubscribe", json!({ "id": subscription }))
+            .await?;
+        if result.success {
+            transport.streams().close(&subscription.to_string());
+        }
+        Ok(result)
+    }
+}
+
+/// Tool: return the most recent samples buffered for a subscription.
+pub struct PeripheralReadStreamTool {
+    pub boards: Arc<SerialBoards>,
+}
+
+#[async_trait]
+impl Tool for PeripheralReadStreamTool {
+    fn name(&self) -> &str {
+        "peripheral_read_stream"
+    }
+
+    fn description(&self) -> &str {
+        "Read the most recent samples from a stream started with peripheral_subscribe, oldest first, with device uptime (t_ms) and host receive time (received_at_ms, Unix milliseconds)"
+    }
+
+    fn parameters_schema(&self) -> Value {
+        self.boards.with_board_param(json!({
+            "type": "object",
+            "properties": {
+                "subscription": {
+                    "type": "integer",
+                    "description": "Subscription id returned by peripheral_subscribe"
+                },
+                "last": {
+                    "type": "integer",
+                    "description": "Number of recent samples to return (default 10)"
+                }
+            },
+            "required": ["subscription"]
+        }))
+    }
+
+    async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let transport = match self.boards.resolve(&args) {
+            Ok(transport) => transport,
+            Err(result) => return Ok(result),
+        };
+        let subscription = subscription_arg("peripheral_read_stream", &args)?;
+        let last = args
+            .get("last")
+            .and_then(Value::as_u64)
+            .map_or(DEFAULT_READ_LAST, |n| {
+                usize::try_from(n).unwrap_or(usize::MAX)
+            });
+
+        let Some(samples) = transport.streams().latest(&subscription.to_string(), last) else {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new().into(),
+                error: Some(format!("Unknown subscription {subscription}")),
+            });
+        };
+        let samples: Vec<Value> = samples
+            .into_iter()
+            .map(|s| {
+                json!({
+                    "value": s.value,
+                    "t_ms": s.t_ms,
+                    "received_at_ms": s.received_at_ms,
+                })
+            })
+            .collect();
+        Ok(ToolResult {
+            success: true,
+            output: Value::Array(samples).into(),
+            error: None,
+        })
+    }
+}
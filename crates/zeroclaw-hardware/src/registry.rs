@@ -79,6 +79,34 @@ pub fn known_boards() -> &'static [BoardInfo] {
     KNOWN_BOARDS
 }
 
+/// Board profiles that may sit behind a generic USB-UART bridge. The bridge
+/// chip alone does not identify the board, so these VID/PID pairs map to
+/// more than one peripheral profile and need a firmware probe (or the user)
+/// to decide.
+const BRIDGE_PROFILES: &[(u16, u16, &[&str])] = &[
+    // Silicon Labs CP210x — ESP32 DevKitC and most Espressif dev boards
+    (0x10c4, 0xea60, &["esp32"]),
+    (0x10c4, 0xea70, &["esp32"]),
+    // WCH CH340 — cheap ESP32 boards and Arduino Uno clones alike
+    (0x1a86, 0x7523, &["esp32", "arduino-uno"]),
+    (0x1a86, 0x55d4, &["esp32", "arduino-uno"]),
+];
+
+/// Peripheral board profiles (as used in `[[peripherals.boards]]`) that a
+/// device with this VID/PID could be. Empty when the device is unknown;
+/// more than one entry when the match is ambiguous.
+pub fn board_candidates(vid: u16, pid: u16) -> Vec<&'static str> {
+    if let Some((_, _, profiles)) = BRIDGE_PROFILES
+        .iter()
+        .find(|(v, p, _)| *v == vid && *p == pid)
+    {
+        return profiles.to_vec();
+    }
+    lookup_board(vid, pid)
+        .map(|b| vec![b.name])
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,6 +159,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn board_candidates_map_bridges_to_profiles() {
+        assert_eq!(board_candidates(0x0483, 0x374b), vec!["nucleo-f401re"]);
+        assert_eq!(board_candidates(0x10c4, 0xea60), vec!["esp32"]);
+        assert_eq!(
+            board_candidates(0x1a86, 0x7523),
+            vec!["esp32", "arduino-uno"]
+        );
+        assert!(board_candidates(0x0000, 0x0000).is_empty());
+    }
+
     #[test]
     fn every_known_board_resolves_to_itself() {
         for b in known_boards() {
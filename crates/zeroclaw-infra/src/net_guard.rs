@@ -36,6 +36,23 @@ pub fn is_private_or_local_host(host: &str) -> bool {
     false
 }
 
+/// True when `ip` is a cloud instance-metadata endpoint (EC2/GCP/Azure
+/// `169.254.169.254` and the EC2 IPv6 `fd00:ec2::254`). Callers refuse these
+/// even when private hosts are otherwise allowed.
+#[must_use]
+pub fn is_cloud_metadata_ip(ip: std::net::IpAddr) -> bool {
+    const EC2_IMDS_V4: std::net::Ipv4Addr = std::net::Ipv4Addr::new(169, 254, 169, 254);
+    const EC2_IMDS_V6: std::net::Ipv6Addr =
+        std::net::Ipv6Addr::new(0xfd00, 0x0ec2, 0, 0, 0, 0, 0, 0x0254);
+
+    match ip {
+        std::net::IpAddr::V4(v4) => v4 == EC2_IMDS_V4,
+        std::net::IpAddr::V6(v6) => {
+            v6 == EC2_IMDS_V6 || v6.to_ipv4_mapped().is_some_and(|v4| v4 == EC2_IMDS_V4)
+        }
+    }
+}
+
 /// True when an IPv4 address is not globally routable (loopback, RFC 1918,
 /// link-local, CGNAT, documentation, benchmarking, reserved, multicast).
 #[must_use]
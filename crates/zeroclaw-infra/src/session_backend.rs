@@ -63,6 +63,9 @@ pub struct SessionQuery {
 pub struct TimestampedMessage {
     pub message: ChatMessage,
     pub created_at: Option<DateTime<Utc>>,
+    /// Platform message id the channel reported when this reply was
+    /// delivered (see [`SessionBackend::set_last_delivery_id`]).
+    pub delivery_id: Option<String>,
 }
 
 /// Trait for session persistence backends.
@@ -80,6 +83,7 @@ pub trait SessionBackend: Send + Sync {
             .map(|message| TimestampedMessage {
                 message,
                 created_at: None,
+                delivery_id: None,
             })
             .collect()
     }
@@ -99,6 +103,17 @@ pub trait SessionBackend: Send + Sync {
         }
     }
 
+    /// Attach the platform message id of a delivered reply to the latest
+    /// assistant message in a session. Returns `true` if a row was updated.
+    /// No-op for backends that don't track delivery ids.
+    fn set_last_delivery_id(
+        &self,
+        _session_key: &str,
+        _delivery_id: &str,
+    ) -> std::io::Result<bool> {
+        Ok(false)
+    }
+
     /// List all session keys.
     fn list_sessions(&self) -> Vec<String>;
 
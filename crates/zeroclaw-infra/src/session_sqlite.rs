@@ -124,6 +124,30 @@ impl SqliteSessionBackend {
             );
         }
 
+        // Migration: platform message id of delivered replies
+        let has_delivery_id: bool = conn
+            .query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('sessions') WHERE name = 'delivery_id'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap_or(false);
+        if !has_delivery_id {
+            let _ = conn.execute("ALTER TABLE sessions ADD COLUMN delivery_id TEXT", []);
+        }
+
+        // Migration: model reasoning trace kept alongside assistant replies
+        let has_reasoning: bool = conn
+            .query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('sessions') WHERE name = 'reasoning'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap_or(false);
+        if !has_reasoning {
+            let _ = conn.execute("ALTER TABLE sessions ADD COLUMN reasoning TEXT", []);
+        }
+
         for (column, ddl) in [
             (
                 "channel_id",
@@ -228,9 +252,9 @@ impl SqliteSessionBackend {
 impl SessionBackend for SqliteSessionBackend {
     fn load(&self, session_key: &str) -> Vec<ChatMessage> {
         let conn = self.conn.lock();
-        let mut stmt = match conn
-            .prepare("SELECT role, content FROM sessions WHERE session_key = ?1 ORDER BY id ASC")
-        {
+        let mut stmt = match conn.prepare(
+            "SELECT role, content, reasoning FROM sessions WHERE session_key = ?1 ORDER BY id ASC",
+        ) {
             Ok(s) => s,
             Err(_) => return Vec::new(),
         };
@@ -239,6 +263,7 @@ impl SessionBackend for SqliteSessionBackend {
             Ok(ChatMessage {
                 role: row.get(0)?,
                 content: row.get(1)?,
+                reasoning: row.get::<_, Option<String>>(2).ok().flatten(),
             })
         }) {
             Ok(r) => r,
@@ -255,7 +280,8 @@ impl SessionBackend for SqliteSessionBackend {
         use crate::session_backend::TimestampedMessage;
         let conn = self.conn.lock();
         let mut stmt = match conn.prepare(
-            "SELECT role, content, created_at FROM sessions WHERE session_key = ?1 ORDER BY id ASC",
+            "SELECT role, content, created_at, delivery_id, reasoning FROM sessions \
+             WHERE session_key = ?1 ORDER BY id ASC",
         ) {
             Ok(s) => s,
             Err(_) => return Vec::new(),
@@ -270,8 +296,13 @@ impl SessionBackend for SqliteSessionBackend {
                 .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
                 .map(|dt| dt.with_timezone(&Utc));
             Ok(TimestampedMessage {
-                message: ChatMessage { role, content },
+                message: ChatMessage {
+                    role,
+                    content,
+                    reasoning: row.get::<_, Option<String>>(4).ok().flatten(),
+                },
                 created_at,
+                delivery_id: row.get::<_, Option<String>>(3).ok().flatten(),
             })
         }) {
             Ok(r) => r,
@@ -286,9 +317,15 @@ impl SessionBackend for SqliteSessionBackend {
         let now = Utc::now().to_rfc3339();
 
         conn.execute(
-            "INSERT INTO sessions (session_key, role, content, created_at)
-             VALUES (?1, ?2, ?3, ?4)",
-            params![session_key, message.role, message.content, now],
+            "INSERT INTO sessions (session_key, role, content, created_at, reasoning)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                session_key,
+                message.role,
+                message.content,
+                now,
+                message.reasoning
+            ],
         )
         .map_err(std::io::Error::other)?;
 
@@ -373,6 +410,21 @@ impl SessionBackend for SqliteSessionBackend {
         Ok(true)
     }
 
+    fn set_last_delivery_id(&self, session_key: &str, delivery_id: &str) -> std::io::Result<bool> {
+        let conn = self.conn.lock();
+        let updated = conn
+            .execute(
+                "UPDATE sessions SET delivery_id = ?1 WHERE id = (
+                    SELECT id FROM sessions
+                    WHERE session_key = ?2 AND role = 'assistant'
+                    ORDER BY id DESC LIMIT 1
+                 )",
+                params![delivery_id, session_key],
+            )
+            .map_err(std::io::Error::other)?;
+        Ok(updated > 0)
+    }
+
     fn list_sessions(&self) -> Vec<String> {
         let conn = self.conn.lock();
         let mut stmt = match conn
@@ -926,6 +978,43 @@ mod tests {
         assert_eq!(msgs[1].role, "assistant");
     }
 
+    #[test]
+    fn delivery_id_attaches_to_latest_assistant_turn() {
+        let tmp = TempDir::new().unwrap();
+        let backend = SqliteSessionBackend::new(tmp.path()).unwrap();
+
+        assert!(!backend.set_last_delivery_id("u", "1").unwrap());
+        backend.append("u", &ChatMessage::user("hello")).unwrap();
+        backend.append("u", &ChatMessage::assistant("hi")).unwrap();
+        backend.append("u", &ChatMessage::user("again")).unwrap();
+        assert!(backend.set_last_delivery_id("u", "1712.0042").unwrap());
+
+        let rows = backend.load_with_timestamps("u");
+        let ids: Vec<_> = rows.iter().map(|r| r.delivery_id.as_deref()).collect();
+        assert_eq!(ids, vec![None, Some("1712.0042"), None]);
+    }
+
+    #[test]
+    fn reasoning_round_trips_sqlite() {
+        let tmp = TempDir::new().unwrap();
+        let backend = SqliteSessionBackend::new(tmp.path()).unwrap();
+
+        backend.append("u", &ChatMessage::user("hello")).unwrap();
+        backend
+            .append(
+                "u",
+                &ChatMessage::assistant("hi").with_reasoning(Some("greet back".into())),
+            )
+            .unwrap();
+
+        let msgs = backend.load("u");
+        assert_eq!(msgs[0].reasoning, None);
+        assert_eq!(msgs[1].content, "hi");
+        assert_eq!(msgs[1].reasoning.as_deref(), Some("greet back"));
+        let rows = backend.load_with_timestamps("u");
+        assert_eq!(rows[1].message.reasoning.as_deref(), Some("greet back"));
+    }
+
     #[test]
     fn remove_last_sqlite() {
         let tmp = TempDir::new().unwrap();
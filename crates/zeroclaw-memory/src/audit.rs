@@ -408,6 +408,10 @@ impl<M: Memory> Memory for AuditedMemory<M> {
         self.inner.reindex().await
     }
 
+    async fn vacuum(&self) -> anyhow::Result<u64> {
+        self.inner.vacuum().await
+    }
+
     async fn store_procedural(
         &self,
         messages: &[ProceduralMessage],
@@ -1,5 +1,12 @@
 use async_trait::async_trait;
 
+/// Texts per embedding request when `[memory] embedding_batch_size` is unset
+/// or the caller has no config at hand.
+pub const DEFAULT_EMBEDDING_BATCH_SIZE: usize = 64;
+
+/// Base URL for a local Ollama server.
+pub const DEFAULT_OLLAMA_URL: &str = "http://localhost:11434";
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EmbeddingIdentity {
     pub provider: String,
@@ -29,6 +36,12 @@ pub trait EmbeddingProvider: Send + Sync {
     /// Embed a batch of texts into vectors
     async fn embed(&self, texts: &[&str]) -> anyhow::Result<Vec<Vec<f32>>>;
 
+    /// Largest number of texts sent in one request, when this provider
+    /// splits batches itself. `None` means `embed` sends everything at once.
+    fn max_batch_size(&self) -> Option<usize> {
+        None
+    }
+
     /// Embed a single text
     async fn embed_one(&self, text: &str) -> anyhow::Result<Vec<f32>> {
         let mut results = self.embed(&[text]).await?;
@@ -190,6 +203,166 @@ impl EmbeddingProvider for OpenAiEmbedding {
     }
 }
 
+// ── Ollama embedding model_provider (local, keyless) ───────────────
+
+pub struct OllamaEmbedding {
+    base_url: String,
+    model: String,
+    dims: usize,
+}
+
+impl OllamaEmbedding {
+    pub fn new(base_url: &str, model: &str, dims: usize) -> Self {
+        let base_url = base_url.trim().trim_end_matches('/');
+        Self {
+            base_url: if base_url.is_empty() {
+                DEFAULT_OLLAMA_URL.to_string()
+            } else {
+                base_url.to_string()
+            },
+            model: model.to_string(),
+            dims,
+        }
+    }
+
+    fn embed_url(&self) -> String {
+        format!("{}/api/embed", self.base_url)
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for OllamaEmbedding {
+    fn name(&self) -> &str {
+        "ollama"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dims
+    }
+
+    async fn embed(&self, texts: &[&str]) -> anyhow::Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let body = serde_json::json!({
+            "model": self.model,
+            "input": texts,
+        });
+
+        let resp = zeroclaw_config::schema::build_runtime_proxy_client("memory.embeddings")
+            .post(self.embed_url())
+            .json(&body)
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            anyhow::bail!("Ollama embedding API error {status}: {text}");
+        }
+
+        let json: serde_json::Value = resp.json().await?;
+        parse_ollama_embeddings(&json)
+    }
+}
+
+fn parse_ollama_embeddings(json: &serde_json::Value) -> anyhow::Result<Vec<Vec<f32>>> {
+    let data = json
+        .get("embeddings")
+        .and_then(|d| d.as_array())
+        .ok_or_else(|| {
+            anyhow::Error::msg("Invalid Ollama embedding response: missing 'embeddings'")
+        })?;
+
+    data.iter()
+        .map(|item| {
+            let values = item
+                .as_array()
+                .ok_or_else(|| anyhow::Error::msg("Invalid Ollama embedding item"))?;
+            #[allow(clippy::cast_possible_truncation)]
+            Ok(values
+                .iter()
+                .filter_map(|v| v.as_f64().map(|f| f as f32))
+                .collect())
+        })
+        .collect()
+}
+
+// ── Batching + dimension guard ───────────────────────────────
+
+/// Wraps a provider so callers can hand over any number of texts: requests
+/// are split into chunks of at most `max_batch_size`, and every returned
+/// vector is checked against the configured width. A model that emits a
+/// different width than `embedding_dimensions` fails the call instead of
+/// writing vectors that silently never match anything at recall time.
+pub struct BatchedEmbedding {
+    inner: Box<dyn EmbeddingProvider>,
+    max_batch_size: usize,
+}
+
+impl BatchedEmbedding {
+    pub fn new(inner: Box<dyn EmbeddingProvider>, max_batch_size: usize) -> Self {
+        Self {
+            inner,
+            max_batch_size: max_batch_size.max(1),
+        }
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for BatchedEmbedding {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn dimensions(&self) -> usize {
+        self.inner.dimensions()
+    }
+
+    fn max_batch_size(&self) -> Option<usize> {
+        Some(self.max_batch_size)
+    }
+
+    async fn embed(&self, texts: &[&str]) -> anyhow::Result<Vec<Vec<f32>>> {
+        let expected = self.inner.dimensions();
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for chunk in texts.chunks(self.max_batch_size) {
+            let batch = self.inner.embed(chunk).await?;
+            if batch.len() != chunk.len() {
+                anyhow::bail!(
+                    "Embedding provider '{}' returned {} vectors for {} inputs",
+                    self.inner.name(),
+                    batch.len(),
+                    chunk.len()
+                );
+            }
+            if let Some(actual) = batch.iter().map(Vec::len).find(|len| *len != expected) {
+                ::zeroclaw_log::record!(
+                    ERROR,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "provider": self.inner.name(),
+                            "expected_dimensions": expected,
+                            "actual_dimensions": actual,
+                        })),
+                    "embedding dimension mismatch"
+                );
+                anyhow::bail!(
+                    "Embedding dimension mismatch: provider '{}' returned {actual}-dimensional \
+                     vectors but memory.embedding_dimensions = {expected}. Set \
+                     memory.embedding_dimensions = {actual} and run `zeroclaw memory reindex` \
+                     to migrate the existing vectors.",
+                    self.inner.name()
+                );
+            }
+            embeddings.extend(batch);
+        }
+        Ok(embeddings)
+    }
+}
+
 // ── Factory ──────────────────────────────────────────────────
 
 pub fn create_embedding_provider(
@@ -197,6 +370,37 @@ pub fn create_embedding_provider(
     api_key: Option<&str>,
     model: &str,
     dims: usize,
+) -> Box<dyn EmbeddingProvider> {
+    create_batched_embedding_provider(
+        model_provider,
+        api_key,
+        model,
+        dims,
+        DEFAULT_EMBEDDING_BATCH_SIZE,
+    )
+}
+
+/// Build an embedder that sends at most `max_batch_size` texts per request.
+/// The keyword-only Noop embedder is returned unwrapped.
+pub fn create_batched_embedding_provider(
+    model_provider: &str,
+    api_key: Option<&str>,
+    model: &str,
+    dims: usize,
+    max_batch_size: usize,
+) -> Box<dyn EmbeddingProvider> {
+    let inner = create_unbatched_embedding_provider(model_provider, api_key, model, dims);
+    if inner.dimensions() == 0 {
+        return inner;
+    }
+    Box::new(BatchedEmbedding::new(inner, max_batch_size))
+}
+
+fn create_unbatched_embedding_provider(
+    model_provider: &str,
+    api_key: Option<&str>,
+    model: &str,
+    dims: usize,
 ) -> Box<dyn EmbeddingProvider> {
     match model_provider {
         "openai" => {
@@ -222,6 +426,11 @@ pub fn create_embedding_provider(
             let key = api_key.unwrap_or("");
             Box::new(OpenAiEmbedding::new(base_url, key, model, dims))
         }
+        "ollama" => Box::new(OllamaEmbedding::new(DEFAULT_OLLAMA_URL, model, dims)),
+        name if name.starts_with("ollama:") => {
+            let base_url = name.strip_prefix("ollama:").unwrap_or("");
+            Box::new(OllamaEmbedding::new(base_url, model, dims))
+        }
         _ => Box::new(NoopEmbedding),
     }
 }
@@ -391,4 +600,90 @@ mod tests {
             "https://my-api.example.com/api/v2/embeddings"
         );
     }
+
+    #[test]
+    fn factory_ollama_defaults_to_local_server() {
+        let p = create_embedding_provider("ollama", None, "nomic-embed-text", 768);
+        assert_eq!(p.name(), "ollama");
+        assert_eq!(p.dimensions(), 768);
+        assert_eq!(
+            OllamaEmbedding::new(DEFAULT_OLLAMA_URL, "nomic-embed-text", 768).embed_url(),
+            "http://localhost:11434/api/embed"
+        );
+    }
+
+    #[test]
+    fn factory_ollama_custom_url() {
+        let p = create_embedding_provider("ollama:http://gpu-box:11434/", None, "m", 768);
+        assert_eq!(p.name(), "ollama");
+        let o = OllamaEmbedding::new("http://gpu-box:11434/", "m", 768);
+        assert_eq!(o.embed_url(), "http://gpu-box:11434/api/embed");
+    }
+
+    #[test]
+    fn parse_ollama_embeddings_response() {
+        let json = serde_json::json!({"model": "m", "embeddings": [[0.5, 1.0], [2.0, 3.0]]});
+        let parsed = parse_ollama_embeddings(&json).unwrap();
+        assert_eq!(parsed, vec![vec![0.5, 1.0], vec![2.0, 3.0]]);
+        assert!(parse_ollama_embeddings(&serde_json::json!({"error": "x"})).is_err());
+    }
+
+    struct FixedWidth {
+        width: usize,
+        calls: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl EmbeddingProvider for FixedWidth {
+        fn name(&self) -> &str {
+            "fixed"
+        }
+
+        fn dimensions(&self) -> usize {
+            3
+        }
+
+        async fn embed(&self, texts: &[&str]) -> anyhow::Result<Vec<Vec<f32>>> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(texts.iter().map(|_| vec![0.1; self.width]).collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn batched_embedding_splits_requests() {
+        let calls = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let p = BatchedEmbedding::new(
+            Box::new(FixedWidth {
+                width: 3,
+                calls: calls.clone(),
+            }),
+            2,
+        );
+        let result = p.embed(&["a", "b", "c", "d", "e"]).await.unwrap();
+        assert_eq!(result.len(), 5);
+        assert_eq!(p.max_batch_size(), Some(2));
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn batched_embedding_rejects_dimension_mismatch() {
+        let p = BatchedEmbedding::new(
+            Box::new(FixedWidth {
+                width: 4,
+                calls: Default::default(),
+            }),
+            8,
+        );
+        let err = p.embed(&["a"]).await.unwrap_err().to_string();
+        assert!(err.contains("dimension mismatch"), "{err}");
+        assert!(err.contains("embedding_dimensions = 4"), "{err}");
+    }
+
+    #[test]
+    fn factory_noop_is_not_wrapped() {
+        let p = create_batched_embedding_provider("none", None, "model", 1536, 8);
+        assert_eq!(p.max_batch_size(), None);
+        let p = create_batched_embedding_provider("openai", Some("k"), "model", 1536, 8);
+        assert_eq!(p.max_batch_size(), Some(8));
+    }
 }
@@ -38,6 +38,7 @@ pub mod rerank;
 pub mod response_cache;
 pub mod retrieval;
 pub mod scanned;
+pub mod scope;
 pub mod snapshot;
 pub mod sqlite;
 pub mod threat;
@@ -375,8 +376,10 @@ fn resolve_provider_ref(
     providers: Option<&ModelProviders>,
 ) -> ResolvedEmbeddingConfig {
     let trimmed = model_provider.trim();
-    let is_dotted_ref =
-        !trimmed.is_empty() && !trimmed.starts_with("custom:") && trimmed.contains('.');
+    let is_dotted_ref = !trimmed.is_empty()
+        && !trimmed.starts_with("custom:")
+        && !trimmed.starts_with("ollama:")
+        && trimmed.contains('.');
     if !is_dotted_ref {
         return ResolvedEmbeddingConfig {
             model_provider,
@@ -422,7 +425,7 @@ fn resolve_provider_ref(
         .filter(|value| !value.is_empty())
     {
         Some(uri) => Some(format!("custom:{uri}")),
-        None if matches!(kind, "openai" | "openrouter") => Some(kind.to_string()),
+        None if matches!(kind, "openai" | "openrouter" | "ollama") => Some(kind.to_string()),
         None => None,
     };
     let Some(concrete_provider) = concrete_provider else {
@@ -607,11 +610,12 @@ pub fn create_memory_with_storage_and_routes(
         resolved_embedding: &ResolvedEmbeddingConfig,
     ) -> anyhow::Result<SqliteMemory> {
         let embedder: Arc<dyn embeddings::EmbeddingProvider> =
-            Arc::from(embeddings::create_embedding_provider(
+            Arc::from(embeddings::create_batched_embedding_provider(
                 &resolved_embedding.model_provider,
                 resolved_embedding.api_key.as_deref(),
                 &resolved_embedding.model,
                 resolved_embedding.dimensions,
+                config.embedding_batch_size,
             ));
         let has_embedder = embedder.dimensions() > 0;
 
@@ -625,7 +629,9 @@ pub fn create_memory_with_storage_and_routes(
             config.embedding_cache_size,
             sqlite_open_timeout_secs,
             config.search_mode.clone(),
-        )?;
+        )?
+        .with_dedup(config.dedup, config.dedup_similarity_threshold)
+        .with_deferred_embeddings(config.deferred_embeddings);
 
         if has_embedder {
             reconcile_embedding_identity(
@@ -664,11 +670,12 @@ pub fn create_memory_with_storage_and_routes(
         let collection = qdrant_cfg.collection.clone();
         let qdrant_api_key = qdrant_cfg.api_key.clone().filter(|s| !s.trim().is_empty());
         let embedder: Arc<dyn embeddings::EmbeddingProvider> =
-            Arc::from(embeddings::create_embedding_provider(
+            Arc::from(embeddings::create_batched_embedding_provider(
                 &resolved_embedding.model_provider,
                 resolved_embedding.api_key.as_deref(),
                 &resolved_embedding.model,
                 resolved_embedding.dimensions,
+                config.embedding_batch_size,
             ));
         ::zeroclaw_log::record!(
             INFO,
@@ -2578,6 +2585,51 @@ store_timeout_ms = 900
         assert_eq!(embedder.name(), "openai");
     }
 
+    #[test]
+    fn resolve_embedding_config_ollama_family_without_uri_uses_local_server() {
+        let cfg = MemoryConfig {
+            embedding_provider: "none".into(),
+            embedding_model: "hint:local".into(),
+            embedding_dimensions: 768,
+            ..MemoryConfig::default()
+        };
+        let routes = vec![EmbeddingRouteConfig {
+            hint: "local".into(),
+            model_provider: "ollama.local".into(),
+            model: "nomic-embed-text".into(),
+            dimensions: None,
+            api_key: None,
+        }];
+        let providers = catalog_with("ollama", "local", None, None);
+
+        let resolved =
+            resolve_embedding_config(&cfg, &routes, Some("chat-provider-key"), Some(&providers));
+
+        assert_eq!(resolved.model_provider, "ollama");
+        assert_eq!(resolved.dimensions, 768);
+        let embedder = embeddings::create_embedding_provider(
+            &resolved.model_provider,
+            resolved.api_key.as_deref(),
+            &resolved.model,
+            resolved.dimensions,
+        );
+        assert_eq!(embedder.name(), "ollama");
+    }
+
+    #[test]
+    fn resolve_embedding_config_ollama_url_is_not_a_dotted_ref() {
+        let cfg = MemoryConfig {
+            embedding_provider: "ollama:http://10.0.0.5:11434".into(),
+            embedding_model: "nomic-embed-text".into(),
+            embedding_dimensions: 768,
+            ..MemoryConfig::default()
+        };
+
+        let resolved = resolve_embedding_config(&cfg, &[], None, None);
+
+        assert_eq!(resolved.model_provider, "ollama:http://10.0.0.5:11434");
+    }
+
     #[allow(clippy::await_holding_lock)]
     #[tokio::test]
     async fn resolve_embedding_config_no_endpoint_emits_loud_warning() {
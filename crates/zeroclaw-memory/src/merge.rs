@@ -7,25 +7,26 @@ pub struct MergedFact {
 }
 
 pub fn merge_into_survivor(survivor: &MemoryEntry, incoming: &str) -> MergedFact {
-    let survivor_content = survivor.content.trim();
+    MergedFact {
+        content: merge_texts(&survivor.content, incoming),
+        importance: survivor.importance,
+    }
+}
+
+/// Combined text of two near-duplicate facts: the one that contains the
+/// other, or both, one per line in a stable order.
+pub fn merge_texts(survivor: &str, incoming: &str) -> String {
+    let survivor = survivor.trim();
     let incoming = incoming.trim();
 
-    let content = if incoming.is_empty()
-        || survivor_content == incoming
-        || survivor_content.contains(incoming)
-    {
-        survivor_content.to_string()
-    } else if incoming.contains(survivor_content) {
+    if incoming.is_empty() || survivor == incoming || survivor.contains(incoming) {
+        survivor.to_string()
+    } else if incoming.contains(survivor) {
         incoming.to_string()
     } else {
-        let mut facts = [survivor_content, incoming];
+        let mut facts = [survivor, incoming];
         facts.sort_unstable();
         facts.join("\n")
-    };
-
-    MergedFact {
-        content,
-        importance: survivor.importance,
     }
 }
 
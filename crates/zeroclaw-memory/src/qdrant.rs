@@ -234,7 +234,23 @@ impl QdrantMemory {
 
         match resp {
             Ok(r) if r.status().is_success() => {
-                // Collection exists
+                // Collection exists — it must have been created for the same
+                // vector width, or every upsert/search would fail or miss.
+                let info: serde_json::Value = r
+                    .json()
+                    .await
+                    .context("failed to parse Qdrant collection info")?;
+                if let Some(existing) = collection_vector_size(&info)
+                    && existing != dims
+                {
+                    anyhow::bail!(
+                        "Qdrant collection '{}' stores {existing}-dimensional vectors but the \
+                         configured embedder produces {dims}. Point memory at a new collection \
+                         (or drop this one) and run `zeroclaw memory reindex`, or set \
+                         memory.embedding_dimensions = {existing} with a matching model.",
+                        self.collection
+                    );
+                }
                 return Ok(());
             }
             Ok(r) if r.status().as_u16() == 404 => {
@@ -540,6 +556,15 @@ struct QdrantPoint {
     payload: Option<MemoryPayload>,
 }
 
+/// Vector width of an existing collection from a `GET /collections/{name}`
+/// response. `None` for named-vector layouts or unexpected shapes, which are
+/// left to Qdrant to reject.
+fn collection_vector_size(info: &serde_json::Value) -> Option<usize> {
+    info.pointer("/result/config/params/vectors/size")
+        .and_then(serde_json::Value::as_u64)
+        .and_then(|size| usize::try_from(size).ok())
+}
+
 #[async_trait]
 impl Memory for QdrantMemory {
     fn name(&self) -> &str {
@@ -557,12 +582,20 @@ impl Memory for QdrantMemory {
         // Qdrant collection was created for the old vector dimensions; a
         // dimension change still needs a manual reindex/collection rebuild, but
         // the live handle no longer embeds against a stale endpoint/key
+        // Keep the configured batch size across the swap; a Noop embedder
+        // carries none, so fall back to the default.
+        let max_batch_size = self
+            .embedder
+            .read()
+            .max_batch_size()
+            .unwrap_or(super::embeddings::DEFAULT_EMBEDDING_BATCH_SIZE);
         let embedder: Arc<dyn EmbeddingProvider> =
-            Arc::from(super::embeddings::create_embedding_provider(
+            Arc::from(super::embeddings::create_batched_embedding_provider(
                 model_provider,
                 api_key,
                 model,
                 dimensions,
+                max_batch_size,
             ));
         self.swap_embedder(embedder);
     }
@@ -1114,6 +1147,20 @@ impl ::zeroclaw_api::attribution::Attributable for QdrantMemory {
 mod tests {
     use super::*;
 
+    #[test]
+    fn collection_vector_size_reads_unnamed_vector_config() {
+        let info = serde_json::json!({
+            "result": {"config": {"params": {"vectors": {"size": 768, "distance": "Cosine"}}}},
+            "status": "ok"
+        });
+        assert_eq!(collection_vector_size(&info), Some(768));
+
+        let named = serde_json::json!({
+            "result": {"config": {"params": {"vectors": {"dense": {"size": 768}}}}}
+        });
+        assert_eq!(collection_vector_size(&named), None);
+    }
+
     #[test]
     fn refresh_embedder_swaps_embedder_in_place() {
         let mem = QdrantMemory::new_lazy(
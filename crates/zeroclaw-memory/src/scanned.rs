@@ -458,6 +458,10 @@ impl<M: Memory> Memory for ScannedMemory<M> {
         self.inner.reindex().await
     }
 
+    async fn vacuum(&self) -> anyhow::Result<u64> {
+        self.inner.vacuum().await
+    }
+
     async fn recall_namespaced(
         &self,
         namespace: &str,
@@ -0,0 +1,113 @@
+//! Channel memory namespaces (`[memory] scope`).
+//!
+//! Channel turns resolve a [`ScopedNamespace`] from the configured
+//! [`MemoryScope`]; the orchestrator scopes it into [`MEMORY_NAMESPACE`] for
+//! the turn so tool writes (`memory_store`) land in the same namespace as the
+//! turn's auto-save, and tool recall (`memory_recall`) sees the same
+//! namespaces as the injected memory context.
+
+use zeroclaw_config::schema::{MemoryConfig, MemoryScope};
+
+tokio::task_local! {
+    /// Namespace of the channel turn currently executing. Unset (or `None`)
+    /// for CLI, cron, and other non-channel turns, which keep writing to the
+    /// backend's default namespace and recalling across all of them.
+    pub static MEMORY_NAMESPACE: Option<ScopedNamespace>;
+}
+
+/// A scoped turn's namespace plus the shared namespace it may also read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopedNamespace {
+    /// Where the turn's writes land.
+    pub namespace: String,
+    /// `[memory] shared_namespace`, readable from every scope.
+    pub shared: Option<String>,
+}
+
+impl ScopedNamespace {
+    /// Namespaces recall is restricted to: the turn's own, then the shared one.
+    #[must_use]
+    pub fn recall_namespaces(&self) -> Vec<String> {
+        let mut namespaces = vec![self.namespace.clone()];
+        if let Some(shared) = &self.shared
+            && shared != &self.namespace
+        {
+            namespaces.push(shared.clone());
+        }
+        namespaces
+    }
+}
+
+/// Namespace for a channel turn under `memory.scope`. `None` under
+/// [`MemoryScope::Global`], meaning "default namespace, unrestricted recall".
+#[must_use]
+pub fn channel_namespace(
+    memory: &MemoryConfig,
+    channel_key: &str,
+    sender: &str,
+    reply_target: &str,
+) -> Option<ScopedNamespace> {
+    let namespace = match memory.scope {
+        MemoryScope::Global => return None,
+        MemoryScope::PerSender => format!("channel:{channel_key}:{sender}"),
+        MemoryScope::PerChannel => format!("channel:{channel_key}:{reply_target}"),
+    };
+    let shared = memory.shared_namespace.trim();
+    Some(ScopedNamespace {
+        namespace,
+        shared: (!shared.is_empty()).then(|| shared.to_string()),
+    })
+}
+
+/// The namespace scoped into the current task, if any.
+#[must_use]
+pub fn current_namespace() -> Option<ScopedNamespace> {
+    MEMORY_NAMESPACE.try_with(Clone::clone).ok().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory_config(scope: MemoryScope) -> MemoryConfig {
+        MemoryConfig {
+            scope,
+            ..MemoryConfig::default()
+        }
+    }
+
+    #[test]
+    fn global_scope_has_no_namespace() {
+        let cfg = memory_config(MemoryScope::Global);
+        assert_eq!(channel_namespace(&cfg, "discord.main", "alice", "g1"), None);
+    }
+
+    #[test]
+    fn scoped_namespaces_include_shared() {
+        let cfg = memory_config(MemoryScope::PerSender);
+        let sender = channel_namespace(&cfg, "discord.main", "alice", "g1").unwrap();
+        assert_eq!(sender.namespace, "channel:discord.main:alice");
+        assert_eq!(
+            sender.recall_namespaces(),
+            vec!["channel:discord.main:alice", "shared"]
+        );
+
+        let mut cfg = memory_config(MemoryScope::PerChannel);
+        cfg.shared_namespace = String::new();
+        let room = channel_namespace(&cfg, "discord.main", "alice", "g1").unwrap();
+        assert_eq!(room.recall_namespaces(), vec!["channel:discord.main:g1"]);
+    }
+
+    #[tokio::test]
+    async fn current_namespace_follows_task_scope() {
+        assert_eq!(current_namespace(), None);
+        let scoped = ScopedNamespace {
+            namespace: "channel:cli:bob".into(),
+            shared: None,
+        };
+        let inside = MEMORY_NAMESPACE
+            .scope(Some(scoped.clone()), async { current_namespace() })
+            .await;
+        assert_eq!(inside, Some(scoped));
+    }
+}
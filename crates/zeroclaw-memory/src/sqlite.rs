@@ -12,16 +12,26 @@ use rusqlite::{Connection, params};
 use std::fmt::Write as _;
 use std::path::Path;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc;
 use std::sync::{Mutex as StdMutex, MutexGuard};
 use std::thread;
 use std::time::Duration;
 use uuid::Uuid;
 use zeroclaw_api::session_keys::sanitize_session_key;
-use zeroclaw_config::schema::SearchMode;
+use zeroclaw_config::schema::{MemoryStoreDedup, SearchMode};
 
 /// Maximum allowed open timeout (seconds) to avoid unreasonable waits.
 const SQLITE_OPEN_TIMEOUT_CAP_SECS: u64 = 300;
+/// Most recent entries in the same category and namespace that a store is
+/// compared against for duplicates.
+const DEDUP_CANDIDATE_WINDOW: i64 = 200;
+/// Prepared statements kept per connection. Sized for the fixed-shape hot
+/// paths (embedding cache, store, get, search) plus their filter variants.
+const STATEMENT_CACHE_CAPACITY: usize = 64;
+/// How long the deferred-embedding writer waits after a store before
+/// embedding, so a burst of auto-saves goes out as one batch.
+const EMBEDDING_WRITER_DEBOUNCE: Duration = Duration::from_millis(250);
 static SQLITE_MEMORY_STARTUP_LOCK: StdMutex<()> = StdMutex::new(());
 
 fn acquire_sqlite_startup_lock() -> MutexGuard<'static, ()> {
@@ -39,6 +49,28 @@ pub struct SqliteMemory {
     keyword_weight: f32,
     cache_max: usize,
     search_mode: SearchMode,
+    dedup: MemoryStoreDedup,
+    dedup_similarity_threshold: f32,
+    deferred_embeddings: bool,
+    pending: Arc<PendingEmbeddings>,
+}
+
+/// Rows stored without a vector, waiting on the deferred-embedding writer.
+#[derive(Default)]
+struct PendingEmbeddings {
+    /// `(row id, content)` in store order.
+    rows: Mutex<Vec<(String, String)>>,
+    /// Held while a batch is embedded, so recall waits for rows in flight.
+    drain: tokio::sync::Mutex<()>,
+    /// Whether a writer task is scheduled or running.
+    writer_active: AtomicBool,
+}
+
+/// A recent entry a store matched as a duplicate.
+struct DuplicateEntry {
+    id: String,
+    content: String,
+    exact: bool,
 }
 
 impl SqliteMemory {
@@ -86,6 +118,10 @@ impl SqliteMemory {
             keyword_weight: 0.3,
             cache_max: 10_000,
             search_mode: SearchMode::default(),
+            dedup: MemoryStoreDedup::Off,
+            dedup_similarity_threshold: 1.0,
+            deferred_embeddings: false,
+            pending: Arc::default(),
         })
     }
 
@@ -129,9 +165,37 @@ impl SqliteMemory {
             keyword_weight,
             cache_max,
             search_mode,
+            dedup: MemoryStoreDedup::Off,
+            dedup_similarity_threshold: 1.0,
+            deferred_embeddings: false,
+            pending: Arc::default(),
         })
     }
 
+    /// Handle duplicate stores per `[memory] dedup`: an entry whose content
+    /// matches a recent one in the same category and namespace (exactly, or
+    /// by embedding cosine at or above `similarity_threshold`) is skipped or
+    /// merged into it instead of written as a new row.
+    #[must_use]
+    pub fn with_dedup(mut self, mode: MemoryStoreDedup, similarity_threshold: f64) -> Self {
+        #[allow(clippy::cast_possible_truncation)]
+        let threshold = similarity_threshold as f32;
+        self.dedup = mode;
+        self.dedup_similarity_threshold = threshold;
+        self
+    }
+
+    /// Per `[memory] deferred_embeddings`: write new rows without a vector
+    /// and embed them in batches from a background writer, so a store never
+    /// waits on the embedding endpoint. Recall flushes the queue before its
+    /// vector stage. Dedup needs the vector at store time, so stores stay
+    /// inline while it is on.
+    #[must_use]
+    pub fn with_deferred_embeddings(mut self, enabled: bool) -> Self {
+        self.deferred_embeddings = enabled;
+        self
+    }
+
     /// Open SQLite connection, optionally with a timeout (for locked/slow storage).
     fn open_connection(
         db_path: &Path,
@@ -159,6 +223,7 @@ impl SqliteMemory {
         } else {
             Connection::open(&path_buf).context("SQLite failed to open database")?
         };
+        conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
 
         Ok(conn)
     }
@@ -335,11 +400,29 @@ impl SqliteMemory {
             "tenant_id",
             "ALTER TABLE memories ADD COLUMN tenant_id TEXT;",
         )?;
+        add_memories_column_if_missing(
+            conn,
+            "seen_count",
+            "ALTER TABLE memories ADD COLUMN seen_count INTEGER NOT NULL DEFAULT 1;",
+        )?;
+        add_memories_column_if_missing(
+            conn,
+            "last_seen",
+            "ALTER TABLE memories ADD COLUMN last_seen TEXT;",
+        )?;
         execute_batch_retry(
             conn,
             "CREATE INDEX IF NOT EXISTS idx_memories_namespace_category ON memories(namespace, category);",
         )
         .with_context(|| "SQLite init_schema failed: CREATE INDEX idx_memories_namespace_category")?;
+        execute_batch_retry(
+            conn,
+            "CREATE INDEX IF NOT EXISTS idx_memories_category_namespace_created \
+             ON memories(category, namespace, created_at);",
+        )
+        .with_context(
+            || "SQLite init_schema failed: CREATE INDEX idx_memories_category_namespace_created",
+        )?;
 
         Self::migrate_session_ids_to_sanitized(conn)?;
 
@@ -386,7 +469,15 @@ impl SqliteMemory {
         options: StoreOptions,
         agent_id: Option<&str>,
     ) -> anyhow::Result<()> {
-        let embedding_bytes = match self.get_or_compute_embedding(content).await {
+        let defer_embedding = self.deferred_embeddings
+            && self.dedup == MemoryStoreDedup::Off
+            && self.embedder.read().dimensions() > 0;
+        let embedding = if defer_embedding {
+            Ok(None)
+        } else {
+            self.get_or_compute_embedding(content).await
+        };
+        let embedding_bytes = match embedding {
             Ok(emb) => emb.map(|emb| vector::vec_to_bytes(&emb)),
             Err(e) => {
                 ::zeroclaw_log::record!(
@@ -404,11 +495,26 @@ impl SqliteMemory {
             }
         };
 
+        let ns = options.namespace.unwrap_or_else(|| "default".to_string());
+        if self.dedup != MemoryStoreDedup::Off
+            && let Some(duplicate) = self
+                .find_duplicate(
+                    key,
+                    content,
+                    &category,
+                    &ns,
+                    embedding_bytes.as_deref(),
+                    agent_id,
+                )
+                .await?
+        {
+            return self.absorb_duplicate(key, duplicate, content).await;
+        }
+
         let conn = self.conn.clone();
         let key = key.to_string();
         let content = content.to_string();
         let sid = session_id.map(String::from);
-        let ns = options.namespace.unwrap_or_else(|| "default".to_string());
         let imp = options.importance.unwrap_or(0.5);
         let kind = options
             .kind
@@ -418,14 +524,15 @@ impl SqliteMemory {
         let pinned = i64::from(options.pinned);
         let tenant_id = options.tenant_id;
         let aid = agent_id.map(String::from);
+        let queued_content = defer_embedding.then(|| content.clone());
 
-        tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+        let row_id = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let conn = conn.lock();
             let now = Local::now().to_rfc3339();
             let cat = Self::category_to_str(&category);
             let id = Uuid::new_v4().to_string();
 
-            conn.execute(
+            let mut stmt = conn.prepare_cached(
                 "INSERT INTO memories (
                     id, key, content, category, embedding, created_at, updated_at,
                     session_id, namespace, importance, agent_id, kind, pinned, tenant_id
@@ -445,7 +552,12 @@ impl SqliteMemory {
                     importance = excluded.importance,
                     kind = excluded.kind,
                     pinned = excluded.pinned,
-                    tenant_id = excluded.tenant_id",
+                    tenant_id = excluded.tenant_id
+                 RETURNING id",
+            )?;
+            // An upsert keeps the existing row's id, which is what a
+            // deferred embedding has to target.
+            let row_id = stmt.query_row(
                 params![
                     id,
                     key,
@@ -462,12 +574,276 @@ impl SqliteMemory {
                     pinned,
                     tenant_id
                 ],
+                |row| row.get(0),
             )?;
+            Ok(row_id)
+        })
+        .await??;
+
+        if let Some(content) = queued_content {
+            self.queue_embedding(row_id, content);
+        }
+        Ok(())
+    }
+
+    /// Queue a stored row for the deferred-embedding writer, starting the
+    /// writer if none is running.
+    fn queue_embedding(&self, id: String, content: String) {
+        self.pending.rows.lock().push((id, content));
+        if self.pending.writer_active.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let mem = self.clone();
+        zeroclaw_spawn::spawn!(async move {
+            loop {
+                tokio::time::sleep(EMBEDDING_WRITER_DEBOUNCE).await;
+                mem.flush_pending_embeddings().await;
+                mem.pending.writer_active.store(false, Ordering::Release);
+                // A store that queued after the drain saw the writer active
+                // and did not start another; pick its rows up here.
+                if mem.pending.rows.lock().is_empty()
+                    || mem.pending.writer_active.swap(true, Ordering::AcqRel)
+                {
+                    return;
+                }
+            }
+        });
+    }
+
+    /// Embed every row queued by a deferred store and write the vectors.
+    /// Waits for a batch already in flight. Rows whose batch fails keep a
+    /// NULL vector for `zeroclaw memory reindex` to backfill.
+    pub async fn flush_pending_embeddings(&self) {
+        let _drain = self.pending.drain.lock().await;
+        let rows = std::mem::take(&mut *self.pending.rows.lock());
+        if rows.is_empty() {
+            return;
+        }
+        let batch_size = self
+            .embedder
+            .read()
+            .max_batch_size()
+            .unwrap_or(super::embeddings::DEFAULT_EMBEDDING_BATCH_SIZE);
+        for chunk in rows.chunks(batch_size) {
+            let texts: Vec<&str> = chunk.iter().map(|(_, content)| content.as_str()).collect();
+            let embeddings = match self.get_or_compute_embeddings(&texts).await {
+                Ok(Some(embeddings)) => embeddings,
+                // The embedder was swapped out for Noop while rows waited.
+                Ok(None) => return,
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                            .with_attrs(::serde_json::json!({
+                                "batch": chunk.len(),
+                                "error": format!("{e}"),
+                            })),
+                        "memory store: deferred embedding failed; rows keep no vector \
+                         (run `zeroclaw memory reindex` to backfill once the embedder recovers)"
+                    );
+                    continue;
+                }
+            };
+            let updates: Vec<(String, String, Vec<u8>)> = chunk
+                .iter()
+                .zip(&embeddings)
+                .map(|((id, content), emb)| {
+                    (id.clone(), content.clone(), vector::vec_to_bytes(emb))
+                })
+                .collect();
+            let conn = self.conn.clone();
+            let written = tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+                let conn = conn.lock();
+                let tx = conn.unchecked_transaction()?;
+                {
+                    // The content check skips rows rewritten since they were
+                    // queued; the rewrite queued its own vector.
+                    let mut update = tx.prepare_cached(
+                        "UPDATE memories SET embedding = ?1 WHERE id = ?2 AND content = ?3",
+                    )?;
+                    for (id, content, bytes) in &updates {
+                        update.execute(params![bytes, id, content])?;
+                    }
+                }
+                tx.commit()?;
+                Ok(())
+            })
+            .await
+            .map_err(anyhow::Error::from)
+            .and_then(std::convert::identity);
+            if let Err(e) = written {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Write)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "batch": chunk.len(),
+                            "error": format!("{e:#}"),
+                        })),
+                    "memory store: failed to write deferred embeddings"
+                );
+            }
+        }
+    }
+
+    /// A recent entry that `content` duplicates, in the same category,
+    /// namespace, and agent. Writes to a key that already exists are updates,
+    /// never duplicates.
+    async fn find_duplicate(
+        &self,
+        key: &str,
+        content: &str,
+        category: &MemoryCategory,
+        namespace: &str,
+        embedding: Option<&[u8]>,
+        agent_id: Option<&str>,
+    ) -> anyhow::Result<Option<DuplicateEntry>> {
+        let conn = self.conn.clone();
+        let key = key.to_string();
+        let hash = Self::content_hash(&Self::dedup_normalize(content));
+        let cat = Self::category_to_str(category);
+        let ns = namespace.to_string();
+        let embedding = embedding.map(vector::bytes_to_vec);
+        let threshold = self.dedup_similarity_threshold;
+        let aid = agent_id.map(String::from);
+
+        tokio::task::spawn_blocking(move || -> anyhow::Result<Option<DuplicateEntry>> {
+            let conn = conn.lock();
+            let agent = "COALESCE(?1, (SELECT id FROM agents WHERE alias = 'default' LIMIT 1))";
+            let key_exists: bool = conn.query_row(
+                &format!(
+                    "SELECT EXISTS(SELECT 1 FROM memories WHERE key = ?2 AND agent_id = {agent})"
+                ),
+                params![aid, key],
+                |row| row.get(0),
+            )?;
+            if key_exists {
+                return Ok(None);
+            }
+            let mut stmt = conn.prepare_cached(&format!(
+                "SELECT id, content, embedding FROM memories
+                 WHERE agent_id = {agent} AND category = ?2 AND namespace = ?3
+                   AND superseded_by IS NULL
+                 ORDER BY updated_at DESC
+                 LIMIT ?4"
+            ))?;
+            let rows = stmt.query_map(params![aid, cat, ns, DEDUP_CANDIDATE_WINDOW], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<Vec<u8>>>(2)?,
+                ))
+            })?;
+            let mut best: Option<(f32, DuplicateEntry)> = None;
+            for row in rows {
+                let (id, existing, existing_embedding) = row?;
+                if Self::content_hash(&Self::dedup_normalize(&existing)) == hash {
+                    return Ok(Some(DuplicateEntry {
+                        id,
+                        content: existing,
+                        exact: true,
+                    }));
+                }
+                let (Some(incoming), Some(bytes)) = (embedding.as_deref(), existing_embedding)
+                else {
+                    continue;
+                };
+                let similarity = vector::cosine_similarity(incoming, &vector::bytes_to_vec(&bytes));
+                if similarity >= threshold && best.as_ref().is_none_or(|(top, _)| similarity > *top)
+                {
+                    best = Some((
+                        similarity,
+                        DuplicateEntry {
+                            id,
+                            content: existing,
+                            exact: false,
+                        },
+                    ));
+                }
+            }
+            Ok(best.map(|(_, duplicate)| duplicate))
+        })
+        .await?
+    }
+
+    /// Count a duplicate store against the entry it matched: bump its seen
+    /// count and, in merge mode, fold the new text into it.
+    async fn absorb_duplicate(
+        &self,
+        key: &str,
+        duplicate: DuplicateEntry,
+        incoming: &str,
+    ) -> anyhow::Result<()> {
+        let merged = if self.dedup == MemoryStoreDedup::Merge && !duplicate.exact {
+            let merged = crate::merge::merge_texts(&duplicate.content, incoming);
+            (merged != duplicate.content).then_some(merged)
+        } else {
+            None
+        };
+        let merged_embedding = match &merged {
+            Some(merged) => self
+                .get_or_compute_embedding(merged)
+                .await
+                .ok()
+                .flatten()
+                .map(|emb| vector::vec_to_bytes(&emb)),
+            None => None,
+        };
+
+        ::zeroclaw_log::record!(
+            DEBUG,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note).with_attrs(
+                ::serde_json::json!({
+                    "key": key,
+                    "duplicate_of": duplicate.id,
+                    "exact": duplicate.exact,
+                    "merged": merged.is_some(),
+                })
+            ),
+            "memory store: duplicate of a recent entry; not writing a new row"
+        );
+
+        let conn = self.conn.clone();
+        tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+            let conn = conn.lock();
+            let now = Local::now().to_rfc3339();
+            match merged {
+                Some(content) => conn.execute(
+                    "UPDATE memories
+                     SET content = ?1, embedding = COALESCE(?2, embedding), updated_at = ?3,
+                         seen_count = seen_count + 1, last_seen = ?3
+                     WHERE id = ?4",
+                    params![content, merged_embedding, now, duplicate.id],
+                )?,
+                None => conn.execute(
+                    "UPDATE memories SET seen_count = seen_count + 1, last_seen = ?1 WHERE id = ?2",
+                    params![now, duplicate.id],
+                )?,
+            };
             Ok(())
         })
         .await?
     }
 
+    /// Content compared for exact duplicates: case and whitespace runs
+    /// don't distinguish two entries.
+    fn dedup_normalize(content: &str) -> String {
+        content
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    }
+
+    /// Recall score multiplier for an entry stored `seen_count` times: a
+    /// slight, logarithmic preference that never outweighs relevance.
+    fn seen_boost(seen_count: i64) -> f32 {
+        #[allow(clippy::cast_precision_loss)]
+        let seen = seen_count.max(1) as f32;
+        1.0 + 0.05 * seen.ln().min(3.0)
+    }
+
     fn category_to_str(cat: &MemoryCategory) -> String {
         match cat {
             MemoryCategory::Core => "core".into(),
@@ -628,8 +1004,8 @@ impl SqliteMemory {
         let now_c = now.clone();
         let cached = tokio::task::spawn_blocking(move || -> anyhow::Result<Option<Vec<f32>>> {
             let conn = conn.lock();
-            let mut stmt =
-                conn.prepare("SELECT embedding FROM embedding_cache WHERE content_hash = ?1")?;
+            let mut stmt = conn
+                .prepare_cached("SELECT embedding FROM embedding_cache WHERE content_hash = ?1")?;
             let blob: Option<Vec<u8>> = stmt.query_row(params![hash_c], |row| row.get(0)).ok();
             if let Some(bytes) = blob {
                 conn.execute(
@@ -651,16 +1027,102 @@ impl SqliteMemory {
         let bytes = vector::vec_to_bytes(&embedding);
 
         // Store in cache + LRU eviction (offloaded to blocking thread)
+        self.cache_embeddings(vec![(hash, bytes)], now).await?;
+
+        Ok(Some(embedding))
+    }
+
+    /// Batched [`Self::get_or_compute_embedding`]: cache hits are served from
+    /// `embedding_cache` and every miss goes to the embedder in a single
+    /// `embed` call, which the provider splits by its max batch size.
+    /// Returns one vector per input, in order, or `None` for the Noop embedder.
+    pub async fn get_or_compute_embeddings(
+        &self,
+        texts: &[&str],
+    ) -> anyhow::Result<Option<Vec<Vec<f32>>>> {
+        let embedder = self.embedder.read().clone();
+        if embedder.dimensions() == 0 {
+            return Ok(None); // Noop embedder
+        }
+
+        let hashes: Vec<String> = texts.iter().map(|text| Self::content_hash(text)).collect();
+        let now = Local::now().to_rfc3339();
+
+        let conn = self.conn.clone();
+        let hashes_c = hashes.clone();
+        let now_c = now.clone();
+        let mut results =
+            tokio::task::spawn_blocking(move || -> anyhow::Result<Vec<Option<Vec<f32>>>> {
+                let conn = conn.lock();
+                let mut select = conn.prepare_cached(
+                    "SELECT embedding FROM embedding_cache WHERE content_hash = ?1",
+                )?;
+                let mut touch = conn.prepare_cached(
+                    "UPDATE embedding_cache SET accessed_at = ?1 WHERE content_hash = ?2",
+                )?;
+                let mut cached = Vec::with_capacity(hashes_c.len());
+                for hash in &hashes_c {
+                    let blob: Option<Vec<u8>> =
+                        select.query_row(params![hash], |row| row.get(0)).ok();
+                    if let Some(bytes) = blob {
+                        touch.execute(params![now_c, hash])?;
+                        cached.push(Some(vector::bytes_to_vec(&bytes)));
+                    } else {
+                        cached.push(None);
+                    }
+                }
+                Ok(cached)
+            })
+            .await??;
+
+        let missing: Vec<usize> = results
+            .iter()
+            .enumerate()
+            .filter_map(|(i, cached)| cached.is_none().then_some(i))
+            .collect();
+        if !missing.is_empty() {
+            let batch: Vec<&str> = missing.iter().map(|&i| texts[i]).collect();
+            let computed = embedder.embed(&batch).await?;
+            if computed.len() != batch.len() {
+                anyhow::bail!(
+                    "embedder returned {} vectors for {} inputs",
+                    computed.len(),
+                    batch.len()
+                );
+            }
+            let rows = missing
+                .iter()
+                .zip(&computed)
+                .map(|(&i, embedding)| (hashes[i].clone(), vector::vec_to_bytes(embedding)))
+                .collect();
+            for (&i, embedding) in missing.iter().zip(computed) {
+                results[i] = Some(embedding);
+            }
+            self.cache_embeddings(rows, now).await?;
+        }
+
+        Ok(Some(results.into_iter().flatten().collect()))
+    }
+
+    /// Insert `(content_hash, vector bytes)` rows into `embedding_cache`,
+    /// then evict least-recently-accessed entries beyond `cache_max`.
+    async fn cache_embeddings(
+        &self,
+        rows: Vec<(String, Vec<u8>)>,
+        now: String,
+    ) -> anyhow::Result<()> {
         let conn = self.conn.clone();
         #[allow(clippy::cast_possible_wrap)]
         let cache_max = self.cache_max as i64;
         tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
             let conn = conn.lock();
-            conn.execute(
+            let mut insert = conn.prepare_cached(
                 "INSERT OR REPLACE INTO embedding_cache (content_hash, embedding, created_at, accessed_at)
                  VALUES (?1, ?2, ?3, ?4)",
-                params![hash, bytes, now, now],
             )?;
+            for (hash, bytes) in rows {
+                insert.execute(params![hash, bytes, now, now])?;
+            }
             conn.execute(
                 "DELETE FROM embedding_cache WHERE content_hash IN (
                     SELECT content_hash FROM embedding_cache
@@ -671,9 +1133,7 @@ impl SqliteMemory {
             )?;
             Ok(())
         })
-        .await??;
-
-        Ok(Some(embedding))
+        .await?
     }
 
     /// FTS5 BM25 keyword search
@@ -772,7 +1232,7 @@ impl SqliteMemory {
         let params_ref: Vec<&dyn rusqlite::types::ToSql> =
             param_values.iter().map(AsRef::as_ref).collect();
 
-        let mut stmt = conn.prepare(&sql)?;
+        let mut stmt = conn.prepare_cached(&sql)?;
         let rows = stmt.query_map(params_ref.as_slice(), |row| {
             let id: String = row.get(0)?;
             let score: f64 = row.get(1)?;
@@ -920,7 +1380,7 @@ impl SqliteMemory {
             }
         }
 
-        let mut stmt = conn.prepare(&sql)?;
+        let mut stmt = conn.prepare_cached(&sql)?;
         let params_ref: Vec<&dyn rusqlite::types::ToSql> =
             param_values.iter().map(AsRef::as_ref).collect();
         let rows = stmt.query_map(params_ref.as_slice(), |row| {
@@ -929,6 +1389,12 @@ impl SqliteMemory {
             Ok((id, blob))
         })?;
 
+        // Score rows as they stream in and keep only the best `limit`, so a
+        // large store never holds every id in memory at once.
+        let by_score = |a: &(String, f32), b: &(String, f32)| {
+            b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal)
+        };
+        let prune_at = limit.saturating_mul(2).max(64);
         let mut scored: Vec<(String, f32)> = Vec::new();
         for row in rows {
             let (id, blob) = row?;
@@ -936,10 +1402,14 @@ impl SqliteMemory {
             let sim = vector::cosine_similarity(query_embedding, &emb);
             if sim > 0.0 {
                 scored.push((id, sim));
+                if scored.len() >= prune_at {
+                    scored.sort_by(by_score);
+                    scored.truncate(limit);
+                }
             }
         }
 
-        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.sort_by(by_score);
         scored.truncate(limit);
         Ok(scored)
     }
@@ -951,6 +1421,7 @@ impl SqliteMemory {
         session_id: Option<&str>,
         since: Option<&str>,
         until: Option<&str>,
+        allowed_agent_ids: Vec<String>,
     ) -> anyhow::Result<Vec<MemoryEntry>> {
         let conn = self.conn.clone();
         let sid = session_id.map(String::from);
@@ -985,11 +1456,22 @@ impl SqliteMemory {
                 param_values.push(Box::new(u.to_string()));
                 idx += 1;
             }
+            if !allowed_agent_ids.is_empty() {
+                let agent_placeholders = (0..allowed_agent_ids.len())
+                    .map(|offset| format!("?{}", idx + offset))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let _ = write!(sql, " AND m.agent_id IN ({agent_placeholders})");
+                idx += allowed_agent_ids.len();
+                for agent_id in allowed_agent_ids {
+                    param_values.push(Box::new(agent_id));
+                }
+            }
             let _ = write!(sql, " ORDER BY m.updated_at DESC LIMIT ?{idx}");
             #[allow(clippy::cast_possible_wrap)]
             param_values.push(Box::new(limit as i64));
 
-            let mut stmt = conn.prepare(&sql)?;
+            let mut stmt = conn.prepare_cached(&sql)?;
             let params_ref: Vec<&dyn rusqlite::types::ToSql> =
                 param_values.iter().map(AsRef::as_ref).collect();
             let rows = stmt.query_map(params_ref.as_slice(), |row| {
@@ -1035,33 +1517,18 @@ impl SqliteMemory {
         // Treat only a bare "*" as the same recent-entry request; keep
         // real wildcard searches such as "wild*" on the keyword path.
         if is_recent_recall_query(query) {
-            let recall_limit = if allowed_agent_ids.is_empty() {
-                limit
-            } else {
-                self.count().await?.max(limit)
-            };
-            let raw = self
-                .recall_by_time_only(recall_limit, session_id, since, until)
-                .await?;
-            if allowed_agent_ids.is_empty() {
-                return Ok(raw);
-            }
-            return Ok(raw
-                .into_iter()
-                .filter(|entry| {
-                    entry
-                        .agent_id
-                        .as_deref()
-                        .is_some_and(|agent_id| allowed_agent_ids.iter().any(|id| id == agent_id))
-                })
-                .take(limit)
-                .collect());
+            return self
+                .recall_by_time_only(limit, session_id, since, until, allowed_agent_ids)
+                .await;
         }
 
-        // Compute query embedding only when needed (skip for BM25-only mode)
+        // Compute query embedding only when needed (skip for BM25-only mode).
+        // Rows still queued for the deferred writer get their vectors first,
+        // so a fresh store is never invisible to the vector stage.
         let query_embedding = if self.search_mode == SearchMode::Bm25 {
             None
         } else {
+            self.flush_pending_embeddings().await;
             self.get_or_compute_embedding(query).await?
         };
 
@@ -1175,7 +1642,7 @@ impl SqliteMemory {
                     .collect::<Vec<_>>()
                     .join(", ");
                 let sql = format!(
-                    "SELECT m.id, m.key, m.content, m.category, m.created_at, m.session_id, m.namespace, m.importance, m.superseded_by, m.kind, m.pinned, a.alias, m.agent_id, m.tenant_id \
+                    "SELECT m.id, m.key, m.content, m.category, m.created_at, m.session_id, m.namespace, m.importance, m.superseded_by, m.kind, m.pinned, a.alias, m.agent_id, m.tenant_id, m.seen_count \
                      FROM memories m LEFT JOIN agents a ON a.id = m.agent_id \
                      WHERE m.superseded_by IS NULL AND m.id IN ({placeholders})"
                 );
@@ -1202,6 +1669,7 @@ impl SqliteMemory {
                         row.get::<_, Option<String>>(11)?,
                         row.get::<_, Option<String>>(12)?,
                         row.get::<_, Option<String>>(13)?,
+                        row.get::<_, Option<i64>>(14)?.unwrap_or(1),
                     ))
                 })?;
 
@@ -1222,12 +1690,13 @@ impl SqliteMemory {
                         alias,
                         aid,
                         tenant,
+                        seen,
                     ) = row?;
                     entry_map.insert(
                         id,
                         (
                             key, content, cat, ts, sid, ns, imp, sup, kind, pinned, alias, aid,
-                            tenant,
+                            tenant, seen,
                         ),
                     );
                 }
@@ -1247,6 +1716,7 @@ impl SqliteMemory {
                         alias,
                         aid,
                         tenant,
+                        seen,
                     )) = entry_map.remove(&scored.id)
                     {
                         if let Some(s) = since_ref
@@ -1264,7 +1734,7 @@ impl SqliteMemory {
                             category: Self::str_to_category(&cat),
                             timestamp: ts,
                             session_id: sid,
-                            score: Some(f64::from(scored.final_score)),
+                            score: Some(f64::from(scored.final_score * Self::seen_boost(seen))),
                             namespace: ns.unwrap_or_else(|| "default".into()),
                             importance: imp,
                             superseded_by: sup,
@@ -1292,6 +1762,11 @@ impl SqliteMemory {
                         results.push(entry);
                     }
                 }
+                results.sort_by(|a, b| {
+                    b.score
+                        .partial_cmp(&a.score)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
             }
 
             // If hybrid returned nothing, fall back to LIKE search.
@@ -1468,12 +1943,20 @@ impl Memory for SqliteMemory {
         // Rebuild from the freshly-resolved settings and swap in place. No
         // provider state is duplicated into a separate cache — the endpoint/key
         // come from the canonical config via the runtime resolver.
+        // Keep the configured batch size across the swap; a Noop embedder
+        // carries none, so fall back to the default.
+        let max_batch_size = self
+            .embedder
+            .read()
+            .max_batch_size()
+            .unwrap_or(super::embeddings::DEFAULT_EMBEDDING_BATCH_SIZE);
         let embedder: Arc<dyn EmbeddingProvider> =
-            Arc::from(super::embeddings::create_embedding_provider(
+            Arc::from(super::embeddings::create_batched_embedding_provider(
                 model_provider,
                 api_key,
                 model,
                 dimensions,
+                max_batch_size,
             ));
         self.swap_embedder(embedder);
     }
@@ -1511,7 +1994,7 @@ impl Memory for SqliteMemory {
 
         tokio::task::spawn_blocking(move || -> anyhow::Result<Option<MemoryEntry>> {
             let conn = conn.lock();
-            let mut stmt = conn.prepare(
+            let mut stmt = conn.prepare_cached(
                 "SELECT m.id, m.key, m.content, m.category, m.created_at, m.session_id, m.namespace, m.importance, m.superseded_by, m.kind, m.pinned, a.alias, m.agent_id, m.tenant_id \
                  FROM memories m LEFT JOIN agents a ON a.id = m.agent_id \
                  WHERE m.key = ?1",
@@ -1556,7 +2039,7 @@ impl Memory for SqliteMemory {
 
         tokio::task::spawn_blocking(move || -> anyhow::Result<Option<MemoryEntry>> {
             let conn = conn.lock();
-            let mut stmt = conn.prepare(
+            let mut stmt = conn.prepare_cached(
                 "SELECT m.id, m.key, m.content, m.category, m.created_at, m.session_id, m.namespace, m.importance, m.superseded_by, m.kind, m.pinned, a.alias, m.agent_id, m.tenant_id \
                  FROM memories m LEFT JOIN agents a ON a.id = m.agent_id \
                  WHERE m.key = ?1 AND m.agent_id = ?2",
@@ -1860,6 +2343,7 @@ impl Memory for SqliteMemory {
         if self.embedder.read().dimensions() == 0 {
             return Ok(0);
         }
+        self.flush_pending_embeddings().await;
 
         let conn = self.conn.clone();
         let entries: Vec<(String, String)> = tokio::task::spawn_blocking(move || {
@@ -1873,26 +2357,88 @@ impl Memory for SqliteMemory {
         })
         .await??;
 
+        // Embed in provider-sized batches. A failed batch is skipped so one
+        // bad chunk doesn't cost the rest; if nothing could be embedded at
+        // all (e.g. a dimension mismatch), the error is returned.
+        let batch_size = self
+            .embedder
+            .read()
+            .max_batch_size()
+            .unwrap_or(super::embeddings::DEFAULT_EMBEDDING_BATCH_SIZE);
         let mut count = 0;
-        for (id, content) in &entries {
-            if let Ok(Some(emb)) = self.get_or_compute_embedding(content).await {
-                let bytes = vector::vec_to_bytes(&emb);
-                let conn = self.conn.clone();
-                let id = id.clone();
-                tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
-                    let conn = conn.lock();
-                    conn.execute(
+        let mut last_error = None;
+        for chunk in entries.chunks(batch_size) {
+            let texts: Vec<&str> = chunk.iter().map(|(_, content)| content.as_str()).collect();
+            let embeddings = match self.get_or_compute_embeddings(&texts).await {
+                Ok(Some(embeddings)) => embeddings,
+                Ok(None) => break,
+                Err(e) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                            .with_attrs(::serde_json::json!({
+                                "batch": chunk.len(),
+                                "error": format!("{e}"),
+                            })),
+                        "memory reindex: embedding batch failed; skipping"
+                    );
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+
+            let rows: Vec<(String, Vec<u8>)> = chunk
+                .iter()
+                .zip(&embeddings)
+                .map(|((id, _), emb)| (id.clone(), vector::vec_to_bytes(emb)))
+                .collect();
+            count += rows.len();
+            let conn = self.conn.clone();
+            tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+                let conn = conn.lock();
+                let tx = conn.unchecked_transaction()?;
+                for (id, bytes) in rows {
+                    tx.execute(
                         "UPDATE memories SET embedding = ?1 WHERE id = ?2",
                         params![bytes, id],
                     )?;
-                    Ok(())
-                })
-                .await??;
-                count += 1;
-            }
+                }
+                tx.commit()?;
+                Ok(())
+            })
+            .await??;
         }
 
-        Ok(count)
+        match last_error {
+            Some(e) if count == 0 => Err(e),
+            _ => Ok(count),
+        }
+    }
+
+    async fn vacuum(&self) -> anyhow::Result<u64> {
+        self.flush_pending_embeddings().await;
+        let conn = self.conn.clone();
+        tokio::task::spawn_blocking(move || -> anyhow::Result<u64> {
+            let conn = conn.lock();
+            let db_size = |conn: &Connection| -> rusqlite::Result<i64> {
+                conn.query_row(
+                    "SELECT page_count * page_size FROM pragma_page_count(), pragma_page_size()",
+                    [],
+                    |row| row.get(0),
+                )
+            };
+            let before = db_size(&conn)?;
+            conn.execute_batch(
+                "INSERT INTO memories_fts(memories_fts) VALUES('optimize');
+                 VACUUM;
+                 ANALYZE;
+                 PRAGMA wal_checkpoint(TRUNCATE);",
+            )?;
+            let after = db_size(&conn)?;
+            Ok(u64::try_from(before - after).unwrap_or(0))
+        })
+        .await?
     }
 
     async fn export(&self, filter: &ExportFilter) -> anyhow::Result<Vec<MemoryEntry>> {
@@ -3000,6 +3546,151 @@ mod tests {
         assert_eq!(entry.content, "this content must be retained");
     }
 
+    // ── Duplicate stores ─────────────────────────────────────────
+
+    /// Bag-of-words embedder: texts sharing most words land close together.
+    struct WordsEmbedding;
+
+    #[async_trait::async_trait]
+    impl super::super::embeddings::EmbeddingProvider for WordsEmbedding {
+        fn name(&self) -> &str {
+            "words"
+        }
+        fn dimensions(&self) -> usize {
+            64
+        }
+        async fn embed(&self, texts: &[&str]) -> anyhow::Result<Vec<Vec<f32>>> {
+            Ok(texts
+                .iter()
+                .map(|text| {
+                    let mut v = vec![0.0f32; 64];
+                    for word in text.split_whitespace() {
+                        let slot = word.bytes().fold(7usize, |h, b| h * 31 + usize::from(b)) % 64;
+                        v[slot] += 1.0;
+                    }
+                    v
+                })
+                .collect())
+        }
+    }
+
+    fn seen_count(mem: &SqliteMemory, key: &str) -> i64 {
+        mem.conn
+            .lock()
+            .query_row(
+                "SELECT seen_count FROM memories WHERE key = ?1",
+                params![key],
+                |row| row.get(0),
+            )
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn dedup_skip_drops_exact_duplicates_and_counts_them() {
+        let tmp = TempDir::new().unwrap();
+        let mem = SqliteMemory::new("test", tmp.path())
+            .unwrap()
+            .with_dedup(MemoryStoreDedup::Skip, 0.95);
+        let category = MemoryCategory::Conversation;
+
+        mem.store(
+            "a1",
+            "deploy the app to staging please",
+            category.clone(),
+            None,
+        )
+        .await
+        .unwrap();
+        for key in ["a2", "a3"] {
+            mem.store(
+                key,
+                "Deploy the app  to staging please",
+                category.clone(),
+                None,
+            )
+            .await
+            .unwrap();
+        }
+        assert_eq!(mem.count().await.unwrap(), 1);
+        assert_eq!(seen_count(&mem, "a1"), 3);
+
+        // Another category or namespace, or an update to an existing key,
+        // is not a duplicate.
+        mem.store(
+            "c1",
+            "deploy the app to staging please",
+            MemoryCategory::Core,
+            None,
+        )
+        .await
+        .unwrap();
+        mem.store("a1", "deploy the app to production", category, None)
+            .await
+            .unwrap();
+        assert_eq!(mem.count().await.unwrap(), 2);
+        let updated = mem.get("a1").await.unwrap().unwrap();
+        assert_eq!(updated.content, "deploy the app to production");
+    }
+
+    #[tokio::test]
+    async fn dedup_merge_folds_near_duplicates_into_the_existing_entry() {
+        let tmp = TempDir::new().unwrap();
+        let mem = SqliteMemory::with_embedder(
+            "test",
+            tmp.path(),
+            Arc::new(WordsEmbedding),
+            0.7,
+            0.3,
+            1000,
+            None,
+            SearchMode::default(),
+        )
+        .unwrap()
+        .with_dedup(MemoryStoreDedup::Merge, 0.9);
+
+        mem.store(
+            "pref",
+            "user prefers dark mode in every editor they use",
+            MemoryCategory::Core,
+            None,
+        )
+        .await
+        .unwrap();
+        mem.store(
+            "pref-again",
+            "user prefers dark mode in every editor they use daily",
+            MemoryCategory::Core,
+            None,
+        )
+        .await
+        .unwrap();
+        mem.store(
+            "other",
+            "release train leaves on thursdays",
+            MemoryCategory::Core,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(mem.count().await.unwrap(), 2);
+        assert!(mem.get("pref-again").await.unwrap().is_none());
+        let merged = mem.get("pref").await.unwrap().unwrap();
+        assert_eq!(
+            merged.content,
+            "user prefers dark mode in every editor they use daily"
+        );
+        assert_eq!(seen_count(&mem, "pref"), 2);
+        assert_eq!(seen_count(&mem, "other"), 1);
+    }
+
+    #[test]
+    fn seen_boost_is_slight_and_bounded() {
+        assert!((SqliteMemory::seen_boost(1) - 1.0).abs() < f32::EPSILON);
+        assert!(SqliteMemory::seen_boost(5) > SqliteMemory::seen_boost(2));
+        assert!(SqliteMemory::seen_boost(1_000_000) <= 1.15);
+    }
+
     // ── Embedder hot-swap────────────────────────────────
 
     /// A working embedder double that returns a fixed-length vector (each
@@ -3058,6 +3749,59 @@ mod tests {
         assert_eq!(embedding.len(), 4, "vector must come from the new embedder");
     }
 
+    #[tokio::test]
+    async fn get_or_compute_embeddings_serves_cache_hits_and_batches_misses() {
+        let (_tmp, mem) = temp_sqlite();
+        let stub = Arc::new(StubEmbedding::new(4, 0.2));
+        let calls = Arc::clone(&stub.calls);
+        mem.swap_embedder(stub);
+
+        mem.get_or_compute_embedding("alpha").await.unwrap();
+        let embeddings = mem
+            .get_or_compute_embeddings(&["alpha", "beta", "gamma"])
+            .await
+            .unwrap()
+            .expect("live embedder must produce vectors");
+
+        assert_eq!(embeddings.len(), 3);
+        assert!(embeddings.iter().all(|v| v == &vec![0.2_f32; 4]));
+        assert_eq!(
+            calls.load(std::sync::atomic::Ordering::SeqCst),
+            3,
+            "cached content must not be re-embedded"
+        );
+    }
+
+    #[tokio::test]
+    async fn reindex_surfaces_dimension_mismatch() {
+        let (_tmp, mem) = temp_sqlite();
+        mem.store("a", "Rust is fast", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+
+        // Configured for 8 dimensions, but the model emits 4.
+        struct Narrow;
+        #[async_trait::async_trait]
+        impl super::super::embeddings::EmbeddingProvider for Narrow {
+            fn name(&self) -> &str {
+                "narrow"
+            }
+            fn dimensions(&self) -> usize {
+                8
+            }
+            async fn embed(&self, texts: &[&str]) -> anyhow::Result<Vec<Vec<f32>>> {
+                Ok(texts.iter().map(|_| vec![0.5; 4]).collect())
+            }
+        }
+        mem.swap_embedder(Arc::new(super::super::embeddings::BatchedEmbedding::new(
+            Box::new(Narrow),
+            16,
+        )));
+
+        let err = mem.reindex().await.unwrap_err().to_string();
+        assert!(err.contains("dimension mismatch"), "{err}");
+    }
+
     #[tokio::test]
     async fn swap_embedder_invalidates_stale_embedding_cache() {
         let tmp = TempDir::new().unwrap();
@@ -3709,6 +4453,112 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn deferred_store_returns_before_embedding_and_recall_flushes_the_queue() {
+        let tmp = TempDir::new().unwrap();
+        let mem = SqliteMemory::with_embedder(
+            "test",
+            tmp.path(),
+            Arc::new(FixedEmbedding(4)),
+            0.7,
+            0.3,
+            1000,
+            None,
+            SearchMode::default(),
+        )
+        .unwrap()
+        .with_deferred_embeddings(true);
+
+        mem.store("a", "alpha content", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        mem.store("a", "alpha rewritten", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        mem.store("b", "beta content", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            count_scalar(
+                &mem,
+                "SELECT COUNT(*) FROM memories WHERE embedding IS NOT NULL"
+            ),
+            0
+        );
+
+        let hits = mem.recall("content", 10, None, None, None).await.unwrap();
+        assert!(hits.iter().any(|entry| entry.key == "b"));
+        assert_eq!(
+            count_scalar(
+                &mem,
+                "SELECT COUNT(*) FROM memories WHERE embedding IS NOT NULL"
+            ),
+            2
+        );
+        // All three queued texts were embedded, plus the query; the stale
+        // write to `a` was skipped when its vector landed.
+        assert_eq!(
+            count_scalar(&mem, "SELECT COUNT(*) FROM embedding_cache"),
+            4
+        );
+    }
+
+    #[tokio::test]
+    async fn recall_stays_fast_on_a_large_store() {
+        let (_tmp, mem) = temp_sqlite();
+        let agent_id = mem.ensure_agent_uuid("default").await.unwrap();
+        {
+            let conn = mem.connection().lock();
+            let tx = conn.unchecked_transaction().unwrap();
+            {
+                let mut insert = tx
+                    .prepare(
+                        "INSERT INTO memories
+                            (id, key, content, category, created_at, updated_at, namespace, agent_id)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?5, 'default', ?6)",
+                    )
+                    .unwrap();
+                for i in 0..50_000 {
+                    let category = if i % 5 == 0 { "core" } else { "conversation" };
+                    insert
+                        .execute(params![
+                            format!("id-{i}"),
+                            format!("key-{i}"),
+                            format!("note {i} about topic{}", i % 1000),
+                            category,
+                            format!("2026-01-{:02}T00:00:00+00:00", 1 + i % 28),
+                            agent_id,
+                        ])
+                        .unwrap();
+                }
+            }
+            tx.commit().unwrap();
+        }
+
+        let started = std::time::Instant::now();
+        let hits = mem.recall("topic42", 10, None, None, None).await.unwrap();
+        let keyword_elapsed = started.elapsed();
+        assert_eq!(hits.len(), 10);
+        assert!(hits.iter().all(|entry| entry.content.ends_with("topic42")));
+
+        let started = std::time::Instant::now();
+        let recent = mem
+            .recall_for_agents(&[agent_id.as_str()], "*", 10, None, None, None)
+            .await
+            .unwrap();
+        let recent_elapsed = started.elapsed();
+        assert_eq!(recent.len(), 10);
+
+        assert!(
+            keyword_elapsed < Duration::from_secs(1),
+            "keyword recall took {keyword_elapsed:?}"
+        );
+        assert!(
+            recent_elapsed < Duration::from_secs(1),
+            "recent recall took {recent_elapsed:?}"
+        );
+    }
+
     // ── Recall limit test ────────────────────────────────────────
 
     #[tokio::test]
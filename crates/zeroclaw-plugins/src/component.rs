@@ -8,7 +8,10 @@ use wasmtime::component::{Component, ResourceTable};
 use wasmtime::{Config, Engine, Store, StoreLimits, StoreLimitsBuilder};
 use wasmtime_wasi::{WasiCtx, WasiCtxView, WasiView};
 use wasmtime_wasi_http::WasiHttpCtx;
-use wasmtime_wasi_http::p2::{WasiHttpCtxView, WasiHttpView};
+use wasmtime_wasi_http::p2::bindings::http::types::ErrorCode;
+use wasmtime_wasi_http::p2::body::HyperOutgoingBody;
+use wasmtime_wasi_http::p2::types::{HostFutureIncomingResponse, OutgoingRequestConfig};
+use wasmtime_wasi_http::p2::{HttpResult, WasiHttpCtxView, WasiHttpHooks, WasiHttpView};
 
 use crate::PluginPermission;
 use crate::instance::PluginInstanceScope;
@@ -81,6 +84,11 @@ pub(crate) fn test_limits(call_fuel: u64) -> PluginLimits {
     }
 }
 
+/// Host-side check run on the authority of every outbound `wasi:http`
+/// request a plugin makes. Receives the request host; an `Err` carries the
+/// refusal reason and the request fails with `HTTP-request-denied`.
+pub type OutboundHostCheck = Arc<dyn Fn(&str) -> std::result::Result<(), String> + Send + Sync>;
+
 /// Complete host-side inputs for constructing one scoped plugin store.
 ///
 /// The immutable instance scope supplies identity and effective grants. Limits
@@ -91,6 +99,7 @@ pub(crate) struct PluginStoreSpec {
     limits: PluginLimits,
     inbound: InboundQueue,
     http: bool,
+    outbound: Option<OutboundHostCheck>,
 }
 
 impl PluginStoreSpec {
@@ -102,6 +111,7 @@ impl PluginStoreSpec {
             limits,
             inbound: InboundQueue::default(),
             http: false,
+            outbound: None,
         }
     }
 
@@ -116,6 +126,14 @@ impl PluginStoreSpec {
         self
     }
 
+    /// Check every outbound HTTP request against `check` (the host's network
+    /// policy). Without one, granted HTTP reaches any host.
+    #[must_use]
+    pub(crate) fn with_outbound_check(mut self, check: Option<OutboundHostCheck>) -> Self {
+        self.outbound = check;
+        self
+    }
+
     /// Attach the queue shared with a host-owned inbound listener.
     #[must_use]
     pub(crate) fn with_inbound(mut self, inbound: InboundQueue) -> Self {
@@ -156,6 +174,7 @@ pub struct PluginState {
     wasi: WasiCtx,
     table: ResourceTable,
     http: Option<WasiHttpCtx>,
+    http_hooks: OutboundHooks,
     inbound: InboundQueue,
     limits: StoreLimits,
     fuel_per_call: u64,
@@ -175,6 +194,9 @@ impl PluginState {
             wasi: WasiCtx::builder().build(),
             table: ResourceTable::new(),
             http,
+            http_hooks: OutboundHooks {
+                check: spec.outbound,
+            },
             inbound: spec.inbound,
             limits: StoreLimitsBuilder::new()
                 .memory_size(spec.limits.max_memory_bytes)
@@ -220,8 +242,37 @@ impl WasiHttpView for PluginState {
         WasiHttpCtxView {
             ctx,
             table: &mut self.table,
-            hooks: wasmtime_wasi_http::p2::default_hooks(),
+            hooks: &mut self.http_hooks,
+        }
+    }
+}
+
+/// `wasi:http` hooks that run the store's [`OutboundHostCheck`] before
+/// handing the request to the default sender.
+struct OutboundHooks {
+    check: Option<OutboundHostCheck>,
+}
+
+impl WasiHttpHooks for OutboundHooks {
+    fn send_request(
+        &mut self,
+        request: http::Request<HyperOutgoingBody>,
+        config: OutgoingRequestConfig,
+    ) -> HttpResult<HostFutureIncomingResponse> {
+        if let Some(check) = &self.check {
+            let host = request.uri().host().unwrap_or_default();
+            if let Err(reason) = check(host) {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"host": host, "reason": reason})),
+                    "plugin outbound request refused by network policy"
+                );
+                return Err(ErrorCode::HttpRequestDenied.into());
+            }
         }
+        wasmtime_wasi_http::p2::default_hooks().send_request(request, config)
     }
 }
 
@@ -72,11 +72,24 @@ pub async fn create_plugin(
     wasm_path: &Path,
     scope: &PluginInstanceScope,
     limits: crate::component::PluginLimits,
+) -> Result<Plugin> {
+    create_plugin_with_outbound_check(wasm_path, scope, limits, None).await
+}
+
+/// [`create_plugin`] with every outbound `wasi:http` request checked by
+/// `outbound` (the host's network policy) before it is sent.
+pub async fn create_plugin_with_outbound_check(
+    wasm_path: &Path,
+    scope: &PluginInstanceScope,
+    limits: crate::component::PluginLimits,
+    outbound: Option<crate::component::OutboundHostCheck>,
 ) -> Result<Plugin> {
     scope.require_capability(PluginCapability::Tool)?;
     let component = load_component(wasm_path)?;
     let mut store = crate::component::new_store(
-        PluginStoreSpec::new(scope.clone(), limits).with_granted_http(),
+        PluginStoreSpec::new(scope.clone(), limits)
+            .with_granted_http()
+            .with_outbound_check(outbound),
     );
     let http = store.data().http_enabled();
     let linker = if http {
@@ -1,7 +1,7 @@
 //! Bridge between WASM plugins and the Tool trait.
 
 use crate::PluginCapability;
-use crate::component::PluginLimits;
+use crate::component::{OutboundHostCheck, PluginLimits};
 use crate::instance::PluginInstanceScope;
 use crate::runtime;
 use async_trait::async_trait;
@@ -20,6 +20,7 @@ pub struct WasmTool {
     scope: PluginInstanceScope,
     config: HashMap<String, String>,
     limits: PluginLimits,
+    outbound: Option<OutboundHostCheck>,
 }
 
 impl Attributable for WasmTool {
@@ -55,6 +56,7 @@ impl WasmTool {
             scope,
             config,
             limits,
+            outbound: None,
         })
     }
 
@@ -87,8 +89,17 @@ impl WasmTool {
             scope,
             config,
             limits,
+            outbound: None,
         })
     }
+
+    /// Check every outbound HTTP request the plugin makes against the host's
+    /// network policy.
+    #[must_use]
+    pub fn with_outbound_check(mut self, check: OutboundHostCheck) -> Self {
+        self.outbound = Some(check);
+        self
+    }
 }
 
 /// Run a one-shot async plugin probe to completion from a synchronous context.
@@ -128,7 +139,13 @@ impl Tool for WasmTool {
 
     async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
         let args_json = serde_json::to_vec(&args)?;
-        let mut plugin = runtime::create_plugin(&self.wasm_path, &self.scope, self.limits).await?;
+        let mut plugin = runtime::create_plugin_with_outbound_check(
+            &self.wasm_path,
+            &self.scope,
+            self.limits,
+            self.outbound.clone(),
+        )
+        .await?;
         runtime::call_execute(&mut plugin, &args_json, &self.config).await
     }
 }
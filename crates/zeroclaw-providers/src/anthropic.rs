@@ -26,6 +26,10 @@ pub struct AnthropicModelProvider {
     base_url: String,
     max_tokens: u32,
     timeout_secs: u64,
+    connect_timeout_secs: u64,
+    proxy_url: Option<String>,
+    /// Extended-thinking budget applied when a request carries none.
+    default_thinking_budget: Option<u32>,
 }
 
 #[cfg(test)]
@@ -243,6 +247,9 @@ pub struct AnthropicBuilder {
     base_url: Option<String>,
     max_tokens: Option<u32>,
     timeout_secs: Option<u64>,
+    connect_timeout_secs: Option<u64>,
+    proxy_url: Option<String>,
+    thinking_budget_tokens: Option<u32>,
 }
 
 impl AnthropicBuilder {
@@ -278,6 +285,32 @@ impl AnthropicBuilder {
         self
     }
 
+    /// Override the TCP/TLS connect timeout. Values of 0 are ignored (the
+    /// default 10 s is kept).
+    pub fn connect_timeout_secs(mut self, secs: u64) -> Self {
+        if secs > 0 {
+            self.connect_timeout_secs = Some(secs);
+        }
+        self
+    }
+
+    /// Route requests through `proxy_url` instead of the global `[proxy]`
+    /// settings.
+    pub fn proxy_url(mut self, proxy_url: &str) -> Self {
+        self.proxy_url = Some(proxy_url.to_string());
+        self
+    }
+
+    /// Default extended-thinking budget for requests that do not set one
+    /// (e.g. no `/think` level chosen). Clamped to the API's accepted range.
+    pub fn thinking_budget_tokens(mut self, budget: u32) -> Self {
+        self.thinking_budget_tokens = Some(budget.clamp(
+            zeroclaw_api::model_provider::MIN_BUDGET_TOKENS,
+            zeroclaw_api::model_provider::MAX_BUDGET_TOKENS,
+        ));
+        self
+    }
+
     pub fn build(self) -> AnthropicModelProvider {
         AnthropicModelProvider {
             alias: self.alias,
@@ -289,6 +322,9 @@ impl AnthropicBuilder {
             timeout_secs: self
                 .timeout_secs
                 .unwrap_or(zeroclaw_api::model_provider::BASELINE_TIMEOUT_SECS),
+            connect_timeout_secs: self.connect_timeout_secs.unwrap_or(10),
+            proxy_url: self.proxy_url,
+            default_thinking_budget: self.thinking_budget_tokens,
         }
     }
 }
@@ -303,6 +339,9 @@ impl AnthropicModelProvider {
             base_url: None,
             max_tokens: None,
             timeout_secs: None,
+            connect_timeout_secs: None,
+            proxy_url: None,
+            thinking_budget_tokens: None,
         }
     }
 
@@ -813,6 +852,9 @@ impl AnthropicModelProvider {
         temperature: Option<f64>,
         model: &str,
     ) -> (Option<f64>, Option<NativeThinkingConfig>, u32) {
+        let thinking = thinking.or(self.default_thinking_budget.map(|budget_tokens| {
+            zeroclaw_api::model_provider::NativeThinkingParams { budget_tokens }
+        }));
         match thinking {
             Some(params) if anthropic_model_supports_native_thinking(model) => {
                 ::zeroclaw_log::record!(
@@ -851,10 +893,11 @@ impl AnthropicModelProvider {
     }
 
     fn http_client(&self) -> Client {
-        zeroclaw_config::schema::build_runtime_proxy_client_with_timeouts(
+        zeroclaw_config::schema::build_provider_proxy_client_with_timeouts(
             "model_provider.anthropic",
+            self.proxy_url.as_deref(),
             self.timeout_secs,
-            10,
+            self.connect_timeout_secs,
         )
     }
 
@@ -2262,6 +2305,26 @@ data: {\"type\":\"message_stop\"}\n\n";
         assert!((temp.unwrap() - 1.0_f64).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn resolve_thinking_uses_configured_default_budget() {
+        let provider = AnthropicModelProvider::builder("test")
+            .credential(Some("test-key"))
+            .thinking_budget_tokens(8_000)
+            .build();
+        let (temp, config, max_tokens) =
+            provider.resolve_thinking(None, Some(0.7_f64), "claude-sonnet-4-6");
+        assert_eq!(config.map(|c| c.budget_tokens), Some(8_000));
+        assert!((temp.unwrap() - 1.0_f64).abs() < f64::EPSILON);
+        assert!(max_tokens > 8_000);
+
+        // An explicit per-request budget still wins.
+        let params = zeroclaw_api::model_provider::NativeThinkingParams {
+            budget_tokens: 2_000,
+        };
+        let (_, config, _) = provider.resolve_thinking(Some(params), None, "claude-sonnet-4-6");
+        assert_eq!(config.map(|c| c.budget_tokens), Some(2_000));
+    }
+
     #[test]
     fn native_chat_request_serializes_without_temperature_when_none() {
         let req = NativeChatRequest {
@@ -2435,10 +2498,12 @@ data: {\"type\":\"message_stop\"}\n\n";
             ChatMessage {
                 role: "system".to_string(),
                 content: "System prompt".to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".to_string(),
                 content: "Hello".to_string(),
+                reasoning: None,
             },
         ];
         // Only 1 non-system message — should not cache
@@ -2452,12 +2517,14 @@ data: {\"type\":\"message_stop\"}\n\n";
         let mut messages = vec![ChatMessage {
             role: "system".to_string(),
             content: "System prompt".to_string(),
+            reasoning: None,
         }];
         // Add 3 non-system messages
         for i in 0..3 {
             messages.push(ChatMessage {
                 role: if i % 2 == 0 { "user" } else { "assistant" }.to_string(),
                 content: format!("Message {i}"),
+                reasoning: None,
             });
         }
         assert!(AnthropicModelProvider::should_cache_conversation(&messages));
@@ -2468,6 +2535,7 @@ data: {\"type\":\"message_stop\"}\n\n";
         let messages = vec![ChatMessage {
             role: "user".to_string(),
             content: "Hello".to_string(),
+            reasoning: None,
         }];
         // Exactly 1 non-system message — should not cache
         assert!(!AnthropicModelProvider::should_cache_conversation(
@@ -2479,10 +2547,12 @@ data: {\"type\":\"message_stop\"}\n\n";
             ChatMessage {
                 role: "user".to_string(),
                 content: "Hello".to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "assistant".to_string(),
                 content: "Hi".to_string(),
+                reasoning: None,
             },
         ];
         assert!(AnthropicModelProvider::should_cache_conversation(&messages));
@@ -2678,6 +2748,7 @@ data: {\"type\":\"message_stop\"}\n\n";
         let messages = vec![ChatMessage {
             role: "system".to_string(),
             content: "Short system prompt".to_string(),
+            reasoning: None,
         }];
 
         let (system_prompt, _) = AnthropicModelProvider::convert_messages(&messages);
@@ -2703,6 +2774,7 @@ data: {\"type\":\"message_stop\"}\n\n";
         let messages = vec![ChatMessage {
             role: "system".to_string(),
             content: large_content.clone(),
+            reasoning: None,
         }];
 
         let (system_prompt, _) = AnthropicModelProvider::convert_messages(&messages);
@@ -2790,18 +2862,22 @@ data: {\"type\":\"message_stop\"}\n\n";
             ChatMessage {
                 role: "system".to_string(),
                 content: "You are helpful.".to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".to_string(),
                 content: "gen a 2 sum in golang".to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "assistant".to_string(),
                 content: "```go\nfunc twoSum(nums []int) {}\n```".to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".to_string(),
                 content: "what's meaning of make here?".to_string(),
+                reasoning: None,
             },
         ];
 
@@ -2859,6 +2935,9 @@ data: {\"type\":\"message_stop\"}\n\n";
             base_url: format!("http://{addr}"),
             max_tokens: 4096,
             timeout_secs: 120,
+            connect_timeout_secs: 10,
+            proxy_url: None,
+            default_thinking_budget: None,
         };
 
         // Multi-turn conversation: system → user (Go code) → assistant (code response) → user (follow-up)
@@ -3059,6 +3138,7 @@ data: {\"type\":\"message_stop\"}\n\n";
             role: "user".to_string(),
             content: "Check this image: [IMAGE:data:image/jpeg;base64,/9j/4AAQ] What do you see?"
                 .to_string(),
+            reasoning: None,
         }];
 
         let (_, native_msgs) = AnthropicModelProvider::convert_messages(&messages);
@@ -3097,6 +3177,7 @@ data: {\"type\":\"message_stop\"}\n\n";
         let messages = vec![ChatMessage {
             role: "user".to_string(),
             content: "[IMAGE:data:image/png;base64,iVBORw0KGgo]".to_string(),
+            reasoning: None,
         }];
 
         let (_, native_msgs) = AnthropicModelProvider::convert_messages(&messages);
@@ -3126,6 +3207,7 @@ data: {\"type\":\"message_stop\"}\n\n";
         let messages = vec![ChatMessage {
             role: "user".to_string(),
             content: "Hello, how are you?".to_string(),
+            reasoning: None,
         }];
 
         let (_, native_msgs) = AnthropicModelProvider::convert_messages(&messages);
@@ -3170,10 +3252,12 @@ data: {\"type\":\"message_stop\"}\n\n";
             ChatMessage {
                 role: "system".to_string(),
                 content: "You are helpful.".to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".to_string(),
                 content: "Do two things.".to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "assistant".to_string(),
@@ -3185,6 +3269,7 @@ data: {\"type\":\"message_stop\"}\n\n";
                     ]
                 })
                 .to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "tool".to_string(),
@@ -3193,6 +3278,7 @@ data: {\"type\":\"message_stop\"}\n\n";
                     "content": "file1.txt\nfile2.txt"
                 })
                 .to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "tool".to_string(),
@@ -3201,6 +3287,7 @@ data: {\"type\":\"message_stop\"}\n\n";
                     "content": "/home/user"
                 })
                 .to_string(),
+                reasoning: None,
             },
         ];
 
@@ -3237,6 +3324,7 @@ data: {\"type\":\"message_stop\"}\n\n";
             ChatMessage {
                 role: "user".to_string(),
                 content: "Do a thing.".to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "assistant".to_string(),
@@ -3247,10 +3335,12 @@ data: {\"type\":\"message_stop\"}\n\n";
                     ]
                 })
                 .to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".to_string(),
                 content: "Actually, never mind.".to_string(),
+                reasoning: None,
             },
         ];
 
@@ -3292,6 +3382,7 @@ data: {\"type\":\"message_stop\"}\n\n";
             ChatMessage {
                 role: "user".to_string(),
                 content: "Do a thing.".to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "assistant".to_string(),
@@ -3302,6 +3393,7 @@ data: {\"type\":\"message_stop\"}\n\n";
                     ]
                 })
                 .to_string(),
+                reasoning: None,
             },
         ];
 
@@ -3326,6 +3418,7 @@ data: {\"type\":\"message_stop\"}\n\n";
             ChatMessage {
                 role: "user".to_string(),
                 content: "Hello".to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "assistant".to_string(),
@@ -3336,6 +3429,7 @@ data: {\"type\":\"message_stop\"}\n\n";
                     ]
                 })
                 .to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "tool".to_string(),
@@ -3344,10 +3438,12 @@ data: {\"type\":\"message_stop\"}\n\n";
                     "content": "hi"
                 })
                 .to_string(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".to_string(),
                 content: "Thanks!".to_string(),
+                reasoning: None,
             },
         ];
 
@@ -1042,6 +1042,7 @@ mod tests {
             role: "assistant".into(),
             content: r#"{"content":"trying","tool_calls":[{"id":"call_bad","name":"shell","arguments":"{\"command\":\"rm -rf"}]}"#
                 .into(),
+            reasoning: None,
         }];
 
         let native = AzureOpenAiModelProvider::convert_messages(&messages);
@@ -1061,6 +1062,7 @@ mod tests {
             role: "assistant".into(),
             content: r#"{"content":"using","tool_calls":[{"id":"call_ok","name":"shell","arguments":"{\"command\":\"pwd\"}"}]}"#
                 .into(),
+            reasoning: None,
         }];
 
         let native = AzureOpenAiModelProvider::convert_messages(&messages);
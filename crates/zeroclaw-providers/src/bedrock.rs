@@ -2200,6 +2200,7 @@ mod tests {
             messages.push(ChatMessage {
                 role: if i % 2 == 0 { "user" } else { "assistant" }.to_string(),
                 content: format!("Message {i}"),
+                reasoning: None,
             });
         }
         assert!(BedrockModelProvider::should_cache_conversation(&messages));
@@ -2499,6 +2500,7 @@ mod tests {
             ChatMessage {
                 role: "tool".to_string(),
                 content: "not valid json".to_string(),
+                reasoning: None,
             },
         ];
         let (_, msgs) = BedrockModelProvider::convert_messages(&messages);
@@ -2521,6 +2523,7 @@ mod tests {
             ChatMessage {
                 role: "tool".to_string(),
                 content: "raw output with no json".to_string(),
+                reasoning: None,
             },
         ];
         let (_, msgs) = BedrockModelProvider::convert_messages(&messages);
@@ -2660,6 +2663,7 @@ mod tests {
             ChatMessage {
                 role: "assistant".to_string(),
                 content: String::new(),
+                reasoning: None,
             },
             ChatMessage::user("Continue"),
         ];
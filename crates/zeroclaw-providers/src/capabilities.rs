@@ -0,0 +1,480 @@
+//! Model capability registry: context window, image input, native tool
+//! calling, and token prices per model.
+//!
+//! Three layers, most specific first: the operator's config (the provider
+//! entry's `context_window` / `vision` / `native_tools` and the
+//! `[cost.rates]` sheet), rows merged from the models.dev catalog by
+//! `zeroclaw models refresh`, and a built-in table of well-known models.
+//! Each dimension resolves independently, so a catalog row that only knows
+//! the context window still lets the built-in table fill the price.
+
+use crate::pricing::{ModelRates, model_id_candidates};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use zeroclaw_config::schema::Config;
+
+/// What a model can do and what it costs. `None` means "this layer does
+/// not know", never "unsupported".
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelCapabilities {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_window: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supports_vision: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supports_tools: Option<bool>,
+    /// USD per 1M input tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_per_mtok: Option<f64>,
+    /// USD per 1M output tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_per_mtok: Option<f64>,
+}
+
+impl ModelCapabilities {
+    /// True when no dimension is known.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Per-dimension merge: `self` wins, `fallback` fills what `self` left unset.
+    #[must_use]
+    pub fn or(self, fallback: ModelCapabilities) -> ModelCapabilities {
+        ModelCapabilities {
+            context_window: self.context_window.or(fallback.context_window),
+            supports_vision: self.supports_vision.or(fallback.supports_vision),
+            supports_tools: self.supports_tools.or(fallback.supports_tools),
+            input_per_mtok: self.input_per_mtok.or(fallback.input_per_mtok),
+            output_per_mtok: self.output_per_mtok.or(fallback.output_per_mtok),
+        }
+    }
+
+    /// The price dimensions as [`ModelRates`] for the cost path.
+    #[must_use]
+    pub fn rates(&self) -> ModelRates {
+        ModelRates {
+            input_per_mtok: self.input_per_mtok,
+            output_per_mtok: self.output_per_mtok,
+            cached_input_per_mtok: None,
+        }
+    }
+}
+
+/// Which layer a resolved value came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CapabilitySource {
+    /// Set by the operator in config.toml.
+    Config,
+    /// Merged from a provider catalog during `models refresh`.
+    Catalog,
+    /// The built-in table of well-known models.
+    Builtin,
+}
+
+impl std::fmt::Display for CapabilitySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Config => "config",
+            Self::Catalog => "catalog",
+            Self::Builtin => "builtin",
+        })
+    }
+}
+
+/// A resolved value and the layer that supplied it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sourced<T> {
+    pub value: T,
+    pub source: CapabilitySource,
+}
+
+/// Every dimension after layering; `None` when no layer knows it.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ResolvedCapabilities {
+    pub context_window: Option<Sourced<usize>>,
+    pub supports_vision: Option<Sourced<bool>>,
+    pub supports_tools: Option<Sourced<bool>>,
+    pub input_per_mtok: Option<Sourced<f64>>,
+    pub output_per_mtok: Option<Sourced<f64>>,
+}
+
+impl ResolvedCapabilities {
+    /// Layer `layers` in order; the first layer that knows a dimension wins it.
+    #[must_use]
+    pub fn from_layers(layers: &[(CapabilitySource, ModelCapabilities)]) -> Self {
+        fn pick<T: Copy>(
+            layers: &[(CapabilitySource, ModelCapabilities)],
+            field: impl Fn(&ModelCapabilities) -> Option<T>,
+        ) -> Option<Sourced<T>> {
+            layers.iter().find_map(|(source, caps)| {
+                field(caps).map(|value| Sourced {
+                    value,
+                    source: *source,
+                })
+            })
+        }
+        Self {
+            context_window: pick(layers, |c| c.context_window),
+            supports_vision: pick(layers, |c| c.supports_vision),
+            supports_tools: pick(layers, |c| c.supports_tools),
+            input_per_mtok: pick(layers, |c| c.input_per_mtok),
+            output_per_mtok: pick(layers, |c| c.output_per_mtok),
+        }
+    }
+}
+
+/// Catalog rows keyed by provider family (`anthropic`, `openrouter`, ...),
+/// then by model id.
+pub type CapabilityCatalog = HashMap<String, HashMap<String, ModelCapabilities>>;
+
+/// Catalog rows in effect for this process. Loaded from disk at channel
+/// startup and replaced by `models refresh`.
+static CATALOG: LazyLock<RwLock<Arc<CapabilityCatalog>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(HashMap::new())));
+
+/// File under `<data_dir>/state/` that `models refresh` writes.
+const CATALOG_FILE: &str = "model_capabilities.json";
+
+#[must_use]
+pub fn catalog_path(data_dir: &Path) -> PathBuf {
+    data_dir.join("state").join(CATALOG_FILE)
+}
+
+/// Non-blocking read of the catalog rows in effect.
+#[must_use]
+pub fn current_catalog() -> Arc<CapabilityCatalog> {
+    Arc::clone(&CATALOG.read())
+}
+
+fn store_catalog(catalog: CapabilityCatalog) {
+    *CATALOG.write() = Arc::new(catalog);
+}
+
+fn read_catalog_file(data_dir: &Path) -> CapabilityCatalog {
+    std::fs::read_to_string(catalog_path(data_dir))
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+/// Load the rows a previous `models refresh` saved. A missing or unreadable
+/// file leaves only the built-in table in effect.
+pub fn load_catalog(data_dir: &Path) {
+    store_catalog(read_catalog_file(data_dir));
+}
+
+/// Fetch the models.dev catalog and merge rows for each provider family in
+/// `families` into the saved catalog, replacing those families' previous
+/// rows. Families with no models.dev listing are skipped. Returns the number
+/// of model rows written.
+pub async fn refresh_from_models_dev(
+    data_dir: &Path,
+    families: &[String],
+) -> anyhow::Result<usize> {
+    let wanted: Vec<(&str, &str)> = families
+        .iter()
+        .filter_map(|family| {
+            let (key, _) = crate::catalog::catalog_source_for(family)?;
+            Some((family.as_str(), key?))
+        })
+        .collect();
+    if wanted.is_empty() {
+        return Ok(0);
+    }
+    let source = crate::models_dev::fetch_catalog().await?;
+    let mut catalog = read_catalog_file(data_dir);
+    let mut written = 0;
+    for (family, key) in wanted {
+        let rows = crate::models_dev::capabilities_from_catalog(&source, key);
+        written += rows.len();
+        catalog.insert(family.to_string(), rows);
+    }
+    let path = catalog_path(data_dir);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(&catalog)?)?;
+    store_catalog(catalog);
+    Ok(written)
+}
+
+fn family_of(provider_ref: &str) -> &str {
+    provider_ref
+        .split_once('.')
+        .map_or(provider_ref, |(family, _)| family)
+}
+
+/// Catalog row for `model` under `provider_ref` (`type.alias` or bare family).
+#[must_use]
+pub fn catalog_lookup(
+    catalog: &CapabilityCatalog,
+    provider_ref: &str,
+    model: &str,
+) -> Option<ModelCapabilities> {
+    let models = catalog.get(family_of(provider_ref))?;
+    model_id_candidates(model).find_map(|id| models.get(id).copied())
+}
+
+/// Built-in rows for well-known models, matched by id prefix (longest wins).
+/// Prices are list prices at the time of writing and only fill in when
+/// neither config nor a refreshed catalog has one.
+const BUILTIN: &[(&str, ModelCapabilities)] = &[
+    ("claude-opus-4", builtin(200_000, Some(true), 15.0, 75.0)),
+    ("claude-opus-4-5", builtin(200_000, Some(true), 5.0, 25.0)),
+    ("claude-sonnet-4", builtin(200_000, Some(true), 3.0, 15.0)),
+    ("claude-haiku-4-5", builtin(200_000, Some(true), 1.0, 5.0)),
+    ("claude-3-5-haiku", builtin(200_000, Some(true), 0.8, 4.0)),
+    ("gpt-4o", builtin(128_000, Some(true), 2.5, 10.0)),
+    ("gpt-4o-mini", builtin(128_000, Some(true), 0.15, 0.6)),
+    ("gpt-4.1", builtin(1_047_576, Some(true), 2.0, 8.0)),
+    ("gpt-4.1-mini", builtin(1_047_576, Some(true), 0.4, 1.6)),
+    ("gpt-4.1-nano", builtin(1_047_576, Some(true), 0.1, 0.4)),
+    ("gpt-5", builtin(400_000, Some(true), 1.25, 10.0)),
+    ("gpt-5-mini", builtin(400_000, Some(true), 0.25, 2.0)),
+    ("gpt-5-nano", builtin(400_000, Some(true), 0.05, 0.4)),
+    ("o3", builtin(200_000, Some(true), 2.0, 8.0)),
+    ("o3-mini", builtin(200_000, Some(false), 1.1, 4.4)),
+    ("o4-mini", builtin(200_000, Some(true), 1.1, 4.4)),
+    ("gemini-2.5-pro", builtin(1_048_576, Some(true), 1.25, 10.0)),
+    ("gemini-2.5-flash", builtin(1_048_576, Some(true), 0.3, 2.5)),
+    ("grok-4", builtin(256_000, Some(true), 3.0, 15.0)),
+    ("deepseek-chat", unpriced(128_000, Some(false))),
+    ("deepseek-reasoner", unpriced(128_000, Some(false))),
+    ("mistral-large", unpriced(128_000, None)),
+    ("qwen2.5", unpriced(32_768, Some(false))),
+    ("llama3.1", unpriced(131_072, Some(false))),
+];
+
+const fn builtin(
+    context_window: usize,
+    supports_vision: Option<bool>,
+    input_per_mtok: f64,
+    output_per_mtok: f64,
+) -> ModelCapabilities {
+    ModelCapabilities {
+        context_window: Some(context_window),
+        supports_vision,
+        supports_tools: Some(true),
+        input_per_mtok: Some(input_per_mtok),
+        output_per_mtok: Some(output_per_mtok),
+    }
+}
+
+const fn unpriced(context_window: usize, supports_vision: Option<bool>) -> ModelCapabilities {
+    ModelCapabilities {
+        context_window: Some(context_window),
+        supports_vision,
+        supports_tools: Some(true),
+        input_per_mtok: None,
+        output_per_mtok: None,
+    }
+}
+
+/// True when `prefix` names `model` or a dated/sized variant of it
+/// (`claude-sonnet-4` matches `claude-sonnet-4-5-20250929`, not `gpt-4` →
+/// `gpt-4o`).
+fn prefix_matches(model: &str, prefix: &str) -> bool {
+    model
+        .strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['-', ':', '.', '@', '_']))
+}
+
+/// Built-in row for `model`, if it is a well-known model.
+#[must_use]
+pub fn builtin_lookup(model: &str) -> Option<ModelCapabilities> {
+    model_id_candidates(model).find_map(|id| {
+        let id = id.to_ascii_lowercase();
+        BUILTIN
+            .iter()
+            .filter(|(prefix, _)| prefix_matches(&id, prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, caps)| *caps)
+    })
+}
+
+/// What the operator set for `model` on `provider_ref` in config.
+#[must_use]
+pub fn config_capabilities(config: &Config, provider_ref: &str, model: &str) -> ModelCapabilities {
+    let family = family_of(provider_ref);
+    let entry = provider_ref.split_once('.').and_then(|(ty, alias)| {
+        config
+            .providers
+            .models
+            .iter_entries()
+            .find(|(t, a, _)| *t == ty && *a == alias)
+            .map(|(_, _, entry)| entry)
+    });
+    let sheet = model_id_candidates(model).find_map(|id| config.cost.rates.model_rates(family, id));
+    // The provider entry's own `pricing` map (`<model>.input` or a flat
+    // `<model>` rate) fills what the rate sheet leaves unset.
+    let entry_rate = |dimension: &str| {
+        let pricing = &entry?.pricing;
+        model_id_candidates(model).find_map(|id| {
+            pricing
+                .get(&format!("{id}.{dimension}"))
+                .or_else(|| pricing.get(id))
+                .copied()
+        })
+    };
+    ModelCapabilities {
+        context_window: entry.and_then(|e| e.context_window),
+        supports_vision: entry.and_then(|e| e.vision),
+        supports_tools: entry.and_then(|e| e.native_tools),
+        input_per_mtok: sheet
+            .and_then(|r| r.input_per_mtok)
+            .or_else(|| entry_rate("input")),
+        output_per_mtok: sheet
+            .and_then(|r| r.output_per_mtok)
+            .or_else(|| entry_rate("output")),
+    }
+}
+
+/// Resolve every dimension for `model` on `provider_ref`: config, then the
+/// refreshed catalog, then the built-in table.
+#[must_use]
+pub fn resolve(config: &Config, provider_ref: &str, model: &str) -> ResolvedCapabilities {
+    let catalog = current_catalog();
+    ResolvedCapabilities::from_layers(&[
+        (
+            CapabilitySource::Config,
+            config_capabilities(config, provider_ref, model),
+        ),
+        (
+            CapabilitySource::Catalog,
+            catalog_lookup(&catalog, provider_ref, model).unwrap_or_default(),
+        ),
+        (
+            CapabilitySource::Builtin,
+            builtin_lookup(model).unwrap_or_default(),
+        ),
+    ])
+}
+
+/// Catalog and built-in knowledge for `model`, without config. Used where
+/// config has already been consulted (the cost path, the context budget).
+#[must_use]
+pub fn known(provider_ref: &str, model: &str) -> ModelCapabilities {
+    let catalog = current_catalog();
+    catalog_lookup(&catalog, provider_ref, model)
+        .unwrap_or_default()
+        .or(builtin_lookup(model).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_matches_longest_prefix_on_a_boundary() {
+        let sonnet = builtin_lookup("claude-sonnet-4-5-20250929").unwrap();
+        assert_eq!(sonnet.input_per_mtok, Some(3.0));
+        assert_eq!(
+            builtin_lookup("gpt-4o-mini").unwrap().input_per_mtok,
+            Some(0.15)
+        );
+        assert_eq!(
+            builtin_lookup("o3-mini").unwrap().supports_vision,
+            Some(false)
+        );
+        // Vendor-prefixed ids (OpenRouter) fall back to the slug.
+        assert_eq!(
+            builtin_lookup("anthropic/claude-opus-4-5")
+                .unwrap()
+                .output_per_mtok,
+            Some(25.0)
+        );
+        assert!(builtin_lookup("gpt-4").is_none());
+        assert!(builtin_lookup("o30").is_none());
+    }
+
+    #[test]
+    fn layers_resolve_per_dimension_and_record_their_source() {
+        let config_layer = ModelCapabilities {
+            context_window: Some(64_000),
+            ..Default::default()
+        };
+        let catalog_layer = ModelCapabilities {
+            context_window: Some(200_000),
+            supports_vision: Some(false),
+            ..Default::default()
+        };
+        let resolved = ResolvedCapabilities::from_layers(&[
+            (CapabilitySource::Config, config_layer),
+            (CapabilitySource::Catalog, catalog_layer),
+            (
+                CapabilitySource::Builtin,
+                builtin_lookup("claude-sonnet-4").unwrap(),
+            ),
+        ]);
+        assert_eq!(
+            resolved.context_window,
+            Some(Sourced {
+                value: 64_000,
+                source: CapabilitySource::Config
+            })
+        );
+        assert_eq!(
+            resolved.supports_vision.map(|s| (s.value, s.source)),
+            Some((false, CapabilitySource::Catalog))
+        );
+        assert_eq!(
+            resolved.input_per_mtok.map(|s| s.source),
+            Some(CapabilitySource::Builtin)
+        );
+    }
+
+    #[test]
+    fn config_capabilities_read_the_provider_entry_and_rate_sheet() {
+        let mut config = Config::default();
+        config.providers.models.anthropic.insert(
+            "default".to_string(),
+            zeroclaw_config::schema::AnthropicModelProviderConfig {
+                base: zeroclaw_config::schema::ModelProviderConfig {
+                    model: Some("claude-sonnet-4-6".into()),
+                    context_window: Some(100_000),
+                    vision: Some(false),
+                    pricing: HashMap::from([("claude-sonnet-4-6.output".into(), 9.0)]),
+                    ..Default::default()
+                },
+            },
+        );
+        config.cost.rates.providers.models.anthropic.insert(
+            "claude-sonnet-4-6".to_string(),
+            zeroclaw_config::schema::ModelCostRates {
+                input_per_mtok: Some(2.0),
+                ..Default::default()
+            },
+        );
+        let caps = config_capabilities(&config, "anthropic.default", "claude-sonnet-4-6");
+        assert_eq!(caps.context_window, Some(100_000));
+        assert_eq!(caps.supports_vision, Some(false));
+        assert_eq!(caps.input_per_mtok, Some(2.0));
+        assert_eq!(caps.output_per_mtok, Some(9.0));
+        assert!(
+            config_capabilities(&config, "anthropic.other", "claude-sonnet-4-6")
+                .context_window
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn catalog_lookup_keys_by_family() {
+        let mut catalog = CapabilityCatalog::new();
+        catalog.insert(
+            "openrouter".into(),
+            HashMap::from([(
+                "qwen/qwen3-vl".to_string(),
+                ModelCapabilities {
+                    supports_vision: Some(true),
+                    ..Default::default()
+                },
+            )]),
+        );
+        assert!(catalog_lookup(&catalog, "openrouter.default", "qwen/qwen3-vl").is_some());
+        assert!(catalog_lookup(&catalog, "openai.default", "qwen/qwen3-vl").is_none());
+    }
+}
@@ -8,6 +8,16 @@ use serde::Deserialize;
 const NEARAI_CATALOG_URL: &str = "https://cloud-api.near.ai/v1/model/list";
 const FETCH_TIMEOUT_SECS: u64 = 10;
 
+/// Perplexity's chat models. The API has no `/models` endpoint, so the
+/// provider and `list_models_for_family` serve this table instead.
+pub const PERPLEXITY_MODELS: &[&str] = &[
+    "sonar",
+    "sonar-pro",
+    "sonar-reasoning",
+    "sonar-reasoning-pro",
+    "sonar-deep-research",
+];
+
 /// `(models.dev key, openrouter.ai vendor prefix)` for a family name.
 /// Either or both can be `None` for families with no public catalog
 /// (local-only servers, credential-required APIs without a public
@@ -156,6 +166,12 @@ pub async fn list_models_for_family(family: &str) -> Result<Vec<String>> {
     if family == "nearai" {
         return list_nearai_models().await;
     }
+    if family == "perplexity" {
+        return Ok(PERPLEXITY_MODELS
+            .iter()
+            .map(|id| (*id).to_string())
+            .collect());
+    }
 
     let Some((md_key, or_prefix)) = catalog_source_for(family) else {
         anyhow::bail!("unknown provider family {family:?}");
@@ -54,6 +54,10 @@ pub struct OpenAiCompatibleModelProvider {
     native_tool_calling: bool,
     /// HTTP request timeout in seconds for LLM API calls. Default: 120.
     timeout_secs: u64,
+    /// TCP/TLS connect timeout in seconds. Default: 10.
+    connect_timeout_secs: u64,
+    /// Per-provider proxy URL; `None` uses the global runtime proxy.
+    proxy_url: Option<String>,
     /// Extra HTTP headers to include in all API requests.
     extra_headers: std::collections::HashMap<String, String>,
     /// Optional reasoning effort for GPT-5/Codex-compatible backends.
@@ -82,6 +86,15 @@ pub struct OpenAiCompatibleModelProvider {
     tls_ca_cert_pem: Option<Vec<u8>>,
     /// Extra JSON fields merged into every API request body.
     extra_body: Option<serde_json::Value>,
+    /// Readiness endpoint probed by `warmup`; see
+    /// [`OpenAiCompatibleBuilder::health_url`].
+    health_url: Option<String>,
+    /// Append response `citations` to the reply; see
+    /// [`OpenAiCompatibleBuilder::append_citations`].
+    append_citations: bool,
+    /// Fixed model list served by `list_models` for providers without a
+    /// `/models` endpoint.
+    static_models: Option<&'static [&'static str]>,
 }
 
 /// How the model_provider expects the API key to be sent.
@@ -301,6 +314,8 @@ pub struct OpenAiCompatibleBuilder {
     /// `None` preserves the default derived from `merge_system_into_user`.
     native_tool_calling_override: Option<bool>,
     timeout_secs: Option<u64>,
+    connect_timeout_secs: Option<u64>,
+    proxy_url: Option<String>,
     extra_headers: std::collections::HashMap<String, String>,
     reasoning_effort: Option<String>,
     /// Set to `Some(false)` by
@@ -315,6 +330,9 @@ pub struct OpenAiCompatibleBuilder {
     public_model_listing: bool,
     tls_ca_cert_path: Option<String>,
     extra_body: Option<serde_json::Value>,
+    health_url: Option<String>,
+    append_citations: bool,
+    static_models: Option<&'static [&'static str]>,
     auth_model_provider: Option<String>,
     auth_service: Option<AuthService>,
     auth_profile_override: Option<String>,
@@ -408,6 +426,22 @@ impl OpenAiCompatibleBuilder {
         self
     }
 
+    /// Override the TCP/TLS connect timeout. Values of 0 are ignored (the
+    /// default 10 s is kept).
+    pub fn connect_timeout_secs(mut self, connect_timeout_secs: u64) -> Self {
+        if connect_timeout_secs > 0 {
+            self.connect_timeout_secs = Some(connect_timeout_secs);
+        }
+        self
+    }
+
+    /// Route this provider's requests through `proxy_url` instead of the
+    /// global `[proxy]` settings.
+    pub fn proxy_url(mut self, proxy_url: &str) -> Self {
+        self.proxy_url = Some(proxy_url.to_string());
+        self
+    }
+
     /// Set extra HTTP headers to include in all API requests.
     pub fn extra_headers(mut self, headers: std::collections::HashMap<String, String>) -> Self {
         self.extra_headers = headers;
@@ -471,9 +505,40 @@ impl OpenAiCompatibleBuilder {
         self
     }
 
-    /// Inject extra JSON fields into every API request body.
+    /// Inject extra JSON fields into every API request body. Repeated calls
+    /// merge object fields, later calls winning on conflicting keys, so a
+    /// family default and the operator's `provider_extra` can coexist.
     pub fn extra_body(mut self, extra: serde_json::Value) -> Self {
-        self.extra_body = Some(extra);
+        self.extra_body = Some(match (self.extra_body.take(), extra) {
+            (Some(serde_json::Value::Object(mut base)), serde_json::Value::Object(extra)) => {
+                base.extend(extra);
+                serde_json::Value::Object(base)
+            }
+            (_, extra) => extra,
+        });
+        self
+    }
+
+    /// Probe this readiness endpoint on `warmup` instead of priming the
+    /// chat URL, failing while it answers non-2xx (e.g. llama-server's 503
+    /// during model load).
+    pub fn health_url(mut self, url: &str) -> Self {
+        self.health_url = Some(url.to_string());
+        self
+    }
+
+    /// Append the top-level `citations` URLs some providers (Perplexity)
+    /// return alongside the message as a numbered source list under the
+    /// reply. Streaming is reported unsupported so the list is never lost
+    /// to a streamed draft.
+    pub fn append_citations(mut self) -> Self {
+        self.append_citations = true;
+        self
+    }
+
+    /// Serve `list_models` from a fixed table instead of `/models`.
+    pub fn static_models(mut self, models: &'static [&'static str]) -> Self {
+        self.static_models = Some(models);
         self
     }
 
@@ -556,6 +621,8 @@ impl OpenAiCompatibleBuilder {
             native_tool_calling,
             merge_system_into_user,
             timeout_secs: self.timeout_secs.unwrap_or(120),
+            connect_timeout_secs: self.connect_timeout_secs.unwrap_or(10),
+            proxy_url: self.proxy_url,
             extra_headers: self.extra_headers,
             reasoning_effort: self.reasoning_effort,
             replay_assistant_reasoning: self.replay_assistant_reasoning_override.unwrap_or(true),
@@ -567,6 +634,9 @@ impl OpenAiCompatibleBuilder {
             public_model_listing: self.public_model_listing,
             tls_ca_cert_pem,
             extra_body: self.extra_body,
+            health_url: self.health_url,
+            append_citations: self.append_citations,
+            static_models: self.static_models,
         }
     }
 }
@@ -592,6 +662,8 @@ impl OpenAiCompatibleModelProvider {
             merge_system_into_user_preserve_native: false,
             native_tool_calling_override: None,
             timeout_secs: None,
+            connect_timeout_secs: None,
+            proxy_url: None,
             extra_headers: std::collections::HashMap::new(),
             reasoning_effort: None,
             replay_assistant_reasoning_override: None,
@@ -603,6 +675,9 @@ impl OpenAiCompatibleModelProvider {
             public_model_listing: false,
             tls_ca_cert_path: None,
             extra_body: None,
+            health_url: None,
+            append_citations: false,
+            static_models: None,
             auth_model_provider: None,
             auth_service: None,
             auth_profile_override: None,
@@ -711,12 +786,13 @@ impl OpenAiCompatibleModelProvider {
 
             let builder = Client::builder()
                 .timeout(std::time::Duration::from_secs(timeout))
-                .connect_timeout(std::time::Duration::from_secs(10))
+                .connect_timeout(std::time::Duration::from_secs(self.connect_timeout_secs))
                 .default_headers(headers);
             let builder = self.add_tls_cert_to_builder(builder);
-            let builder = zeroclaw_config::schema::apply_runtime_proxy_to_builder(
+            let builder = zeroclaw_config::schema::apply_provider_proxy_to_builder(
                 builder,
                 "model_provider.compatible",
+                self.proxy_url.as_deref(),
             );
 
             return builder.build().unwrap_or_else(|error| {
@@ -733,10 +809,11 @@ impl OpenAiCompatibleModelProvider {
             });
         }
 
-        zeroclaw_config::schema::build_runtime_proxy_client_with_timeouts(
+        zeroclaw_config::schema::build_provider_proxy_client_with_timeouts(
             "model_provider.compatible",
+            self.proxy_url.as_deref(),
             timeout,
-            10,
+            self.connect_timeout_secs,
         )
     }
 
@@ -780,13 +857,14 @@ impl OpenAiCompatibleModelProvider {
             }
 
             let builder = Client::builder()
-                .connect_timeout(std::time::Duration::from_secs(10))
+                .connect_timeout(std::time::Duration::from_secs(self.connect_timeout_secs))
                 .read_timeout(STREAM_IDLE_TIMEOUT)
                 .default_headers(headers);
             let builder = self.add_tls_cert_to_builder(builder);
-            let builder = zeroclaw_config::schema::apply_runtime_proxy_to_builder(
+            let builder = zeroclaw_config::schema::apply_provider_proxy_to_builder(
                 builder,
                 "provider.compatible",
+                self.proxy_url.as_deref(),
             );
             return builder.build().unwrap_or_else(|error| {
                 ::zeroclaw_log::record!(
@@ -803,10 +881,13 @@ impl OpenAiCompatibleModelProvider {
         }
 
         let builder = Client::builder()
-            .connect_timeout(std::time::Duration::from_secs(10))
+            .connect_timeout(std::time::Duration::from_secs(self.connect_timeout_secs))
             .read_timeout(STREAM_IDLE_TIMEOUT);
-        let builder =
-            zeroclaw_config::schema::apply_runtime_proxy_to_builder(builder, "provider.compatible");
+        let builder = zeroclaw_config::schema::apply_provider_proxy_to_builder(
+            builder,
+            "provider.compatible",
+            self.proxy_url.as_deref(),
+        );
         builder.build().unwrap_or_else(|error| {
             ::zeroclaw_log::record!(
                 WARN,
@@ -1016,6 +1097,11 @@ struct ApiChatResponse {
     choices: Vec<Choice>,
     #[serde(default)]
     usage: Option<UsageInfo>,
+    /// Sources cited by the answer (Perplexity), in citation order. Kept
+    /// loose so an unexpected shape from another provider never fails the
+    /// whole response.
+    #[serde(default)]
+    citations: Vec<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -1129,6 +1215,26 @@ fn strip_think_tags(s: &str) -> String {
     result.trim().to_string()
 }
 
+/// Collect the contents of `<think>...</think>` blocks (an unclosed block
+/// runs to the end) so inline reasoning is kept out of the reply text but
+/// still reported as `reasoning_content`.
+fn extract_think_content(s: &str) -> Option<String> {
+    let mut parts = Vec::new();
+    let mut rest = s;
+    while let Some(start) = rest.find("<think>") {
+        let body = &rest[start + "<think>".len()..];
+        let (inner, next) = match body.find("</think>") {
+            Some(end) => (&body[..end], &body[end + "</think>".len()..]),
+            None => (body, ""),
+        };
+        if !inner.trim().is_empty() {
+            parts.push(inner.trim().to_string());
+        }
+        rest = next;
+    }
+    (!parts.is_empty()).then(|| parts.join("\n\n"))
+}
+
 /// OpenAI Chat Completions may return assistant `message.content` as a string,
 /// null, or an array of typed parts. Normalize it before storing the internal
 /// response shape so compatible gateways that preserve typed parts still work,
@@ -1199,10 +1305,23 @@ struct RawResponseMessage {
 impl From<RawResponseMessage> for ResponseMessage {
     fn from(raw: RawResponseMessage) -> Self {
         // Canonical field wins when both are present; the alias fills in only
-        // when the canonical name is absent or null.
-        let reasoning_content = raw.reasoning_content.or(raw.reasoning);
+        // when the canonical name is absent or null. For plain replies from
+        // models that only emit inline `<think>` blocks, those blocks become
+        // the reasoning trace. Tool-call turns are left alone: their
+        // `reasoning_content` is replayed to the provider, which never sent
+        // the field in the first place.
+        let content = openai_assistant_content_plaintext(raw.content);
+        let has_tool_calls = raw
+            .tool_calls
+            .as_ref()
+            .is_some_and(|calls| !calls.is_empty());
+        let reasoning_content = raw.reasoning_content.or(raw.reasoning).or_else(|| {
+            (!has_tool_calls)
+                .then(|| content.as_deref().and_then(extract_think_content))
+                .flatten()
+        });
         ResponseMessage {
-            content: openai_assistant_content_plaintext(raw.content),
+            content,
             reasoning_content,
             tool_calls: raw.tool_calls,
         }
@@ -1973,6 +2092,24 @@ impl OpenAiCompatibleModelProvider {
         apply_auth_to_request(req, &self.auth_header, credential)
     }
 
+    /// Append `citations` to `text` as a numbered source list matching the
+    /// `[n]` markers in the answer. No-op unless enabled and non-empty.
+    fn with_citations(&self, text: String, citations: &[serde_json::Value]) -> String {
+        if !self.append_citations || citations.is_empty() || text.trim().is_empty() {
+            return text;
+        }
+        let mut out = text.trim_end().to_string();
+        out.push_str("\n\nSources:");
+        for (index, citation) in citations.iter().enumerate() {
+            let url = citation
+                .as_str()
+                .or_else(|| citation.get("url").and_then(serde_json::Value::as_str))
+                .unwrap_or_default();
+            out.push_str(&format!("\n[{}] {url}", index + 1));
+        }
+        out
+    }
+
     fn convert_tool_specs(
         tools: Option<&[zeroclaw_api::tool::ToolSpec]>,
     ) -> Option<Vec<serde_json::Value>> {
@@ -2456,6 +2593,9 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
     }
 
     async fn list_models(&self) -> anyhow::Result<Vec<String>> {
+        if let Some(models) = self.static_models {
+            return Ok(models.iter().map(|id| (*id).to_string()).collect());
+        }
         // When a credential is present, hit the model_provider's native /models endpoint
         // (OpenAI-compatible: GET {base_url}/models). Local OpenAI-compatible
         // servers with a public catalog use the same path without an Authorization header.
@@ -2529,6 +2669,15 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
     async fn list_models_with_pricing(
         &self,
     ) -> anyhow::Result<Vec<zeroclaw_api::model_provider::ModelInfo>> {
+        if let Some(models) = self.static_models {
+            return Ok(models
+                .iter()
+                .map(|id| zeroclaw_api::model_provider::ModelInfo {
+                    id: (*id).to_string(),
+                    pricing: None,
+                })
+                .collect());
+        }
         // When a credential is present, hit the provider's native /models
         // endpoint — this returns pricing data that we can capture.
         let list_credential = self.resolve_credential().await?;
@@ -2616,6 +2765,7 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
         let user_msg = ChatMessage {
             role: "user".to_string(),
             content: message.to_string(),
+            reasoning: None,
         };
         let normalized_user =
             Self::normalize_messages_for_upstream(std::slice::from_ref(&user_msg))
@@ -2687,6 +2837,7 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
 
         let body = response.text().await?;
         let chat_response = parse_chat_response_body(&self.name, &body)?;
+        let citations = chat_response.citations;
 
         chat_response
             .choices
@@ -2702,7 +2853,7 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
                     serde_json::to_string(&c.message)
                         .unwrap_or_else(|_| c.message.effective_content())
                 } else {
-                    c.message.effective_content()
+                    self.with_citations(c.message.effective_content(), &citations)
                 }
             })
             .ok_or_else(|| {
@@ -2770,6 +2921,7 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
 
         let body = response.text().await?;
         let chat_response = parse_chat_response_body(&self.name, &body)?;
+        let citations = chat_response.citations;
 
         chat_response
             .choices
@@ -2785,7 +2937,7 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
                     serde_json::to_string(&c.message)
                         .unwrap_or_else(|_| c.message.effective_content())
                 } else {
-                    c.message.effective_content()
+                    self.with_citations(c.message.effective_content(), &citations)
                 }
             })
             .ok_or_else(|| {
@@ -2878,7 +3030,10 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
             anyhow::Error::msg(format!("No response from {}", self.name))
         })?;
 
-        let text = choice.message.effective_content_optional();
+        let text = choice
+            .message
+            .effective_content_optional()
+            .map(|text| self.with_citations(text, &chat_response.citations));
         let reasoning_content = choice.message.reasoning_content;
         let mut used_tool_call_ids = std::collections::HashSet::new();
         let tool_calls = choice
@@ -3006,6 +3161,9 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
 
         let mut result = self.parse_native_response(message);
         result.usage = usage;
+        result.text = result
+            .text
+            .map(|text| self.with_citations(text, &native_response.citations));
         Ok(result)
     }
 
@@ -3014,7 +3172,7 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
     }
 
     fn supports_streaming(&self) -> bool {
-        true
+        !self.append_citations
     }
 
     fn supports_streaming_tool_events(&self) -> bool {
@@ -3231,6 +3389,7 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
             let user_msg = ChatMessage {
                 role: "user".to_string(),
                 content: message_owned,
+                reasoning: None,
             };
             let normalized_user = match Self::normalize_messages_for_upstream(std::slice::from_ref(
                 &user_msg,
@@ -3462,10 +3621,26 @@ impl ModelProvider for OpenAiCompatibleModelProvider {
     }
 
     async fn warmup(&self) -> anyhow::Result<()> {
+        let credential = self.resolve_credential().await?;
+        if let Some(ref url) = self.health_url {
+            let response = self
+                .apply_auth_header(self.http_client().get(url), credential.as_deref())
+                .send()
+                .await?;
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                anyhow::bail!(
+                    "{} health check at {url} returned {status}: {}",
+                    self.name,
+                    body.trim()
+                );
+            }
+            return Ok(());
+        }
         // Hit the appropriate URL with a GET to prime the connection pool.
         // The server will likely return 405 Method Not Allowed, which is fine.
         let url = self.chat_completions_url();
-        let credential = self.resolve_credential().await?;
         let _ = self
             .apply_auth_header(self.http_client().get(&url), credential.as_deref())
             .send()
@@ -3854,6 +4029,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extra_body_calls_merge_objects() {
+        let p = OpenAiCompatibleModelProvider::builder("test")
+            .display_name("test")
+            .base_url("https://example.com")
+            .credential(None)
+            .auth_style(AuthStyle::Bearer)
+            .extra_body(serde_json::json!({"safe_prompt": true, "top_k": 1}))
+            .extra_body(serde_json::json!({"top_k": 5}))
+            .build();
+        assert_eq!(
+            p.extra_body,
+            Some(serde_json::json!({"safe_prompt": true, "top_k": 5}))
+        );
+    }
+
+    #[test]
+    fn citations_are_appended_as_numbered_sources_when_enabled() {
+        let body = r#"{"choices":[{"message":{"content":"Rust 1.0 shipped in 2015 [1][2]."}}],
+            "citations":["https://blog.rust-lang.org/2015/05/15/Rust-1.0.html","https://en.wikipedia.org/wiki/Rust_(programming_language)"]}"#;
+        let resp = parse_chat_response_body("perplexity", body).unwrap();
+        assert_eq!(resp.citations.len(), 2);
+
+        let builder = || {
+            OpenAiCompatibleModelProvider::builder("pplx")
+                .display_name("Perplexity")
+                .base_url("https://api.perplexity.ai")
+                .credential(None)
+                .auth_style(AuthStyle::Bearer)
+        };
+        let text = "Rust 1.0 shipped in 2015 [1][2].".to_string();
+
+        let on = builder().append_citations().build();
+        assert!(!on.supports_streaming());
+        assert_eq!(
+            on.with_citations(text.clone(), &resp.citations),
+            "Rust 1.0 shipped in 2015 [1][2].\n\nSources:\n\
+             [1] https://blog.rust-lang.org/2015/05/15/Rust-1.0.html\n\
+             [2] https://en.wikipedia.org/wiki/Rust_(programming_language)"
+        );
+        assert_eq!(on.with_citations(text.clone(), &[]), text);
+
+        let off = builder().build();
+        assert!(off.supports_streaming());
+        assert_eq!(off.with_citations(text.clone(), &resp.citations), text);
+    }
+
+    #[tokio::test]
+    async fn static_models_serve_list_models_without_network() {
+        let p = OpenAiCompatibleModelProvider::builder("pplx")
+            .display_name("Perplexity")
+            .base_url("http://127.0.0.1:9")
+            .credential(Some("key"))
+            .auth_style(AuthStyle::Bearer)
+            .static_models(&["sonar", "sonar-pro"])
+            .build();
+        assert_eq!(p.list_models().await.unwrap(), ["sonar", "sonar-pro"]);
+        let priced = p.list_models_with_pricing().await.unwrap();
+        assert_eq!(priced.len(), 2);
+        assert!(priced.iter().all(|m| m.pricing.is_none()));
+    }
+
     #[test]
     fn normalize_model_ids_trims_filters_and_sorts() {
         let body = serde_json::from_value(serde_json::json!({
@@ -5094,6 +5331,7 @@ mod tests {
         let msg = ChatMessage {
             role: "user".into(),
             content: format!("Caption please [IMAGE:{}]", path_str),
+            reasoning: None,
         };
 
         let normalized = OpenAiCompatibleModelProvider::normalize_messages_for_upstream(
@@ -5320,6 +5558,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "user".to_string(),
             content: "hello".to_string(),
+            reasoning: None,
         }];
         let tools = vec![serde_json::json!({
             "type": "function",
@@ -5552,6 +5791,26 @@ mod tests {
         assert_eq!(msg.reasoning_content.as_deref(), Some("Thinking text"));
     }
 
+    #[test]
+    fn inline_think_tags_become_reasoning_content() {
+        // DeepSeek-R1 / MiniMax style: reasoning only arrives inline.
+        let json =
+            r#"{"choices":[{"message":{"content":"<think>Weigh both options.</think>Pick B."}}]}"#;
+        let resp: ApiChatResponse = serde_json::from_str(json).unwrap();
+        let msg = &resp.choices[0].message;
+        assert_eq!(msg.effective_content(), "Pick B.");
+        assert_eq!(
+            msg.reasoning_content.as_deref(),
+            Some("Weigh both options.")
+        );
+
+        // A tool-call turn keeps reasoning_content unset so nothing new is
+        // replayed to the provider.
+        let json = r#"{"choices":[{"message":{"content":"<think>Run ls.</think>","tool_calls":[{"id":"call_1","type":"function","function":{"name":"shell","arguments":"{}"}}]}}]}"#;
+        let resp: ApiChatResponse = serde_json::from_str(json).unwrap();
+        assert!(resp.choices[0].message.reasoning_content.is_none());
+    }
+
     #[test]
     fn reasoning_content_both_absent_returns_empty() {
         // Neither content nor reasoning_content - returns empty string
@@ -6554,6 +6813,7 @@ mod tests {
             ChatMessage {
                 role: "assistant".to_string(),
                 content: ChatMessage::pruned_tool_exchange_summary(1),
+                reasoning: None,
             },
             ChatMessage::pruned_context_separator(),
             ChatMessage::assistant(
@@ -916,6 +916,7 @@ mod tests {
             role: "assistant".into(),
             content: r#"{"content":"trying","tool_calls":[{"id":"call_bad","name":"shell","arguments":"{\"command\":\"rm -rf"}]}"#
                 .into(),
+            reasoning: None,
         }];
 
         let api_messages = CopilotModelProvider::convert_messages(&messages);
@@ -936,6 +937,7 @@ mod tests {
             role: "assistant".into(),
             content: r#"{"content":"using","tool_calls":[{"id":"call_ok","name":"shell","arguments":"{\"command\":\"pwd\"}"}]}"#
                 .into(),
+            reasoning: None,
         }];
 
         let api_messages = CopilotModelProvider::convert_messages(&messages);
@@ -0,0 +1,302 @@
+//! Discovery of provider credentials that already exist on the machine.
+//!
+//! Quickstart uses this to offer keys the operator has already exported
+//! (`OPENROUTER_API_KEY`, `ANTHROPIC_API_KEY`, ...) or stored in a vendor CLI's
+//! config file, and `doctor` uses it to explain where a key was found. Nothing
+//! here feeds the runtime: apart from the families listed in
+//! [`crate::NATIVE_API_KEY_ENV_VARS`], providers only ever read credentials
+//! from config.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Ecosystem-default env vars per family, in Quickstart preference order:
+/// when several families have a key, the first one listed wins `auto`.
+const KNOWN_ENV_VARS: &[(&str, &[&str])] = &[
+    ("openrouter", &["OPENROUTER_API_KEY"]),
+    ("anthropic", &["ANTHROPIC_API_KEY"]),
+    ("openai", &["OPENAI_API_KEY"]),
+    ("gemini", &["GEMINI_API_KEY", "GOOGLE_API_KEY"]),
+    ("xai", &["XAI_API_KEY", "GROK_API_KEY"]),
+    ("deepseek", &["DEEPSEEK_API_KEY"]),
+    ("mistral", &["MISTRAL_API_KEY"]),
+    ("groq", &["GROQ_API_KEY"]),
+    ("together", &["TOGETHER_API_KEY"]),
+    ("fireworks", &["FIREWORKS_API_KEY"]),
+    ("perplexity", &["PERPLEXITY_API_KEY"]),
+    ("cohere", &["COHERE_API_KEY", "CO_API_KEY"]),
+    ("moonshot", &["MOONSHOT_API_KEY"]),
+    ("nvidia", &["NVIDIA_API_KEY"]),
+];
+
+/// How a well-known credential file stores its key.
+#[derive(Debug, Clone, Copy)]
+enum FileFormat {
+    /// The whole file is the key.
+    PlainText,
+    /// A top-level string field of a JSON object.
+    JsonField(&'static str),
+}
+
+/// Well-known credential files, relative to the home directory. Checked after
+/// env vars, so an exported key always wins over a file.
+const KNOWN_FILES: &[(&str, &str, FileFormat)] = &[
+    ("openai", ".config/openai/api_key", FileFormat::PlainText),
+    (
+        "openai",
+        ".codex/auth.json",
+        FileFormat::JsonField("OPENAI_API_KEY"),
+    ),
+];
+
+/// Where a discovered key came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CredentialSource {
+    Env(&'static str),
+    File(PathBuf),
+}
+
+impl fmt::Display for CredentialSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Env(var) => write!(f, "${var}"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// A provider key found outside ZeroClaw's config.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DiscoveredCredential {
+    /// Canonical model-provider family (`"openai"`, `"xai"`, ...).
+    pub family: &'static str,
+    pub source: CredentialSource,
+    pub api_key: String,
+}
+
+impl DiscoveredCredential {
+    /// Key with everything past the first four characters hidden, for display.
+    #[must_use]
+    pub fn redacted_key(&self) -> String {
+        let prefix: String = self.api_key.chars().take(4).collect();
+        if self.api_key.chars().count() <= 8 {
+            "***".to_string()
+        } else {
+            format!("{prefix}***")
+        }
+    }
+}
+
+// Hand-written so the key never lands in debug output or logs.
+impl fmt::Debug for DiscoveredCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DiscoveredCredential")
+            .field("family", &self.family)
+            .field("source", &self.source)
+            .field("api_key", &self.redacted_key())
+            .finish()
+    }
+}
+
+/// Scan known env vars and credential files. Returns at most one credential
+/// per family, in preference order (see [`best_credential`]).
+#[must_use]
+pub fn discover_credentials() -> Vec<DiscoveredCredential> {
+    let home = directories::UserDirs::new().map(|dirs| dirs.home_dir().to_path_buf());
+    discover_with(|var| std::env::var(var).ok(), home.as_deref())
+}
+
+/// The credential Quickstart's `--model-provider auto` should use.
+#[must_use]
+pub fn best_credential(found: &[DiscoveredCredential]) -> Option<&DiscoveredCredential> {
+    found.first()
+}
+
+/// Discovered credential for `family` (synonyms accepted), if any.
+#[must_use]
+pub fn credential_for<'a>(
+    found: &'a [DiscoveredCredential],
+    family: &str,
+) -> Option<&'a DiscoveredCredential> {
+    let family = crate::canonicalize_v2_model_provider_name(family);
+    found.iter().find(|cred| cred.family == family)
+}
+
+/// Ecosystem-default env vars for `family` (synonyms accepted), or `&[]`
+/// for families that don't use an API key (local servers) or aren't known.
+#[must_use]
+pub fn known_env_vars(family: &str) -> &'static [&'static str] {
+    let family = crate::canonicalize_v2_model_provider_name(family);
+    KNOWN_ENV_VARS
+        .iter()
+        .find(|(name, _)| *name == family)
+        .map_or(&[], |(_, vars)| *vars)
+}
+
+fn discover_with(
+    env: impl Fn(&str) -> Option<String>,
+    home: Option<&Path>,
+) -> Vec<DiscoveredCredential> {
+    let mut found: Vec<DiscoveredCredential> = Vec::new();
+
+    for (family, vars) in KNOWN_ENV_VARS {
+        if let Some((var, key)) = vars
+            .iter()
+            .find_map(|var| non_empty(env(var)).map(|key| (*var, key)))
+        {
+            found.push(DiscoveredCredential {
+                family,
+                source: CredentialSource::Env(var),
+                api_key: key,
+            });
+        }
+    }
+
+    if let Some(home) = home {
+        for (family, relative, format) in KNOWN_FILES {
+            if found.iter().any(|cred| cred.family == *family) {
+                continue;
+            }
+            let path = home.join(relative);
+            if let Some(key) = read_key_file(&path, *format) {
+                found.push(DiscoveredCredential {
+                    family,
+                    source: CredentialSource::File(path),
+                    api_key: key,
+                });
+            }
+        }
+    }
+
+    found.sort_by_key(|cred| preference_rank(cred.family));
+    found
+}
+
+fn preference_rank(family: &str) -> usize {
+    KNOWN_ENV_VARS
+        .iter()
+        .position(|(name, _)| *name == family)
+        .unwrap_or(usize::MAX)
+}
+
+fn read_key_file(path: &Path, format: FileFormat) -> Option<String> {
+    let raw = std::fs::read_to_string(path).ok()?;
+    match format {
+        FileFormat::PlainText => non_empty(Some(raw)),
+        FileFormat::JsonField(field) => {
+            let value: serde_json::Value = serde_json::from_str(&raw).ok()?;
+            non_empty(value.get(field)?.as_str().map(str::to_string))
+        }
+    }
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env_of(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect();
+        move |var| map.get(var).cloned()
+    }
+
+    #[test]
+    fn known_families_are_registered_providers() {
+        let registered: Vec<&str> = crate::list_model_providers()
+            .into_iter()
+            .map(|info| info.name)
+            .collect();
+        for (family, _) in KNOWN_ENV_VARS {
+            assert!(registered.contains(family), "{family} is not registered");
+        }
+        for (family, _, _) in KNOWN_FILES {
+            assert!(registered.contains(family), "{family} is not registered");
+        }
+    }
+
+    #[test]
+    fn known_env_vars_resolve_synonyms_and_skip_keyless_families() {
+        assert_eq!(known_env_vars("grok"), &["XAI_API_KEY", "GROK_API_KEY"]);
+        assert_eq!(known_env_vars("openrouter"), &["OPENROUTER_API_KEY"]);
+        assert!(known_env_vars("ollama").is_empty());
+    }
+
+    #[test]
+    fn native_env_bridges_are_discoverable() {
+        for (family, vars) in crate::NATIVE_API_KEY_ENV_VARS {
+            let known = KNOWN_ENV_VARS
+                .iter()
+                .find(|(name, _)| name == family)
+                .map(|(_, vars)| *vars);
+            assert_eq!(known, Some(*vars), "{family} bridge drifted from discovery");
+        }
+    }
+
+    #[test]
+    fn env_keys_are_ordered_by_preference() {
+        let found = discover_with(
+            env_of(&[
+                ("GROK_API_KEY", "xai-from-grok"),
+                ("ANTHROPIC_API_KEY", " sk-ant-api03-abcdef "),
+                ("OPENAI_API_KEY", ""),
+            ]),
+            None,
+        );
+        let families: Vec<&str> = found.iter().map(|cred| cred.family).collect();
+        assert_eq!(families, ["anthropic", "xai"]);
+        assert_eq!(found[0].api_key, "sk-ant-api03-abcdef");
+        assert_eq!(found[1].source, CredentialSource::Env("GROK_API_KEY"));
+        assert_eq!(
+            best_credential(&found).map(|cred| cred.family),
+            Some("anthropic")
+        );
+        assert_eq!(
+            credential_for(&found, "grok").map(|cred| cred.family),
+            Some("xai")
+        );
+    }
+
+    #[test]
+    fn credential_files_fill_in_behind_env() {
+        let home = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(home.path().join(".codex")).unwrap();
+        std::fs::write(
+            home.path().join(".codex/auth.json"),
+            r#"{"OPENAI_API_KEY":"sk-proj-fromfile","tokens":null}"#,
+        )
+        .unwrap();
+
+        let found = discover_with(env_of(&[]), Some(home.path()));
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].family, "openai");
+        assert_eq!(
+            found[0].source,
+            CredentialSource::File(home.path().join(".codex/auth.json"))
+        );
+
+        let found = discover_with(env_of(&[("OPENAI_API_KEY", "sk-env")]), Some(home.path()));
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].source, CredentialSource::Env("OPENAI_API_KEY"));
+    }
+
+    #[test]
+    fn debug_output_redacts_the_key() {
+        let cred = DiscoveredCredential {
+            family: "openai",
+            source: CredentialSource::Env("OPENAI_API_KEY"),
+            api_key: "sk-proj-verysecret".to_string(),
+        };
+        assert_eq!(cred.redacted_key(), "sk-p***");
+        assert!(!format!("{cred:?}").contains("verysecret"));
+        assert_eq!(cred.source.to_string(), "$OPENAI_API_KEY");
+    }
+}
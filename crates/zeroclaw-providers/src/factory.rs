@@ -142,7 +142,7 @@ fn has_non_empty_value(value: Option<&str>) -> bool {
     value.map(str::trim).is_some_and(|value| !value.is_empty())
 }
 
-/// Apply cross-cutting compat overrides (timeout, headers, api_path,
+/// Apply cross-cutting compat overrides (timeouts, proxy, headers, api_path,
 /// max_tokens, reasoning effort, TLS CA, `provider_extra`) to a compat
 /// builder before calling `.build()` and boxing the trait object. Single
 /// source of the override chain — every compat impl funnels through here.
@@ -153,6 +153,12 @@ pub fn apply_compat_options(
     if let Some(t) = opts.provider_timeout_secs {
         b = b.timeout_secs(t);
     }
+    if let Some(t) = opts.provider_connect_timeout_secs {
+        b = b.connect_timeout_secs(t);
+    }
+    if let Some(ref proxy) = opts.provider_proxy_url {
+        b = b.proxy_url(proxy);
+    }
     if let Some(ref effort) = opts.reasoning_effort {
         b = b.reasoning_effort(Some(effort.clone()));
     }
@@ -222,6 +228,12 @@ fn build_responses_provider_if_requested(
     if let Some(t) = opts.provider_timeout_secs {
         builder = builder.timeout_secs(t);
     }
+    if let Some(t) = opts.provider_connect_timeout_secs {
+        builder = builder.connect_timeout_secs(t);
+    }
+    if let Some(ref proxy) = opts.provider_proxy_url {
+        builder = builder.proxy_url(proxy);
+    }
     if let Some(mt) = opts.provider_max_tokens {
         builder = builder.max_tokens(Some(mt));
     }
@@ -414,6 +426,8 @@ pub fn get_default_url(provider_type: &str) -> Option<&'static str> {
         "novita" => <NovitaModelProviderConfig as CompatFamilySpec>::DEFAULT_URL,
         "nebius" => <NebiusModelProviderConfig as CompatFamilySpec>::DEFAULT_URL,
         "nvidia" => <NvidiaModelProviderConfig as CompatFamilySpec>::DEFAULT_URL,
+        "llamacpp" => crate::llamacpp::DEFAULT_BASE_URL,
+        "mistral" => <MistralModelProviderConfig as CompatFamilySpec>::DEFAULT_URL,
         _ => return None,
     })
 }
@@ -457,12 +471,6 @@ impl CompatFamilySpec for DoubaoModelProviderConfig {
     const AUTH: AuthStyle = AuthStyle::Bearer;
     const OPENROUTER_VENDOR_PREFIX: Option<&'static str> = Some("bytedance");
 }
-impl CompatFamilySpec for MistralModelProviderConfig {
-    const DISPLAY: &'static str = "Mistral";
-    const DEFAULT_URL: &'static str = "https://api.mistral.ai/v1";
-    const AUTH: AuthStyle = AuthStyle::Bearer;
-    const MODELS_DEV_KEY: Option<&'static str> = Some("mistral");
-}
 impl CompatFamilySpec for DeepseekModelProviderConfig {
     const DISPLAY: &'static str = "DeepSeek";
     const DEFAULT_URL: &'static str = "https://api.deepseek.com";
@@ -471,7 +479,7 @@ impl CompatFamilySpec for DeepseekModelProviderConfig {
 }
 impl CompatFamilySpec for TogetherModelProviderConfig {
     const DISPLAY: &'static str = "Together AI";
-    const DEFAULT_URL: &'static str = "https://api.together.xyz";
+    const DEFAULT_URL: &'static str = "https://api.together.xyz/v1";
     const AUTH: AuthStyle = AuthStyle::Bearer;
     const MODELS_DEV_KEY: Option<&'static str> = Some("togetherai");
 }
@@ -487,12 +495,6 @@ impl CompatFamilySpec for NovitaModelProviderConfig {
     const AUTH: AuthStyle = AuthStyle::Bearer;
     const MODELS_DEV_KEY: Option<&'static str> = Some("novita-ai");
 }
-impl CompatFamilySpec for PerplexityModelProviderConfig {
-    const DISPLAY: &'static str = "Perplexity";
-    const DEFAULT_URL: &'static str = "https://api.perplexity.ai";
-    const AUTH: AuthStyle = AuthStyle::Bearer;
-    const MODELS_DEV_KEY: Option<&'static str> = Some("perplexity");
-}
 impl CompatFamilySpec for CohereModelProviderConfig {
     const DISPLAY: &'static str = "Cohere";
     const DEFAULT_URL: &'static str = "https://api.cohere.com/compatibility";
@@ -731,6 +733,85 @@ impl CompatFamilySpec for NearaiModelProviderConfig {
     const DEFAULT_URL: &'static str = "https://cloud-api.near.ai/v1";
     const AUTH: AuthStyle = AuthStyle::Bearer;
 }
+impl CompatFamilySpec for MistralModelProviderConfig {
+    const DISPLAY: &'static str = "Mistral";
+    const DEFAULT_URL: &'static str = "https://api.mistral.ai/v1";
+    const AUTH: AuthStyle = AuthStyle::Bearer;
+    const MODELS_DEV_KEY: Option<&'static str> = Some("mistral");
+    const OPENROUTER_VENDOR_PREFIX: Option<&'static str> = Some("mistralai");
+    fn build_compat(
+        &self,
+        alias: &str,
+        key: Option<&str>,
+        api_url: Option<&str>,
+    ) -> crate::compatible::OpenAiCompatibleBuilder {
+        // Mistral documents `MISTRAL_API_KEY`; an alias entry without a key
+        // falls back to it.
+        let env_key = if has_api_key(key) {
+            None
+        } else {
+            crate::native_env_api_key("mistral").map(|(_, value)| value)
+        };
+        let mut b = self.build_compat_base(alias, env_key.as_deref().or(key), api_url);
+        if self.safe_prompt {
+            b = b.extra_body(serde_json::json!({ "safe_prompt": true }));
+        }
+        b
+    }
+}
+impl CompatFamilySpec for PerplexityModelProviderConfig {
+    const DISPLAY: &'static str = "Perplexity";
+    const DEFAULT_URL: &'static str = "https://api.perplexity.ai";
+    const AUTH: AuthStyle = AuthStyle::Bearer;
+    const MODELS_DEV_KEY: Option<&'static str> = Some("perplexity");
+    fn build_compat(
+        &self,
+        alias: &str,
+        key: Option<&str>,
+        api_url: Option<&str>,
+    ) -> crate::compatible::OpenAiCompatibleBuilder {
+        // Perplexity documents `PERPLEXITY_API_KEY`; an alias entry without a
+        // key falls back to it.
+        let env_key = if has_api_key(key) {
+            None
+        } else {
+            crate::native_env_api_key("perplexity").map(|(_, value)| value)
+        };
+        // Perplexity has no `/models` endpoint; list the documented lineup.
+        let mut b = self
+            .build_compat_base(alias, env_key.as_deref().or(key), api_url)
+            .static_models(crate::catalog::PERPLEXITY_MODELS);
+        if self.citations {
+            b = b.append_citations();
+        }
+        if let Some(options) = perplexity_search_options(self) {
+            b = b.extra_body(options);
+        }
+        b
+    }
+}
+
+/// Perplexity-only request fields from the alias entry, or `None` when none
+/// are set.
+fn perplexity_search_options(config: &PerplexityModelProviderConfig) -> Option<serde_json::Value> {
+    let mut options = serde_json::Map::new();
+    if !config.search_domain_filter.is_empty() {
+        options.insert(
+            "search_domain_filter".into(),
+            serde_json::json!(config.search_domain_filter),
+        );
+    }
+    if let Some(recency) = &config.search_recency_filter {
+        options.insert("search_recency_filter".into(), serde_json::json!(recency));
+    }
+    if let Some(size) = &config.search_context_size {
+        options.insert(
+            "web_search_options".into(),
+            serde_json::json!({ "search_context_size": size }),
+        );
+    }
+    (!options.is_empty()).then_some(serde_json::Value::Object(options))
+}
 impl CompatFamilySpec for AtomicChatModelProviderConfig {
     const DISPLAY: &'static str = "Atomic Chat";
     /// Default endpoint for the Jan / Atomic Chat local OpenAI-compatible
@@ -759,6 +840,15 @@ impl FamilyProviderFactory for XaiModelProviderConfig {
         api_url: Option<&str>,
         opts: &ModelProviderRuntimeOptions,
     ) -> Result<Box<dyn ModelProvider>> {
+        // xAI documents a native env bridge: an alias entry without a key
+        // falls back to `XAI_API_KEY` / `GROK_API_KEY` before the OAuth profile.
+        let env_key = if has_api_key(key) {
+            None
+        } else {
+            crate::native_env_api_key("xai").map(|(_, value)| value)
+        };
+        let key = env_key.as_deref().or(key);
+
         if let Some(p) = build_responses_provider_if_requested(
             self.base.wire_api,
             alias,
@@ -944,6 +1034,12 @@ impl FamilyProviderFactory for OpenRouterModelProviderConfig {
         if let Some(t) = opts.provider_timeout_secs {
             b = b.timeout_secs(t);
         }
+        if let Some(t) = opts.provider_connect_timeout_secs {
+            b = b.connect_timeout_secs(t);
+        }
+        if let Some(ref proxy) = opts.provider_proxy_url {
+            b = b.proxy_url(proxy);
+        }
         if let Some(extra) = opts.provider_extra.clone() {
             b = b.extra_body(extra);
         }
@@ -969,6 +1065,17 @@ impl FamilyProviderFactory for AnthropicModelProviderConfig {
         if let Some(ts) = opts.provider_timeout_secs {
             b = b.timeout_secs(ts);
         }
+        if let Some(t) = opts.provider_connect_timeout_secs {
+            b = b.connect_timeout_secs(t);
+        }
+        if let Some(ref proxy) = opts.provider_proxy_url {
+            b = b.proxy_url(proxy);
+        }
+        if let Some(budget) = opts.thinking_budget_tokens
+            && opts.reasoning_enabled != Some(false)
+        {
+            b = b.thinking_budget_tokens(budget);
+        }
         Ok(Box::new(b.build()))
     }
 }
@@ -1026,6 +1133,12 @@ impl FamilyProviderFactory for OpenAIModelProviderConfig {
         if let Some(t) = opts.provider_timeout_secs {
             b = b.timeout_secs(t);
         }
+        if let Some(t) = opts.provider_connect_timeout_secs {
+            b = b.connect_timeout_secs(t);
+        }
+        if let Some(ref proxy) = opts.provider_proxy_url {
+            b = b.proxy_url(proxy);
+        }
         if let Some(mt) = opts.provider_max_tokens {
             b = b.max_tokens(Some(mt));
         }
@@ -1441,7 +1554,7 @@ impl FamilyProviderFactory for LlamacppModelProviderConfig {
         api_url: Option<&str>,
         opts: &ModelProviderRuntimeOptions,
     ) -> Result<Box<dyn ModelProvider>> {
-        let base_url = api_url.unwrap_or("http://localhost:8080/v1");
+        let base_url = api_url.unwrap_or(crate::llamacpp::DEFAULT_BASE_URL);
         let llama_cpp_key = key
             .map(str::trim)
             .filter(|value| !value.is_empty())
@@ -1460,6 +1573,10 @@ impl FamilyProviderFactory for LlamacppModelProviderConfig {
             .base_url(base_url)
             .credential(Some(llama_cpp_key))
             .auth_style(AuthStyle::Bearer)
+            .health_url(&format!(
+                "{}/health",
+                crate::llamacpp::server_root(base_url)
+            ))
             .vision(true)
             .local_model_tool_sanitize();
         if opts.merge_system_into_user {
@@ -1620,6 +1737,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn together_and_fireworks_default_urls_match_schema_endpoints() {
+        use zeroclaw_config::schema::{FireworksEndpoint, ModelEndpoint, TogetherEndpoint};
+        assert_eq!(
+            <TogetherModelProviderConfig as CompatFamilySpec>::DEFAULT_URL,
+            TogetherEndpoint::default().uri(),
+        );
+        assert_eq!(
+            <FireworksModelProviderConfig as CompatFamilySpec>::DEFAULT_URL,
+            FireworksEndpoint::default().uri(),
+        );
+    }
+
+    #[test]
+    fn mistral_spec_matches_catalog_table_and_schema_endpoint() {
+        use zeroclaw_config::schema::{MistralEndpoint, ModelEndpoint};
+        assert_eq!(
+            crate::catalog::catalog_source_for("mistral"),
+            Some((
+                <MistralModelProviderConfig as CompatFamilySpec>::MODELS_DEV_KEY,
+                <MistralModelProviderConfig as CompatFamilySpec>::OPENROUTER_VENDOR_PREFIX,
+            ))
+        );
+        assert_eq!(
+            get_default_url("mistral"),
+            Some(MistralEndpoint::default().uri())
+        );
+    }
+
+    #[test]
+    fn perplexity_alias_options_map_to_request_fields_and_streaming() {
+        let defaults = PerplexityModelProviderConfig::default();
+        assert!(defaults.citations);
+        assert_eq!(perplexity_search_options(&defaults), None);
+
+        let cfg = PerplexityModelProviderConfig {
+            citations: false,
+            search_domain_filter: vec!["docs.rs".into(), "-pinterest.com".into()],
+            search_recency_filter: Some("week".into()),
+            search_context_size: Some("high".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            perplexity_search_options(&cfg),
+            Some(serde_json::json!({
+                "search_domain_filter": ["docs.rs", "-pinterest.com"],
+                "search_recency_filter": "week",
+                "web_search_options": { "search_context_size": "high" },
+            }))
+        );
+
+        let opts = ModelProviderRuntimeOptions::default();
+        let streaming = cfg
+            .create_provider("pplx", Some("key"), None, &opts)
+            .unwrap();
+        assert!(streaming.supports_streaming());
+        let cited = defaults
+            .create_provider("pplx", Some("key"), None, &opts)
+            .unwrap();
+        assert!(!cited.supports_streaming());
+    }
+
     #[test]
     fn openai_factory_routes_to_codex_when_requires_openai_auth_true() {
         let cfg = OpenAIModelProviderConfig {
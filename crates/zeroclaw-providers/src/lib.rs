@@ -5,15 +5,19 @@ pub mod anthropic;
 pub mod auth;
 pub mod azure_openai;
 pub mod bedrock;
+pub mod capabilities;
 pub mod catalog;
 pub mod compatible;
 pub mod copilot;
+pub mod discovery;
 pub mod dispatch;
 pub mod factory;
 pub mod gemini;
 pub mod gemini_cli;
 // glm.rs excluded — not compiled in upstream (dead code with known issues)
 pub mod kilocli;
+pub mod llamacpp;
+pub mod middleware;
 pub mod model_pin;
 pub mod models_dev;
 pub mod multimodal;
@@ -27,6 +31,7 @@ pub mod reliable;
 pub mod router;
 pub(crate) mod stream_guard;
 pub mod telnyx;
+pub mod tokens;
 pub mod traits;
 pub mod vision_override;
 
@@ -577,10 +582,20 @@ pub struct ModelProviderRuntimeOptions {
     pub zeroclaw_dir: Option<PathBuf>,
     pub secrets_encrypt: bool,
     pub reasoning_enabled: Option<bool>,
+    /// Reasoning effort level. The entry's `[reasoning] reasoning_effort`
+    /// wins over `runtime.reasoning_effort`.
     pub reasoning_effort: Option<String>,
+    /// Default extended-thinking budget from the entry's `[reasoning]`
+    /// table, used when a request carries no thinking parameters.
+    pub thinking_budget_tokens: Option<u32>,
     /// HTTP request timeout in seconds for LLM model_provider API calls.
     /// `None` uses the model_provider's built-in default (120s for compatible model_providers).
     pub provider_timeout_secs: Option<u64>,
+    /// TCP/TLS connect timeout in seconds. `None` keeps the 10s default.
+    pub provider_connect_timeout_secs: Option<u64>,
+    /// Per-provider proxy URL. `None` falls back to the global `[proxy]`
+    /// section, then to `HTTPS_PROXY` / `NO_PROXY` from the environment.
+    pub provider_proxy_url: Option<String>,
     /// Extra HTTP headers to include in model_provider API requests.
     pub extra_headers: std::collections::HashMap<String, String>,
     /// Custom API path suffix for OpenAI-compatible model_providers
@@ -630,7 +645,10 @@ impl Default for ModelProviderRuntimeOptions {
             secrets_encrypt: true,
             reasoning_enabled: None,
             reasoning_effort: None,
+            thinking_budget_tokens: None,
             provider_timeout_secs: None,
+            provider_connect_timeout_secs: None,
+            provider_proxy_url: None,
             extra_headers: std::collections::HashMap::new(),
             api_path: None,
             provider_max_tokens: None,
@@ -678,6 +696,7 @@ pub fn model_provider_runtime_options_from_model_provider_entry(
         .unwrap_or(false);
 
     let tls_ca_cert_path = entry.and_then(|e| e.tls_ca_cert_path.clone());
+    let reasoning = entry.and_then(|e| e.reasoning.as_ref());
 
     ModelProviderRuntimeOptions {
         auth_profile_override: None,
@@ -692,8 +711,13 @@ pub fn model_provider_runtime_options_from_model_provider_entry(
         zeroclaw_dir: config.config_path.parent().map(PathBuf::from),
         secrets_encrypt: config.secrets.encrypt,
         reasoning_enabled: config.runtime.reasoning_enabled,
-        reasoning_effort: config.runtime.reasoning_effort.clone(),
+        reasoning_effort: reasoning
+            .and_then(|r| r.reasoning_effort.clone())
+            .or_else(|| config.runtime.reasoning_effort.clone()),
+        thinking_budget_tokens: reasoning.and_then(|r| r.thinking_budget_tokens),
         provider_timeout_secs: Some(entry.and_then(|e| e.timeout_secs).unwrap_or(120)),
+        provider_connect_timeout_secs: entry.and_then(|e| e.connect_timeout_secs),
+        provider_proxy_url: entry.and_then(|e| e.proxy_url.clone()),
         extra_headers: entry.map(|e| e.extra_headers.clone()).unwrap_or_default(),
         api_path: None,
         provider_max_tokens: entry.and_then(|e| e.max_tokens),
@@ -892,6 +916,40 @@ fn resolve_model_provider_credential(
         .map(ToString::to_string)
 }
 
+/// Families that document a native API-key env bridge, with the env vars
+/// they fall back to (in precedence order) when the alias entry has no key.
+/// Every other family takes credentials only from config or the schema-mirror
+/// `ZEROCLAW_*` overrides.
+pub const NATIVE_API_KEY_ENV_VARS: &[(&str, &[&str])] = &[
+    ("xai", &["XAI_API_KEY", "GROK_API_KEY"]),
+    ("mistral", &["MISTRAL_API_KEY"]),
+    ("perplexity", &["PERPLEXITY_API_KEY"]),
+];
+
+/// Native API-key env vars for `family` (synonyms accepted), or `&[]` when
+/// the family has no documented env bridge.
+#[must_use]
+pub fn native_api_key_env_vars(family: &str) -> &'static [&'static str] {
+    let family = canonicalize_v2_model_provider_name(family);
+    NATIVE_API_KEY_ENV_VARS
+        .iter()
+        .find(|(name, _)| *name == family)
+        .map_or(&[], |(_, vars)| *vars)
+}
+
+/// First non-empty native env var for `family`, paired with the variable it
+/// was read from so callers can explain where a key came from.
+#[must_use]
+pub fn native_env_api_key(family: &str) -> Option<(&'static str, String)> {
+    native_api_key_env_vars(family).iter().find_map(|var| {
+        std::env::var(var)
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(|value| (*var, value))
+    })
+}
+
 /// Single source of truth for `(key_prefix, canonical_model_provider_family)`
 /// pairs used by `check_api_key_prefix`. Order matters: longer prefixes
 /// must come before shorter ones that share a head (`sk-ant-` and `sk-or-`
@@ -998,10 +1056,12 @@ pub fn canonicalize_v2_model_provider_name(name: &str) -> &str {
     match name {
         // Vendor-canonical synonyms.
         "azure_openai" | "azure-openai" => "azure",
-        "grok" => "xai",
+        "grok" | "x-ai" => "xai",
         "google" | "google-gemini" => "gemini",
-        "together-ai" => "together",
-        "fireworks-ai" => "fireworks",
+        "together-ai" | "togetherai" => "together",
+        "fireworks-ai" | "fireworksai" => "fireworks",
+        "mistralai" | "mistral-ai" => "mistral",
+        "pplx" => "perplexity",
         "vercel-ai" => "vercel",
         "cloudflare-ai" => "cloudflare",
         "nvidia-nim" | "build.nvidia.com" => "nvidia",
@@ -1015,7 +1075,7 @@ pub fn canonicalize_v2_model_provider_name(name: &str) -> &str {
         "github-copilot" => "copilot",
         "ovhcloud" => "ovh",
         "opencode-zen" => "opencode",
-        "llama.cpp" => "llamacpp",
+        "llama.cpp" | "llama-server" => "llamacpp",
         "deep-myst" => "deepmyst",
         "silicon-flow" => "siliconflow",
         "deep-infra" => "deepinfra",
@@ -1228,15 +1288,17 @@ pub fn create_resilient_model_provider_with_options(
     let primary_model_provider =
         create_model_provider_inner(None, primary_name, "default", api_key, api_url, options)?;
 
-    let reliable = ReliableModelProvider::new(
+    let entry = ReliableModelProviderEntry::new(primary_name, primary_name, primary_model_provider)
+        .via_proxy(has_proxy_url(options.provider_proxy_url.as_deref()));
+    let reliable = ReliableModelProvider::new_with_entries(
         primary_name,
-        vec![(primary_name.to_string(), primary_model_provider)],
+        vec![entry],
         reliability.provider_retries,
         reliability.provider_backoff_ms,
     )
     .with_api_keys(reliability.api_keys.clone());
 
-    Ok(Box::new(reliable))
+    Ok(middleware::apply_provider_middleware(Box::new(reliable)))
 }
 
 /// Wrap the primary model_provider in a retry/backoff harness with full
@@ -1306,7 +1368,11 @@ fn create_resilient_model_provider_for_alias_with_model_override(
     )
     .with_api_keys(reliability.api_keys.clone());
 
-    Ok(Box::new(reliable))
+    Ok(middleware::apply_provider_middleware(Box::new(reliable)))
+}
+
+fn has_proxy_url(proxy_url: Option<&str>) -> bool {
+    proxy_url.is_some_and(|url| !url.trim().is_empty())
 }
 
 fn push_pinned_entries(
@@ -1326,31 +1392,38 @@ fn push_pinned_entries(
         .or_else(|| entry.and_then(|e| e.model.as_deref()));
     let extra_models: &[String] = entry.map(|e| e.fallback_models.as_slice()).unwrap_or(&[]);
     let cooldown_key = format!("{family}.{alias}");
+    let via_proxy = has_proxy_url(entry.and_then(|e| e.proxy_url.as_deref()));
 
     let Some(primary_model) = primary_model else {
-        out.push(ReliableModelProviderEntry::new(family, cooldown_key, built));
+        out.push(ReliableModelProviderEntry::new(family, cooldown_key, built).via_proxy(via_proxy));
         return;
     };
 
     let built: std::sync::Arc<dyn ModelProvider> = std::sync::Arc::from(built);
-    out.push(ReliableModelProviderEntry::new_pinned(
-        family,
-        cooldown_key.clone(),
-        alias,
-        primary_model,
-        Box::new(std::sync::Arc::clone(&built)),
-    ));
-    for model in extra_models {
-        if model.trim().is_empty() || model == primary_model {
-            continue;
-        }
-        out.push(ReliableModelProviderEntry::new_pinned(
+    out.push(
+        ReliableModelProviderEntry::new_pinned(
             family,
             cooldown_key.clone(),
             alias,
-            model,
+            primary_model,
             Box::new(std::sync::Arc::clone(&built)),
-        ));
+        )
+        .via_proxy(via_proxy),
+    );
+    for model in extra_models {
+        if model.trim().is_empty() || model == primary_model {
+            continue;
+        }
+        out.push(
+            ReliableModelProviderEntry::new_pinned(
+                family,
+                cooldown_key.clone(),
+                alias,
+                model,
+                Box::new(std::sync::Arc::clone(&built)),
+            )
+            .via_proxy(via_proxy),
+        );
     }
 }
 
@@ -2738,6 +2811,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn provider_runtime_options_from_config_applies_reasoning_table() {
+        use zeroclaw_config::schema::{
+            AnthropicModelProviderConfig, ModelProviderConfig, ModelProviderReasoningConfig,
+        };
+        let mut config = zeroclaw_config::schema::Config::default();
+        config.runtime.reasoning_effort = Some("low".to_string());
+        config.providers.models.anthropic.insert(
+            "thinker".to_string(),
+            AnthropicModelProviderConfig {
+                base: ModelProviderConfig {
+                    reasoning: Some(ModelProviderReasoningConfig {
+                        reasoning_effort: Some("high".to_string()),
+                        thinking_budget_tokens: Some(16_000),
+                    }),
+                    ..Default::default()
+                },
+            },
+        );
+        config
+            .providers
+            .models
+            .anthropic
+            .insert("plain".to_string(), AnthropicModelProviderConfig::default());
+
+        let options = provider_runtime_options_for_alias(&config, "anthropic", "thinker");
+        assert_eq!(options.reasoning_effort.as_deref(), Some("high"));
+        assert_eq!(options.thinking_budget_tokens, Some(16_000));
+
+        let options = provider_runtime_options_for_alias(&config, "anthropic", "plain");
+        assert_eq!(options.reasoning_effort.as_deref(), Some("low"));
+        assert_eq!(options.thinking_budget_tokens, None);
+    }
+
     #[test]
     fn provider_runtime_options_from_config_propagates_provider_kind() {
         use zeroclaw_config::schema::{ModelProviderConfig, OpenAIModelProviderConfig};
@@ -2946,6 +3053,69 @@ mod tests {
         assert!(create_model_provider("xai", Some("key")).is_ok());
     }
 
+    #[test]
+    fn factory_xai_accepts_synonyms() {
+        assert_eq!(canonicalize_v2_model_provider_name("grok"), "xai");
+        assert_eq!(canonicalize_v2_model_provider_name("x-ai"), "xai");
+        assert!(create_model_provider("x-ai", Some("key")).is_ok());
+    }
+
+    #[test]
+    fn native_env_api_key_prefers_xai_over_grok() {
+        let _env_lock = env_lock();
+        let _xai = EnvGuard::set("XAI_API_KEY", Some("  xai-primary  "));
+        let _grok = EnvGuard::set("GROK_API_KEY", Some("xai-secondary"));
+        assert_eq!(
+            native_env_api_key("grok"),
+            Some(("XAI_API_KEY", "xai-primary".to_string()))
+        );
+
+        let _xai = EnvGuard::set("XAI_API_KEY", Some(""));
+        assert_eq!(
+            native_env_api_key("xai"),
+            Some(("GROK_API_KEY", "xai-secondary".to_string()))
+        );
+    }
+
+    #[test]
+    fn native_env_api_key_ignores_families_without_a_bridge() {
+        let _env_lock = env_lock();
+        let _guard = EnvGuard::set("XAI_API_KEY", Some("xai-key"));
+        assert!(native_api_key_env_vars("openai").is_empty());
+        assert_eq!(native_env_api_key("openai"), None);
+    }
+
+    #[test]
+    fn factory_mistral_accepts_synonyms_and_env_key() {
+        let _env_lock = env_lock();
+        assert_eq!(canonicalize_v2_model_provider_name("mistralai"), "mistral");
+        assert_eq!(canonicalize_v2_model_provider_name("mistral-ai"), "mistral");
+        assert!(create_model_provider("mistralai", Some("key")).is_ok());
+
+        let _guard = EnvGuard::set("MISTRAL_API_KEY", Some(" mistral-key "));
+        assert_eq!(native_api_key_env_vars("mistralai"), &["MISTRAL_API_KEY"]);
+        assert_eq!(
+            native_env_api_key("mistral"),
+            Some(("MISTRAL_API_KEY", "mistral-key".to_string()))
+        );
+        assert!(create_model_provider("mistral", None).is_ok());
+    }
+
+    #[test]
+    fn factory_perplexity_accepts_pplx_and_env_key() {
+        let _env_lock = env_lock();
+        assert_eq!(canonicalize_v2_model_provider_name("pplx"), "perplexity");
+        assert!(create_model_provider("pplx", Some("key")).is_ok());
+
+        let _guard = EnvGuard::set("PERPLEXITY_API_KEY", Some("pplx-key"));
+        assert_eq!(native_api_key_env_vars("pplx"), &["PERPLEXITY_API_KEY"]);
+        assert_eq!(
+            native_env_api_key("perplexity"),
+            Some(("PERPLEXITY_API_KEY", "pplx-key".to_string()))
+        );
+        assert!(create_model_provider("perplexity", None).is_ok());
+    }
+
     #[test]
     fn factory_deepseek() {
         assert!(create_model_provider("deepseek", Some("key")).is_ok());
@@ -2968,6 +3138,28 @@ mod tests {
         assert!(create_model_provider("fireworks", Some("key")).is_ok());
     }
 
+    #[test]
+    fn factory_together_and_fireworks_accept_synonyms() {
+        assert_eq!(
+            canonicalize_v2_model_provider_name("together-ai"),
+            "together"
+        );
+        assert_eq!(
+            canonicalize_v2_model_provider_name("togetherai"),
+            "together"
+        );
+        assert_eq!(
+            canonicalize_v2_model_provider_name("fireworks-ai"),
+            "fireworks"
+        );
+        assert_eq!(
+            canonicalize_v2_model_provider_name("fireworksai"),
+            "fireworks"
+        );
+        assert!(create_model_provider("togetherai", Some("key")).is_ok());
+        assert!(create_model_provider("fireworks-ai", Some("key")).is_ok());
+    }
+
     #[test]
     fn factory_novita() {
         assert!(create_model_provider("novita", Some("key")).is_ok());
@@ -4578,6 +4770,7 @@ pub async fn fetch_context_window(
         "openrouter" => fetch_openrouter_context_window(config).await,
         "together" | "groq" | "fireworks" | "deepinfra" | "hyperbolic" | "anyscale" | "novita"
         | "nebius" => fetch_openai_compatible_context_window(provider_type, config).await,
+        "llamacpp" => llamacpp::fetch_context_window(config).await,
         _ => None, // anthropic, openai, ollama, bedrock, etc. don't expose it
     }
 }
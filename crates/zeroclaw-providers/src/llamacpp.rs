@@ -0,0 +1,175 @@
+//! llama.cpp `llama-server` metadata: the loaded model and its real context
+//! window (`/props`) and readiness (`/health`). Chat traffic goes through the
+//! OpenAI-compatible provider built in `factory.rs`; this module covers only
+//! the server-specific endpoints that live outside the `/v1` prefix.
+
+use anyhow::{Context, Result};
+use std::time::Duration;
+use zeroclaw_config::schema::ModelProviderConfig;
+
+/// Default `llama-server` OpenAI-compatible base URL.
+pub const DEFAULT_BASE_URL: &str = "http://127.0.0.1:8080/v1";
+
+/// Metadata probes run at startup and from `doctor`; a dead server should
+/// not stall either for long.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What `/props` reports about the loaded model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LlamaServerProps {
+    /// Context window per slot (`n_ctx`), in tokens.
+    pub n_ctx: usize,
+    /// Loaded model: `model_alias` when the server sets one, else the GGUF
+    /// file name from `model_path`.
+    pub model: Option<String>,
+}
+
+/// Server root for a configured base URL. `/props` and `/health` are served
+/// beside the OpenAI API, so a trailing `/v1` is stripped.
+#[must_use]
+pub fn server_root(base_url: &str) -> &str {
+    let trimmed = base_url.trim_end_matches('/');
+    trimmed.strip_suffix("/v1").unwrap_or(trimmed)
+}
+
+/// Base URL for a configured provider entry, falling back to
+/// [`DEFAULT_BASE_URL`].
+#[must_use]
+pub fn base_url_for(config: &ModelProviderConfig) -> &str {
+    config
+        .uri
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty() && *s != "<unset>")
+        .unwrap_or(DEFAULT_BASE_URL)
+}
+
+/// Parse a `/props` body. Current servers nest `n_ctx` under
+/// `default_generation_settings`; older builds report it at the top level.
+#[must_use]
+pub fn parse_props(body: &serde_json::Value) -> Option<LlamaServerProps> {
+    let n_ctx = body
+        .pointer("/default_generation_settings/n_ctx")
+        .or_else(|| body.get("n_ctx"))
+        .and_then(serde_json::Value::as_u64)
+        .and_then(|n| usize::try_from(n).ok())
+        .filter(|n| *n > 0)?;
+    let model = body
+        .get("model_alias")
+        .and_then(serde_json::Value::as_str)
+        .filter(|alias| !alias.trim().is_empty())
+        .map(str::to_string)
+        .or_else(|| {
+            body.get("model_path")
+                .and_then(serde_json::Value::as_str)
+                .and_then(|path| path.rsplit(['/', '\\']).next())
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+        });
+    Some(LlamaServerProps { n_ctx, model })
+}
+
+fn probe_request(url: &str, api_key: Option<&str>) -> Result<reqwest::RequestBuilder> {
+    let client = reqwest::Client::builder()
+        .timeout(PROBE_TIMEOUT)
+        .build()
+        .context("failed to build HTTP client")?;
+    let mut request = client.get(url);
+    if let Some(key) = api_key.map(str::trim).filter(|key| !key.is_empty()) {
+        request = request.bearer_auth(key);
+    }
+    Ok(request)
+}
+
+/// Query `/props` for the loaded model and its context window.
+pub async fn fetch_props(base_url: &str, api_key: Option<&str>) -> Result<LlamaServerProps> {
+    let url = format!("{}/props", server_root(base_url));
+    let response = probe_request(&url, api_key)?
+        .send()
+        .await
+        .with_context(|| format!("llama-server unreachable at {url}"))?;
+    let status = response.status();
+    if !status.is_success() {
+        anyhow::bail!("GET {url} returned {status}");
+    }
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .with_context(|| format!("invalid JSON from {url}"))?;
+    parse_props(&body).with_context(|| format!("{url} did not report n_ctx"))
+}
+
+/// Check `/health`: 200 once the model is loaded, 503 while it is loading.
+pub async fn check_health(base_url: &str, api_key: Option<&str>) -> Result<()> {
+    let url = format!("{}/health", server_root(base_url));
+    let response = probe_request(&url, api_key)?
+        .send()
+        .await
+        .with_context(|| format!("llama-server unreachable at {url}"))?;
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    let body = response.text().await.unwrap_or_default();
+    let message = serde_json::from_str::<serde_json::Value>(&body)
+        .ok()
+        .and_then(|v| {
+            v.pointer("/error/message")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string)
+        })
+        .unwrap_or(body);
+    anyhow::bail!("llama-server not ready ({status}): {}", message.trim())
+}
+
+/// Context window for a configured `llamacpp` entry, from `/props`. `None`
+/// when the server is unreachable or does not report `n_ctx`.
+pub async fn fetch_context_window(config: &ModelProviderConfig) -> Option<usize> {
+    fetch_props(base_url_for(config), config.api_key.as_deref())
+        .await
+        .ok()
+        .map(|props| props.n_ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn server_root_strips_openai_prefix() {
+        assert_eq!(
+            server_root("http://127.0.0.1:8080/v1"),
+            "http://127.0.0.1:8080"
+        );
+        assert_eq!(server_root("http://gpu:9000/v1/"), "http://gpu:9000");
+        assert_eq!(server_root("http://gpu:9000"), "http://gpu:9000");
+    }
+
+    #[test]
+    fn parse_props_reads_nested_and_legacy_n_ctx() {
+        let current = json!({
+            "default_generation_settings": {"n_ctx": 8192},
+            "model_path": "/models/qwen2.5-7b-instruct-q4_k_m.gguf",
+            "total_slots": 1
+        });
+        assert_eq!(
+            parse_props(&current),
+            Some(LlamaServerProps {
+                n_ctx: 8192,
+                model: Some("qwen2.5-7b-instruct-q4_k_m.gguf".into()),
+            })
+        );
+
+        let legacy = json!({"n_ctx": 4096, "model_alias": "local-llama"});
+        assert_eq!(
+            parse_props(&legacy),
+            Some(LlamaServerProps {
+                n_ctx: 4096,
+                model: Some("local-llama".into()),
+            })
+        );
+
+        assert_eq!(parse_props(&json!({"model_path": "m.gguf"})), None);
+    }
+}
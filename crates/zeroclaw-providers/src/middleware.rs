@@ -0,0 +1,620 @@
+//! Provider-level request/response middleware.
+//!
+//! Middleware registered with [`register_provider_middleware`] is applied by
+//! the resilient provider factories, so every chat call made through a
+//! retry/fallback wrapper passes through it: `before_request` rewrites the
+//! outgoing conversation once, before retries and fallbacks, and
+//! `after_response` rewrites the final reply text. Each step is bounded by
+//! [`MIDDLEWARE_TIMEOUT`] and isolated with `catch_unwind`; a middleware that
+//! panics or hangs fails the call closed rather than letting unscrubbed data
+//! through or wedging the dispatch loop.
+
+use super::ModelProvider;
+use super::dispatch::ProviderDispatch;
+use super::traits::{
+    ChatMessage, ChatRequest, ChatResponse, StreamChunk, StreamError, StreamEvent, StreamOptions,
+    StreamResult,
+};
+use async_trait::async_trait;
+use futures_util::FutureExt as _;
+use futures_util::stream::{self, BoxStream, StreamExt as _};
+use parking_lot::RwLock;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+use zeroclaw_api::hook::HookResult;
+use zeroclaw_api::model_provider::ModelInfo;
+pub use zeroclaw_api::provider_middleware::{MiddlewareRejection, ProviderMiddleware};
+
+/// Upper bound on a single middleware step.
+pub const MIDDLEWARE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Middleware in effect for this process, sorted by descending priority.
+static REGISTRY: LazyLock<RwLock<Vec<Arc<dyn ProviderMiddleware>>>> =
+    LazyLock::new(|| RwLock::new(Vec::new()));
+
+/// Register a middleware for every resilient provider built after this call.
+/// Middleware with equal priority keeps registration order.
+pub fn register_provider_middleware(middleware: Arc<dyn ProviderMiddleware>) {
+    let mut registry = REGISTRY.write();
+    registry.push(middleware);
+    registry.sort_by_key(|m| std::cmp::Reverse(m.priority()));
+}
+
+/// Snapshot of the registered middleware, in application order.
+#[must_use]
+pub fn registered_provider_middleware() -> Vec<Arc<dyn ProviderMiddleware>> {
+    REGISTRY.read().clone()
+}
+
+/// The policy notice to show the sender when `err` came from a middleware
+/// aborting the call.
+#[must_use]
+pub fn policy_rejection_message(err: &anyhow::Error) -> Option<String> {
+    err.chain()
+        .find_map(|cause| cause.downcast_ref::<MiddlewareRejection>())
+        .map(|rejection| format!("🚫 {}", rejection.reason))
+}
+
+/// Wrap `provider` in the registered middleware, or return it unchanged when
+/// none is registered.
+pub(crate) fn apply_provider_middleware(
+    provider: Box<dyn ModelProvider>,
+) -> Box<dyn ModelProvider> {
+    let middleware = registered_provider_middleware();
+    if middleware.is_empty() {
+        provider
+    } else {
+        Box::new(MiddlewareProvider::new(provider, middleware))
+    }
+}
+
+/// Decorator that runs a middleware chain around the wrapped provider's chat
+/// calls. Streaming is reported as unsupported so callers fall back to the
+/// buffered path and every reply passes through `after_response`.
+pub struct MiddlewareProvider {
+    middleware: Vec<Arc<dyn ProviderMiddleware>>,
+    timeout: Duration,
+    inner: Box<dyn ModelProvider>,
+}
+
+impl MiddlewareProvider {
+    pub fn new(
+        inner: Box<dyn ModelProvider>,
+        middleware: Vec<Arc<dyn ProviderMiddleware>>,
+    ) -> Self {
+        Self {
+            middleware,
+            timeout: MIDDLEWARE_TIMEOUT,
+            inner,
+        }
+    }
+
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    async fn run_step<T, F>(
+        &self,
+        middleware: &Arc<dyn ProviderMiddleware>,
+        stage: &str,
+        step: F,
+    ) -> anyhow::Result<T>
+    where
+        F: Future<Output = HookResult<T>>,
+    {
+        let name = middleware.name().to_string();
+        let outcome =
+            tokio::time::timeout(self.timeout, AssertUnwindSafe(step).catch_unwind()).await;
+        let failure = match outcome {
+            Ok(Ok(HookResult::Continue(value))) => return Ok(value),
+            Ok(Ok(HookResult::Cancel(reason))) => {
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_attrs(::serde_json::json!({
+                            "middleware": name,
+                            "stage": stage,
+                            "reason": reason,
+                        })),
+                    "provider middleware rejected the request"
+                );
+                return Err(MiddlewareRejection {
+                    middleware: name,
+                    reason,
+                }
+                .into());
+            }
+            Ok(Err(_)) => "panicked",
+            Err(_) => "timed out",
+        };
+        ::zeroclaw_log::record!(
+            ERROR,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                .with_attrs(::serde_json::json!({
+                    "middleware": name,
+                    "stage": stage,
+                    "failure": failure,
+                })),
+            "provider middleware failed; aborting the request"
+        );
+        Err(MiddlewareRejection {
+            middleware: name,
+            reason: "The request could not be checked against policy. Please try again.".into(),
+        }
+        .into())
+    }
+
+    async fn before_request(
+        &self,
+        mut messages: Vec<ChatMessage>,
+    ) -> anyhow::Result<Vec<ChatMessage>> {
+        for middleware in &self.middleware {
+            messages = self
+                .run_step(
+                    middleware,
+                    "before_request",
+                    middleware.before_request(messages),
+                )
+                .await?;
+        }
+        Ok(messages)
+    }
+
+    async fn after_response(&self, mut text: String) -> anyhow::Result<String> {
+        for middleware in &self.middleware {
+            text = self
+                .run_step(
+                    middleware,
+                    "after_response",
+                    middleware.after_response(text),
+                )
+                .await?;
+        }
+        Ok(text)
+    }
+
+    async fn after_chat_response(
+        &self,
+        mut response: ChatResponse,
+    ) -> anyhow::Result<ChatResponse> {
+        if let Some(text) = response.text.take() {
+            response.text = Some(self.after_response(text).await?);
+        }
+        Ok(response)
+    }
+}
+
+fn streaming_disabled<T: Send + 'static>() -> BoxStream<'static, StreamResult<T>> {
+    stream::once(async {
+        Err(StreamError::ModelProvider(
+            "streaming is disabled while provider middleware is registered".into(),
+        ))
+    })
+    .boxed()
+}
+
+#[async_trait]
+impl ModelProvider for MiddlewareProvider {
+    fn capabilities(&self) -> super::traits::ProviderCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn capabilities_for_model(&self, model: &str) -> super::traits::ProviderCapabilities {
+        self.inner.capabilities_for_model(model)
+    }
+
+    fn default_temperature(&self) -> f64 {
+        self.inner.default_temperature()
+    }
+
+    fn default_max_tokens(&self) -> u32 {
+        self.inner.default_max_tokens()
+    }
+
+    fn default_timeout_secs(&self) -> u64 {
+        self.inner.default_timeout_secs()
+    }
+
+    fn default_base_url(&self) -> Option<&str> {
+        self.inner.default_base_url()
+    }
+
+    fn default_wire_api(&self) -> &str {
+        self.inner.default_wire_api()
+    }
+
+    fn convert_tools(&self, tools: &[zeroclaw_api::tool::ToolSpec]) -> super::traits::ToolsPayload {
+        self.inner.convert_tools(tools)
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        self.inner.supports_native_tools()
+    }
+
+    fn supports_vision(&self) -> bool {
+        self.inner.supports_vision()
+    }
+
+    fn supports_streaming(&self) -> bool {
+        false
+    }
+
+    fn supports_streaming_tool_events(&self) -> bool {
+        false
+    }
+
+    async fn list_models(&self) -> anyhow::Result<Vec<String>> {
+        ProviderDispatch::from_ref(&*self.inner).list_models().await
+    }
+
+    async fn list_models_with_pricing(&self) -> anyhow::Result<Vec<ModelInfo>> {
+        ProviderDispatch::from_ref(&*self.inner)
+            .list_models_with_pricing()
+            .await
+    }
+
+    async fn warmup(&self) -> anyhow::Result<()> {
+        ProviderDispatch::from_ref(&*self.inner).warmup().await
+    }
+
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: Option<f64>,
+    ) -> anyhow::Result<String> {
+        let mut messages = Vec::with_capacity(2);
+        if let Some(system_prompt) = system_prompt {
+            messages.push(ChatMessage::system(system_prompt));
+        }
+        messages.push(ChatMessage::user(message));
+        let messages = self.before_request(messages).await?;
+        let text = ProviderDispatch::from_ref(&*self.inner)
+            .chat_with_history(&messages, model, temperature)
+            .await?;
+        self.after_response(text).await
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: Option<f64>,
+    ) -> anyhow::Result<String> {
+        let messages = self.before_request(messages.to_vec()).await?;
+        let text = ProviderDispatch::from_ref(&*self.inner)
+            .chat_with_history(&messages, model, temperature)
+            .await?;
+        self.after_response(text).await
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: Option<f64>,
+    ) -> anyhow::Result<ChatResponse> {
+        let messages = self.before_request(request.messages.to_vec()).await?;
+        let request = ChatRequest {
+            messages: &messages,
+            ..request
+        };
+        let response = ProviderDispatch::from_ref(&*self.inner)
+            .chat(request, model, temperature)
+            .await?;
+        self.after_chat_response(response).await
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: Option<f64>,
+    ) -> anyhow::Result<ChatResponse> {
+        let messages = self.before_request(messages.to_vec()).await?;
+        let response = ProviderDispatch::from_ref(&*self.inner)
+            .chat_with_tools(&messages, tools, model, temperature)
+            .await?;
+        self.after_chat_response(response).await
+    }
+
+    fn stream_chat_with_system(
+        &self,
+        _system_prompt: Option<&str>,
+        _message: &str,
+        _model: &str,
+        _temperature: Option<f64>,
+        _options: StreamOptions,
+    ) -> BoxStream<'static, StreamResult<StreamChunk>> {
+        streaming_disabled()
+    }
+
+    fn stream_chat_with_history(
+        &self,
+        _messages: &[ChatMessage],
+        _model: &str,
+        _temperature: Option<f64>,
+        _options: StreamOptions,
+    ) -> BoxStream<'static, StreamResult<StreamChunk>> {
+        streaming_disabled()
+    }
+
+    fn stream_chat(
+        &self,
+        _request: ChatRequest<'_>,
+        _model: &str,
+        _temperature: Option<f64>,
+        _options: StreamOptions,
+    ) -> BoxStream<'static, StreamResult<StreamEvent>> {
+        streaming_disabled()
+    }
+}
+
+impl zeroclaw_api::attribution::Attributable for MiddlewareProvider {
+    fn role(&self) -> zeroclaw_api::attribution::Role {
+        self.inner.role()
+    }
+    fn alias(&self) -> &str {
+        self.inner.alias()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+    use regex::Regex;
+    use zeroclaw_api::attribution::{Attributable, ModelProviderKind, ProviderKind, Role};
+
+    /// Inner provider that records the last conversation it was sent and
+    /// replies with a fixed text.
+    struct RecordingFake {
+        reply: String,
+        seen: Arc<Mutex<Vec<ChatMessage>>>,
+    }
+
+    impl RecordingFake {
+        fn new(reply: &str) -> (Self, Arc<Mutex<Vec<ChatMessage>>>) {
+            let seen = Arc::new(Mutex::new(Vec::new()));
+            let fake = Self {
+                reply: reply.to_string(),
+                seen: Arc::clone(&seen),
+            };
+            (fake, seen)
+        }
+    }
+
+    impl Attributable for RecordingFake {
+        fn role(&self) -> Role {
+            Role::Provider(ProviderKind::Model(ModelProviderKind::Anthropic))
+        }
+        fn alias(&self) -> &str {
+            "recording_fake"
+        }
+    }
+
+    #[async_trait]
+    impl ModelProvider for RecordingFake {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: Option<f64>,
+        ) -> anyhow::Result<String> {
+            unreachable!("the middleware wrapper routes through chat_with_history")
+        }
+
+        async fn chat_with_history(
+            &self,
+            messages: &[ChatMessage],
+            _model: &str,
+            _temperature: Option<f64>,
+        ) -> anyhow::Result<String> {
+            *self.seen.lock() = messages.to_vec();
+            Ok(self.reply.clone())
+        }
+    }
+
+    /// Example middleware: masks email addresses and phone numbers before
+    /// they leave the process, and in the model's reply.
+    struct PiiScrub {
+        email: Regex,
+        phone: Regex,
+    }
+
+    impl PiiScrub {
+        fn new() -> Self {
+            Self {
+                email: Regex::new(r"[\w.+-]+@[\w-]+\.[\w.-]+").unwrap(),
+                phone: Regex::new(r"\+?\d[\d -]{7,}\d").unwrap(),
+            }
+        }
+
+        fn scrub(&self, text: &str) -> String {
+            let text = self.email.replace_all(text, "[email]");
+            self.phone.replace_all(&text, "[phone]").into_owned()
+        }
+    }
+
+    #[async_trait]
+    impl ProviderMiddleware for PiiScrub {
+        fn name(&self) -> &str {
+            "pii-scrub"
+        }
+
+        async fn before_request(&self, messages: Vec<ChatMessage>) -> HookResult<Vec<ChatMessage>> {
+            HookResult::Continue(
+                messages
+                    .into_iter()
+                    .map(|mut m| {
+                        m.content = self.scrub(&m.content);
+                        m
+                    })
+                    .collect(),
+            )
+        }
+
+        async fn after_response(&self, text: String) -> HookResult<String> {
+            HookResult::Continue(self.scrub(&text))
+        }
+    }
+
+    /// Appends its tag to every message so tests can observe ordering.
+    struct Tag(&'static str, i32);
+
+    #[async_trait]
+    impl ProviderMiddleware for Tag {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn priority(&self) -> i32 {
+            self.1
+        }
+
+        async fn after_response(&self, text: String) -> HookResult<String> {
+            HookResult::Continue(format!("{text}+{}", self.0))
+        }
+    }
+
+    struct Misbehaving {
+        hang: bool,
+    }
+
+    #[async_trait]
+    impl ProviderMiddleware for Misbehaving {
+        fn name(&self) -> &str {
+            "misbehaving"
+        }
+
+        async fn before_request(&self, messages: Vec<ChatMessage>) -> HookResult<Vec<ChatMessage>> {
+            if self.hang {
+                std::future::pending::<()>().await;
+            }
+            if messages.is_empty() {
+                return HookResult::Continue(messages);
+            }
+            panic!("middleware bug")
+        }
+    }
+
+    struct BlockSecrets;
+
+    #[async_trait]
+    impl ProviderMiddleware for BlockSecrets {
+        fn name(&self) -> &str {
+            "block-secrets"
+        }
+
+        async fn before_request(&self, messages: Vec<ChatMessage>) -> HookResult<Vec<ChatMessage>> {
+            if messages.iter().any(|m| m.content.contains("CONFIDENTIAL")) {
+                HookResult::Cancel(
+                    "Messages marked CONFIDENTIAL cannot be sent to the model.".into(),
+                )
+            } else {
+                HookResult::Continue(messages)
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn pii_scrub_rewrites_request_and_response() {
+        let (fake, seen) = RecordingFake::new("Reach me at bot@example.com");
+        let provider = MiddlewareProvider::new(Box::new(fake), vec![Arc::new(PiiScrub::new())]);
+
+        let reply = provider
+            .chat_with_system(
+                Some("system"),
+                "I'm ada@example.org, call +1 555 010 9999",
+                "model",
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(reply, "Reach me at [email]");
+        let seen = seen.lock();
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[1].content, "I'm [email], call [phone]");
+    }
+
+    #[tokio::test]
+    async fn middleware_runs_in_priority_order() {
+        let (fake, _) = RecordingFake::new("reply");
+        let provider = MiddlewareProvider::new(
+            Box::new(fake),
+            vec![Arc::new(Tag("high", 10)), Arc::new(Tag("low", 0))],
+        );
+
+        let reply = provider
+            .chat_with_history(&[ChatMessage::user("hi")], "model", None)
+            .await
+            .unwrap();
+
+        assert_eq!(reply, "reply+high+low");
+    }
+
+    #[tokio::test]
+    async fn cancel_surfaces_as_policy_message() {
+        let (fake, seen) = RecordingFake::new("reply");
+        let provider = MiddlewareProvider::new(Box::new(fake), vec![Arc::new(BlockSecrets)]);
+
+        let err = provider
+            .chat_with_history(&[ChatMessage::user("CONFIDENTIAL plan")], "model", None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            policy_rejection_message(&err).as_deref(),
+            Some("🚫 Messages marked CONFIDENTIAL cannot be sent to the model.")
+        );
+        assert!(
+            seen.lock().is_empty(),
+            "rejected request must not reach the model"
+        );
+    }
+
+    #[tokio::test]
+    async fn panicking_or_hanging_middleware_fails_closed() {
+        for hang in [false, true] {
+            let (fake, seen) = RecordingFake::new("reply");
+            let provider =
+                MiddlewareProvider::new(Box::new(fake), vec![Arc::new(Misbehaving { hang })])
+                    .with_timeout(Duration::from_millis(50));
+
+            let err = provider
+                .chat_with_history(&[ChatMessage::user("hi")], "model", None)
+                .await
+                .unwrap_err();
+
+            assert!(policy_rejection_message(&err).is_some());
+            assert!(seen.lock().is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn streaming_is_disabled_behind_middleware() {
+        let (fake, _) = RecordingFake::new("reply");
+        let provider = MiddlewareProvider::new(Box::new(fake), vec![Arc::new(PiiScrub::new())]);
+
+        assert!(!provider.supports_streaming());
+        let first = provider
+            .stream_chat_with_history(
+                &[ChatMessage::user("hi")],
+                "model",
+                None,
+                StreamOptions::default(),
+            )
+            .next()
+            .await;
+        assert!(matches!(first, Some(Err(StreamError::ModelProvider(_)))));
+    }
+}
@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
+use crate::capabilities::ModelCapabilities;
 use crate::pricing::{ModelRates, sane_mtok};
 use anyhow::Result;
 use serde::Deserialize;
@@ -23,6 +24,26 @@ struct ModelEntry {
     id: String,
     #[serde(default)]
     cost: Option<ModelCost>,
+    #[serde(default)]
+    limit: Option<ModelLimit>,
+    #[serde(default)]
+    modalities: Option<ModelModalities>,
+    #[serde(default)]
+    tool_call: Option<bool>,
+}
+
+/// models.dev `limit` block, in tokens.
+#[derive(Debug, Deserialize, Clone, Copy, Default)]
+struct ModelLimit {
+    #[serde(default)]
+    context: Option<u64>,
+}
+
+/// models.dev `modalities` block; `input` lists `text`, `image`, `audio`, ...
+#[derive(Debug, Deserialize, Clone, Default)]
+struct ModelModalities {
+    #[serde(default)]
+    input: Vec<String>,
 }
 
 /// models.dev `cost` block: USD per 1M tokens (the same unit ZeroClaw's rate
@@ -118,6 +139,39 @@ pub(crate) fn pricing_from_catalog(
     out
 }
 
+/// Capability rows for every model under `provider_key`, keyed by model id.
+/// Models the catalog says nothing useful about are omitted.
+pub(crate) fn capabilities_from_catalog(
+    catalog: &Catalog,
+    provider_key: &str,
+) -> HashMap<String, ModelCapabilities> {
+    let mut out = HashMap::new();
+    let Some(entry) = catalog.get(provider_key) else {
+        return out;
+    };
+    for model in entry.models.values() {
+        let cost = model.cost.unwrap_or_default();
+        let capabilities = ModelCapabilities {
+            context_window: model
+                .limit
+                .and_then(|limit| limit.context)
+                .filter(|context| *context > 0)
+                .and_then(|context| usize::try_from(context).ok()),
+            supports_vision: model
+                .modalities
+                .as_ref()
+                .map(|modalities| modalities.input.iter().any(|m| m == "image")),
+            supports_tools: model.tool_call,
+            input_per_mtok: cost.input.and_then(sane_mtok),
+            output_per_mtok: cost.output.and_then(sane_mtok),
+        };
+        if !capabilities.is_empty() {
+            out.insert(model.id.clone(), capabilities);
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,4 +257,33 @@ mod tests {
         // Unknown provider key yields an empty map, not an error.
         assert!(pricing_from_catalog(&catalog, "absent").is_empty());
     }
+
+    #[test]
+    fn capabilities_from_catalog_reads_limit_modalities_and_tool_call() {
+        let raw = r#"{
+            "acme": {
+                "models": {
+                    "a": {
+                        "id": "acme-vision",
+                        "limit": {"context": 200000, "output": 8192},
+                        "modalities": {"input": ["text", "image"], "output": ["text"]},
+                        "tool_call": true,
+                        "cost": {"input": 3.0, "output": 15.0}
+                    },
+                    "b": {"id": "acme-text", "modalities": {"input": ["text"]}},
+                    "c": {"id": "acme-bare"}
+                }
+            }
+        }"#;
+        let catalog = parse_catalog(raw.as_bytes()).unwrap();
+        let map = capabilities_from_catalog(&catalog, "acme");
+        let vision = map.get("acme-vision").expect("described");
+        assert_eq!(vision.context_window, Some(200_000));
+        assert_eq!(vision.supports_vision, Some(true));
+        assert_eq!(vision.supports_tools, Some(true));
+        assert_eq!(vision.input_per_mtok, Some(3.0));
+        assert_eq!(vision.output_per_mtok, Some(15.0));
+        assert_eq!(map.get("acme-text").unwrap().supports_vision, Some(false));
+        assert!(!map.contains_key("acme-bare"));
+    }
 }
@@ -356,6 +356,7 @@ pub fn sanitize_audio_markers(messages: &[ChatMessage]) -> Cow<'_, [ChatMessage]
             ChatMessage {
                 role: m.role.clone(),
                 content,
+                reasoning: None,
             }
         })
         .collect();
@@ -465,12 +466,14 @@ fn strip_tool_result_image_markers(message: &ChatMessage) -> ChatMessage {
         return ChatMessage {
             role: message.role.clone(),
             content: serde_json::Value::Object(obj).to_string(),
+            reasoning: None,
         };
     }
 
     ChatMessage {
         role: message.role.clone(),
         content: stripped_image_marker_text(&message.content),
+        reasoning: None,
     }
 }
 
@@ -616,6 +619,7 @@ async fn prepare_messages_inner(
             normalized_messages.push(ChatMessage {
                 role: message.role.clone(),
                 content: prepared,
+                reasoning: None,
             });
             has_successful_images |= contains_images;
             continue;
@@ -649,6 +653,7 @@ async fn prepare_messages_inner(
         normalized_messages.push(ChatMessage {
             role: message.role.clone(),
             content,
+            reasoning: None,
         });
     }
 
@@ -737,6 +742,7 @@ fn trim_images_by_age(messages: &[ChatMessage], max_turns: usize) -> Vec<ChatMes
                 ChatMessage {
                     role: m.role.clone(),
                     content: text,
+                    reasoning: None,
                 }
             } else {
                 m.clone()
@@ -790,6 +796,7 @@ fn trim_old_images(messages: &[ChatMessage], max_images: usize) -> Vec<ChatMessa
                 ChatMessage {
                     role: m.role.clone(),
                     content: text,
+                    reasoning: None,
                 }
             } else {
                 replay_message_without_stale_tool_images(i, m, &latest_tool_indices)
@@ -1828,6 +1835,7 @@ mod tests {
             ChatMessage {
                 role: "assistant".to_string(),
                 content: "I generated the screenshot.".to_string(),
+                reasoning: None,
             },
             ChatMessage::user("What happened next?".to_string()),
         ];
@@ -1876,6 +1884,7 @@ mod tests {
             ChatMessage {
                 role: "assistant".to_string(),
                 content: "I generated the screenshot.".to_string(),
+                reasoning: None,
             },
             ChatMessage::user("Continue.".to_string()),
         ];
@@ -1916,6 +1925,7 @@ mod tests {
             ChatMessage {
                 role: "assistant".to_string(),
                 content: "I generated the screenshot.".to_string(),
+                reasoning: None,
             },
             ChatMessage::user(format!(
                 "Now inspect this [IMAGE:{}]",
@@ -1958,6 +1968,7 @@ mod tests {
             ChatMessage {
                 role: "assistant".to_string(),
                 content: "Done.".to_string(),
+                reasoning: None,
             },
             ChatMessage::user("Next question".to_string()),
         ];
@@ -1984,6 +1995,7 @@ mod tests {
             ChatMessage {
                 role: "assistant".to_string(),
                 content: "hello".to_string(),
+                reasoning: None,
             },
             ChatMessage::user("look at this [IMAGE:/tmp/a.png]".to_string()),
         ];
@@ -2083,6 +2095,7 @@ mod tests {
             ChatMessage {
                 role: "assistant".to_string(),
                 content: "[IMAGE:/tmp/assistant.png]\nAssistant generated".to_string(),
+                reasoning: None,
             },
             ChatMessage::user("[IMAGE:/tmp/user1.png]\nFirst".to_string()),
             ChatMessage::user("[IMAGE:/tmp/user2.png]\nSecond".to_string()),
@@ -2156,11 +2169,13 @@ mod tests {
             ChatMessage {
                 role: "assistant".to_string(),
                 content: "I see a photo.".to_string(),
+                reasoning: None,
             },
             ChatMessage::user("[IMAGE:/tmp/2.png]\nWhat about this?".to_string()),
             ChatMessage {
                 role: "assistant".to_string(),
                 content: "That's a chart.".to_string(),
+                reasoning: None,
             },
             ChatMessage::user("[IMAGE:/tmp/3.png]\nAnd this one".to_string()),
         ];
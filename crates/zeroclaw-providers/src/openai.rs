@@ -37,6 +37,8 @@ pub struct OpenAiModelProvider {
     credential: Option<String>,
     max_tokens: Option<u32>,
     timeout_secs: u64,
+    connect_timeout_secs: u64,
+    proxy_url: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -225,6 +227,8 @@ pub struct OpenAiBuilder {
     base_url: Option<String>,
     max_tokens: Option<u32>,
     timeout_secs: Option<u64>,
+    connect_timeout_secs: Option<u64>,
+    proxy_url: Option<String>,
 }
 
 impl OpenAiBuilder {
@@ -260,6 +264,22 @@ impl OpenAiBuilder {
         self
     }
 
+    /// Override the TCP/TLS connect timeout. Values of 0 are ignored (the
+    /// default 10 s is kept).
+    pub fn connect_timeout_secs(mut self, secs: u64) -> Self {
+        if secs > 0 {
+            self.connect_timeout_secs = Some(secs);
+        }
+        self
+    }
+
+    /// Route requests through `proxy_url` instead of the global `[proxy]`
+    /// settings.
+    pub fn proxy_url(mut self, proxy_url: &str) -> Self {
+        self.proxy_url = Some(proxy_url.to_string());
+        self
+    }
+
     pub fn build(self) -> OpenAiModelProvider {
         OpenAiModelProvider {
             alias: self.alias,
@@ -267,6 +287,8 @@ impl OpenAiBuilder {
             credential: self.credential,
             max_tokens: self.max_tokens,
             timeout_secs: self.timeout_secs.unwrap_or(120),
+            connect_timeout_secs: self.connect_timeout_secs.unwrap_or(10),
+            proxy_url: self.proxy_url,
         }
     }
 }
@@ -281,6 +303,8 @@ impl OpenAiModelProvider {
             base_url: None,
             max_tokens: None,
             timeout_secs: None,
+            connect_timeout_secs: None,
+            proxy_url: None,
         }
     }
 
@@ -430,10 +454,11 @@ impl OpenAiModelProvider {
     }
 
     fn http_client(&self) -> Client {
-        zeroclaw_config::schema::build_runtime_proxy_client_with_timeouts(
+        zeroclaw_config::schema::build_provider_proxy_client_with_timeouts(
             "model_provider.openai",
+            self.proxy_url.as_deref(),
             self.timeout_secs,
-            10,
+            self.connect_timeout_secs,
         )
     }
 }
@@ -944,6 +969,10 @@ pub struct OpenAiResponsesModelProvider {
     /// connect timeout so long-running responses aren't killed mid-stream.
     /// Default: 120 (matches `OpenAiCompatibleModelProvider`).
     timeout_secs: u64,
+    /// TCP/TLS connect timeout in seconds for both clients. Default: 10.
+    connect_timeout_secs: u64,
+    /// Per-provider proxy URL; `None` uses the global runtime proxy.
+    proxy_url: Option<String>,
     extra_headers: std::collections::HashMap<String, String>,
 }
 
@@ -952,8 +981,8 @@ pub struct OpenAiResponsesModelProvider {
 /// Only `alias` is required. `api_url` defaults to the OpenAI Responses
 /// endpoint; if a custom URL is supplied, `/responses` is appended when
 /// not already present so callers can pass either shape. Every runtime
-/// override (`timeout_secs` / `max_tokens` / `reasoning_effort` /
-/// `extra_headers`) is set via a chain method on this builder before
+/// override (`timeout_secs` / `connect_timeout_secs` / `proxy_url` /
+/// `max_tokens` / `reasoning_effort` / `extra_headers`) is set via a chain method on this builder before
 /// [`Self::build`] — the built provider itself has no post-construction
 /// mutators.
 #[must_use]
@@ -964,6 +993,8 @@ pub struct OpenAiResponsesBuilder {
     max_tokens: Option<u32>,
     reasoning_effort: Option<String>,
     timeout_secs: Option<u64>,
+    connect_timeout_secs: Option<u64>,
+    proxy_url: Option<String>,
     extra_headers: std::collections::HashMap<String, String>,
 }
 
@@ -1008,6 +1039,22 @@ impl OpenAiResponsesBuilder {
         self
     }
 
+    /// Override the TCP/TLS connect timeout for both the request and the
+    /// streaming client. Values of 0 are ignored (the default 10 s is kept).
+    pub fn connect_timeout_secs(mut self, secs: u64) -> Self {
+        if secs > 0 {
+            self.connect_timeout_secs = Some(secs);
+        }
+        self
+    }
+
+    /// Route requests through `proxy_url` instead of the global `[proxy]`
+    /// settings.
+    pub fn proxy_url(mut self, proxy_url: &str) -> Self {
+        self.proxy_url = Some(proxy_url.to_string());
+        self
+    }
+
     /// Set extra HTTP headers to include on every request. Reserved
     /// keys (e.g. `Authorization`) are dropped at request-build time —
     /// see [`OpenAiResponsesModelProvider`] for details.
@@ -1036,6 +1083,8 @@ impl OpenAiResponsesBuilder {
             max_tokens: self.max_tokens,
             reasoning_effort: self.reasoning_effort,
             timeout_secs: self.timeout_secs.unwrap_or(120),
+            connect_timeout_secs: self.connect_timeout_secs.unwrap_or(10),
+            proxy_url: self.proxy_url,
             extra_headers: self.extra_headers,
         }
     }
@@ -1052,6 +1101,8 @@ impl OpenAiResponsesModelProvider {
             max_tokens: None,
             reasoning_effort: None,
             timeout_secs: None,
+            connect_timeout_secs: None,
+            proxy_url: None,
             extra_headers: std::collections::HashMap::new(),
         }
     }
@@ -1127,22 +1178,34 @@ impl OpenAiResponsesModelProvider {
         let default_headers = self.build_default_headers();
         let mut builder = Client::builder()
             .timeout(std::time::Duration::from_secs(self.timeout_secs))
-            .connect_timeout(std::time::Duration::from_secs(10));
+            .connect_timeout(std::time::Duration::from_secs(self.connect_timeout_secs));
         if !default_headers.is_empty() {
             builder = builder.default_headers(default_headers);
         }
-        builder.build().unwrap_or_else(|_| Client::new())
+        zeroclaw_config::schema::apply_provider_proxy_to_builder(
+            builder,
+            "model_provider.openai",
+            self.proxy_url.as_deref(),
+        )
+        .build()
+        .unwrap_or_else(|_| Client::new())
     }
 
     fn streaming_client(&self) -> Client {
         let default_headers = self.build_default_headers();
         let mut builder = Client::builder()
-            .connect_timeout(std::time::Duration::from_secs(10))
+            .connect_timeout(std::time::Duration::from_secs(self.connect_timeout_secs))
             .read_timeout(STREAM_IDLE_TIMEOUT);
         if !default_headers.is_empty() {
             builder = builder.default_headers(default_headers);
         }
-        builder.build().unwrap_or_else(|_| Client::new())
+        zeroclaw_config::schema::apply_provider_proxy_to_builder(
+            builder,
+            "model_provider.openai",
+            self.proxy_url.as_deref(),
+        )
+        .build()
+        .unwrap_or_else(|_| Client::new())
     }
 }
 
@@ -2207,18 +2207,22 @@ data: [DONE]
             ChatMessage {
                 role: "system".into(),
                 content: "You are helpful.".into(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".into(),
                 content: "Hi".into(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "assistant".into(),
                 content: "Hello!".into(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".into(),
                 content: "Thanks".into(),
+                reasoning: None,
             },
         ];
         let (instructions, input) = build_responses_input(&messages);
@@ -2242,6 +2246,7 @@ data: [DONE]
         let messages = vec![ChatMessage {
             role: "user".into(),
             content: "Hello".into(),
+            reasoning: None,
         }];
         let (instructions, input) = build_responses_input(&messages);
         assert_eq!(instructions, DEFAULT_CODEX_INSTRUCTIONS);
@@ -2254,10 +2259,12 @@ data: [DONE]
             ChatMessage {
                 role: "tool".into(),
                 content: r#"{"tool_call_id":"call_123","content":"result"}"#.into(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".into(),
                 content: "Go".into(),
+                reasoning: None,
             },
         ];
         let (instructions, input) = build_responses_input(&messages);
@@ -2274,6 +2281,7 @@ data: [DONE]
         let messages = vec![ChatMessage {
             role: "tool".into(),
             content: "legacy plain text result".into(),
+            reasoning: None,
         }];
 
         let (_, input) = build_responses_input(&messages);
@@ -2294,6 +2302,7 @@ data: [DONE]
         let messages = vec![ChatMessage {
             role: "tool".into(),
             content: r#"{"content":"legacy result","status":"ok"}"#.into(),
+            reasoning: None,
         }];
 
         let (_, input) = build_responses_input(&messages);
@@ -2320,6 +2329,7 @@ data: [DONE]
                     "content": "legacy result"
                 })
                 .to_string(),
+                reasoning: None,
             }];
 
             let (_, input) = build_responses_input(&messages);
@@ -20,6 +20,8 @@ pub struct OpenRouterModelProvider {
     alias: String,
     credential: Option<String>,
     timeout_secs: u64,
+    connect_timeout_secs: u64,
+    proxy_url: Option<String>,
     max_tokens: Option<u32>,
     extra_body: Option<serde_json::Value>,
 }
@@ -204,6 +206,8 @@ pub struct OpenRouterBuilder {
     alias: String,
     credential: Option<String>,
     timeout_secs: Option<u64>,
+    connect_timeout_secs: Option<u64>,
+    proxy_url: Option<String>,
     max_tokens: Option<u32>,
     extra_body: Option<serde_json::Value>,
 }
@@ -229,6 +233,22 @@ impl OpenRouterBuilder {
         self
     }
 
+    /// Override the TCP/TLS connect timeout. Values of 0 are ignored (the
+    /// default 10 s is kept).
+    pub fn connect_timeout_secs(mut self, secs: u64) -> Self {
+        if secs > 0 {
+            self.connect_timeout_secs = Some(secs);
+        }
+        self
+    }
+
+    /// Route requests through `proxy_url` instead of the global `[proxy]`
+    /// settings.
+    pub fn proxy_url(mut self, proxy_url: &str) -> Self {
+        self.proxy_url = Some(proxy_url.to_string());
+        self
+    }
+
     pub fn max_tokens(mut self, max_tokens: Option<u32>) -> Self {
         self.max_tokens = max_tokens;
         self
@@ -249,6 +269,10 @@ impl OpenRouterBuilder {
             timeout_secs: self
                 .timeout_secs
                 .unwrap_or(zeroclaw_api::model_provider::BASELINE_TIMEOUT_SECS),
+            connect_timeout_secs: self
+                .connect_timeout_secs
+                .unwrap_or(OPENROUTER_CONNECT_TIMEOUT_SECS),
+            proxy_url: self.proxy_url,
             max_tokens: self.max_tokens,
             extra_body: self.extra_body,
         }
@@ -263,6 +287,8 @@ impl OpenRouterModelProvider {
             alias: alias.to_string(),
             credential: None,
             timeout_secs: None,
+            connect_timeout_secs: None,
+            proxy_url: None,
             max_tokens: None,
             extra_body: None,
         }
@@ -530,10 +556,11 @@ impl OpenRouterModelProvider {
     }
 
     fn http_client(&self) -> Client {
-        zeroclaw_config::schema::build_runtime_proxy_client_with_timeouts(
+        zeroclaw_config::schema::build_provider_proxy_client_with_timeouts(
             "model_provider.openrouter",
+            self.proxy_url.as_deref(),
             self.timeout_secs,
-            OPENROUTER_CONNECT_TIMEOUT_SECS,
+            self.connect_timeout_secs,
         )
     }
 }
@@ -1106,6 +1133,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "user".into(),
             content: "hello".into(),
+            reasoning: None,
         }];
         let request = ChatRequest {
             messages: &messages,
@@ -1147,6 +1175,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "user".into(),
             content: "hello".into(),
+            reasoning: None,
         }];
         let request = ChatRequest {
             messages: &messages,
@@ -1272,10 +1301,12 @@ mod tests {
             ChatMessage {
                 role: "system".into(),
                 content: "be concise".into(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".into(),
                 content: "hello".into(),
+                reasoning: None,
             },
         ];
 
@@ -1321,10 +1352,12 @@ mod tests {
             ChatMessage {
                 role: "assistant".into(),
                 content: "Previous answer".into(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".into(),
                 content: "Follow-up".into(),
+                reasoning: None,
             },
         ];
 
@@ -1408,6 +1441,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "user".into(),
             content: "What is the date?".into(),
+            reasoning: None,
         }];
         let tools = vec![serde_json::json!({
             "type": "function",
@@ -1503,6 +1537,7 @@ mod tests {
             role: "assistant".into(),
             content: r#"{"content":"Using tool","tool_calls":[{"id":"call_abc","name":"shell","arguments":"{\"command\":\"pwd\"}"}]}"#
                 .into(),
+            reasoning: None,
         }];
 
         let converted = OpenRouterModelProvider::convert_messages(&messages);
@@ -1531,6 +1566,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "tool".into(),
             content: r#"{"tool_call_id":"call_xyz","content":"done"}"#.into(),
+            reasoning: None,
         }];
 
         let converted = OpenRouterModelProvider::convert_messages(&messages);
@@ -1654,10 +1690,12 @@ mod tests {
             ChatMessage {
                 role: "system".into(),
                 content: "Be helpful".into(),
+                reasoning: None,
             },
             ChatMessage {
                 role: "user".into(),
                 content: "Hi".into(),
+                reasoning: None,
             },
         ];
         let native = OpenRouterModelProvider::convert_messages(&messages);
@@ -1838,6 +1876,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "assistant".into(),
             content: history_json.to_string(),
+            reasoning: None,
         }];
         let native = OpenRouterModelProvider::convert_messages(&messages);
         assert_eq!(native.len(), 1);
@@ -1861,6 +1900,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "assistant".into(),
             content: history_json.to_string(),
+            reasoning: None,
         }];
         let native = OpenRouterModelProvider::convert_messages(&messages);
         assert_eq!(native.len(), 1);
@@ -1878,6 +1918,7 @@ mod tests {
             role: "assistant".into(),
             content: r#"{"content":"trying","tool_calls":[{"id":"call_bad","name":"shell","arguments":"{\"command\":\"rm -rf"}]}"#
                 .into(),
+            reasoning: None,
         }];
 
         let converted = OpenRouterModelProvider::convert_messages(&messages);
@@ -1897,6 +1938,7 @@ mod tests {
             role: "assistant".into(),
             content: r#"{"content":"using","tool_calls":[{"id":"call_ok","name":"shell","arguments":"{\"command\":\"pwd\"}"}]}"#
                 .into(),
+            reasoning: None,
         }];
 
         let converted = OpenRouterModelProvider::convert_messages(&messages);
@@ -1913,6 +1955,7 @@ mod tests {
             role: "assistant".into(),
             content: r#"{"content":"testing","tool_calls":[{"id":"c1","name":"f","arguments":"null"},{"id":"c2","name":"g","arguments":"[]"},{"id":"c3","name":"h","arguments":"42"}]}"#
                 .into(),
+            reasoning: None,
         }];
 
         let converted = OpenRouterModelProvider::convert_messages(&messages);
@@ -426,7 +426,39 @@ fn endpoint_from_error_text(text: &str) -> Option<String> {
     Some(sanitized_url_endpoint(url))
 }
 
-fn provider_error_diagnostic(err: &anyhow::Error) -> ProviderErrorDiagnostic {
+/// Connection-level failures reported by reqwest when tunnelling through an
+/// HTTP or SOCKS proxy.
+fn is_proxy_failure(lower: &str) -> bool {
+    [
+        "unsuccessful tunnel",
+        "proxy authentication required",
+        "while tunneling",
+        "proxy headers too long",
+        "socks connect",
+    ]
+    .iter()
+    .any(|marker| lower.contains(marker))
+}
+
+/// Classify a provider failure. `via_proxy` marks entries configured with
+/// their own `proxy_url`; connect-level failures on those are reported as
+/// proxy failures, since the provider host was never reached.
+fn provider_error_diagnostic(err: &anyhow::Error, via_proxy: bool) -> ProviderErrorDiagnostic {
+    let diagnostic = classify_provider_error(err);
+    let connect_failure = matches!(diagnostic.kind, "connect" | "connect_timeout" | "dns");
+    if (via_proxy && connect_failure) || is_proxy_failure(&compact_error_detail(err).to_lowercase())
+    {
+        return ProviderErrorDiagnostic {
+            kind: "proxy",
+            phase: "proxy_connect",
+            hint: "proxy connection failed; check the provider's proxy_url, the [proxy] section, or HTTPS_PROXY/NO_PROXY",
+            endpoint: diagnostic.endpoint,
+        };
+    }
+    diagnostic
+}
+
+fn classify_provider_error(err: &anyhow::Error) -> ProviderErrorDiagnostic {
     let error_detail = compact_error_detail(err);
     let lower = error_detail.to_lowercase();
     let endpoint = err
@@ -716,6 +748,8 @@ pub(crate) struct ReliableModelProviderEntry {
     display_name: String,
     cooldown_key: String,
     provider: ReliableModelProviderEntryProvider,
+    /// The provider's requests go through its own `proxy_url`.
+    via_proxy: bool,
 }
 
 impl ReliableModelProviderEntry {
@@ -728,6 +762,7 @@ impl ReliableModelProviderEntry {
             display_name: display_name.into(),
             cooldown_key: cooldown_key.into(),
             provider: ReliableModelProviderEntryProvider::Direct(provider),
+            via_proxy: false,
         }
     }
 
@@ -751,9 +786,17 @@ impl ReliableModelProviderEntry {
                     .inner(inner)
                     .build(),
             ),
+            via_proxy: false,
         }
     }
 
+    /// Mark the entry as routed through a per-provider proxy so connect
+    /// failures are reported as proxy failures.
+    pub(crate) fn via_proxy(mut self, via_proxy: bool) -> Self {
+        self.via_proxy = via_proxy;
+        self
+    }
+
     /// Model this entry serves for `requested_model`: the pinned model when
     /// the entry is model-pinned, otherwise the requested model unchanged.
     fn served_model<'a>(&'a self, requested_model: &'a str) -> &'a str {
@@ -1097,7 +1140,7 @@ impl ModelProvider for ReliableModelProvider {
                             let rate_limited = is_rate_limited(&e);
                             let failure_reason = failure_reason(rate_limited, non_retryable);
                             let error_detail = compact_error_detail(&e);
-                            let diagnostic = provider_error_diagnostic(&e);
+                            let diagnostic = provider_error_diagnostic(&e, entry.via_proxy);
                             last_error_detail = Some(error_detail.clone());
                             last_diagnostic = Some(diagnostic.clone());
 
@@ -1307,7 +1350,7 @@ impl ModelProvider for ReliableModelProvider {
                             let rate_limited = is_rate_limited(&e);
                             let failure_reason = failure_reason(rate_limited, non_retryable);
                             let error_detail = compact_error_detail(&e);
-                            let diagnostic = provider_error_diagnostic(&e);
+                            let diagnostic = provider_error_diagnostic(&e, entry.via_proxy);
                             last_error_detail = Some(error_detail.clone());
                             last_diagnostic = Some(diagnostic.clone());
 
@@ -1556,7 +1599,7 @@ impl ModelProvider for ReliableModelProvider {
                             let rate_limited = is_rate_limited(&e);
                             let failure_reason = failure_reason(rate_limited, non_retryable);
                             let error_detail = compact_error_detail(&e);
-                            let diagnostic = provider_error_diagnostic(&e);
+                            let diagnostic = provider_error_diagnostic(&e, entry.via_proxy);
                             last_error_detail = Some(error_detail.clone());
                             last_diagnostic = Some(diagnostic.clone());
 
@@ -1766,7 +1809,7 @@ impl ModelProvider for ReliableModelProvider {
                             let rate_limited = is_rate_limited(&e);
                             let failure_reason = failure_reason(rate_limited, non_retryable);
                             let error_detail = compact_error_detail(&e);
-                            let diagnostic = provider_error_diagnostic(&e);
+                            let diagnostic = provider_error_diagnostic(&e, entry.via_proxy);
                             last_error_detail = Some(error_detail.clone());
                             last_diagnostic = Some(diagnostic.clone());
 
@@ -2746,7 +2789,7 @@ mod tests {
              client error (Connect): operation timed out",
         );
 
-        let diagnostic = provider_error_diagnostic(&err);
+        let diagnostic = provider_error_diagnostic(&err, false);
 
         assert_eq!(diagnostic.kind, "connect_timeout");
         assert_eq!(diagnostic.phase, "tls_or_connect");
@@ -2757,6 +2800,33 @@ mod tests {
         assert!(diagnostic.hint.contains("VPN"));
     }
 
+    #[test]
+    fn provider_error_diagnostic_reports_proxy_failures() {
+        let unresolved = anyhow::Error::msg(
+            "error sending request for url (https://api.openai.com/v1/chat/completions): \
+             client error (Connect): dns error: failed to lookup address information",
+        );
+        assert_eq!(provider_error_diagnostic(&unresolved, false).kind, "dns");
+        let diagnostic = provider_error_diagnostic(&unresolved, true);
+        assert_eq!(diagnostic.kind, "proxy");
+        assert_eq!(diagnostic.phase, "proxy_connect");
+        assert!(diagnostic.hint.starts_with("proxy connection failed"));
+        assert_eq!(
+            diagnostic.endpoint.as_deref(),
+            Some("https://api.openai.com/v1/chat/completions")
+        );
+
+        // Tunnel failures are recognisable even from the global proxy.
+        let tunnel = anyhow::Error::msg(
+            "client error (Connect): unsuccessful tunnel: 407 Proxy Authentication Required",
+        );
+        assert_eq!(provider_error_diagnostic(&tunnel, false).kind, "proxy");
+
+        // Failures after the connection is up are not blamed on the proxy.
+        let timeout = anyhow::Error::msg("request timed out while waiting for provider");
+        assert_eq!(provider_error_diagnostic(&timeout, true).kind, "timeout");
+    }
+
     #[test]
     fn endpoint_from_error_text_strips_url_userinfo() {
         let endpoint = endpoint_from_error_text(
@@ -2848,7 +2918,7 @@ mod tests {
         ];
 
         for (message, expected_kind, expected_phase, expected_hint) in cases {
-            let diagnostic = provider_error_diagnostic(&anyhow::Error::msg(message));
+            let diagnostic = provider_error_diagnostic(&anyhow::Error::msg(message), false);
 
             assert_eq!(diagnostic.kind, expected_kind, "{message}");
             assert_eq!(diagnostic.phase, expected_phase, "{message}");
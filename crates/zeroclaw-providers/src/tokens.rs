@@ -0,0 +1,172 @@
+//! Token counting for context budgets.
+//!
+//! History compaction, memory injection, and bootstrap-file trimming are all
+//! budgeted in tokens. A flat chars/4 rule is close for English prose but
+//! badly undercounts CJK text, where BPE tokenizers spend about one token per
+//! character, so a CJK-heavy request could pass the budget check and still
+//! overflow the model's window. [`TokenCounter`] is the seam a budget goes
+//! through; [`HeuristicTokenCounter`] is the script-aware estimate used when
+//! no exact tokenizer is wired for the model.
+
+/// Counts the tokens a model would see for a piece of text.
+pub trait TokenCounter: Send + Sync {
+    /// Tokens in `text`.
+    fn count(&self, text: &str) -> usize;
+
+    /// Byte length of the longest prefix of `text` that fits in
+    /// `max_tokens`, always on a char boundary.
+    fn fit_prefix(&self, text: &str, max_tokens: usize) -> usize {
+        if self.count(text) <= max_tokens {
+            return text.len();
+        }
+        // Binary search over char boundaries; counts grow with the prefix.
+        let boundaries: Vec<usize> = text.char_indices().map(|(i, c)| i + c.len_utf8()).collect();
+        let (mut lo, mut hi) = (0usize, boundaries.len());
+        while lo < hi {
+            let mid = (lo + hi).div_ceil(2);
+            if self.count(&text[..boundaries[mid - 1]]) <= max_tokens {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        if lo == 0 { 0 } else { boundaries[lo - 1] }
+    }
+}
+
+/// Script-aware estimate: ~4 ASCII bytes per token (the rule the history
+/// estimator always used, so English budgets are unchanged), one token per
+/// CJK ideograph, kana, or Hangul syllable, and ~3 UTF-8 bytes per token for
+/// everything else (accented Latin, Cyrillic, emoji).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HeuristicTokenCounter;
+
+#[derive(Default)]
+struct ScriptTally {
+    ascii_bytes: usize,
+    cjk_chars: usize,
+    other_bytes: usize,
+}
+
+impl ScriptTally {
+    fn add(&mut self, c: char) {
+        if c.is_ascii() {
+            self.ascii_bytes += 1;
+        } else if is_cjk(c) {
+            self.cjk_chars += 1;
+        } else {
+            self.other_bytes += c.len_utf8();
+        }
+    }
+
+    fn tokens(&self) -> usize {
+        self.ascii_bytes.div_ceil(4) + self.cjk_chars + self.other_bytes.div_ceil(3)
+    }
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3040}'..='\u{30FF}'   // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{AC00}'..='\u{D7AF}' // Hangul syllables
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+        | '\u{3000}'..='\u{303F}' // CJK punctuation
+        | '\u{FF00}'..='\u{FFEF}' // Half/full-width forms
+        | '\u{20000}'..='\u{2FFFF}' // CJK Extensions B and later
+    )
+}
+
+impl TokenCounter for HeuristicTokenCounter {
+    fn count(&self, text: &str) -> usize {
+        let mut tally = ScriptTally::default();
+        text.chars().for_each(|c| tally.add(c));
+        tally.tokens()
+    }
+
+    fn fit_prefix(&self, text: &str, max_tokens: usize) -> usize {
+        let mut tally = ScriptTally::default();
+        for (idx, c) in text.char_indices() {
+            tally.add(c);
+            if tally.tokens() > max_tokens {
+                return idx;
+            }
+        }
+        text.len()
+    }
+}
+
+/// The counter budgets use by default.
+pub static DEFAULT_TOKEN_COUNTER: HeuristicTokenCounter = HeuristicTokenCounter;
+
+/// Tokens in `text` per [`DEFAULT_TOKEN_COUNTER`].
+#[must_use]
+pub fn estimate_tokens(text: &str) -> usize {
+    DEFAULT_TOKEN_COUNTER.count(text)
+}
+
+/// The longest prefix of `text` within `max_tokens` per
+/// [`DEFAULT_TOKEN_COUNTER`].
+#[must_use]
+pub fn truncate_to_tokens(text: &str, max_tokens: usize) -> &str {
+    &text[..DEFAULT_TOKEN_COUNTER.fit_prefix(text, max_tokens)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_matches_the_four_chars_per_token_rule() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("hello world"), 3);
+        assert_eq!(estimate_tokens(&"x".repeat(40_000)), 10_000);
+    }
+
+    #[test]
+    fn cjk_counts_a_token_per_character() {
+        // 13 ideographs: bytes/4 would say 10; a tokenizer sees 13 or more.
+        let zh = "今天天气很好我们去公园散步";
+        assert_eq!(estimate_tokens(zh), zh.chars().count());
+        assert!(estimate_tokens(zh) > zh.len().div_ceil(4));
+        assert_eq!(estimate_tokens("こんにちは世界"), 7);
+        assert_eq!(estimate_tokens("안녕하세요"), 5);
+    }
+
+    #[test]
+    fn mixed_scripts_add_up() {
+        // 8 ASCII bytes -> 2, 2 ideographs -> 2, "é" (2 bytes) -> 1.
+        assert_eq!(estimate_tokens("deploy 部署 é"), 2 + 2 + 1);
+        assert_eq!(estimate_tokens("Привет"), 4);
+    }
+
+    #[test]
+    fn truncation_stays_within_budget_on_char_boundaries() {
+        let text = "summary: 这是一个很长的中文句子，用来测试截断。";
+        for budget in 0..estimate_tokens(text) + 2 {
+            let prefix = truncate_to_tokens(text, budget);
+            assert!(estimate_tokens(prefix) <= budget, "budget {budget}");
+            assert!(text.is_char_boundary(prefix.len()));
+        }
+        assert_eq!(truncate_to_tokens(text, usize::MAX), text);
+    }
+
+    #[test]
+    fn default_fit_prefix_agrees_with_the_heuristic() {
+        struct ViaCount;
+        impl TokenCounter for ViaCount {
+            fn count(&self, text: &str) -> usize {
+                HeuristicTokenCounter.count(text)
+            }
+        }
+        let text = "mixed 内容 with ascii と かな";
+        for budget in 0..estimate_tokens(text) + 1 {
+            assert_eq!(
+                ViaCount.fit_prefix(text, budget),
+                HeuristicTokenCounter.fit_prefix(text, budget),
+                "budget {budget}"
+            );
+        }
+    }
+}
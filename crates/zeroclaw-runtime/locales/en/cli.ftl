@@ -12,6 +12,8 @@ cli-doctor-about = Run diagnostics for daemon/scheduler/channel freshness
 cli-status-about = Show system status (full details)
 cli-estop-about = Engage, inspect, and resume emergency-stop states
 cli-cron-about = Configure and manage scheduled tasks
+cli-tasks-about = Inspect and cancel background agent tasks
+cli-approvals-about = Review tool calls waiting in the approval queue
 cli-models-about = Manage provider model catalogs
 cli-providers-about = List supported AI providers
 cli-channel-about = Manage communication channels
@@ -19,6 +21,7 @@ cli-integrations-about = Browse 50+ integrations
 cli-skills-about = Manage skills (user-defined capabilities)
 cli-sop-about = Manage standard operating procedures (SOPs)
 cli-migrate-about = Migrate data from other agent runtimes
+cli-backup-about = Back up or restore this install (config, memory, skills, transcripts)
 cli-auth-about = Manage provider subscription authentication profiles
 cli-hardware-about = Discover and introspect USB hardware
 cli-peripheral-about = Manage hardware peripherals
@@ -35,6 +38,7 @@ cli-config-get-about = Get a config property value
 cli-config-set-about = Set a config property (secret fields auto-prompt for masked input)
 cli-config-init-about = Initialize unconfigured sections with defaults (enabled=false)
 cli-config-migrate-about = Migrate config.toml to the current schema version on disk (preserves comments)
+cli-config-validate-about = Check config.toml for unknown keys and values that fail to deserialize
 
 cli-service-install-about = Install daemon service unit for auto-start and restart
 cli-service-start-about = Start daemon service
@@ -50,6 +54,10 @@ cli-channel-doctor-about = Run health checks for configured channels
 cli-channel-add-about = Add a new channel configuration
 cli-channel-remove-about = Remove a channel configuration
 cli-channel-send-about = Send a one-off message to a configured channel
+cli-channel-outbox-about = Inspect replies waiting for redelivery
+cli-channel-outbox-list-about = List undelivered replies, oldest first
+cli-channel-outbox-retry-about = Requeue a pending or dead-lettered reply for immediate delivery
+cli-channel-outbox-drop-about = Delete an undelivered reply
 cli-wechat-pairing-required = 🔐 WeChat pairing required. One-time bind code: {$code}
 cli-wechat-send-bind-command = Send `{$command} <code>` from your WeChat.
 cli-wechat-qr-login = 📱 WeChat QR Login ({$attempt}/{$max})
@@ -141,6 +149,14 @@ cli-cron-update-about = Update one or more fields of an existing scheduled task
 cli-cron-pause-about = Pause a scheduled task
 cli-cron-resume-about = Resume a paused task
 
+cli-tasks-list-about = List recent background tasks
+cli-tasks-show-about = Show one background task, including its output
+cli-tasks-cancel-about = Cancel a queued or running background task
+
+cli-approvals-list-about = List tool calls waiting for approval
+cli-approvals-approve-about = Approve a queued tool call (talks to the running daemon)
+cli-approvals-deny-about = Deny a queued tool call (talks to the running daemon)
+
 cli-auth-login-about = Login with OAuth (OpenAI Codex, Gemini, or xAI)
 cli-auth-refresh-about = Refresh OAuth access token using refresh token
 cli-auth-logout-about = Remove auth profile
@@ -152,6 +168,7 @@ cli-memory-list-about = List memory entries with optional filters
 cli-memory-get-about = Get a specific memory entry by key
 cli-memory-stats-about = Show memory backend statistics and health
 cli-memory-clear-about = Clear memories by category, by key, or clear all
+cli-memory-vacuum-about = Compact the memory database and refresh its query statistics
 cli-memory-clear-unsupported-backend = memory clear is unsupported for append-only backend '{$backend}'; switch to a deletable backend (sqlite, lucid, or postgres)
 
 cli-estop-status-about = Print current estop status
@@ -161,14 +178,17 @@ cli-models-refresh-about = Refresh and cache provider models
 cli-models-list-about = List cached models for a provider
 cli-models-set-about = Set the default model in config
 cli-models-status-about = Show current model configuration and cache status
+cli-models-show-about = Show a model's context window, vision and tool support, and pricing
 
 cli-doctor-models-about = Probe model catalogs across providers and report availability
+cli-doctor-providers-about = Verify provider API keys and report latency and remaining quota
 cli-doctor-traces-about = Query runtime trace events (tool diagnostics and model replies)
 cli-doctor-update-context-windows-about = Update context_window in config.toml from provider /models endpoints
 
 cli-hardware-discover-about = Enumerate USB devices and show known boards
 cli-hardware-introspect-about = Introspect a device by its serial or device path
 cli-hardware-info-about = Get chip info via USB using probe-rs over ST-Link
+cli-hardware-scan-about = Detect boards on serial ports by USB VID/PID and probe their firmware
 
 cli-peripheral-list-about = List configured peripherals
 cli-peripheral-add-about = Add a peripheral by board type and transport path
@@ -190,6 +210,7 @@ cli-agent-long-about =
       zeroclaw agent -m "Summarize today's logs"  # single message
       zeroclaw agent -p anthropic --model claude-sonnet-4-20250514
       zeroclaw agent --peripheral nucleo-f401re:/dev/ttyACM0
+      zeroclaw agent --peripheral auto            # attach every detected board
 
 cli-gateway-long-about =
     Manage the gateway server (webhooks, websockets).
@@ -242,6 +263,28 @@ cli-cron-long-about =
       zeroclaw cron pause TASK_ID
       zeroclaw cron update TASK_ID --expression '0 8 * * *' --tz Europe/London
 
+cli-tasks-long-about =
+    Inspect and cancel background agent tasks.
+
+    Agents start background tasks with the task_spawn tool for work that would outlast a channel reply. The daemon runs them, capped by [tasks] max_concurrent, and posts progress back to the originating chat.
+
+    Examples:
+      zeroclaw tasks list
+      zeroclaw tasks list --agent default --limit 5
+      zeroclaw tasks show TASK_ID
+      zeroclaw tasks cancel TASK_ID
+
+cli-approvals-long-about =
+    Review tool calls waiting in the approval queue.
+
+    With [approvals] enabled = true, a tool call that needs approval on a channel with no inline approval waits in the daemon's queue instead of being denied. Approve or deny it here; the decision reaches the daemon through the gateway admin API and the call resumes immediately. Unanswered requests are denied after [approvals] timeout_secs.
+
+    Examples:
+      zeroclaw approvals list
+      zeroclaw approvals list --all
+      zeroclaw approvals approve APPROVAL_ID
+      zeroclaw approvals deny APPROVAL_ID --reason 'not during the freeze'
+
 cli-channel-long-about =
     Manage communication channels.
 
@@ -262,6 +305,7 @@ cli-hardware-long-about =
 
     Examples:
       zeroclaw hardware discover
+      zeroclaw hardware scan
       zeroclaw hardware introspect /dev/ttyACM0
       zeroclaw hardware info --chip STM32F401RETx
 
@@ -273,6 +317,7 @@ cli-peripheral-long-about =
     Examples:
       zeroclaw peripheral list
       zeroclaw peripheral add nucleo-f401re /dev/ttyACM0
+      zeroclaw agent --peripheral auto
       zeroclaw peripheral add rpi-gpio native
       zeroclaw peripheral flash --port /dev/cu.usbmodem12345
       zeroclaw peripheral flash-nucleo
@@ -320,12 +365,16 @@ cli-update-long-about =
     Use --check to only check for updates without installing.
     Use --force to skip the confirmation prompt.
     Use --version to target a specific release instead of latest.
+    Use --rollback to restore the binary kept by the last update.
+
+    Downloads are verified against the release's SHA256SUMS before anything is replaced. The release track comes from `[updater] channel` (stable, beta, or nightly).
 
     Examples:
       zeroclaw update                      # download and install latest
       zeroclaw update --check              # check only, don't install
       zeroclaw update --force              # install without confirmation
       zeroclaw update --version 0.6.0      # install specific version
+      zeroclaw update --rollback           # restore the previous binary
 
 cli-self-test-long-about =
     Run diagnostic self-tests to verify the ZeroClaw installation.
@@ -455,9 +504,25 @@ cli-doctor-web-dist-dir-expansion-warning = gateway.web_dist_dir = "{$path}" —
 cli-doctor-codex-auth-profile-no-slot = OpenAI Codex credentials are signed in but no model provider slot uses them. Set `requires_openai_auth = true` on an OpenAI provider slot and point an agent's `model_provider` at it, or run `zeroclaw quickstart`.
 cli-doctor-codex-auth-slot-no-profile = OpenAI slot(s) {$slots} set `requires_openai_auth = true` but no OpenAI Codex credentials are signed in. Run `zeroclaw auth login --provider openai-codex`.
 cli-doctor-codex-auth-ok = OpenAI Codex credentials are signed in and referenced by a model provider slot.
+cli-doctor-tunnel-ok = {$url} reaches this gateway
+cli-doctor-tunnel-unreachable = {$url}/health is unreachable: {$error}
+cli-doctor-tunnel-not-gateway = {$url}/health answered, but not with a ZeroClaw gateway health response — check the tunnel's origin/ingress rule.
+cli-doctor-tunnel-other-gateway = {$url} reaches a different gateway (pid {$public_pid}, local gateway pid {$local_pid}) — check the tunnel's origin/ingress rule.
+cli-doctor-tunnel-local-unconfirmed = {$url} answered as a gateway, but the local gateway did not respond, so it could not be confirmed as this one. Is the daemon running?
+cli-doctor-tunnel-no-public-url = tunnel provider `{$provider}` is configured but `tunnel.public_url` is not set, so webhook reachability can't be checked. Run `zeroclaw quickstart` (Channels → Expose gateway publicly) or `zeroclaw config set tunnel.public_url <url>`.
+cli-doctor-integration-ok = {$name}: {$detail}
+cli-doctor-integration-pending = {$name}: credentials work, but the account is not connected yet. Run `zeroclaw integrations setup {$slug}` for the connect link.
+cli-doctor-integration-failed = {$name}: credential check failed: {$error}. Run `zeroclaw integrations setup {$slug}` to fix it.
 cli-doctor-systemd-linger-enabled = systemd user lingering enabled
 cli-doctor-systemd-linger-disabled = systemd user lingering disabled; user service may stop after logout. Enable with: loginctl enable-linger {$user}
 cli-doctor-systemd-linger-unknown = systemd user lingering could not be checked with loginctl
+cli-doctor-credential-found = { $provider }: key found via { $source }
+cli-doctor-credential-found-unused = { $provider }: no api_key configured; a key exists in { $source } but only config keys are read at runtime — import it with `zeroclaw quickstart`
+cli-doctor-provider-fix-key = set `{ $config_key }` in config.toml or run `zeroclaw quickstart`
+cli-doctor-provider-fix-key-env = set `{ $config_key }` in config.toml or export `{ $env_var }`
+cli-doctor-provider-fix-quota = add credits or raise the spending limit on this account, or set `{ $config_key }` to a key with quota left
+cli-doctor-provider-fix-rate-limited = the key works but is being rate limited; retry later
+cli-doctor-provider-fix-network = check that the provider endpoint is reachable (`{ $uri_key }` overrides it; proxy: { $proxy })
 cli-self-test-web-dist-dir-name = web_dist_dir
 cli-self-test-web-dist-dir-pass-unset = not set (using auto-detect)
 cli-self-test-web-dist-dir-pass-literal = {$path} (literal path)
@@ -472,6 +537,14 @@ cli-peripherals-add-hint = Add one with: zeroclaw peripheral add <board> <path>
 cli-peripherals-add-example = {"  "}Example: zeroclaw peripheral add nucleo-f401re <serial-path>
 cli-peripherals-config-hint = Or add to config.toml:
 cli-peripherals-configured = Configured peripherals:
+cli-peripherals-state-connected = connected
+cli-peripherals-state-detached = detached ({$error})
+cli-peripherals-state-no-session = no live session
+cli-peripherals-spec-invalid = Invalid --peripheral value "{$spec}". Use <board>:<path>, e.g. nucleo-f401re:/dev/ttyACM0, or auto.
+cli-peripherals-detected-none = No detection results yet. Run: zeroclaw hardware scan
+cli-peripherals-detected-header = Last hardware scan ({$age}s ago):
+cli-peripherals-auto-none = --peripheral auto found no boards that answered the capabilities probe.
+cli-peripherals-auto-attached = Attached {$board} at {$path}
 cli-peripherals-already-configured = Board {$board} at {$path} already configured.
 cli-peripherals-added = Added {$board} at {$path}. Restart daemon to apply.
 cli-peripherals-flash-needs-hardware = Arduino flash requires the 'hardware' feature.
@@ -527,6 +600,8 @@ cli-sop-step-tools = Tools: {$tools}
 
 # ── memory (zeroclaw memory) ──
 cli-memory-reindexing = Reindexing memory backend...
+cli-memory-vacuuming = Compacting memory database...
+cli-memory-vacuumed = Memory database compacted; reclaimed {$kib} KiB.
 cli-memory-none = No memory entries found.
 cli-memory-none-at-offset = No entries at offset {$offset} (total: {$total}).
 cli-memory-next-page = Use --offset {$offset} to see the next page.
@@ -552,6 +627,11 @@ cli-cron-usage = Usage:
 cli-cron-jobs-header = 🕒 Scheduled jobs ({$count}):
 cli-cron-list-cmd = {"    "}cmd: {$cmd}
 cli-cron-list-prompt = {"    "}prompt: {$prompt}
+cli-cron-list-notify = {"    "}notify: {$channel} → {$to} (on {$on})
+cli-cron-list-catch-up = {"    "}catch-up: {$policy}
+cli-cron-list-catch-up-default = {"    "}catch-up: {$policy} (scheduler default)
+cli-cron-list-concurrency = {"    "}concurrency: {$policy}
+cli-cron-list-concurrency-running = {"    "}concurrency: {$policy} (running now)
 cli-cron-added-agent = ✅ Added agent cron job {$id}
 cli-cron-added = ✅ Added cron job {$id}
 cli-cron-added-oneshot-agent = ✅ Added one-shot agent cron job {$id}
@@ -559,10 +639,11 @@ cli-cron-added-oneshot = ✅ Added one-shot cron job {$id}
 cli-cron-added-interval-agent = ✅ Added interval agent cron job {$id}
 cli-cron-added-interval = ✅ Added interval cron job {$id}
 cli-cron-updated = ✅ Updated cron job {$id}
-cli-cron-update-no-field = At least one of --expression, --tz, --command, --name, --allowed-tool, or --uses-memory must be provided
+cli-cron-update-no-field = At least one of --expression, --tz, --command, --name, --allowed-tool, --uses-memory, --catch-up, or --concurrency must be provided
 cli-cron-removed = ✅ Removed cron job {$id}
 cli-cron-paused = ⏸️  Paused cron job {$id}
 cli-cron-resumed = ▶️  Resumed cron job {$id}
+cli-cron-natural = 🗓️  Schedule: {$summary} → {$spec}
 cli-cron-expr = {"  "}Expr  : {$v}
 cli-cron-expr2 = {"  "}Expr: {$v}
 cli-cron-next = {"  "}Next  : {$v}
@@ -571,11 +652,43 @@ cli-cron-next3 = {"  "}Next     : {$v}
 cli-cron-prompt = {"  "}Prompt: {$v}
 cli-cron-prompt3 = {"  "}Prompt   : {$v}
 cli-cron-cmd = {"  "}Cmd : {$v}
+cli-cron-notify = {"  "}Notify: {$channel} → {$to} (on {$on})
+cli-cron-catch-up = {"  "}Catch-up: {$v}
+cli-cron-concurrency = {"  "}Concurrency: {$v}
 cli-cron-cmd3 = {"  "}Cmd      : {$v}
 cli-cron-at = {"  "}At    : {$v}
 cli-cron-at2 = {"  "}At  : {$v}
 cli-cron-every = {"  "}Every(ms): {$v}
 
+# ── tasks (zeroclaw tasks) ──
+cli-tasks-none = No background tasks.
+cli-tasks-header = 🧵 Background tasks ({$count}):
+cli-tasks-show-id = {"  "}ID         : {$v}
+cli-tasks-show-status = {"  "}Status     : {$v}
+cli-tasks-show-agent = {"  "}Agent      : {$v}
+cli-tasks-show-description = {"  "}Description: {$v}
+cli-tasks-show-reports-to = {"  "}Reports to : {$v}
+cli-tasks-show-started = {"  "}Started    : {$v}
+cli-tasks-show-finished = {"  "}Finished   : {$v}
+cli-tasks-show-attempts = {"  "}Attempts   : {$v}
+cli-tasks-show-prompt = {"  "}Prompt     : {$v}
+cli-tasks-show-output = Output:
+cli-tasks-cancelled = 🛑 Cancelled background task {$id}
+cli-tasks-cancel-requested = 🛑 Cancellation requested for running task {$id}; the daemon stops it within a few seconds
+
+# ── approvals (zeroclaw approvals) ──
+cli-approvals-none = No tool calls waiting for approval.
+cli-approvals-none-all = No approval requests recorded.
+cli-approvals-header = ⏳ Approval requests ({$count}):
+cli-approvals-row = - {$id} | {$status} | {$tool} | {$origin} | expires={$expires}
+cli-approvals-row-args = {"    "}args   : {$v}
+cli-approvals-row-reasons = {"    "}why    : {$v}
+cli-approvals-row-decided = {"    "}decided: {$by} via {$via}{$reason}
+cli-approvals-resolved = ✅ Approval {$id}: {$status}
+cli-approvals-already = Approval {$id} was already {$status}
+cli-approvals-not-found = No approval request {$id}
+cli-approvals-direct = Gateway unreachable; recorded the decision in the queue directly. The daemon picks it up within a few seconds.
+
 # ── main / status / quickstart / pairing / desktop ──
 cli-no-command = No command provided.
 cli-press-enter = Press Enter to exit...
@@ -589,6 +702,12 @@ cli-quickstart-open-selector-prompt = Open a selector (Enter), or pick Create. E
 cli-quickstart-use-existing = Use existing
 cli-quickstart-create-new = Create new
 cli-quickstart-model-provider-prompt = Model provider
+cli-quickstart-discovered-header = Found existing provider credentials:
+cli-quickstart-discovered-row = {"  "}• {$name}: {$source} ({$key})
+cli-quickstart-auto-selected = Using {$name}, which has a key in {$source}.
+cli-quickstart-using-discovered-key = Using the {$name} key from {$source} ({$key}).
+cli-quickstart-auto-none = No existing provider credentials found; pick a provider manually.
+cli-quickstart-use-discovered-key = Use the {$name} key from {$source} ({$key})?
 cli-quickstart-pick-configured-provider = Pick a configured provider
 cli-quickstart-row-model-provider = {$glyph} Model provider     — {$summary}
 cli-quickstart-row-risk-profile = {$glyph} Risk profile       — {$summary}
@@ -629,6 +748,16 @@ cli-quickstart-risk-profile-prompt = Risk profile
 cli-quickstart-memory-backend-prompt = Memory backend
 cli-quickstart-add-channel = + Add a channel
 cli-quickstart-channels-done = Done (channels selector counts as visited)
+cli-quickstart-public-tunnel = + Expose gateway publicly (Cloudflare Tunnel)
+cli-quickstart-public-tunnel-set = ✓ Gateway exposed publicly at {$url} (select to change)
+cli-quickstart-public-tunnel-hint = {"  "}Needs `cloudflared` on PATH and a tunnel token from Cloudflare Zero Trust → Networks → Tunnels. The tunnel's public hostname is routed to this gateway; the daemon keeps it running.
+cli-quickstart-public-tunnel-token = Cloudflare Tunnel token (empty to cancel)
+cli-quickstart-public-tunnel-starting = {"  "}Starting the tunnel to learn its public URL…
+cli-quickstart-public-tunnel-failed = {"  "}Could not provision the tunnel: {$error}. Enter its public URL manually (or leave empty to set it later with `zeroclaw config set tunnel.public_url <url>`).
+cli-quickstart-public-tunnel-url = Public URL
+cli-quickstart-public-tunnel-url-row = {"  "}🌐 Public URL: {$url}
+cli-quickstart-public-tunnel-whatsapp-row = {"  "}   WhatsApp webhook (callback + verify URL): {$url}
+cli-quickstart-public-tunnel-webhook-row = {"  "}   Generic webhook: {$url}
 cli-quickstart-channels-prompt = Channels (optional, 0..N)
 cli-quickstart-channel-source-prompt = Channel source
 cli-quickstart-all-channels-bound = {"  "}Every configured channel is already bound to an agent. Free one with `zeroclaw config set agents.<alias>.channels ...` before reusing it here.
@@ -651,6 +780,8 @@ cli-quickstart-personality-file-prompt = {$filename}{$position} — what next?{$
 cli-quickstart-next-agent-command = {"  "}zeroclaw agent -a {$alias}  # chat with this agent in your terminal
 cli-quickstart-fix-and-rerun = Your existing config is untouched. Fix the following and run quickstart again:
 cli-quickstart-could-not-finish = quickstart could not finish: {$count} problem(s) to fix
+cli-quickstart-plan-header = Changes:
+cli-quickstart-dry-run = Dry run — nothing was written.
 cli-quickstart-pick-preset = Pick a preset
 cli-quickstart-pick-existing-prompt = Pick an existing {$prompt}
 cli-quickstart-pick-preset-prompt = Pick a {$prompt} preset
@@ -675,6 +806,7 @@ cli-quickstart-error-no-profile = no `{$alias}` profile configured
 cli-quickstart-error-unknown-risk-preset = unknown risk preset `{$preset}`
 cli-quickstart-error-unknown-runtime-preset = unknown runtime preset `{$preset}`
 cli-quickstart-error-channel-bound = channel `{$reference}` is already bound to agent `{$owner}`
+cli-quickstart-error-tunnel-token = a Cloudflare Tunnel token is required to expose the gateway publicly
 cli-quickstart-error-channel-required = channel type and alias are required
 cli-quickstart-error-channel-field-not-advertised = channel field `{$field}` is not available in Quickstart
 cli-quickstart-error-channel-token-required = Telegram bot token is required
@@ -692,6 +824,9 @@ cli-quickstart-error-agent-name-required = agent name is required
 cli-quickstart-error-agent-exists = agent `{$name}` already exists
 cli-no-channels-compiled = {"  "}No channel types are compiled into this binary.
 cli-quickstart-complete = Quickstart complete. Created agent `{$alias}`.
+cli-quickstart-pair-whatsapp-prompt = Link WhatsApp Web channel `whatsapp.{$alias}` now? (scan a QR code with your phone)
+cli-quickstart-pair-whatsapp-later = {"  "}Pair it later with: zeroclaw channel pair whatsapp --alias {$alias}
+cli-quickstart-pair-whatsapp-failed = {"  "}WhatsApp pairing did not finish: {$error}
 cli-next-steps = Next steps:
 cli-agent-not-created = Your agent was not created — and nothing on disk was changed.
 cli-onboard-deprecated = `zeroclaw onboard` is deprecated — use `zeroclaw quickstart`.
@@ -719,6 +854,11 @@ cli-security-status-workspace = Workspace:  {$dir}; workspace-only: {$workspace_
 cli-security-status-credentials = Credentials: encryption: {$encryption}; secrets set: {$secrets_set}/{$secrets_total}; classified fields: {$classified_total}; classes: {$classification_summary}
 cli-security-status-credentials-classes-none = none
 cli-security-status-gateway = Gateway:    {$host}:{$port}; pairing required: {$pairing}; public bind: {$public_bind}; TLS: {$tls}
+cli-security-status-network = Network:    mode: {$mode}; allowed: {$allowed}; blocked: {$blocked}
+cli-security-status-network-domains-none = none
+cli-security-status-tool-policies = Tool policies:
+cli-security-status-tool-policies-none = Tool policies: none
+cli-security-status-tool-policy-row = {"  "}{$tool}: {$permission}{$exceptions}
 cli-security-status-warnings = Warnings:   {$v}
 cli-security-status-warnings-none = Warnings:   none
 cli-security-status-warning-agent-disabled = agent is disabled
@@ -731,6 +871,15 @@ cli-security-status-warning-secrets-unencrypted = config secret encryption is di
 cli-security-status-warning-credential-follow-up = some credential-shaped config surfaces still require follow-up
 cli-security-status-warning-pairing-disabled = gateway pairing is not required
 cli-security-status-warning-public-bind-no-tls = gateway allows public bind without TLS enabled
+cli-security-explain-command = Command:     {$v}
+cli-security-explain-agent = Agent:       {$agent} (risk profile {$profile}, autonomy {$autonomy})
+cli-security-explain-allowlist = Allowlist:   {$v}
+cli-security-explain-allowlist-pass = passes
+cli-security-explain-risk = Risk:        {$v}
+cli-security-explain-segment = Matched in:  {$v}
+cli-security-explain-decision-allowed = Decision:    allowed without approval
+cli-security-explain-decision-approval = Decision:    requires approval ({$reason})
+cli-security-explain-decision-blocked = Decision:    blocked ({$reason})
 cli-status-provider-none = 🤖 ModelProvider:      (none configured)
 cli-status-agents-none = 🛡️  Agents:        (none configured)
 cli-status-service-running = 🟢 Service:       running
@@ -756,7 +905,9 @@ cli-status-agents = 🛡️  Agents:        {$v}
 cli-status-runtime = ⚙️  Runtime:       {$v}
 cli-status-heartbeat = 💓 Heartbeat:      {$v}
 cli-status-heartbeat-every-minutes = every {$minutes}min
+cli-status-watchers = 👀 Watchers:       {$v}
 cli-status-memory = 🧠 Memory:         {$backend} (auto-save: {$auto_save})
+cli-status-feedback = 🗳️  Feedback today: {$positive} 👍 · {$negative} 👎
 cli-status-security-noprofile = Security ({$alias}): <no risk_profile>
 cli-status-security = Security ({$alias}):
 cli-status-workspace-only = {"  "}Workspace only:    {$v}
@@ -782,6 +933,10 @@ cli-status-word-none = (none)
 cli-status-word-configured = configured
 cli-status-word-not-configured = not configured
 cli-status-channel-not-compiled = 🚫 configured, not compiled
+cli-status-channels-draining = {"  "}Drain mode: on ({$in_flight} in flight)
+cli-status-channels-paused = {"  "}Paused:   {$v} ({$queued} queued)
+cli-status-channels-outbox = {"  "}Outbox:   {$v} (pending/dead)
+cli-digest-disabled-hint = Scheduled digests are off; set [observability.digest] enabled = true.
 
 # ── desktop / config / plugins / estop / auth ──
 cli-desktop-not-installed = ZeroClaw companion app is not installed.
@@ -826,6 +981,7 @@ cli-config-secret-unset = {$path} is not set (encrypted secret)
 cli-config-updated = {$path} updated.
 cli-config-review-hint = Run `zeroclaw config list` to review, then set required fields.
 cli-config-backed-up = Backed up to {$path}
+cli-config-valid = No issues found in {$path}
 cli-plugin-name-version = Plugin: {$name} v{$version}
 cli-plugin-description = Description: {$desc}
 cli-plugin-capabilities = Capabilities: {$v}
@@ -875,8 +1031,32 @@ cli-update-success = Successfully updated to v{$version}!
 cli-update-prebuilt-channel-note = Pre-built updates use the lean standard distribution. Build from source with `./install.sh --source --preset full`, `--features channels-full`, or a specific `channel-*` feature for Slack and other channels outside that distribution.
 cli-update-available = Update available: v{$current} -> v{$latest}
 cli-update-forcing-reinstall = Forcing reinstall: v{$current} -> v{$latest}
+cli-update-channel = {"  "}Channel: {$channel}
+cli-update-expected-checksum = {"  "}Expected SHA-256: {$sha256}
+cli-update-backup-kept = Previous binary kept at {$path}; run `zeroclaw update --rollback` to restore it.
+cli-update-rollback-missing = no previous binary to roll back to ({$path} does not exist)
+cli-update-rollback-success = Rolled back to the previous binary ({$version}).
 cli-update-not-writable = install directory {$dir} is not writable ({$error}); re-run `zeroclaw update` with elevated privileges (sudo on macOS/Linux, an Administrator console on Windows)
 
+# ── backup (zeroclaw backup) ──
+cli-backup-created = ✅ Backup written to {$path} ({$count} files)
+cli-backup-secrets-included = {"  "}Secrets are included, encrypted with your passphrase.
+cli-backup-secrets-excluded = {"  "}Secrets (.secret_key, auth profiles) were left out; encrypted values in config.toml need the original .secret_key. Use --include-secrets to archive them.
+cli-backup-plaintext-config-excluded = {"  "}config.toml was left out too: secrets.encrypt is off, so it stores credentials in plain text.
+cli-backup-passphrase = Backup passphrase
+cli-backup-passphrase-confirm = Repeat passphrase
+cli-backup-passphrase-mismatch = Passphrases do not match
+cli-backup-restore-header = Restoring {$archive} (ZeroClaw {$version}, config schema v{$schema}, created {$created})
+cli-backup-restore-create = {"  "}+ {$path}
+cli-backup-restore-overwrite = {"  "}~ {$path} (overwrite)
+cli-backup-restore-unchanged = {"  "}{$count} file(s) already match and are left alone
+cli-backup-restore-dry-run = Dry run complete — nothing was written.
+cli-backup-restore-confirm = Overwrite the files marked ~?
+cli-backup-restore-aborted = Restore cancelled; nothing was written.
+cli-backup-restored = ✅ Restored {$count} file(s)
+cli-backup-config-migrated = Migrated config to schema v{$version} (previous copy at {$path})
+cli-backup-memory-migrated = Memory schema is up to date.
+
 # ── self-test (zeroclaw self-test) ──
 cli-selftest-all-passed = All {$total} checks passed.
 cli-selftest-some-failed = {$failed}/{$total} checks failed.
@@ -886,11 +1066,24 @@ cli-selftest-channel-config-uncompiled = {$compiled} compiled channel types, {$c
 cli-channels-header = Channels:
 cli-channels-cli-always = {"  "}✅ CLI (always available)
 cli-channels-notion = {"  "}{$status} Notion
+cli-channels-webhooks-header = {"  "}Named webhooks (gateway):
+cli-channels-webhook-entry = {"    "}{$status} POST {$route} ({$mode})
 cli-channels-not-compiled-header = {"  "}Configured but not compiled in this binary:
 cli-channels-not-compiled-entry = {"  "}🚫 {$name} (configured, not compiled)
 cli-channels-build-hint = {"  "}Build from source with `./install.sh --source --preset full`, `--features channels-full`, or the specific `channel-*` feature.
 cli-channels-start-hint = To start channels: zeroclaw channel start
 cli-channels-doctor-hint = To check health:    zeroclaw channel doctor
+cli-channel-outbox-none = No undelivered replies in the outbox.
+cli-channel-outbox-none-dead = No dead-lettered replies.
+cli-channel-outbox-header = 📮 Outbox ({$count}):
+cli-channel-outbox-row = - {$id} | {$status} | {$channel} → {$recipient} | attempts={$attempts} | queued={$queued}
+cli-channel-outbox-row-next = {"    "}next : {$v}
+cli-channel-outbox-row-error = {"    "}error: {$v}
+cli-channel-outbox-row-text = {"    "}text : {$v}
+cli-channel-outbox-requeued = ✅ Outbox entry {$id} requeued. A running daemon retries it within a few seconds.
+cli-channel-outbox-already-delivered = Outbox entry {$id} was already delivered
+cli-channel-outbox-not-found = No undelivered outbox entry {$id}
+cli-channel-outbox-dropped = 🗑️  Dropped outbox entry {$id}
 cli-channels-configure-hint = To configure:      zeroclaw config set channels.<name>.<field>=<value>
 
 cli-models-set-ok = Default model set to "{ $model }" on { $provider }.
@@ -935,6 +1128,7 @@ channel-runtime-malformed-tool-output = I generated an internal tool-call format
 channel-runtime-new-session = Conversation history cleared. Starting fresh.
 channel-runtime-stop-sent = Stop signal sent.
 channel-runtime-stop-no-task = No in-flight task for this sender scope.
+channel-runtime-feedback-follow-up = Sorry that wasn't helpful — want me to retry with more detail?
 channel-runtime-model-empty = Model ID cannot be empty. Use `/model <model-id>`.
 channel-runtime-model-switched = Model switched to `{ $model }` (model_provider: `{ $provider }`). Context preserved.
 channel-runtime-agent-scope-rejected = Sender `{ $sender }` is not authorized for `/model --agent` on agent `{ $agent }`. Use `/model --user { $model }` for a session-only override, or ask an admin to mark a peer group `admin_for_agent_scope = true` with you as a member.
@@ -987,6 +1181,19 @@ channel-runtime-thinking-default =
     Thinking is already using agent default `{ $default }` for this sender session.
     Use `/thinking high`, `/thinking max`, or `/thinking off` to override it.
 channel-runtime-thinking-invalid = Unknown thinking level `{ $raw }`. Use `/thinking off|minimal|low|medium|high|max`, `/thinking on`, or `/thinking reset`.
+channel-runtime-persona-none-available = No personas found. Add one as `personas/<name>/SOUL.md` in the workspace.
+channel-runtime-persona-list =
+    Current persona: `{ $current }`.
+    Available personas: { $list }
+    Switch with `/persona <name>`, or go back to the workspace files with `/persona default`.
+channel-runtime-persona-switched = Persona switched to `{ $persona }` for this sender session. Context preserved.
+channel-runtime-persona-reset = Persona reset. Using the workspace SOUL.md and IDENTITY.md for this sender session.
+channel-runtime-persona-unknown = Unknown persona `{ $persona }`. Use `/persona` to list available personas.
+channel-runtime-voice-unavailable = Voice replies are not configured for this agent. Ask the operator to enable `[tts]` and set the agent's `tts_provider`.
+channel-runtime-voice-status-on = Voice replies are on for this sender session. Turn them off with `/voice off`.
+channel-runtime-voice-status-off = Voice replies are off for this sender session. Turn them on with `/voice on`.
+channel-runtime-voice-on = Voice replies on. Replies will arrive as voice notes, or as text when they cannot be voiced.
+channel-runtime-voice-off = Voice replies off. Replies will arrive as text.
 channel-runtime-provider-turn-init-failed =
     ⚠️ Failed to initialize model_provider `{ $provider }`. Please run `/models` to choose another model_provider.
     Details: { $error }
@@ -1058,6 +1265,13 @@ cli-daemon-gateway-port-occupied = Gateway address {$host}:{$port} is already in
 # ── Context window (doctor update-context-windows, agent interactive) ──
 cli-agent-context-bar = ctx: {$used} / {$max}  {$bar}  {$pct}%
 cli-agent-context-bar-unknown = ctx: unknown / {$max}
+
+# ── Checkpointed CLI sessions (zeroclaw agent --resume / --list-sessions) ──
+cli-agent-session-resumed = Resumed session {$id} ({$count} messages restored).
+cli-agent-session-saved = Session saved. Resume with: zeroclaw agent --resume {$id}
+cli-agent-sessions-none = No saved sessions for agent '{$agent}'.
+cli-agent-sessions-header = Saved sessions for agent '{$agent}' (most recent first):
+cli-agent-sessions-row = {"  "}{$id}  {$updated}  {$turns} turns  {$preview}
 cli-doctor-ctxwin-already-set = {$provider_ref}: already has context_window = {$ctx}
 cli-doctor-ctxwin-no-model = {$provider_ref}: no model configured, skipping
 cli-doctor-ctxwin-would-set = {$provider_ref}: would set context_window = {$ctx} (dry run)
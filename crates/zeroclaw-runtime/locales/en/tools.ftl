@@ -7,7 +7,7 @@
 
 tool-backup = Create, list, verify, and restore workspace backups
 
-tool-browser = Web/browser automation with pluggable backends (agent-browser, rust-native, computer_use). Supports DOM actions plus optional OS-level actions (mouse_move, mouse_click, mouse_drag, key_type, key_press, screen_capture) through a computer-use sidecar. Use 'snapshot' to map interactive elements to refs (@e1, @e2). Enforces browser.allowed_domains for open actions.
+tool-browser = Web/browser automation with pluggable backends (agent-browser, rust-native, computer_use). Supports DOM actions plus optional OS-level actions (mouse_move, mouse_click, mouse_drag, key_type, key_press, screen_capture) through a computer-use sidecar. Use 'snapshot' to map interactive elements to refs (@e1, @e2). Enforces browser.allowed_domains for open actions, only fills credential fields on browser.credential_domains, and saves screenshots under the workspace.
 
 tool-browser-delegate = Delegate browser-based tasks to a browser-capable CLI for interacting with web applications like Teams, Outlook, Jira, Confluence
 
@@ -86,7 +86,7 @@ tool-file-download-error-flush = Failed to flush downloaded file: { $err }
 tool-file-download-error-move = Failed to move downloaded file into place: { $err }
 tool-file-download-success = Downloaded { $written } bytes to { $dest_path } ({ $status })
 
-tool-file-read = Read file contents with line numbers. Supports partial reading via offset and limit. Binary and image files are rejected (use the image_info tool for images). Set encoding="base64" to return raw bytes base64-encoded (for binary files such as .pdf/.xlsx/.docx); offset/limit are ignored in that mode.
+tool-file-read = Read file contents with line numbers. Supports partial reading via offset and limit, or scoped reading: symbol extracts one function/class/struct definition (e.g. "parse" or "Parser::parse"), around_line returns the lines around one line, and regex returns the matching lines with surrounding context. Prefer a scoped read for large source files. Binary and image files are rejected (use the image_info tool for images). Set encoding="base64" to return raw bytes base64-encoded (for binary files such as .pdf/.xlsx/.docx); offset/limit and scoped reads are ignored in that mode.
 
 tool-file-write = Write contents to a file in the workspace
 
@@ -115,7 +115,7 @@ tool-hardware-memory-map = Return the memory map (flash and RAM address ranges)
 
 tool-hardware-memory-read = Read actual memory/register values from Nucleo via USB. Use when: user asks to 'read register values', 'read memory at address', 'dump memory', 'lower memory 0-126', or 'give address and value'. Returns hex dump. Requires Nucleo connected via USB and probe feature. Params: address (hex, e.g. 0x20000000 for RAM start), length (bytes, default 128).
 
-tool-http-request = Make HTTP requests to external APIs. Supports GET, POST, PUT, DELETE, PATCH, HEAD, OPTIONS methods. Security constraints: allowlist-only domains, no local/private hosts, configurable timeout and response size limits.
+tool-http-request = Make HTTP requests to external APIs. Supports GET, POST, PUT, DELETE, PATCH, HEAD, OPTIONS methods. Named profiles supply a base URL, headers, and credentials; responses can be narrowed with a jq-style path, reduced from HTML to text, or capped in size. Security constraints: allowlist-only domains, no local/private hosts, configurable timeout and response size limits.
 
 tool-image-info = Read image file metadata (format, dimensions, size) and optionally return base64-encoded data.
 
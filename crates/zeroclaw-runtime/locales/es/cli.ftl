@@ -46,6 +46,10 @@ cli-channel-doctor-about = Ejecuta comprobaciones de estado para los canales con
 cli-channel-add-about = Añade una nueva configuración de canal
 cli-channel-remove-about = Elimina una configuración de canal
 cli-channel-send-about = Envía un mensaje único a un canal configurado
+cli-channel-outbox-about = Revisa las respuestas pendientes de reenvío
+cli-channel-outbox-list-about = Lista las respuestas no entregadas, de la más antigua a la más reciente
+cli-channel-outbox-retry-about = Vuelve a encolar una respuesta pendiente o descartada para entregarla de inmediato
+cli-channel-outbox-drop-about = Elimina una respuesta no entregada
 cli-wechat-pairing-required = 🔐 Se requiere emparejamiento de WeChat. Código de vinculación único: {$code}
 cli-wechat-send-bind-command = Envía `{$command} <code>` desde tu WeChat.
 cli-wechat-qr-login = 📱 Inicio de sesión QR de WeChat ({$attempt}/{$max})
@@ -128,6 +132,7 @@ cli-memory-list-about = Lista entradas de memoria con filtros opcionales
 cli-memory-get-about = Obtiene una entrada de memoria específica por clave
 cli-memory-stats-about = Muestra estadísticas y estado del backend de memoria
 cli-memory-clear-about = Borra memorias por categoría, por clave, o borra todas
+cli-memory-vacuum-about = Compacta la base de datos de memoria y actualiza sus estadísticas de consulta
 cli-memory-clear-unsupported-backend = memory clear no es compatible con el backend de solo anexado '{$backend}'; cambia a un backend con capacidad de eliminación (sqlite, lucid o postgres)
 cli-estop-status-about = Imprimir el estado actual de estop
 cli-estop-resume-about = Reanudar desde un nivel de estop activado
@@ -135,7 +140,9 @@ cli-models-refresh-about = Actualiza y almacena en caché los modelos del provee
 cli-models-list-about = Lista los modelos en caché para un proveedor
 cli-models-set-about = Establece el modelo predeterminado en la configuración
 cli-models-status-about = Muestra la configuración actual del modelo y el estado de la caché
+cli-models-show-about = Muestra la ventana de contexto, el soporte de visión y herramientas, y el precio de un modelo
 cli-doctor-models-about = Sondea catálogos de modelos en todos los proveedores e informa sobre la disponibilidad
+cli-doctor-providers-about = Verifica las claves API de los proveedores e informa de la latencia y la cuota restante
 cli-doctor-traces-about = Consulta eventos de traza en tiempo de ejecución (diagnósticos de herramientas y respuestas de modelos)
 cli-doctor-update-context-windows-about = Actualiza context_window en config.toml desde los endpoints /models del proveedor
 cli-hardware-discover-about = Enumera dispositivos USB y muestra placas conocidas
@@ -401,6 +408,10 @@ cli-peripherals-add-hint = Agregue uno con: zeroclaw peripheral add <board> <pat
 cli-peripherals-add-example = {"  "}Ejemplo: zeroclaw peripheral add nucleo-f401re <serial-path>
 cli-peripherals-config-hint = O agregue a config.toml:
 cli-peripherals-configured = Periféricos configurados:
+cli-peripherals-state-connected = conectado
+cli-peripherals-state-detached = desconectado ({$error})
+cli-peripherals-state-no-session = sin sesión activa
+cli-peripherals-spec-invalid = Valor de --peripheral no válido "{$spec}". Usa <placa>:<ruta>, p. ej. nucleo-f401re:/dev/ttyACM0, o auto.
 cli-peripherals-already-configured = La placa {$board} en {$path} ya está configurada.
 cli-peripherals-added = Se agregó {$board} en {$path}. Reinicie el daemon para aplicar.
 cli-peripherals-flash-needs-hardware = El flasheo de Arduino requiere la característica 'hardware'.
@@ -449,6 +460,8 @@ cli-sop-triggers = {"  "}Disparadores:
 cli-sop-steps = {"  "}Pasos:
 cli-sop-step-tools = Herramientas: {$tools}
 cli-memory-reindexing = Reindexando el backend de memoria...
+cli-memory-vacuuming = Compactando la base de datos de memoria...
+cli-memory-vacuumed = Base de datos de memoria compactada; se recuperaron {$kib} KiB.
 cli-memory-none = No se encontraron entradas de memoria.
 cli-memory-none-at-offset = No hay entradas en el desplazamiento {$offset} (total: {$total}).
 cli-memory-next-page = Use --offset {$offset} para ver la página siguiente.
@@ -569,6 +582,8 @@ cli-quickstart-personality-file-prompt = {$filename}{$position} — ¿qué sigue
 cli-quickstart-next-agent-command = {"  "}zeroclaw agent -a {$alias}  # chatea con este agente en tu terminal
 cli-quickstart-fix-and-rerun = Tu configuración existente no se modificó. Corrige lo siguiente y vuelve a ejecutar quickstart:
 cli-quickstart-could-not-finish = quickstart no pudo terminar: {$count} problema(s) por corregir
+cli-quickstart-plan-header = Cambios:
+cli-quickstart-dry-run = Simulación: no se escribió nada.
 cli-quickstart-pick-preset = Elegir un preset
 cli-quickstart-pick-existing-prompt = Elegir un {$prompt} existente
 cli-quickstart-pick-preset-prompt = Elegir un preset de {$prompt}
@@ -610,6 +625,9 @@ cli-quickstart-error-agent-name-required = se requiere el nombre del agente
 cli-quickstart-error-agent-exists = el agente `{$name}` ya existe
 cli-no-channels-compiled = {"  "}No hay tipos de canal compilados en este binario.
 cli-quickstart-complete = Quickstart completado. Se creó el agente `{$alias}`.
+cli-quickstart-pair-whatsapp-prompt = ¿Vincular ahora el canal de WhatsApp Web `whatsapp.{$alias}`? (escanea un código QR con tu teléfono)
+cli-quickstart-pair-whatsapp-later = {"  "}Vincúlalo más tarde con: zeroclaw channel pair whatsapp --alias {$alias}
+cli-quickstart-pair-whatsapp-failed = {"  "}La vinculación de WhatsApp no terminó: {$error}
 cli-next-steps = Siguientes pasos:
 cli-agent-not-created = Tu agente no fue creado — y no se cambió nada en el disco.
 cli-onboard-deprecated = `zeroclaw onboard` está obsoleto — usa `zeroclaw quickstart`.
@@ -673,6 +691,7 @@ cli-status-runtime = ⚙️  Entorno de ejecución:       {$v}
 cli-status-heartbeat = 💓 Latido:      {$v}
 cli-status-heartbeat-every-minutes = cada {$minutes}min
 cli-status-memory = 🧠 Memoria:         {$backend} (autoguardado: {$auto_save})
+cli-status-feedback = 🗳️  Valoraciones hoy: {$positive} 👍 · {$negative} 👎
 cli-status-security-noprofile = Seguridad ({$alias}): <sin risk_profile>
 cli-status-security = Seguridad ({$alias}):
 cli-status-workspace-only = {"  "}Solo espacio de trabajo:    {$v}
@@ -698,6 +717,10 @@ cli-status-word-none = (ninguno)
 cli-status-word-configured = configurado
 cli-status-word-not-configured = no configurado
 cli-status-channel-not-compiled = 🚫 configurado, no compilado
+cli-status-channels-draining = {"  "}Modo de vaciado: activo ({$in_flight} en curso)
+cli-status-channels-paused = {"  "}En pausa: {$v} ({$queued} en cola)
+cli-status-channels-outbox = {"  "}Bandeja de salida: {$v} (pendientes/descartadas)
+cli-digest-disabled-hint = Los resúmenes programados están desactivados; configura [observability.digest] enabled = true.
 cli-desktop-not-installed = La aplicación complementaria de ZeroClaw no está instalada.
 cli-desktop-blurb1 = La aplicación complementaria es una ligera app de la barra de menú que
 cli-desktop-blurb2 = se conecta a la misma puerta de enlace que la CLI.
@@ -786,11 +809,24 @@ cli-selftest-channel-config-uncompiled = {$compiled} tipos de canal compilados,
 cli-channels-header = Canales:
 cli-channels-cli-always = {"  "}✅ CLI (siempre disponible)
 cli-channels-notion = {"  "}{$status} Notion
+cli-channels-webhooks-header = {"  "}Webhooks con nombre (gateway):
+cli-channels-webhook-entry = {"    "}{$status} POST {$route} ({$mode})
 cli-channels-not-compiled-header = {"  "}Configurados pero no compilados en este binario:
 cli-channels-not-compiled-entry = {"  "}🚫 {$name} (configurado, no compilado)
 cli-channels-build-hint = {"  "}Compila desde el código fuente con `./install.sh --source --preset full`, `--features channels-full` o la característica `channel-*` específica.
 cli-channels-start-hint = Para iniciar canales: zeroclaw channel start
 cli-channels-doctor-hint = Para comprobar el estado:    zeroclaw channel doctor
+cli-channel-outbox-none = No hay respuestas sin entregar en la bandeja de salida.
+cli-channel-outbox-none-dead = No hay respuestas descartadas.
+cli-channel-outbox-header = 📮 Bandeja de salida ({$count}):
+cli-channel-outbox-row = - {$id} | {$status} | {$channel} → {$recipient} | intentos={$attempts} | encolada={$queued}
+cli-channel-outbox-row-next = {"    "}siguiente: {$v}
+cli-channel-outbox-row-error = {"    "}error    : {$v}
+cli-channel-outbox-row-text = {"    "}texto    : {$v}
+cli-channel-outbox-requeued = ✅ Entrada {$id} encolada de nuevo. El daemon en ejecución la reintenta en unos segundos.
+cli-channel-outbox-already-delivered = La entrada {$id} ya se entregó
+cli-channel-outbox-not-found = No hay ninguna entrada sin entregar con id {$id}
+cli-channel-outbox-dropped = 🗑️  Entrada {$id} eliminada
 cli-channels-configure-hint = Para configurar:      zeroclaw config set channels.<name>.<field>=<value>
 cli-models-set-ok = Modelo predeterminado establecido en "{ $model }" en { $provider }.
 cli-models-status-current = Modelo predeterminado: { $model } (proveedor: { $provider })
@@ -809,6 +845,7 @@ channel-runtime-malformed-tool-output = Generé un error de formato interno en l
 channel-runtime-new-session = Historial de conversación borrado. Empezando de nuevo.
 channel-runtime-stop-sent = Señal de detención enviada.
 channel-runtime-stop-no-task = No hay una tarea en curso para este ámbito de remitente.
+channel-runtime-feedback-follow-up = Siento que no haya sido útil. ¿Quieres que lo intente de nuevo con más detalle?
 channel-runtime-model-empty = El ID del modelo no puede estar vacío. Usa `/model <model-id>`.
 channel-runtime-model-switched = Modelo cambiado a `{ $model }` (model_provider: `{ $provider }`). Contexto conservado.
 channel-runtime-agent-scope-rejected = El remitente `{ $sender }` no está autorizado para `/model --agent` en el agente `{ $agent }`. Usa `/model --user { $model }` para una anulación solo de la sesión, o pide a un administrador que marque un grupo de pares con `admin_for_agent_scope = true` contigo como miembro.
@@ -861,6 +898,19 @@ channel-runtime-thinking-default =
     Thinking ya está usando el valor predeterminado del agente `{ $default }` para esta sesión del remitente.
     Usa `/thinking high`, `/thinking max` o `/thinking off` para sobrescribirlo.
 channel-runtime-thinking-invalid = Nivel de thinking desconocido `{ $raw }`. Usa `/thinking off|minimal|low|medium|high|max`, `/thinking on` o `/thinking reset`.
+channel-runtime-persona-none-available = No se encontraron personas. Añade una como `personas/<name>/SOUL.md` en el workspace.
+channel-runtime-persona-list =
+    Persona actual: `{ $current }`.
+    Personas disponibles: { $list }
+    Cambia con `/persona <name>`, o vuelve a los archivos del workspace con `/persona default`.
+channel-runtime-persona-switched = Persona cambiada a `{ $persona }` para esta sesión del remitente. Contexto conservado.
+channel-runtime-persona-reset = Persona restablecida. Se usan SOUL.md e IDENTITY.md del workspace para esta sesión del remitente.
+channel-runtime-persona-unknown = Persona desconocida `{ $persona }`. Usa `/persona` para listar las personas disponibles.
+channel-runtime-voice-unavailable = Las respuestas de voz no están configuradas para este agente. Pide al operador que active `[tts]` y defina el `tts_provider` del agente.
+channel-runtime-voice-status-on = Las respuestas de voz están activadas para esta sesión del remitente. Desactívalas con `/voice off`.
+channel-runtime-voice-status-off = Las respuestas de voz están desactivadas para esta sesión del remitente. Actívalas con `/voice on`.
+channel-runtime-voice-on = Respuestas de voz activadas. Las respuestas llegarán como notas de voz, o como texto cuando no se puedan sintetizar.
+channel-runtime-voice-off = Respuestas de voz desactivadas. Las respuestas llegarán como texto.
 channel-runtime-provider-turn-init-failed =
     ⚠️ Error al inicializar model_provider `{ $provider }`. Ejecuta `/models` para elegir otro model_provider.
     Detalles: { $error }
@@ -934,6 +984,14 @@ cli-doctor-ctxwin-write-failed = {$provider_ref}: error al escribir context_wind
 # ── Degraded config sections (doctor diagnose, #8835) ──
 cli-doctor-degraded-security = Sección de configuración CRÍTICA PARA LA SEGURIDAD `{$path}` no es válida y se restableció a sus valores predeterminados para que el daemon pueda arrancar; la postura en ejecución puede ser MÁS DÉBIL de lo previsto. Ejecute `zeroclaw config migrate` para ver el error de análisis y luego repare el archivo.
 cli-doctor-degraded-section = La sección de configuración `{$path}` está mal formada y se restableció a sus valores predeterminados; los valores de esa sección NO están en efecto. Ejecute `zeroclaw config migrate` para ver el error de análisis y luego repare el archivo.
+cli-doctor-integration-ok = {$name}: {$detail}
+cli-doctor-integration-pending = {$name}: las credenciales funcionan, pero la cuenta aún no está conectada. Ejecute `zeroclaw integrations setup {$slug}` para obtener el enlace de conexión.
+cli-doctor-integration-failed = {$name}: falló la comprobación de credenciales: {$error}. Ejecute `zeroclaw integrations setup {$slug}` para corregirlo.
+cli-doctor-provider-fix-key = define `{ $config_key }` en config.toml o ejecuta `zeroclaw quickstart`
+cli-doctor-provider-fix-key-env = define `{ $config_key }` en config.toml o exporta `{ $env_var }`
+cli-doctor-provider-fix-quota = añade créditos o sube el límite de gasto de la cuenta, o define `{ $config_key }` con una clave que tenga cuota
+cli-doctor-provider-fix-rate-limited = la clave funciona pero está limitada por tasa; reinténtalo más tarde
+cli-doctor-provider-fix-network = comprueba que el endpoint del proveedor es accesible (`{ $uri_key }` lo sustituye; proxy: { $proxy })
 sop-approval-deferred-at-capacity = No se pudo reanudar la ejecución {$run_id}: los cupos de ejecución están llenos. La aprobación sigue en espera; inténtalo de nuevo cuando se libere un cupo.
 sop-approval-policy-unavailable = La aprobación falló porque el paso de SOP en espera no está disponible: {$reason}. La ejecución sigue en espera.
 sop-rpc-decision-invalid-state = La ejecución {$run_id} no se puede resolver en su estado actual.
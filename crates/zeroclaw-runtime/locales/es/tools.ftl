@@ -1,5 +1,5 @@
 tool-backup = Crear, listar, verificar y restaurar copias de seguridad del espacio de trabajo
-tool-browser = Automatización web/navegador con backends conectables (agent-browser, rust-native, computer_use). Admite acciones DOM más acciones opcionales a nivel de SO (mouse_move, mouse_click, mouse_drag, key_type, key_press, screen_capture) a través de un sidecar de uso de computadora. Use 'snapshot' para mapear elementos interactivos a refs (@e1, @e2). Aplica browser.allowed_domains para acciones de apertura.
+tool-browser = Automatización web/navegador con backends conectables (agent-browser, rust-native, computer_use). Admite acciones DOM más acciones opcionales a nivel de SO (mouse_move, mouse_click, mouse_drag, key_type, key_press, screen_capture) a través de un sidecar de uso de computadora. Use 'snapshot' para mapear elementos interactivos a refs (@e1, @e2). Aplica browser.allowed_domains para acciones de apertura, solo rellena campos de credenciales en browser.credential_domains y guarda las capturas de pantalla en el espacio de trabajo.
 tool-browser-delegate = Delegar tareas basadas en navegador a una CLI con capacidad de navegador para interactuar con aplicaciones web como Teams, Outlook, Jira, Confluence
 tool-browser-open = Abrir una URL HTTPS aprobada en el navegador del sistema. Restricciones de seguridad: solo dominios de la lista de permitidos, sin hosts locales/privados, sin scraping.
 tool-channel-room = Crea salas e invita usuarios a través de un canal activo. Proporciona una clave de canal como 'matrix.default', la acción 'create_room' o 'invite_user', y los campos de sala específicos de la acción.
@@ -60,7 +60,7 @@ tool-file-download-error-write-body = Error al escribir los bytes descargados: {
 tool-file-download-error-flush = No se pudo vaciar el archivo descargado: { $err }
 tool-file-download-error-move = No se pudo mover el archivo descargado a su lugar: { $err }
 tool-file-download-success = Se descargaron { $written } bytes en { $dest_path } ({ $status })
-tool-file-read = Leer el contenido de un archivo con números de línea. Admite lectura parcial mediante offset y limit. Los archivos binarios e imágenes se rechazan (use la herramienta image_info para imágenes). Establezca encoding="base64" para devolver bytes en bruto codificados en base64 (para archivos binarios como .pdf/.xlsx/.docx); offset/limit se ignoran en ese modo.
+tool-file-read = Leer el contenido de un archivo con números de línea. Admite lectura parcial mediante offset y limit, o lectura acotada: symbol extrae la definición de una función/clase/struct (p. ej. "parse" o "Parser::parse"), around_line devuelve las líneas alrededor de una línea y regex devuelve las líneas coincidentes con su contexto. Prefiera una lectura acotada para archivos de código grandes. Los archivos binarios e imágenes se rechazan (use la herramienta image_info para imágenes). Establezca encoding="base64" para devolver bytes en bruto codificados en base64 (para archivos binarios como .pdf/.xlsx/.docx); offset/limit y las lecturas acotadas se ignoran en ese modo.
 tool-file-write = Escribir contenido en un archivo del espacio de trabajo
 tool-git-operations = Realizar operaciones Git estructuradas (status, diff, log, branch, commit, add, checkout, stash). Proporciona salida JSON analizada e integra con la política de seguridad para controles de autonomía.
 tool-git-operations-error-not-in-repo = No se encuentra en un repositorio Git en '{ $path }'. Elija una ruta dentro de un árbol de trabajo Git, pase 'path' para un subdirectorio del repositorio, o inicialice un repositorio antes de ejecutar git_operations.
@@ -46,6 +46,10 @@ cli-channel-doctor-about = Exécuter des vérifications de santé pour les canau
 cli-channel-add-about = Ajouter une nouvelle configuration de canal
 cli-channel-remove-about = Supprimer une configuration de canal
 cli-channel-send-about = Envoyer un message ponctuel à un canal configuré
+cli-channel-outbox-about = Inspecter les réponses en attente de réémission
+cli-channel-outbox-list-about = Lister les réponses non distribuées, de la plus ancienne à la plus récente
+cli-channel-outbox-retry-about = Remettre en file une réponse en attente ou abandonnée pour un envoi immédiat
+cli-channel-outbox-drop-about = Supprimer une réponse non distribuée
 cli-wechat-pairing-required = 🔐 Appairage WeChat requis. Code de liaison à usage unique : {$code}
 cli-wechat-send-bind-command = Envoyez `{$command} <code>` depuis votre WeChat.
 cli-wechat-qr-login = 📱 Connexion QR WeChat ({$attempt}/{$max})
@@ -128,6 +132,7 @@ cli-memory-list-about = Lister les entrées de mémoire avec des filtres optionn
 cli-memory-get-about = Obtenir une entrée de mémoire spécifique par clé
 cli-memory-stats-about = Afficher les statistiques et l'état de santé du backend mémoire
 cli-memory-clear-about = Effacer les mémoires par catégorie, par clé, ou tout effacer
+cli-memory-vacuum-about = Compacter la base de données mémoire et actualiser ses statistiques de requête
 cli-memory-clear-unsupported-backend = memory clear n'est pas pris en charge pour le backend en ajout seul '{$backend}' ; passez à un backend supprimable (sqlite, lucid ou postgres)
 cli-estop-status-about = Imprimer le statut actuel d'arrêt d'urgence
 cli-estop-resume-about = Reprendre depuis un niveau d'arrêt d'urgence engagé
@@ -135,7 +140,9 @@ cli-models-refresh-about = Actualiser et mettre en cache les modèles du fournis
 cli-models-list-about = Lister les modèles mis en cache pour un fournisseur
 cli-models-set-about = Définir le modèle par défaut dans la configuration
 cli-models-status-about = Afficher la configuration actuelle du modèle et l'état du cache
+cli-models-show-about = Afficher la fenêtre de contexte, la prise en charge de la vision et des outils, et le tarif d'un modèle
 cli-doctor-models-about = Sonder les catalogues de modèles à travers les fournisseurs et signaler la disponibilité
+cli-doctor-providers-about = Vérifier les clés API des fournisseurs et signaler la latence et le quota restant
 cli-doctor-traces-about = Interroger les événements de trace d'exécution (diagnostics d'outils et réponses de modèle)
 cli-doctor-update-context-windows-about = Mettre à jour context_window dans config.toml depuis les endpoints /models du fournisseur
 cli-hardware-discover-about = Énumérer les dispositifs USB et afficher les cartes connues
@@ -404,6 +411,10 @@ cli-peripherals-add-hint = Ajoutez-en un avec : zeroclaw peripheral add <board>
 cli-peripherals-add-example = {"  "}Exemple : zeroclaw peripheral add nucleo-f401re <serial-path>
 cli-peripherals-config-hint = Ou ajoutez à config.toml :
 cli-peripherals-configured = Périphériques configurés :
+cli-peripherals-state-connected = connecté
+cli-peripherals-state-detached = détaché ({$error})
+cli-peripherals-state-no-session = aucune session active
+cli-peripherals-spec-invalid = Valeur --peripheral invalide « {$spec} ». Utilisez <carte>:<chemin>, par ex. nucleo-f401re:/dev/ttyACM0, ou auto.
 cli-peripherals-already-configured = La carte {$board} à {$path} est déjà configurée.
 cli-peripherals-added = {$board} ajouté à {$path}. Redémarrez le démon pour appliquer.
 cli-peripherals-flash-needs-hardware = Le flash Arduino nécessite la fonctionnalité « hardware ».
@@ -452,6 +463,8 @@ cli-sop-triggers = {"  "}Déclencheurs :
 cli-sop-steps = {"  "}Étapes :
 cli-sop-step-tools = Outils : {$tools}
 cli-memory-reindexing = Réindexation du backend mémoire...
+cli-memory-vacuuming = Compactage de la base de données mémoire...
+cli-memory-vacuumed = Base de données mémoire compactée ; {$kib} Kio récupérés.
 cli-memory-none = Aucune entrée mémoire trouvée.
 cli-memory-none-at-offset = Aucune entrée à la position {$offset} (total : {$total}).
 cli-memory-next-page = Utilisez --offset {$offset} pour voir la page suivante.
@@ -572,6 +585,8 @@ cli-quickstart-personality-file-prompt = {$filename}{$position} — suite ?{$bac
 cli-quickstart-next-agent-command = {"  "}zeroclaw agent -a {$alias}  # discuter avec cet agent dans le terminal
 cli-quickstart-fix-and-rerun = Votre configuration existante est inchangée. Corrigez ce qui suit puis relancez quickstart :
 cli-quickstart-could-not-finish = quickstart n'a pas pu se terminer : {$count} problème(s) à corriger
+cli-quickstart-plan-header = Modifications :
+cli-quickstart-dry-run = Simulation : rien n'a été écrit.
 cli-quickstart-pick-preset = Choisir un preset
 cli-quickstart-pick-existing-prompt = Choisir un {$prompt} existant
 cli-quickstart-pick-preset-prompt = Choisir un preset {$prompt}
@@ -613,6 +628,9 @@ cli-quickstart-error-agent-name-required = le nom de l'agent est requis
 cli-quickstart-error-agent-exists = l'agent `{$name}` existe déjà
 cli-no-channels-compiled = {"  "}Aucun type de canal n'est compilé dans ce binaire.
 cli-quickstart-complete = Quickstart terminé. Agent `{$alias}` créé.
+cli-quickstart-pair-whatsapp-prompt = Associer maintenant le canal WhatsApp Web `whatsapp.{$alias}` ? (scannez un code QR avec votre téléphone)
+cli-quickstart-pair-whatsapp-later = {"  "}Associez-le plus tard avec : zeroclaw channel pair whatsapp --alias {$alias}
+cli-quickstart-pair-whatsapp-failed = {"  "}L'association WhatsApp n'a pas abouti : {$error}
 cli-next-steps = Étapes suivantes :
 cli-agent-not-created = Votre agent n'a pas été créé — et rien n'a été modifié sur le disque.
 cli-onboard-deprecated = `zeroclaw onboard` est obsolète — utilisez `zeroclaw quickstart`.
@@ -676,6 +694,7 @@ cli-status-runtime = ⚙️  Runtime :       {$v}
 cli-status-heartbeat = 💓 Battement de cœur :      {$v}
 cli-status-heartbeat-every-minutes = toutes les {$minutes}min
 cli-status-memory = 🧠 Mémoire :         {$backend} (sauvegarde auto : {$auto_save})
+cli-status-feedback = 🗳️  Avis du jour : {$positive} 👍 · {$negative} 👎
 cli-status-security-noprofile = Sécurité ({$alias}) : <aucun risk_profile>
 cli-status-security = Sécurité ({$alias}) :
 cli-status-workspace-only = {"  "}Espace de travail uniquement :    {$v}
@@ -701,6 +720,10 @@ cli-status-word-none = (aucun)
 cli-status-word-configured = configuré
 cli-status-word-not-configured = non configuré
 cli-status-channel-not-compiled = 🚫 configuré, non compilé
+cli-status-channels-draining = {"  "}Mode drainage : actif ({$in_flight} en cours)
+cli-status-channels-paused = {"  "}En pause : {$v} ({$queued} en attente)
+cli-status-channels-outbox = {"  "}File d'envoi : {$v} (en attente/abandonnées)
+cli-digest-disabled-hint = Les résumés planifiés sont désactivés ; définissez [observability.digest] enabled = true.
 cli-desktop-not-installed = L'application compagnon ZeroClaw n'est pas installée.
 cli-desktop-blurb1 = L'application compagnon est une application légère de barre de menus qui
 cli-desktop-blurb2 = se connecte à la même passerelle que la CLI.
@@ -789,11 +812,24 @@ cli-selftest-channel-config-uncompiled = { $compiled } types de canaux compilés
 cli-channels-header = Canaux :
 cli-channels-cli-always = {"  "}✅ CLI (toujours disponible)
 cli-channels-notion = {"  "}{ $status } Notion
+cli-channels-webhooks-header = {"  "}Webhooks nommés (passerelle) :
+cli-channels-webhook-entry = {"    "}{ $status } POST { $route } ({ $mode })
 cli-channels-not-compiled-header = {"  "}Configurés mais non compilés dans ce binaire :
 cli-channels-not-compiled-entry = {"  "}🚫 {$name} (configuré, non compilé)
 cli-channels-build-hint = {"  "}Compilez depuis les sources avec `./install.sh --source --preset full`, `--features channels-full` ou la fonctionnalité `channel-*` spécifique.
 cli-channels-start-hint = Pour démarrer les canaux : zeroclaw channel start
 cli-channels-doctor-hint = Pour vérifier l'état :    zeroclaw channel doctor
+cli-channel-outbox-none = Aucune réponse non distribuée dans la file d'envoi.
+cli-channel-outbox-none-dead = Aucune réponse abandonnée.
+cli-channel-outbox-header = 📮 File d'envoi ({$count}) :
+cli-channel-outbox-row = - {$id} | {$status} | {$channel} → {$recipient} | tentatives={$attempts} | en file depuis={$queued}
+cli-channel-outbox-row-next = {"    "}prochain : {$v}
+cli-channel-outbox-row-error = {"    "}erreur   : {$v}
+cli-channel-outbox-row-text = {"    "}texte    : {$v}
+cli-channel-outbox-requeued = ✅ Entrée {$id} remise en file. Le daemon en cours la réessaie d'ici quelques secondes.
+cli-channel-outbox-already-delivered = L'entrée {$id} a déjà été distribuée
+cli-channel-outbox-not-found = Aucune entrée non distribuée {$id}
+cli-channel-outbox-dropped = 🗑️  Entrée {$id} supprimée
 cli-channels-configure-hint = Pour configurer :      zeroclaw config set channels.<name>.<field>=<value>
 cli-models-set-ok = Modèle par défaut défini sur « { $model } » sur { $provider }.
 cli-models-status-current = Modèle par défaut : { $model } (fournisseur : { $provider })
@@ -812,6 +848,7 @@ channel-runtime-malformed-tool-output = J'ai généré une erreur de format d'ap
 channel-runtime-new-session = Historique de conversation effacé. Nouveau départ.
 channel-runtime-stop-sent = Signal d'arrêt envoyé.
 channel-runtime-stop-no-task = Aucune tâche en cours pour ce périmètre d'expéditeur.
+channel-runtime-feedback-follow-up = Désolé que ce ne soit pas utile — voulez-vous que je réessaie avec plus de détails ?
 channel-runtime-model-empty = L'ID du modèle ne peut pas être vide. Utilisez `/model <model-id>`.
 channel-runtime-model-switched = Modèle changé vers `{ $model }` (model_provider : `{ $provider }`). Contexte conservé.
 channel-runtime-agent-scope-rejected = L'expéditeur `{ $sender }` n'est pas autorisé à utiliser `/model --agent` sur l'agent `{ $agent }`. Utilisez `/model --user { $model }` pour un remplacement limité à la session, ou demandez à un administrateur de marquer un groupe de pairs `admin_for_agent_scope = true` avec vous comme membre.
@@ -864,6 +901,19 @@ channel-runtime-thinking-default =
     Thinking utilise déjà la valeur par défaut de l'agent `{ $default }` pour cette session d'expéditeur.
     Utilisez `/thinking high`, `/thinking max` ou `/thinking off` pour le surcharger.
 channel-runtime-thinking-invalid = Niveau de thinking inconnu `{ $raw }`. Utilisez `/thinking off|minimal|low|medium|high|max`, `/thinking on` ou `/thinking reset`.
+channel-runtime-persona-none-available = Aucune persona trouvée. Ajoutez-en une sous `personas/<name>/SOUL.md` dans le workspace.
+channel-runtime-persona-list =
+    Persona actuelle : `{ $current }`.
+    Personas disponibles : { $list }
+    Changez avec `/persona <name>`, ou revenez aux fichiers du workspace avec `/persona default`.
+channel-runtime-persona-switched = Persona changée pour `{ $persona }` pour cette session d'expéditeur. Contexte conservé.
+channel-runtime-persona-reset = Persona réinitialisée. Les fichiers SOUL.md et IDENTITY.md du workspace sont utilisés pour cette session d'expéditeur.
+channel-runtime-persona-unknown = Persona inconnue `{ $persona }`. Utilisez `/persona` pour lister les personas disponibles.
+channel-runtime-voice-unavailable = Les réponses vocales ne sont pas configurées pour cet agent. Demandez à l'opérateur d'activer `[tts]` et de définir le `tts_provider` de l'agent.
+channel-runtime-voice-status-on = Les réponses vocales sont activées pour cette session d'expéditeur. Désactivez-les avec `/voice off`.
+channel-runtime-voice-status-off = Les réponses vocales sont désactivées pour cette session d'expéditeur. Activez-les avec `/voice on`.
+channel-runtime-voice-on = Réponses vocales activées. Les réponses arriveront en notes vocales, ou en texte lorsqu'elles ne peuvent pas être synthétisées.
+channel-runtime-voice-off = Réponses vocales désactivées. Les réponses arriveront en texte.
 channel-runtime-provider-turn-init-failed =
     ⚠️ Échec de l'initialisation de model_provider `{ $provider }`. Exécutez `/models` pour choisir un autre model_provider.
     Détails : { $error }
@@ -937,6 +987,14 @@ cli-doctor-ctxwin-write-failed = {$provider_ref}: échec de l'écriture de conte
 # ── Degraded config sections (doctor diagnose, #8835) ──
 cli-doctor-degraded-security = La section de configuration CRITIQUE POUR LA SÉCURITÉ `{$path}` est invalide et a été réinitialisée à sa valeur par défaut pour permettre au daemon de démarrer ; la posture en cours d'exécution peut être PLUS FAIBLE que prévu. Exécutez `zeroclaw config migrate` pour voir l'erreur d'analyse, puis réparez le fichier.
 cli-doctor-degraded-section = La section de configuration `{$path}` est malformée et a été réinitialisée aux valeurs par défaut ; les valeurs de cette section ne sont PAS en vigueur. Exécutez `zeroclaw config migrate` pour voir l'erreur d'analyse, puis réparez le fichier.
+cli-doctor-integration-ok = {$name} : {$detail}
+cli-doctor-integration-pending = {$name} : les identifiants fonctionnent, mais le compte n'est pas encore connecté. Exécutez `zeroclaw integrations setup {$slug}` pour obtenir le lien de connexion.
+cli-doctor-integration-failed = {$name} : échec de la vérification des identifiants : {$error}. Exécutez `zeroclaw integrations setup {$slug}` pour corriger.
+cli-doctor-provider-fix-key = définissez `{ $config_key }` dans config.toml ou lancez `zeroclaw quickstart`
+cli-doctor-provider-fix-key-env = définissez `{ $config_key }` dans config.toml ou exportez `{ $env_var }`
+cli-doctor-provider-fix-quota = ajoutez des crédits ou relevez la limite de dépenses du compte, ou définissez `{ $config_key }` avec une clé disposant de quota
+cli-doctor-provider-fix-rate-limited = la clé fonctionne mais subit une limitation de débit ; réessayez plus tard
+cli-doctor-provider-fix-network = vérifiez que l'endpoint du fournisseur est joignable (`{ $uri_key }` le remplace ; proxy : { $proxy })
 sop-approval-deferred-at-capacity = Impossible de reprendre l’exécution {$run_id} : tous les créneaux d’exécution sont occupés. L’approbation reste en attente ; réessayez lorsqu’un créneau se libère.
 sop-approval-policy-unavailable = L’approbation a échoué car l’étape SOP en attente est indisponible : {$reason}. L’exécution reste en attente.
 sop-rpc-decision-invalid-state = L’exécution {$run_id} ne peut pas être résolue dans son état actuel.
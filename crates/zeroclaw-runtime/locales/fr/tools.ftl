@@ -1,5 +1,5 @@
 tool-backup = Créer, répertorier, vérifier et restaurer les sauvegardes de l'espace de travail
-tool-browser = Automatisation de navigateur avec des backends plugables (agent-browser, rust-native, computer_use). Prend en charge les actions DOM et des actions au niveau du système d'exploitation (mouse_move, mouse_click, mouse_drag, key_type, key_press, screen_capture) via un sidecar d'utilisation de l'ordinateur. Utilisez 'snapshot' pour mapper les éléments interactifs aux refs (@e1, @e2). Applique browser.allowed_domains pour les actions d'ouverture.
+tool-browser = Automatisation de navigateur avec des backends plugables (agent-browser, rust-native, computer_use). Prend en charge les actions DOM et des actions au niveau du système d'exploitation (mouse_move, mouse_click, mouse_drag, key_type, key_press, screen_capture) via un sidecar d'utilisation de l'ordinateur. Utilisez 'snapshot' pour mapper les éléments interactifs aux refs (@e1, @e2). Applique browser.allowed_domains pour les actions d'ouverture, ne remplit les champs d'identifiants que sur browser.credential_domains et enregistre les captures d'écran dans l'espace de travail.
 tool-browser-delegate = Déférer les tâches de navigateur vers un outil de ligne de commande compatible navigateur pour interagir avec des applications web comme Teams, Outlook, Jira, Confluence
 tool-browser-open = Ouvrir une URL HTTPS approuvée dans le navigateur du système. Contraintes de sécurité : whitelist de domaines uniquement, aucun hôte local/privé, aucun scraping.
 tool-channel-room = Créez des salons et invitez des utilisateurs via un canal actif. Fournissez une clé de canal telle que 'matrix.default', l'action 'create_room' ou 'invite_user', et les champs de salon spécifiques à l'action.
@@ -60,7 +60,7 @@ tool-file-download-error-write-body = Échec lors de l'écriture des octets tél
 tool-file-download-error-flush = Échec du vidage du fichier téléchargé : { $err }
 tool-file-download-error-move = Échec du déplacement du fichier téléchargé à sa place : { $err }
 tool-file-download-success = { $written } octets téléchargés vers { $dest_path } ({ $status })
-tool-file-read = Lire le contenu du fichier avec les numéros de ligne. Prise en charge de la lecture partielle via offset et limite. Les fichiers binaires et images sont rejetés (utilisez l'outil image_info pour les images). Définissez encoding="base64" pour renvoyer les octets bruts encodés en base64 (pour les fichiers binaires tels que .pdf/.xlsx/.docx) ; offset/limit sont ignorés dans ce mode.
+tool-file-read = Lire le contenu du fichier avec les numéros de ligne. Prise en charge de la lecture partielle via offset et limite, ou de la lecture ciblée : symbol extrait la définition d'une fonction/classe/struct (par ex. "parse" ou "Parser::parse"), around_line renvoie les lignes autour d'une ligne et regex renvoie les lignes correspondantes avec leur contexte. Préférez une lecture ciblée pour les gros fichiers source. Les fichiers binaires et images sont rejetés (utilisez l'outil image_info pour les images). Définissez encoding="base64" pour renvoyer les octets bruts encodés en base64 (pour les fichiers binaires tels que .pdf/.xlsx/.docx) ; offset/limit et les lectures ciblées sont ignorés dans ce mode.
 tool-file-write = Écrire le contenu dans un fichier de l'espace de travail
 tool-git-operations = Effectue des opérations Git structurées (état, diff, journal, branche, engagement, ajouter, checkout, stash). Fournit une sortie JSON analysée et s'intègre à la politique de sécurité pour les contrôles d'autonomie.
 tool-git-operations-error-not-in-repo = Pas dans un dépôt Git à '{ $path }'. Choisissez un chemin dans un arbre de travail Git, passez 'path' pour un sous-répertoire du dépôt, ou initialisez un dépôt avant d'exécuter git_operations.
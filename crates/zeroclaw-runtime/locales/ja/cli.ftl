@@ -46,6 +46,10 @@ cli-channel-doctor-about = 設定済みチャネルのヘルスチェックを
 cli-channel-add-about = 新しいチャネル設定を追加
 cli-channel-remove-about = チャネル設定を削除
 cli-channel-send-about = 設定済みチャネルに1回限りのメッセージを送信
+cli-channel-outbox-about = 再送待ちの返信を確認
+cli-channel-outbox-list-about = 未配信の返信を古い順に一覧表示
+cli-channel-outbox-retry-about = 保留中または配信断念の返信を再キューして即時配信
+cli-channel-outbox-drop-about = 未配信の返信を削除
 cli-wechat-pairing-required = 🔐 WeChatのペアリングが必要です。ワンタイムバインドコード: {$code}
 cli-wechat-send-bind-command = WeChatから `{$command} <code>` を送信してください。
 cli-wechat-qr-login = 📱 WeChat QRログイン（{$attempt}/{$max}）
@@ -128,6 +132,7 @@ cli-memory-list-about = オプションのフィルター付きでメモリエ
 cli-memory-get-about = キーで特定のメモリエントリを取得
 cli-memory-stats-about = メモリバックエンド統計とヘルスを表示
 cli-memory-clear-about = カテゴリ別、キー別、またはすべてをクリアしてメモリをクリア
+cli-memory-vacuum-about = メモリデータベースを圧縮し、クエリ統計を更新
 cli-memory-clear-unsupported-backend = memory clear は追記専用バックエンド '{$backend}' ではサポートされていません。削除可能なバックエンド（sqlite、lucid、またはpostgres）に切り替えてください
 cli-estop-status-about = 現在の estop ステータスを表示
 cli-estop-resume-about = エンゲージされた estop レベルから再開
@@ -135,7 +140,9 @@ cli-models-refresh-about = プロバイダーモデルをリフレッシュし
 cli-models-list-about = プロバイダーのキャッシュされたモデルを一覧表示
 cli-models-set-about = 設定でデフォルトモデルを設定
 cli-models-status-about = 現在のモデル設定とキャッシュステータスを表示
+cli-models-show-about = モデルのコンテキストウィンドウ、ビジョンとツールの対応、料金を表示
 cli-doctor-models-about = プロバイダー全体のモデルカタログをプローブして可用性を報告
+cli-doctor-providers-about = プロバイダーの API キーを検証し、レイテンシと残りクォータを報告
 cli-doctor-traces-about = ランタイムトレースイベント (ツール診断とモデル応答) をクエリ
 cli-doctor-update-context-windows-about = プロバイダーの /models エンドポイントから config.toml の context_window を更新
 cli-hardware-discover-about = USB デバイスを列挙して既知のボードを表示
@@ -401,6 +408,10 @@ cli-peripherals-add-hint = 次のコマンドで追加します: zeroclaw periph
 cli-peripherals-add-example = {"  "}例: zeroclaw peripheral add nucleo-f401re <serial-path>
 cli-peripherals-config-hint = または config.toml に追加します:
 cli-peripherals-configured = 設定済みの周辺機器:
+cli-peripherals-state-connected = 接続中
+cli-peripherals-state-detached = 切断 ({$error})
+cli-peripherals-state-no-session = ライブセッションなし
+cli-peripherals-spec-invalid = --peripheral の値 "{$spec}" が無効です。<board>:<path> (例: nucleo-f401re:/dev/ttyACM0) または auto を指定してください。
 cli-peripherals-already-configured = ボード {$board} ({$path}) は既に設定されています。
 cli-peripherals-added = {$board} を {$path} に追加しました。適用するにはデーモンを再起動してください。
 cli-peripherals-flash-needs-hardware = Arduino のフラッシュには 'hardware' 機能が必要です。
@@ -449,6 +460,8 @@ cli-sop-triggers = {"  "}トリガー:
 cli-sop-steps = {"  "}ステップ:
 cli-sop-step-tools = ツール: {$tools}
 cli-memory-reindexing = メモリバックエンドを再インデックス中...
+cli-memory-vacuuming = メモリデータベースを圧縮中...
+cli-memory-vacuumed = メモリデータベースを圧縮しました。{$kib} KiB を回収しました。
 cli-memory-none = メモリエントリが見つかりません。
 cli-memory-none-at-offset = オフセット {$offset} にエントリがありません (合計: {$total})。
 cli-memory-next-page = 次のページを表示するには --offset {$offset} を使用してください。
@@ -569,6 +582,8 @@ cli-quickstart-personality-file-prompt = {$filename}{$position} — 次は?{$bac
 cli-quickstart-next-agent-command = {"  "}zeroclaw agent -a {$alias}  # ターミナルでこのエージェントとチャット
 cli-quickstart-fix-and-rerun = 既存の設定は変更されていません。次を修正してから quickstart を再実行してください:
 cli-quickstart-could-not-finish = quickstart を完了できませんでした: 修正が必要な問題 {$count} 件
+cli-quickstart-plan-header = 変更内容:
+cli-quickstart-dry-run = ドライラン — 何も書き込まれていません。
 cli-quickstart-pick-preset = プリセットを選択
 cli-quickstart-pick-existing-prompt = 既存の {$prompt} を選択
 cli-quickstart-pick-preset-prompt = {$prompt} プリセットを選択
@@ -610,6 +625,9 @@ cli-quickstart-error-agent-name-required = エージェント名が必要です
 cli-quickstart-error-agent-exists = エージェント `{$name}` は既に存在します
 cli-no-channels-compiled = {"  "}このバイナリにコンパイルされているチャンネルタイプはありません。
 cli-quickstart-complete = クイックスタートが完了しました。エージェント `{$alias}` を作成しました。
+cli-quickstart-pair-whatsapp-prompt = WhatsApp Web チャンネル `whatsapp.{$alias}` を今すぐリンクしますか？（スマートフォンで QR コードをスキャンします）
+cli-quickstart-pair-whatsapp-later = {"  "}後でリンクするには: zeroclaw channel pair whatsapp --alias {$alias}
+cli-quickstart-pair-whatsapp-failed = {"  "}WhatsApp のペアリングが完了しませんでした: {$error}
 cli-next-steps = 次のステップ:
 cli-agent-not-created = エージェントは作成されませんでした — ディスク上の変更はありません。
 cli-onboard-deprecated = `zeroclaw onboard` は非推奨です — `zeroclaw quickstart` を使用してください。
@@ -673,6 +691,7 @@ cli-status-runtime = ⚙️  ランタイム:       {$v}
 cli-status-heartbeat = 💓 ハートビート:      {$v}
 cli-status-heartbeat-every-minutes = {$minutes}分ごと
 cli-status-memory = 🧠 メモリ:         {$backend} (自動保存: {$auto_save})
+cli-status-feedback = 🗳️  本日のフィードバック: {$positive} 👍 · {$negative} 👎
 cli-status-security-noprofile = セキュリティ ({$alias}): <risk_profile なし>
 cli-status-security = セキュリティ ({$alias}):
 cli-status-workspace-only = {"  "}ワークスペースのみ:    {$v}
@@ -698,6 +717,10 @@ cli-status-word-none = (なし)
 cli-status-word-configured = 設定済み
 cli-status-word-not-configured = 未設定
 cli-status-channel-not-compiled = 🚫 設定済み、未コンパイル
+cli-status-channels-draining = {"  "}ドレインモード: オン (処理中 {$in_flight} 件)
+cli-status-channels-paused = {"  "}一時停止: {$v} (待機中 {$queued} 件)
+cli-status-channels-outbox = {"  "}送信キュー: {$v} (保留/配信断念)
+cli-digest-disabled-hint = 定期ダイジェストは無効です。[observability.digest] enabled = true を設定してください。
 cli-desktop-not-installed = ZeroClaw コンパニオンアプリがインストールされていません。
 cli-desktop-blurb1 = コンパニオンアプリは軽量なメニューバーアプリで、
 cli-desktop-blurb2 = CLI と同じゲートウェイに接続します。
@@ -786,11 +809,24 @@ cli-selftest-channel-config-uncompiled = コンパイル済みチャンネル種
 cli-channels-header = チャンネル:
 cli-channels-cli-always = {"  "}✅ CLI (常に利用可能)
 cli-channels-notion = {"  "}{$status} Notion
+cli-channels-webhooks-header = {"  "}名前付き Webhook (ゲートウェイ):
+cli-channels-webhook-entry = {"    "}{$status} POST {$route} ({$mode})
 cli-channels-not-compiled-header = {"  "}設定済みですが、このバイナリにはコンパイルされていません:
 cli-channels-not-compiled-entry = {"  "}🚫 {$name} (設定済み、未コンパイル)
 cli-channels-build-hint = {"  "}ソースから `./install.sh --source --preset full`、`--features channels-full`、または特定の `channel-*` 機能でビルドしてください。
 cli-channels-start-hint = チャンネルを開始するには: zeroclaw channel start
 cli-channels-doctor-hint = 状態を確認するには:    zeroclaw channel doctor
+cli-channel-outbox-none = 送信キューに未配信の返信はありません。
+cli-channel-outbox-none-dead = 配信断念の返信はありません。
+cli-channel-outbox-header = 📮 送信キュー ({$count} 件):
+cli-channel-outbox-row = - {$id} | {$status} | {$channel} → {$recipient} | 試行={$attempts} | 登録={$queued}
+cli-channel-outbox-row-next = {"    "}次回  : {$v}
+cli-channel-outbox-row-error = {"    "}エラー: {$v}
+cli-channel-outbox-row-text = {"    "}本文  : {$v}
+cli-channel-outbox-requeued = ✅ エントリ {$id} を再キューしました。実行中のデーモンが数秒以内に再送します。
+cli-channel-outbox-already-delivered = エントリ {$id} は既に配信済みです
+cli-channel-outbox-not-found = 未配信のエントリ {$id} はありません
+cli-channel-outbox-dropped = 🗑️  エントリ {$id} を削除しました
 cli-channels-configure-hint = 設定するには:      zeroclaw config set channels.<name>.<field>=<value>
 cli-models-set-ok = デフォルトモデルが { $provider } の "{ $model }" に設定されました。
 cli-models-status-current = デフォルトモデル: { $model } (プロバイダー: { $provider })
@@ -809,6 +845,7 @@ channel-runtime-malformed-tool-output = 内部ツール呼び出し形式のエ
 channel-runtime-new-session = 会話履歴を消去しました。新しく開始します。
 channel-runtime-stop-sent = 停止シグナルを送信しました。
 channel-runtime-stop-no-task = この送信者スコープに実行中のタスクはありません。
+channel-runtime-feedback-follow-up = お役に立てずすみません。もう少し詳しく回答し直しましょうか？
 channel-runtime-model-empty = モデル ID は空にできません。`/model <model-id>` を使用してください。
 channel-runtime-model-switched = モデルを `{ $model }`（model_provider: `{ $provider }`）に切り替えました。コンテキストは保持されています。
 channel-runtime-agent-scope-rejected = 送信者 `{ $sender }` はエージェント `{ $agent }` で `/model --agent` を実行する権限がありません。セッション限定の上書きには `/model --user { $model }` を使用するか、管理者にあなたをメンバーとして `admin_for_agent_scope = true` のピアグループへ登録するよう依頼してください。
@@ -861,6 +898,19 @@ channel-runtime-thinking-default =
     thinking はすでにこの送信者セッションで agent デフォルト `{ $default }` を使用しています。
     上書きするには `/thinking high`、`/thinking max`、または `/thinking off` を使用してください。
 channel-runtime-thinking-invalid = 不明な thinking レベル `{ $raw }` です。`/thinking off|minimal|low|medium|high|max`、`/thinking on`、または `/thinking reset` を使用してください。
+channel-runtime-persona-none-available = ペルソナが見つかりません。workspace に `personas/<name>/SOUL.md` として追加してください。
+channel-runtime-persona-list =
+    現在のペルソナ: `{ $current }`
+    利用可能なペルソナ: { $list }
+    `/persona <name>` で切り替え、`/persona default` で workspace のファイルに戻します。
+channel-runtime-persona-switched = この送信者セッションのペルソナを `{ $persona }` に切り替えました。コンテキストは保持されます。
+channel-runtime-persona-reset = ペルソナをリセットしました。この送信者セッションでは workspace の SOUL.md と IDENTITY.md を使用します。
+channel-runtime-persona-unknown = 不明なペルソナ `{ $persona }` です。`/persona` で利用可能なペルソナを一覧表示できます。
+channel-runtime-voice-unavailable = このエージェントでは音声返信が設定されていません。オペレーターに `[tts]` を有効にし、エージェントの `tts_provider` を設定するよう依頼してください。
+channel-runtime-voice-status-on = この送信者セッションでは音声返信がオンです。`/voice off` でオフにできます。
+channel-runtime-voice-status-off = この送信者セッションでは音声返信がオフです。`/voice on` でオンにできます。
+channel-runtime-voice-on = 音声返信をオンにしました。返信はボイスメッセージで届きます。音声化できない場合はテキストで届きます。
+channel-runtime-voice-off = 音声返信をオフにしました。返信はテキストで届きます。
 channel-runtime-provider-turn-init-failed =
     ⚠️ model_provider `{ $provider }` の初期化に失敗しました。`/models` を実行して別の model_provider を選択してください。
     詳細: { $error }
@@ -934,6 +984,14 @@ cli-doctor-ctxwin-write-failed = {$provider_ref}: context_window の書き込み
 # ── Degraded config sections (doctor diagnose, #8835) ──
 cli-doctor-degraded-security = セキュリティ上重要な設定セクション `{$path}` が無効なため、デーモンを起動できるようデフォルト値にリセットされました。実行中のセキュリティ設定は意図したものより弱くなっている可能性があります。`zeroclaw config migrate` を実行してパースエラーを確認し、ファイルを修復してください。
 cli-doctor-degraded-section = 設定セクション `{$path}` は不正な形式のためデフォルト値にリセットされました。このセクションの値は反映されていません。`zeroclaw config migrate` を実行してパースエラーを確認し、ファイルを修復してください。
+cli-doctor-integration-ok = {$name}: {$detail}
+cli-doctor-integration-pending = {$name}: 認証情報は有効ですが、アカウントがまだ接続されていません。`zeroclaw integrations setup {$slug}` を実行して接続リンクを取得してください。
+cli-doctor-integration-failed = {$name}: 認証情報の確認に失敗しました: {$error}。`zeroclaw integrations setup {$slug}` を実行して修正してください。
+cli-doctor-provider-fix-key = config.toml で `{ $config_key }` を設定するか `zeroclaw quickstart` を実行してください
+cli-doctor-provider-fix-key-env = config.toml で `{ $config_key }` を設定するか `{ $env_var }` をエクスポートしてください
+cli-doctor-provider-fix-quota = アカウントにクレジットを追加するか利用上限を引き上げるか、`{ $config_key }` をクォータの残っているキーに変更してください
+cli-doctor-provider-fix-rate-limited = キーは有効ですがレート制限されています。後で再試行してください
+cli-doctor-provider-fix-network = プロバイダーのエンドポイントに到達できるか確認してください（`{ $uri_key }` で上書き可能、プロキシ: { $proxy }）
 sop-approval-deferred-at-capacity = 実行スロットが満杯のため、実行 {$run_id} を再開できませんでした。承認は待機状態のままです。スロットが空いてから再試行してください。
 sop-approval-policy-unavailable = 待機中の SOP ステップを利用できないため、承認に失敗しました: {$reason}。実行は待機状態のままです。
 sop-rpc-decision-invalid-state = 実行 {$run_id} は現在の状態では解決できません。
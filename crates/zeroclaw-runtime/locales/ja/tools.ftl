@@ -1,5 +1,5 @@
 tool-backup = ワークスペースバックアップの作成、一覧表示、検証、復元
-tool-browser = プラグイン可能なバックエンド（agent-browser、rust-native、computer_use）を使用したWeb/ブラウザオートメーション。DOMアクションに加えて、オプションのOSレベルアクション（mouse_move、mouse_click、mouse_drag、key_type、key_press、screen_capture）をコンピュータユースサイドカー経由でサポート。'snapshot'を使用して対話的要素をref（@e1、@e2）にマップします。openアクション向けにbrowser.allowed_domainsを強制します。
+tool-browser = プラグイン可能なバックエンド（agent-browser、rust-native、computer_use）を使用したWeb/ブラウザオートメーション。DOMアクションに加えて、オプションのOSレベルアクション（mouse_move、mouse_click、mouse_drag、key_type、key_press、screen_capture）をコンピュータユースサイドカー経由でサポート。'snapshot'を使用して対話的要素をref（@e1、@e2）にマップします。openアクション向けにbrowser.allowed_domainsを強制し、認証情報フィールドへの入力はbrowser.credential_domainsのドメインに限り、スクリーンショットはワークスペース内に保存します。
 tool-browser-delegate = ブラウザ対応CLIへのブラウザベースのタスクの委譲。Teams、Outlook、Jira、Confluenceなどのウェブアプリケーションと相互作用するため
 tool-browser-open = 承認されたHTTPS URLをシステムブラウザで開く。セキュリティ制約：許可リストのみのドメイン、ローカル/プライベートホストなし、スクレイピングなし。
 tool-channel-room = アクティブなチャンネルを通じてルームを作成し、ユーザーを招待します。'matrix.default' などのチャンネルキー、アクション 'create_room' または 'invite_user'、およびアクション固有のルームフィールドを指定してください。
@@ -60,7 +60,7 @@ tool-file-download-error-write-body = ダウンロードしたバイトの書き
 tool-file-download-error-flush = ダウンロードしたファイルのフラッシュに失敗しました: { $err }
 tool-file-download-error-move = ダウンロードしたファイルの移動に失敗しました: { $err }
 tool-file-download-success = { $written } バイトを { $dest_path } にダウンロードしました ({ $status })
-tool-file-read = 行番号付きのファイルコンテンツを読み込み。offsetとlimitによる部分読み込みをサポート。バイナリファイルと画像ファイルは拒否されます（画像の場合は image_info ツールを使用）。encoding="base64" を設定すると、生のバイトをbase64エンコードして返します（.pdf/.xlsx/.docx などのバイナリファイル用）。そのモードでは offset/limit は無視されます。
+tool-file-read = 行番号付きのファイルコンテンツを読み込み。offsetとlimitによる部分読み込み、またはスコープ指定の読み込みをサポート：symbol は関数/クラス/構造体の定義を1つ抽出し（例："parse" や "Parser::parse"）、around_line は指定行の前後を返し、regex は一致した行を前後の文脈付きで返します。大きなソースファイルではスコープ指定の読み込みを優先してください。バイナリファイルと画像ファイルは拒否されます（画像の場合は image_info ツールを使用）。encoding="base64" を設定すると、生のバイトをbase64エンコードして返します（.pdf/.xlsx/.docx などのバイナリファイル用）。そのモードでは offset/limit とスコープ指定は無視されます。
 tool-file-write = ワークスペース内のファイルにコンテンツを書き込み
 tool-git-operations = 構造化されたGit操作（status、diff、log、branch、commit、add、checkout、stash）を実行。解析されたJSON出力を提供し、自律性制御のためのセキュリティポリシーと統合します。
 tool-git-operations-error-not-in-repo = '{ $path }' はGitリポジトリ内にありません。Gitワークツリー内のパスを選択し、リポジトリのサブディレクトリに 'path' を渡すか、git_operationsを実行する前にリポジトリを初期化してください。
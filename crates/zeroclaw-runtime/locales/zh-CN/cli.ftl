@@ -46,6 +46,10 @@ cli-channel-doctor-about = 对已配置的渠道运行健康检查
 cli-channel-add-about = 添加新的渠道配置
 cli-channel-remove-about = 移除渠道配置
 cli-channel-send-about = 向已配置的渠道发送一次性消息
+cli-channel-outbox-about = 查看等待重新投递的回复
+cli-channel-outbox-list-about = 按时间从早到晚列出未投递的回复
+cli-channel-outbox-retry-about = 将待处理或已放弃的回复重新排队并立即投递
+cli-channel-outbox-drop-about = 删除一条未投递的回复
 cli-wechat-pairing-required = 🔐 需要绑定 WeChat。一次性绑定码：{$code}
 cli-wechat-send-bind-command = 请在 WeChat 中发送 `{$command} <code>`。
 cli-wechat-qr-login = 📱 WeChat 二维码登录（{$attempt}/{$max}）
@@ -127,6 +131,7 @@ cli-memory-list-about = 列出内存条目，可使用可选过滤器
 cli-memory-get-about = 按键获取特定的内存条目
 cli-memory-stats-about = 显示内存后端的统计信息和健康状况
 cli-memory-clear-about = 按类别、按键清除内存，或清除全部
+cli-memory-vacuum-about = 压缩记忆数据库并刷新其查询统计信息
 cli-memory-clear-unsupported-backend = 内存清除不支持仅追加后端 '{$backend}'；请切换到可删除的后端（sqlite、lucid 或 postgres）
 cli-estop-status-about = 打印当前急停状态
 cli-estop-resume-about = 从已激活的急停级别恢复
@@ -134,7 +139,9 @@ cli-models-refresh-about = 刷新并缓存提供商模型
 cli-models-list-about = 列出提供商的缓存模型
 cli-models-set-about = 在配置中设置默认模型
 cli-models-status-about = 显示当前模型配置和缓存状态
+cli-models-show-about = 显示模型的上下文窗口、视觉与工具调用支持以及价格
 cli-doctor-models-about = 探测各提供商的模型目录并报告可用性
+cli-doctor-providers-about = 验证提供商 API 密钥并报告延迟和剩余配额
 cli-doctor-traces-about = 查询运行时跟踪事件（工具诊断和模型回复）
 cli-doctor-update-context-windows-about = 从提供商的 /models 端点更新 config.toml 中的 context_window
 cli-hardware-discover-about = 枚举 USB 设备并显示已知开发板
@@ -400,6 +407,10 @@ cli-peripherals-add-hint = 使用以下命令添加: zeroclaw peripheral add <bo
 cli-peripherals-add-example = {"  "}示例: zeroclaw peripheral add nucleo-f401re <serial-path>
 cli-peripherals-config-hint = 或添加到 config.toml:
 cli-peripherals-configured = 已配置的外设:
+cli-peripherals-state-connected = 已连接
+cli-peripherals-state-detached = 已断开（{$error}）
+cli-peripherals-state-no-session = 无活动会话
+cli-peripherals-spec-invalid = 无效的 --peripheral 值“{$spec}”。请使用 <board>:<path>，例如 nucleo-f401re:/dev/ttyACM0，或 auto。
 cli-peripherals-already-configured = 位于 {$path} 的开发板 {$board} 已配置。
 cli-peripherals-added = 已在 {$path} 添加 {$board}。重启守护进程以应用。
 cli-peripherals-flash-needs-hardware = Arduino 烧录需要 'hardware' 功能。
@@ -448,6 +459,8 @@ cli-sop-triggers = {"  "}触发器:
 cli-sop-steps = {"  "}步骤:
 cli-sop-step-tools = 工具: {$tools}
 cli-memory-reindexing = 正在重新索引记忆后端...
+cli-memory-vacuuming = 正在压缩记忆数据库...
+cli-memory-vacuumed = 记忆数据库已压缩，回收了 {$kib} KiB。
 cli-memory-none = 未找到记忆条目。
 cli-memory-none-at-offset = 偏移量 {$offset} 处无条目(总计: {$total})。
 cli-memory-next-page = 使用 --offset {$offset} 查看下一页。
@@ -568,6 +581,8 @@ cli-quickstart-personality-file-prompt = {$filename}{$position} — 下一步？
 cli-quickstart-next-agent-command = {"  "}zeroclaw agent -a {$alias}  # 在终端中与此 agent 聊天
 cli-quickstart-fix-and-rerun = 现有配置未被修改。请修复以下问题，然后重新运行 quickstart：
 cli-quickstart-could-not-finish = quickstart 无法完成：需要修复 {$count} 个问题
+cli-quickstart-plan-header = 变更：
+cli-quickstart-dry-run = 试运行 — 未写入任何内容。
 cli-quickstart-pick-preset = 选择预设
 cli-quickstart-pick-existing-prompt = 选择已有的 {$prompt}
 cli-quickstart-pick-preset-prompt = 选择 {$prompt} 预设
@@ -609,6 +624,9 @@ cli-quickstart-error-agent-name-required = 必须填写 agent 名称
 cli-quickstart-error-agent-exists = agent `{$name}` 已存在
 cli-no-channels-compiled = {"  "}此二进制文件中未编译任何通道类型。
 cli-quickstart-complete = Quickstart 完成。已创建 agent `{$alias}`。
+cli-quickstart-pair-whatsapp-prompt = 现在关联 WhatsApp Web 通道 `whatsapp.{$alias}` 吗？（用手机扫描二维码）
+cli-quickstart-pair-whatsapp-later = {"  "}稍后关联：zeroclaw channel pair whatsapp --alias {$alias}
+cli-quickstart-pair-whatsapp-failed = {"  "}WhatsApp 配对未完成：{$error}
 cli-next-steps = 后续步骤：
 cli-agent-not-created = 未创建您的 agent — 磁盘上没有任何更改。
 cli-onboard-deprecated = `zeroclaw onboard` 已弃用 — 请使用 `zeroclaw quickstart`。
@@ -672,6 +690,7 @@ cli-status-runtime = ⚙️  运行时：       {$v}
 cli-status-heartbeat = 💓 心跳：      {$v}
 cli-status-heartbeat-every-minutes = 每 {$minutes} 分钟
 cli-status-memory = 🧠 内存：         {$backend}（自动保存：{$auto_save}）
+cli-status-feedback = 🗳️  今日反馈：{$positive} 👍 · {$negative} 👎
 cli-status-security-noprofile = 安全（{$alias}）：<无 risk_profile>
 cli-status-security = 安全（{$alias}）：
 cli-status-workspace-only = {"  "}仅工作区：    {$v}
@@ -697,6 +716,10 @@ cli-status-word-none = （无）
 cli-status-word-configured = 已配置
 cli-status-word-not-configured = 未配置
 cli-status-channel-not-compiled = 🚫 已配置，未编译
+cli-status-channels-draining = {"  "}排空模式：开启（{$in_flight} 条处理中）
+cli-status-channels-paused = {"  "}已暂停：{$v}（{$queued} 条排队中）
+cli-status-channels-outbox = {"  "}发件队列：{$v}（待投递/已放弃）
+cli-digest-disabled-hint = 定时摘要已关闭；请设置 [observability.digest] enabled = true。
 cli-desktop-not-installed = 未安装 ZeroClaw 配套应用。
 cli-desktop-blurb1 = 该配套应用是一个轻量级菜单栏应用，
 cli-desktop-blurb2 = 它连接到与 CLI 相同的网关。
@@ -785,11 +808,24 @@ cli-selftest-channel-config-uncompiled = {$compiled} 个通道类型已编译，
 cli-channels-header = 渠道：
 cli-channels-cli-always = {"  "}✅ CLI（始终可用）
 cli-channels-notion = {"  "}{$status} Notion
+cli-channels-webhooks-header = {"  "}命名 Webhook（网关）：
+cli-channels-webhook-entry = {"    "}{$status} POST {$route} ({$mode})
 cli-channels-not-compiled-header = {"  "}已配置但未编译进此二进制文件：
 cli-channels-not-compiled-entry = {"  "}🚫 {$name}（已配置，未编译）
 cli-channels-build-hint = {"  "}请从源码构建：`./install.sh --source --preset full`、`--features channels-full`，或指定对应的 `channel-*` 功能。
 cli-channels-start-hint = 启动渠道：zeroclaw channel start
 cli-channels-doctor-hint = 检查健康状况：    zeroclaw channel doctor
+cli-channel-outbox-none = 发件队列中没有未投递的回复。
+cli-channel-outbox-none-dead = 没有已放弃的回复。
+cli-channel-outbox-header = 📮 发件队列（{$count}）：
+cli-channel-outbox-row = - {$id} | {$status} | {$channel} → {$recipient} | 尝试={$attempts} | 入队={$queued}
+cli-channel-outbox-row-next = {"    "}下次：{$v}
+cli-channel-outbox-row-error = {"    "}错误：{$v}
+cli-channel-outbox-row-text = {"    "}内容：{$v}
+cli-channel-outbox-requeued = ✅ 条目 {$id} 已重新排队。运行中的守护进程会在几秒内重试。
+cli-channel-outbox-already-delivered = 条目 {$id} 已投递
+cli-channel-outbox-not-found = 没有未投递的条目 {$id}
+cli-channel-outbox-dropped = 🗑️  已删除条目 {$id}
 cli-channels-configure-hint = 配置方法：      zeroclaw config set channels.<name>.<field>=<value>
 cli-models-set-ok = 默认模型已设置为 "{ $model }" (provider: { $provider })。
 cli-models-status-current = 默认模型: { $model } (provider: { $provider })
@@ -808,6 +844,7 @@ channel-runtime-malformed-tool-output = 我生成了内部工具调用格式错
 channel-runtime-new-session = 对话历史已清除。重新开始。
 channel-runtime-stop-sent = 已发送停止信号。
 channel-runtime-stop-no-task = 此发送者范围内没有正在执行的任务。
+channel-runtime-feedback-follow-up = 抱歉没能帮上忙——需要我提供更详细的回答吗？
 channel-runtime-model-empty = 模型 ID 不能为空。请使用 `/model <model-id>`。
 channel-runtime-model-switched = 已切换到模型 `{ $model }`（model_provider：`{ $provider }`）。上下文已保留。
 channel-runtime-agent-scope-rejected = 发送者 `{ $sender }` 无权在 agent `{ $agent }` 上执行 `/model --agent`。请改用 `/model --user { $model }`（仅本次会话生效），或请管理员将 peer group 的 `admin_for_agent_scope` 设为 `true` 并将你列为成员。
@@ -860,6 +897,19 @@ channel-runtime-thinking-default =
     thinking 已经在此发送者会话中使用 agent 默认值 `{ $default }`。
     使用 `/thinking high`、`/thinking max` 或 `/thinking off` 覆盖。
 channel-runtime-thinking-invalid = 未知 thinking 等级 `{ $raw }`。使用 `/thinking off|minimal|low|medium|high|max`、`/thinking on` 或 `/thinking reset`。
+channel-runtime-persona-none-available = 未找到人格。请在 workspace 中以 `personas/<name>/SOUL.md` 的形式添加。
+channel-runtime-persona-list =
+    当前人格：`{ $current }`。
+    可用人格：{ $list }
+    使用 `/persona <name>` 切换，或使用 `/persona default` 恢复 workspace 文件。
+channel-runtime-persona-switched = 已将此发送者会话的人格切换为 `{ $persona }`。上下文已保留。
+channel-runtime-persona-reset = 人格已重置。此发送者会话将使用 workspace 中的 SOUL.md 和 IDENTITY.md。
+channel-runtime-persona-unknown = 未知人格 `{ $persona }`。使用 `/persona` 列出可用人格。
+channel-runtime-voice-unavailable = 此 agent 未配置语音回复。请让运维人员启用 `[tts]` 并设置该 agent 的 `tts_provider`。
+channel-runtime-voice-status-on = 此发送者会话的语音回复已开启。使用 `/voice off` 关闭。
+channel-runtime-voice-status-off = 此发送者会话的语音回复已关闭。使用 `/voice on` 开启。
+channel-runtime-voice-on = 语音回复已开启。回复将以语音消息发送，无法合成语音时以文本发送。
+channel-runtime-voice-off = 语音回复已关闭。回复将以文本发送。
 channel-runtime-provider-turn-init-failed =
     ⚠️ 初始化 model_provider `{ $provider }` 失败。请运行 `/models` 选择另一个 model_provider。
     详情：{ $error }
@@ -933,6 +983,14 @@ cli-doctor-ctxwin-write-failed = {$provider_ref}: 写入 context_window 失败:
 # ── Degraded config sections (doctor diagnose, #8835) ──
 cli-doctor-degraded-security = 安全关键配置节 `{$path}` 无效，已重置为默认值以便守护进程启动；当前运行的安全态势可能弱于预期。运行 `zeroclaw config migrate` 查看解析错误，然后修复该文件。
 cli-doctor-degraded-section = 配置节 `{$path}` 格式错误，已重置为默认值；该节中的值当前不生效。运行 `zeroclaw config migrate` 查看解析错误，然后修复该文件。
+cli-doctor-integration-ok = {$name}：{$detail}
+cli-doctor-integration-pending = {$name}：凭据有效，但账号尚未连接。运行 `zeroclaw integrations setup {$slug}` 获取连接链接。
+cli-doctor-integration-failed = {$name}：凭据检查失败：{$error}。运行 `zeroclaw integrations setup {$slug}` 进行修复。
+cli-doctor-provider-fix-key = 在 config.toml 中设置 `{ $config_key }`，或运行 `zeroclaw quickstart`
+cli-doctor-provider-fix-key-env = 在 config.toml 中设置 `{ $config_key }`，或导出 `{ $env_var }`
+cli-doctor-provider-fix-quota = 为账户充值或提高消费上限，或将 `{ $config_key }` 设为仍有配额的密钥
+cli-doctor-provider-fix-rate-limited = 密钥有效但正被限流，请稍后重试
+cli-doctor-provider-fix-network = 请检查能否访问提供商端点（可用 `{ $uri_key }` 覆盖；代理：{ $proxy }）
 sop-approval-deferred-at-capacity = 执行槽位已满，无法恢复运行 {$run_id}。审批仍处于等待状态；请在槽位释放后重试。
 sop-approval-policy-unavailable = 无法使用暂停的 SOP 步骤，审批失败：{$reason}。运行仍处于等待状态。
 sop-rpc-decision-invalid-state = 运行 {$run_id} 无法在当前状态下完成决策。
@@ -1,5 +1,5 @@
 tool-backup = 创建、列出、验证和恢复工作区备份
-tool-browser = 使用可插拔后端（agent-browser、rust-native、computer_use）进行网页/浏览器自动化。支持 DOM 操作以及通过 computer-use 边车进行的可选系统级操作（mouse_move、mouse_click、mouse_drag、key_type、key_press、screen_capture）。使用 'snapshot' 将交互元素映射到引用（@e1、@e2）。对 open 操作强制执行 browser.allowed_domains。
+tool-browser = 使用可插拔后端（agent-browser、rust-native、computer_use）进行网页/浏览器自动化。支持 DOM 操作以及通过 computer-use 边车进行的可选系统级操作（mouse_move、mouse_click、mouse_drag、key_type、key_press、screen_capture）。使用 'snapshot' 将交互元素映射到引用（@e1、@e2）。对 open 操作强制执行 browser.allowed_domains，仅在 browser.credential_domains 中的域名上填写凭据字段，并将截图保存在工作区内。
 tool-browser-delegate = 将基于浏览器的任务委托给具备浏览器能力的 CLI，用于与 Teams、Outlook、Jira、Confluence 等 Web 应用进行交互
 tool-browser-open = 在系统浏览器中打开经批准的 HTTPS URL。安全约束：仅限允许列表中的域名，不允许本地/私有主机，不允许抓取。
 tool-channel-room = 通过活动频道创建房间并邀请用户。提供频道键（如 'matrix.default'）、操作 'create_room' 或 'invite_user'，以及特定操作的房间字段。
@@ -60,7 +60,7 @@ tool-file-download-error-write-body = 写入已下载字节时失败：{ $err }
 tool-file-download-error-flush = 刷新已下载文件失败：{ $err }
 tool-file-download-error-move = 将已下载文件移动到位失败：{ $err }
 tool-file-download-success = 已下载 { $written } 字节到 { $dest_path }（{ $status }）
-tool-file-read = 读取带行号的文件内容。支持通过 offset 和 limit 进行部分读取。二进制文件和图像文件会被拒绝（图像请使用 image_info 工具）。设置 encoding="base64" 可以返回经 base64 编码的原始字节（适用于 .pdf/.xlsx/.docx 等二进制文件）；该模式下 offset/limit 被忽略。
+tool-file-read = 读取带行号的文件内容。支持通过 offset 和 limit 进行部分读取，或按范围读取：symbol 提取单个函数/类/结构体定义（例如 "parse" 或 "Parser::parse"），around_line 返回某一行附近的内容，regex 返回匹配的行及其上下文。读取大型源文件时优先使用按范围读取。二进制文件和图像文件会被拒绝（图像请使用 image_info 工具）。设置 encoding="base64" 可以返回经 base64 编码的原始字节（适用于 .pdf/.xlsx/.docx 等二进制文件）；该模式下 offset/limit 和按范围读取被忽略。
 tool-file-write = 将内容写入工作区中的文件
 tool-git-operations = 执行结构化的 Git 操作（status、diff、log、branch、commit、add、checkout、stash）。提供解析后的 JSON 输出，并与安全策略集成以实现自主控制。
 tool-git-operations-error-not-in-repo = 当前路径 '{ $path }' 不在 Git 仓库中。请选择一个 Git 工作树内的路径，通过 'path' 参数指定仓库子目录，或在运行 git_operations 前初始化一个仓库。
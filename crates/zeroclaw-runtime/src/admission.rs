@@ -0,0 +1,190 @@
+//! Channel admission control: which channels are paused and whether the
+//! daemon is draining.
+//!
+//! The gateway's admin endpoints write it, the channel dispatch loop reads it
+//! before spawning workers, and `/health/details` and `zeroclaw status`
+//! report it. A paused channel stops getting new messages handled. Drain mode
+//! stops the dispatch loop from reading any new message, while workers already
+//! running finish.
+
+use crate::outbox::OutboxDepth;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock};
+use tokio::sync::watch;
+
+/// Paused channel keys and the drain flag.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdmissionState {
+    /// Channel keys (`telegram.default`) or whole channel types (`telegram`).
+    pub paused: BTreeSet<String>,
+    pub draining: bool,
+}
+
+/// Point-in-time view for health reports.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdmissionSnapshot {
+    #[serde(default)]
+    pub paused_channels: Vec<String>,
+    #[serde(default)]
+    pub draining: bool,
+    /// Worker tasks the dispatch loop has not yet joined.
+    #[serde(default)]
+    pub in_flight: usize,
+    /// Messages held for paused channels, dispatched on resume.
+    #[serde(default)]
+    pub queued: usize,
+    /// Undelivered replies in the outbox, per channel key.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub outbox: BTreeMap<String, OutboxDepth>,
+}
+
+pub struct ChannelAdmission {
+    state: watch::Sender<AdmissionState>,
+    /// Serializes read-modify-write so concurrent admin calls report
+    /// accurate "changed" results.
+    write: Mutex<()>,
+    in_flight: AtomicUsize,
+    queued: AtomicUsize,
+    outbox: Mutex<BTreeMap<String, OutboxDepth>>,
+}
+
+impl Default for ChannelAdmission {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChannelAdmission {
+    pub fn new() -> Self {
+        Self {
+            state: watch::Sender::new(AdmissionState::default()),
+            write: Mutex::new(()),
+            in_flight: AtomicUsize::new(0),
+            queued: AtomicUsize::new(0),
+            outbox: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    fn update(&self, apply: impl FnOnce(&mut AdmissionState) -> bool) -> bool {
+        let _guard = self.write.lock();
+        self.state.send_if_modified(apply)
+    }
+
+    /// Pause `channel`. Returns `false` when it was already paused.
+    pub fn pause(&self, channel: &str) -> bool {
+        self.update(|state| state.paused.insert(channel.to_string()))
+    }
+
+    /// Resume `channel`. Returns `false` when it was not paused.
+    pub fn resume(&self, channel: &str) -> bool {
+        self.update(|state| state.paused.remove(channel))
+    }
+
+    /// Enter or leave drain mode. Returns `false` when already in that mode.
+    pub fn set_draining(&self, draining: bool) -> bool {
+        self.update(|state| std::mem::replace(&mut state.draining, draining) != draining)
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.state.borrow().draining
+    }
+
+    /// Whether messages for `channel_key` (`<type>` or `<type>.<alias>`) are
+    /// held back, either by that key or by its whole channel type.
+    pub fn is_paused(&self, channel_key: &str) -> bool {
+        let state = self.state.borrow();
+        if state.paused.is_empty() {
+            return false;
+        }
+        let channel_type = channel_key
+            .split_once('.')
+            .map_or(channel_key, |(ty, _)| ty);
+        state.paused.contains(channel_key) || state.paused.contains(channel_type)
+    }
+
+    /// Receiver that wakes on every pause, resume, or drain change.
+    pub fn subscribe(&self) -> watch::Receiver<AdmissionState> {
+        self.state.subscribe()
+    }
+
+    pub fn set_in_flight(&self, count: usize) {
+        self.in_flight.store(count, Ordering::Relaxed);
+    }
+
+    pub fn set_queued(&self, count: usize) {
+        self.queued.store(count, Ordering::Relaxed);
+    }
+
+    /// Replace the outbox depth reported by [`Self::snapshot`].
+    pub fn set_outbox_depth(&self, depth: BTreeMap<String, OutboxDepth>) {
+        *self.outbox.lock() = depth;
+    }
+
+    pub fn snapshot(&self) -> AdmissionSnapshot {
+        let state = self.state.borrow();
+        AdmissionSnapshot {
+            paused_channels: state.paused.iter().cloned().collect(),
+            draining: state.draining,
+            in_flight: self.in_flight.load(Ordering::Relaxed),
+            queued: self.queued.load(Ordering::Relaxed),
+            outbox: self.outbox.lock().clone(),
+        }
+    }
+}
+
+static GLOBAL: LazyLock<Arc<ChannelAdmission>> =
+    LazyLock::new(|| Arc::new(ChannelAdmission::new()));
+
+/// The process-wide admission state shared by the gateway and the channel
+/// dispatch loop.
+pub fn global() -> Arc<ChannelAdmission> {
+    Arc::clone(&GLOBAL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pausing_a_type_covers_every_alias() {
+        let admission = ChannelAdmission::new();
+        assert!(admission.pause("telegram"));
+        assert!(!admission.pause("telegram"));
+        assert!(admission.is_paused("telegram.default"));
+        assert!(admission.is_paused("telegram"));
+        assert!(!admission.is_paused("discord.default"));
+
+        assert!(admission.resume("telegram"));
+        assert!(admission.pause("discord.ops"));
+        assert!(admission.is_paused("discord.ops"));
+        assert!(!admission.is_paused("discord.default"));
+        assert!(!admission.is_paused("discord"));
+    }
+
+    #[test]
+    fn changes_wake_subscribers_and_show_in_the_snapshot() {
+        let admission = ChannelAdmission::new();
+        let mut rx = admission.subscribe();
+        assert!(admission.set_draining(true));
+        assert!(rx.has_changed().unwrap());
+        rx.mark_unchanged();
+        assert!(!admission.set_draining(true));
+        assert!(!rx.has_changed().unwrap());
+
+        admission.pause("slack.default");
+        admission.set_in_flight(3);
+        assert_eq!(
+            admission.snapshot(),
+            AdmissionSnapshot {
+                paused_channels: vec!["slack.default".into()],
+                draining: true,
+                in_flight: 3,
+                queued: 0,
+                outbox: BTreeMap::new(),
+            }
+        );
+    }
+}
@@ -1398,6 +1398,7 @@ impl Agent {
             Arc::from(observability::create_observer(&config.observability));
         let runtime: Arc<dyn platform::RuntimeAdapter> =
             Arc::from(platform::create_runtime(&config.runtime)?);
+        platform::prepare_runtime(&config.runtime).await?;
         // Per-agent workspace becomes the SecurityPolicy boundary
         // (file_read/write/edit + shell tool jail to the agent's own
         // dir). The session-cwd override still wins so ACP sessions
@@ -2368,6 +2369,7 @@ impl Agent {
                             handle: self.memory.as_ref(),
                             query: user_message.to_string(),
                             sessions: vec![self.memory_session_id.clone()],
+                            namespaces: Vec::new(),
                             suppress: false,
                             cfg: self.memory_inject_cfg,
                         }),
@@ -2742,6 +2744,7 @@ impl Agent {
                             handle: self.memory.as_ref(),
                             query: user_message.to_string(),
                             sessions: vec![self.memory_session_id.clone()],
+                            namespaces: Vec::new(),
                             suppress: false,
                             cfg: self.memory_inject_cfg,
                         }),
@@ -8013,6 +8016,7 @@ mod tests {
                 .collect(),
             prompts: vec![],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         }
     }
@@ -8141,6 +8145,7 @@ mod tests {
             }],
             prompts: vec![],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         };
         tools::register_skill_tools_with_context(
@@ -0,0 +1,346 @@
+//! Line editing and slash commands for interactive `zeroclaw agent`.
+//!
+//! On a terminal, input goes through a line editor with history persisted
+//! to `<agent workspace>/state/cli_history.txt`. Pasted text arrives as one
+//! message via bracketed paste, and a line ending in `\` continues on the
+//! next. Piped stdin keeps the plain capped line reader. `-m` never reaches
+//! this module.
+
+use crate::agent::loop_::{CappedLine, MAX_INTERACTIVE_INPUT_BYTES, read_capped_line};
+use rustyline::DefaultEditor;
+use rustyline::error::ReadlineError;
+use std::fmt::Write as _;
+use std::io::{IsTerminal, Write as _};
+use std::path::{Path, PathBuf};
+use zeroclaw_config::schema::Config;
+use zeroclaw_providers::ChatMessage;
+
+/// Entries kept in the persistent history file.
+const HISTORY_LIMIT: usize = 1000;
+
+const PROMPT: &str = "> ";
+const CONTINUATION_PROMPT: &str = ". ";
+
+/// Persistent input history for `agent_alias`.
+pub fn history_path(config: &Config, agent_alias: &str) -> PathBuf {
+    config
+        .agent_workspace_dir(agent_alias)
+        .join("state")
+        .join("cli_history.txt")
+}
+
+/// One read from the prompt.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReplInput {
+    /// A complete message, continuation lines joined with `\n`.
+    Line(String),
+    /// Ctrl-C at the prompt: discard the draft and prompt again.
+    Interrupted,
+    /// The input exceeded [`MAX_INTERACTIVE_INPUT_BYTES`] and was dropped.
+    TooLong,
+    /// Ctrl-D or end of piped input.
+    Eof,
+}
+
+/// Prompt reader for the interactive loop.
+pub struct ReplEditor {
+    editor: Option<DefaultEditor>,
+    history_path: Option<PathBuf>,
+}
+
+impl ReplEditor {
+    /// Line editor when stdin and stdout are terminals, otherwise the plain
+    /// stdin reader. History is loaded from and saved to `history_path`.
+    pub fn new(history_path: Option<PathBuf>) -> Self {
+        let interactive = std::io::stdin().is_terminal() && std::io::stdout().is_terminal();
+        let editor = interactive
+            .then(|| {
+                let config = rustyline::Config::builder()
+                    .max_history_size(HISTORY_LIMIT)
+                    .ok()?
+                    .auto_add_history(false)
+                    .bracketed_paste(true)
+                    .build();
+                DefaultEditor::with_config(config).ok()
+            })
+            .flatten();
+        let mut repl = Self {
+            editor,
+            history_path,
+        };
+        if let (Some(editor), Some(path)) = (repl.editor.as_mut(), repl.history_path.as_ref()) {
+            // A missing file is the first session.
+            let _ = editor.load_history(path);
+        }
+        repl
+    }
+
+    /// Read one message, following `\` continuations.
+    pub fn read_input(&mut self) -> ReplInput {
+        let mut lines: Vec<String> = Vec::new();
+        loop {
+            let prompt = if lines.is_empty() {
+                PROMPT
+            } else {
+                CONTINUATION_PROMPT
+            };
+            let line = match self.read_line(prompt) {
+                ReplInput::Line(line) => line,
+                ReplInput::Eof if !lines.is_empty() => break,
+                other => return other,
+            };
+            let more = push_continuation(&mut lines, &line);
+            if lines.iter().map(|l| l.len() + 1).sum::<usize>() > MAX_INTERACTIVE_INPUT_BYTES {
+                return ReplInput::TooLong;
+            }
+            if !more {
+                break;
+            }
+        }
+        let message = lines.join("\n");
+        if let Some(editor) = self.editor.as_mut()
+            && !message.trim().is_empty()
+        {
+            let _ = editor.add_history_entry(message.as_str());
+            // Saved per message so a killed session keeps its history.
+            self.save_history();
+        }
+        ReplInput::Line(message)
+    }
+
+    /// Ask a yes/no question; anything but `y`/`yes` is no.
+    pub fn confirm(&mut self, question: &str) -> bool {
+        match self.read_line(question) {
+            ReplInput::Line(answer) => {
+                matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+            }
+            _ => false,
+        }
+    }
+
+    /// Write the history file. Failures are ignored: history is a
+    /// convenience, not session state.
+    fn save_history(&mut self) {
+        if let (Some(editor), Some(path)) = (self.editor.as_mut(), self.history_path.as_ref()) {
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            let _ = editor.save_history(path);
+        }
+    }
+
+    fn read_line(&mut self, prompt: &str) -> ReplInput {
+        let Some(editor) = self.editor.as_mut() else {
+            print!("{prompt}");
+            let _ = std::io::stdout().flush();
+            let stdin = std::io::stdin().lock();
+            return match read_capped_line(stdin, MAX_INTERACTIVE_INPUT_BYTES) {
+                Ok(CappedLine::Line(line)) => ReplInput::Line(line),
+                Ok(CappedLine::Truncated) => ReplInput::TooLong,
+                Ok(CappedLine::Eof) => ReplInput::Eof,
+                Err(e) => {
+                    eprintln!("\nError reading input: {e}\n");
+                    ReplInput::Eof
+                }
+            };
+        };
+        match editor.readline(prompt) {
+            Ok(line) if line.len() > MAX_INTERACTIVE_INPUT_BYTES => ReplInput::TooLong,
+            Ok(line) => ReplInput::Line(line),
+            Err(ReadlineError::Interrupted) => ReplInput::Interrupted,
+            Err(ReadlineError::Eof) => ReplInput::Eof,
+            Err(e) => {
+                eprintln!("\nError reading input: {e}\n");
+                ReplInput::Eof
+            }
+        }
+    }
+}
+
+/// Append `line` to a message being assembled. Returns `true` when the line
+/// ends in a `\` continuation, which is stripped.
+fn push_continuation(lines: &mut Vec<String>, line: &str) -> bool {
+    match line.strip_suffix('\\') {
+        Some(head) => {
+            lines.push(head.to_string());
+            true
+        }
+        None => {
+            lines.push(line.to_string());
+            false
+        }
+    }
+}
+
+/// Slash commands handled by the interactive loop before a message reaches
+/// the model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplCommand {
+    Help,
+    Quit,
+    Clear,
+    ShowModel,
+    SetModel(String),
+    ShowProvider,
+    SetProvider(String),
+    /// `/save <file>`; `None` when the path is missing.
+    Save(Option<PathBuf>),
+    Tools,
+}
+
+/// Parse a slash command. Anything else, including `/think:<level>`, is
+/// left to the turn.
+pub fn parse_repl_command(input: &str) -> Option<ReplCommand> {
+    let mut parts = input.trim().splitn(2, char::is_whitespace);
+    let command = parts.next()?;
+    let arg = parts.next().map(str::trim).unwrap_or_default();
+    let command = match command {
+        "/help" => ReplCommand::Help,
+        "/quit" | "/exit" => ReplCommand::Quit,
+        "/clear" | "/new" => ReplCommand::Clear,
+        "/model" if arg.is_empty() => ReplCommand::ShowModel,
+        "/model" => ReplCommand::SetModel(arg.to_string()),
+        "/provider" | "/models" if arg.is_empty() => ReplCommand::ShowProvider,
+        "/provider" | "/models" => ReplCommand::SetProvider(arg.to_string()),
+        "/save" => ReplCommand::Save((!arg.is_empty()).then(|| PathBuf::from(arg))),
+        "/tools" => ReplCommand::Tools,
+        _ => return None,
+    };
+    Some(command)
+}
+
+pub const HELP: &str = "Available commands:
+  /help               Show this help message
+  /model [name]       Show or switch the model
+  /provider [ref]     Show or switch the model provider (<type>.<alias>)
+  /tools              List the tools available to the agent
+  /save <file>        Write the conversation to a Markdown file
+  /clear /new         Clear conversation history
+  /quit /exit         Exit interactive mode
+  /think:<level>      Set reasoning depth (off|minimal|low|medium|high|max)
+
+End a line with \\ to continue on the next. Ctrl-C cancels a running reply.
+";
+
+/// Resolve a `/provider` argument to a configured `<type>.<alias>` ref. A
+/// bare type resolves when exactly one alias of it is configured.
+pub fn resolve_provider_ref(config: &Config, raw: &str) -> Result<String, String> {
+    let candidate = raw.trim();
+    if let Some((family, alias)) = candidate.split_once('.') {
+        return match config.providers.models.find(family, alias) {
+            Some(_) => Ok(candidate.to_string()),
+            None => Err(format!(
+                "No [providers.models.{family}.{alias}] entry is configured."
+            )),
+        };
+    }
+    let mut aliases: Vec<String> = config
+        .providers
+        .models
+        .aliases_of(candidate)
+        .map(ToString::to_string)
+        .collect();
+    aliases.sort();
+    match aliases.as_slice() {
+        [alias] => Ok(format!("{candidate}.{alias}")),
+        [] => Err(format!(
+            "No [providers.models.{candidate}.<alias>] entry is configured."
+        )),
+        _ => Err(format!(
+            "'{candidate}' has several aliases; use one of: {}",
+            aliases
+                .iter()
+                .map(|a| format!("{candidate}.{a}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// The conversation as Markdown for `/save`: user and assistant turns only.
+pub fn render_transcript(history: &[ChatMessage], agent_alias: &str) -> String {
+    let mut out = format!("# zeroclaw agent session ({agent_alias})\n");
+    for message in history {
+        let heading = match message.role.as_str() {
+            "user" => "User",
+            "assistant" if !message.content.trim().is_empty() => "Assistant",
+            _ => continue,
+        };
+        let _ = write!(out, "\n## {heading}\n\n{}\n", message.content.trim());
+    }
+    out
+}
+
+/// Write the transcript for `/save`, creating parent directories.
+pub fn save_transcript(
+    path: &Path,
+    history: &[ChatMessage],
+    agent_alias: &str,
+) -> std::io::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, render_transcript(history, agent_alias))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_slash_commands_and_leaves_other_input_alone() {
+        assert_eq!(parse_repl_command("/help"), Some(ReplCommand::Help));
+        assert_eq!(parse_repl_command(" /exit "), Some(ReplCommand::Quit));
+        assert_eq!(parse_repl_command("/new"), Some(ReplCommand::Clear));
+        assert_eq!(parse_repl_command("/model"), Some(ReplCommand::ShowModel));
+        assert_eq!(
+            parse_repl_command("/model  gpt-4o-mini "),
+            Some(ReplCommand::SetModel("gpt-4o-mini".into()))
+        );
+        assert_eq!(
+            parse_repl_command("/provider openai.work"),
+            Some(ReplCommand::SetProvider("openai.work".into()))
+        );
+        assert_eq!(
+            parse_repl_command("/provider"),
+            Some(ReplCommand::ShowProvider)
+        );
+        assert_eq!(
+            parse_repl_command("/save notes/chat.md"),
+            Some(ReplCommand::Save(Some(PathBuf::from("notes/chat.md"))))
+        );
+        assert_eq!(parse_repl_command("/save"), Some(ReplCommand::Save(None)));
+        assert_eq!(parse_repl_command("/tools"), Some(ReplCommand::Tools));
+        assert_eq!(parse_repl_command("/think:high explain"), None);
+        assert_eq!(parse_repl_command("/modelx"), None);
+        assert_eq!(parse_repl_command("what does /model do?"), None);
+    }
+
+    #[test]
+    fn backslash_continues_onto_the_next_line() {
+        let mut lines = Vec::new();
+        assert!(push_continuation(&mut lines, "first line \\"));
+        assert!(!push_continuation(&mut lines, "second line"));
+        assert_eq!(lines.join("\n"), "first line \nsecond line");
+    }
+
+    #[test]
+    fn transcript_keeps_user_and_assistant_turns() {
+        let history = vec![
+            ChatMessage::system("system prompt"),
+            ChatMessage::user("What is 2+2?"),
+            ChatMessage::assistant(""),
+            ChatMessage::tool("{\"result\":4}"),
+            ChatMessage::assistant("4"),
+        ];
+
+        let transcript = render_transcript(&history, "default");
+
+        assert!(transcript.starts_with("# zeroclaw agent session (default)\n"));
+        assert!(transcript.contains("## User\n\nWhat is 2+2?\n"));
+        assert!(transcript.contains("## Assistant\n\n4\n"));
+        assert!(!transcript.contains("system prompt"));
+        assert!(!transcript.contains("result"));
+        assert_eq!(transcript.matches("## Assistant").count(), 1);
+    }
+}
@@ -0,0 +1,424 @@
+//! Checkpointed interactive `zeroclaw agent` sessions.
+//!
+//! Every interactive CLI session is written to
+//! `<agent workspace>/state/sessions/<id>.json` after each assistant turn so
+//! a crash or closed terminal loses at most the turn in flight.
+//! `zeroclaw agent --resume [id|last]` reloads one; `--list-sessions`
+//! enumerates them. Only user/assistant turns are restored — the system
+//! prompt is always rebuilt from current config and skills on resume.
+
+use crate::agent::history_pruner::{
+    extract_assistant_tool_call_ids, remove_orphaned_tool_messages,
+};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use zeroclaw_config::schema::Config;
+use zeroclaw_providers::ChatMessage;
+
+const SESSION_FILE_VERSION: u32 = 1;
+
+/// Selector accepted by `--resume` for the most recently updated session.
+pub const LAST_SESSION: &str = "last";
+
+/// Maximum characters of the first user message shown by `--list-sessions`.
+const PREVIEW_CHARS: usize = 60;
+
+/// Directory holding checkpointed CLI sessions for `agent_alias`.
+pub fn sessions_dir(config: &Config, agent_alias: &str) -> PathBuf {
+    config
+        .agent_workspace_dir(agent_alias)
+        .join("state")
+        .join("sessions")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CliSessionFile {
+    version: u32,
+    id: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    history: Vec<ChatMessage>,
+}
+
+/// One saved session as shown by `zeroclaw agent --list-sessions`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliSessionSummary {
+    pub id: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Number of user messages in the session.
+    pub turns: usize,
+    /// Truncated first user message; empty when the session has none yet.
+    pub preview: String,
+}
+
+/// A checkpointed interactive session bound to one file in the sessions
+/// directory. Handed to the agent loop through `AgentRunOverrides`.
+#[derive(Debug, Clone)]
+pub struct CliSession {
+    dir: PathBuf,
+    id: String,
+    created_at: DateTime<Utc>,
+    /// Turns restored from disk, seeded into the history on start.
+    restored: Vec<ChatMessage>,
+    /// Number of sessions to keep; older ones are pruned on save. `0`
+    /// keeps everything.
+    retention: usize,
+}
+
+impl CliSession {
+    /// Start a fresh session with a new time-ordered id.
+    pub fn create(dir: PathBuf, retention: usize) -> Self {
+        let now = Utc::now();
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+        Self {
+            dir,
+            id: format!("{}-{}", now.format("%Y%m%d-%H%M%S"), &suffix[..6]),
+            created_at: now,
+            restored: Vec::new(),
+            retention,
+        }
+    }
+
+    /// Load a saved session by id, or the most recent one for
+    /// [`LAST_SESSION`].
+    pub fn resume(dir: PathBuf, selector: &str, retention: usize) -> Result<Self> {
+        let id = if selector == LAST_SESSION {
+            list_sessions(&dir)?
+                .into_iter()
+                .next()
+                .map(|s| s.id)
+                .with_context(|| format!("no saved sessions in {}", dir.display()))?
+        } else {
+            validate_id(selector)?;
+            selector.to_string()
+        };
+        let path = session_path(&dir, &id);
+        let file = read_session_file(&path)
+            .with_context(|| format!("failed to load session '{id}' from {}", path.display()))?;
+        Ok(Self {
+            dir,
+            id: file.id,
+            created_at: file.created_at,
+            restored: restorable_turns(file.history),
+            retention,
+        })
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Number of turns restored from disk (0 for a fresh session).
+    pub fn restored_len(&self) -> usize {
+        self.restored.len()
+    }
+
+    /// Initial loop history: the freshly built system prompt followed by
+    /// the restored user/assistant turns.
+    pub fn initial_history(&self, system_prompt: &str) -> Vec<ChatMessage> {
+        let mut history = Vec::with_capacity(self.restored.len() + 1);
+        history.push(ChatMessage::system(system_prompt));
+        history.extend(self.restored.iter().cloned());
+        history
+    }
+
+    /// Persist `history` (minus the system prompt) and prune sessions beyond
+    /// the retention count. The current session is never pruned, and a
+    /// retention of `0` (checkpointing off, e.g. a `--resume` under it)
+    /// prunes nothing.
+    pub fn save(&self, history: &[ChatMessage]) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let file = CliSessionFile {
+            version: SESSION_FILE_VERSION,
+            id: self.id.clone(),
+            created_at: self.created_at,
+            updated_at: Utc::now(),
+            history: history
+                .iter()
+                .filter(|m| m.role != "system")
+                .cloned()
+                .collect(),
+        };
+        let path = session_path(&self.dir, &self.id);
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_string_pretty(&file)?)?;
+        std::fs::rename(&tmp, &path)?;
+        prune_sessions(&self.dir, self.retention, &self.id)?;
+        Ok(())
+    }
+}
+
+/// Saved sessions in `dir`, most recently updated first. Unreadable files
+/// are skipped.
+pub fn list_sessions(dir: &Path) -> Result<Vec<CliSessionSummary>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut sessions: Vec<CliSessionSummary> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| read_session_file(&path).ok())
+        .map(|file| summarize(&file))
+        .collect();
+    sessions.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(b.id.cmp(&a.id)));
+    Ok(sessions)
+}
+
+/// Delete all but the `keep` most recently updated sessions, never touching
+/// `current`. `keep == 0` deletes nothing.
+fn prune_sessions(dir: &Path, keep: usize, current: &str) -> Result<()> {
+    if keep == 0 {
+        return Ok(());
+    }
+    let stale = list_sessions(dir)?
+        .into_iter()
+        .filter(|s| s.id != current)
+        .skip(keep.saturating_sub(1));
+    for session in stale {
+        let path = session_path(dir, &session.id);
+        if let Err(e) = std::fs::remove_file(&path) {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                    .with_attrs(::serde_json::json!({
+                        "path": path.display().to_string(),
+                        "error": e.to_string(),
+                    })),
+                "failed to prune CLI session"
+            );
+        }
+    }
+    Ok(())
+}
+
+fn session_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{id}.json"))
+}
+
+/// Ids are generated as `YYYYMMDD-HHMMSS-xxxxxx`; reject anything that could
+/// escape the sessions directory.
+fn validate_id(id: &str) -> Result<()> {
+    if id.is_empty()
+        || !id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        anyhow::bail!("invalid session id '{id}'");
+    }
+    Ok(())
+}
+
+fn read_session_file(path: &Path) -> Result<CliSessionFile> {
+    let raw = std::fs::read_to_string(path)?;
+    let file: CliSessionFile = serde_json::from_str(&raw)?;
+    if file.version > SESSION_FILE_VERSION {
+        anyhow::bail!(
+            "session file version {} is newer than supported version {SESSION_FILE_VERSION}",
+            file.version
+        );
+    }
+    Ok(file)
+}
+
+/// A message the user actually typed, as opposed to the `[Tool results]`
+/// block prompt-guided dispatch feeds back under the user role.
+fn is_user_turn(message: &ChatMessage) -> bool {
+    message.role == "user" && !message.content.starts_with("[Tool results]")
+}
+
+/// Keep only user turns and plain assistant replies. Tool-call dispatches
+/// and tool results are dropped: the tools they reference may no longer be
+/// registered when the session is resumed.
+fn restorable_turns(history: Vec<ChatMessage>) -> Vec<ChatMessage> {
+    let mut turns: Vec<ChatMessage> = history
+        .into_iter()
+        .filter(|m| match m.role.as_str() {
+            "user" => is_user_turn(m),
+            "assistant" => extract_assistant_tool_call_ids(&m.content).is_none(),
+            _ => false,
+        })
+        .collect();
+    remove_orphaned_tool_messages(&mut turns);
+    turns
+}
+
+fn summarize(file: &CliSessionFile) -> CliSessionSummary {
+    let mut users = file.history.iter().filter(|m| is_user_turn(m));
+    let preview = users
+        .next()
+        .map(|m| preview_text(&m.content))
+        .unwrap_or_default();
+    CliSessionSummary {
+        id: file.id.clone(),
+        created_at: file.created_at,
+        updated_at: file.updated_at,
+        turns: usize::from(!preview.is_empty()) + users.count(),
+        preview,
+    }
+}
+
+fn preview_text(content: &str) -> String {
+    let flat = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    if flat.chars().count() > PREVIEW_CHARS {
+        let cut: String = flat.chars().take(PREVIEW_CHARS).collect();
+        format!("{cut}…")
+    } else {
+        flat
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn conversation() -> Vec<ChatMessage> {
+        vec![
+            ChatMessage::system("old system prompt"),
+            ChatMessage::user("first question"),
+            ChatMessage::assistant("first answer"),
+            ChatMessage::user("second question"),
+            ChatMessage::assistant("second answer"),
+        ]
+    }
+
+    #[test]
+    fn save_then_resume_rebuilds_system_prompt() {
+        let dir = tempdir().unwrap();
+        let session = CliSession::create(dir.path().to_path_buf(), 10);
+        session.save(&conversation()).unwrap();
+
+        let resumed = CliSession::resume(dir.path().to_path_buf(), session.id(), 10).unwrap();
+        let history = resumed.initial_history("fresh system prompt");
+
+        assert_eq!(resumed.id(), session.id());
+        assert_eq!(history.len(), 5);
+        assert_eq!(history[0].role, "system");
+        assert_eq!(history[0].content, "fresh system prompt");
+        assert_eq!(history[1].content, "first question");
+        assert_eq!(history[4].content, "second answer");
+    }
+
+    #[test]
+    fn resume_last_picks_most_recent_session() {
+        let dir = tempdir().unwrap();
+        let older = CliSession::create(dir.path().to_path_buf(), 10);
+        older.save(&[ChatMessage::user("older")]).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        let newer = CliSession::create(dir.path().to_path_buf(), 10);
+        newer.save(&[ChatMessage::user("newer")]).unwrap();
+
+        let resumed = CliSession::resume(dir.path().to_path_buf(), LAST_SESSION, 10).unwrap();
+        assert_eq!(resumed.id(), newer.id());
+    }
+
+    #[test]
+    fn resume_last_without_sessions_errors() {
+        let dir = tempdir().unwrap();
+        assert!(CliSession::resume(dir.path().to_path_buf(), LAST_SESSION, 10).is_err());
+    }
+
+    #[test]
+    fn resume_rejects_path_like_ids() {
+        let dir = tempdir().unwrap();
+        let err = CliSession::resume(dir.path().to_path_buf(), "../secrets", 10).unwrap_err();
+        assert!(err.to_string().contains("invalid session id"));
+    }
+
+    #[test]
+    fn restore_drops_tool_traffic() {
+        let history = vec![
+            ChatMessage::user("list files"),
+            ChatMessage::assistant(
+                r#"{"content":"","tool_calls":[{"id":"call_1","name":"shell","arguments":"{}"}]}"#,
+            ),
+            ChatMessage::tool(r#"{"tool_call_id":"call_1","content":"a.txt"}"#),
+            ChatMessage::user("[Tool results]\n<tool_result>a.txt</tool_result>"),
+            ChatMessage::assistant("There is one file: a.txt"),
+        ];
+        let turns = restorable_turns(history);
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[0].role, "user");
+        assert_eq!(turns[1].content, "There is one file: a.txt");
+    }
+
+    #[test]
+    fn list_sessions_reports_preview_and_turns() {
+        let dir = tempdir().unwrap();
+        let session = CliSession::create(dir.path().to_path_buf(), 10);
+        session.save(&conversation()).unwrap();
+        std::fs::write(dir.path().join("garbage.json"), "not json").unwrap();
+
+        let sessions = list_sessions(dir.path()).unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].id, session.id());
+        assert_eq!(sessions[0].turns, 2);
+        assert_eq!(sessions[0].preview, "first question");
+    }
+
+    #[test]
+    fn list_sessions_missing_dir_is_empty() {
+        let dir = tempdir().unwrap();
+        assert!(
+            list_sessions(&dir.path().join("absent"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn save_prunes_beyond_retention_but_keeps_current() {
+        let dir = tempdir().unwrap();
+        let mut ids = Vec::new();
+        for i in 0..4 {
+            let session = CliSession::create(dir.path().to_path_buf(), 2);
+            session
+                .save(&[ChatMessage::user(format!("question {i}"))])
+                .unwrap();
+            ids.push(session.id().to_string());
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        let remaining: Vec<String> = list_sessions(dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(remaining, vec![ids[3].clone(), ids[2].clone()]);
+    }
+
+    #[test]
+    fn resume_with_zero_retention_keeps_other_sessions() {
+        let dir = tempdir().unwrap();
+        let mut ids = Vec::new();
+        for i in 0..3 {
+            let session = CliSession::create(dir.path().to_path_buf(), 20);
+            session
+                .save(&[ChatMessage::user(format!("question {i}"))])
+                .unwrap();
+            ids.push(session.id().to_string());
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        let resumed = CliSession::resume(dir.path().to_path_buf(), &ids[0], 0).unwrap();
+        resumed.save(&conversation()).unwrap();
+
+        assert_eq!(list_sessions(dir.path()).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn preview_truncates_and_flattens_whitespace() {
+        assert_eq!(preview_text("a\n  b"), "a b");
+        let long = "x".repeat(PREVIEW_CHARS + 5);
+        let preview = preview_text(&long);
+        assert!(preview.ends_with('…'));
+        assert_eq!(preview.chars().count(), PREVIEW_CHARS + 1);
+    }
+}
@@ -277,7 +277,12 @@ pub fn record_tool_loop_cost_usage(
             }
             true
         } else {
-            false
+            // Last resort: the model capability registry (catalog rows from
+            // `models refresh`, then the built-in table of well-known models).
+            let known = zeroclaw_providers::capabilities::known(model_provider_name, model).rates();
+            input_rate = known.input_per_mtok.unwrap_or(0.0);
+            output_rate = known.output_per_mtok.unwrap_or(0.0);
+            input_rate > 0.0 || output_rate > 0.0
         }
     } else {
         false
@@ -802,4 +807,32 @@ mod tests {
         assert_eq!(recorded.output_tokens, 200);
         assert!((recorded.cost_usd - expected).abs() < 1e-12);
     }
+
+    #[test]
+    fn record_tool_loop_cost_usage_falls_back_to_builtin_capability_prices() {
+        let workspace = tempfile::TempDir::new().unwrap();
+        let tracker = Arc::new(
+            CostTracker::new(
+                zeroclaw_config::schema::CostConfig::default(),
+                workspace.path(),
+            )
+            .unwrap(),
+        );
+        let ctx = ToolLoopCostTrackingContext::new(Arc::clone(&tracker), Arc::new(HashMap::new()));
+        let usage = zeroclaw_providers::traits::TokenUsage {
+            input_tokens: Some(1_000_000),
+            output_tokens: Some(100_000),
+            cached_input_tokens: None,
+        };
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let (_, cost_usd) = runtime
+            .block_on(TOOL_LOOP_COST_TRACKING_CONTEXT.scope(Some(ctx), async {
+                record_tool_loop_cost_usage("openai.default", "gpt-4o-mini-2024-07-18", &usage)
+            }))
+            .expect("cost usage");
+
+        // gpt-4o-mini lists at $0.15 in / $0.60 out per 1M tokens.
+        assert!((cost_usd - (0.15 + 0.06)).abs() < 1e-9);
+    }
 }
@@ -233,15 +233,15 @@ pub fn truncate_tool_message(msg_content: &str, max_chars: usize) -> String {
     truncate_tool_result(msg_content, max_chars)
 }
 
-/// Estimate the token cost of a single message using the ~4 chars/token
-/// heuristic plus ~4 framing tokens (role, delimiters). Single-sourced so the
+/// Estimate the token cost of a single message with the providers' token
+/// counter plus ~4 framing tokens (role, delimiters). Single-sourced so the
 /// history and system-floor estimates stay in lock-step.
 fn estimate_message_tokens(message: &ChatMessage) -> usize {
-    message.content.len().div_ceil(4) + 4
+    zeroclaw_providers::tokens::estimate_tokens(&message.content) + 4
 }
 
-/// Estimate token count for a message history using ~4 chars/token heuristic.
-/// Includes a small overhead per message for role/framing tokens.
+/// Estimate token count for a message history. Includes a small overhead per
+/// message for role/framing tokens.
 pub fn estimate_history_tokens(history: &[ChatMessage]) -> usize {
     history.iter().map(estimate_message_tokens).sum()
 }
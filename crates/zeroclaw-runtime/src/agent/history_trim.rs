@@ -949,6 +949,31 @@ mod tests {
         assert!(r.history.iter().any(|m| m.content.contains("recent short")));
     }
 
+    #[test]
+    fn token_budget_trims_cjk_history_the_char_estimate_let_through() {
+        let model_context_window = 2_000;
+        let budget = model_context_window * 9 / 10;
+        let mut h = vec![sys("system")];
+        for i in 0..7 {
+            let text = format!("第{i}轮：{}", "模型上下文窗口按令牌计算".repeat(12));
+            h.push(user(&text));
+            h.push(asst(&text));
+        }
+
+        // Every CJK character costs at least one token, so this history
+        // overflows the window, yet the old bytes/4 estimate fit the budget.
+        let legacy: usize = h.iter().map(|m| m.content.len().div_ceil(4) + 4).sum();
+        let chars: usize = h.iter().map(|m| m.content.chars().count()).sum();
+        assert!(legacy <= budget, "char estimate would not trim: {legacy}");
+        assert!(chars > model_context_window, "request really overflows");
+
+        let r = trim_to_recent_turns(h, budget);
+        assert!(r.trimmed, "token estimate must trim");
+        assert!(r.tokens_after <= budget);
+        let kept_chars: usize = r.history.iter().map(|m| m.content.chars().count()).sum();
+        assert!(kept_chars < model_context_window);
+    }
+
     #[test]
     fn insert_breadcrumb_deduped_does_not_stack() {
         let mut h = vec![sys("system"), user("turn1"), asst("a1")];
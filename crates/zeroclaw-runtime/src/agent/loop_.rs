@@ -118,6 +118,7 @@ pub(crate) fn live_channel_registry() -> Option<tools::PerToolChannelHandle> {
     Some(Arc::new(parking_lot::RwLock::new(map)))
 }
 use crate::agent::TurnMeta;
+use crate::agent::cli_repl::{ReplCommand, ReplEditor, ReplInput};
 use crate::observability::{self, Observer, ObserverEvent};
 use crate::platform;
 use crate::security::{AutonomyLevel, SecurityPolicy};
@@ -1090,6 +1091,30 @@ pub struct AgentRunOverrides {
     /// (CLI / one-shot), which is correct for callers that have no
     /// cross-turn reuse contract.
     pub mcp_registry: Option<Arc<crate::tools::McpRegistry>>,
+    /// Checkpointed interactive CLI session (`zeroclaw agent` / `--resume`).
+    /// When `Some` and no `session_state_file` is given, the interactive loop
+    /// seeds its history from the session and saves it after every turn.
+    pub cli_session: Option<crate::agent::cli_sessions::CliSession>,
+}
+
+/// Save an interactive CLI checkpoint. A failed write is logged rather than
+/// ending the session: losing the checkpoint is better than losing the REPL.
+fn checkpoint_cli_session(
+    session: &crate::agent::cli_sessions::CliSession,
+    history: &[ChatMessage],
+) {
+    if let Err(e) = session.save(history) {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                .with_attrs(::serde_json::json!({
+                    "session_id": session.id(),
+                    "error": format!("{e:#}"),
+                })),
+            "failed to checkpoint CLI session"
+        );
+    }
 }
 
 fn agent_provider_composite(
@@ -1153,6 +1178,32 @@ fn api_key_and_uri_for_provider(
     )
 }
 
+/// Build the provider for a mid-session switch to `provider_name` /
+/// `model`, resolving credentials the same way as the initial provider.
+fn create_switched_model_provider(
+    config: &zeroclaw_config::schema::Config,
+    agent_alias: &str,
+    provider_name: &str,
+    model: &str,
+    fallback: Option<&zeroclaw_config::schema::ModelProviderConfig>,
+) -> Result<Box<dyn ModelProvider>> {
+    let (api_key, uri) = api_key_and_uri_for_provider(config, provider_name, fallback);
+    zeroclaw_providers::create_routed_model_provider_with_options(
+        config,
+        provider_name,
+        api_key.as_deref(),
+        uri.as_deref(),
+        &config.reliability,
+        &config.model_routes,
+        model,
+        &zeroclaw_providers::options_for_provider_ref(
+            config,
+            provider_name,
+            &zeroclaw_providers::provider_runtime_options_for_agent(config, agent_alias),
+        ),
+    )
+}
+
 #[allow(clippy::too_many_lines, clippy::too_many_arguments)]
 pub async fn run(
     config: Config,
@@ -1237,9 +1288,11 @@ pub async fn run(
         }
         let runtime: Arc<dyn platform::RuntimeAdapter> =
             Arc::from(platform::create_runtime(&config.runtime)?);
+        platform::prepare_runtime(&config.runtime).await?;
         let is_subagent_caller = overrides.is_subagent;
         let suppress_memory_inject = overrides.suppress_memory_inject;
         let memory_free = overrides.memory_free;
+        let cli_session = overrides.cli_session;
         let security = match overrides.security {
             Some(sec) => sec,
             None => Arc::new(SecurityPolicy::for_agent(&config, agent_alias)?),
@@ -1554,6 +1607,10 @@ pub async fn run(
             "Load the full source for an available skill by name. Use when: compact mode only shows a summary and you need the complete skill instructions.",
         ));
         }
+        tool_descs.push((
+            "skill_invoke",
+            "Run an available skill with arguments. Use when: a skill declares <parameters>.",
+        ));
         tool_descs.push((
         "cron_add",
         "Create a cron job. Supports schedule kinds: cron, at, every; and job types: shell or agent.",
@@ -1572,6 +1629,17 @@ pub async fn run(
             "Force-run a cron job immediately and record a run history entry.",
         ));
         tool_descs.push(("cron_runs", "Show recent run history for a cron job."));
+        if config.tasks.enabled {
+            tool_descs.push((
+                "task_spawn",
+                "Run a long job in the background and report progress back to this conversation. Use when: work would outlast a single reply (crawling, large refactors, deep research).",
+            ));
+            tool_descs.push((
+                "task_status",
+                "Show a background task's status and output, or list recent tasks.",
+            ));
+            tool_descs.push(("task_cancel", "Cancel a queued or running background task."));
+        }
         tool_descs.push((
         "screenshot",
         "Capture a screenshot of the current screen. Returns file path and base64-encoded PNG. Use when: visual verification, UI inspection, debugging displays.",
@@ -1955,6 +2023,7 @@ pub async fn run(
                                     handle: mem.as_ref(),
                                     query: effective_msg.clone(),
                                     sessions: vec![memory_session_id.clone()],
+                                    namespaces: Vec::new(),
                                     suppress: suppress_memory_inject,
                                     cfg: crate::agent::memory_inject::MemoryInjectConfig::from_memory_config(
                                         &config.memory,
@@ -2153,70 +2222,157 @@ pub async fn run(
             )();
 
             // Persistent conversation history across turns
+            let cli_session = cli_session.filter(|_| session_state_file.is_none());
             let mut history = if let Some(path) = session_state_file.as_deref() {
                 load_interactive_session_history(path, &system_prompt)?
+            } else if let Some(session) = cli_session.as_ref() {
+                if session.restored_len() > 0 {
+                    println!(
+                        "{}\n",
+                        crate::i18n::get_required_cli_string_with_args(
+                            "cli-agent-session-resumed",
+                            &[
+                                ("id", session.id()),
+                                ("count", session.restored_len().to_string().as_str()),
+                            ],
+                        )
+                    );
+                }
+                session.initial_history(&system_prompt)
             } else {
                 vec![ChatMessage::system(&system_prompt)]
             };
 
+            let mut editor = ReplEditor::new(Some(crate::agent::cli_repl::history_path(
+                &config,
+                agent_alias,
+            )));
             loop {
-                print!("> ");
-                let _ = std::io::stdout().flush();
-
-                let input = {
-                    let stdin = std::io::stdin().lock();
-                    match read_capped_line(stdin, MAX_INTERACTIVE_INPUT_BYTES) {
-                        Ok(CappedLine::Eof) => break,
-                        Ok(CappedLine::Line(s)) => s,
-                        Ok(CappedLine::Truncated) => {
-                            eprintln!(
-                                "\nWarning: input line exceeds {} bytes and was discarded.",
-                                MAX_INTERACTIVE_INPUT_BYTES
-                            );
-                            continue;
-                        }
-                        Err(e) => {
-                            eprintln!("\nError reading input: {e}\n");
-                            break;
-                        }
+                let input = match editor.read_input() {
+                    ReplInput::Line(s) => s,
+                    ReplInput::Interrupted => {
+                        println!("(Type /quit to exit.)");
+                        continue;
                     }
+                    ReplInput::TooLong => {
+                        eprintln!(
+                            "\nWarning: input exceeds {} bytes and was discarded.",
+                            MAX_INTERACTIVE_INPUT_BYTES
+                        );
+                        continue;
+                    }
+                    ReplInput::Eof => break,
                 };
 
                 let user_input = input.trim().to_string();
                 if user_input.is_empty() {
                     continue;
                 }
-                match user_input.as_str() {
-                    "/quit" | "/exit" => break,
-                    "/help" => {
-                        println!("Available commands:");
-                        println!("  /help             Show this help message");
-                        println!("  /clear /new       Clear conversation history");
-                        println!("  /quit /exit       Exit interactive mode");
-                        println!(
-                            "  /think:<level>    Set reasoning depth (off|minimal|low|medium|high|max)\n"
-                        );
+                match crate::agent::cli_repl::parse_repl_command(&user_input) {
+                    Some(ReplCommand::Quit) => break,
+                    Some(ReplCommand::Help) => {
+                        println!("{}", crate::agent::cli_repl::HELP);
                         continue;
                     }
-                    "/clear" | "/new" => {
+                    Some(ReplCommand::ShowModel) => {
+                        println!("Model: {model_name} (provider: {provider_name})\n");
+                        continue;
+                    }
+                    Some(ReplCommand::SetModel(new_model)) => {
+                        match create_switched_model_provider(
+                            &config,
+                            agent_alias,
+                            &provider_name,
+                            &new_model,
+                            agent_model_provider,
+                        ) {
+                            Ok(switched) => {
+                                model_provider = switched;
+                                model_name = new_model;
+                                println!("Switched to model {model_name}.\n");
+                            }
+                            Err(e) => eprintln!("\nCould not switch model: {e}\n"),
+                        }
+                        continue;
+                    }
+                    Some(ReplCommand::ShowProvider) => {
+                        let mut refs: Vec<String> = config
+                            .providers
+                            .models
+                            .iter_entries()
+                            .map(|(family, alias, _)| format!("{family}.{alias}"))
+                            .collect();
+                        refs.sort();
+                        println!("Provider: {provider_name}");
+                        println!("Configured: {}\n", refs.join(", "));
+                        continue;
+                    }
+                    Some(ReplCommand::SetProvider(raw)) => {
+                        let resolved = crate::agent::cli_repl::resolve_provider_ref(&config, &raw)
+                            .and_then(|new_provider| {
+                                // Take the alias's own model when it sets one.
+                                let new_model = new_provider
+                                    .split_once('.')
+                                    .and_then(|(f, a)| config.providers.models.find(f, a))
+                                    .and_then(|entry| entry.model.clone())
+                                    .unwrap_or_else(|| model_name.clone());
+                                create_switched_model_provider(
+                                    &config,
+                                    agent_alias,
+                                    &new_provider,
+                                    &new_model,
+                                    agent_model_provider,
+                                )
+                                .map(|switched| (new_provider, new_model, switched))
+                                .map_err(|e| e.to_string())
+                            });
+                        match resolved {
+                            Ok((new_provider, new_model, switched)) => {
+                                model_provider = switched;
+                                provider_name = new_provider;
+                                model_name = new_model;
+                                println!(
+                                    "Switched to provider {provider_name} (model {model_name}).\n"
+                                );
+                            }
+                            Err(e) => eprintln!("\nCould not switch provider: {e}\n"),
+                        }
+                        continue;
+                    }
+                    Some(ReplCommand::Tools) => {
+                        let mut tools: Vec<(&str, &str)> = tools_registry
+                            .iter()
+                            .map(|tool| {
+                                let summary = tool.description().lines().next().unwrap_or_default();
+                                (tool.name(), summary)
+                            })
+                            .collect();
+                        tools.sort_unstable();
+                        println!("Tools ({}):", tools.len());
+                        for (name, summary) in tools {
+                            println!("  {name:<24} {summary}");
+                        }
+                        println!();
+                        continue;
+                    }
+                    Some(ReplCommand::Save(None)) => {
+                        println!("Usage: /save <file>\n");
+                        continue;
+                    }
+                    Some(ReplCommand::Save(Some(path))) => {
+                        match crate::agent::cli_repl::save_transcript(&path, &history, agent_alias)
+                        {
+                            Ok(()) => println!("Conversation saved to {}.\n", path.display()),
+                            Err(e) => eprintln!("\nCould not save {}: {e}\n", path.display()),
+                        }
+                        continue;
+                    }
+                    Some(ReplCommand::Clear) => {
                         println!(
                             "This will clear the current conversation and delete all session memory."
                         );
                         println!("Core memories (long-term facts/preferences) will be preserved.");
-                        print!("Continue? [y/N] ");
-                        let _ = std::io::stdout().flush();
-
-                        let confirm = {
-                            let stdin = std::io::stdin().lock();
-                            match read_capped_line(stdin, MAX_INTERACTIVE_INPUT_BYTES) {
-                                Ok(CappedLine::Line(s)) => s,
-                                Ok(CappedLine::Truncated) | Ok(CappedLine::Eof) | Err(_) => {
-                                    println!("Cancelled.\n");
-                                    continue;
-                                }
-                            }
-                        };
-                        if !matches!(confirm.trim().to_lowercase().as_str(), "y" | "yes") {
+                        if !editor.confirm("Continue? [y/N] ") {
                             println!("Cancelled.\n");
                             continue;
                         }
@@ -2241,9 +2397,12 @@ pub async fn run(
                         if let Some(path) = session_state_file.as_deref() {
                             save_interactive_session_history(path, &history)?;
                         }
+                        if let Some(session) = cli_session.as_ref() {
+                            checkpoint_cli_session(session, &history);
+                        }
                         continue;
                     }
-                    _ => {}
+                    None => {}
                 }
 
                 // ── Parse thinking directive from interactive input ───
@@ -2513,6 +2672,7 @@ pub async fn run(
                                         handle: mem.as_ref(),
                                         query: effective_input.clone(),
                                         sessions: vec![memory_session_id.clone()],
+                                        namespaces: Vec::new(),
                                         suppress: suppress_memory_inject,
                                         cfg: crate::agent::memory_inject::MemoryInjectConfig::from_memory_config(
                                             &config.memory,
@@ -2553,29 +2713,13 @@ pub async fn run(
                                     )
                                 );
 
-                                let (switch_api_key2, switch_uri2) = api_key_and_uri_for_provider(
+                                model_provider = create_switched_model_provider(
                                     &config,
+                                    agent_alias,
                                     &new_model_provider,
+                                    &new_model,
                                     agent_model_provider,
-                                );
-                                model_provider =
-                                    zeroclaw_providers::create_routed_model_provider_with_options(
-                                        &config,
-                                        &new_model_provider,
-                                        switch_api_key2.as_deref(),
-                                        switch_uri2.as_deref(),
-                                        &config.reliability,
-                                        &config.model_routes,
-                                        &new_model,
-                                        &zeroclaw_providers::options_for_provider_ref(
-                                            &config,
-                                            &new_model_provider,
-                                            &zeroclaw_providers::provider_runtime_options_for_agent(
-                                                &config,
-                                                agent_alias,
-                                            ),
-                                        ),
-                                    )?;
+                                )?;
 
                                 provider_name = new_model_provider;
                                 model_name = new_model;
@@ -2781,6 +2925,21 @@ pub async fn run(
                 if let Some(path) = session_state_file.as_deref() {
                     save_interactive_session_history(path, &history)?;
                 }
+                if let Some(session) = cli_session.as_ref() {
+                    checkpoint_cli_session(session, &history);
+                }
+            }
+
+            if let Some(session) = cli_session.as_ref()
+                && history.iter().any(|m| m.role == "user")
+            {
+                println!(
+                    "{}",
+                    crate::i18n::get_required_cli_string_with_args(
+                        "cli-agent-session-saved",
+                        &[("id", session.id())],
+                    )
+                );
             }
         }
 
@@ -3094,6 +3253,7 @@ pub async fn process_message(
                 "Load the full source for an available skill by name.",
             ));
         }
+        tool_descs.push(("skill_invoke", "Run an available skill with arguments."));
         if config.browser.enabled {
             tool_descs.push(("browser_open", "Open approved URLs in browser."));
         }
@@ -3353,6 +3513,7 @@ pub async fn process_message(
                         handle: mem.as_ref(),
                         query: effective_message.clone(),
                         sessions: vec![session_id.map(str::to_string)],
+                        namespaces: Vec::new(),
                         suppress: false,
                         cfg: crate::agent::memory_inject::MemoryInjectConfig::from_memory_config(
                             &config.memory,
@@ -3902,6 +4063,7 @@ mod tests {
                 tools_registry: &[],
                 activated_tools: None,
                 excluded_tools: &[],
+                iteration: 0,
             },
             &meta,
             &observer,
@@ -3945,6 +4107,7 @@ mod tests {
                 tools_registry: &[],
                 activated_tools: Some(&activated),
                 excluded_tools: &[],
+                iteration: 0,
             },
             &meta,
             &observer,
@@ -3994,6 +4157,7 @@ mod tests {
                 tools_registry: &[],
                 activated_tools: Some(&activated),
                 excluded_tools: &[],
+                iteration: 0,
             },
             &meta,
             &observer,
@@ -4028,6 +4192,7 @@ mod tests {
                 tools_registry: &tools,
                 activated_tools: None,
                 excluded_tools: &[],
+                iteration: 0,
             },
             &meta,
             &observer,
@@ -4083,6 +4248,7 @@ mod tests {
                 tools_registry: &tools,
                 activated_tools: None,
                 excluded_tools: &[],
+                iteration: 0,
             },
             &meta,
             &observer,
@@ -4740,6 +4906,7 @@ mod tests {
     struct CountingTool {
         name: String,
         invocations: Arc<AtomicUsize>,
+        read_only: bool,
     }
 
     impl CountingTool {
@@ -4747,8 +4914,14 @@ mod tests {
             Self {
                 name: name.to_string(),
                 invocations,
+                read_only: false,
             }
         }
+
+        fn read_only(mut self) -> Self {
+            self.read_only = true;
+            self
+        }
     }
 
     #[async_trait]
@@ -4770,6 +4943,10 @@ mod tests {
             })
         }
 
+        fn is_read_only(&self) -> bool {
+            self.read_only
+        }
+
         async fn execute(
             &self,
             args: serde_json::Value,
@@ -5134,6 +5311,120 @@ mod tests {
         assert_eq!(calls.load(Ordering::SeqCst), 0);
     }
 
+    /// One attempt of a channel message: a fresh loop over `history`,
+    /// as the orchestrator re-runs it after a provider failure.
+    async fn run_message_attempt(
+        model_provider: &ScriptedModelProvider,
+        tools_registry: &[Box<dyn Tool>],
+        mut history: Vec<ChatMessage>,
+    ) -> Result<String> {
+        let turn_id = uuid::Uuid::new_v4().to_string();
+        let observer = NoopObserver;
+        run_tool_call_loop(ToolLoop {
+            parent_agent_alias: None,
+            sop_reassembly: None,
+            exec: ResolvedAgentExecution {
+                model_access: ResolvedModelAccess {
+                    model_provider,
+                    provider_name: "mock-provider",
+                    model: "mock-model",
+                    temperature: Some(0.0),
+                },
+                tools_registry,
+                observer: &observer,
+                silent: true,
+                approval: None,
+                multimodal_config: &zeroclaw_config::schema::MultimodalConfig::default(),
+                config: None,
+                max_tool_iterations: 5,
+                hooks: None,
+                excluded_tools: &[],
+                dedup_exempt_tools: &[],
+                activated_tools: None,
+                model_switch_callback: None,
+                pacing: &zeroclaw_config::schema::PacingConfig::default(),
+                strict_tool_parsing: false,
+                parallel_tools: false,
+                max_tool_result_chars: 0,
+                context_token_budget: 0,
+                receipt_generator: None,
+                knobs: &LoopKnobs::default(),
+            },
+            history: &mut history,
+            channel_name: "cli",
+            channel_reply_target: None,
+            cancellation_token: None,
+            on_delta: None,
+            shared_budget: None,
+            channel: None,
+            collected_receipts: None,
+            event_tx: None,
+            steering: None,
+            new_messages_out: None,
+            image_cache: None,
+            memory: None,
+            ingress: IngressContext::sub_turn(),
+            agent_alias: None,
+            turn_id: &turn_id,
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn retried_message_replays_mutating_tool_from_ledger() {
+        let writes = Arc::new(AtomicUsize::new(0));
+        let sends = Arc::new(AtomicUsize::new(0));
+        let reads = Arc::new(AtomicUsize::new(0));
+        let tools_registry: Vec<Box<dyn Tool>> = vec![
+            Box::new(CountingTool::new("write_note", Arc::clone(&writes))),
+            Box::new(CountingTool::new("send_note", Arc::clone(&sends))),
+            Box::new(CountingTool::new("read_note", Arc::clone(&reads)).read_only()),
+        ];
+        let first_round = r#"<tool_call>
+{"name":"write_note","arguments":{"value":"a"}}
+</tool_call>
+<tool_call>
+{"name":"read_note","arguments":{"value":"a"}}
+</tool_call>"#;
+        let second_round = r#"<tool_call>
+{"name":"send_note","arguments":{"value":"a"}}
+</tool_call>"#;
+        let history = vec![ChatMessage::user(
+            "write the note, then send it".to_string(),
+        )];
+        let ledger = Arc::new(crate::agent::tool_ledger::ToolExecutionLedger::new("msg-1"));
+
+        // Attempt one runs the first round, then the provider fails before
+        // the second tool call is issued (the script is exhausted).
+        let failing = ScriptedModelProvider::from_text_responses(vec![first_round]);
+        let first = crate::agent::tool_ledger::scope_ledger(
+            Some(Arc::clone(&ledger)),
+            run_message_attempt(&failing, &tools_registry, history.clone()),
+        )
+        .await;
+        assert!(first.is_err(), "provider failure should fail the attempt");
+
+        // The retry re-issues the same first round, then continues.
+        let retry =
+            ScriptedModelProvider::from_text_responses(vec![first_round, second_round, "done"]);
+        let second = crate::agent::tool_ledger::scope_ledger(
+            Some(Arc::clone(&ledger)),
+            run_message_attempt(&retry, &tools_registry, history),
+        )
+        .await
+        .expect("retry should complete");
+
+        assert_eq!(second, "done");
+        assert_eq!(writes.load(Ordering::SeqCst), 1, "write must not run twice");
+        assert_eq!(sends.load(Ordering::SeqCst), 1);
+        assert_eq!(reads.load(Ordering::SeqCst), 2, "read-only calls re-run");
+        let audit = ledger.to_transcript_json();
+        assert_eq!(audit["calls"].as_array().unwrap().len(), 2);
+        assert_eq!(audit["calls"][0]["tool"], "write_note");
+        assert_eq!(audit["calls"][0]["replays"], 1);
+        assert_eq!(audit["calls"][1]["tool"], "send_note");
+    }
+
     #[tokio::test]
     async fn run_tool_call_loop_skips_oversized_image_payload() {
         let turn_id = uuid::Uuid::new_v4().to_string();
@@ -5853,6 +6144,7 @@ mod tests {
                     handle: mem,
                     query: "what server?".to_string(),
                     sessions: vec![Some("session-1".to_string())],
+                    namespaces: Vec::new(),
                     suppress: false,
                     cfg: crate::agent::memory_inject::MemoryInjectConfig::default(),
                 }),
@@ -14441,6 +14733,7 @@ Let me check the result."#;
             ChatMessage {
                 role: "tool".to_string(),
                 content: "ok".to_string(),
+                reasoning: None,
             },
         ]
     }
@@ -15607,6 +15900,275 @@ Let me check the result."#;
         assert_all_events_share_turn_id(&events, Some("test-agent"), Some("cli"));
     }
 
+    #[tokio::test]
+    async fn run_tool_call_loop_emits_iteration_and_summary_events() {
+        use super::run_tool_call_loop;
+
+        let turn_id = uuid::Uuid::new_v4().to_string();
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let model_provider = ScriptedModelProvider::from_text_responses(vec![
+            r#"<tool_call>
+{"name":"count_tool","arguments":{"value":"X"}}
+</tool_call>"#,
+            "done",
+        ]);
+
+        let tools_registry: Vec<Box<dyn Tool>> = vec![Box::new(CountingTool::new(
+            "count_tool",
+            Arc::clone(&invocations),
+        ))];
+
+        let capturing = Arc::new(CapturingObserver::default());
+        let observer: Arc<dyn Observer> = capturing.clone();
+        let mut history = vec![ChatMessage::system("test"), ChatMessage::user("hello")];
+
+        let result = run_tool_call_loop(ToolLoop {
+            parent_agent_alias: None,
+            sop_reassembly: None,
+            exec: ResolvedAgentExecution {
+                model_access: ResolvedModelAccess {
+                    model_provider: &model_provider,
+                    provider_name: "mock-provider",
+                    model: "mock-model",
+                    temperature: Some(0.0),
+                },
+                tools_registry: &tools_registry,
+                observer: observer.as_ref(),
+                silent: true,
+                approval: None,
+                multimodal_config: &zeroclaw_config::schema::MultimodalConfig::default(),
+                config: None,
+                max_tool_iterations: 10,
+                hooks: None,
+                excluded_tools: &[],
+                dedup_exempt_tools: &[],
+                activated_tools: None,
+                model_switch_callback: None,
+                pacing: &zeroclaw_config::schema::PacingConfig::default(),
+                strict_tool_parsing: false,
+                parallel_tools: false,
+                max_tool_result_chars: 0,
+                context_token_budget: 0,
+                receipt_generator: None,
+                knobs: &LoopKnobs::default(),
+            },
+            history: &mut history,
+            channel_name: "cli",
+            channel_reply_target: None,
+            cancellation_token: None,
+            on_delta: None,
+            shared_budget: None,
+            channel: None,
+            collected_receipts: None,
+            event_tx: None,
+            steering: None,
+            new_messages_out: None,
+            image_cache: None,
+            memory: None,
+            ingress: IngressContext::sub_turn(),
+            agent_alias: Some("test-agent"),
+            turn_id: &turn_id,
+        })
+        .await
+        .expect("tool loop should succeed");
+
+        assert_eq!(result, "done");
+
+        let events = capturing.events.lock();
+        let loop_events: Vec<&ObserverEvent> = events
+            .iter()
+            .filter(|e| {
+                matches!(
+                    e,
+                    ObserverEvent::ToolCall { .. }
+                        | ObserverEvent::ToolLoopIteration { .. }
+                        | ObserverEvent::ToolLoopSummary { .. }
+                )
+            })
+            .collect();
+        assert_eq!(loop_events.len(), 4, "got {loop_events:?}");
+
+        // The tool call lands inside iteration 1, which closes when the
+        // second provider call arrives.
+        assert!(
+            matches!(loop_events[0], ObserverEvent::ToolCall { tool, .. } if tool == "count_tool")
+        );
+        match loop_events[1] {
+            ObserverEvent::ToolLoopIteration {
+                iteration,
+                model_provider,
+                model,
+                llm_success,
+                tools,
+                turn_id: tid,
+                ..
+            } => {
+                assert_eq!(*iteration, 1);
+                assert_eq!(model_provider, "mock-provider");
+                assert_eq!(model, "mock-model");
+                assert!(*llm_success);
+                assert_eq!(tools.len(), 1);
+                assert_eq!(tools[0].tool, "count_tool");
+                assert!(tools[0].success);
+                assert_eq!(tid.as_deref(), Some(turn_id.as_str()));
+            }
+            other => panic!("expected first ToolLoopIteration, got {other:?}"),
+        }
+        match loop_events[2] {
+            ObserverEvent::ToolLoopIteration {
+                iteration, tools, ..
+            } => {
+                assert_eq!(*iteration, 2);
+                assert!(tools.is_empty());
+            }
+            other => panic!("expected second ToolLoopIteration, got {other:?}"),
+        }
+        match loop_events[3] {
+            ObserverEvent::ToolLoopSummary {
+                iterations,
+                tool_calls,
+                tool_failures,
+                success,
+                agent_alias,
+                channel,
+                ..
+            } => {
+                assert_eq!(*iterations, 2);
+                assert_eq!(*tool_calls, 1);
+                assert_eq!(*tool_failures, 0);
+                assert!(*success);
+                assert_eq!(agent_alias.as_deref(), Some("test-agent"));
+                assert_eq!(channel.as_deref(), Some("cli"));
+            }
+            other => panic!("expected ToolLoopSummary, got {other:?}"),
+        }
+    }
+
+    /// Drive `run_tool_call_loop` with a model that issues the same
+    /// `count_tool` call `repeats` times before answering "done".
+    async fn run_repeating_tool_loop(
+        repeats: usize,
+    ) -> (
+        anyhow::Result<String>,
+        Vec<ChatMessage>,
+        Vec<ObserverEvent>,
+        usize,
+    ) {
+        use super::run_tool_call_loop;
+
+        let turn_id = uuid::Uuid::new_v4().to_string();
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let call = r#"<tool_call>
+{"name":"count_tool","arguments":{"value":"same"}}
+</tool_call>"#;
+        let mut script = vec![call; repeats];
+        script.push("done");
+        let model_provider = ScriptedModelProvider::from_text_responses(script);
+        let tools_registry: Vec<Box<dyn Tool>> = vec![Box::new(CountingTool::new(
+            "count_tool",
+            Arc::clone(&invocations),
+        ))];
+        let capturing = Arc::new(CapturingObserver::default());
+        let observer: Arc<dyn Observer> = capturing.clone();
+        let mut history = vec![ChatMessage::system("test"), ChatMessage::user("hello")];
+
+        let result = run_tool_call_loop(ToolLoop {
+            parent_agent_alias: None,
+            sop_reassembly: None,
+            exec: ResolvedAgentExecution {
+                model_access: ResolvedModelAccess {
+                    model_provider: &model_provider,
+                    provider_name: "mock-provider",
+                    model: "mock-model",
+                    temperature: Some(0.0),
+                },
+                tools_registry: &tools_registry,
+                observer: observer.as_ref(),
+                silent: true,
+                approval: None,
+                multimodal_config: &zeroclaw_config::schema::MultimodalConfig::default(),
+                config: None,
+                max_tool_iterations: 10,
+                hooks: None,
+                excluded_tools: &[],
+                dedup_exempt_tools: &[],
+                activated_tools: None,
+                model_switch_callback: None,
+                pacing: &zeroclaw_config::schema::PacingConfig::default(),
+                strict_tool_parsing: false,
+                parallel_tools: false,
+                max_tool_result_chars: 0,
+                context_token_budget: 0,
+                receipt_generator: None,
+                knobs: &LoopKnobs::default(),
+            },
+            history: &mut history,
+            channel_name: "cli",
+            channel_reply_target: None,
+            cancellation_token: None,
+            on_delta: None,
+            shared_budget: None,
+            channel: None,
+            collected_receipts: None,
+            event_tx: None,
+            steering: None,
+            new_messages_out: None,
+            image_cache: None,
+            memory: None,
+            ingress: IngressContext::sub_turn(),
+            agent_alias: Some("test-agent"),
+            turn_id: &turn_id,
+        })
+        .await;
+
+        let events = capturing.events.lock().clone();
+        (result, history, events, invocations.load(Ordering::SeqCst))
+    }
+
+    fn loop_detection_actions(events: &[ObserverEvent]) -> Vec<&str> {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                ObserverEvent::ToolLoopDetected { tool, action, .. } => {
+                    assert_eq!(tool, "count_tool");
+                    Some(action.as_str())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn repeated_identical_tool_call_gets_a_nudge_and_the_turn_continues() {
+        let (result, history, events, invocations) = run_repeating_tool_loop(3).await;
+
+        assert_eq!(result.expect("tool loop should succeed"), "done");
+        assert_eq!(invocations, 3);
+        assert!(
+            history.iter().any(|m| m.role == "system"
+                && m.content.contains("[Loop Detection]")
+                && m.content.contains("result has not changed")),
+            "expected a loop-detection nudge in history: {history:?}"
+        );
+        assert_eq!(loop_detection_actions(&events), vec!["nudge"]);
+    }
+
+    #[tokio::test]
+    async fn identical_tool_call_that_keeps_repeating_aborts_with_a_targeted_error() {
+        let (result, _history, events, invocations) = run_repeating_tool_loop(8).await;
+
+        let err = result.expect_err("loop detector should abort the turn");
+        let msg = format!("{err:#}");
+        assert!(msg.contains("loop detector"), "got: {msg}");
+        assert!(msg.contains("count_tool"), "got: {msg}");
+        // Nudge at 3, block at 4, abort at 5 — well before the iteration cap.
+        assert_eq!(invocations, 5);
+        assert_eq!(
+            loop_detection_actions(&events),
+            vec!["nudge", "block", "abort"]
+        );
+    }
+
     #[tokio::test]
     async fn agent_turn_propagates_resolved_agent_alias_to_observer_events() {
         // Regression guard: process_message resolves agent_alias but
@@ -152,12 +152,13 @@ impl LoopDetector {
         }
 
         let last = self.window.back()?;
-        let consecutive = self
+        let repeats: Vec<&ToolCallRecord> = self
             .window
             .iter()
             .rev()
             .take_while(|r| r.name == last.name && r.args_hash == last.args_hash)
-            .count();
+            .collect();
+        let consecutive = repeats.len();
 
         if consecutive >= max + 2 {
             Some(LoopDetectionResult::Break(format!(
@@ -170,10 +171,17 @@ impl LoopDetector {
                 last.name, consecutive
             )))
         } else if consecutive >= max {
+            let unchanged = repeats.iter().all(|r| r.result_hash == last.result_hash);
             Some(LoopDetectionResult::Warning(format!(
-                "Warning: tool '{}' has been called {} times consecutively with identical arguments. \
-                 Try a different approach.",
-                last.name, consecutive
+                "Warning: tool '{}' has been called {} times consecutively with identical arguments{}. \
+                 Do not call it again with these arguments; proceed without it or try a different approach.",
+                last.name,
+                consecutive,
+                if unchanged {
+                    " and the result has not changed"
+                } else {
+                    ""
+                }
             )))
         } else {
             None
@@ -238,7 +246,7 @@ impl LoopDetector {
         } else {
             Some(LoopDetectionResult::Warning(format!(
                 "Warning: tools '{}' and '{}' appear to be alternating ({} cycles). \
-                 Consider a different strategy.",
+                 Stop alternating between them and proceed with the results you have.",
                 a_name, b_name, cycles
             )))
         }
@@ -334,6 +342,22 @@ mod tests {
             LoopDetectionResult::Warning(msg) => {
                 assert!(msg.contains("file_read"));
                 assert!(msg.contains("3 times"));
+                assert!(msg.contains("result has not changed"));
+            }
+            other => panic!("expected Warning, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn exact_repeat_warning_only_claims_unchanged_result_when_true() {
+        let mut det = LoopDetector::new(config_with_repeats(3));
+        let args = json!({"url": "https://example.com/status"});
+
+        det.record("http_request", &args, "pending");
+        det.record("http_request", &args, "pending");
+        match det.record("http_request", &args, "done") {
+            LoopDetectionResult::Warning(msg) => {
+                assert!(!msg.contains("result has not changed"), "{msg}");
             }
             other => panic!("expected Warning, got {other:?}"),
         }
@@ -17,7 +17,7 @@
 //! replacing decay on that arm) -> relevance filter -> skip set (autosave
 //! keys/content, `*_history` keys, `[IMAGE:` markers, `<tool_result`
 //! blocks, optional Conversation-category exclusion) -> budget caps (entry
-//! count, per-entry chars, total chars) -> `[Memory context]` wrapper.
+//! count, per-entry chars, total tokens) -> `[Memory context]` wrapper.
 //! Exactly one `MemoryRecall` observer event is emitted per render,
 //! covering all recalls.
 
@@ -42,8 +42,8 @@ pub const DEFAULT_RECALL_LIMIT: usize = 5;
 pub const DEFAULT_MAX_ENTRIES: usize = 4;
 /// Default per-entry character cap before ellipsis truncation.
 pub const DEFAULT_ENTRY_MAX_CHARS: usize = 800;
-/// Default total character budget for the rendered block.
-pub const DEFAULT_MAX_TOTAL_CHARS: usize = 4_000;
+/// Default total token budget for the rendered block.
+pub const DEFAULT_MAX_TOTAL_TOKENS: usize = 1_000;
 
 /// The stable, per-agent-config half of the injection policy. Callers build
 /// it from the agent's resolved memory config; the per-turn half (origin,
@@ -61,8 +61,9 @@ pub struct MemoryInjectConfig {
     pub max_entries: usize,
     /// Per-entry character cap (ellipsis-truncated beyond it).
     pub entry_max_chars: usize,
-    /// Total character budget for the block.
-    pub max_total_chars: usize,
+    /// Total token budget for the block, counted with the providers' token
+    /// counter so CJK entries are not admitted at several times their cost.
+    pub max_total_tokens: usize,
     /// Gate for the rerank stage. Off (the default) keeps the pipeline on
     /// the time-decay arm, byte-identical to the pre-rerank renderer.
     pub rerank_enabled: bool,
@@ -81,7 +82,7 @@ impl Default for MemoryInjectConfig {
             min_relevance_score: 0.0,
             max_entries: DEFAULT_MAX_ENTRIES,
             entry_max_chars: DEFAULT_ENTRY_MAX_CHARS,
-            max_total_chars: DEFAULT_MAX_TOTAL_CHARS,
+            max_total_tokens: DEFAULT_MAX_TOTAL_TOKENS,
             rerank_enabled: false,
             candidate_multiplier: 1,
             rerank: rerank::RerankConfig::disabled(DEFAULT_RECALL_LIMIT, 0.0),
@@ -175,6 +176,9 @@ pub struct TurnMemory<'a> {
     /// Session scopes to recall from (multi-scope recalls are key-deduped
     /// in order). Empty means one unscoped recall.
     pub sessions: Vec<Option<String>>,
+    /// Namespaces recall is restricted to (`[memory] scope`): the turn's own
+    /// plus the shared namespace. Empty means unrestricted.
+    pub namespaces: Vec<String>,
     /// Spawn-site opt-out (e.g. a cron job with `uses_memory = false`).
     pub suppress: bool,
     /// The stable config half (limits, relevance floor, budgets).
@@ -257,6 +261,33 @@ pub async fn render_memory_context(
     cfg: &MemoryInjectConfig,
     exclude_conversation: bool,
     turn: TurnMeta<'_>,
+) -> String {
+    render_scoped_memory_context(
+        mem,
+        observer,
+        user_msg,
+        sessions,
+        &[],
+        cfg,
+        exclude_conversation,
+        turn,
+    )
+    .await
+}
+
+/// [`render_memory_context`] restricted to `namespaces`: each session scope
+/// is recalled once per namespace and the results are key-deduped in order.
+/// An empty slice recalls across every namespace.
+#[allow(clippy::too_many_arguments)]
+pub async fn render_scoped_memory_context(
+    mem: &dyn Memory,
+    observer: &dyn Observer,
+    user_msg: &str,
+    sessions: &[Option<&str>],
+    namespaces: &[String],
+    cfg: &MemoryInjectConfig,
+    exclude_conversation: bool,
+    turn: TurnMeta<'_>,
 ) -> String {
     let backend = mem.name().to_string();
     let query_summary = make_query_summary(user_msg);
@@ -284,21 +315,35 @@ pub async fn render_memory_context(
     } else {
         sessions
     };
+    let namespace_filter: Vec<Option<&str>> = if namespaces.is_empty() {
+        vec![None]
+    } else {
+        namespaces.iter().map(|ns| Some(ns.as_str())).collect()
+    };
     for session_id in scopes {
-        match mem
-            .recall(user_msg, recall_limit, *session_id, None, None)
-            .await
-        {
-            Ok(recalled) => {
-                any_ok = true;
-                for entry in recalled {
-                    if seen_keys.insert(entry.key.clone()) {
-                        entries.push(entry);
+        for namespace in &namespace_filter {
+            let recalled = match namespace {
+                Some(ns) => {
+                    mem.recall_namespaced(ns, user_msg, recall_limit, *session_id, None, None)
+                        .await
+                }
+                None => {
+                    mem.recall(user_msg, recall_limit, *session_id, None, None)
+                        .await
+                }
+            };
+            match recalled {
+                Ok(recalled) => {
+                    any_ok = true;
+                    for entry in recalled {
+                        if seen_keys.insert(entry.key.clone()) {
+                            entries.push(entry);
+                        }
                     }
                 }
-            }
-            Err(_) => {
-                // Swallowed: memory failure must not fail the turn.
+                Err(_) => {
+                    // Swallowed: memory failure must not fail the turn.
+                }
             }
         }
     }
@@ -336,7 +381,7 @@ pub async fn render_memory_context(
 
     let mut context = String::new();
     let mut included = 0usize;
-    let mut used_chars = 0usize;
+    let mut used_tokens = 0usize;
 
     for entry in entries.iter().filter(|e| match e.score {
         Some(score) => score >= cfg.min_relevance_score,
@@ -360,8 +405,8 @@ pub async fn render_memory_context(
 
         let mut line = String::new();
         let _ = writeln!(line, "- {}: {}", entry.key, content);
-        let line_chars = line.chars().count();
-        if used_chars + line_chars > cfg.max_total_chars {
+        let line_tokens = zeroclaw_providers::tokens::estimate_tokens(&line);
+        if used_tokens + line_tokens > cfg.max_total_tokens {
             break;
         }
 
@@ -370,7 +415,7 @@ pub async fn render_memory_context(
             context.push('\n');
         }
         context.push_str(&line);
-        used_chars += line_chars;
+        used_tokens += line_tokens;
         included += 1;
     }
 
@@ -899,6 +944,43 @@ mod tests {
         assert!(context.contains("- unscored: keyword backend"));
     }
 
+    #[tokio::test]
+    async fn scoped_recall_sees_own_and_shared_namespaces_only() {
+        let in_namespace = |key: &str, content: &str, namespace: &str| MemoryEntry {
+            namespace: namespace.into(),
+            ..entry(key, content, MemoryCategory::Core, None)
+        };
+        let mem = FixtureMemory::with(vec![
+            in_namespace("alice_pref", "alice likes tea", "channel:discord:alice"),
+            in_namespace("bob_secret", "bob's private note", "channel:discord:bob"),
+            in_namespace("team_fact", "standup is at 10", "shared"),
+            in_namespace("legacy", "global fact", "default"),
+        ]);
+        let observer = RecordingObserver::default();
+
+        let context = render_scoped_memory_context(
+            &mem,
+            &observer,
+            "query",
+            &[],
+            &["channel:discord:alice".to_string(), "shared".to_string()],
+            &MemoryInjectConfig::default(),
+            false,
+            TurnMeta {
+                parent_agent_alias: None,
+                agent_alias: None,
+                turn_id: "t",
+                channel_name: "discord",
+            },
+        )
+        .await;
+
+        assert!(context.contains("- alice_pref: alice likes tea"));
+        assert!(context.contains("- team_fact: standup is at 10"));
+        assert!(!context.contains("bob_secret"), "{context}");
+        assert!(!context.contains("legacy"), "{context}");
+    }
+
     #[tokio::test]
     async fn budgets_cap_entry_count_and_truncate_long_content() {
         let long = "x".repeat(900);
@@ -946,7 +1028,7 @@ mod tests {
         let observer = RecordingObserver::default();
 
         let cfg = MemoryInjectConfig {
-            max_total_chars: 1_500,
+            max_total_tokens: 375,
             ..Default::default()
         };
         let context = render_memory_context(
@@ -970,6 +1052,42 @@ mod tests {
         assert!(!context.contains("- c: "));
     }
 
+    #[tokio::test]
+    async fn total_budget_counts_cjk_entries_in_tokens() {
+        // 400 ideographs is ~1.2k bytes but ~400 tokens; a 4k-char budget
+        // would admit all four entries at ~1.6k tokens.
+        let chunk = "记忆".repeat(200);
+        let mem = FixtureMemory::with(vec![
+            entry("a", &chunk, MemoryCategory::Core, None),
+            entry("b", &chunk, MemoryCategory::Core, None),
+            entry("c", &chunk, MemoryCategory::Core, None),
+            entry("d", &chunk, MemoryCategory::Core, None),
+        ]);
+        let observer = RecordingObserver::default();
+
+        let context = render_memory_context(
+            &mem,
+            &observer,
+            "query",
+            &[],
+            &MemoryInjectConfig::default(),
+            false,
+            TurnMeta {
+                parent_agent_alias: None,
+                agent_alias: None,
+                turn_id: "t",
+                channel_name: "test",
+            },
+        )
+        .await;
+
+        assert!(context.contains("- b: "));
+        assert!(!context.contains("- c: "));
+        assert!(
+            zeroclaw_providers::tokens::estimate_tokens(&context) <= DEFAULT_MAX_TOTAL_TOKENS + 20
+        );
+    }
+
     #[tokio::test]
     async fn multi_session_recall_dedups_by_key_in_order() {
         let mut by_session = HashMap::new();
@@ -1305,12 +1423,12 @@ mod tests {
                 GOLDEN_TIGHT_BUDGET,
             ),
             (
-                "tight_total_char_budget",
+                "tight_total_token_budget",
                 TurnOrigin::Interactive,
                 true,
                 false,
                 MemoryInjectConfig {
-                    max_total_chars: 100,
+                    max_total_tokens: 26,
                     ..flags_off
                 },
                 GOLDEN_TIGHT_BUDGET,
@@ -2,6 +2,8 @@
 pub mod agent;
 pub(crate) mod approval_bridge;
 pub mod classifier;
+pub mod cli_repl;
+pub mod cli_sessions;
 pub mod context_analyzer;
 pub mod cost;
 pub mod dispatcher;
@@ -17,9 +19,11 @@ pub mod personality;
 pub mod personality_templates;
 pub mod pricing_catalog;
 pub mod prompt;
+pub mod prompt_cache;
 pub mod system_prompt;
 pub mod thinking;
 pub mod tool_execution;
+pub mod tool_ledger;
 pub mod tool_receipts;
 pub(crate) mod turn;
 
@@ -481,6 +481,7 @@ mod tests {
             }],
             prompts: vec!["Run smoke tests before deploy.".into()],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         }];
 
@@ -530,6 +531,7 @@ mod tests {
             }],
             prompts: vec!["Run smoke tests before deploy.".into()],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: Some(Path::new("/tmp/workspace/skills/deploy/SKILL.md").to_path_buf()),
         }];
 
@@ -612,6 +614,7 @@ mod tests {
             }],
             prompts: vec!["Use <tool_call> and & keep output \"safe\"".into()],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         }];
         let ctx = PromptContext {
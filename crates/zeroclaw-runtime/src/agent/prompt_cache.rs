@@ -0,0 +1,277 @@
+//! Cache for the file-backed sections of the system prompt.
+//!
+//! Rebuilding the prompt re-reads the workspace bootstrap files (AGENTS.md,
+//! SOUL.md, ...) and re-renders skills. [`PromptSectionCache`] keeps each
+//! rendered section next to a stamp (path, mtime, size) of every file it was
+//! built from, and serves the cached text while the stamps still match. The
+//! date and runtime sections are never cached; builders render them per call.
+//!
+//! One cache belongs to one runtime context, whose config is fixed, so config
+//! values that shape a section but are not part of its key (skill bundles,
+//! open-skills settings) never change under a live entry.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+/// Hit and miss counters across both section kinds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PromptCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Identity of one input file at render time. A missing file stamps as
+/// `None`, so creating it later is a change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileStamp {
+    path: PathBuf,
+    stamp: Option<(SystemTime, u64)>,
+}
+
+impl FileStamp {
+    fn of(path: PathBuf) -> Self {
+        let stamp = std::fs::metadata(&path)
+            .ok()
+            .and_then(|meta| Some((meta.modified().ok()?, meta.len())));
+        Self { path, stamp }
+    }
+}
+
+/// Workspace, persona directory, per-file budget, and whether MEMORY.md is
+/// injected.
+type ProjectContextKey = (PathBuf, Option<PathBuf>, usize, bool);
+
+struct CachedSection {
+    stamps: Vec<FileStamp>,
+    rendered: Arc<str>,
+}
+
+#[derive(Default)]
+pub struct PromptSectionCache {
+    project_context: Mutex<HashMap<ProjectContextKey, CachedSection>>,
+    skills: Mutex<HashMap<(PathBuf, String), CachedSection>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl PromptSectionCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stats(&self) -> PromptCacheStats {
+        PromptCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// The rendered workspace-file block of the Project Context section,
+    /// optionally with a persona directory's files overriding the workspace
+    /// ones (see [`crate::agent::system_prompt::persona_dir`]).
+    pub fn project_context(
+        &self,
+        workspace_dir: &Path,
+        persona_dir: Option<&Path>,
+        max_chars_per_file: usize,
+        inject_memory: bool,
+    ) -> Arc<str> {
+        let mut names: Vec<&str> = crate::agent::system_prompt::BOOTSTRAP_FILES.to_vec();
+        names.push("BOOTSTRAP.md");
+        if inject_memory {
+            names.push("MEMORY.md");
+        }
+        let mut stamps: Vec<FileStamp> = names
+            .iter()
+            .map(|name| FileStamp::of(workspace_dir.join(name)))
+            .collect();
+        if let Some(dir) = persona_dir {
+            stamps.extend(
+                crate::agent::system_prompt::PERSONA_FILES
+                    .iter()
+                    .map(|name| FileStamp::of(dir.join(name))),
+            );
+        }
+        let key = (
+            workspace_dir.to_path_buf(),
+            persona_dir.map(Path::to_path_buf),
+            max_chars_per_file,
+            inject_memory,
+        );
+        self.lookup(&self.project_context, key, stamps, || {
+            crate::agent::system_prompt::render_openclaw_bootstrap_files(
+                workspace_dir,
+                persona_dir,
+                max_chars_per_file,
+                inject_memory,
+            )
+        })
+    }
+
+    /// The rendered skills section for `agent_alias`, empty when the agent
+    /// has no skills. Rebuilt when any file under a skill source directory
+    /// is added, removed, or modified.
+    pub fn skills_section(
+        &self,
+        workspace_dir: &Path,
+        config: &zeroclaw_config::schema::Config,
+        agent_alias: &str,
+    ) -> Arc<str> {
+        let mut stamps = Vec::new();
+        for dir in crate::skills::skill_source_dirs(workspace_dir, config, agent_alias) {
+            stamp_tree(&dir, &mut stamps);
+        }
+        let key = (workspace_dir.to_path_buf(), agent_alias.to_string());
+        self.lookup(&self.skills, key, stamps, || {
+            let skills = crate::skills::load_skills_for_agent(workspace_dir, config, agent_alias);
+            crate::skills::skills_to_prompt_with_mode(
+                &skills,
+                workspace_dir,
+                config.effective_skills_prompt_mode(agent_alias),
+            )
+        })
+    }
+
+    fn lookup<K: std::hash::Hash + Eq>(
+        &self,
+        map: &Mutex<HashMap<K, CachedSection>>,
+        key: K,
+        stamps: Vec<FileStamp>,
+        render: impl FnOnce() -> String,
+    ) -> Arc<str> {
+        let mut map = map.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(cached) = map.get(&key)
+            && cached.stamps == stamps
+        {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Arc::clone(&cached.rendered);
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let rendered: Arc<str> = render().into();
+        map.insert(
+            key,
+            CachedSection {
+                stamps,
+                rendered: Arc::clone(&rendered),
+            },
+        );
+        rendered
+    }
+}
+
+/// Stamp `dir` and everything below it in a stable order. Symlinked
+/// directories are stamped but not descended into.
+fn stamp_tree(dir: &Path, stamps: &mut Vec<FileStamp>) {
+    stamps.push(FileStamp::of(dir.to_path_buf()));
+    let Ok(read) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut entries: Vec<_> = read.flatten().collect();
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let path = entry.path();
+        if entry.file_type().is_ok_and(|t| t.is_dir()) {
+            stamp_tree(&path, stamps);
+        } else {
+            stamps.push(FileStamp::of(path));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::system_prompt::build_system_prompt_cached;
+    use crate::security::AutonomyLevel;
+    use zeroclaw_config::schema::{RiskProfileConfig, SkillsPromptInjectionMode};
+
+    fn build(cache: &PromptSectionCache, workspace: &Path) -> String {
+        let autonomy = RiskProfileConfig {
+            level: AutonomyLevel::Supervised,
+            ..Default::default()
+        };
+        build_system_prompt_cached(
+            cache,
+            workspace,
+            "test-model",
+            &[],
+            &[],
+            None,
+            None,
+            Some(&autonomy),
+            false,
+            SkillsPromptInjectionMode::Full,
+            false,
+            0,
+            true,
+            false,
+        )
+    }
+
+    #[test]
+    fn thousand_consecutive_builds_hit_the_cache() {
+        let workspace = tempfile::TempDir::new().unwrap();
+        std::fs::write(workspace.path().join("SOUL.md"), "Be kind.").unwrap();
+        std::fs::write(workspace.path().join("AGENTS.md"), "Follow the plan.").unwrap();
+        let cache = PromptSectionCache::new();
+
+        let started = std::time::Instant::now();
+        for _ in 0..1000 {
+            let prompt = build(&cache, workspace.path());
+            assert!(prompt.contains("Be kind."));
+            assert!(prompt.contains("## Current Date"));
+        }
+        let elapsed = started.elapsed();
+
+        assert_eq!(
+            cache.stats(),
+            PromptCacheStats {
+                hits: 999,
+                misses: 1
+            },
+            "1000 builds took {elapsed:?}"
+        );
+    }
+
+    #[test]
+    fn editing_soul_md_invalidates_the_cached_context() {
+        let workspace = tempfile::TempDir::new().unwrap();
+        let soul = workspace.path().join("SOUL.md");
+        std::fs::write(&soul, "Be kind.").unwrap();
+        let cache = PromptSectionCache::new();
+
+        assert!(build(&cache, workspace.path()).contains("Be kind."));
+        std::fs::write(&soul, "Be terse and precise.").unwrap();
+        let prompt = build(&cache, workspace.path());
+
+        assert!(prompt.contains("Be terse and precise."));
+        assert!(!prompt.contains("Be kind."));
+        assert_eq!(cache.stats().misses, 2);
+    }
+
+    #[test]
+    fn adding_a_skill_invalidates_the_cached_skills_section() {
+        let workspace = tempfile::TempDir::new().unwrap();
+        let config = zeroclaw_config::schema::Config::default();
+        let write_skill = |name: &str| {
+            let dir = workspace.path().join("skills").join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("SKILL.md"), format!("# {name}\n")).unwrap();
+        };
+
+        write_skill("alpha");
+        let cache = PromptSectionCache::new();
+        let first = cache.skills_section(workspace.path(), &config, "default");
+        let again = cache.skills_section(workspace.path(), &config, "default");
+        assert!(first.contains("alpha"));
+        assert!(Arc::ptr_eq(&first, &again));
+
+        write_skill("beta");
+        let second = cache.skills_section(workspace.path(), &config, "default");
+        assert!(second.contains("beta"));
+        assert_eq!(cache.stats(), PromptCacheStats { hits: 1, misses: 2 });
+    }
+}
@@ -7,13 +7,62 @@ use crate::security::AutonomyLevel;
 use crate::skills::Skill;
 
 /// Maximum characters per injected workspace file (matches `OpenClaw` default).
+/// Enforced as a token budget of a quarter of this (see
+/// [`inject_workspace_file`]), so non-Latin files are not let through at
+/// several times the intended cost.
 pub const BOOTSTRAP_MAX_CHARS: usize = 20_000;
 pub const NO_TOOLS_TASK_FRAMING: &str = "No tools are available for this turn";
 pub const NATIVE_TOOLS_TASK_FRAMING: &str = "Use tools when the request requires action";
 
+/// Workspace files injected on every build, in prompt order.
+pub(crate) const BOOTSTRAP_FILES: [&str; 5] =
+    ["AGENTS.md", "SOUL.md", "TOOLS.md", "IDENTITY.md", "USER.md"];
+
+/// Workspace subdirectory holding named personas, one directory each.
+pub const PERSONAS_DIR: &str = "personas";
+
+/// Bootstrap files a persona directory may replace. Whatever the persona
+/// does not provide (typically TOOLS.md) comes from the workspace.
+pub(crate) const PERSONA_FILES: [&str; 3] = ["SOUL.md", "TOOLS.md", "IDENTITY.md"];
+
+/// Names of the personas under `<workspace>/personas/`, sorted. A directory
+/// counts as a persona once it has a SOUL.md.
+pub fn list_personas(workspace_dir: &std::path::Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(workspace_dir.join(PERSONAS_DIR)) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().join("SOUL.md").is_file())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| is_valid_persona_name(name))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Directory of persona `name`, or `None` when no such persona exists.
+pub fn persona_dir(workspace_dir: &std::path::Path, name: &str) -> Option<std::path::PathBuf> {
+    if !is_valid_persona_name(name) {
+        return None;
+    }
+    let dir = workspace_dir.join(PERSONAS_DIR).join(name);
+    dir.join("SOUL.md").is_file().then_some(dir)
+}
+
+/// A persona name is a single plain path component, so a chat command can
+/// never point the prompt builder outside `personas/`.
+fn is_valid_persona_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('.')
+        && !name.contains(['/', '\\'])
+        && !name.eq_ignore_ascii_case("default")
+}
+
 fn load_openclaw_bootstrap_files(
     prompt: &mut String,
     workspace_dir: &std::path::Path,
+    persona_dir: Option<&std::path::Path>,
     max_chars_per_file: usize,
     inject_memory: bool,
 ) {
@@ -21,10 +70,11 @@ fn load_openclaw_bootstrap_files(
         "The following workspace files define your identity, behavior, and context. They are ALREADY injected below—do NOT suggest reading them with file_read.\n\n",
     );
 
-    let bootstrap_files = ["AGENTS.md", "SOUL.md", "TOOLS.md", "IDENTITY.md", "USER.md"];
-
-    for filename in &bootstrap_files {
-        inject_workspace_file(prompt, workspace_dir, filename, max_chars_per_file);
+    for filename in &BOOTSTRAP_FILES {
+        let source_dir = persona_dir
+            .filter(|dir| PERSONA_FILES.contains(filename) && dir.join(filename).is_file())
+            .unwrap_or(workspace_dir);
+        inject_workspace_file(prompt, source_dir, filename, max_chars_per_file);
     }
 
     // BOOTSTRAP.md — only if it exists (first-run ritual)
@@ -41,6 +91,26 @@ fn load_openclaw_bootstrap_files(
     }
 }
 
+/// The rendered workspace-file block of the Project Context section, with
+/// the persona's SOUL/IDENTITY/TOOLS files in place of the workspace ones
+/// when `persona_dir` is set.
+pub(crate) fn render_openclaw_bootstrap_files(
+    workspace_dir: &std::path::Path,
+    persona_dir: Option<&std::path::Path>,
+    max_chars_per_file: usize,
+    inject_memory: bool,
+) -> String {
+    let mut rendered = String::new();
+    load_openclaw_bootstrap_files(
+        &mut rendered,
+        workspace_dir,
+        persona_dir,
+        max_chars_per_file,
+        inject_memory,
+    );
+    rendered
+}
+
 pub fn build_system_prompt(
     workspace_dir: &std::path::Path,
     model_name: &str,
@@ -142,6 +212,89 @@ pub fn build_system_prompt_with_mode_and_autonomy(
     // response. When `false` (default), the system prompt instructs
     // the model to treat tool calls as invisible infrastructure.
     show_tool_calls: bool,
+) -> String {
+    build_system_prompt_sections(
+        None,
+        workspace_dir,
+        model_name,
+        tools,
+        skills,
+        identity_config,
+        bootstrap_max_chars,
+        autonomy_config,
+        native_tool_specs_present,
+        skills_prompt_mode,
+        compact_context,
+        max_system_prompt_chars,
+        inject_memory,
+        show_tool_calls,
+    )
+}
+
+/// Like [`build_system_prompt_with_mode_and_autonomy`], but the workspace
+/// files come from `cache` and are only re-read from disk when one of them
+/// changed. The date and runtime sections are rendered fresh on every call.
+#[allow(clippy::too_many_arguments)]
+pub fn build_system_prompt_cached(
+    cache: &crate::agent::prompt_cache::PromptSectionCache,
+    workspace_dir: &std::path::Path,
+    model_name: &str,
+    tools: &[(&str, &str)],
+    skills: &[Skill],
+    identity_config: Option<&zeroclaw_config::schema::IdentityConfig>,
+    bootstrap_max_chars: Option<usize>,
+    autonomy_config: Option<&zeroclaw_config::schema::RiskProfileConfig>,
+    native_tool_specs_present: bool,
+    skills_prompt_mode: zeroclaw_config::schema::SkillsPromptInjectionMode,
+    compact_context: bool,
+    max_system_prompt_chars: usize,
+    // When `false`, `MEMORY.md` is omitted from the injected bootstrap files.
+    // Set to `false` for isolated / ACP sessions that use `exclude_memory`.
+    inject_memory: bool,
+    // When `true`, the model is allowed to narrate tool usage in its
+    // response. When `false` (default), the system prompt instructs
+    // the model to treat tool calls as invisible infrastructure.
+    show_tool_calls: bool,
+) -> String {
+    build_system_prompt_sections(
+        Some(cache),
+        workspace_dir,
+        model_name,
+        tools,
+        skills,
+        identity_config,
+        bootstrap_max_chars,
+        autonomy_config,
+        native_tool_specs_present,
+        skills_prompt_mode,
+        compact_context,
+        max_system_prompt_chars,
+        inject_memory,
+        show_tool_calls,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_system_prompt_sections(
+    cache: Option<&crate::agent::prompt_cache::PromptSectionCache>,
+    workspace_dir: &std::path::Path,
+    model_name: &str,
+    tools: &[(&str, &str)],
+    skills: &[Skill],
+    identity_config: Option<&zeroclaw_config::schema::IdentityConfig>,
+    bootstrap_max_chars: Option<usize>,
+    autonomy_config: Option<&zeroclaw_config::schema::RiskProfileConfig>,
+    native_tool_specs_present: bool,
+    skills_prompt_mode: zeroclaw_config::schema::SkillsPromptInjectionMode,
+    compact_context: bool,
+    max_system_prompt_chars: usize,
+    // When `false`, `MEMORY.md` is omitted from the injected bootstrap files.
+    // Set to `false` for isolated / ACP sessions that use `exclude_memory`.
+    inject_memory: bool,
+    // When `true`, the model is allowed to narrate tool usage in its
+    // response. When `false` (default), the system prompt instructs
+    // the model to treat tool calls as invisible infrastructure.
+    show_tool_calls: bool,
 ) -> String {
     use std::fmt::Write;
     let mut prompt = String::with_capacity(8192);
@@ -319,6 +472,29 @@ pub fn build_system_prompt_with_mode_and_autonomy(
     // ── 5. Bootstrap files (injected into context) ──────────────
     prompt.push_str("## Project Context\n\n");
 
+    let push_bootstrap_files = |prompt: &mut String| {
+        let max_chars = bootstrap_max_chars.unwrap_or(BOOTSTRAP_MAX_CHARS);
+        match cache {
+            Some(cache) => {
+                prompt.push_str(&cache.project_context(
+                    workspace_dir,
+                    None,
+                    max_chars,
+                    inject_memory,
+                ));
+            }
+            None => {
+                load_openclaw_bootstrap_files(
+                    prompt,
+                    workspace_dir,
+                    None,
+                    max_chars,
+                    inject_memory,
+                );
+            }
+        }
+    };
+
     // Check if AIEOS identity is configured
     if let Some(config) = identity_config {
         if identity::is_aieos_configured(config) {
@@ -334,37 +510,23 @@ pub fn build_system_prompt_with_mode_and_autonomy(
                 Ok(None) => {
                     // No AIEOS identity loaded (shouldn't happen if is_aieos_configured returned true)
                     // Fall back to OpenClaw bootstrap files
-                    let max_chars = bootstrap_max_chars.unwrap_or(BOOTSTRAP_MAX_CHARS);
-                    load_openclaw_bootstrap_files(
-                        &mut prompt,
-                        workspace_dir,
-                        max_chars,
-                        inject_memory,
-                    );
+                    push_bootstrap_files(&mut prompt);
                 }
                 Err(e) => {
                     // Log error but don't fail - fall back to OpenClaw
                     eprintln!(
                         "Warning: Failed to load AIEOS identity: {e}. Using OpenClaw format."
                     );
-                    let max_chars = bootstrap_max_chars.unwrap_or(BOOTSTRAP_MAX_CHARS);
-                    load_openclaw_bootstrap_files(
-                        &mut prompt,
-                        workspace_dir,
-                        max_chars,
-                        inject_memory,
-                    );
+                    push_bootstrap_files(&mut prompt);
                 }
             }
         } else {
             // OpenClaw format
-            let max_chars = bootstrap_max_chars.unwrap_or(BOOTSTRAP_MAX_CHARS);
-            load_openclaw_bootstrap_files(&mut prompt, workspace_dir, max_chars, inject_memory);
+            push_bootstrap_files(&mut prompt);
         }
     } else {
         // No identity config - use OpenClaw format
-        let max_chars = bootstrap_max_chars.unwrap_or(BOOTSTRAP_MAX_CHARS);
-        load_openclaw_bootstrap_files(&mut prompt, workspace_dir, max_chars, inject_memory);
+        push_bootstrap_files(&mut prompt);
     }
 
     // ── 6. Date ─────────────────────────────────────────────────
@@ -435,6 +597,10 @@ pub fn build_system_prompt_with_mode_and_autonomy(
 }
 
 /// Inject a single workspace file into the prompt with truncation and missing-file markers.
+///
+/// `max_chars` is sized for English text; the file is cut at the equivalent
+/// token budget (`max_chars / 4`), which keeps ASCII files at the same length
+/// and trims CJK or other multi-byte content to what it actually costs.
 fn inject_workspace_file(
     prompt: &mut String,
     workspace_dir: &std::path::Path,
@@ -451,21 +617,13 @@ fn inject_workspace_file(
                 return;
             }
             let _ = writeln!(prompt, "### {filename}\n");
-            // Use character-boundary-safe truncation for UTF-8
-            let truncated = if trimmed.chars().count() > max_chars {
-                trimmed
-                    .char_indices()
-                    .nth(max_chars)
-                    .map(|(idx, _)| &trimmed[..idx])
-                    .unwrap_or(trimmed)
-            } else {
-                trimmed
-            };
+            let max_tokens = max_chars / 4;
+            let truncated = zeroclaw_providers::tokens::truncate_to_tokens(trimmed, max_tokens);
             if truncated.len() < trimmed.len() {
                 prompt.push_str(truncated);
                 let _ = writeln!(
                     prompt,
-                    "\n\n[... truncated at {max_chars} chars — use `read` for full file]\n"
+                    "\n\n[... truncated at {max_tokens} tokens — use `read` for full file]\n"
                 );
             } else {
                 prompt.push_str(trimmed);
@@ -607,4 +765,56 @@ mod tests {
             "Tool Authorization should be skipped when no power tools (shell/file_write/file_edit) are registered"
         );
     }
+
+    #[test]
+    fn bootstrap_files_are_cut_at_a_token_budget() {
+        let workspace = tempfile::TempDir::new().expect("tempdir");
+        let ascii = "a".repeat(1_000);
+        let cjk = "上下文预算按令牌计算".repeat(100);
+        std::fs::write(workspace.path().join("AGENTS.md"), &ascii).unwrap();
+        std::fs::write(workspace.path().join("SOUL.md"), &cjk).unwrap();
+
+        let rendered = render_openclaw_bootstrap_files(workspace.path(), None, 400, false);
+        let section = |name: &str| {
+            let start = rendered.find(&format!("### {name}\n\n")).unwrap() + name.len() + 6;
+            let end = rendered[start..].find("\n\n").unwrap();
+            rendered[start..start + end].to_string()
+        };
+
+        // 400 chars of English is 100 tokens, and so is 100 ideographs.
+        assert_eq!(section("AGENTS.md"), "a".repeat(400));
+        assert_eq!(section("SOUL.md").chars().count(), 100);
+        assert!(rendered.contains("[... truncated at 100 tokens"));
+    }
+
+    #[test]
+    fn persona_files_replace_soul_and_identity_but_keep_agents() {
+        let workspace = tempfile::TempDir::new().expect("tempdir");
+        std::fs::write(workspace.path().join("AGENTS.md"), "Follow the plan.").unwrap();
+        std::fs::write(workspace.path().join("SOUL.md"), "Be kind.").unwrap();
+        std::fs::write(workspace.path().join("TOOLS.md"), "Prefer shell.").unwrap();
+        let pirate = workspace.path().join("personas/pirate");
+        std::fs::create_dir_all(&pirate).unwrap();
+        std::fs::write(pirate.join("SOUL.md"), "Talk like a pirate.").unwrap();
+        std::fs::write(pirate.join("IDENTITY.md"), "Name: Redbeard").unwrap();
+        std::fs::create_dir_all(workspace.path().join("personas/empty")).unwrap();
+
+        assert_eq!(list_personas(workspace.path()), ["pirate"]);
+        assert_eq!(
+            persona_dir(workspace.path(), "pirate"),
+            Some(pirate.clone())
+        );
+        assert_eq!(persona_dir(workspace.path(), "empty"), None);
+        assert_eq!(persona_dir(workspace.path(), "../personas/pirate"), None);
+
+        let rendered = render_openclaw_bootstrap_files(workspace.path(), Some(&pirate), 400, false);
+        assert!(rendered.contains("Talk like a pirate."));
+        assert!(rendered.contains("Name: Redbeard"));
+        assert!(!rendered.contains("Be kind."));
+        assert!(rendered.contains("Follow the plan."));
+        assert!(
+            rendered.contains("Prefer shell."),
+            "TOOLS.md falls back to the workspace"
+        );
+    }
 }
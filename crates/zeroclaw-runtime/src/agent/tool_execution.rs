@@ -42,6 +42,24 @@ pub(crate) struct ToolDispatchContext<'a> {
     pub tools_registry: &'a [Box<dyn Tool>],
     pub activated_tools: Option<&'a std::sync::Arc<std::sync::Mutex<ActivatedToolSet>>>,
     pub excluded_tools: &'a [String],
+    /// Tool-loop iteration the calls belong to; part of the execution
+    /// ledger key.
+    pub iteration: usize,
+}
+
+impl ToolDispatchContext<'_> {
+    /// Whether the named call would change state, per [`Tool::mutates`].
+    /// `None` when no callable tool has that name.
+    pub(crate) fn call_mutates(&self, name: &str, args: &serde_json::Value) -> Option<bool> {
+        if let Some(tool) = find_tool(self.tools_registry, name) {
+            return Some(tool.mutates(args));
+        }
+        let activated = self
+            .activated_tools?
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        activated.get_resolved(name).map(|tool| tool.mutates(args))
+    }
 }
 
 fn is_excluded_tool(name: &str, excluded_tools: &[String]) -> bool {
@@ -205,6 +223,59 @@ pub(crate) async fn execute_one_tool(
         ));
     }
 
+    // A retried message replays non-read-only calls that already succeeded
+    // instead of running their side effects again.
+    let ledger = super::tool_ledger::current_ledger().filter(|_| !tool.is_read_only());
+    let ledger_key = ledger
+        .as_ref()
+        .map(|l| l.key(dispatch.iteration, tool.name(), &call_arguments));
+    if let (Some(ledger), Some(key)) = (ledger.as_ref(), ledger_key.as_deref())
+        && let Some(entry) = ledger.replay(key)
+    {
+        ::zeroclaw_log::record!(
+            INFO,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Skip)
+                .with_category(::zeroclaw_log::EventCategory::Tool)
+                .with_outcome(::zeroclaw_log::EventOutcome::Success)
+                .with_attrs(::serde_json::json!({
+                    "tool": call_name,
+                    "tool_call_id": tool_call_id,
+                    "message_id": ledger.message_id(),
+                    "ledger_key": key,
+                    "iteration": dispatch.iteration,
+                })),
+            format!("tool replayed from execution ledger: {call_name}")
+        );
+        let outcome = ToolExecutionOutcome {
+            output: entry.output,
+            output_data: entry.output_data,
+            success: true,
+            error_reason: None,
+            duration: start.elapsed(),
+            receipt: None,
+        };
+        if let Some(tx) = event_tx {
+            let id = tool_call_id_owned
+                .clone()
+                .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+            let _ = tx
+                .send(TurnEvent::ToolCall {
+                    id: id.clone(),
+                    name: call_name.to_string(),
+                    args: call_arguments.clone(),
+                })
+                .await;
+            let _ = tx
+                .send(TurnEvent::ToolResult {
+                    id,
+                    name: call_name.to_string(),
+                    output: scrub_credentials(&outcome.output),
+                })
+                .await;
+        }
+        return Ok(outcome);
+    }
+
     use ::zeroclaw_log::Instrument;
     let tool_span = ::zeroclaw_log::info_span!(
         target: "zeroclaw_log_internal_scope",
@@ -393,6 +464,18 @@ pub(crate) async fn execute_one_tool(
         }
     };
 
+    if let (Some(ledger), Some(key), Ok(out)) = (ledger, ledger_key, &outcome)
+        && out.success
+    {
+        ledger.record(
+            key,
+            dispatch.iteration,
+            tool.name(),
+            &out.output,
+            out.output_data.clone(),
+        );
+    }
+
     if let Some(tx) = event_tx
         && let Ok(out) = &outcome
     {
@@ -633,6 +716,7 @@ mod tests {
                 tools_registry: &[], // no static tools - force activated-tools path
                 activated_tools: Some(&activated),
                 excluded_tools: &[],
+                iteration: 0,
             },
             &meta,
             &NoopObserver,
@@ -688,6 +772,7 @@ mod tests {
                 tools_registry: &[],
                 activated_tools: Some(&activated),
                 excluded_tools: &excluded,
+                iteration: 0,
             },
             &meta,
             &NoopObserver,
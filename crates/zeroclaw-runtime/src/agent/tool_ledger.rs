@@ -0,0 +1,222 @@
+//! Per-message tool execution ledger.
+//!
+//! When a provider call fails after some tools in the loop already ran and
+//! the whole message is retried, the model usually re-issues the same calls.
+//! The ledger records every successful non-read-only call under a
+//! deterministic key of (message id, iteration, tool name, arguments); a
+//! matching call on the retry replays the recorded result instead of running
+//! the side effect a second time. Read-only tools are never recorded and
+//! always re-run.
+//!
+//! The ledger is in-memory only and lives for one inbound message. It is
+//! scoped into the tool loop through [`TOOL_EXECUTION_LEDGER`] by the entry
+//! point that owns the retry; with no scope, execution is unchanged.
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, Mutex};
+
+/// One successful tool call as recorded for replay and audit.
+#[derive(Debug, Clone, Serialize)]
+pub struct LedgerEntry {
+    pub key: String,
+    pub iteration: usize,
+    pub tool: String,
+    pub output: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_data: Option<serde_json::Value>,
+    /// How many times a retry was answered from this entry.
+    pub replays: u32,
+}
+
+#[derive(Debug)]
+pub struct ToolExecutionLedger {
+    message_id: String,
+    /// Execution order; a message makes few enough calls that a linear
+    /// lookup is cheaper than keeping a map in sync.
+    entries: Mutex<Vec<LedgerEntry>>,
+}
+
+impl ToolExecutionLedger {
+    pub fn new(message_id: impl Into<String>) -> Self {
+        Self {
+            message_id: message_id.into(),
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn message_id(&self) -> &str {
+        &self.message_id
+    }
+
+    /// Deterministic key for a call. Object keys in `args` are sorted first
+    /// so two renderings of the same arguments hash identically.
+    pub fn key(&self, iteration: usize, tool: &str, args: &serde_json::Value) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.message_id.as_bytes());
+        hasher.update([0]);
+        hasher.update(iteration.to_le_bytes());
+        hasher.update(tool.as_bytes());
+        hasher.update([0]);
+        hasher.update(canonical_json(args).as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    /// Recorded result for `key`, counting the replay. `None` when the call
+    /// has not succeeded yet in this message.
+    pub fn replay(&self, key: &str) -> Option<LedgerEntry> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = entries.iter_mut().find(|e| e.key == key)?;
+        entry.replays += 1;
+        Some(entry.clone())
+    }
+
+    pub fn record(
+        &self,
+        key: String,
+        iteration: usize,
+        tool: &str,
+        output: &str,
+        output_data: Option<serde_json::Value>,
+    ) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.iter().any(|e| e.key == key) {
+            return;
+        }
+        entries.push(LedgerEntry {
+            key,
+            iteration,
+            tool: tool.to_string(),
+            output: output.to_string(),
+            output_data,
+            replays: 0,
+        });
+    }
+
+    /// Entries in execution order.
+    pub fn entries(&self) -> Vec<LedgerEntry> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_empty()
+    }
+
+    /// Audit view for the message transcript: the message id plus every
+    /// recorded call with its key, tool, iteration, and replay count.
+    /// Outputs are omitted; they are already in the conversation history.
+    pub fn to_transcript_json(&self) -> serde_json::Value {
+        let calls: Vec<serde_json::Value> = self
+            .entries()
+            .into_iter()
+            .map(|e| {
+                serde_json::json!({
+                    "key": e.key,
+                    "iteration": e.iteration,
+                    "tool": e.tool,
+                    "replays": e.replays,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "message_id": self.message_id,
+            "calls": calls,
+        })
+    }
+}
+
+fn canonical_json(value: &serde_json::Value) -> String {
+    fn sort(value: &serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => {
+                let mut keys: Vec<&String> = map.keys().collect();
+                keys.sort();
+                let mut sorted = serde_json::Map::new();
+                for key in keys {
+                    sorted.insert(key.clone(), sort(&map[key]));
+                }
+                serde_json::Value::Object(sorted)
+            }
+            serde_json::Value::Array(items) => {
+                serde_json::Value::Array(items.iter().map(sort).collect())
+            }
+            other => other.clone(),
+        }
+    }
+    sort(value).to_string()
+}
+
+tokio::task_local! {
+    /// Set by entry points that retry a whole message on provider failure.
+    /// `execute_one_tool` consults it before running a non-read-only tool.
+    pub static TOOL_EXECUTION_LEDGER: Option<Arc<ToolExecutionLedger>>;
+}
+
+/// Scope `TOOL_EXECUTION_LEDGER` around `fut`. A `None` ledger is inert.
+pub async fn scope_ledger<F>(ledger: Option<Arc<ToolExecutionLedger>>, fut: F) -> F::Output
+where
+    F: std::future::Future,
+{
+    TOOL_EXECUTION_LEDGER.scope(ledger, fut).await
+}
+
+/// The ledger scoped around the current task, if any.
+pub(crate) fn current_ledger() -> Option<Arc<ToolExecutionLedger>> {
+    TOOL_EXECUTION_LEDGER.try_with(Clone::clone).ok().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_ignores_argument_key_order() {
+        let ledger = ToolExecutionLedger::new("msg-1");
+        let a: serde_json::Value =
+            serde_json::from_str(r#"{"path":"a.txt","content":"x"}"#).unwrap();
+        let b: serde_json::Value =
+            serde_json::from_str(r#"{"content":"x","path":"a.txt"}"#).unwrap();
+        assert_eq!(
+            ledger.key(0, "file_write", &a),
+            ledger.key(0, "file_write", &b)
+        );
+    }
+
+    #[test]
+    fn key_distinguishes_message_iteration_tool_and_args() {
+        let ledger = ToolExecutionLedger::new("msg-1");
+        let other = ToolExecutionLedger::new("msg-2");
+        let args = serde_json::json!({"path": "a.txt"});
+        let base = ledger.key(0, "file_write", &args);
+        assert_ne!(base, other.key(0, "file_write", &args));
+        assert_ne!(base, ledger.key(1, "file_write", &args));
+        assert_ne!(base, ledger.key(0, "file_edit", &args));
+        assert_ne!(
+            base,
+            ledger.key(0, "file_write", &serde_json::json!({"path": "b.txt"}))
+        );
+    }
+
+    #[test]
+    fn replay_returns_recorded_entry_and_counts() {
+        let ledger = ToolExecutionLedger::new("msg-1");
+        let key = ledger.key(0, "file_write", &serde_json::json!({}));
+        assert!(ledger.replay(&key).is_none());
+
+        ledger.record(key.clone(), 0, "file_write", "wrote 3 bytes", None);
+        let entry = ledger.replay(&key).expect("recorded entry");
+        assert_eq!(entry.output, "wrote 3 bytes");
+        assert_eq!(entry.replays, 1);
+
+        let audit = ledger.to_transcript_json();
+        assert_eq!(audit["message_id"], "msg-1");
+        assert_eq!(audit["calls"][0]["tool"], "file_write");
+        assert_eq!(audit["calls"][0]["replays"], 1);
+    }
+}
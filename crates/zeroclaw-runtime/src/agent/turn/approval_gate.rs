@@ -1,11 +1,13 @@
-//! The per-tool-call approval gate: CLI prompt, channel inline approval, or
-//! auto-deny, plus decision recording.
+//! The per-tool-call approval gate: CLI prompt, channel inline approval, the
+//! persistent approval queue, or auto-deny, plus decision recording.
 
 use super::context::TurnCtx;
 use super::events::StreamDelta;
 use super::redact::scrub_credentials;
 use crate::agent::tool_execution::ToolExecutionOutcome;
-use crate::approval::{ApprovalRequest, ApprovalRequirement, ApprovalResponse};
+use crate::approval::queue::{self, NewApproval, WaitOutcome};
+use crate::approval::{ApprovalManager, ApprovalRequest, ApprovalRequirement, ApprovalResponse};
+use crate::security::policy::{CommandRiskLevel, CommandRiskVerdict, browser_action_risk};
 use std::time::Duration;
 
 pub(crate) enum ApprovalGateOutcome {
@@ -17,16 +19,26 @@ pub(crate) enum ApprovalGateOutcome {
 /// Run the approval flow for one tool call (upstream loop body, approval
 /// section): resolve the tool's approval requirement, prompt interactively on
 /// CLI or via the channel's inline approval on non-interactive channels
-/// (falling back to auto-deny), and record the decision.
+/// (falling back to the `[approvals]` queue when the daemon runs one, else
+/// auto-deny), and record the decision. `force_prompt` is set when a
+/// `require_approval` tool policy matched the call; it overrides
+/// `auto_approve`, session "Always" grants, and full autonomy.
 pub(crate) async fn gate_tool_approval(
     ctx: &TurnCtx<'_>,
     tool_name: &str,
     tool_args: &serde_json::Value,
     iteration: usize,
+    force_prompt: bool,
 ) -> ApprovalGateOutcome {
     let mut approval_requirement = ctx
         .approval
-        .map(|mgr| mgr.approval_requirement(tool_name))
+        .map(|mgr| {
+            if force_prompt {
+                ApprovalRequirement::Prompt
+            } else {
+                mgr.approval_requirement(tool_name)
+            }
+        })
         .unwrap_or(ApprovalRequirement::NotRequired);
     if let Some(mgr) = ctx.approval
         && approval_requirement == ApprovalRequirement::Prompt
@@ -35,16 +47,27 @@ pub(crate) async fn gate_tool_approval(
             tool_name: tool_name.to_string(),
             arguments: tool_args.clone(),
         };
+        let risk = shell_risk_verdict(ctx, tool_name, tool_args)
+            .filter(|verdict| verdict.level != CommandRiskLevel::Low);
+        let risk_note = risk
+            .as_ref()
+            .map(ToString::to_string)
+            .or_else(|| browser_risk_note(tool_name, tool_args));
 
         // Interactive CLI: prompt the operator.
         // Non-interactive (channels): try the channel's inline
-        // approval (e.g. Telegram inline keyboard) before falling
-        // back to auto-deny.
+        // approval (e.g. Telegram inline keyboard), then the daemon's
+        // approval queue, before falling back to auto-deny.
+        let mut deny_reason = None;
         let (decision, decided_by) = if mgr.is_non_interactive() {
             let attributed = if let Some(ch) = ctx.channel {
+                let mut arguments_summary = crate::approval::summarize_args(&request.arguments);
+                if let Some(note) = &risk_note {
+                    arguments_summary.push_str(&format!("\nRisk: {note}"));
+                }
                 let ch_request = zeroclaw_api::channel::ChannelApprovalRequest {
                     tool_name: request.tool_name.clone(),
-                    arguments_summary: crate::approval::summarize_args(&request.arguments),
+                    arguments_summary,
                     raw_arguments: Some(request.arguments.clone()),
                 };
                 let recipient = ctx.channel_reply_target.unwrap_or_default();
@@ -72,31 +95,56 @@ pub(crate) async fn gate_tool_approval(
             // The deciding back-channel (when a fan-out bridge answered) rides
             // back on the response itself, so attribution can't be cross-wired
             // by a concurrent approval on the same channel instance.
-            let decided_by = attributed.as_ref().and_then(|a| a.decided_by.clone());
-            let decision = match attributed.map(|a| a.response) {
-                Some(zeroclaw_api::channel::ChannelApprovalResponse::Approve) => {
-                    ApprovalResponse::Yes
-                }
-                Some(zeroclaw_api::channel::ChannelApprovalResponse::AlwaysApprove) => {
-                    ApprovalResponse::Always
+            let mut decided_by = attributed.as_ref().and_then(|a| a.decided_by.clone());
+            let queued = if attributed.is_none()
+                && let Some(approvals) = queue::active()
+            {
+                let reasons = queue_reasons(ctx, mgr, tool_name, tool_args, force_prompt);
+                queue_for_approval(ctx, &approvals, tool_name, tool_args, reasons).await
+            } else {
+                None
+            };
+            let decision = if let Some((decision, by, reason)) = queued {
+                decided_by = Some(by);
+                deny_reason = reason;
+                decision
+            } else {
+                match attributed.map(|a| a.response) {
+                    Some(zeroclaw_api::channel::ChannelApprovalResponse::Approve) => {
+                        ApprovalResponse::Yes
+                    }
+                    Some(zeroclaw_api::channel::ChannelApprovalResponse::AlwaysApprove) => {
+                        ApprovalResponse::Always
+                    }
+                    Some(zeroclaw_api::channel::ChannelApprovalResponse::Deny) => {
+                        ApprovalResponse::No
+                    }
+                    Some(zeroclaw_api::channel::ChannelApprovalResponse::DenyWithEdit {
+                        replacement,
+                    }) => ApprovalResponse::ReplaceWith(replacement),
+                    // Channel doesn't support approval — auto-deny.
+                    None => ApprovalResponse::No,
                 }
-                Some(zeroclaw_api::channel::ChannelApprovalResponse::Deny) => ApprovalResponse::No,
-                Some(zeroclaw_api::channel::ChannelApprovalResponse::DenyWithEdit {
-                    replacement,
-                }) => ApprovalResponse::ReplaceWith(replacement),
-                // Channel doesn't support approval — auto-deny.
-                None => ApprovalResponse::No,
             };
             (decision, decided_by)
         } else {
-            (mgr.prompt_cli(&request), None)
+            (
+                mgr.prompt_cli_with_risk(&request, risk_note.as_deref()),
+                None,
+            )
         };
 
         let decision_channel = decided_by.unwrap_or_else(|| ctx.channel_name.to_string());
         mgr.record_decision(tool_name, tool_args, &decision, &decision_channel);
 
         if decision == ApprovalResponse::No {
-            let denied = "Denied by user.".to_string();
+            let mut denied = match deny_reason {
+                Some(reason) => format!("Denied: {reason}"),
+                None => "Denied by user.".to_string(),
+            };
+            if let Some(note) = &risk_note {
+                denied.push_str(&format!(" Risk: {note}"));
+            }
             ::zeroclaw_log::record!(
                 WARN,
                 ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
@@ -108,6 +156,7 @@ pub(crate) async fn gate_tool_approval(
                         "tool": tool_name,
                         "arguments": scrub_credentials(&tool_args.to_string()),
                         "result": denied,
+                        "risk": risk,
                         "trace_id": ctx.turn_id,
                     })),
                 "tool_call_result"
@@ -174,3 +223,212 @@ pub(crate) async fn gate_tool_approval(
         approved: approval_requirement == ApprovalRequirement::Approved,
     }
 }
+
+/// Why this call needs approval, as shown in `zeroclaw approvals list`.
+fn queue_reasons(
+    ctx: &TurnCtx<'_>,
+    mgr: &ApprovalManager,
+    tool_name: &str,
+    tool_args: &serde_json::Value,
+    force_prompt: bool,
+) -> Vec<String> {
+    let mut reasons = Vec::new();
+    if force_prompt {
+        reasons.push("matched a require_approval tool policy".to_string());
+    }
+    reasons.extend(mgr.approval_reasons(tool_name));
+    if let Some(verdict) = shell_risk_verdict(ctx, tool_name, tool_args)
+        && verdict.level != CommandRiskLevel::Low
+    {
+        reasons.push(format!("shell command is {verdict}"));
+    }
+    reasons.extend(browser_risk_note(tool_name, tool_args));
+    reasons
+}
+
+/// The shell policy's risk verdict for a `shell` call; `None` for other tools.
+fn shell_risk_verdict(
+    ctx: &TurnCtx<'_>,
+    tool_name: &str,
+    tool_args: &serde_json::Value,
+) -> Option<CommandRiskVerdict> {
+    if tool_name != "shell" {
+        return None;
+    }
+    let policy = ctx.tool_policy?;
+    let command = tool_args.get("command").and_then(|c| c.as_str())?;
+    Some(policy.command_risk_verdict(command))
+}
+
+/// Why a `browser` call is medium risk; `None` for other tools and for
+/// browser actions that only navigate or read.
+fn browser_risk_note(tool_name: &str, tool_args: &serde_json::Value) -> Option<String> {
+    if tool_name != "browser" || browser_action_risk(tool_args) != CommandRiskLevel::Medium {
+        return None;
+    }
+    let action = tool_args.get("action").and_then(|a| a.as_str())?;
+    Some(format!("medium risk: browser `{action}` acts on the page"))
+}
+
+/// Park the call in the approval queue until an operator decides or the
+/// request expires. Returns the decision, who made it (`<who> via <where>`)
+/// and any deny reason; `None` when the request could not be queued.
+async fn queue_for_approval(
+    ctx: &TurnCtx<'_>,
+    approvals: &queue::ApprovalQueue,
+    tool_name: &str,
+    tool_args: &serde_json::Value,
+    risk_reasons: Vec<String>,
+) -> Option<(ApprovalResponse, String, Option<String>)> {
+    let queued = match approvals.submit(NewApproval {
+        tool_name: tool_name.to_string(),
+        arguments: tool_args.clone(),
+        risk_reasons,
+        agent_alias: ctx.agent_alias.map(str::to_string),
+        channel: ctx.channel_name.to_string(),
+        sender: ctx.channel_reply_target.map(str::to_string),
+    }) {
+        Ok(queued) => queued,
+        Err(e) => {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                    .with_category(::zeroclaw_log::EventCategory::Tool)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(
+                        ::serde_json::json!({"tool": tool_name, "error": format!("{e:#}")})
+                    ),
+                "Failed to queue tool approval; denying"
+            );
+            return None;
+        }
+    };
+    ::zeroclaw_log::record!(
+        INFO,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Defer)
+            .with_category(::zeroclaw_log::EventCategory::Tool)
+            .with_attrs(::serde_json::json!({
+                "approval_id": queued.id,
+                "tool": tool_name,
+                "channel": ctx.channel_name,
+                "expires_at": queued.expires_at.to_rfc3339(),
+                "trace_id": ctx.turn_id,
+            })),
+        "Tool call waiting in the approval queue"
+    );
+    notify_origin(
+        ctx,
+        format!(
+            "\u{23f3} `{tool_name}` needs approval (request {id}, expires {expires}). \
+             Run `zeroclaw approvals approve {id}` or `zeroclaw approvals deny {id}`.",
+            id = queued.id,
+            expires = queued.expires_at.format("%Y-%m-%d %H:%M UTC"),
+        ),
+    )
+    .await;
+
+    let outcome = match approvals.wait(&queued.id).await {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                    .with_category(::zeroclaw_log::EventCategory::Tool)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({
+                        "approval_id": queued.id,
+                        "error": format!("{e:#}"),
+                    })),
+                "Approval queue wait failed; denying"
+            );
+            return Some((
+                ApprovalResponse::No,
+                "approval-queue".to_string(),
+                Some("the approval queue failed".to_string()),
+            ));
+        }
+    };
+    match outcome {
+        WaitOutcome::Decided(decision) => {
+            let (action, outcome) = if decision.approved {
+                (
+                    ::zeroclaw_log::Action::Approve,
+                    ::zeroclaw_log::EventOutcome::Success,
+                )
+            } else {
+                (
+                    ::zeroclaw_log::Action::Reject,
+                    ::zeroclaw_log::EventOutcome::Failure,
+                )
+            };
+            ::zeroclaw_log::record!(
+                INFO,
+                ::zeroclaw_log::Event::new(module_path!(), action)
+                    .with_category(::zeroclaw_log::EventCategory::Tool)
+                    .with_outcome(outcome)
+                    .with_attrs(::serde_json::json!({
+                        "approval_id": queued.id,
+                        "tool": tool_name,
+                        "decided_by": decision.decided_by,
+                        "decided_via": decision.decided_via,
+                        "reason": decision.reason,
+                        "trace_id": ctx.turn_id,
+                    })),
+                "Queued tool approval decided"
+            );
+            let by = format!("{} via {}", decision.decided_by, decision.decided_via);
+            if decision.approved {
+                Some((ApprovalResponse::Yes, by, None))
+            } else {
+                let reason = decision
+                    .reason
+                    .map_or_else(|| format!("denied by {by}"), |r| format!("{r} ({by})"));
+                Some((ApprovalResponse::No, by, Some(reason)))
+            }
+        }
+        WaitOutcome::Expired => {
+            ::zeroclaw_log::record!(
+                INFO,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Timeout)
+                    .with_category(::zeroclaw_log::EventCategory::Tool)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({
+                        "approval_id": queued.id,
+                        "tool": tool_name,
+                        "trace_id": ctx.turn_id,
+                    })),
+                "Queued tool approval expired"
+            );
+            notify_origin(
+                ctx,
+                format!(
+                    "\u{231b} Approval request {} for `{tool_name}` expired and was denied.",
+                    queued.id
+                ),
+            )
+            .await;
+            Some((
+                ApprovalResponse::No,
+                "approval-queue".to_string(),
+                Some("the approval request expired".to_string()),
+            ))
+        }
+    }
+}
+
+/// Best-effort notice to the channel and sender the request came from.
+async fn notify_origin(ctx: &TurnCtx<'_>, text: String) {
+    let (Some(ch), Some(recipient)) = (ctx.channel, ctx.channel_reply_target) else {
+        return;
+    };
+    let message = zeroclaw_api::channel::SendMessage::new(text, recipient);
+    if let Err(e) = ch.send(&message).await {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Send)
+                .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                .with_attrs(::serde_json::json!({"error": format!("{e}")})),
+            "Failed to send approval notice to the originating channel"
+        );
+    }
+}
@@ -7,7 +7,8 @@ use super::context::TurnCtx;
 use super::delivery_defaults::maybe_inject_channel_delivery_defaults;
 use super::events::{StreamDelta, emit_tool_call_pair};
 use super::redact::scrub_credentials;
-use crate::agent::tool_execution::ToolExecutionOutcome;
+use crate::agent::tool_execution::{ToolDispatchContext, ToolExecutionOutcome};
+use crate::security::policy::ToolPolicyDecision;
 use crate::util::truncate_with_ellipsis;
 use anyhow::Result;
 use std::collections::HashSet;
@@ -68,6 +69,61 @@ async fn record_duplicate_tool_call(
     }
 }
 
+async fn record_tool_policy_refusal(
+    ctx: &TurnCtx<'_>,
+    tool_name: &str,
+    tool_args: &serde_json::Value,
+    iteration: usize,
+    refusal: String,
+) -> ToolExecutionOutcome {
+    ::zeroclaw_log::record!(
+        WARN,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+            .with_category(::zeroclaw_log::EventCategory::Tool)
+            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+            .with_attrs(::serde_json::json!({
+                "model": ctx.model,
+                "iteration": iteration + 1,
+                "tool": tool_name,
+                "arguments": scrub_credentials(&tool_args.to_string()),
+                "result": refusal,
+                "trace_id": ctx.turn_id,
+            })),
+        "tool_call_result"
+    );
+    if let Some(tx) = ctx.on_delta {
+        let _ = tx
+            .send(StreamDelta::Status(format!(
+                "\u{274c} {}: {}\n",
+                tool_name, refusal
+            )))
+            .await;
+    }
+    ToolExecutionOutcome {
+        output: refusal.clone(),
+        success: false,
+        error_reason: Some(refusal),
+        duration: Duration::ZERO,
+        receipt: None,
+        output_data: None,
+    }
+}
+
+/// The per-call permission policy for an agent's turn: per-tool entries
+/// (`[risk_profiles.<alias>.tools.<tool>]`), the read-only autonomy mask and
+/// the supervised medium-risk gate for `browser` page actions. Built for
+/// every agent with a resolvable risk profile; configless paths get `None`
+/// and skip the per-call check.
+pub(crate) fn turn_tool_policy(
+    config: Option<&zeroclaw_config::schema::Config>,
+    agent_alias: Option<&str>,
+) -> Option<crate::security::SecurityPolicy> {
+    config
+        .zip(agent_alias)
+        .filter(|(config, alias)| config.risk_profile_for_agent(alias).is_some())
+        .and_then(|(config, alias)| crate::security::SecurityPolicy::for_agent(config, alias).ok())
+}
+
 /// Run per-call preparation over this round's parsed tool calls (upstream
 /// loop body, per-call prep loop).
 pub(crate) async fn prepare_tool_calls(
@@ -75,9 +131,10 @@ pub(crate) async fn prepare_tool_calls(
     tool_calls: &[ParsedToolCall],
     seen_tool_signatures: &mut HashSet<(String, String)>,
     prompt_approval_tool_signatures: &mut HashSet<(String, String)>,
-    iteration: usize,
+    dispatch: ToolDispatchContext<'_>,
     dedup_enabled: bool,
 ) -> Result<PreparedToolCalls> {
+    let iteration = dispatch.iteration;
     let mut ordered_results: Vec<Option<(String, Option<String>, ToolExecutionOutcome)>> =
         (0..tool_calls.len()).map(|_| None).collect();
     let mut executable_indices: Vec<usize> = Vec::new();
@@ -154,10 +211,48 @@ pub(crate) async fn prepare_tool_calls(
 
         crate::agent::set_runtime_approved_arg(&tool_name, &mut tool_args, false);
 
-        let requires_prompt = ctx
-            .approval
-            .map(|mgr| mgr.needs_approval(&tool_name))
-            .unwrap_or(false);
+        // ── Tool permission policy ───────────────────────
+        let mut policy_requires_approval = false;
+        if let Some(policy) = ctx.tool_policy {
+            // The autonomy mask runs first so read-only mode holds even for
+            // tools a per-tool entry would otherwise allow. A tool that
+            // cannot be resolved is assumed to mutate.
+            let mutates = dispatch
+                .call_mutates(&tool_name, &tool_args)
+                .unwrap_or(true);
+            let decision = match policy.check_mutation(&tool_name, mutates) {
+                ToolPolicyDecision::Allow => policy.check_tool(&tool_name, &tool_args),
+                decision => decision,
+            };
+            let refusal = match decision {
+                ToolPolicyDecision::Allow => None,
+                ToolPolicyDecision::RequireApproval if ctx.approval.is_some() => {
+                    policy_requires_approval = true;
+                    None
+                }
+                ToolPolicyDecision::RequireApproval => Some(format!(
+                    "Tool '{tool_name}' requires approval under the security policy, but no approver is available for this run."
+                )),
+                ToolPolicyDecision::Deny(reason) => Some(reason),
+            };
+            if let Some(refusal) = refusal {
+                let outcome =
+                    record_tool_policy_refusal(ctx, &tool_name, &tool_args, iteration, refusal)
+                        .await;
+                if let Some(tx) = ctx.event_tx {
+                    emit_tool_call_pair(tx, call, &outcome).await;
+                }
+                ordered_results[idx] =
+                    Some((tool_name.clone(), call.tool_call_id.clone(), outcome));
+                continue;
+            }
+        }
+
+        let requires_prompt = policy_requires_approval
+            || ctx
+                .approval
+                .map(|mgr| mgr.needs_approval(&tool_name))
+                .unwrap_or(false);
         let reentrant_agent_tool =
             crate::tools::REENTRANT_AGENT_TOOLS.contains(&tool_name.as_str());
         if requires_prompt && tool_name == "shell" && !reentrant_agent_tool {
@@ -200,7 +295,15 @@ pub(crate) async fn prepare_tool_calls(
         }
 
         // ── Approval hook ────────────────────────────────
-        let approved = match gate_tool_approval(ctx, &tool_name, &tool_args, iteration).await {
+        let approved = match gate_tool_approval(
+            ctx,
+            &tool_name,
+            &tool_args,
+            iteration,
+            policy_requires_approval,
+        )
+        .await
+        {
             ApprovalGateOutcome::Proceed { approved } => approved,
             ApprovalGateOutcome::Deny(outcome) | ApprovalGateOutcome::Replace(outcome) => {
                 // Streaming consumers see the denied/replaced call and its
@@ -294,3 +397,284 @@ pub(crate) async fn prepare_tool_calls(
         executable_calls,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observability::NoopObserver;
+    use crate::security::SecurityPolicy;
+    use std::collections::HashMap;
+    use zeroclaw_config::schema::{PacingConfig, ToolPermission, ToolPolicyConfig};
+
+    fn policy_ctx<'a>(
+        observer: &'a NoopObserver,
+        pacing: &'a PacingConfig,
+        policy: &'a SecurityPolicy,
+    ) -> TurnCtx<'a> {
+        TurnCtx {
+            parent_agent_alias: None,
+            observer,
+            provider_name: "stub",
+            model: "stub-model",
+            temperature: None,
+            approval: None,
+            tool_policy: Some(policy),
+            injection_scanner: None,
+            channel_name: "test",
+            channel_reply_target: None,
+            cancellation_token: None,
+            on_delta: None,
+            event_tx: None,
+            hooks: None,
+            dedup_exempt_tools: &[],
+            pacing,
+            strict_tool_parsing: false,
+            channel: None,
+            agent_alias: None,
+            turn_id: "trace-tool-policy",
+        }
+    }
+
+    fn dispatch(tools_registry: &[Box<dyn crate::tools::Tool>]) -> ToolDispatchContext<'_> {
+        ToolDispatchContext {
+            tools_registry,
+            activated_tools: None,
+            excluded_tools: &[],
+            iteration: 0,
+        }
+    }
+
+    /// Plugin-style tool relying on the default `Tool::mutates`.
+    struct PluginTool {
+        name: &'static str,
+        read_only: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::tools::Tool for PluginTool {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn description(&self) -> &str {
+            "Stub plugin tool"
+        }
+
+        fn parameters_schema(&self) -> serde_json::Value {
+            serde_json::json!({"type": "object"})
+        }
+
+        fn is_read_only(&self) -> bool {
+            self.read_only
+        }
+
+        async fn execute(
+            &self,
+            _args: serde_json::Value,
+        ) -> anyhow::Result<crate::tools::ToolResult> {
+            unreachable!("prepare_tool_calls never executes tools")
+        }
+    }
+
+    impl zeroclaw_api::attribution::Attributable for PluginTool {
+        fn role(&self) -> zeroclaw_api::attribution::Role {
+            zeroclaw_api::attribution::Role::Tool(zeroclaw_api::attribution::ToolKind::Plugin)
+        }
+
+        fn alias(&self) -> &str {
+            self.name
+        }
+    }
+
+    fn call(name: &str, arguments: serde_json::Value) -> ParsedToolCall {
+        ParsedToolCall {
+            name: name.to_string(),
+            arguments,
+            tool_call_id: Some(format!("call-{name}")),
+        }
+    }
+
+    #[tokio::test]
+    async fn tool_policy_refuses_denied_and_unapprovable_calls_before_execution() {
+        let permission = |permission| ToolPolicyConfig {
+            permission,
+            ..Default::default()
+        };
+        let policy = SecurityPolicy {
+            risk_profile_name: "default".into(),
+            tool_policies: HashMap::from([
+                ("browser".to_string(), permission(ToolPermission::Deny)),
+                (
+                    "file_write".to_string(),
+                    permission(ToolPermission::RequireApproval),
+                ),
+            ]),
+            ..SecurityPolicy::default()
+        };
+        let observer = NoopObserver;
+        let pacing = PacingConfig::default();
+        let ctx = policy_ctx(&observer, &pacing, &policy);
+        let calls = vec![
+            call("browser", serde_json::json!({"url": "https://example.com"})),
+            call(
+                "file_write",
+                serde_json::json!({"path": "a.txt", "content": "x"}),
+            ),
+            call("shell", serde_json::json!({"command": "ls"})),
+        ];
+
+        let prepared = prepare_tool_calls(
+            &ctx,
+            &calls,
+            &mut HashSet::new(),
+            &mut HashSet::new(),
+            dispatch(&[]),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(prepared.executable_indices, vec![2]);
+        assert_eq!(prepared.executable_calls[0].name, "shell");
+
+        let (_, _, browser) = prepared.ordered_results[0].as_ref().unwrap();
+        assert!(!browser.success);
+        assert!(
+            browser
+                .output
+                .contains("[risk_profiles.default.tools.browser]")
+        );
+
+        let (_, _, file_write) = prepared.ordered_results[1].as_ref().unwrap();
+        assert!(!file_write.success);
+        assert!(file_write.output.contains("requires approval"));
+    }
+
+    #[tokio::test]
+    async fn default_supervised_profile_gates_browser_page_actions() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config = zeroclaw_config::schema::Config {
+            data_dir: tmp.path().to_path_buf(),
+            config_path: tmp.path().join("config.toml"),
+            agents: HashMap::from([(
+                "assistant".to_string(),
+                zeroclaw_config::schema::AliasedAgentConfig {
+                    enabled: true,
+                    risk_profile: "default".into(),
+                    ..Default::default()
+                },
+            )]),
+            risk_profiles: HashMap::from([(
+                "default".to_string(),
+                zeroclaw_config::schema::RiskProfileConfig::default(),
+            )]),
+            ..zeroclaw_config::schema::Config::default()
+        };
+        let policy = turn_tool_policy(Some(&config), Some("assistant"))
+            .expect("an agent with a risk profile gets a per-call policy");
+        assert_eq!(policy.autonomy, crate::security::AutonomyLevel::Supervised);
+        assert!(policy.tool_policies.is_empty());
+
+        let observer = NoopObserver;
+        let pacing = PacingConfig::default();
+        let ctx = policy_ctx(&observer, &pacing, &policy);
+        let calls = vec![
+            call(
+                "browser",
+                serde_json::json!({"action": "click", "selector": "#buy"}),
+            ),
+            call(
+                "browser",
+                serde_json::json!({"action": "open", "url": "https://example.com"}),
+            ),
+        ];
+
+        let prepared = prepare_tool_calls(
+            &ctx,
+            &calls,
+            &mut HashSet::new(),
+            &mut HashSet::new(),
+            dispatch(&[]),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(prepared.executable_indices, vec![1]);
+        let (_, _, click) = prepared.ordered_results[0].as_ref().unwrap();
+        assert!(!click.success);
+        assert!(click.output.contains("requires approval"));
+        assert!(turn_tool_policy(None, Some("assistant")).is_none());
+    }
+
+    #[tokio::test]
+    async fn read_only_autonomy_refuses_mutating_calls_at_dispatch() {
+        let policy = SecurityPolicy {
+            autonomy: crate::security::AutonomyLevel::ReadOnly,
+            ..SecurityPolicy::default()
+        };
+        let observer = NoopObserver;
+        let pacing = PacingConfig::default();
+        let ctx = policy_ctx(&observer, &pacing, &policy);
+        let tools: Vec<Box<dyn crate::tools::Tool>> = vec![
+            Box::new(PluginTool {
+                name: "plugin_write",
+                read_only: false,
+            }),
+            Box::new(PluginTool {
+                name: "plugin_lookup",
+                read_only: true,
+            }),
+        ];
+        let calls = vec![
+            call("plugin_write", serde_json::json!({"path": "a.txt"})),
+            call("plugin_lookup", serde_json::json!({"query": "x"})),
+        ];
+
+        let prepared = prepare_tool_calls(
+            &ctx,
+            &calls,
+            &mut HashSet::new(),
+            &mut HashSet::new(),
+            dispatch(&tools),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(prepared.executable_indices, vec![1]);
+        let (_, _, write) = prepared.ordered_results[0].as_ref().unwrap();
+        assert!(!write.success);
+        assert_eq!(
+            write.output,
+            "Security policy: read-only mode, cannot perform 'plugin_write'"
+        );
+    }
+
+    #[tokio::test]
+    async fn read_only_autonomy_refuses_unresolved_tools() {
+        let policy = SecurityPolicy {
+            autonomy: crate::security::AutonomyLevel::ReadOnly,
+            ..SecurityPolicy::default()
+        };
+        let observer = NoopObserver;
+        let pacing = PacingConfig::default();
+        let ctx = policy_ctx(&observer, &pacing, &policy);
+        let calls = vec![call("unknown_tool", serde_json::json!({}))];
+
+        let prepared = prepare_tool_calls(
+            &ctx,
+            &calls,
+            &mut HashSet::new(),
+            &mut HashSet::new(),
+            dispatch(&[]),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert!(prepared.executable_indices.is_empty());
+        let (_, _, unknown) = prepared.ordered_results[0].as_ref().unwrap();
+        assert!(!unknown.success);
+    }
+}
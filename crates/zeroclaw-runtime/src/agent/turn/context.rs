@@ -4,6 +4,7 @@ use super::events::DraftEvent;
 use crate::approval::ApprovalManager;
 use crate::hooks::HookRunner;
 use crate::observability::Observer;
+use crate::security::{SecurityPolicy, ToolOutputScanner};
 use tokio::sync::mpsc::Sender;
 use tokio_util::sync::CancellationToken;
 use zeroclaw_api::agent::TurnEvent;
@@ -16,6 +17,12 @@ pub(crate) struct TurnCtx<'a> {
     pub(crate) model: &'a str,
     pub(crate) temperature: Option<f64>,
     pub(crate) approval: Option<&'a ApprovalManager>,
+    /// The agent's security policy when its risk profile has per-tool
+    /// permission entries; checked before every call. `None` otherwise.
+    pub(crate) tool_policy: Option<&'a SecurityPolicy>,
+    /// Prompt-injection scanner for tool results
+    /// (`[security.prompt_injection]`). `None` when scanning is off.
+    pub(crate) injection_scanner: Option<&'a ToolOutputScanner>,
     pub(crate) channel_name: &'a str,
     pub(crate) channel_reply_target: Option<&'a str>,
     pub(crate) cancellation_token: Option<&'a CancellationToken>,
@@ -1,4 +1,4 @@
-//! Channel delivery-default injection for cron_add tool calls.
+//! Channel delivery-default injection for cron_add and task_spawn tool calls.
 
 const AUTO_DELIVERY_DEFAULT_CHANNELS: &[&str] = &[
     "telegram",
@@ -17,7 +17,7 @@ pub(crate) fn maybe_inject_channel_delivery_defaults(
     channel_name: &str,
     channel_reply_target: Option<&str>,
 ) {
-    if tool_name != "cron_add" {
+    if tool_name != "cron_add" && tool_name != "task_spawn" {
         return;
     }
 
@@ -36,6 +36,34 @@ pub(crate) fn maybe_inject_channel_delivery_defaults(
         return;
     };
 
+    if tool_name == "task_spawn" {
+        // Progress goes back to the conversation the task was spawned from,
+        // unless the model explicitly picked another channel.
+        let explicit_channel = args
+            .get("channel")
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+        if explicit_channel.is_some_and(|channel| channel != channel_name) {
+            return;
+        }
+        args.insert(
+            "channel".to_string(),
+            serde_json::Value::String(channel_name.to_string()),
+        );
+        let needs_target = args
+            .get("reply_target")
+            .and_then(serde_json::Value::as_str)
+            .is_none_or(|value| value.trim().is_empty());
+        if needs_target {
+            args.insert(
+                "reply_target".to_string(),
+                serde_json::Value::String(reply_target.to_string()),
+            );
+        }
+        return;
+    }
+
     let is_agent_job = args
         .get("job_type")
         .and_then(serde_json::Value::as_str)
@@ -129,4 +157,31 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn task_spawn_gets_reply_context_from_channel_turn() {
+        let mut args = serde_json::json!({
+            "description": "crawl docs",
+            "prompt": "crawl the docs site",
+        });
+
+        maybe_inject_channel_delivery_defaults("task_spawn", &mut args, "telegram", Some("42"));
+
+        assert_eq!(args["channel"], "telegram");
+        assert_eq!(args["reply_target"], "42");
+    }
+
+    #[test]
+    fn task_spawn_keeps_explicit_other_channel() {
+        let mut args = serde_json::json!({
+            "description": "crawl docs",
+            "prompt": "crawl the docs site",
+            "channel": "slack",
+        });
+
+        maybe_inject_channel_delivery_defaults("task_spawn", &mut args, "telegram", Some("42"));
+
+        assert_eq!(args["channel"], "slack");
+        assert!(args.get("reply_target").is_none());
+    }
 }
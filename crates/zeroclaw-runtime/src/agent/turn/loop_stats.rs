@@ -0,0 +1,184 @@
+//! Per-iteration cost and latency accounting for the tool-call loop.
+//!
+//! The loop records each provider call and the tools that followed it; an
+//! iteration is closed (and its `ToolLoopIteration` event emitted) when the
+//! next provider call lands or the loop returns. `finish` emits the closing
+//! `ToolLoopSummary` exactly once.
+
+use super::context::TurnMeta;
+use crate::observability::{Observer, ObserverEvent};
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+use zeroclaw_api::observability_traits::ToolIterationStat;
+
+/// One provider call as seen by the loop.
+pub(crate) struct LlmCallStat<'s> {
+    pub(crate) model_provider: &'s str,
+    pub(crate) model: &'s str,
+    pub(crate) duration: Duration,
+    pub(crate) success: bool,
+    pub(crate) input_tokens: Option<u64>,
+    pub(crate) output_tokens: Option<u64>,
+    pub(crate) cost_usd: Option<f64>,
+}
+
+struct OpenIteration {
+    iteration: usize,
+    model_provider: String,
+    model: String,
+    llm_duration: Duration,
+    llm_success: bool,
+    input_tokens: Option<u64>,
+    output_tokens: Option<u64>,
+    cost_usd: Option<f64>,
+    tools: Vec<ToolIterationStat>,
+}
+
+#[derive(Default)]
+struct Totals {
+    iterations: usize,
+    llm_duration: Duration,
+    tool_duration: Duration,
+    tool_calls: usize,
+    tool_failures: usize,
+    input_tokens: u64,
+    output_tokens: u64,
+    cost_usd: Option<f64>,
+}
+
+#[derive(Default)]
+struct State {
+    open: Option<OpenIteration>,
+    totals: Totals,
+}
+
+pub(crate) struct ToolLoopStats<'a> {
+    observer: &'a dyn Observer,
+    meta: TurnMeta<'a>,
+    model_provider: &'a str,
+    model: &'a str,
+    started_at: Instant,
+    state: Mutex<State>,
+}
+
+impl<'a> ToolLoopStats<'a> {
+    pub(crate) fn new(
+        observer: &'a dyn Observer,
+        meta: TurnMeta<'a>,
+        model_provider: &'a str,
+        model: &'a str,
+    ) -> Self {
+        Self {
+            observer,
+            meta,
+            model_provider,
+            model,
+            started_at: Instant::now(),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Open iteration `iteration` (0-based) with its provider call, closing
+    /// the previous one.
+    pub(crate) fn record_llm_call(&self, iteration: usize, call: LlmCallStat<'_>) {
+        let closed = {
+            let mut state = self.state.lock();
+            let totals = &mut state.totals;
+            totals.iterations += 1;
+            totals.llm_duration += call.duration;
+            totals.input_tokens += call.input_tokens.unwrap_or(0);
+            totals.output_tokens += call.output_tokens.unwrap_or(0);
+            if let Some(cost) = call.cost_usd {
+                *totals.cost_usd.get_or_insert(0.0) += cost;
+            }
+            state.open.replace(OpenIteration {
+                iteration: iteration + 1,
+                model_provider: call.model_provider.to_string(),
+                model: call.model.to_string(),
+                llm_duration: call.duration,
+                llm_success: call.success,
+                input_tokens: call.input_tokens,
+                output_tokens: call.output_tokens,
+                cost_usd: call.cost_usd,
+                tools: Vec::new(),
+            })
+        };
+        // Emit outside the lock: observers may be slow or re-entrant.
+        if let Some(done) = closed {
+            self.emit_iteration(done);
+        }
+    }
+
+    /// Attach a completed tool call to the current iteration.
+    pub(crate) fn record_tool(&self, tool: &str, duration: Duration, success: bool) {
+        let mut state = self.state.lock();
+        state.totals.tool_calls += 1;
+        state.totals.tool_duration += duration;
+        if !success {
+            state.totals.tool_failures += 1;
+        }
+        if let Some(open) = state.open.as_mut() {
+            open.tools.push(ToolIterationStat {
+                tool: tool.to_string(),
+                duration,
+                success,
+            });
+        }
+    }
+
+    /// Report a loop-detector intervention (`nudge`, `block`, or `abort`).
+    pub(crate) fn record_loop_detection(&self, tool: &str, action: &str, reason: &str) {
+        self.observer
+            .record_event(&ObserverEvent::ToolLoopDetected {
+                tool: tool.to_string(),
+                action: action.to_string(),
+                reason: reason.to_string(),
+                channel: Some(self.meta.channel_name.to_string()),
+                agent_alias: self.meta.agent_alias.map(str::to_string),
+                turn_id: Some(self.meta.turn_id.to_string()),
+            });
+    }
+
+    /// Close the last iteration and emit the per-message summary.
+    pub(crate) fn finish(&self, success: bool) {
+        let State { open, totals } = std::mem::take(&mut *self.state.lock());
+        if let Some(done) = open {
+            self.emit_iteration(done);
+        }
+        self.observer.record_event(&ObserverEvent::ToolLoopSummary {
+            model_provider: self.model_provider.to_string(),
+            model: self.model.to_string(),
+            iterations: totals.iterations,
+            duration: self.started_at.elapsed(),
+            llm_duration: totals.llm_duration,
+            tool_duration: totals.tool_duration,
+            tool_calls: totals.tool_calls,
+            tool_failures: totals.tool_failures,
+            input_tokens: totals.input_tokens,
+            output_tokens: totals.output_tokens,
+            cost_usd: totals.cost_usd,
+            success,
+            channel: Some(self.meta.channel_name.to_string()),
+            agent_alias: self.meta.agent_alias.map(str::to_string),
+            turn_id: Some(self.meta.turn_id.to_string()),
+        });
+    }
+
+    fn emit_iteration(&self, done: OpenIteration) {
+        self.observer
+            .record_event(&ObserverEvent::ToolLoopIteration {
+                iteration: done.iteration,
+                model_provider: done.model_provider,
+                model: done.model,
+                llm_duration: done.llm_duration,
+                llm_success: done.llm_success,
+                input_tokens: done.input_tokens,
+                output_tokens: done.output_tokens,
+                cost_usd: done.cost_usd,
+                tools: done.tools,
+                channel: Some(self.meta.channel_name.to_string()),
+                agent_alias: self.meta.agent_alias.map(str::to_string),
+                turn_id: Some(self.meta.turn_id.to_string()),
+            });
+    }
+}
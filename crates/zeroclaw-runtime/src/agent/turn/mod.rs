@@ -10,6 +10,7 @@ pub(crate) mod execution;
 pub(crate) mod history_append;
 pub(crate) mod history_window;
 pub(crate) mod knobs;
+pub(crate) mod loop_stats;
 pub(crate) mod max_iter;
 pub(crate) mod outcome;
 pub(crate) mod parse_response;
@@ -18,6 +19,7 @@ pub(crate) mod protocol_detect;
 pub(crate) mod provider_call;
 pub(crate) mod redact;
 pub(crate) mod results_collect;
+pub(crate) mod spill;
 pub(crate) mod steering;
 pub(crate) mod stream_consume;
 pub(crate) mod stream_guard;
@@ -36,6 +38,7 @@ pub use execution::{
 pub(crate) use history_append::append_tool_round_to_history;
 pub(crate) use history_window::preflight_history_maintenance;
 pub use knobs::{LoopKnobs, MaxIterationBehavior};
+pub(crate) use loop_stats::{LlmCallStat, ToolLoopStats};
 pub(crate) use max_iter::finish_after_max_iterations;
 pub(crate) use outcome::StreamCancelledAfterOutput;
 pub use outcome::{
@@ -176,6 +179,23 @@ async fn enforce_reported_budget(
 }
 
 pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
+    let stats = ToolLoopStats::new(
+        p.exec.observer,
+        TurnMeta {
+            agent_alias: p.agent_alias,
+            parent_agent_alias: p.parent_agent_alias,
+            turn_id: p.turn_id,
+            channel_name: p.channel_name,
+        },
+        p.exec.model_access.provider_name,
+        p.exec.model_access.model,
+    );
+    let result = run_tool_call_loop_inner(p, &stats).await;
+    stats.finish(result.is_ok());
+    result
+}
+
+async fn run_tool_call_loop_inner(p: ToolLoop<'_>, stats: &ToolLoopStats<'_>) -> Result<String> {
     let ToolLoop {
         exec,
         history,
@@ -270,11 +290,12 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
         {
             let scopes: Vec<Option<&str>> =
                 turn_memory.sessions.iter().map(|s| s.as_deref()).collect();
-            let context = crate::agent::memory_inject::render_memory_context(
+            let context = crate::agent::memory_inject::render_scoped_memory_context(
                 turn_memory.handle,
                 observer,
                 &turn_memory.query,
                 &scopes,
+                &turn_memory.namespaces,
                 &turn_memory.cfg,
                 exclude_conversation,
                 TurnMeta {
@@ -306,6 +327,11 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
         .collect();
     let mut consecutive_identical_outputs: usize = 0;
     let mut last_tool_output_hash: Option<u64> = None;
+    // Oversized tool outputs spill into the agent workspace; configless or
+    // alias-less (test) paths fall back to truncation.
+    let spill_dir = config
+        .zip(agent_alias)
+        .map(|(cfg, alias)| spill::spill_dir(&cfg.agent_workspace_dir(alias)));
 
     let mut loop_detector = crate::agent::loop_detector::LoopDetector::new(
         crate::agent::loop_detector::LoopDetectorConfig {
@@ -323,12 +349,17 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
     // Shared-ref context for the turn step functions. Every `&mut` the loop
     // owns stays a loop local passed as an explicit argument (RUN_SHEET
     // `turn.context.TurnCtx`).
+    let tool_policy = call_prep::turn_tool_policy(config, agent_alias);
+    let injection_scanner = config.and_then(crate::security::ToolOutputScanner::from_config);
+
     let ctx = TurnCtx {
         observer,
         provider_name,
         model,
         temperature,
         approval,
+        tool_policy: tool_policy.as_ref(),
+        injection_scanner: injection_scanner.as_ref(),
         channel_name,
         channel_reply_target,
         cancellation_token: cancellation_token.as_ref(),
@@ -631,6 +662,7 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
 
         let (
             response_text,
+            reasoning,
             parsed_text,
             tool_calls,
             assistant_history_content,
@@ -652,8 +684,21 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
                     knobs.detect_protocol_without_tools,
                 )
                 .await;
+                stats.record_llm_call(
+                    iteration,
+                    LlmCallStat {
+                        model_provider: ctx.provider_name,
+                        model: ctx.model,
+                        duration: llm_started_at.elapsed(),
+                        success: true,
+                        input_tokens: interpreted.input_tokens,
+                        output_tokens: interpreted.output_tokens,
+                        cost_usd: interpreted.cost_usd,
+                    },
+                );
                 (
                     interpreted.response_text,
+                    interpreted.reasoning,
                     interpreted.parsed_text,
                     interpreted.tool_calls,
                     interpreted.assistant_history_content,
@@ -676,8 +721,22 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
                 )
                 .await;
                 if recovered {
+                    // The compacted retry opens the iteration once it
+                    // succeeds; the overflowed attempt is not a loop turn.
                     continue;
                 }
+                stats.record_llm_call(
+                    iteration,
+                    LlmCallStat {
+                        model_provider: ctx.provider_name,
+                        model: ctx.model,
+                        duration: llm_started_at.elapsed(),
+                        success: false,
+                        input_tokens: None,
+                        output_tokens: None,
+                        cost_usd: None,
+                    },
+                );
                 // A stream that died after caller-visible output: persist the
                 // partial with the interruption marker so wrappers/channels
                 // can commit what the consumer already saw.
@@ -832,7 +891,7 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
                     .await?;
             }
 
-            let msg = ChatMessage::assistant(response_text.clone());
+            let msg = ChatMessage::assistant(response_text.clone()).with_reasoning(reasoning);
             if let Some(out) = new_messages_out.as_deref_mut() {
                 out.push(msg.clone());
             }
@@ -887,7 +946,12 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
             &tool_calls,
             &mut seen_tool_signatures,
             &mut prompt_approval_tool_signatures,
-            iteration,
+            ToolDispatchContext {
+                tools_registry,
+                activated_tools,
+                excluded_tools,
+                iteration,
+            },
             knobs.dedup_enabled,
         )
         .await?;
@@ -901,6 +965,7 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
                         tools_registry,
                         activated_tools,
                         excluded_tools,
+                        iteration,
                     };
                     execute_tools_parallel(
                         &executable_calls,
@@ -918,6 +983,7 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
                         tools_registry,
                         activated_tools,
                         excluded_tools,
+                        iteration,
                     };
                     execute_tools_sequential(
                         &executable_calls,
@@ -958,6 +1024,12 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
             }
         }
 
+        for (call, outcome) in executed_completed_calls
+            .iter()
+            .zip(executed_completed_outcomes.iter())
+        {
+            stats.record_tool(&call.name, outcome.duration, outcome.success);
+        }
         record_executed_outcomes(
             &ctx,
             &executed_completed_indices,
@@ -1024,10 +1096,12 @@ pub async fn run_tool_call_loop(p: ToolLoop<'_>) -> Result<String> {
             &mut loop_detector,
             &loop_ignore_tools,
             max_tool_result_chars,
+            spill_dir.as_deref(),
             collected_receipts,
             model,
             iteration,
             turn_id,
+            stats,
         )?;
 
         if !cancelled_mid_batch {
@@ -15,7 +15,7 @@ use std::time::Instant;
 use zeroclaw_api::agent::TurnEvent;
 use zeroclaw_providers::{ChatMessage, ChatResponse, ToolCall};
 use zeroclaw_tool_call_parser::{
-    ParsedToolCall, build_native_assistant_history_from_parsed_calls,
+    ParsedToolCall, build_native_assistant_history_from_parsed_calls, extract_think_content,
     looks_like_tool_protocol_example, parse_tool_calls, strip_think_tags,
 };
 
@@ -108,12 +108,27 @@ pub(crate) fn unforwarded_narration<'a>(
 /// The interpreted Ok-arm of one provider call.
 pub(crate) struct InterpretedResponse {
     pub(crate) response_text: String,
+    /// Reasoning trace for the final assistant message; never part of
+    /// `response_text`.
+    pub(crate) reasoning: Option<String>,
     pub(crate) parsed_text: String,
     pub(crate) tool_calls: Vec<ParsedToolCall>,
     pub(crate) assistant_history_content: String,
     pub(crate) native_tool_calls: Vec<ToolCall>,
     pub(crate) parse_issue_detected: bool,
     pub(crate) input_tokens: Option<u64>,
+    pub(crate) output_tokens: Option<u64>,
+    pub(crate) cost_usd: Option<f64>,
+}
+
+/// Reasoning trace of a response: the provider's separate reasoning field
+/// (OpenAI/DeepSeek `reasoning_content`, Anthropic thinking blocks) when
+/// present, else the contents of inline `<think>` blocks in the text.
+pub(crate) fn response_reasoning(resp: &ChatResponse) -> Option<String> {
+    resp.reasoning_content
+        .clone()
+        .filter(|r| !r.trim().is_empty())
+        .or_else(|| extract_think_content(resp.text_or_empty()))
 }
 
 /// Interpret a successful chat response. Takes the response by value and
@@ -277,6 +292,7 @@ pub(crate) async fn interpret_chat_response(
 
     // Preserve native tool call IDs in assistant history so role=tool
     // follow-up messages can reference the exact call id.
+    let reasoning = response_reasoning(&resp);
     let reasoning_content = resp.reasoning_content.clone();
     let assistant_history_content = if resp.tool_calls.is_empty() {
         if specs.use_native_tools {
@@ -300,19 +316,56 @@ pub(crate) async fn interpret_chat_response(
     let native_calls = resp.tool_calls;
     InterpretedResponse {
         response_text,
+        reasoning,
         parsed_text,
         tool_calls: calls,
         assistant_history_content,
         native_tool_calls: native_calls,
         parse_issue_detected: parse_issue.is_some(),
         input_tokens: resp_input_tokens,
+        output_tokens: resp_output_tokens,
+        cost_usd: call_cost_usd,
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{build_native_assistant_history, unforwarded_narration};
-    use zeroclaw_providers::ToolCall;
+    use super::{build_native_assistant_history, response_reasoning, unforwarded_narration};
+    use zeroclaw_providers::{ChatResponse, ToolCall};
+
+    fn text_response(text: &str, reasoning_content: Option<&str>) -> ChatResponse {
+        ChatResponse {
+            text: Some(text.to_string()),
+            tool_calls: vec![],
+            usage: None,
+            reasoning_content: reasoning_content.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn response_reasoning_reads_inline_think_tags() {
+        let resp = text_response("<think>Check the date first.</think>It is Friday.", None);
+        assert_eq!(
+            response_reasoning(&resp).as_deref(),
+            Some("Check the date first.")
+        );
+        assert_eq!(
+            response_reasoning(&text_response("It is Friday.", None)),
+            None
+        );
+    }
+
+    #[test]
+    fn response_reasoning_prefers_separate_reasoning_field() {
+        // OpenAI/DeepSeek-style: reasoning arrives outside the text.
+        let resp = text_response("It is Friday.", Some("The user wants the weekday."));
+        assert_eq!(
+            response_reasoning(&resp).as_deref(),
+            Some("The user wants the weekday.")
+        );
+        let blank = text_response("<think>inline</think>It is Friday.", Some("  "));
+        assert_eq!(response_reasoning(&blank).as_deref(), Some("inline"));
+    }
 
     #[test]
     fn native_assistant_history_preserves_tool_call_extra_content() {
@@ -464,6 +517,8 @@ mod cost_usd_regression_tests {
             model,
             temperature: None,
             approval: None,
+            tool_policy: None,
+            injection_scanner: None,
             channel_name: "",
             channel_reply_target: None,
             cancellation_token: None,
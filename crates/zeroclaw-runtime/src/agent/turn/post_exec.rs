@@ -1,17 +1,19 @@
 //! Post-execution recording: result log line, the `after_tool_call` hook, the
-//! completion Status, and filling the executed calls' `ordered_results` slots.
+//! prompt-injection scan, the completion Status, and filling the executed
+//! calls' `ordered_results` slots.
 
 use super::context::TurnCtx;
 use super::events::StreamDelta;
 use super::redact::scrub_credentials;
 use crate::agent::tool_execution::ToolExecutionOutcome;
+use crate::security::{InjectionDisposition, ToolOutputScanner};
 use crate::util::truncate_with_ellipsis;
 use zeroclaw_tool_call_parser::ParsedToolCall;
 
 /// Record each executed tool call's outcome (upstream loop body,
 /// post-execution section): one `tool_call_result` log line, the
-/// `after_tool_call` hook, a completion Status to the draft, and the
-/// call's slot in `ordered_results`.
+/// `after_tool_call` hook, the prompt-injection scan, a completion Status
+/// to the draft, and the call's slot in `ordered_results`.
 pub(crate) async fn record_executed_outcomes(
     ctx: &TurnCtx<'_>,
     executable_indices: &[usize],
@@ -20,7 +22,7 @@ pub(crate) async fn record_executed_outcomes(
     ordered_results: &mut [Option<(String, Option<String>, ToolExecutionOutcome)>],
     iteration: usize,
 ) {
-    for ((idx, call), outcome) in executable_indices
+    for ((idx, call), mut outcome) in executable_indices
         .iter()
         .zip(executable_calls.iter())
         .zip(executed_outcomes)
@@ -63,6 +65,11 @@ pub(crate) async fn record_executed_outcomes(
                 .await;
         }
 
+        // ── Prompt-injection scan (before history, SOP capture) ──
+        if let Some(scanner) = ctx.injection_scanner {
+            screen_tool_output(ctx, scanner, &call.name, &mut outcome, iteration).await;
+        }
+
         // ── Progress: tool completion ───────────────────────
         if let Some(tx) = ctx.on_delta {
             let secs = outcome.duration.as_secs();
@@ -99,6 +106,78 @@ pub(crate) async fn record_executed_outcomes(
     }
 }
 
+/// Score a tool result for prompt injection and, at or above the threshold,
+/// frame or withhold it per `[security.prompt_injection].action`. Hooks see
+/// the raw output first (`after_tool_call` runs before this); history and SOP
+/// step capture see the screened text.
+async fn screen_tool_output(
+    ctx: &TurnCtx<'_>,
+    scanner: &ToolOutputScanner,
+    tool: &str,
+    outcome: &mut ToolExecutionOutcome,
+    iteration: usize,
+) {
+    if outcome.output.is_empty() || !scanner.scans(tool) {
+        return;
+    }
+    let report = scanner.scan(tool, &outcome.output, ctx.hooks).await;
+    if report.signals.is_empty() {
+        return;
+    }
+    let (disposition, replacement) = scanner.apply(tool, &outcome.output, &report);
+    let Some(replacement) = replacement else {
+        ::zeroclaw_log::record!(
+            DEBUG,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Validate)
+                .with_category(::zeroclaw_log::EventCategory::Tool)
+                .with_attrs(::serde_json::json!({
+                    "tool": tool,
+                    "score": report.score,
+                    "patterns": report.labels(),
+                    "trace_id": ctx.turn_id,
+                })),
+            "tool output below prompt injection threshold"
+        );
+        return;
+    };
+
+    ::zeroclaw_log::record!(
+        WARN,
+        ::zeroclaw_log::Event::new(
+            module_path!(),
+            if disposition == InjectionDisposition::Blocked {
+                ::zeroclaw_log::Action::Reject
+            } else {
+                ::zeroclaw_log::Action::Validate
+            }
+        )
+        .with_category(::zeroclaw_log::EventCategory::Tool)
+        .with_attrs(::serde_json::json!({
+            "model": ctx.model,
+            "iteration": iteration + 1,
+            "tool": tool,
+            "score": report.score,
+            "patterns": report.labels(),
+            "blocked": disposition == InjectionDisposition::Blocked,
+            "agent_alias": ctx.agent_alias,
+            "trace_id": ctx.turn_id,
+        })),
+        "prompt injection detected in tool output"
+    );
+    scanner.audit(
+        tool,
+        &report,
+        disposition,
+        ctx.channel_name,
+        ctx.agent_alias,
+    );
+
+    if disposition == InjectionDisposition::Blocked {
+        outcome.output_data = None;
+    }
+    outcome.output = replacement;
+}
+
 /// Build the CLI completion-progress line. Failure text is scrubbed here
 /// because the progress channel is a human-facing rendering surface; the
 /// source `error_reason` carries raw bytes on the data path.
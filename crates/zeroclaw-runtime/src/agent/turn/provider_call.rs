@@ -340,6 +340,8 @@ mod payload_capture_tests {
             model: "stub-model",
             temperature: None,
             approval: None,
+            tool_policy: None,
+            injection_scanner: None,
             channel_name: "test",
             channel_reply_target: None,
             cancellation_token: None,
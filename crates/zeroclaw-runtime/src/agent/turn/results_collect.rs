@@ -2,6 +2,7 @@
 //! feed the pattern-based loop detector, and run the time-gated
 //! identical-output abort.
 
+use super::loop_stats::ToolLoopStats;
 use crate::agent::history::{
     append_or_merge_system_message, canonicalize_tool_result_media_markers_for,
     truncate_tool_result,
@@ -11,6 +12,7 @@ use crate::agent::tool_execution::ToolExecutionOutcome;
 use anyhow::Result;
 use std::collections::HashSet;
 use std::fmt::Write;
+use std::path::Path;
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
 use zeroclaw_config::schema::PacingConfig;
@@ -30,8 +32,9 @@ pub(crate) struct CollectedResults {
 
 /// Collect this round's tool results (upstream loop body, results-collection
 /// section): feed the loop detector (Warning/Block append system messages;
-/// Break bails), canonicalize media markers, truncate, append receipts, and
-/// build the per-call and XML result forms.
+/// Break bails; each is reported to the observer through `stats`), canonicalize media markers, spill oversized outputs to
+/// `spill_dir` (truncating when there is none), append receipts, and build
+/// the per-call and XML result forms.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn collect_tool_results(
     ordered_results: Vec<Option<(String, Option<String>, ToolExecutionOutcome)>>,
@@ -40,10 +43,12 @@ pub(crate) fn collect_tool_results(
     loop_detector: &mut LoopDetector,
     loop_ignore_tools: &HashSet<&str>,
     max_tool_result_chars: usize,
+    spill_dir: Option<&Path>,
     collected_receipts: Option<&Mutex<Vec<String>>>,
     model: &str,
     iteration: usize,
     turn_id: &str,
+    stats: &ToolLoopStats<'_>,
 ) -> Result<CollectedResults> {
     let mut tool_results = String::new();
     let mut individual_results: Vec<(Option<String>, String)> = Vec::new();
@@ -82,6 +87,7 @@ pub(crate) fn collect_tool_results(
                             ),
                         "loop detector warning"
                     );
+                    stats.record_loop_detection(&tool_name, "nudge", msg);
                     append_or_merge_system_message(history, format!("[Loop Detection] {msg}"));
                 }
                 crate::agent::loop_detector::LoopDetectionResult::Block(ref msg) => {
@@ -95,6 +101,7 @@ pub(crate) fn collect_tool_results(
                             ),
                         "loop detector blocked tool call"
                     );
+                    stats.record_loop_detection(&tool_name, "block", msg);
                     // Replace the tool output with the block message.
                     // We still continue the loop so the LLM sees the block feedback.
                     append_or_merge_system_message(
@@ -117,13 +124,23 @@ pub(crate) fn collect_tool_results(
                             })),
                         "loop_detector_circuit_breaker"
                     );
+                    stats.record_loop_detection(&tool_name, "abort", &msg);
                     anyhow::bail!("Agent loop aborted by loop detector: {msg}");
                 }
             }
         }
         let canonical_output =
             canonicalize_tool_result_media_markers_for(&tool_name, &outcome.output);
-        let mut result_output = truncate_tool_result(&canonical_output, max_tool_result_chars);
+        let mut result_output = spill_dir
+            .and_then(|dir| {
+                super::spill::spill_tool_result(
+                    &canonical_output,
+                    max_tool_result_chars,
+                    dir,
+                    &tool_name,
+                )
+            })
+            .unwrap_or_else(|| truncate_tool_result(&canonical_output, max_tool_result_chars));
         // Append HMAC receipt to tool result when receipts are enabled
         if let Some(ref receipt) = outcome.receipt {
             ::zeroclaw_log::record!(
@@ -216,8 +233,23 @@ mod tests {
     use super::*;
     use crate::agent::loop_detector::{LoopDetector, LoopDetectorConfig};
     use crate::agent::tool_execution::ToolExecutionOutcome;
+    use crate::agent::turn::context::TurnMeta;
     use zeroclaw_tool_call_parser::ParsedToolCall;
 
+    fn stats() -> ToolLoopStats<'static> {
+        ToolLoopStats::new(
+            &crate::observability::NoopObserver,
+            TurnMeta {
+                agent_alias: None,
+                parent_agent_alias: None,
+                turn_id: "turn-test",
+                channel_name: "cli",
+            },
+            "test-provider",
+            "test-model",
+        )
+    }
+
     const RATE_LIMIT_ERR: &str = "Rate limit exceeded: too many actions in the last hour";
 
     fn outcome(output: &str, success: bool) -> ToolExecutionOutcome {
@@ -264,9 +296,11 @@ mod tests {
             &ignore,
             10_000,
             None,
+            None,
             "test-model",
             0,
             "turn-test",
+            &stats(),
         )
     }
 
@@ -321,9 +355,11 @@ mod tests {
                 &ignore,
                 10_000,
                 None,
+                None,
                 "test-model",
                 iteration,
                 "turn-test",
+                &stats(),
             )?;
             check_identical_output_abort(
                 &collected.detection_relevant_output,
@@ -343,4 +379,47 @@ mod tests {
     fn failed_identical_outputs_do_not_trip_hash_based_abort() {
         assert!(run_hash_path(8, RATE_LIMIT_ERR, false).is_ok());
     }
+
+    #[test]
+    fn oversized_result_is_spilled_and_preview_stays_under_budget() {
+        let tmp = tempfile::tempdir().unwrap();
+        let spill_dir = super::super::spill::spill_dir(tmp.path());
+        let big: String = (0..20_000).map(|i| format!("{{\"row\": {i}}}\n")).collect();
+        let mut detector = LoopDetector::new(LoopDetectorConfig::default());
+        let mut history: Vec<ChatMessage> = Vec::new();
+        let tool_calls = vec![ParsedToolCall {
+            name: "http_request".to_string(),
+            arguments: serde_json::json!({ "url": "https://example.com/big.json" }),
+            tool_call_id: Some("call-1".to_string()),
+        }];
+        let ordered = vec![Some((
+            "http_request".to_string(),
+            Some("call-1".to_string()),
+            outcome(&big, true),
+        ))];
+
+        let collected = collect_tool_results(
+            ordered,
+            &tool_calls,
+            &mut history,
+            &mut detector,
+            &HashSet::new(),
+            4_000,
+            Some(&spill_dir),
+            None,
+            "test-model",
+            0,
+            "turn-test",
+            &stats(),
+        )
+        .unwrap();
+
+        let (id, preview) = &collected.individual_results[0];
+        assert_eq!(id.as_deref(), Some("call-1"));
+        assert!(preview.len() <= 4_000, "preview is {} chars", preview.len());
+        assert!(preview.contains("file_read"));
+        let spilled: Vec<_> = std::fs::read_dir(&spill_dir).unwrap().flatten().collect();
+        assert_eq!(spilled.len(), 1);
+        assert_eq!(std::fs::read_to_string(spilled[0].path()).unwrap(), big);
+    }
 }
@@ -0,0 +1,139 @@
+//! Tool-output spillover: a result over the `max_tool_result_chars` budget is
+//! written in full to `<workspace>/state/tool-output/<id>.txt` and replaced in
+//! history by a head/tail preview plus a pointer the model can page through
+//! with `file_read` (`offset`/`limit`), instead of losing the middle outright.
+
+use crate::agent::history::truncate_tool_result;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Spill files older than this are pruned on the next spill.
+const SPILL_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Room reserved for `truncate_tool_result`'s `[... N characters truncated ...]`
+/// marker so the preview plus note stays within the budget.
+const TRUNCATION_MARKER_RESERVE: usize = 64;
+
+/// Spill directory for an agent workspace.
+pub(crate) fn spill_dir(workspace_dir: &Path) -> PathBuf {
+    workspace_dir.join("state").join("tool-output")
+}
+
+/// Spill `output` when it exceeds `max_chars` (0 = unlimited). Returns the
+/// in-history replacement, or `None` when the output fits or the spill file
+/// could not be written — callers then fall back to plain truncation.
+pub(crate) fn spill_tool_result(
+    output: &str,
+    max_chars: usize,
+    dir: &Path,
+    tool_name: &str,
+) -> Option<String> {
+    if max_chars == 0 || output.len() <= max_chars {
+        return None;
+    }
+
+    let path = dir.join(format!("{}.txt", uuid::Uuid::new_v4()));
+    if let Err(e) = std::fs::create_dir_all(dir).and_then(|()| std::fs::write(&path, output)) {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                .with_category(::zeroclaw_log::EventCategory::Tool)
+                .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                .with_attrs(::serde_json::json!({
+                    "tool": tool_name,
+                    "path": path.display().to_string(),
+                    "error": format!("{e}"),
+                })),
+            "tool output spill failed; truncating instead"
+        );
+        return None;
+    }
+    prune_stale_spills(dir);
+
+    let note = format!(
+        "[Tool output too large for context: {} characters, {} lines. Full output saved to {}. \
+         Read it with file_read using offset/limit (1-based line numbers) to page through it.]",
+        output.len(),
+        output.lines().count(),
+        path.display()
+    );
+    let preview_budget = max_chars
+        .saturating_sub(note.len() + 2)
+        .saturating_sub(TRUNCATION_MARKER_RESERVE);
+    ::zeroclaw_log::record!(
+        DEBUG,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+            .with_category(::zeroclaw_log::EventCategory::Tool)
+            .with_attrs(::serde_json::json!({
+                "tool": tool_name,
+                "chars": output.len(),
+                "path": path.display().to_string(),
+            })),
+        "tool output spilled to file"
+    );
+    if preview_budget == 0 {
+        return Some(note);
+    }
+    Some(format!(
+        "{}\n\n{note}",
+        truncate_tool_result(output, preview_budget)
+    ))
+}
+
+/// Best-effort removal of spill files past [`SPILL_RETENTION`].
+fn prune_stale_spills(dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let now = SystemTime::now();
+    for entry in entries.flatten() {
+        let stale = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok())
+            .is_some_and(|age| age > SPILL_RETENTION);
+        if stale {
+            let _ = std::fs::remove_file(entry.path());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_output_is_not_spilled() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(spill_tool_result("short", 100, tmp.path(), "shell").is_none());
+        assert!(spill_tool_result(&"x".repeat(500), 0, tmp.path(), "shell").is_none());
+        assert!(!tmp.path().join("state").exists());
+    }
+
+    #[test]
+    fn oversized_output_spills_with_preview_under_budget() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = spill_dir(tmp.path());
+        let output: String = (0..5_000).map(|i| format!("line {i}\n")).collect();
+
+        let preview = spill_tool_result(&output, 2_000, &dir, "http_request").unwrap();
+        assert!(preview.len() <= 2_000, "preview is {} chars", preview.len());
+        assert!(preview.starts_with("line 0\n"));
+        assert!(preview.contains("file_read"));
+
+        let files: Vec<_> = std::fs::read_dir(&dir).unwrap().flatten().collect();
+        assert_eq!(files.len(), 1);
+        let spilled = files[0].path();
+        assert!(preview.contains(&spilled.display().to_string()));
+        assert_eq!(std::fs::read_to_string(spilled).unwrap(), output);
+    }
+
+    #[test]
+    fn tiny_budget_keeps_only_the_pointer() {
+        let tmp = tempfile::tempdir().unwrap();
+        let preview = spill_tool_result(&"y".repeat(1_000), 50, tmp.path(), "shell").unwrap();
+        assert!(preview.starts_with("[Tool output too large"));
+        assert!(!preview.contains("yyyy"));
+    }
+}
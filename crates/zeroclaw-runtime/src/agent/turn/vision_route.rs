@@ -143,6 +143,7 @@ pub(crate) async fn prepare_messages_for_iteration(
             .map(|m| ChatMessage {
                 role: m.role.clone(),
                 content: multimodal::strip_media_markers(&m.content),
+                reasoning: None,
             })
             .collect();
         match image_cache {
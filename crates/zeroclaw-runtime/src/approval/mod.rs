@@ -12,6 +12,8 @@ use std::io::BufReader;
 use std::io::{self, BufRead, Write};
 use zeroclaw_config::schema::RiskProfileConfig;
 
+pub mod queue;
+
 // ── Types ────────────────────────────────────────────────────────
 
 /// A request to approve a tool call before execution.
@@ -210,6 +212,18 @@ impl ApprovalManager {
         ApprovalRequirement::Prompt
     }
 
+    /// Human-readable reasons a call to `tool_name` needs approval, for the
+    /// persistent approval queue.
+    pub fn approval_reasons(&self, tool_name: &str) -> Vec<String> {
+        let mut reasons = Vec::new();
+        if self.always_ask.contains("*") || self.always_ask.contains(tool_name) {
+            reasons.push("listed in always_ask".to_string());
+        } else if self.autonomy_level == AutonomyLevel::Supervised {
+            reasons.push("supervised mode: tool is not in auto_approve".to_string());
+        }
+        reasons
+    }
+
     /// Record an approval decision and update session state.
     pub fn record_decision(
         &self,
@@ -251,7 +265,17 @@ impl ApprovalManager {
     /// Only called for interactive (CLI) managers. Non-interactive managers
     /// auto-deny in the tool-call loop before reaching this point.
     pub fn prompt_cli(&self, request: &ApprovalRequest) -> ApprovalResponse {
-        prompt_cli_interactive(request)
+        self.prompt_cli_with_risk(request, None)
+    }
+
+    /// Like [`Self::prompt_cli`], with a line explaining why the call is
+    /// risky (e.g. the shell policy's risk verdict) shown under the summary.
+    pub fn prompt_cli_with_risk(
+        &self,
+        request: &ApprovalRequest,
+        risk: Option<&str>,
+    ) -> ApprovalResponse {
+        prompt_cli_interactive(request, risk)
     }
 }
 
@@ -259,11 +283,14 @@ impl ApprovalManager {
 
 /// Display the approval prompt and read user input from the controlling
 /// terminal when available, falling back to stdin otherwise.
-fn prompt_cli_interactive(request: &ApprovalRequest) -> ApprovalResponse {
+fn prompt_cli_interactive(request: &ApprovalRequest, risk: Option<&str>) -> ApprovalResponse {
     let summary = summarize_args(&request.arguments);
     eprintln!();
     eprintln!("🔧 Agent wants to execute: {}", request.tool_name);
     eprintln!("   {summary}");
+    if let Some(risk) = risk {
+        eprintln!("   Risk: {risk}");
+    }
     eprint!("   [Y]es / [N]o / [A]lways for {}: ", request.tool_name);
     let _ = io::stderr().flush();
 
@@ -581,6 +608,16 @@ mod tests {
         assert!(!mgr.needs_approval("file_write"));
     }
 
+    #[test]
+    fn approval_reasons_name_the_rule() {
+        let mgr = ApprovalManager::from_risk_profile(&supervised_config());
+        assert_eq!(mgr.approval_reasons("shell"), vec!["listed in always_ask"]);
+        assert_eq!(
+            mgr.approval_reasons("file_write"),
+            vec!["supervised mode: tool is not in auto_approve"]
+        );
+    }
+
     #[test]
     fn always_ask_overrides_session_allowlist() {
         let mgr = ApprovalManager::from_risk_profile(&supervised_config());
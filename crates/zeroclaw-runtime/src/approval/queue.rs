@@ -0,0 +1,640 @@
+//! Persistent approval queue for unattended daemons (`[approvals]`).
+//!
+//! A tool call that needs approval on a surface where nobody can answer
+//! inline is recorded in `<data_dir>/approvals/approvals.db` and waits there.
+//! `zeroclaw approvals approve|deny` resolves it through the gateway admin
+//! API, which wakes the waiting call at once; a decision written straight to
+//! the database (gateway down) is picked up by the waiter's poll instead.
+//! Unanswered requests are denied as `expired` after `timeout_secs`.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use parking_lot::{Mutex, RwLock};
+use rusqlite::{Connection, OptionalExtension, params};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+use zeroclaw_config::schema::Config;
+
+/// How often a waiter re-reads its row for decisions written without the
+/// gateway.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+const APPROVAL_COLUMNS: &str = "id, tool_name, arguments_summary, risk_reasons, agent_alias, \
+     channel, sender, status, created_at, expires_at, decided_at, decided_by, decided_via, reason";
+
+/// Lifecycle state of a queued approval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalStatus {
+    Pending,
+    Approved,
+    Denied,
+    /// Nobody answered within `timeout_secs`; the call was denied.
+    Expired,
+    /// The waiting turn ended (timeout, cancellation, daemon restart)
+    /// before a decision arrived.
+    Cancelled,
+}
+
+impl ApprovalStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Approved => "approved",
+            Self::Denied => "denied",
+            Self::Expired => "expired",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        Some(match raw {
+            "pending" => Self::Pending,
+            "approved" => Self::Approved,
+            "denied" => Self::Denied,
+            "expired" => Self::Expired,
+            "cancelled" => Self::Cancelled,
+            _ => return None,
+        })
+    }
+}
+
+impl std::fmt::Display for ApprovalStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A persisted approval request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct QueuedApproval {
+    pub id: String,
+    pub tool_name: String,
+    /// Argument summary with secret-looking values redacted
+    /// (see [`super::summarize_args`]).
+    pub arguments_summary: String,
+    /// Why the call needs approval, e.g. `listed in always_ask`.
+    pub risk_reasons: Vec<String>,
+    pub agent_alias: Option<String>,
+    /// Channel the request came from (e.g. `telegram`).
+    pub channel: String,
+    /// Sender / reply target on that channel.
+    pub sender: Option<String>,
+    pub status: ApprovalStatus,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub decided_at: Option<DateTime<Utc>>,
+    /// Who decided, e.g. `cli (alice)` or `http:<token-hash>`.
+    pub decided_by: Option<String>,
+    /// Where the decision came from, e.g. `gateway 127.0.0.1`.
+    pub decided_via: Option<String>,
+    /// Deny reason, or why the request expired or was cancelled.
+    pub reason: Option<String>,
+}
+
+/// Fields for a new queue entry.
+#[derive(Debug, Clone)]
+pub struct NewApproval {
+    pub tool_name: String,
+    pub arguments: serde_json::Value,
+    pub risk_reasons: Vec<String>,
+    pub agent_alias: Option<String>,
+    pub channel: String,
+    pub sender: Option<String>,
+}
+
+/// An operator's answer to a queued approval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovalDecision {
+    pub approved: bool,
+    pub decided_by: String,
+    pub decided_via: String,
+    pub reason: Option<String>,
+}
+
+/// Result of [`resolve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolveOutcome {
+    Resolved(QueuedApproval),
+    /// The request was already approved, denied, expired or cancelled.
+    AlreadyResolved(QueuedApproval),
+    NotFound,
+}
+
+/// What a waiting tool call ends up with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WaitOutcome {
+    Decided(ApprovalDecision),
+    Expired,
+}
+
+/// The daemon's approval queue: the database plus the in-process waiters
+/// that gateway resolutions wake directly.
+pub struct ApprovalQueue {
+    db_path: PathBuf,
+    timeout: Duration,
+    waiters: Mutex<HashMap<String, oneshot::Sender<ApprovalDecision>>>,
+}
+
+static ACTIVE: RwLock<Option<Arc<ApprovalQueue>>> = RwLock::new(None);
+
+/// Install (or clear) the queue tool calls in this process wait on. The
+/// daemon installs one when `[approvals] enabled = true`.
+pub fn install(queue: Option<Arc<ApprovalQueue>>) {
+    *ACTIVE.write() = queue;
+}
+
+/// The installed queue, if any.
+pub fn active() -> Option<Arc<ApprovalQueue>> {
+    ACTIVE.read().clone()
+}
+
+impl ApprovalQueue {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            db_path: approvals_db_path(config),
+            timeout: Duration::from_secs(config.approvals.timeout_secs),
+            waiters: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Mark requests left pending by a previous daemon as cancelled: the
+    /// tool calls that were waiting on them no longer exist.
+    pub fn recover_stale(&self) -> Result<usize> {
+        let reason = "daemon restarted before a decision";
+        with_connection(&self.db_path, |conn| {
+            conn.execute(
+                "UPDATE approvals SET status = ?1, decided_at = ?2, reason = ?3
+                 WHERE status = ?4",
+                params![
+                    ApprovalStatus::Cancelled.as_str(),
+                    Utc::now().to_rfc3339(),
+                    reason,
+                    ApprovalStatus::Pending.as_str(),
+                ],
+            )
+            .context("Failed to recover stale approvals")
+        })
+    }
+
+    /// Persist a new pending request.
+    pub fn submit(&self, request: NewApproval) -> Result<QueuedApproval> {
+        let id = Uuid::new_v4().simple().to_string()[..8].to_string();
+        let now = Utc::now();
+        let expires_at = i64::try_from(self.timeout.as_secs())
+            .ok()
+            .and_then(chrono::Duration::try_seconds)
+            .and_then(|timeout| now.checked_add_signed(timeout))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        let summary = super::summarize_args(&request.arguments);
+        let reasons = serde_json::to_string(&request.risk_reasons)?;
+        with_connection(&self.db_path, |conn| {
+            conn.execute(
+                "INSERT INTO approvals (id, tool_name, arguments_summary, risk_reasons,
+                                        agent_alias, channel, sender, status, created_at,
+                                        expires_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    id,
+                    request.tool_name,
+                    summary,
+                    reasons,
+                    request.agent_alias,
+                    request.channel,
+                    request.sender,
+                    ApprovalStatus::Pending.as_str(),
+                    now.to_rfc3339(),
+                    expires_at.to_rfc3339(),
+                ],
+            )
+            .context("Failed to insert approval request")?;
+            Ok(())
+        })?;
+        get_from(&self.db_path, &id)?.with_context(|| format!("Approval '{id}' vanished"))
+    }
+
+    /// Wait for a decision on `id`, denying it as expired once its deadline
+    /// passes. Dropping the future before it completes (the turn timed out
+    /// or was cancelled) marks the request `cancelled`.
+    pub async fn wait(&self, id: &str) -> Result<WaitOutcome> {
+        let (tx, mut rx) = oneshot::channel();
+        self.waiters.lock().insert(id.to_string(), tx);
+        let mut guard = PendingGuard {
+            queue: self,
+            id,
+            armed: true,
+        };
+        let now = tokio::time::Instant::now();
+        let deadline = now
+            .checked_add(self.timeout)
+            .unwrap_or_else(|| now + Duration::from_secs(86_400 * 365));
+        let mut waker_open = true;
+        let mut poll = tokio::time::interval(POLL_INTERVAL);
+        poll.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let outcome = loop {
+            tokio::select! {
+                decision = &mut rx, if waker_open => match decision {
+                    Ok(decision) => break WaitOutcome::Decided(decision),
+                    Err(_) => waker_open = false,
+                },
+                _ = poll.tick() => {
+                    if let Some(decision) = get_from(&self.db_path, id)?.and_then(|a| a.decision()) {
+                        break WaitOutcome::Decided(decision);
+                    }
+                }
+                () = tokio::time::sleep_until(deadline) => {
+                    let expired = finish(
+                        &self.db_path,
+                        id,
+                        ApprovalStatus::Expired,
+                        None,
+                        None,
+                        Some("no decision before the approval timeout"),
+                    )?;
+                    if expired {
+                        break WaitOutcome::Expired;
+                    }
+                    // Resolved between the last poll and the deadline.
+                    if let Some(decision) = get_from(&self.db_path, id)?.and_then(|a| a.decision()) {
+                        break WaitOutcome::Decided(decision);
+                    }
+                    break WaitOutcome::Expired;
+                }
+            }
+        };
+        guard.armed = false;
+        self.waiters.lock().remove(id);
+        Ok(outcome)
+    }
+
+    /// Approve or deny `id`, waking the waiting tool call when it lives in
+    /// this process.
+    pub fn resolve(&self, id: &str, decision: ApprovalDecision) -> Result<ResolveOutcome> {
+        let outcome = resolve_at(&self.db_path, id, &decision)?;
+        if matches!(outcome, ResolveOutcome::Resolved(_))
+            && let Some(waiter) = self.waiters.lock().remove(id)
+        {
+            let _ = waiter.send(decision);
+        }
+        Ok(outcome)
+    }
+}
+
+/// Marks the request cancelled if its waiter is dropped mid-wait.
+struct PendingGuard<'a> {
+    queue: &'a ApprovalQueue,
+    id: &'a str,
+    armed: bool,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+        self.queue.waiters.lock().remove(self.id);
+        let _ = finish(
+            &self.queue.db_path,
+            self.id,
+            ApprovalStatus::Cancelled,
+            None,
+            None,
+            Some("the waiting turn ended before a decision"),
+        );
+    }
+}
+
+impl QueuedApproval {
+    /// The operator decision recorded on this row, if it has one.
+    fn decision(&self) -> Option<ApprovalDecision> {
+        let approved = match self.status {
+            ApprovalStatus::Approved => true,
+            ApprovalStatus::Denied => false,
+            _ => return None,
+        };
+        Some(ApprovalDecision {
+            approved,
+            decided_by: self.decided_by.clone().unwrap_or_default(),
+            decided_via: self.decided_via.clone().unwrap_or_default(),
+            reason: self.reason.clone(),
+        })
+    }
+}
+
+/// Resolve `id` in `config`'s queue. Uses the installed queue when this
+/// process has one, so an in-process waiter wakes immediately.
+pub fn resolve(config: &Config, id: &str, decision: ApprovalDecision) -> Result<ResolveOutcome> {
+    match active() {
+        Some(queue) if queue.db_path == approvals_db_path(config) => queue.resolve(id, decision),
+        _ => resolve_at(&approvals_db_path(config), id, &decision),
+    }
+}
+
+/// Pending requests first, then the most recent decisions.
+pub fn list_approvals(
+    config: &Config,
+    pending_only: bool,
+    limit: usize,
+) -> Result<Vec<QueuedApproval>> {
+    let limit = i64::try_from(limit.max(1)).unwrap_or(i64::MAX);
+    with_connection(&approvals_db_path(config), |conn| {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {APPROVAL_COLUMNS} FROM approvals
+             WHERE (?1 = 0 OR status = 'pending')
+             ORDER BY status = 'pending' DESC, created_at DESC
+             LIMIT ?2"
+        ))?;
+        let rows = stmt.query_map(params![i64::from(pending_only), limit], map_row)?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to list approvals")
+    })
+}
+
+pub fn get_approval(config: &Config, id: &str) -> Result<Option<QueuedApproval>> {
+    get_from(&approvals_db_path(config), id)
+}
+
+fn resolve_at(
+    db_path: &std::path::Path,
+    id: &str,
+    decision: &ApprovalDecision,
+) -> Result<ResolveOutcome> {
+    let status = if decision.approved {
+        ApprovalStatus::Approved
+    } else {
+        ApprovalStatus::Denied
+    };
+    let changed = finish(
+        db_path,
+        id,
+        status,
+        Some(&decision.decided_by),
+        Some(&decision.decided_via),
+        decision.reason.as_deref(),
+    )?;
+    Ok(match get_from(db_path, id)? {
+        None => ResolveOutcome::NotFound,
+        Some(approval) if changed => ResolveOutcome::Resolved(approval),
+        Some(approval) => ResolveOutcome::AlreadyResolved(approval),
+    })
+}
+
+/// Move a pending row to `status`. Returns `false` when the row was no
+/// longer pending (or does not exist).
+fn finish(
+    db_path: &std::path::Path,
+    id: &str,
+    status: ApprovalStatus,
+    decided_by: Option<&str>,
+    decided_via: Option<&str>,
+    reason: Option<&str>,
+) -> Result<bool> {
+    with_connection(db_path, |conn| {
+        let changed = conn
+            .execute(
+                "UPDATE approvals
+                 SET status = ?1, decided_at = ?2, decided_by = ?3, decided_via = ?4, reason = ?5
+                 WHERE id = ?6 AND status = ?7",
+                params![
+                    status.as_str(),
+                    Utc::now().to_rfc3339(),
+                    decided_by,
+                    decided_via,
+                    reason,
+                    id,
+                    ApprovalStatus::Pending.as_str(),
+                ],
+            )
+            .context("Failed to update approval")?;
+        Ok(changed > 0)
+    })
+}
+
+fn get_from(db_path: &std::path::Path, id: &str) -> Result<Option<QueuedApproval>> {
+    with_connection(db_path, |conn| {
+        conn.query_row(
+            &format!("SELECT {APPROVAL_COLUMNS} FROM approvals WHERE id = ?1"),
+            params![id],
+            map_row,
+        )
+        .optional()
+        .context("Failed to query approval")
+    })
+}
+
+fn parse_timestamp(raw: Option<String>) -> rusqlite::Result<Option<DateTime<Utc>>> {
+    raw.map(|raw| {
+        DateTime::parse_from_rfc3339(&raw)
+            .map(|ts| ts.with_timezone(&Utc))
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))
+    })
+    .transpose()
+}
+
+fn map_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<QueuedApproval> {
+    let status_raw: String = row.get(7)?;
+    let status = ApprovalStatus::parse(&status_raw).ok_or_else(|| {
+        rusqlite::Error::ToSqlConversionFailure(
+            format!("unknown approval status {status_raw:?}").into(),
+        )
+    })?;
+    let reasons_raw: String = row.get(3)?;
+    Ok(QueuedApproval {
+        id: row.get(0)?,
+        tool_name: row.get(1)?,
+        arguments_summary: row.get(2)?,
+        risk_reasons: serde_json::from_str(&reasons_raw).unwrap_or_default(),
+        agent_alias: row.get(4)?,
+        channel: row.get(5)?,
+        sender: row.get(6)?,
+        status,
+        created_at: parse_timestamp(Some(row.get::<_, String>(8)?))?.unwrap_or_else(Utc::now),
+        expires_at: parse_timestamp(Some(row.get::<_, String>(9)?))?.unwrap_or_else(Utc::now),
+        decided_at: parse_timestamp(row.get(10)?)?,
+        decided_by: row.get(11)?,
+        decided_via: row.get(12)?,
+        reason: row.get(13)?,
+    })
+}
+
+fn approvals_db_path(config: &Config) -> PathBuf {
+    config.data_dir.join("approvals").join("approvals.db")
+}
+
+fn with_connection<T>(
+    db_path: &std::path::Path,
+    f: impl FnOnce(&Connection) -> Result<T>,
+) -> Result<T> {
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent).with_context(|| {
+            format!("Failed to create approvals directory: {}", parent.display())
+        })?;
+    }
+    let conn = Connection::open(db_path)
+        .with_context(|| format!("Failed to open approvals DB: {}", db_path.display()))?;
+    conn.busy_timeout(Duration::from_secs(5))?;
+    initialize_schema(&conn)?;
+    f(&conn)
+}
+
+fn initialize_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS approvals (
+            id                TEXT PRIMARY KEY,
+            tool_name         TEXT NOT NULL,
+            arguments_summary TEXT NOT NULL,
+            risk_reasons      TEXT NOT NULL DEFAULT '[]',
+            agent_alias       TEXT,
+            channel           TEXT NOT NULL,
+            sender            TEXT,
+            status            TEXT NOT NULL,
+            created_at        TEXT NOT NULL,
+            expires_at        TEXT NOT NULL,
+            decided_at        TEXT,
+            decided_by        TEXT,
+            decided_via       TEXT,
+            reason            TEXT
+        );
+        CREATE INDEX IF NOT EXISTS idx_approvals_status ON approvals(status, created_at);",
+    )
+    .context("Failed to initialize approvals schema")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_config(tmp: &TempDir, timeout_secs: u64) -> Config {
+        let mut config = Config {
+            data_dir: tmp.path().join("data"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        config.approvals.timeout_secs = timeout_secs;
+        config
+    }
+
+    fn request() -> NewApproval {
+        NewApproval {
+            tool_name: "shell".into(),
+            arguments: serde_json::json!({"command": "rm -rf build", "api_key": "sk-123"}),
+            risk_reasons: vec!["listed in always_ask".into()],
+            agent_alias: Some("default".into()),
+            channel: "telegram".into(),
+            sender: Some("42".into()),
+        }
+    }
+
+    fn decision(approved: bool) -> ApprovalDecision {
+        ApprovalDecision {
+            approved,
+            decided_by: "cli (alice)".into(),
+            decided_via: "gateway 127.0.0.1".into(),
+            reason: (!approved).then(|| "not today".into()),
+        }
+    }
+
+    #[test]
+    fn submitted_request_is_listed_with_redacted_args() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp, 60);
+        let queue = ApprovalQueue::new(&config);
+        let queued = queue.submit(request()).unwrap();
+
+        assert_eq!(queued.status, ApprovalStatus::Pending);
+        assert!(queued.arguments_summary.contains("command: rm -rf build"));
+        assert!(!queued.arguments_summary.contains("sk-123"));
+        assert_eq!(queued.risk_reasons, vec!["listed in always_ask"]);
+
+        let pending = list_approvals(&config, true, 10).unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, queued.id);
+    }
+
+    #[tokio::test]
+    async fn resolve_wakes_the_waiter_and_records_who_decided() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp, 60);
+        let queue = Arc::new(ApprovalQueue::new(&config));
+        let queued = queue.submit(request()).unwrap();
+
+        let waiter = {
+            let queue = Arc::clone(&queue);
+            let id = queued.id.clone();
+            tokio::spawn(async move { queue.wait(&id).await })
+        };
+        tokio::task::yield_now().await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let outcome = queue.resolve(&queued.id, decision(true)).unwrap();
+        assert!(matches!(outcome, ResolveOutcome::Resolved(_)));
+        assert_eq!(
+            waiter.await.unwrap().unwrap(),
+            WaitOutcome::Decided(decision(true))
+        );
+
+        let stored = get_approval(&config, &queued.id).unwrap().unwrap();
+        assert_eq!(stored.status, ApprovalStatus::Approved);
+        assert_eq!(stored.decided_by.as_deref(), Some("cli (alice)"));
+        assert_eq!(stored.decided_via.as_deref(), Some("gateway 127.0.0.1"));
+
+        assert!(matches!(
+            queue.resolve(&queued.id, decision(false)).unwrap(),
+            ResolveOutcome::AlreadyResolved(_)
+        ));
+        assert_eq!(
+            queue.resolve("missing", decision(true)).unwrap(),
+            ResolveOutcome::NotFound
+        );
+    }
+
+    #[tokio::test]
+    async fn decision_written_without_the_gateway_is_polled() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp, 60);
+        let queue = ApprovalQueue::new(&config);
+        let queued = queue.submit(request()).unwrap();
+
+        // Another process (the CLI with the gateway down) writes the row.
+        resolve_at(&approvals_db_path(&config), &queued.id, &decision(false)).unwrap();
+        assert_eq!(
+            queue.wait(&queued.id).await.unwrap(),
+            WaitOutcome::Decided(decision(false))
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn unanswered_request_expires() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp, 5);
+        let queue = ApprovalQueue::new(&config);
+        let queued = queue.submit(request()).unwrap();
+
+        assert_eq!(queue.wait(&queued.id).await.unwrap(), WaitOutcome::Expired);
+        let stored = get_approval(&config, &queued.id).unwrap().unwrap();
+        assert_eq!(stored.status, ApprovalStatus::Expired);
+    }
+
+    #[tokio::test]
+    async fn dropped_waiter_cancels_and_restart_recovers_stale_rows() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp, 60);
+        let queue = ApprovalQueue::new(&config);
+        let first = queue.submit(request()).unwrap();
+        let second = queue.submit(request()).unwrap();
+
+        let _ = tokio::time::timeout(Duration::from_millis(20), queue.wait(&first.id)).await;
+        let stored = get_approval(&config, &first.id).unwrap().unwrap();
+        assert_eq!(stored.status, ApprovalStatus::Cancelled);
+
+        assert_eq!(queue.recover_stale().unwrap(), 1);
+        let stored = get_approval(&config, &second.id).unwrap().unwrap();
+        assert_eq!(stored.status, ApprovalStatus::Cancelled);
+    }
+}
@@ -0,0 +1,167 @@
+//! In-process run locks for the scheduler loop: how many runs of each job
+//! are in flight and how many occurrences wait behind them. The
+//! `cron_jobs.locked_at` column mirrors "at least one run in flight" so
+//! other processes (and `cron list`) can see it.
+
+use super::ConcurrencyPolicy;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+
+/// What the scheduler does with an occurrence that just came due.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Admission {
+    /// Start a run now. `first` is set when no other run of the job is in
+    /// flight, so the caller must take the store lock.
+    Start { first: bool },
+    /// Drop the occurrence; a run is already in flight.
+    Skip,
+    /// Hold the occurrence until the current run finishes.
+    Queue,
+    /// Would queue, but the backlog is already at its cap.
+    QueueFull,
+}
+
+/// What follows a finished run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AfterRun {
+    /// Run the next queued occurrence; the job's run slot stays held.
+    RunQueued,
+    /// Other runs of the job are still in flight.
+    StillRunning,
+    /// The job is idle; release its store lock.
+    Idle,
+}
+
+#[derive(Debug, Default)]
+struct RunState {
+    running: usize,
+    queued: usize,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct RunLocks {
+    jobs: Mutex<HashMap<String, RunState>>,
+}
+
+impl RunLocks {
+    pub(crate) fn admit(
+        &self,
+        job_id: &str,
+        policy: ConcurrencyPolicy,
+        max_queued: usize,
+    ) -> Admission {
+        let mut jobs = self.jobs.lock();
+        let state = jobs.entry(job_id.to_string()).or_default();
+        if state.running == 0 {
+            state.running = 1;
+            return Admission::Start { first: true };
+        }
+        match policy {
+            ConcurrencyPolicy::Allow => {
+                state.running += 1;
+                Admission::Start { first: false }
+            }
+            ConcurrencyPolicy::Skip => Admission::Skip,
+            ConcurrencyPolicy::Queue if state.queued < max_queued => {
+                state.queued += 1;
+                Admission::Queue
+            }
+            ConcurrencyPolicy::Queue => Admission::QueueFull,
+        }
+    }
+
+    pub(crate) fn is_running(&self, job_id: &str) -> bool {
+        self.jobs
+            .lock()
+            .get(job_id)
+            .is_some_and(|state| state.running > 0)
+    }
+
+    pub(crate) fn finish(&self, job_id: &str) -> AfterRun {
+        let mut jobs = self.jobs.lock();
+        let Some(state) = jobs.get_mut(job_id) else {
+            return AfterRun::Idle;
+        };
+        if state.queued > 0 {
+            state.queued -= 1;
+            return AfterRun::RunQueued;
+        }
+        state.running = state.running.saturating_sub(1);
+        if state.running > 0 {
+            return AfterRun::StillRunning;
+        }
+        jobs.remove(job_id);
+        AfterRun::Idle
+    }
+
+    /// Give back a slot taken by [`Self::admit`] for a run that never
+    /// started, dropping anything queued behind it.
+    pub(crate) fn abandon(&self, job_id: &str) -> AfterRun {
+        if let Some(state) = self.jobs.lock().get_mut(job_id) {
+            state.queued = 0;
+        }
+        self.finish(job_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skip_drops_occurrences_while_running() {
+        let locks = RunLocks::default();
+        let policy = ConcurrencyPolicy::Skip;
+        assert_eq!(
+            locks.admit("job", policy, 3),
+            Admission::Start { first: true }
+        );
+        assert_eq!(locks.admit("job", policy, 3), Admission::Skip);
+        assert!(locks.is_running("job"));
+        assert_eq!(locks.finish("job"), AfterRun::Idle);
+        assert!(!locks.is_running("job"));
+    }
+
+    #[test]
+    fn queue_holds_up_to_the_cap_and_runs_them_in_turn() {
+        let locks = RunLocks::default();
+        let policy = ConcurrencyPolicy::Queue;
+        assert_eq!(
+            locks.admit("job", policy, 2),
+            Admission::Start { first: true }
+        );
+        assert_eq!(locks.admit("job", policy, 2), Admission::Queue);
+        assert_eq!(locks.admit("job", policy, 2), Admission::Queue);
+        assert_eq!(locks.admit("job", policy, 2), Admission::QueueFull);
+
+        assert_eq!(locks.finish("job"), AfterRun::RunQueued);
+        assert_eq!(locks.finish("job"), AfterRun::RunQueued);
+        assert_eq!(locks.finish("job"), AfterRun::Idle);
+    }
+
+    #[test]
+    fn allow_counts_overlapping_runs() {
+        let locks = RunLocks::default();
+        let policy = ConcurrencyPolicy::Allow;
+        assert_eq!(
+            locks.admit("job", policy, 0),
+            Admission::Start { first: true }
+        );
+        assert_eq!(
+            locks.admit("job", policy, 0),
+            Admission::Start { first: false }
+        );
+        assert_eq!(locks.finish("job"), AfterRun::StillRunning);
+        assert_eq!(locks.finish("job"), AfterRun::Idle);
+    }
+
+    #[test]
+    fn abandon_releases_the_slot_and_clears_the_queue() {
+        let locks = RunLocks::default();
+        let policy = ConcurrencyPolicy::Queue;
+        locks.admit("job", policy, 3);
+        locks.admit("job", policy, 3);
+        assert_eq!(locks.abandon("job"), AfterRun::Idle);
+        assert!(!locks.is_running("job"));
+    }
+}
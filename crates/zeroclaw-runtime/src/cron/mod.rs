@@ -2,27 +2,32 @@ use crate::security::SecurityPolicy;
 use anyhow::{Result, bail};
 use zeroclaw_config::schema::Config;
 
+mod concurrency;
+mod natural;
 mod schedule;
 mod store;
 mod types;
 
+pub mod quiet_hours;
 pub mod scheduler;
 
+pub use natural::{NaturalSchedule, parse_natural_schedule};
 #[allow(unused_imports)]
 pub use schedule::{
     next_run_for_schedule, normalize_expression, schedule_cron_expression, validate_schedule,
 };
 #[allow(unused_imports)]
 pub use store::{
-    add_agent_job, all_overdue_jobs, claim_job, clear_stale_locks, due_jobs, get_job, list_jobs,
-    list_jobs_by_agent, list_runs, record_last_run, record_last_run_with_status, record_run,
-    release_job, remove_job, remove_jobs_by_agent, rename_jobs_by_agent, reschedule_after_run,
+    add_agent_job, add_agent_job_with_options, all_overdue_jobs, claim_job, clear_stale_locks,
+    due_jobs, due_jobs_including_running, get_job, list_jobs, list_jobs_by_agent, list_runs,
+    record_last_run, record_last_run_with_status, record_run, release_job, remove_job,
+    remove_jobs_by_agent, rename_jobs_by_agent, reschedule_after_run,
     reschedule_after_run_with_status, resolve_job_id_or_name, skip_missed_run,
     sync_declarative_jobs, update_job,
 };
 pub use types::{
-    CronJob, CronJobPatch, CronRun, DeliveryConfig, JobType, Schedule, SessionTarget,
-    deserialize_maybe_stringified,
+    CatchUpPolicy, ConcurrencyPolicy, CronJob, CronJobPatch, CronRun, DeliveryConfig, JobType,
+    NewJobOptions, NotifyConfig, NotifyOn, Schedule, SessionTarget, deserialize_maybe_stringified,
 };
 
 /// Channel names exposed by the cron tool schemas. Actual runtime delivery is
@@ -109,6 +114,21 @@ pub fn validate_delivery_config(delivery: Option<&DeliveryConfig>) -> Result<()>
     Ok(())
 }
 
+pub fn validate_notify_config(notify: Option<&NotifyConfig>) -> Result<()> {
+    let Some(notify) = notify else {
+        return Ok(());
+    };
+
+    if notify.channel.trim().is_empty() {
+        bail!("notify.channel must not be empty");
+    }
+    if notify.recipient.trim().is_empty() {
+        bail!("notify.recipient must not be empty");
+    }
+
+    Ok(())
+}
+
 pub fn add_shell_job_with_approval(
     config: &Config,
     agent_alias: &str,
@@ -176,6 +196,20 @@ pub fn add_shell_job(
     add_shell_job_with_approval(config, agent_alias, name, schedule, command, None, false)
 }
 
+/// [`add_shell_job`] with notify, catch-up and concurrency settings written
+/// in the same insert as the job.
+pub fn add_shell_job_with_options(
+    config: &Config,
+    agent_alias: &str,
+    name: Option<String>,
+    schedule: Schedule,
+    command: &str,
+    options: NewJobOptions,
+) -> Result<CronJob> {
+    validate_shell_command(config, agent_alias, command, false)?;
+    store::add_shell_job_with_options(config, agent_alias, name, schedule, command, None, options)
+}
+
 pub fn add_job(
     config: &Config,
     agent_alias: &str,
@@ -0,0 +1,1035 @@
+//! Natural-language schedules for `zeroclaw cron add --natural` and the
+//! `schedule` tool's `when` argument.
+//!
+//! Recurring phrases become a 5-field cron expression (or a fixed interval
+//! when cron can't express the period); one-off phrases resolve to an
+//! absolute `at` time. Wall-clock times are read in the given IANA timezone,
+//! falling back to the runtime local timezone exactly like cron schedules
+//! without `--tz`. Phrases with more than one plausible reading are rejected
+//! with suggested rewrites instead of guessing.
+
+use crate::cron::Schedule;
+use anyhow::{Context, Result, bail};
+use chrono::{
+    DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
+};
+use std::str::FromStr;
+
+const EXAMPLES: &str = "'every weekday at 9am', 'every 2 hours', \
+     'first monday of the month at 10:00', 'tomorrow at 17:30', \
+     'on 2026-03-01 at 9am', 'in 45 minutes'";
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "sunday",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+];
+
+/// A parsed natural-language schedule plus the reading it was derived from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NaturalSchedule {
+    pub schedule: Schedule,
+    /// Plain-English reading of the input, echoed back for confirmation.
+    pub summary: String,
+}
+
+impl NaturalSchedule {
+    /// The derived spec as it will be stored, e.g. `cron '0 9 * * 1-5'
+    /// (America/New_York)` or `at 2026-03-05T14:00:00+00:00`.
+    pub fn spec(&self) -> String {
+        match &self.schedule {
+            Schedule::Cron { expr, tz } => {
+                format!("cron '{expr}' ({})", tz.as_deref().unwrap_or("local time"))
+            }
+            Schedule::At { at } => format!("at {}", at.to_rfc3339()),
+            Schedule::Every { every_ms } => format!("every {every_ms}ms"),
+        }
+    }
+}
+
+/// Parse `text` into a schedule. `tz` is the IANA timezone wall-clock times
+/// are read in (`None` = runtime local time); `now` anchors relative phrases
+/// such as "tomorrow" or "in 2 hours".
+pub fn parse_natural_schedule(
+    text: &str,
+    tz: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<NaturalSchedule> {
+    let zone = Zone::parse(tz)?;
+    let tokens = tokenize(text);
+    if tokens.is_empty() {
+        bail!("Schedule text is empty. Examples: {EXAMPLES}");
+    }
+    let tokens: Vec<&str> = tokens.iter().map(String::as_str).collect();
+    let (days, time) = split_time(&tokens)?;
+
+    let parser = Parser {
+        text: text.trim(),
+        tz,
+        zone,
+        now,
+        time,
+    };
+    parser.parse(days).inspect_err(|err| {
+        ::zeroclaw_log::record!(
+            DEBUG,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                .with_attrs(::serde_json::json!({
+                    "text": text,
+                    "error": format!("{err}"),
+                })),
+            "natural-language schedule rejected"
+        );
+    })
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .replace([',', ';'], " ")
+        .split_whitespace()
+        .map(|token| token.trim_end_matches(['.', '!', '?']).to_string())
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+/// Split off the time-of-day: everything after the last `at`, or a trailing
+/// token that can only be a time (`9am`, `17:30`, `noon`).
+fn split_time<'a>(tokens: &'a [&'a str]) -> Result<(&'a [&'a str], Option<NaiveTime>)> {
+    if let Some(pos) = tokens.iter().rposition(|token| *token == "at") {
+        let time = parse_time(&tokens[pos + 1..])?;
+        return Ok((&tokens[..pos], Some(time)));
+    }
+    let n = tokens.len();
+    if n >= 2 && matches!(tokens[n - 1], "am" | "pm" | "a.m" | "p.m") {
+        let time = parse_time(&tokens[n - 2..])?;
+        return Ok((&tokens[..n - 2], Some(time)));
+    }
+    if looks_like_time(tokens[n - 1]) {
+        let time = parse_time(&tokens[n - 1..])?;
+        return Ok((&tokens[..n - 1], Some(time)));
+    }
+    Ok((tokens, None))
+}
+
+fn looks_like_time(token: &str) -> bool {
+    let token = token.replace('.', "");
+    if matches!(token.as_str(), "noon" | "midday" | "midnight") {
+        return true;
+    }
+    let starts_with_digit = token.starts_with(|c: char| c.is_ascii_digit());
+    starts_with_digit && (token.ends_with("am") || token.ends_with("pm") || token.contains(':'))
+}
+
+fn parse_time(tokens: &[&str]) -> Result<NaiveTime> {
+    let raw = tokens.concat().replace('.', "");
+    if raw.is_empty() {
+        bail!("Missing time after 'at'. Write e.g. 'at 9am' or 'at 17:30'");
+    }
+    match raw.as_str() {
+        "noon" | "midday" => return Ok(hm(12, 0)),
+        "midnight" => return Ok(hm(0, 0)),
+        _ => {}
+    }
+    let unreadable = || {
+        anyhow::Error::msg(format!(
+            "Could not read time '{raw}'. Write e.g. '9am', '9:30pm' or '17:30'"
+        ))
+    };
+
+    let (clock, pm) = if let Some(clock) = raw.strip_suffix("am") {
+        (clock, Some(false))
+    } else if let Some(clock) = raw.strip_suffix("pm") {
+        (clock, Some(true))
+    } else {
+        (raw.as_str(), None)
+    };
+    let (hour, minute) = match clock.split_once(':') {
+        Some((h, m)) if m.len() == 2 => (
+            h.parse::<u32>().map_err(|_| unreadable())?,
+            Some(m.parse::<u32>().map_err(|_| unreadable())?),
+        ),
+        Some(_) => return Err(unreadable()),
+        None => (clock.parse::<u32>().map_err(|_| unreadable())?, None),
+    };
+    let minute_value = minute.unwrap_or(0);
+    if minute_value > 59 {
+        return Err(unreadable());
+    }
+
+    match pm {
+        Some(pm) => {
+            if !(1..=12).contains(&hour) {
+                return Err(unreadable());
+            }
+            let hour = hour % 12 + if pm { 12 } else { 0 };
+            Ok(hm(hour, minute_value))
+        }
+        None if hour > 23 => Err(unreadable()),
+        // A bare "7" could be morning or evening; "07:00" or "19:00" is not.
+        None if minute.is_none() && (1..=12).contains(&hour) => bail!(
+            "Ambiguous time '{raw}': write '{hour}am', '{hour}pm' or '{:02}:00'",
+            hour % 12 + 12
+        ),
+        None => Ok(hm(hour, minute_value)),
+    }
+}
+
+fn hm(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).expect("hour and minute are range-checked")
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Zone {
+    Named(chrono_tz::Tz),
+    Local,
+}
+
+impl Zone {
+    fn parse(tz: Option<&str>) -> Result<Self> {
+        match tz {
+            Some(name) => chrono_tz::Tz::from_str(name)
+                .map(Self::Named)
+                .with_context(|| format!("Invalid IANA timezone: {name}")),
+            None => Ok(Self::Local),
+        }
+    }
+
+    fn today(self, now: DateTime<Utc>) -> NaiveDate {
+        match self {
+            Self::Named(tz) => now.with_timezone(&tz).date_naive(),
+            Self::Local => now.with_timezone(&chrono::Local).date_naive(),
+        }
+    }
+
+    /// Resolve a wall-clock time; in a DST gap there is no such instant.
+    fn to_utc(self, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Named(tz) => tz
+                .from_local_datetime(&local)
+                .earliest()
+                .map(|at| at.with_timezone(&Utc)),
+            Self::Local => chrono::Local
+                .from_local_datetime(&local)
+                .earliest()
+                .map(|at| at.with_timezone(&Utc)),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Named(tz) => tz.name(),
+            Self::Local => "local time",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Minute,
+    Hour,
+    Day,
+    Week,
+}
+
+impl Unit {
+    fn parse(token: &str) -> Option<Self> {
+        match token {
+            "m" | "min" | "mins" | "minute" | "minutes" => Some(Self::Minute),
+            "h" | "hr" | "hrs" | "hour" | "hours" => Some(Self::Hour),
+            "d" | "day" | "days" => Some(Self::Day),
+            "w" | "week" | "weeks" => Some(Self::Week),
+            _ => None,
+        }
+    }
+
+    fn millis(self) -> u64 {
+        match self {
+            Self::Minute => 60_000,
+            Self::Hour => 3_600_000,
+            Self::Day => 86_400_000,
+            Self::Week => 604_800_000,
+        }
+    }
+
+    fn label(self, amount: u64) -> String {
+        let name = match self {
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+        };
+        if amount == 1 {
+            name.to_string()
+        } else {
+            format!("{amount} {name}s")
+        }
+    }
+}
+
+/// `2 hours`, `an hour`, `other day`, `30m` → amount and unit.
+fn parse_amount_unit(tokens: &[&str]) -> Option<(u64, Unit)> {
+    match tokens {
+        [unit] => {
+            if let Some(unit) = Unit::parse(unit) {
+                return Some((1, unit));
+            }
+            let split = unit.find(|c: char| !c.is_ascii_digit())?;
+            let (amount, unit) = unit.split_at(split);
+            Some((amount.parse().ok()?, Unit::parse(unit)?))
+        }
+        [amount, unit] => Some((parse_amount(amount)?, Unit::parse(unit)?)),
+        _ => None,
+    }
+}
+
+fn parse_amount(token: &str) -> Option<u64> {
+    const WORDS: [&str; 12] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+        "twelve",
+    ];
+    match token {
+        "a" | "an" => Some(1),
+        "other" => Some(2),
+        _ => token.parse().ok().or_else(|| {
+            WORDS
+                .iter()
+                .position(|word| *word == token)
+                .map(|index| index as u64 + 1)
+        }),
+    }
+}
+
+/// Crontab weekday number (0 = Sunday) for a day name, singular or plural.
+fn parse_weekday(token: &str) -> Option<u32> {
+    let lookup = |word: &str| -> Option<u32> {
+        let index = match word {
+            "sun" => 0,
+            "mon" => 1,
+            "tue" | "tues" => 2,
+            "wed" => 3,
+            "thu" | "thur" | "thurs" => 4,
+            "fri" => 5,
+            "sat" => 6,
+            _ => WEEKDAY_NAMES.iter().position(|name| *name == word)?,
+        };
+        u32::try_from(index).ok()
+    };
+    lookup(token).or_else(|| lookup(token.strip_suffix('s')?))
+}
+
+fn parse_month(token: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    let index = MONTHS
+        .iter()
+        .position(|name| *name == token || (token.len() >= 3 && name.starts_with(token)))?;
+    u32::try_from(index).ok().map(|index| index + 1)
+}
+
+/// `first`/`1st` … `fourth`/`4th` → week-of-month.
+fn parse_week_ordinal(token: &str) -> Option<u32> {
+    match token {
+        "first" | "1st" => Some(1),
+        "second" | "2nd" => Some(2),
+        "third" | "3rd" => Some(3),
+        "fourth" | "4th" => Some(4),
+        _ => None,
+    }
+}
+
+/// `15`, `15th`, `1st`, `22nd` → day of month.
+fn parse_day_of_month(token: &str) -> Option<u32> {
+    let digits = token
+        .strip_suffix("st")
+        .or_else(|| token.strip_suffix("nd"))
+        .or_else(|| token.strip_suffix("rd"))
+        .or_else(|| token.strip_suffix("th"))
+        .unwrap_or(token);
+    digits.parse().ok().filter(|day| (1..=31).contains(day))
+}
+
+/// A set of weekdays: `weekday(s)`, `weekend(s)`, or a list such as
+/// `mon wed fri`, `monday and thursday`, `monday through friday`, `mon-fri`.
+/// Returns the sorted crontab numbers and whether the phrase was plural.
+fn parse_weekday_set(tokens: &[&str]) -> Option<(Vec<u32>, bool)> {
+    match tokens {
+        ["weekday"] => return Some((vec![1, 2, 3, 4, 5], false)),
+        ["weekdays"] => return Some((vec![1, 2, 3, 4, 5], true)),
+        ["weekend"] => return Some((vec![0, 6], false)),
+        ["weekends"] => return Some((vec![0, 6], true)),
+        [] => return None,
+        _ => {}
+    }
+
+    let mut days = Vec::new();
+    let mut plural = false;
+    let mut range_from: Option<u32> = None;
+    for token in tokens {
+        match *token {
+            "and" | "&" => continue,
+            "through" | "thru" | "to" | "-" => {
+                range_from = Some(*days.last()?);
+                continue;
+            }
+            _ => {}
+        }
+        let (start, end) = match token.split_once('-') {
+            Some((start, end)) => (parse_weekday(start)?, Some(parse_weekday(end)?)),
+            None => (parse_weekday(token)?, None),
+        };
+        plural |= token.ends_with('s') && !matches!(*token, "tues" | "thurs");
+        let (from, to) = match (range_from.take(), end) {
+            (Some(from), None) => (from, start),
+            (None, Some(end)) => (start, end),
+            (None, None) => (start, start),
+            (Some(_), Some(_)) => return None,
+        };
+        if from > to {
+            return None;
+        }
+        days.extend(from..=to);
+    }
+    if range_from.is_some() {
+        return None;
+    }
+    days.sort_unstable();
+    days.dedup();
+    Some((days, plural))
+}
+
+fn weekday_field(days: &[u32]) -> String {
+    if days == [1, 2, 3, 4, 5] {
+        return "1-5".into();
+    }
+    days.iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn weekday_label(days: &[u32]) -> String {
+    match days {
+        [1, 2, 3, 4, 5] => "weekday".into(),
+        [0, 6] => "saturday and sunday".into(),
+        _ => days
+            .iter()
+            .map(|day| WEEKDAY_NAMES[*day as usize])
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+fn ordinal_suffix(n: u32) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Strip a month qualifier (`of the month`, `of every month`, `each month`,
+/// `monthly`, a leading `month`) and report whether one was present.
+fn strip_monthly<'a>(tokens: &'a [&'a str]) -> (&'a [&'a str], bool) {
+    match tokens {
+        [rest @ .., "of", "the" | "every" | "each", "month"] => (rest, true),
+        [rest @ .., "every" | "each" | "a", "month"] => (rest, true),
+        ["month", rest @ ..] => (rest, true),
+        _ => (tokens, false),
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    tz: Option<&'a str>,
+    zone: Zone,
+    now: DateTime<Utc>,
+    time: Option<NaiveTime>,
+}
+
+impl Parser<'_> {
+    fn parse(&self, tokens: &[&str]) -> Result<NaturalSchedule> {
+        let tokens = match tokens {
+            ["on", rest @ ..] => rest,
+            _ => tokens,
+        };
+        match tokens {
+            [] => self.time_without_day(),
+            ["in", rest @ ..] => self.relative(rest),
+            ["hourly"] => self.interval(1, Unit::Hour),
+            ["daily"] => self.weekly(&[], "day"),
+            ["every" | "each", rest @ ..] => self.recurring(rest),
+            ["monthly", rest @ ..] => self.monthly(rest),
+            _ => {
+                let (rest, monthly) = strip_monthly(tokens);
+                if monthly {
+                    return self.monthly(rest);
+                }
+                if let Some((days, plural)) = parse_weekday_set(tokens)
+                    && (plural || matches!(tokens, ["weekday" | "weekend"]))
+                {
+                    return self.weekly(&days, &weekday_label(&days));
+                }
+                self.once(tokens)
+            }
+        }
+    }
+
+    fn time_label(&self) -> String {
+        self.time
+            .map(|time| time.format("%H:%M").to_string())
+            .unwrap_or_default()
+    }
+
+    fn require_time(&self, phrase: &str) -> Result<NaiveTime> {
+        self.time.ok_or_else(|| {
+            anyhow::Error::msg(format!(
+                "'{phrase}' needs a time of day, e.g. '{phrase} at 9am' or '{phrase} at 17:30'"
+            ))
+        })
+    }
+
+    fn cron(
+        &self,
+        minute: &str,
+        hour: &str,
+        dom: &str,
+        dow: &str,
+        summary: String,
+    ) -> NaturalSchedule {
+        NaturalSchedule {
+            schedule: Schedule::Cron {
+                expr: format!("{minute} {hour} {dom} * {dow}"),
+                tz: self.tz.map(str::to_string),
+            },
+            summary,
+        }
+    }
+
+    fn time_without_day(&self) -> Result<NaturalSchedule> {
+        if self.time.is_none() {
+            bail!(
+                "Could not understand schedule '{}'. Examples: {EXAMPLES}",
+                self.text
+            );
+        }
+        let at = self.time_label();
+        bail!(
+            "'{}' doesn't say which day. Try 'every day at {at}', 'today at {at}' or 'tomorrow at {at}'",
+            self.text
+        )
+    }
+
+    fn recurring(&self, tokens: &[&str]) -> Result<NaturalSchedule> {
+        match tokens {
+            [] => bail!("'every' needs a period. Examples: {EXAMPLES}"),
+            ["day" | "night"] => return self.weekly(&[], "day"),
+            ["week"] => bail!("'every week' doesn't say which day. Try e.g. 'every monday at 9am'"),
+            ["month"] => bail!(
+                "'every month' doesn't say which day. Try e.g. 'on the 1st of every month at 9am' \
+                 or 'first monday of the month at 9am'"
+            ),
+            _ => {}
+        }
+        if let Some((amount, unit)) = parse_amount_unit(tokens) {
+            return self.interval(amount, unit);
+        }
+        if let Some((days, _)) = parse_weekday_set(tokens) {
+            return self.weekly(&days, &weekday_label(&days));
+        }
+        let (rest, monthly) = strip_monthly(tokens);
+        if monthly {
+            return self.monthly(rest);
+        }
+        bail!(
+            "Could not understand schedule '{}'. Examples: {EXAMPLES}",
+            self.text
+        )
+    }
+
+    fn interval(&self, amount: u64, unit: Unit) -> Result<NaturalSchedule> {
+        if amount == 0 {
+            bail!("Interval must be greater than zero");
+        }
+        if unit == Unit::Day && amount == 1 {
+            return self.weekly(&[], "day");
+        }
+        if unit == Unit::Week && amount == 1 {
+            bail!("'every week' doesn't say which day. Try e.g. 'every monday at 9am'");
+        }
+        let period = unit.label(amount);
+        if let Some(time) = self.time {
+            let at = time.format("%H:%M");
+            bail!(
+                "'every {period}' can't be pinned to {at}. Try 'every day at {at}', a list of days \
+                 such as 'every monday and thursday at {at}', or 'every {period}' on its own"
+            );
+        }
+        let summary = format!("every {period}");
+        // Periods that divide the hour/day evenly stay aligned to the clock
+        // as cron; anything else runs on a fixed interval from now.
+        let cron = match unit {
+            Unit::Minute if amount == 1 => Some(("*".to_string(), "*".to_string())),
+            Unit::Minute if amount < 60 && 60 % amount == 0 => {
+                Some((format!("*/{amount}"), "*".to_string()))
+            }
+            Unit::Hour if amount == 1 => Some(("0".to_string(), "*".to_string())),
+            Unit::Hour if amount < 24 && 24 % amount == 0 => {
+                Some(("0".to_string(), format!("*/{amount}")))
+            }
+            _ => None,
+        };
+        if let Some((minute, hour)) = cron {
+            return Ok(self.cron(&minute, &hour, "*", "*", summary));
+        }
+        let every_ms = amount
+            .checked_mul(unit.millis())
+            .context("Interval is too large")?;
+        Ok(NaturalSchedule {
+            schedule: Schedule::Every { every_ms },
+            summary,
+        })
+    }
+
+    /// Fixed time on some weekdays; an empty `days` means every day.
+    fn weekly(&self, days: &[u32], label: &str) -> Result<NaturalSchedule> {
+        let time = self.require_time(&format!("every {label}"))?;
+        let dow = if days.is_empty() {
+            "*".to_string()
+        } else {
+            weekday_field(days)
+        };
+        Ok(self.cron(
+            &time.minute().to_string(),
+            &time.hour().to_string(),
+            "*",
+            &dow,
+            format!("every {label} at {}", self.time_label()),
+        ))
+    }
+
+    fn monthly(&self, tokens: &[&str]) -> Result<NaturalSchedule> {
+        let tokens = match tokens {
+            ["on", "the", rest @ ..] | ["on" | "the", rest @ ..] => rest,
+            _ => tokens,
+        };
+        match tokens {
+            ["last", day] if parse_weekday(day).is_some() => bail!(
+                "'last {day} of the month' can't be expressed as a cron schedule. \
+                 Try 'fourth {day} of the month' or a cron expression"
+            ),
+            ["last"] | ["last", "day"] => bail!(
+                "'the last day of the month' can't be expressed as a cron schedule. \
+                 Try 'on the 28th of every month' or a cron expression"
+            ),
+            [ordinal, day] => {
+                let (Some(week), Some(dow)) = (parse_week_ordinal(ordinal), parse_weekday(day))
+                else {
+                    bail!(
+                        "Could not understand schedule '{}'. Examples: {EXAMPLES}",
+                        self.text
+                    );
+                };
+                let name = WEEKDAY_NAMES[dow as usize];
+                let time = self.require_time(&format!("{ordinal} {name} of the month"))?;
+                // The cron crate matches day-of-month AND day-of-week, so
+                // days 1-7 on a Monday is exactly the first Monday.
+                let first = (week - 1) * 7 + 1;
+                Ok(self.cron(
+                    &time.minute().to_string(),
+                    &time.hour().to_string(),
+                    &format!("{first}-{}", first + 6),
+                    &dow.to_string(),
+                    format!("{ordinal} {name} of every month at {}", self.time_label()),
+                ))
+            }
+            [day] => {
+                let Some(dom) = parse_day_of_month(day) else {
+                    bail!(
+                        "Could not understand schedule '{}'. Examples: {EXAMPLES}",
+                        self.text
+                    );
+                };
+                let label = format!("the {dom}{} of every month", ordinal_suffix(dom));
+                let time = self.require_time(&label)?;
+                Ok(self.cron(
+                    &time.minute().to_string(),
+                    &time.hour().to_string(),
+                    &dom.to_string(),
+                    "*",
+                    format!("{label} at {}", self.time_label()),
+                ))
+            }
+            _ => bail!(
+                "Could not understand schedule '{}'. Examples: {EXAMPLES}",
+                self.text
+            ),
+        }
+    }
+
+    fn relative(&self, tokens: &[&str]) -> Result<NaturalSchedule> {
+        let Some((amount, unit)) = parse_amount_unit(tokens) else {
+            bail!(
+                "Could not read delay in '{}'. Write e.g. 'in 45 minutes' or 'in 2 hours'",
+                self.text
+            );
+        };
+        if self.time.is_some() {
+            bail!(
+                "'{}' gives both a delay and a time. Use one, e.g. 'in 2 hours' or 'today at {}'",
+                self.text,
+                self.time_label()
+            );
+        }
+        if amount == 0 {
+            bail!("Delay must be greater than zero");
+        }
+        let millis = amount
+            .checked_mul(unit.millis())
+            .and_then(|ms| i64::try_from(ms).ok())
+            .context("Delay is too large")?;
+        let at = self
+            .now
+            .checked_add_signed(Duration::milliseconds(millis))
+            .context("Delay is too large")?;
+        Ok(NaturalSchedule {
+            schedule: Schedule::At { at },
+            summary: format!("once, in {}", unit.label(amount)),
+        })
+    }
+
+    fn once(&self, tokens: &[&str]) -> Result<NaturalSchedule> {
+        let today = self.zone.today(self.now);
+        let (date, phrase) = match tokens {
+            ["today" | "tonight"] => (today, tokens[0].to_string()),
+            ["tomorrow"] => (today + Duration::days(1), "tomorrow".to_string()),
+            [which @ ("next" | "this"), day] if parse_weekday(day).is_some() => {
+                let target = parse_weekday(day).unwrap_or_default();
+                let current = today.weekday().num_days_from_sunday();
+                // "this friday" may be today; "next friday" is always ahead.
+                let ahead = match (target + 7 - current) % 7 {
+                    0 if *which == "next" => 7,
+                    ahead => ahead,
+                };
+                let name = WEEKDAY_NAMES[target as usize];
+                (
+                    today + Duration::days(i64::from(ahead)),
+                    format!("{which} {name}"),
+                )
+            }
+            [day] if parse_weekday(day).is_some() => {
+                let at = self
+                    .time
+                    .map(|_| format!(" at {}", self.time_label()))
+                    .unwrap_or_else(|| " at 9am".into());
+                bail!(
+                    "'{}' could mean once or every week. Try 'every {day}{at}' or 'next {day}{at}'",
+                    self.text
+                )
+            }
+            _ => self.calendar_date(tokens, today)?,
+        };
+        let time = self.require_time(&phrase)?;
+        let local = date.and_time(time);
+        let at = self.zone.to_utc(local).ok_or_else(|| {
+            anyhow::Error::msg(format!(
+                "{local} does not exist in {} (daylight-saving gap); pick another time",
+                self.zone.name()
+            ))
+        })?;
+        if at <= self.now {
+            bail!(
+                "'{}' is in the past ({} {}). Try 'tomorrow at {}' or a later date",
+                self.text,
+                local.format("%Y-%m-%d %H:%M"),
+                self.zone.name(),
+                self.time_label()
+            );
+        }
+        Ok(NaturalSchedule {
+            schedule: Schedule::At { at },
+            summary: format!(
+                "once on {} at {} ({})",
+                date.format("%Y-%m-%d"),
+                self.time_label(),
+                self.zone.name()
+            ),
+        })
+    }
+
+    /// `2026-03-10`, `march 20`, `the 5th of april`, `dec 25 2026`.
+    fn calendar_date(&self, tokens: &[&str], today: NaiveDate) -> Result<(NaiveDate, String)> {
+        let unknown = || {
+            anyhow::Error::msg(format!(
+                "Could not understand schedule '{}'. Examples: {EXAMPLES}",
+                self.text
+            ))
+        };
+        let words: Vec<&str> = tokens
+            .iter()
+            .copied()
+            .filter(|token| !matches!(*token, "the" | "of"))
+            .collect();
+
+        if let [single] = words.as_slice() {
+            if let Ok(date) = NaiveDate::parse_from_str(single, "%Y-%m-%d") {
+                return Ok((date, date.format("%Y-%m-%d").to_string()));
+            }
+            if let Some((a, b)) = single.split_once('/') {
+                let b = b.split('/').next().unwrap_or(b);
+                let year = today.year();
+                bail!(
+                    "Ambiguous date '{single}' (month/day or day/month?). Write it as \
+                     {year}-{a:0>2}-{b:0>2} or {year}-{b:0>2}-{a:0>2}"
+                );
+            }
+        }
+
+        let (month, day, year) = match words.as_slice() {
+            [m, d] if parse_month(m).is_some() => (parse_month(m), parse_day_of_month(d), None),
+            [d, m] if parse_month(m).is_some() => (parse_month(m), parse_day_of_month(d), None),
+            [m, d, y] if parse_month(m).is_some() => {
+                (parse_month(m), parse_day_of_month(d), y.parse::<i32>().ok())
+            }
+            [d, m, y] if parse_month(m).is_some() => {
+                (parse_month(m), parse_day_of_month(d), y.parse::<i32>().ok())
+            }
+            _ => return Err(unknown()),
+        };
+        let (Some(month), Some(day)) = (month, day) else {
+            return Err(unknown());
+        };
+
+        let date = match year {
+            Some(year) => NaiveDate::from_ymd_opt(year, month, day),
+            None => {
+                // No year: the next time this date comes round.
+                let this_year = NaiveDate::from_ymd_opt(today.year(), month, day);
+                match (this_year, self.time) {
+                    (Some(date), Some(time))
+                        if self
+                            .zone
+                            .to_utc(date.and_time(time))
+                            .is_some_and(|at| at > self.now) =>
+                    {
+                        Some(date)
+                    }
+                    (Some(date), None) if date >= today => Some(date),
+                    _ => NaiveDate::from_ymd_opt(today.year() + 1, month, day),
+                }
+            }
+        };
+        let date = date.ok_or_else(|| {
+            anyhow::Error::msg(format!("'{}' is not a valid calendar date", self.text))
+        })?;
+        Ok((date, date.format("%Y-%m-%d").to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cron::next_run_for_schedule;
+
+    /// Wednesday afternoon, a few days before US daylight saving starts.
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-03-04T15:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn utc(raw: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(raw)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn recurring_phrases_map_to_cron() {
+        let cases = [
+            ("every weekday at 9am", "0 9 * * 1-5"),
+            ("Every Weekday At 9AM.", "0 9 * * 1-5"),
+            ("every weekday at 9:30am", "30 9 * * 1-5"),
+            ("weekdays at 17:45", "45 17 * * 1-5"),
+            ("on weekdays at 8 am", "0 8 * * 1-5"),
+            ("every weekend at 10am", "0 10 * * 0,6"),
+            ("every day at noon", "0 12 * * *"),
+            ("daily at midnight", "0 0 * * *"),
+            ("every night at 11pm", "0 23 * * *"),
+            ("every day 07:15", "15 7 * * *"),
+            ("every monday at 9am", "0 9 * * 1"),
+            ("every sunday at 23:00", "0 23 * * 0"),
+            ("every monday and thursday at 18:00", "0 18 * * 1,4"),
+            ("every mon, wed, fri at 7pm", "0 19 * * 1,3,5"),
+            ("mondays at 8 a.m.", "0 8 * * 1"),
+            ("tuesdays and thursdays at 12:30pm", "30 12 * * 2,4"),
+            ("every monday through friday at 6:15", "15 6 * * 1-5"),
+            ("every mon-wed at 14:00", "0 14 * * 1,2,3"),
+            ("every minute", "* * * * *"),
+            ("every 15 minutes", "*/15 * * * *"),
+            ("every 30m", "*/30 * * * *"),
+            ("every hour", "0 * * * *"),
+            ("hourly", "0 * * * *"),
+            ("every 2 hours", "0 */2 * * *"),
+            ("every other hour", "0 */2 * * *"),
+            ("every six hours", "0 */6 * * *"),
+            ("first monday of the month at 10:00", "0 10 1-7 * 1"),
+            ("every second tuesday of the month at 9am", "0 9 8-14 * 2"),
+            ("third friday of every month at 5pm", "0 17 15-21 * 5"),
+            ("4th sunday of each month at 8pm", "0 20 22-28 * 0"),
+            ("on the 15th of every month at 9am", "0 9 15 * *"),
+            ("monthly on the 1st at 08:00", "0 8 1 * *"),
+            ("every month on the 28th at 23:30", "30 23 28 * *"),
+        ];
+        for (input, expected) in cases {
+            let parsed = parse_natural_schedule(input, Some("UTC"), now())
+                .unwrap_or_else(|err| panic!("{input}: {err}"));
+            assert_eq!(
+                parsed.schedule,
+                Schedule::Cron {
+                    expr: expected.into(),
+                    tz: Some("UTC".into()),
+                },
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn uneven_periods_map_to_intervals() {
+        let cases = [
+            ("every 90 minutes", 5_400_000),
+            ("every 5 hours", 18_000_000),
+            ("every 3 days", 259_200_000),
+            ("every other day", 172_800_000),
+            ("every 2 weeks", 1_209_600_000),
+        ];
+        for (input, every_ms) in cases {
+            let parsed = parse_natural_schedule(input, None, now())
+                .unwrap_or_else(|err| panic!("{input}: {err}"));
+            assert_eq!(parsed.schedule, Schedule::Every { every_ms }, "{input}");
+        }
+    }
+
+    #[test]
+    fn one_off_phrases_resolve_in_timezone() {
+        let cases = [
+            ("tomorrow at 9am", "UTC", "2026-03-05T09:00:00Z"),
+            (
+                "tomorrow at 9am",
+                "America/New_York",
+                "2026-03-05T14:00:00Z",
+            ),
+            ("today at 17:30", "UTC", "2026-03-04T17:30:00Z"),
+            ("tonight at 11pm", "UTC", "2026-03-04T23:00:00Z"),
+            ("tomorrow 6pm", "UTC", "2026-03-05T18:00:00Z"),
+            ("next friday at 9am", "UTC", "2026-03-06T09:00:00Z"),
+            ("next wednesday at 9am", "UTC", "2026-03-11T09:00:00Z"),
+            // Daylight saving starts 2026-03-08 in New York.
+            (
+                "next monday at 9am",
+                "America/New_York",
+                "2026-03-09T13:00:00Z",
+            ),
+            (
+                "on 2026-03-10 at 14:00",
+                "Europe/Berlin",
+                "2026-03-10T13:00:00Z",
+            ),
+            ("march 20 at 8am", "UTC", "2026-03-20T08:00:00Z"),
+            ("the 5th of april at 10:30pm", "UTC", "2026-04-05T22:30:00Z"),
+            ("jan 2 at 9am", "UTC", "2027-01-02T09:00:00Z"),
+            ("dec 25 2026 at noon", "UTC", "2026-12-25T12:00:00Z"),
+            ("in 45 minutes", "UTC", "2026-03-04T15:45:00Z"),
+            ("in 2 hours", "UTC", "2026-03-04T17:00:00Z"),
+            ("in an hour", "UTC", "2026-03-04T16:00:00Z"),
+            ("in 3 days", "UTC", "2026-03-07T15:00:00Z"),
+        ];
+        for (input, tz, expected) in cases {
+            let parsed = parse_natural_schedule(input, Some(tz), now())
+                .unwrap_or_else(|err| panic!("{input}: {err}"));
+            assert_eq!(
+                parsed.schedule,
+                Schedule::At { at: utc(expected) },
+                "{input} ({tz})"
+            );
+        }
+    }
+
+    #[test]
+    fn ambiguous_or_unsupported_inputs_are_rejected_with_suggestions() {
+        let cases = [
+            ("monday at 9am", "every monday at 09:00"),
+            ("at 5pm", "tomorrow at 17:00"),
+            ("every weekday at 9", "'9am', '9pm' or '21:00'"),
+            ("every weekday", "every weekday at 9am"),
+            ("tomorrow", "tomorrow at 9am"),
+            ("3/4 at 9am", "2026-03-04 or 2026-04-03"),
+            ("last friday of the month at 9am", "fourth friday"),
+            ("every week", "every monday at 9am"),
+            ("every month", "1st of every month"),
+            ("every 2 hours at 9am", "every day at 09:00"),
+            ("every 3 days at 9am", "every day at 09:00"),
+            ("in 2 hours at 5pm", "in 2 hours"),
+            ("today at 9am", "in the past"),
+            ("every 0 minutes", "greater than zero"),
+            ("every day at 25:00", "Could not read time"),
+            ("sometime soon", "Examples"),
+            ("", "empty"),
+            ("february 30 at 9am", "not a valid calendar date"),
+        ];
+        for (input, expected) in cases {
+            let err = parse_natural_schedule(input, Some("UTC"), now())
+                .expect_err(input)
+                .to_string();
+            assert!(err.contains(expected), "{input}: {err}");
+        }
+    }
+
+    #[test]
+    fn invalid_timezone_is_rejected() {
+        let err = parse_natural_schedule("every day at 9am", Some("Mars/Base"), now())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Invalid IANA timezone"), "{err}");
+    }
+
+    #[test]
+    fn cron_schedules_carry_timezone_and_spec() {
+        let parsed =
+            parse_natural_schedule("every weekday at 9am", Some("America/New_York"), now())
+                .unwrap();
+        assert_eq!(parsed.spec(), "cron '0 9 * * 1-5' (America/New_York)");
+        assert_eq!(parsed.summary, "every weekday at 09:00");
+
+        let local = parse_natural_schedule("every weekday at 9am", None, now()).unwrap();
+        assert_eq!(local.spec(), "cron '0 9 * * 1-5' (local time)");
+    }
+
+    #[test]
+    fn nth_weekday_of_month_fires_on_that_weekday_only() {
+        let parsed =
+            parse_natural_schedule("first monday of the month at 10:00", Some("UTC"), now())
+                .unwrap();
+        let next = next_run_for_schedule(&parsed.schedule, now()).unwrap();
+        assert_eq!(next, utc("2026-04-06T10:00:00Z"));
+    }
+}
@@ -0,0 +1,403 @@
+//! Quiet hours (`[quiet_hours]`).
+//!
+//! While the configured window is open, announcements produced by scheduled
+//! cron runs and heartbeat ticks are appended to a queue under
+//! `<data_dir>/cron/` instead of being sent, and the daemon's `quiet_hours`
+//! component delivers them once the window closes. Direct replies to a user
+//! message never pass through here. The window is judged on the wall clock
+//! of the configured timezone, so it follows DST shifts.
+
+use super::scheduler::deliver_announcement;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+use tokio_util::sync::CancellationToken;
+use zeroclaw_config::schema::{Config, QuietHoursConfig};
+
+/// Daemon component name for the queue flusher.
+pub const QUIET_HOURS_COMPONENT: &str = "quiet_hours";
+
+/// How often the flusher checks whether the window has closed.
+const FLUSH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Serializes queue file access between the scheduler, the heartbeat, and
+/// the flusher.
+static QUEUE_LOCK: parking_lot::Mutex<()> = parking_lot::Mutex::new(());
+
+/// A delivery held back until quiet hours end.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueuedDelivery {
+    pub channel: String,
+    pub target: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<String>,
+    pub output: String,
+    pub queued_at: DateTime<Utc>,
+}
+
+fn parse_hhmm(field: &str, raw: &str) -> Option<NaiveTime> {
+    let parsed = NaiveTime::parse_from_str(raw.trim(), "%H:%M").ok();
+    if parsed.is_none() {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                .with_attrs(::serde_json::json!({"field": field, "value": raw})),
+            "quiet_hours time is not HH:MM; quiet hours ignored"
+        );
+    }
+    parsed
+}
+
+/// End of the quiet window containing `now`, or `None` when `now` falls
+/// outside it. Windows whose end is earlier than their start cross midnight.
+fn window_end_in<Z: TimeZone>(
+    tz: &Z,
+    start: NaiveTime,
+    end: NaiveTime,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let local = now.with_timezone(tz).naive_local();
+    let (date, time) = (local.date(), local.time());
+    let end_date = if start < end {
+        if time < start || time >= end {
+            return None;
+        }
+        date
+    } else if time >= start {
+        date.succ_opt()?
+    } else if time < end {
+        date
+    } else {
+        return None;
+    };
+    let end_local = end_date.and_time(end);
+    // An end time inside a spring-forward gap does not exist on that day;
+    // the window then closes at the first wall-clock time after the gap.
+    tz.from_local_datetime(&end_local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(end_local + Duration::hours(1)))
+                .earliest()
+        })
+        .map(|at| at.with_timezone(&Utc))
+}
+
+/// When the quiet window containing `now` ends, or `None` when quiet hours
+/// are off, misconfigured, or not in effect at `now`.
+#[must_use]
+pub fn window_end(cfg: &QuietHoursConfig, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if !cfg.is_enabled() {
+        return None;
+    }
+    let start = parse_hhmm("quiet_hours.start", &cfg.start)?;
+    let end = parse_hhmm("quiet_hours.end", &cfg.end)?;
+    match cfg
+        .timezone
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+    {
+        Some(name) => match chrono_tz::Tz::from_str(name) {
+            Ok(tz) => window_end_in(&tz, start, end, now),
+            Err(_) => {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"timezone": name})),
+                    "quiet_hours.timezone is not a valid IANA timezone; quiet hours ignored"
+                );
+                None
+            }
+        },
+        None => window_end_in(&chrono::Local, start, end, now),
+    }
+}
+
+/// Whether deliveries to `channel` are held during quiet hours. Entries name
+/// a channel type (`telegram`) or a single alias (`telegram.ops`).
+#[must_use]
+pub fn covers_channel(cfg: &QuietHoursConfig, channel: &str) -> bool {
+    if cfg.channels.is_empty() {
+        return true;
+    }
+    let channel_type = channel.split('.').next().unwrap_or(channel);
+    cfg.channels.iter().map(|entry| entry.trim()).any(|entry| {
+        entry.eq_ignore_ascii_case(channel) || entry.eq_ignore_ascii_case(channel_type)
+    })
+}
+
+fn queue_path(config: &Config) -> PathBuf {
+    config.data_dir.join("cron").join("quiet_hours_queue.jsonl")
+}
+
+fn enqueue(config: &Config, delivery: &QueuedDelivery) -> Result<()> {
+    let path = queue_path(config);
+    let line = serde_json::to_string(delivery)?;
+    let _guard = QUEUE_LOCK.lock();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    writeln!(file, "{line}").with_context(|| format!("failed to append to {}", path.display()))
+}
+
+/// Remove and return every queued delivery. Unparseable lines are dropped
+/// with a warning rather than wedging the queue.
+fn drain_queue(config: &Config) -> Result<Vec<QueuedDelivery>> {
+    let path = queue_path(config);
+    let _guard = QUEUE_LOCK.lock();
+    let raw = match std::fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+    };
+    std::fs::remove_file(&path).with_context(|| format!("failed to remove {}", path.display()))?;
+    let mut queued = Vec::new();
+    for line in raw.lines().filter(|line| !line.trim().is_empty()) {
+        match serde_json::from_str(line) {
+            Ok(delivery) => queued.push(delivery),
+            Err(e) => ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                    .with_attrs(::serde_json::json!({"error": format!("{e}")})),
+                "Dropping malformed quiet-hours queue entry"
+            ),
+        }
+    }
+    Ok(queued)
+}
+
+/// Deliver output from scheduled or heartbeat work, queueing it instead when
+/// quiet hours cover `channel`. `bypass` sends immediately regardless
+/// (critical heartbeat tasks, `cron_run` with `now`).
+pub async fn deliver_scheduled(
+    config: &Config,
+    channel: &str,
+    target: &str,
+    thread_id: Option<&str>,
+    output: &str,
+    bypass: bool,
+) -> Result<()> {
+    if !bypass
+        && covers_channel(&config.quiet_hours, channel)
+        && let Some(until) = window_end(&config.quiet_hours, Utc::now())
+    {
+        enqueue(
+            config,
+            &QueuedDelivery {
+                channel: channel.to_string(),
+                target: target.to_string(),
+                thread_id: thread_id.map(str::to_string),
+                output: output.to_string(),
+                queued_at: Utc::now(),
+            },
+        )?;
+        ::zeroclaw_log::record!(
+            INFO,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note).with_attrs(
+                ::serde_json::json!({
+                    "channel": channel,
+                    "target": target,
+                    "until": until.to_rfc3339(),
+                })
+            ),
+            "Quiet hours: delivery queued until the window ends"
+        );
+        return Ok(());
+    }
+    deliver_announcement(config, channel, target, thread_id, output).await
+}
+
+/// Deliver everything queued once quiet hours are over. Returns how many
+/// deliveries were attempted; failures are logged and dropped, like a failed
+/// scheduled announcement.
+pub async fn flush_due(config: &Config) -> Result<usize> {
+    if window_end(&config.quiet_hours, Utc::now()).is_some() {
+        return Ok(0);
+    }
+    let queued = drain_queue(config)?;
+    for delivery in &queued {
+        if let Err(e) = deliver_announcement(
+            config,
+            &delivery.channel,
+            &delivery.target,
+            delivery.thread_id.as_deref(),
+            &delivery.output,
+        )
+        .await
+        {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Send)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({
+                        "channel": delivery.channel,
+                        "target": delivery.target,
+                        "queued_at": delivery.queued_at.to_rfc3339(),
+                        "error": format!("{e:#}"),
+                    })),
+                "Quiet hours: queued delivery failed"
+            );
+        }
+    }
+    if !queued.is_empty() {
+        ::zeroclaw_log::record!(
+            INFO,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_attrs(::serde_json::json!({"count": queued.len()})),
+            "Quiet hours ended: flushed queued deliveries"
+        );
+    }
+    Ok(queued.len())
+}
+
+/// Daemon component: flush the queue whenever the window is closed.
+pub async fn run(config: Config, cancel: CancellationToken) -> Result<()> {
+    let mut interval = tokio::time::interval(FLUSH_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        tokio::select! {
+            () = cancel.cancelled() => return Ok(()),
+            _ = interval.tick() => {}
+        }
+        match flush_due(&config).await {
+            Ok(_) => crate::health::mark_component_ok(QUIET_HOURS_COMPONENT),
+            Err(e) => crate::health::mark_component_error(QUIET_HOURS_COMPONENT, e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quiet(start: &str, end: &str, timezone: &str) -> QuietHoursConfig {
+        QuietHoursConfig {
+            start: start.into(),
+            end: end.into(),
+            timezone: Some(timezone.into()),
+            channels: vec!["telegram".into()],
+        }
+    }
+
+    fn utc(raw: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(raw)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn window_crossing_midnight() {
+        let cfg = quiet("23:00", "07:00", "UTC");
+        assert_eq!(
+            window_end(&cfg, utc("2026-05-04T23:30:00Z")),
+            Some(utc("2026-05-05T07:00:00Z"))
+        );
+        assert_eq!(
+            window_end(&cfg, utc("2026-05-05T06:59:00Z")),
+            Some(utc("2026-05-05T07:00:00Z"))
+        );
+        assert_eq!(
+            window_end(&cfg, utc("2026-05-04T23:00:00Z")),
+            Some(utc("2026-05-05T07:00:00Z"))
+        );
+        assert_eq!(window_end(&cfg, utc("2026-05-05T07:00:00Z")), None);
+        assert_eq!(window_end(&cfg, utc("2026-05-05T12:00:00Z")), None);
+
+        let daytime = quiet("13:00", "15:00", "UTC");
+        assert_eq!(
+            window_end(&daytime, utc("2026-05-05T14:00:00Z")),
+            Some(utc("2026-05-05T15:00:00Z"))
+        );
+        assert_eq!(window_end(&daytime, utc("2026-05-05T23:30:00Z")), None);
+    }
+
+    #[test]
+    fn window_follows_dst_transitions() {
+        let cfg = quiet("23:00", "07:00", "Europe/Berlin");
+        // Fall back (2026-10-25): 07:00 CET is 06:00Z, a 9-hour window.
+        assert_eq!(
+            window_end(&cfg, utc("2026-10-24T22:00:00Z")),
+            Some(utc("2026-10-25T06:00:00Z"))
+        );
+        assert_eq!(window_end(&cfg, utc("2026-10-25T06:30:00Z")), None);
+        // Spring forward (2026-03-29): 07:00 CEST is 05:00Z, a 7-hour window.
+        assert_eq!(
+            window_end(&cfg, utc("2026-03-28T23:00:00Z")),
+            Some(utc("2026-03-29T05:00:00Z"))
+        );
+        assert_eq!(window_end(&cfg, utc("2026-03-29T05:00:00Z")), None);
+
+        // 02:30 does not exist on 2026-03-29; the window closes at 03:30 CEST.
+        let gap = quiet("22:00", "02:30", "Europe/Berlin");
+        assert_eq!(
+            window_end(&gap, utc("2026-03-29T00:30:00Z")),
+            Some(utc("2026-03-29T01:30:00Z"))
+        );
+        assert_eq!(window_end(&gap, utc("2026-03-29T01:00:00Z")), None);
+    }
+
+    #[test]
+    fn disabled_or_invalid_window_is_never_quiet() {
+        let now = utc("2026-05-05T02:00:00Z");
+        assert_eq!(window_end(&QuietHoursConfig::default(), now), None);
+        assert_eq!(window_end(&quiet("23:00", "23:00", "UTC"), now), None);
+        assert_eq!(window_end(&quiet("11pm", "07:00", "UTC"), now), None);
+        assert_eq!(
+            window_end(&quiet("23:00", "07:00", "Mars/Olympus"), now),
+            None
+        );
+    }
+
+    #[test]
+    fn channel_filter_matches_type_or_alias() {
+        let mut cfg = quiet("23:00", "07:00", "UTC");
+        assert!(covers_channel(&cfg, "telegram"));
+        assert!(covers_channel(&cfg, "telegram.ops"));
+        assert!(!covers_channel(&cfg, "discord"));
+
+        cfg.channels = vec!["slack.alerts".into()];
+        assert!(covers_channel(&cfg, "slack.alerts"));
+        assert!(!covers_channel(&cfg, "slack.general"));
+
+        cfg.channels.clear();
+        assert!(covers_channel(&cfg, "discord"));
+    }
+
+    #[tokio::test]
+    async fn queued_deliveries_survive_until_flushed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut config = Config {
+            data_dir: tmp.path().to_path_buf(),
+            ..Config::default()
+        };
+        // The whole day except one minute is quiet, so this queues.
+        let now = Utc::now();
+        let minute = |offset: i64| {
+            (now + Duration::minutes(offset))
+                .format("%H:%M")
+                .to_string()
+        };
+        config.quiet_hours = quiet(&minute(-1), &minute(-2), "UTC");
+        deliver_scheduled(&config, "telegram", "42", None, "nightly report", false)
+            .await
+            .unwrap();
+        assert_eq!(flush_due(&config).await.unwrap(), 0);
+
+        let queued = drain_queue(&config).unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].output, "nightly report");
+        assert!(!queue_path(&config).exists());
+    }
+}
@@ -1,11 +1,12 @@
+use crate::cron::concurrency::{Admission, AfterRun, RunLocks};
 use crate::cron::store::{
-    RunCompletionAction, persist_manual_run_result, persist_run_completion_state,
-    persist_run_result,
+    RunCompletionAction, advance_next_run, persist_manual_run_result, persist_run_completion_state,
+    persist_run_result, record_skipped_run,
 };
 use crate::cron::{
-    CronJob, DeliveryConfig, JobType, Schedule, SessionTarget, all_overdue_jobs, claim_job,
-    clear_stale_locks, due_jobs, next_run_for_schedule, release_job, skip_missed_run,
-    sync_declarative_jobs,
+    CatchUpPolicy, CronJob, DeliveryConfig, JobType, NotifyOn, Schedule, SessionTarget,
+    all_overdue_jobs, claim_job, clear_stale_locks, due_jobs_including_running, get_job,
+    next_run_for_schedule, release_job, skip_missed_run, sync_declarative_jobs,
 };
 use crate::security::SecurityPolicy;
 use anyhow::Result;
@@ -14,6 +15,7 @@ use futures_util::{StreamExt, stream};
 use std::process::Stdio;
 use std::sync::Arc;
 use tokio::process::Command;
+use tokio::sync::Semaphore;
 use tokio::time::{self, Duration};
 use tokio_util::sync::CancellationToken;
 use zeroclaw_config::schema::Config;
@@ -23,6 +25,9 @@ use zeroclaw_log::Instrument;
 const MIN_POLL_SECONDS: u64 = 5;
 const SHELL_JOB_TIMEOUT_SECS: u64 = 120;
 const SCHEDULER_COMPONENT: &str = "scheduler";
+/// Upper bound on schedule steps walked when counting missed occurrences,
+/// so a per-second cron across a months-long outage can't stall startup.
+const MAX_MISSED_OCCURRENCE_SCAN: usize = 100_000;
 const CRON_AGENT_DEFAULT_EXCLUDED_TOOLS: &[&str] = &[
     "cron_add",
     "cron_update",
@@ -91,7 +96,10 @@ pub fn announce_delivery_decision(output: &str) -> AnnounceDecision {
 #[derive(Clone, Copy)]
 pub enum CronDeliveryContext {
     Scheduled,
-    ToolManual,
+    /// `cron_run`; `now` delivers immediately even during quiet hours.
+    ToolManual {
+        now: bool,
+    },
     GatewayManual,
     RpcManual,
 }
@@ -101,14 +109,18 @@ impl CronDeliveryContext {
         match (self, best_effort) {
             (Self::Scheduled, true) => "Cron delivery failed (best_effort)",
             (Self::Scheduled, false) => "Cron delivery failed",
-            (Self::ToolManual, true) => "cron_run delivery failed (best_effort)",
-            (Self::ToolManual, false) => "cron_run delivery failed",
+            (Self::ToolManual { .. }, true) => "cron_run delivery failed (best_effort)",
+            (Self::ToolManual { .. }, false) => "cron_run delivery failed",
             (Self::GatewayManual, true) => "manual cron trigger delivery failed (best_effort)",
             (Self::GatewayManual, false) => "manual cron trigger delivery failed",
             (Self::RpcManual, true) => "RPC cron trigger delivery failed (best_effort)",
             (Self::RpcManual, false) => "RPC cron trigger delivery failed",
         }
     }
+
+    fn bypasses_quiet_hours(self) -> bool {
+        matches!(self, Self::ToolManual { now: true })
+    }
 }
 
 pub struct ManualCronRunResult {
@@ -136,7 +148,7 @@ pub async fn deliver_and_classify_run_result(
 ) -> CronDeliveryOutcome {
     let mut status = if success { "ok" } else { "error" }.to_string();
 
-    if let Err(e) = deliver_if_configured(config, job, &output).await {
+    if let Err(e) = deliver_if_configured(config, job, &output, context).await {
         // Cron add-time accepts dangling delivery refs (the job's channel
         // may not be provisioned yet); the loudly-logged warn here is
         // the scheduler-side half of that contract. Manual trigger paths
@@ -188,6 +200,8 @@ pub async fn deliver_and_classify_run_result(
         }
     }
 
+    notify_if_configured(config, job, success, &status, &output, context).await;
+
     CronDeliveryOutcome {
         success,
         status,
@@ -276,6 +290,8 @@ pub async fn run(
             uses_memory: true,
             session_target: None,
             delivery: None,
+            catch_up: None,
+            concurrency: None,
         };
         ::zeroclaw_log::record!(
             DEBUG,
@@ -326,16 +342,12 @@ pub async fn run(
         ),
     }
 
-    if config.scheduler.catch_up_on_startup {
-        catch_up_overdue_jobs(&config, &event_tx).await;
-    } else {
-        ::zeroclaw_log::record!(
-            INFO,
-            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
-            "Scheduler startup: catch-up disabled by config"
-        );
-        skip_missed_jobs_on_startup(&config).await;
-    }
+    catch_up_missed_runs(&config, &event_tx, Utc::now()).await;
+
+    // Runs outlive the tick that started them, so overlap is decided per job
+    // against these locks; the semaphore keeps `max_concurrent` global.
+    let run_locks = Arc::new(RunLocks::default());
+    let run_permits = Arc::new(Semaphore::new(config.scheduler.max_concurrent.max(1)));
 
     loop {
         tokio::select! {
@@ -343,7 +355,7 @@ pub async fn run(
                 // Keep scheduler liveness fresh even when there are no due jobs.
                 crate::health::mark_component_ok(SCHEDULER_COMPONENT);
 
-                let jobs = match due_jobs(&config, Utc::now()) {
+                let jobs = match due_jobs_including_running(&config, Utc::now()) {
                     Ok(jobs) => jobs,
                     Err(e) => {
                         crate::health::mark_component_error(SCHEDULER_COMPONENT, e.to_string());
@@ -358,8 +370,7 @@ pub async fn run(
                     }
                 };
 
-                let jobs = claim_due_jobs(&config, jobs);
-                process_due_jobs(&config, jobs, SCHEDULER_COMPONENT, &event_tx).await;
+                dispatch_due_jobs(&config, jobs, &run_locks, &run_permits, &event_tx);
             }
             _ = cancel.cancelled() => {
                 crate::health::mark_component_ok(SCHEDULER_COMPONENT);
@@ -386,11 +397,107 @@ fn resolve_owning_agent<'a>(config: &'a Config, job: &CronJob) -> Option<&'a str
     config.agent_for_cron_job(&job.id)
 }
 
-/// Fetch **all** overdue jobs (ignoring `max_tasks`) and execute them.
-/// Called once at scheduler startup so that jobs missed during downtime
-/// (e.g. late boot, daemon restart) are caught up immediately.
-async fn catch_up_overdue_jobs(config: &Config, event_tx: &EventBroadcast) {
-    let now = Utc::now();
+/// Startup handling of jobs whose `next_run` passed while the daemon was
+/// down, per job `catch_up` policy (default from
+/// `[scheduler] catch_up_on_startup`).
+#[derive(Debug, Default)]
+struct CatchUpPlan {
+    /// Jobs to run now, each with the missed occurrences to replay, oldest first.
+    runs: Vec<(CronJob, Vec<DateTime<Utc>>)>,
+    /// Jobs advanced to their next occurrence without running.
+    skipped: Vec<CronJob>,
+    /// Missed occurrences dropped by `[scheduler] max_catchup_runs`.
+    capped: usize,
+}
+
+/// Missed occurrences of `job` from its stored `next_run` up to `now`,
+/// oldest first, keeping only the most recent `keep`. Also returns how many
+/// were missed in total.
+fn missed_occurrences(
+    job: &CronJob,
+    now: DateTime<Utc>,
+    keep: usize,
+) -> (Vec<DateTime<Utc>>, usize) {
+    if job.next_run > now {
+        return (Vec::new(), 0);
+    }
+    match job.schedule {
+        Schedule::At { .. } if keep == 0 => (Vec::new(), 1),
+        Schedule::At { .. } => (vec![job.next_run], 1),
+        Schedule::Every { every_ms } if every_ms > 0 => {
+            let step = i64::try_from(every_ms).unwrap_or(i64::MAX);
+            let elapsed = (now - job.next_run).num_milliseconds();
+            let missed = usize::try_from(elapsed / step)
+                .unwrap_or(usize::MAX)
+                .saturating_add(1);
+            let first_kept = missed.saturating_sub(keep);
+            let occurrences = (first_kept..missed)
+                .filter_map(|i| {
+                    let offset = i64::try_from(i).ok()?.checked_mul(step)?;
+                    job.next_run
+                        .checked_add_signed(chrono::Duration::milliseconds(offset))
+                })
+                .collect();
+            (occurrences, missed)
+        }
+        _ => {
+            let mut recent = std::collections::VecDeque::with_capacity(keep.min(64));
+            let mut missed = 0usize;
+            let mut at = job.next_run;
+            while at <= now && missed < MAX_MISSED_OCCURRENCE_SCAN {
+                missed += 1;
+                if keep > 0 {
+                    if recent.len() == keep {
+                        recent.pop_front();
+                    }
+                    recent.push_back(at);
+                }
+                match next_run_for_schedule(&job.schedule, at) {
+                    Ok(next) if next > at => at = next,
+                    _ => break,
+                }
+            }
+            (recent.into(), missed)
+        }
+    }
+}
+
+/// Decide what each overdue job does at startup. `jobs` come longest-overdue
+/// first (`all_overdue_jobs` order), and spend the shared `max_runs` budget
+/// in that order.
+fn plan_catch_up(
+    jobs: Vec<CronJob>,
+    default_policy: CatchUpPolicy,
+    max_runs: usize,
+    now: DateTime<Utc>,
+) -> CatchUpPlan {
+    let mut plan = CatchUpPlan::default();
+    let mut budget = max_runs;
+    for job in jobs {
+        let keep = match job.catch_up.unwrap_or(default_policy) {
+            CatchUpPolicy::None => 0,
+            CatchUpPolicy::Once => 1,
+            CatchUpPolicy::All => usize::MAX,
+        };
+        if keep == 0 {
+            plan.skipped.push(job);
+            continue;
+        }
+        let (occurrences, missed) = missed_occurrences(&job, now, keep.min(budget));
+        plan.capped += missed.min(keep) - occurrences.len();
+        if occurrences.is_empty() {
+            plan.skipped.push(job);
+        } else {
+            budget -= occurrences.len();
+            plan.runs.push((job, occurrences));
+        }
+    }
+    plan
+}
+
+/// Run (or skip) jobs missed while the scheduler was down. Ignores
+/// `max_tasks` so every overdue job is handled before polling resumes.
+async fn catch_up_missed_runs(config: &Config, event_tx: &EventBroadcast, now: DateTime<Utc>) {
     let jobs = match all_overdue_jobs(config, now) {
         Ok(jobs) => jobs,
         Err(e) => {
@@ -414,86 +521,65 @@ async fn catch_up_overdue_jobs(config: &Config, event_tx: &EventBroadcast) {
         return;
     }
 
-    ::zeroclaw_log::record!(
-        INFO,
-        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-            .with_attrs(::serde_json::json!({"count": jobs.len()})),
-        "Scheduler startup: catching up overdue jobs"
-    );
-
-    let jobs = claim_due_jobs(config, jobs);
-    process_due_jobs(config, jobs, SCHEDULER_COMPONENT, event_tx).await;
+    let default_policy = CatchUpPolicy::from_startup_default(config.scheduler.catch_up_on_startup);
+    let max_runs = usize::try_from(config.scheduler.max_catchup_runs).unwrap_or(usize::MAX);
+    let plan = plan_catch_up(jobs, default_policy, max_runs, now);
+    let catch_up_runs: usize = plan.runs.iter().map(|(_, at)| at.len()).sum();
 
     ::zeroclaw_log::record!(
         INFO,
-        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
-        "Scheduler startup: catch-up complete"
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note).with_attrs(
+            ::serde_json::json!({
+                "jobs": plan.runs.len(),
+                "runs": catch_up_runs,
+                "skipped": plan.skipped.len(),
+            })
+        ),
+        "Scheduler startup: catching up missed runs"
     );
-}
+    if plan.capped > 0 {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Skip).with_attrs(
+                ::serde_json::json!({
+                    "dropped": plan.capped,
+                    "max_catchup_runs": config.scheduler.max_catchup_runs,
+                })
+            ),
+            "Scheduler startup: missed runs beyond max_catchup_runs were skipped"
+        );
+    }
 
-async fn skip_missed_jobs_on_startup(config: &Config) {
-    let now = Utc::now();
-    let jobs = match all_overdue_jobs(config, now) {
-        Ok(jobs) => jobs,
-        Err(e) => {
+    for job in &plan.skipped {
+        if let Err(e) = skip_missed_run(config, job, now) {
             ::zeroclaw_log::record!(
                 WARN,
                 ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
                     .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                    .with_attrs(::serde_json::json!({"error": format!("{}", e)})),
-                "Scheduler startup skip: query failed",
+                    .with_attrs(::serde_json::json!({
+                        "job_id": job.id,
+                        "error": format!("{}", e),
+                    })),
+                "Scheduler startup skip: failed to advance job",
             );
-            return;
         }
-    };
-
-    if jobs.is_empty() {
-        ::zeroclaw_log::record!(
-            INFO,
-            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
-            "Scheduler startup skip: no overdue jobs to advance",
-        );
-        return;
     }
 
-    let mut skipped_recurring: u64 = 0;
-    let mut skipped_oneshot: u64 = 0;
-
-    for job in &jobs {
-        let is_oneshot = matches!(job.schedule, Schedule::At { .. });
-        match skip_missed_run(config, job, now) {
-            Ok(()) => {
-                if is_oneshot {
-                    skipped_oneshot += 1;
-                } else {
-                    skipped_recurring += 1;
-                }
-            }
-            Err(e) => {
-                ::zeroclaw_log::record!(
-                    WARN,
-                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                        .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                        .with_attrs(::serde_json::json!({
-                            "job_id": job.id,
-                            "error": format!("{}", e),
-                        })),
-                    "Scheduler startup skip: failed to advance job",
-                );
-            }
-        }
-    }
+    let runs = plan
+        .runs
+        .into_iter()
+        .filter_map(|(job, occurrences)| {
+            claim_due_jobs(config, vec![job])
+                .pop()
+                .map(|job| (job, occurrences))
+        })
+        .collect();
+    process_job_runs(config, runs, SCHEDULER_COMPONENT, event_tx).await;
 
     ::zeroclaw_log::record!(
         INFO,
-        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note).with_attrs(
-            ::serde_json::json!({
-                "total": jobs.len(),
-                "skipped_recurring": skipped_recurring,
-                "skipped_oneshot": skipped_oneshot,
-            })
-        ),
-        "Scheduler startup skip: advanced overdue jobs without executing",
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
+        "Scheduler startup: catch-up complete"
     );
 }
 
@@ -606,66 +692,262 @@ fn claim_due_jobs(config: &Config, jobs: Vec<CronJob>) -> Vec<CronJob> {
         .collect()
 }
 
+#[cfg(test)]
 async fn process_due_jobs(
     config: &Config,
     jobs: Vec<CronJob>,
     component: &str,
     event_tx: &EventBroadcast,
+) {
+    let runs = jobs.into_iter().map(|job| (job, Vec::new())).collect();
+    process_job_runs(config, runs, component, event_tx).await;
+}
+
+/// Start the jobs that came due this tick without waiting for them. Each
+/// job's `concurrency` policy decides what happens to an occurrence whose
+/// previous run is still in flight.
+fn dispatch_due_jobs(
+    config: &Config,
+    jobs: Vec<CronJob>,
+    locks: &Arc<RunLocks>,
+    permits: &Arc<Semaphore>,
+    event_tx: &EventBroadcast,
+) {
+    crate::health::mark_component_ok(SCHEDULER_COMPONENT);
+
+    let max_queued = usize::try_from(config.scheduler.max_queued_runs).unwrap_or(usize::MAX);
+    let now = Utc::now();
+    for mut job in jobs {
+        // A one-shot stays due until its single run disables or deletes it.
+        if matches!(job.schedule, Schedule::At { .. }) && locks.is_running(&job.id) {
+            continue;
+        }
+        match locks.admit(&job.id, job.concurrency, max_queued) {
+            Admission::Start { first } => {
+                if first && claim_due_jobs(config, vec![job.clone()]).is_empty() {
+                    locks.abandon(&job.id);
+                    continue;
+                }
+                // Move recurring jobs past this occurrence now, so the next
+                // tick sees the following one while this run is in flight.
+                if !matches!(job.schedule, Schedule::At { .. }) {
+                    match advance_next_run(config, &job, now) {
+                        Ok(next_run) => job.next_run = next_run,
+                        Err(e) => {
+                            ::zeroclaw_log::record!(
+                                WARN,
+                                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                                    .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                                    .with_attrs(::serde_json::json!({"job_id": job.id, "error": format!("{}", e)})),
+                                "Cron job: failed to advance schedule; skipping launch"
+                            );
+                            if locks.abandon(&job.id) == AfterRun::Idle {
+                                release_job_lock(config, &job.id);
+                            }
+                            continue;
+                        }
+                    }
+                }
+                spawn_job_runs(
+                    config.clone(),
+                    job,
+                    Arc::clone(locks),
+                    Arc::clone(permits),
+                    event_tx.clone(),
+                );
+            }
+            Admission::Queue => {
+                ::zeroclaw_log::record!(
+                    DEBUG,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_attrs(::serde_json::json!({"job_id": job.id})),
+                    "Cron job still running; queued this occurrence"
+                );
+                if let Err(e) = advance_next_run(config, &job, now) {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                            .with_attrs(
+                                ::serde_json::json!({"job_id": job.id, "error": format!("{}", e)})
+                            ),
+                        "Cron job: failed to advance schedule after queueing"
+                    );
+                }
+            }
+            Admission::Skip => {
+                skip_overlapping_run(config, &job, now, "skipped: previous run still in progress");
+            }
+            Admission::QueueFull => {
+                skip_overlapping_run(config, &job, now, "skipped: run queue is full");
+            }
+        }
+    }
+}
+
+fn skip_overlapping_run(config: &Config, job: &CronJob, now: DateTime<Utc>, reason: &str) {
+    ::zeroclaw_log::record!(
+        INFO,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Skip)
+            .with_attrs(::serde_json::json!({"job_id": job.id, "reason": reason})),
+        "Cron job occurrence skipped by concurrency policy"
+    );
+    if let Err(e) = record_skipped_run(config, job, now, reason) {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                .with_attrs(::serde_json::json!({"job_id": job.id, "error": format!("{}", e)})),
+            "Cron job: failed to record skipped occurrence"
+        );
+    }
+}
+
+/// Run a dispatched job in the background, then any occurrences queued
+/// behind it, and release the store lock once the job is idle.
+fn spawn_job_runs(
+    config: Config,
+    mut job: CronJob,
+    locks: Arc<RunLocks>,
+    permits: Arc<Semaphore>,
+    event_tx: EventBroadcast,
+) {
+    zeroclaw_spawn::spawn!(async move {
+        loop {
+            let permit = permits.acquire().await.ok();
+            if let Some((job_id, success, output)) =
+                run_claimed_job(&config, &job, SCHEDULER_COMPONENT, &[], true).await
+            {
+                report_job_result(&event_tx, &job_id, success, &output);
+            }
+            drop(permit);
+
+            match locks.finish(&job.id) {
+                AfterRun::RunQueued => match get_job(&config, &job.id) {
+                    Ok(latest) if latest.enabled => job = latest,
+                    // Removed or disabled while runs were queued.
+                    _ => {
+                        if locks.abandon(&job.id) == AfterRun::Idle {
+                            release_job_lock(&config, &job.id);
+                        }
+                        break;
+                    }
+                },
+                AfterRun::StillRunning => break,
+                AfterRun::Idle => {
+                    release_job_lock(&config, &job.id);
+                    break;
+                }
+            }
+        }
+    });
+}
+
+/// Execute claimed jobs, each paired with the missed occurrences it replays
+/// (empty for a regular run).
+async fn process_job_runs(
+    config: &Config,
+    runs: Vec<(CronJob, Vec<DateTime<Utc>>)>,
+    component: &str,
+    event_tx: &EventBroadcast,
 ) {
     // Refresh scheduler health on every successful poll cycle, including idle cycles.
     crate::health::mark_component_ok(component);
 
     let max_concurrent = config.scheduler.max_concurrent.max(1);
-    let mut in_flight = stream::iter(jobs.into_iter().filter_map(|job| {
-        let Some(agent_alias) = resolve_owning_agent(config, &job) else {
-            ::zeroclaw_log::record!(WARN, ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note).with_outcome(::zeroclaw_log::EventOutcome::Unknown).with_attrs(::serde_json::json!({"job_id": job.id})), "Cron job has no owning agent; add the alias to an [agents.<x>].cron_jobs list");
-            let _ = release_job(config, &job.id);
-            return None;
-        };
-        let agent_alias = agent_alias.to_owned();
-        let security = match SecurityPolicy::for_agent(config, &agent_alias) {
-            Ok(s) => Arc::new(s),
-            Err(e) => {
-                ::zeroclaw_log::record!(WARN, ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note).with_outcome(::zeroclaw_log::EventOutcome::Unknown).with_attrs(::serde_json::json!({"job_id": job.id, "agent": agent_alias, "error": format!("{}", e)})), "Cron job: failed to build SecurityPolicy for owning agent");
-                let _ = release_job(config, &job.id);
-                return None;
-            }
-        };
+    let mut in_flight = stream::iter(runs.into_iter().map(|(job, catch_up_for)| {
         let config = config.clone();
         let component = component.to_owned();
-        Some(async move {
-            Box::pin(execute_and_persist_job(
-                &config,
-                security.as_ref(),
-                &agent_alias,
-                &job,
-                &component,
-            ))
-            .await
-        })
+        async move {
+            let result = run_claimed_job(&config, &job, &component, &catch_up_for, false).await;
+            release_job_lock(&config, &job.id);
+            result
+        }
     }))
     .buffer_unordered(max_concurrent);
 
-    while let Some((job_id, success, output)) = in_flight.next().await {
-        if !success {
-            ::zeroclaw_log::record!(
-                WARN,
-                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                    .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                    .with_attrs(::serde_json::json!({"job_id": job_id, "output": output})),
-                "Scheduler job '' failed: "
-            );
+    while let Some(result) = in_flight.next().await {
+        if let Some((job_id, success, output)) = result {
+            report_job_result(event_tx, &job_id, success, &output);
         }
-        // Broadcast cron result to dashboard/SSE clients.
-        if let Some(tx) = event_tx {
-            let _ = tx.send(serde_json::json!({
-                "type": "cron_result",
-                "job_id": job_id,
-                "success": success,
-                "output": output,
-                "timestamp": chrono::Utc::now().to_rfc3339(),
-            }));
+    }
+}
+
+/// Resolve the owning agent and its security policy, then run the job.
+/// Returns `None` when the job cannot run under any agent.
+async fn run_claimed_job(
+    config: &Config,
+    job: &CronJob,
+    component: &str,
+    catch_up_for: &[DateTime<Utc>],
+    schedule_advanced: bool,
+) -> Option<(String, bool, String)> {
+    let Some(agent_alias) = resolve_owning_agent(config, job) else {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                .with_attrs(::serde_json::json!({"job_id": job.id})),
+            "Cron job has no owning agent; add the alias to an [agents.<x>].cron_jobs list"
+        );
+        return None;
+    };
+    let security = match SecurityPolicy::for_agent(config, agent_alias) {
+        Ok(s) => s,
+        Err(e) => {
+            ::zeroclaw_log::record!(WARN, ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note).with_outcome(::zeroclaw_log::EventOutcome::Unknown).with_attrs(::serde_json::json!({"job_id": job.id, "agent": agent_alias, "error": format!("{}", e)})), "Cron job: failed to build SecurityPolicy for owning agent");
+            return None;
         }
+    };
+    Some(
+        Box::pin(execute_and_persist_job(
+            config,
+            &security,
+            agent_alias,
+            job,
+            component,
+            catch_up_for,
+            schedule_advanced,
+        ))
+        .await,
+    )
+}
+
+fn report_job_result(event_tx: &EventBroadcast, job_id: &str, success: bool, output: &str) {
+    if !success {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                .with_attrs(::serde_json::json!({"job_id": job_id, "output": output})),
+            "Scheduler job '' failed: "
+        );
+    }
+    // Broadcast cron result to dashboard/SSE clients.
+    if let Some(tx) = event_tx {
+        let _ = tx.send(serde_json::json!({
+            "type": "cron_result",
+            "job_id": job_id,
+            "success": success,
+            "output": output,
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+        }));
+    }
+}
+
+/// Release the in-flight lock claimed during selection (`claim_due_jobs`) once
+/// the job's runs (and their reschedule/disable/delete) are done. A deleted
+/// one-shot row simply releases nothing. If this fails the lock is recovered
+/// by `clear_stale_locks` at the next startup.
+fn release_job_lock(config: &Config, job_id: &str) {
+    if let Err(e) = release_job(config, job_id) {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                .with_attrs(::serde_json::json!({"job_id": job_id, "error": format!("{}", e)})),
+            "Cron job: failed to release in-flight lock after run"
+        );
     }
 }
 
@@ -675,38 +957,41 @@ async fn execute_and_persist_job(
     agent_alias: &str,
     job: &CronJob,
     component: &str,
+    catch_up_for: &[DateTime<Utc>],
+    schedule_advanced: bool,
 ) -> (String, bool, String) {
     crate::health::mark_component_ok(component);
     warn_if_high_frequency_agent_job(job);
 
-    let started_at = Utc::now();
-    let span = zeroclaw_log::attribution_span!(job);
-    let (success, output) = Box::pin(execute_job_with_retry(config, security, agent_alias, job))
-        .instrument(span)
+    // A regular run is one pass with no catch-up marker; a catch-up replays
+    // each missed occurrence in order.
+    let occurrences: Vec<Option<DateTime<Utc>>> = if catch_up_for.is_empty() {
+        vec![None]
+    } else {
+        catch_up_for.iter().copied().map(Some).collect()
+    };
+    let mut success = false;
+    let mut output = String::new();
+    for occurrence in occurrences {
+        let started_at = Utc::now();
+        let span = zeroclaw_log::attribution_span!(job);
+        let (run_success, run_output) =
+            Box::pin(execute_job_with_retry(config, security, agent_alias, job))
+                .instrument(span)
+                .await;
+        let finished_at = Utc::now();
+        success = Box::pin(persist_job_result_with_schedule(
+            config,
+            job,
+            run_success,
+            &run_output,
+            started_at,
+            finished_at,
+            occurrence,
+            schedule_advanced,
+        ))
         .await;
-    let finished_at = Utc::now();
-    let success = Box::pin(persist_job_result(
-        config,
-        job,
-        success,
-        &output,
-        started_at,
-        finished_at,
-    ))
-    .await;
-
-    // Release the in-flight lock claimed during selection (`claim_due_jobs`) now
-    // that the run (and its reschedule/disable/delete in `persist_job_result`) is
-    // done. A deleted one-shot row simply releases nothing. If this fails the lock
-    // is recovered by `clear_stale_locks` at the next startup
-    if let Err(e) = release_job(config, &job.id) {
-        ::zeroclaw_log::record!(
-            WARN,
-            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
-                .with_attrs(::serde_json::json!({"job_id": job.id, "error": format!("{}", e)})),
-            "Cron job: failed to release in-flight lock after run"
-        );
+        output = run_output;
     }
 
     (job.id.clone(), success, output)
@@ -788,6 +1073,7 @@ async fn run_agent_job(
         // `agent::run` is the correct choice. The daemon heartbeat
         // worker is the only `mcp_registry` supplier.
         mcp_registry: None,
+        cli_session: None,
     };
     let run_result = match job.session_target {
         SessionTarget::Main | SessionTarget::Isolated => {
@@ -846,6 +1132,7 @@ async fn run_agent_job(
     }
 }
 
+#[cfg(test)]
 async fn persist_job_result(
     config: &Config,
     job: &CronJob,
@@ -853,6 +1140,33 @@ async fn persist_job_result(
     output: &str,
     started_at: DateTime<Utc>,
     finished_at: DateTime<Utc>,
+    catch_up_for: Option<DateTime<Utc>>,
+) -> bool {
+    persist_job_result_with_schedule(
+        config,
+        job,
+        success,
+        output,
+        started_at,
+        finished_at,
+        catch_up_for,
+        false,
+    )
+    .await
+}
+
+/// `schedule_advanced` is set when the dispatcher already moved a recurring
+/// job's `next_run` past this occurrence, so only the last-run fields change.
+#[allow(clippy::too_many_arguments)]
+async fn persist_job_result_with_schedule(
+    config: &Config,
+    job: &CronJob,
+    success: bool,
+    output: &str,
+    started_at: DateTime<Utc>,
+    finished_at: DateTime<Utc>,
+    catch_up_for: Option<DateTime<Utc>>,
+    schedule_advanced: bool,
 ) -> bool {
     let duration_ms = (finished_at - started_at).num_milliseconds();
     let outcome = deliver_and_classify_run_result(
@@ -868,6 +1182,8 @@ async fn persist_job_result(
         RunCompletionAction::Delete
     } else if matches!(job.schedule, Schedule::At { .. }) {
         RunCompletionAction::Disable
+    } else if schedule_advanced {
+        RunCompletionAction::Record
     } else {
         RunCompletionAction::Reschedule
     };
@@ -883,6 +1199,7 @@ async fn persist_job_result(
         Some(&outcome.output),
         duration_ms,
         action,
+        catch_up_for,
     ) {
         ::zeroclaw_log::record!(
             WARN,
@@ -973,7 +1290,12 @@ fn warn_if_high_frequency_agent_job(job: &CronJob) {
     }
 }
 
-async fn deliver_if_configured(config: &Config, job: &CronJob, output: &str) -> Result<()> {
+async fn deliver_if_configured(
+    config: &Config,
+    job: &CronJob,
+    output: &str,
+    context: CronDeliveryContext,
+) -> Result<()> {
     let delivery: &DeliveryConfig = &job.delivery;
     if !delivery.mode.eq_ignore_ascii_case("announce") {
         return Ok(());
@@ -1011,16 +1333,94 @@ async fn deliver_if_configured(config: &Config, job: &CronJob, output: &str) ->
         anyhow::Error::msg("delivery.to is required for announce mode")
     })?;
 
-    deliver_announcement(
+    super::quiet_hours::deliver_scheduled(
         config,
         channel,
         target,
         delivery.thread_id.as_deref(),
         output,
+        context.bypasses_quiet_hours(),
     )
     .await
 }
 
+/// Cap on the output excerpt carried by a notify message; keeps it inside
+/// the smallest per-message limit of the supported channels.
+const MAX_NOTIFY_OUTPUT_CHARS: usize = 1500;
+
+/// Build the notify message for a finished run, or `None` when the job's
+/// `notify.on` trigger does not match (or the output is a quiet sentinel).
+fn notify_message(job: &CronJob, success: bool, status: &str, output: &str) -> Option<String> {
+    let notify = job.notify.as_ref()?;
+    if success && notify.on == NotifyOn::Failure {
+        return None;
+    }
+    if success && !announce_delivery_decision(output).should_deliver() {
+        return None;
+    }
+
+    let label = job.name.as_deref().unwrap_or(&job.id);
+    let header = if success {
+        format!("Cron job '{label}' finished ({status})")
+    } else {
+        format!("Cron job '{label}' failed")
+    };
+    let output = output.trim();
+    if output.is_empty() {
+        return Some(header);
+    }
+    let excerpt: String = output.chars().take(MAX_NOTIFY_OUTPUT_CHARS).collect();
+    let marker = if excerpt.len() < output.len() {
+        "\n...[truncated]"
+    } else {
+        ""
+    };
+    Some(format!("{header}\n\n{excerpt}{marker}"))
+}
+
+/// Send the job's `notify` message. Failures are logged only: a run's
+/// recorded status never depends on whether its notification got through.
+async fn notify_if_configured(
+    config: &Config,
+    job: &CronJob,
+    success: bool,
+    status: &str,
+    output: &str,
+    context: CronDeliveryContext,
+) {
+    let (Some(notify), Some(message)) = (
+        job.notify.as_ref(),
+        notify_message(job, success, status, output),
+    ) else {
+        return;
+    };
+    let channel = notify.channel_ref();
+    if let Err(e) = super::quiet_hours::deliver_scheduled(
+        config,
+        &channel,
+        &notify.recipient,
+        None,
+        &message,
+        context.bypasses_quiet_hours(),
+    )
+    .await
+    {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                .with_attrs(::serde_json::json!({
+                    "job_id": job.id,
+                    "agent_alias": job.agent_alias,
+                    "channel": channel,
+                    "target": notify.recipient,
+                    "error": e.to_string()
+                })),
+            "Cron notify failed; job status unaffected"
+        );
+    }
+}
+
 /// Delivery function type — takes owned values so the returned future is 'static.
 /// The fourth `Option<String>` is the optional thread/conversation id propagated
 /// to channels whose outbound `thread_id` is distinct from the recipient (webhook).
@@ -1179,7 +1579,7 @@ fn build_cron_shell_command(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::cron::{self, DeliveryConfig};
+    use crate::cron::{self, ConcurrencyPolicy, CronJobPatch, DeliveryConfig};
     use crate::security::SecurityPolicy;
     use chrono::{Duration as ChronoDuration, Utc};
     use tempfile::TempDir;
@@ -1293,6 +1693,10 @@ mod tests {
             agent_alias: TEST_AGENT.into(),
             enabled: true,
             delivery: DeliveryConfig::default(),
+            notify: None,
+            catch_up: None,
+            concurrency: ConcurrencyPolicy::default(),
+            running: false,
             delete_after_run: false,
             allowed_tools: None,
             uses_memory: true,
@@ -1857,7 +2261,7 @@ mod tests {
         let started = Utc::now();
         let finished = started + ChronoDuration::milliseconds(10);
 
-        let success = persist_job_result(&config, &job, true, "ok", started, finished).await;
+        let success = persist_job_result(&config, &job, true, "ok", started, finished, None).await;
         assert!(success);
 
         let runs = cron::list_runs(&config, &job.id, 10).unwrap();
@@ -1875,7 +2279,7 @@ mod tests {
         let finished = started + ChronoDuration::milliseconds(10);
 
         crate::cron::store::reset_write_connection_count_for_tests(&config);
-        let success = persist_job_result(&config, &job, true, "ok", started, finished).await;
+        let success = persist_job_result(&config, &job, true, "ok", started, finished, None).await;
 
         assert!(success);
         assert_eq!(
@@ -1897,7 +2301,8 @@ mod tests {
             let finished = started + ChronoDuration::milliseconds(10);
             let output = format!("run-{idx}");
 
-            let success = persist_job_result(&config, &job, true, &output, started, finished).await;
+            let success =
+                persist_job_result(&config, &job, true, &output, started, finished, None).await;
             assert!(success);
         }
 
@@ -1933,7 +2338,7 @@ mod tests {
         .unwrap();
         drop(conn);
 
-        let success = persist_job_result(&config, &job, true, "ok", started, finished).await;
+        let success = persist_job_result(&config, &job, true, "ok", started, finished, None).await;
 
         assert!(success);
         assert!(cron::list_runs(&config, &job.id, 10).unwrap().is_empty());
@@ -1967,7 +2372,7 @@ mod tests {
         let started = Utc::now();
         let finished = started + ChronoDuration::milliseconds(10);
 
-        let success = persist_job_result(&config, &job, true, "ok", started, finished).await;
+        let success = persist_job_result(&config, &job, true, "ok", started, finished, None).await;
         assert!(success);
         let lookup = cron::get_job(&config, &job.id);
         assert!(lookup.is_err());
@@ -1995,7 +2400,8 @@ mod tests {
         let started = Utc::now();
         let finished = started + ChronoDuration::milliseconds(10);
 
-        let success = persist_job_result(&config, &job, false, "boom", started, finished).await;
+        let success =
+            persist_job_result(&config, &job, false, "boom", started, finished, None).await;
         assert!(!success);
         let updated = cron::get_job(&config, &job.id).unwrap();
         assert!(!updated.enabled);
@@ -2025,7 +2431,8 @@ mod tests {
         let finished = started + ChronoDuration::milliseconds(10);
 
         crate::cron::store::reset_write_connection_count_for_tests(&config);
-        let success = persist_job_result(&config, &job, false, "boom", started, finished).await;
+        let success =
+            persist_job_result(&config, &job, false, "boom", started, finished, None).await;
 
         assert!(!success);
         assert_eq!(
@@ -2071,7 +2478,7 @@ mod tests {
         .unwrap();
         drop(conn);
 
-        let success = persist_job_result(&config, &job, true, "ok", started, finished).await;
+        let success = persist_job_result(&config, &job, true, "ok", started, finished, None).await;
         assert!(success);
 
         let runs = cron::list_runs(&config, &job.id, 10).unwrap();
@@ -2107,7 +2514,7 @@ mod tests {
         .unwrap();
         drop(conn);
 
-        let success = persist_job_result(&config, &job, true, "ok", started, finished).await;
+        let success = persist_job_result(&config, &job, true, "ok", started, finished, None).await;
         assert!(success);
 
         let updated = cron::get_job(&config, &job.id).unwrap();
@@ -2127,7 +2534,7 @@ mod tests {
         let started = Utc::now();
         let finished = started + ChronoDuration::milliseconds(10);
 
-        let success = persist_job_result(&config, &job, true, "ok", started, finished).await;
+        let success = persist_job_result(&config, &job, true, "ok", started, finished, None).await;
         assert!(success);
         let lookup = cron::get_job(&config, &job.id);
         assert!(lookup.is_err());
@@ -2143,7 +2550,8 @@ mod tests {
         let started = Utc::now();
         let finished = started + ChronoDuration::milliseconds(10);
 
-        let success = persist_job_result(&config, &job, false, "boom", started, finished).await;
+        let success =
+            persist_job_result(&config, &job, false, "boom", started, finished, None).await;
         assert!(!success);
         let updated = cron::get_job(&config, &job.id).unwrap();
         assert!(!updated.enabled);
@@ -2182,7 +2590,7 @@ mod tests {
         let started = Utc::now();
         let finished = started + ChronoDuration::milliseconds(10);
 
-        let success = persist_job_result(&config, &job, true, "ok", started, finished).await;
+        let success = persist_job_result(&config, &job, true, "ok", started, finished, None).await;
         assert!(success);
 
         let updated = cron::get_job(&config, &job.id).unwrap();
@@ -2210,7 +2618,7 @@ mod tests {
         let started = Utc::now();
         let finished = started + ChronoDuration::milliseconds(10);
 
-        let success = persist_job_result(&config, &job, true, "ok", started, finished).await;
+        let success = persist_job_result(&config, &job, true, "ok", started, finished, None).await;
         assert!(success);
 
         let updated = cron::get_job(&config, &job.id).unwrap();
@@ -2280,7 +2688,7 @@ mod tests {
 
         let started = Utc::now();
         let finished = started + ChronoDuration::milliseconds(10);
-        let success = persist_job_result(&config, &job, true, "ok", started, finished).await;
+        let success = persist_job_result(&config, &job, true, "ok", started, finished, None).await;
         assert!(success);
 
         // After reschedule_after_run, At schedule jobs should be disabled
@@ -2300,7 +2708,11 @@ mod tests {
         let job = test_job("echo ok");
 
         // Default delivery mode is not "announce", so should be a no-op.
-        assert!(deliver_if_configured(&config, &job, "x").await.is_ok());
+        assert!(
+            deliver_if_configured(&config, &job, "x", CronDeliveryContext::Scheduled)
+                .await
+                .is_ok()
+        );
     }
 
     static DELIVERED: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
@@ -2315,7 +2727,7 @@ mod tests {
         // delivery-classification tests so it composes regardless of order.
         register_delivery_fn(Box::new(|_config, channel, _target, _thread, _output| {
             Box::pin(async move {
-                if channel == "fail-delivery" {
+                if channel.starts_with("fail-delivery") {
                     anyhow::bail!("synthetic delivery failure");
                 }
                 if channel == COUNT_CHANNEL {
@@ -2353,7 +2765,9 @@ mod tests {
             "NO_REPLY[INFO]: healthy",
         ] {
             let before = DELIVERED.load(SeqCst);
-            deliver_if_configured(&config, &job, quiet).await.unwrap();
+            deliver_if_configured(&config, &job, quiet, CronDeliveryContext::Scheduled)
+                .await
+                .unwrap();
             assert_eq!(
                 DELIVERED.load(SeqCst),
                 before,
@@ -2363,9 +2777,14 @@ mod tests {
 
         // Real content must be delivered.
         let before = DELIVERED.load(SeqCst);
-        deliver_if_configured(&config, &job, "All systems nominal")
-            .await
-            .unwrap();
+        deliver_if_configured(
+            &config,
+            &job,
+            "All systems nominal",
+            CronDeliveryContext::Scheduled,
+        )
+        .await
+        .unwrap();
         assert_eq!(
             DELIVERED.load(SeqCst),
             before + 1,
@@ -2378,7 +2797,9 @@ mod tests {
             "NO_REPLY[REFUSE]: policy prevented the check",
         ] {
             let before = DELIVERED.load(SeqCst);
-            deliver_if_configured(&config, &job, visible).await.unwrap();
+            deliver_if_configured(&config, &job, visible, CronDeliveryContext::Scheduled)
+                .await
+                .unwrap();
             assert_eq!(
                 DELIVERED.load(SeqCst),
                 before + 1,
@@ -2387,6 +2808,60 @@ mod tests {
         }
     }
 
+    fn notify_job(on: NotifyOn) -> CronJob {
+        let mut job = test_job("echo ok");
+        job.name = Some("nightly-backup".into());
+        job.notify = Some(crate::cron::NotifyConfig {
+            channel: "telegram".into(),
+            recipient: "12345".into(),
+            on,
+        });
+        job
+    }
+
+    #[test]
+    fn notify_message_respects_trigger_and_truncates_output() {
+        let always = notify_job(NotifyOn::Always);
+        let message = notify_message(&always, true, "ok", "backup done").unwrap();
+        assert_eq!(
+            message,
+            "Cron job 'nightly-backup' finished (ok)\n\nbackup done"
+        );
+        assert!(notify_message(&always, true, "ok", "NO_REPLY").is_none());
+
+        let failure_only = notify_job(NotifyOn::Failure);
+        assert!(notify_message(&failure_only, true, "ok", "backup done").is_none());
+        let long = "x".repeat(MAX_NOTIFY_OUTPUT_CHARS + 10);
+        let message = notify_message(&failure_only, false, "error", &long).unwrap();
+        assert!(message.starts_with("Cron job 'nightly-backup' failed"));
+        assert!(message.ends_with("...[truncated]"));
+
+        assert!(notify_message(&test_job("echo ok"), false, "error", "boom").is_none());
+    }
+
+    #[tokio::test]
+    async fn notify_failure_does_not_change_run_status() {
+        register_recording_delivery_fn();
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp).await;
+        let mut job = notify_job(NotifyOn::Always);
+        if let Some(notify) = job.notify.as_mut() {
+            notify.channel = "fail-delivery".into();
+        }
+
+        let outcome = deliver_and_classify_run_result(
+            &config,
+            &job,
+            true,
+            "backup done".into(),
+            CronDeliveryContext::Scheduled,
+        )
+        .await;
+        assert!(outcome.success);
+        assert_eq!(outcome.status, "ok");
+        assert_eq!(outcome.output, "backup done");
+    }
+
     #[test]
     fn heartbeat_announce_decision_matches_worker_behavior() {
         // NO_REPLY heartbeat: suppressed.
@@ -2442,6 +2917,143 @@ mod tests {
         assert!(stdout.contains("cron-ok"));
     }
 
+    fn every_minute_job(next_run: DateTime<Utc>, catch_up: Option<CatchUpPolicy>) -> CronJob {
+        CronJob {
+            schedule: Schedule::Every { every_ms: 60_000 },
+            next_run,
+            catch_up,
+            ..test_job("echo ok")
+        }
+    }
+
+    #[test]
+    fn missed_occurrences_walks_the_gap_and_keeps_the_most_recent() {
+        let start = Utc::now() - ChronoDuration::hours(1);
+        let job = every_minute_job(start, None);
+        let now = start + ChronoDuration::seconds(10 * 60 + 30);
+
+        let (recent, missed) = missed_occurrences(&job, now, 3);
+        assert_eq!(missed, 11);
+        assert_eq!(
+            recent,
+            vec![
+                start + ChronoDuration::minutes(8),
+                start + ChronoDuration::minutes(9),
+                start + ChronoDuration::minutes(10),
+            ]
+        );
+
+        let hourly = CronJob {
+            schedule: Schedule::Cron {
+                expr: "0 * * * *".into(),
+                tz: Some("UTC".into()),
+            },
+            next_run: "2026-03-01T10:00:00Z".parse().unwrap(),
+            ..test_job("echo ok")
+        };
+        let now: DateTime<Utc> = "2026-03-01T15:30:00Z".parse().unwrap();
+        let (all, missed) = missed_occurrences(&hourly, now, usize::MAX);
+        assert_eq!(missed, 6);
+        assert_eq!(all.first(), Some(&hourly.next_run));
+        assert_eq!(all.last(), Some(&"2026-03-01T15:00:00Z".parse().unwrap()));
+
+        // Not overdue yet: nothing missed.
+        assert_eq!(
+            missed_occurrences(&hourly, hourly.next_run - ChronoDuration::seconds(1), 1).1,
+            0
+        );
+    }
+
+    #[test]
+    fn plan_catch_up_applies_policies_and_shared_cap() {
+        let start = Utc::now() - ChronoDuration::days(1);
+        let now = start + ChronoDuration::minutes(9);
+        let jobs = vec![
+            CronJob {
+                id: "once".into(),
+                ..every_minute_job(start, Some(CatchUpPolicy::Once))
+            },
+            CronJob {
+                id: "all".into(),
+                ..every_minute_job(start, Some(CatchUpPolicy::All))
+            },
+            CronJob {
+                id: "none".into(),
+                ..every_minute_job(start, Some(CatchUpPolicy::None))
+            },
+            CronJob {
+                id: "default".into(),
+                ..every_minute_job(start, None)
+            },
+        ];
+
+        let plan = plan_catch_up(jobs, CatchUpPolicy::Once, 5, now);
+        let runs: Vec<(&str, usize)> = plan
+            .runs
+            .iter()
+            .map(|(job, at)| (job.id.as_str(), at.len()))
+            .collect();
+        // `once` takes one run and `all` the four most recent of its ten
+        // misses; the defaulted job finds the budget spent.
+        assert_eq!(runs, vec![("once", 1), ("all", 4)]);
+        assert_eq!(plan.runs[0].1, vec![now]);
+        assert_eq!(plan.runs[1].1.last(), Some(&now));
+        let skipped: Vec<&str> = plan.skipped.iter().map(|job| job.id.as_str()).collect();
+        assert_eq!(skipped, vec!["none", "default"]);
+        assert_eq!(plan.capped, 6 + 1);
+
+        let plan = plan_catch_up(
+            vec![every_minute_job(start, None)],
+            CatchUpPolicy::None,
+            5,
+            now,
+        );
+        assert!(plan.runs.is_empty());
+        assert_eq!(plan.skipped.len(), 1);
+        assert_eq!(plan.capped, 0);
+    }
+
+    #[tokio::test]
+    async fn startup_catch_up_replays_missed_runs_and_marks_history() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = test_config(&tmp).await;
+        config.scheduler.max_catchup_runs = 3;
+        let job = cron::add_shell_job(
+            &config,
+            TEST_AGENT,
+            None,
+            Schedule::Every { every_ms: 60_000 },
+            "echo caught-up",
+            None,
+        )
+        .unwrap();
+        cron::update_job(
+            &config,
+            &job.id,
+            cron::CronJobPatch {
+                catch_up: Some(CatchUpPolicy::All),
+                ..cron::CronJobPatch::default()
+            },
+        )
+        .unwrap();
+
+        // Simulate a daemon that comes back four and a half intervals late.
+        let now = job.next_run + ChronoDuration::seconds(4 * 60 + 30);
+        catch_up_missed_runs(&config, &None, now).await;
+
+        let mut runs = cron::list_runs(&config, &job.id, 10).unwrap();
+        runs.reverse();
+        let replayed: Vec<DateTime<Utc>> = runs.iter().filter_map(|run| run.catch_up_for).collect();
+        assert_eq!(
+            replayed,
+            (2..=4)
+                .map(|i| job.next_run + ChronoDuration::minutes(i))
+                .collect::<Vec<_>>()
+        );
+        assert!(runs.iter().all(|run| run.status == "ok"));
+        assert!(cron::get_job(&config, &job.id).unwrap().next_run > Utc::now());
+    }
+
     #[tokio::test]
     async fn catch_up_queries_all_overdue_jobs_ignoring_max_tasks() {
         let tmp = TempDir::new().unwrap();
@@ -2581,6 +3193,118 @@ mod tests {
         );
     }
 
+    /// A shell job that outlives the gap between two scheduler ticks.
+    async fn long_running_job(config: &mut Config, policy: ConcurrencyPolicy) -> CronJob {
+        config
+            .risk_profiles
+            .entry(TEST_AGENT.into())
+            .or_default()
+            .allowed_commands = vec!["sleep".into()];
+        let job = cron::add_job(config, TEST_AGENT, "* * * * *", "sleep 1").unwrap();
+        cron::update_job(
+            config,
+            &job.id,
+            CronJobPatch {
+                concurrency: Some(policy),
+                ..CronJobPatch::default()
+            },
+        )
+        .unwrap()
+    }
+
+    async fn wait_until_idle(config: &Config, locks: &RunLocks, job_id: &str) {
+        for _ in 0..100 {
+            if !locks.is_running(job_id) && !cron::get_job(config, job_id).unwrap().running {
+                return;
+            }
+            time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("cron job {job_id} still running after 10s");
+    }
+
+    fn statuses(config: &Config, job_id: &str) -> Vec<String> {
+        let mut statuses: Vec<String> = cron::list_runs(config, job_id, 10)
+            .unwrap()
+            .into_iter()
+            .map(|run| run.status)
+            .collect();
+        statuses.sort();
+        statuses
+    }
+
+    #[tokio::test]
+    #[cfg(not(target_os = "windows"))]
+    async fn dispatch_skip_policy_records_overlapping_occurrence_as_skipped() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = test_config(&tmp).await;
+        let job = long_running_job(&mut config, ConcurrencyPolicy::Skip).await;
+        let locks = Arc::new(RunLocks::default());
+        let permits = Arc::new(Semaphore::new(4));
+
+        dispatch_due_jobs(&config, vec![job.clone()], &locks, &permits, &None);
+        assert!(cron::get_job(&config, &job.id).unwrap().running);
+        dispatch_due_jobs(&config, vec![job.clone()], &locks, &permits, &None);
+        wait_until_idle(&config, &locks, &job.id).await;
+
+        assert_eq!(statuses(&config, &job.id), ["ok", "skipped"]);
+        let skipped = cron::list_runs(&config, &job.id, 10)
+            .unwrap()
+            .into_iter()
+            .find(|run| run.status == "skipped")
+            .unwrap();
+        assert_eq!(
+            skipped.output.as_deref(),
+            Some("skipped: previous run still in progress")
+        );
+        assert!(cron::get_job(&config, &job.id).unwrap().enabled);
+    }
+
+    #[tokio::test]
+    #[cfg(not(target_os = "windows"))]
+    async fn dispatch_queue_policy_runs_backlog_after_current_run_up_to_cap() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = test_config(&tmp).await;
+        config.scheduler.max_queued_runs = 1;
+        let job = long_running_job(&mut config, ConcurrencyPolicy::Queue).await;
+        let locks = Arc::new(RunLocks::default());
+        let permits = Arc::new(Semaphore::new(4));
+
+        for _ in 0..3 {
+            dispatch_due_jobs(&config, vec![job.clone()], &locks, &permits, &None);
+        }
+        wait_until_idle(&config, &locks, &job.id).await;
+
+        assert_eq!(statuses(&config, &job.id), ["ok", "ok", "skipped"]);
+        let runs = cron::list_runs(&config, &job.id, 10).unwrap();
+        let ok: Vec<_> = runs.iter().filter(|run| run.status == "ok").collect();
+        // The queued run starts only after the first one finished.
+        assert!(ok[0].started_at >= ok[1].finished_at || ok[1].started_at >= ok[0].finished_at);
+        assert!(
+            runs.iter()
+                .any(|run| run.output.as_deref() == Some("skipped: run queue is full"))
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(not(target_os = "windows"))]
+    async fn dispatch_allow_policy_overlaps_runs() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = test_config(&tmp).await;
+        let job = long_running_job(&mut config, ConcurrencyPolicy::Allow).await;
+        let locks = Arc::new(RunLocks::default());
+        let permits = Arc::new(Semaphore::new(4));
+
+        dispatch_due_jobs(&config, vec![job.clone()], &locks, &permits, &None);
+        dispatch_due_jobs(&config, vec![job.clone()], &locks, &permits, &None);
+        wait_until_idle(&config, &locks, &job.id).await;
+
+        assert_eq!(statuses(&config, &job.id), ["ok", "ok"]);
+        let runs = cron::list_runs(&config, &job.id, 10).unwrap();
+        assert!(runs[0].started_at < runs[1].finished_at);
+        assert!(runs[1].started_at < runs[0].finished_at);
+        assert!(claim_job(&config, &job.id, Utc::now()).unwrap());
+    }
+
     #[tokio::test]
     async fn broadcast_none_skips_without_error() {
         let tmp = TempDir::new().unwrap();
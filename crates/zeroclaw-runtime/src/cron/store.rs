@@ -1,6 +1,7 @@
 use crate::cron::{
-    CronJob, CronJobPatch, CronRun, DeliveryConfig, JobType, Schedule, SessionTarget,
-    next_run_for_schedule, schedule_cron_expression, validate_delivery_config, validate_schedule,
+    CatchUpPolicy, ConcurrencyPolicy, CronJob, CronJobPatch, CronRun, DeliveryConfig, JobType,
+    NewJobOptions, NotifyConfig, Schedule, SessionTarget, next_run_for_schedule,
+    schedule_cron_expression, validate_delivery_config, validate_notify_config, validate_schedule,
 };
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
@@ -15,6 +16,9 @@ const TRUNCATED_OUTPUT_MARKER: &str = "\n...[truncated]";
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) enum RunCompletionAction {
     Reschedule,
+    /// Update the last-run fields only; `next_run` was already advanced when
+    /// the run was dispatched.
+    Record,
     Disable,
     Delete,
 }
@@ -68,10 +72,31 @@ pub fn add_shell_job(
     schedule: Schedule,
     command: &str,
     delivery: Option<DeliveryConfig>,
+) -> Result<CronJob> {
+    add_shell_job_with_options(
+        config,
+        agent_alias,
+        name,
+        schedule,
+        command,
+        delivery,
+        NewJobOptions::default(),
+    )
+}
+
+pub fn add_shell_job_with_options(
+    config: &Config,
+    agent_alias: &str,
+    name: Option<String>,
+    schedule: Schedule,
+    command: &str,
+    delivery: Option<DeliveryConfig>,
+    options: NewJobOptions,
 ) -> Result<CronJob> {
     let now = Utc::now();
     validate_schedule(&schedule, now)?;
     validate_delivery_config(delivery.as_ref())?;
+    validate_notify_config(options.notify.as_ref())?;
     let next_run = next_run_for_schedule(&schedule, now)?;
     let id = Uuid::new_v4().to_string();
     let expression = schedule_cron_expression(&schedule).unwrap_or_default();
@@ -88,8 +113,10 @@ pub fn add_shell_job(
         conn.execute(
             "INSERT INTO cron_jobs (
                 id, expression, command, schedule, job_type, prompt, name, session_target, model,
-                enabled, delivery, delete_after_run, agent_alias, created_at, next_run
-             ) VALUES (?1, ?2, ?3, ?4, 'shell', NULL, ?5, 'isolated', NULL, 1, ?6, ?7, ?8, ?9, ?10)",
+                enabled, delivery, delete_after_run, agent_alias, created_at, next_run, notify,
+                catch_up, concurrency
+             ) VALUES (?1, ?2, ?3, ?4, 'shell', NULL, ?5, 'isolated', NULL, 1, ?6, ?7, ?8, ?9, ?10,
+                ?11, ?12, ?13)",
             params![
                 id,
                 expression,
@@ -101,6 +128,9 @@ pub fn add_shell_job(
                 agent_alias,
                 now.to_rfc3339(),
                 next_run.to_rfc3339(),
+                encode_notify(options.notify.as_ref())?,
+                options.catch_up.map(|policy| policy.as_str()),
+                options.concurrency.unwrap_or_default().as_str(),
             ],
         )
         .context("Failed to insert cron shell job")?;
@@ -123,10 +153,42 @@ pub fn add_agent_job(
     delete_after_run: bool,
     allowed_tools: Option<Vec<String>>,
     uses_memory: bool,
+) -> Result<CronJob> {
+    add_agent_job_with_options(
+        config,
+        agent_alias,
+        name,
+        schedule,
+        prompt,
+        session_target,
+        model,
+        delivery,
+        delete_after_run,
+        allowed_tools,
+        uses_memory,
+        NewJobOptions::default(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn add_agent_job_with_options(
+    config: &Config,
+    agent_alias: &str,
+    name: Option<String>,
+    schedule: Schedule,
+    prompt: &str,
+    session_target: SessionTarget,
+    model: Option<String>,
+    delivery: Option<DeliveryConfig>,
+    delete_after_run: bool,
+    allowed_tools: Option<Vec<String>>,
+    uses_memory: bool,
+    options: NewJobOptions,
 ) -> Result<CronJob> {
     let now = Utc::now();
     validate_schedule(&schedule, now)?;
     validate_delivery_config(delivery.as_ref())?;
+    validate_notify_config(options.notify.as_ref())?;
     let next_run = next_run_for_schedule(&schedule, now)?;
     let id = Uuid::new_v4().to_string();
     let expression = schedule_cron_expression(&schedule).unwrap_or_default();
@@ -142,8 +204,9 @@ pub fn add_agent_job(
             "INSERT INTO cron_jobs (
                 id, expression, command, schedule, job_type, prompt, name, session_target, model,
                 enabled, delivery, delete_after_run, allowed_tools, agent_alias, created_at, next_run,
-                uses_memory
-             ) VALUES (?1, ?2, '', ?3, 'agent', ?4, ?5, ?6, ?7, 1, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+                uses_memory, notify, catch_up, concurrency
+             ) VALUES (?1, ?2, '', ?3, 'agent', ?4, ?5, ?6, ?7, 1, ?8, ?9, ?10, ?11, ?12, ?13, ?14,
+                ?15, ?16, ?17)",
             params![
                 id,
                 expression,
@@ -159,6 +222,9 @@ pub fn add_agent_job(
                 now.to_rfc3339(),
                 next_run.to_rfc3339(),
                 if uses_memory { 1 } else { 0 },
+                encode_notify(options.notify.as_ref())?,
+                options.catch_up.map(|policy| policy.as_str()),
+                options.concurrency.unwrap_or_default().as_str(),
             ],
         )
         .context("Failed to insert cron agent job")?;
@@ -173,7 +239,8 @@ pub fn list_jobs(config: &Config) -> Result<Vec<CronJob>> {
         let mut stmt = conn.prepare(
             "SELECT id, expression, command, schedule, job_type, prompt, name, session_target, model,
                     enabled, delivery, delete_after_run, created_at, next_run, last_run, last_status, last_output,
-                    allowed_tools, source, uses_memory, agent_alias
+                    allowed_tools, source, uses_memory, agent_alias, notify, catch_up, concurrency,
+                    locked_at IS NOT NULL
              FROM cron_jobs ORDER BY next_run ASC",
         )?;
 
@@ -197,7 +264,8 @@ pub fn get_job(config: &Config, job_id: &str) -> Result<CronJob> {
         let mut stmt = conn.prepare(
             "SELECT id, expression, command, schedule, job_type, prompt, name, session_target, model,
                     enabled, delivery, delete_after_run, created_at, next_run, last_run, last_status, last_output,
-                    allowed_tools, source, uses_memory, agent_alias
+                    allowed_tools, source, uses_memory, agent_alias, notify, catch_up, concurrency,
+                    locked_at IS NOT NULL
              FROM cron_jobs WHERE id = ?1",
         )?;
 
@@ -270,7 +338,8 @@ pub fn list_jobs_by_agent(config: &Config, agent_alias: &str) -> Result<Vec<Cron
         let mut stmt = conn.prepare(
             "SELECT id, expression, command, schedule, job_type, prompt, name, session_target, model,
                     enabled, delivery, delete_after_run, created_at, next_run, last_run, last_status, last_output,
-                    allowed_tools, source, uses_memory, agent_alias
+                    allowed_tools, source, uses_memory, agent_alias, notify, catch_up, concurrency,
+                    locked_at IS NOT NULL
              FROM cron_jobs WHERE agent_alias = ?1 ORDER BY next_run ASC",
         )?;
         let rows = stmt.query_map(params![agent_alias], map_cron_job_row)?;
@@ -316,20 +385,38 @@ pub fn rename_jobs_by_agent(config: &Config, from: &str, to: &str) -> Result<usi
 }
 
 pub fn due_jobs(config: &Config, now: DateTime<Utc>) -> Result<Vec<CronJob>> {
+    select_due_jobs(config, now, false)
+}
+
+/// Like [`due_jobs`], but also returns jobs whose previous run still holds
+/// the in-flight lock, so the scheduler can apply their concurrency policy.
+pub fn due_jobs_including_running(config: &Config, now: DateTime<Utc>) -> Result<Vec<CronJob>> {
+    select_due_jobs(config, now, true)
+}
+
+fn select_due_jobs(
+    config: &Config,
+    now: DateTime<Utc>,
+    include_running: bool,
+) -> Result<Vec<CronJob>> {
     let lim = i64::try_from(config.scheduler.max_tasks.max(1))
         .context("Scheduler max_tasks overflows i64")?;
     let Some(jobs) = with_read_connection(config, |conn| {
         let mut stmt = conn.prepare(
             "SELECT id, expression, command, schedule, job_type, prompt, name, session_target, model,
                     enabled, delivery, delete_after_run, created_at, next_run, last_run, last_status, last_output,
-                    allowed_tools, source, uses_memory, agent_alias
+                    allowed_tools, source, uses_memory, agent_alias, notify, catch_up, concurrency,
+                    locked_at IS NOT NULL
              FROM cron_jobs
-             WHERE enabled = 1 AND next_run <= ?1 AND locked_at IS NULL
+             WHERE enabled = 1 AND next_run <= ?1 AND (?3 OR locked_at IS NULL)
              ORDER BY next_run ASC
              LIMIT ?2",
         )?;
 
-        let rows = stmt.query_map(params![now.to_rfc3339(), lim], map_cron_job_row)?;
+        let rows = stmt.query_map(
+            params![now.to_rfc3339(), lim, include_running],
+            map_cron_job_row,
+        )?;
 
         let mut jobs = Vec::new();
         for row in rows {
@@ -358,7 +445,8 @@ pub fn all_overdue_jobs(config: &Config, now: DateTime<Utc>) -> Result<Vec<CronJ
         let mut stmt = conn.prepare(
             "SELECT id, expression, command, schedule, job_type, prompt, name, session_target, model,
                     enabled, delivery, delete_after_run, created_at, next_run, last_run, last_status, last_output,
-                    allowed_tools, source, uses_memory, agent_alias
+                    allowed_tools, source, uses_memory, agent_alias, notify, catch_up, concurrency,
+                    locked_at IS NOT NULL
              FROM cron_jobs
              WHERE enabled = 1 AND next_run <= ?1 AND locked_at IS NULL
              ORDER BY next_run ASC",
@@ -413,6 +501,16 @@ pub fn update_job(config: &Config, job_id: &str, patch: CronJobPatch) -> Result<
     if let Some(delivery) = patch.delivery {
         job.delivery = delivery;
     }
+    if let Some(notify) = patch.notify {
+        validate_notify_config(Some(&notify))?;
+        job.notify = Some(notify);
+    }
+    if let Some(catch_up) = patch.catch_up {
+        job.catch_up = Some(catch_up);
+    }
+    if let Some(concurrency) = patch.concurrency {
+        job.concurrency = concurrency;
+    }
     if let Some(model) = patch.model {
         job.model = Some(model);
     }
@@ -444,8 +542,9 @@ pub fn update_job(config: &Config, job_id: &str, patch: CronJobPatch) -> Result<
             "UPDATE cron_jobs
              SET expression = ?1, command = ?2, schedule = ?3, job_type = ?4, prompt = ?5, name = ?6,
                  session_target = ?7, model = ?8, enabled = ?9, delivery = ?10, delete_after_run = ?11,
-                 allowed_tools = ?12, next_run = ?13, uses_memory = ?14
-             WHERE id = ?15",
+                 allowed_tools = ?12, next_run = ?13, uses_memory = ?14, notify = ?15,
+                 catch_up = ?16, concurrency = ?17
+             WHERE id = ?18",
             params![
                 job.expression,
                 job.command,
@@ -461,6 +560,9 @@ pub fn update_job(config: &Config, job_id: &str, patch: CronJobPatch) -> Result<
                 encode_allowed_tools(job.allowed_tools.as_ref())?,
                 job.next_run.to_rfc3339(),
                 if job.uses_memory { 1 } else { 0 },
+                encode_notify(job.notify.as_ref())?,
+                job.catch_up.map(|policy| policy.as_str()),
+                job.concurrency.as_str(),
                 job.id,
             ],
         )
@@ -552,7 +654,7 @@ pub fn skip_missed_run(config: &Config, job: &CronJob, now: DateTime<Utc>) -> Re
     if matches!(job.schedule, Schedule::At { .. }) {
         // One-shot job whose scheduled moment has already passed —
         // disable it so it won't execute late.
-        let bounded_output = truncate_cron_output("skipped — catch_up_on_startup disabled");
+        let bounded_output = truncate_cron_output("skipped — missed while the scheduler was down");
         with_initialized_connection(config, |conn| {
             conn.execute(
                 "UPDATE cron_jobs
@@ -577,6 +679,58 @@ pub fn skip_missed_run(config: &Config, job: &CronJob, now: DateTime<Utc>) -> Re
     }
 }
 
+/// Move a recurring job's `next_run` to its first occurrence after `now`, so
+/// the scheduler can watch for the next one while this run is in flight.
+pub(crate) fn advance_next_run(
+    config: &Config,
+    job: &CronJob,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>> {
+    let next_run = next_run_for_schedule(&job.schedule, now)?;
+    with_initialized_connection(config, |conn| {
+        conn.execute(
+            "UPDATE cron_jobs SET next_run = ?1 WHERE id = ?2",
+            params![next_run.to_rfc3339(), job.id],
+        )
+        .context("Failed to advance cron job next_run")?;
+        Ok(())
+    })?;
+    Ok(next_run)
+}
+
+/// Record an occurrence the concurrency policy dropped as a `skipped` run and
+/// advance the job past it.
+pub(crate) fn record_skipped_run(
+    config: &Config,
+    job: &CronJob,
+    now: DateTime<Utc>,
+    reason: &str,
+) -> Result<()> {
+    let next_run = next_run_for_schedule(&job.schedule, now)?;
+    with_initialized_connection(config, |conn| {
+        let tx = conn.unchecked_transaction()?;
+        insert_run_and_prune(
+            &tx,
+            config,
+            &job.id,
+            now,
+            now,
+            "skipped",
+            Some(reason),
+            0,
+            None,
+        )?;
+        tx.execute(
+            "UPDATE cron_jobs SET next_run = ?1 WHERE id = ?2",
+            params![next_run.to_rfc3339(), job.id],
+        )
+        .context("Failed to advance cron job past skipped occurrence")?;
+        tx.commit()
+            .context("Failed to commit skipped cron run transaction")?;
+        Ok(())
+    })
+}
+
 pub fn claim_job(config: &Config, job_id: &str, now: DateTime<Utc>) -> Result<bool> {
     with_initialized_connection(config, |conn| {
         let claimed = conn
@@ -636,6 +790,7 @@ pub fn record_run(
             status,
             bounded_output.as_deref(),
             duration_ms,
+            None,
         )?;
 
         tx.commit()
@@ -668,6 +823,7 @@ pub(crate) fn persist_manual_run_result(
             status,
             bounded_output.as_deref(),
             duration_ms,
+            None,
         )?;
 
         apply_last_run_state(
@@ -695,6 +851,7 @@ pub(crate) fn persist_run_result(
     output: Option<&str>,
     duration_ms: i64,
     action: RunCompletionAction,
+    catch_up_for: Option<DateTime<Utc>>,
 ) -> Result<()> {
     let bounded_output = output.map(truncate_cron_output);
 
@@ -710,6 +867,7 @@ pub(crate) fn persist_run_result(
             status,
             bounded_output.as_deref(),
             duration_ms,
+            catch_up_for,
         )?;
 
         apply_run_completion_state(
@@ -750,10 +908,11 @@ fn insert_run_and_prune(
     status: &str,
     output: Option<&str>,
     duration_ms: i64,
+    catch_up_for: Option<DateTime<Utc>>,
 ) -> Result<()> {
     conn.execute(
-        "INSERT INTO cron_runs (job_id, started_at, finished_at, status, output, duration_ms)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        "INSERT INTO cron_runs (job_id, started_at, finished_at, status, output, duration_ms, catch_up_for)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
         params![
             job_id,
             started_at.to_rfc3339(),
@@ -761,6 +920,7 @@ fn insert_run_and_prune(
             status,
             output,
             duration_ms,
+            catch_up_for.map(|at| at.to_rfc3339()),
         ],
     )
     .context("Failed to insert cron run")?;
@@ -822,7 +982,7 @@ pub fn list_runs(config: &Config, job_id: &str, limit: usize) -> Result<Vec<Cron
     let Some(runs) = with_read_connection(config, |conn| {
         let lim = i64::try_from(limit.max(1)).context("Run history limit overflow")?;
         let mut stmt = conn.prepare(
-            "SELECT id, job_id, started_at, finished_at, status, output, duration_ms
+            "SELECT id, job_id, started_at, finished_at, status, output, duration_ms, catch_up_for
              FROM cron_runs
              WHERE job_id = ?1
              ORDER BY started_at DESC, id DESC
@@ -840,6 +1000,12 @@ pub fn list_runs(config: &Config, job_id: &str, limit: usize) -> Result<Vec<Cron
                 status: row.get(4)?,
                 output: row.get(5)?,
                 duration_ms: row.get(6)?,
+                catch_up_for: row
+                    .get::<_, Option<String>>(7)?
+                    .as_deref()
+                    .map(parse_rfc3339)
+                    .transpose()
+                    .map_err(sql_conversion_error)?,
             })
         })?;
 
@@ -882,6 +1048,9 @@ fn map_cron_job_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<CronJob> {
     let source: Option<String> = row.get(18)?;
     let uses_memory: Option<i64> = row.get(19)?;
     let agent_alias: Option<String> = row.get(20)?;
+    let notify_raw: Option<String> = row.get(21)?;
+    let catch_up_raw: Option<String> = row.get(22)?;
+    let concurrency_raw: Option<String> = row.get(23)?;
 
     Ok(CronJob {
         id: row.get(0)?,
@@ -898,6 +1067,11 @@ fn map_cron_job_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<CronJob> {
             .unwrap_or_default(),
         enabled: row.get::<_, i64>(9)? != 0,
         delivery,
+        notify: decode_notify(notify_raw.as_deref()).map_err(sql_conversion_error)?,
+        catch_up: decode_catch_up(catch_up_raw.as_deref()).map_err(sql_conversion_error)?,
+        concurrency: decode_concurrency(concurrency_raw.as_deref())
+            .map_err(sql_conversion_error)?,
+        running: row.get(24)?,
         delete_after_run: row.get::<_, i64>(11)? != 0,
         source: source.unwrap_or_else(|| "imperative".to_string()),
         uses_memory: uses_memory != Some(0),
@@ -944,6 +1118,35 @@ fn decode_delivery(delivery_raw: Option<&str>) -> Result<DeliveryConfig> {
     Ok(DeliveryConfig::default())
 }
 
+fn decode_notify(notify_raw: Option<&str>) -> Result<Option<NotifyConfig>> {
+    let Some(raw) = notify_raw.map(str::trim).filter(|raw| !raw.is_empty()) else {
+        return Ok(None);
+    };
+    serde_json::from_str(raw).with_context(|| format!("Failed to parse cron notify JSON: {raw}"))
+}
+
+fn decode_catch_up(raw: Option<&str>) -> Result<Option<CatchUpPolicy>> {
+    raw.map(str::trim)
+        .filter(|raw| !raw.is_empty())
+        .map(|raw| CatchUpPolicy::try_from(raw).map_err(anyhow::Error::msg))
+        .transpose()
+}
+
+fn decode_concurrency(raw: Option<&str>) -> Result<ConcurrencyPolicy> {
+    raw.map(str::trim)
+        .filter(|raw| !raw.is_empty())
+        .map_or(Ok(ConcurrencyPolicy::default()), |raw| {
+            ConcurrencyPolicy::try_from(raw).map_err(anyhow::Error::msg)
+        })
+}
+
+fn encode_notify(notify: Option<&NotifyConfig>) -> Result<Option<String>> {
+    notify
+        .map(serde_json::to_string)
+        .transpose()
+        .context("Failed to serialize cron notify")
+}
+
 fn encode_allowed_tools(allowed_tools: Option<&Vec<String>>) -> Result<Option<String>> {
     allowed_tools
         .map(serde_json::to_string)
@@ -1040,6 +1243,20 @@ pub fn sync_declarative_jobs(
             let allowed_tools_json = encode_allowed_tools(decl.allowed_tools.as_ref())?;
             let command = decl.command.as_deref().unwrap_or("");
             let delete_after_run = matches!(decl.schedule, CronScheduleDecl::At { .. });
+            let catch_up = decl
+                .catch_up
+                .as_deref()
+                .map(CatchUpPolicy::try_from)
+                .transpose()
+                .map_err(anyhow::Error::msg)?
+                .map(|policy| policy.as_str());
+            let concurrency = decl
+                .concurrency
+                .as_deref()
+                .map(ConcurrencyPolicy::try_from)
+                .transpose()
+                .map_err(anyhow::Error::msg)?
+                .map(|policy| policy.as_str());
 
             // Check if job already exists.
             let exists: bool = conn
@@ -1067,8 +1284,8 @@ pub fn sync_declarative_jobs(
                              prompt = ?5, name = ?6, session_target = ?7, model = ?8,
                              enabled = ?9, delivery = ?10, delete_after_run = ?11,
                              allowed_tools = ?12, source = 'declarative', next_run = ?13,
-                             uses_memory = ?14
-                         WHERE id = ?15",
+                             uses_memory = ?14, catch_up = ?15, concurrency = ?16
+                         WHERE id = ?17",
                         params![
                             expression,
                             command,
@@ -1084,6 +1301,8 @@ pub fn sync_declarative_jobs(
                             allowed_tools_json,
                             next_run.to_rfc3339(),
                             i32::from(decl.uses_memory),
+                            catch_up,
+                            concurrency,
                             id,
                         ],
                     )
@@ -1095,8 +1314,8 @@ pub fn sync_declarative_jobs(
                              prompt = ?5, name = ?6, session_target = ?7, model = ?8,
                              enabled = ?9, delivery = ?10, delete_after_run = ?11,
                              allowed_tools = ?12, source = 'declarative',
-                             uses_memory = ?13
-                         WHERE id = ?14",
+                             uses_memory = ?13, catch_up = ?14, concurrency = ?15
+                         WHERE id = ?16",
                         params![
                             expression,
                             command,
@@ -1111,6 +1330,8 @@ pub fn sync_declarative_jobs(
                             i32::from(delete_after_run),
                             allowed_tools_json,
                             i32::from(decl.uses_memory),
+                            catch_up,
+                            concurrency,
                             id,
                         ],
                     )
@@ -1143,8 +1364,9 @@ pub fn sync_declarative_jobs(
                     "INSERT INTO cron_jobs (
                         id, expression, command, schedule, job_type, prompt, name,
                         session_target, model, enabled, delivery, delete_after_run,
-                        allowed_tools, source, uses_memory, agent_alias, created_at, next_run
-                     ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, 'declarative', ?14, ?15, ?16, ?17)",
+                        allowed_tools, source, uses_memory, agent_alias, created_at, next_run,
+                        catch_up, concurrency
+                     ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, 'declarative', ?14, ?15, ?16, ?17, ?18, ?19)",
                     params![
                         id,
                         expression,
@@ -1163,6 +1385,8 @@ pub fn sync_declarative_jobs(
                         agent_alias,
                         now.to_rfc3339(),
                         next_run.to_rfc3339(),
+                        catch_up,
+                        concurrency,
                     ],
                 )
                 .with_context(|| {
@@ -1188,6 +1412,18 @@ fn validate_decl(id: &str, decl: &zeroclaw_config::schema::CronJobDecl) -> Resul
         anyhow::bail!("Declarative cron job has empty id");
     }
 
+    if let Some(catch_up) = decl.catch_up.as_deref()
+        && let Err(e) = CatchUpPolicy::try_from(catch_up)
+    {
+        anyhow::bail!("Declarative cron job '{id}': {e}");
+    }
+
+    if let Some(concurrency) = decl.concurrency.as_deref()
+        && let Err(e) = ConcurrencyPolicy::try_from(concurrency)
+    {
+        anyhow::bail!("Declarative cron job '{id}': {e}");
+    }
+
     match decl.job_type.to_lowercase().as_str() {
         "shell" => {
             if decl.command.as_deref().is_none_or(|c| c.trim().is_empty()) {
@@ -1247,7 +1483,16 @@ fn convert_delivery_decl(decl: &zeroclaw_config::schema::DeliveryConfigDecl) ->
 }
 
 fn add_column_if_missing(conn: &Connection, name: &str, sql_type: &str) -> Result<()> {
-    let mut stmt = conn.prepare("PRAGMA table_info(cron_jobs)")?;
+    add_table_column_if_missing(conn, "cron_jobs", name, sql_type)
+}
+
+fn add_table_column_if_missing(
+    conn: &Connection,
+    table: &str,
+    name: &str,
+    sql_type: &str,
+) -> Result<()> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({table})"))?;
     let mut rows = stmt.query([])?;
     while let Some(row) = rows.next()? {
         let col_name: String = row.get(1)?;
@@ -1262,7 +1507,7 @@ fn add_column_if_missing(conn: &Connection, name: &str, sql_type: &str) -> Resul
     // Tolerate "duplicate column name" errors to handle the race where
     // another process adds the column between our PRAGMA check and ALTER.
     match conn.execute(
-        &format!("ALTER TABLE cron_jobs ADD COLUMN {name} {sql_type}"),
+        &format!("ALTER TABLE {table} ADD COLUMN {name} {sql_type}"),
         [],
     ) {
         Ok(_) => Ok(()),
@@ -1272,12 +1517,12 @@ fn add_column_if_missing(conn: &Connection, name: &str, sql_type: &str) -> Resul
             ::zeroclaw_log::record!(
                 DEBUG,
                 ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                    .with_attrs(::serde_json::json!({"error": format!("{}", err), "name": name})),
-                "Column cron_jobs. already exists (concurrent migration)"
+                    .with_attrs(::serde_json::json!({"error": format!("{}", err), "table": table, "name": name})),
+                "Cron column already exists (concurrent migration)"
             );
             Ok(())
         }
-        Err(e) => Err(e).with_context(|| format!("Failed to add cron_jobs.{name}")),
+        Err(e) => Err(e).with_context(|| format!("Failed to add {table}.{name}")),
     }
 }
 
@@ -1383,6 +1628,15 @@ fn apply_run_completion_state(
                 anyhow::bail!("Cron job '{}' not found", job.id);
             }
         }
+        RunCompletionAction::Record => {
+            apply_last_run_state(
+                conn,
+                &job.id,
+                job_state_at,
+                status,
+                bounded_output.as_deref().unwrap_or(""),
+            )?;
+        }
         RunCompletionAction::Disable => {
             let changed = conn
                 .execute(
@@ -1476,6 +1730,15 @@ fn initialize_schema(conn: &Connection) -> Result<()> {
     // runs longer than the poll interval cannot be launched again while still in
     // flight (see `claim_job`/`release_job` and
     add_column_if_missing(conn, "locked_at", "TEXT")?;
+    // Optional post-run notification target (JSON `NotifyConfig`).
+    add_column_if_missing(conn, "notify", "TEXT")?;
+    // Missed-run policy (`none`/`once`/`all`); NULL follows
+    // `[scheduler] catch_up_on_startup`.
+    add_column_if_missing(conn, "catch_up", "TEXT")?;
+    // Overlap policy (`allow`/`skip`/`queue`); NULL means `skip`.
+    add_column_if_missing(conn, "concurrency", "TEXT")?;
+    // Scheduled time a startup catch-up run made up for; NULL for regular runs.
+    add_table_column_if_missing(conn, "cron_runs", "catch_up_for", "TEXT")?;
 
     Ok(())
 }
@@ -1644,6 +1907,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn due_jobs_including_running_reports_in_flight_jobs() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let job = add_job(&config, "test-agent", "*/5 * * * *", "echo ok").unwrap();
+        force_due(&config, &job.id);
+        let now = Utc::now();
+        assert!(claim_job(&config, &job.id, now).unwrap());
+
+        let due = due_jobs_including_running(&config, now).unwrap();
+        assert_eq!(due.len(), 1);
+        assert!(due[0].running);
+        assert_eq!(due[0].concurrency, ConcurrencyPolicy::Skip);
+        assert!(get_job(&config, &job.id).unwrap().running);
+
+        release_job(&config, &job.id).unwrap();
+        assert!(!get_job(&config, &job.id).unwrap().running);
+    }
+
+    #[test]
+    fn record_skipped_run_writes_history_and_advances_next_run() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let job = add_job(&config, "test-agent", "*/5 * * * *", "echo ok").unwrap();
+        force_due(&config, &job.id);
+        let job = get_job(&config, &job.id).unwrap();
+        let now = Utc::now();
+
+        record_skipped_run(&config, &job, now, "previous run still in progress").unwrap();
+
+        let runs = list_runs(&config, &job.id, 10).unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].status, "skipped");
+        assert_eq!(
+            runs[0].output.as_deref(),
+            Some("previous run still in progress")
+        );
+        assert!(get_job(&config, &job.id).unwrap().next_run > now);
+    }
+
     #[test]
     fn clear_stale_locks_releases_in_flight_locks() {
         let tmp = TempDir::new().unwrap();
@@ -2087,6 +2390,121 @@ mod tests {
         );
     }
 
+    #[test]
+    fn update_job_persists_notify_patch() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let job = add_job(&config, "default", "*/5 * * * *", "echo ok").unwrap();
+        assert!(job.notify.is_none());
+
+        let notify = NotifyConfig {
+            channel: "telegram".into(),
+            recipient: "12345".into(),
+            on: crate::cron::NotifyOn::Failure,
+        };
+        update_job(
+            &config,
+            &job.id,
+            CronJobPatch {
+                notify: Some(notify.clone()),
+                ..CronJobPatch::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(get_job(&config, &job.id).unwrap().notify, Some(notify));
+        assert!(
+            update_job(
+                &config,
+                &job.id,
+                CronJobPatch {
+                    notify: Some(NotifyConfig {
+                        channel: "telegram".into(),
+                        recipient: " ".into(),
+                        on: crate::cron::NotifyOn::Always,
+                    }),
+                    ..CronJobPatch::default()
+                },
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn new_job_options_are_written_with_the_job() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let notify = NotifyConfig {
+            channel: "telegram".into(),
+            recipient: "12345".into(),
+            on: crate::cron::NotifyOn::Failure,
+        };
+        let schedule = Schedule::Cron {
+            expr: "*/5 * * * *".into(),
+            tz: None,
+        };
+
+        let job = add_shell_job_with_options(
+            &config,
+            "default",
+            None,
+            schedule.clone(),
+            "echo ok",
+            None,
+            NewJobOptions {
+                notify: Some(notify.clone()),
+                catch_up: Some(CatchUpPolicy::Once),
+                concurrency: Some(ConcurrencyPolicy::Queue),
+            },
+        )
+        .unwrap();
+        assert_eq!(job.notify, Some(notify.clone()));
+        assert_eq!(job.catch_up, Some(CatchUpPolicy::Once));
+        assert_eq!(job.concurrency, ConcurrencyPolicy::Queue);
+
+        let job = add_agent_job_with_options(
+            &config,
+            "default",
+            None,
+            schedule.clone(),
+            "summarize",
+            SessionTarget::Isolated,
+            None,
+            None,
+            false,
+            None,
+            true,
+            NewJobOptions {
+                notify: Some(notify),
+                ..NewJobOptions::default()
+            },
+        )
+        .unwrap();
+        assert!(job.notify.is_some());
+        assert_eq!(job.concurrency, ConcurrencyPolicy::default());
+
+        let blank_recipient = NewJobOptions {
+            notify: Some(NotifyConfig {
+                channel: "telegram".into(),
+                recipient: " ".into(),
+                on: crate::cron::NotifyOn::Always,
+            }),
+            ..NewJobOptions::default()
+        };
+        assert!(
+            add_shell_job_with_options(
+                &config,
+                "default",
+                None,
+                schedule,
+                "echo ok",
+                None,
+                blank_recipient,
+            )
+            .is_err()
+        );
+    }
+
     #[test]
     fn reschedule_after_run_persists_last_status_and_last_run() {
         let tmp = TempDir::new().unwrap();
@@ -2340,6 +2758,8 @@ mod tests {
                 uses_memory: true,
                 session_target: None,
                 delivery: None,
+                catch_up: None,
+                concurrency: None,
             },
         )
     }
@@ -2366,6 +2786,8 @@ mod tests {
                 uses_memory: true,
                 session_target: None,
                 delivery: None,
+                catch_up: None,
+                concurrency: None,
             },
         )
     }
@@ -2553,6 +2975,8 @@ mod tests {
             uses_memory: true,
             session_target: None,
             delivery: None,
+            catch_up: None,
+            concurrency: None,
         };
 
         let mut decls = std::collections::HashMap::new();
@@ -2694,6 +3118,10 @@ schedule = { kind = "every", every_ms = 300000 }
             agent_alias: agent_alias.to_string(),
             enabled: true,
             delivery: DeliveryConfig::default(),
+            notify: None,
+            catch_up: None,
+            concurrency: ConcurrencyPolicy::default(),
+            running: false,
             delete_after_run: false,
             allowed_tools: None,
             uses_memory: false,
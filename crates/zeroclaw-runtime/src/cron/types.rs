@@ -121,6 +121,157 @@ impl Default for DeliveryConfig {
     }
 }
 
+/// When a job's [`NotifyConfig`] fires.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifyOn {
+    /// After every run, with the (truncated) output.
+    #[default]
+    Always,
+    /// Only after failed runs, with a failure summary.
+    Failure,
+}
+
+impl NotifyOn {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Always => "always",
+            Self::Failure => "failure",
+        }
+    }
+}
+
+impl TryFrom<&str> for NotifyOn {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_lowercase().as_str() {
+            "always" => Ok(NotifyOn::Always),
+            "failure" => Ok(NotifyOn::Failure),
+            _ => Err(format!(
+                "Invalid notify trigger '{}'. Expected one of: 'always', 'failure'",
+                value
+            )),
+        }
+    }
+}
+
+/// Post-run notification target. Unlike [`DeliveryConfig`] announce mode,
+/// a failed notification never changes the job's recorded status.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NotifyConfig {
+    /// Channel type (`telegram`, resolved to its `default` alias) or a
+    /// dotted `<type>.<alias>` ref.
+    pub channel: String,
+    pub recipient: String,
+    #[serde(default)]
+    pub on: NotifyOn,
+}
+
+impl NotifyConfig {
+    /// Dotted `<type>.<alias>` ref for the delivery handler. A bare type
+    /// resolves to the `default` alias, matching `zeroclaw channel send`.
+    pub fn channel_ref(&self) -> String {
+        let channel = self.channel.trim();
+        if channel.contains('.') {
+            channel.to_string()
+        } else {
+            format!("{channel}.default")
+        }
+    }
+}
+
+/// What the scheduler does at startup with occurrences of a job that fell
+/// inside a daemon outage.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CatchUpPolicy {
+    /// Skip missed occurrences and wait for the next scheduled one.
+    None,
+    /// Run the most recent missed occurrence once.
+    Once,
+    /// Replay every missed occurrence, oldest first, up to
+    /// `[scheduler] max_catchup_runs`.
+    All,
+}
+
+impl CatchUpPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Once => "once",
+            Self::All => "all",
+        }
+    }
+
+    /// Policy for jobs without an explicit `catch_up`, following
+    /// `[scheduler] catch_up_on_startup`.
+    pub fn from_startup_default(catch_up_on_startup: bool) -> Self {
+        if catch_up_on_startup {
+            Self::Once
+        } else {
+            Self::None
+        }
+    }
+}
+
+impl TryFrom<&str> for CatchUpPolicy {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_lowercase().as_str() {
+            "none" => Ok(CatchUpPolicy::None),
+            "once" => Ok(CatchUpPolicy::Once),
+            "all" => Ok(CatchUpPolicy::All),
+            _ => Err(format!(
+                "Invalid catch-up policy '{}'. Expected one of: 'none', 'once', 'all'",
+                value
+            )),
+        }
+    }
+}
+
+/// What the scheduler does when a job comes due while its previous run is
+/// still going.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ConcurrencyPolicy {
+    /// Start another run alongside the one in flight.
+    Allow,
+    /// Drop the occurrence and record it in run history as skipped.
+    #[default]
+    Skip,
+    /// Run the occurrence as soon as the current run finishes, holding at
+    /// most `[scheduler] max_queued_runs` waiting occurrences.
+    Queue,
+}
+
+impl ConcurrencyPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Skip => "skip",
+            Self::Queue => "queue",
+        }
+    }
+}
+
+impl TryFrom<&str> for ConcurrencyPolicy {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_lowercase().as_str() {
+            "allow" => Ok(ConcurrencyPolicy::Allow),
+            "skip" => Ok(ConcurrencyPolicy::Skip),
+            "queue" => Ok(ConcurrencyPolicy::Queue),
+            _ => Err(format!(
+                "Invalid concurrency policy '{}'. Expected one of: 'allow', 'skip', 'queue'",
+                value
+            )),
+        }
+    }
+}
+
 pub fn default_true() -> bool {
     true
 }
@@ -148,6 +299,18 @@ pub struct CronJob {
     pub agent_alias: String,
     pub enabled: bool,
     pub delivery: DeliveryConfig,
+    /// Optional post-run notification; see [`NotifyConfig`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify: Option<NotifyConfig>,
+    /// Missed-run policy; `None` follows `[scheduler] catch_up_on_startup`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catch_up: Option<CatchUpPolicy>,
+    /// Overlap policy when the job comes due while a run is in flight.
+    #[serde(default)]
+    pub concurrency: ConcurrencyPolicy,
+    /// Whether a run of this job holds the in-flight lock right now.
+    #[serde(default)]
+    pub running: bool,
     pub delete_after_run: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allowed_tools: Option<Vec<String>>,
@@ -175,6 +338,10 @@ pub struct CronRun {
     pub status: String,
     pub output: Option<String>,
     pub duration_ms: Option<i64>,
+    /// Scheduled time of the missed occurrence this run made up for at
+    /// startup; `None` for regular and manual runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catch_up_for: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -185,6 +352,9 @@ pub struct CronJobPatch {
     pub name: Option<String>,
     pub enabled: Option<bool>,
     pub delivery: Option<DeliveryConfig>,
+    pub notify: Option<NotifyConfig>,
+    pub catch_up: Option<CatchUpPolicy>,
+    pub concurrency: Option<ConcurrencyPolicy>,
     pub model: Option<String>,
     pub session_target: Option<SessionTarget>,
     pub delete_after_run: Option<bool>,
@@ -192,6 +362,15 @@ pub struct CronJobPatch {
     pub uses_memory: Option<bool>,
 }
 
+/// Optional settings for a new job, written in the same insert as the job
+/// itself.
+#[derive(Debug, Clone, Default)]
+pub struct NewJobOptions {
+    pub notify: Option<NotifyConfig>,
+    pub catch_up: Option<CatchUpPolicy>,
+    pub concurrency: Option<ConcurrencyPolicy>,
+}
+
 impl ::zeroclaw_api::attribution::Attributable for CronJob {
     fn role(&self) -> ::zeroclaw_api::attribution::Role {
         let kind = match self.schedule {
@@ -262,4 +441,51 @@ mod tests {
         assert!(JobType::try_from("").is_err());
         assert!(JobType::try_from("unknown").is_err());
     }
+
+    #[test]
+    fn notify_on_parses_known_values() {
+        assert_eq!(NotifyOn::try_from("always").unwrap(), NotifyOn::Always);
+        assert_eq!(NotifyOn::try_from("FAILURE").unwrap(), NotifyOn::Failure);
+        assert!(NotifyOn::try_from("success").is_err());
+    }
+
+    #[test]
+    fn catch_up_policy_parses_known_values() {
+        assert_eq!(
+            CatchUpPolicy::try_from("none").unwrap(),
+            CatchUpPolicy::None
+        );
+        assert_eq!(
+            CatchUpPolicy::try_from(" ONCE ").unwrap(),
+            CatchUpPolicy::Once
+        );
+        assert_eq!(CatchUpPolicy::try_from("all").unwrap(), CatchUpPolicy::All);
+        assert!(CatchUpPolicy::try_from("some").is_err());
+    }
+
+    #[test]
+    fn concurrency_policy_parses_known_values_and_defaults_to_skip() {
+        assert_eq!(
+            ConcurrencyPolicy::try_from("allow").unwrap(),
+            ConcurrencyPolicy::Allow
+        );
+        assert_eq!(
+            ConcurrencyPolicy::try_from(" QUEUE ").unwrap(),
+            ConcurrencyPolicy::Queue
+        );
+        assert_eq!(ConcurrencyPolicy::default(), ConcurrencyPolicy::Skip);
+        assert!(ConcurrencyPolicy::try_from("parallel").is_err());
+    }
+
+    #[test]
+    fn notify_channel_ref_defaults_bare_type_to_default_alias() {
+        let mut notify = NotifyConfig {
+            channel: "telegram".into(),
+            recipient: "12345".into(),
+            on: NotifyOn::Always,
+        };
+        assert_eq!(notify.channel_ref(), "telegram.default");
+        notify.channel = "telegram.work".into();
+        assert_eq!(notify.channel_ref(), "telegram.work");
+    }
 }
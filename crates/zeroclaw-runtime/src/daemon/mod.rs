@@ -18,6 +18,10 @@ pub enum DaemonExit {
 
 const EPHEMERAL_GRACE_SECS: u64 = 1;
 
+/// How long a run the self-heal watchdog restarts gets to wind down after
+/// its cancellation token fires before its task is aborted.
+const SELFHEAL_RESTART_GRACE: Duration = Duration::from_secs(5);
+
 #[cfg(test)]
 static SCHEDULER_CLEAN_SHUTDOWN_OBSERVED: std::sync::atomic::AtomicBool =
     std::sync::atomic::AtomicBool::new(false);
@@ -303,6 +307,23 @@ pub async fn run(
         config.gateway.port = port;
     }
 
+    // The loader already logged unknown keys as warnings; with
+    // `strict_config` any lint finding keeps the daemon from starting.
+    if config.strict_config
+        && let Ok(raw) = tokio::fs::read_to_string(&config.config_path).await
+    {
+        let issues = zeroclaw_config::lint::lint_config_toml(&raw);
+        if !issues.is_empty() {
+            let report: Vec<String> = issues.iter().map(ToString::to_string).collect();
+            anyhow::bail!(
+                "strict_config is set and {} has {} issue(s):\n  {}\nRun `zeroclaw config validate` for details.",
+                config.config_path.display(),
+                issues.len(),
+                report.join("\n  ")
+            );
+        }
+    }
+
     let initial_backoff = config.reliability.channel_initial_backoff_secs.max(1);
     let max_backoff = config
         .reliability
@@ -311,6 +332,13 @@ pub async fn run(
 
     crate::health::mark_component_ok("daemon");
 
+    install_approval_queue(&config);
+
+    // Count activity for the daily digest from the first channel message on.
+    if config.observability.digest.enabled {
+        crate::observability::activity::install(&config.data_dir);
+    }
+
     // Shared broadcast channel so all daemon components (gateway, cron,
     // heartbeat) can publish real-time events to dashboard clients.
     let (event_tx, _rx) = tokio::sync::broadcast::channel::<serde_json::Value>(256);
@@ -327,9 +355,16 @@ pub async fn run(
     }
 
     crate::agent::pricing_catalog::load_global_pricing_catalog(&config.data_dir);
+    zeroclaw_providers::capabilities::load_catalog(&config.data_dir);
 
     let mut handles: Vec<JoinHandle<()>> = vec![spawn_state_writer(config.clone())];
 
+    // Supervisors the `[health.selfheal]` watchdog may restart.
+    let selfheal_enabled = config.health.selfheal.enabled;
+    let restart_triggers = crate::health::selfheal::RestartTriggers::default();
+    let restart_trigger =
+        |component: &'static str| selfheal_enabled.then(|| restart_triggers.register(component));
+
     // Reload channel: gateway's /admin/reload writes here; our wait loop
     // (below) selects on it alongside OS signals. Cross-platform.
     let (reload_tx, reload_rx) = tokio::sync::watch::channel::<bool>(false);
@@ -406,17 +441,24 @@ pub async fn run(
         if has_supervised_channels(&config) {
             let channels_cfg = config.clone();
             let channels_start = std::sync::Arc::new(channels_start);
-            let cancel_for_supervisor = channels_cancel.clone();
-            handles.push(spawn_component_supervisor(
-                "channels",
+            handles.push(spawn_restartable_supervisor(
+                crate::health::selfheal::CHANNELS_TARGET,
                 initial_backoff,
                 max_backoff,
                 channels_cancel.clone(),
-                move || {
+                restart_trigger(crate::health::selfheal::CHANNELS_TARGET),
+                move |cancel, run_start| {
                     let cfg = channels_cfg.clone();
                     let start = channels_start.clone();
-                    let cancel = cancel_for_supervisor.clone();
-                    async move { start(cfg, cancel).await }
+                    async move {
+                        // A self-heal restart rebuilds the channels from the
+                        // config on disk so rotated tokens are picked up.
+                        let cfg = match run_start {
+                            RunStart::SelfHeal => reload_channels_config(cfg).await,
+                            RunStart::Normal => cfg,
+                        };
+                        start(cfg, cancel).await
+                    }
                 },
             ));
         } else {
@@ -664,19 +706,50 @@ pub async fn run(
         ));
     }
 
+    if config.quiet_hours.is_enabled() {
+        let quiet_cfg = config.clone();
+        let quiet_cancel = channels_cancel.clone();
+        handles.push(spawn_component_supervisor(
+            crate::cron::quiet_hours::QUIET_HOURS_COMPONENT,
+            initial_backoff,
+            max_backoff,
+            channels_cancel.clone(),
+            move || {
+                let cfg = quiet_cfg.clone();
+                let cancel = quiet_cancel.clone();
+                async move { crate::cron::quiet_hours::run(cfg, cancel).await }
+            },
+        ));
+    }
+
+    if config.observability.digest.enabled {
+        let digest_cfg = config.clone();
+        let digest_cancel = channels_cancel.clone();
+        handles.push(spawn_component_supervisor(
+            crate::observability::digest::DIGEST_COMPONENT,
+            initial_backoff,
+            max_backoff,
+            channels_cancel.clone(),
+            move || {
+                let cfg = digest_cfg.clone();
+                let cancel = digest_cancel.clone();
+                async move { crate::observability::digest::run(cfg, cancel).await }
+            },
+        ));
+    }
+
     if config.scheduler.enabled {
         let scheduler_cfg = config.clone();
         let scheduler_event_tx = event_tx.clone();
-        let scheduler_cancel = channels_cancel.clone();
-        handles.push(spawn_component_supervisor(
-            "scheduler",
+        handles.push(spawn_restartable_supervisor(
+            crate::health::selfheal::SCHEDULER_TARGET,
             initial_backoff,
             max_backoff,
             channels_cancel.clone(),
-            move || {
+            restart_trigger(crate::health::selfheal::SCHEDULER_TARGET),
+            move |cancel, _| {
                 let cfg = scheduler_cfg.clone();
                 let tx = scheduler_event_tx.clone();
-                let cancel = scheduler_cancel.clone();
                 async move { Box::pin(crate::cron::scheduler::run(cfg, Some(tx), cancel)).await }
             },
         ));
@@ -689,6 +762,65 @@ pub async fn run(
         );
     }
 
+    if config.tasks.enabled {
+        let tasks_cfg = config.clone();
+        let tasks_cancel = channels_cancel.clone();
+        handles.push(spawn_component_supervisor(
+            crate::tasks::worker::TASKS_COMPONENT,
+            initial_backoff,
+            max_backoff,
+            channels_cancel.clone(),
+            move || {
+                let cfg = tasks_cfg.clone();
+                let cancel = tasks_cancel.clone();
+                async move { Box::pin(crate::tasks::worker::run(cfg, cancel)).await }
+            },
+        ));
+    } else {
+        crate::health::mark_component_ok(crate::tasks::worker::TASKS_COMPONENT);
+        ::zeroclaw_log::record!(
+            INFO,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
+            "Background tasks disabled; tasks supervisor not started"
+        );
+    }
+
+    if config.watchers.iter().any(|w| w.enabled) {
+        let watchers_cfg = config.clone();
+        let watchers_cancel = channels_cancel.clone();
+        handles.push(spawn_component_supervisor(
+            crate::watchers::WATCHERS_COMPONENT,
+            initial_backoff,
+            max_backoff,
+            channels_cancel.clone(),
+            move || {
+                let cfg = watchers_cfg.clone();
+                let cancel = watchers_cancel.clone();
+                async move { Box::pin(crate::watchers::run(cfg, cancel)).await }
+            },
+        ));
+    } else {
+        crate::health::mark_component_ok(crate::watchers::WATCHERS_COMPONENT);
+    }
+
+    if selfheal_enabled {
+        let selfheal_cfg = config.clone();
+        let selfheal_cancel = channels_cancel.clone();
+        let triggers = restart_triggers.clone();
+        handles.push(spawn_component_supervisor(
+            crate::health::selfheal::SELFHEAL_COMPONENT,
+            initial_backoff,
+            max_backoff,
+            channels_cancel.clone(),
+            move || {
+                let cfg = selfheal_cfg.clone();
+                let triggers = triggers.clone();
+                let cancel = selfheal_cancel.clone();
+                async move { crate::health::selfheal::run(cfg, triggers, cancel).await }
+            },
+        ));
+    }
+
     record_daemon_started(&config, &host, port);
 
     // Wait for shutdown (SIGINT/SIGTERM/Ctrl+C) or reload (in-process channel).
@@ -702,6 +834,7 @@ pub async fn run(
     );
 
     channels_cancel.cancel();
+    crate::approval::queue::install(None);
 
     const GRACE_WINDOW: Duration = Duration::from_millis(500);
     let deadline = tokio::time::Instant::now() + GRACE_WINDOW;
@@ -759,6 +892,33 @@ fn record_daemon_started(config: &Config, host: &str, port: u16) {
     );
 }
 
+/// Install the `[approvals]` queue channel turns wait on, cancelling
+/// requests a previous daemon left pending (their tool calls are gone).
+fn install_approval_queue(config: &Config) {
+    if !config.approvals.enabled {
+        crate::approval::queue::install(None);
+        return;
+    }
+    let queue = crate::approval::queue::ApprovalQueue::new(config);
+    match queue.recover_stale() {
+        Ok(0) => {}
+        Ok(cancelled) => ::zeroclaw_log::record!(
+            INFO,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Cancel)
+                .with_attrs(::serde_json::json!({"cancelled": cancelled})),
+            "Cancelled approval requests left pending by a previous daemon"
+        ),
+        Err(e) => ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Load)
+                .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+            "Failed to recover the approval queue"
+        ),
+    }
+    crate::approval::queue::install(Some(std::sync::Arc::new(queue)));
+}
+
 fn spawn_state_writer(config: Config) -> JoinHandle<()> {
     zeroclaw_spawn::spawn!(async move {
         let path = state_file_path(&config);
@@ -792,17 +952,78 @@ fn spawn_component_supervisor<F, Fut>(
 where
     F: FnMut() -> Fut + Send + 'static,
     Fut: Future<Output = Result<()>> + Send + 'static,
+{
+    spawn_restartable_supervisor(
+        name,
+        initial_backoff_secs,
+        max_backoff_secs,
+        cancel,
+        None,
+        move |_, _| run_component(),
+    )
+}
+
+/// Why a supervised run is starting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RunStart {
+    /// First run, or a retry after the previous run exited or failed.
+    Normal,
+    /// Respawn requested by the self-heal watchdog.
+    SelfHeal,
+}
+
+/// [`spawn_component_supervisor`] for components the self-heal watchdog may
+/// restart. Each run gets a child of `cancel`; with a `restart` trigger the
+/// run is driven in its own task so a restart can cancel it and, after
+/// [`SELFHEAL_RESTART_GRACE`], abort it even when it is wedged.
+fn spawn_restartable_supervisor<F, Fut>(
+    name: &'static str,
+    initial_backoff_secs: u64,
+    max_backoff_secs: u64,
+    cancel: tokio_util::sync::CancellationToken,
+    mut restart: Option<tokio::sync::watch::Receiver<u64>>,
+    mut run_component: F,
+) -> JoinHandle<()>
+where
+    F: FnMut(tokio_util::sync::CancellationToken, RunStart) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<()>> + Send + 'static,
 {
     zeroclaw_spawn::spawn!(async move {
         let mut backoff = initial_backoff_secs.max(1);
         let max_backoff = max_backoff_secs.max(backoff);
 
         let stable_run = Duration::from_secs(initial_backoff_secs.max(1).saturating_mul(5));
+        let mut run_start = RunStart::Normal;
 
         loop {
             crate::health::mark_component_ok(name);
             let run_started = std::time::Instant::now();
-            let outcome = run_component().await;
+            let run_cancel = cancel.child_token();
+            let run = run_component(run_cancel.clone(), run_start);
+            let outcome = match restart.as_mut() {
+                None => run.await,
+                Some(restart) => {
+                    let Some(outcome) = run_until_restart(run, &run_cancel, restart).await else {
+                        ::zeroclaw_log::record!(
+                            WARN,
+                            ::zeroclaw_log::Event::new(
+                                module_path!(),
+                                ::zeroclaw_log::Action::Retry
+                            )
+                            .with_attrs(::serde_json::json!({
+                                "name": name,
+                                "ran_for_secs": run_started.elapsed().as_secs(),
+                            })),
+                            &format!("Daemon component '{name}' restarted by self-heal")
+                        );
+                        crate::health::bump_component_restart(name);
+                        run_start = RunStart::SelfHeal;
+                        continue;
+                    };
+                    outcome
+                }
+            };
+            run_start = RunStart::Normal;
             let ran_for = run_started.elapsed();
             match outcome {
                 Ok(()) => {
@@ -874,6 +1095,68 @@ where
     })
 }
 
+/// Drive one supervised run in its own task until it finishes or a restart
+/// is requested. On restart the run's token is cancelled and the task is
+/// aborted if it has not wound down within [`SELFHEAL_RESTART_GRACE`];
+/// returns `None`.
+async fn run_until_restart<Fut>(
+    run: Fut,
+    run_cancel: &tokio_util::sync::CancellationToken,
+    restart: &mut tokio::sync::watch::Receiver<u64>,
+) -> Option<Result<()>>
+where
+    Fut: Future<Output = Result<()>> + Send + 'static,
+{
+    /// Aborts the run if the supervisor itself is aborted at shutdown.
+    struct AbortOnDrop(tokio::task::AbortHandle);
+    impl Drop for AbortOnDrop {
+        fn drop(&mut self) {
+            self.0.abort();
+        }
+    }
+
+    // Requests made while the previous run was backing off are moot: this
+    // run starts fresh anyway.
+    restart.borrow_and_update();
+    let mut task = zeroclaw_spawn::spawn!(run);
+    let _guard = AbortOnDrop(task.abort_handle());
+    tokio::select! {
+        joined = &mut task => Some(
+            joined.unwrap_or_else(|e| Err(anyhow::anyhow!("component task ended abnormally: {e}"))),
+        ),
+        () = crate::health::selfheal::restart_requested(restart) => {
+            run_cancel.cancel();
+            if tokio::time::timeout(SELFHEAL_RESTART_GRACE, &mut task).await.is_err() {
+                task.abort();
+            }
+            None
+        }
+    }
+}
+
+/// Config for a channels run restarted by self-heal: the config on disk,
+/// keeping this daemon's gateway bind, or `fallback` when it cannot be
+/// loaded.
+async fn reload_channels_config(fallback: Config) -> Config {
+    match Box::pin(Config::load_or_init()).await {
+        Ok(mut fresh) => {
+            fresh.gateway.host = fallback.gateway.host.clone();
+            fresh.gateway.port = fallback.gateway.port;
+            fresh
+        }
+        Err(e) => {
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Load)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                "Self-heal could not reload config; restarting channels with the running config"
+            );
+            fallback
+        }
+    }
+}
+
 fn resolve_heartbeat_workspace_dir(config: &Config) -> Result<(String, PathBuf)> {
     let agent_alias = config.heartbeat.agent.trim().to_string();
     if agent_alias.is_empty() {
@@ -1447,6 +1730,26 @@ async fn run_heartbeat_worker(config: Config) -> Result<()> {
 
         // Collect runnable tasks (active only, sorted by priority)
         let mut tasks = engine.collect_runnable_tasks().await?;
+
+        // ── Quiet hours: only high-priority tasks run inside the window;
+        //    the rest stay in HEARTBEAT.md and run on the first tick after.
+        let quiet_until =
+            crate::cron::quiet_hours::window_end(&config.quiet_hours, chrono::Utc::now());
+        if let Some(until) = quiet_until {
+            let before = tasks.len();
+            tasks.retain(|t| t.priority == TaskPriority::High);
+            if tasks.len() < before {
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_attrs(::serde_json::json!({
+                            "deferred": before - tasks.len(),
+                            "until": until.to_rfc3339(),
+                        })),
+                    "heartbeat: quiet hours, deferring non-critical tasks"
+                );
+            }
+        }
         let has_high_priority = tasks.iter().any(|t| t.priority == TaskPriority::High);
 
         if tasks.is_empty() {
@@ -1455,7 +1758,7 @@ async fn run_heartbeat_worker(config: Config) -> Result<()> {
                 .message
                 .as_deref()
                 .map(str::trim)
-                .filter(|m| !m.is_empty())
+                .filter(|m| !m.is_empty() && quiet_until.is_none())
             {
                 tasks.push(HeartbeatTask {
                     text: fallback.to_string(),
@@ -1713,12 +2016,13 @@ async fn run_heartbeat_worker(config: Config) -> Result<()> {
                     {
                         let delivery_result = tokio::time::timeout(
                             Duration::from_secs(30),
-                            crate::cron::scheduler::deliver_announcement(
+                            crate::cron::quiet_hours::deliver_scheduled(
                                 &config,
                                 channel,
                                 target,
                                 None,
                                 &announcement,
+                                task.priority == TaskPriority::High,
                             ),
                         )
                         .await;
@@ -2393,9 +2697,13 @@ mod tests {
                 intents_mask: None,
                 reaction_notifications: zeroclaw_config::schema::DiscordReactionScope::Off,
                 interrupt_on_new_message: false,
+                respond_to_edits: false,
+                edit_window_secs: 0,
+                reply_context: false,
                 archive: false,
                 approval_timeout_secs: 0,
                 proxy_url: None,
+                translate: None,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -2419,9 +2727,13 @@ mod tests {
                 intents_mask: None,
                 reaction_notifications: zeroclaw_config::schema::DiscordReactionScope::Off,
                 interrupt_on_new_message: false,
+                respond_to_edits: false,
+                edit_window_secs: 0,
+                reply_context: false,
                 archive: false,
                 approval_timeout_secs: 0,
                 proxy_url: None,
+                translate: None,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -2443,9 +2755,11 @@ mod tests {
                 draft_update_interval_ms: 1000,
                 interrupt_on_new_message: false,
                 mention_only: false,
+                allowed_topics: Vec::new(),
                 ack_reactions: None,
                 proxy_url: None,
                 approval_timeout_secs: 120,
+                translate: None,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -2466,6 +2780,7 @@ mod tests {
                 client_secret: "client_secret".into(),
                 proxy_url: None,
                 excluded_tools: vec![],
+                rich_text: Default::default(),
             },
         );
         assert!(has_supervised_channels(&config));
@@ -2490,6 +2805,8 @@ mod tests {
                 interrupt_on_new_message: false,
                 proxy_url: None,
                 listen_mode: MattermostListenMode::default(),
+                stream_drafts: false,
+                draft_update_interval_ms: 1000,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -2547,6 +2864,8 @@ mod tests {
                 send_method: None,
                 auth_header: None,
                 secret: None,
+                template: None,
+                sync_response: false,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -2685,9 +3004,11 @@ mod tests {
                 draft_update_interval_ms: 1000,
                 interrupt_on_new_message: false,
                 mention_only: false,
+                allowed_topics: Vec::new(),
                 ack_reactions: None,
                 proxy_url: None,
                 approval_timeout_secs: 120,
+                translate: None,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -2714,9 +3035,11 @@ mod tests {
                 draft_update_interval_ms: 1000,
                 interrupt_on_new_message: false,
                 mention_only: false,
+                allowed_topics: Vec::new(),
                 ack_reactions: None,
                 proxy_url: None,
                 approval_timeout_secs: 120,
+                translate: None,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -0,0 +1,594 @@
+//! `zeroclaw doctor providers`: check every provider's credentials with a
+//! minimal authenticated call and report latency and, where the API
+//! exposes it, remaining quota.
+//!
+//! The auth probe is the same catalog fetch `doctor models` uses, so no
+//! tokens are spent. Catalog endpoints that don't check the key (OpenRouter
+//! serves `/models` to anyone) are backed by the quota call, which does.
+
+use super::{
+    Severity, configured_model_provider_api_key, doctor_model_targets, effective_provider_proxy,
+    fetch_provider_catalog, format_error_chain, truncate_for_display,
+};
+use anyhow::Result;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use zeroclaw_config::schema::{Config, ModelProviderConfig};
+
+/// Upper bound for one provider's auth probe.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Remaining quota below this fraction of the limit is flagged as low.
+const LOW_QUOTA_FRACTION: f64 = 0.1;
+
+const OPENROUTER_API: &str = "https://openrouter.ai/api/v1";
+const ANTHROPIC_API: &str = "https://api.anthropic.com";
+
+/// Result of checking one provider entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProviderStatus {
+    Ok,
+    MissingKey,
+    InvalidKey,
+    QuotaExhausted,
+    RateLimited,
+    Network,
+    /// The provider has no authenticated endpoint to probe.
+    Unsupported,
+    Error,
+}
+
+impl ProviderStatus {
+    fn severity(self) -> Severity {
+        match self {
+            Self::Ok => Severity::Ok,
+            Self::RateLimited | Self::Unsupported => Severity::Warn,
+            _ => Severity::Error,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::MissingKey => "missing key",
+            Self::InvalidKey => "invalid key",
+            Self::QuotaExhausted => "quota exhausted",
+            Self::RateLimited => "rate limited",
+            Self::Network => "network",
+            Self::Unsupported => "skipped",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// One remaining-quota figure reported by a provider.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QuotaReading {
+    /// What is being counted: `credits_usd`, `requests`, `tokens`, ...
+    pub metric: String,
+    pub remaining: f64,
+    pub limit: Option<f64>,
+}
+
+impl QuotaReading {
+    fn is_exhausted(&self) -> bool {
+        self.remaining <= 0.0
+    }
+
+    fn is_low(&self) -> bool {
+        self.limit
+            .is_some_and(|limit| limit > 0.0 && self.remaining / limit < LOW_QUOTA_FRACTION)
+    }
+}
+
+/// Outcome of checking one provider, as printed by `--json`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderCheck {
+    /// `type.alias`, or a bare family probed from a native env var.
+    pub provider: String,
+    pub status: ProviderStatus,
+    /// `config` or `$ENV_VAR`; `None` when no key was found.
+    pub key_source: Option<String>,
+    pub latency_ms: Option<u64>,
+    pub models: Option<usize>,
+    pub quota: Vec<QuotaReading>,
+    pub detail: Option<String>,
+    /// What to change to fix a failure, naming the config key or env var.
+    pub fix: Option<String>,
+}
+
+/// Map a probe error to a status. Quota wording is checked before auth
+/// wording because some providers answer an empty balance with a 401/403.
+fn classify_provider_error(text: &str) -> ProviderStatus {
+    let lower = text.to_lowercase();
+    let has = |hints: &[&str]| hints.iter().any(|hint| lower.contains(hint));
+
+    if lower.contains("does not support live model discovery") {
+        ProviderStatus::Unsupported
+    } else if has(&[
+        "insufficient_quota",
+        "insufficient quota",
+        "insufficient balance",
+        "exceeded your current quota",
+        "credit balance is too low",
+        "payment required",
+        "402",
+    ]) {
+        ProviderStatus::QuotaExhausted
+    } else if has(&[
+        "401",
+        "403",
+        "unauthorized",
+        "forbidden",
+        "invalid api key",
+        "invalid_api_key",
+        "invalid x-api-key",
+        "authentication",
+    ]) {
+        ProviderStatus::InvalidKey
+    } else if has(&["429", "rate limit", "too many requests"]) {
+        ProviderStatus::RateLimited
+    } else if has(&[
+        "error sending request",
+        "connection",
+        "dns",
+        "timed out",
+        "timeout",
+        "tls",
+        "unreachable",
+    ]) {
+        ProviderStatus::Network
+    } else {
+        ProviderStatus::Error
+    }
+}
+
+/// Config path of a field on a provider entry.
+fn entry_key(provider_ref: &str, field: &str) -> String {
+    let (family, alias) = provider_ref
+        .split_once('.')
+        .unwrap_or((provider_ref, "default"));
+    format!("providers.models.{family}.{alias}.{field}")
+}
+
+fn fix_for(config: &Config, provider_ref: &str, status: ProviderStatus) -> Option<String> {
+    let family = provider_ref.split('.').next().unwrap_or(provider_ref);
+    let api_key = entry_key(provider_ref, "api_key");
+    let text = match status {
+        ProviderStatus::Ok | ProviderStatus::Unsupported | ProviderStatus::Error => return None,
+        ProviderStatus::MissingKey | ProviderStatus::InvalidKey => {
+            // Only the native bridges are read at runtime; other exports are ignored.
+            match zeroclaw_providers::native_api_key_env_vars(family).first() {
+                Some(var) => crate::i18n::get_required_cli_string_with_args(
+                    "cli-doctor-provider-fix-key-env",
+                    &[("config_key", &api_key), ("env_var", var)],
+                ),
+                None => crate::i18n::get_required_cli_string_with_args(
+                    "cli-doctor-provider-fix-key",
+                    &[("config_key", &api_key)],
+                ),
+            }
+        }
+        ProviderStatus::QuotaExhausted => crate::i18n::get_required_cli_string_with_args(
+            "cli-doctor-provider-fix-quota",
+            &[("config_key", &api_key)],
+        ),
+        ProviderStatus::RateLimited => {
+            crate::i18n::get_required_cli_string("cli-doctor-provider-fix-rate-limited")
+        }
+        ProviderStatus::Network => {
+            let proxy = entry_for(config, provider_ref)
+                .map(|entry| effective_provider_proxy(config, family, entry))
+                .unwrap_or_else(|| "none (direct)".to_string());
+            crate::i18n::get_required_cli_string_with_args(
+                "cli-doctor-provider-fix-network",
+                &[
+                    ("uri_key", &entry_key(provider_ref, "uri")),
+                    ("proxy", &proxy),
+                ],
+            )
+        }
+    };
+    Some(text)
+}
+
+fn entry_for<'a>(config: &'a Config, provider_ref: &str) -> Option<&'a ModelProviderConfig> {
+    let (family, alias) = provider_ref.split_once('.')?;
+    config.providers.models.find(family, alias)
+}
+
+/// The key a probe would authenticate with, and where it came from.
+fn resolve_key(config: &Config, provider_ref: &str) -> Option<(String, String)> {
+    let family = provider_ref.split('.').next().unwrap_or(provider_ref);
+    configured_model_provider_api_key(config, provider_ref)
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(|key| (key.to_string(), "config".to_string()))
+        .or_else(|| {
+            zeroclaw_providers::native_env_api_key(family)
+                .map(|(var, key)| (key, format!("${var}")))
+        })
+}
+
+/// Whether the entry authenticates some other way than `api_key`.
+fn uses_other_auth(config: &Config, provider_ref: &str) -> bool {
+    entry_for(config, provider_ref).is_some_and(|entry| entry.requires_openai_auth)
+}
+
+/// `{"data": {"total_credits": 10.0, "total_usage": 5.88}}`
+fn parse_openrouter_credits(body: &serde_json::Value) -> Option<QuotaReading> {
+    let data = body.get("data")?;
+    let total = data.get("total_credits")?.as_f64()?;
+    let used = data.get("total_usage")?.as_f64()?;
+    Some(QuotaReading {
+        metric: "credits_usd".to_string(),
+        remaining: total - used,
+        limit: Some(total),
+    })
+}
+
+/// `anthropic-ratelimit-<metric>-remaining` / `-limit` response headers.
+fn parse_anthropic_ratelimit_headers(headers: &reqwest::header::HeaderMap) -> Vec<QuotaReading> {
+    let number = |name: String| {
+        headers
+            .get(name.as_str())
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<f64>().ok())
+    };
+    ["requests", "tokens", "input-tokens", "output-tokens"]
+        .into_iter()
+        .filter_map(|metric| {
+            let remaining = number(format!("anthropic-ratelimit-{metric}-remaining"))?;
+            Some(QuotaReading {
+                metric: metric.replace('-', "_"),
+                remaining,
+                limit: number(format!("anthropic-ratelimit-{metric}-limit")),
+            })
+        })
+        .collect()
+}
+
+async fn http_error(resp: reqwest::Response) -> anyhow::Error {
+    let status = resp.status();
+    let body = resp.text().await.unwrap_or_default();
+    anyhow::Error::msg(format!(
+        "HTTP {}: {}",
+        status.as_u16(),
+        truncate_for_display(body.trim(), 200)
+    ))
+}
+
+/// Read remaining quota for families that expose it. Entries pointed at a
+/// custom `uri` are skipped: a gateway in front of the vendor won't serve
+/// the vendor's account endpoints.
+async fn probe_quota(config: &Config, provider_ref: &str, key: &str) -> Result<Vec<QuotaReading>> {
+    let family = provider_ref.split('.').next().unwrap_or(provider_ref);
+    if entry_for(config, provider_ref).is_some_and(|entry| entry.uri.is_some()) {
+        return Ok(Vec::new());
+    }
+    let client = zeroclaw_config::schema::build_runtime_proxy_client_with_timeouts(
+        &format!("model_provider.{family}"),
+        15,
+        10,
+    );
+    match family {
+        "openrouter" => {
+            let resp = client
+                .get(format!("{OPENROUTER_API}/credits"))
+                .bearer_auth(key)
+                .send()
+                .await?;
+            if !resp.status().is_success() {
+                return Err(http_error(resp).await);
+            }
+            let body: serde_json::Value = resp.json().await?;
+            Ok(parse_openrouter_credits(&body).into_iter().collect())
+        }
+        "anthropic" => {
+            let resp = client
+                .get(format!("{ANTHROPIC_API}/v1/models?limit=1"))
+                .header("x-api-key", key)
+                .header("anthropic-version", "2023-06-01")
+                .send()
+                .await?;
+            if !resp.status().is_success() {
+                return Err(http_error(resp).await);
+            }
+            Ok(parse_anthropic_ratelimit_headers(resp.headers()))
+        }
+        _ => Ok(Vec::new()),
+    }
+}
+
+async fn check_provider(config: &Config, provider_ref: &str) -> ProviderCheck {
+    let family = provider_ref.split('.').next().unwrap_or(provider_ref);
+    let key = resolve_key(config, provider_ref);
+    let mut check = ProviderCheck {
+        provider: provider_ref.to_string(),
+        status: ProviderStatus::Ok,
+        key_source: key.as_ref().map(|(_, source)| source.clone()),
+        latency_ms: None,
+        models: None,
+        quota: Vec::new(),
+        detail: None,
+        fix: None,
+    };
+
+    let needs_key = !zeroclaw_providers::discovery::known_env_vars(family).is_empty()
+        && !uses_other_auth(config, provider_ref);
+    if key.is_none() && needs_key {
+        check.status = ProviderStatus::MissingKey;
+        check.fix = fix_for(config, provider_ref, check.status);
+        return check;
+    }
+
+    let started = Instant::now();
+    let probe = tokio::time::timeout(PROBE_TIMEOUT, fetch_provider_catalog(config, provider_ref))
+        .await
+        .unwrap_or_else(|_| {
+            Err(anyhow::Error::msg(format!(
+                "timed out after {}s",
+                PROBE_TIMEOUT.as_secs()
+            )))
+        });
+    check.latency_ms = Some(u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX));
+
+    match probe {
+        Ok(models) => check.models = Some(models.len()),
+        Err(e) => {
+            let text = format_error_chain(&e);
+            check.status = classify_provider_error(&text);
+            check.detail = Some(truncate_for_display(&text, 200));
+        }
+    }
+
+    if matches!(
+        check.status,
+        ProviderStatus::Ok | ProviderStatus::Unsupported
+    ) && let Some((key, _)) = &key
+    {
+        match probe_quota(config, provider_ref, key).await {
+            Ok(quota) => {
+                if quota.iter().any(QuotaReading::is_exhausted) {
+                    check.status = ProviderStatus::QuotaExhausted;
+                } else if check.status == ProviderStatus::Unsupported && !quota.is_empty() {
+                    // The quota call authenticated even though the catalog couldn't.
+                    check.status = ProviderStatus::Ok;
+                    check.detail = None;
+                }
+                check.quota = quota;
+            }
+            Err(e) => {
+                let text = format_error_chain(&e);
+                let status = classify_provider_error(&text);
+                if status != ProviderStatus::Error && status != ProviderStatus::Unsupported {
+                    check.status = status;
+                }
+                check.detail = Some(truncate_for_display(&text, 200));
+            }
+        }
+    }
+
+    check.fix = fix_for(config, provider_ref, check.status);
+    check
+}
+
+fn format_quota(reading: &QuotaReading) -> String {
+    let amount = |v: f64| {
+        if reading.metric == "credits_usd" {
+            format!("${v:.2}")
+        } else {
+            format!("{v:.0}")
+        }
+    };
+    match reading.limit {
+        Some(limit) => format!(
+            "{}: {} of {} remaining",
+            reading.metric,
+            amount(reading.remaining),
+            amount(limit)
+        ),
+        None => format!(
+            "{}: {} remaining",
+            reading.metric,
+            amount(reading.remaining)
+        ),
+    }
+}
+
+fn print_check(check: &ProviderCheck) {
+    println!("  [{}]", check.provider);
+    let icon = match check.status.severity() {
+        Severity::Ok => "✅",
+        Severity::Warn => "⚠️ ",
+        Severity::Error => "❌",
+    };
+    let mut facts = vec![check.status.label().to_string()];
+    if let Some(source) = &check.key_source {
+        facts.push(format!("key: {source}"));
+    }
+    if let Some(ms) = check.latency_ms {
+        facts.push(format!("{ms} ms"));
+    }
+    if let Some(models) = check.models {
+        facts.push(format!("{models} models"));
+    }
+    println!("    {icon} {}", facts.join(" · "));
+    for reading in &check.quota {
+        let marker = if reading.is_low() {
+            "⚠️  low — "
+        } else {
+            ""
+        };
+        println!("    {marker}{}", format_quota(reading));
+    }
+    if let Some(detail) = &check.detail {
+        println!("    {detail}");
+    }
+    if let Some(fix) = &check.fix {
+        println!("    💡 {fix}");
+    }
+    println!();
+}
+
+/// Verify credentials for every configured provider (or the one named by
+/// `provider_override`), printed as a report or as JSON.
+pub async fn run_providers(
+    config: &Config,
+    provider_override: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let targets = doctor_model_targets(config, provider_override);
+    if targets.is_empty() {
+        anyhow::bail!(
+            "No configured model_providers to check — run `zeroclaw quickstart` to set one up first"
+        );
+    }
+
+    let mut checks = Vec::with_capacity(targets.len());
+    for provider_ref in &targets {
+        checks.push(check_provider(config, provider_ref).await);
+    }
+
+    let ok = checks
+        .iter()
+        .filter(|c| c.status == ProviderStatus::Ok)
+        .count();
+    if json {
+        let report = serde_json::json!({
+            "providers": checks,
+            "summary": { "total": checks.len(), "ok": ok, "failed": checks.len() - ok },
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("🩺 ZeroClaw Doctor — Provider Credentials");
+        println!("  Providers to check: {}", targets.len());
+        println!();
+        checks.iter().for_each(print_check);
+        println!("  Summary: {ok} ok, {} need attention", checks.len() - ok);
+    }
+
+    if provider_override.is_some() && ok == 0 {
+        anyhow::bail!("Credential check failed for target model_provider");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_probe_errors() {
+        let cases = [
+            ("HTTP 401: invalid x-api-key", ProviderStatus::InvalidKey),
+            ("403 Forbidden", ProviderStatus::InvalidKey),
+            (
+                "429: You exceeded your current quota (insufficient_quota)",
+                ProviderStatus::QuotaExhausted,
+            ),
+            ("HTTP 402: Payment Required", ProviderStatus::QuotaExhausted),
+            (
+                "400: Your credit balance is too low to access the API",
+                ProviderStatus::QuotaExhausted,
+            ),
+            ("429 Too Many Requests", ProviderStatus::RateLimited),
+            (
+                "error sending request for url (https://api.example.com/models)",
+                ProviderStatus::Network,
+            ),
+            ("timed out after 30s", ProviderStatus::Network),
+            (
+                "provider does not support live model discovery",
+                ProviderStatus::Unsupported,
+            ),
+            ("HTTP 500: internal", ProviderStatus::Error),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(classify_provider_error(text), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn parses_openrouter_credits() {
+        let body = serde_json::json!({"data": {"total_credits": 10.0, "total_usage": 9.5}});
+        let reading = parse_openrouter_credits(&body).unwrap();
+        assert_eq!(reading.metric, "credits_usd");
+        assert!((reading.remaining - 0.5).abs() < f64::EPSILON);
+        assert!(reading.is_low());
+        assert!(!reading.is_exhausted());
+        assert_eq!(
+            format_quota(&reading),
+            "credits_usd: $0.50 of $10.00 remaining"
+        );
+
+        let spent = serde_json::json!({"data": {"total_credits": 5, "total_usage": 5.2}});
+        assert!(parse_openrouter_credits(&spent).unwrap().is_exhausted());
+        assert!(parse_openrouter_credits(&serde_json::json!({"data": {}})).is_none());
+    }
+
+    #[test]
+    fn parses_anthropic_ratelimit_headers() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("anthropic-ratelimit-requests-limit", "50".parse().unwrap());
+        headers.insert(
+            "anthropic-ratelimit-requests-remaining",
+            "49".parse().unwrap(),
+        );
+        headers.insert(
+            "anthropic-ratelimit-output-tokens-remaining",
+            "8000".parse().unwrap(),
+        );
+        let readings = parse_anthropic_ratelimit_headers(&headers);
+        assert_eq!(
+            readings,
+            vec![
+                QuotaReading {
+                    metric: "requests".into(),
+                    remaining: 49.0,
+                    limit: Some(50.0),
+                },
+                QuotaReading {
+                    metric: "output_tokens".into(),
+                    remaining: 8000.0,
+                    limit: None,
+                },
+            ]
+        );
+        assert!(parse_anthropic_ratelimit_headers(&reqwest::header::HeaderMap::new()).is_empty());
+    }
+
+    #[test]
+    fn fixes_name_the_config_key_and_native_env_var() {
+        let config = Config::default();
+        let fix = fix_for(&config, "openrouter.work", ProviderStatus::InvalidKey).unwrap();
+        assert!(
+            fix.contains("providers.models.openrouter.work.api_key"),
+            "{fix}"
+        );
+        let fix = fix_for(&config, "xai", ProviderStatus::MissingKey).unwrap();
+        assert!(
+            fix.contains("providers.models.xai.default.api_key"),
+            "{fix}"
+        );
+        assert!(fix.contains("XAI_API_KEY"), "{fix}");
+        let fix = fix_for(&config, "ollama.default", ProviderStatus::Network).unwrap();
+        assert!(fix.contains("providers.models.ollama.default.uri"), "{fix}");
+        assert!(fix_for(&config, "ollama.default", ProviderStatus::Ok).is_none());
+    }
+
+    #[tokio::test]
+    async fn unconfigured_key_is_reported_without_a_network_call() {
+        let config = Config::default();
+        let check = check_provider(&config, "openrouter.default").await;
+        assert_eq!(check.status, ProviderStatus::MissingKey);
+        assert_eq!(check.latency_ms, None);
+        assert!(
+            check
+                .fix
+                .as_deref()
+                .is_some_and(|fix| fix.contains("providers.models.openrouter.default.api_key"))
+        );
+    }
+}
@@ -1,25 +1,36 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::sync::OnceLock;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-#[derive(Debug, Clone, Serialize)]
+pub mod selfheal;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComponentHealth {
     pub status: String,
     pub updated_at: String,
     pub last_ok: Option<String>,
     pub last_error: Option<String>,
+    /// When the component entered its current error state; cleared on ok.
+    #[serde(default)]
+    pub error_since: Option<String>,
     pub restart_count: u64,
+    /// When the self-heal watchdog last restarted this component.
+    #[serde(default)]
+    pub last_selfheal_at: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthSnapshot {
     pub pid: u32,
     pub updated_at: String,
     pub uptime_seconds: u64,
     pub components: BTreeMap<String, ComponentHealth>,
+    /// Paused channels and drain mode.
+    #[serde(default)]
+    pub channels: crate::admission::AdmissionSnapshot,
 }
 
 struct HealthRegistry {
@@ -62,7 +73,9 @@ where
             updated_at: now.clone(),
             last_ok: None,
             last_error: None,
+            error_since: None,
             restart_count: 0,
+            last_selfheal_at: None,
         });
     update(entry);
     entry.updated_at = now;
@@ -73,6 +86,7 @@ pub fn mark_component_ok(component: &str) {
         entry.status = "ok".into();
         entry.last_ok = Some(now_rfc3339());
         entry.last_error = None;
+        entry.error_since = None;
     });
 }
 
@@ -80,6 +94,9 @@ pub fn mark_component_ok(component: &str) {
 pub fn mark_component_error(component: &str, error: impl ToString) {
     let err = error.to_string();
     upsert_component(component, move |entry| {
+        if entry.status != "error" || entry.error_since.is_none() {
+            entry.error_since = Some(now_rfc3339());
+        }
         entry.status = "error".into();
         entry.last_error = Some(err);
     });
@@ -91,6 +108,15 @@ pub fn bump_component_restart(component: &str) {
     });
 }
 
+/// Record that the self-heal watchdog acted on `component`. Leaves
+/// `updated_at` alone: it tracks the component's own status reports, which
+/// liveness checks depend on.
+pub fn mark_component_selfheal(component: &str) {
+    if let Some(entry) = registry().components.lock().get_mut(component) {
+        entry.last_selfheal_at = Some(now_rfc3339());
+    }
+}
+
 pub fn snapshot() -> HealthSnapshot {
     let components = registry().components.lock().clone();
 
@@ -99,6 +125,7 @@ pub fn snapshot() -> HealthSnapshot {
         updated_at: now_rfc3339(),
         uptime_seconds: registry().started_at.elapsed().as_secs(),
         components,
+        channels: crate::admission::global().snapshot(),
     }
 }
 
@@ -111,6 +138,113 @@ pub fn snapshot_json() -> serde_json::Value {
     })
 }
 
+/// Per-component view served by `GET /health/details` and
+/// `zeroclaw doctor --json`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentDetail {
+    pub state: String,
+    pub last_error: Option<String>,
+    pub restart_count: u64,
+    pub last_ok: Option<String>,
+    pub error_since: Option<String>,
+    /// In error for longer than the configured threshold.
+    pub stuck: bool,
+    pub last_selfheal_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthDetails {
+    /// `ok`, or `degraded` when any component is stuck in error.
+    pub status: String,
+    pub pid: u32,
+    pub updated_at: String,
+    pub uptime_seconds: u64,
+    pub error_threshold_secs: u64,
+    /// Most recent self-heal action across all components.
+    pub last_selfheal_at: Option<String>,
+    pub components: BTreeMap<String, ComponentDetail>,
+    pub channels: crate::admission::AdmissionSnapshot,
+}
+
+impl HealthDetails {
+    pub fn is_degraded(&self) -> bool {
+        self.components.values().any(|c| c.stuck)
+    }
+
+    pub fn stuck_components(&self) -> Vec<&str> {
+        self.components
+            .iter()
+            .filter(|(_, c)| c.stuck)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+}
+
+pub(crate) fn stuck_in_error(
+    component: &ComponentHealth,
+    threshold: Duration,
+    now: DateTime<Utc>,
+) -> bool {
+    if component.status != "error" {
+        return false;
+    }
+    component
+        .error_since
+        .as_deref()
+        .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+        .is_some_and(|since| {
+            now.signed_duration_since(since.with_timezone(&Utc))
+                .to_std()
+                .is_ok_and(|age| age > threshold)
+        })
+}
+
+/// Per-component detail for a snapshot, flagging components that have been
+/// in error for longer than `error_threshold`.
+pub fn details_from(snapshot: &HealthSnapshot, error_threshold: Duration) -> HealthDetails {
+    let now = Utc::now();
+    let components: BTreeMap<String, ComponentDetail> = snapshot
+        .components
+        .iter()
+        .map(|(name, c)| {
+            (
+                name.clone(),
+                ComponentDetail {
+                    state: c.status.clone(),
+                    last_error: c.last_error.clone(),
+                    restart_count: c.restart_count,
+                    last_ok: c.last_ok.clone(),
+                    error_since: c.error_since.clone(),
+                    stuck: stuck_in_error(c, error_threshold, now),
+                    last_selfheal_at: c.last_selfheal_at.clone(),
+                },
+            )
+        })
+        .collect();
+    let degraded = components.values().any(|c| c.stuck);
+    // RFC 3339 UTC strings from `now_rfc3339` order chronologically.
+    let last_selfheal_at = components
+        .values()
+        .filter_map(|c| c.last_selfheal_at.clone())
+        .max();
+
+    HealthDetails {
+        status: if degraded { "degraded" } else { "ok" }.into(),
+        pid: snapshot.pid,
+        updated_at: snapshot.updated_at.clone(),
+        uptime_seconds: snapshot.uptime_seconds,
+        error_threshold_secs: error_threshold.as_secs(),
+        last_selfheal_at,
+        components,
+        channels: snapshot.channels.clone(),
+    }
+}
+
+/// [`details_from`] for this process's registry.
+pub fn details(error_threshold: Duration) -> HealthDetails {
+    details_from(&snapshot(), error_threshold)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,4 +324,78 @@ mod tests {
         assert!(component_json["last_ok"].as_str().is_some());
         assert!(json["uptime_seconds"].as_u64().is_some());
     }
+
+    #[test]
+    fn error_since_is_kept_across_repeated_errors_and_cleared_on_ok() {
+        let component = unique_component("health-error-since");
+
+        mark_component_error(&component, "first");
+        let first = snapshot().components[&component].error_since.clone();
+        assert!(first.is_some());
+
+        mark_component_error(&component, "second");
+        let second = snapshot().components[&component].error_since.clone();
+        assert_eq!(first, second);
+
+        mark_component_ok(&component);
+        assert!(snapshot().components[&component].error_since.is_none());
+    }
+
+    #[test]
+    fn selfheal_timestamp_survives_recovery_and_surfaces_in_details() {
+        let component = unique_component("health-selfheal");
+
+        mark_component_error(&component, "stuck");
+        mark_component_selfheal(&component);
+        mark_component_ok(&component);
+
+        let snapshot = snapshot();
+        let stamped = snapshot.components[&component].last_selfheal_at.clone();
+        assert!(stamped.is_some());
+        let details = details_from(&snapshot, Duration::from_secs(300));
+        assert_eq!(details.components[&component].last_selfheal_at, stamped);
+        assert!(details.last_selfheal_at >= stamped);
+    }
+
+    #[test]
+    fn details_flag_components_stuck_in_error_past_threshold() {
+        let old = (Utc::now() - chrono::Duration::seconds(600)).to_rfc3339();
+        let recent = Utc::now().to_rfc3339();
+        let component = |status: &str, since: Option<&str>| ComponentHealth {
+            status: status.into(),
+            updated_at: recent.clone(),
+            last_ok: None,
+            last_error: (status == "error").then(|| "boom".to_string()),
+            error_since: since.map(String::from),
+            restart_count: 3,
+            last_selfheal_at: None,
+        };
+        let snapshot = HealthSnapshot {
+            pid: 1,
+            updated_at: recent.clone(),
+            uptime_seconds: 10,
+            components: BTreeMap::from([
+                ("channel:telegram".into(), component("error", Some(&old))),
+                ("heartbeat".into(), component("error", Some(&recent))),
+                ("scheduler".into(), component("ok", None)),
+            ]),
+            channels: Default::default(),
+        };
+
+        let details = details_from(&snapshot, Duration::from_secs(300));
+        assert!(details.is_degraded());
+        assert_eq!(details.status, "degraded");
+        assert_eq!(details.stuck_components(), vec!["channel:telegram"]);
+        assert_eq!(details.components["channel:telegram"].restart_count, 3);
+        assert_eq!(
+            details.components["channel:telegram"].last_error.as_deref(),
+            Some("boom")
+        );
+
+        assert!(details.last_selfheal_at.is_none());
+
+        let lenient = details_from(&snapshot, Duration::from_secs(3600));
+        assert!(!lenient.is_degraded());
+        assert_eq!(lenient.status, "ok");
+    }
 }
@@ -0,0 +1,464 @@
+//! Self-heal watchdog (`[health.selfheal]`).
+//!
+//! On every check the watchdog reads the component registry and decides
+//! what to do about components that are stuck: a channel listener in error
+//! past `listener_error_threshold_secs` restarts the `channels` supervisor
+//! (which rebuilds every channel from the config on disk), and a scheduler
+//! that has not ticked for `scheduler_stall_secs` restarts the `scheduler`
+//! supervisor. A component that needs `escalate_after` restarts without
+//! recovering is reported to the configured channel and left alone until it
+//! recovers, so a permanently broken listener does not keep bouncing the
+//! healthy ones.
+
+use super::{ComponentHealth, HealthSnapshot};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use zeroclaw_config::schema::{Config, SelfHealConfig};
+
+/// Daemon component name for the watchdog itself.
+pub const SELFHEAL_COMPONENT: &str = "selfheal";
+
+/// Supervisor restarted when a channel listener is stuck.
+pub const CHANNELS_TARGET: &str = "channels";
+
+/// Supervisor restarted when the scheduler is wedged.
+pub const SCHEDULER_TARGET: &str = "scheduler";
+
+/// Restart triggers for the daemon supervisors the watchdog may respawn.
+/// Each trigger is a generation counter: a supervisor marks the current
+/// value seen when a run starts and restarts the run when it changes.
+#[derive(Clone, Default)]
+pub struct RestartTriggers {
+    senders: Arc<Mutex<BTreeMap<&'static str, watch::Sender<u64>>>>,
+}
+
+impl RestartTriggers {
+    /// Register `component` as restartable and return the receiver its
+    /// supervisor waits on.
+    pub fn register(&self, component: &'static str) -> watch::Receiver<u64> {
+        self.senders
+            .lock()
+            .entry(component)
+            .or_insert_with(|| watch::channel(0).0)
+            .subscribe()
+    }
+
+    /// Components with a registered supervisor.
+    pub fn targets(&self) -> Vec<&'static str> {
+        self.senders.lock().keys().copied().collect()
+    }
+
+    /// Ask `component`'s supervisor to restart its run. Returns `false`
+    /// when no supervisor is registered under that name.
+    pub fn trigger(&self, component: &str) -> bool {
+        let senders = self.senders.lock();
+        let Some(sender) = senders.get(component) else {
+            return false;
+        };
+        sender.send_modify(|generation| *generation = generation.wrapping_add(1));
+        true
+    }
+}
+
+/// Resolves once a restart is requested on `restart`. Never resolves when
+/// the trigger is gone, so a dropped registry cannot spin the supervisor.
+pub async fn restart_requested(restart: &mut watch::Receiver<u64>) {
+    if restart.changed().await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+/// One decision taken by [`Watchdog::plan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelfHealAction {
+    /// Abort and respawn the `target` supervisor because `component` is
+    /// stuck; `attempt` counts restarts since the component last recovered.
+    Restart {
+        target: &'static str,
+        component: String,
+        reason: String,
+        attempt: u32,
+    },
+    /// `component` stayed stuck through `attempts` restarts.
+    Escalate {
+        component: String,
+        attempts: u32,
+        reason: String,
+    },
+}
+
+/// Restart bookkeeping carried across checks.
+#[derive(Debug, Default)]
+pub struct Watchdog {
+    /// Restarts per stuck component since it was last seen healthy.
+    attempts: HashMap<String, u32>,
+}
+
+impl Watchdog {
+    /// Decide what to do about the components in `snapshot`. Only
+    /// components whose supervisor appears in `targets` are acted on, and
+    /// each target is restarted at most once per check.
+    pub fn plan(
+        &mut self,
+        snapshot: &HealthSnapshot,
+        config: &SelfHealConfig,
+        targets: &[&str],
+        now: DateTime<Utc>,
+    ) -> Vec<SelfHealAction> {
+        let mut actions = Vec::new();
+        let mut restarting = BTreeSet::new();
+        for (name, component) in &snapshot.components {
+            let Some((target, reason)) = diagnose(name, component, config, now) else {
+                if component.status == "ok" {
+                    self.attempts.remove(name);
+                }
+                continue;
+            };
+            if !targets.contains(&target) {
+                continue;
+            }
+            let attempts = self.attempts.entry(name.clone()).or_default();
+            if *attempts >= config.escalate_after {
+                // Escalate once, then leave the component alone until it
+                // recovers.
+                if *attempts == config.escalate_after {
+                    actions.push(SelfHealAction::Escalate {
+                        component: name.clone(),
+                        attempts: *attempts,
+                        reason,
+                    });
+                    *attempts += 1;
+                }
+                continue;
+            }
+            *attempts += 1;
+            if restarting.insert(target) {
+                actions.push(SelfHealAction::Restart {
+                    target,
+                    component: name.clone(),
+                    reason,
+                    attempt: *attempts,
+                });
+            }
+        }
+        actions
+    }
+}
+
+/// The supervisor to restart for a stuck component, and why.
+fn diagnose(
+    name: &str,
+    component: &ComponentHealth,
+    config: &SelfHealConfig,
+    now: DateTime<Utc>,
+) -> Option<(&'static str, String)> {
+    if name.starts_with("channel:") {
+        let threshold = Duration::from_secs(config.listener_error_threshold_secs);
+        if !super::stuck_in_error(component, threshold, now) {
+            return None;
+        }
+        let error = component.last_error.as_deref().unwrap_or("unknown error");
+        return Some((
+            CHANNELS_TARGET,
+            format!(
+                "listener in error for more than {}s: {error}",
+                config.listener_error_threshold_secs
+            ),
+        ));
+    }
+    if name == SCHEDULER_TARGET {
+        let stalled_for = DateTime::parse_from_rfc3339(&component.updated_at)
+            .ok()
+            .and_then(|at| {
+                now.signed_duration_since(at.with_timezone(&Utc))
+                    .to_std()
+                    .ok()
+            })?;
+        if stalled_for <= Duration::from_secs(config.scheduler_stall_secs) {
+            return None;
+        }
+        return Some((
+            SCHEDULER_TARGET,
+            format!("no scheduler tick for {}s", stalled_for.as_secs()),
+        ));
+    }
+    None
+}
+
+/// Daemon component: check the registry every `interval_secs` and apply the
+/// watchdog's decisions.
+pub async fn run(
+    config: Config,
+    triggers: RestartTriggers,
+    cancel: CancellationToken,
+) -> Result<()> {
+    let selfheal = config.health.selfheal.clone();
+    let mut interval = tokio::time::interval(Duration::from_secs(selfheal.interval_secs.max(1)));
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    // The first tick completes immediately; give components one interval to
+    // come up before judging them.
+    interval.tick().await;
+    let mut watchdog = Watchdog::default();
+    loop {
+        tokio::select! {
+            () = cancel.cancelled() => return Ok(()),
+            _ = interval.tick() => {}
+        }
+        crate::health::mark_component_ok(SELFHEAL_COMPONENT);
+        let actions = watchdog.plan(
+            &crate::health::snapshot(),
+            &selfheal,
+            &triggers.targets(),
+            Utc::now(),
+        );
+        for action in actions {
+            apply(&config, &triggers, action).await;
+        }
+    }
+}
+
+async fn apply(config: &Config, triggers: &RestartTriggers, action: SelfHealAction) {
+    match action {
+        SelfHealAction::Restart {
+            target,
+            component,
+            reason,
+            attempt,
+        } => {
+            crate::health::mark_component_selfheal(&component);
+            crate::health::mark_component_selfheal(target);
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Retry)
+                    .with_category(::zeroclaw_log::EventCategory::System)
+                    .with_attrs(::serde_json::json!({
+                        "component": component,
+                        "target": target,
+                        "reason": reason,
+                        "attempt": attempt,
+                    })),
+                &format!("Self-heal: restarting '{target}' because '{component}' is stuck")
+            );
+            triggers.trigger(target);
+        }
+        SelfHealAction::Escalate {
+            component,
+            attempts,
+            reason,
+        } => {
+            crate::health::mark_component_selfheal(&component);
+            ::zeroclaw_log::record!(
+                ERROR,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                    .with_category(::zeroclaw_log::EventCategory::System)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({
+                        "component": component,
+                        "attempts": attempts,
+                        "reason": reason,
+                    })),
+                &format!(
+                    "Self-heal: '{component}' still stuck after {attempts} restart(s); escalating"
+                )
+            );
+            if let Err(e) = escalate(config, &component, attempts, &reason).await {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Send)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                    "Self-heal escalation delivery failed"
+                );
+            }
+        }
+    }
+}
+
+/// Send the escalation notice to `[health.selfheal]` `channel` /
+/// `recipient`. A no-op when no channel is configured.
+async fn escalate(config: &Config, component: &str, attempts: u32, reason: &str) -> Result<()> {
+    let selfheal = &config.health.selfheal;
+    let (channel, recipient) = (selfheal.channel.trim(), selfheal.recipient.trim());
+    if channel.is_empty() {
+        return Ok(());
+    }
+    let text = format!(
+        "⚠️ ZeroClaw self-heal: '{component}' is still failing after {attempts} automatic \
+         restart(s) ({reason}). Automatic restarts are paused until it recovers."
+    );
+    tokio::time::timeout(
+        Duration::from_secs(30),
+        crate::cron::quiet_hours::deliver_scheduled(config, channel, recipient, None, &text, true),
+    )
+    .await
+    .map_err(|_| anyhow::anyhow!("delivery timed out (30s)"))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(
+        status: &str,
+        updated_at: DateTime<Utc>,
+        error_since: Option<DateTime<Utc>>,
+    ) -> ComponentHealth {
+        ComponentHealth {
+            status: status.into(),
+            updated_at: updated_at.to_rfc3339(),
+            last_ok: None,
+            last_error: (status == "error").then(|| "401 Unauthorized".to_string()),
+            error_since: error_since.map(|at| at.to_rfc3339()),
+            restart_count: 0,
+            last_selfheal_at: None,
+        }
+    }
+
+    fn snapshot(components: Vec<(&str, ComponentHealth)>) -> HealthSnapshot {
+        HealthSnapshot {
+            pid: 1,
+            updated_at: Utc::now().to_rfc3339(),
+            uptime_seconds: 10,
+            components: components
+                .into_iter()
+                .map(|(name, c)| (name.to_string(), c))
+                .collect(),
+            channels: Default::default(),
+        }
+    }
+
+    fn config(escalate_after: u32) -> SelfHealConfig {
+        SelfHealConfig {
+            enabled: true,
+            listener_error_threshold_secs: 1800,
+            scheduler_stall_secs: 600,
+            escalate_after,
+            ..SelfHealConfig::default()
+        }
+    }
+
+    const TARGETS: &[&str] = &[CHANNELS_TARGET, SCHEDULER_TARGET];
+
+    #[test]
+    fn stuck_listeners_restart_the_channel_supervisor_once_per_check() {
+        let now = Utc::now();
+        let long_ago = now - chrono::Duration::minutes(45);
+        let snap = snapshot(vec![
+            ("channel:telegram", component("error", now, Some(long_ago))),
+            ("channel:slack.ops", component("error", now, Some(long_ago))),
+            ("channel:discord", component("error", now, Some(now))),
+        ]);
+
+        let actions = Watchdog::default().plan(&snap, &config(3), TARGETS, now);
+
+        assert_eq!(actions.len(), 1);
+        let SelfHealAction::Restart {
+            target,
+            component,
+            reason,
+            attempt,
+        } = &actions[0]
+        else {
+            panic!("expected a restart, got {actions:?}");
+        };
+        assert_eq!(*target, CHANNELS_TARGET);
+        assert_eq!(component, "channel:slack.ops");
+        assert!(reason.contains("401 Unauthorized"));
+        assert_eq!(*attempt, 1);
+    }
+
+    #[test]
+    fn wedged_scheduler_restarts_and_fresh_one_does_not() {
+        let now = Utc::now();
+        let stale = snapshot(vec![(
+            "scheduler",
+            component("ok", now - chrono::Duration::minutes(20), None),
+        )]);
+        let fresh = snapshot(vec![("scheduler", component("ok", now, None))]);
+        let mut watchdog = Watchdog::default();
+
+        assert!(watchdog.plan(&fresh, &config(3), TARGETS, now).is_empty());
+        let actions = watchdog.plan(&stale, &config(3), TARGETS, now);
+        assert!(matches!(
+            actions.as_slice(),
+            [SelfHealAction::Restart {
+                target: SCHEDULER_TARGET,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn components_without_a_registered_supervisor_are_left_alone() {
+        let now = Utc::now();
+        let snap = snapshot(vec![(
+            "scheduler",
+            component("ok", now - chrono::Duration::hours(2), None),
+        )]);
+
+        let actions = Watchdog::default().plan(&snap, &config(3), &[CHANNELS_TARGET], now);
+
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn repeated_failures_escalate_once_and_recovery_resets() {
+        let now = Utc::now();
+        let stuck = snapshot(vec![(
+            "channel:telegram",
+            component("error", now, Some(now - chrono::Duration::hours(1))),
+        )]);
+        let recovered = snapshot(vec![("channel:telegram", component("ok", now, None))]);
+        let cfg = config(2);
+        let mut watchdog = Watchdog::default();
+
+        let kinds = |actions: Vec<SelfHealAction>| -> Vec<&'static str> {
+            actions
+                .iter()
+                .map(|a| match a {
+                    SelfHealAction::Restart { .. } => "restart",
+                    SelfHealAction::Escalate { .. } => "escalate",
+                })
+                .collect()
+        };
+        assert_eq!(
+            kinds(watchdog.plan(&stuck, &cfg, TARGETS, now)),
+            ["restart"]
+        );
+        assert_eq!(
+            kinds(watchdog.plan(&stuck, &cfg, TARGETS, now)),
+            ["restart"]
+        );
+        assert_eq!(
+            kinds(watchdog.plan(&stuck, &cfg, TARGETS, now)),
+            ["escalate"]
+        );
+        assert!(watchdog.plan(&stuck, &cfg, TARGETS, now).is_empty());
+
+        assert!(watchdog.plan(&recovered, &cfg, TARGETS, now).is_empty());
+        assert_eq!(
+            kinds(watchdog.plan(&stuck, &cfg, TARGETS, now)),
+            ["restart"]
+        );
+    }
+
+    #[tokio::test]
+    async fn trigger_wakes_the_registered_supervisor() {
+        let triggers = RestartTriggers::default();
+        let mut rx = triggers.register(CHANNELS_TARGET);
+        rx.borrow_and_update();
+
+        assert!(!triggers.trigger("gateway"));
+        assert!(triggers.trigger(CHANNELS_TARGET));
+        tokio::time::timeout(Duration::from_secs(1), restart_requested(&mut rx))
+            .await
+            .expect("restart should be signalled");
+        assert_eq!(triggers.targets(), vec![CHANNELS_TARGET]);
+    }
+}
@@ -8,7 +8,7 @@ use zeroclaw_api::channel::ChannelMessage;
 use zeroclaw_api::model_provider::{ChatMessage, ChatResponse};
 use zeroclaw_api::tool::ToolResult;
 
-use super::traits::{HookHandler, HookResult};
+use super::traits::{HookHandler, HookResult, InjectionSignal};
 
 pub struct HookRunner {
     handlers: Vec<Box<dyn HookHandler>>,
@@ -129,6 +129,35 @@ impl HookRunner {
         join_all(futs).await;
     }
 
+    // ---------------------------------------------------------------
+    // Detector dispatchers (parallel, collect every non-abstaining verdict)
+    // ---------------------------------------------------------------
+
+    pub async fn run_scan_tool_output(&self, tool: &str, output: &str) -> Vec<InjectionSignal> {
+        let futs: Vec<_> = self
+            .handlers
+            .iter()
+            .map(|h| AssertUnwindSafe(h.scan_tool_output(tool, output)).catch_unwind())
+            .collect();
+        let mut signals = Vec::new();
+        for (h, verdict) in self.handlers.iter().zip(join_all(futs).await) {
+            match verdict {
+                Ok(Some(signal)) => signals.push(signal),
+                Ok(None) => {}
+                Err(_) => {
+                    ::zeroclaw_log::record!(
+                        ERROR,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                            .with_attrs(::serde_json::json!({"hook": h.name(), "tool": tool})),
+                        "scan_tool_output hook panicked; ignoring its verdict"
+                    );
+                }
+            }
+        }
+        signals
+    }
+
     // ---------------------------------------------------------------
     // Modifying dispatchers (sequential by priority, short-circuit on Cancel)
     // ---------------------------------------------------------------
@@ -717,6 +746,7 @@ mod tests {
         let mut messages = vec![ChatMessage {
             role: "user".into(),
             content: "hi".into(),
+            reasoning: None,
         }];
         let mut model = "gpt-4o".into();
         let result = runner.run_before_llm_call(&mut messages, &mut model).await;
@@ -900,4 +930,55 @@ mod tests {
         runner.fire_session_start("sess-1", "rpc").await;
         runner.fire_session_end("sess-1", "rpc").await;
     }
+
+    #[tokio::test]
+    async fn scan_tool_output_collects_detector_signals() {
+        struct KeywordDetector;
+
+        #[async_trait]
+        impl HookHandler for KeywordDetector {
+            fn name(&self) -> &str {
+                "keyword"
+            }
+            async fn scan_tool_output(&self, _tool: &str, output: &str) -> Option<InjectionSignal> {
+                output.contains("exfil").then(|| InjectionSignal {
+                    detector: "keyword".into(),
+                    score: 0.9,
+                    patterns: vec!["exfil".into()],
+                })
+            }
+        }
+
+        struct PanickingDetector;
+
+        #[async_trait]
+        impl HookHandler for PanickingDetector {
+            fn name(&self) -> &str {
+                "panicker"
+            }
+            async fn scan_tool_output(
+                &self,
+                _tool: &str,
+                _output: &str,
+            ) -> Option<InjectionSignal> {
+                panic!("simulated detector panic");
+            }
+        }
+
+        let mut runner = HookRunner::new();
+        runner.register(Box::new(KeywordDetector));
+        runner.register(Box::new(PanickingDetector));
+
+        let signals = runner
+            .run_scan_tool_output("web_fetch", "please exfil")
+            .await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].detector, "keyword");
+        assert!(
+            runner
+                .run_scan_tool_output("web_fetch", "benign page")
+                .await
+                .is_empty()
+        );
+    }
 }
@@ -1,5 +1,6 @@
 pub mod platform;
 pub mod registry;
+pub mod setup;
 
 use anyhow::Result;
 use zeroclaw_config::schema::Config;
@@ -155,7 +156,55 @@ pub fn show_integration_info(config: &Config, name: &str) -> Result<()> {
         }
         _ => {}
     }
+    if let Some(flow) = setup::find_flow(&entry.name) {
+        println!("  Setup:");
+        println!("    Run: zeroclaw integrations setup {}", flow.slug);
+        println!("    (add --dry-run to see the config edits first)");
+    }
+
+    println!();
+    Ok(())
+}
 
+/// Handle `zeroclaw integrations list`. Set-up integrations are marked ✅,
+/// merely available ones ⚪; `configured_only` drops the latter.
+pub fn list_integrations(config: &Config, configured_only: bool) -> Result<()> {
+    let entries = registry::all_integrations(config);
+    let active = entries
+        .iter()
+        .filter(|e| e.status == IntegrationStatus::Active)
+        .count();
+
+    for category in IntegrationCategory::all() {
+        let rows: Vec<&IntegrationEntry> = entries
+            .iter()
+            .filter(|e| e.category == *category)
+            .filter(|e| !configured_only || e.status == IntegrationStatus::Active)
+            .collect();
+        if rows.is_empty() {
+            continue;
+        }
+        println!();
+        println!("  {}", console::style(category.label()).white().bold());
+        for entry in rows {
+            let icon = match entry.status {
+                IntegrationStatus::Active => "✅",
+                IntegrationStatus::Available => "⚪",
+            };
+            let mut line = format!("    {icon} {}", entry.name);
+            if !entry.description.is_empty() {
+                line.push_str(&format!(" — {}", entry.description));
+            }
+            if entry.status == IntegrationStatus::Available
+                && let Some(flow) = setup::find_flow(&entry.name)
+            {
+                line.push_str(&format!("  (zeroclaw integrations setup {})", flow.slug));
+            }
+            println!("{line}");
+        }
+    }
+    println!();
+    println!("  {active} set up, {} available", entries.len() - active);
     println!();
     Ok(())
 }
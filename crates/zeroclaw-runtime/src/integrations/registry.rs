@@ -83,6 +83,13 @@ pub fn all_integrations(config: &Config) -> Vec<IntegrationEntry> {
             status: IntegrationStatus::Active,
         });
 
+    let setup_flows = super::setup::FLOWS.iter().map(|flow| IntegrationEntry {
+        name: flow.display_name.to_string(),
+        description: flow.description.to_string(),
+        category: IntegrationCategory::ToolsAutomation,
+        status: bool_to_status(flow.is_configured(config)),
+    });
+
     let platforms = PLATFORMS.iter().map(|(name, available)| IntegrationEntry {
         name: (*name).to_string(),
         description: String::new(),
@@ -94,6 +101,7 @@ pub fn all_integrations(config: &Config) -> Vec<IntegrationEntry> {
         .chain(toggles)
         .chain(providers)
         .chain(builtins)
+        .chain(setup_flows)
         .chain(platforms)
         .collect()
 }
@@ -190,9 +198,11 @@ mod tests {
                 draft_update_interval_ms: 1000,
                 interrupt_on_new_message: false,
                 mention_only: false,
+                allowed_topics: Vec::new(),
                 ack_reactions: None,
                 proxy_url: None,
                 approval_timeout_secs: 120,
+                translate: None,
                 excluded_tools: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
@@ -222,6 +232,7 @@ mod tests {
             IMessageConfig {
                 enabled: true,
                 excluded_tools: vec![],
+                group_ids: vec![],
                 reply_min_interval_secs: 0,
                 reply_queue_depth_max: 0,
             },
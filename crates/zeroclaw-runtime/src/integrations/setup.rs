@@ -0,0 +1,570 @@
+//! Runnable setup flows behind `zeroclaw integrations setup <name>`.
+//!
+//! A flow collects its credential (the value already in config, then an
+//! environment variable, then a masked prompt), plans the config edits that
+//! register its tool, writes them, and makes one verification call against
+//! the service. GitHub and Google Calendar go through Composio's managed
+//! OAuth; Notion talks to the Notion API directly.
+
+use anyhow::{Context, Result};
+use std::io::IsTerminal;
+use std::sync::Arc;
+use zeroclaw_config::schema::Config;
+
+const NOTION_API_BASE: &str = "https://api.notion.com/v1";
+const NOTION_VERSION: &str = "2022-06-28";
+
+/// Where a flow's tool comes from.
+pub enum Backend {
+    /// The `notion` tool, configured under `[notion]`.
+    Notion,
+    /// The `composio` tool, with `toolkit` connected for the configured entity.
+    Composio { toolkit: &'static str },
+}
+
+pub struct SetupFlow {
+    /// Name accepted by `integrations setup`.
+    pub slug: &'static str,
+    pub display_name: &'static str,
+    pub description: &'static str,
+    pub backend: Backend,
+}
+
+pub const FLOWS: &[SetupFlow] = &[
+    SetupFlow {
+        slug: "github",
+        display_name: "GitHub",
+        description: "Issues, pull requests, and repositories via Composio",
+        backend: Backend::Composio { toolkit: "github" },
+    },
+    SetupFlow {
+        slug: "notion",
+        display_name: "Notion",
+        description: "Query and update a Notion database",
+        backend: Backend::Notion,
+    },
+    SetupFlow {
+        slug: "google-calendar",
+        display_name: "Google Calendar",
+        description: "Calendar events via Composio",
+        backend: Backend::Composio {
+            toolkit: "googlecalendar",
+        },
+    },
+];
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// The flow named by `name`, matching slug or display name loosely
+/// (`google-calendar`, `Google Calendar`, `googlecalendar`).
+pub fn find_flow(name: &str) -> Option<&'static SetupFlow> {
+    let wanted = normalize(name);
+    FLOWS
+        .iter()
+        .find(|flow| normalize(flow.slug) == wanted || normalize(flow.display_name) == wanted)
+}
+
+struct CredentialSpec {
+    env_var: &'static str,
+    path: &'static str,
+    label: &'static str,
+}
+
+/// Where an input value came from, for the plan printout.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum InputSource {
+    Env(&'static str),
+    Prompt,
+    /// Dry run: the value would be asked for on a real run.
+    Deferred,
+}
+
+pub struct SetupInput {
+    pub value: String,
+    pub source: InputSource,
+}
+
+/// Values collected for a flow. `None` keeps what config already has.
+#[derive(Default)]
+pub struct SetupInputs {
+    pub credential: Option<SetupInput>,
+    pub database_id: Option<SetupInput>,
+}
+
+/// One planned config write.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ConfigEdit {
+    pub path: String,
+    /// Display form of the current value.
+    pub before: String,
+    /// Display form of the new value; secrets are masked.
+    pub after: String,
+    value: String,
+}
+
+impl std::fmt::Display for ConfigEdit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} → {}", self.path, self.before, self.after)
+    }
+}
+
+/// Outcome of a verification call.
+pub enum Verification {
+    Ok(String),
+    /// The credential works but the service account is not connected yet.
+    Pending {
+        connect_url: Option<String>,
+    },
+}
+
+impl SetupFlow {
+    /// Name of the tool the flow registers.
+    pub fn tool(&self) -> &'static str {
+        match self.backend {
+            Backend::Notion => "notion",
+            Backend::Composio { .. } => "composio",
+        }
+    }
+
+    fn credential(&self) -> CredentialSpec {
+        match self.backend {
+            Backend::Notion => CredentialSpec {
+                env_var: "NOTION_API_KEY",
+                path: "notion.api_key",
+                label: "Notion integration token",
+            },
+            Backend::Composio { .. } => CredentialSpec {
+                env_var: "COMPOSIO_API_KEY",
+                path: "composio.api_key",
+                label: "Composio API key",
+            },
+        }
+    }
+
+    fn configured_secret<'a>(&self, config: &'a Config) -> Option<&'a str> {
+        let secret = match self.backend {
+            Backend::Notion => Some(config.notion.api_key.as_str()),
+            Backend::Composio { .. } => config.composio.api_key.as_deref(),
+        };
+        secret.map(str::trim).filter(|s| !s.is_empty())
+    }
+
+    /// The credential the tool will use at runtime. Notion also reads
+    /// `NOTION_API_KEY` when config has no key.
+    fn effective_secret(&self, config: &Config) -> Option<String> {
+        if let Some(secret) = self.configured_secret(config) {
+            return Some(secret.to_string());
+        }
+        match self.backend {
+            Backend::Notion => std::env::var("NOTION_API_KEY")
+                .ok()
+                .filter(|s| !s.trim().is_empty()),
+            Backend::Composio { .. } => None,
+        }
+    }
+
+    /// Whether the flow's config is in place (no network call).
+    pub fn is_configured(&self, config: &Config) -> bool {
+        match self.backend {
+            Backend::Notion => {
+                config.notion.enabled
+                    && !config.notion.database_id.trim().is_empty()
+                    && self.effective_secret(config).is_some()
+            }
+            Backend::Composio { toolkit } => {
+                config.composio.enabled
+                    && self.configured_secret(config).is_some()
+                    && config.composio.toolkits.iter().any(|t| t == toolkit)
+            }
+        }
+    }
+}
+
+fn push_edit(edits: &mut Vec<ConfigEdit>, path: &str, before: String, after: String) {
+    if before != after {
+        edits.push(ConfigEdit {
+            path: path.to_string(),
+            before: display(&before),
+            after: display(&after),
+            value: after,
+        });
+    }
+}
+
+fn display(value: &str) -> String {
+    if value.is_empty() {
+        "(unset)".into()
+    } else {
+        value.to_string()
+    }
+}
+
+fn source_note(source: InputSource) -> String {
+    match source {
+        InputSource::Env(var) => format!("from {var}"),
+        InputSource::Prompt => "entered at the prompt".into(),
+        InputSource::Deferred => "asked for on a real run".into(),
+    }
+}
+
+/// The config edits that set `flow` up with `inputs`. Values config already
+/// has are left alone, so re-running a finished setup plans nothing.
+pub fn plan(flow: &SetupFlow, config: &Config, inputs: &SetupInputs) -> Vec<ConfigEdit> {
+    let mut edits = Vec::new();
+    let (enabled_path, enabled) = match flow.backend {
+        Backend::Notion => ("notion.enabled", config.notion.enabled),
+        Backend::Composio { .. } => ("composio.enabled", config.composio.enabled),
+    };
+    push_edit(&mut edits, enabled_path, enabled.to_string(), "true".into());
+
+    if let Some(input) = &inputs.credential
+        && flow.configured_secret(config) != Some(input.value.trim())
+    {
+        edits.push(ConfigEdit {
+            path: flow.credential().path.to_string(),
+            before: if flow.configured_secret(config).is_some() {
+                "(secret)".into()
+            } else {
+                "(unset)".into()
+            },
+            after: format!("(secret, {})", source_note(input.source)),
+            value: input.value.trim().to_string(),
+        });
+    }
+
+    match flow.backend {
+        Backend::Notion => {
+            if let Some(input) = &inputs.database_id {
+                let mut after = input.value.trim().to_string();
+                if input.source == InputSource::Deferred {
+                    after = format!("({})", source_note(input.source));
+                }
+                push_edit(
+                    &mut edits,
+                    "notion.database_id",
+                    config.notion.database_id.clone(),
+                    after,
+                );
+            }
+        }
+        Backend::Composio { toolkit } => {
+            let toolkits = &config.composio.toolkits;
+            if !toolkits.iter().any(|t| t == toolkit) {
+                let mut after = toolkits.clone();
+                after.push(toolkit.to_string());
+                edits.push(ConfigEdit {
+                    path: "composio.toolkits".into(),
+                    before: format!("{toolkits:?}"),
+                    after: format!("{after:?}"),
+                    value: serde_json::to_string(&after).unwrap_or_default(),
+                });
+            }
+        }
+    }
+    edits
+}
+
+/// Write `edits` into `config` and mark them for `save_dirty`.
+pub fn apply(config: &mut Config, edits: &[ConfigEdit]) -> Result<()> {
+    for edit in edits {
+        config
+            .set_prop_persistent(&edit.path, &edit.value)
+            .with_context(|| format!("failed to set {}", edit.path))?;
+    }
+    Ok(())
+}
+
+/// Make one call against the service with the configured credential.
+pub async fn verify(flow: &SetupFlow, config: &Config) -> Result<Verification> {
+    let Some(secret) = flow.effective_secret(config) else {
+        anyhow::bail!("no {} configured", flow.credential().label);
+    };
+    match flow.backend {
+        Backend::Notion => verify_notion(&secret, &config.notion.database_id).await,
+        Backend::Composio { toolkit } => {
+            let entity_id = config.composio.entity_id.as_str();
+            let composio = crate::tools::ComposioTool::new(
+                &secret,
+                Some(entity_id),
+                Arc::new(crate::security::SecurityPolicy::default()),
+            );
+            if composio.has_active_connection(toolkit, entity_id).await? {
+                return Ok(Verification::Ok(format!(
+                    "{} is connected for Composio entity `{entity_id}`",
+                    flow.display_name
+                )));
+            }
+            let connect_url = composio
+                .get_connection_url(Some(toolkit), None, entity_id)
+                .await
+                .ok()
+                .map(|link| link.redirect_url);
+            Ok(Verification::Pending { connect_url })
+        }
+    }
+}
+
+async fn verify_notion(api_key: &str, database_id: &str) -> Result<Verification> {
+    let database_id = database_id.trim();
+    if database_id.is_empty() {
+        anyhow::bail!("notion.database_id is not set");
+    }
+    let client = zeroclaw_config::schema::build_runtime_proxy_client_with_timeouts(
+        "integrations.notion",
+        15,
+        10,
+    );
+    let resp = client
+        .get(format!("{NOTION_API_BASE}/databases/{database_id}"))
+        .bearer_auth(api_key)
+        .header("Notion-Version", NOTION_VERSION)
+        .send()
+        .await
+        .context("Notion API unreachable")?;
+    let status = resp.status();
+    let body: serde_json::Value = resp.json().await.unwrap_or_default();
+    if !status.is_success() {
+        let message = body["message"].as_str().unwrap_or("no error message");
+        anyhow::bail!("Notion API returned {status}: {message}");
+    }
+    let title = body["title"][0]["plain_text"]
+        .as_str()
+        .unwrap_or(database_id);
+    Ok(Verification::Ok(format!(
+        "Notion database \"{title}\" is readable"
+    )))
+}
+
+/// Verify every flow whose config is in place, for `zeroclaw doctor`.
+pub async fn check_configured(config: &Config) -> Vec<(&'static SetupFlow, Result<Verification>)> {
+    let mut results = Vec::new();
+    for flow in FLOWS.iter().filter(|flow| flow.is_configured(config)) {
+        results.push((flow, verify(flow, config).await));
+    }
+    results
+}
+
+fn collect_secret(flow: &SetupFlow, config: &Config, dry_run: bool) -> Result<Option<SetupInput>> {
+    if flow.configured_secret(config).is_some() {
+        return Ok(None);
+    }
+    let spec = flow.credential();
+    if let Ok(value) = std::env::var(spec.env_var)
+        && !value.trim().is_empty()
+    {
+        return Ok(Some(SetupInput {
+            value,
+            source: InputSource::Env(spec.env_var),
+        }));
+    }
+    if dry_run {
+        return Ok(Some(SetupInput {
+            value: String::new(),
+            source: InputSource::Deferred,
+        }));
+    }
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!(
+            "{} required: set {} or run the setup in a terminal",
+            spec.label,
+            spec.env_var
+        );
+    }
+    let value = dialoguer::Password::new()
+        .with_prompt(spec.label)
+        .interact()?;
+    if value.trim().is_empty() {
+        anyhow::bail!("{} cannot be empty", spec.label);
+    }
+    Ok(Some(SetupInput {
+        value,
+        source: InputSource::Prompt,
+    }))
+}
+
+fn collect_database_id(config: &Config, dry_run: bool) -> Result<Option<SetupInput>> {
+    if !config.notion.database_id.trim().is_empty() {
+        return Ok(None);
+    }
+    if dry_run {
+        return Ok(Some(SetupInput {
+            value: String::new(),
+            source: InputSource::Deferred,
+        }));
+    }
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!(
+            "Notion database id required: run `zeroclaw config set notion.database_id <id>` first"
+        );
+    }
+    let value: String = dialoguer::Input::new()
+        .with_prompt("Notion database id (from the database URL)")
+        .interact_text()?;
+    Ok(Some(SetupInput {
+        value,
+        source: InputSource::Prompt,
+    }))
+}
+
+/// Handle `zeroclaw integrations setup <name> [--dry-run]`.
+pub async fn run(config: &mut Config, name: &str, dry_run: bool) -> Result<()> {
+    let Some(flow) = find_flow(name) else {
+        let known: Vec<&str> = FLOWS.iter().map(|flow| flow.slug).collect();
+        anyhow::bail!(
+            "No setup flow for `{name}`. Available: {}. Run `zeroclaw integrations info {name}` for manual steps.",
+            known.join(", ")
+        );
+    };
+
+    println!();
+    println!(
+        "  {} — {}",
+        console::style(flow.display_name).white().bold(),
+        flow.description
+    );
+    println!();
+
+    let inputs = SetupInputs {
+        credential: collect_secret(flow, config, dry_run)?,
+        database_id: match flow.backend {
+            Backend::Notion => collect_database_id(config, dry_run)?,
+            Backend::Composio { .. } => None,
+        },
+    };
+    let edits = plan(flow, config, &inputs);
+
+    if dry_run {
+        if edits.is_empty() {
+            println!("  No config changes needed.");
+        } else {
+            println!("  Planned changes:");
+            for edit in &edits {
+                println!("    {edit}");
+            }
+        }
+        println!();
+        println!("  Dry run: nothing was written and no verification call was made.");
+        return Ok(());
+    }
+
+    apply(config, &edits)?;
+    config.save_dirty().await?;
+    if edits.is_empty() {
+        println!("  No config changes needed.");
+    } else {
+        println!("  Changed:");
+        for edit in &edits {
+            println!("    {edit}");
+        }
+    }
+    println!(
+        "  Tool `{}` is registered on the next agent or daemon start.",
+        flow.tool()
+    );
+    println!();
+
+    match verify(flow, config).await {
+        Ok(Verification::Ok(detail)) => println!("  ✅ Verified: {detail}"),
+        Ok(Verification::Pending { connect_url }) => {
+            println!(
+                "  ⏳ {} is not connected to Composio yet.",
+                flow.display_name
+            );
+            match connect_url {
+                Some(url) => println!("     Open {url} to connect it, then run this setup again."),
+                None => println!(
+                    "     Connect it in the Composio dashboard, then run this setup again."
+                ),
+            }
+        }
+        Err(e) => {
+            println!();
+            anyhow::bail!("Verification failed (config changes were kept): {e:#}");
+        }
+    }
+    println!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flows_match_by_slug_or_display_name() {
+        for name in ["google-calendar", "Google Calendar", "googlecalendar"] {
+            assert_eq!(find_flow(name).unwrap().slug, "google-calendar");
+        }
+        assert_eq!(find_flow("GitHub").unwrap().tool(), "composio");
+        assert!(find_flow("jira").is_none());
+    }
+
+    #[test]
+    fn composio_plan_applies_and_then_plans_nothing() {
+        let flow = find_flow("github").unwrap();
+        let mut config = Config::default();
+        let inputs = SetupInputs {
+            credential: Some(SetupInput {
+                value: "comp-key".into(),
+                source: InputSource::Env("COMPOSIO_API_KEY"),
+            }),
+            database_id: None,
+        };
+        let edits = plan(flow, &config, &inputs);
+        let shown: Vec<String> = edits.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            shown,
+            [
+                "composio.enabled: false → true",
+                "composio.api_key: (unset) → (secret, from COMPOSIO_API_KEY)",
+                "composio.toolkits: [] → [\"github\"]",
+            ]
+        );
+        assert!(!flow.is_configured(&config));
+
+        apply(&mut config, &edits).unwrap();
+        assert!(flow.is_configured(&config));
+        assert_eq!(config.composio.api_key.as_deref(), Some("comp-key"));
+        assert!(plan(flow, &config, &SetupInputs::default()).is_empty());
+
+        // A second Composio toolkit reuses the key and only adds itself.
+        let calendar = find_flow("google-calendar").unwrap();
+        assert!(!calendar.is_configured(&config));
+        let edits = plan(calendar, &config, &SetupInputs::default());
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].path, "composio.toolkits");
+    }
+
+    #[test]
+    fn notion_dry_run_plan_defers_prompted_values() {
+        let flow = find_flow("notion").unwrap();
+        let config = Config::default();
+        let inputs = SetupInputs {
+            credential: Some(SetupInput {
+                value: String::new(),
+                source: InputSource::Deferred,
+            }),
+            database_id: Some(SetupInput {
+                value: String::new(),
+                source: InputSource::Deferred,
+            }),
+        };
+        let shown: Vec<String> = plan(flow, &config, &inputs)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            shown,
+            [
+                "notion.enabled: false → true",
+                "notion.api_key: (unset) → (secret, asked for on a real run)",
+                "notion.database_id: (unset) → (asked for on a real run)",
+            ]
+        );
+    }
+}
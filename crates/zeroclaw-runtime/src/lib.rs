@@ -10,6 +10,7 @@ pub mod identity;
 pub mod migration;
 pub mod util;
 
+pub mod admission;
 pub mod agent;
 pub mod approval;
 pub mod browse;
@@ -24,8 +25,10 @@ pub mod heartbeat;
 pub mod hooks;
 pub mod i18n;
 pub mod integrations;
+pub mod model_cache;
 pub mod nodes;
 pub mod observability;
+pub mod outbox;
 pub mod peers;
 pub mod platform;
 pub mod process_stats;
@@ -40,7 +43,9 @@ pub mod skillforge;
 pub mod skills;
 pub mod sop;
 pub mod subagent;
+pub mod tasks;
 pub mod tools;
 pub mod trust;
 pub mod tunnel;
 pub mod verifiable_intent;
+pub mod watchers;
@@ -0,0 +1,1155 @@
+//! Config side of `zeroclaw migrate openclaw`: maps an OpenClaw
+//! `openclaw.json` (channel tokens and allowlists, the default model, provider
+//! keys) and the workspace identity files onto this install.
+//!
+//! Every migrated value becomes a [`FieldChange`] against the current config,
+//! so `--dry-run` shows exactly which fields would be written. Values the
+//! operator already set are kept unless `--overwrite` is passed, and OpenClaw
+//! settings without a ZeroClaw equivalent are listed instead of dropped.
+//! Tokens and keys land in `#[secret]` fields and are encrypted through the
+//! `SecretStore` when the config is saved.
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value as Json};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use zeroclaw_config::helpers::validate_alias_key;
+use zeroclaw_config::providers::ModelProviders;
+use zeroclaw_config::schema::Config;
+
+const CONFIG_FILE: &str = "openclaw.json";
+/// Workspace files read by the system prompt and heartbeat in both tools.
+const IDENTITY_FILES: &[&str] = &[
+    "AGENTS.md",
+    "SOUL.md",
+    "TOOLS.md",
+    "IDENTITY.md",
+    "USER.md",
+    "BOOTSTRAP.md",
+    "HEARTBEAT.md",
+];
+/// Alias for migrated entries that have no OpenClaw account id, and the agent
+/// the migrated model and channels are bound to.
+const DEFAULT_ALIAS: &str = "default";
+/// Top-level `openclaw.json` keys that are OpenClaw bookkeeping, not settings.
+const IGNORED_TOP_LEVEL: &[&str] = &["$schema", "meta", "wizard"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Not set in ZeroClaw yet; will be written.
+    Add,
+    /// Set to a different value and replaced because of `--overwrite`.
+    Overwrite,
+    /// Set to a different value and left alone (no `--overwrite`).
+    Keep,
+    /// Already holds the migrated value.
+    Unchanged,
+}
+
+impl ChangeKind {
+    fn writes(self) -> bool {
+        matches!(self, Self::Add | Self::Overwrite)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldChange {
+    pub path: String,
+    pub kind: ChangeKind,
+    /// Display form of the migrated value; secrets are masked.
+    pub value: String,
+    /// Display form of the current value, when one is set.
+    pub current: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileChange {
+    pub source: PathBuf,
+    pub target: PathBuf,
+    pub kind: ChangeKind,
+}
+
+#[derive(Debug, Default)]
+pub struct ConfigMigrationPlan {
+    /// The `openclaw.json` that was read, if one was found.
+    pub config_source: Option<PathBuf>,
+    pub fields: Vec<FieldChange>,
+    pub files: Vec<FileChange>,
+    /// OpenClaw settings (dotted `openclaw.json` paths) with no ZeroClaw
+    /// mapping.
+    pub unsupported: Vec<String>,
+}
+
+impl ConfigMigrationPlan {
+    fn writes_config(&self) -> bool {
+        self.fields.iter().any(|f| f.kind.writes())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.files.is_empty() && self.unsupported.is_empty()
+    }
+}
+
+pub async fn migrate_openclaw_config(
+    config: &Config,
+    source_workspace: Option<PathBuf>,
+    dry_run: bool,
+    overwrite: bool,
+) -> Result<()> {
+    let source_workspace = super::resolve_openclaw_workspace(source_workspace)?;
+    let (plan, migrated) = plan_config_migration(config, &source_workspace, overwrite)?;
+
+    if plan.is_empty() {
+        println!(
+            "No OpenClaw config or identity files found for {}",
+            source_workspace.display()
+        );
+        println!("Checked for: {CONFIG_FILE}, {}", IDENTITY_FILES.join(", "));
+        return Ok(());
+    }
+
+    if dry_run {
+        println!("🔎 Dry run: OpenClaw config migration preview");
+        print_plan(&plan);
+        println!();
+        println!("Run without --dry-run to apply the + and ~ changes above.");
+        return Ok(());
+    }
+
+    for file in plan.files.iter().filter(|f| f.kind.writes()) {
+        if let Some(parent) = file.target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(&file.source, &file.target).with_context(|| {
+            format!(
+                "Failed to copy {} to {}",
+                file.source.display(),
+                file.target.display()
+            )
+        })?;
+    }
+    if plan.writes_config() {
+        migrated.save().await?;
+    }
+
+    println!("✅ OpenClaw config migration complete");
+    print_plan(&plan);
+    Ok(())
+}
+
+fn print_plan(plan: &ConfigMigrationPlan) {
+    if let Some(source) = &plan.config_source {
+        println!("  Source: {}", source.display());
+    }
+    println!("  Legend: + add  ~ overwrite  = keep existing  (unchanged values omitted)");
+    for field in &plan.fields {
+        match field.kind {
+            ChangeKind::Add => println!("  + {} = {}", field.path, field.value),
+            ChangeKind::Overwrite => println!(
+                "  ~ {} = {} (was {})",
+                field.path,
+                field.value,
+                field.current.as_deref().unwrap_or_default()
+            ),
+            ChangeKind::Keep => println!(
+                "  = {} keeps {} (OpenClaw has {}; pass --overwrite to replace)",
+                field.path,
+                field.current.as_deref().unwrap_or_default(),
+                field.value
+            ),
+            ChangeKind::Unchanged => {}
+        }
+    }
+    for file in &plan.files {
+        let marker = match file.kind {
+            ChangeKind::Add => "+",
+            ChangeKind::Overwrite => "~",
+            ChangeKind::Keep => "=",
+            ChangeKind::Unchanged => continue,
+        };
+        println!("  {marker} file {}", file.target.display());
+    }
+    if !plan.unsupported.is_empty() {
+        println!("  Not migrated (no ZeroClaw equivalent):");
+        for path in &plan.unsupported {
+            println!("    - {path}");
+        }
+    }
+}
+
+/// Work out every change the migration would make, and the config with the
+/// `Add`/`Overwrite` changes applied. Nothing is written.
+pub fn plan_config_migration(
+    config: &Config,
+    source_workspace: &Path,
+    overwrite: bool,
+) -> Result<(ConfigMigrationPlan, Config)> {
+    let mut plan = ConfigMigrationPlan::default();
+    let mut migrated = config.clone();
+
+    if let Some(path) = find_openclaw_config(source_workspace) {
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let json = parse_openclaw_json(&text)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        let mut collector = Collector::new(&json);
+        collector.collect(&json);
+        plan.unsupported = collector.unsupported;
+
+        let original = config_table(config)?;
+        let mut table = original.clone();
+        for mapped in collector.mapped {
+            let segments: Vec<&str> = mapped.path.split('.').collect();
+            let current = get_path(&original, &segments).filter(|v| !is_unset(v));
+            let desired = match (&mapped.value, current) {
+                (toml::Value::Array(new), Some(toml::Value::Array(existing)))
+                    if mapped.additive =>
+                {
+                    let mut merged = existing.clone();
+                    merged.extend(new.iter().filter(|v| !existing.contains(v)).cloned());
+                    toml::Value::Array(merged)
+                }
+                _ => mapped.value.clone(),
+            };
+            let kind = match current {
+                None => ChangeKind::Add,
+                Some(existing) if *existing == desired => ChangeKind::Unchanged,
+                Some(_) if mapped.additive || overwrite => ChangeKind::Overwrite,
+                Some(_) => ChangeKind::Keep,
+            };
+            if kind.writes() {
+                set_path(&mut table, &segments, desired.clone());
+            }
+            let display = |value: &toml::Value| {
+                if mapped.secret {
+                    "****".to_string()
+                } else {
+                    value.to_string()
+                }
+            };
+            plan.fields.push(FieldChange {
+                path: mapped.path.clone(),
+                kind,
+                value: display(&desired),
+                current: current.map(display),
+            });
+        }
+        if plan.writes_config() {
+            reload_sections(&mut migrated, &table)?;
+        }
+        plan.config_source = Some(path);
+    }
+
+    let workspace = config.agent_workspace_dir(DEFAULT_ALIAS);
+    for name in IDENTITY_FILES {
+        let source = source_workspace.join(name);
+        if !source.is_file() {
+            continue;
+        }
+        let target = workspace.join(name);
+        let kind = if !target.exists() {
+            ChangeKind::Add
+        } else if fs::read(&source).ok() == fs::read(&target).ok() {
+            ChangeKind::Unchanged
+        } else if overwrite {
+            ChangeKind::Overwrite
+        } else {
+            ChangeKind::Keep
+        };
+        plan.files.push(FileChange {
+            source,
+            target,
+            kind,
+        });
+    }
+
+    Ok((plan, migrated))
+}
+
+/// `openclaw.json` lives next to the workspace (`~/.openclaw/openclaw.json`
+/// for `~/.openclaw/workspace`); a copy inside the workspace is also accepted.
+fn find_openclaw_config(source_workspace: &Path) -> Option<PathBuf> {
+    std::iter::once(source_workspace.join(CONFIG_FILE))
+        .chain(source_workspace.parent().map(|p| p.join(CONFIG_FILE)))
+        .find(|p| p.is_file())
+}
+
+/// One value read from OpenClaw, addressed by its ZeroClaw config path.
+struct Mapped {
+    path: String,
+    value: toml::Value,
+    secret: bool,
+    /// Lists that gain entries instead of being replaced (agent channels).
+    additive: bool,
+}
+
+struct Collector {
+    /// OpenClaw `env` block, used for provider keys and `${VAR}` references.
+    env: Map<String, Json>,
+    used_env: HashSet<String>,
+    mapped: Vec<Mapped>,
+    unsupported: Vec<String>,
+}
+
+impl Collector {
+    fn new(json: &Json) -> Self {
+        let mut env = Map::new();
+        if let Some(block) = json.get("env").and_then(Json::as_object) {
+            for (key, value) in block {
+                match (key.as_str(), value) {
+                    ("vars", Json::Object(vars)) => env.extend(vars.clone()),
+                    (_, Json::String(_)) => {
+                        env.insert(key.clone(), value.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Self {
+            env,
+            used_env: HashSet::new(),
+            mapped: Vec::new(),
+            unsupported: Vec::new(),
+        }
+    }
+
+    fn collect(&mut self, json: &Json) {
+        let Some(root) = json.as_object() else {
+            self.unsupported
+                .push("(top level is not an object)".to_string());
+            return;
+        };
+        for (key, value) in root {
+            match key.as_str() {
+                "channels" => self.collect_channels(value),
+                "agents" => self.collect_agents(value),
+                "models" => self.collect_models(value),
+                "env" => {}
+                key if IGNORED_TOP_LEVEL.contains(&key) => {}
+                key => self.unsupported.push(key.to_string()),
+            }
+        }
+        self.collect_env_keys();
+    }
+
+    fn push(&mut self, path: String, value: toml::Value, secret: bool, additive: bool) {
+        if let Some(existing) = self.mapped.iter_mut().find(|m| m.path == path) {
+            // Additive lists collect every source; otherwise the first source
+            // wins, so an explicit `models.providers` key beats an `env` one.
+            if let (true, toml::Value::Array(items), toml::Value::Array(new)) =
+                (additive, &mut existing.value, value)
+            {
+                for item in new {
+                    if !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+            }
+            return;
+        }
+        self.mapped.push(Mapped {
+            path,
+            value,
+            secret,
+            additive,
+        });
+    }
+
+    fn set(&mut self, path: String, value: impl Into<toml::Value>) {
+        self.push(path, value.into(), false, false);
+    }
+
+    /// Record a token or key, resolving OpenClaw's `${VAR}` substitution from
+    /// its `env` block and then the process environment.
+    fn secret(&mut self, path: String, source: &str, raw: &str) {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            return;
+        }
+        let value = match raw.strip_prefix("${").and_then(|r| r.strip_suffix('}')) {
+            Some(var) => {
+                self.used_env.insert(var.to_string());
+                let resolved = self
+                    .env
+                    .get(var)
+                    .and_then(Json::as_str)
+                    .map(str::to_string)
+                    .or_else(|| std::env::var(var).ok());
+                match resolved {
+                    Some(value) if !value.trim().is_empty() => value,
+                    _ => {
+                        self.unsupported
+                            .push(format!("{source} (unresolved ${{{var}}})"));
+                        return;
+                    }
+                }
+            }
+            None => raw.to_string(),
+        };
+        self.push(path, toml::Value::String(value), true, false);
+    }
+
+    fn collect_channels(&mut self, channels: &Json) {
+        let Some(channels) = channels.as_object() else {
+            return;
+        };
+        for (kind, section) in channels {
+            let source = format!("channels.{kind}");
+            let Some(section) = section.as_object() else {
+                self.unsupported.push(source);
+                continue;
+            };
+            if !matches!(kind.as_str(), "telegram" | "discord" | "slack") {
+                self.unsupported.push(source);
+                continue;
+            }
+            let accounts = section.get("accounts").and_then(Json::as_object);
+            if has_token(kind, section) {
+                self.collect_channel_account(kind, DEFAULT_ALIAS, section, &source);
+            } else if accounts.is_none() {
+                self.unsupported.push(format!("{source} (no bot token)"));
+            }
+            for (id, account) in accounts.into_iter().flatten() {
+                let account_source = format!("{source}.accounts.{id}");
+                let alias = id.to_ascii_lowercase();
+                match account.as_object() {
+                    Some(account) if validate_alias_key(&alias).is_ok() => {
+                        self.collect_channel_account(kind, &alias, account, &account_source);
+                    }
+                    _ => self
+                        .unsupported
+                        .push(format!("{account_source} (not a valid ZeroClaw alias)")),
+                }
+            }
+        }
+    }
+
+    fn collect_channel_account(
+        &mut self,
+        kind: &str,
+        alias: &str,
+        section: &Map<String, Json>,
+        source: &str,
+    ) {
+        let target = format!("channels.{kind}.{alias}");
+        let enabled = section
+            .get("enabled")
+            .and_then(Json::as_bool)
+            .unwrap_or(true);
+        self.set(format!("{target}.enabled"), enabled);
+
+        let mut allow_from: Vec<String> = Vec::new();
+        for (key, value) in section {
+            let handled = match (kind, key.as_str()) {
+                (_, "enabled" | "accounts" | "name") => true,
+                ("telegram", "botToken") | ("discord", "token") | ("slack", "botToken") => {
+                    if let Some(token) = value.as_str() {
+                        self.secret(
+                            format!("{target}.bot_token"),
+                            &format!("{source}.{key}"),
+                            token,
+                        );
+                    }
+                    true
+                }
+                ("telegram", "tokenFile") => {
+                    if let Some(token) = value
+                        .as_str()
+                        .and_then(|p| fs::read_to_string(expand_home(p)).ok())
+                    {
+                        self.secret(
+                            format!("{target}.bot_token"),
+                            &format!("{source}.{key}"),
+                            &token,
+                        );
+                    } else {
+                        self.unsupported
+                            .push(format!("{source}.{key} (file not readable)"));
+                    }
+                    true
+                }
+                ("slack", "appToken") => {
+                    if let Some(token) = value.as_str() {
+                        self.secret(
+                            format!("{target}.app_token"),
+                            &format!("{source}.{key}"),
+                            token,
+                        );
+                    }
+                    true
+                }
+                ("discord", "guilds") => {
+                    self.set_id_keys(format!("{target}.guild_ids"), value);
+                    true
+                }
+                ("slack", "channels") => {
+                    self.set_id_keys(format!("{target}.channel_ids"), value);
+                    true
+                }
+                (_, "allowFrom") => {
+                    allow_from.extend(string_list(value));
+                    true
+                }
+                ("discord" | "slack", "dm") => {
+                    for (dm_key, dm_value) in value.as_object().into_iter().flatten() {
+                        if dm_key == "allowFrom" {
+                            allow_from.extend(string_list(dm_value));
+                        } else if dm_key != "enabled" {
+                            self.unsupported.push(format!("{source}.dm.{dm_key}"));
+                        }
+                    }
+                    true
+                }
+                _ => false,
+            };
+            if !handled {
+                self.unsupported.push(format!("{source}.{key}"));
+            }
+        }
+
+        let channel_ref = format!("{kind}.{alias}");
+        let peers: Vec<toml::Value> = allow_from
+            .iter()
+            .map(|entry| strip_channel_prefix(entry))
+            .filter(|entry| !entry.is_empty() && *entry != "*")
+            .map(|entry| toml::Value::String(entry.to_string()))
+            .collect();
+        if !peers.is_empty() {
+            let mut group = toml::Table::new();
+            group.insert("channel".into(), channel_ref.clone().into());
+            group.insert(
+                "agents".into(),
+                toml::Value::Array(vec![DEFAULT_ALIAS.into()]),
+            );
+            group.insert("external_peers".into(), toml::Value::Array(peers));
+            self.set(
+                format!("peer_groups.{kind}_{alias}"),
+                toml::Value::Table(group),
+            );
+        }
+        self.push(
+            format!("agents.{DEFAULT_ALIAS}.channels"),
+            toml::Value::Array(vec![channel_ref.into()]),
+            false,
+            true,
+        );
+    }
+
+    fn set_id_keys(&mut self, path: String, value: &Json) {
+        let ids: Vec<toml::Value> = value
+            .as_object()
+            .into_iter()
+            .flat_map(Map::keys)
+            .filter(|id| id.as_str() != "*")
+            .map(|id| toml::Value::String(id.clone()))
+            .collect();
+        if !ids.is_empty() {
+            self.set(path, toml::Value::Array(ids));
+        }
+    }
+
+    fn collect_agents(&mut self, agents: &Json) {
+        for (key, value) in agents.as_object().into_iter().flatten() {
+            if key != "defaults" {
+                self.unsupported.push(format!("agents.{key}"));
+                continue;
+            }
+            for (key, value) in value.as_object().into_iter().flatten() {
+                match key.as_str() {
+                    "model" => self.collect_model(value),
+                    // The workspace itself is what `--source` points at.
+                    "workspace" => {}
+                    _ => self.unsupported.push(format!("agents.defaults.{key}")),
+                }
+            }
+        }
+    }
+
+    fn collect_model(&mut self, model: &Json) {
+        let (primary, fallbacks) = match model {
+            Json::String(primary) => (Some(primary.as_str()), Vec::new()),
+            Json::Object(model) => (
+                model.get("primary").and_then(Json::as_str),
+                model.get("fallbacks").map(string_list).unwrap_or_default(),
+            ),
+            _ => (None, Vec::new()),
+        };
+        let Some(primary) = primary else {
+            return;
+        };
+        let Some((provider, model_id)) = split_model_ref(primary) else {
+            self.unsupported
+                .push(format!("agents.defaults.model.primary ({primary})"));
+            return;
+        };
+        let target = format!("providers.models.{provider}.{DEFAULT_ALIAS}");
+        self.set(format!("{target}.model"), model_id);
+        self.set(
+            format!("agents.{DEFAULT_ALIAS}.model_provider"),
+            format!("{provider}.{DEFAULT_ALIAS}"),
+        );
+
+        let mut same_provider = Vec::new();
+        for fallback in fallbacks {
+            match split_model_ref(&fallback) {
+                Some((p, m)) if p == provider => same_provider.push(toml::Value::String(m)),
+                _ => self
+                    .unsupported
+                    .push(format!("agents.defaults.model.fallbacks ({fallback})")),
+            }
+        }
+        if !same_provider.is_empty() {
+            self.set(
+                format!("{target}.fallback_models"),
+                toml::Value::Array(same_provider),
+            );
+        }
+    }
+
+    fn collect_models(&mut self, models: &Json) {
+        for (key, value) in models.as_object().into_iter().flatten() {
+            if key != "providers" {
+                self.unsupported.push(format!("models.{key}"));
+                continue;
+            }
+            for (id, provider) in value.as_object().into_iter().flatten() {
+                let source = format!("models.providers.{id}");
+                let Some(provider_type) = map_provider_type(id) else {
+                    self.unsupported.push(source);
+                    continue;
+                };
+                let target = format!("providers.models.{provider_type}.{DEFAULT_ALIAS}");
+                for (key, value) in provider.as_object().into_iter().flatten() {
+                    match (key.as_str(), value.as_str()) {
+                        ("apiKey", Some(key_value)) => self.secret(
+                            format!("{target}.api_key"),
+                            &format!("{source}.apiKey"),
+                            key_value,
+                        ),
+                        ("baseUrl", Some(uri)) => self.set(format!("{target}.uri"), uri),
+                        _ => self.unsupported.push(format!("{source}.{key}")),
+                    }
+                }
+            }
+        }
+    }
+
+    /// `<PROVIDER>_API_KEY` entries in OpenClaw's `env` block become that
+    /// provider's key; other variables are reported unless a `${VAR}`
+    /// reference already consumed them.
+    fn collect_env_keys(&mut self) {
+        let env: Vec<(String, String)> = self
+            .env
+            .iter()
+            .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+            .collect();
+        for (name, value) in env {
+            let provider = name
+                .strip_suffix("_API_KEY")
+                .and_then(|p| map_provider_type(&p.to_ascii_lowercase()));
+            match provider {
+                Some(provider_type) => self.secret(
+                    format!("providers.models.{provider_type}.{DEFAULT_ALIAS}.api_key"),
+                    &format!("env.{name}"),
+                    &value,
+                ),
+                None if self.used_env.contains(&name) => {}
+                None => self.unsupported.push(format!("env.{name}")),
+            }
+        }
+    }
+}
+
+fn has_token(kind: &str, section: &Map<String, Json>) -> bool {
+    let keys: &[&str] = match kind {
+        "telegram" => &["botToken", "tokenFile"],
+        "discord" => &["token"],
+        _ => &["botToken"],
+    };
+    keys.iter().any(|k| section.contains_key(*k))
+}
+
+fn string_list(value: &Json) -> Vec<String> {
+    value
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| match v {
+            Json::String(s) => Some(s.trim().to_string()),
+            Json::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// OpenClaw allowlists may prefix ids with the channel (`tg:123`,
+/// `telegram:123`, `discord:456`); ZeroClaw peers are bare ids.
+fn strip_channel_prefix(entry: &str) -> &str {
+    entry
+        .split_once(':')
+        .filter(|(prefix, _)| {
+            matches!(
+                prefix.to_ascii_lowercase().as_str(),
+                "tg" | "telegram" | "discord" | "slack" | "user"
+            )
+        })
+        .map_or(entry, |(_, id)| id)
+        .trim()
+}
+
+/// Split an OpenClaw `provider/model` reference into the ZeroClaw provider
+/// type and model id. The model keeps any further slashes
+/// (`openrouter/anthropic/claude-sonnet-4-5`).
+fn split_model_ref(reference: &str) -> Option<(&'static str, String)> {
+    let (provider, model) = reference.trim().split_once('/')?;
+    let model = model.trim();
+    if model.is_empty() {
+        return None;
+    }
+    Some((map_provider_type(provider)?, model.to_string()))
+}
+
+/// ZeroClaw provider type for an OpenClaw provider id.
+fn map_provider_type(id: &str) -> Option<&'static str> {
+    let id = id.trim().to_ascii_lowercase();
+    let renamed = match id.as_str() {
+        "google" | "google-gemini" => "gemini",
+        "github-copilot" => "copilot",
+        "amazon-bedrock" => "bedrock",
+        "vercel-ai-gateway" => "vercel",
+        "x-ai" => "xai",
+        "z-ai" => "zai",
+        "lm-studio" => "lmstudio",
+        other => other,
+    };
+    let slot = renamed.replace('-', "_");
+    ModelProviders::slot_names()
+        .iter()
+        .find(|name| **name == slot)
+        .copied()
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => directories::UserDirs::new()
+            .map(|u| u.home_dir().join(rest))
+            .unwrap_or_else(|| PathBuf::from(path)),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Parse `openclaw.json`. OpenClaw reads it as JSON5, so hand-edited files
+/// may carry comments, trailing commas, single-quoted strings and bare keys;
+/// those are rewritten to strict JSON first.
+fn parse_openclaw_json(text: &str) -> Result<Json> {
+    if let Ok(value) = serde_json::from_str(text) {
+        return Ok(value);
+    }
+    serde_json::from_str(&json5_to_json(text)).context("not valid JSON or JSON5")
+}
+
+fn json5_to_json(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut pending_comma = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            i += 2;
+            continue;
+        }
+        if c.is_whitespace() {
+            out.push(c);
+            i += 1;
+            continue;
+        }
+        if c == ',' {
+            pending_comma = true;
+            i += 1;
+            continue;
+        }
+        if pending_comma {
+            if c != '}' && c != ']' {
+                out.push(',');
+            }
+            pending_comma = false;
+        }
+        if c == '"' || c == '\'' {
+            out.push('"');
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                match chars[i] {
+                    '\\' if i + 1 < chars.len() => {
+                        if chars[i + 1] != '\'' {
+                            out.push('\\');
+                        }
+                        out.push(chars[i + 1]);
+                        i += 2;
+                        continue;
+                    }
+                    '"' => out.push_str("\\\""),
+                    other => out.push(other),
+                }
+                i += 1;
+            }
+            out.push('"');
+            i += 1;
+            continue;
+        }
+        if c.is_ascii_alphabetic() || c == '_' || c == '$' {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_ascii_alphanumeric() || chars[i] == '_' || chars[i] == '$')
+            {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let next = chars[i..].iter().find(|ch| !ch.is_whitespace());
+            if next == Some(&':') {
+                out.push('"');
+                out.push_str(&word);
+                out.push('"');
+            } else {
+                out.push_str(&word);
+            }
+            continue;
+        }
+        out.push(c);
+        i += 1;
+    }
+    out
+}
+
+fn config_table(config: &Config) -> Result<toml::Table> {
+    toml::Value::try_from(config)
+        .context("Failed to serialize config")?
+        .try_into()
+        .context("Serialized config is not a table")
+}
+
+fn get_path<'t>(table: &'t toml::Table, segments: &[&str]) -> Option<&'t toml::Value> {
+    let (last, parents) = segments.split_last()?;
+    let mut current = table;
+    for segment in parents {
+        current = current.get(*segment)?.as_table()?;
+    }
+    current.get(*last)
+}
+
+fn set_path(table: &mut toml::Table, segments: &[&str], value: toml::Value) {
+    let Some((last, parents)) = segments.split_last() else {
+        return;
+    };
+    let mut current = table;
+    for segment in parents {
+        let entry = current
+            .entry(segment.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        if !entry.is_table() {
+            *entry = toml::Value::Table(toml::Table::new());
+        }
+        let toml::Value::Table(next) = entry else {
+            return;
+        };
+        current = next;
+    }
+    current.insert((*last).to_string(), value);
+}
+
+fn is_unset(value: &toml::Value) -> bool {
+    match value {
+        toml::Value::String(s) => s.trim().is_empty(),
+        toml::Value::Array(a) => a.is_empty(),
+        toml::Value::Table(t) => t.is_empty(),
+        _ => false,
+    }
+}
+
+/// Re-read the sections the migration writes from the edited table. Agent
+/// runtime state is not serialized, so it is carried over by alias.
+fn reload_sections(config: &mut Config, table: &toml::Table) -> Result<()> {
+    fn section<T: DeserializeOwned + Default>(table: &toml::Table, key: &str) -> Result<T> {
+        match table.get(key) {
+            Some(value) => value
+                .clone()
+                .try_into()
+                .with_context(|| format!("Migrated [{key}] section is invalid")),
+            None => Ok(T::default()),
+        }
+    }
+    config.channels = section(table, "channels")?;
+    config.providers = section(table, "providers")?;
+    config.peer_groups = section(table, "peer_groups")?;
+    let mut previous = std::mem::take(&mut config.agents);
+    config.agents = section(table, "agents")?;
+    for (alias, agent) in &mut config.agents {
+        if let Some(old) = previous.remove(alias) {
+            agent.resolved = old.resolved;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use zeroclaw_config::schema::TelegramConfig;
+
+    /// A fake `~/.openclaw` tree: config next to the workspace, identity
+    /// files inside it.
+    fn openclaw_tree(config: &str) -> (TempDir, PathBuf) {
+        let root = TempDir::new().unwrap();
+        let workspace = root.path().join("workspace");
+        fs::create_dir_all(&workspace).unwrap();
+        fs::write(root.path().join(CONFIG_FILE), config).unwrap();
+        fs::write(workspace.join("SOUL.md"), "# Soul\nCalm and direct.\n").unwrap();
+        fs::write(workspace.join("USER.md"), "# User\nPrefers Rust.\n").unwrap();
+        (root, workspace)
+    }
+
+    fn target_config(dir: &Path) -> Config {
+        Config {
+            data_dir: dir.join("data"),
+            config_path: dir.join("config.toml"),
+            ..Config::default()
+        }
+    }
+
+    const OPENCLAW_CONFIG: &str = r#"{
+      // Written by hand, so JSON5.
+      agents: {
+        defaults: {
+          workspace: "~/.openclaw/workspace",
+          model: {
+            primary: "anthropic/claude-opus-4-6",
+            fallbacks: ["anthropic/claude-sonnet-4-5", "openai/gpt-5"],
+          },
+          thinkingDefault: "low",
+        },
+      },
+      channels: {
+        telegram: {
+          enabled: true,
+          botToken: "123456:telegram-token",
+          dmPolicy: "allowlist",
+          allowFrom: ["tg:1001", "1002", "*"],
+        },
+        discord: {
+          token: "${DISCORD_BOT_TOKEN}",
+          dm: { allowFrom: ["555"], groupEnabled: false },
+          guilds: { "900": { requireMention: true } },
+        },
+        slack: {
+          accounts: {
+            work: { botToken: "xoxb-work", appToken: "xapp-work" },
+          },
+        },
+        whatsapp: { allowFrom: ["+15550001"] },
+      },
+      env: {
+        OPENAI_API_KEY: "sk-openai",
+        DISCORD_BOT_TOKEN: "discord-token",
+        vars: { UNRELATED: "x" },
+      },
+      models: {
+        providers: {
+          anthropic: { apiKey: "sk-ant", baseUrl: "https://proxy.example/v1", api: "anthropic-messages" },
+          "my-local": { baseUrl: "http://127.0.0.1:8080" },
+        },
+      },
+      gateway: { port: 18789 },
+      meta: { lastTouchedVersion: "2026.1.1" },
+    }"#;
+
+    fn field<'p>(plan: &'p ConfigMigrationPlan, path: &str) -> &'p FieldChange {
+        plan.fields
+            .iter()
+            .find(|f| f.path == path)
+            .unwrap_or_else(|| panic!("no planned change for {path}"))
+    }
+
+    #[test]
+    fn maps_channels_models_and_keys_from_openclaw_json() {
+        let (_root, workspace) = openclaw_tree(OPENCLAW_CONFIG);
+        let target = TempDir::new().unwrap();
+        let config = target_config(target.path());
+
+        let (plan, migrated) = plan_config_migration(&config, &workspace, false).unwrap();
+
+        let telegram = &migrated.channels.telegram["default"];
+        assert!(telegram.enabled);
+        assert_eq!(telegram.bot_token, "123456:telegram-token");
+        let discord = &migrated.channels.discord["default"];
+        assert_eq!(discord.bot_token, "discord-token");
+        assert_eq!(discord.guild_ids, vec!["900"]);
+        let slack = &migrated.channels.slack["work"];
+        assert_eq!(slack.bot_token.as_deref(), Some("xoxb-work"));
+        assert_eq!(slack.app_token.as_deref(), Some("xapp-work"));
+
+        let peers: Vec<&str> = migrated.peer_groups["telegram_default"]
+            .external_peers
+            .iter()
+            .map(|p| p.as_str())
+            .collect();
+        assert_eq!(peers, vec!["1001", "1002"]);
+
+        let anthropic = migrated
+            .providers
+            .models
+            .find("anthropic", "default")
+            .unwrap();
+        assert_eq!(anthropic.model.as_deref(), Some("claude-opus-4-6"));
+        assert_eq!(anthropic.fallback_models, vec!["claude-sonnet-4-5"]);
+        assert_eq!(anthropic.api_key.as_deref(), Some("sk-ant"));
+        assert_eq!(anthropic.uri.as_deref(), Some("https://proxy.example/v1"));
+        let openai = migrated.providers.models.find("openai", "default").unwrap();
+        assert_eq!(openai.api_key.as_deref(), Some("sk-openai"));
+
+        let agent = &migrated.agents["default"];
+        assert_eq!(agent.model_provider.as_str(), "anthropic.default");
+        let mut channels: Vec<&str> = agent.channels.iter().map(|c| c.as_str()).collect();
+        channels.sort_unstable();
+        assert_eq!(
+            channels,
+            vec!["discord.default", "slack.work", "telegram.default"]
+        );
+
+        // Secrets are masked in the preview.
+        assert_eq!(
+            field(&plan, "channels.telegram.default.bot_token").value,
+            "****"
+        );
+        for expected in [
+            "agents.defaults.thinkingDefault",
+            "agents.defaults.model.fallbacks (openai/gpt-5)",
+            "channels.telegram.dmPolicy",
+            "channels.discord.dm.groupEnabled",
+            "channels.whatsapp",
+            "models.providers.anthropic.api",
+            "models.providers.my-local",
+            "env.UNRELATED",
+            "gateway",
+        ] {
+            assert!(
+                plan.unsupported.iter().any(|u| u == expected),
+                "{expected} missing from {:?}",
+                plan.unsupported
+            );
+        }
+        assert!(!plan.unsupported.iter().any(|u| u.starts_with("meta")));
+        assert!(
+            !plan
+                .unsupported
+                .iter()
+                .any(|u| u.contains("DISCORD_BOT_TOKEN"))
+        );
+
+        let files: Vec<PathBuf> = plan.files.iter().map(|f| f.target.clone()).collect();
+        let agent_workspace = config.agent_workspace_dir("default");
+        assert_eq!(
+            files,
+            vec![
+                agent_workspace.join("SOUL.md"),
+                agent_workspace.join("USER.md")
+            ]
+        );
+        assert!(plan.files.iter().all(|f| f.kind == ChangeKind::Add));
+    }
+
+    #[test]
+    fn existing_settings_are_kept_without_overwrite() {
+        let (_root, workspace) = openclaw_tree(OPENCLAW_CONFIG);
+        let target = TempDir::new().unwrap();
+        let mut config = target_config(target.path());
+        config.channels.telegram.insert(
+            "default".into(),
+            TelegramConfig {
+                bot_token: "existing-token".into(),
+                ..TelegramConfig::default()
+            },
+        );
+        let soul = config.agent_workspace_dir("default").join("SOUL.md");
+        fs::create_dir_all(soul.parent().unwrap()).unwrap();
+        fs::write(&soul, "# My own soul\n").unwrap();
+
+        let (plan, migrated) = plan_config_migration(&config, &workspace, false).unwrap();
+        assert_eq!(
+            migrated.channels.telegram["default"].bot_token,
+            "existing-token"
+        );
+        let token = field(&plan, "channels.telegram.default.bot_token");
+        assert_eq!(token.kind, ChangeKind::Keep);
+        assert_eq!(token.current.as_deref(), Some("****"));
+        assert_eq!(
+            plan.files.iter().find(|f| f.target == soul).map(|f| f.kind),
+            Some(ChangeKind::Keep)
+        );
+
+        let (plan, migrated) = plan_config_migration(&config, &workspace, true).unwrap();
+        assert_eq!(
+            migrated.channels.telegram["default"].bot_token,
+            "123456:telegram-token"
+        );
+        assert_eq!(
+            field(&plan, "channels.telegram.default.bot_token").kind,
+            ChangeKind::Overwrite
+        );
+    }
+
+    #[tokio::test]
+    async fn apply_writes_encrypted_config_and_copies_identity_files() {
+        let (_root, workspace) = openclaw_tree(OPENCLAW_CONFIG);
+        let target = TempDir::new().unwrap();
+        let config = target_config(target.path());
+
+        migrate_openclaw_config(&config, Some(workspace.clone()), true, false)
+            .await
+            .unwrap();
+        assert!(!config.config_path.exists(), "dry run must not write");
+
+        migrate_openclaw_config(&config, Some(workspace), false, false)
+            .await
+            .unwrap();
+        let saved = fs::read_to_string(&config.config_path).unwrap();
+        assert!(saved.contains("[channels.telegram.default]"));
+        assert!(
+            !saved.contains("123456:telegram-token"),
+            "bot token must be encrypted at rest"
+        );
+        assert!(saved.contains("enc2:"));
+        let soul = config.agent_workspace_dir("default").join("SOUL.md");
+        assert_eq!(
+            fs::read_to_string(soul).unwrap(),
+            "# Soul\nCalm and direct.\n"
+        );
+    }
+
+    #[test]
+    fn json5_comments_trailing_commas_and_bare_keys_parse() {
+        let parsed = parse_openclaw_json(
+            "{ /* block */ a: 'it\\'s \"quoted\"', // line\n b: [1, 2,], 'c': { d: true, }, }",
+        )
+        .unwrap();
+        assert_eq!(parsed["a"], "it's \"quoted\"");
+        assert_eq!(parsed["b"], serde_json::json!([1, 2]));
+        assert_eq!(parsed["c"]["d"], true);
+
+        // `//` inside a string is not a comment.
+        let parsed = parse_openclaw_json("{ url: \"https://x.example/a\", }").unwrap();
+        assert_eq!(parsed["url"], "https://x.example/a");
+    }
+}
@@ -0,0 +1,110 @@
+//! Per-workspace cache of provider model catalogs (`state/models_cache.json`).
+//!
+//! `zeroclaw doctor models` writes it after refreshing catalogs; channel
+//! `/models` previews read it so listing models does not hit the provider.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const MODEL_CACHE_FILE: &str = "models_cache.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ModelCacheState {
+    #[serde(default)]
+    entries: Vec<ModelCacheEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ModelCacheEntry {
+    model_provider: String,
+    models: Vec<String>,
+}
+
+fn cache_path(workspace_dir: &Path) -> PathBuf {
+    workspace_dir.join("state").join(MODEL_CACHE_FILE)
+}
+
+/// A missing or unreadable cache is treated as empty.
+fn load(workspace_dir: &Path) -> ModelCacheState {
+    std::fs::read_to_string(cache_path(workspace_dir))
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+/// Models cached for `provider_ref` in this workspace; empty when none are.
+pub fn cached_models(workspace_dir: &Path, provider_ref: &str) -> Vec<String> {
+    load(workspace_dir)
+        .entries
+        .into_iter()
+        .find(|entry| entry.model_provider == provider_ref)
+        .map(|entry| entry.models)
+        .unwrap_or_default()
+}
+
+/// Merge freshly fetched `(provider ref, models)` catalogs into the
+/// workspace's cache, replacing entries for the same provider ref and
+/// keeping the rest.
+pub fn save_catalogs(workspace_dir: &Path, catalogs: &[(String, Vec<String>)]) -> Result<()> {
+    let mut state = load(workspace_dir);
+    for (provider_ref, models) in catalogs {
+        state
+            .entries
+            .retain(|entry| &entry.model_provider != provider_ref);
+        state.entries.push(ModelCacheEntry {
+            model_provider: provider_ref.clone(),
+            models: models.clone(),
+        });
+    }
+    let path = cache_path(workspace_dir);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(&state)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn save_catalogs_replaces_only_the_refreshed_provider() {
+        let tmp = TempDir::new().unwrap();
+        save_catalogs(
+            tmp.path(),
+            &[
+                ("xai.default".to_string(), vec!["grok-4".to_string()]),
+                ("openai.default".to_string(), vec!["gpt-5".to_string()]),
+            ],
+        )
+        .unwrap();
+        save_catalogs(
+            tmp.path(),
+            &[(
+                "xai.default".to_string(),
+                vec!["grok-4".to_string(), "grok-4-mini".to_string()],
+            )],
+        )
+        .unwrap();
+
+        assert_eq!(
+            cached_models(tmp.path(), "xai.default"),
+            ["grok-4", "grok-4-mini"]
+        );
+        assert_eq!(cached_models(tmp.path(), "openai.default"), ["gpt-5"]);
+        assert!(cached_models(tmp.path(), "groq.default").is_empty());
+    }
+
+    #[test]
+    fn malformed_cache_reads_as_empty() {
+        let tmp = TempDir::new().unwrap();
+        assert!(cached_models(tmp.path(), "xai.default").is_empty());
+
+        std::fs::create_dir_all(tmp.path().join("state")).unwrap();
+        std::fs::write(cache_path(tmp.path()), "not json").unwrap();
+        assert!(cached_models(tmp.path(), "xai.default").is_empty());
+    }
+}
@@ -0,0 +1,284 @@
+//! Per-day activity counts for the daily digest.
+//!
+//! While `[observability.digest]` is enabled the daemon installs an
+//! [`ActivityLedger`]; every observer built by `create_observer` feeds it.
+//! Counting happens in memory on the hot path, and the digest component
+//! writes the current day to `<data_dir>/state/activity/<YYYY-MM-DD>.json`
+//! once a minute, which is what `zeroclaw digest preview` reads back. Days
+//! follow the host's local time.
+
+use super::traits::ObserverEvent;
+use anyhow::{Context, Result};
+use chrono::{Local, NaiveDate};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+/// What happened on one day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyActivity {
+    pub date: NaiveDate,
+    /// Inbound channel messages by channel.
+    #[serde(default)]
+    pub messages: BTreeMap<String, u64>,
+    /// Completed tool calls by tool name.
+    #[serde(default)]
+    pub tool_calls: BTreeMap<String, u64>,
+    #[serde(default)]
+    pub tool_failures: u64,
+    /// Errors by component (`model_provider`, `channel_send`, ...).
+    #[serde(default)]
+    pub errors: BTreeMap<String, u64>,
+    #[serde(default)]
+    pub input_tokens: u64,
+    #[serde(default)]
+    pub output_tokens: u64,
+    /// Sum of the per-turn cost the runtime reported.
+    #[serde(default)]
+    pub cost_usd: f64,
+    /// 👍 reactions to delivered replies.
+    #[serde(default)]
+    pub feedback_positive: u64,
+    /// 👎 reactions to delivered replies.
+    #[serde(default)]
+    pub feedback_negative: u64,
+}
+
+impl DailyActivity {
+    pub fn new(date: NaiveDate) -> Self {
+        Self {
+            date,
+            messages: BTreeMap::new(),
+            tool_calls: BTreeMap::new(),
+            tool_failures: 0,
+            errors: BTreeMap::new(),
+            input_tokens: 0,
+            output_tokens: 0,
+            cost_usd: 0.0,
+            feedback_positive: 0,
+            feedback_negative: 0,
+        }
+    }
+
+    pub fn record(&mut self, event: &ObserverEvent) {
+        match event {
+            ObserverEvent::ChannelReceive { channel, .. } => {
+                *self.messages.entry(channel.clone()).or_default() += 1;
+            }
+            ObserverEvent::ChannelSend { success: false, .. } => self.count_error("channel_send"),
+            ObserverEvent::ToolCall { tool, success, .. } => {
+                *self.tool_calls.entry(tool.clone()).or_default() += 1;
+                if !success {
+                    self.tool_failures += 1;
+                }
+            }
+            ObserverEvent::LlmResponse {
+                success,
+                input_tokens,
+                output_tokens,
+                ..
+            } => {
+                self.input_tokens += input_tokens.unwrap_or(0);
+                self.output_tokens += output_tokens.unwrap_or(0);
+                if !success {
+                    self.count_error("model_provider");
+                }
+            }
+            ObserverEvent::AgentEnd { cost_usd, .. } => {
+                self.cost_usd += cost_usd.unwrap_or(0.0);
+            }
+            ObserverEvent::Error { component, .. } => self.count_error(component),
+            ObserverEvent::ReplyFeedback { positive, .. } => {
+                if *positive {
+                    self.feedback_positive += 1;
+                } else {
+                    self.feedback_negative += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn count_error(&mut self, component: &str) {
+        *self.errors.entry(component.to_string()).or_default() += 1;
+    }
+}
+
+pub fn activity_dir(data_dir: &Path) -> PathBuf {
+    data_dir.join("state").join("activity")
+}
+
+fn day_path(data_dir: &Path, date: NaiveDate) -> PathBuf {
+    activity_dir(data_dir).join(format!("{date}.json"))
+}
+
+/// The persisted counts for `date`, or `None` when nothing was recorded.
+pub fn load_day(data_dir: &Path, date: NaiveDate) -> Result<Option<DailyActivity>> {
+    let path = day_path(data_dir, date);
+    let raw = match std::fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+    };
+    let day = serde_json::from_str(&raw)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+    Ok(Some(day))
+}
+
+fn save_day(data_dir: &Path, day: &DailyActivity) -> Result<()> {
+    let path = day_path(data_dir, day.date);
+    std::fs::create_dir_all(activity_dir(data_dir))
+        .with_context(|| format!("failed to create {}", activity_dir(data_dir).display()))?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_vec_pretty(day)?)
+        .with_context(|| format!("failed to write {}", tmp.display()))?;
+    std::fs::rename(&tmp, &path).with_context(|| format!("failed to write {}", path.display()))
+}
+
+struct LedgerState {
+    today: DailyActivity,
+    /// A day that ended since the last persist.
+    finished: Option<DailyActivity>,
+}
+
+/// In-memory counts for the current day, persisted by [`Self::persist`].
+pub struct ActivityLedger {
+    data_dir: PathBuf,
+    state: Mutex<LedgerState>,
+}
+
+impl ActivityLedger {
+    /// Resume today's counts from disk, so a daemon restart keeps them.
+    pub fn open(data_dir: &Path) -> Self {
+        let date = Local::now().date_naive();
+        let today = load_day(data_dir, date)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| DailyActivity::new(date));
+        Self {
+            data_dir: data_dir.to_path_buf(),
+            state: Mutex::new(LedgerState {
+                today,
+                finished: None,
+            }),
+        }
+    }
+
+    pub fn record(&self, event: &ObserverEvent) {
+        self.record_on(Local::now().date_naive(), event);
+    }
+
+    fn record_on(&self, date: NaiveDate, event: &ObserverEvent) {
+        let mut state = self.state.lock();
+        if state.today.date != date {
+            let ended = std::mem::replace(&mut state.today, DailyActivity::new(date));
+            state.finished = Some(ended);
+        }
+        state.today.record(event);
+    }
+
+    pub fn snapshot(&self) -> DailyActivity {
+        self.state.lock().today.clone()
+    }
+
+    /// Write the current day, and a day that just ended, to disk.
+    pub fn persist(&self) -> Result<()> {
+        let (finished, today) = {
+            let mut state = self.state.lock();
+            (state.finished.take(), state.today.clone())
+        };
+        if let Some(finished) = finished {
+            save_day(&self.data_dir, &finished)?;
+        }
+        save_day(&self.data_dir, &today)
+    }
+}
+
+static LEDGER: OnceLock<Arc<ActivityLedger>> = OnceLock::new();
+
+/// Start counting activity for the process. Later calls return the ledger
+/// installed first.
+pub fn install(data_dir: &Path) -> Arc<ActivityLedger> {
+    Arc::clone(LEDGER.get_or_init(|| Arc::new(ActivityLedger::open(data_dir))))
+}
+
+/// Count `event` when a ledger is installed.
+pub(crate) fn record(event: &ObserverEvent) {
+    if let Some(ledger) = LEDGER.get() {
+        ledger.record(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn tool_call(tool: &str, success: bool) -> ObserverEvent {
+        ObserverEvent::ToolCall {
+            tool: tool.into(),
+            tool_call_id: None,
+            duration: Duration::from_millis(5),
+            success,
+            arguments: None,
+            result: None,
+            channel: None,
+            agent_alias: None,
+            parent_agent_alias: None,
+            turn_id: None,
+        }
+    }
+
+    #[test]
+    fn ledger_counts_a_day_and_rolls_over_at_midnight() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let ledger = ActivityLedger::open(tmp.path());
+        let day = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let next = day.succ_opt().unwrap();
+
+        for channel in ["telegram", "telegram", "discord"] {
+            ledger.record_on(
+                day,
+                &ObserverEvent::ChannelReceive {
+                    channel: channel.into(),
+                    sender_hash: "abc".into(),
+                    turn_id: "t".into(),
+                },
+            );
+        }
+        ledger.record_on(day, &tool_call("shell", true));
+        ledger.record_on(day, &tool_call("shell", false));
+        ledger.record_on(
+            day,
+            &ObserverEvent::Error {
+                component: "heartbeat".into(),
+                message: "boom".into(),
+            },
+        );
+        for positive in [true, true, false] {
+            ledger.record_on(
+                day,
+                &ObserverEvent::ReplyFeedback {
+                    channel: "telegram".into(),
+                    positive,
+                },
+            );
+        }
+        ledger.record_on(next, &tool_call("web_fetch", true));
+        ledger.persist().unwrap();
+
+        let first = load_day(tmp.path(), day).unwrap().unwrap();
+        assert_eq!(first.messages["telegram"], 2);
+        assert_eq!(first.messages["discord"], 1);
+        assert_eq!(first.tool_calls["shell"], 2);
+        assert_eq!(first.tool_failures, 1);
+        assert_eq!(first.errors["heartbeat"], 1);
+        assert_eq!((first.feedback_positive, first.feedback_negative), (2, 1));
+
+        let second = load_day(tmp.path(), next).unwrap().unwrap();
+        assert_eq!(second.tool_calls.len(), 1);
+        assert!(second.messages.is_empty());
+    }
+}
@@ -0,0 +1,364 @@
+//! Daily digest (`[observability.digest]`).
+//!
+//! Summarizes one day of activity: channel messages, tool calls, errors,
+//! token usage and cost, and the words that came up most in memories stored
+//! that day. Each part comes from its own source (the [`activity`] ledger,
+//! the cost tracker, the memory backend); a part whose source is off or
+//! empty is reported as unavailable instead of failing the digest. The
+//! daemon's `digest` component sends the previous day's digest on the
+//! configured cron schedule.
+
+use super::activity::{self, DailyActivity};
+use crate::cron::{Schedule, next_run_for_schedule};
+use anyhow::{Result, bail};
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use tokio_util::sync::CancellationToken;
+use zeroclaw_api::memory_traits::ExportFilter;
+use zeroclaw_config::schema::Config;
+
+/// Daemon component name for the digest sender.
+pub const DIGEST_COMPONENT: &str = "digest";
+
+/// How often the component persists the ledger and checks the schedule.
+const TICK: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Entries listed per breakdown.
+const TOP_N: usize = 5;
+
+/// Words too common to be a topic.
+const STOPWORDS: &[&str] = &[
+    "about", "after", "again", "also", "been", "before", "being", "could", "does", "doing", "from",
+    "have", "here", "into", "just", "like", "more", "need", "only", "other", "please", "should",
+    "some", "than", "that", "their", "them", "then", "there", "these", "they", "this", "user",
+    "very", "want", "were", "what", "when", "where", "which", "while", "will", "with", "would",
+    "your",
+];
+
+/// Token and cost totals for the day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageTotals {
+    pub tokens: u64,
+    pub cost_usd: f64,
+    /// Provider requests, when the cost tracker supplied the totals.
+    pub requests: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Digest {
+    pub date: NaiveDate,
+    /// Built before the day ended (`zeroclaw digest preview`).
+    pub partial: bool,
+    pub activity: Option<DailyActivity>,
+    pub usage: Option<UsageTotals>,
+    pub memory_topics: Option<Vec<(String, usize)>>,
+}
+
+/// `date` as a UTC range over the host's local day.
+fn local_day_bounds(date: NaiveDate) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let start = Local
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+        .earliest()?;
+    let end = Local
+        .from_local_datetime(&date.succ_opt()?.and_hms_opt(0, 0, 0)?)
+        .earliest()?;
+    Some((start.with_timezone(&Utc), end.with_timezone(&Utc)))
+}
+
+/// Gather the digest for `date` from every source that is available.
+pub async fn build(config: &Config, date: NaiveDate, partial: bool) -> Digest {
+    let activity = activity::load_day(&config.data_dir, date).unwrap_or_else(|e| {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Unknown)
+                .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+            "digest: activity ledger unreadable; reporting without it"
+        );
+        None
+    });
+    let bounds = local_day_bounds(date);
+
+    let tracked = bounds.and_then(|(from, to)| {
+        let tracker =
+            crate::cost::CostTracker::get_or_init_global(config.cost.clone(), &config.data_dir)?;
+        let summary = tracker.get_summary_in_bounds(Some(from), Some(to)).ok()?;
+        Some(UsageTotals {
+            tokens: summary.total_tokens,
+            cost_usd: summary.session_cost_usd,
+            requests: Some(summary.request_count),
+        })
+    });
+    let usage = tracked.or_else(|| {
+        let activity = activity.as_ref()?;
+        let tokens = activity.input_tokens + activity.output_tokens;
+        (tokens > 0).then_some(UsageTotals {
+            tokens,
+            cost_usd: activity.cost_usd,
+            requests: None,
+        })
+    });
+
+    let memory_topics = match bounds {
+        Some((from, to)) => memory_topics(config, from, to).await,
+        None => None,
+    };
+
+    Digest {
+        date,
+        partial,
+        activity,
+        usage,
+        memory_topics,
+    }
+}
+
+async fn memory_topics(
+    config: &Config,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Option<Vec<(String, usize)>> {
+    let memory = zeroclaw_memory::create_memory_from_config(config, None).ok()?;
+    let entries = memory
+        .export(&ExportFilter {
+            since: Some(from.to_rfc3339()),
+            until: Some(to.to_rfc3339()),
+            ..ExportFilter::default()
+        })
+        .await
+        .ok()?;
+    Some(top_words(entries.iter().map(|e| e.content.as_str())))
+}
+
+/// Most frequent topic words, counted once per text.
+fn top_words<'a>(texts: impl Iterator<Item = &'a str>) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for text in texts {
+        let mut seen = std::collections::HashSet::new();
+        for word in text.split(|c: char| !c.is_alphanumeric()) {
+            let word = word.to_lowercase();
+            if word.chars().count() >= 4
+                && !word.chars().all(|c| c.is_ascii_digit())
+                && !STOPWORDS.contains(&word.as_str())
+                && seen.insert(word.clone())
+            {
+                *counts.entry(word).or_default() += 1;
+            }
+        }
+    }
+    let mut ranked: Vec<(String, usize)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(TOP_N);
+    ranked
+}
+
+/// `total (a 3, b 2, ...)` over the largest entries of `counts`.
+fn breakdown(counts: &BTreeMap<String, u64>) -> String {
+    let mut ranked: Vec<(&String, &u64)> = counts.iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    let top: Vec<String> = ranked
+        .iter()
+        .take(TOP_N)
+        .map(|(name, count)| format!("{name} {count}"))
+        .collect();
+    let total: u64 = counts.values().sum();
+    if top.is_empty() {
+        total.to_string()
+    } else {
+        format!("{total} ({})", top.join(", "))
+    }
+}
+
+impl Digest {
+    /// Compact plain-text report, one line per part.
+    pub fn render(&self) -> String {
+        let mut out = if self.partial {
+            format!("Digest for {} (so far)\n", self.date)
+        } else {
+            format!("Digest for {}\n", self.date)
+        };
+        match &self.activity {
+            Some(activity) => {
+                let _ = writeln!(out, "Messages: {}", breakdown(&activity.messages));
+                let mut tools = format!("Tool calls: {}", breakdown(&activity.tool_calls));
+                if activity.tool_failures > 0 {
+                    let _ = write!(tools, ", {} failed", activity.tool_failures);
+                }
+                let _ = writeln!(out, "{tools}");
+                let _ = writeln!(out, "Errors: {}", breakdown(&activity.errors));
+                if activity.feedback_positive + activity.feedback_negative > 0 {
+                    let _ = writeln!(
+                        out,
+                        "Feedback: {} 👍 · {} 👎",
+                        activity.feedback_positive, activity.feedback_negative
+                    );
+                }
+            }
+            None => out.push_str(
+                "Activity: not recorded (the daemon records it while [observability.digest] is enabled)\n",
+            ),
+        }
+        match &self.usage {
+            Some(usage) => {
+                let _ = write!(
+                    out,
+                    "Tokens: {} · cost ${:.2}",
+                    usage.tokens, usage.cost_usd
+                );
+                if let Some(requests) = usage.requests {
+                    let _ = write!(out, " ({requests} requests)");
+                }
+                out.push('\n');
+            }
+            None => out.push_str("Usage: not tracked (enable [cost] for token and cost totals)\n"),
+        }
+        match &self.memory_topics {
+            Some(topics) if !topics.is_empty() => {
+                let words: Vec<&str> = topics.iter().map(|(word, _)| word.as_str()).collect();
+                let _ = writeln!(out, "Memory topics: {}", words.join(", "));
+            }
+            Some(_) => out.push_str("Memory topics: nothing stored\n"),
+            None => out.push_str("Memory topics: memory unavailable\n"),
+        }
+        out.trim_end().to_string()
+    }
+}
+
+/// Build yesterday's digest and deliver it to the configured target.
+pub async fn send(config: &Config) -> Result<()> {
+    let digest_cfg = &config.observability.digest;
+    let (channel, recipient) = (digest_cfg.channel.trim(), digest_cfg.recipient.trim());
+    if channel.is_empty() || recipient.is_empty() {
+        bail!("[observability.digest] needs both `channel` and `recipient`");
+    }
+    let Some(yesterday) = Local::now().date_naive().pred_opt() else {
+        bail!("no previous day to summarize");
+    };
+    let text = build(config, yesterday, false).await.render();
+    crate::cron::quiet_hours::deliver_scheduled(config, channel, recipient, None, &text, false)
+        .await
+}
+
+/// Daemon component: keep the activity ledger on disk and send the digest
+/// whenever the cron schedule comes due.
+pub async fn run(config: Config, cancel: CancellationToken) -> Result<()> {
+    let ledger = activity::install(&config.data_dir);
+    let schedule = Schedule::Cron {
+        expr: config.observability.digest.cron.clone(),
+        tz: None,
+    };
+    let mut next_send = next_run_for_schedule(&schedule, Utc::now())?;
+    let mut interval = tokio::time::interval(TICK);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        tokio::select! {
+            () = cancel.cancelled() => return ledger.persist(),
+            _ = interval.tick() => {}
+        }
+        if let Err(e) = ledger.persist() {
+            crate::health::mark_component_error(DIGEST_COMPONENT, format!("{e:#}"));
+            continue;
+        }
+        if Utc::now() < next_send {
+            crate::health::mark_component_ok(DIGEST_COMPONENT);
+            continue;
+        }
+        next_send = next_run_for_schedule(&schedule, Utc::now())?;
+        match send(&config).await {
+            Ok(()) => {
+                crate::health::mark_component_ok(DIGEST_COMPONENT);
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Send)
+                        .with_attrs(::serde_json::json!({
+                            "channel": config.observability.digest.channel,
+                        })),
+                    "Daily digest sent"
+                );
+            }
+            Err(e) => {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Send)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                    "Daily digest delivery failed"
+                );
+                crate::health::mark_component_error(DIGEST_COMPONENT, format!("{e:#}"));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 15).unwrap()
+    }
+
+    #[test]
+    fn digest_reports_each_part_it_has() {
+        let mut activity = DailyActivity::new(date());
+        activity.messages.insert("telegram".into(), 30);
+        activity.messages.insert("discord".into(), 12);
+        activity.tool_calls.insert("shell".into(), 9);
+        activity.tool_failures = 2;
+        activity.feedback_positive = 5;
+        activity.feedback_negative = 1;
+        let digest = Digest {
+            date: date(),
+            partial: false,
+            activity: Some(activity),
+            usage: Some(UsageTotals {
+                tokens: 120_345,
+                cost_usd: 1.234,
+                requests: Some(58),
+            }),
+            memory_topics: Some(vec![("deploy".into(), 4), ("staging".into(), 3)]),
+        };
+        assert_eq!(
+            digest.render(),
+            "Digest for 2026-10-15\n\
+             Messages: 42 (telegram 30, discord 12)\n\
+             Tool calls: 9 (shell 9), 2 failed\n\
+             Errors: 0\n\
+             Feedback: 5 👍 · 1 👎\n\
+             Tokens: 120345 · cost $1.23 (58 requests)\n\
+             Memory topics: deploy, staging"
+        );
+    }
+
+    #[test]
+    fn digest_degrades_when_sources_are_off() {
+        let digest = Digest {
+            date: date(),
+            partial: true,
+            activity: None,
+            usage: None,
+            memory_topics: None,
+        };
+        let text = digest.render();
+        assert!(text.starts_with("Digest for 2026-10-15 (so far)"));
+        assert!(text.contains("Activity: not recorded"));
+        assert!(text.contains("Usage: not tracked"));
+        assert!(text.contains("Memory topics: memory unavailable"));
+    }
+
+    #[test]
+    fn topics_count_each_word_once_per_memory() {
+        let topics = top_words(
+            [
+                "Deploy the app to staging please",
+                "deploy deploy deploy to production",
+                "Staging database credentials rotated",
+            ]
+            .into_iter(),
+        );
+        assert_eq!(topics[0], ("deploy".to_string(), 2));
+        assert_eq!(topics[1], ("staging".to_string(), 2));
+        assert!(!topics.iter().any(|(word, _)| word == "please"));
+    }
+}
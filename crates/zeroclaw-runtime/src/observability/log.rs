@@ -82,6 +82,16 @@ impl Observer for LogObserver {
                     "channel.message"
                 );
             }
+            ObserverEvent::ReplyFeedback { channel, positive } => {
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_attrs(
+                            ::serde_json::json!({"channel": channel, "positive": positive})
+                        ),
+                    "reply.feedback"
+                );
+            }
             ObserverEvent::HeartbeatTick => {
                 ::zeroclaw_log::record!(
                     INFO,
@@ -222,6 +232,85 @@ impl Observer for LogObserver {
                 let ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
                 ::zeroclaw_log::record!(INFO, ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note).with_attrs(::serde_json::json!({"model_provider": model_provider, "model": model, "duration_ms": ms, "success": success, "error": error_message, "input_tokens": input_tokens, "output_tokens": output_tokens})), "llm.response");
             }
+            ObserverEvent::ToolLoopIteration {
+                iteration,
+                llm_duration,
+                llm_success,
+                input_tokens,
+                output_tokens,
+                cost_usd,
+                tools,
+                turn_id,
+                ..
+            } => {
+                let llm_ms = u64::try_from(llm_duration.as_millis()).unwrap_or(u64::MAX);
+                let tools: Vec<_> = tools
+                    .iter()
+                    .map(|t| {
+                        ::serde_json::json!({
+                            "tool": t.tool,
+                            "duration_ms": u64::try_from(t.duration.as_millis()).unwrap_or(u64::MAX),
+                            "success": t.success,
+                        })
+                    })
+                    .collect();
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_attrs(::serde_json::json!({
+                            "iteration": iteration,
+                            "llm_duration_ms": llm_ms,
+                            "llm_success": llm_success,
+                            "input_tokens": input_tokens,
+                            "output_tokens": output_tokens,
+                            "cost_usd": cost_usd,
+                            "tools": tools,
+                            "turn_id": turn_id,
+                        })),
+                    "tool_loop.iteration"
+                );
+            }
+            ObserverEvent::ToolLoopSummary {
+                iterations,
+                duration,
+                llm_duration,
+                tool_duration,
+                tool_calls,
+                tool_failures,
+                input_tokens,
+                output_tokens,
+                cost_usd,
+                success,
+                turn_id,
+                ..
+            } => {
+                let ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+                let llm_ms = u64::try_from(llm_duration.as_millis()).unwrap_or(u64::MAX);
+                let tool_ms = u64::try_from(tool_duration.as_millis()).unwrap_or(u64::MAX);
+                let cost = cost_usd.map_or_else(String::new, |c| format!(", ${c:.4}"));
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                        .with_attrs(::serde_json::json!({
+                            "iterations": iterations,
+                            "duration_ms": ms,
+                            "llm_duration_ms": llm_ms,
+                            "tool_duration_ms": tool_ms,
+                            "tool_calls": tool_calls,
+                            "tool_failures": tool_failures,
+                            "input_tokens": input_tokens,
+                            "output_tokens": output_tokens,
+                            "cost_usd": cost_usd,
+                            "success": success,
+                            "turn_id": turn_id,
+                        })),
+                    &format!(
+                        "tool_loop.summary: {iterations} iter, {ms}ms (llm {llm_ms}ms, tools {tool_ms}ms), \
+                         {tool_calls} tool calls ({tool_failures} failed), \
+                         {input_tokens} in / {output_tokens} out tokens{cost}"
+                    )
+                );
+            }
             ObserverEvent::DeploymentStarted { deploy_id } => {
                 ::zeroclaw_log::record!(
                     INFO,
@@ -420,6 +509,41 @@ mod tests {
             component: "model_provider".into(),
             message: "timeout".into(),
         });
+        obs.record_event(&ObserverEvent::ToolLoopIteration {
+            iteration: 1,
+            model_provider: "openrouter".into(),
+            model: "claude-sonnet".into(),
+            llm_duration: Duration::from_millis(800),
+            llm_success: true,
+            input_tokens: Some(120),
+            output_tokens: Some(30),
+            cost_usd: None,
+            tools: vec![crate::observability::traits::ToolIterationStat {
+                tool: "shell".into(),
+                duration: Duration::from_millis(15),
+                success: false,
+            }],
+            channel: None,
+            agent_alias: None,
+            turn_id: None,
+        });
+        obs.record_event(&ObserverEvent::ToolLoopSummary {
+            model_provider: "openrouter".into(),
+            model: "claude-sonnet".into(),
+            iterations: 2,
+            duration: Duration::from_millis(1_900),
+            llm_duration: Duration::from_millis(1_700),
+            tool_duration: Duration::from_millis(15),
+            tool_calls: 1,
+            tool_failures: 1,
+            input_tokens: 260,
+            output_tokens: 70,
+            cost_usd: Some(0.0021),
+            success: true,
+            channel: None,
+            agent_alias: None,
+            turn_id: None,
+        });
     }
 
     #[test]
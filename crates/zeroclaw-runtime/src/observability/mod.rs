@@ -1,3 +1,5 @@
+pub mod activity;
+pub mod digest;
 pub mod dora;
 pub mod log;
 pub mod multi;
@@ -130,6 +132,84 @@ impl Drop for AgentTurnGuard<'_> {
     }
 }
 
+/// Drop-safe trace bracket for one inbound channel message.
+///
+/// Construction emits [`ObserverEvent::ChannelReceive`]; dropping the guard
+/// emits the matching [`ObserverEvent::ChannelMessageEnd`]. The guard's
+/// `turn_id` is the trace context for the message: pass the same id to
+/// [`AgentTurnGuard`] and the tool loop so provider, memory, and tool events
+/// nest under the message in trace backends.
+#[must_use = "hold the guard for the lifetime of the channel message"]
+pub struct ChannelMessageGuard<'a> {
+    observer: &'a dyn Observer,
+    channel: String,
+    turn_id: String,
+    started_at: Instant,
+}
+
+impl<'a> ChannelMessageGuard<'a> {
+    /// Open the message bracket and emit its receive event. Only a hash of
+    /// `sender` leaves the process.
+    pub fn start(
+        observer: &'a dyn Observer,
+        channel: impl Into<String>,
+        sender: &str,
+        turn_id: impl Into<String>,
+    ) -> Self {
+        let channel = channel.into();
+        let turn_id = turn_id.into();
+        observer.record_event(&ObserverEvent::ChannelReceive {
+            channel: channel.clone(),
+            sender_hash: sender_hash(sender),
+            turn_id: turn_id.clone(),
+        });
+        Self {
+            observer,
+            channel,
+            turn_id,
+            started_at: Instant::now(),
+        }
+    }
+
+    pub fn turn_id(&self) -> &str {
+        &self.turn_id
+    }
+
+    /// Record delivery of the reply to this message, with the platform
+    /// message id when the channel returned a delivery receipt.
+    pub fn record_send(
+        &self,
+        duration: std::time::Duration,
+        success: bool,
+        message_id: Option<&str>,
+    ) {
+        self.observer.record_event(&ObserverEvent::ChannelSend {
+            channel: self.channel.clone(),
+            duration,
+            success,
+            turn_id: self.turn_id.clone(),
+            message_id: message_id.map(str::to_string),
+        });
+    }
+}
+
+impl Drop for ChannelMessageGuard<'_> {
+    fn drop(&mut self) {
+        self.observer
+            .record_event(&ObserverEvent::ChannelMessageEnd {
+                channel: self.channel.clone(),
+                duration: self.started_at.elapsed(),
+                turn_id: self.turn_id.clone(),
+            });
+    }
+}
+
+/// First 16 hex chars of the sender id's SHA-256.
+fn sender_hash(sender: &str) -> String {
+    use sha2::{Digest, Sha256};
+    hex::encode(&Sha256::digest(sender.as_bytes())[..8])
+}
+
 /// Process-wide broadcast hook installed by long-running subsystems (today: the
 /// gateway) so that events emitted by observers built in *other* subsystems —
 /// notably the agent loop's `process_message` — also fan out to the SSE
@@ -258,7 +338,8 @@ impl Drop for FlushGuard {
 }
 
 /// Wrapper that forwards every event to a primary observer plus the
-/// process-wide broadcast hook (when set). Metrics flow only to the primary.
+/// process-wide broadcast hook and activity ledger (when set). Metrics flow
+/// only to the primary.
 struct TeeObserver {
     primary: Box<dyn Observer>,
 }
@@ -269,6 +350,7 @@ impl Observer for TeeObserver {
         if let Some(hook) = current_broadcast_hook() {
             hook.record_event(event);
         }
+        activity::record(event);
     }
 
     fn record_metric(&self, metric: &ObserverMetric) {
@@ -800,4 +882,23 @@ mod tests {
             "explicit finish and drop must still emit one matched pair"
         );
     }
+
+    #[test]
+    fn channel_message_guard_emits_receive_send_and_end() {
+        let observer = CountingObserver::default();
+        let guard = ChannelMessageGuard::start(&observer, "telegram", "user-42", "turn");
+        guard.record_send(std::time::Duration::from_millis(5), true, Some("42"));
+        assert_eq!(guard.turn_id(), "turn");
+        drop(guard);
+
+        assert_eq!(observer.events.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn sender_hash_is_stable_and_opaque() {
+        let hash = sender_hash("user-42");
+        assert_eq!(hash.len(), 16);
+        assert_eq!(hash, sender_hash("user-42"));
+        assert_ne!(hash, sender_hash("user-43"));
+    }
 }
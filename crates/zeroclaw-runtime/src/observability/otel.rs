@@ -2,12 +2,14 @@ use super::traits::{LlmMessageSnapshot, Observer, ObserverEvent, ObserverMetric}
 use crate::agent::loop_::scrub_for_export;
 use crate::observability::otel_config::OtelContentConfig;
 use crate::util::{truncate_field, truncate_json_leaves};
-use opentelemetry::metrics::{Counter, Gauge, Histogram};
-use opentelemetry::trace::{Span, SpanKind, Status, TraceContextExt as _, Tracer};
+use opentelemetry::metrics::{Counter, Gauge, Histogram, MeterProvider as _};
+use opentelemetry::trace::{
+    Span, SpanKind, Status, TraceContextExt as _, Tracer, TracerProvider as _,
+};
 use opentelemetry::{Context, KeyValue, global};
 use opentelemetry_otlp::{WithExportConfig, WithHttpConfig};
 use opentelemetry_sdk::metrics::SdkMeterProvider;
-use opentelemetry_sdk::trace::SdkTracerProvider;
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
 use std::any::Any;
 use std::collections::HashMap;
 use std::sync::Mutex;
@@ -15,12 +17,19 @@ use std::time::SystemTime;
 use zeroclaw_config::schema::OtelContentPolicy;
 
 struct ActiveAgentSpan {
-    span: global::BoxedSpan,
+    span: opentelemetry_sdk::trace::Span,
     context: Context,
     first_user_input: Option<String>,
     last_output_text: Option<String>,
 }
 
+/// Root span of one inbound channel message; agent turns and the reply send
+/// sharing its `turn_id` nest under it.
+struct ActiveMessageSpan {
+    span: opentelemetry_sdk::trace::Span,
+    context: Context,
+}
+
 /// OpenTelemetry-backed observer — exports traces and metrics via OTLP.
 pub struct OtelObserver {
     /// Per-observer OTel content policy, derived once from
@@ -30,6 +39,7 @@ pub struct OtelObserver {
     content_config: OtelContentConfig,
     tracer_provider: SdkTracerProvider,
     meter_provider: SdkMeterProvider,
+    tracer: SdkTracer,
 
     // Metrics instruments
     agent_starts: Counter<u64>,
@@ -51,8 +61,11 @@ pub struct OtelObserver {
     memory_audit_count: Counter<u64>,
     rag_retrieve_count: Counter<u64>,
     rag_retrieve_duration: Histogram<f64>,
+    tool_loop_iterations: Histogram<f64>,
+    tool_loop_duration: Histogram<f64>,
 
-    // Turn span tracking for parent/child correlation
+    // Message and turn span tracking for parent/child correlation
+    active_message_spans: Mutex<HashMap<String, ActiveMessageSpan>>,
     active_agent_spans: Mutex<HashMap<String, ActiveAgentSpan>>,
 }
 
@@ -116,11 +129,26 @@ impl OtelObserver {
             )
             .build();
 
-        let meter_provider_clone = meter_provider.clone();
-        global::set_meter_provider(meter_provider);
+        global::set_meter_provider(meter_provider.clone());
+
+        Ok(Self::with_providers(
+            tracer_provider,
+            meter_provider,
+            content_config,
+        ))
+    }
+
+    /// Build the observer on caller-supplied providers. Spans and metrics go
+    /// to these providers only, never the process-global ones.
+    pub(crate) fn with_providers(
+        tracer_provider: SdkTracerProvider,
+        meter_provider: SdkMeterProvider,
+        content_config: OtelContentConfig,
+    ) -> Self {
+        let tracer = tracer_provider.tracer("zeroclaw");
 
         // ── Create metric instruments ────────────────────────────
-        let meter = global::meter("zeroclaw");
+        let meter = meter_provider.meter("zeroclaw");
 
         let agent_starts = meter
             .u64_counter("zeroclaw.agent.starts")
@@ -223,10 +251,22 @@ impl OtelObserver {
             .with_unit("s")
             .build();
 
-        Ok(Self {
+        let tool_loop_iterations = meter
+            .f64_histogram("zeroclaw.tool_loop.iterations")
+            .with_description("Tool-loop iterations needed to answer one message")
+            .build();
+
+        let tool_loop_duration = meter
+            .f64_histogram("zeroclaw.tool_loop.duration")
+            .with_description("Wall-clock tool-loop duration per message in seconds")
+            .with_unit("s")
+            .build();
+
+        Self {
             content_config,
             tracer_provider,
-            meter_provider: meter_provider_clone,
+            meter_provider,
+            tracer,
             agent_starts,
             agent_duration,
             llm_calls,
@@ -246,10 +286,15 @@ impl OtelObserver {
             memory_audit_count,
             rag_retrieve_count,
             rag_retrieve_duration,
+            tool_loop_iterations,
+            tool_loop_duration,
+            active_message_spans: Mutex::new(HashMap::new()),
             active_agent_spans: Mutex::new(HashMap::new()),
-        })
+        }
     }
 
+    /// Innermost live span for `turn_id`: the agent turn when one is open,
+    /// otherwise the channel message that carries it.
     fn parent_cx_for(&self, turn_id: Option<&str>) -> Context {
         if let Some(tid) = turn_id
             && let Some(entry) = self
@@ -260,13 +305,26 @@ impl OtelObserver {
         {
             return entry.context.clone();
         }
+        self.message_cx_for(turn_id)
+    }
+
+    fn message_cx_for(&self, turn_id: Option<&str>) -> Context {
+        if let Some(tid) = turn_id
+            && let Some(entry) = self
+                .active_message_spans
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .get(tid)
+        {
+            return entry.context.clone();
+        }
         Context::current()
     }
 }
 
 impl Observer for OtelObserver {
     fn record_event(&self, event: &ObserverEvent) {
-        let tracer = global::tracer("zeroclaw");
+        let tracer = &self.tracer;
 
         match event {
             ObserverEvent::AgentStart {
@@ -284,7 +342,8 @@ impl Observer for OtelObserver {
                     ],
                 );
 
-                let span = tracer.build(
+                let parent_cx = self.message_cx_for(turn_id.as_deref());
+                let span = tracer.build_with_context(
                     opentelemetry::trace::SpanBuilder::from_name("gen_ai.agent.invoke")
                         .with_kind(SpanKind::Internal)
                         .with_attributes(vec![
@@ -297,10 +356,11 @@ impl Observer for OtelObserver {
                             ),
                             KeyValue::new("zeroclaw.turn_id", turn_id.clone().unwrap_or_default()),
                         ]),
+                    &parent_cx,
                 );
 
                 if let Some(tid) = turn_id {
-                    let parent_cx =
+                    let turn_cx =
                         Context::current().with_remote_span_context(span.span_context().clone());
                     self.active_agent_spans
                         .lock()
@@ -309,7 +369,7 @@ impl Observer for OtelObserver {
                             tid.clone(),
                             ActiveAgentSpan {
                                 span,
-                                context: parent_cx,
+                                context: turn_cx,
                                 first_user_input: None,
                                 last_output_text: None,
                             },
@@ -831,6 +891,193 @@ impl Observer for OtelObserver {
                 self.tool_duration
                     .record(secs, &[KeyValue::new("tool", tool.clone())]);
             }
+            ObserverEvent::ToolLoopIteration {
+                iteration,
+                model_provider,
+                model,
+                llm_duration,
+                llm_success,
+                input_tokens,
+                output_tokens,
+                cost_usd,
+                tools,
+                channel,
+                agent_alias,
+                turn_id,
+            } => {
+                let tool_time: std::time::Duration = tools.iter().map(|t| t.duration).sum();
+                let start_time = SystemTime::now()
+                    .checked_sub(*llm_duration + tool_time)
+                    .unwrap_or(SystemTime::now());
+                let tool_names: Vec<_> = tools.iter().map(|t| t.tool.clone().into()).collect();
+                let mut span_attrs = vec![
+                    KeyValue::new("zeroclaw.iteration", *iteration as i64),
+                    KeyValue::new("gen_ai.provider.name", model_provider.clone()),
+                    KeyValue::new("gen_ai.request.model", model.clone()),
+                    KeyValue::new("llm.duration_s", llm_duration.as_secs_f64()),
+                    KeyValue::new("llm.success", *llm_success),
+                    KeyValue::new("tool.count", tools.len() as i64),
+                    KeyValue::new(
+                        "tool.failures",
+                        tools.iter().filter(|t| !t.success).count() as i64,
+                    ),
+                    KeyValue::new("tool.duration_s", tool_time.as_secs_f64()),
+                    KeyValue::new(
+                        "tool.names",
+                        opentelemetry::Value::Array(opentelemetry::Array::String(tool_names)),
+                    ),
+                    KeyValue::new("zeroclaw.channel", channel.clone().unwrap_or_default()),
+                    KeyValue::new("gen_ai.agent.name", agent_alias.clone().unwrap_or_default()),
+                    KeyValue::new("zeroclaw.turn_id", turn_id.clone().unwrap_or_default()),
+                ];
+                if let Some(input) = input_tokens {
+                    span_attrs.push(KeyValue::new("gen_ai.usage.input_tokens", *input as i64));
+                }
+                if let Some(output) = output_tokens {
+                    span_attrs.push(KeyValue::new("gen_ai.usage.output_tokens", *output as i64));
+                }
+                if let Some(c) = cost_usd {
+                    span_attrs.push(KeyValue::new("cost_usd", *c));
+                }
+
+                let parent_cx = self.parent_cx_for(turn_id.as_deref());
+                let mut span = tracer.build_with_context(
+                    opentelemetry::trace::SpanBuilder::from_name("tool_loop.iteration")
+                        .with_kind(SpanKind::Internal)
+                        .with_start_time(start_time)
+                        .with_attributes(span_attrs),
+                    &parent_cx,
+                );
+                if *llm_success {
+                    span.set_status(Status::Ok);
+                } else {
+                    span.set_status(Status::error(""));
+                }
+                span.end();
+            }
+            ObserverEvent::ToolLoopSummary {
+                model_provider,
+                model,
+                iterations,
+                duration,
+                llm_duration,
+                tool_duration,
+                tool_calls,
+                tool_failures,
+                success,
+                agent_alias,
+                turn_id,
+                ..
+            } => {
+                // Aggregates ride on the turn's agent span (closed by the
+                // following AgentEnd) rather than a span of their own.
+                if let Some(tid) = turn_id
+                    && let Ok(mut spans) = self.active_agent_spans.lock()
+                    && let Some(agent_span) = spans.get_mut(tid)
+                {
+                    for kv in [
+                        KeyValue::new("zeroclaw.tool_loop.iterations", *iterations as i64),
+                        KeyValue::new(
+                            "zeroclaw.tool_loop.llm_duration_s",
+                            llm_duration.as_secs_f64(),
+                        ),
+                        KeyValue::new(
+                            "zeroclaw.tool_loop.tool_duration_s",
+                            tool_duration.as_secs_f64(),
+                        ),
+                        KeyValue::new("zeroclaw.tool_loop.tool_calls", *tool_calls as i64),
+                        KeyValue::new("zeroclaw.tool_loop.tool_failures", *tool_failures as i64),
+                    ] {
+                        agent_span.span.set_attribute(kv);
+                    }
+                }
+
+                let attrs = [
+                    KeyValue::new("gen_ai.provider.name", model_provider.clone()),
+                    KeyValue::new("gen_ai.request.model", model.clone()),
+                    KeyValue::new("gen_ai.agent.name", agent_alias.clone().unwrap_or_default()),
+                    KeyValue::new("success", success.to_string()),
+                ];
+                self.tool_loop_iterations.record(*iterations as f64, &attrs);
+                self.tool_loop_duration
+                    .record(duration.as_secs_f64(), &attrs);
+            }
+            ObserverEvent::ChannelReceive {
+                channel,
+                sender_hash,
+                turn_id,
+            } => {
+                let span = tracer.build(
+                    opentelemetry::trace::SpanBuilder::from_name("channel.message")
+                        .with_kind(SpanKind::Server)
+                        .with_attributes(vec![
+                            KeyValue::new("zeroclaw.channel", channel.clone()),
+                            KeyValue::new("zeroclaw.sender_hash", sender_hash.clone()),
+                            KeyValue::new("zeroclaw.turn_id", turn_id.clone()),
+                        ]),
+                );
+                let message_cx =
+                    Context::current().with_remote_span_context(span.span_context().clone());
+                self.active_message_spans
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .insert(
+                        turn_id.clone(),
+                        ActiveMessageSpan {
+                            span,
+                            context: message_cx,
+                        },
+                    );
+            }
+            ObserverEvent::ChannelSend {
+                channel,
+                duration,
+                success,
+                turn_id,
+                message_id,
+            } => {
+                let start_time = SystemTime::now()
+                    .checked_sub(*duration)
+                    .unwrap_or(SystemTime::now());
+                let parent_cx = self.parent_cx_for(Some(turn_id));
+                let mut attributes = vec![
+                    KeyValue::new("zeroclaw.channel", channel.clone()),
+                    KeyValue::new("success", *success),
+                    KeyValue::new("duration_s", duration.as_secs_f64()),
+                    KeyValue::new("zeroclaw.turn_id", turn_id.clone()),
+                ];
+                if let Some(id) = message_id {
+                    attributes.push(KeyValue::new("zeroclaw.message_id", id.clone()));
+                }
+                let mut span = tracer.build_with_context(
+                    opentelemetry::trace::SpanBuilder::from_name("channel.send")
+                        .with_kind(SpanKind::Producer)
+                        .with_start_time(start_time)
+                        .with_attributes(attributes),
+                    &parent_cx,
+                );
+                if *success {
+                    span.set_status(Status::Ok);
+                } else {
+                    span.set_status(Status::error(""));
+                }
+                span.end();
+            }
+            ObserverEvent::ChannelMessageEnd {
+                duration, turn_id, ..
+            } => {
+                let entry = self
+                    .active_message_spans
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .remove(turn_id);
+                if let Some(mut message_span) = entry {
+                    message_span
+                        .span
+                        .set_attribute(KeyValue::new("duration_s", duration.as_secs_f64()));
+                    message_span.span.end();
+                }
+            }
             ObserverEvent::ChannelMessage { channel, direction } => {
                 self.channel_messages.add(
                     1,
@@ -903,6 +1150,17 @@ impl Observer for OtelObserver {
         for mut orphan in orphans {
             orphan.span.end();
         }
+        // Message spans close after their turns so children end first.
+        let orphan_messages: Vec<ActiveMessageSpan> = self
+            .active_message_spans
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .drain()
+            .map(|(_, v)| v)
+            .collect();
+        for mut orphan in orphan_messages {
+            orphan.span.end();
+        }
 
         if let Err(e) = self.tracer_provider.force_flush() {
             ::zeroclaw_log::record!(
@@ -933,6 +1191,17 @@ impl Observer for OtelObserver {
     }
 }
 
+impl Drop for OtelObserver {
+    /// Export whatever is still buffered before the providers go away —
+    /// the batch processor would otherwise drop the tail of the last
+    /// messages handled before shutdown.
+    fn drop(&mut self) {
+        self.flush();
+        let _ = self.tracer_provider.shutdown();
+        let _ = self.meter_provider.shutdown();
+    }
+}
+
 fn clean_for_display(content: &str) -> String {
     let mut cleaned = content.to_string();
 
@@ -1179,6 +1448,7 @@ fn message_attrs(
 mod tests {
     use super::super::traits::{LlmMessageSnapshot, MessageSnapshot, ToolCallSnapshot};
     use super::*;
+    use opentelemetry_sdk::trace::InMemorySpanExporter;
     use std::time::Duration;
 
     fn attr_value(attrs: &[opentelemetry::KeyValue], key: &str) -> Option<String> {
@@ -1406,6 +1676,41 @@ mod tests {
             agent_alias: None,
             turn_id: None,
         });
+        obs.record_event(&ObserverEvent::ToolLoopIteration {
+            iteration: 1,
+            model_provider: "openrouter".into(),
+            model: "claude-sonnet".into(),
+            llm_duration: Duration::from_millis(250),
+            llm_success: true,
+            input_tokens: Some(100),
+            output_tokens: Some(50),
+            cost_usd: Some(0.001),
+            tools: vec![crate::observability::traits::ToolIterationStat {
+                tool: "shell".into(),
+                duration: Duration::from_millis(10),
+                success: true,
+            }],
+            channel: None,
+            agent_alias: None,
+            turn_id: None,
+        });
+        obs.record_event(&ObserverEvent::ToolLoopSummary {
+            model_provider: "openrouter".into(),
+            model: "claude-sonnet".into(),
+            iterations: 1,
+            duration: Duration::from_millis(300),
+            llm_duration: Duration::from_millis(250),
+            tool_duration: Duration::from_millis(10),
+            tool_calls: 1,
+            tool_failures: 0,
+            input_tokens: 100,
+            output_tokens: 50,
+            cost_usd: Some(0.001),
+            success: true,
+            channel: None,
+            agent_alias: None,
+            turn_id: None,
+        });
         obs.record_event(&ObserverEvent::TurnComplete);
         obs.record_event(&ObserverEvent::ChannelMessage {
             channel: "telegram".into(),
@@ -1692,6 +1997,153 @@ mod tests {
         );
     }
 
+    fn in_memory_observer() -> (OtelObserver, InMemorySpanExporter) {
+        let exporter = InMemorySpanExporter::default();
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let obs = OtelObserver::with_providers(
+            tracer_provider,
+            SdkMeterProvider::builder().build(),
+            all_off_config(),
+        );
+        (obs, exporter)
+    }
+
+    /// A channel message is one trace: receive → agent turn → memory recall,
+    /// provider call, and tool call → reply send, all linked by `turn_id`.
+    #[test]
+    fn channel_message_trace_nests_turn_and_send() {
+        let (obs, exporter) = in_memory_observer();
+        let turn_id = Some("turn-1".to_string());
+        obs.record_event(&ObserverEvent::ChannelReceive {
+            channel: "telegram".into(),
+            sender_hash: "5e1f0c2a9b7d3e44".into(),
+            turn_id: "turn-1".into(),
+        });
+        obs.record_event(&ObserverEvent::AgentStart {
+            model_provider: "anthropic".into(),
+            model: "claude-sonnet-4-6".into(),
+            channel: Some("telegram".into()),
+            agent_alias: Some("default".into()),
+            turn_id: turn_id.clone(),
+        });
+        obs.record_event(&ObserverEvent::MemoryRecall {
+            query_summary: None,
+            duration: Duration::from_millis(3),
+            num_entries: 2,
+            backend: "sqlite".into(),
+            success: true,
+            channel: Some("telegram".into()),
+            agent_alias: Some("default".into()),
+            turn_id: turn_id.clone(),
+        });
+        obs.record_event(&ObserverEvent::LlmResponse {
+            parent_agent_alias: None,
+            model_provider: "anthropic".into(),
+            model: "claude-sonnet-4-6".into(),
+            duration: Duration::from_millis(25),
+            success: true,
+            error_message: None,
+            input_tokens: Some(10),
+            output_tokens: Some(5),
+            channel: Some("telegram".into()),
+            agent_alias: Some("default".into()),
+            turn_id: turn_id.clone(),
+            messages: None,
+        });
+        obs.record_event(&ObserverEvent::ToolCall {
+            parent_agent_alias: None,
+            tool: "shell".into(),
+            tool_call_id: Some("call-1".into()),
+            duration: Duration::from_millis(5),
+            success: true,
+            arguments: None,
+            result: None,
+            channel: Some("telegram".into()),
+            agent_alias: Some("default".into()),
+            turn_id: turn_id.clone(),
+        });
+        obs.record_event(&ObserverEvent::AgentEnd {
+            model_provider: "anthropic".into(),
+            model: "claude-sonnet-4-6".into(),
+            duration: Duration::from_millis(50),
+            tokens_used: None,
+            cost_usd: None,
+            channel: Some("telegram".into()),
+            agent_alias: Some("default".into()),
+            turn_id: turn_id.clone(),
+        });
+        obs.record_event(&ObserverEvent::ChannelSend {
+            channel: "telegram".into(),
+            duration: Duration::from_millis(8),
+            success: true,
+            turn_id: "turn-1".into(),
+            message_id: Some("42".into()),
+        });
+        obs.record_event(&ObserverEvent::ChannelMessageEnd {
+            channel: "telegram".into(),
+            duration: Duration::from_millis(70),
+            turn_id: "turn-1".into(),
+        });
+
+        let spans = exporter.get_finished_spans().expect("finished spans");
+        let span = |name: &str| {
+            spans
+                .iter()
+                .find(|s| s.name == name)
+                .unwrap_or_else(|| panic!("missing span {name}"))
+        };
+        let message = span("channel.message");
+        let agent = span("gen_ai.agent.invoke");
+        let trace_id = message.span_context.trace_id();
+
+        assert_eq!(
+            message.parent_span_id,
+            opentelemetry::trace::SpanId::INVALID
+        );
+        assert_eq!(
+            attr_value(&message.attributes, "zeroclaw.sender_hash").as_deref(),
+            Some("5e1f0c2a9b7d3e44")
+        );
+        assert_eq!(agent.parent_span_id, message.span_context.span_id());
+        for name in ["memory.recall", "llm.response", "tool_call.result"] {
+            let child = span(name);
+            assert_eq!(
+                child.parent_span_id,
+                agent.span_context.span_id(),
+                "{name} should nest under the agent turn"
+            );
+            assert_eq!(child.span_context.trace_id(), trace_id);
+        }
+        assert_eq!(
+            attr_value(
+                &span("llm.response").attributes,
+                "gen_ai.usage.input_tokens"
+            )
+            .as_deref(),
+            Some("10")
+        );
+        assert_eq!(
+            attr_value(&span("tool_call.result").attributes, "tool.name").as_deref(),
+            Some("shell")
+        );
+        let send = span("channel.send");
+        assert_eq!(send.parent_span_id, message.span_context.span_id());
+        assert_eq!(send.span_context.trace_id(), trace_id);
+        assert_eq!(
+            attr_value(&send.attributes, "zeroclaw.message_id").as_deref(),
+            Some("42")
+        );
+        assert!(
+            obs.active_message_spans
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .is_empty(),
+            "ChannelMessageEnd should close the message span"
+        );
+    }
+
     /// Memory/RAG events carrying a live turn_id must parent under the
     /// active agent span. As with the other span tests we
     /// cannot assert exported parent/child linkage (OTLP export is async);
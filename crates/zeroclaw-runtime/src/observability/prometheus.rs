@@ -14,7 +14,9 @@ pub struct PrometheusObserver {
     tokens_input_total: IntCounterVec,
     tokens_output_total: IntCounterVec,
     tool_calls: IntCounterVec,
+    tool_loop_detections: IntCounterVec,
     channel_messages: IntCounterVec,
+    reply_feedback: IntCounterVec,
     memory_audits: IntCounterVec,
     heartbeat_ticks: prometheus::IntCounter,
     errors: IntCounterVec,
@@ -27,6 +29,9 @@ pub struct PrometheusObserver {
     tool_duration: HistogramVec,
     memory_audit_duration: HistogramVec,
     request_latency: Histogram,
+    llm_call_duration: HistogramVec,
+    tool_loop_iterations: HistogramVec,
+    tool_loop_duration: HistogramVec,
 
     // Gauges
     tokens_used: prometheus::IntGauge,
@@ -95,6 +100,24 @@ impl PrometheusObserver {
         )
         .expect("valid metric");
 
+        let tool_loop_detections = IntCounterVec::new(
+            prometheus::Opts::new(
+                "zeroclaw_tool_loop_detections_total",
+                "Repeated or alternating tool calls caught by the loop detector",
+            ),
+            &["tool", "action"],
+        )
+        .expect("valid metric");
+
+        let reply_feedback = IntCounterVec::new(
+            prometheus::Opts::new(
+                "zeroclaw_reply_feedback_total",
+                "Reactions to delivered replies",
+            ),
+            &["channel", "sentiment"],
+        )
+        .expect("valid metric");
+
         let memory_audits = IntCounterVec::new(
             prometheus::Opts::new(
                 "zeroclaw_memory_audit_total",
@@ -165,6 +188,36 @@ impl PrometheusObserver {
         )
         .expect("valid metric");
 
+        let llm_call_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "zeroclaw_llm_call_duration_seconds",
+                "Provider call latency per tool-loop iteration in seconds",
+            )
+            .buckets(vec![0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0]),
+            &["model_provider", "model", "success"],
+        )
+        .expect("valid metric");
+
+        let tool_loop_iterations = HistogramVec::new(
+            HistogramOpts::new(
+                "zeroclaw_tool_loop_iterations",
+                "Tool-loop iterations needed to answer one message",
+            )
+            .buckets(vec![1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0]),
+            &["model_provider", "model"],
+        )
+        .expect("valid metric");
+
+        let tool_loop_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "zeroclaw_tool_loop_duration_seconds",
+                "Wall-clock tool-loop duration per message in seconds",
+            )
+            .buckets(vec![0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0]),
+            &["model_provider", "model", "success"],
+        )
+        .expect("valid metric");
+
         let request_latency = Histogram::with_opts(
             HistogramOpts::new(
                 "zeroclaw_request_latency_seconds",
@@ -238,7 +291,11 @@ impl PrometheusObserver {
             .register(Box::new(tokens_output_total.clone()))
             .ok();
         registry.register(Box::new(tool_calls.clone())).ok();
+        registry
+            .register(Box::new(tool_loop_detections.clone()))
+            .ok();
         registry.register(Box::new(channel_messages.clone())).ok();
+        registry.register(Box::new(reply_feedback.clone())).ok();
         registry.register(Box::new(memory_audits.clone())).ok();
         registry.register(Box::new(heartbeat_ticks.clone())).ok();
         registry.register(Box::new(errors.clone())).ok();
@@ -251,6 +308,11 @@ impl PrometheusObserver {
             .register(Box::new(memory_audit_duration.clone()))
             .ok();
         registry.register(Box::new(request_latency.clone())).ok();
+        registry.register(Box::new(llm_call_duration.clone())).ok();
+        registry
+            .register(Box::new(tool_loop_iterations.clone()))
+            .ok();
+        registry.register(Box::new(tool_loop_duration.clone())).ok();
         registry.register(Box::new(tokens_used.clone())).ok();
         registry.register(Box::new(active_sessions.clone())).ok();
         registry.register(Box::new(queue_depth.clone())).ok();
@@ -271,7 +333,9 @@ impl PrometheusObserver {
             tokens_input_total,
             tokens_output_total,
             tool_calls,
+            tool_loop_detections,
             channel_messages,
+            reply_feedback,
             memory_audits,
             heartbeat_ticks,
             errors,
@@ -282,6 +346,9 @@ impl PrometheusObserver {
             tool_duration,
             memory_audit_duration,
             request_latency,
+            llm_call_duration,
+            tool_loop_iterations,
+            tool_loop_duration,
             tokens_used,
             active_sessions,
             queue_depth,
@@ -404,11 +471,50 @@ impl Observer for PrometheusObserver {
                     .with_label_values(&[tool.as_str()])
                     .observe(duration.as_secs_f64());
             }
+            ObserverEvent::ToolLoopIteration {
+                model_provider,
+                model,
+                llm_duration,
+                llm_success,
+                ..
+            } => {
+                let success_str = if *llm_success { "true" } else { "false" };
+                self.llm_call_duration
+                    .with_label_values(&[model_provider.as_str(), model.as_str(), success_str])
+                    .observe(llm_duration.as_secs_f64());
+            }
+            ObserverEvent::ToolLoopSummary {
+                model_provider,
+                model,
+                iterations,
+                duration,
+                success,
+                ..
+            } => {
+                let success_str = if *success { "true" } else { "false" };
+                self.tool_loop_iterations
+                    .with_label_values(&[model_provider.as_str(), model.as_str()])
+                    .observe(*iterations as f64);
+                self.tool_loop_duration
+                    .with_label_values(&[model_provider.as_str(), model.as_str(), success_str])
+                    .observe(duration.as_secs_f64());
+            }
             ObserverEvent::ChannelMessage { channel, direction } => {
                 self.channel_messages
                     .with_label_values(&[channel, direction])
                     .inc();
             }
+            ObserverEvent::ToolLoopDetected { tool, action, .. } => {
+                self.tool_loop_detections
+                    .with_label_values(&[tool.as_str(), action.as_str()])
+                    .inc();
+            }
+            ObserverEvent::ReplyFeedback { channel, positive } => {
+                let sentiment = if *positive { "positive" } else { "negative" };
+                self.reply_feedback
+                    .with_label_values(&[channel.as_str(), sentiment])
+                    .inc();
+            }
             ObserverEvent::HeartbeatTick => {
                 self.heartbeat_ticks.inc();
             }
@@ -799,6 +905,54 @@ mod tests {
         assert!(!output.contains("zeroclaw_tokens_output_total{"));
     }
 
+    #[test]
+    fn tool_loop_events_track_iterations_and_latency() {
+        let obs = PrometheusObserver::new();
+
+        obs.record_event(&ObserverEvent::ToolLoopIteration {
+            iteration: 1,
+            model_provider: "openrouter".into(),
+            model: "claude-sonnet".into(),
+            llm_duration: Duration::from_millis(700),
+            llm_success: true,
+            input_tokens: Some(100),
+            output_tokens: Some(20),
+            cost_usd: None,
+            tools: Vec::new(),
+            channel: None,
+            agent_alias: None,
+            turn_id: None,
+        });
+        obs.record_event(&ObserverEvent::ToolLoopSummary {
+            model_provider: "openrouter".into(),
+            model: "claude-sonnet".into(),
+            iterations: 2,
+            duration: Duration::from_millis(1_500),
+            llm_duration: Duration::from_millis(1_200),
+            tool_duration: Duration::from_millis(250),
+            tool_calls: 1,
+            tool_failures: 0,
+            input_tokens: 220,
+            output_tokens: 60,
+            cost_usd: None,
+            success: true,
+            channel: None,
+            agent_alias: None,
+            turn_id: None,
+        });
+
+        let output = obs.encode();
+        assert!(output.contains(
+            r#"zeroclaw_llm_call_duration_seconds_count{model="claude-sonnet",model_provider="openrouter",success="true"} 1"#
+        ));
+        assert!(output.contains(
+            r#"zeroclaw_tool_loop_iterations_sum{model="claude-sonnet",model_provider="openrouter"} 2"#
+        ));
+        assert!(output.contains(
+            r#"zeroclaw_tool_loop_duration_seconds_count{model="claude-sonnet",model_provider="openrouter",success="true"} 1"#
+        ));
+    }
+
     #[test]
     fn dora_deployment_events_track_counters() {
         let obs = PrometheusObserver::new();
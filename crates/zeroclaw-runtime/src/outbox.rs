@@ -0,0 +1,590 @@
+//! Persistent outbound queue for channel replies (`[channels.outbox]`).
+//!
+//! A reply the channel failed to deliver is parked in
+//! `<data_dir>/state/channel_outbox.db` instead of being dropped. The channel
+//! supervisor's retry worker resends due entries with exponential backoff,
+//! one entry per `(channel, recipient)` at a time and in the order they were
+//! queued, and records the platform receipt once a send lands so a delivered
+//! entry is never sent again. Entries still undelivered after `max_age_secs`
+//! are dead-lettered; `zeroclaw channel outbox list|retry|drop` manages them.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use rusqlite::{Connection, OptionalExtension, params};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use zeroclaw_config::schema::Config;
+
+const OUTBOX_COLUMNS: &str = "id, channel, recipient, thread_ts, content, status, attempts, \
+     created_at, next_attempt_at, expires_at, last_error, delivery_id, delivered_at";
+
+/// Lifecycle state of an outbox entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxStatus {
+    /// Waiting for its next retry.
+    Pending,
+    Delivered,
+    /// Still undelivered after `max_age_secs`; only retried on request.
+    Dead,
+}
+
+impl OutboxStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::Dead => "dead",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        Some(match raw {
+            "pending" => Self::Pending,
+            "delivered" => Self::Delivered,
+            "dead" => Self::Dead,
+            _ => return None,
+        })
+    }
+}
+
+impl std::fmt::Display for OutboxStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A queued reply.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OutboxEntry {
+    pub id: i64,
+    /// Channel key the reply goes out on (`telegram.default`).
+    pub channel: String,
+    pub recipient: String,
+    pub thread_ts: Option<String>,
+    pub content: String,
+    pub status: OutboxStatus,
+    /// Send attempts so far, counting the live send that failed.
+    pub attempts: u32,
+    pub created_at: DateTime<Utc>,
+    pub next_attempt_at: DateTime<Utc>,
+    /// A failed attempt after this dead-letters the entry.
+    pub expires_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    /// Platform message id from the delivery receipt, when the channel
+    /// reports one.
+    pub delivery_id: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// Fields for a new outbox entry.
+#[derive(Debug, Clone)]
+pub struct NewOutboxEntry {
+    pub channel: String,
+    pub recipient: String,
+    pub thread_ts: Option<String>,
+    pub content: String,
+}
+
+/// Undelivered entries for one channel, as reported by `/health/details`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutboxDepth {
+    #[serde(default)]
+    pub pending: usize,
+    #[serde(default)]
+    pub dead: usize,
+}
+
+/// Result of [`ChannelOutbox::retry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetryOutcome {
+    Requeued(OutboxEntry),
+    /// Already delivered; retrying would send it twice.
+    AlreadyDelivered(OutboxEntry),
+    NotFound,
+}
+
+/// The outbox database plus the retry policy from `[channels.outbox]`.
+pub struct ChannelOutbox {
+    db_path: PathBuf,
+    max_age: Duration,
+    base_retry: Duration,
+    max_retry: Duration,
+}
+
+static ACTIVE: RwLock<Option<Arc<ChannelOutbox>>> = RwLock::new(None);
+
+/// Install (or clear) the outbox failed replies are parked in. The channel
+/// supervisor installs one when `[channels.outbox] enabled = true`.
+pub fn install(outbox: Option<Arc<ChannelOutbox>>) {
+    *ACTIVE.write() = outbox;
+}
+
+/// The installed outbox, if any.
+pub fn active() -> Option<Arc<ChannelOutbox>> {
+    ACTIVE.read().clone()
+}
+
+impl ChannelOutbox {
+    pub fn new(config: &Config) -> Self {
+        let outbox = &config.channels.outbox;
+        Self {
+            db_path: outbox_db_path(config),
+            max_age: Duration::from_secs(outbox.max_age_secs),
+            base_retry: Duration::from_secs(outbox.base_retry_secs),
+            max_retry: Duration::from_secs(outbox.max_retry_secs.max(outbox.base_retry_secs)),
+        }
+    }
+
+    /// Park a reply whose live send failed with `error`.
+    pub fn enqueue(&self, entry: NewOutboxEntry, error: &str) -> Result<OutboxEntry> {
+        let now = Utc::now().timestamp();
+        let id = with_connection(&self.db_path, |conn| {
+            conn.execute(
+                "INSERT INTO outbox (channel, recipient, thread_ts, content, status, attempts,
+                                     created_at, next_attempt_at, expires_at, last_error)
+                 VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, ?7, ?8, ?9)",
+                params![
+                    entry.channel,
+                    entry.recipient,
+                    entry.thread_ts,
+                    entry.content,
+                    OutboxStatus::Pending.as_str(),
+                    now,
+                    after(now, self.base_retry),
+                    after(now, self.max_age),
+                    error,
+                ],
+            )
+            .context("Failed to queue outbound message")?;
+            Ok(conn.last_insert_rowid())
+        })?;
+        self.get(id)?
+            .with_context(|| format!("Outbox entry {id} vanished"))
+    }
+
+    /// Whether `recipient` on `channel` already has replies waiting. New
+    /// replies for it are queued behind them so they arrive in order.
+    pub fn has_backlog(&self, channel: &str, recipient: &str) -> Result<bool> {
+        with_connection(&self.db_path, |conn| {
+            conn.query_row(
+                "SELECT EXISTS(SELECT 1 FROM outbox
+                               WHERE channel = ?1 AND recipient = ?2 AND status = ?3)",
+                params![channel, recipient, OutboxStatus::Pending.as_str()],
+                |row| row.get(0),
+            )
+            .context("Failed to query outbox backlog")
+        })
+    }
+
+    /// Entries ready for another attempt: the oldest pending entry of each
+    /// `(channel, recipient)`, once its backoff has elapsed.
+    pub fn due(&self) -> Result<Vec<OutboxEntry>> {
+        self.due_at(Utc::now().timestamp())
+    }
+
+    fn due_at(&self, now: i64) -> Result<Vec<OutboxEntry>> {
+        with_connection(&self.db_path, |conn| {
+            let mut stmt = conn.prepare(&format!(
+                "SELECT {OUTBOX_COLUMNS} FROM outbox AS o
+                 WHERE status = ?1 AND next_attempt_at <= ?2
+                   AND id = (SELECT MIN(id) FROM outbox
+                             WHERE channel = o.channel AND recipient = o.recipient
+                               AND status = ?1)
+                 ORDER BY id"
+            ))?;
+            let rows = stmt.query_map(params![OutboxStatus::Pending.as_str(), now], map_row)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+                .context("Failed to list due outbox entries")
+        })
+    }
+
+    /// Record a successful send. Returns `false` when the entry was no
+    /// longer pending (dropped, or delivered by an earlier attempt).
+    pub fn mark_delivered(&self, id: i64, delivery_id: Option<&str>) -> Result<bool> {
+        with_connection(&self.db_path, |conn| {
+            let changed = conn
+                .execute(
+                    "UPDATE outbox SET status = ?1, delivery_id = ?2, delivered_at = ?3,
+                                       attempts = attempts + 1, last_error = NULL
+                     WHERE id = ?4 AND status = ?5",
+                    params![
+                        OutboxStatus::Delivered.as_str(),
+                        delivery_id,
+                        Utc::now().timestamp(),
+                        id,
+                        OutboxStatus::Pending.as_str(),
+                    ],
+                )
+                .context("Failed to mark outbox entry delivered")?;
+            Ok(changed > 0)
+        })
+    }
+
+    /// Record a failed attempt and schedule the next one, or dead-letter the
+    /// entry once it has expired. Returns the entry's new status.
+    pub fn mark_failed(&self, id: i64, error: &str) -> Result<Option<OutboxStatus>> {
+        self.mark_failed_at(id, error, Utc::now().timestamp())
+    }
+
+    fn mark_failed_at(&self, id: i64, error: &str, now: i64) -> Result<Option<OutboxStatus>> {
+        let Some(entry) = self.get(id)? else {
+            return Ok(None);
+        };
+        if entry.status != OutboxStatus::Pending {
+            return Ok(Some(entry.status));
+        }
+        let attempts = entry.attempts.saturating_add(1);
+        let expires_at = entry.expires_at.timestamp();
+        let (status, next_attempt_at) = if now >= expires_at {
+            (OutboxStatus::Dead, now)
+        } else {
+            (
+                OutboxStatus::Pending,
+                after(now, self.backoff(attempts)).min(expires_at),
+            )
+        };
+        with_connection(&self.db_path, |conn| {
+            conn.execute(
+                "UPDATE outbox SET status = ?1, attempts = ?2, next_attempt_at = ?3,
+                                   last_error = ?4
+                 WHERE id = ?5 AND status = ?6",
+                params![
+                    status.as_str(),
+                    attempts,
+                    next_attempt_at,
+                    error,
+                    id,
+                    OutboxStatus::Pending.as_str(),
+                ],
+            )
+            .context("Failed to record outbox attempt")?;
+            Ok(Some(status))
+        })
+    }
+
+    /// Delay after the `attempts`-th failure: `base_retry`, doubling each
+    /// time, capped at `max_retry`.
+    fn backoff(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(31);
+        self.base_retry
+            .checked_mul(1_u32 << exponent)
+            .map_or(self.max_retry, |delay| delay.min(self.max_retry))
+    }
+
+    /// Forget delivered entries older than `max_age_secs`; their receipts
+    /// are no longer needed once nothing can retry them.
+    pub fn prune_delivered(&self) -> Result<usize> {
+        let cutoff = Utc::now()
+            .timestamp()
+            .saturating_sub(i64::try_from(self.max_age.as_secs()).unwrap_or(i64::MAX));
+        with_connection(&self.db_path, |conn| {
+            conn.execute(
+                "DELETE FROM outbox WHERE status = ?1 AND delivered_at < ?2",
+                params![OutboxStatus::Delivered.as_str(), cutoff],
+            )
+            .context("Failed to prune delivered outbox entries")
+        })
+    }
+
+    /// Pending and dead entries per channel key.
+    pub fn depth(&self) -> Result<BTreeMap<String, OutboxDepth>> {
+        with_connection(&self.db_path, |conn| {
+            let mut stmt = conn.prepare(
+                "SELECT channel, status, COUNT(*) FROM outbox
+                 WHERE status != ?1 GROUP BY channel, status",
+            )?;
+            let mut depth: BTreeMap<String, OutboxDepth> = BTreeMap::new();
+            let rows = stmt.query_map(params![OutboxStatus::Delivered.as_str()], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, i64>(2)?,
+                ))
+            })?;
+            for row in rows {
+                let (channel, status, count) = row?;
+                let count = usize::try_from(count).unwrap_or(0);
+                let slot = depth.entry(channel).or_default();
+                match OutboxStatus::parse(&status) {
+                    Some(OutboxStatus::Pending) => slot.pending += count,
+                    Some(OutboxStatus::Dead) => slot.dead += count,
+                    _ => {}
+                }
+            }
+            Ok(depth)
+        })
+    }
+
+    /// Entries in `status` (all undelivered ones when `None`), oldest first.
+    pub fn list(&self, status: Option<OutboxStatus>, limit: usize) -> Result<Vec<OutboxEntry>> {
+        let limit = i64::try_from(limit.max(1)).unwrap_or(i64::MAX);
+        with_connection(&self.db_path, |conn| {
+            let mut stmt = conn.prepare(&format!(
+                "SELECT {OUTBOX_COLUMNS} FROM outbox
+                 WHERE (?1 IS NULL AND status != 'delivered') OR status = ?1
+                 ORDER BY id
+                 LIMIT ?2"
+            ))?;
+            let rows = stmt.query_map(params![status.map(OutboxStatus::as_str), limit], map_row)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+                .context("Failed to list outbox entries")
+        })
+    }
+
+    /// Make `id` due now with a fresh `max_age_secs` window. Works on dead
+    /// and pending entries alike.
+    pub fn retry(&self, id: i64) -> Result<RetryOutcome> {
+        let now = Utc::now().timestamp();
+        let changed = with_connection(&self.db_path, |conn| {
+            conn.execute(
+                "UPDATE outbox SET status = ?1, next_attempt_at = ?2, expires_at = ?3
+                 WHERE id = ?4 AND status != ?5",
+                params![
+                    OutboxStatus::Pending.as_str(),
+                    now,
+                    after(now, self.max_age),
+                    id,
+                    OutboxStatus::Delivered.as_str(),
+                ],
+            )
+            .context("Failed to requeue outbox entry")
+        })?;
+        Ok(match self.get(id)? {
+            None => RetryOutcome::NotFound,
+            Some(entry) if changed > 0 => RetryOutcome::Requeued(entry),
+            Some(entry) => RetryOutcome::AlreadyDelivered(entry),
+        })
+    }
+
+    /// Delete an undelivered entry. Returns `false` when there was none.
+    pub fn drop_entry(&self, id: i64) -> Result<bool> {
+        with_connection(&self.db_path, |conn| {
+            let changed = conn
+                .execute(
+                    "DELETE FROM outbox WHERE id = ?1 AND status != ?2",
+                    params![id, OutboxStatus::Delivered.as_str()],
+                )
+                .context("Failed to drop outbox entry")?;
+            Ok(changed > 0)
+        })
+    }
+
+    pub fn get(&self, id: i64) -> Result<Option<OutboxEntry>> {
+        with_connection(&self.db_path, |conn| {
+            conn.query_row(
+                &format!("SELECT {OUTBOX_COLUMNS} FROM outbox WHERE id = ?1"),
+                params![id],
+                map_row,
+            )
+            .optional()
+            .context("Failed to query outbox entry")
+        })
+    }
+}
+
+/// `now` plus `delay` in unix seconds, saturating.
+fn after(now: i64, delay: Duration) -> i64 {
+    now.saturating_add(i64::try_from(delay.as_secs()).unwrap_or(i64::MAX))
+}
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+fn map_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<OutboxEntry> {
+    let status_raw: String = row.get(5)?;
+    let status = OutboxStatus::parse(&status_raw).ok_or_else(|| {
+        rusqlite::Error::ToSqlConversionFailure(
+            format!("unknown outbox status {status_raw:?}").into(),
+        )
+    })?;
+    Ok(OutboxEntry {
+        id: row.get(0)?,
+        channel: row.get(1)?,
+        recipient: row.get(2)?,
+        thread_ts: row.get(3)?,
+        content: row.get(4)?,
+        status,
+        attempts: row.get(6)?,
+        created_at: timestamp(row.get(7)?),
+        next_attempt_at: timestamp(row.get(8)?),
+        expires_at: timestamp(row.get(9)?),
+        last_error: row.get(10)?,
+        delivery_id: row.get(11)?,
+        delivered_at: row.get::<_, Option<i64>>(12)?.map(timestamp),
+    })
+}
+
+fn outbox_db_path(config: &Config) -> PathBuf {
+    config.data_dir.join("state").join("channel_outbox.db")
+}
+
+fn with_connection<T>(db_path: &Path, f: impl FnOnce(&Connection) -> Result<T>) -> Result<T> {
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create outbox directory: {}", parent.display()))?;
+    }
+    let conn = Connection::open(db_path)
+        .with_context(|| format!("Failed to open outbox DB: {}", db_path.display()))?;
+    conn.busy_timeout(Duration::from_secs(5))?;
+    initialize_schema(&conn)?;
+    f(&conn)
+}
+
+fn initialize_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS outbox (
+            id              INTEGER PRIMARY KEY AUTOINCREMENT,
+            channel         TEXT NOT NULL,
+            recipient       TEXT NOT NULL,
+            thread_ts       TEXT,
+            content         TEXT NOT NULL,
+            status          TEXT NOT NULL,
+            attempts        INTEGER NOT NULL DEFAULT 0,
+            created_at      INTEGER NOT NULL,
+            next_attempt_at INTEGER NOT NULL,
+            expires_at      INTEGER NOT NULL,
+            last_error      TEXT,
+            delivery_id     TEXT,
+            delivered_at    INTEGER
+        );
+        CREATE INDEX IF NOT EXISTS idx_outbox_recipient
+            ON outbox(channel, recipient, status, id);",
+    )
+    .context("Failed to initialize outbox schema")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_outbox(tmp: &TempDir, base_retry_secs: u64, max_age_secs: u64) -> ChannelOutbox {
+        let mut config = Config {
+            data_dir: tmp.path().join("data"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        config.channels.outbox.base_retry_secs = base_retry_secs;
+        config.channels.outbox.max_retry_secs = 600;
+        config.channels.outbox.max_age_secs = max_age_secs;
+        ChannelOutbox::new(&config)
+    }
+
+    fn reply(channel: &str, recipient: &str, content: &str) -> NewOutboxEntry {
+        NewOutboxEntry {
+            channel: channel.into(),
+            recipient: recipient.into(),
+            thread_ts: None,
+            content: content.into(),
+        }
+    }
+
+    #[test]
+    fn only_the_oldest_entry_per_recipient_is_due() {
+        let tmp = TempDir::new().unwrap();
+        let outbox = test_outbox(&tmp, 0, 3600);
+        let first = outbox
+            .enqueue(reply("telegram.default", "alice", "one"), "timeout")
+            .unwrap();
+        let second = outbox
+            .enqueue(reply("telegram.default", "alice", "two"), "timeout")
+            .unwrap();
+        let bob = outbox
+            .enqueue(reply("telegram.default", "bob", "hi"), "timeout")
+            .unwrap();
+        assert!(outbox.has_backlog("telegram.default", "alice").unwrap());
+        assert!(!outbox.has_backlog("discord.default", "alice").unwrap());
+
+        let due: Vec<i64> = outbox.due().unwrap().iter().map(|e| e.id).collect();
+        assert_eq!(due, [first.id, bob.id]);
+
+        assert!(outbox.mark_delivered(first.id, Some("msg-1")).unwrap());
+        // A second confirmation is a no-op: the receipt is recorded once.
+        assert!(!outbox.mark_delivered(first.id, Some("msg-2")).unwrap());
+        let delivered = outbox.get(first.id).unwrap().unwrap();
+        assert_eq!(delivered.status, OutboxStatus::Delivered);
+        assert_eq!(delivered.delivery_id.as_deref(), Some("msg-1"));
+
+        let due: Vec<i64> = outbox.due().unwrap().iter().map(|e| e.id).collect();
+        assert_eq!(due, [second.id, bob.id]);
+    }
+
+    #[test]
+    fn failures_back_off_and_dead_letter_after_max_age() {
+        let tmp = TempDir::new().unwrap();
+        let outbox = test_outbox(&tmp, 30, 3600);
+        let entry = outbox
+            .enqueue(reply("slack.default", "C1", "hello"), "503")
+            .unwrap();
+        let now = entry.created_at.timestamp();
+        assert_eq!(entry.next_attempt_at.timestamp(), now + 30);
+        assert!(outbox.due_at(now).unwrap().is_empty());
+        assert_eq!(outbox.due_at(now + 30).unwrap().len(), 1);
+
+        assert_eq!(
+            outbox.mark_failed_at(entry.id, "503", now + 30).unwrap(),
+            Some(OutboxStatus::Pending)
+        );
+        let retried = outbox.get(entry.id).unwrap().unwrap();
+        assert_eq!(retried.attempts, 2);
+        assert_eq!(retried.next_attempt_at.timestamp(), now + 30 + 60);
+        assert_eq!(outbox.backoff(10), Duration::from_secs(600));
+
+        assert_eq!(
+            outbox.mark_failed_at(entry.id, "503", now + 3600).unwrap(),
+            Some(OutboxStatus::Dead)
+        );
+        assert!(!outbox.has_backlog("slack.default", "C1").unwrap());
+        let depth = outbox.depth().unwrap();
+        assert_eq!(
+            depth.get("slack.default"),
+            Some(&OutboxDepth {
+                pending: 0,
+                dead: 1
+            })
+        );
+        let dead = outbox.list(Some(OutboxStatus::Dead), 10).unwrap();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].last_error.as_deref(), Some("503"));
+    }
+
+    #[test]
+    fn retry_and_drop_leave_delivered_entries_alone() {
+        let tmp = TempDir::new().unwrap();
+        let outbox = test_outbox(&tmp, 30, 0);
+        let dead = outbox
+            .enqueue(reply("discord.default", "42", "late"), "offline")
+            .unwrap();
+        let now = dead.created_at.timestamp();
+        assert_eq!(
+            outbox.mark_failed_at(dead.id, "offline", now).unwrap(),
+            Some(OutboxStatus::Dead)
+        );
+
+        let RetryOutcome::Requeued(requeued) = outbox.retry(dead.id).unwrap() else {
+            panic!("dead entry should be requeued");
+        };
+        assert_eq!(requeued.status, OutboxStatus::Pending);
+        assert_eq!(outbox.due().unwrap().len(), 1);
+
+        assert!(outbox.mark_delivered(dead.id, None).unwrap());
+        assert!(matches!(
+            outbox.retry(dead.id).unwrap(),
+            RetryOutcome::AlreadyDelivered(_)
+        ));
+        assert!(!outbox.drop_entry(dead.id).unwrap());
+        assert_eq!(outbox.retry(999).unwrap(), RetryOutcome::NotFound);
+
+        let other = outbox
+            .enqueue(reply("discord.default", "42", "again"), "offline")
+            .unwrap();
+        assert!(outbox.drop_entry(other.id).unwrap());
+        assert!(outbox.list(None, 10).unwrap().is_empty());
+    }
+}
@@ -1,5 +1,7 @@
 //! Quickstart apply path.
 
+pub mod provision;
+
 use serde::{Deserialize, Serialize};
 
 use zeroclaw_config::helpers::kebab_to_snake;
@@ -182,6 +184,24 @@ pub fn validate_only_with_surface(
     config: &Config,
     surface: Surface,
 ) -> Result<(), Vec<QuickstartError>> {
+    plan_with_surface(submission, config, surface).map(|_| ())
+}
+
+/// One config value an apply would write, for dry-run and summary output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedWrite {
+    pub path: String,
+    /// Display value; secrets are replaced with the mask.
+    pub value: String,
+}
+
+/// Validate `submission` like [`validate_only_with_surface`] and return the
+/// config paths an apply would write, sorted. Nothing is committed.
+pub fn plan_with_surface(
+    submission: &BuilderSubmission,
+    config: &Config,
+    surface: Surface,
+) -> Result<Vec<PlannedWrite>, Vec<QuickstartError>> {
     let ctx = RunCtx::new(surface);
     let mut staged = config.clone();
     let mut errors = Vec::new();
@@ -221,7 +241,22 @@ pub fn validate_only_with_surface(
             "quickstart: validate_only"
         );
     }
-    if ok { Ok(()) } else { Err(errors) }
+    if !ok {
+        return Err(errors);
+    }
+    let mut paths: Vec<&String> = staged.dirty_paths.difference(&config.dirty_paths).collect();
+    paths.sort();
+    Ok(paths
+        .into_iter()
+        .map(|path| PlannedWrite {
+            path: path.clone(),
+            value: if Config::prop_is_secret(path) {
+                zeroclaw_config::traits::MASKED_SECRET.to_string()
+            } else {
+                staged.get_prop(path).unwrap_or_default()
+            },
+        })
+        .collect())
 }
 
 pub async fn apply(
@@ -884,6 +919,9 @@ fn apply_into(
     );
 
     let channel_refs = apply_channels(config, &submission.channels, errors, ctx);
+    if let Some(tunnel) = &submission.public_tunnel {
+        apply_public_tunnel(config, tunnel, errors, ctx);
+    }
     if let Some(ctx) = ctx {
         ::zeroclaw_log::record!(
             DEBUG,
@@ -1428,6 +1466,38 @@ fn usable_quickstart_value(value: &str) -> Option<&str> {
     (!zeroclaw_config::traits::is_unset_display_value(value)).then_some(value)
 }
 
+/// Write the Channels step's "expose gateway publicly" choice into
+/// `[tunnel]`: Cloudflare with the submitted token, plus the provisioned
+/// public URL when the surface learned one.
+fn apply_public_tunnel(
+    config: &mut Config,
+    tunnel: &zeroclaw_config::presets::PublicTunnelChoice,
+    errors: &mut Vec<QuickstartError>,
+    ctx: Option<&RunCtx>,
+) {
+    let token = tunnel.token.trim();
+    if token.is_empty() {
+        errors.push(QuickstartError::for_surface(
+            ctx,
+            QuickstartStep::Channels,
+            "public_tunnel.token",
+            "a Cloudflare Tunnel token is required to expose the gateway publicly",
+            "cli-quickstart-error-tunnel-token",
+            &[],
+        ));
+        return;
+    }
+    config.tunnel.tunnel_provider = "cloudflare".into();
+    config.tunnel.cloudflare = Some(zeroclaw_config::schema::CloudflareTunnelConfig {
+        token: token.to_string(),
+    });
+    config.tunnel.public_url = tunnel
+        .public_url
+        .as_deref()
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty());
+}
+
 fn apply_channels(
     config: &mut Config,
     channels: &[SelectorChoice<ChannelQuickStart>],
@@ -2236,6 +2306,7 @@ mod tests {
             memory: SelectorChoice::Fresh(MemoryChoice::Sqlite),
             channels: vec![],
             peer_groups: vec![],
+            public_tunnel: None,
             agent: AgentIdentity {
                 name: agent_name.into(),
                 system_prompt: "You are helpful.".into(),
@@ -2344,6 +2415,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn apply_public_tunnel_writes_cloudflare_and_public_url() {
+        let mut cfg = Config::default();
+        let mut submission = fresh_submission("bot");
+        submission.public_tunnel = Some(zeroclaw_config::presets::PublicTunnelChoice {
+            token: "cf-token".into(),
+            public_url: Some("https://bot.example.com/".into()),
+        });
+        let mut staged = Vec::new();
+        let mut errors = Vec::new();
+        let applied = apply_into(&mut cfg, &submission, &mut staged, &mut errors, None);
+        assert!(errors.is_empty(), "apply_into errors: {errors:?}");
+        assert!(applied.is_some());
+        assert_eq!(cfg.tunnel.tunnel_provider, "cloudflare");
+        assert_eq!(cfg.tunnel.cloudflare.as_ref().unwrap().token, "cf-token");
+        assert_eq!(
+            cfg.tunnel.public_url.as_deref(),
+            Some("https://bot.example.com")
+        );
+
+        let mut cfg = Config::default();
+        submission.public_tunnel = Some(zeroclaw_config::presets::PublicTunnelChoice {
+            token: "  ".into(),
+            public_url: None,
+        });
+        let mut errors = Vec::new();
+        assert!(apply_into(&mut cfg, &submission, &mut staged, &mut errors, None).is_none());
+        assert_eq!(errors[0].field, "public_tunnel.token");
+        assert_eq!(cfg.tunnel.tunnel_provider, "none");
+    }
+
     #[test]
     fn apply_provider_type_trims_and_canonicalizes_whitespace() {
         // A provider type with stray whitespace must canonicalize to the
@@ -0,0 +1,156 @@
+//! Provisioning files for non-interactive Quickstart
+//! (`zeroclaw quickstart --from-file`).
+//!
+//! A provisioning file is a [`BuilderSubmission`] written as TOML: the same
+//! payload the web form posts to `/api/quickstart/apply`. String values may
+//! reference environment variables as `${NAME}`; they are resolved when the
+//! file is loaded, so tokens never have to be written into the file itself.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use zeroclaw_config::presets::BuilderSubmission;
+
+/// Read `path`, resolve `${NAME}` references from the process environment,
+/// and parse the result as a Quickstart submission.
+pub fn load(path: &Path) -> Result<BuilderSubmission> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read provisioning file {}", path.display()))?;
+    parse(&text, |name| std::env::var(name).ok())
+        .with_context(|| format!("invalid provisioning file {}", path.display()))
+}
+
+/// Parse a provisioning file, resolving `${NAME}` references through `env`.
+/// Every unresolved reference is reported at once.
+pub fn parse(text: &str, env: impl Fn(&str) -> Option<String>) -> Result<BuilderSubmission> {
+    let mut value: toml::Value = toml::from_str(text)?;
+    let mut missing = Vec::new();
+    expand_value(&mut value, &env, &mut missing)?;
+    if !missing.is_empty() {
+        missing.sort();
+        missing.dedup();
+        bail!(
+            "environment variable(s) not set: {}",
+            missing
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    value
+        .try_into()
+        .context("provisioning file is not a valid Quickstart submission")
+}
+
+fn expand_value(
+    value: &mut toml::Value,
+    env: &impl Fn(&str) -> Option<String>,
+    missing: &mut Vec<String>,
+) -> Result<()> {
+    match value {
+        toml::Value::String(s) => *s = expand(s, env, missing)?,
+        toml::Value::Array(items) => {
+            for item in items {
+                expand_value(item, env, missing)?;
+            }
+        }
+        toml::Value::Table(table) => {
+            for (_, item) in table.iter_mut() {
+                expand_value(item, env, missing)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Replace each `${NAME}` in `s`. Unset names are collected into `missing`
+/// and expand to nothing.
+fn expand(
+    s: &str,
+    env: &impl Fn(&str) -> Option<String>,
+    missing: &mut Vec<String>,
+) -> Result<String> {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('}') else {
+            bail!("unterminated `${{` in `{s}`");
+        };
+        let name = &after[..end];
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            bail!("invalid environment variable reference `${{{name}}}`");
+        }
+        match env(name) {
+            Some(v) => out.push_str(&v),
+            None => missing.push(name.to_string()),
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zeroclaw_config::presets::SelectorChoice;
+
+    const FILE: &str = r#"
+risk_profile = { mode = "fresh", value = "balanced" }
+runtime_profile = { mode = "fresh", value = "balanced" }
+memory = { mode = "fresh", value = "sqlite" }
+
+[model_provider]
+mode = "fresh"
+value = { provider_type = "openrouter", alias = "default", model = "x/y", fields = { api_key = "${OPENROUTER_API_KEY}" } }
+
+[[channels]]
+mode = "fresh"
+value = { channel_type = "telegram", alias = "default", fields = { bot_token = "${TELEGRAM_BOT_TOKEN}" } }
+
+[agent]
+name = "assistant"
+system_prompt = "You run on ${HOST_NAME}."
+"#;
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "OPENROUTER_API_KEY" => Some("sk-or".into()),
+            "TELEGRAM_BOT_TOKEN" => Some("123:abc".into()),
+            "HOST_NAME" => Some("pi".into()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn env_references_are_resolved_in_every_string() {
+        let submission = parse(FILE, env).unwrap();
+        let SelectorChoice::Fresh(provider) = &submission.model_provider else {
+            panic!("expected a fresh provider");
+        };
+        assert_eq!(provider.fields["api_key"], "sk-or");
+        let SelectorChoice::Fresh(channel) = &submission.channels[0] else {
+            panic!("expected a fresh channel");
+        };
+        assert_eq!(channel.fields["bot_token"], "123:abc");
+        assert_eq!(submission.agent.system_prompt, "You run on pi.");
+    }
+
+    #[test]
+    fn unset_references_and_missing_fields_fail_loudly() {
+        let err = parse(FILE, |_| None).unwrap_err().to_string();
+        assert!(err.contains("`HOST_NAME`"), "{err}");
+        assert!(err.contains("`OPENROUTER_API_KEY`"), "{err}");
+        assert!(err.contains("`TELEGRAM_BOT_TOKEN`"), "{err}");
+
+        let without_agent = FILE.split("[agent]").next().unwrap();
+        let err = format!("{:#}", parse(without_agent, env).unwrap_err());
+        assert!(err.contains("agent"), "{err}");
+
+        assert!(parse("x = \"${UNTERMINATED\"", env).is_err());
+    }
+}
@@ -6209,6 +6209,7 @@ mod tests {
             memory: SelectorChoice::Fresh(MemoryChoice::Sqlite),
             channels: vec![],
             peer_groups: vec![],
+            public_tunnel: None,
             agent: AgentIdentity {
                 name: "quickstart_bot".into(),
                 system_prompt: "You are helpful.".into(),
@@ -7590,6 +7591,7 @@ mod tests {
                     ConversationMessage::Chat(ChatMessage {
                         role: "user".into(),
                         content: "hello from prior turn".into(),
+                        reasoning: None,
                     }),
                     ConversationMessage::AssistantToolCalls {
                         text: Some("let me check the logs".into()),
@@ -7624,6 +7626,7 @@ mod tests {
                     ConversationMessage::Chat(ChatMessage {
                         role: "assistant".into(),
                         content: "ack from prior turn".into(),
+                        reasoning: None,
                     }),
                 ],
             )
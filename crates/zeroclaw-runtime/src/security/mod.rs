@@ -15,6 +15,7 @@ pub mod external_content;
 pub mod firejail;
 pub mod iam_policy;
 pub mod ingress;
+pub mod injection_scan;
 #[cfg(feature = "sandbox-landlock")]
 pub mod landlock;
 pub mod leak_detector;
@@ -66,6 +67,8 @@ pub use iam_policy::{IamPolicy, PolicyDecision};
 pub use nevis::{NevisAuthProvider, NevisIdentity};
 // Prompt injection defense exports
 #[allow(unused_imports)]
+pub use injection_scan::{InjectionDisposition, InjectionReport, ToolOutputScanner};
+#[allow(unused_imports)]
 pub use leak_detector::{LeakDetector, LeakResult};
 #[allow(unused_imports)]
 pub use prompt_guard::{GuardAction, GuardResult, PromptGuard};
@@ -5,7 +5,13 @@ use std::process::Command;
 use std::str::FromStr;
 use zeroclaw_config::schema::Config;
 
+mod scm;
+pub use scm::dispatch_daemon;
+
 const SERVICE_LABEL: &str = "com.zeroclaw.daemon";
+/// Scheduled task that ran the daemon on Windows before it became an SCM
+/// service; install and uninstall remove it.
+#[cfg(any(windows, test))]
 const WINDOWS_TASK_NAME: &str = "ZeroClaw Daemon";
 
 #[derive(Debug, PartialEq, Eq)]
@@ -89,6 +95,7 @@ pub(crate) fn linux_systemd_runtime_present() -> bool {
     cfg!(target_os = "linux") && Path::new("/run/systemd/system").exists()
 }
 
+#[cfg(any(windows, test))]
 fn windows_task_name() -> &'static str {
     WINDOWS_TASK_NAME
 }
@@ -180,15 +187,7 @@ pub fn is_running(config: &Config) -> bool {
     } else if cfg!(target_os = "linux") {
         is_running_linux(config)
     } else if cfg!(target_os = "windows") {
-        run_capture(Command::new("schtasks").args([
-            "/Query",
-            "/TN",
-            WINDOWS_TASK_NAME,
-            "/FO",
-            "LIST",
-        ]))
-        .map(|out| out.contains("Running"))
-        .unwrap_or(false)
+        scm::is_running()
     } else {
         false
     }
@@ -214,7 +213,7 @@ pub fn install(config: &Config, init_system: InitSystem) -> Result<()> {
         let resolved = init_system.resolve()?;
         install_linux(config, resolved)
     } else if cfg!(target_os = "windows") {
-        install_windows(config)
+        scm::install(config)
     } else {
         anyhow::bail!("Service management is supported on macOS and Linux only");
     }
@@ -240,7 +239,7 @@ pub fn start(config: &Config, init_system: InitSystem) -> Result<()> {
         start_linux(config, resolved)
     } else if cfg!(target_os = "windows") {
         let _ = config;
-        run_checked(Command::new("schtasks").args(["/Run", "/TN", windows_task_name()]))?;
+        scm::start()?;
         println!("✅ Service started");
         Ok(())
     } else {
@@ -286,8 +285,7 @@ pub fn stop(config: &Config, init_system: InitSystem) -> Result<()> {
         stop_linux(config, resolved)
     } else if cfg!(target_os = "windows") {
         let _ = config;
-        let task_name = windows_task_name();
-        let _ = run_checked(Command::new("schtasks").args(["/End", "/TN", task_name]));
+        scm::stop()?;
         println!("✅ Service stopped");
         Ok(())
     } else {
@@ -379,27 +377,7 @@ pub fn status(config: &Config, init_system: InitSystem) -> Result<()> {
 
     if cfg!(target_os = "windows") {
         let _ = config;
-        let task_name = windows_task_name();
-        let out =
-            run_capture(Command::new("schtasks").args(["/Query", "/TN", task_name, "/FO", "LIST"]));
-        match out {
-            Ok(text) => {
-                let running = text.contains("Running");
-                println!(
-                    "Service: {}",
-                    if running {
-                        "✅ running"
-                    } else {
-                        "❌ not running"
-                    }
-                );
-                println!("Task: {}", task_name);
-            }
-            Err(_) => {
-                println!("Service: ❌ not installed");
-            }
-        }
-        return Ok(());
+        return scm::status();
     }
 
     anyhow::bail!("Service management is supported on macOS and Linux only")
@@ -532,14 +510,8 @@ fn logs_linux(config: &Config, init_system: InitSystem, lines: usize, follow: bo
 }
 
 fn logs_windows(config: &Config, lines: usize, follow: bool) -> Result<()> {
-    let logs_dir = config
-        .config_path
-        .parent()
-        .map_or_else(|| PathBuf::from("."), PathBuf::from)
-        .join("logs");
-
-    let stderr_log = logs_dir.join("daemon.stderr.log");
-    let stdout_log = logs_dir.join("daemon.stdout.log");
+    let logs_dir = scm::logs_dir(config);
+    let (stdout_log, stderr_log) = scm::log_files(&logs_dir);
 
     let log_file = if stderr_log.exists() {
         stderr_log
@@ -625,23 +597,7 @@ pub fn uninstall(config: &Config, init_system: InitSystem) -> Result<()> {
     }
 
     if cfg!(target_os = "windows") {
-        let task_name = windows_task_name();
-        let _ = run_checked(Command::new("schtasks").args(["/Delete", "/TN", task_name, "/F"]));
-        // Remove the wrapper script. It now lives in the config dir root, but
-        // older installs left it under logs/ — clean up both so an upgrade
-        // doesn't strand the legacy copy.
-        let base_dir = config
-            .config_path
-            .parent()
-            .map_or_else(|| PathBuf::from("."), PathBuf::from);
-        for wrapper in [
-            base_dir.join("zeroclaw-daemon.cmd"),
-            base_dir.join("logs").join("zeroclaw-daemon.cmd"),
-        ] {
-            if wrapper.exists() {
-                fs::remove_file(&wrapper).ok();
-            }
-        }
+        scm::uninstall(config)?;
         println!("✅ Service uninstalled");
         return Ok(());
     }
@@ -1481,58 +1437,6 @@ fn install_linux_openrc(config: &Config) -> Result<()> {
     Ok(())
 }
 
-fn install_windows(config: &Config) -> Result<()> {
-    let exe = std::env::current_exe().context("Failed to resolve current executable")?;
-    let base_dir = config
-        .config_path
-        .parent()
-        .map_or_else(|| PathBuf::from("."), PathBuf::from);
-    let logs_dir = base_dir.join("logs");
-    fs::create_dir_all(&logs_dir)?;
-
-    // The launch wrapper is an install artifact, not log output — keep it in
-    // the config dir root so the logs dir holds only `.log` files. (Previously
-    // it landed in logs/, where a `.cmd` next to the daemon's log files reads
-    // as misplaced.)
-    let wrapper = base_dir.join("zeroclaw-daemon.cmd");
-    let stdout_log = logs_dir.join("daemon.stdout.log");
-    let stderr_log = logs_dir.join("daemon.stderr.log");
-
-    let wrapper_content = format!(
-        "@echo off\r\n\"{}\" daemon >>\"{}\" 2>>\"{}\"",
-        exe.display().to_string(),
-        stdout_log.display().to_string(),
-        stderr_log.display()
-    );
-    fs::write(&wrapper, &wrapper_content)?;
-
-    let task_name = windows_task_name();
-
-    // Remove any existing task first (ignore errors if it doesn't exist)
-    let _ = Command::new("schtasks")
-        .args(["/Delete", "/TN", task_name, "/F"])
-        .output();
-
-    run_checked(Command::new("schtasks").args([
-        "/Create",
-        "/TN",
-        task_name,
-        "/SC",
-        "ONLOGON",
-        "/TR",
-        &format!("\"{}\"", wrapper.display().to_string()),
-        "/RL",
-        "LIMITED",
-        "/F",
-    ]))?;
-
-    println!("✅ Installed Windows scheduled task: {}", task_name);
-    println!("   Wrapper: {}", wrapper.display().to_string());
-    println!("   Logs: {}", logs_dir.display().to_string());
-    println!("   Start with: zeroclaw service start");
-    Ok(())
-}
-
 fn macos_service_file() -> Result<PathBuf> {
     let home = directories::UserDirs::new()
         .map(|u| u.home_dir().to_path_buf())
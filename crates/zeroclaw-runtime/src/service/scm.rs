@@ -0,0 +1,554 @@
+//! Windows service support through the Service Control Manager (SCM).
+//!
+//! `service install` registers `zeroclaw --config-dir <dir> daemon` as an
+//! auto-start service that the SCM restarts when it fails. Windows has no
+//! journal, so the service's stdout and stderr go to `logs/` in the config
+//! directory, where `service logs` reads them.
+//!
+//! When the SCM launches the daemon it must hand the main thread to the
+//! service dispatcher; [`dispatch_daemon`] does that and turns stop and
+//! shutdown controls into the daemon's graceful in-process shutdown.
+
+use anyhow::Result;
+#[cfg(any(windows, test))]
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+#[cfg(any(windows, test))]
+use std::time::Duration;
+use zeroclaw_config::schema::Config;
+
+/// Delays before the SCM restarts a failed service: first, second, and
+/// every later failure within [`FAILURE_RESET_PERIOD`].
+#[cfg(any(windows, test))]
+const RESTART_DELAYS: [Duration; 3] = [
+    Duration::from_secs(10),
+    Duration::from_secs(30),
+    Duration::from_secs(60),
+];
+/// Failure-free run time after which the SCM resets the failure count.
+#[cfg(any(windows, test))]
+const FAILURE_RESET_PERIOD: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Arguments the SCM passes to the executable. The config dir is explicit
+/// because the service runs as LocalSystem, whose default config dir is not
+/// the installing user's.
+#[cfg(any(windows, test))]
+fn launch_arguments(config: &Config) -> Vec<OsString> {
+    vec![
+        "--config-dir".into(),
+        config_dir(config).into_os_string(),
+        "daemon".into(),
+    ]
+}
+
+fn config_dir(config: &Config) -> PathBuf {
+    config
+        .config_path
+        .parent()
+        .map_or_else(|| PathBuf::from("."), PathBuf::from)
+}
+
+pub(super) fn logs_dir(config: &Config) -> PathBuf {
+    config_dir(config).join("logs")
+}
+
+/// The service's `(stdout, stderr)` log files.
+pub(super) fn log_files(logs_dir: &Path) -> (PathBuf, PathBuf) {
+    (
+        logs_dir.join("daemon.stdout.log"),
+        logs_dir.join("daemon.stderr.log"),
+    )
+}
+
+/// Run the daemon entry point `run`. When the SCM started this process, it
+/// runs inside the service dispatcher with stdio redirected to
+/// `logs_dir`; stop and shutdown controls request a graceful shutdown.
+/// Started any other way, and on other platforms, `run` is called directly.
+pub fn dispatch_daemon<F>(logs_dir: Option<PathBuf>, run: F) -> Result<()>
+where
+    F: FnOnce() -> Result<()> + Send + 'static,
+{
+    #[cfg(windows)]
+    {
+        imp::dispatch_daemon(logs_dir, Box::new(run))
+    }
+    #[cfg(not(windows))]
+    {
+        let _ = logs_dir;
+        run()
+    }
+}
+
+#[cfg(windows)]
+pub(super) use imp::{install, is_running, start, status, stop, uninstall};
+
+#[cfg(not(windows))]
+mod unsupported {
+    use super::Config;
+    use anyhow::{Result, bail};
+
+    const MESSAGE: &str = "Windows services are only available on Windows";
+
+    pub(in super::super) fn install(_: &Config) -> Result<()> {
+        bail!(MESSAGE)
+    }
+
+    pub(in super::super) fn start() -> Result<()> {
+        bail!(MESSAGE)
+    }
+
+    pub(in super::super) fn stop() -> Result<()> {
+        bail!(MESSAGE)
+    }
+
+    pub(in super::super) fn status() -> Result<()> {
+        bail!(MESSAGE)
+    }
+
+    pub(in super::super) fn uninstall(_: &Config) -> Result<()> {
+        bail!(MESSAGE)
+    }
+
+    pub(in super::super) fn is_running() -> bool {
+        false
+    }
+}
+
+#[cfg(not(windows))]
+pub(super) use unsupported::{install, is_running, start, status, stop, uninstall};
+
+#[cfg(windows)]
+mod imp {
+    use super::{
+        Config, FAILURE_RESET_PERIOD, RESTART_DELAYS, config_dir, launch_arguments, log_files,
+        logs_dir,
+    };
+    use anyhow::{Context, Result, anyhow, bail};
+    use parking_lot::Mutex;
+    use std::ffi::{OsStr, OsString};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use std::time::{Duration, Instant};
+    use windows_service::service::{
+        Service, ServiceAccess, ServiceAction, ServiceActionType, ServiceControl,
+        ServiceControlAccept, ServiceErrorControl, ServiceExitCode, ServiceFailureActions,
+        ServiceFailureResetPeriod, ServiceInfo, ServiceStartType, ServiceState, ServiceStatus,
+        ServiceType,
+    };
+    use windows_service::service_control_handler::{self, ServiceControlHandlerResult};
+    use windows_service::service_manager::{ServiceManager, ServiceManagerAccess};
+    use windows_service::{define_windows_service, service_dispatcher};
+
+    const SERVICE_NAME: &str = "zeroclaw";
+    const DISPLAY_NAME: &str = "ZeroClaw Daemon";
+    const DESCRIPTION: &str =
+        "Runs the ZeroClaw daemon: gateway, channels, scheduler, and heartbeat.";
+    /// How long `service stop` waits for the daemon's graceful shutdown.
+    const STOP_TIMEOUT: Duration = Duration::from_secs(30);
+
+    const ERROR_ACCESS_DENIED: i32 = 5;
+    const ERROR_SERVICE_ALREADY_RUNNING: i32 = 1056;
+    const ERROR_SERVICE_DOES_NOT_EXIST: i32 = 1060;
+    const ERROR_SERVICE_NOT_ACTIVE: i32 = 1062;
+    const ERROR_FAILED_SERVICE_CONTROLLER_CONNECT: i32 = 1063;
+
+    fn os_error(err: &windows_service::Error) -> Option<i32> {
+        match err {
+            windows_service::Error::Winapi(e) => e.raw_os_error(),
+            _ => None,
+        }
+    }
+
+    fn scm_error(err: windows_service::Error, action: &str) -> anyhow::Error {
+        if os_error(&err) == Some(ERROR_ACCESS_DENIED) {
+            anyhow!(
+                "Failed to {action}: access denied. Run this command from an elevated \
+                 (Administrator) terminal."
+            )
+        } else {
+            anyhow::Error::new(err).context(format!("Failed to {action}"))
+        }
+    }
+
+    fn manager(access: ServiceManagerAccess) -> Result<ServiceManager> {
+        ServiceManager::local_computer(None::<&str>, access)
+            .map_err(|e| scm_error(e, "connect to the Service Control Manager"))
+    }
+
+    /// `Ok(None)` when the service is not installed.
+    fn open(access: ServiceAccess) -> Result<Option<Service>> {
+        match manager(ServiceManagerAccess::CONNECT)?.open_service(SERVICE_NAME, access) {
+            Ok(service) => Ok(Some(service)),
+            Err(e) if os_error(&e) == Some(ERROR_SERVICE_DOES_NOT_EXIST) => Ok(None),
+            Err(e) => Err(scm_error(e, "open the zeroclaw service")),
+        }
+    }
+
+    fn failure_actions() -> ServiceFailureActions {
+        ServiceFailureActions {
+            reset_period: ServiceFailureResetPeriod::After(FAILURE_RESET_PERIOD),
+            reboot_msg: None,
+            command: None,
+            actions: Some(
+                RESTART_DELAYS
+                    .iter()
+                    .map(|&delay| ServiceAction {
+                        action_type: ServiceActionType::Restart,
+                        delay,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn state_label(state: ServiceState) -> &'static str {
+        match state {
+            ServiceState::Running => "✅ running",
+            ServiceState::Stopped => "❌ stopped",
+            ServiceState::StartPending => "⏳ starting",
+            ServiceState::StopPending => "⏳ stopping",
+            ServiceState::Paused | ServiceState::PausePending | ServiceState::ContinuePending => {
+                "⏸ paused"
+            }
+        }
+    }
+
+    pub(in super::super) fn install(config: &Config) -> Result<()> {
+        let exe = std::env::current_exe().context("Failed to resolve current executable")?;
+        let logs_dir = logs_dir(config);
+        fs::create_dir_all(&logs_dir)?;
+        // Earlier releases ran the daemon as a scheduled task; two
+        // supervisors would fight over the gateway port.
+        remove_legacy_task(config);
+
+        let info = ServiceInfo {
+            name: SERVICE_NAME.into(),
+            display_name: DISPLAY_NAME.into(),
+            service_type: ServiceType::OWN_PROCESS,
+            start_type: ServiceStartType::AutoStart,
+            error_control: ServiceErrorControl::Normal,
+            executable_path: exe,
+            launch_arguments: launch_arguments(config),
+            dependencies: Vec::new(),
+            account_name: None,
+            account_password: None,
+        };
+        let manager =
+            manager(ServiceManagerAccess::CONNECT | ServiceManagerAccess::CREATE_SERVICE)?;
+        let access = ServiceAccess::CHANGE_CONFIG | ServiceAccess::START;
+        let service = match manager.open_service(SERVICE_NAME, access) {
+            Ok(existing) => {
+                existing
+                    .change_config(&info)
+                    .map_err(|e| scm_error(e, "update the zeroclaw service"))?;
+                existing
+            }
+            Err(e) if os_error(&e) == Some(ERROR_SERVICE_DOES_NOT_EXIST) => manager
+                .create_service(&info, access)
+                .map_err(|e| scm_error(e, "create the zeroclaw service"))?,
+            Err(e) => return Err(scm_error(e, "open the zeroclaw service")),
+        };
+        service
+            .set_description(DESCRIPTION)
+            .map_err(|e| scm_error(e, "describe the zeroclaw service"))?;
+        service
+            .update_failure_actions(failure_actions())
+            .map_err(|e| scm_error(e, "set the zeroclaw service restart policy"))?;
+        // The daemon reports a service-specific exit code when it fails
+        // cleanly; restart on that too, not only on crashes.
+        service
+            .set_failure_actions_on_non_crash_failures(true)
+            .map_err(|e| scm_error(e, "set the zeroclaw service restart policy"))?;
+
+        println!("✅ Installed Windows service: {SERVICE_NAME} ({DISPLAY_NAME})");
+        println!("   Logs: {}", logs_dir.display());
+        println!("   Start with: zeroclaw service start");
+        Ok(())
+    }
+
+    pub(in super::super) fn start() -> Result<()> {
+        let service = open(ServiceAccess::START)?
+            .context("The zeroclaw service is not installed. Run: zeroclaw service install")?;
+        match service.start(&[] as &[&OsStr]) {
+            Ok(()) => Ok(()),
+            Err(e) if os_error(&e) == Some(ERROR_SERVICE_ALREADY_RUNNING) => Ok(()),
+            Err(e) => Err(scm_error(e, "start the zeroclaw service")),
+        }
+    }
+
+    /// Stop the service and wait for it to report stopped, so a following
+    /// start or delete does not race the shutdown.
+    pub(in super::super) fn stop() -> Result<()> {
+        let Some(service) = open(ServiceAccess::STOP | ServiceAccess::QUERY_STATUS)? else {
+            return Ok(());
+        };
+        match service.stop() {
+            Ok(_) => {}
+            Err(e) if os_error(&e) == Some(ERROR_SERVICE_NOT_ACTIVE) => return Ok(()),
+            Err(e) => return Err(scm_error(e, "stop the zeroclaw service")),
+        }
+        let deadline = Instant::now() + STOP_TIMEOUT;
+        while Instant::now() < deadline {
+            let stopped = service
+                .query_status()
+                .map_or(true, |s| s.current_state == ServiceState::Stopped);
+            if stopped {
+                return Ok(());
+            }
+            std::thread::sleep(Duration::from_millis(250));
+        }
+        bail!(
+            "The zeroclaw service did not stop within {}s",
+            STOP_TIMEOUT.as_secs()
+        )
+    }
+
+    pub(in super::super) fn status() -> Result<()> {
+        let Some(service) = open(ServiceAccess::QUERY_STATUS)? else {
+            println!("Service: ❌ not installed");
+            return Ok(());
+        };
+        let state = service
+            .query_status()
+            .map_err(|e| scm_error(e, "query the zeroclaw service"))?
+            .current_state;
+        println!("Service: {}", state_label(state));
+        println!("Service name: {SERVICE_NAME}");
+        Ok(())
+    }
+
+    pub(in super::super) fn is_running() -> bool {
+        open(ServiceAccess::QUERY_STATUS)
+            .ok()
+            .flatten()
+            .and_then(|service| service.query_status().ok())
+            .is_some_and(|s| s.current_state == ServiceState::Running)
+    }
+
+    pub(in super::super) fn uninstall(config: &Config) -> Result<()> {
+        if let Some(service) = open(ServiceAccess::DELETE)? {
+            service
+                .delete()
+                .map_err(|e| scm_error(e, "delete the zeroclaw service"))?;
+        }
+        remove_legacy_task(config);
+        Ok(())
+    }
+
+    /// Remove the scheduled task and launch wrapper earlier releases
+    /// installed. The wrapper lived in the config dir root, and before
+    /// that under `logs/`.
+    fn remove_legacy_task(config: &Config) {
+        let _ = Command::new("schtasks")
+            .args(["/Delete", "/TN", super::super::windows_task_name(), "/F"])
+            .output();
+        let base_dir = config_dir(config);
+        for wrapper in [
+            base_dir.join("zeroclaw-daemon.cmd"),
+            base_dir.join("logs").join("zeroclaw-daemon.cmd"),
+        ] {
+            if wrapper.exists() {
+                fs::remove_file(&wrapper).ok();
+            }
+        }
+    }
+
+    // ── Service dispatcher ──────────────────────────────────────────
+
+    type DaemonMain = Box<dyn FnOnce() -> Result<()> + Send>;
+
+    struct Daemon {
+        run: DaemonMain,
+        logs_dir: Option<PathBuf>,
+    }
+
+    /// Handed from `dispatch_daemon` to `service_main`, which the SCM calls
+    /// on its own thread with no way to pass state.
+    static DAEMON: Mutex<Option<Daemon>> = Mutex::new(None);
+    static DAEMON_RESULT: Mutex<Option<Result<()>>> = Mutex::new(None);
+
+    define_windows_service!(ffi_service_main, service_main);
+
+    pub(super) fn dispatch_daemon(logs_dir: Option<PathBuf>, run: DaemonMain) -> Result<()> {
+        *DAEMON.lock() = Some(Daemon { run, logs_dir });
+        match service_dispatcher::start(SERVICE_NAME, ffi_service_main) {
+            Ok(()) => DAEMON_RESULT.lock().take().unwrap_or(Ok(())),
+            // Not started by the SCM: an ordinary console `zeroclaw daemon`.
+            Err(e) if os_error(&e) == Some(ERROR_FAILED_SERVICE_CONTROLLER_CONNECT) => {
+                let daemon = DAEMON
+                    .lock()
+                    .take()
+                    .context("daemon entry point already consumed")?;
+                (daemon.run)()
+            }
+            Err(e) => Err(anyhow::Error::new(e).context("Failed to start the service dispatcher")),
+        }
+    }
+
+    fn service_main(_arguments: Vec<OsString>) {
+        let Some(daemon) = DAEMON.lock().take() else {
+            return;
+        };
+        let result = run_service(daemon);
+        *DAEMON_RESULT.lock() = Some(result);
+    }
+
+    fn run_service(daemon: Daemon) -> Result<()> {
+        let status = service_control_handler::register(SERVICE_NAME, |control| match control {
+            ServiceControl::Stop | ServiceControl::Shutdown => {
+                crate::restart::request_shutdown();
+                ServiceControlHandlerResult::NoError
+            }
+            ServiceControl::Interrogate => ServiceControlHandlerResult::NoError,
+            _ => ServiceControlHandlerResult::NotImplemented,
+        })
+        .context("Failed to register the service control handler")?;
+        let report = |current_state, controls_accepted, exit_code| {
+            status.set_service_status(ServiceStatus {
+                service_type: ServiceType::OWN_PROCESS,
+                current_state,
+                controls_accepted,
+                exit_code,
+                checkpoint: 0,
+                wait_hint: Duration::default(),
+                process_id: None,
+            })
+        };
+
+        if let Some(logs_dir) = &daemon.logs_dir {
+            // Nowhere to report a failure yet; the daemon still runs, just
+            // without captured output.
+            let _ = redirect_stdio(logs_dir);
+        }
+        report(
+            ServiceState::Running,
+            ServiceControlAccept::STOP | ServiceControlAccept::SHUTDOWN,
+            ServiceExitCode::Win32(0),
+        )?;
+
+        let result = (daemon.run)();
+        if let Err(e) = &result {
+            eprintln!("Error: {e:#}");
+        }
+        // A non-zero service-specific code lets the failure actions set at
+        // install time restart the daemon.
+        let exit_code = match result {
+            Ok(()) => ServiceExitCode::Win32(0),
+            Err(_) => ServiceExitCode::ServiceSpecific(1),
+        };
+        report(
+            ServiceState::Stopped,
+            ServiceControlAccept::empty(),
+            exit_code,
+        )?;
+        result
+    }
+
+    /// Point the process's stdout and stderr at the service log files. A
+    /// service has no console, so without this all output is lost.
+    fn redirect_stdio(logs_dir: &Path) -> Result<()> {
+        use std::os::windows::io::IntoRawHandle;
+        use windows::Win32::Foundation::HANDLE;
+        use windows::Win32::System::Console::{STD_ERROR_HANDLE, STD_OUTPUT_HANDLE, SetStdHandle};
+
+        fs::create_dir_all(logs_dir)?;
+        let (stdout_log, stderr_log) = log_files(logs_dir);
+        for (path, std_handle) in [
+            (stdout_log, STD_OUTPUT_HANDLE),
+            (stderr_log, STD_ERROR_HANDLE),
+        ] {
+            let file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .with_context(|| format!("Failed to open {}", path.display()))?;
+            // SAFETY: the handle belongs to a file opened above and
+            // deliberately leaked, so it stays valid for the process lifetime.
+            unsafe { SetStdHandle(std_handle, HANDLE(file.into_raw_handle())) }?;
+        }
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn failure_actions_restart_with_increasing_delays() {
+            let actions = failure_actions();
+            assert!(matches!(
+                actions.reset_period,
+                ServiceFailureResetPeriod::After(period) if period == FAILURE_RESET_PERIOD
+            ));
+            let actions = actions.actions.unwrap();
+            assert_eq!(actions.len(), RESTART_DELAYS.len());
+            assert!(
+                actions
+                    .iter()
+                    .all(|a| a.action_type == ServiceActionType::Restart)
+            );
+            assert_eq!(actions[0].delay, Duration::from_secs(10));
+        }
+
+        #[test]
+        fn state_labels_distinguish_running_from_stopped() {
+            assert_eq!(state_label(ServiceState::Running), "✅ running");
+            assert_eq!(state_label(ServiceState::Stopped), "❌ stopped");
+            assert_eq!(state_label(ServiceState::StopPending), "⏳ stopping");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_at(path: &str) -> Config {
+        Config {
+            config_path: PathBuf::from(path),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn launch_arguments_pin_the_installing_config_dir() {
+        let config = config_at("/home/alice/.zeroclaw/config.toml");
+        assert_eq!(
+            launch_arguments(&config),
+            [
+                OsString::from("--config-dir"),
+                OsString::from("/home/alice/.zeroclaw"),
+                OsString::from("daemon"),
+            ]
+        );
+    }
+
+    #[test]
+    fn service_logs_live_under_the_config_dir() {
+        let config = config_at("/srv/zeroclaw/config.toml");
+        let logs = logs_dir(&config);
+        assert_eq!(logs, Path::new("/srv/zeroclaw/logs"));
+        let (stdout_log, stderr_log) = log_files(&logs);
+        assert_eq!(
+            stdout_log,
+            Path::new("/srv/zeroclaw/logs/daemon.stdout.log")
+        );
+        assert_eq!(
+            stderr_log,
+            Path::new("/srv/zeroclaw/logs/daemon.stderr.log")
+        );
+    }
+
+    #[test]
+    fn restart_delays_back_off_within_the_reset_period() {
+        assert!(RESTART_DELAYS.windows(2).all(|w| w[0] <= w[1]));
+        assert!(RESTART_DELAYS.iter().all(|&d| d < FAILURE_RESET_PERIOD));
+    }
+
+    #[test]
+    fn dispatch_daemon_runs_the_entry_point_outside_the_scm() {
+        let result = dispatch_daemon(None, || anyhow::bail!("daemon failed"));
+        assert_eq!(result.unwrap_err().to_string(), "daemon failed");
+    }
+}
@@ -214,6 +214,7 @@ mod tests {
                     tools: vec![],
                     prompts: vec![],
                     slash_options: vec![],
+                    parameters: Vec::new(),
                     location: None,
                 }],
                 dropped: vec![],
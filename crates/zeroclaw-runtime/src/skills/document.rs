@@ -3,7 +3,7 @@
 use std::fmt::Write as _;
 
 use super::frontmatter::SkillFrontmatter;
-use super::{SkillSlashChoice, SkillSlashOption};
+use super::{SkillParameter, SkillSlashChoice, SkillSlashOption};
 
 // `Eq` is intentionally NOT derived: the frontmatter's `slash_options` carry
 // `f64` bounds (no total ordering). `PartialEq` covers the round-trip tests.
@@ -44,6 +44,7 @@ impl SkillDocument {
         write_optional(&mut out, "category", self.frontmatter.category.as_deref());
         write_tags(&mut out, &self.frontmatter.tags);
         write_slash_options(&mut out, &self.frontmatter.slash_options);
+        write_parameters(&mut out, &self.frontmatter.parameters);
         out.push_str("---\n");
         if !self.body.is_empty() {
             if !self.body.starts_with('\n') {
@@ -81,10 +82,11 @@ fn parse_frontmatter(src: &str) -> Result<SkillFrontmatter, DocumentParseError>
     let mut multiline: Option<(String, Vec<String>)> = None;
     let mut collecting_tags = false;
 
-    // Carve the nested `slash_options:` block out of the flat scan: its indented
-    // lines must not be (mis)read as flat keys — e.g. an option `description:`
-    // would otherwise hijack the skill's block-scalar collector.
-    let block_range = locate_slash_options_block(src);
+    // Carve the nested `slash_options:` / `parameters:` blocks out of the flat
+    // scan: their indented lines must not be (mis)read as flat keys — e.g. an
+    // option `description:` would otherwise hijack the skill's block-scalar
+    // collector.
+    let nested = nested_block_ranges(src);
 
     let flush = |fm: &mut SkillFrontmatter, key: &str, parts: &[String]| {
         let val = parts.join(" ");
@@ -96,10 +98,7 @@ fn parse_frontmatter(src: &str) -> Result<SkillFrontmatter, DocumentParseError>
     };
 
     for (idx, line) in src.lines().enumerate() {
-        if let Some((start, end)) = block_range
-            && idx >= start
-            && idx < end
-        {
+        if in_nested_block(&nested, idx) {
             continue;
         }
         if let Some((ref key, ref mut parts)) = multiline {
@@ -155,10 +154,10 @@ fn parse_frontmatter(src: &str) -> Result<SkillFrontmatter, DocumentParseError>
         flush(&mut fm, &key, &parts);
     }
 
-    // The one nested field. The flat loop above skips the `slash_options:`
-    // block (its indented lines have no top-level `key: value` it recognizes);
-    // the shared helper lifts it out and parses it.
+    // The nested fields. The flat loop above skips their blocks; the shared
+    // helpers lift them out and parse them.
     fm.slash_options = parse_slash_options(src);
+    fm.parameters = parse_parameters(src);
 
     if fm.name.is_empty() {
         return Err(DocumentParseError::MissingRequiredField("name"));
@@ -222,14 +221,20 @@ fn indent_of(line: &str) -> usize {
     line.len() - line.trim_start().len()
 }
 
-fn locate_slash_options_block(src: &str) -> Option<(usize, usize)> {
+/// Top-level frontmatter keys whose value is a nested list of maps rather
+/// than a flat scalar.
+const NESTED_BLOCK_KEYS: &[&str] = &["slash_options", "parameters"];
+
+/// Line range `[start, end)` of the nested block under the top-level `key:`,
+/// where `start` is the key line itself.
+fn locate_block(src: &str, key: &str) -> Option<(usize, usize)> {
     let lines: Vec<&str> = src.lines().collect();
     let start = lines.iter().position(|line| {
         if line.starts_with(' ') || line.starts_with('\t') {
             return false;
         }
         match line.split_once(':') {
-            Some((key, value)) => key.trim() == "slash_options" && value.trim().is_empty(),
+            Some((k, value)) => k.trim() == key && value.trim().is_empty(),
             None => false,
         }
     })?;
@@ -245,18 +250,35 @@ fn locate_slash_options_block(src: &str) -> Option<(usize, usize)> {
     Some((start, end))
 }
 
-pub(crate) fn parse_slash_options(src: &str) -> Vec<SkillSlashOption> {
-    let Some((start, end)) = locate_slash_options_block(src) else {
+/// Ranges of every nested block in the frontmatter, for flat scanners that
+/// must skip them.
+pub(crate) fn nested_block_ranges(src: &str) -> Vec<(usize, usize)> {
+    NESTED_BLOCK_KEYS
+        .iter()
+        .filter_map(|key| locate_block(src, key))
+        .collect()
+}
+
+pub(crate) fn in_nested_block(ranges: &[(usize, usize)], idx: usize) -> bool {
+    ranges.iter().any(|&(start, end)| idx >= start && idx < end)
+}
+
+/// Item lines of the nested block under `key:`, without the key line.
+fn block_lines<'a>(src: &'a str, key: &str) -> Vec<&'a str> {
+    let Some((start, end)) = locate_block(src, key) else {
         return Vec::new();
     };
-    let lines: Vec<&str> = src.lines().collect();
-    let block = &lines[start + 1..end];
+    src.lines().skip(start + 1).take(end - start - 1).collect()
+}
+
+pub(crate) fn parse_slash_options(src: &str) -> Vec<SkillSlashOption> {
+    let block = block_lines(src, "slash_options");
 
     let mut options: Vec<SkillSlashOption> = Vec::new();
     let mut item_indent: Option<usize> = None;
     let mut cur: Option<OptionBuilder> = None;
 
-    for &line in block {
+    for line in block {
         if line.trim().is_empty() {
             continue;
         }
@@ -417,6 +439,74 @@ fn parse_choice(s: &str) -> Option<SkillSlashChoice> {
     Some(SkillSlashChoice { name, value })
 }
 
+/// Parse the nested `parameters:` block: a list of `name` / `type` /
+/// `description` / `required` maps. Items without a name are dropped; a
+/// missing `type` means `string`.
+pub(crate) fn parse_parameters(src: &str) -> Vec<SkillParameter> {
+    let mut params: Vec<SkillParameter> = Vec::new();
+    let mut cur: Option<SkillParameter> = None;
+    let flush = |cur: Option<SkillParameter>, params: &mut Vec<SkillParameter>| {
+        if let Some(p) = cur.filter(|p| !p.name.is_empty()) {
+            params.push(p);
+        }
+    };
+
+    for line in block_lines(src, "parameters") {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let field = if trimmed == "-" {
+            Some("")
+        } else {
+            trimmed.strip_prefix("- ")
+        };
+        let field = match field {
+            Some(rest) => {
+                flush(cur.take(), &mut params);
+                cur = Some(SkillParameter {
+                    name: String::new(),
+                    description: String::new(),
+                    kind: super::default_parameter_kind(),
+                    required: false,
+                });
+                rest
+            }
+            None => trimmed,
+        };
+        let (Some(p), Some((key, value))) = (cur.as_mut(), field.split_once(':')) else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"').trim_matches('\'');
+        match key.trim() {
+            "name" => p.name = value.to_string(),
+            "description" => p.description = value.to_string(),
+            "type" if !value.is_empty() => p.kind = value.to_ascii_lowercase(),
+            "required" => p.required = value.eq_ignore_ascii_case("true"),
+            _ => {}
+        }
+    }
+    flush(cur.take(), &mut params);
+    params
+}
+
+fn write_parameters(out: &mut String, params: &[SkillParameter]) {
+    if params.is_empty() {
+        return;
+    }
+    out.push_str("parameters:\n");
+    for p in params {
+        let _ = writeln!(out, "  - name: {}", p.name);
+        let _ = writeln!(out, "    type: {}", p.kind);
+        if !p.description.is_empty() {
+            let _ = writeln!(out, "    description: {}", p.description);
+        }
+        if p.required {
+            out.push_str("    required: true\n");
+        }
+    }
+}
+
 fn write_slash_options(out: &mut String, opts: &[SkillSlashOption]) {
     if opts.is_empty() {
         return;
@@ -549,6 +639,7 @@ mod tests {
                 category: Some("coding".into()),
                 tags: vec!["slash".into(), "ops".into()],
                 slash_options: Vec::new(),
+                parameters: Vec::new(),
             },
             body: "# Code Review\n\nReviews diffs.\n".into(),
         };
@@ -702,4 +793,50 @@ mod tests {
         let parsed = SkillDocument::parse(&original.serialize()).unwrap();
         assert_eq!(parsed.frontmatter, original.frontmatter);
     }
+
+    #[test]
+    fn parses_parameters_alongside_slash_options() {
+        let content = "---\nname: deploy\ndescription: Ship a build.\nparameters:\n  \
+            - name: env\n    type: string\n    description: Target environment.\n    \
+            required: true\n  - name: replicas\n    type: integer\n  - type: boolean\n\
+            slash_options:\n  - name: q\n    type: string\n---\n# Deploy\n";
+        let fm = SkillDocument::parse(content).unwrap().frontmatter;
+        assert_eq!(fm.description, "Ship a build.");
+        assert_eq!(fm.parameters.len(), 2, "nameless items are dropped");
+        assert_eq!(fm.parameters[0].name, "env");
+        assert_eq!(fm.parameters[0].description, "Target environment.");
+        assert!(fm.parameters[0].required);
+        assert_eq!(fm.parameters[1].kind, "integer");
+        assert!(!fm.parameters[1].required);
+        assert_eq!(fm.slash_options.len(), 1);
+    }
+
+    #[test]
+    fn round_trips_parameters() {
+        let original = SkillDocument {
+            frontmatter: SkillFrontmatter {
+                name: "deploy".into(),
+                description: "Ship a build.".into(),
+                parameters: vec![
+                    SkillParameter {
+                        name: "env".into(),
+                        description: "Target environment.".into(),
+                        kind: "string".into(),
+                        required: true,
+                    },
+                    SkillParameter {
+                        name: "dry_run".into(),
+                        description: String::new(),
+                        kind: "boolean".into(),
+                        required: false,
+                    },
+                ],
+                ..Default::default()
+            },
+            body: "# Deploy\n\nDeploy to {{env}}.\n".into(),
+        };
+        let parsed = SkillDocument::parse(&original.serialize()).unwrap();
+        assert_eq!(parsed.frontmatter, original.frontmatter);
+        assert_eq!(parsed.body, original.body);
+    }
 }
@@ -3,7 +3,7 @@
 use serde::{Deserialize, Serialize};
 use zeroclaw_config::traits::{PropFieldInfo, PropKind};
 
-use super::SkillSlashOption;
+use super::{SkillParameter, SkillSlashOption};
 
 // `Eq` is intentionally NOT derived: `slash_options` carries `SkillSlashOption`,
 // whose `min`/`max` bounds are `f64` (no total ordering). `PartialEq` is all the
@@ -24,6 +24,8 @@ pub struct SkillFrontmatter {
     pub tags: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub slash_options: Vec<SkillSlashOption>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<SkillParameter>,
 }
 
 impl SkillFrontmatter {
@@ -124,14 +126,16 @@ mod tests {
             fields.len(),
             7,
             "SkillFrontmatter::prop_fields drifted from struct definition; \
-             update both when adding/removing FLAT fields (slash_options is \
-             nested and deliberately excluded)"
-        );
-        // slash_options must never sneak into the flat form.
-        assert!(
-            !fields.iter().any(|f| f.name == "slash_options"),
-            "slash_options is nested and must stay out of the flat prop_fields form"
+             update both when adding/removing FLAT fields (slash_options and \
+             parameters are nested and deliberately excluded)"
         );
+        // Nested fields must never sneak into the flat form.
+        for nested in ["slash_options", "parameters"] {
+            assert!(
+                !fields.iter().any(|f| f.name == nested),
+                "{nested} is nested and must stay out of the flat prop_fields form"
+            );
+        }
     }
 
     #[test]
@@ -671,6 +671,7 @@ mod tests {
             ChatMessage {
                 role: "tool".into(),
                 content: "Error: connection refused".into(),
+                reasoning: None,
             },
         ];
         let executions = extract_skill_executions_from_history(&history);
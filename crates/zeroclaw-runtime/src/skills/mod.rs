@@ -77,6 +77,11 @@ pub struct Skill {
     /// then fall back to a single free-text option. See [`SkillSlashOption`].
     #[serde(default)]
     pub slash_options: Vec<SkillSlashOption>,
+    /// Named arguments the skill accepts through the `skill_invoke` tool,
+    /// substituted into its instructions as `{{name}}`. Empty for static
+    /// skills. See [`SkillParameter`].
+    #[serde(default)]
+    pub parameters: Vec<SkillParameter>,
     #[serde(skip)]
     pub location: Option<PathBuf>,
 }
@@ -268,6 +273,28 @@ pub struct SkillSlashChoice {
     pub value: String,
 }
 
+/// A named argument a skill accepts when invoked through `skill_invoke`.
+/// Declared in SKILL.md frontmatter under `parameters:` or in SKILL.toml under
+/// `[[skill.parameters]]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SkillParameter {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// `string` | `integer` | `number` | `boolean`.
+    #[serde(rename = "type", default = "default_parameter_kind")]
+    pub kind: String,
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// Parameter types `skill_invoke` can validate.
+pub const SKILL_PARAMETER_KINDS: &[&str] = &["string", "integer", "number", "boolean"];
+
+fn default_parameter_kind() -> String {
+    "string".to_string()
+}
+
 impl ::zeroclaw_api::attribution::Attributable for Skill {
     fn role(&self) -> ::zeroclaw_api::attribution::Role {
         ::zeroclaw_api::attribution::Role::Skill
@@ -333,6 +360,8 @@ struct SkillMeta {
     prompts: Vec<String>,
     #[serde(default)]
     slash_options: Vec<SkillSlashOption>,
+    #[serde(default)]
+    parameters: Vec<SkillParameter>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -376,6 +405,8 @@ struct SkillMarkdownMeta {
     /// so a SKILL.md skill can drive native Discord slash commands — parity with
     /// SKILL.toml's `[[skill.slash_options]]`.
     slash_options: Vec<SkillSlashOption>,
+    /// Invocation parameters from the nested `parameters:` block.
+    parameters: Vec<SkillParameter>,
 }
 
 fn default_version() -> String {
@@ -588,6 +619,35 @@ pub fn load_skills_for_agent(
     load_skills_for_agent_audited(workspace_dir, config, agent_alias).0
 }
 
+/// Directories [`load_skills_for_agent`] reads, for callers that fingerprint
+/// skill state without loading it. Never clones or syncs the open-skills repo.
+/// Plugin skills are not directory-backed and are not included.
+pub fn skill_source_dirs(
+    workspace_dir: &Path,
+    config: &zeroclaw_config::schema::Config,
+    agent_alias: &str,
+) -> Vec<PathBuf> {
+    let mut dirs = vec![skills_dir(workspace_dir)];
+    if open_skills_enabled(Some(config.skills.open_skills_enabled))
+        && let Some(dir) = resolve_open_skills_dir(config.skills.open_skills_dir.as_deref())
+    {
+        dirs.push(dir);
+    }
+    if let Some(agent) = config.agent(agent_alias) {
+        let install_root = config.install_root_dir();
+        for bundle_alias in &agent.skill_bundles {
+            if let Ok(dir) = zeroclaw_config::skill_bundles::resolve_directory(
+                config,
+                &install_root,
+                bundle_alias,
+            ) {
+                dirs.push(dir);
+            }
+        }
+    }
+    dirs
+}
+
 fn origin_hint_of(skill: &Skill) -> &'static str {
     if skill.tags.iter().any(|t| t == "open-skills") {
         "open-skills"
@@ -1321,6 +1381,7 @@ fn load_skill_toml(path: &Path) -> Result<Skill> {
         tools: manifest.tools,
         prompts,
         slash_options: manifest.skill.slash_options,
+        parameters: manifest.skill.parameters,
         location: Some(path.to_path_buf()),
     })
 }
@@ -1350,6 +1411,7 @@ fn load_skill_md(path: &Path, dir: &Path) -> Result<Skill> {
         tools: Vec::new(),
         prompts: vec![parsed.body],
         slash_options: parsed.meta.slash_options,
+        parameters: parsed.meta.parameters,
         location: Some(path.to_path_buf()),
     })
 }
@@ -1392,6 +1454,7 @@ fn load_open_skill_md(path: &Path) -> Result<Skill> {
         tools: Vec::new(),
         prompts: vec![parsed.body],
         slash_options: parsed.meta.slash_options,
+        parameters: parsed.meta.parameters,
         location: Some(path.to_path_buf()),
     }))
 }
@@ -1434,7 +1497,14 @@ fn parse_simple_frontmatter(s: &str) -> SkillMarkdownMeta {
         }
     };
 
-    for line in s.lines() {
+    // Nested `slash_options:` / `parameters:` blocks are parsed separately
+    // below; their indented `description:` lines must not reach this scan.
+    let nested = document::nested_block_ranges(s);
+
+    for (idx, line) in s.lines().enumerate() {
+        if document::in_nested_block(&nested, idx) {
+            continue;
+        }
         // Collect indented continuation lines for YAML block scalars (>- or |)
         if let Some(ref key) = collecting_multiline {
             // A blank/whitespace-only line is a paragraph break *inside* the
@@ -1498,10 +1568,11 @@ fn parse_simple_frontmatter(s: &str) -> SkillMarkdownMeta {
     if let Some(ref key) = collecting_multiline {
         flush_multiline(key, &multiline_parts, &mut meta);
     }
-    // The one nested field. Parsed by the shared helper so the loader and the
-    // service (`SkillDocument`) read `slash_options` identically — no second
-    // nested parser to drift.
+    // The nested fields. Parsed by the shared helpers so the loader and the
+    // service (`SkillDocument`) read them identically — no second nested
+    // parser to drift.
     meta.slash_options = document::parse_slash_options(s);
+    meta.parameters = document::parse_parameters(s);
     meta
 }
 
@@ -1648,6 +1719,31 @@ pub fn skills_to_prompt_with_mode(
         );
         write_xml_text_element(&mut prompt, 4, "location", &location);
 
+        // Parameters are listed in both modes: they are how the model calls a
+        // parameterized skill, whether or not its instructions are inlined.
+        if !skill.parameters.is_empty() {
+            let _ = writeln!(
+                prompt,
+                "    <parameters hint=\"Run this skill with skill_invoke(skill, args); each {{{{name}}}} in its instructions is replaced by the matching argument.\">"
+            );
+            for param in &skill.parameters {
+                let _ = writeln!(prompt, "      <parameter>");
+                write_xml_text_element(&mut prompt, 8, "name", &param.name);
+                write_xml_text_element(&mut prompt, 8, "type", &param.kind);
+                write_xml_text_element(
+                    &mut prompt,
+                    8,
+                    "required",
+                    if param.required { "true" } else { "false" },
+                );
+                if !param.description.is_empty() {
+                    write_xml_text_element(&mut prompt, 8, "description", &param.description);
+                }
+                let _ = writeln!(prompt, "      </parameter>");
+            }
+            let _ = writeln!(prompt, "    </parameters>");
+        }
+
         // In Full mode, inline both instructions and tools.
         // In Compact mode, skip instructions (loaded on demand) but keep tools
         // so the LLM knows which skill tools are available.
@@ -3669,6 +3765,106 @@ Write it.
         assert!(skill.slash_options.is_empty());
     }
 
+    #[test]
+    fn load_skill_md_parses_parameters_from_frontmatter() {
+        let tmp = TempDir::new().unwrap();
+        let md = r#"---
+name: deploy
+description: Deploy the service.
+parameters:
+  - name: env
+    type: string
+    description: Target environment.
+    required: true
+  - name: replicas
+    type: integer
+---
+# Deploy
+
+Deploy to {{env}} with {{replicas}} replicas.
+"#;
+        let path = tmp.path().join("SKILL.md");
+        std::fs::write(&path, md).unwrap();
+        let skill = load_skill_md(&path, tmp.path()).unwrap();
+
+        assert_eq!(skill.description, "Deploy the service.");
+        assert_eq!(skill.parameters.len(), 2);
+        assert_eq!(skill.parameters[0].name, "env");
+        assert_eq!(skill.parameters[0].description, "Target environment.");
+        assert!(skill.parameters[0].required);
+        assert_eq!(skill.parameters[1].kind, "integer");
+        assert!(!skill.prompts[0].contains("parameters:"));
+    }
+
+    #[test]
+    fn parameters_are_parsed_from_the_skill_table() {
+        let tmp = TempDir::new().unwrap();
+        let path = write_manifest(
+            tmp.path(),
+            r#"
+[skill]
+name = "deploy"
+description = "Deploy the service"
+
+[[skill.parameters]]
+name = "env"
+required = true
+
+[[skill.parameters]]
+name = "dry_run"
+type = "boolean"
+"#,
+        );
+        let skill = load_skill_toml(&path).unwrap();
+        assert_eq!(skill.parameters.len(), 2);
+        assert_eq!(skill.parameters[0].kind, "string");
+        assert!(skill.parameters[0].required);
+        assert_eq!(skill.parameters[1].kind, "boolean");
+    }
+
+    #[test]
+    fn prompt_lists_skill_parameters_in_both_modes() {
+        let skill = Skill {
+            name: "deploy".to_string(),
+            description: "Deploy the service".to_string(),
+            description_localizations: Default::default(),
+            version: "0.1.0".to_string(),
+            author: None,
+            tags: Vec::new(),
+            tools: Vec::new(),
+            prompts: vec!["Deploy to {{env}}.".to_string()],
+            slash_options: Vec::new(),
+            parameters: vec![SkillParameter {
+                name: "env".to_string(),
+                description: "Target <environment>".to_string(),
+                kind: "string".to_string(),
+                required: true,
+            }],
+            location: None,
+        };
+        for mode in [
+            zeroclaw_config::schema::SkillsPromptInjectionMode::Full,
+            zeroclaw_config::schema::SkillsPromptInjectionMode::Compact,
+        ] {
+            let prompt = skills_to_prompt_with_mode(
+                std::slice::from_ref(&skill),
+                Path::new("/tmp/ws"),
+                mode,
+            );
+            assert!(prompt.contains("skill_invoke(skill, args)"));
+            assert!(prompt.contains("<name>env</name>"));
+            assert!(prompt.contains("<type>string</type>"));
+            assert!(prompt.contains("<required>true</required>"));
+            assert!(prompt.contains("<description>Target &lt;environment&gt;</description>"));
+        }
+
+        let plain = Skill {
+            parameters: Vec::new(),
+            ..skill
+        };
+        assert!(!skills_to_prompt(&[plain], Path::new("/tmp/ws")).contains("<parameters"));
+    }
+
     #[test]
     fn prompts_at_root_level_still_work() {
         let tmp = TempDir::new().unwrap();
@@ -4081,6 +4277,7 @@ mod prompt_callable_name_tests {
             tools: vec![tool("run.lint", "shell")],
             prompts: Vec::new(),
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         };
 
@@ -4158,6 +4355,7 @@ mod prompt_callable_name_tests {
             ],
             prompts: Vec::new(),
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         };
 
@@ -4197,6 +4395,7 @@ mod prompt_callable_name_tests {
             ],
             prompts: Vec::new(),
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         };
 
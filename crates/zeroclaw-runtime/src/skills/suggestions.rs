@@ -416,6 +416,7 @@ mod tests {
             tools: vec![],
             prompts: vec![],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         }
     }
@@ -0,0 +1,100 @@
+//! Background task queue for long-running agent work.
+//!
+//! The `task_spawn` tool enqueues a prompt in `<data_dir>/tasks/tasks.db`.
+//! The daemon's `tasks` worker ([`worker::run`]) runs queued tasks outside
+//! the channel `message_timeout_secs` budget, capped by
+//! `[tasks] max_concurrent`, and pushes start, progress and completion
+//! messages back to the channel and reply target the task was spawned from.
+
+mod store;
+pub mod worker;
+
+pub use store::{
+    claim_next_queued, enqueue_task, finish_task, get_task, is_cancel_requested, list_tasks,
+    recover_running_tasks, request_cancel,
+};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Lifecycle state of a background task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+    /// The daemon stopped while the task was running and
+    /// `[tasks] resume_interrupted` is off.
+    Interrupted,
+}
+
+impl TaskStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+            Self::Interrupted => "interrupted",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        Some(match raw {
+            "queued" => Self::Queued,
+            "running" => Self::Running,
+            "succeeded" => Self::Succeeded,
+            "failed" => Self::Failed,
+            "cancelled" => Self::Cancelled,
+            "interrupted" => Self::Interrupted,
+            _ => return None,
+        })
+    }
+
+    pub fn is_finished(self) -> bool {
+        !matches!(self, Self::Queued | Self::Running)
+    }
+}
+
+impl std::fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A persisted background task.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackgroundTask {
+    pub id: String,
+    /// Agent the task runs as.
+    pub agent_alias: String,
+    /// Short human-readable summary used in progress messages.
+    pub description: String,
+    pub prompt: String,
+    pub status: TaskStatus,
+    /// Channel progress and completion messages go to (e.g. `telegram`).
+    pub channel: Option<String>,
+    pub reply_target: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub cancel_requested: bool,
+    /// Final agent response or failure reason, truncated to 16 KiB.
+    pub output: Option<String>,
+    /// Times the worker has started this task (more than 1 after a resume).
+    pub attempts: u32,
+}
+
+/// Input for [`enqueue_task`].
+#[derive(Debug, Clone)]
+pub struct NewTask {
+    pub agent_alias: String,
+    pub description: String,
+    pub prompt: String,
+    pub channel: Option<String>,
+    pub reply_target: Option<String>,
+}
@@ -0,0 +1,411 @@
+use super::{BackgroundTask, NewTask, TaskStatus};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, OptionalExtension, params};
+use uuid::Uuid;
+use zeroclaw_config::schema::Config;
+
+const MAX_TASK_OUTPUT_BYTES: usize = 16 * 1024;
+const TRUNCATED_OUTPUT_MARKER: &str = "\n...[truncated]";
+
+const TASK_COLUMNS: &str = "id, agent_alias, description, prompt, status, channel, reply_target, \
+     created_at, started_at, finished_at, cancel_requested, output, attempts";
+
+pub fn enqueue_task(config: &Config, task: NewTask) -> Result<BackgroundTask> {
+    let id = Uuid::new_v4().to_string();
+    let now = Utc::now();
+    with_connection(config, |conn| {
+        conn.execute(
+            "INSERT INTO tasks (id, agent_alias, description, prompt, status, channel,
+                                reply_target, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                id,
+                task.agent_alias,
+                task.description,
+                task.prompt,
+                TaskStatus::Queued.as_str(),
+                task.channel,
+                task.reply_target,
+                now.to_rfc3339(),
+            ],
+        )
+        .context("Failed to insert background task")?;
+        Ok(())
+    })?;
+    get_task(config, &id)
+}
+
+pub fn get_task(config: &Config, id: &str) -> Result<BackgroundTask> {
+    with_connection(config, |conn| {
+        conn.query_row(
+            &format!("SELECT {TASK_COLUMNS} FROM tasks WHERE id = ?1"),
+            params![id],
+            map_task_row,
+        )
+        .optional()
+        .context("Failed to query background task")?
+        .with_context(|| format!("Background task '{id}' not found"))
+    })
+}
+
+/// Most recent tasks first, optionally filtered to one agent.
+pub fn list_tasks(
+    config: &Config,
+    agent_alias: Option<&str>,
+    limit: usize,
+) -> Result<Vec<BackgroundTask>> {
+    let limit = i64::try_from(limit.max(1)).unwrap_or(i64::MAX);
+    with_connection(config, |conn| {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {TASK_COLUMNS} FROM tasks
+             WHERE ?1 IS NULL OR agent_alias = ?1
+             ORDER BY created_at DESC, rowid DESC
+             LIMIT ?2"
+        ))?;
+        let rows = stmt.query_map(params![agent_alias, limit], map_task_row)?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to list background tasks")
+    })
+}
+
+/// Cancel a task. Queued tasks are cancelled immediately; running tasks are
+/// flagged and stopped by the worker on its next check.
+pub fn request_cancel(config: &Config, id: &str) -> Result<BackgroundTask> {
+    let task = get_task(config, id)?;
+    match task.status {
+        TaskStatus::Queued => {
+            with_connection(config, |conn| {
+                conn.execute(
+                    "UPDATE tasks SET status = ?1, finished_at = ?2, cancel_requested = 1
+                     WHERE id = ?3 AND status = ?4",
+                    params![
+                        TaskStatus::Cancelled.as_str(),
+                        Utc::now().to_rfc3339(),
+                        id,
+                        TaskStatus::Queued.as_str(),
+                    ],
+                )
+                .context("Failed to cancel background task")?;
+                Ok(())
+            })?;
+        }
+        TaskStatus::Running => {
+            with_connection(config, |conn| {
+                conn.execute(
+                    "UPDATE tasks SET cancel_requested = 1 WHERE id = ?1",
+                    params![id],
+                )
+                .context("Failed to flag background task for cancellation")?;
+                Ok(())
+            })?;
+        }
+        status => anyhow::bail!("Background task '{id}' already finished ({status})"),
+    }
+    get_task(config, id)
+}
+
+pub fn is_cancel_requested(config: &Config, id: &str) -> Result<bool> {
+    with_connection(config, |conn| {
+        conn.query_row(
+            "SELECT cancel_requested FROM tasks WHERE id = ?1",
+            params![id],
+            |row| row.get::<_, i64>(0),
+        )
+        .optional()
+        .context("Failed to read background task cancel flag")
+        .map(|flag| flag.unwrap_or(1) != 0)
+    })
+}
+
+/// Move the oldest queued task to `running` and return it.
+pub fn claim_next_queued(config: &Config) -> Result<Option<BackgroundTask>> {
+    let claimed = with_connection(config, |conn| {
+        let Some(id) = conn
+            .query_row(
+                "SELECT id FROM tasks WHERE status = ?1 ORDER BY created_at ASC, rowid ASC LIMIT 1",
+                params![TaskStatus::Queued.as_str()],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .context("Failed to query queued background tasks")?
+        else {
+            return Ok(None);
+        };
+        let updated = conn
+            .execute(
+                "UPDATE tasks SET status = ?1, started_at = ?2, attempts = attempts + 1
+                 WHERE id = ?3 AND status = ?4",
+                params![
+                    TaskStatus::Running.as_str(),
+                    Utc::now().to_rfc3339(),
+                    id,
+                    TaskStatus::Queued.as_str(),
+                ],
+            )
+            .context("Failed to claim background task")?;
+        Ok((updated == 1).then_some(id))
+    })?;
+    claimed.map(|id| get_task(config, &id)).transpose()
+}
+
+pub fn finish_task(config: &Config, id: &str, status: TaskStatus, output: &str) -> Result<()> {
+    with_connection(config, |conn| {
+        conn.execute(
+            "UPDATE tasks SET status = ?1, finished_at = ?2, output = ?3 WHERE id = ?4",
+            params![
+                status.as_str(),
+                Utc::now().to_rfc3339(),
+                truncate_task_output(output),
+                id,
+            ],
+        )
+        .context("Failed to record background task result")?;
+        Ok(())
+    })
+}
+
+/// Tasks still marked `running` at startup were cut off by the previous
+/// daemon. Re-queue them when `resume` is set, otherwise mark them
+/// `interrupted`. Returns the affected tasks as they were before the update.
+pub fn recover_running_tasks(config: &Config, resume: bool) -> Result<Vec<BackgroundTask>> {
+    let running: Vec<BackgroundTask> = list_all_with_status(config, TaskStatus::Running)?;
+    if running.is_empty() {
+        return Ok(running);
+    }
+    with_connection(config, |conn| {
+        // A cancel that arrived while the daemon was down wins over resume.
+        conn.execute(
+            "UPDATE tasks SET status = ?1, finished_at = ?2
+             WHERE status = ?3 AND cancel_requested = 1",
+            params![
+                TaskStatus::Cancelled.as_str(),
+                Utc::now().to_rfc3339(),
+                TaskStatus::Running.as_str(),
+            ],
+        )
+        .context("Failed to cancel flagged background tasks")?;
+        if resume {
+            conn.execute(
+                "UPDATE tasks SET status = ?1, started_at = NULL WHERE status = ?2",
+                params![TaskStatus::Queued.as_str(), TaskStatus::Running.as_str()],
+            )
+        } else {
+            conn.execute(
+                "UPDATE tasks SET status = ?1, finished_at = ?2 WHERE status = ?3",
+                params![
+                    TaskStatus::Interrupted.as_str(),
+                    Utc::now().to_rfc3339(),
+                    TaskStatus::Running.as_str(),
+                ],
+            )
+        }
+        .context("Failed to recover running background tasks")?;
+        Ok(())
+    })?;
+    Ok(running)
+}
+
+fn list_all_with_status(config: &Config, status: TaskStatus) -> Result<Vec<BackgroundTask>> {
+    with_connection(config, |conn| {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {TASK_COLUMNS} FROM tasks WHERE status = ?1 ORDER BY created_at ASC"
+        ))?;
+        let rows = stmt.query_map(params![status.as_str()], map_task_row)?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to list background tasks by status")
+    })
+}
+
+fn truncate_task_output(output: &str) -> String {
+    if output.len() <= MAX_TASK_OUTPUT_BYTES {
+        return output.to_string();
+    }
+    let mut end = MAX_TASK_OUTPUT_BYTES - TRUNCATED_OUTPUT_MARKER.len();
+    while !output.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{TRUNCATED_OUTPUT_MARKER}", &output[..end])
+}
+
+fn parse_timestamp(raw: Option<String>) -> rusqlite::Result<Option<DateTime<Utc>>> {
+    raw.map(|raw| {
+        DateTime::parse_from_rfc3339(&raw)
+            .map(|ts| ts.with_timezone(&Utc))
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))
+    })
+    .transpose()
+}
+
+fn map_task_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<BackgroundTask> {
+    let status_raw: String = row.get(4)?;
+    let status = TaskStatus::parse(&status_raw).ok_or_else(|| {
+        rusqlite::Error::ToSqlConversionFailure(
+            format!("unknown background task status {status_raw:?}").into(),
+        )
+    })?;
+    Ok(BackgroundTask {
+        id: row.get(0)?,
+        agent_alias: row.get(1)?,
+        description: row.get(2)?,
+        prompt: row.get(3)?,
+        status,
+        channel: row.get(5)?,
+        reply_target: row.get(6)?,
+        created_at: parse_timestamp(Some(row.get::<_, String>(7)?))?.unwrap_or_else(Utc::now),
+        started_at: parse_timestamp(row.get(8)?)?,
+        finished_at: parse_timestamp(row.get(9)?)?,
+        cancel_requested: row.get::<_, i64>(10)? != 0,
+        output: row.get(11)?,
+        attempts: row.get(12)?,
+    })
+}
+
+fn tasks_db_path(config: &Config) -> std::path::PathBuf {
+    config.data_dir.join("tasks").join("tasks.db")
+}
+
+fn with_connection<T>(config: &Config, f: impl FnOnce(&Connection) -> Result<T>) -> Result<T> {
+    let db_path = tasks_db_path(config);
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create tasks directory: {}", parent.display()))?;
+    }
+    let conn = Connection::open(&db_path)
+        .with_context(|| format!("Failed to open tasks DB: {}", db_path.display()))?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))?;
+    initialize_schema(&conn)?;
+    f(&conn)
+}
+
+fn initialize_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS tasks (
+            id               TEXT PRIMARY KEY,
+            agent_alias      TEXT NOT NULL,
+            description      TEXT NOT NULL,
+            prompt           TEXT NOT NULL,
+            status           TEXT NOT NULL,
+            channel          TEXT,
+            reply_target     TEXT,
+            created_at       TEXT NOT NULL,
+            started_at       TEXT,
+            finished_at      TEXT,
+            cancel_requested INTEGER NOT NULL DEFAULT 0,
+            output           TEXT,
+            attempts         INTEGER NOT NULL DEFAULT 0
+        );
+        CREATE INDEX IF NOT EXISTS idx_tasks_status ON tasks(status, created_at);
+        CREATE INDEX IF NOT EXISTS idx_tasks_agent ON tasks(agent_alias, created_at);",
+    )
+    .context("Failed to initialize tasks schema")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_config(tmp: &TempDir) -> Config {
+        Config {
+            data_dir: tmp.path().join("data"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        }
+    }
+
+    fn new_task(agent: &str, description: &str) -> NewTask {
+        NewTask {
+            agent_alias: agent.into(),
+            description: description.into(),
+            prompt: format!("do {description}"),
+            channel: Some("telegram".into()),
+            reply_target: Some("12345".into()),
+        }
+    }
+
+    #[test]
+    fn tasks_are_claimed_in_fifo_order() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let first = enqueue_task(&config, new_task("alpha", "first")).unwrap();
+        let second = enqueue_task(&config, new_task("alpha", "second")).unwrap();
+        assert_eq!(first.status, TaskStatus::Queued);
+
+        let claimed = claim_next_queued(&config).unwrap().unwrap();
+        assert_eq!(claimed.id, first.id);
+        assert_eq!(claimed.status, TaskStatus::Running);
+        assert_eq!(claimed.attempts, 1);
+        assert!(claimed.started_at.is_some());
+
+        let claimed = claim_next_queued(&config).unwrap().unwrap();
+        assert_eq!(claimed.id, second.id);
+        assert!(claim_next_queued(&config).unwrap().is_none());
+    }
+
+    #[test]
+    fn cancel_stops_queued_task_and_flags_running_task() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let running = enqueue_task(&config, new_task("alpha", "running")).unwrap();
+        let queued = enqueue_task(&config, new_task("alpha", "queued")).unwrap();
+        claim_next_queued(&config).unwrap();
+
+        let cancelled = request_cancel(&config, &queued.id).unwrap();
+        assert_eq!(cancelled.status, TaskStatus::Cancelled);
+        assert!(cancelled.finished_at.is_some());
+
+        let flagged = request_cancel(&config, &running.id).unwrap();
+        assert_eq!(flagged.status, TaskStatus::Running);
+        assert!(is_cancel_requested(&config, &running.id).unwrap());
+
+        let err = request_cancel(&config, &queued.id).unwrap_err();
+        assert!(err.to_string().contains("already finished"));
+    }
+
+    #[test]
+    fn recovery_marks_running_tasks_interrupted_or_requeues_them() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let task = enqueue_task(&config, new_task("alpha", "long")).unwrap();
+        claim_next_queued(&config).unwrap();
+
+        let recovered = recover_running_tasks(&config, true).unwrap();
+        assert_eq!(recovered.len(), 1);
+        assert_eq!(
+            get_task(&config, &task.id).unwrap().status,
+            TaskStatus::Queued
+        );
+
+        claim_next_queued(&config).unwrap();
+        recover_running_tasks(&config, false).unwrap();
+        let task = get_task(&config, &task.id).unwrap();
+        assert_eq!(task.status, TaskStatus::Interrupted);
+        assert_eq!(task.attempts, 2);
+    }
+
+    #[test]
+    fn list_filters_by_agent_and_truncates_output() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let task = enqueue_task(&config, new_task("alpha", "a")).unwrap();
+        enqueue_task(&config, new_task("beta", "b")).unwrap();
+
+        assert_eq!(list_tasks(&config, None, 10).unwrap().len(), 2);
+        let alpha = list_tasks(&config, Some("alpha"), 10).unwrap();
+        assert_eq!(alpha.len(), 1);
+        assert_eq!(alpha[0].id, task.id);
+
+        finish_task(
+            &config,
+            &task.id,
+            TaskStatus::Succeeded,
+            &"x".repeat(20_000),
+        )
+        .unwrap();
+        let task = get_task(&config, &task.id).unwrap();
+        let output = task.output.unwrap();
+        assert!(output.len() <= MAX_TASK_OUTPUT_BYTES);
+        assert!(output.ends_with(TRUNCATED_OUTPUT_MARKER));
+    }
+}
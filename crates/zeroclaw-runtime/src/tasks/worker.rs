@@ -0,0 +1,400 @@
+//! Daemon worker that drains the background task queue.
+
+use super::{
+    BackgroundTask, TaskStatus, claim_next_queued, finish_task, is_cancel_requested,
+    recover_running_tasks,
+};
+use crate::security::SecurityPolicy;
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tokio::time::{self, Duration, Instant};
+use tokio_util::sync::CancellationToken;
+use zeroclaw_config::schema::Config;
+use zeroclaw_log::Instrument;
+
+pub const TASKS_COMPONENT: &str = "tasks";
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+/// Tools a background task may not call: it cannot fan out into more
+/// background work or mutate the scheduler.
+const TASK_DEFAULT_EXCLUDED_TOOLS: &[&str] = &[
+    "task_spawn",
+    "task_cancel",
+    "cron_add",
+    "cron_update",
+    "cron_remove",
+    "cron_run",
+    "schedule",
+];
+
+/// Run the worker until `cancel` fires.
+///
+/// Tasks left `running` by a previous daemon are requeued or marked
+/// `interrupted` first, depending on `[tasks] resume_interrupted`. Tasks
+/// still running at shutdown are aborted and recovered on the next start.
+pub async fn run(config: Config, cancel: CancellationToken) -> Result<()> {
+    crate::health::mark_component_ok(TASKS_COMPONENT);
+    let config = Arc::new(config);
+    let resume = config.tasks.resume_interrupted;
+
+    match recover_running_tasks(&config, resume) {
+        Ok(recovered) => {
+            for task in &recovered {
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Resolve)
+                        .with_attrs(::serde_json::json!({
+                            "task_id": task.id,
+                            "status": task.status.as_str(),
+                        })),
+                    "Recovered background task left running by a previous daemon"
+                );
+                if task.status != TaskStatus::Queued {
+                    notify(&config, task, &finished_message(task, task.status, "")).await;
+                }
+            }
+        }
+        Err(e) => {
+            crate::health::mark_component_error(TASKS_COMPONENT, format!("{e:#}"));
+            ::zeroclaw_log::record!(
+                WARN,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Resolve)
+                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                    .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                "Failed to recover interrupted background tasks"
+            );
+        }
+    }
+
+    let max_concurrent = config.tasks.max_concurrent.max(1);
+    let mut running: JoinSet<()> = JoinSet::new();
+    let mut interval = time::interval(POLL_INTERVAL);
+    interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+
+    loop {
+        tokio::select! {
+            () = cancel.cancelled() => {
+                running.abort_all();
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Cancel)
+                        .with_attrs(::serde_json::json!({"in_flight": running.len()})),
+                    "Background task worker stopping"
+                );
+                return Ok(());
+            }
+            _ = interval.tick() => {
+                while running.try_join_next().is_some() {}
+                crate::health::mark_component_ok(TASKS_COMPONENT);
+
+                while running.len() < max_concurrent {
+                    match claim_next_queued(&config) {
+                        Ok(Some(task)) => {
+                            let config = Arc::clone(&config);
+                            running.spawn(async move { run_task(&config, task).await });
+                        }
+                        Ok(None) => break,
+                        Err(e) => {
+                            crate::health::mark_component_error(
+                                TASKS_COMPONENT,
+                                format!("{e:#}"),
+                            );
+                            ::zeroclaw_log::record!(
+                                WARN,
+                                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Query)
+                                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                                    .with_attrs(::serde_json::json!({"error": format!("{e:#}")})),
+                                "Failed to claim queued background task"
+                            );
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn run_task(config: &Config, task: BackgroundTask) {
+    ::zeroclaw_log::record!(
+        INFO,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Start).with_attrs(
+            ::serde_json::json!({
+                "task_id": task.id,
+                "agent": task.agent_alias,
+                "attempt": task.attempts,
+            })
+        ),
+        "Background task started"
+    );
+    notify(config, &task, &started_message(&task)).await;
+
+    let started = Instant::now();
+    let agent_run = run_task_agent(config, &task);
+    tokio::pin!(agent_run);
+
+    let mut progress = (config.tasks.progress_interval_secs > 0).then(|| {
+        let period = Duration::from_secs(config.tasks.progress_interval_secs);
+        time::interval_at(Instant::now() + period, period)
+    });
+    let mut cancel_check = time::interval_at(
+        Instant::now() + CANCEL_CHECK_INTERVAL,
+        CANCEL_CHECK_INTERVAL,
+    );
+    let timeout_secs = config.tasks.timeout_secs;
+    let deadline = async {
+        if timeout_secs == 0 {
+            std::future::pending::<()>().await;
+        }
+        time::sleep(Duration::from_secs(timeout_secs)).await;
+    };
+    tokio::pin!(deadline);
+
+    let (status, output) = loop {
+        tokio::select! {
+            result = &mut agent_run => {
+                break match result {
+                    Ok(output) => (TaskStatus::Succeeded, output),
+                    Err(reason) => (TaskStatus::Failed, reason),
+                };
+            }
+            () = &mut deadline => {
+                break (TaskStatus::Failed, format!("timed out after {timeout_secs}s"));
+            }
+            _ = cancel_check.tick() => {
+                if is_cancel_requested(config, &task.id).unwrap_or(false) {
+                    break (TaskStatus::Cancelled, "cancelled on request".to_string());
+                }
+            }
+            () = tick_optional(progress.as_mut()) => {
+                notify(config, &task, &progress_message(&task, started.elapsed())).await;
+            }
+        }
+    };
+
+    if let Err(e) = finish_task(config, &task.id, status, &output) {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Write)
+                .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                .with_attrs(::serde_json::json!({"task_id": task.id, "error": format!("{e:#}")})),
+            "Failed to persist background task result"
+        );
+    }
+
+    let (action, outcome) = match status {
+        TaskStatus::Succeeded => (
+            ::zeroclaw_log::Action::Complete,
+            ::zeroclaw_log::EventOutcome::Success,
+        ),
+        TaskStatus::Cancelled => (
+            ::zeroclaw_log::Action::Cancel,
+            ::zeroclaw_log::EventOutcome::Unknown,
+        ),
+        _ => (
+            ::zeroclaw_log::Action::Fail,
+            ::zeroclaw_log::EventOutcome::Failure,
+        ),
+    };
+    ::zeroclaw_log::record!(
+        INFO,
+        ::zeroclaw_log::Event::new(module_path!(), action)
+            .with_outcome(outcome)
+            .with_attrs(::serde_json::json!({
+                "task_id": task.id,
+                "status": status.as_str(),
+                "elapsed_secs": started.elapsed().as_secs(),
+            })),
+        "Background task finished"
+    );
+    notify(config, &task, &finished_message(&task, status, &output)).await;
+}
+
+async fn tick_optional(interval: Option<&mut time::Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Run the task prompt through the owning agent. Returns the agent's final
+/// response, or a failure reason.
+async fn run_task_agent(config: &Config, task: &BackgroundTask) -> Result<String, String> {
+    let alias = task.agent_alias.as_str();
+    let subagent_ctx = crate::subagent::SubAgentSpawn::for_agent(config, alias)
+        .and_then(|spawn| spawn.build(crate::subagent::SubAgentOverrides::default()))
+        .map_err(|e| format!("subagent spawn failed: {e:#}"))?;
+
+    let security = SecurityPolicy::for_agent(config, alias)
+        .map_err(|e| format!("security policy unavailable: {e:#}"))?;
+    if !security.can_act() {
+        return Err("blocked by security policy: autonomy is read-only".to_string());
+    }
+    if security.is_rate_limited() {
+        return Err("blocked by security policy: rate limit exceeded".to_string());
+    }
+    if !security.record_action() {
+        return Err("blocked by security policy: action budget exhausted".to_string());
+    }
+
+    let mut policy = subagent_ctx.policy.as_ref().clone();
+    let excluded = policy.excluded_tools.get_or_insert_with(Vec::new);
+    for tool in TASK_DEFAULT_EXCLUDED_TOOLS {
+        if !excluded.iter().any(|existing| existing == tool) {
+            excluded.push((*tool).to_string());
+        }
+    }
+
+    let mut run_config = config.clone();
+    run_config.memory.auto_save = false;
+    let prompt = format!("[task:{} {}] {}", task.id, task.description, task.prompt);
+    let span = zeroclaw_log::info_span!(
+        "subagent",
+        category = "tasks",
+        agent_alias = %alias,
+        task_id = %task.id,
+        spawn_site = "task_spawn",
+    );
+    let run_overrides = crate::agent::loop_::AgentRunOverrides {
+        security: Some(Arc::new(policy)),
+        memory: None,
+        is_subagent: false,
+        suppress_memory_inject: false,
+        memory_free: false,
+        mcp_registry: None,
+        cli_session: None,
+    };
+
+    let response = Box::pin(
+        crate::agent::run(
+            run_config,
+            alias,
+            Some(prompt),
+            None,
+            None,
+            config
+                .model_provider_for_agent(alias)
+                .and_then(|e| e.temperature),
+            vec![],
+            false,
+            Some(std::path::PathBuf::from(format!("task-{}", task.id))),
+            None,
+            zeroclaw_api::ingress::TurnOrigin::Daemon,
+            run_overrides,
+        )
+        .instrument(span),
+    )
+    .await
+    .map_err(|e| format!("agent run failed: {e:#}"))?;
+
+    Ok(if response.trim().is_empty() {
+        "task completed with no output".to_string()
+    } else {
+        response
+    })
+}
+
+/// Push `message` to the channel the task was spawned from, if any.
+async fn notify(config: &Config, task: &BackgroundTask, message: &str) {
+    let (Some(channel), Some(target)) = (task.channel.as_deref(), task.reply_target.as_deref())
+    else {
+        return;
+    };
+    if let Err(e) =
+        crate::cron::scheduler::deliver_announcement(config, channel, target, None, message).await
+    {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Send)
+                .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                .with_attrs(::serde_json::json!({
+                    "task_id": task.id,
+                    "channel": channel,
+                    "error": format!("{e:#}"),
+                })),
+            "Failed to deliver background task update"
+        );
+    }
+}
+
+fn started_message(task: &BackgroundTask) -> String {
+    format!(
+        "Background task '{}' started (id {}).",
+        task.description, task.id
+    )
+}
+
+fn progress_message(task: &BackgroundTask, elapsed: Duration) -> String {
+    format!(
+        "Background task '{}' is still running ({} min elapsed).",
+        task.description,
+        elapsed.as_secs() / 60
+    )
+}
+
+fn finished_message(task: &BackgroundTask, status: TaskStatus, output: &str) -> String {
+    let name = &task.description;
+    match status {
+        TaskStatus::Succeeded => format!("Background task '{name}' finished.\n\n{output}"),
+        TaskStatus::Failed => format!("Background task '{name}' failed: {output}"),
+        TaskStatus::Cancelled => format!("Background task '{name}' was cancelled."),
+        TaskStatus::Interrupted => format!(
+            "Background task '{name}' was interrupted by a daemon restart. Spawn it again to retry."
+        ),
+        TaskStatus::Queued | TaskStatus::Running => {
+            format!("Background task '{name}' is {status}.")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn task() -> BackgroundTask {
+        BackgroundTask {
+            id: "abc".into(),
+            agent_alias: "default".into(),
+            description: "crawl docs".into(),
+            prompt: "crawl the docs site".into(),
+            status: TaskStatus::Running,
+            channel: Some("telegram".into()),
+            reply_target: Some("42".into()),
+            created_at: Utc::now(),
+            started_at: Some(Utc::now()),
+            finished_at: None,
+            cancel_requested: false,
+            output: None,
+            attempts: 1,
+        }
+    }
+
+    #[test]
+    fn finished_message_includes_output_on_success() {
+        let msg = finished_message(&task(), TaskStatus::Succeeded, "all done");
+        assert!(msg.starts_with("Background task 'crawl docs' finished."));
+        assert!(msg.ends_with("all done"));
+    }
+
+    #[test]
+    fn finished_message_explains_interruption() {
+        let msg = finished_message(&task(), TaskStatus::Interrupted, "");
+        assert!(msg.contains("interrupted by a daemon restart"));
+    }
+
+    #[test]
+    fn progress_message_reports_elapsed_minutes() {
+        let msg = progress_message(&task(), Duration::from_secs(600));
+        assert!(msg.contains("10 min elapsed"));
+    }
+
+    #[tokio::test]
+    async fn tick_optional_without_interval_never_fires() {
+        let fired = time::timeout(Duration::from_millis(20), tick_optional(None)).await;
+        assert!(fired.is_err());
+    }
+}
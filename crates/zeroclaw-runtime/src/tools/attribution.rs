@@ -17,6 +17,7 @@ use crate::tools::security_ops::SecurityOpsTool;
 use crate::tools::send_message_to_peer::SendMessageToPeerTool;
 use crate::tools::shell::ShellTool;
 use crate::tools::skill_http::SkillHttpTool;
+use crate::tools::skill_invoke::SkillInvokeTool;
 use crate::tools::skill_manage::{SkillManageTool, SkillViewTool, SkillsListTool};
 use crate::tools::skill_tool::{SkillBuiltinTool, SkillShellTool};
 use crate::tools::sop_advance::SopAdvanceTool;
@@ -42,6 +43,7 @@ tool_attribution!(SecurityOpsTool, ToolKind::Plugin);
 tool_attribution!(SendMessageToPeerTool, ToolKind::Plugin);
 tool_attribution!(ShellTool, ToolKind::Shell);
 tool_attribution!(SkillHttpTool, ToolKind::Plugin);
+tool_attribution!(SkillInvokeTool, ToolKind::Plugin);
 tool_attribution!(SkillsListTool, ToolKind::Plugin);
 tool_attribution!(SkillViewTool, ToolKind::Plugin);
 tool_attribution!(SkillManageTool, ToolKind::Plugin);
@@ -34,6 +34,10 @@ impl Tool for CronListTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, _args: serde_json::Value) -> anyhow::Result<ToolResult> {
         if !self.config.scheduler.enabled {
             return Ok(ToolResult {
@@ -36,6 +36,11 @@ impl Tool for CronRunTool {
                     "type": "boolean",
                     "description": "Set true to explicitly approve medium/high-risk shell commands in supervised mode",
                     "default": false
+                },
+                "now": {
+                    "type": "boolean",
+                    "description": "Deliver the result immediately even during quiet hours instead of queueing it until they end",
+                    "default": false
                 }
             },
             "required": ["job_id"]
@@ -65,6 +70,10 @@ impl Tool for CronRunTool {
             .get("approved")
             .and_then(serde_json::Value::as_bool)
             .unwrap_or(false);
+        let now = args
+            .get("now")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
 
         if !self.security.can_act() {
             return Ok(ToolResult {
@@ -116,7 +125,7 @@ impl Tool for CronRunTool {
         let result = cron::scheduler::run_manual_job(
             &self.config,
             &job,
-            cron::scheduler::CronDeliveryContext::ToolManual,
+            cron::scheduler::CronDeliveryContext::ToolManual { now },
             &None,
         )
         .await;
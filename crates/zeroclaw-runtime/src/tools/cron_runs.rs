@@ -50,6 +50,10 @@ impl Tool for CronRunsTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         if !self.config.scheduler.enabled {
             return Ok(ToolResult {
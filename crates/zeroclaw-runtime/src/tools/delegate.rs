@@ -2768,6 +2768,14 @@ impl Tool for ToolArcRef {
         self.inner.param_domains()
     }
 
+    fn is_read_only(&self) -> bool {
+        self.inner.is_read_only()
+    }
+
+    fn mutates(&self, args: &serde_json::Value) -> bool {
+        self.inner.mutates(args)
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         self.inner.execute(args).await
     }
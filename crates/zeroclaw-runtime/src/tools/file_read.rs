@@ -1,11 +1,31 @@
+use super::file_read_scope::{
+    DEFAULT_AROUND_CONTEXT, DEFAULT_REGEX_CONTEXT, DEFAULT_SYMBOL_CONTEXT, MAX_REGEX_MATCHES,
+    find_symbol, regex_spans, render_spans, window,
+};
 use crate::security::SecurityPolicy;
+use crate::security::policy::ToolOperation;
 use async_trait::async_trait;
+use regex::Regex;
 use serde_json::json;
+use std::path::Path;
 use std::sync::Arc;
 use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult, with_ephemeral_workspace_warning};
 
 const MAX_FILE_SIZE_BYTES: u64 = 10 * 1024 * 1024;
 
+/// Whole-file reads longer than this get a hint to read a scoped slice
+/// instead. Matches the default runtime-profile `max_tool_result_chars`,
+/// past which the agent loop truncates the middle of the output.
+const LARGE_READ_HINT_CHARS: usize = 50_000;
+
+/// A scoped read: only part of a text file, chosen by content rather than
+/// by line range.
+enum Scope {
+    Symbol(String),
+    AroundLine(usize),
+    Regex(Regex),
+}
+
 /// Read file contents with workspace sandboxing.
 pub struct FileReadTool {
     security: Arc<SecurityPolicy>,
@@ -29,20 +49,6 @@ impl FileReadTool {
             persistent_writes,
         }
     }
-
-    fn resolve_candidate(&self, path: &str) -> anyhow::Result<std::path::PathBuf> {
-        if path.contains('\0') {
-            anyhow::bail!("Path not allowed: contains null byte");
-        }
-        if std::path::Path::new(path)
-            .components()
-            .any(|c| matches!(c, std::path::Component::ParentDir))
-        {
-            anyhow::bail!("Path not allowed by security policy: {path}");
-        }
-
-        Ok(self.security.resolve_tool_path(path))
-    }
 }
 
 #[async_trait]
@@ -52,7 +58,7 @@ impl Tool for FileReadTool {
     }
 
     fn description(&self) -> &str {
-        "Read file contents with line numbers. Supports partial reading via offset and limit. Binary and image files are rejected (use the image_info tool for images). Set encoding=\"base64\" to return raw bytes base64-encoded (for binary files such as .pdf/.xlsx/.docx); offset/limit are ignored in that mode."
+        "Read file contents with line numbers. Supports partial reading via offset and limit, or scoped reading: symbol extracts one function/class/struct definition (e.g. \"parse\" or \"Parser::parse\"), around_line returns the lines around one line, and regex returns the matching lines with surrounding context. Prefer a scoped read for large source files. Binary and image files are rejected (use the image_info tool for images). Set encoding=\"base64\" to return raw bytes base64-encoded (for binary files such as .pdf/.xlsx/.docx); offset/limit and scoped reads are ignored in that mode."
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
@@ -75,12 +81,32 @@ impl Tool for FileReadTool {
                     "type": "string",
                     "enum": ["utf8", "base64"],
                     "description": "Output encoding (default: 'utf8'). Use 'base64' to read binary files as base64-encoded bytes."
+                },
+                "symbol": {
+                    "type": "string",
+                    "description": "Return only this function, method, class, struct, enum, trait, or impl, with a few lines of context. Qualify with 'Type::name' or 'Class.name' to pick one of several. Overrides offset/limit."
+                },
+                "around_line": {
+                    "type": "integer",
+                    "description": "Return the lines around this 1-based line number (20 on each side unless 'context' is set). Overrides offset/limit."
+                },
+                "regex": {
+                    "type": "string",
+                    "description": "Return every line matching this regular expression, with line numbers and surrounding context (2 lines unless 'context' is set). Overrides offset/limit."
+                },
+                "context": {
+                    "type": "integer",
+                    "description": "Lines of context to include around a symbol, around_line, or regex match."
                 }
             },
             "required": ["path"]
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         // Base64 reads return a verbatim payload the caller decodes, so they
         // must NOT be annotated — a prepended banner would corrupt decoding.
@@ -111,21 +137,26 @@ impl FileReadTool {
             anyhow::Error::msg("Missing 'path' parameter")
         })?;
 
-        // Validate and build candidate path using workspace_dir directly.
-        let full_path = match self.resolve_candidate(path) {
+        // Resolve symlinks and confine to the readable roots: workspace +
+        // read-write allowlist + read-only allowlist + universal POSIX
+        // device files (/dev/null, etc.).
+        let confined_path = match self
+            .security
+            .resolve_confined_path(path, ToolOperation::Read)
+        {
             Ok(p) => p,
             Err(e) => {
                 let _ = self.security.record_action();
                 return Ok(ToolResult {
                     success: false,
                     output: ToolOutput::default(),
-                    error: Some(e.to_string()),
+                    error: Some(e),
                 });
             }
         };
 
-        // Canonicalize to resolve symlinks, then enforce workspace boundary.
-        let resolved_path = match tokio::fs::canonicalize(&full_path).await {
+        // The confined path may name a file that does not exist yet.
+        let resolved_path = match tokio::fs::canonicalize(&confined_path).await {
             Ok(p) => p,
             Err(e) => {
                 let _ = self.security.record_action();
@@ -137,16 +168,6 @@ impl FileReadTool {
             }
         };
 
-        // Read access: workspace + read-write allowlist + read-only allowlist
-        // + universal POSIX device files (/dev/null, etc.).
-        if !self.security.is_resolved_path_readable(&resolved_path) {
-            return Ok(ToolResult {
-                success: false,
-                output: ToolOutput::default(),
-                error: Some(format!("Path escapes workspace directory: {path}")),
-            });
-        }
-
         // Check file size AFTER canonicalization to prevent TOCTOU symlink bypass
         match tokio::fs::metadata(&resolved_path).await {
             Ok(meta) => {
@@ -205,6 +226,21 @@ impl FileReadTool {
             });
         }
 
+        let scope = match parse_scope(&args) {
+            Ok(scope) => scope,
+            Err(e) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: ToolOutput::default(),
+                    error: Some(e),
+                });
+            }
+        };
+        let context = args
+            .get("context")
+            .and_then(|v| v.as_u64())
+            .map(|v| usize::try_from(v).unwrap_or(usize::MAX));
+
         match tokio::fs::read_to_string(&resolved_path).await {
             Ok(contents) => {
                 let lines: Vec<&str> = contents.lines().collect();
@@ -218,6 +254,16 @@ impl FileReadTool {
                     });
                 }
 
+                if let Some(scope) = scope {
+                    return Ok(scoped_read(
+                        &resolved_path,
+                        &contents,
+                        &lines,
+                        scope,
+                        context,
+                    ));
+                }
+
                 let offset = args
                     .get("offset")
                     .and_then(|v| v.as_u64())
@@ -255,6 +301,12 @@ impl FileReadTool {
                 let partial = start > 0 || end < total;
                 let summary = if partial {
                     format!("\n[Lines {}-{} of {total}]", start + 1, end)
+                } else if numbered.len() > LARGE_READ_HINT_CHARS {
+                    format!(
+                        "\n[{total} lines total. This file is larger than a tool result \
+                         usually keeps; read one `symbol`, the lines `around_line`, the \
+                         `regex` matches, or an offset/limit range instead.]"
+                    )
                 } else {
                     format!("\n[{total} lines total]")
                 };
@@ -325,6 +377,123 @@ impl FileReadTool {
     }
 }
 
+/// Read the scoped-read arguments. At most one of `symbol`, `around_line`,
+/// and `regex` may be given.
+fn parse_scope(args: &serde_json::Value) -> Result<Option<Scope>, String> {
+    let symbol = args.get("symbol").and_then(|v| v.as_str());
+    let around_line = args.get("around_line").and_then(|v| v.as_u64());
+    let pattern = args.get("regex").and_then(|v| v.as_str());
+    let given = [symbol.is_some(), around_line.is_some(), pattern.is_some()];
+    if given.iter().filter(|&&g| g).count() > 1 {
+        return Err("Use only one of 'symbol', 'around_line', or 'regex' per read".into());
+    }
+    if let Some(symbol) = symbol {
+        let symbol = symbol.trim();
+        if symbol.is_empty() {
+            return Err("'symbol' must not be empty".into());
+        }
+        return Ok(Some(Scope::Symbol(symbol.to_string())));
+    }
+    if let Some(line) = around_line {
+        return Ok(Some(Scope::AroundLine(
+            usize::try_from(line).unwrap_or(usize::MAX),
+        )));
+    }
+    if let Some(pattern) = pattern {
+        return Regex::new(pattern)
+            .map(|re| Some(Scope::Regex(re)))
+            .map_err(|e| format!("Invalid regex: {e}"));
+    }
+    Ok(None)
+}
+
+fn scoped_read(
+    path: &Path,
+    contents: &str,
+    lines: &[&str],
+    scope: Scope,
+    context: Option<usize>,
+) -> ToolResult {
+    let total = lines.len();
+    let output = match scope {
+        Scope::Symbol(symbol) => {
+            let lookup = find_symbol(path, contents, &symbol);
+            if lookup.definitions.is_empty() {
+                return ToolResult {
+                    success: false,
+                    output: ToolOutput::default(),
+                    error: Some(format!(
+                        "Symbol '{symbol}' not found in {}. Use 'regex' to search the file \
+                         instead.",
+                        path.display()
+                    )),
+                };
+            }
+            let context = context.unwrap_or(DEFAULT_SYMBOL_CONTEXT);
+            let mut output = lookup
+                .definitions
+                .iter()
+                .map(|d| {
+                    let span = window(d.start, d.end, context, total);
+                    format!(
+                        "[{} '{symbol}': lines {}-{} of {total}]\n{}",
+                        d.kind,
+                        d.start + 1,
+                        d.end,
+                        render_spans(lines, &[span])
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            if !lookup.syntax_aware {
+                output.push_str(
+                    "\n[Matched heuristically; no syntax grammar for this file. Widen with \
+                     'context' or 'around_line' if the definition looks cut off.]",
+                );
+            }
+            output
+        }
+        Scope::AroundLine(line) => {
+            if line == 0 || line > total {
+                format!("[Line {line} is out of range, file has {total} lines]")
+            } else {
+                let (start, end) = window(
+                    line - 1,
+                    line,
+                    context.unwrap_or(DEFAULT_AROUND_CONTEXT),
+                    total,
+                );
+                format!(
+                    "{}\n[Lines {}-{end} of {total}]",
+                    render_spans(lines, &[(start, end)]),
+                    start + 1
+                )
+            }
+        }
+        Scope::Regex(re) => {
+            let (spans, count) = regex_spans(lines, &re, context.unwrap_or(DEFAULT_REGEX_CONTEXT));
+            if count == 0 {
+                format!("[No lines match /{re}/, file has {total} lines]")
+            } else {
+                let shown = if count > MAX_REGEX_MATCHES {
+                    format!(", first {MAX_REGEX_MATCHES} shown")
+                } else {
+                    String::new()
+                };
+                format!(
+                    "{}\n[{count} matching lines of {total}{shown}]",
+                    render_spans(lines, &spans)
+                )
+            }
+        }
+    };
+    ToolResult {
+        success: true,
+        output: output.into(),
+        error: None,
+    }
+}
+
 fn detect_image_format(bytes: &[u8]) -> Option<&'static str> {
     if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
         Some("png")
@@ -796,6 +965,33 @@ mod tests {
         let _ = tokio::fs::remove_dir_all(&root).await;
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn file_read_blocks_symlinked_directory_escape() {
+        use std::os::unix::fs::symlink;
+
+        let root = tempfile::tempdir().unwrap();
+        let workspace = root.path().join("workspace");
+        let outside = root.path().join("outside");
+        tokio::fs::create_dir_all(&workspace).await.unwrap();
+        tokio::fs::create_dir_all(outside.join("nested"))
+            .await
+            .unwrap();
+        tokio::fs::write(outside.join("nested/secret.txt"), "outside")
+            .await
+            .unwrap();
+        symlink(&outside, workspace.join("linked_dir")).unwrap();
+
+        let tool = test_tool(workspace.clone());
+        let result = tool
+            .execute(json!({"path": "linked_dir/nested/secret.txt"}))
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(result.error.as_ref().unwrap().contains("escapes workspace"));
+    }
+
     #[tokio::test]
     async fn file_read_blocks_outside_workspace_regardless_of_policy() {
         let root = std::env::temp_dir().join("zeroclaw_test_file_read_blocks_outside");
@@ -912,6 +1108,151 @@ mod tests {
         let _ = tokio::fs::remove_dir_all(&dir).await;
     }
 
+    #[tokio::test]
+    async fn file_read_symbol_extracts_rust_and_python_definitions() {
+        let dir = std::env::temp_dir().join("zeroclaw_test_file_read_symbol");
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        let rust = "use std::fmt;\n\n/// Adds.\npub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n\n\n\n\npub fn sub(a: i32, b: i32) -> i32 {\n    a - b\n}\n";
+        tokio::fs::write(dir.join("math.rs"), rust).await.unwrap();
+        let python = "import os\n\n\nclass Greeter:\n    def greet(self):\n        return 'hi'\n\n\n\n\ndef main():\n    pass\n";
+        tokio::fs::write(dir.join("greet.py"), python)
+            .await
+            .unwrap();
+
+        let tool = test_tool(dir.clone());
+
+        let result = tool
+            .execute(json!({"path": "math.rs", "symbol": "add"}))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("[function 'add': lines 3-6 of 13]"));
+        assert!(result.output.contains("3: /// Adds."));
+        assert!(result.output.contains("6: }"));
+        assert!(!result.output.contains("sub"));
+
+        let result = tool
+            .execute(json!({"path": "greet.py", "symbol": "Greeter.greet", "context": 0}))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("5:     def greet(self):"));
+        assert!(result.output.contains("6:         return 'hi'"));
+        assert!(!result.output.contains("4: class Greeter:"));
+        assert!(!result.output.contains("main"));
+
+        let result = tool
+            .execute(json!({"path": "math.rs", "symbol": "mul"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Symbol 'mul' not found"));
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn file_read_symbol_without_grammar_is_marked_heuristic() {
+        let dir = std::env::temp_dir().join("zeroclaw_test_file_read_symbol_fallback");
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        tokio::fs::write(
+            dir.join("math.js"),
+            "function add(a, b) {\n  return a + b;\n}\n",
+        )
+        .await
+        .unwrap();
+
+        let tool = test_tool(dir.clone());
+        let result = tool
+            .execute(json!({"path": "math.js", "symbol": "add"}))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("3: }"));
+        assert!(result.output.contains("[Matched heuristically"));
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn file_read_regex_and_around_line() {
+        let dir = std::env::temp_dir().join("zeroclaw_test_file_read_regex");
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        let contents: String = (1..=50).map(|i| format!("line {i}\n")).collect();
+        tokio::fs::write(dir.join("lines.txt"), contents)
+            .await
+            .unwrap();
+
+        let tool = test_tool(dir.clone());
+
+        let result = tool
+            .execute(json!({"path": "lines.txt", "regex": "^line (10|40)$", "context": 1}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(
+            result
+                .output
+                .contains("9: line 9\n10: line 10\n11: line 11\n...\n39: line 39")
+        );
+        assert!(result.output.contains("[2 matching lines of 50]"));
+
+        let result = tool
+            .execute(json!({"path": "lines.txt", "around_line": 25, "context": 2}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.starts_with("23: line 23"));
+        assert!(result.output.contains("[Lines 23-27 of 50]"));
+
+        let result = tool
+            .execute(json!({"path": "lines.txt", "regex": "(", "symbol": "x"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("only one of"));
+
+        let result = tool
+            .execute(json!({"path": "lines.txt", "regex": "("}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Invalid regex"));
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn file_read_large_whole_file_suggests_scoped_reads() {
+        let dir = std::env::temp_dir().join("zeroclaw_test_file_read_large_hint");
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        let contents = format!("{}\n", "x".repeat(99));
+        tokio::fs::write(dir.join("big.txt"), contents.repeat(600))
+            .await
+            .unwrap();
+
+        let tool = test_tool(dir.clone());
+        let result = tool.execute(json!({"path": "big.txt"})).await.unwrap();
+        assert!(result.success);
+        assert!(
+            result
+                .output
+                .contains("[600 lines total. This file is larger")
+        );
+
+        let result = tool
+            .execute(json!({"path": "big.txt", "limit": 10}))
+            .await
+            .unwrap();
+        assert!(result.output.contains("[Lines 1-10 of 600]"));
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
     #[tokio::test]
     async fn file_read_offset_beyond_end() {
         let dir = std::env::temp_dir().join("zeroclaw_test_file_read_offset_end");
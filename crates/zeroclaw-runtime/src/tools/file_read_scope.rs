@@ -0,0 +1,565 @@
+//! Scoped reads for `file_read`: one symbol's definition, a window around a
+//! line, or the regions matching a regex.
+//!
+//! Symbol lookup uses tree-sitter when the `code-symbols` feature is enabled
+//! and a grammar exists for the file (Rust, Python). Everything else goes
+//! through a line-based heuristic: a definition pattern per language, then
+//! brace matching or indentation to find where the body ends.
+
+use regex::Regex;
+use std::path::Path;
+
+/// Lines shown above and below an extracted symbol.
+pub(crate) const DEFAULT_SYMBOL_CONTEXT: usize = 3;
+/// Lines shown above and below `around_line`.
+pub(crate) const DEFAULT_AROUND_CONTEXT: usize = 20;
+/// Lines shown above and below each regex match.
+pub(crate) const DEFAULT_REGEX_CONTEXT: usize = 2;
+/// Definitions returned for one symbol name (overloads, impls per type, ...).
+pub(crate) const MAX_SYMBOL_MATCHES: usize = 5;
+/// Matching lines reported for one regex before the rest are counted only.
+pub(crate) const MAX_REGEX_MATCHES: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Language {
+    Rust,
+    Python,
+    /// Brace-delimited languages without a grammar (C, Go, JS/TS, Java, ...).
+    Other,
+}
+
+impl Language {
+    fn of(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("rs") => Self::Rust,
+            Some("py" | "pyi") => Self::Python,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// One definition, as 0-based line indices (`end` exclusive). `start`
+/// includes attached attributes, decorators, and doc comments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Definition {
+    pub kind: &'static str,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct SymbolLookup {
+    pub definitions: Vec<Definition>,
+    /// `false` when the heuristic extractor was used.
+    pub syntax_aware: bool,
+}
+
+/// Find the definitions of `symbol` in `source`. A qualified symbol
+/// (`Type::method`, `Class.method`) only matches definitions nested inside a
+/// definition of the qualifier.
+pub(crate) fn find_symbol(path: &Path, source: &str, symbol: &str) -> SymbolLookup {
+    let mut segments: Vec<&str> = symbol
+        .split("::")
+        .flat_map(|s| s.split('.'))
+        .filter(|s| !s.is_empty())
+        .collect();
+    let Some(name) = segments.pop() else {
+        return SymbolLookup {
+            definitions: Vec::new(),
+            syntax_aware: false,
+        };
+    };
+    let qualifier = segments.pop();
+    let language = Language::of(path);
+
+    let (mut definitions, syntax_aware) = match syntax::definitions(language, source, name) {
+        Some(found) => {
+            let outer = qualifier.map(|q| syntax::definitions(language, source, q));
+            (qualify(found, outer.flatten()), true)
+        }
+        None => {
+            let lines: Vec<&str> = source.lines().collect();
+            let found = heuristic_definitions(language, &lines, name);
+            let outer = qualifier.map(|q| heuristic_definitions(language, &lines, q));
+            (qualify(found, outer), false)
+        }
+    };
+    definitions.truncate(MAX_SYMBOL_MATCHES);
+    SymbolLookup {
+        definitions,
+        syntax_aware,
+    }
+}
+
+/// Keep only the definitions nested in one of `outer`, when a qualifier
+/// was given.
+fn qualify(found: Vec<Definition>, outer: Option<Vec<Definition>>) -> Vec<Definition> {
+    let Some(outer) = outer else {
+        return found;
+    };
+    found
+        .into_iter()
+        .filter(|d| {
+            outer
+                .iter()
+                .any(|o| o != d && o.start <= d.start && d.end <= o.end)
+        })
+        .collect()
+}
+
+/// Line spans (0-based, `end` exclusive) around every line matching `re`,
+/// plus the total number of matching lines. Only the first
+/// [`MAX_REGEX_MATCHES`] matches get a span.
+pub(crate) fn regex_spans(
+    lines: &[&str],
+    re: &Regex,
+    context: usize,
+) -> (Vec<(usize, usize)>, usize) {
+    let mut spans = Vec::new();
+    let mut count = 0;
+    for (i, line) in lines.iter().enumerate() {
+        if re.is_match(line) {
+            count += 1;
+            if count <= MAX_REGEX_MATCHES {
+                spans.push(window(i, i + 1, context, lines.len()));
+            }
+        }
+    }
+    (spans, count)
+}
+
+/// Widen `[start, end)` by `context` lines on each side, clamped to `total`.
+pub(crate) fn window(start: usize, end: usize, context: usize, total: usize) -> (usize, usize) {
+    (
+        start.saturating_sub(context),
+        end.saturating_add(context).min(total),
+    )
+}
+
+/// Render spans as numbered lines. Overlapping or adjacent spans are merged;
+/// gaps between them are marked with `...`.
+pub(crate) fn render_spans(lines: &[&str], spans: &[(usize, usize)]) -> String {
+    let mut sorted = spans.to_vec();
+    sorted.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in sorted {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+        .iter()
+        .map(|&(start, end)| {
+            (start..end)
+                .map(|i| format!("{}: {}", i + 1, lines[i]))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n...\n")
+}
+
+// ── Heuristic extractor ─────────────────────────────────────────────
+
+fn definition_pattern(language: Language, name: &str) -> Regex {
+    let name = regex::escape(name);
+    let pattern = match language {
+        Language::Rust => format!(
+            r#"^\s*(?:pub(?:\([^)]*\))?\s+)?(?:(?:default|const|async|unsafe|extern(?:\s+"[^"]*")?)\s+)*(?:(fn|struct|enum|union|trait|mod|type|const|static)\s+|(macro_rules!)\s*|(impl)(?:<[^{{]*?>)?\s+(?:[\w:<>, ]+\s+for\s+)?(?:[\w]+::)*){name}\b"#
+        ),
+        Language::Python => format!(r"^\s*(?:async\s+)?(def|class)\s+{name}\b"),
+        Language::Other => format!(
+            r"^\s*(?:(?:export|default|public|private|protected|internal|static|async|abstract|final|pub)\s+)*(function|func|fn|def|class|interface|struct|enum|trait|type)\s+{name}\b"
+        ),
+    };
+    Regex::new(&pattern).expect("definition pattern is valid")
+}
+
+fn kind_name(keyword: &str) -> &'static str {
+    match keyword {
+        "fn" | "def" | "function" | "func" => "function",
+        "class" => "class",
+        "struct" => "struct",
+        "enum" => "enum",
+        "union" => "union",
+        "trait" => "trait",
+        "interface" => "interface",
+        "mod" => "module",
+        "type" => "type",
+        "const" => "const",
+        "static" => "static",
+        "macro_rules!" => "macro",
+        "impl" => "impl",
+        _ => "definition",
+    }
+}
+
+fn heuristic_definitions(language: Language, lines: &[&str], name: &str) -> Vec<Definition> {
+    let pattern = definition_pattern(language, name);
+    lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let caps = pattern.captures(line)?;
+            // `impl fmt::Display for Point` does not define `fmt`.
+            if line[caps.get(0)?.end()..].starts_with("::") {
+                return None;
+            }
+            let keyword = (1..caps.len())
+                .find_map(|g| caps.get(g))
+                .map_or("", |m| m.as_str());
+            let end = match language {
+                Language::Python => indented_block_end(lines, i),
+                Language::Rust | Language::Other => brace_block_end(lines, i),
+            };
+            Some(Definition {
+                kind: kind_name(keyword),
+                start: leading_decorations_start(lines, i),
+                end,
+            })
+        })
+        .collect()
+}
+
+/// Walk up over attributes, decorators, and comments attached to line `i`.
+fn leading_decorations_start(lines: &[&str], i: usize) -> usize {
+    let mut start = i;
+    while start > 0 {
+        let above = lines[start - 1].trim_start();
+        let attached = ["//", "#", "@", "/*", "*"]
+            .iter()
+            .any(|prefix| above.starts_with(prefix));
+        if !attached {
+            break;
+        }
+        start -= 1;
+    }
+    start
+}
+
+/// End of a brace-delimited definition starting at line `i`. A `;` before
+/// any `{` ends it on that line (`struct Unit;`, `type A = B;`). String
+/// literals and `//` comments are skipped; that is enough for the braces a
+/// heuristic needs to count.
+fn brace_block_end(lines: &[&str], i: usize) -> usize {
+    let mut depth = 0usize;
+    let mut opened = false;
+    for (offset, line) in lines[i..].iter().enumerate() {
+        let mut chars = line.chars().peekable();
+        let mut quote: Option<char> = None;
+        while let Some(c) = chars.next() {
+            if let Some(q) = quote {
+                if c == '\\' {
+                    chars.next();
+                } else if c == q {
+                    quote = None;
+                }
+                continue;
+            }
+            match c {
+                '"' => quote = Some('"'),
+                '/' if chars.peek() == Some(&'/') => break,
+                '{' => {
+                    depth += 1;
+                    opened = true;
+                }
+                '}' => {
+                    depth = depth.saturating_sub(1);
+                    if opened && depth == 0 {
+                        return i + offset + 1;
+                    }
+                }
+                ';' if !opened => return i + offset + 1,
+                _ => {}
+            }
+        }
+    }
+    lines.len()
+}
+
+/// End of an indentation-delimited (Python) definition starting at line `i`:
+/// the header runs to the line ending in `:`, the body to the last line
+/// indented deeper than the header.
+fn indented_block_end(lines: &[&str], i: usize) -> usize {
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let base = indent(lines[i]);
+    let mut header_end = i;
+    while header_end < lines.len() && !lines[header_end].trim_end().ends_with(':') {
+        header_end += 1;
+    }
+    if header_end == lines.len() {
+        return i + 1;
+    }
+    let mut end = header_end + 1;
+    for (offset, line) in lines[header_end + 1..].iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if indent(line) <= base {
+            break;
+        }
+        end = header_end + 1 + offset + 1;
+    }
+    end
+}
+
+// ── tree-sitter extractor ───────────────────────────────────────────
+
+#[cfg(feature = "code-symbols")]
+mod syntax {
+    use super::{Definition, Language};
+    use tree_sitter::{Node, Parser};
+
+    /// `None` when there is no grammar for `language` or the parse fails.
+    pub(super) fn definitions(
+        language: Language,
+        source: &str,
+        name: &str,
+    ) -> Option<Vec<Definition>> {
+        let grammar: tree_sitter::Language = match language {
+            Language::Rust => tree_sitter_rust::LANGUAGE.into(),
+            Language::Python => tree_sitter_python::LANGUAGE.into(),
+            Language::Other => return None,
+        };
+        let mut parser = Parser::new();
+        parser.set_language(&grammar).ok()?;
+        let tree = parser.parse(source, None)?;
+        let mut found = Vec::new();
+        collect(tree.root_node(), source.as_bytes(), name, &mut found);
+        Some(found)
+    }
+
+    fn collect(node: Node<'_>, source: &[u8], name: &str, found: &mut Vec<Definition>) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if let Some(kind) = kind_name(child.kind())
+                && node_name(child, source).as_deref() == Some(name)
+            {
+                found.push(definition(child, kind));
+            }
+            collect(child, source, name, found);
+        }
+    }
+
+    fn kind_name(kind: &str) -> Option<&'static str> {
+        Some(match kind {
+            "function_item" | "function_signature_item" | "function_definition" => "function",
+            "class_definition" => "class",
+            "struct_item" => "struct",
+            "enum_item" => "enum",
+            "union_item" => "union",
+            "trait_item" => "trait",
+            "impl_item" => "impl",
+            "mod_item" => "module",
+            "type_item" => "type",
+            "const_item" => "const",
+            "static_item" => "static",
+            "macro_definition" => "macro",
+            _ => return None,
+        })
+    }
+
+    /// The defined name. An `impl` block is named after its self type,
+    /// without path or generics.
+    fn node_name(node: Node<'_>, source: &[u8]) -> Option<String> {
+        if node.kind() == "impl_item" {
+            let ty = node.child_by_field_name("type")?.utf8_text(source).ok()?;
+            let ty = ty.split('<').next().unwrap_or(ty);
+            return ty.rsplit("::").next().map(|s| s.trim().to_string());
+        }
+        let name = node.child_by_field_name("name")?.utf8_text(source).ok()?;
+        Some(name.to_string())
+    }
+
+    fn definition(node: Node<'_>, kind: &'static str) -> Definition {
+        let mut top = node;
+        if let Some(parent) = node.parent()
+            && parent.kind() == "decorated_definition"
+        {
+            top = parent;
+        }
+        let mut start = top.start_position().row;
+        let mut prev = top.prev_sibling();
+        while let Some(sibling) = prev {
+            let attached = matches!(
+                sibling.kind(),
+                "attribute_item" | "line_comment" | "block_comment" | "comment"
+            );
+            if !attached || sibling.end_position().row + 1 < start {
+                break;
+            }
+            start = sibling.start_position().row;
+            prev = sibling.prev_sibling();
+        }
+        let end = node.end_position();
+        let end = if end.column == 0 && end.row > start {
+            end.row
+        } else {
+            end.row + 1
+        };
+        Definition { kind, start, end }
+    }
+}
+
+#[cfg(not(feature = "code-symbols"))]
+mod syntax {
+    use super::{Definition, Language};
+
+    pub(super) fn definitions(_: Language, _: &str, _: &str) -> Option<Vec<Definition>> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUST: &str = r#"use std::fmt;
+
+/// A point.
+#[derive(Debug)]
+pub struct Point {
+    x: i32,
+}
+
+impl Point {
+    pub fn norm(&self) -> i32 {
+        let s = "}";
+        self.x.abs()
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.x)
+    }
+}
+
+pub struct Unit;
+"#;
+
+    const PYTHON: &str = r#"import os
+
+
+class Greeter:
+    """Says hello."""
+
+    @staticmethod
+    def greet(name):
+        if name:
+            return f"hi {name}"
+
+        return "hi"
+
+    def other(self):
+        pass
+
+
+def greet(name):
+    return name
+"#;
+
+    fn lines(source: &str) -> Vec<&str> {
+        source.lines().collect()
+    }
+
+    #[test]
+    fn heuristic_rust_extraction_includes_docs_and_stops_at_the_closing_brace() {
+        let src = lines(RUST);
+        let point = heuristic_definitions(Language::Rust, &src, "Point");
+        let kinds: Vec<_> = point.iter().map(|d| d.kind).collect();
+        assert_eq!(kinds, ["struct", "impl", "impl"]);
+        assert_eq!((point[0].start, point[0].end), (2, 7));
+        assert_eq!((point[1].start, point[1].end), (8, 14));
+
+        let norm = heuristic_definitions(Language::Rust, &src, "norm");
+        assert_eq!(
+            (norm[0].kind, norm[0].start, norm[0].end),
+            ("function", 9, 13)
+        );
+
+        let unit = heuristic_definitions(Language::Rust, &src, "Unit");
+        assert_eq!((unit[0].start, unit[0].end), (21, 22));
+    }
+
+    #[test]
+    fn heuristic_python_extraction_follows_indentation() {
+        let src = lines(PYTHON);
+        let greet = heuristic_definitions(Language::Python, &src, "greet");
+        assert_eq!(greet.len(), 2);
+        // Decorator included, blank line inside the body kept, next method
+        // excluded.
+        assert_eq!(
+            (greet[0].kind, greet[0].start, greet[0].end),
+            ("function", 6, 12)
+        );
+        assert_eq!((greet[1].start, greet[1].end), (17, 19));
+
+        let class = heuristic_definitions(Language::Python, &src, "Greeter");
+        assert_eq!(
+            (class[0].kind, class[0].start, class[0].end),
+            ("class", 3, 15)
+        );
+    }
+
+    #[test]
+    fn qualified_symbols_only_match_inside_the_qualifier() {
+        let method = find_symbol(Path::new("a.py"), PYTHON, "Greeter.greet");
+        assert_eq!(method.definitions.len(), 1);
+        assert_eq!(method.definitions[0].start, 6);
+
+        let method = find_symbol(Path::new("a.rs"), RUST, "Point::fmt");
+        assert_eq!(method.definitions.len(), 1);
+        assert_eq!(method.definitions[0].start, 16);
+
+        assert!(
+            find_symbol(Path::new("a.rs"), RUST, "Unit::norm")
+                .definitions
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn files_without_a_grammar_fall_back_to_the_heuristic() {
+        let js = "// Adds.\nexport function add(a, b) {\n  return a + b;\n}\n";
+        let lookup = find_symbol(Path::new("math.js"), js, "add");
+        assert!(!lookup.syntax_aware);
+        assert_eq!(
+            lookup.definitions,
+            [Definition {
+                kind: "function",
+                start: 0,
+                end: 4
+            }]
+        );
+    }
+
+    #[cfg(feature = "code-symbols")]
+    #[test]
+    fn tree_sitter_extracts_rust_and_python_symbols() {
+        let lookup = find_symbol(Path::new("a.rs"), RUST, "Point");
+        assert!(lookup.syntax_aware);
+        let kinds: Vec<_> = lookup.definitions.iter().map(|d| d.kind).collect();
+        assert_eq!(kinds, ["struct", "impl", "impl"]);
+        assert_eq!(lookup.definitions[0].start, 2);
+
+        let lookup = find_symbol(Path::new("a.py"), PYTHON, "Greeter.greet");
+        assert!(lookup.syntax_aware);
+        assert_eq!(lookup.definitions.len(), 1);
+        assert_eq!(lookup.definitions[0].start, 6);
+        assert_eq!(lookup.definitions[0].end, 12);
+    }
+
+    #[test]
+    fn regex_spans_are_merged_when_rendered() {
+        let src = lines("a\nmatch 1\nb\nmatch 2\nc\nd\ne\nf\nmatch 3\n");
+        let re = Regex::new("match").unwrap();
+        let (spans, count) = regex_spans(&src, &re, 1);
+        assert_eq!(count, 3);
+        assert_eq!(
+            render_spans(&src, &spans),
+            "1: a\n2: match 1\n3: b\n4: match 2\n5: c\n...\n8: f\n9: match 3"
+        );
+    }
+}
@@ -10,6 +10,7 @@ pub mod cron_runs;
 pub mod cron_update;
 pub mod delegate;
 pub mod file_read;
+pub(crate) mod file_read_scope;
 pub mod model_switch;
 pub mod param_options;
 pub mod read_skill;
@@ -18,7 +19,9 @@ pub mod scoped;
 pub mod security_ops;
 pub mod send_message_to_peer;
 pub mod shell;
+pub mod shell_session;
 pub mod skill_http;
+pub mod skill_invoke;
 pub mod skill_manage;
 pub mod skill_tool;
 pub mod sop_advance;
@@ -28,6 +31,9 @@ pub mod sop_list;
 pub mod sop_status;
 pub mod sop_workshop;
 pub mod spawn_subagent;
+pub mod task_cancel;
+pub mod task_spawn;
+pub mod task_status;
 pub mod todo_write;
 pub mod verifiable_intent;
 
@@ -91,6 +97,7 @@ pub use zeroclaw_tools::memory_recall::MemoryRecallTool;
 pub use zeroclaw_tools::memory_store::MemoryStoreTool;
 pub use zeroclaw_tools::microsoft365::Microsoft365Tool;
 pub use zeroclaw_tools::model_routing_config::ModelRoutingConfigTool;
+pub use zeroclaw_tools::notify::NotifyTool;
 pub use zeroclaw_tools::notion_tool::NotionTool;
 pub use zeroclaw_tools::opencode_cli::OpenCodeCliTool;
 pub use zeroclaw_tools::pipeline::PipelineTool;
@@ -135,6 +142,7 @@ pub use security_ops::SecurityOpsTool;
 pub use send_message_to_peer::SendMessageToPeerTool;
 pub use shell::ShellTool;
 pub use skill_http::SkillHttpTool;
+pub use skill_invoke::SkillInvokeTool;
 pub use skill_tool::{SkillBuiltinTool, SkillShellTool};
 pub use sop_advance::SopAdvanceTool;
 pub use sop_approve::SopApproveTool;
@@ -143,6 +151,9 @@ pub use sop_list::SopListTool;
 pub use sop_status::SopStatusTool;
 pub use sop_workshop::SopWorkshopTool;
 pub use spawn_subagent::SpawnSubagentTool;
+pub use task_cancel::TaskCancelTool;
+pub use task_spawn::TaskSpawnTool;
+pub use task_status::TaskStatusTool;
 pub use todo_write::TodoWriteTool;
 pub use verifiable_intent::VerifiableIntentTool;
 
@@ -196,6 +207,14 @@ impl Tool for ArcToolRef {
         self.0.param_domains()
     }
 
+    fn is_read_only(&self) -> bool {
+        self.0.is_read_only()
+    }
+
+    fn mutates(&self, args: &serde_json::Value) -> bool {
+        self.0.mutates(args)
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         self.0.execute(args).await
     }
@@ -243,6 +262,14 @@ impl Tool for ArcDelegatingTool {
         self.inner.param_domains()
     }
 
+    fn is_read_only(&self) -> bool {
+        self.inner.is_read_only()
+    }
+
+    fn mutates(&self, args: &serde_json::Value) -> bool {
+        self.inner.mutates(args)
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         self.inner.execute(args).await
     }
@@ -621,6 +648,17 @@ pub fn all_tools_with_runtime(
         )),
         Arc::new(CronRunTool::new(config.clone(), security.clone())),
         Arc::new(CronRunsTool::new(config.clone())),
+        Arc::new(TaskSpawnTool::new(
+            config.clone(),
+            security.clone(),
+            agent_alias,
+        )),
+        Arc::new(TaskStatusTool::new(config.clone(), agent_alias)),
+        Arc::new(TaskCancelTool::new(
+            config.clone(),
+            security.clone(),
+            agent_alias,
+        )),
         Arc::new(MemoryStoreTool::new(memory.clone(), security.clone())),
         Arc::new(MemoryRecallTool::new(memory.clone())),
         Arc::new(MemoryForgetTool::new(memory.clone(), security.clone())),
@@ -645,10 +683,10 @@ pub fn all_tools_with_runtime(
         )),
         Arc::new(ModelSwitchTool::new(security.clone(), config.clone())),
         Arc::new(ProxyConfigTool::new(config.clone(), security.clone())),
-        Arc::new(GitOperationsTool::new(
-            security.clone(),
-            workspace_dir.to_path_buf(),
-        )),
+        Arc::new(
+            GitOperationsTool::new(security.clone(), workspace_dir.to_path_buf())
+                .with_config(root_config.git_tool.clone()),
+        ),
         Arc::new(PushoverTool::new(
             security.clone(),
             workspace_dir.to_path_buf(),
@@ -751,6 +789,10 @@ pub fn all_tools_with_runtime(
             agent_alias.to_string(),
         )));
     }
+    tool_arcs.push(Arc::new(SkillInvokeTool::new(
+        config.clone(),
+        agent_alias.to_string(),
+    )));
 
     if browser_config.enabled {
         // Add legacy browser_open tool for simple URL opening
@@ -792,7 +834,9 @@ pub fn all_tools_with_runtime(
                 max_coordinate_y: browser_config.computer_use.max_coordinate_y,
             },
             browser_config.allowed_private_hosts.clone(),
-        ) {
+        )
+        .and_then(|tool| tool.with_credential_domains(browser_config.credential_domains.clone()))
+        {
             Ok(tool) => {
                 tool_arcs.push(Arc::new(RateLimitedTool::new(tool, security.clone())));
             }
@@ -837,6 +881,7 @@ pub fn all_tools_with_runtime(
             root_config.secrets.encrypt,
         ) {
             Ok(tool) => {
+                let tool = tool.with_profiles(http_config.profiles.clone());
                 tool_arcs.push(Arc::new(RateLimitedTool::new(tool, security.clone())));
             }
             Err(e) => {
@@ -901,7 +946,7 @@ pub fn all_tools_with_runtime(
 
     // Web search tool (enabled by default for GLM and other models)
     if root_config.web_search.enabled {
-        tool_arcs.push(Arc::new(WebSearchTool::new_with_config(
+        let web_search = WebSearchTool::new_with_config(
             root_config.web_search.search_provider.clone(),
             root_config.web_search.brave_api_key.clone(),
             root_config.web_search.tavily_api_key.clone(),
@@ -911,7 +956,9 @@ pub fn all_tools_with_runtime(
             root_config.web_search.timeout_secs,
             root_config.config_path.clone(),
             root_config.secrets.encrypt,
-        )));
+        )
+        .with_security(security.clone());
+        tool_arcs.push(Arc::new(web_search));
     }
 
     // Notion API tool (conditionally registered)
@@ -986,6 +1033,14 @@ pub fn all_tools_with_runtime(
         }
     }
 
+    // Push notifications (registered once a target is configured)
+    if !root_config.notifications.targets.is_empty() {
+        tool_arcs.push(Arc::new(NotifyTool::new(
+            security.clone(),
+            root_config.notifications.targets.clone(),
+        )));
+    }
+
     // Project delivery intelligence
     if root_config.project_intel.enabled {
         tool_arcs.push(Arc::new(ProjectIntelTool::new(
@@ -1461,6 +1516,13 @@ pub fn all_tools_with_runtime(
                     let details = host.tool_plugin_details();
                     let discovered_count = details.len();
                     let mut registered_count = 0_usize;
+                    let network_guard = security.network_guard();
+                    let outbound_check: zeroclaw_plugins::component::OutboundHostCheck =
+                        Arc::new(move |host: &str| {
+                            network_guard
+                                .check_host(host, false)
+                                .map_err(|e| e.to_string())
+                        });
                     let plugin_limits = zeroclaw_plugins::component::PluginLimits {
                         call_fuel: config.plugins.limits.call_fuel,
                         max_memory_bytes: config
@@ -1485,12 +1547,13 @@ pub fn all_tools_with_runtime(
                                     manifest.name.clone(),
                                     manifest.permissions.iter().copied(),
                                 )?;
-                            zeroclaw_plugins::wasm_tool::WasmTool::from_wasm(
+                            Ok(zeroclaw_plugins::wasm_tool::WasmTool::from_wasm(
                                 wasm_path.to_path_buf(),
                                 scope,
                                 plugin_config,
                                 plugin_limits,
-                            )
+                            )?
+                            .with_outbound_check(outbound_check.clone()))
                         })();
                         match tool {
                             Ok(tool) => {
@@ -2370,6 +2433,7 @@ mod tests {
         assert!(names.contains(&"model_routing_config"));
         assert!(names.contains(&"pushover"));
         assert!(names.contains(&"proxy_config"));
+        assert!(!names.contains(&"notify"));
     }
 
     #[test]
@@ -2420,6 +2484,50 @@ mod tests {
         assert!(names.contains(&"proxy_config"));
     }
 
+    #[test]
+    fn all_tools_registers_notify_when_a_target_is_configured() {
+        let tmp = TempDir::new().unwrap();
+        let security = Arc::new(SecurityPolicy::default());
+        let mem_cfg = MemoryConfig {
+            backend: "markdown".into(),
+            ..MemoryConfig::default()
+        };
+        let mem: Arc<dyn Memory> =
+            Arc::from(zeroclaw_memory::create_memory(&mem_cfg, tmp.path(), None).unwrap());
+        let mut cfg = test_config(&tmp);
+        cfg.notifications.targets.insert(
+            "phone".into(),
+            zeroclaw_config::schema::NotificationTargetConfig {
+                url: "https://ntfy.sh/alerts".into(),
+                ..Default::default()
+            },
+        );
+
+        let tools = all_tools(
+            Arc::new(Config::default()),
+            &security,
+            &zeroclaw_config::schema::RiskProfileConfig::default(),
+            "test-agent",
+            mem,
+            None,
+            None,
+            &BrowserConfig::default(),
+            &zeroclaw_config::schema::HttpRequestConfig::default(),
+            &zeroclaw_config::schema::WebFetchConfig::default(),
+            tmp.path(),
+            &HashMap::new(),
+            None,
+            &cfg,
+            None,
+            false,
+            None,
+        )
+        .tools;
+        let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
+        assert!(names.contains(&"notify"));
+        assert!(names.contains(&"pushover"));
+    }
+
     #[tokio::test]
     async fn registered_sop_tools_persist_audit_trail() {
         let tmp = TempDir::new().unwrap();
@@ -394,6 +394,7 @@ fn hardcoded_models_for(provider_family: &str) -> Vec<String> {
         "mistral" => vec![
             "mistral-large-latest",
             "mistral-small-latest",
+            "codestral-latest",
             "mistral-nemo",
         ],
         "gemini" => vec!["gemini-2.0-flash", "gemini-1.5-pro", "gemini-1.5-flash"],
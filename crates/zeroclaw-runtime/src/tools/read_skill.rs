@@ -43,6 +43,10 @@ impl Tool for ReadSkillTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let requested = args
             .get("name")
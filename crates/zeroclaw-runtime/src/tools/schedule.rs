@@ -38,6 +38,8 @@ impl Tool for ScheduleTool {
 
     fn description(&self) -> &str {
         "Manage scheduled shell-only tasks. Actions: create/add/once/list/get/cancel/remove/pause/resume. \
+         Give the schedule as a cron 'expression' or as plain English in 'when' (e.g. 'every weekday at 9am'); \
+         the derived cron expression or run time is returned for confirmation. \
          WARNING: This tool creates shell jobs whose output is only logged, NOT delivered to any channel. \
          To send a scheduled message to Discord/Telegram/Slack/Matrix, use the cron_add tool with job_type='agent' \
          and a delivery config like {\"mode\":\"announce\",\"channel\":\"discord\",\"to\":\"<channel_id>\"}."
@@ -56,6 +58,14 @@ impl Tool for ScheduleTool {
                     "type": "string",
                     "description": "Cron expression for recurring tasks (e.g. '*/5 * * * *')."
                 },
+                "when": {
+                    "type": "string",
+                    "description": "Plain-English schedule instead of expression/delay/run_at, e.g. 'every weekday at 9am', 'every 2 hours', 'first monday of the month at 10:00', 'tomorrow at 17:30'. Ambiguous phrases are rejected with suggestions."
+                },
+                "tz": {
+                    "type": "string",
+                    "description": "IANA timezone for 'expression' and 'when' (e.g. 'America/New_York'). Defaults to the runtime local timezone."
+                },
                 "delay": {
                     "type": "string",
                     "description": "Delay for one-shot tasks (e.g. '30m', '2h', '1d')."
@@ -82,6 +92,13 @@ impl Tool for ScheduleTool {
         })
     }
 
+    fn mutates(&self, args: &serde_json::Value) -> bool {
+        !matches!(
+            args.get("action").and_then(serde_json::Value::as_str),
+            Some("list" | "get")
+        )
+    }
+
     async fn execute(&self, args: serde_json::Value) -> Result<ToolResult> {
         let action = args
             .get("action")
@@ -333,46 +350,67 @@ impl ScheduleTool {
             })?;
 
         let expression = args.get("expression").and_then(|value| value.as_str());
+        let when = args.get("when").and_then(|value| value.as_str());
         let delay = args.get("delay").and_then(|value| value.as_str());
         let run_at = args.get("run_at").and_then(|value| value.as_str());
+        let tz = args
+            .get("tz")
+            .and_then(|value| value.as_str())
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
 
         match action {
             "add" => {
-                if expression.is_none() || delay.is_some() || run_at.is_some() {
+                if expression.is_some() == when.is_some() || delay.is_some() || run_at.is_some() {
                     return Ok(ToolResult {
                         success: false,
                         output: ToolOutput::default(),
-                        error: Some("'add' requires 'expression' and forbids delay/run_at".into()),
+                        error: Some(
+                            "'add' requires one of 'expression' or 'when' and forbids delay/run_at"
+                                .into(),
+                        ),
                     });
                 }
             }
             "once" => {
-                if expression.is_some() || (delay.is_none() && run_at.is_none()) {
+                if expression.is_some() || (delay.is_none() && run_at.is_none() && when.is_none()) {
                     return Ok(ToolResult {
                         success: false,
                         output: ToolOutput::default(),
-                        error: Some("'once' requires exactly one of 'delay' or 'run_at'".into()),
+                        error: Some(
+                            "'once' requires exactly one of 'delay', 'run_at' or 'when'".into(),
+                        ),
                     });
                 }
-                if delay.is_some() && run_at.is_some() {
+                if [delay.is_some(), run_at.is_some(), when.is_some()]
+                    .into_iter()
+                    .filter(|value| *value)
+                    .count()
+                    > 1
+                {
                     return Ok(ToolResult {
                         success: false,
                         output: ToolOutput::default(),
-                        error: Some("'once' supports either delay or run_at, not both".into()),
+                        error: Some("'once' supports only one of delay, run_at or when".into()),
                     });
                 }
             }
             _ => {
-                let count = [expression.is_some(), delay.is_some(), run_at.is_some()]
-                    .into_iter()
-                    .filter(|value| *value)
-                    .count();
+                let count = [
+                    expression.is_some(),
+                    when.is_some(),
+                    delay.is_some(),
+                    run_at.is_some(),
+                ]
+                .into_iter()
+                .filter(|value| *value)
+                .count();
                 if count != 1 {
                     return Ok(ToolResult {
                         success: false,
                         output: ToolOutput::default(),
                         error: Some(
-                            "Exactly one of 'expression', 'delay', or 'run_at' must be provided"
+                            "Exactly one of 'expression', 'when', 'delay', or 'run_at' must be provided"
                                 .into(),
                         ),
                     });
@@ -380,6 +418,43 @@ impl ScheduleTool {
             }
         }
 
+        // Parse natural-language schedules up front so an unreadable or
+        // ambiguous phrase is reported without consuming the action budget.
+        let natural = match when.map(|text| cron::parse_natural_schedule(text, tz, Utc::now())) {
+            Some(Ok(parsed)) => Some(parsed),
+            Some(Err(error)) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: ToolOutput::default(),
+                    error: Some(error.to_string()),
+                });
+            }
+            None => None,
+        };
+        if let Some(parsed) = &natural {
+            let one_shot = matches!(parsed.schedule, cron::Schedule::At { .. });
+            let mismatch = match action {
+                "add" if one_shot => {
+                    Some("'add' creates recurring jobs; use 'once' for a one-off time")
+                }
+                "once" if !one_shot => {
+                    Some("'once' needs a one-off time; use 'add' for a repeating schedule")
+                }
+                _ => None,
+            };
+            if let Some(message) = mismatch {
+                return Ok(ToolResult {
+                    success: false,
+                    output: ToolOutput::default(),
+                    error: Some(format!(
+                        "{message} ('{}' means {})",
+                        when.unwrap_or_default(),
+                        parsed.summary
+                    )),
+                });
+            }
+        }
+
         // Enforce rate-limiting AFTER command/args validation so that invalid
         // requests do not consume the action budget.
         if let Some(blocked) = self.enforce_mutation_allowed(action) {
@@ -388,6 +463,42 @@ impl ScheduleTool {
 
         // All job creation routes through validated cron helpers, which enforce
         // the full security policy (allowlist + risk gate) before persistence.
+        if let Some(parsed) = natural {
+            let one_shot = matches!(parsed.schedule, cron::Schedule::At { .. });
+            let job = match cron::add_shell_job_with_approval(
+                &self.config,
+                &self.agent_alias,
+                None,
+                parsed.schedule.clone(),
+                command,
+                None,
+                approved,
+            ) {
+                Ok(job) => job,
+                Err(error) => {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: ToolOutput::default(),
+                        error: Some(error.to_string()),
+                    });
+                }
+            };
+            return Ok(ToolResult {
+                success: true,
+                output: format!(
+                    "Created {} job {} (when: {}, spec: {}, next: {}, cmd: {})",
+                    if one_shot { "one-shot" } else { "recurring" },
+                    job.id,
+                    parsed.summary,
+                    parsed.spec(),
+                    job.next_run.to_rfc3339(),
+                    job.command
+                )
+                .into(),
+                error: None,
+            });
+        }
+
         if let Some(value) = expression {
             let job = match cron::add_shell_job_with_approval(
                 &self.config,
@@ -395,7 +506,7 @@ impl ScheduleTool {
                 None,
                 cron::Schedule::Cron {
                     expr: value.to_string(),
-                    tz: None,
+                    tz: tz.map(str::to_string),
                 },
                 command,
                 None,
@@ -841,6 +952,74 @@ mod tests {
         assert!(result.error.as_deref().unwrap().contains("Unknown action"));
     }
 
+    #[tokio::test]
+    async fn natural_when_creates_job_and_echoes_spec() {
+        let (_tmp, config, security) = test_setup().await;
+        let tool = ScheduleTool::new(security, config.clone(), TEST_AGENT);
+
+        let result = tool
+            .execute(json!({
+                "action": "add",
+                "when": "every weekday at 9am",
+                "tz": "America/New_York",
+                "command": "echo standup"
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("Created recurring job"));
+        assert!(
+            result
+                .output
+                .contains("cron '0 9 * * 1-5' (America/New_York)")
+        );
+
+        let jobs = cron::list_jobs(&config).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(
+            jobs[0].schedule,
+            cron::Schedule::Cron {
+                expr: "0 9 * * 1-5".into(),
+                tz: Some("America/New_York".into()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn natural_when_rejects_ambiguous_and_mismatched_phrases() {
+        let (_tmp, config, security) = test_setup().await;
+        let tool = ScheduleTool::new(security, config.clone(), TEST_AGENT);
+
+        let ambiguous = tool
+            .execute(json!({
+                "action": "create",
+                "when": "monday at 9am",
+                "command": "echo hi"
+            }))
+            .await
+            .unwrap();
+        assert!(!ambiguous.success);
+        assert!(ambiguous.error.unwrap().contains("every monday"));
+
+        let mismatched = tool
+            .execute(json!({
+                "action": "once",
+                "when": "every 2 hours",
+                "command": "echo hi"
+            }))
+            .await
+            .unwrap();
+        assert!(!mismatched.success);
+        assert!(
+            mismatched
+                .error
+                .unwrap()
+                .contains("'once' needs a one-off time")
+        );
+
+        assert!(cron::list_jobs(&config).unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn mutating_actions_fail_when_cron_disabled() {
         let tmp = TempDir::new().unwrap();
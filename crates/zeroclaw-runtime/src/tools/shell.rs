@@ -1,9 +1,11 @@
+use super::shell_session;
 use crate::platform::RuntimeAdapter;
 use crate::security::SecurityPolicy;
 use crate::security::traits::Sandbox;
 use async_trait::async_trait;
 use serde_json::json;
 use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 use zeroclaw_api::platform::is_android;
@@ -242,7 +244,7 @@ impl Tool for ShellTool {
     }
 
     fn description(&self) -> &str {
-        "Execute a shell command in the workspace directory"
+        "Execute a shell command in the workspace directory. The working directory (set with `cwd` or a bare `cd <dir>` command) and variables exported with `env` persist for the rest of the conversation; `reset` restores the defaults"
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
@@ -257,12 +259,39 @@ impl Tool for ShellTool {
                     "type": "boolean",
                     "description": "Set true to explicitly approve medium/high-risk commands in supervised mode",
                     "default": false
+                },
+                "cwd": {
+                    "type": "string",
+                    "description": "Change the session working directory (relative to the current one, inside the workspace) before running. Persists across calls"
+                },
+                "env": {
+                    "type": "object",
+                    "description": "Variables to export for this and later commands in the session; a null value unsets. Secret-looking names are refused",
+                    "additionalProperties": {"type": ["string", "null"]}
+                },
+                "reset": {
+                    "type": "boolean",
+                    "description": "Reset the session to the workspace root with no exported variables before running",
+                    "default": false
                 }
             },
             "required": ["command"]
         })
     }
 
+    /// Low-risk commands without output redirection (`ls`, `cat`, `git
+    /// status`) leave the system as it was; anything else is treated as a
+    /// mutation.
+    fn mutates(&self, args: &serde_json::Value) -> bool {
+        args.get("command")
+            .and_then(serde_json::Value::as_str)
+            .is_none_or(|command| {
+                command.contains('>')
+                    || self.security.command_risk_level(command)
+                        != crate::security::policy::CommandRiskLevel::Low
+            })
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let command = args
             .get("command")
@@ -283,9 +312,63 @@ impl Tool for ShellTool {
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
 
+        // Session state (cwd + exported vars) is applied before the command
+        // runs and persists even if the command then fails, as in a shell.
+        // Without a session key there is nothing to tie it to, so `cwd` and
+        // `env` then apply to this call only.
+        let session_key = get_session_id();
+        let mut session = session_key
+            .as_deref()
+            .map(shell_session::load)
+            .unwrap_or_default();
+        let mut notes = Vec::new();
+        if !shell_session::cwd_still_valid(&self.security, &session.cwd) {
+            session.cwd = PathBuf::new();
+            notes.push("previous working directory is gone; cwd is now .".to_string());
+        }
+        let mut command = command.trim();
+        let mut cwd_arg = args.get("cwd").and_then(|v| v.as_str());
+        if let Some(target) = bare_cd_target(command) {
+            cwd_arg = Some(target);
+            command = "";
+        }
+        if let Err(reason) =
+            apply_session_args(&self.security, &mut session, &args, cwd_arg, &mut notes)
+        {
+            return Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(reason),
+            });
+        }
+        if let Some(session_key) = &session_key {
+            shell_session::store(session_key, session.clone());
+        }
+
+        // An empty command with session arguments only updates the state.
+        if command.is_empty() && !notes.is_empty() {
+            return Ok(ToolResult {
+                success: true,
+                output: notes.join("\n").into(),
+                error: None,
+            });
+        }
+
         match self.security.validate_command_execution(command, approved) {
             Ok(_) => {}
             Err(reason) => {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                        .with_category(::zeroclaw_log::EventCategory::Tool)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "approved": approved,
+                            "risk": self.security.command_risk_verdict(command),
+                            "reason": reason,
+                        })),
+                    "shell command denied by policy"
+                );
                 return Ok(ToolResult {
                     success: false,
                     output: ToolOutput::default(),
@@ -297,9 +380,10 @@ impl Tool for ShellTool {
         // Execute with timeout to prevent hanging commands.
         // Clear the environment to prevent leaking API keys and other secrets
         // (CWE-200), then re-add only safe, functional variables.
+        let command = with_session_state(command, &session);
         let mut cmd = match self
             .runtime
-            .build_shell_command(command, &self.security.workspace_dir)
+            .build_shell_command(&command, &self.security.workspace_dir)
         {
             Ok(cmd) => cmd,
             Err(e) => {
@@ -347,6 +431,11 @@ impl Tool for ShellTool {
             }
         }
 
+        #[cfg(windows)]
+        for (k, v) in &session.env {
+            cmd.env(k, v);
+        }
+
         // Android: platform tools (sh, getprop, am, dumpsys, content, pm, ...)
         // live in /system/bin and /system/xbin. The cleared+rebuilt PATH above
         // may omit them, leaving the shell unable to resolve any platform tool.
@@ -452,10 +541,130 @@ impl Tool for ShellTool {
             }
         }
 
+        if !notes.is_empty() {
+            let notes = notes.join("\n");
+            if result.output.is_empty() {
+                result.output = notes.into();
+            } else {
+                result.output = format!("{notes}\n{}", result.output).into();
+            }
+        }
+
         Ok(result)
     }
 }
 
+/// The directory of a bare `cd` command (`cd`, `cd dir`, `cd "my dir"`), which
+/// changes the session's working directory instead of running a subshell
+/// whose `cd` would be lost. Compound commands are left to the shell.
+fn bare_cd_target(command: &str) -> Option<&str> {
+    if command == "cd" {
+        return Some("");
+    }
+    let target = command.strip_prefix("cd ")?.trim();
+    let unquoted = ['"', '\'']
+        .iter()
+        .find_map(|q| target.strip_prefix(*q)?.strip_suffix(*q))
+        .unwrap_or(target);
+    let has_shell_syntax = unquoted.chars().any(|c| {
+        matches!(
+            c,
+            ';' | '&' | '|' | '<' | '>' | '$' | '`' | '(' | ')' | '*' | '?' | '\n'
+        )
+    });
+    let has_space = unquoted.len() == target.len() && target.contains(char::is_whitespace);
+    (!has_shell_syntax && !has_space && !unquoted.starts_with('-')).then_some(unquoted)
+}
+
+/// Apply `reset`, `cwd` and `env` to the session state, recording a note for
+/// the model about each change. Nothing is applied if any part is invalid.
+fn apply_session_args(
+    security: &SecurityPolicy,
+    session: &mut shell_session::ShellSessionState,
+    args: &serde_json::Value,
+    cwd: Option<&str>,
+    notes: &mut Vec<String>,
+) -> Result<(), String> {
+    let mut next = session.clone();
+    if args.get("reset").and_then(|v| v.as_bool()).unwrap_or(false) {
+        next = shell_session::ShellSessionState::default();
+        notes.push("shell session reset: cwd is now ., no exported variables".into());
+    }
+    if let Some(target) = cwd {
+        next.cwd = shell_session::resolve_cwd(security, &next.cwd, target)?;
+        notes.push(format!("cwd is now {}", next.cwd_display()));
+    }
+    if let Some(env) = args.get("env") {
+        let env = env
+            .as_object()
+            .ok_or_else(|| "'env' must be an object of NAME: value".to_string())?;
+        let mut exported = Vec::new();
+        let mut unset = Vec::new();
+        for (name, value) in env {
+            shell_session::check_env_name(name)?;
+            match value {
+                serde_json::Value::Null => {
+                    next.env.remove(name);
+                    unset.push(name.as_str());
+                }
+                serde_json::Value::String(v) => {
+                    next.env.insert(name.clone(), v.clone());
+                    exported.push(name.as_str());
+                }
+                serde_json::Value::Number(_) | serde_json::Value::Bool(_) => {
+                    next.env.insert(name.clone(), value.to_string());
+                    exported.push(name.as_str());
+                }
+                _ => return Err(format!("Value for {name} must be a string or null")),
+            }
+        }
+        if next.env.len() > shell_session::MAX_SESSION_ENV_VARS {
+            return Err(format!(
+                "A session can export at most {} variables",
+                shell_session::MAX_SESSION_ENV_VARS
+            ));
+        }
+        if !exported.is_empty() {
+            notes.push(format!("exported {}", exported.join(", ")));
+        }
+        if !unset.is_empty() {
+            notes.push(format!("unset {}", unset.join(", ")));
+        }
+    }
+    *session = next;
+    Ok(())
+}
+
+/// Prefix `command` with the session's exported variables and a `cd` into
+/// its directory. Done in the command string rather than on the host process
+/// so both also apply inside container runtimes, where the command runs
+/// under `docker exec` with the container's own environment and root. The
+/// exports run after the safe-env snapshot and TUI env, so they win over both.
+///
+/// `cmd.exe` has no quoting that keeps an arbitrary value inert, so on
+/// Windows (native runtime only) the variables are set on the process.
+fn with_session_state(command: &str, session: &shell_session::ShellSessionState) -> String {
+    let mut prefix = String::new();
+    if !cfg!(windows) {
+        for (name, value) in &session.env {
+            prefix.push_str(&format!("export {name}={}; ", sh_quote(value)));
+        }
+    }
+    if !session.cwd.as_os_str().is_empty() {
+        let cwd = session.cwd.to_string_lossy();
+        if cfg!(windows) {
+            prefix.push_str(&format!("cd /d \"{cwd}\" && "));
+        } else {
+            prefix.push_str(&format!("cd {} && ", sh_quote(&cwd)));
+        }
+    }
+    prefix + command
+}
+
+fn sh_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
 struct DrainHandle {
     task: tokio::task::JoinHandle<()>,
     output: Arc<std::sync::Mutex<DrainOutput>>,
@@ -1145,6 +1354,174 @@ mod tests {
         ));
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn shell_session_cd_makes_relative_paths_resolve_from_new_cwd() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("repo/src")).unwrap();
+        std::fs::write(dir.path().join("repo/src/notes.txt"), "from-src").unwrap();
+        let security = Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::Supervised,
+            workspace_dir: dir.path().to_path_buf(),
+            allowed_commands: vec!["cat".into()],
+            ..SecurityPolicy::default()
+        });
+        let tool = ShellTool::new(security, test_runtime());
+
+        crate::agent::loop_::scope_session_key(Some("shell-test-cd".into()), async {
+            let cd = tool.execute(json!({"command": "cd repo"})).await.unwrap();
+            assert!(cd.success, "{:?}", cd.error);
+            assert!(cd.output.contains("cwd is now repo"));
+
+            let cat = tool
+                .execute(json!({"command": "cat notes.txt", "cwd": "src"}))
+                .await
+                .unwrap();
+            assert!(cat.success, "{:?}", cat.error);
+            assert!(cat.output.contains("cwd is now repo/src"));
+            assert!(cat.output.contains("from-src"));
+
+            // The directory sticks for later calls without restating it.
+            let again = tool
+                .execute(json!({"command": "cat notes.txt"}))
+                .await
+                .unwrap();
+            assert_eq!(again.output.trim(), "from-src");
+        })
+        .await;
+
+        // Another conversation starts at the workspace root.
+        let other = crate::agent::loop_::scope_session_key(
+            Some("shell-test-cd-other".into()),
+            tool.execute(json!({"command": "cat notes.txt"})),
+        )
+        .await
+        .unwrap();
+        assert!(!other.success);
+        shell_session::forget("shell-test-cd");
+    }
+
+    #[tokio::test]
+    async fn shell_session_cd_cannot_escape_the_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("ws/sub")).unwrap();
+        let security = Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::Supervised,
+            workspace_dir: dir.path().join("ws"),
+            ..SecurityPolicy::default()
+        });
+        let tool = ShellTool::new(security, test_runtime());
+
+        crate::agent::loop_::scope_session_key(Some("shell-test-escape".into()), async {
+            assert!(
+                tool.execute(json!({"command": "cd sub"}))
+                    .await
+                    .unwrap()
+                    .success
+            );
+            for target in ["../..", "..\\..", dir.path().to_str().unwrap()] {
+                let result = tool
+                    .execute(json!({"command": "", "cwd": target}))
+                    .await
+                    .unwrap();
+                assert!(!result.success, "cd {target} should be blocked");
+            }
+            assert_eq!(
+                shell_session::load("shell-test-escape").cwd,
+                std::path::Path::new("sub")
+            );
+        })
+        .await;
+        shell_session::forget("shell-test-escape");
+    }
+
+    #[tokio::test]
+    async fn shell_session_env_persists_until_reset() {
+        let tool = ShellTool::new(test_security_with_env_cmd(), test_runtime());
+
+        crate::agent::loop_::scope_session_key(Some("shell-test-env".into()), async {
+            let refused = tool
+                .execute(json!({"command": "", "env": {"GITHUB_TOKEN": "x"}}))
+                .await
+                .unwrap();
+            assert!(!refused.success);
+            assert!(refused.error.unwrap().contains("secret"));
+
+            let set = tool
+                .execute(json!({"command": "", "env": {"ZC_SESSION_FOO": "bar"}}))
+                .await
+                .unwrap();
+            assert!(set.success);
+            assert!(set.output.contains("exported ZC_SESSION_FOO"));
+
+            let env = tool
+                .execute(json!({"command": env_print_command()}))
+                .await
+                .unwrap();
+            assert!(env_output_contains_assignment(
+                &env.output,
+                "ZC_SESSION_FOO",
+                "bar"
+            ));
+
+            let reset = tool
+                .execute(json!({"command": env_print_command(), "reset": true}))
+                .await
+                .unwrap();
+            assert!(reset.output.contains("shell session reset"));
+            assert!(!env_output_contains_key(&reset.output, "ZC_SESSION_FOO"));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn shell_unscoped_calls_do_not_share_session_state() {
+        let tool = ShellTool::new(test_security_with_env_cmd(), test_runtime());
+
+        let first = tool
+            .execute(json!({"command": env_print_command(), "env": {"ZC_UNSCOPED_FOO": "bar"}}))
+            .await
+            .unwrap();
+        assert!(env_output_contains_assignment(
+            &first.output,
+            "ZC_UNSCOPED_FOO",
+            "bar"
+        ));
+
+        let second = tool
+            .execute(json!({"command": env_print_command()}))
+            .await
+            .unwrap();
+        assert!(!env_output_contains_key(&second.output, "ZC_UNSCOPED_FOO"));
+        assert_eq!(shell_session::load(""), Default::default());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn session_state_is_applied_in_the_command_string() {
+        let mut session = shell_session::ShellSessionState::default();
+        assert_eq!(with_session_state("ls", &session), "ls");
+
+        session.cwd = PathBuf::from("repo/it's");
+        session.env.insert("GREETING".into(), "a 'b' $c".into());
+        assert_eq!(
+            with_session_state("ls", &session),
+            "export GREETING='a '\\''b'\\'' $c'; cd 'repo/it'\\''s' && ls"
+        );
+    }
+
+    #[test]
+    fn bare_cd_target_only_matches_single_directory_commands() {
+        assert_eq!(bare_cd_target("cd"), Some(""));
+        assert_eq!(bare_cd_target("cd repo"), Some("repo"));
+        assert_eq!(bare_cd_target("cd \"my dir\""), Some("my dir"));
+        assert_eq!(bare_cd_target("cd repo && cargo test"), None);
+        assert_eq!(bare_cd_target("cd a b"), None);
+        assert_eq!(bare_cd_target("cd -"), None);
+        assert_eq!(bare_cd_target("cd $HOME"), None);
+        assert_eq!(bare_cd_target("cdrom"), None);
+    }
+
     #[test]
     fn invalid_shell_env_passthrough_names_are_filtered() {
         let security = SecurityPolicy {
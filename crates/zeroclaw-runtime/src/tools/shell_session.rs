@@ -0,0 +1,250 @@
+//! Per-conversation state for the `shell` tool: a working directory and a
+//! set of exported variables that carry over between calls in the same
+//! session, so "cd into the repo, set FOO, run tests" composes.
+//!
+//! State is keyed by the tool-loop session key (the channel
+//! `conversation_history_key`) and cleared with that conversation's history.
+
+use crate::security::SecurityPolicy;
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// Sessions kept in memory; least recently used state is dropped beyond
+/// this, matching the channel conversation history bound.
+const MAX_SHELL_SESSIONS: usize = 1000;
+/// Exported variables a single session may hold.
+pub(crate) const MAX_SESSION_ENV_VARS: usize = 64;
+
+/// Substrings that mark a variable name as secret-looking. Secrets must come
+/// from `shell_env_passthrough`, not be pinned into conversation state.
+const SECRET_NAME_MARKERS: &[&str] = &[
+    "KEY",
+    "TOKEN",
+    "SECRET",
+    "PASSWORD",
+    "PASSWD",
+    "CREDENTIAL",
+    "AUTH",
+    "PRIVATE",
+    "COOKIE",
+];
+
+/// Variables that change how commands are resolved or how the shell itself
+/// behaves; setting them would sidestep the command allowlist.
+const RESERVED_NAMES: &[&str] = &[
+    "PATH",
+    "PATHEXT",
+    "BASH_ENV",
+    "ENV",
+    "IFS",
+    "SHELLOPTS",
+    "BASHOPTS",
+    "PROMPT_COMMAND",
+    "PS4",
+    "COMSPEC",
+];
+const RESERVED_PREFIXES: &[&str] = &["LD_", "DYLD_", "ZEROCLAW_"];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ShellSessionState {
+    /// Working directory relative to the workspace root; empty is the root.
+    pub cwd: PathBuf,
+    pub env: BTreeMap<String, String>,
+}
+
+impl ShellSessionState {
+    /// Working directory as shown to the model.
+    pub fn cwd_display(&self) -> String {
+        if self.cwd.as_os_str().is_empty() {
+            ".".into()
+        } else {
+            self.cwd.display().to_string()
+        }
+    }
+}
+
+static SESSIONS: LazyLock<Mutex<lru::LruCache<String, ShellSessionState>>> = LazyLock::new(|| {
+    Mutex::new(lru::LruCache::new(
+        NonZeroUsize::new(MAX_SHELL_SESSIONS).expect("session bound is non-zero"),
+    ))
+});
+
+/// Current state for `session_key`, or the default (workspace root, no
+/// exported variables).
+pub(crate) fn load(session_key: &str) -> ShellSessionState {
+    SESSIONS
+        .lock()
+        .get(session_key)
+        .cloned()
+        .unwrap_or_default()
+}
+
+pub(crate) fn store(session_key: &str, state: ShellSessionState) {
+    let mut sessions = SESSIONS.lock();
+    if state == ShellSessionState::default() {
+        sessions.pop(session_key);
+    } else {
+        sessions.put(session_key.to_string(), state);
+    }
+}
+
+/// Drop the shell state for a conversation. Called when its history is
+/// cleared (`/new`, fresh-session turns).
+pub fn forget(session_key: &str) {
+    SESSIONS.lock().pop(session_key);
+}
+
+fn canonical_workspace(security: &SecurityPolicy) -> PathBuf {
+    security
+        .workspace_dir
+        .canonicalize()
+        .unwrap_or_else(|_| security.workspace_dir.clone())
+}
+
+/// Resolve `target` against the session's current directory and return the
+/// new directory relative to the workspace root. The result must be an
+/// existing directory inside the workspace; `~` or an empty target returns
+/// to the root.
+pub(crate) fn resolve_cwd(
+    security: &SecurityPolicy,
+    current: &Path,
+    target: &str,
+) -> Result<PathBuf, String> {
+    let target = target.trim();
+    if target.is_empty() || target == "~" {
+        return Ok(PathBuf::new());
+    }
+    if target.contains('\0') {
+        return Err(format!("Invalid directory: {target}"));
+    }
+    let workspace = canonical_workspace(security);
+    let requested = Path::new(target);
+    let joined = if requested.is_absolute() {
+        requested.to_path_buf()
+    } else {
+        workspace.join(current).join(requested)
+    };
+    let resolved = joined
+        .canonicalize()
+        .map_err(|_| format!("No such directory: {target}"))?;
+    if !resolved.is_dir() {
+        return Err(format!("Not a directory: {target}"));
+    }
+    if !security.is_resolved_path_allowed(&resolved) {
+        return Err(format!("Directory is outside the allowed paths: {target}"));
+    }
+    resolved
+        .strip_prefix(&workspace)
+        .map(Path::to_path_buf)
+        .map_err(|_| format!("Working directory must stay inside the workspace: {target}"))
+}
+
+/// Whether the stored directory still exists inside the workspace. A
+/// directory removed or replaced by a symlink since the last call fails.
+pub(crate) fn cwd_still_valid(security: &SecurityPolicy, cwd: &Path) -> bool {
+    cwd.as_os_str().is_empty()
+        || resolve_cwd(security, Path::new(""), &cwd.to_string_lossy()).as_deref() == Ok(cwd)
+}
+
+/// Reject names that are not plain identifiers, look like secrets, or are
+/// reserved.
+pub(crate) fn check_env_name(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let valid = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(format!("Invalid environment variable name: {name}"));
+    }
+    let upper = name.to_ascii_uppercase();
+    if RESERVED_NAMES.contains(&upper.as_str())
+        || RESERVED_PREFIXES.iter().any(|p| upper.starts_with(p))
+    {
+        return Err(format!(
+            "Environment variable {name} cannot be set from the shell tool"
+        ));
+    }
+    if SECRET_NAME_MARKERS.iter().any(|m| upper.contains(m)) {
+        return Err(format!(
+            "Environment variable {name} looks like a secret; use shell_env_passthrough in the config instead"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(workspace: &Path) -> SecurityPolicy {
+        SecurityPolicy {
+            workspace_dir: workspace.to_path_buf(),
+            ..SecurityPolicy::default()
+        }
+    }
+
+    #[test]
+    fn resolve_cwd_follows_relative_steps_inside_the_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("repo/src")).unwrap();
+        let security = policy(dir.path());
+
+        let repo = resolve_cwd(&security, Path::new(""), "repo").unwrap();
+        assert_eq!(repo, Path::new("repo"));
+        let src = resolve_cwd(&security, &repo, "src").unwrap();
+        assert_eq!(src, Path::new("repo/src"));
+        assert_eq!(resolve_cwd(&security, &src, "..").unwrap(), repo);
+        assert_eq!(resolve_cwd(&security, &src, "~").unwrap(), PathBuf::new());
+        assert!(resolve_cwd(&security, &repo, "missing").is_err());
+    }
+
+    #[test]
+    fn resolve_cwd_blocks_escapes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("ws/sub")).unwrap();
+        let security = policy(&dir.path().join("ws"));
+
+        assert!(resolve_cwd(&security, Path::new(""), "..").is_err());
+        assert!(resolve_cwd(&security, Path::new("sub"), "../..").is_err());
+        assert!(resolve_cwd(&security, Path::new(""), &dir.path().to_string_lossy()).is_err());
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(dir.path(), dir.path().join("ws/out")).unwrap();
+            assert!(resolve_cwd(&security, Path::new(""), "out").is_err());
+        }
+    }
+
+    #[test]
+    fn env_names_reject_secrets_and_reserved_variables() {
+        assert!(check_env_name("RUST_LOG").is_ok());
+        assert!(check_env_name("FOO_1").is_ok());
+        for name in [
+            "OPENAI_API_KEY",
+            "github_token",
+            "DB_PASSWORD",
+            "PATH",
+            "LD_PRELOAD",
+            "ZEROCLAW_SESSION_ID",
+            "1BAD",
+            "A-B",
+        ] {
+            assert!(check_env_name(name).is_err(), "{name} should be rejected");
+        }
+    }
+
+    #[test]
+    fn default_state_is_not_stored() {
+        let key = "shell-session-test-default";
+        let mut state = ShellSessionState::default();
+        state.env.insert("FOO".into(), "1".into());
+        store(key, state.clone());
+        assert_eq!(load(key), state);
+        store(key, ShellSessionState::default());
+        assert!(SESSIONS.lock().peek(key).is_none());
+        store(key, state);
+        forget(key);
+        assert_eq!(load(key), ShellSessionState::default());
+    }
+}
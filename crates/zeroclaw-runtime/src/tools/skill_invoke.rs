@@ -0,0 +1,355 @@
+use crate::skills::{Skill, SkillParameter};
+use async_trait::async_trait;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
+use zeroclaw_config::schema::Config;
+
+/// Run a parameterized skill: validate the caller's arguments against the
+/// skill's declared `parameters` and return its instructions with each
+/// `{{name}}` placeholder filled in, for the model to follow next.
+pub struct SkillInvokeTool {
+    config: Arc<Config>,
+    agent_alias: String,
+}
+
+impl SkillInvokeTool {
+    pub fn new(config: Arc<Config>, agent_alias: String) -> Self {
+        Self {
+            config,
+            agent_alias,
+        }
+    }
+}
+
+fn failure(error: String) -> ToolResult {
+    ToolResult {
+        success: false,
+        output: ToolOutput::default(),
+        error: Some(error),
+    }
+}
+
+/// Check `args` against the skill's declared parameters and render each
+/// supplied value as the text that replaces its placeholder. Integers,
+/// numbers and booleans may also be passed as strings that parse as such.
+fn validate_args(
+    skill: &Skill,
+    args: &Map<String, Value>,
+) -> Result<BTreeMap<String, String>, String> {
+    if let Some(unknown) = args
+        .keys()
+        .find(|key| !skill.parameters.iter().any(|p| &p.name == *key))
+    {
+        let accepted: Vec<&str> = skill.parameters.iter().map(|p| p.name.as_str()).collect();
+        let accepted = if accepted.is_empty() {
+            "none".to_string()
+        } else {
+            accepted.join(", ")
+        };
+        return Err(format!(
+            "Unknown argument '{unknown}' for skill '{}'. Accepted arguments: {accepted}",
+            skill.name
+        ));
+    }
+
+    let mut values = BTreeMap::new();
+    for param in &skill.parameters {
+        match args.get(&param.name).filter(|v| !v.is_null()) {
+            Some(value) => {
+                let rendered = coerce(param, value).ok_or_else(|| {
+                    format!(
+                        "Argument '{}' for skill '{}' must be of type {}, got {value}",
+                        param.name, skill.name, param.kind
+                    )
+                })?;
+                values.insert(param.name.clone(), rendered);
+            }
+            None if param.required => {
+                return Err(format!(
+                    "Missing required argument '{}' for skill '{}'",
+                    param.name, skill.name
+                ));
+            }
+            None => {}
+        }
+    }
+    Ok(values)
+}
+
+fn coerce(param: &SkillParameter, value: &Value) -> Option<String> {
+    let text = value.as_str().map(str::trim);
+    match param.kind.as_str() {
+        "integer" => value
+            .as_i64()
+            .or_else(|| text.and_then(|s| s.parse().ok()))
+            .map(|n| n.to_string()),
+        "number" => {
+            let n: f64 = value
+                .as_f64()
+                .or_else(|| text.and_then(|s| s.parse().ok()))?;
+            n.is_finite()
+                .then(|| text.map_or_else(|| value.to_string(), str::to_string))
+        }
+        "boolean" => value
+            .as_bool()
+            .or_else(|| text.and_then(|s| s.to_ascii_lowercase().parse().ok()))
+            .map(|b| b.to_string()),
+        "string" => value.as_str().map(str::to_string),
+        // Undeclared or unknown types are not checked.
+        _ => Some(text.map_or_else(|| value.to_string(), str::to_string)),
+    }
+}
+
+/// Replace `{{name}}` (inner whitespace allowed) for every declared
+/// parameter. Omitted optional parameters render as empty text; placeholders
+/// that name no parameter are left as written.
+fn render_template(body: &str, skill: &Skill, values: &BTreeMap<String, String>) -> String {
+    let mut out = String::with_capacity(body.len());
+    let mut rest = body;
+    while let Some(open) = rest.find("{{") {
+        let Some(close) = rest[open + 2..].find("}}") else {
+            break;
+        };
+        let key = rest[open + 2..open + 2 + close].trim();
+        out.push_str(&rest[..open]);
+        if skill.parameters.iter().any(|p| p.name == key) {
+            out.push_str(values.get(key).map_or("", String::as_str));
+        } else {
+            out.push_str(&rest[open..open + 2 + close + 2]);
+        }
+        rest = &rest[open + 2 + close + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[async_trait]
+impl Tool for SkillInvokeTool {
+    fn name(&self) -> &str {
+        "skill_invoke"
+    }
+
+    fn description(&self) -> &str {
+        "Run an available skill with arguments. Validates args against the skill's declared <parameters> and returns its instructions with each {{name}} filled in; follow the returned instructions as your next step."
+    }
+
+    fn parameters_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "skill": {
+                    "type": "string",
+                    "description": "The skill name exactly as listed in <available_skills>."
+                },
+                "args": {
+                    "type": "object",
+                    "description": "Argument values keyed by parameter name."
+                }
+            },
+            "required": ["skill"]
+        })
+    }
+
+    async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let requested = args
+            .get("skill")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"param": "skill"})),
+                    "tool argument validation failed"
+                );
+
+                anyhow::Error::msg("Missing 'skill' parameter")
+            })?;
+        let empty = Map::new();
+        let skill_args = match args.get("args") {
+            None | Some(Value::Null) => &empty,
+            Some(Value::Object(map)) => map,
+            Some(_) => return Ok(failure("'args' must be an object".to_string())),
+        };
+
+        // Resolve from config on each call, like `read_skill`, so the prompt
+        // and the invoked skill cannot drift.
+        let skills =
+            crate::skills::load_skills_for_agent_from_config(&self.config, &self.agent_alias);
+        let Some(skill) = skills
+            .iter()
+            .find(|skill| skill.name.eq_ignore_ascii_case(requested))
+        else {
+            let mut names: Vec<&str> = skills.iter().map(|skill| skill.name.as_str()).collect();
+            names.sort_unstable();
+            let available = if names.is_empty() {
+                "none".to_string()
+            } else {
+                names.join(", ")
+            };
+            return Ok(failure(format!(
+                "Unknown skill '{requested}'. Available skills: {available}"
+            )));
+        };
+
+        let values = match validate_args(skill, skill_args) {
+            Ok(values) => values,
+            Err(error) => {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({"skill": skill.name})),
+                    "skill_invoke: argument validation failed"
+                );
+                return Ok(failure(error));
+            }
+        };
+
+        if skill.prompts.iter().all(|p| p.trim().is_empty()) {
+            return Ok(failure(format!(
+                "Skill '{}' has no instructions to invoke.",
+                skill.name
+            )));
+        }
+        let rendered = render_template(&skill.prompts.join("\n\n"), skill, &values);
+        Ok(ToolResult {
+            success: true,
+            output: format!(
+                "Invoked skill '{}'. Follow these instructions as your next step:\n\n{}",
+                skill.name,
+                rendered.trim()
+            )
+            .into(),
+            error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use zeroclaw_config::schema::SkillsConfig;
+
+    const DEPLOY_SKILL: &str = "---
+name: deploy
+description: Deploy the service.
+parameters:
+  - name: env
+    type: string
+    required: true
+  - name: replicas
+    type: integer
+  - name: dry_run
+    type: boolean
+---
+# Deploy
+
+Deploy to {{env}} with {{ replicas }} replicas (dry run: {{dry_run}}). Keep {{unrelated}}.
+";
+
+    fn make_tool(tmp: &TempDir) -> SkillInvokeTool {
+        let config = Config {
+            config_path: tmp.path().join("config.toml"),
+            data_dir: tmp.path().join("data"),
+            skills: SkillsConfig {
+                open_skills_enabled: false,
+                allow_scripts: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let skill_dir = config.agent_workspace_dir("default").join("skills/deploy");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), DEPLOY_SKILL).unwrap();
+        SkillInvokeTool::new(Arc::new(config), "default".to_string())
+    }
+
+    #[tokio::test]
+    async fn renders_arguments_into_skill_instructions() {
+        let tmp = TempDir::new().unwrap();
+        let result = make_tool(&tmp)
+            .execute(json!({
+                "skill": "deploy",
+                "args": { "env": "staging", "replicas": "3", "dry_run": true }
+            }))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("Invoked skill 'deploy'"));
+        assert!(
+            result
+                .output
+                .contains("Deploy to staging with 3 replicas (dry run: true). Keep {{unrelated}}.")
+        );
+    }
+
+    #[tokio::test]
+    async fn omitted_optional_arguments_render_empty() {
+        let tmp = TempDir::new().unwrap();
+        let result = make_tool(&tmp)
+            .execute(json!({ "skill": "deploy", "args": { "env": "prod" } }))
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert!(
+            result
+                .output
+                .contains("Deploy to prod with  replicas (dry run: ).")
+        );
+    }
+
+    #[tokio::test]
+    async fn validation_errors_are_tool_errors() {
+        let tmp = TempDir::new().unwrap();
+        let tool = make_tool(&tmp);
+
+        let missing = tool.execute(json!({ "skill": "deploy" })).await.unwrap();
+        assert!(!missing.success);
+        assert_eq!(
+            missing.error.as_deref(),
+            Some("Missing required argument 'env' for skill 'deploy'")
+        );
+
+        let wrong_type = tool
+            .execute(json!({ "skill": "deploy", "args": { "env": "prod", "replicas": "many" } }))
+            .await
+            .unwrap();
+        assert!(!wrong_type.success);
+        assert_eq!(
+            wrong_type.error.as_deref(),
+            Some("Argument 'replicas' for skill 'deploy' must be of type integer, got \"many\"")
+        );
+
+        let not_string = tool
+            .execute(json!({ "skill": "deploy", "args": { "env": 5 } }))
+            .await
+            .unwrap();
+        assert!(!not_string.success);
+
+        let unknown = tool
+            .execute(json!({ "skill": "deploy", "args": { "env": "prod", "region": "eu" } }))
+            .await
+            .unwrap();
+        assert!(!unknown.success);
+        assert!(
+            unknown
+                .error
+                .unwrap()
+                .contains("Accepted arguments: env, replicas, dry_run")
+        );
+
+        let unknown_skill = tool.execute(json!({ "skill": "rollback" })).await.unwrap();
+        assert_eq!(
+            unknown_skill.error.as_deref(),
+            Some("Unknown skill 'rollback'. Available skills: deploy")
+        );
+    }
+}
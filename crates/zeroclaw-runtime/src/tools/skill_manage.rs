@@ -81,6 +81,10 @@ impl Tool for SkillsListTool {
         json!({ "type": "object", "properties": {}, "additionalProperties": false })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, _args: Value) -> Result<ToolResult> {
         let root = skills_root(&self.workspace_dir);
         let entries = match list_skill_entries(&root).await {
@@ -192,6 +196,10 @@ impl Tool for SkillViewTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: Value) -> Result<ToolResult> {
         let slug = args
             .get("slug")
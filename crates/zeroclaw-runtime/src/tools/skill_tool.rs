@@ -1096,6 +1096,7 @@ mod tests {
             }],
             prompts: vec![],
             slash_options: Vec::new(),
+            parameters: Vec::new(),
             location: None,
         };
         crate::tools::register_skill_tools_with_context(
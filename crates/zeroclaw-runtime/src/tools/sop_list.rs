@@ -40,6 +40,10 @@ impl Tool for SopListTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let filter = args.get("filter").and_then(|v| v.as_str()).unwrap_or("");
         let filter_lower = filter.to_lowercase();
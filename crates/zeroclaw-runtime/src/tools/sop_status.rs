@@ -70,6 +70,10 @@ impl Tool for SopStatusTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let run_id = args.get("run_id").and_then(|v| v.as_str());
         let sop_name = args.get("sop_name").and_then(|v| v.as_str());
@@ -189,6 +189,7 @@ impl Tool for SpawnSubagentTool {
             // the correct choice. The daemon heartbeat worker is the
             // only `mcp_registry` supplier.
             mcp_registry: None,
+            cli_session: None,
         };
         let parent_alias = subagent_ctx.parent_alias.clone();
 
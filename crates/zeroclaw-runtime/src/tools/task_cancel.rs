@@ -0,0 +1,172 @@
+use crate::security::SecurityPolicy;
+use crate::tasks;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
+use zeroclaw_config::schema::Config;
+
+pub struct TaskCancelTool {
+    config: Arc<Config>,
+    security: Arc<SecurityPolicy>,
+    /// Owning agent — only this agent's tasks can be cancelled.
+    agent_alias: String,
+}
+
+impl TaskCancelTool {
+    pub fn new(
+        config: Arc<Config>,
+        security: Arc<SecurityPolicy>,
+        agent_alias: impl Into<String>,
+    ) -> Self {
+        Self {
+            config,
+            security,
+            agent_alias: agent_alias.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for TaskCancelTool {
+    fn name(&self) -> &str {
+        "task_cancel"
+    }
+
+    fn description(&self) -> &str {
+        "Cancel a queued or running background task by id"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "task_id": {
+                    "type": "string",
+                    "description": "ID returned by task_spawn"
+                }
+            },
+            "required": ["task_id"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        if !self.config.tasks.enabled {
+            return Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some("background tasks are disabled by config (tasks.enabled=false)".into()),
+            });
+        }
+
+        let task_id = match args.get("task_id").and_then(serde_json::Value::as_str) {
+            Some(v) if !v.trim().is_empty() => v.trim(),
+            _ => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: ToolOutput::default(),
+                    error: Some("Missing 'task_id' parameter".to_string()),
+                });
+            }
+        };
+
+        if !self.security.can_act() {
+            return Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(
+                    "Security policy: read-only mode, cannot perform 'task_cancel'".to_string(),
+                ),
+            });
+        }
+
+        let owned = tasks::get_task(&self.config, task_id)
+            .is_ok_and(|task| task.agent_alias == self.agent_alias);
+        if !owned {
+            return Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(format!("Background task '{task_id}' not found")),
+            });
+        }
+
+        match tasks::request_cancel(&self.config, task_id) {
+            Ok(task) => Ok(ToolResult {
+                success: true,
+                output: if task.status == tasks::TaskStatus::Running {
+                    format!("Cancellation requested for running task {task_id}")
+                } else {
+                    format!("Cancelled task {task_id}")
+                }
+                .into(),
+                error: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tasks::NewTask;
+    use tempfile::TempDir;
+
+    async fn test_config(tmp: &TempDir) -> Arc<Config> {
+        let config = Config {
+            data_dir: tmp.path().join("data"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        tokio::fs::create_dir_all(&config.data_dir).await.unwrap();
+        Arc::new(config)
+    }
+
+    fn enqueue(cfg: &Config, agent: &str) -> tasks::BackgroundTask {
+        tasks::enqueue_task(
+            cfg,
+            NewTask {
+                agent_alias: agent.into(),
+                description: "crawl docs".into(),
+                prompt: "crawl".into(),
+                channel: None,
+                reply_target: None,
+            },
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn cancels_queued_task() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = test_config(&tmp).await;
+        let task = enqueue(&cfg, "alpha");
+        let tool = TaskCancelTool::new(cfg.clone(), Arc::new(SecurityPolicy::default()), "alpha");
+
+        let result = tool.execute(json!({"task_id": task.id})).await.unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(
+            tasks::get_task(&cfg, &task.id).unwrap().status,
+            tasks::TaskStatus::Cancelled
+        );
+    }
+
+    #[tokio::test]
+    async fn refuses_other_agents_task() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = test_config(&tmp).await;
+        let task = enqueue(&cfg, "alpha");
+        let tool = TaskCancelTool::new(cfg.clone(), Arc::new(SecurityPolicy::default()), "beta");
+
+        let result = tool.execute(json!({"task_id": task.id})).await.unwrap();
+        assert!(!result.success);
+        assert_eq!(
+            tasks::get_task(&cfg, &task.id).unwrap().status,
+            tasks::TaskStatus::Queued
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use crate::security::SecurityPolicy;
+use crate::tasks::{self, NewTask};
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
+use zeroclaw_config::schema::Config;
+
+pub struct TaskSpawnTool {
+    config: Arc<Config>,
+    security: Arc<SecurityPolicy>,
+    /// Owning agent — the task runs as this agent.
+    agent_alias: String,
+}
+
+impl TaskSpawnTool {
+    pub fn new(
+        config: Arc<Config>,
+        security: Arc<SecurityPolicy>,
+        agent_alias: impl Into<String>,
+    ) -> Self {
+        Self {
+            config,
+            security,
+            agent_alias: agent_alias.into(),
+        }
+    }
+
+    fn enforce_mutation_allowed(&self, action: &str) -> Option<ToolResult> {
+        if !self.security.can_act() {
+            return Some(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(format!(
+                    "Security policy: read-only mode, cannot perform '{action}'"
+                )),
+            });
+        }
+
+        if self.security.is_rate_limited() {
+            return Some(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some("Rate limit exceeded: too many actions in the last hour".to_string()),
+            });
+        }
+
+        if !self.security.record_action() {
+            return Some(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some("Rate limit exceeded: action budget exhausted".to_string()),
+            });
+        }
+
+        None
+    }
+}
+
+fn optional_str(args: &serde_json::Value, key: &str) -> Option<String> {
+    args.get(key)
+        .and_then(serde_json::Value::as_str)
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(ToString::to_string)
+}
+
+#[async_trait]
+impl Tool for TaskSpawnTool {
+    fn name(&self) -> &str {
+        "task_spawn"
+    }
+
+    fn description(&self) -> &str {
+        "Run a long task (crawling, large refactors, multi-step research) in the background \
+         instead of inside this turn. Returns a task id immediately; progress and the final \
+         result are posted back to this conversation. Use task_status to check on it and \
+         task_cancel to stop it."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "description": {
+                    "type": "string",
+                    "description": "Short summary shown in progress and completion messages"
+                },
+                "prompt": {
+                    "type": "string",
+                    "description": "Full instructions for the background agent run. It does not see this conversation, so include all needed context."
+                },
+                "channel": {
+                    "type": "string",
+                    "description": "Channel to report progress to. Filled in automatically when spawned from a channel conversation."
+                },
+                "reply_target": {
+                    "type": "string",
+                    "description": "Chat or user id on that channel. Filled in automatically when spawned from a channel conversation."
+                }
+            },
+            "required": ["description", "prompt"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        if !self.config.tasks.enabled {
+            return Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some("background tasks are disabled by config (tasks.enabled=false)".into()),
+            });
+        }
+
+        let (Some(description), Some(prompt)) = (
+            optional_str(&args, "description"),
+            optional_str(&args, "prompt"),
+        ) else {
+            return Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some("Missing 'description' or 'prompt' parameter".to_string()),
+            });
+        };
+
+        if let Some(blocked) = self.enforce_mutation_allowed("task_spawn") {
+            return Ok(blocked);
+        }
+
+        let new_task = NewTask {
+            agent_alias: self.agent_alias.clone(),
+            description,
+            prompt,
+            channel: optional_str(&args, "channel"),
+            reply_target: optional_str(&args, "reply_target"),
+        };
+
+        match tasks::enqueue_task(&self.config, new_task) {
+            Ok(task) => Ok(ToolResult {
+                success: true,
+                output: serde_json::to_string_pretty(&json!({
+                    "id": task.id,
+                    "status": task.status,
+                    "description": task.description,
+                    "reports_to": task.channel,
+                }))?
+                .into(),
+                error: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const TEST_AGENT: &str = "test-agent";
+
+    async fn test_config(tmp: &TempDir) -> Arc<Config> {
+        let mut config = Config {
+            data_dir: tmp.path().join("data"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        seed_test_agent(&mut config);
+        tokio::fs::create_dir_all(&config.data_dir).await.unwrap();
+        Arc::new(config)
+    }
+
+    fn seed_test_agent(config: &mut Config) {
+        config
+            .risk_profiles
+            .entry(TEST_AGENT.to_string())
+            .or_default();
+        config
+            .runtime_profiles
+            .entry(TEST_AGENT.to_string())
+            .or_default();
+        config
+            .providers
+            .models
+            .ensure("openrouter", TEST_AGENT)
+            .expect("known family");
+        config.agents.entry(TEST_AGENT.to_string()).or_insert(
+            zeroclaw_config::schema::AliasedAgentConfig {
+                model_provider: format!("openrouter.{TEST_AGENT}").into(),
+                risk_profile: TEST_AGENT.into(),
+                runtime_profile: TEST_AGENT.into(),
+                ..Default::default()
+            },
+        );
+    }
+
+    fn test_security(cfg: &Config) -> Arc<SecurityPolicy> {
+        Arc::new(
+            SecurityPolicy::for_agent(cfg, TEST_AGENT).expect("test-agent has resolvable profiles"),
+        )
+    }
+
+    #[tokio::test]
+    async fn enqueues_task_with_reply_context() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = test_config(&tmp).await;
+        let tool = TaskSpawnTool::new(cfg.clone(), test_security(&cfg), TEST_AGENT);
+
+        let result = tool
+            .execute(json!({
+                "description": "crawl docs",
+                "prompt": "crawl the docs site and summarize it",
+                "channel": "telegram",
+                "reply_target": "42"
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+
+        let queued = tasks::list_tasks(&cfg, Some(TEST_AGENT), 10).unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].status, tasks::TaskStatus::Queued);
+        assert_eq!(queued[0].channel.as_deref(), Some("telegram"));
+        assert_eq!(queued[0].reply_target.as_deref(), Some("42"));
+    }
+
+    #[tokio::test]
+    async fn rejects_when_tasks_disabled() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = (*test_config(&tmp).await).clone();
+        config.tasks.enabled = false;
+        let cfg = Arc::new(config);
+        let tool = TaskSpawnTool::new(cfg.clone(), test_security(&cfg), TEST_AGENT);
+
+        let result = tool
+            .execute(json!({"description": "x", "prompt": "y"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("disabled"));
+    }
+
+    #[tokio::test]
+    async fn blocks_readonly_mode() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = (*test_config(&tmp).await).clone();
+        config
+            .risk_profiles
+            .entry(TEST_AGENT.into())
+            .or_default()
+            .level = crate::security::AutonomyLevel::ReadOnly;
+        let cfg = Arc::new(config);
+        let tool = TaskSpawnTool::new(cfg.clone(), test_security(&cfg), TEST_AGENT);
+
+        let result = tool
+            .execute(json!({"description": "x", "prompt": "y"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("read-only"));
+        assert!(tasks::list_tasks(&cfg, None, 10).unwrap().is_empty());
+    }
+}
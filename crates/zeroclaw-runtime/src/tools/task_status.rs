@@ -0,0 +1,154 @@
+use crate::tasks;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
+use zeroclaw_config::schema::Config;
+
+const DEFAULT_LIST_LIMIT: usize = 10;
+
+pub struct TaskStatusTool {
+    config: Arc<Config>,
+    /// Owning agent — only this agent's tasks are visible.
+    agent_alias: String,
+}
+
+impl TaskStatusTool {
+    pub fn new(config: Arc<Config>, agent_alias: impl Into<String>) -> Self {
+        Self {
+            config,
+            agent_alias: agent_alias.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for TaskStatusTool {
+    fn name(&self) -> &str {
+        "task_status"
+    }
+
+    fn description(&self) -> &str {
+        "Show the status and output of a background task started with task_spawn. \
+         Without task_id, lists this agent's most recent tasks."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "task_id": {
+                    "type": "string",
+                    "description": "ID returned by task_spawn. Omit to list recent tasks."
+                }
+            }
+        })
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        if !self.config.tasks.enabled {
+            return Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some("background tasks are disabled by config (tasks.enabled=false)".into()),
+            });
+        }
+
+        let task_id = args
+            .get("task_id")
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .filter(|v| !v.is_empty());
+
+        let result = match task_id {
+            Some(id) => tasks::get_task(&self.config, id)
+                .and_then(|task| {
+                    if task.agent_alias == self.agent_alias {
+                        Ok(task)
+                    } else {
+                        anyhow::bail!("Background task '{id}' not found")
+                    }
+                })
+                .and_then(|task| Ok(serde_json::to_string_pretty(&task)?)),
+            None => tasks::list_tasks(&self.config, Some(&self.agent_alias), DEFAULT_LIST_LIMIT)
+                .and_then(|list| Ok(serde_json::to_string_pretty(&list)?)),
+        };
+
+        match result {
+            Ok(output) => Ok(ToolResult {
+                success: true,
+                output: output.into(),
+                error: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tasks::NewTask;
+    use tempfile::TempDir;
+
+    async fn test_config(tmp: &TempDir) -> Arc<Config> {
+        let config = Config {
+            data_dir: tmp.path().join("data"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        tokio::fs::create_dir_all(&config.data_dir).await.unwrap();
+        Arc::new(config)
+    }
+
+    fn enqueue(cfg: &Config, agent: &str) -> tasks::BackgroundTask {
+        tasks::enqueue_task(
+            cfg,
+            NewTask {
+                agent_alias: agent.into(),
+                description: "crawl docs".into(),
+                prompt: "crawl".into(),
+                channel: None,
+                reply_target: None,
+            },
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn shows_own_task_by_id() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = test_config(&tmp).await;
+        let task = enqueue(&cfg, "alpha");
+        let tool = TaskStatusTool::new(cfg, "alpha");
+
+        let result = tool.execute(json!({"task_id": task.id})).await.unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("\"status\": \"queued\""));
+    }
+
+    #[tokio::test]
+    async fn hides_other_agents_tasks() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = test_config(&tmp).await;
+        let task = enqueue(&cfg, "alpha");
+        enqueue(&cfg, "beta");
+        let tool = TaskStatusTool::new(cfg, "beta");
+
+        let result = tool.execute(json!({"task_id": task.id})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("not found"));
+
+        let listed = tool.execute(json!({})).await.unwrap();
+        assert!(listed.success);
+        assert!(!listed.output.contains(&task.id));
+    }
+}
@@ -26,11 +26,25 @@ fn extract_tunnel_url(line: &str) -> Option<String> {
     }
 }
 
+/// Origin `cloudflared` forwards to. Wildcard binds (`0.0.0.0`, `::`, the
+/// usual Docker setup) are reached over loopback since `cloudflared` runs as
+/// our child in the same network namespace; a TLS gateway is addressed over
+/// `https` and needs `--no-tls-verify` for its (typically self-signed) cert.
+fn origin_url(local_host: &str, local_port: u16, tls: bool) -> String {
+    let scheme = if tls { "https" } else { "http" };
+    match local_host.trim() {
+        "" | "0.0.0.0" | "::" | "[::]" => format!("{scheme}://localhost:{local_port}"),
+        h if h.contains(':') && !h.starts_with('[') => format!("{scheme}://[{h}]:{local_port}"),
+        h => format!("{scheme}://{h}:{local_port}"),
+    }
+}
+
 /// Cloudflare Tunnel — wraps the `cloudflared` binary.
 /// Requires `cloudflared` installed and a tunnel token from the
 /// Cloudflare Zero Trust dashboard.
 pub struct CloudflareTunnel {
     token: String,
+    origin_tls: bool,
     proc: SharedProcess,
 }
 
@@ -38,9 +52,17 @@ impl CloudflareTunnel {
     pub fn new(token: String) -> Self {
         Self {
             token,
+            origin_tls: false,
             proc: new_shared_process(),
         }
     }
+
+    /// Forward to the gateway over `https` (set when `[gateway.tls]` is on).
+    #[must_use]
+    pub fn with_origin_tls(mut self, origin_tls: bool) -> Self {
+        self.origin_tls = origin_tls;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -49,18 +71,23 @@ impl Tunnel for CloudflareTunnel {
         "cloudflare"
     }
 
-    async fn start(&self, _local_host: &str, local_port: u16) -> Result<String> {
+    async fn start(&self, local_host: &str, local_port: u16) -> Result<String> {
         // cloudflared tunnel --no-autoupdate run --token <TOKEN> --url http://localhost:<port>
+        let origin = origin_url(local_host, local_port, self.origin_tls);
+        let mut args = vec![
+            "tunnel",
+            "--no-autoupdate",
+            "run",
+            "--token",
+            &self.token,
+            "--url",
+            &origin,
+        ];
+        if self.origin_tls {
+            args.push("--no-tls-verify");
+        }
         let mut child = Command::new("cloudflared")
-            .args([
-                "tunnel",
-                "--no-autoupdate",
-                "run",
-                "--token",
-                &self.token,
-                "--url",
-                &format!("http://localhost:{local_port}"),
-            ])
+            .args(&args)
             .stdout(std::process::Stdio::piped())
             .stderr(std::process::Stdio::piped())
             .kill_on_drop(true)
@@ -169,6 +196,27 @@ mod tests {
         assert!(!tunnel.health_check().await);
     }
 
+    #[test]
+    fn origin_url_maps_wildcard_binds_to_loopback() {
+        assert_eq!(
+            origin_url("0.0.0.0", 42617, false),
+            "http://localhost:42617"
+        );
+        assert_eq!(origin_url("::", 42617, false), "http://localhost:42617");
+        assert_eq!(
+            origin_url("127.0.0.1", 8080, false),
+            "http://127.0.0.1:8080"
+        );
+        assert_eq!(origin_url("::1", 8080, false), "http://[::1]:8080");
+    }
+
+    #[test]
+    fn origin_url_uses_https_for_tls_gateway() {
+        let tunnel = CloudflareTunnel::new("cf-token".into()).with_origin_tls(true);
+        assert!(tunnel.origin_tls);
+        assert_eq!(origin_url("0.0.0.0", 443, true), "https://localhost:443");
+    }
+
     #[test]
     fn extract_skips_quic_go_github_url() {
         let line = "2024-01-01T00:00:00Z WRN failed to sufficiently increase receive buffer size. See https://github.com/quic-go/quic-go/wiki/UDP-Buffer-Sizes for details.";
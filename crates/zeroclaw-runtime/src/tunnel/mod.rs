@@ -17,8 +17,15 @@ pub use tailscale::TailscaleTunnel;
 
 use anyhow::{Result, bail};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
-use zeroclaw_config::schema::{TailscaleTunnelConfig, TunnelConfig};
+use zeroclaw_config::schema::{Config, TailscaleTunnelConfig, TunnelConfig};
+
+/// Health component the tunnel supervisor reports under.
+pub const TUNNEL_COMPONENT: &str = "tunnel";
+
+/// How often a running tunnel is health-checked.
+const TUNNEL_HEALTH_INTERVAL: Duration = Duration::from_secs(15);
 
 // ── Tunnel trait ─────────────────────────────────────────────────
 
@@ -70,6 +77,18 @@ pub async fn kill_shared(proc: &SharedProcess) -> Result<()> {
 
 /// Create a tunnel from config. Returns `None` for tunnel_provider "none".
 pub fn create_tunnel(config: &TunnelConfig) -> Result<Option<Box<dyn Tunnel>>> {
+    build_tunnel(config, false)
+}
+
+/// Create the tunnel fronting the gateway. Like [`create_tunnel`], but
+/// providers that forward to a local origin (Cloudflare) are told whether the
+/// gateway serves TLS (`[gateway.tls]`).
+pub fn create_gateway_tunnel(config: &Config) -> Result<Option<Box<dyn Tunnel>>> {
+    let origin_tls = config.gateway.tls.as_ref().is_some_and(|tls| tls.enabled);
+    build_tunnel(&config.tunnel, origin_tls)
+}
+
+fn build_tunnel(config: &TunnelConfig, origin_tls: bool) -> Result<Option<Box<dyn Tunnel>>> {
     match config.tunnel_provider.as_str() {
         "none" | "" => Ok(None),
 
@@ -88,7 +107,9 @@ pub fn create_tunnel(config: &TunnelConfig) -> Result<Option<Box<dyn Tunnel>>> {
                 )
             }
             })?;
-            Ok(Some(Box::new(CloudflareTunnel::new(cf.token.clone()))))
+            Ok(Some(Box::new(
+                CloudflareTunnel::new(cf.token.clone()).with_origin_tls(origin_tls),
+            )))
         }
 
         "tailscale" => {
@@ -188,6 +209,102 @@ pub fn create_tunnel(config: &TunnelConfig) -> Result<Option<Box<dyn Tunnel>>> {
     }
 }
 
+// ── Supervision ──────────────────────────────────────────────────
+
+/// Start a tunnel just long enough to learn its public URL, then stop it.
+/// Quickstart uses this to report and persist the URL before the daemon's
+/// gateway takes over running the tunnel.
+pub async fn probe_public_url(
+    tunnel: &dyn Tunnel,
+    local_host: &str,
+    local_port: u16,
+) -> Result<String> {
+    let url = tunnel.start(local_host, local_port).await;
+    tunnel.stop().await.ok();
+    url
+}
+
+/// Watch a started tunnel and restart it with exponential backoff when its
+/// health check fails, the same way the daemon supervises channel listeners.
+/// State is reported under the [`TUNNEL_COMPONENT`] health component. Returns
+/// once `shutdown` flips (or its sender is dropped).
+pub async fn supervise_tunnel(
+    tunnel: Arc<dyn Tunnel>,
+    local_host: String,
+    local_port: u16,
+    initial_backoff_secs: u64,
+    max_backoff_secs: u64,
+    mut shutdown: tokio::sync::watch::Receiver<bool>,
+) {
+    let initial_backoff = initial_backoff_secs.max(1);
+    let max_backoff = max_backoff_secs.max(initial_backoff);
+    let mut backoff = initial_backoff;
+
+    loop {
+        tokio::select! {
+            _ = shutdown.changed() => return,
+            () = tokio::time::sleep(TUNNEL_HEALTH_INTERVAL) => {}
+        }
+        if tunnel.health_check().await {
+            crate::health::mark_component_ok(TUNNEL_COMPONENT);
+            backoff = initial_backoff;
+            continue;
+        }
+
+        crate::health::mark_component_error(
+            TUNNEL_COMPONENT,
+            format!("{} tunnel process is not running", tunnel.name()),
+        );
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
+                .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                .with_attrs(::serde_json::json!({
+                    "tunnel_provider": tunnel.name(),
+                    "backoff_secs": backoff,
+                })),
+            "tunnel health check failed; restarting after backoff"
+        );
+        tokio::select! {
+            _ = shutdown.changed() => return,
+            () = tokio::time::sleep(Duration::from_secs(backoff)) => {}
+        }
+
+        crate::health::bump_component_restart(TUNNEL_COMPONENT);
+        tunnel.stop().await.ok();
+        match tunnel.start(&local_host, local_port).await {
+            Ok(url) => {
+                crate::health::mark_component_ok(TUNNEL_COMPONENT);
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Start)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Success)
+                        .with_attrs(::serde_json::json!({
+                            "tunnel_provider": tunnel.name(),
+                            "public_url": url,
+                        })),
+                    "tunnel restarted"
+                );
+                backoff = initial_backoff;
+            }
+            Err(e) => {
+                crate::health::mark_component_error(TUNNEL_COMPONENT, format!("{e:#}"));
+                ::zeroclaw_log::record!(
+                    ERROR,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "tunnel_provider": tunnel.name(),
+                            "error": format!("{e:#}"),
+                        })),
+                    "tunnel restart failed"
+                );
+                backoff = backoff.saturating_mul(2).min(max_backoff);
+            }
+        }
+    }
+}
+
 // ── Tests ────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -368,6 +485,38 @@ mod tests {
         assert_eq!(url, "http://127.0.0.1:8080");
     }
 
+    #[test]
+    fn gateway_factory_builds_cloudflare_tunnel() {
+        let mut config = Config::default();
+        config.tunnel = TunnelConfig {
+            tunnel_provider: "cloudflare".into(),
+            cloudflare: Some(CloudflareTunnelConfig {
+                token: "test-token".into(),
+            }),
+            ..TunnelConfig::default()
+        };
+        let t = create_gateway_tunnel(&config).unwrap();
+        assert_eq!(t.unwrap().name(), "cloudflare");
+    }
+
+    #[tokio::test]
+    async fn probe_public_url_reports_started_url() {
+        let url = probe_public_url(&NoneTunnel, "127.0.0.1", 8080)
+            .await
+            .unwrap();
+        assert_eq!(url, "http://127.0.0.1:8080");
+    }
+
+    #[tokio::test]
+    async fn supervisor_returns_on_shutdown() {
+        let (tx, rx) = tokio::sync::watch::channel(false);
+        let supervisor = supervise_tunnel(Arc::new(NoneTunnel), "127.0.0.1".into(), 8080, 1, 4, rx);
+        tx.send(true).unwrap();
+        tokio::time::timeout(Duration::from_secs(5), supervisor)
+            .await
+            .expect("supervisor should stop once shutdown flips");
+    }
+
     #[test]
     fn cloudflare_tunnel_name() {
         let t = CloudflareTunnel::new("tok".into());
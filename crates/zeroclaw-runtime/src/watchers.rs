@@ -0,0 +1,577 @@
+//! Daemon-managed file watchers (`[[watchers]]`).
+//!
+//! Each enabled watcher observes a directory with `notify`, coalesces bursts
+//! of changes to files matching its glob, renders its prompt template with
+//! the changed paths and runs it through the agent loop. The reply goes to
+//! the watcher's channel/recipient when one is configured.
+
+use crate::security::SecurityPolicy;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+use tokio::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+use zeroclaw_config::schema::{Config, WatcherAction, WatcherConfig};
+use zeroclaw_log::Instrument;
+
+pub const WATCHERS_COMPONENT: &str = "watchers";
+
+/// Runs a rendered watcher prompt and returns the agent's reply. The daemon
+/// uses [`AgentRunner`]; tests substitute a stub.
+#[async_trait]
+pub trait PromptRunner: Send + Sync {
+    async fn run(&self, watcher: &WatcherConfig, prompt: String) -> Result<String>;
+}
+
+/// Runs watcher prompts through the full agent loop as the watcher's agent.
+pub struct AgentRunner {
+    config: Arc<Config>,
+}
+
+impl AgentRunner {
+    pub fn new(config: Arc<Config>) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl PromptRunner for AgentRunner {
+    async fn run(&self, watcher: &WatcherConfig, prompt: String) -> Result<String> {
+        let config = self.config.as_ref();
+        let alias = watcher.agent.trim();
+        let subagent_ctx = crate::subagent::SubAgentSpawn::for_agent(config, alias)
+            .and_then(|spawn| spawn.build(crate::subagent::SubAgentOverrides::default()))
+            .context("subagent spawn failed")?;
+
+        let security = SecurityPolicy::for_agent(config, alias)?;
+        if !security.can_act() {
+            anyhow::bail!("blocked by security policy: autonomy is read-only");
+        }
+        if security.is_rate_limited() {
+            anyhow::bail!("blocked by security policy: rate limit exceeded");
+        }
+        if !security.record_action() {
+            anyhow::bail!("blocked by security policy: action budget exhausted");
+        }
+
+        let mut run_config = config.clone();
+        run_config.memory.auto_save = false;
+        let span = zeroclaw_log::info_span!(
+            "subagent",
+            category = "watchers",
+            agent_alias = %alias,
+            watcher = %watcher.name,
+            spawn_site = "watcher",
+        );
+        let run_overrides = crate::agent::loop_::AgentRunOverrides {
+            security: Some(Arc::new(subagent_ctx.policy.as_ref().clone())),
+            memory: None,
+            is_subagent: false,
+            suppress_memory_inject: false,
+            memory_free: false,
+            mcp_registry: None,
+            cli_session: None,
+        };
+
+        Box::pin(
+            crate::agent::run(
+                run_config,
+                alias,
+                Some(prompt),
+                None,
+                None,
+                config
+                    .model_provider_for_agent(alias)
+                    .and_then(|e| e.temperature),
+                vec![],
+                false,
+                Some(PathBuf::from(format!("watcher-{}", watcher.name.trim()))),
+                None,
+                zeroclaw_api::ingress::TurnOrigin::Daemon,
+                run_overrides,
+            )
+            .instrument(span),
+        )
+        .await
+    }
+}
+
+/// A watcher whose path has been resolved and checked against its agent's
+/// security policy.
+#[derive(Debug, Clone)]
+pub struct ResolvedWatcher {
+    pub config: WatcherConfig,
+    pub root: PathBuf,
+    pattern: glob::Pattern,
+}
+
+impl ResolvedWatcher {
+    /// Resolve `watcher.path` against the agent's workspace. Paths the
+    /// agent may not read (outside the workspace under `workspace_only`,
+    /// or forbidden) are refused unless `allow_outside_workspace` is set.
+    pub fn new(policy: &SecurityPolicy, watcher: &WatcherConfig) -> Result<Self> {
+        let path = policy.resolve_tool_path(watcher.path.trim());
+        let root = path
+            .canonicalize()
+            .with_context(|| format!("cannot watch {}", path.display()))?;
+        if !watcher.allow_outside_workspace && !policy.is_resolved_path_readable(&root) {
+            anyhow::bail!(
+                "{} is outside the paths agent {:?} may read; set allow_outside_workspace = true to watch it anyway",
+                root.display(),
+                watcher.agent.trim()
+            );
+        }
+        let pattern = glob::Pattern::new(watcher.glob.trim())
+            .with_context(|| format!("invalid glob {:?}", watcher.glob))?;
+        Ok(Self {
+            config: watcher.clone(),
+            root,
+            pattern,
+        })
+    }
+
+    /// Whether a changed path is one this watcher reacts to. Patterns
+    /// containing `/` match the path relative to the watched root; others
+    /// match the file name alone.
+    pub fn matches(&self, path: &Path) -> bool {
+        if self.pattern.as_str().contains('/') {
+            path.strip_prefix(&self.root)
+                .is_ok_and(|relative| self.pattern.matches_path(relative))
+        } else {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| self.pattern.matches(name))
+        }
+    }
+}
+
+/// Expand `{path}` and `{paths}` in a watcher prompt template.
+pub fn render_prompt(template: &str, paths: &[PathBuf]) -> String {
+    let first = paths.first().map(|p| p.display().to_string());
+    let all = paths
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    template
+        .replace("{paths}", &all)
+        .replace("{path}", first.as_deref().unwrap_or_default())
+}
+
+/// Prompts to run for one coalesced batch: a single prompt when the
+/// template takes `{paths}`, otherwise one per changed file.
+fn prompts_for(template: &str, paths: &[PathBuf]) -> Vec<String> {
+    if template.contains("{paths}") {
+        vec![render_prompt(template, paths)]
+    } else {
+        paths
+            .iter()
+            .map(|path| render_prompt(template, std::slice::from_ref(path)))
+            .collect()
+    }
+}
+
+/// Changed paths collected until the watcher has been quiet for the
+/// debounce window.
+#[derive(Debug, Default)]
+struct PendingBatch {
+    paths: BTreeSet<PathBuf>,
+    deadline: Option<Instant>,
+}
+
+impl PendingBatch {
+    fn push(&mut self, path: PathBuf, now: Instant, window: Duration) {
+        self.paths.insert(path);
+        self.deadline = Some(now + window);
+    }
+
+    fn take(&mut self) -> Vec<PathBuf> {
+        self.deadline = None;
+        std::mem::take(&mut self.paths).into_iter().collect()
+    }
+}
+
+fn is_change(kind: &EventKind) -> bool {
+    matches!(kind, EventKind::Create(_) | EventKind::Modify(_))
+}
+
+/// Watch one resolved watcher until `cancel` fires.
+pub async fn watch(
+    config: Arc<Config>,
+    watcher: ResolvedWatcher,
+    runner: Arc<dyn PromptRunner>,
+    cancel: CancellationToken,
+) -> Result<()> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut fs_watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        let _ = tx.send(res);
+    })
+    .context("failed to create file watcher")?;
+    fs_watcher
+        .watch(&watcher.root, RecursiveMode::Recursive)
+        .with_context(|| format!("failed to watch {}", watcher.root.display()))?;
+
+    let window = Duration::from_millis(watcher.config.debounce_ms);
+    let mut pending = PendingBatch::default();
+    loop {
+        let deadline = pending.deadline;
+        tokio::select! {
+            () = cancel.cancelled() => return Ok(()),
+            event = rx.recv() => match event {
+                Some(Ok(event)) if is_change(&event.kind) => {
+                    for path in event.paths {
+                        if watcher.matches(&path) {
+                            pending.push(path, Instant::now(), window);
+                        }
+                    }
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => {
+                    ::zeroclaw_log::record!(
+                        WARN,
+                        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Receive)
+                            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                            .with_attrs(::serde_json::json!({
+                                "watcher": watcher.config.name,
+                                "error": e.to_string(),
+                            })),
+                        "File watcher reported an error"
+                    );
+                }
+                None => anyhow::bail!("file watcher for {} stopped", watcher.root.display()),
+            },
+            () = tokio::time::sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => {
+                // Editors write through temp files; only act on what is
+                // still there once the burst settles.
+                let paths: Vec<PathBuf> =
+                    pending.take().into_iter().filter(|p| p.is_file()).collect();
+                if !paths.is_empty() {
+                    handle_batch(&config, &watcher, runner.as_ref(), &paths).await;
+                }
+            }
+        }
+    }
+}
+
+async fn handle_batch(
+    config: &Config,
+    watcher: &ResolvedWatcher,
+    runner: &dyn PromptRunner,
+    paths: &[PathBuf],
+) {
+    let prompts = match watcher.config.action {
+        WatcherAction::Prompt => prompts_for(&watcher.config.prompt_template, paths),
+    };
+    for prompt in prompts {
+        ::zeroclaw_log::record!(
+            INFO,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Trigger).with_attrs(
+                ::serde_json::json!({
+                    "watcher": watcher.config.name,
+                    "agent_alias": watcher.config.agent,
+                    "paths": paths.len(),
+                })
+            ),
+            "File watcher triggered"
+        );
+        match runner.run(&watcher.config, prompt).await {
+            Ok(reply) => {
+                crate::health::mark_component_ok(WATCHERS_COMPONENT);
+                deliver(config, &watcher.config, &reply).await;
+            }
+            Err(e) => {
+                crate::health::mark_component_error(
+                    WATCHERS_COMPONENT,
+                    format!("{}: {e:#}", watcher.config.name),
+                );
+                ::zeroclaw_log::record!(
+                    ERROR,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Fail)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "watcher": watcher.config.name,
+                            "error": format!("{e:#}"),
+                        })),
+                    "File watcher prompt failed"
+                );
+            }
+        }
+    }
+}
+
+/// Send the reply to the watcher's channel, or log it when none is set.
+async fn deliver(config: &Config, watcher: &WatcherConfig, reply: &str) {
+    let channel = watcher.channel.as_deref().map(str::trim);
+    let recipient = watcher.recipient.as_deref().map(str::trim);
+    let (Some(channel), Some(recipient)) = (
+        channel.filter(|c| !c.is_empty()),
+        recipient.filter(|r| !r.is_empty()),
+    ) else {
+        ::zeroclaw_log::record!(
+            INFO,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Complete)
+                .with_outcome(::zeroclaw_log::EventOutcome::Success)
+                .with_attrs(::serde_json::json!({
+                    "watcher": watcher.name,
+                    "reply_chars": reply.chars().count(),
+                })),
+            "File watcher prompt completed; no channel configured"
+        );
+        return;
+    };
+    if let Err(e) =
+        crate::cron::scheduler::deliver_announcement(config, channel, recipient, None, reply).await
+    {
+        ::zeroclaw_log::record!(
+            WARN,
+            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Send)
+                .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                .with_attrs(::serde_json::json!({
+                    "watcher": watcher.name,
+                    "channel": channel,
+                    "error": format!("{e:#}"),
+                })),
+            "Failed to deliver file watcher reply"
+        );
+    }
+}
+
+/// Daemon component: run every enabled watcher until `cancel` fires.
+/// Watchers that fail to start are reported on the `watchers` health
+/// component; the rest keep running.
+pub async fn run(config: Config, cancel: CancellationToken) -> Result<()> {
+    let config = Arc::new(config);
+    let runner: Arc<dyn PromptRunner> = Arc::new(AgentRunner::new(Arc::clone(&config)));
+    let mut watchers = JoinSet::new();
+    let mut failures = Vec::new();
+
+    for watcher in config.watchers.iter().filter(|w| w.enabled) {
+        let resolved = SecurityPolicy::for_agent(&config, watcher.agent.trim())
+            .and_then(|policy| ResolvedWatcher::new(&policy, watcher));
+        match resolved {
+            Ok(resolved) => {
+                ::zeroclaw_log::record!(
+                    INFO,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Start)
+                        .with_attrs(::serde_json::json!({
+                            "watcher": watcher.name,
+                            "path": resolved.root.display().to_string(),
+                            "glob": watcher.glob,
+                        })),
+                    "File watcher started"
+                );
+                watchers.spawn(watch(
+                    Arc::clone(&config),
+                    resolved,
+                    Arc::clone(&runner),
+                    cancel.clone(),
+                ));
+            }
+            Err(e) => {
+                ::zeroclaw_log::record!(
+                    ERROR,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Start)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "watcher": watcher.name,
+                            "error": format!("{e:#}"),
+                        })),
+                    "File watcher not started"
+                );
+                failures.push(format!("{}: {e:#}", watcher.name));
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        crate::health::mark_component_ok(WATCHERS_COMPONENT);
+    } else {
+        crate::health::mark_component_error(WATCHERS_COMPONENT, failures.join("; "));
+    }
+
+    while let Some(joined) = watchers.join_next().await {
+        match joined {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => return Err(e),
+            Err(e) => anyhow::bail!("file watcher task failed: {e}"),
+        }
+    }
+    cancel.cancelled().await;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use zeroclaw_api::model_provider::ModelProvider;
+
+    /// Provider that echoes a fixed reply and records every prompt.
+    #[derive(Default)]
+    struct MockProvider {
+        prompts: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl ModelProvider for MockProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            message: &str,
+            _model: &str,
+            _temperature: Option<f64>,
+        ) -> Result<String> {
+            self.prompts.lock().unwrap().push(message.to_string());
+            Ok("summarised".into())
+        }
+    }
+
+    impl ::zeroclaw_api::attribution::Attributable for MockProvider {
+        fn role(&self) -> ::zeroclaw_api::attribution::Role {
+            ::zeroclaw_api::attribution::Role::Provider(
+                ::zeroclaw_api::attribution::ProviderKind::Model(
+                    ::zeroclaw_api::attribution::ModelProviderKind::Custom,
+                ),
+            )
+        }
+        fn alias(&self) -> &str {
+            "MockProvider"
+        }
+    }
+
+    /// Runs watcher prompts as a single provider call.
+    struct ProviderRunner(Arc<MockProvider>);
+
+    #[async_trait]
+    impl PromptRunner for ProviderRunner {
+        async fn run(&self, _watcher: &WatcherConfig, prompt: String) -> Result<String> {
+            self.0.chat_with_system(None, &prompt, "mock", None).await
+        }
+    }
+
+    fn policy(workspace: &Path) -> SecurityPolicy {
+        SecurityPolicy {
+            workspace_dir: workspace.to_path_buf(),
+            workspace_only: true,
+            forbidden_paths: Vec::new(),
+            ..SecurityPolicy::default()
+        }
+    }
+
+    fn watcher(path: &Path, glob: &str) -> WatcherConfig {
+        WatcherConfig {
+            name: "inbox".into(),
+            agent: "default".into(),
+            path: path.display().to_string(),
+            glob: glob.into(),
+            prompt_template: "Summarise {path}".into(),
+            debounce_ms: 300,
+            ..WatcherConfig::default()
+        }
+    }
+
+    #[test]
+    fn render_prompt_expands_path_and_paths() {
+        let paths = vec![PathBuf::from("/w/a.md"), PathBuf::from("/w/b.md")];
+        assert_eq!(render_prompt("Read {path}", &paths[..1]), "Read /w/a.md");
+        assert_eq!(
+            render_prompt("Changed:\n{paths}", &paths),
+            "Changed:\n/w/a.md\n/w/b.md"
+        );
+        assert_eq!(prompts_for("Read {path}", &paths).len(), 2);
+        assert_eq!(prompts_for("Read {paths}", &paths).len(), 1);
+    }
+
+    #[test]
+    fn glob_matches_file_name_or_relative_path() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("notes")).unwrap();
+        let by_name =
+            ResolvedWatcher::new(&policy(dir.path()), &watcher(dir.path(), "*.md")).unwrap();
+        assert!(by_name.matches(&by_name.root.join("notes/today.md")));
+        assert!(!by_name.matches(&by_name.root.join("notes/today.txt")));
+
+        let by_path =
+            ResolvedWatcher::new(&policy(dir.path()), &watcher(dir.path(), "notes/*.md")).unwrap();
+        assert!(by_path.matches(&by_path.root.join("notes/today.md")));
+        assert!(!by_path.matches(&by_path.root.join("today.md")));
+    }
+
+    #[test]
+    fn workspace_only_refuses_outside_paths_unless_allowed() {
+        let workspace = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        let policy = policy(workspace.path());
+
+        let mut cfg = watcher(outside.path(), "*");
+        let err = ResolvedWatcher::new(&policy, &cfg).unwrap_err();
+        assert!(err.to_string().contains("allow_outside_workspace"), "{err}");
+
+        cfg.allow_outside_workspace = true;
+        assert!(ResolvedWatcher::new(&policy, &cfg).is_ok());
+
+        let relative = WatcherConfig {
+            path: ".".into(),
+            ..watcher(workspace.path(), "*")
+        };
+        assert!(ResolvedWatcher::new(&policy, &relative).is_ok());
+    }
+
+    #[test]
+    fn pending_batch_extends_deadline_and_dedupes() {
+        let window = Duration::from_millis(100);
+        let start = Instant::now();
+        let mut batch = PendingBatch::default();
+        batch.push("/w/a.md".into(), start, window);
+        batch.push("/w/a.md".into(), start + Duration::from_millis(50), window);
+        assert_eq!(batch.deadline, Some(start + Duration::from_millis(150)));
+        assert_eq!(batch.take(), vec![PathBuf::from("/w/a.md")]);
+        assert!(batch.deadline.is_none());
+    }
+
+    #[tokio::test]
+    async fn burst_of_writes_runs_one_prompt_per_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let resolved =
+            ResolvedWatcher::new(&policy(dir.path()), &watcher(dir.path(), "*.md")).unwrap();
+        let root = resolved.root.clone();
+        let provider = Arc::new(MockProvider::default());
+        let cancel = CancellationToken::new();
+        let handle = tokio::spawn(watch(
+            Arc::new(Config::default()),
+            resolved,
+            Arc::new(ProviderRunner(Arc::clone(&provider))),
+            cancel.clone(),
+        ));
+        // Give the watcher a moment to register before writing.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        for i in 0..5 {
+            std::fs::write(root.join("note.md"), format!("draft {i}")).unwrap();
+            std::fs::write(root.join("ignored.txt"), "x").unwrap();
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        let waited = Instant::now();
+        while provider.prompts.lock().unwrap().is_empty()
+            && waited.elapsed() < Duration::from_secs(5)
+        {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        // Well past the debounce window: nothing else should arrive.
+        tokio::time::sleep(Duration::from_millis(800)).await;
+        cancel.cancel();
+        handle.await.unwrap().unwrap();
+
+        let prompts = provider.prompts.lock().unwrap().clone();
+        assert_eq!(
+            prompts,
+            vec![format!("Summarise {}", root.join("note.md").display())]
+        );
+    }
+}
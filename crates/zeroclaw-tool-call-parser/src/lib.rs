@@ -2147,28 +2147,61 @@ pub fn parse_tool_calls(response: &str) -> (String, Vec<ParsedToolCall>) {
     (text_parts.join("\n"), calls)
 }
 
-/// Remove `<think>...</think>` blocks from model output.
-/// Qwen and other reasoning models embed chain-of-thought inline in the
-/// response text using `<think>` tags.  These must be removed before parsing
-/// tool-call tags or displaying output.
+/// Inline reasoning wrappers emitted by reasoning models: `<think>` (Qwen,
+/// DeepSeek-R1, MiniMax) and `<thinking>` (Claude-style prompt reasoning).
+const REASONING_TAGS: &[(&str, &str)] = &[("<think>", "</think>"), ("<thinking>", "</thinking>")];
+
+/// Remove `<think>...</think>` and `<thinking>...</thinking>` blocks from
+/// model output. Reasoning models embed chain-of-thought inline in the
+/// response text; anything inside these blocks, including text that looks
+/// like a tool call, must be removed before parsing tool-call tags or
+/// displaying output.
 pub fn strip_think_tags(s: &str) -> String {
-    let mut result = String::with_capacity(s.len());
-    let mut rest = s;
-    loop {
-        if let Some(start) = rest.find("<think>") {
-            result.push_str(&rest[..start]);
-            if let Some(end) = rest[start..].find("</think>") {
-                rest = &rest[start + end + "</think>".len()..];
+    let mut text = s.to_string();
+    for (open, close) in REASONING_TAGS {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text.as_str();
+        loop {
+            if let Some(start) = rest.find(open) {
+                result.push_str(&rest[..start]);
+                if let Some(end) = rest[start..].find(close) {
+                    rest = &rest[start + end + close.len()..];
+                } else {
+                    // Unclosed tag: drop the rest to avoid leaking partial reasoning.
+                    break;
+                }
             } else {
-                // Unclosed tag: drop the rest to avoid leaking partial reasoning.
+                result.push_str(rest);
                 break;
             }
-        } else {
-            result.push_str(rest);
-            break;
         }
+        text = result;
     }
-    result.trim().to_string()
+    text.trim().to_string()
+}
+
+/// Collect the contents of inline reasoning blocks (see [`strip_think_tags`])
+/// so they can be kept as the message's reasoning trace. An unclosed block
+/// contributes everything after its opening tag. Returns `None` when the
+/// text carries no non-empty reasoning.
+pub fn extract_think_content(s: &str) -> Option<String> {
+    let mut parts = Vec::new();
+    for (open, close) in REASONING_TAGS {
+        let mut rest = s;
+        while let Some(start) = rest.find(open) {
+            let body = &rest[start + open.len()..];
+            let (inner, next) = match body.find(close) {
+                Some(end) => (&body[..end], &body[end + close.len()..]),
+                None => (body, ""),
+            };
+            let inner = inner.trim();
+            if !inner.is_empty() {
+                parts.push(inner.to_string());
+            }
+            rest = next;
+        }
+    }
+    (!parts.is_empty()).then(|| parts.join("\n\n"))
 }
 
 /// Strip prompt-guided tool artifacts from visible output while preserving
@@ -3175,6 +3208,38 @@ Final answer."#;
         assert!(text.is_empty());
     }
 
+    #[test]
+    fn parse_tool_calls_ignores_tool_calls_inside_think_blocks() {
+        // DeepSeek-R1 style: the model drafts a call while reasoning, then
+        // answers in plain text. The drafted call must not execute.
+        let response = "<think>Maybe I should run <tool_call>{\"name\":\"shell\",\"arguments\":{\"command\":\"rm -rf /tmp/x\"}}</tool_call> but no.</think>The directory is fine as is.";
+        let (text, calls) = parse_tool_calls(response);
+        assert!(calls.is_empty(), "tool call inside <think> must be ignored");
+        assert_eq!(text, "The directory is fine as is.");
+
+        let response = "<thinking>\n<tool_call>{\"name\":\"shell\",\"arguments\":{\"command\":\"ls\"}}</tool_call>\n</thinking>\nNo tools needed.";
+        let (text, calls) = parse_tool_calls(response);
+        assert!(
+            calls.is_empty(),
+            "tool call inside <thinking> must be ignored"
+        );
+        assert_eq!(text, "No tools needed.");
+    }
+
+    #[test]
+    fn extract_think_content_collects_reasoning_blocks() {
+        assert_eq!(
+            extract_think_content("<think> step one </think>A<think>step two</think>B").as_deref(),
+            Some("step one\n\nstep two")
+        );
+        assert_eq!(
+            extract_think_content("visible<think>cut off").as_deref(),
+            Some("cut off")
+        );
+        assert_eq!(extract_think_content("<think>  </think>plain"), None);
+        assert_eq!(extract_think_content("no reasoning here"), None);
+    }
+
     #[test]
     fn parse_tool_calls_handles_qwen_think_with_multiple_tool_calls() {
         let response = "<think>I need to check two things</think>\n<tool_call>\n{\"name\":\"shell\",\"arguments\":{\"command\":\"date\"}}\n</tool_call>\n<tool_call>\n{\"name\":\"shell\",\"arguments\":{\"command\":\"pwd\"}}\n</tool_call>";
@@ -52,6 +52,7 @@ use crate::memory_recall::MemoryRecallTool;
 use crate::memory_store::MemoryStoreTool;
 use crate::microsoft365::Microsoft365Tool;
 use crate::model_routing_config::ModelRoutingConfigTool;
+use crate::notify::NotifyTool;
 use crate::notion_tool::NotionTool;
 use crate::opencode_cli::OpenCodeCliTool;
 use crate::pipeline::PipelineTool;
@@ -119,6 +120,7 @@ tool_attribution!(MemoryRecallTool, ToolKind::Memory);
 tool_attribution!(MemoryStoreTool, ToolKind::Memory);
 tool_attribution!(Microsoft365Tool, ToolKind::Plugin);
 tool_attribution!(ModelRoutingConfigTool, ToolKind::Plugin);
+tool_attribution!(NotifyTool, ToolKind::Plugin);
 tool_attribution!(NotionTool, ToolKind::Plugin);
 tool_attribution!(OpenCodeCliTool, ToolKind::Plugin);
 tool_attribution!(PipelineTool, ToolKind::Plugin);
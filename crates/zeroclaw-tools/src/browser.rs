@@ -11,7 +11,7 @@ use std::sync::Arc;
 use std::time::Duration;
 use tokio::process::Command;
 use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
-use zeroclaw_config::policy::SecurityPolicy;
+use zeroclaw_config::policy::{SecurityPolicy, ToolOperation};
 
 /// Computer-use sidecar settings.
 #[derive(Clone)]
@@ -57,6 +57,7 @@ pub struct BrowserTool {
     security: Arc<SecurityPolicy>,
     allowed_domains: Vec<String>,
     allowed_private_hosts: Vec<String>,
+    credential_domains: Vec<String>,
     session_name: Option<String>,
     backend: String,
     headed: Option<bool>,
@@ -239,6 +240,7 @@ impl BrowserTool {
                 allowed_private_hosts,
                 "browser.allowed_private_hosts",
             )?,
+            credential_domains: Vec::new(),
             session_name,
             backend,
             headed,
@@ -251,6 +253,14 @@ impl BrowserTool {
         })
     }
 
+    /// Domains where `fill`, `type` and `find … fill` may write into a
+    /// credential field. Without an entry the tool never types credentials.
+    pub fn with_credential_domains(mut self, domains: Vec<String>) -> anyhow::Result<Self> {
+        self.credential_domains =
+            domain_guard::normalize_allowed_domains(domains, "browser.credential_domains")?;
+        Ok(self)
+    }
+
     /// Check if agent-browser CLI is available
     pub async fn is_agent_browser_available() -> bool {
         let cmd = if cfg!(target_os = "windows") {
@@ -495,6 +505,177 @@ impl BrowserTool {
         Ok(())
     }
 
+    /// Guardrails that apply whatever the backend: navigation stays on the
+    /// allowlist, credentials are only typed on `browser.credential_domains`,
+    /// and screenshots are written under the workspace. Returns the action
+    /// with its screenshot path resolved.
+    async fn guard_action(
+        &self,
+        action: BrowserAction,
+        backend: ResolvedBackend,
+    ) -> anyhow::Result<BrowserAction> {
+        match action {
+            BrowserAction::Open { ref url } => {
+                self.validate_url(url)?;
+                Ok(action)
+            }
+            BrowserAction::Screenshot { path, full_page } => Ok(BrowserAction::Screenshot {
+                path: Some(self.screenshot_path(path.as_deref()).await?),
+                full_page,
+            }),
+            BrowserAction::Fill { ref selector, .. } | BrowserAction::Type { ref selector, .. } => {
+                if self.is_credential_field(selector, backend).await {
+                    let url = self.current_page_url(backend).await?;
+                    self.check_credential_domain(selector, &url)?;
+                }
+                Ok(action)
+            }
+            BrowserAction::Find {
+                ref value,
+                action: ref find_action,
+                ..
+            } if find_action == "fill" => {
+                if looks_like_credential_field(value) {
+                    let url = self.current_page_url(backend).await?;
+                    self.check_credential_domain(value, &url)?;
+                }
+                Ok(action)
+            }
+            other => Ok(other),
+        }
+    }
+
+    /// Resolve where a screenshot is written. Relative paths are taken from
+    /// the workspace and anything outside the writable roots is refused; with
+    /// no path the file goes to `screenshots/browser-<timestamp>.png`.
+    async fn screenshot_path(&self, path: Option<&str>) -> anyhow::Result<String> {
+        let requested = path.map(str::to_string).unwrap_or_else(|| {
+            format!(
+                "screenshots/browser-{}.png",
+                chrono::Utc::now().format("%Y%m%d-%H%M%S-%3f")
+            )
+        });
+        let resolved = self
+            .security
+            .resolve_confined_path(&requested, ToolOperation::Act)
+            .map_err(anyhow::Error::msg)?;
+        if tokio::fs::symlink_metadata(&resolved)
+            .await
+            .is_ok_and(|meta| meta.file_type().is_symlink())
+        {
+            anyhow::bail!("Refusing to write screenshot through symlink: {requested}");
+        }
+        if let Some(parent) = resolved.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        Ok(resolved.to_string_lossy().into_owned())
+    }
+
+    /// Whether `selector` targets a credential field: by its name (see
+    /// [`looks_like_credential_field`]) or, for `@ref` and other opaque
+    /// selectors, by the element's `type` / `autocomplete` attributes.
+    async fn is_credential_field(&self, selector: &str, backend: ResolvedBackend) -> bool {
+        if looks_like_credential_field(selector) {
+            return true;
+        }
+        for name in ["type", "autocomplete"] {
+            if let Some(value) = self.field_attribute(selector, name, backend).await
+                && looks_like_credential_field(&value)
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Best-effort attribute read; `None` when the backend can't tell.
+    async fn field_attribute(
+        &self,
+        selector: &str,
+        name: &str,
+        backend: ResolvedBackend,
+    ) -> Option<String> {
+        match backend {
+            ResolvedBackend::AgentBrowser => {
+                let resp = self
+                    .run_command(&["get", "attr", selector, name])
+                    .await
+                    .ok()?;
+                if !resp.success {
+                    return None;
+                }
+                response_string(resp.data.as_ref()?, "value")
+            }
+            #[cfg(feature = "browser-native")]
+            ResolvedBackend::RustNative => self
+                .native_state
+                .lock()
+                .await
+                .field_attribute(selector, name)
+                .await
+                .ok()
+                .flatten(),
+            _ => None,
+        }
+    }
+
+    /// URL of the page the session is on, read back from the backend so a
+    /// click that navigated away is accounted for.
+    async fn current_page_url(&self, backend: ResolvedBackend) -> anyhow::Result<String> {
+        let data = match backend {
+            ResolvedBackend::AgentBrowser => {
+                let resp = self.run_command(&["get", "url"]).await?;
+                if !resp.success {
+                    anyhow::bail!(
+                        "Failed to read current URL: {}",
+                        resp.error.unwrap_or_default()
+                    );
+                }
+                resp.data.unwrap_or_default()
+            }
+            #[cfg(feature = "browser-native")]
+            ResolvedBackend::RustNative => {
+                self.native_state
+                    .lock()
+                    .await
+                    .execute_action(
+                        BrowserAction::GetUrl,
+                        self.native_headless,
+                        &self.native_webdriver_url,
+                        self.native_chrome_path.as_deref(),
+                    )
+                    .await?
+            }
+            _ => anyhow::bail!(
+                "Cannot read the current URL on the {} backend",
+                backend_name(backend)
+            ),
+        };
+        response_string(&data, "url")
+            .ok_or_else(|| anyhow::Error::msg("Browser did not report the current URL"))
+    }
+
+    /// Credentials are only typed on hosts listed in `browser.credential_domains`.
+    fn check_credential_domain(&self, field: &str, page_url: &str) -> anyhow::Result<()> {
+        let host = reqwest::Url::parse(page_url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_ascii_lowercase));
+        match host {
+            Some(host) if domain_guard::host_matches_allowlist(&host, &self.credential_domains) => {
+                Ok(())
+            }
+            Some(host) => anyhow::bail!(
+                "Refusing to type into credential field '{field}' on {host}: \
+                add the domain to browser.credential_domains to allow it"
+            ),
+            None => anyhow::bail!(
+                "Refusing to type into credential field '{field}': the current page has no host"
+            ),
+        }
+    }
+
     /// Execute an agent-browser command
     async fn run_command(&self, args: &[&str]) -> anyhow::Result<AgentBrowserResponse> {
         let mut cmd = self.agent_browser_command();
@@ -586,7 +767,6 @@ impl BrowserTool {
     ) -> anyhow::Result<ToolResult> {
         match action {
             BrowserAction::Open { url } => {
-                self.validate_url(&url)?;
                 let resp = self.run_command(&["open", &url]).await?;
                 self.to_result(resp)
             }
@@ -1009,7 +1189,8 @@ impl Tool for BrowserTool {
             "Web/browser automation with pluggable backends (agent-browser, rust-native, computer_use). ",
             "Supports DOM actions plus optional OS-level actions (mouse_move, mouse_click, mouse_drag, ",
             "key_type, key_press, screen_capture) through a computer-use sidecar. Use 'snapshot' to map ",
-            "interactive elements to refs (@e1, @e2). Enforces browser.allowed_domains for open actions."
+            "interactive elements to refs (@e1, @e2). Enforces browser.allowed_domains for open actions, ",
+            "only fills credential fields on browser.credential_domains, and saves screenshots under the workspace."
         )
     }
 
@@ -1019,16 +1200,16 @@ impl Tool for BrowserTool {
             "properties": {
                 "action": {
                     "type": "string",
-                    "enum": ["open", "snapshot", "click", "fill", "type", "get_text",
-                             "get_title", "get_url", "screenshot", "wait", "press",
-                             "hover", "scroll", "is_visible", "close", "find",
-                             "mouse_move", "mouse_click", "mouse_drag", "key_type",
+                    "enum": ["open", "goto", "snapshot", "click", "fill", "type", "get_text",
+                             "extract_text", "get_title", "get_url", "screenshot", "wait",
+                             "wait_for", "press", "hover", "scroll", "is_visible", "close",
+                             "find", "mouse_move", "mouse_click", "mouse_drag", "key_type",
                              "key_press", "screen_capture"],
-                    "description": "Browser action to perform (OS-level actions require backend=computer_use)"
+                    "description": "Browser action to perform; goto, extract_text and wait_for are aliases of open, get_text and wait (OS-level actions require backend=computer_use)"
                 },
                 "url": {
                     "type": "string",
-                    "description": "URL to navigate to (for 'open' action)"
+                    "description": "URL to navigate to (for 'open' / 'goto')"
                 },
                 "selector": {
                     "type": "string",
@@ -1102,7 +1283,7 @@ impl Tool for BrowserTool {
                 },
                 "path": {
                     "type": "string",
-                    "description": "File path for screenshot"
+                    "description": "Screenshot file path inside the workspace (default: screenshots/browser-<timestamp>.png)"
                 },
                 "ms": {
                     "type": "integer",
@@ -1193,6 +1374,28 @@ impl Tool for BrowserTool {
             }
         };
 
+        let action = match self.guard_action(action, backend).await {
+            Ok(a) => a,
+            Err(e) => {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+                        .with_category(::zeroclaw_log::EventCategory::Tool)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "action": action_str,
+                            "reason": e.to_string(),
+                        })),
+                    "browser: action refused by guardrail"
+                );
+                return Ok(ToolResult {
+                    success: false,
+                    output: ToolOutput::default(),
+                    error: Some(e.to_string()),
+                });
+            }
+        };
+
         self.execute_action(action, backend).await
     }
 }
@@ -1562,6 +1765,11 @@ mod native_backend {
             }
         }
 
+        pub async fn field_attribute(&self, selector: &str, name: &str) -> Result<Option<String>> {
+            let client = self.active_client()?;
+            Ok(find_element(client, selector).await?.attr(name).await?)
+        }
+
         pub async fn reset_session(&mut self) {
             if let Some(client) = self.client.take() {
                 let _ = client.close().await;
@@ -1971,7 +2179,7 @@ mod native_backend {
 /// Parse a JSON `args` object into a typed `BrowserAction`.
 fn parse_browser_action(action_str: &str, args: &Value) -> anyhow::Result<BrowserAction> {
     match action_str {
-        "open" => {
+        "open" | "goto" => {
             let url = args.get("url").and_then(|v| v.as_str()).ok_or_else(|| {
                 ::zeroclaw_log::record!(
                     WARN,
@@ -2068,7 +2276,7 @@ fn parse_browser_action(action_str: &str, args: &Value) -> anyhow::Result<Browse
                 text: text.into(),
             })
         }
-        "get_text" => {
+        "get_text" | "extract_text" => {
             let selector = args
                 .get("selector")
                 .and_then(|v| v.as_str())
@@ -2094,7 +2302,7 @@ fn parse_browser_action(action_str: &str, args: &Value) -> anyhow::Result<Browse
                 .and_then(serde_json::Value::as_bool)
                 .unwrap_or(false),
         }),
-        "wait" => Ok(BrowserAction::Wait {
+        "wait" | "wait_for" => Ok(BrowserAction::Wait {
             selector: args
                 .get("selector")
                 .and_then(|v| v.as_str())
@@ -2221,15 +2429,18 @@ fn is_supported_browser_action(action: &str) -> bool {
     matches!(
         action,
         "open"
+            | "goto"
             | "snapshot"
             | "click"
             | "fill"
             | "type"
             | "get_text"
+            | "extract_text"
             | "get_title"
             | "get_url"
             | "screenshot"
             | "wait"
+            | "wait_for"
             | "press"
             | "hover"
             | "scroll"
@@ -2245,6 +2456,44 @@ fn is_supported_browser_action(action: &str) -> bool {
     )
 }
 
+/// Name fragments that mark a field as taking a credential. Matched against
+/// selector words, `find` locator values and `type` / `autocomplete`
+/// attributes, so `#password`, `input[type=password]`, `label=One-time code`
+/// and `autocomplete="current-password"` all count.
+const CREDENTIAL_FIELD_MARKERS: &[&str] = &[
+    "password",
+    "passwd",
+    "passcode",
+    "secret",
+    "token",
+    "apikey",
+    "api_key",
+    "api-key",
+    "one-time-code",
+    "one time code",
+];
+
+/// Whole words that mark a credential field; too short to match as substrings.
+const CREDENTIAL_FIELD_WORDS: &[&str] = &["pwd", "pin", "otp", "totp", "mfa", "2fa", "cvv", "cvc"];
+
+fn looks_like_credential_field(target: &str) -> bool {
+    let lower = target.to_ascii_lowercase();
+    CREDENTIAL_FIELD_MARKERS
+        .iter()
+        .any(|marker| lower.contains(marker))
+        || lower
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|word| CREDENTIAL_FIELD_WORDS.contains(&word))
+}
+
+/// A string field from a backend response: either the payload itself or
+/// its `key` member.
+fn response_string(data: &Value, key: &str) -> Option<String> {
+    data.as_str()
+        .or_else(|| data.get(key).and_then(Value::as_str))
+        .map(str::to_string)
+}
+
 fn is_computer_use_only_action(action: &str) -> bool {
     matches!(
         action,
@@ -2950,4 +3199,101 @@ mod tests {
             "expected private-host block, got: {err}",
         );
     }
+
+    #[test]
+    fn action_aliases_parse_to_their_targets() {
+        let goto = parse_browser_action("goto", &json!({"url": "https://example.com"})).unwrap();
+        assert!(matches!(goto, BrowserAction::Open { url } if url == "https://example.com"));
+        let extract = parse_browser_action("extract_text", &json!({"selector": "table"})).unwrap();
+        assert!(matches!(extract, BrowserAction::GetText { selector } if selector == "table"));
+        let wait = parse_browser_action("wait_for", &json!({"selector": "#done"})).unwrap();
+        assert!(matches!(wait, BrowserAction::Wait { selector: Some(s), .. } if s == "#done"));
+        for alias in ["goto", "extract_text", "wait_for"] {
+            assert!(is_supported_browser_action(alias), "{alias}");
+        }
+    }
+
+    #[test]
+    fn credential_fields_are_recognized() {
+        for target in [
+            "#password",
+            "input[type=password]",
+            "current-password",
+            "one-time-code",
+            "One time code",
+            "#otp",
+            "input[name='api_key']",
+            "#user-pin",
+        ] {
+            assert!(looks_like_credential_field(target), "{target}");
+        }
+        for target in [
+            "#username",
+            "input[name=email]",
+            "#footprint",
+            "@e3",
+            "text",
+        ] {
+            assert!(!looks_like_credential_field(target), "{target}");
+        }
+    }
+
+    #[test]
+    fn credentials_are_only_typed_on_credential_domains() {
+        let tool = private_host_tool(vec!["*"], vec![]);
+        let err = tool
+            .check_credential_domain("#password", "https://intranet.example.com/login")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("browser.credential_domains"), "got: {err}");
+
+        let tool = tool
+            .with_credential_domains(vec!["intranet.example.com".into()])
+            .unwrap();
+        assert!(
+            tool.check_credential_domain("#password", "https://intranet.example.com/login")
+                .is_ok()
+        );
+        assert!(
+            tool.check_credential_domain("#password", "https://sso.intranet.example.com/")
+                .is_ok()
+        );
+        assert!(
+            tool.check_credential_domain("#password", "https://example.com/login")
+                .is_err()
+        );
+        assert!(
+            tool.check_credential_domain("#password", "about:blank")
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn screenshots_are_confined_to_the_workspace() {
+        let tmp = tempfile::tempdir().unwrap();
+        let workspace = tmp.path().canonicalize().unwrap();
+        let security = Arc::new(SecurityPolicy {
+            workspace_dir: workspace.clone(),
+            ..SecurityPolicy::default()
+        });
+        let tool = BrowserTool::new(security, vec!["example.com".into()], None).unwrap();
+
+        let explicit = tool.screenshot_path(Some("shots/page.png")).await.unwrap();
+        assert_eq!(
+            std::path::PathBuf::from(explicit),
+            workspace.join("shots/page.png")
+        );
+        assert!(workspace.join("shots").is_dir());
+
+        let default = std::path::PathBuf::from(tool.screenshot_path(None).await.unwrap());
+        assert!(default.starts_with(workspace.join("screenshots")));
+        assert_eq!(default.extension().and_then(|e| e.to_str()), Some("png"));
+
+        assert!(tool.screenshot_path(Some("../escape.png")).await.is_err());
+        assert!(
+            tool.screenshot_path(Some("/etc/zeroclaw.png"))
+                .await
+                .is_err()
+        );
+    }
 }
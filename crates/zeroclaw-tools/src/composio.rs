@@ -60,6 +60,20 @@ impl ComposioTool {
         self.list_actions_v3(app_name).await
     }
 
+    /// Whether `entity_id` has an active connected account for `app_name`.
+    pub async fn has_active_connection(
+        &self,
+        app_name: &str,
+        entity_id: &str,
+    ) -> anyhow::Result<bool> {
+        let accounts = self
+            .list_connected_accounts(Some(app_name), Some(entity_id))
+            .await?;
+        Ok(accounts
+            .iter()
+            .any(|account| account.status.eq_ignore_ascii_case("ACTIVE")))
+    }
+
     async fn list_actions_v3(&self, app_name: Option<&str>) -> anyhow::Result<Vec<ComposioAction>> {
         let url = format!("{COMPOSIO_API_BASE_V3}/tools");
         let req = self
@@ -7,7 +7,7 @@ use std::process::Stdio;
 use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
 use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
-use zeroclaw_config::policy::SecurityPolicy;
+use zeroclaw_config::policy::{SecurityPolicy, ToolOperation};
 
 const MAX_RESULTS: usize = 1000;
 const MAX_OUTPUT_BYTES: usize = 1_048_576; // 1 MB
@@ -116,6 +116,10 @@ impl Tool for ContentSearchTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         // --- Parse parameters ---
         let pattern = args
@@ -193,10 +197,13 @@ impl Tool for ContentSearchTool {
         // RateLimitedTool + PathGuardedTool wrappers at registration time
         // (see zeroclaw-runtime::tools::mod).
 
-        // Path-shape checks only; the allowlist gate is
-        // `SecurityPolicy::is_resolved_path_readable` after canonicalize
-        // (sees `allowed_roots` ∪ `allowed_roots_read_only`).
-        if search_path.contains("../") || search_path.contains("..\\") || search_path == ".." {
+        // Path-shape check for a clearer message; the allowlist gate is
+        // `SecurityPolicy::resolve_confined_path` (sees `allowed_roots` ∪
+        // `allowed_roots_read_only`).
+        if Path::new(search_path)
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
             return Ok(ToolResult {
                 success: false,
                 output: ToolOutput::default(),
@@ -205,7 +212,21 @@ impl Tool for ContentSearchTool {
         }
 
         // --- Resolve search directory ---
-        let resolved_path = self.security.resolve_tool_path(search_path);
+        let resolved_path = match self
+            .security
+            .resolve_confined_path(search_path, ToolOperation::Read)
+        {
+            Ok(p) => p,
+            Err(e) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: ToolOutput::default(),
+                    error: Some(format!(
+                        "Resolved path for '{search_path}' is outside the allowed workspace: {e}"
+                    )),
+                });
+            }
+        };
 
         let resolved_canon = match std::fs::canonicalize(&resolved_path) {
             Ok(p) => p,
@@ -218,16 +239,6 @@ impl Tool for ContentSearchTool {
             }
         };
 
-        if !self.security.is_resolved_path_readable(&resolved_canon) {
-            return Ok(ToolResult {
-                success: false,
-                output: ToolOutput::default(),
-                error: Some(format!(
-                    "Resolved path for '{search_path}' is outside the allowed workspace."
-                )),
-            });
-        }
-
         // --- Multiline check for non-ripgrep fallbacks ---
         if multiline && self.backend != SearchBackend::Ripgrep {
             return Ok(ToolResult {
@@ -54,6 +54,10 @@ impl Tool for DiscordSearchTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let query = args.get("query").and_then(|v| v.as_str()).unwrap_or("");
         let channel_id = args.get("channel_id").and_then(|v| v.as_str());
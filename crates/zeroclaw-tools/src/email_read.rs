@@ -62,6 +62,10 @@ impl Tool for EmailReadTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let uid: u32 = args
             .get("uid")
@@ -80,6 +80,10 @@ impl Tool for EmailSearchTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let channel_alias = args.get("channel").and_then(|v| v.as_str());
         let from_filter = args.get("from").and_then(|v| v.as_str());
@@ -1,5 +1,7 @@
 use async_trait::async_trait;
 use serde_json::json;
+use std::fmt::Write as _;
+use std::path::Path;
 use std::sync::Arc;
 use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult, with_ephemeral_workspace_warning};
 use zeroclaw_config::policy::SecurityPolicy;
@@ -35,7 +37,9 @@ impl Tool for FileEditTool {
     }
 
     fn description(&self) -> &str {
-        "Edit a file by replacing an exact string match with new content"
+        "Edit a file by exact search/replace (old_string/new_string or an edits list) or by \
+         applying a unified diff. All changes apply atomically; a block or hunk whose context \
+         does not match is rejected with the mismatching region and nothing is written"
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
@@ -53,9 +57,29 @@ impl Tool for FileEditTool {
                 "new_string": {
                     "type": "string",
                     "description": "The replacement text (empty string to delete the matched text)"
+                },
+                "edits": {
+                    "type": "array",
+                    "description": "Search/replace blocks applied in order; each old_string must match exactly once at the time it is applied. Use instead of old_string/new_string.",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "old_string": { "type": "string" },
+                            "new_string": { "type": "string" }
+                        },
+                        "required": ["old_string", "new_string"]
+                    }
+                },
+                "diff": {
+                    "type": "string",
+                    "description": "Unified diff for this file (`@@ -a,b +c,d @@` hunks; ---/+++ headers optional). Use instead of old_string/new_string or edits."
+                },
+                "dry_run": {
+                    "type": "boolean",
+                    "description": "Return the summary and resulting content without writing the file (default false)"
                 }
             },
-            "required": ["path", "old_string", "new_string"]
+            "required": ["path"]
         })
     }
 
@@ -71,56 +95,30 @@ impl Tool for FileEditTool {
 }
 
 impl FileEditTool {
-    /// Perform the exact-string replacement edit. The ephemeral workspace
-    /// warning is applied by the `Tool::execute` wrapper above.
+    /// Apply the requested search/replace blocks or unified diff. The
+    /// ephemeral workspace warning is applied by the `Tool::execute` wrapper
+    /// above.
     async fn edit_file(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         // ── 1. Extract parameters ──────────────────────────────────
-        let path = args.get("path").and_then(|v| v.as_str()).ok_or_else(|| {
-            ::zeroclaw_log::record!(
-                WARN,
-                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
-                    .with_outcome(::zeroclaw_log::EventOutcome::Failure)
-                    .with_attrs(::serde_json::json!({"param": "path"})),
-                "file_edit: missing path parameter"
-            );
-            anyhow::Error::msg("Missing 'path' parameter")
-        })?;
-
-        let old_string = args
-            .get("old_string")
+        let path = args
+            .get("path")
             .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                ::zeroclaw_log::record!(
-                    WARN,
-                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
-                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
-                        .with_attrs(::serde_json::json!({"param": "old_string"})),
-                    "file_edit: missing old_string parameter"
-                );
-                anyhow::Error::msg("Missing 'old_string' parameter")
-            })?;
-
-        let new_string = args
-            .get("new_string")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                ::zeroclaw_log::record!(
-                    WARN,
-                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
-                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
-                        .with_attrs(::serde_json::json!({"param": "new_string"})),
-                    "file_edit: missing new_string parameter"
-                );
-                anyhow::Error::msg("Missing 'new_string' parameter")
-            })?;
-
-        if old_string.is_empty() {
-            return Ok(ToolResult {
-                success: false,
-                output: ToolOutput::default(),
-                error: Some("old_string must not be empty".into()),
-            });
-        }
+            .ok_or_else(|| missing_param("path"))?;
+        let dry_run = args
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let request = match EditRequest::from_args(&args)? {
+            Ok(request) => request,
+            Err(message) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: ToolOutput::default(),
+                    error: Some(message),
+                });
+            }
+        };
 
         // ── 2. Autonomy check ──────────────────────────────────────
         if !self.security.can_act() {
@@ -204,7 +202,7 @@ impl FileEditTool {
             });
         }
 
-        // ── 9. Read → match → replace → write ─────────────────────
+        // ── 9. Read → apply → write ────────────────────────────────
         let content = match tokio::fs::read_to_string(&resolved_target).await {
             Ok(c) => c,
             Err(e) => {
@@ -216,34 +214,38 @@ impl FileEditTool {
             }
         };
 
-        let match_count = content.matches(old_string).count();
-
-        if match_count == 0 {
-            return Ok(ToolResult {
-                success: false,
-                output: ToolOutput::default(),
-                error: Some(no_match_diagnostic(&content, old_string)),
-            });
-        }
+        let outcome = match apply_edit(&content, &request) {
+            Ok(outcome) => outcome,
+            Err(message) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: ToolOutput::default(),
+                    error: Some(message),
+                });
+            }
+        };
 
-        if match_count > 1 {
+        if dry_run {
             return Ok(ToolResult {
-                success: false,
-                output: ToolOutput::default(),
-                error: Some(format!(
-                    "old_string matches {match_count} times; must match exactly once"
-                )),
+                success: true,
+                output: format!(
+                    "Dry run for {path}: {} ({} bytes); file not modified.\n\n{}",
+                    outcome.summary,
+                    outcome.content.len(),
+                    outcome.content
+                )
+                .into(),
+                error: None,
             });
         }
 
-        let new_content = content.replacen(old_string, new_string, 1);
-
-        match tokio::fs::write(&resolved_target, &new_content).await {
+        match write_atomically(&resolved_target, &outcome.content).await {
             Ok(()) => Ok(ToolResult {
                 success: true,
                 output: format!(
-                    "Edited {path}: replaced 1 occurrence ({} bytes)",
-                    new_content.len()
+                    "Edited {path}: {} ({} bytes)",
+                    outcome.summary,
+                    outcome.content.len()
                 )
                 .into(),
                 error: None,
@@ -257,6 +259,162 @@ impl FileEditTool {
     }
 }
 
+fn missing_param(param: &str) -> anyhow::Error {
+    ::zeroclaw_log::record!(
+        WARN,
+        ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Reject)
+            .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+            .with_attrs(::serde_json::json!({"param": param})),
+        "file_edit: missing parameter"
+    );
+    anyhow::anyhow!("Missing '{param}' parameter")
+}
+
+/// What the caller asked `file_edit` to apply.
+#[derive(Debug)]
+enum EditRequest {
+    /// Search/replace blocks, applied in order.
+    Replace(Vec<(String, String)>),
+    /// A unified diff against the target file.
+    Diff(String),
+}
+
+impl EditRequest {
+    /// Outer `Err` is a missing required parameter (a tool-call error);
+    /// inner `Err` is a malformed request reported back as a failed result.
+    fn from_args(args: &serde_json::Value) -> anyhow::Result<Result<Self, String>> {
+        let diff = args.get("diff").and_then(|v| v.as_str());
+        let edits = args.get("edits");
+        let has_pair = args.get("old_string").is_some() || args.get("new_string").is_some();
+        if usize::from(diff.is_some()) + usize::from(edits.is_some()) + usize::from(has_pair) > 1 {
+            return Ok(Err(
+                "Provide exactly one of diff, edits, or old_string/new_string".into(),
+            ));
+        }
+
+        if let Some(diff) = diff {
+            if diff.trim().is_empty() {
+                return Ok(Err("diff must not be empty".into()));
+            }
+            return Ok(Ok(Self::Diff(diff.to_string())));
+        }
+
+        if let Some(edits) = edits {
+            let Some(items) = edits.as_array().filter(|items| !items.is_empty()) else {
+                return Ok(Err("edits must be a non-empty array".into()));
+            };
+            let mut blocks = Vec::with_capacity(items.len());
+            for (i, item) in items.iter().enumerate() {
+                let old = item.get("old_string").and_then(|v| v.as_str());
+                let new = item.get("new_string").and_then(|v| v.as_str());
+                let (Some(old), Some(new)) = (old, new) else {
+                    return Ok(Err(format!(
+                        "edit {}: old_string and new_string must both be strings",
+                        i + 1
+                    )));
+                };
+                if old.is_empty() {
+                    return Ok(Err(format!("edit {}: old_string must not be empty", i + 1)));
+                }
+                blocks.push((old.to_string(), new.to_string()));
+            }
+            return Ok(Ok(Self::Replace(blocks)));
+        }
+
+        let old_string = args
+            .get("old_string")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| missing_param("old_string"))?;
+        let new_string = args
+            .get("new_string")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| missing_param("new_string"))?;
+        if old_string.is_empty() {
+            return Ok(Err("old_string must not be empty".into()));
+        }
+        Ok(Ok(Self::Replace(vec![(
+            old_string.to_string(),
+            new_string.to_string(),
+        )])))
+    }
+}
+
+/// Resulting file content plus a one-line summary of what changed.
+#[derive(Debug)]
+struct EditOutcome {
+    content: String,
+    summary: String,
+}
+
+/// Apply `request` to `content` in memory. Matching runs on LF-normalised
+/// text; the file's CRLF line endings and trailing-newline state are restored
+/// on the result.
+fn apply_edit(content: &str, request: &EditRequest) -> Result<EditOutcome, String> {
+    let crlf = content.contains("\r\n");
+    let text = if crlf {
+        content.replace("\r\n", "\n")
+    } else {
+        content.to_string()
+    };
+
+    let (mut edited, summary) = match request {
+        EditRequest::Replace(blocks) => {
+            let edited = apply_replacements(&text, blocks)?;
+            let summary = if blocks.len() == 1 {
+                "replaced 1 occurrence".to_string()
+            } else {
+                format!("replaced {} occurrences", blocks.len())
+            };
+            (edited, summary)
+        }
+        EditRequest::Diff(diff) => apply_unified_diff(&text, diff)?,
+    };
+
+    if !edited.is_empty() {
+        match (text.ends_with('\n'), edited.ends_with('\n')) {
+            (true, false) => edited.push('\n'),
+            (false, true) => {
+                edited.pop();
+            }
+            _ => {}
+        }
+    }
+    if crlf {
+        edited = edited.replace('\n', "\r\n");
+    }
+
+    Ok(EditOutcome {
+        content: edited,
+        summary,
+    })
+}
+
+fn apply_replacements(text: &str, blocks: &[(String, String)]) -> Result<String, String> {
+    let mut current = text.to_string();
+    for (i, (old, new)) in blocks.iter().enumerate() {
+        let old = old.replace("\r\n", "\n");
+        let new = new.replace("\r\n", "\n");
+        let label = if blocks.len() > 1 {
+            format!("edit {}: ", i + 1)
+        } else {
+            String::new()
+        };
+        match current.matches(old.as_str()).count() {
+            0 => {
+                return Err(format!("{label}{}", no_match_diagnostic(&current, &old)));
+            }
+            1 => {}
+            n => {
+                return Err(format!(
+                    "{label}old_string matches {n} times; must match exactly once"
+                ));
+            }
+        }
+        current = current.replacen(old.as_str(), &new, 1);
+    }
+    Ok(current)
+}
+
 fn no_match_diagnostic(content: &str, old_string: &str) -> String {
     fn strip_leading_ws(s: &str) -> String {
         s.lines()
@@ -284,6 +442,252 @@ fn no_match_diagnostic(content: &str, old_string: &str) -> String {
         ),
     }
 }
+/// One `@@` hunk of a unified diff.
+#[derive(Debug)]
+struct Hunk {
+    header: String,
+    /// 1-based start line in the original file (the line *after which* to
+    /// insert when the hunk removes nothing).
+    old_start: usize,
+    /// Context plus removed lines: what the file must contain.
+    old: Vec<String>,
+    /// Context plus added lines: what replaces it.
+    new: Vec<String>,
+    added: usize,
+    removed: usize,
+}
+
+/// Parse `@@ -a[,b] +c[,d] @@` into `(a, b, d)`.
+fn parse_hunk_header(line: &str) -> Option<(usize, usize, usize)> {
+    let (ranges, _) = line.strip_prefix("@@ ")?.split_once(" @@")?;
+    let (old, new) = ranges.split_once(' ')?;
+    let range = |r: &str| -> Option<(usize, usize)> {
+        match r.split_once(',') {
+            Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+            None => Some((r.parse().ok()?, 1)),
+        }
+    };
+    let (old_start, old_len) = range(old.strip_prefix('-')?)?;
+    let (_, new_len) = range(new.strip_prefix('+')?)?;
+    Some((old_start, old_len, new_len))
+}
+
+fn is_file_header(line: &str) -> bool {
+    ["--- ", "+++ ", "diff ", "index "]
+        .iter()
+        .any(|prefix| line.starts_with(prefix))
+}
+
+fn parse_unified_diff(diff: &str) -> Result<Vec<Hunk>, String> {
+    let mut hunks: Vec<Hunk> = Vec::new();
+    let mut in_hunk = false;
+    // (old, new) lines still owed to the open hunk per its header; once both
+    // reach zero a file header closes the hunk instead of reading as a change.
+    let mut remaining = (0usize, 0usize);
+    let mut targets = 0;
+
+    for line in diff.lines() {
+        if line.starts_with("@@") {
+            let (old_start, old_len, new_len) =
+                parse_hunk_header(line).ok_or_else(|| format!("Malformed hunk header: {line}"))?;
+            hunks.push(Hunk {
+                header: line.to_string(),
+                old_start,
+                old: Vec::new(),
+                new: Vec::new(),
+                added: 0,
+                removed: 0,
+            });
+            remaining = (old_len, new_len);
+            in_hunk = true;
+            continue;
+        }
+        if !in_hunk || (remaining == (0, 0) && is_file_header(line)) {
+            in_hunk = false;
+            if line.starts_with("+++ ") {
+                targets += 1;
+                if targets > 1 {
+                    return Err("diff touches more than one file; file_edit applies a diff \
+                                to `path` only"
+                        .into());
+                }
+            }
+            continue;
+        }
+
+        let hunk_number = hunks.len();
+        let Some(hunk) = hunks.last_mut() else {
+            continue;
+        };
+        let mut chars = line.chars();
+        let marker = chars.next();
+        let body = chars.as_str();
+        match marker {
+            Some('-') => {
+                hunk.old.push(body.to_string());
+                hunk.removed += 1;
+                remaining.0 = remaining.0.saturating_sub(1);
+            }
+            Some('+') => {
+                hunk.new.push(body.to_string());
+                hunk.added += 1;
+                remaining.1 = remaining.1.saturating_sub(1);
+            }
+            // An empty line is a blank context line whose leading space was
+            // stripped by an editor.
+            Some(' ') | None => {
+                hunk.old.push(body.to_string());
+                hunk.new.push(body.to_string());
+                remaining.0 = remaining.0.saturating_sub(1);
+                remaining.1 = remaining.1.saturating_sub(1);
+            }
+            // "\ No newline at end of file": the original trailing-newline
+            // state is preserved regardless.
+            Some('\\') => {}
+            _ => {
+                return Err(format!(
+                    "Unexpected line in hunk {hunk_number} ({}): {line}",
+                    hunk.header
+                ));
+            }
+        }
+    }
+
+    if hunks.is_empty() {
+        return Err("diff contains no @@ hunks".into());
+    }
+    Ok(hunks)
+}
+
+/// Find where `old` applies: at the header's line when it matches there,
+/// otherwise at its unique occurrence at or after `cursor`.
+fn locate_hunk(
+    lines: &[&str],
+    cursor: usize,
+    expected: usize,
+    old: &[&str],
+) -> Result<usize, String> {
+    if old.is_empty() {
+        return if (cursor..=lines.len()).contains(&expected) {
+            Ok(expected)
+        } else {
+            Err("insertion point is outside the file".into())
+        };
+    }
+    if expected >= cursor && lines.get(expected..expected + old.len()) == Some(old) {
+        return Ok(expected);
+    }
+    let last_start = lines.len().checked_sub(old.len());
+    let candidates: Vec<usize> = last_start
+        .map(|last| {
+            (cursor..=last)
+                .filter(|&p| lines[p..p + old.len()] == *old)
+                .collect()
+        })
+        .unwrap_or_default();
+    match candidates.as_slice() {
+        [pos] => Ok(*pos),
+        [] => Err("context does not match the file".into()),
+        many => Err(format!(
+            "context matches {} places; include more context lines",
+            many.len()
+        )),
+    }
+}
+
+/// Rejection message showing the hunk's expected lines beside what the file
+/// actually holds at that position, so the caller can re-read and retry.
+fn hunk_conflict(n: usize, hunk: &Hunk, lines: &[&str], expected: usize, reason: &str) -> String {
+    let mut report = format!(
+        "Hunk {n} ({}) rejected: {reason}. No changes were written.\nExpected at line {}:\n",
+        hunk.header,
+        expected + 1
+    );
+    for line in &hunk.old {
+        let _ = writeln!(report, "  {line}");
+    }
+    let start = expected.min(lines.len());
+    let end = (expected + hunk.old.len().max(1)).min(lines.len());
+    if start == end {
+        report.push_str("Found: end of file\n");
+    } else {
+        report.push_str("Found:\n");
+        for (i, line) in lines[start..end].iter().enumerate() {
+            let _ = writeln!(report, "{:>5} | {line}", start + i + 1);
+        }
+    }
+    report.push_str("Re-read this region and retry with context that matches the file.");
+    report
+}
+
+/// Apply every hunk of `diff` to `text` or none of them. Returns the new
+/// text and a summary of the hunks applied.
+fn apply_unified_diff(text: &str, diff: &str) -> Result<(String, String), String> {
+    let hunks = parse_unified_diff(diff)?;
+    let lines: Vec<&str> = if text.is_empty() {
+        Vec::new()
+    } else {
+        text.strip_suffix('\n')
+            .unwrap_or(text)
+            .split('\n')
+            .collect()
+    };
+
+    let mut out: Vec<&str> = Vec::with_capacity(lines.len());
+    let mut cursor = 0;
+    let (mut added, mut removed) = (0, 0);
+    let mut shifted = Vec::new();
+    for (i, hunk) in hunks.iter().enumerate() {
+        let old: Vec<&str> = hunk.old.iter().map(String::as_str).collect();
+        let expected = if old.is_empty() {
+            hunk.old_start
+        } else {
+            hunk.old_start.saturating_sub(1)
+        };
+        let pos = locate_hunk(&lines, cursor, expected, &old)
+            .map_err(|reason| hunk_conflict(i + 1, hunk, &lines, expected, &reason))?;
+        if pos != expected {
+            shifted.push(format!("hunk {} at line {}", i + 1, pos + 1));
+        }
+        out.extend_from_slice(&lines[cursor..pos]);
+        out.extend(hunk.new.iter().map(String::as_str));
+        cursor = pos + old.len();
+        added += hunk.added;
+        removed += hunk.removed;
+    }
+    out.extend_from_slice(&lines[cursor..]);
+
+    let mut summary = format!(
+        "applied {} hunk{} (+{added} -{removed} lines)",
+        hunks.len(),
+        if hunks.len() == 1 { "" } else { "s" }
+    );
+    if !shifted.is_empty() {
+        let _ = write!(summary, "; relocated {}", shifted.join(", "));
+    }
+    Ok((out.join("\n"), summary))
+}
+
+/// Write via a sibling temp file and rename so readers never observe a
+/// half-written file; the original permissions carry over to the new inode.
+async fn write_atomically(target: &Path, content: &str) -> std::io::Result<()> {
+    let permissions = tokio::fs::metadata(target).await?.permissions();
+    let file_name = target
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let tmp = target.with_file_name(format!(".{file_name}.{}.tmp", uuid::Uuid::new_v4()));
+
+    tokio::fs::write(&tmp, content).await?;
+    let result = match tokio::fs::set_permissions(&tmp, permissions).await {
+        Ok(()) => tokio::fs::rename(&tmp, target).await,
+        Err(e) => Err(e),
+    };
+    if result.is_err() {
+        let _ = tokio::fs::remove_file(&tmp).await;
+    }
+    result
+}
 
 #[cfg(test)]
 mod tests {
@@ -471,10 +875,236 @@ mod tests {
         assert!(schema["properties"]["path"].is_object());
         assert!(schema["properties"]["old_string"].is_object());
         assert!(schema["properties"]["new_string"].is_object());
+        assert!(schema["properties"]["edits"].is_object());
+        assert!(schema["properties"]["diff"].is_object());
+        assert!(schema["properties"]["dry_run"].is_object());
         let required = schema["required"].as_array().unwrap();
-        assert!(required.contains(&json!("path")));
-        assert!(required.contains(&json!("old_string")));
-        assert!(required.contains(&json!("new_string")));
+        assert_eq!(required, &vec![json!("path")]);
+    }
+
+    #[tokio::test]
+    async fn file_edit_applies_unified_diff() {
+        let dir = std::env::temp_dir().join("zeroclaw_test_file_edit_diff");
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        tokio::fs::write(dir.join("lib.rs"), "a\nb\nc\nd\ne\nf\ng\n")
+            .await
+            .unwrap();
+
+        let diff = "--- a/lib.rs\n+++ b/lib.rs\n@@ -1,3 +1,3 @@\n a\n-b\n+B\n c\n@@ -5,3 +5,4 @@\n e\n f\n+f2\n g\n";
+        let tool = test_tool(dir.clone());
+        let result = tool
+            .execute(json!({"path": "lib.rs", "diff": diff}))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("applied 2 hunks (+2 -1 lines)"));
+
+        let content = tokio::fs::read_to_string(dir.join("lib.rs")).await.unwrap();
+        assert_eq!(content, "a\nB\nc\nd\ne\nf\nf2\ng\n");
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn file_edit_rejects_diff_with_mismatched_context() {
+        let dir = std::env::temp_dir().join("zeroclaw_test_file_edit_diff_conflict");
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        tokio::fs::write(dir.join("lib.rs"), "one\ntwo\nthree\nfour\n")
+            .await
+            .unwrap();
+
+        // First hunk applies cleanly; the second's context is stale, so
+        // neither may be written.
+        let diff = "@@ -1,2 +1,2 @@\n-one\n+ONE\n two\n@@ -3,2 +3,2 @@\n three\n-FOUR\n+4\n";
+        let tool = test_tool(dir.clone());
+        let result = tool
+            .execute(json!({"path": "lib.rs", "diff": diff}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        let error = result.error.unwrap();
+        assert!(error.contains("Hunk 2"), "{error}");
+        assert!(error.contains("    4 | four"), "{error}");
+
+        let content = tokio::fs::read_to_string(dir.join("lib.rs")).await.unwrap();
+        assert_eq!(content, "one\ntwo\nthree\nfour\n");
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[test]
+    fn diff_hunk_relocates_when_line_numbers_drift() {
+        let (text, summary) =
+            apply_unified_diff("x\ny\na\nb\n", "@@ -1,2 +1,2 @@\n a\n-b\n+c\n").unwrap();
+        assert_eq!(text, "x\ny\na\nc");
+        assert!(summary.contains("relocated hunk 1 at line 3"), "{summary}");
+    }
+
+    #[tokio::test]
+    async fn file_edit_preserves_crlf_and_missing_trailing_newline() {
+        let dir = std::env::temp_dir().join("zeroclaw_test_file_edit_crlf");
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        tokio::fs::write(dir.join("win.txt"), "alpha\r\nbeta\r\ngamma")
+            .await
+            .unwrap();
+
+        let tool = test_tool(dir.clone());
+        let result = tool
+            .execute(json!({
+                "path": "win.txt",
+                "diff": "@@ -2,2 +2,2 @@\n beta\n-gamma\n+delta\n\\ No newline at end of file\n"
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        let content = tokio::fs::read_to_string(dir.join("win.txt"))
+            .await
+            .unwrap();
+        assert_eq!(content, "alpha\r\nbeta\r\ndelta");
+
+        let result = tool
+            .execute(json!({
+                "path": "win.txt",
+                "old_string": "alpha\nbeta",
+                "new_string": "alpha\nBETA"
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        let content = tokio::fs::read_to_string(dir.join("win.txt"))
+            .await
+            .unwrap();
+        assert_eq!(content, "alpha\r\nBETA\r\ndelta");
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn file_edit_edits_list_is_all_or_nothing() {
+        let dir = std::env::temp_dir().join("zeroclaw_test_file_edit_edits_list");
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        tokio::fs::write(dir.join("cfg.toml"), "a = 1\nb = 2\n")
+            .await
+            .unwrap();
+
+        let tool = test_tool(dir.clone());
+        let result = tool
+            .execute(json!({
+                "path": "cfg.toml",
+                "edits": [
+                    {"old_string": "a = 1", "new_string": "a = 10"},
+                    {"old_string": "c = 3", "new_string": "c = 30"}
+                ]
+            }))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().starts_with("edit 2: "));
+        let content = tokio::fs::read_to_string(dir.join("cfg.toml"))
+            .await
+            .unwrap();
+        assert_eq!(content, "a = 1\nb = 2\n");
+
+        let result = tool
+            .execute(json!({
+                "path": "cfg.toml",
+                "edits": [
+                    {"old_string": "a = 1", "new_string": "a = 10"},
+                    {"old_string": "b = 2", "new_string": "b = 20"}
+                ]
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("replaced 2 occurrences"));
+        let content = tokio::fs::read_to_string(dir.join("cfg.toml"))
+            .await
+            .unwrap();
+        assert_eq!(content, "a = 10\nb = 20\n");
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn file_edit_dry_run_does_not_write() {
+        let dir = std::env::temp_dir().join("zeroclaw_test_file_edit_dry_run");
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        tokio::fs::write(dir.join("test.txt"), "hello world\n")
+            .await
+            .unwrap();
+
+        let tool = test_tool(dir.clone());
+        let result = tool
+            .execute(json!({
+                "path": "test.txt",
+                "old_string": "world",
+                "new_string": "there",
+                "dry_run": true
+            }))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("file not modified"));
+        assert!(result.output.ends_with("hello there\n"));
+
+        let content = tokio::fs::read_to_string(dir.join("test.txt"))
+            .await
+            .unwrap();
+        assert_eq!(content, "hello world\n");
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn file_edit_rejects_mixed_modes() {
+        let tool = test_tool(std::env::temp_dir());
+        let result = tool
+            .execute(json!({
+                "path": "f.txt",
+                "diff": "@@ -1 +1 @@\n-a\n+b\n",
+                "old_string": "a",
+                "new_string": "b"
+            }))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("exactly one of"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn file_edit_preserves_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join("zeroclaw_test_file_edit_permissions");
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        let script = dir.join("run.sh");
+        tokio::fs::write(&script, "echo old\n").await.unwrap();
+        tokio::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o750))
+            .await
+            .unwrap();
+
+        let tool = test_tool(dir.clone());
+        let result = tool
+            .execute(json!({"path": "run.sh", "old_string": "old", "new_string": "new"}))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+
+        let meta = tokio::fs::metadata(&script).await.unwrap();
+        assert_eq!(meta.permissions().mode() & 0o777, 0o750);
+        assert_eq!(
+            tokio::fs::read_to_string(&script).await.unwrap(),
+            "echo new\n"
+        );
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
     }
 
     #[tokio::test]
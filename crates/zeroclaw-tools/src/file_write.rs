@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use serde_json::json;
 use std::sync::Arc;
 use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
-use zeroclaw_config::policy::SecurityPolicy;
+use zeroclaw_config::policy::{SecurityPolicy, ToolOperation};
 
 /// Write file contents with path sandboxing
 pub struct FileWriteTool {
@@ -152,52 +152,27 @@ impl Tool for FileWriteTool {
         // RateLimitedTool + PathGuardedTool wrappers at registration time
         // (see zeroclaw-runtime::tools::mod).
 
-        let full_path = self.security.resolve_tool_path(path);
-
-        let Some(parent) = full_path.parent() else {
-            return Ok(ToolResult {
-                success: false,
-                output: ToolOutput::default(),
-                error: Some("Invalid path: missing parent directory".into()),
-            });
-        };
-
-        // Ensure parent directory exists before canonicalising.
-        tokio::fs::create_dir_all(parent).await?;
-
-        // Canonicalise parent AFTER creation to detect symlink escapes.
-        let resolved_parent = match tokio::fs::canonicalize(parent).await {
+        // Resolve symlinks along the parent chain and confine the target to
+        // the writable roots BEFORE creating directories, so a symlinked
+        // directory cannot make `create_dir_all` materialize paths outside.
+        let resolved_target = match self
+            .security
+            .resolve_confined_path(path, ToolOperation::Act)
+        {
             Ok(p) => p,
             Err(e) => {
                 return Ok(ToolResult {
                     success: false,
                     output: ToolOutput::default(),
-                    error: Some(format!("Failed to resolve file path: {e}")),
+                    error: Some(e),
                 });
             }
         };
 
-        if !self.security.is_resolved_path_allowed(&resolved_parent) {
-            return Ok(ToolResult {
-                success: false,
-                output: ToolOutput::default(),
-                error: Some(
-                    self.security
-                        .resolved_path_violation_message(&resolved_parent),
-                ),
-            });
+        if let Some(parent) = resolved_target.parent() {
+            tokio::fs::create_dir_all(parent).await?;
         }
 
-        let Some(file_name) = full_path.file_name() else {
-            return Ok(ToolResult {
-                success: false,
-                output: ToolOutput::default(),
-                error: Some("Invalid path: missing file name".into()),
-            });
-        };
-
-        let resolved_target = resolved_parent.join(file_name);
-
         if self.security.is_runtime_config_path(&resolved_target) {
             return Ok(ToolResult {
                 success: false,
@@ -676,6 +651,38 @@ mod tests {
         let _ = tokio::fs::remove_dir_all(&root).await;
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn file_write_symlink_escape_creates_no_outside_directories() {
+        use std::os::unix::fs::symlink;
+
+        let root = tempfile::tempdir().unwrap();
+        let workspace = root.path().join("workspace");
+        let outside = root.path().join("outside");
+        tokio::fs::create_dir_all(&workspace).await.unwrap();
+        tokio::fs::create_dir_all(&outside).await.unwrap();
+        symlink(&outside, workspace.join("escape_dir")).unwrap();
+
+        // Unwrapped tool: the confinement must hold without PathGuardedTool.
+        let tool = test_tool(workspace.clone());
+        for path in ["escape_dir/nested/deeper/hijack.txt", "../outside/x.txt"] {
+            let result = tool
+                .execute(json!({"path": path, "content": "bad"}))
+                .await
+                .unwrap();
+            assert!(!result.success, "{path} must be blocked");
+        }
+        assert!(!outside.join("nested").exists());
+        assert!(!outside.join("x.txt").exists());
+
+        let result = tool
+            .execute(json!({"path": "fresh/dir/ok.txt", "content": "ok"}))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(workspace.join("fresh/dir/ok.txt").exists());
+    }
+
     #[tokio::test]
     async fn file_write_blocks_ephemeral_runtime() {
         let dir = std::env::temp_dir().join("zeroclaw_test_file_write_ephemeral");
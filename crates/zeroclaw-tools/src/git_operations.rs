@@ -5,12 +5,14 @@ use std::sync::Arc;
 use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
 use zeroclaw_config::autonomy::AutonomyLevel;
 use zeroclaw_config::policy::SecurityPolicy;
+use zeroclaw_config::schema::GitToolConfig;
 
 /// Git operations tool for structured repository management.
 /// Provides safe, parsed git operations with JSON output.
 pub struct GitOperationsTool {
     security: Arc<SecurityPolicy>,
     workspace_dir: std::path::PathBuf,
+    config: GitToolConfig,
 }
 
 impl GitOperationsTool {
@@ -18,9 +20,17 @@ impl GitOperationsTool {
         Self {
             security,
             workspace_dir,
+            config: GitToolConfig::default(),
         }
     }
 
+    /// Apply `[git_tool]` settings: remote access, commit identity, extra
+    /// repository roots, and the output cap.
+    pub fn with_config(mut self, config: GitToolConfig) -> Self {
+        self.config = config;
+        self
+    }
+
     /// Sanitize git arguments to prevent injection attacks
     fn sanitize_git_args(&self, args: &str) -> anyhow::Result<Vec<String>> {
         let mut result = Vec::new();
@@ -55,7 +65,15 @@ impl GitOperationsTool {
     fn requires_write_access(&self, operation: &str) -> bool {
         matches!(
             operation,
-            "commit" | "add" | "checkout" | "stash" | "reset" | "revert" | "worktree"
+            "commit"
+                | "add"
+                | "checkout"
+                | "stash"
+                | "reset"
+                | "revert"
+                | "worktree"
+                | "push"
+                | "pull"
         )
     }
 
@@ -67,9 +85,10 @@ impl GitOperationsTool {
         )
     }
 
-    /// Resolve a user-provided path to an absolute path within the workspace.
+    /// Resolve a user-provided path to an absolute path within the workspace
+    /// or one of the configured `allowed_repos`.
     /// Returns the workspace_dir if no path is provided.
-    /// Rejects paths that escape the workspace via traversal.
+    /// Rejects paths that escape those roots via traversal.
     fn resolve_working_dir(&self, path: Option<&str>) -> anyhow::Result<std::path::PathBuf> {
         let base = match path {
             Some(p) if !p.is_empty() => {
@@ -95,7 +114,11 @@ impl GitOperationsTool {
                     .workspace_dir
                     .canonicalize()
                     .unwrap_or_else(|_| self.workspace_dir.clone());
-                if !resolved.starts_with(&workspace_canonical) {
+                let in_allowed_repo = self.config.allowed_repos.iter().any(|repo| {
+                    let root = PathBuf::from(repo);
+                    resolved.starts_with(root.canonicalize().unwrap_or(root))
+                });
+                if !resolved.starts_with(&workspace_canonical) && !in_allowed_repo {
                     anyhow::bail!("Path '{}' resolves outside the workspace directory", p);
                 }
                 resolved
@@ -204,11 +227,21 @@ impl GitOperationsTool {
         &self,
         args: &[&str],
         working_dir: &std::path::Path,
+    ) -> anyhow::Result<String> {
+        self.run_git_command_with_env(args, working_dir, &[]).await
+    }
+
+    async fn run_git_command_with_env(
+        &self,
+        args: &[&str],
+        working_dir: &std::path::Path,
+        envs: &[(&str, &str)],
     ) -> anyhow::Result<String> {
         let output = tokio::process::Command::new("git")
             .args(args)
             .current_dir(working_dir)
             .env("GIT_TERMINAL_PROMPT", "0")
+            .envs(envs.iter().copied())
             .stdin(std::process::Stdio::null())
             .output()
             .await?;
@@ -221,6 +254,21 @@ impl GitOperationsTool {
         Ok(String::from_utf8_lossy(&output.stdout).to_string())
     }
 
+    /// Cut raw git output to `max_output_bytes` at the last complete line.
+    /// Returns the kept text and whether anything was dropped.
+    fn cap_output(&self, output: String) -> (String, bool) {
+        let max = self.config.max_output_bytes;
+        if max == 0 || output.len() <= max {
+            return (output, false);
+        }
+        let mut end = max;
+        while !output.is_char_boundary(end) {
+            end -= 1;
+        }
+        let end = output[..end].rfind('\n').map_or(end, |i| i + 1);
+        (output[..end].to_string(), true)
+    }
+
     async fn git_status(
         &self,
         _args: serde_json::Value,
@@ -229,6 +277,7 @@ impl GitOperationsTool {
         let output = self
             .run_git_command(&["status", "--porcelain=2", "--branch"], working_dir)
             .await?;
+        let (output, truncated) = self.cap_output(output);
 
         // Parse git status output into structured format
         let mut result = serde_json::Map::new();
@@ -268,6 +317,7 @@ impl GitOperationsTool {
             "clean".to_string(),
             json!(staged.is_empty() && unstaged.is_empty() && untracked.is_empty()),
         );
+        result.insert("truncated".to_string(), json!(truncated));
 
         Ok(ToolResult {
             success: true,
@@ -300,6 +350,7 @@ impl GitOperationsTool {
         git_args.push(files);
 
         let output = self.run_git_command(&git_args, working_dir).await?;
+        let (output, truncated) = self.cap_output(output);
 
         // Parse diff into structured hunks
         let mut result = serde_json::Map::new();
@@ -353,6 +404,7 @@ impl GitOperationsTool {
 
         result.insert("hunks".to_string(), json!(hunks));
         result.insert("file_count".to_string(), json!(hunks.len()));
+        result.insert("truncated".to_string(), json!(truncated));
 
         Ok(ToolResult {
             success: true,
@@ -509,8 +561,26 @@ impl GitOperationsTool {
         // Limit message length
         let message = Self::truncate_commit_message(&sanitized);
 
+        if let Some(denied) = self.denied_staged_path(working_dir).await? {
+            return Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(format!(
+                    "Commit refused: staged file '{denied}' is not writable under the security policy"
+                )),
+            });
+        }
+
+        let mut identity: Vec<(&str, &str)> = Vec::new();
+        if let Some(name) = self.config.author_name.as_deref() {
+            identity.extend([("GIT_AUTHOR_NAME", name), ("GIT_COMMITTER_NAME", name)]);
+        }
+        if let Some(email) = self.config.author_email.as_deref() {
+            identity.extend([("GIT_AUTHOR_EMAIL", email), ("GIT_COMMITTER_EMAIL", email)]);
+        }
+
         let output = self
-            .run_git_command(&["commit", "-m", &message], working_dir)
+            .run_git_command_with_env(&["commit", "-m", &message], working_dir, &identity)
             .await;
 
         match output {
@@ -527,6 +597,36 @@ impl GitOperationsTool {
         }
     }
 
+    /// First staged path the security policy would not let the agent write,
+    /// relative to the repository root.
+    async fn denied_staged_path(&self, working_dir: &Path) -> anyhow::Result<Option<String>> {
+        let toplevel = self
+            .run_git_command(&["rev-parse", "--show-toplevel"], working_dir)
+            .await?;
+        let toplevel = PathBuf::from(toplevel.trim());
+        let staged = self
+            .run_git_command(&["diff", "--cached", "--name-only", "-z"], working_dir)
+            .await?;
+
+        for rel in staged.split('\0').filter(|p| !p.is_empty()) {
+            let path = toplevel.join(rel);
+            // Judge the file by where its parent really lives so a symlinked
+            // directory cannot smuggle an outside path into the commit.
+            let resolved = match (path.parent(), path.file_name()) {
+                (Some(parent), Some(name)) => parent
+                    .canonicalize()
+                    .map_or_else(|_| path.clone(), |p| p.join(name)),
+                _ => path.clone(),
+            };
+            if !self.security.is_resolved_path_allowed(&resolved)
+                || self.security.is_runtime_config_path(&resolved)
+            {
+                return Ok(Some(rel.to_string()));
+            }
+        }
+        Ok(None)
+    }
+
     async fn git_add(
         &self,
         args: serde_json::Value,
@@ -618,6 +718,49 @@ impl GitOperationsTool {
         }
     }
 
+    /// `push` / `pull` against a named remote. Only reachable when
+    /// `[git_tool] allow_remote` is set; `pull` is fast-forward only.
+    async fn git_remote(
+        &self,
+        operation: &str,
+        args: serde_json::Value,
+        working_dir: &std::path::Path,
+    ) -> anyhow::Result<ToolResult> {
+        let mut refs = Vec::new();
+        for (key, default) in [("remote", Some("origin")), ("branch", None)] {
+            let Some(value) = args.get(key).and_then(|v| v.as_str()).or(default) else {
+                continue;
+            };
+            let sanitized = self.sanitize_git_args(value)?;
+            if sanitized.len() != 1 || sanitized[0].starts_with('-') {
+                anyhow::bail!("Invalid {key} specification");
+            }
+            refs.push(sanitized.into_iter().next().unwrap_or_default());
+        }
+
+        let mut git_args: Vec<&str> = match operation {
+            "push" => vec!["push"],
+            _ => vec!["pull", "--ff-only"],
+        };
+        git_args.extend(refs.iter().map(String::as_str));
+
+        match self.run_git_command(&git_args, working_dir).await {
+            Ok(output) => {
+                let (output, _) = self.cap_output(output);
+                Ok(ToolResult {
+                    success: true,
+                    output: format!("git {}: ok\n{}", git_args.join(" "), output.trim_end()).into(),
+                    error: None,
+                })
+            }
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(format!("{operation} failed: {e}")),
+            }),
+        }
+    }
+
     async fn git_stash(
         &self,
         args: serde_json::Value,
@@ -859,16 +1002,26 @@ impl Tool for GitOperationsTool {
     }
 
     fn description(&self) -> &str {
-        "Perform structured Git operations (status, diff, log, branch, commit, add, checkout, stash, worktree). Provides parsed JSON output and integrates with security policy for autonomy controls."
+        if self.config.allow_remote {
+            "Perform structured Git operations (status, diff, log, branch, commit, add, checkout, stash, worktree, push, pull). Provides parsed JSON output and integrates with security policy for autonomy controls. push and pull are medium-risk and may need approved=true."
+        } else {
+            "Perform structured Git operations (status, diff, log, branch, commit, add, checkout, stash, worktree). Provides parsed JSON output and integrates with security policy for autonomy controls."
+        }
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
-        json!({
+        let mut operations = vec![
+            "status", "diff", "log", "branch", "commit", "add", "checkout", "stash", "worktree",
+        ];
+        if self.config.allow_remote {
+            operations.extend(["push", "pull"]);
+        }
+        let mut schema = json!({
             "type": "object",
             "properties": {
                 "operation": {
                     "type": "string",
-                    "enum": ["status", "diff", "log", "branch", "commit", "add", "checkout", "stash", "worktree"],
+                    "enum": operations,
                     "description": "Git operation to perform"
                 },
                 "subcommand": {
@@ -886,7 +1039,7 @@ impl Tool for GitOperationsTool {
                 },
                 "branch": {
                     "type": "string",
-                    "description": "Branch name (for 'checkout' operation or 'worktree add' subcommand)"
+                    "description": "Branch name (for 'checkout' operation, 'worktree add' subcommand, or the remote branch for 'push'/'pull')"
                 },
                 "worktree_path": {
                     "type": "string",
@@ -923,11 +1076,28 @@ impl Tool for GitOperationsTool {
                 },
                 "path": {
                     "type": "string",
-                    "description": "Optional subdirectory path within the workspace to run git operations in. Defaults to workspace root."
+                    "description": "Optional subdirectory path within the workspace (or an allowed repository) to run git operations in. Defaults to workspace root."
                 }
             },
             "required": ["operation"]
-        })
+        });
+        if self.config.allow_remote {
+            schema["properties"]["remote"] = json!({
+                "type": "string",
+                "description": "Remote name for 'push'/'pull' (default: 'origin')"
+            });
+            schema["properties"]["approved"] = json!({
+                "type": "boolean",
+                "description": "Explicit approval for medium-risk 'push'/'pull' in supervised mode"
+            });
+        }
+        schema
+    }
+
+    fn mutates(&self, args: &serde_json::Value) -> bool {
+        args.get("operation")
+            .and_then(serde_json::Value::as_str)
+            .is_none_or(|operation| !self.is_read_only(operation))
     }
 
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
@@ -981,6 +1151,17 @@ impl Tool for GitOperationsTool {
             }
         }
 
+        let is_remote = matches!(operation, "push" | "pull");
+        if is_remote && !self.config.allow_remote {
+            return Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(format!(
+                    "Unknown operation: {operation} (remote operations require [git_tool] allow_remote = true)"
+                )),
+            });
+        }
+
         // Check autonomy level for write operations
         if self.requires_write_access(operation) {
             if !self.security.can_act() {
@@ -1005,6 +1186,26 @@ impl Tool for GitOperationsTool {
             }
         }
 
+        // Remote operations are medium-risk: same approval gate as a
+        // medium-risk shell command.
+        let approved = args
+            .get("approved")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if is_remote
+            && self.security.autonomy == AutonomyLevel::Supervised
+            && self.security.require_approval_for_medium_risk
+            && !approved
+        {
+            return Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(format!(
+                    "git {operation} requires explicit approval (approved=true): medium-risk operation"
+                )),
+            });
+        }
+
         // Record action for rate limiting
         if !self.security.record_action() {
             return Ok(ToolResult {
@@ -1025,6 +1226,8 @@ impl Tool for GitOperationsTool {
             "checkout" => self.git_checkout(args, &working_dir).await,
             "stash" => self.git_stash(args, &working_dir).await,
             "worktree" => self.git_worktree(args, &working_dir).await,
+            "push" => self.git_remote("push", args, &working_dir).await,
+            "pull" => self.git_remote("pull", args, &working_dir).await,
             _ => Ok(ToolResult {
                 success: false,
                 output: ToolOutput::default(),
@@ -1084,6 +1287,10 @@ mod tests {
         GitOperationsTool::new(security, dir.to_path_buf())
     }
 
+    fn test_tool_with_config(dir: &std::path::Path, config: GitToolConfig) -> GitOperationsTool {
+        test_tool(dir).with_config(config)
+    }
+
     #[test]
     fn sanitize_git_blocks_injection() {
         let tmp = TempDir::new().unwrap();
@@ -1419,6 +1626,142 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn commit_uses_configured_author_identity() {
+        let tmp = TempDir::new().unwrap();
+        git_init_no_sign(tmp.path(), &[]);
+        std::fs::write(tmp.path().join("README.md"), "hello").unwrap();
+        std::process::Command::new("git")
+            .args(["add", "."])
+            .current_dir(tmp.path())
+            .output()
+            .unwrap();
+
+        let tool = test_tool_with_config(
+            tmp.path(),
+            GitToolConfig {
+                author_name: Some("ZeroClaw Bot".into()),
+                author_email: Some("bot@zeroclaw.test".into()),
+                ..GitToolConfig::default()
+            },
+        );
+        let result = tool
+            .execute(json!({"operation": "commit", "message": "init"}))
+            .await
+            .unwrap();
+        assert!(result.success, "commit failed: {:?}", result.error);
+
+        let log_out = std::process::Command::new("git")
+            .args(["log", "-1", "--format=%an <%ae>|%cn <%ce>"])
+            .current_dir(tmp.path())
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&log_out.stdout).trim(),
+            "ZeroClaw Bot <bot@zeroclaw.test>|ZeroClaw Bot <bot@zeroclaw.test>"
+        );
+    }
+
+    #[tokio::test]
+    async fn commit_refuses_staged_files_outside_policy() {
+        let workspace = TempDir::new().unwrap();
+        let repo = TempDir::new().unwrap();
+        git_init_no_sign(repo.path(), &[]);
+        std::fs::write(repo.path().join("notes.txt"), "outside").unwrap();
+        std::process::Command::new("git")
+            .args(["add", "."])
+            .current_dir(repo.path())
+            .output()
+            .unwrap();
+
+        // The repo is reachable through `allowed_repos`, but its files are
+        // not writable under the policy, so the commit must be refused.
+        let tool = test_tool_with_config(
+            workspace.path(),
+            GitToolConfig {
+                allowed_repos: vec![repo.path().display().to_string()],
+                ..GitToolConfig::default()
+            },
+        );
+        let repo_path = repo.path().display().to_string();
+
+        let status = tool
+            .execute(json!({"operation": "status", "path": repo_path}))
+            .await
+            .unwrap();
+        assert!(status.success, "status failed: {:?}", status.error);
+
+        let result = tool
+            .execute(json!({"operation": "commit", "message": "x", "path": repo_path}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(
+            result.error.as_deref().unwrap_or("").contains("notes.txt"),
+            "{:?}",
+            result.error
+        );
+    }
+
+    #[tokio::test]
+    async fn remote_operations_need_opt_in_and_approval() {
+        let tmp = TempDir::new().unwrap();
+        git_init_no_sign(tmp.path(), &[]);
+
+        let hidden = test_tool(tmp.path());
+        assert!(
+            !hidden.parameters_schema()["properties"]["operation"]["enum"]
+                .as_array()
+                .unwrap()
+                .contains(&json!("push"))
+        );
+
+        let tool = test_tool_with_config(
+            tmp.path(),
+            GitToolConfig {
+                allow_remote: true,
+                ..GitToolConfig::default()
+            },
+        );
+        assert!(
+            tool.parameters_schema()["properties"]["operation"]["enum"]
+                .as_array()
+                .unwrap()
+                .contains(&json!("pull"))
+        );
+        let result = tool.execute(json!({"operation": "push"})).await.unwrap();
+        assert!(!result.success);
+        assert!(
+            result
+                .error
+                .as_deref()
+                .unwrap_or("")
+                .contains("approved=true"),
+            "{:?}",
+            result.error
+        );
+    }
+
+    #[test]
+    fn cap_output_cuts_at_line_boundary() {
+        let tmp = TempDir::new().unwrap();
+        let tool = test_tool_with_config(
+            tmp.path(),
+            GitToolConfig {
+                max_output_bytes: 10,
+                ..GitToolConfig::default()
+            },
+        );
+
+        let (kept, truncated) = tool.cap_output("line one\nline two\n".into());
+        assert!(truncated);
+        assert_eq!(kept, "line one\n");
+
+        let (kept, truncated) = tool.cap_output("short\n".into());
+        assert!(!truncated);
+        assert_eq!(kept, "short\n");
+    }
+
     #[test]
     fn truncates_multibyte_commit_message_without_panicking() {
         let long = "🦀".repeat(2500);
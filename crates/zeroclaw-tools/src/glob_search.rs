@@ -42,6 +42,10 @@ impl Tool for GlobSearchTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let pattern = args
             .get("pattern")
@@ -72,8 +76,12 @@ impl Tool for GlobSearchTool {
             });
         }
 
-        // Security: reject path traversal
-        if pattern.contains("../") || pattern.contains("..\\") || pattern == ".." {
+        // Security: reject path traversal anywhere in the pattern, including
+        // a trailing `dir/..` that the substring checks used to miss.
+        if std::path::Path::new(pattern)
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
             return Ok(ToolResult {
                 success: false,
                 output: ToolOutput::default(),
@@ -303,6 +311,15 @@ mod tests {
         assert!(result.error.as_ref().unwrap().contains("Path traversal"));
     }
 
+    #[tokio::test]
+    async fn glob_search_rejects_trailing_dotdot() {
+        let tool = GlobSearchTool::new(test_security(std::env::temp_dir()));
+        let result = tool.execute(json!({"pattern": "sub/.."})).await.unwrap();
+
+        assert!(!result.success);
+        assert!(result.error.as_ref().unwrap().contains("Path traversal"));
+    }
+
     #[tokio::test]
     async fn glob_search_rejects_dotdot_only() {
         let tool = GlobSearchTool::new(test_security(std::env::temp_dir()));
@@ -85,6 +85,10 @@ impl Tool for HardwareBoardInfoTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let board = args
             .get("board")
@@ -58,6 +58,10 @@ impl Tool for HardwareMemoryReadTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         if self.boards.is_empty() {
             return Ok(ToolResult {
@@ -1 +1,2 @@
 pub mod domain_guard;
+pub mod response_transform;
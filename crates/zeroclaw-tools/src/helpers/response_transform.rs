@@ -0,0 +1,218 @@
+//! Response shaping for HTTP tools: `jq`-style path extraction from JSON
+//! bodies and a readability pass that reduces HTML to its main text.
+
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Step {
+    Key(String),
+    Index(i64),
+    Each,
+}
+
+fn parse_path(expr: &str) -> anyhow::Result<Vec<Step>> {
+    let expr = expr.trim();
+    let Some(rest) = expr.strip_prefix('.') else {
+        anyhow::bail!("extract path must start with '.', e.g. .data.items[0].name");
+    };
+    let chars: Vec<char> = rest.chars().collect();
+    let mut steps = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '.' => i += 1,
+            '[' => {
+                let close = chars[i..]
+                    .iter()
+                    .position(|c| *c == ']')
+                    .map(|p| i + p)
+                    .ok_or_else(|| anyhow::anyhow!("unclosed '[' in extract path"))?;
+                let inner: String = chars[i + 1..close].iter().collect();
+                let inner = inner.trim();
+                if inner.is_empty() {
+                    steps.push(Step::Each);
+                } else if let Some(quoted) =
+                    inner.strip_prefix('"').and_then(|s| s.strip_suffix('"'))
+                {
+                    steps.push(Step::Key(quoted.to_string()));
+                } else {
+                    let index = inner.parse::<i64>().map_err(|_| {
+                        anyhow::anyhow!("invalid index '[{inner}]' in extract path")
+                    })?;
+                    steps.push(Step::Index(index));
+                }
+                i = close + 1;
+            }
+            _ => {
+                let start = i;
+                while i < chars.len() && chars[i] != '.' && chars[i] != '[' {
+                    i += 1;
+                }
+                steps.push(Step::Key(chars[start..i].iter().collect()));
+            }
+        }
+    }
+    Ok(steps)
+}
+
+fn apply(values: Vec<Value>, step: &Step) -> anyhow::Result<Vec<Value>> {
+    let mut out = Vec::new();
+    for value in values {
+        match (step, value) {
+            (Step::Key(key), Value::Object(mut map)) => {
+                out.push(map.remove(key).unwrap_or(Value::Null));
+            }
+            (Step::Key(_), Value::Null) => out.push(Value::Null),
+            (Step::Index(index), Value::Array(mut items)) => {
+                let len = i64::try_from(items.len()).unwrap_or(i64::MAX);
+                let resolved = if *index < 0 { len + index } else { *index };
+                let item = usize::try_from(resolved)
+                    .ok()
+                    .filter(|i| *i < items.len())
+                    .map(|i| items.swap_remove(i));
+                out.push(item.unwrap_or(Value::Null));
+            }
+            (Step::Each, Value::Array(items)) => out.extend(items),
+            (Step::Each, Value::Object(map)) => out.extend(map.into_values()),
+            (step, value) => {
+                let kind = match value {
+                    Value::Null => "null",
+                    Value::Bool(_) => "boolean",
+                    Value::Number(_) => "number",
+                    Value::String(_) => "string",
+                    Value::Array(_) => "array",
+                    Value::Object(_) => "object",
+                };
+                let wanted = match step {
+                    Step::Key(key) => format!("key '{key}'"),
+                    Step::Index(index) => format!("index [{index}]"),
+                    Step::Each => "iterator []".into(),
+                };
+                anyhow::bail!("cannot apply {wanted} to a {kind}");
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Evaluate a `jq`-style path (`.data.items[0].name`, `.items[].id`,
+/// `.["odd key"]`, `.[-1]`) against `value`. Missing keys and out-of-range
+/// indexes yield `null`, as in `jq`. A path containing `[]` returns an array
+/// of every match.
+pub fn extract_json_path(value: &Value, expr: &str) -> anyhow::Result<Value> {
+    let steps = parse_path(expr)?;
+    let iterates = steps.contains(&Step::Each);
+    let mut values = vec![value.clone()];
+    for step in &steps {
+        values = apply(values, step)?;
+    }
+    if iterates {
+        Ok(Value::Array(values))
+    } else {
+        Ok(values.into_iter().next().unwrap_or(Value::Null))
+    }
+}
+
+/// Body of the first `<tag ...>...</tag>` element, case-insensitive.
+fn element_body<'a>(html: &'a str, lower: &str, tag: &str) -> Option<&'a str> {
+    let open = format!("<{tag}");
+    let mut from = 0;
+    let start = loop {
+        let at = from + lower[from..].find(&open)?;
+        let next = lower[at + open.len()..].chars().next()?;
+        if next == '>' || next.is_whitespace() {
+            break at;
+        }
+        from = at + open.len();
+    };
+    let body_start = start + lower[start..].find('>')? + 1;
+    let end = body_start + lower[body_start..].find(&format!("</{tag}>"))?;
+    Some(&html[body_start..end])
+}
+
+/// Reduce an HTML page to readable text: the first `<article>` or `<main>`
+/// element when the page has one (dropping navigation and chrome), else the
+/// whole document, converted to plain text with blank runs collapsed.
+pub fn readable_text(html: &str) -> String {
+    let lower = html.to_ascii_lowercase();
+    let content = ["article", "main"]
+        .iter()
+        .find_map(|tag| element_body(html, &lower, tag))
+        .unwrap_or(html);
+    let text = nanohtml2text::html2text(content);
+    let mut out = String::with_capacity(text.len());
+    let mut blank_run = 0;
+    for line in text.lines() {
+        let line = line.trim_end();
+        if line.trim().is_empty() {
+            blank_run += 1;
+            if blank_run > 1 {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    out.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn extract_walks_keys_indexes_and_iterators() {
+        let body = json!({
+            "data": {"items": [{"id": 1, "name": "a"}, {"id": 2, "name": "b"}]},
+            "odd key": true
+        });
+        assert_eq!(
+            extract_json_path(&body, ".data.items[0].name").unwrap(),
+            json!("a")
+        );
+        assert_eq!(
+            extract_json_path(&body, ".data.items[-1].id").unwrap(),
+            json!(2)
+        );
+        assert_eq!(
+            extract_json_path(&body, ".data.items[].id").unwrap(),
+            json!([1, 2])
+        );
+        assert_eq!(
+            extract_json_path(&body, ".[\"odd key\"]").unwrap(),
+            json!(true)
+        );
+        assert_eq!(
+            extract_json_path(&body, ".missing.deeper").unwrap(),
+            Value::Null
+        );
+        assert_eq!(extract_json_path(&body, ".").unwrap(), body);
+    }
+
+    #[test]
+    fn extract_rejects_bad_paths_and_type_mismatches() {
+        let body = json!({"data": "text"});
+        assert!(extract_json_path(&body, "data").is_err());
+        assert!(extract_json_path(&body, ".data[0").is_err());
+        let err = extract_json_path(&body, ".data[0]").unwrap_err();
+        assert!(err.to_string().contains("string"), "{err}");
+    }
+
+    #[test]
+    fn readable_text_prefers_main_content() {
+        let html = "<html><body><nav>Home | About</nav>\
+                    <article><h1>Title</h1><p>Body <b>text</b></p></article>\
+                    <footer>Copyright</footer></body></html>";
+        let text = readable_text(html);
+        assert!(text.contains("Title"));
+        assert!(text.contains("Body text"));
+        assert!(!text.contains("Home | About"));
+        assert!(!text.contains("Copyright"));
+
+        let plain = readable_text("<p>No <i>landmarks</i></p>");
+        assert_eq!(plain, "No landmarks");
+    }
+}
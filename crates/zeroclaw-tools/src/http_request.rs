@@ -1,7 +1,10 @@
 use crate::helpers::domain_guard;
+use crate::helpers::response_transform::{extract_json_path, readable_text};
 use async_trait::async_trait;
+use base64::Engine;
 use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderName, HeaderValue};
 use serde_json::json;
+use std::collections::HashMap;
 use std::future::Future;
 use std::net::{IpAddr, SocketAddr};
 use std::path::{Path, PathBuf};
@@ -10,6 +13,7 @@ use std::sync::Arc;
 use std::time::Duration;
 use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
 use zeroclaw_config::policy::SecurityPolicy;
+use zeroclaw_config::schema::{HttpRequestAuth, HttpRequestProfileConfig};
 
 /// HTTP request tool for API interactions.
 /// Supports GET, POST, PUT, DELETE methods with configurable security.
@@ -22,6 +26,18 @@ pub struct HttpRequestTool {
     allowed_private_hosts: Vec<String>,
     config_path: Option<PathBuf>,
     secrets_encrypt: bool,
+    profiles: HashMap<String, HttpRequestProfileConfig>,
+}
+
+/// How the response body is shaped before it reaches the model.
+#[derive(Debug, Default)]
+struct ResponseOptions {
+    /// `jq`-style path applied to a JSON body.
+    extract: Option<String>,
+    /// Reduce an HTML body to readable text.
+    text: bool,
+    /// Per-call byte cap, never above `max_response_size`.
+    max_bytes: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -62,6 +78,7 @@ impl HttpRequestTool {
             )?,
             config_path: None,
             secrets_encrypt: false,
+            profiles: HashMap::new(),
         })
     }
     pub fn new_with_config(
@@ -89,9 +106,59 @@ impl HttpRequestTool {
             )?,
             config_path: Some(config_path),
             secrets_encrypt,
+            profiles: HashMap::new(),
         })
     }
 
+    /// Named request profiles from `[http_request.profiles]`.
+    #[must_use]
+    pub fn with_profiles(mut self, profiles: HashMap<String, HttpRequestProfileConfig>) -> Self {
+        self.profiles = profiles;
+        self
+    }
+
+    fn profile(&self, name: &str) -> anyhow::Result<&HttpRequestProfileConfig> {
+        self.profiles.get(name).ok_or_else(|| {
+            let mut known: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+            known.sort_unstable();
+            if known.is_empty() {
+                anyhow::Error::msg(format!(
+                    "Unknown profile '{name}': no [http_request.profiles] are configured"
+                ))
+            } else {
+                anyhow::Error::msg(format!(
+                    "Unknown profile '{name}'. Configured profiles: {}",
+                    known.join(", ")
+                ))
+            }
+        })
+    }
+
+    /// Profile headers, then per-call headers (which win), then the
+    /// profile's Authorization. `auth_secret` is applied afterwards and
+    /// overrides all of them.
+    fn build_headers(
+        &self,
+        profile: Option<(&str, &HttpRequestProfileConfig)>,
+        call_headers: &serde_json::Value,
+    ) -> anyhow::Result<HeaderMap> {
+        let mut headers = match profile {
+            Some((_, profile)) => self.parse_headers(&json!(profile.headers))?,
+            None => HeaderMap::new(),
+        };
+        for (name, value) in self.parse_headers(call_headers)? {
+            if let Some(name) = name {
+                headers.insert(name, value);
+            }
+        }
+        if let Some((name, profile)) = profile
+            && let Some(value) = profile_authorization(name, profile)?
+        {
+            headers.insert(AUTHORIZATION, value);
+        }
+        Ok(headers)
+    }
+
     #[cfg(test)]
     fn validate_url(&self, raw_url: &str) -> anyhow::Result<String> {
         Ok(self.validate_url_policy(raw_url)?.url)
@@ -141,6 +208,11 @@ impl HttpRequestTool {
             anyhow::bail!("Host '{host}' is not in http_request.allowed_domains");
         }
 
+        self.security.check_network_host(
+            &host,
+            private_host_explicitly_allowed || self.allow_private_hosts,
+        )?;
+
         let private_resolution_allowed = self.allow_private_hosts
             || domain_guard::host_matches_allowlist(&host, &self.allowed_private_hosts);
 
@@ -351,12 +423,12 @@ impl HttpRequestTool {
         } else {
             self.timeout_secs
         };
-        let builder = reqwest::Client::builder()
+        let builder = self
+            .security
+            .network_client_builder("tool.http_request")
             .timeout(Duration::from_secs(timeout_secs))
             .connect_timeout(Duration::from_secs(10))
             .redirect(reqwest::redirect::Policy::none());
-        let builder =
-            zeroclaw_config::schema::apply_runtime_proxy_to_builder(builder, "tool.http_request");
         let builder = if target.host.parse::<IpAddr>().is_ok() {
             builder
         } else {
@@ -374,21 +446,159 @@ impl HttpRequestTool {
     }
 
     fn truncate_response(&self, text: &str) -> String {
-        // 0 means unlimited — no truncation.
-        if self.max_response_size == 0 {
-            return text.to_string();
+        truncate_to_bytes(text, self.response_limit(None))
+    }
+
+    /// Effective byte cap: the per-call `max_bytes` when smaller than the
+    /// configured limit (0 = unlimited).
+    fn response_limit(&self, max_bytes: Option<usize>) -> usize {
+        match (self.max_response_size, max_bytes.filter(|n| *n > 0)) {
+            (0, Some(requested)) => requested,
+            (limit, Some(requested)) => limit.min(requested),
+            (limit, None) => limit,
         }
-        if text.len() > self.max_response_size {
-            let mut truncated = text
-                .chars()
-                .take(self.max_response_size)
-                .collect::<String>();
-            truncated.push_str("\n\n... [Response truncated due to size limit] ...");
-            truncated
+    }
+
+    /// Apply extraction or text mode, then the byte cap.
+    fn shape_response(&self, text: &str, options: &ResponseOptions) -> anyhow::Result<String> {
+        let shaped = if let Some(path) = &options.extract {
+            let value: serde_json::Value = serde_json::from_str(text).map_err(|e| {
+                anyhow::Error::msg(format!("extract requires a JSON response body: {e}"))
+            })?;
+            serde_json::to_string_pretty(&extract_json_path(&value, path)?)?
+        } else if options.text {
+            readable_text(text)
         } else {
             text.to_string()
-        }
+        };
+        Ok(truncate_to_bytes(
+            &shaped,
+            self.response_limit(options.max_bytes),
+        ))
+    }
+}
+
+/// Cut `text` to at most `limit` bytes on a char boundary and append a
+/// marker saying how much was dropped. 0 means unlimited.
+fn truncate_to_bytes(text: &str, limit: usize) -> String {
+    if limit == 0 || text.len() <= limit {
+        return text.to_string();
+    }
+    let mut end = limit;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!(
+        "{}\n\n... [Response truncated: showing {end} of {} bytes] ...",
+        &text[..end],
+        text.len()
+    )
+}
+
+/// Resolve a call's `url` against a profile's `base_url`. Paths are appended
+/// to the base path; absolute URLs must share the base origin. Anything that
+/// lands outside the base origin or path (`//host`, `..`, backslashes) is
+/// rejected, so profile credentials only reach the profile's own API.
+fn resolve_profile_url(name: &str, base_url: &str, url: &str) -> anyhow::Result<String> {
+    let base_url = base_url.trim();
+    if base_url.is_empty() {
+        anyhow::bail!("Profile '{name}' has no base_url");
+    }
+    let base = reqwest::Url::parse(base_url).map_err(|e| {
+        anyhow::Error::msg(format!("Profile '{name}' has an invalid base_url: {e}"))
+    })?;
+    if !matches!(base.scheme(), "http" | "https") {
+        anyhow::bail!("Profile '{name}' base_url must be http:// or https://");
     }
+    if base.query().is_some() || base.fragment().is_some() {
+        anyhow::bail!("Profile '{name}' base_url must not carry a query or fragment");
+    }
+
+    let url = url.trim();
+    let candidate = if url.starts_with("http://") || url.starts_with("https://") {
+        url.to_string()
+    } else {
+        if url.starts_with("//") || url.contains('\\') || url.contains("://") {
+            anyhow::bail!("Profile '{name}': url must be a path relative to base_url");
+        }
+        format!(
+            "{}/{}",
+            base.as_str().trim_end_matches('/'),
+            url.trim_start_matches('/')
+        )
+    };
+    let resolved = reqwest::Url::parse(&candidate)
+        .map_err(|e| anyhow::Error::msg(format!("Invalid URL format: {e}")))?;
+
+    let same_origin = resolved.scheme() == base.scheme()
+        && resolved.host_str() == base.host_str()
+        && resolved.port_or_known_default() == base.port_or_known_default();
+    let base_path = base.path().trim_end_matches('/');
+    let within_base = resolved.path() == base_path
+        || resolved
+            .path()
+            .strip_prefix(base_path)
+            .is_some_and(|rest| rest.starts_with('/'));
+    if !same_origin || !within_base {
+        anyhow::bail!("Profile '{name}': url '{url}' resolves outside base_url {base_url}");
+    }
+    Ok(resolved.to_string())
+}
+
+/// Authorization header for a profile, reading its secret from the
+/// environment at call time.
+fn profile_authorization(
+    name: &str,
+    profile: &HttpRequestProfileConfig,
+) -> anyhow::Result<Option<HeaderValue>> {
+    let secret = || -> anyhow::Result<String> {
+        let env_name = profile
+            .secret_env
+            .as_deref()
+            .map(str::trim)
+            .filter(|env| !env.is_empty())
+            .ok_or_else(|| {
+                anyhow::Error::msg(format!(
+                    "Profile '{name}' uses auth but has no secret_env configured"
+                ))
+            })?;
+        let value = std::env::var(env_name).map_err(|e| {
+            anyhow::Error::msg(format!(
+                "Profile '{name}' secret_env '{env_name}' could not be read: {e}"
+            ))
+        })?;
+        if value.is_empty() {
+            anyhow::bail!("Profile '{name}' secret_env '{env_name}' is empty");
+        }
+        Ok(value)
+    };
+    let value = match profile.auth {
+        HttpRequestAuth::None => return Ok(None),
+        HttpRequestAuth::Bearer => format!("Bearer {}", secret()?),
+        HttpRequestAuth::Basic => {
+            let username = profile
+                .username
+                .as_deref()
+                .filter(|user| !user.is_empty())
+                .ok_or_else(|| {
+                    anyhow::Error::msg(format!(
+                        "Profile '{name}' uses basic auth but has no username"
+                    ))
+                })?;
+            let credentials = format!("{username}:{}", secret()?);
+            format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(credentials)
+            )
+        }
+    };
+    let mut header = HeaderValue::from_str(&value).map_err(|e| {
+        anyhow::Error::msg(format!(
+            "Profile '{name}' credentials are not a valid Authorization header: {e}"
+        ))
+    })?;
+    header.set_sensitive(true);
+    Ok(Some(header))
 }
 
 fn resolve_env_backed_auth_secret(
@@ -441,6 +651,7 @@ impl Tool for HttpRequestTool {
 
     fn description(&self) -> &str {
         "Make HTTP requests to external APIs. Supports GET, POST, PUT, DELETE, PATCH, HEAD, OPTIONS methods. \
+        Named profiles supply a base URL, headers, and credentials; responses can be narrowed with a jq-style path, reduced from HTML to text, or capped in size. \
         Security constraints: allowlist-only domains, local/private hosts blocked unless explicitly configured, configurable timeout and response size limits."
     }
 
@@ -450,7 +661,11 @@ impl Tool for HttpRequestTool {
             "properties": {
                 "url": {
                     "type": "string",
-                    "description": "HTTP or HTTPS URL to request"
+                    "description": "HTTP or HTTPS URL to request, or a path relative to the profile's base_url when profile is set"
+                },
+                "profile": {
+                    "type": "string",
+                    "description": "Name of a request profile in [http_request.profiles] supplying base_url, default headers, and authentication"
                 },
                 "method": {
                     "type": "string",
@@ -469,6 +684,21 @@ impl Tool for HttpRequestTool {
                 "body": {
                     "type": "string",
                     "description": "Optional request body (for POST, PUT, PATCH requests)"
+                },
+                "extract": {
+                    "type": "string",
+                    "description": "jq-style path applied to a JSON response body, e.g. .data.items[0].name or .items[].id; only the match is returned"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["raw", "text"],
+                    "description": "raw returns the body as-is; text reduces an HTML page to its readable main content",
+                    "default": "raw"
+                },
+                "max_bytes": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Truncate the (extracted or converted) body to this many bytes; cannot exceed the configured max_response_size"
                 }
             },
             "required": ["url"]
@@ -488,6 +718,14 @@ impl Tool for HttpRequestTool {
         }))
     }
 
+    fn mutates(&self, args: &serde_json::Value) -> bool {
+        let method = args
+            .get("method")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("GET");
+        !method.eq_ignore_ascii_case("GET") && !method.eq_ignore_ascii_case("HEAD")
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let url = args.get("url").and_then(|v| v.as_str()).ok_or_else(|| {
             ::zeroclaw_log::record!(
@@ -516,6 +754,52 @@ impl Tool for HttpRequestTool {
             None => None,
         };
         let body = args.get("body").and_then(|v| v.as_str());
+        let profile_name = match args.get("profile") {
+            Some(serde_json::Value::String(name)) => Some(name.as_str()),
+            Some(serde_json::Value::Null) | None => None,
+            Some(_) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: ToolOutput::default(),
+                    error: Some("'profile' must be a string".into()),
+                });
+            }
+        };
+        let response_options = ResponseOptions {
+            extract: args
+                .get("extract")
+                .and_then(|v| v.as_str())
+                .map(str::trim)
+                .filter(|path| !path.is_empty())
+                .map(str::to_string),
+            text: match args.get("format").and_then(|v| v.as_str()) {
+                None | Some("raw") => false,
+                Some("text") => true,
+                Some(other) => {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: ToolOutput::default(),
+                        error: Some(format!(
+                            "Unsupported format '{other}'. Supported: raw, text"
+                        )),
+                    });
+                }
+            },
+            max_bytes: args
+                .get("max_bytes")
+                .and_then(serde_json::Value::as_u64)
+                .and_then(|n| usize::try_from(n).ok()),
+        };
+        if response_options.extract.is_some() && response_options.text {
+            return Ok(ToolResult {
+                success: false,
+                output: ToolOutput::default(),
+                error: Some(
+                    "'extract' applies to JSON bodies and cannot be combined with format=text"
+                        .into(),
+                ),
+            });
+        }
 
         if !self.security.can_act() {
             return Ok(ToolResult {
@@ -528,7 +812,34 @@ impl Tool for HttpRequestTool {
         // Rate limiting is applied by the RateLimitedTool wrapper at
         // registration time (see zeroclaw-runtime::tools::mod).
 
-        let target = match self.validate_request_target(url).await {
+        let profile = match profile_name.map(|name| self.profile(name).map(|p| (name, p))) {
+            Some(Ok(profile)) => Some(profile),
+            Some(Err(e)) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: ToolOutput::default(),
+                    error: Some(e.to_string()),
+                });
+            }
+            None => None,
+        };
+        // Resolve before validation so the allowlist and SSRF checks see the
+        // final URL, not the model-supplied fragment.
+        let url = match profile {
+            Some((name, profile)) => match resolve_profile_url(name, &profile.base_url, url) {
+                Ok(resolved) => resolved,
+                Err(e) => {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: ToolOutput::default(),
+                        error: Some(e.to_string()),
+                    });
+                }
+            },
+            None => url.to_string(),
+        };
+
+        let target = match self.validate_request_target(&url).await {
             Ok(v) => v,
             Err(e) => {
                 return Ok(ToolResult {
@@ -550,7 +861,7 @@ impl Tool for HttpRequestTool {
             }
         };
 
-        let mut request_headers = match self.parse_headers(&headers_val) {
+        let mut request_headers = match self.build_headers(profile, &headers_val) {
             Ok(h) => h,
             Err(e) => {
                 return Ok(ToolResult {
@@ -590,9 +901,16 @@ impl Tool for HttpRequestTool {
                     .collect::<Vec<_>>()
                     .join(", ");
 
-                // Get response body with size limit
+                // Get response body, shaped and capped
+                let mut shape_error = None;
                 let response_text = match response.text().await {
-                    Ok(text) => self.truncate_response(&text),
+                    Ok(text) => match self.shape_response(&text, &response_options) {
+                        Ok(shaped) => shaped,
+                        Err(e) => {
+                            shape_error = Some(e.to_string());
+                            self.truncate_response(&text)
+                        }
+                    },
                     Err(e) => format!("[Failed to read response body: {e}]"),
                 };
 
@@ -615,14 +933,15 @@ impl Tool for HttpRequestTool {
                     "body": body_value,
                 });
 
+                let error = if status.is_client_error() || status.is_server_error() {
+                    Some(format!("HTTP {}", status_code))
+                } else {
+                    shape_error.map(|e| format!("Response returned unshaped: {e}"))
+                };
                 Ok(ToolResult {
-                    success: status.is_success(),
+                    success: status.is_success() && error.is_none(),
                     output: ToolOutput::json_with_text(data, output),
-                    error: if status.is_client_error() || status.is_server_error() {
-                        Some(format!("HTTP {}", status_code))
-                    } else {
-                        None
-                    },
+                    error,
                 })
             }
             Err(e) => Ok(ToolResult {
@@ -777,6 +1096,16 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn only_get_and_head_requests_are_non_mutating() {
+        let tool = test_tool(vec!["example.com"]);
+        assert!(!tool.mutates(&json!({"url": "https://example.com"})));
+        assert!(!tool.mutates(&json!({"url": "https://example.com", "method": "get"})));
+        assert!(!tool.mutates(&json!({"url": "https://example.com", "method": "HEAD"})));
+        assert!(tool.mutates(&json!({"url": "https://example.com", "method": "POST"})));
+        assert!(tool.mutates(&json!({"url": "https://example.com", "method": "DELETE"})));
+    }
+
     #[test]
     fn schema_includes_auth_secret_parameter() {
         let tool = test_tool(vec!["example.com"]);
@@ -1292,6 +1621,191 @@ api_token = "Bearer from-secret"
         assert!(truncated.contains("[Response truncated"));
     }
 
+    #[test]
+    fn truncate_to_bytes_respects_char_boundaries() {
+        let text = "héllo wörld";
+        let truncated = truncate_to_bytes(text, 2);
+        assert!(truncated.starts_with("h\n\n"));
+        assert!(truncated.contains("showing 1 of 13 bytes"));
+        assert_eq!(truncate_to_bytes(text, 0), text);
+    }
+
+    fn profile(base_url: &str) -> HttpRequestProfileConfig {
+        HttpRequestProfileConfig {
+            base_url: base_url.into(),
+            ..HttpRequestProfileConfig::default()
+        }
+    }
+
+    #[test]
+    fn resolve_profile_url_appends_paths_within_base() {
+        let base = "https://api.example.com/v3/";
+        assert_eq!(
+            resolve_profile_url("gh", base, "repos/o/r?per_page=5").unwrap(),
+            "https://api.example.com/v3/repos/o/r?per_page=5"
+        );
+        assert_eq!(
+            resolve_profile_url("gh", base, "/user").unwrap(),
+            "https://api.example.com/v3/user"
+        );
+        assert_eq!(
+            resolve_profile_url("gh", base, "https://api.example.com/v3/rate_limit").unwrap(),
+            "https://api.example.com/v3/rate_limit"
+        );
+        assert_eq!(
+            resolve_profile_url("root", "https://api.example.com", "status").unwrap(),
+            "https://api.example.com/status"
+        );
+    }
+
+    #[test]
+    fn resolve_profile_url_rejects_origin_and_path_escapes() {
+        let base = "https://api.example.com/v3";
+        for url in [
+            "//evil.example.net/steal",
+            "https://evil.example.net/v3/user",
+            "http://api.example.com/v3/user",
+            "https://api.example.com:8443/v3/user",
+            "https://api.example.com/v3x/user",
+            "../admin",
+            "v3/../../admin",
+            "%2e%2e/admin",
+            "\\\\evil.example.net/x",
+            "ftp://api.example.com/v3",
+        ] {
+            assert!(
+                resolve_profile_url("gh", base, url).is_err(),
+                "{url} should be rejected"
+            );
+        }
+        assert!(resolve_profile_url("gh", "", "user").is_err());
+        assert!(resolve_profile_url("gh", "https://api.example.com/?k=1", "user").is_err());
+    }
+
+    #[tokio::test]
+    async fn profile_resolution_still_applies_allowlist() {
+        let mut tool = test_tool(vec!["example.com"]);
+        tool.profiles
+            .insert("other".into(), profile("https://api.other.org/v1"));
+        let result = tool
+            .execute(json!({"profile": "other", "url": "items"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(
+            result
+                .error
+                .unwrap()
+                .contains("not in http_request.allowed_domains")
+        );
+
+        let result = tool
+            .execute(json!({"profile": "missing", "url": "items"}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("Configured profiles: other"));
+    }
+
+    #[test]
+    fn profile_headers_merge_under_call_headers_and_auth() {
+        let tool = test_tool(vec!["example.com"]);
+        let mut gh = profile("https://api.example.com");
+        gh.headers
+            .insert("Accept".into(), "application/json".into());
+        gh.headers.insert("X-Client".into(), "zeroclaw".into());
+        gh.headers.insert("Authorization".into(), "literal".into());
+        gh.auth = HttpRequestAuth::Bearer;
+        gh.secret_env = Some("ZEROCLAW_TEST_HTTP_PROFILE_TOKEN".into());
+        unsafe {
+            std::env::set_var("ZEROCLAW_TEST_HTTP_PROFILE_TOKEN", "tok-123");
+        }
+        scopeguard::defer! {
+            unsafe {
+                std::env::remove_var("ZEROCLAW_TEST_HTTP_PROFILE_TOKEN");
+            }
+        }
+
+        let headers = tool
+            .build_headers(Some(("gh", &gh)), &json!({"X-Client": "override"}))
+            .unwrap();
+        assert_eq!(headers.get("accept").unwrap(), "application/json");
+        assert_eq!(headers.get("x-client").unwrap(), "override");
+        assert_eq!(headers.get(AUTHORIZATION).unwrap(), "Bearer tok-123");
+        assert!(headers.get(AUTHORIZATION).unwrap().is_sensitive());
+
+        gh.auth = HttpRequestAuth::Basic;
+        assert!(
+            tool.build_headers(Some(("gh", &gh)), &json!({}))
+                .unwrap_err()
+                .to_string()
+                .contains("no username")
+        );
+        gh.username = Some("alice".into());
+        let headers = tool.build_headers(Some(("gh", &gh)), &json!({})).unwrap();
+        // base64("alice:tok-123")
+        assert_eq!(
+            headers.get(AUTHORIZATION).unwrap(),
+            "Basic YWxpY2U6dG9rLTEyMw=="
+        );
+
+        gh.secret_env = Some("ZEROCLAW_TEST_HTTP_PROFILE_UNSET".into());
+        assert!(tool.build_headers(Some(("gh", &gh)), &json!({})).is_err());
+    }
+
+    #[test]
+    fn shape_response_extracts_converts_and_caps() {
+        let tool = test_tool(vec!["example.com"]);
+        let body = r#"{"items":[{"id":1},{"id":2}]}"#;
+        let extracted = tool
+            .shape_response(
+                body,
+                &ResponseOptions {
+                    extract: Some(".items[].id".into()),
+                    ..ResponseOptions::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&extracted).unwrap(),
+            json!([1, 2])
+        );
+
+        let html = "<nav>menu</nav><main><p>Readable</p></main>";
+        let text = tool
+            .shape_response(
+                html,
+                &ResponseOptions {
+                    text: true,
+                    ..ResponseOptions::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(text, "Readable");
+
+        let capped = tool
+            .shape_response(
+                body,
+                &ResponseOptions {
+                    max_bytes: Some(8),
+                    ..ResponseOptions::default()
+                },
+            )
+            .unwrap();
+        assert!(capped.starts_with("{\"items\"\n\n"));
+        assert!(capped.contains("[Response truncated"));
+
+        assert!(
+            tool.shape_response(
+                "<html>not json</html>",
+                &ResponseOptions {
+                    extract: Some(".items".into()),
+                    ..ResponseOptions::default()
+                },
+            )
+            .is_err()
+        );
+    }
+
     #[test]
     fn parse_headers_rejects_non_string_values() {
         let tool = test_tool(vec!["example.com"]);
@@ -1720,6 +2234,38 @@ api_token = "Bearer from-secret"
         assert!(err.contains("metadata"), "unexpected error: {err}");
     }
 
+    #[test]
+    fn risk_profile_blocklist_applies_on_top_of_tool_allowlist() {
+        use zeroclaw_config::network_policy::NetworkPolicy;
+        use zeroclaw_config::schema::{NetworkMode, NetworkPolicyConfig};
+
+        let security = Arc::new(SecurityPolicy {
+            risk_profile_name: "default".into(),
+            network: NetworkPolicy::from_config(&NetworkPolicyConfig {
+                mode: NetworkMode::Blocklist,
+                blocked_domains: vec!["*.tracker.example".into()],
+                ..Default::default()
+            }),
+            ..SecurityPolicy::default()
+        });
+        let tool =
+            HttpRequestTool::new(security, vec!["*".into()], 1_000_000, 30, false, vec![]).unwrap();
+
+        let err = tool
+            .validate_url("https://ads.tracker.example/pixel")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("[risk_profiles.default.network.blocked_domains]"),
+            "unexpected error: {err}"
+        );
+        assert!(
+            err.contains("ads.tracker.example"),
+            "unexpected error: {err}"
+        );
+        assert!(tool.validate_url("https://example.com/").is_ok());
+    }
+
     // ── IPv6 end-to-end coverage ──────────────────────────────
 
     #[test]
@@ -147,6 +147,10 @@ impl Tool for ImageInfoTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let path_str = args.get("path").and_then(|v| v.as_str()).ok_or_else(|| {
             ::zeroclaw_log::record!(
@@ -66,6 +66,7 @@ pub mod memory_recall;
 pub mod memory_store;
 pub mod model_routing_config;
 pub mod node_capabilities;
+pub mod notify;
 pub mod notion_tool;
 pub mod opencode_cli;
 pub mod pipeline;
@@ -3,7 +3,8 @@ use serde_json::json;
 use std::fmt::Write;
 use std::sync::Arc;
 use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
-use zeroclaw_memory::Memory;
+use zeroclaw_memory::scope::{self, ScopedNamespace};
+use zeroclaw_memory::{Memory, MemoryEntry};
 
 /// Let the agent search its own memory
 pub struct MemoryRecallTool {
@@ -14,6 +15,28 @@ impl MemoryRecallTool {
     pub fn new(memory: Arc<dyn Memory>) -> Self {
         Self { memory }
     }
+
+    /// Recall restricted to a channel turn's namespace plus the shared one
+    /// (`[memory] scope`), own-namespace hits first.
+    async fn recall_scoped(
+        &self,
+        scoped: &ScopedNamespace,
+        query: &str,
+        limit: usize,
+        since: Option<&str>,
+        until: Option<&str>,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        let mut entries = Vec::new();
+        for namespace in scoped.recall_namespaces() {
+            let recalled = self
+                .memory
+                .recall_namespaced(&namespace, query, limit, None, since, until)
+                .await?;
+            entries.extend(recalled);
+        }
+        entries.truncate(limit);
+        Ok(entries)
+    }
 }
 
 #[async_trait]
@@ -55,6 +78,10 @@ impl Tool for MemoryRecallTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let query = args.get("query").and_then(|v| v.as_str()).unwrap_or("");
         let since = args.get("since").and_then(|v| v.as_str());
@@ -103,7 +130,14 @@ impl Tool for MemoryRecallTool {
             .and_then(serde_json::Value::as_u64)
             .map_or(5, |v| v as usize);
 
-        match self.memory.recall(query, limit, None, since, until).await {
+        let recalled = match scope::current_namespace() {
+            Some(scoped) => {
+                self.recall_scoped(&scoped, query, limit, since, until)
+                    .await
+            }
+            None => self.memory.recall(query, limit, None, since, until).await,
+        };
+        match recalled {
             Ok(entries) if entries.is_empty() => Ok(ToolResult {
                 success: true,
                 output: "No memories found.".into(),
@@ -93,7 +93,13 @@ impl Tool for MemoryStoreTool {
             });
         }
 
-        match self.memory.store(key, content, category, None).await {
+        // Channel turns under `[memory] scope` write into the turn's namespace.
+        let namespace = zeroclaw_memory::scope::current_namespace().map(|scoped| scoped.namespace);
+        match self
+            .memory
+            .store_with_metadata(key, content, category, None, namespace.as_deref(), None)
+            .await
+        {
             Ok(()) => Ok(ToolResult {
                 success: true,
                 output: format!("Stored memory: {key}").into(),
@@ -180,6 +186,27 @@ mod tests {
         assert_eq!(entry.category, MemoryCategory::Custom("project".into()));
     }
 
+    #[tokio::test]
+    async fn store_uses_scoped_channel_namespace() {
+        let (_tmp, mem) = test_mem();
+        let tool = MemoryStoreTool::new(mem.clone(), test_security());
+        let scoped = zeroclaw_memory::scope::ScopedNamespace {
+            namespace: "channel:discord:alice".into(),
+            shared: Some("shared".into()),
+        };
+        let result = zeroclaw_memory::scope::MEMORY_NAMESPACE
+            .scope(
+                Some(scoped),
+                tool.execute(json!({"key": "pref", "content": "Likes tea"})),
+            )
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let entry = mem.get("pref").await.unwrap().unwrap();
+        assert_eq!(entry.namespace, "channel:discord:alice");
+    }
+
     #[tokio::test]
     async fn store_missing_key() {
         let (_tmp, mem) = test_mem();
@@ -0,0 +1,522 @@
+//! `notify` tool: push notifications to named targets from
+//! `[notifications.targets.<name>]`, delivered through ntfy, Gotify, or
+//! Pushover.
+//!
+//! The model picks a target by name and supplies a message, an optional
+//! title, and a backend-neutral priority; each backend maps those onto its
+//! own API. Failures distinguish rejected credentials from an unreachable
+//! server so the agent can tell the user which setting to fix.
+
+use async_trait::async_trait;
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
+use zeroclaw_config::policy::SecurityPolicy;
+use zeroclaw_config::schema::{NotificationBackend, NotificationTargetConfig};
+
+const PUSHOVER_API_URL: &str = "https://api.pushover.net/1/messages.json";
+const NOTIFY_REQUEST_TIMEOUT_SECS: u64 = 15;
+const NOTIFY_CONNECT_TIMEOUT_SECS: u64 = 10;
+const MAX_ERROR_BODY_CHARS: usize = 300;
+
+/// Backend-neutral priority levels, ntfy's five-step scale.
+const PRIORITIES: [&str; 5] = ["min", "low", "default", "high", "urgent"];
+
+pub struct NotifyTool {
+    security: Arc<SecurityPolicy>,
+    targets: BTreeMap<String, NotificationTargetConfig>,
+    pushover_api_url: String,
+}
+
+struct Notification<'a> {
+    message: &'a str,
+    title: Option<&'a str>,
+    /// Index into [`PRIORITIES`].
+    priority: usize,
+    tags: Vec<String>,
+}
+
+impl NotifyTool {
+    pub fn new(
+        security: Arc<SecurityPolicy>,
+        targets: HashMap<String, NotificationTargetConfig>,
+    ) -> Self {
+        Self {
+            security,
+            targets: targets.into_iter().collect(),
+            pushover_api_url: PUSHOVER_API_URL.to_string(),
+        }
+    }
+
+    #[cfg(test)]
+    fn with_pushover_api_url(mut self, url: String) -> Self {
+        self.pushover_api_url = url;
+        self
+    }
+
+    fn client() -> reqwest::Client {
+        zeroclaw_config::schema::build_runtime_proxy_client_with_timeouts(
+            "tool.notify",
+            NOTIFY_REQUEST_TIMEOUT_SECS,
+            NOTIFY_CONNECT_TIMEOUT_SECS,
+        )
+    }
+
+    async fn send_ntfy(
+        &self,
+        target: &NotificationTargetConfig,
+        note: &Notification<'_>,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        // JSON publishing goes to the server root with the topic in the
+        // body, which keeps non-ASCII titles out of HTTP headers.
+        let topic_url = target.url.trim().trim_end_matches('/');
+        let (server, topic) = topic_url.rsplit_once('/').unwrap_or((topic_url, ""));
+        let mut body = json!({
+            "topic": topic,
+            "message": note.message,
+            "priority": note.priority + 1,
+        });
+        if let Some(title) = note.title {
+            body["title"] = json!(title);
+        }
+        if !note.tags.is_empty() {
+            body["tags"] = json!(note.tags);
+        }
+        let mut request = Self::client().post(server).json(&body);
+        if !target.token.trim().is_empty() {
+            request = request.bearer_auth(target.token.trim());
+        }
+        request.send().await
+    }
+
+    async fn send_gotify(
+        &self,
+        target: &NotificationTargetConfig,
+        note: &Notification<'_>,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        const GOTIFY_PRIORITIES: [u8; 5] = [0, 2, 5, 8, 10];
+        let mut body = json!({
+            "message": note.message,
+            "priority": GOTIFY_PRIORITIES[note.priority],
+        });
+        if let Some(title) = note.title {
+            body["title"] = json!(title);
+        }
+        Self::client()
+            .post(format!(
+                "{}/message",
+                target.url.trim().trim_end_matches('/')
+            ))
+            .header("X-Gotify-Key", target.token.trim())
+            .json(&body)
+            .send()
+            .await
+    }
+
+    async fn send_pushover(
+        &self,
+        target: &NotificationTargetConfig,
+        note: &Notification<'_>,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        // Pushover's emergency level (2) requires retry/expire parameters,
+        // so `urgent` stops at high (1).
+        const PUSHOVER_PRIORITIES: [i8; 5] = [-2, -1, 0, 1, 1];
+        let mut form = reqwest::multipart::Form::new()
+            .text("token", target.token.trim().to_string())
+            .text("user", target.user_key.trim().to_string())
+            .text("message", note.message.to_string())
+            .text("priority", PUSHOVER_PRIORITIES[note.priority].to_string());
+        if let Some(title) = note.title {
+            form = form.text("title", title.to_string());
+        }
+        Self::client()
+            .post(&self.pushover_api_url)
+            .multipart(form)
+            .send()
+            .await
+    }
+}
+
+fn backend_name(backend: NotificationBackend) -> &'static str {
+    match backend {
+        NotificationBackend::Ntfy => "ntfy",
+        NotificationBackend::Gotify => "gotify",
+        NotificationBackend::Pushover => "pushover",
+    }
+}
+
+fn failure(error: String) -> ToolResult {
+    ToolResult {
+        success: false,
+        output: ToolOutput::default(),
+        error: Some(error),
+    }
+}
+
+#[async_trait]
+impl Tool for NotifyTool {
+    fn name(&self) -> &str {
+        "notify"
+    }
+
+    fn description(&self) -> &str {
+        "Send a push notification to a configured target (ntfy, Gotify, or Pushover). \
+         Pick the target by name."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        let names: Vec<&str> = self.targets.keys().map(String::as_str).collect();
+        json!({
+            "type": "object",
+            "properties": {
+                "target": {
+                    "type": "string",
+                    "enum": names,
+                    "description": "Configured notification target to deliver to"
+                },
+                "message": {
+                    "type": "string",
+                    "description": "The notification body"
+                },
+                "title": {
+                    "type": "string",
+                    "description": "Optional notification title"
+                },
+                "priority": {
+                    "type": "string",
+                    "enum": PRIORITIES,
+                    "description": "Delivery priority (default: 'default')"
+                },
+                "tags": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Optional tags or emoji shortcodes (ntfy only)"
+                }
+            },
+            "required": ["target", "message"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        if !self.security.can_act() {
+            return Ok(failure("Action blocked: autonomy is read-only".into()));
+        }
+        if !self.security.record_action() {
+            return Ok(failure("Action blocked: rate limit exceeded".into()));
+        }
+
+        let target_name = args
+            .get("target")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .unwrap_or_default();
+        let Some(target) = self.targets.get(target_name) else {
+            let known: Vec<&str> = self.targets.keys().map(String::as_str).collect();
+            return Ok(failure(format!(
+                "Unknown notification target '{target_name}'. Configured targets: {}",
+                known.join(", ")
+            )));
+        };
+        let Some(message) = args
+            .get("message")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+        else {
+            return Ok(failure("Missing 'message' parameter".into()));
+        };
+        let title = args
+            .get("title")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|v| !v.is_empty());
+        let priority = match args.get("priority").and_then(|v| v.as_str()) {
+            None => 2,
+            Some(p) => match PRIORITIES
+                .iter()
+                .position(|known| known.eq_ignore_ascii_case(p))
+            {
+                Some(index) => index,
+                None => {
+                    return Ok(failure(format!(
+                        "Invalid 'priority': {p}. Expected one of: {}",
+                        PRIORITIES.join(", ")
+                    )));
+                }
+            },
+        };
+        let mut tags = target.tags.clone();
+        if let Some(extra) = args.get("tags").and_then(|v| v.as_array()) {
+            tags.extend(extra.iter().filter_map(|t| t.as_str()).map(String::from));
+        }
+        let note = Notification {
+            message,
+            title,
+            priority,
+            tags,
+        };
+
+        let backend = backend_name(target.backend);
+        let sent = match target.backend {
+            NotificationBackend::Ntfy => self.send_ntfy(target, &note).await,
+            NotificationBackend::Gotify => self.send_gotify(target, &note).await,
+            NotificationBackend::Pushover => self.send_pushover(target, &note).await,
+        };
+        let response = match sent {
+            Ok(response) => response,
+            Err(e) => {
+                ::zeroclaw_log::record!(
+                    WARN,
+                    ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Send)
+                        .with_outcome(::zeroclaw_log::EventOutcome::Failure)
+                        .with_attrs(::serde_json::json!({
+                            "target": target_name,
+                            "backend": backend,
+                            "error": e.to_string(),
+                        })),
+                    "notify: backend unreachable"
+                );
+                return Ok(failure(format!(
+                    "Notification target '{target_name}' ({backend}) is unreachable: {e}. \
+                     Check notifications.targets.{target_name}.url and network access."
+                )));
+            }
+        };
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Ok(failure(format!(
+                "Notification target '{target_name}' ({backend}) rejected the credentials \
+                 (HTTP {status}). Check notifications.targets.{target_name}.token{}.",
+                if target.backend == NotificationBackend::Pushover {
+                    " and user_key"
+                } else {
+                    ""
+                }
+            )));
+        }
+        // Pushover reports bad tokens and user keys as 400 with `status: 0`.
+        let pushover_rejected = target.backend == NotificationBackend::Pushover
+            && serde_json::from_str::<serde_json::Value>(&body)
+                .ok()
+                .and_then(|json| json.get("status").and_then(serde_json::Value::as_i64))
+                != Some(1);
+        if !status.is_success() || pushover_rejected {
+            let detail: String = body.chars().take(MAX_ERROR_BODY_CHARS).collect();
+            return Ok(ToolResult {
+                success: false,
+                output: detail.into(),
+                error: Some(format!(
+                    "Notification target '{target_name}' ({backend}) returned HTTP {status}"
+                )),
+            });
+        }
+
+        Ok(ToolResult::ok(format!(
+            "Notification sent to '{target_name}' via {backend}."
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, body_string_contains, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+    use zeroclaw_config::autonomy::AutonomyLevel;
+
+    fn test_security(level: AutonomyLevel) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy {
+            autonomy: level,
+            max_actions_per_hour: 100,
+            workspace_dir: std::env::temp_dir(),
+            ..SecurityPolicy::default()
+        })
+    }
+
+    fn target(backend: NotificationBackend, url: String, token: &str) -> NotificationTargetConfig {
+        NotificationTargetConfig {
+            backend,
+            url,
+            token: token.into(),
+            user_key: "user-key".into(),
+            tags: Vec::new(),
+        }
+    }
+
+    fn tool(name: &str, target: NotificationTargetConfig) -> NotifyTool {
+        NotifyTool::new(
+            test_security(AutonomyLevel::Full),
+            HashMap::from([(name.to_string(), target)]),
+        )
+    }
+
+    #[tokio::test]
+    async fn ntfy_publishes_json_with_priority_tags_and_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(header("Authorization", "Bearer tk_abc"))
+            .and(body_partial_json(json!({
+                "topic": "alerts",
+                "message": "disk full",
+                "title": "host-1",
+                "priority": 4,
+                "tags": ["server", "warning"],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"id":"x"}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut config = target(
+            NotificationBackend::Ntfy,
+            format!("{}/alerts", server.uri()),
+            "tk_abc",
+        );
+        config.tags = vec!["server".into()];
+        let result = tool("phone", config)
+            .execute(json!({
+                "target": "phone",
+                "message": "disk full",
+                "title": "host-1",
+                "priority": "high",
+                "tags": ["warning"],
+            }))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("via ntfy"));
+    }
+
+    #[tokio::test]
+    async fn gotify_posts_message_with_app_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/message"))
+            .and(header("X-Gotify-Key", "app-token"))
+            .and(body_partial_json(json!({"message": "done", "priority": 5})))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"id":1}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = tool(
+            "home",
+            target(NotificationBackend::Gotify, server.uri(), "app-token"),
+        )
+        .execute(json!({"target": "home", "message": "done"}))
+        .await
+        .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+    }
+
+    #[tokio::test]
+    async fn gotify_unauthorized_names_the_token_setting() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/message"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("unauthorized"))
+            .mount(&server)
+            .await;
+
+        let result = tool(
+            "home",
+            target(NotificationBackend::Gotify, server.uri(), "bad"),
+        )
+        .execute(json!({"target": "home", "message": "done"}))
+        .await
+        .unwrap();
+
+        assert!(!result.success);
+        let error = result.error.unwrap();
+        assert!(error.contains("rejected the credentials"), "{error}");
+        assert!(
+            error.contains("notifications.targets.home.token"),
+            "{error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn pushover_backend_posts_form_and_checks_api_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/1/messages.json"))
+            .and(body_string_contains("app-token"))
+            .and(body_string_contains("user-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"status":1}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = tool(
+            "pager",
+            target(NotificationBackend::Pushover, String::new(), "app-token"),
+        )
+        .with_pushover_api_url(format!("{}/1/messages.json", server.uri()))
+        .execute(json!({"target": "pager", "message": "ping", "priority": "urgent"}))
+        .await
+        .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+    }
+
+    #[tokio::test]
+    async fn unreachable_server_is_reported_as_such() {
+        // Bind and drop a listener so the port is closed.
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let result = tool(
+            "phone",
+            target(
+                NotificationBackend::Ntfy,
+                format!("http://127.0.0.1:{port}/alerts"),
+                "",
+            ),
+        )
+        .execute(json!({"target": "phone", "message": "hi"}))
+        .await
+        .unwrap();
+
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("is unreachable"));
+    }
+
+    #[tokio::test]
+    async fn unknown_target_lists_configured_names() {
+        let result = tool(
+            "phone",
+            target(NotificationBackend::Ntfy, "https://ntfy.sh/x".into(), ""),
+        )
+        .execute(json!({"target": "desk", "message": "hi"}))
+        .await
+        .unwrap();
+
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Configured targets: phone"));
+    }
+
+    #[tokio::test]
+    async fn execute_blocks_readonly_mode() {
+        let tool = NotifyTool::new(
+            test_security(AutonomyLevel::ReadOnly),
+            HashMap::from([(
+                "phone".to_string(),
+                target(NotificationBackend::Ntfy, "https://ntfy.sh/x".into(), ""),
+            )]),
+        );
+        let result = tool
+            .execute(json!({"target": "phone", "message": "hi"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("read-only"));
+    }
+}
@@ -539,6 +539,10 @@ impl Tool for ProjectIntelTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let action = args.get("action").and_then(|v| v.as_str()).ok_or_else(|| {
             ::zeroclaw_log::record!(
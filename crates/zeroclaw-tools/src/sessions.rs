@@ -161,6 +161,10 @@ impl Tool for SessionsListTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         #[allow(clippy::cast_possible_truncation)]
         let limit = args
@@ -239,6 +243,10 @@ impl Tool for SessionsHistoryTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         if let Err(error) = self
             .security
@@ -470,6 +478,10 @@ impl Tool for SessionsCurrentTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, _args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let session_key = zeroclaw_api::TOOL_LOOP_SESSION_KEY
             .try_with(Clone::clone)
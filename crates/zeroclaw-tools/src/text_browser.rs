@@ -108,6 +108,8 @@ impl TextBrowserTool {
             anyhow::bail!("Blocked local/private host: {display_host}");
         }
 
+        self.security.check_network_host(&host, host_allowed)?;
+
         if !host_allowed {
             validate_dns(&host)?;
         }
@@ -400,6 +400,10 @@ impl Tool for WeatherTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
         let location = match args.get("location").and_then(|v| v.as_str()) {
             Some(loc) if !loc.trim().is_empty() => loc.trim().to_string(),
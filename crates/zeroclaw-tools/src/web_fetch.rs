@@ -5,6 +5,7 @@ use serde_json::json;
 use std::sync::Arc;
 use std::time::Duration;
 use zeroclaw_api::tool::{Tool, ToolOutput, ToolResult};
+use zeroclaw_config::network_policy::NetworkGuard;
 use zeroclaw_config::policy::SecurityPolicy;
 use zeroclaw_config::schema::FirecrawlConfig;
 
@@ -53,13 +54,19 @@ impl WebFetchTool {
     }
 
     fn validate_url(&self, raw_url: &str) -> anyhow::Result<String> {
-        validate_target_url(
+        let url = validate_target_url(
             raw_url,
             &self.allowed_domains,
             &self.blocked_domains,
             &self.allowed_private_hosts,
             "web_fetch",
-        )
+        )?;
+        check_network_policy(
+            &self.security.network_guard(),
+            &url,
+            &self.allowed_private_hosts,
+        )?;
+        Ok(url)
     }
 
     fn truncate_response(&self, text: &str) -> String {
@@ -334,6 +341,10 @@ impl Tool for WebFetchTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let url = args.get("url").and_then(|v| v.as_str()).ok_or_else(|| {
             ::zeroclaw_log::record!(
@@ -384,6 +395,7 @@ impl Tool for WebFetchTool {
         let allowed_domains = self.allowed_domains.clone();
         let blocked_domains = self.blocked_domains.clone();
         let allowed_private_hosts = self.allowed_private_hosts.clone();
+        let network_guard = self.security.network_guard();
         let redirect_policy = reqwest::redirect::Policy::custom(move |attempt| {
             if attempt.previous().len() >= 10 {
                 return attempt.error(std::io::Error::other("Too many redirects (max 10)"));
@@ -401,17 +413,27 @@ impl Tool for WebFetchTool {
                     format!("Blocked redirect target: {err}"),
                 ));
             }
+            if let Err(err) = check_network_policy(
+                &network_guard,
+                attempt.url().as_str(),
+                &allowed_private_hosts,
+            ) {
+                return attempt.error(std::io::Error::new(
+                    std::io::ErrorKind::PermissionDenied,
+                    format!("Blocked redirect target: {err}"),
+                ));
+            }
 
             attempt.follow()
         });
 
-        let builder = reqwest::Client::builder()
+        let builder = self
+            .security
+            .network_client_builder("tool.web_fetch")
             .timeout(Duration::from_secs(timeout_secs))
             .connect_timeout(Duration::from_secs(10))
             .redirect(redirect_policy)
             .user_agent("ZeroClaw/0.1 (web_fetch)");
-        let builder =
-            zeroclaw_config::schema::apply_runtime_proxy_to_builder(builder, "tool.web_fetch");
         let client = match builder.build() {
             Ok(c) => c,
             Err(e) => {
@@ -468,6 +490,23 @@ impl Tool for WebFetchTool {
 
 // ── Helper functions (independent from http_request.rs per DRY rule-of-three) ──
 
+/// Apply the risk profile's network policy to an already tool-validated URL.
+/// Hosts carved out by `web_fetch.allowed_private_hosts` count as the tool's
+/// private-host opt-in.
+fn check_network_policy(
+    guard: &NetworkGuard,
+    url: &str,
+    allowed_private_hosts: &[String],
+) -> anyhow::Result<()> {
+    let host = extract_host(url)?;
+    let private_allowed = !matches!(
+        private_allowlist_match(&host, allowed_private_hosts),
+        PrivateAllow::None
+    );
+    guard.check_host(&host, private_allowed)?;
+    Ok(())
+}
+
 fn validate_target_url(
     raw_url: &str,
     allowed_domains: &[String],
@@ -871,6 +910,18 @@ mod tests {
         assert!(err.contains("allowed_domains"));
     }
 
+    #[test]
+    fn validate_rejects_metadata_endpoint_despite_private_wildcard() {
+        let tool = test_tool_with_private_hosts(vec!["*"], vec![], vec!["*"]);
+        let err = tool
+            .validate_url("http://169.254.169.254/latest/meta-data/")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("cloud metadata endpoint"), "{err}");
+        assert!(err.contains("169.254.169.254"), "{err}");
+        assert!(tool.validate_url("http://10.0.0.5/status").is_ok());
+    }
+
     #[test]
     fn validate_requires_allowlist() {
         let security = Arc::new(SecurityPolicy::default());
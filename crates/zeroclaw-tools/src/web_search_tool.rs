@@ -5,9 +5,10 @@ use async_trait::async_trait;
 use regex::Regex;
 use serde_json::json;
 use std::path::{Path, PathBuf};
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 use std::time::Duration;
 use zeroclaw_api::tool::{Tool, ToolResult};
+use zeroclaw_config::policy::SecurityPolicy;
 
 /// Web search tool for searching the internet.
 /// Supports multiple model_providers: DuckDuckGo (free), Brave (requires API key),
@@ -39,6 +40,8 @@ pub struct WebSearchTool {
     config_path: PathBuf,
     /// Whether secret encryption is enabled (needed to create a `SecretStore`).
     secrets_encrypt: bool,
+    /// Supplies the risk profile's network policy and HTTP client factory.
+    security: Arc<SecurityPolicy>,
 }
 
 impl WebSearchTool {
@@ -59,6 +62,7 @@ impl WebSearchTool {
             timeout_secs: timeout_secs.max(1),
             config_path: PathBuf::new(),
             secrets_encrypt: false,
+            security: Arc::new(SecurityPolicy::default()),
         }
     }
 
@@ -84,9 +88,30 @@ impl WebSearchTool {
             timeout_secs: timeout_secs.max(1),
             config_path,
             secrets_encrypt,
+            security: Arc::new(SecurityPolicy::default()),
         }
     }
 
+    /// Apply the agent's security policy so provider requests honour its
+    /// `[risk_profiles.<alias>.network]` rules.
+    #[must_use]
+    pub fn with_security(mut self, security: Arc<SecurityPolicy>) -> Self {
+        self.security = security;
+        self
+    }
+
+    /// Client builder for one provider request. The endpoint host must pass
+    /// the network policy; it is operator-configured, so a LAN SearXNG
+    /// instance counts as an explicit private-host opt-in.
+    fn client_builder(&self, endpoint_url: &str) -> anyhow::Result<reqwest::ClientBuilder> {
+        let endpoint = reqwest::Url::parse(endpoint_url)?;
+        self.security.network_guard().check_url(&endpoint, true)?;
+        Ok(self
+            .security
+            .network_client_builder("tool.web_search")
+            .timeout(Duration::from_secs(self.timeout_secs)))
+    }
+
     /// Resolve the Brave API key, preferring the boot-time value but falling
     /// back to a fresh config read + decryption when the boot-time value is
     /// absent.
@@ -187,11 +212,9 @@ impl WebSearchTool {
         let encoded_query = urlencoding::encode(query);
         let search_url = format!("{}?q={}", endpoint_url, encoded_query);
 
-        let builder = reqwest::Client::builder()
-            .timeout(Duration::from_secs(self.timeout_secs))
+        let builder = self
+            .client_builder(endpoint_url)?
             .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36");
-        let builder =
-            zeroclaw_config::schema::apply_runtime_proxy_to_builder(builder, "tool.web_search");
         let client = builder.build()?;
 
         let response = client.get(&search_url).send().await?;
@@ -274,9 +297,7 @@ impl WebSearchTool {
             encoded_query, self.max_results
         );
 
-        let builder = reqwest::Client::builder().timeout(Duration::from_secs(self.timeout_secs));
-        let builder =
-            zeroclaw_config::schema::apply_runtime_proxy_to_builder(builder, "tool.web_search");
+        let builder = self.client_builder(&search_url)?;
         let client = builder.build()?;
 
         let response = client
@@ -380,10 +401,9 @@ impl WebSearchTool {
     }
 
     fn build_tavily_client(&self) -> anyhow::Result<reqwest::Client> {
-        let builder = reqwest::Client::builder().timeout(Duration::from_secs(self.timeout_secs));
-        let builder =
-            zeroclaw_config::schema::apply_runtime_proxy_to_builder(builder, "tool.web_search");
-        Ok(builder.build()?)
+        Ok(self
+            .client_builder("https://api.tavily.com/search")?
+            .build()?)
     }
 
     /// Inner Tavily request implementation, parameterized on the HTTP
@@ -552,11 +572,9 @@ impl WebSearchTool {
     async fn search_jina(&self, query: &str) -> anyhow::Result<String> {
         let api_key = self.resolve_jina_api_key()?;
 
-        let builder = reqwest::Client::builder()
-            .timeout(Duration::from_secs(self.timeout_secs))
+        let builder = self
+            .client_builder("https://s.jina.ai/")?
             .user_agent("ZeroClaw/1.0 (https://zeroclaw.ai)");
-        let builder =
-            zeroclaw_config::schema::apply_runtime_proxy_to_builder(builder, "tool.web_search");
         let client = builder.build()?;
 
         // Jina Search API requires POST with JSON body
@@ -692,9 +710,7 @@ impl WebSearchTool {
     }
 
     async fn search_bocha(&self, query: &str) -> anyhow::Result<String> {
-        let builder = reqwest::Client::builder().timeout(Duration::from_secs(self.timeout_secs));
-        let builder =
-            zeroclaw_config::schema::apply_runtime_proxy_to_builder(builder, "tool.web_search");
+        let builder = self.client_builder("https://api.bochaai.com/v1/web-search")?;
         let client = builder.build()?;
         self.search_bocha_with_client(&client, "https://api.bochaai.com/v1/web-search", query)
             .await
@@ -929,11 +945,7 @@ impl WebSearchTool {
             base_url, encoded_query
         );
 
-        let builder = reqwest::Client::builder()
-            .timeout(Duration::from_secs(self.timeout_secs))
-            .user_agent("ZeroClaw/1.0");
-        let builder =
-            zeroclaw_config::schema::apply_runtime_proxy_to_builder(builder, "tool.web_search");
+        let builder = self.client_builder(&search_url)?.user_agent("ZeroClaw/1.0");
         let client = builder.build()?;
 
         let response = client
@@ -1100,6 +1112,10 @@ impl Tool for WebSearchTool {
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let query = args.get("query").and_then(|q| q.as_str()).ok_or_else(|| {
             ::zeroclaw_log::record!(
@@ -1176,6 +1192,38 @@ mod tests {
         assert!(schema["properties"]["query"].is_object());
     }
 
+    #[test]
+    fn network_allowlist_refuses_unlisted_provider_endpoint() {
+        use zeroclaw_config::network_policy::NetworkPolicy;
+        use zeroclaw_config::schema::{NetworkMode, NetworkPolicyConfig};
+
+        let security = Arc::new(SecurityPolicy {
+            risk_profile_name: "research".into(),
+            network: NetworkPolicy::from_config(&NetworkPolicyConfig {
+                mode: NetworkMode::Allowlist,
+                allowed_domains: vec!["api.search.brave.com".into()],
+                ..Default::default()
+            }),
+            ..SecurityPolicy::default()
+        });
+        let tool =
+            WebSearchTool::new("duckduckgo".to_string(), None, None, 5, 15).with_security(security);
+
+        let err = tool
+            .client_builder("https://html.duckduckgo.com/html/")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("[risk_profiles.research.network.allowed_domains]"),
+            "{err}"
+        );
+        assert!(err.contains("html.duckduckgo.com"), "{err}");
+        assert!(
+            tool.client_builder("https://api.search.brave.com/res/v1/web/search")
+                .is_ok()
+        );
+    }
+
     #[test]
     fn test_strip_tags() {
         let html = "<b>Hello</b> <i>World</i>";
@@ -53,6 +53,14 @@ impl<T: Tool> Tool for RateLimitedTool<T> {
         self.inner.param_domains()
     }
 
+    fn is_read_only(&self) -> bool {
+        self.inner.is_read_only()
+    }
+
+    fn mutates(&self, args: &serde_json::Value) -> bool {
+        self.inner.mutates(args)
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         if self.security.is_rate_limited() {
             return Ok(ToolResult {
@@ -148,6 +156,14 @@ impl<T: Tool> Tool for PathGuardedTool<T> {
         self.inner.param_domains()
     }
 
+    fn is_read_only(&self) -> bool {
+        self.inner.is_read_only()
+    }
+
+    fn mutates(&self, args: &serde_json::Value) -> bool {
+        self.inner.mutates(args)
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         if let Some(arg) = self.extract_path_string(&args) {
             // For shell command arguments, use the full token-aware scanner.
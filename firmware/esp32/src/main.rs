@@ -1,16 +1,68 @@
 //! ZeroClaw ESP32 firmware — JSON-over-serial peripheral.
 
+use std::time::{Duration, Instant};
+
+use esp_idf_svc::hal::adc::attenuation::DB_12;
+use esp_idf_svc::hal::adc::oneshot::config::AdcChannelConfig;
+use esp_idf_svc::hal::adc::oneshot::{AdcChannelDriver, AdcDriver};
 use esp_idf_svc::hal::gpio::PinDriver;
 use esp_idf_svc::hal::peripherals::Peripherals;
 use esp_idf_svc::hal::uart::{UartConfig, UartDriver};
 use esp_idf_svc::hal::units::Hertz;
 use heapless::{String, Vec};
 use log::info;
-use zeroclaw_fw_protocol::{Command, copy_id, write_err, write_ok};
+use zeroclaw_fw_protocol::{Command, StreamSource, copy_id, write_err, write_ok, write_sample};
 
 // Pre-escaped because `write_ok` embeds this value as a JSON string without escaping.
-const CAPABILITIES_RESULT: &str =
-    r#"{\"gpio\":[0,1,2,3,4,5,12,13,14,15,16,17,18,19],\"led_pin\":2}"#;
+const CAPABILITIES_RESULT: &str = r#"{\"gpio\":[0,1,2,3,4,5,12,13,14,15,16,17,18,19],\"led_pin\":2,\"stream\":{\"analog\":[0,1,3,4],\"min_interval_ms\":50}}"#;
+
+/// ADC1 pins that `subscribe {"pin":N}` can stream (raw 12-bit readings).
+const ANALOG_PINS: [i32; 4] = [0, 1, 3, 4];
+/// Fastest sampling interval a subscription may ask for.
+const MIN_INTERVAL_MS: i32 = 50;
+const MAX_SUBSCRIPTIONS: usize = 4;
+
+struct Subscription {
+    id: u32,
+    pin: i32,
+    interval: Duration,
+    next_due: Instant,
+}
+
+/// Active `subscribe` streams, sampled from the main loop.
+struct Streams {
+    subs: Vec<Subscription, MAX_SUBSCRIPTIONS>,
+    next_id: u32,
+}
+
+impl Streams {
+    fn subscribe(&mut self, source: StreamSource, interval_ms: i32) -> Result<u32, &'static str> {
+        let pin = match source {
+            StreamSource::Pin(pin) if ANALOG_PINS.contains(&pin) => pin,
+            StreamSource::Pin(_) => return Err("Pin cannot stream (analog pins: 0,1,3,4)"),
+            StreamSource::Sensor(_) => return Err("Unknown sensor"),
+            StreamSource::Missing => return Err("subscribe needs pin or sensor"),
+        };
+        if interval_ms < MIN_INTERVAL_MS {
+            return Err("interval_ms must be at least 50");
+        }
+        self.next_id += 1;
+        let sub = Subscription {
+            id: self.next_id,
+            pin,
+            interval: Duration::from_millis(interval_ms as u64),
+            next_due: Instant::now(),
+        };
+        self.subs.push(sub).map_err(|_| "Too many subscriptions")?;
+        Ok(self.next_id)
+    }
+
+    fn unsubscribe(&mut self, id: i32) -> bool {
+        let before = self.subs.len();
+        self.subs.retain(|s| i64::from(s.id) != i64::from(id));
+        self.subs.len() != before
+    }
+}
 
 fn main() -> anyhow::Result<()> {
     esp_idf_svc::sys::link_patches();
@@ -23,6 +75,26 @@ fn main() -> anyhow::Result<()> {
     let mut gpio2 = PinDriver::output(pins.gpio2)?;
     let mut gpio13 = PinDriver::output(pins.gpio13)?;
 
+    // ADC1 channels for streaming subscriptions.
+    let adc = AdcDriver::new(peripherals.adc1)?;
+    let adc_config = AdcChannelConfig {
+        attenuation: DB_12,
+        ..Default::default()
+    };
+    let mut adc0 = AdcChannelDriver::new(&adc, pins.gpio0, &adc_config)?;
+    let mut adc1 = AdcChannelDriver::new(&adc, pins.gpio1, &adc_config)?;
+    let mut adc3 = AdcChannelDriver::new(&adc, pins.gpio3, &adc_config)?;
+    let mut adc4 = AdcChannelDriver::new(&adc, pins.gpio4, &adc_config)?;
+    let mut read_analog = |pin: i32| -> Option<u16> {
+        match pin {
+            0 => adc.read(&mut adc0).ok(),
+            1 => adc.read(&mut adc1).ok(),
+            3 => adc.read(&mut adc3).ok(),
+            4 => adc.read(&mut adc4).ok(),
+            _ => None,
+        }
+    };
+
     // UART0: TX=21, RX=20 (ESP32) — ESP32-C3 may use different pins; adjust for your board
     let config = UartConfig::new().baudrate(Hertz(115_200));
     let uart = UartDriver::new(
@@ -39,15 +111,27 @@ fn main() -> anyhow::Result<()> {
     let mut buf = [0u8; 512];
     let mut line: Vec<u8, 400> = Vec::new();
     let mut resp_buf: String<256> = String::new();
+    let mut streams = Streams {
+        subs: Vec::new(),
+        next_id: 0,
+    };
+    let boot = Instant::now();
 
     loop {
-        match uart.read(&mut buf, 100) {
-            Ok(0) => continue,
+        // Short read timeout so subscriptions are sampled close to their interval.
+        match uart.read(&mut buf, 10) {
+            Ok(0) => {}
             Ok(n) => {
                 for &b in &buf[..n] {
                     if b == b'\n' {
                         if !line.is_empty() {
-                            handle_request(&line, &mut gpio2, &mut gpio13, &mut resp_buf);
+                            handle_request(
+                                &line,
+                                &mut gpio2,
+                                &mut gpio13,
+                                &mut streams,
+                                &mut resp_buf,
+                            );
                             let _ = uart.write(resp_buf.as_bytes());
                             let _ = uart.write(b"\n");
                             line.clear();
@@ -59,6 +143,20 @@ fn main() -> anyhow::Result<()> {
             }
             Err(_) => {}
         }
+
+        let now = Instant::now();
+        for sub in streams.subs.iter_mut() {
+            if now < sub.next_due {
+                continue;
+            }
+            sub.next_due = now + sub.interval;
+            if let Some(value) = read_analog(sub.pin) {
+                let t_ms = now.duration_since(boot).as_millis() as u64;
+                write_sample(&mut resp_buf, sub.id, i32::from(value), t_ms);
+                let _ = uart.write(resp_buf.as_bytes());
+                let _ = uart.write(b"\n");
+            }
+        }
     }
 }
 
@@ -66,6 +164,7 @@ fn handle_request<G2, G13>(
     line: &[u8],
     gpio2: &mut PinDriver<'_, G2>,
     gpio13: &mut PinDriver<'_, G13>,
+    streams: &mut Streams,
     resp_buf: &mut String<256>,
 ) where
     G2: esp_idf_svc::hal::gpio::OutputMode,
@@ -91,6 +190,24 @@ fn handle_request<G2, G13>(
             Ok(()) => write_ok(resp_buf, id_str, "done"),
             Err(e) => write_err(resp_buf, id_str, &e.to_string()),
         },
+        Some(Command::Subscribe {
+            source,
+            interval_ms,
+        }) => match streams.subscribe(source, interval_ms) {
+            Ok(sub_id) => {
+                let mut sub_buf: String<12> = String::new();
+                let _ = core::fmt::Write::write_fmt(&mut sub_buf, format_args!("{sub_id}"));
+                write_ok(resp_buf, id_str, &sub_buf);
+            }
+            Err(e) => write_err(resp_buf, id_str, e),
+        },
+        Some(Command::Unsubscribe { id }) => {
+            if streams.unsubscribe(id) {
+                write_ok(resp_buf, id_str, "done");
+            } else {
+                write_err(resp_buf, id_str, "Unknown subscription");
+            }
+        }
         Some(Command::Ping) => {
             write_ok(resp_buf, id_str, "pong");
         }
@@ -80,6 +80,9 @@ async fn main(_spawner: Spawner) {
                                 write_err(&mut resp_buf, id_str, "Invalid pin");
                             }
                         }
+                        Some(Command::Subscribe { .. } | Command::Unsubscribe { .. }) => {
+                            write_err(&mut resp_buf, id_str, "Streaming not supported");
+                        }
                         None => {
                             write_err(&mut resp_buf, id_str, "Unknown command");
                         }
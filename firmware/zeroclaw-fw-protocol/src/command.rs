@@ -1,12 +1,39 @@
-use crate::parse::{has_cmd, parse_arg};
+use crate::parse::{args_of, copy_str_arg, has_cmd, parse_arg};
+use heapless::String;
+
+/// Longest sensor name accepted by `subscribe`.
+pub const MAX_SENSOR_NAME: usize = 16;
+
+/// What a `subscribe` command asks the firmware to sample.
+#[derive(Debug, PartialEq, Eq)]
+pub enum StreamSource {
+    Pin(i32),
+    Sensor(String<MAX_SENSOR_NAME>),
+    /// Neither `pin` nor `sensor` was given (or the name did not fit).
+    Missing,
+}
 
 /// Parsed firmware command.
 #[derive(Debug, PartialEq, Eq)]
 pub enum Command {
     Ping,
     Capabilities,
-    GpioRead { pin: i32 },
-    GpioWrite { pin: i32, value: i32 },
+    GpioRead {
+        pin: i32,
+    },
+    GpioWrite {
+        pin: i32,
+        value: i32,
+    },
+    /// Start pushing `{"event":"sample",...}` lines every `interval_ms`.
+    Subscribe {
+        source: StreamSource,
+        interval_ms: i32,
+    },
+    /// Stop the subscription with this id (`-1` when missing).
+    Unsubscribe {
+        id: i32,
+    },
 }
 
 impl Command {
@@ -24,6 +51,28 @@ impl Command {
             let pin = parse_arg(line, b"pin").unwrap_or(-1);
             let value = parse_arg(line, b"value").unwrap_or(0);
             Some(Command::GpioWrite { pin, value })
+        } else if has_cmd(line, b"subscribe") {
+            let args = args_of(line);
+            let interval_ms = parse_arg(args, b"interval_ms").unwrap_or(0);
+            let mut name = [0u8; MAX_SENSOR_NAME + 1];
+            let source = if let Some(pin) = parse_arg(args, b"pin") {
+                StreamSource::Pin(pin)
+            } else if let Some(len) = copy_str_arg(args, b"sensor", &mut name) {
+                core::str::from_utf8(&name[..len])
+                    .ok()
+                    .filter(|_| len <= MAX_SENSOR_NAME)
+                    .and_then(|n| String::try_from(n).ok())
+                    .map_or(StreamSource::Missing, StreamSource::Sensor)
+            } else {
+                StreamSource::Missing
+            };
+            Some(Command::Subscribe {
+                source,
+                interval_ms,
+            })
+        } else if has_cmd(line, b"unsubscribe") {
+            let id = parse_arg(args_of(line), b"id").unwrap_or(-1);
+            Some(Command::Unsubscribe { id })
         } else {
             None
         }
@@ -79,6 +128,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_subscribe_pin() {
+        let line = br#"{"id":"6","cmd":"subscribe","args":{"pin":34,"interval_ms":500}}"#;
+        assert_eq!(
+            Command::from_line(line),
+            Some(Command::Subscribe {
+                source: StreamSource::Pin(34),
+                interval_ms: 500
+            })
+        );
+    }
+
+    #[test]
+    fn parse_subscribe_sensor() {
+        let line = br#"{"id":"6","cmd":"subscribe","args":{"sensor":"temp","interval_ms":1000}}"#;
+        assert_eq!(
+            Command::from_line(line),
+            Some(Command::Subscribe {
+                source: StreamSource::Sensor(String::try_from("temp").unwrap()),
+                interval_ms: 1000
+            })
+        );
+    }
+
+    #[test]
+    fn parse_subscribe_without_source() {
+        let long = br#"{"id":"6","cmd":"subscribe","args":{"sensor":"a-very-long-sensor-name"}}"#;
+        assert_eq!(
+            Command::from_line(long),
+            Some(Command::Subscribe {
+                source: StreamSource::Missing,
+                interval_ms: 0
+            })
+        );
+    }
+
+    #[test]
+    fn parse_unsubscribe_reads_args_id() {
+        let line = br#"{"id":"8","cmd":"unsubscribe","args":{"id":2}}"#;
+        assert_eq!(
+            Command::from_line(line),
+            Some(Command::Unsubscribe { id: 2 })
+        );
+        let missing = br#"{"id":"8","cmd":"unsubscribe"}"#;
+        assert_eq!(
+            Command::from_line(missing),
+            Some(Command::Unsubscribe { id: -1 })
+        );
+    }
+
     #[test]
     fn parse_unknown_returns_none() {
         let line = br#"{"id":"4","cmd":"reboot"}"#;
@@ -4,6 +4,6 @@ pub mod command;
 pub mod parse;
 pub mod response;
 
-pub use command::Command;
-pub use parse::{copy_id, has_cmd, parse_arg};
-pub use response::{write_err, write_ok};
+pub use command::{Command, StreamSource};
+pub use parse::{args_of, copy_id, copy_str_arg, has_cmd, parse_arg};
+pub use response::{write_err, write_ok, write_sample};
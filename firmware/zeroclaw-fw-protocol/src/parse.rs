@@ -61,6 +61,32 @@ pub fn parse_arg(line: &[u8], key: &[u8]) -> Option<i32> {
     Some(if neg { -num } else { num })
 }
 
+/// The `"args"` object of a request line, or an empty slice when there is
+/// none. Lets argument lookups skip top-level keys such as the request `"id"`.
+pub fn args_of(line: &[u8]) -> &[u8] {
+    match value_start(line, b"args") {
+        Some(start) if start < line.len() && line[start] == b'{' => &line[start..],
+        _ => &[],
+    }
+}
+
+/// Copy a string value such as `"sensor":"temp"` into `out`.
+/// Returns the number of bytes written, or `None` if the key is missing or
+/// its value is not a string.
+pub fn copy_str_arg(line: &[u8], key: &[u8], out: &mut [u8]) -> Option<usize> {
+    let start = value_start(line, key)?;
+    if start >= line.len() || line[start] != b'"' {
+        return None;
+    }
+    let value = &line[start + 1..];
+    let mut j = 0;
+    while j < value.len() && j < out.len() && value[j] != b'"' {
+        out[j] = value[j];
+        j += 1;
+    }
+    Some(j)
+}
+
 /// Check if a JSON line contains `"cmd":"<cmd>"`.
 pub fn has_cmd(line: &[u8], cmd: &[u8]) -> bool {
     let Some(start) = value_start(line, b"cmd") else {
@@ -135,6 +161,23 @@ mod tests {
         assert_eq!(parse_arg(line, b"value"), Some(0));
     }
 
+    #[test]
+    fn args_of_skips_top_level_keys() {
+        let line = br#"{"id":"7","cmd":"unsubscribe","args":{"id":3}}"#;
+        assert_eq!(parse_arg(args_of(line), b"id"), Some(3));
+        assert_eq!(args_of(br#"{"id":"7","cmd":"ping"}"#), b"");
+    }
+
+    #[test]
+    fn copy_str_arg_extracts() {
+        let line = br#"{"args":{"sensor":"temp","interval_ms":500}}"#;
+        let mut buf = [0u8; 16];
+        let len = copy_str_arg(line, b"sensor", &mut buf).unwrap();
+        assert_eq!(&buf[..len], b"temp");
+        assert_eq!(copy_str_arg(line, b"interval_ms", &mut buf), None);
+        assert_eq!(copy_str_arg(line, b"missing", &mut buf), None);
+    }
+
     #[test]
     fn has_cmd_matches() {
         let line = br#"{"id":"1","cmd":"gpio_read","args":{"pin":5}}"#;
@@ -23,6 +23,18 @@ pub fn write_err<const N: usize>(buf: &mut String<N>, id: &str, error: &str) {
     );
 }
 
+/// Write an unsolicited sample event for subscription `sub_id` into `buf`.
+/// Format: `{"event":"sample","id":"<sub_id>","value":<value>,"t_ms":<t_ms>}`
+/// where `t_ms` is the firmware's uptime in milliseconds.
+pub fn write_sample<const N: usize>(buf: &mut String<N>, sub_id: u32, value: i32, t_ms: u64) {
+    buf.clear();
+    let _ = write!(
+        buf,
+        "{{\"event\":\"sample\",\"id\":\"{}\",\"value\":{},\"t_ms\":{}}}",
+        sub_id, value, t_ms
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,6 +71,16 @@ mod tests {
         assert_eq!(buf.as_str(), r#"{"id":"1","ok":true,"result":"pong"}"#);
     }
 
+    #[test]
+    fn write_sample_event() {
+        let mut buf = String::<128>::new();
+        write_sample(&mut buf, 3, 812, 12_345);
+        assert_eq!(
+            buf.as_str(),
+            r#"{"event":"sample","id":"3","value":812,"t_ms":12345}"#
+        );
+    }
+
     #[test]
     fn write_err_unknown_command() {
         let mut buf = String::<128>::new();
@@ -20,7 +20,7 @@ pub async fn handle_command(command: crate::ChannelCommands, config: &Config) ->
         crate::ChannelCommands::Start => {
             anyhow::bail!("Start must be handled in main.rs (requires async runtime)")
         }
-        crate::ChannelCommands::Doctor => {
+        crate::ChannelCommands::Doctor { .. } => {
             anyhow::bail!("Doctor must be handled in main.rs (requires async runtime)")
         }
         crate::ChannelCommands::List => {
@@ -65,6 +65,31 @@ pub async fn handle_command(command: crate::ChannelCommands, config: &Config) ->
                     )
                 );
             }
+            // Named webhooks are served by the gateway at /webhook/<alias>
+            if !config.channels.webhook.is_empty() {
+                let prefix = config.gateway.path_prefix.as_deref().unwrap_or("");
+                let mut aliases: Vec<_> = config.channels.webhook.iter().collect();
+                aliases.sort_by(|a, b| a.0.cmp(b.0));
+                println!();
+                println!(
+                    "{}",
+                    get_required_cli_string("cli-channels-webhooks-header")
+                );
+                for (alias, entry) in aliases {
+                    let route = format!("{prefix}/webhook/{alias}");
+                    println!(
+                        "{}",
+                        get_required_cli_string_with_args(
+                            "cli-channels-webhook-entry",
+                            &[
+                                ("status", if entry.enabled { "✅" } else { "❌" }),
+                                ("route", route.as_str()),
+                                ("mode", if entry.sync_response { "sync" } else { "async" }),
+                            ],
+                        )
+                    );
+                }
+            }
             println!();
             println!("{}", get_required_cli_string("cli-channels-start-hint"));
             println!("{}", get_required_cli_string("cli-channels-doctor-hint"));
@@ -85,10 +110,144 @@ pub async fn handle_command(command: crate::ChannelCommands, config: &Config) ->
         crate::ChannelCommands::BindTelegram { identity, alias } => {
             Box::pin(bind_telegram_identity(config, &identity, &alias)).await
         }
+        crate::ChannelCommands::Pair {
+            channel,
+            alias,
+            png,
+            force,
+            timeout,
+        } => {
+            let options = ChannelPairOptions {
+                png,
+                force,
+                timeout: std::time::Duration::from_secs(timeout.max(1)),
+            };
+            Box::pin(pair_channel(config, &channel, &alias, options)).await
+        }
         crate::ChannelCommands::Send {
             message,
             channel_id,
             recipient,
         } => send_channel_message(config, &channel_id, &recipient, &message).await,
+        crate::ChannelCommands::Outbox { outbox_command } => {
+            handle_outbox_command(outbox_command, config)
+        }
+    }
+}
+
+/// `zeroclaw channel outbox`. Works on the outbox database directly; a
+/// running daemon picks up retries and drops on its next poll.
+fn handle_outbox_command(command: crate::ChannelOutboxCommands, config: &Config) -> Result<()> {
+    use zeroclaw_runtime::outbox::{ChannelOutbox, OutboxStatus, RetryOutcome};
+
+    let outbox = ChannelOutbox::new(config);
+    match command {
+        crate::ChannelOutboxCommands::List { dead, limit } => {
+            let status = dead.then_some(OutboxStatus::Dead);
+            let entries = outbox.list(status, limit)?;
+            if entries.is_empty() {
+                let key = if dead {
+                    "cli-channel-outbox-none-dead"
+                } else {
+                    "cli-channel-outbox-none"
+                };
+                println!("{}", get_required_cli_string(key));
+                return Ok(());
+            }
+            println!(
+                "{}",
+                get_required_cli_string_with_args(
+                    "cli-channel-outbox-header",
+                    &[("count", &entries.len().to_string())]
+                )
+            );
+            for entry in entries {
+                println!(
+                    "{}",
+                    get_required_cli_string_with_args(
+                        "cli-channel-outbox-row",
+                        &[
+                            ("id", &entry.id.to_string()),
+                            ("status", entry.status.as_str()),
+                            ("channel", &entry.channel),
+                            ("recipient", &entry.recipient),
+                            ("attempts", &entry.attempts.to_string()),
+                            ("queued", &entry.created_at.to_rfc3339()),
+                        ]
+                    )
+                );
+                if entry.status == OutboxStatus::Pending {
+                    println!(
+                        "{}",
+                        get_required_cli_string_with_args(
+                            "cli-channel-outbox-row-next",
+                            &[("v", &entry.next_attempt_at.to_rfc3339())]
+                        )
+                    );
+                }
+                if let Some(error) = &entry.last_error {
+                    println!(
+                        "{}",
+                        get_required_cli_string_with_args(
+                            "cli-channel-outbox-row-error",
+                            &[("v", error)]
+                        )
+                    );
+                }
+                let preview = zeroclaw_runtime::util::truncate_with_ellipsis(&entry.content, 80);
+                println!(
+                    "{}",
+                    get_required_cli_string_with_args(
+                        "cli-channel-outbox-row-text",
+                        &[("v", &preview)]
+                    )
+                );
+            }
+            Ok(())
+        }
+        crate::ChannelOutboxCommands::Retry { id } => {
+            let id_str = id.to_string();
+            match outbox.retry(id)? {
+                RetryOutcome::Requeued(_) => println!(
+                    "{}",
+                    get_required_cli_string_with_args(
+                        "cli-channel-outbox-requeued",
+                        &[("id", &id_str)]
+                    )
+                ),
+                RetryOutcome::AlreadyDelivered(_) => anyhow::bail!(
+                    "{}",
+                    get_required_cli_string_with_args(
+                        "cli-channel-outbox-already-delivered",
+                        &[("id", &id_str)]
+                    )
+                ),
+                RetryOutcome::NotFound => anyhow::bail!(
+                    "{}",
+                    get_required_cli_string_with_args(
+                        "cli-channel-outbox-not-found",
+                        &[("id", &id_str)]
+                    )
+                ),
+            }
+            Ok(())
+        }
+        crate::ChannelOutboxCommands::Drop { id } => {
+            let id_str = id.to_string();
+            if !outbox.drop_entry(id)? {
+                anyhow::bail!(
+                    "{}",
+                    get_required_cli_string_with_args(
+                        "cli-channel-outbox-not-found",
+                        &[("id", &id_str)]
+                    )
+                );
+            }
+            println!(
+                "{}",
+                get_required_cli_string_with_args("cli-channel-outbox-dropped", &[("id", &id_str)])
+            );
+            Ok(())
+        }
     }
 }
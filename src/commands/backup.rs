@@ -0,0 +1,1058 @@
+//! `zeroclaw backup` — snapshot an install (config, memory databases,
+//! skills, transcripts, auth profiles) into one `.tar.zst` and restore it.
+//!
+//! Archive layout:
+//! - `manifest.json` — always the first entry: versions plus a SHA-256 per file.
+//! - `files/<root>/<path>` — plain files; `<root>` is `install` or `data`.
+//! - `secrets.enc` — only with `--include-secrets`: an inner tar of the
+//!   secret files (plus `config.toml` when `secrets.encrypt` is off) sealed
+//!   with ChaCha20-Poly1305 under a passphrase-derived key.
+
+use crate::config::Config;
+use anyhow::{Context, Result, anyhow, bail};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng, Payload};
+use chacha20poly1305::{AeadCore, ChaCha20Poly1305, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+use zeroclaw_config::migration::{CURRENT_SCHEMA_VERSION, detect_version, migrate_file_in_place};
+use zeroclaw_runtime::i18n::{get_required_cli_string, get_required_cli_string_with_args};
+
+const FORMAT_VERSION: u32 = 1;
+const MANIFEST_ENTRY: &str = "manifest.json";
+const SECRETS_ENTRY: &str = "secrets.enc";
+const FILES_PREFIX: &str = "files/";
+const RESTORE_TMP_SUFFIX: &str = ".restoring";
+
+/// Non-interactive passphrase source for `--include-secrets` and for
+/// restoring archives that carry secrets.
+const PASSPHRASE_ENV: &str = "ZEROCLAW_BACKUP_PASSPHRASE";
+const KDF_NAME: &str = "pbkdf2-hmac-sha256";
+const KDF_ROUNDS: u32 = if cfg!(test) { 1_000 } else { 600_000 };
+const SECRETS_AAD: &[u8] = b"zeroclaw-backup-secrets-v1";
+
+/// Files holding credentials in the clear, or the key that opens `enc2:`
+/// values in `config.toml`. Matched by file name at any depth.
+const SECRET_FILE_NAMES: &[&str] = &[".secret_key", "auth-profiles.json"];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    format_version: u32,
+    zeroclaw_version: String,
+    /// Schema version of the archived `config.toml`, so a newer build knows
+    /// which migrations to run after restoring.
+    config_schema_version: u32,
+    created_at: String,
+    files: Vec<ManifestFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    secrets: Option<SecretsSeal>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ManifestFile {
+    /// `<root>/<relative path>`, always `/`-separated.
+    path: String,
+    size: u64,
+    sha256: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    secret: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SecretsSeal {
+    kdf: String,
+    rounds: u32,
+    salt: String,
+    nonce: String,
+}
+
+/// A directory tree archived under a fixed label, so restores land in the
+/// current install's directories rather than the absolute paths of the host
+/// that made the backup.
+#[derive(Debug, Clone)]
+struct Root {
+    label: &'static str,
+    dir: PathBuf,
+}
+
+struct Collected {
+    path: String,
+    /// Where the contents are read from: the file itself, or a snapshot of
+    /// it for SQLite databases.
+    source: PathBuf,
+    size: u64,
+    sha256: String,
+    secret: bool,
+}
+
+struct Unpacked {
+    manifest: Manifest,
+    files: BTreeMap<String, Vec<u8>>,
+    sealed: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    Create,
+    Overwrite,
+    Unchanged,
+}
+
+#[derive(Debug)]
+struct Planned {
+    path: String,
+    dest: PathBuf,
+    change: Change,
+    secret: bool,
+}
+
+pub async fn handle_command(command: crate::BackupCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::BackupCommands::Create {
+            output,
+            include_secrets,
+        } => create(config, output, include_secrets),
+        crate::BackupCommands::Restore {
+            archive,
+            dry_run,
+            yes,
+        } => restore(config, &archive, dry_run, yes).await,
+    }
+}
+
+fn create(config: &Config, output: Option<PathBuf>, include_secrets: bool) -> Result<()> {
+    let output = output.unwrap_or_else(|| default_output(config));
+    let passphrase = if include_secrets {
+        Some(read_passphrase(true)?)
+    } else {
+        None
+    };
+    let manifest = write_archive(
+        &archive_roots(config),
+        &output,
+        on_disk_schema_version(&config.config_path),
+        passphrase.as_deref(),
+        (!config.secrets.encrypt).then_some(config.config_path.as_path()),
+    )?;
+
+    println!(
+        "{}",
+        get_required_cli_string_with_args(
+            "cli-backup-created",
+            &[
+                ("path", &output.display().to_string()),
+                ("count", &manifest.files.len().to_string()),
+            ],
+        )
+    );
+    if include_secrets {
+        println!("{}", get_required_cli_string("cli-backup-secrets-included"));
+    } else {
+        println!("{}", get_required_cli_string("cli-backup-secrets-excluded"));
+        if !config.secrets.encrypt {
+            println!(
+                "{}",
+                get_required_cli_string("cli-backup-plaintext-config-excluded")
+            );
+        }
+    }
+    Ok(())
+}
+
+async fn restore(config: &Config, archive: &Path, dry_run: bool, yes: bool) -> Result<()> {
+    let Unpacked {
+        manifest,
+        mut files,
+        sealed,
+    } = read_archive(archive)?;
+    check_compatible(&manifest)?;
+    match (&manifest.secrets, sealed) {
+        (Some(seal), Some(sealed)) => {
+            let passphrase = read_passphrase(false)?;
+            files.extend(open_secrets(seal, &sealed, &passphrase)?);
+        }
+        (None, None) => {}
+        _ => bail!("backup manifest and secrets block disagree; the archive is incomplete"),
+    }
+    verify(&manifest, &files)?;
+    let plan = plan_restore(&manifest, &restore_roots(config))?;
+
+    println!(
+        "{}",
+        get_required_cli_string_with_args(
+            "cli-backup-restore-header",
+            &[
+                ("archive", &archive.display().to_string()),
+                ("version", &manifest.zeroclaw_version),
+                ("schema", &manifest.config_schema_version.to_string()),
+                ("created", &manifest.created_at),
+            ],
+        )
+    );
+    let mut unchanged = 0usize;
+    for item in &plan {
+        let dest = item.dest.display().to_string();
+        match item.change {
+            Change::Create => println!(
+                "{}",
+                get_required_cli_string_with_args("cli-backup-restore-create", &[("path", &dest)])
+            ),
+            Change::Overwrite => println!(
+                "{}",
+                get_required_cli_string_with_args(
+                    "cli-backup-restore-overwrite",
+                    &[("path", &dest)]
+                )
+            ),
+            Change::Unchanged => unchanged += 1,
+        }
+    }
+    if unchanged > 0 {
+        println!(
+            "{}",
+            get_required_cli_string_with_args(
+                "cli-backup-restore-unchanged",
+                &[("count", &unchanged.to_string())],
+            )
+        );
+    }
+
+    if dry_run {
+        println!("{}", get_required_cli_string("cli-backup-restore-dry-run"));
+        return Ok(());
+    }
+    if !yes
+        && plan.iter().any(|item| item.change == Change::Overwrite)
+        && !dialoguer::Confirm::new()
+            .with_prompt(get_required_cli_string("cli-backup-restore-confirm"))
+            .default(false)
+            .interact()?
+    {
+        println!("{}", get_required_cli_string("cli-backup-restore-aborted"));
+        return Ok(());
+    }
+
+    let written = apply_restore(&plan, &files)?;
+    println!(
+        "{}",
+        get_required_cli_string_with_args(
+            "cli-backup-restored",
+            &[("count", &written.to_string())],
+        )
+    );
+    run_pending_migrations().await
+}
+
+/// Bring a restored install up to this build: the V2→V3 filesystem move
+/// runs inside `load_or_init`, the config file is rewritten at the current
+/// schema, and opening memory applies the backend's own schema upgrades.
+async fn run_pending_migrations() -> Result<()> {
+    let config = Box::pin(Config::load_or_init()).await?;
+    if let Some(report) = migrate_file_in_place(&config.config_path)? {
+        println!(
+            "{}",
+            get_required_cli_string_with_args(
+                "cli-backup-config-migrated",
+                &[
+                    ("version", &report.to_version.to_string()),
+                    ("path", &report.backup_path.display().to_string()),
+                ],
+            )
+        );
+    }
+
+    let config = Box::pin(Config::load_or_init()).await?;
+    let backend = crate::memory::backend_kind_from_dotted(&config.memory.backend);
+    if !matches!(
+        crate::memory::classify_memory_backend(&backend),
+        crate::memory::MemoryBackendKind::None
+    ) {
+        drop(crate::memory::create_memory_for_migration(&config)?);
+        println!("{}", get_required_cli_string("cli-backup-memory-migrated"));
+    }
+    Ok(())
+}
+
+fn default_output(config: &Config) -> PathBuf {
+    let ts = chrono::Utc::now().format("%Y%m%dT%H%M%SZ");
+    config
+        .install_root_dir()
+        .join("backups")
+        .join(format!("zeroclaw-backup-{ts}.tar.zst"))
+}
+
+/// Roots to archive. `data_dir` usually lives inside the install root; it
+/// only gets a root of its own when `ZEROCLAW_DATA_DIR` moves it elsewhere.
+fn archive_roots(config: &Config) -> Vec<Root> {
+    let install = config.install_root_dir();
+    let mut roots = vec![Root {
+        label: "install",
+        dir: install.clone(),
+    }];
+    if !config.data_dir.as_os_str().is_empty() && !config.data_dir.starts_with(&install) {
+        roots.push(Root {
+            label: "data",
+            dir: config.data_dir.clone(),
+        });
+    }
+    roots
+}
+
+fn restore_roots(config: &Config) -> Vec<Root> {
+    vec![
+        Root {
+            label: "install",
+            dir: config.install_root_dir(),
+        },
+        Root {
+            label: "data",
+            dir: config.data_dir.clone(),
+        },
+    ]
+}
+
+fn on_disk_schema_version(config_path: &Path) -> u32 {
+    fs::read_to_string(config_path)
+        .ok()
+        .and_then(|raw| toml::from_str::<toml::Value>(&raw).ok())
+        .and_then(|value| detect_version(&value).ok())
+        .unwrap_or(CURRENT_SCHEMA_VERSION)
+}
+
+fn read_passphrase(confirm: bool) -> Result<String> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV)
+        && !passphrase.is_empty()
+    {
+        return Ok(passphrase);
+    }
+    let mut prompt =
+        dialoguer::Password::new().with_prompt(get_required_cli_string("cli-backup-passphrase"));
+    if confirm {
+        prompt = prompt.with_confirmation(
+            get_required_cli_string("cli-backup-passphrase-confirm"),
+            get_required_cli_string("cli-backup-passphrase-mismatch"),
+        );
+    }
+    let passphrase = prompt.interact()?;
+    if passphrase.is_empty() {
+        bail!("an empty passphrase cannot protect backup secrets");
+    }
+    Ok(passphrase)
+}
+
+// -- Archive writing -------------------------------------------------------
+
+/// What to archive from the roots, and which files count as secrets.
+struct Selection<'a> {
+    /// The archive being written, when it lands inside a root.
+    exclude: &'a Path,
+    include_secrets: bool,
+    /// `config.toml` when `secrets.encrypt` is off: its `#[secret]` fields
+    /// are then stored in the clear, so it is handled like the key files.
+    plaintext_config: Option<&'a Path>,
+}
+
+fn write_archive(
+    roots: &[Root],
+    output: &Path,
+    schema_version: u32,
+    passphrase: Option<&str>,
+    plaintext_config: Option<&Path>,
+) -> Result<Manifest> {
+    // Live SQLite databases are copied here first; dropped once written.
+    let snapshots = tempfile::TempDir::new().context("failed to create a snapshot directory")?;
+    let selection = Selection {
+        exclude: output,
+        include_secrets: passphrase.is_some(),
+        plaintext_config,
+    };
+    let collected = collect_files(roots, &selection, snapshots.path())?;
+    let mut manifest = Manifest {
+        format_version: FORMAT_VERSION,
+        zeroclaw_version: env!("CARGO_PKG_VERSION").to_string(),
+        config_schema_version: schema_version,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        files: collected
+            .iter()
+            .map(|file| ManifestFile {
+                path: file.path.clone(),
+                size: file.size,
+                sha256: file.sha256.clone(),
+                secret: file.secret,
+            })
+            .collect(),
+        secrets: None,
+    };
+
+    let mut sealed = None;
+    if let Some(passphrase) = passphrase {
+        // Secret files are a handful of small credential files, so the
+        // inner tar is built in memory to be sealed in one piece.
+        let mut inner = tar::Builder::new(Vec::new());
+        for file in collected.iter().filter(|file| file.secret) {
+            let bytes = fs::read(&file.source)
+                .with_context(|| format!("failed to read {}", file.source.display()))?;
+            append_bytes(&mut inner, &file.path, &bytes, 0o600)?;
+        }
+        let (seal, bytes) = seal_secrets(&inner.into_inner()?, passphrase)?;
+        manifest.secrets = Some(seal);
+        sealed = Some(bytes);
+    }
+
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let file = fs::File::create(output)
+        .with_context(|| format!("failed to create {}", output.display()))?;
+    let mut builder = tar::Builder::new(zstd::Encoder::new(file, 0)?);
+    append_bytes(
+        &mut builder,
+        MANIFEST_ENTRY,
+        &serde_json::to_vec_pretty(&manifest)?,
+        0o644,
+    )?;
+    for file in collected.iter().filter(|file| !file.secret) {
+        append_file(&mut builder, file)?;
+    }
+    if let Some(bytes) = sealed {
+        append_bytes(&mut builder, SECRETS_ENTRY, &bytes, 0o600)?;
+    }
+    builder.into_inner()?.finish()?.flush()?;
+    // Memory and transcripts are private even when secrets are left out.
+    restrict_permissions(output)?;
+    Ok(manifest)
+}
+
+fn collect_files(
+    roots: &[Root],
+    selection: &Selection<'_>,
+    snapshots: &Path,
+) -> Result<Vec<Collected>> {
+    let mut out = Vec::new();
+    for root in roots {
+        if root.dir.is_dir() {
+            walk(root, &root.dir, selection, snapshots, &mut out)?;
+        }
+    }
+    Ok(out)
+}
+
+fn walk(
+    root: &Root,
+    dir: &Path,
+    selection: &Selection<'_>,
+    snapshots: &Path,
+    out: &mut Vec<Collected>,
+) -> Result<()> {
+    let mut entries = fs::read_dir(dir)
+        .with_context(|| format!("failed to read {}", dir.display()))?
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort_by_key(fs::DirEntry::file_name);
+    for entry in entries {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        // `file_type` does not follow symlinks, so linked trees stay out.
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            if !is_skipped_dir(&name) {
+                walk(root, &path, selection, snapshots, out)?;
+            }
+            continue;
+        }
+        if !file_type.is_file()
+            || path == selection.exclude
+            || is_skipped_file(&name)
+            || is_sqlite_journal(&path)
+        {
+            continue;
+        }
+        let secret = SECRET_FILE_NAMES.contains(&name.as_str())
+            || selection.plaintext_config == Some(path.as_path());
+        if secret && !selection.include_secrets {
+            continue;
+        }
+        let rel = path.strip_prefix(&root.dir).unwrap_or(&path);
+        let rel = rel
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let source = if is_sqlite(&path) {
+            snapshot_sqlite(&path, &snapshots.join(format!("{}.db", out.len())))?
+        } else {
+            path
+        };
+        let (size, sha256) = sha256_file(&source)?;
+        out.push(Collected {
+            path: format!("{}/{rel}", root.label),
+            source,
+            size,
+            sha256,
+            secret,
+        });
+    }
+    Ok(())
+}
+
+/// Earlier backups (CLI archives, `backup` tool snapshots, and the
+/// `backup-<ts>` directories schema migrations leave behind) would nest
+/// every previous copy inside the next one.
+fn is_skipped_dir(name: &str) -> bool {
+    name == "backups" || name.starts_with("backup-")
+}
+
+/// SQLite rebuilds `-shm` on open; half-written restore temp files are junk.
+fn is_skipped_file(name: &str) -> bool {
+    name.ends_with("-shm") || name.ends_with(RESTORE_TMP_SUFFIX)
+}
+
+fn is_sqlite(path: &Path) -> bool {
+    let mut magic = [0u8; 16];
+    fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .is_ok_and(|()| &magic == b"SQLite format 3\0")
+}
+
+/// `-wal` and `-journal` files of a database that gets snapshotted; the
+/// snapshot already carries their committed pages.
+fn is_sqlite_journal(path: &Path) -> bool {
+    let name = path.as_os_str().to_string_lossy();
+    ["-wal", "-journal"].iter().any(|suffix| {
+        name.strip_suffix(suffix)
+            .is_some_and(|db| is_sqlite(Path::new(db)))
+    })
+}
+
+/// Copy a live database with `VACUUM INTO`, which reads one consistent
+/// transaction, instead of raw-copying pages a writer may be changing.
+fn snapshot_sqlite(db: &Path, dest: &Path) -> Result<PathBuf> {
+    let conn =
+        rusqlite::Connection::open_with_flags(db, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .with_context(|| format!("failed to open {}", db.display()))?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))?;
+    conn.execute("VACUUM INTO ?1", [dest.to_string_lossy()])
+        .with_context(|| format!("failed to snapshot {}", db.display()))?;
+    Ok(dest.to_path_buf())
+}
+
+fn append_file<W: Write>(builder: &mut tar::Builder<W>, file: &Collected) -> Result<()> {
+    let path = format!("{FILES_PREFIX}{}", file.path);
+    let source = fs::File::open(&file.source)
+        .with_context(|| format!("failed to read {}", file.source.display()))?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(file.size);
+    header.set_mode(0o644);
+    header.set_mtime(chrono::Utc::now().timestamp().max(0) as u64);
+    header.set_cksum();
+    // Held to the hashed size so the tar stays well-formed if the file
+    // changed since; restore's checksum check then reports it.
+    let data = source
+        .take(file.size)
+        .chain(std::io::repeat(0))
+        .take(file.size);
+    builder
+        .append_data(&mut header, &path, data)
+        .with_context(|| format!("failed to archive {path}"))
+}
+
+fn append_bytes<W: Write>(
+    builder: &mut tar::Builder<W>,
+    path: &str,
+    bytes: &[u8],
+    mode: u32,
+) -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(bytes.len() as u64);
+    header.set_mode(mode);
+    header.set_mtime(chrono::Utc::now().timestamp().max(0) as u64);
+    header.set_cksum();
+    builder
+        .append_data(&mut header, path, bytes)
+        .with_context(|| format!("failed to archive {path}"))
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], rounds: u32) -> Key {
+    let mut key = Key::default();
+    pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, rounds, key.as_mut_slice());
+    key
+}
+
+fn seal_secrets(plain: &[u8], passphrase: &str) -> Result<(SecretsSeal, Vec<u8>)> {
+    let mut salt = [0u8; 16];
+    OsRng.fill_bytes(&mut salt);
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &salt, KDF_ROUNDS));
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let sealed = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plain,
+                aad: SECRETS_AAD,
+            },
+        )
+        .map_err(|_| anyhow!("failed to encrypt backup secrets"))?;
+    let seal = SecretsSeal {
+        kdf: KDF_NAME.to_string(),
+        rounds: KDF_ROUNDS,
+        salt: hex::encode(salt),
+        nonce: hex::encode(nonce),
+    };
+    Ok((seal, sealed))
+}
+
+// -- Archive reading -------------------------------------------------------
+
+fn read_archive(path: &Path) -> Result<Unpacked> {
+    let file =
+        fs::File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    let decoder = zstd::Decoder::new(file)
+        .with_context(|| format!("{} is not a zstd archive", path.display()))?;
+    let mut archive = tar::Archive::new(decoder);
+
+    let mut manifest = None;
+    let mut files = BTreeMap::new();
+    let mut sealed = None;
+    for entry in archive
+        .entries()
+        .with_context(|| format!("failed to read {}", path.display()))?
+    {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let name = entry.path()?.to_string_lossy().replace('\\', "/");
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes)?;
+
+        if manifest.is_none() {
+            if name != MANIFEST_ENTRY {
+                bail!(
+                    "{} is not a ZeroClaw backup: first entry is {name}, expected {MANIFEST_ENTRY}",
+                    path.display()
+                );
+            }
+            manifest =
+                Some(serde_json::from_slice(&bytes).context("backup manifest is malformed")?);
+        } else if name == SECRETS_ENTRY {
+            sealed = Some(bytes);
+        } else if let Some(rel) = name.strip_prefix(FILES_PREFIX) {
+            files.insert(rel.to_string(), bytes);
+        } else {
+            bail!("unexpected entry {name} in backup archive");
+        }
+    }
+
+    let manifest = manifest.with_context(|| format!("{} is empty", path.display()))?;
+    Ok(Unpacked {
+        manifest,
+        files,
+        sealed,
+    })
+}
+
+fn check_compatible(manifest: &Manifest) -> Result<()> {
+    if manifest.format_version > FORMAT_VERSION {
+        bail!(
+            "backup format v{} is newer than this build reads (v{FORMAT_VERSION}); upgrade ZeroClaw before restoring",
+            manifest.format_version
+        );
+    }
+    if manifest.config_schema_version > CURRENT_SCHEMA_VERSION {
+        bail!(
+            "backup was made by ZeroClaw {} with config schema v{}, newer than this build's v{CURRENT_SCHEMA_VERSION}; upgrade ZeroClaw before restoring",
+            manifest.zeroclaw_version,
+            manifest.config_schema_version
+        );
+    }
+    Ok(())
+}
+
+fn open_secrets(
+    seal: &SecretsSeal,
+    sealed: &[u8],
+    passphrase: &str,
+) -> Result<BTreeMap<String, Vec<u8>>> {
+    if seal.kdf != KDF_NAME {
+        bail!("unsupported backup key derivation {}", seal.kdf);
+    }
+    let salt = hex::decode(&seal.salt).context("backup secrets salt is not hex")?;
+    let nonce = hex::decode(&seal.nonce).context("backup secrets nonce is not hex")?;
+    if nonce.len() != 12 {
+        bail!("backup secrets nonce has the wrong length");
+    }
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &salt, seal.rounds));
+    let plain = cipher
+        .decrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: sealed,
+                aad: SECRETS_AAD,
+            },
+        )
+        .map_err(|_| anyhow!("wrong passphrase, or the backup secrets are corrupted"))?;
+
+    let mut inner = tar::Archive::new(plain.as_slice());
+    let mut out = BTreeMap::new();
+    for entry in inner.entries()? {
+        let mut entry = entry?;
+        let name = entry.path()?.to_string_lossy().replace('\\', "/");
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes)?;
+        out.insert(name, bytes);
+    }
+    Ok(out)
+}
+
+fn verify(manifest: &Manifest, files: &BTreeMap<String, Vec<u8>>) -> Result<()> {
+    let listed: HashSet<&str> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+    if let Some(extra) = files.keys().find(|path| !listed.contains(path.as_str())) {
+        bail!("backup carries {extra}, which its manifest does not list");
+    }
+    for entry in &manifest.files {
+        let bytes = files
+            .get(&entry.path)
+            .with_context(|| format!("backup is missing {}", entry.path))?;
+        let actual = sha256_hex(bytes);
+        if actual != entry.sha256 {
+            bail!(
+                "checksum mismatch for {}: manifest has {}, archive has {actual}",
+                entry.path,
+                entry.sha256
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Map an archive path onto this install. Anything that would climb out of
+/// its root is rejected rather than sanitised.
+fn destination(path: &str, roots: &[Root]) -> Result<PathBuf> {
+    let (label, rel) = path
+        .split_once('/')
+        .with_context(|| format!("backup path {path} has no root"))?;
+    let root = roots
+        .iter()
+        .find(|root| root.label == label)
+        .with_context(|| format!("backup path {path} names unknown root {label}"))?;
+    let rel = Path::new(rel);
+    if rel.as_os_str().is_empty()
+        || !rel
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+    {
+        bail!("backup path {path} escapes its root");
+    }
+    Ok(root.dir.join(rel))
+}
+
+fn plan_restore(manifest: &Manifest, roots: &[Root]) -> Result<Vec<Planned>> {
+    manifest
+        .files
+        .iter()
+        .map(|entry| {
+            let dest = destination(&entry.path, roots)?;
+            let change = match fs::read(&dest) {
+                Ok(existing) if sha256_hex(&existing) == entry.sha256 => Change::Unchanged,
+                Ok(_) => Change::Overwrite,
+                Err(_) => Change::Create,
+            };
+            Ok(Planned {
+                path: entry.path.clone(),
+                dest,
+                change,
+                secret: entry.secret,
+            })
+        })
+        .collect()
+}
+
+fn apply_restore(plan: &[Planned], files: &BTreeMap<String, Vec<u8>>) -> Result<usize> {
+    let restored: HashSet<&Path> = plan.iter().map(|item| item.dest.as_path()).collect();
+    let mut written = 0;
+    for item in plan {
+        if item.change != Change::Unchanged {
+            if item.dest.extension().is_some_and(|ext| ext == "db") {
+                drop_stale_sqlite_sidecars(&item.dest, &restored)?;
+            }
+            write_restored(&item.dest, &files[&item.path])?;
+            written += 1;
+        }
+        if item.secret {
+            restrict_permissions(&item.dest)?;
+        }
+    }
+    Ok(written)
+}
+
+/// A WAL left over from the database being replaced would be replayed
+/// into the restored file on next open.
+fn drop_stale_sqlite_sidecars(db: &Path, restored: &HashSet<&Path>) -> Result<()> {
+    for suffix in ["-wal", "-shm"] {
+        let mut sidecar = db.as_os_str().to_owned();
+        sidecar.push(suffix);
+        let sidecar = PathBuf::from(sidecar);
+        if restored.contains(sidecar.as_path()) {
+            continue;
+        }
+        match fs::remove_file(&sidecar) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                return Err(e).with_context(|| format!("failed to remove {}", sidecar.display()));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_restored(dest: &Path, bytes: &[u8]) -> Result<()> {
+    let parent = dest
+        .parent()
+        .with_context(|| format!("{} has no parent directory", dest.display()))?;
+    fs::create_dir_all(parent).with_context(|| format!("failed to create {}", parent.display()))?;
+    let name = dest
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let tmp = parent.join(format!(".{name}{RESTORE_TMP_SUFFIX}"));
+    fs::write(&tmp, bytes).with_context(|| format!("failed to write {}", tmp.display()))?;
+    fs::rename(&tmp, dest).with_context(|| format!("failed to replace {}", dest.display()))
+}
+
+#[cfg(unix)]
+fn restrict_permissions(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o600))
+        .with_context(|| format!("failed to restrict permissions on {}", path.display()))
+}
+
+#[cfg(not(unix))]
+fn restrict_permissions(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+fn sha256_file(path: &Path) -> Result<(u64, String)> {
+    let mut file =
+        fs::File::open(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    let size = std::io::copy(&mut file, &mut hasher)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    Ok((size, hex::encode(hasher.finalize())))
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn install_root(dir: &Path) -> Vec<Root> {
+        vec![Root {
+            label: "install",
+            dir: dir.to_path_buf(),
+        }]
+    }
+
+    fn seed_install(dir: &Path) {
+        fs::create_dir_all(dir.join("agents/default/workspace/memory")).unwrap();
+        fs::create_dir_all(dir.join("backups")).unwrap();
+        fs::write(dir.join("config.toml"), "schema_version = 3\n").unwrap();
+        fs::write(dir.join(".secret_key"), "0123abcd").unwrap();
+        fs::write(dir.join("auth-profiles.json"), "{}").unwrap();
+        fs::write(
+            dir.join("agents/default/workspace/memory/brain.db"),
+            b"sqlite",
+        )
+        .unwrap();
+        fs::write(dir.join("backups/old.tar.zst"), b"old").unwrap();
+    }
+
+    #[test]
+    fn round_trip_excludes_secrets_by_default() {
+        let src = TempDir::new().unwrap();
+        seed_install(src.path());
+        let out = TempDir::new().unwrap();
+        let archive = out.path().join("b.tar.zst");
+
+        let written = write_archive(&install_root(src.path()), &archive, 3, None, None).unwrap();
+        let paths: Vec<&str> = written.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "install/agents/default/workspace/memory/brain.db",
+                "install/config.toml",
+            ]
+        );
+
+        let unpacked = read_archive(&archive).unwrap();
+        assert!(unpacked.manifest.secrets.is_none());
+        verify(&unpacked.manifest, &unpacked.files).unwrap();
+
+        let dst = TempDir::new().unwrap();
+        fs::write(dst.path().join("config.toml"), "schema_version = 2\n").unwrap();
+        let plan = plan_restore(&unpacked.manifest, &install_root(dst.path())).unwrap();
+        let changes: Vec<Change> = plan.iter().map(|item| item.change).collect();
+        assert_eq!(changes, [Change::Create, Change::Overwrite]);
+
+        assert_eq!(apply_restore(&plan, &unpacked.files).unwrap(), 2);
+        assert_eq!(
+            fs::read_to_string(dst.path().join("config.toml")).unwrap(),
+            "schema_version = 3\n"
+        );
+        assert!(!dst.path().join(".secret_key").exists());
+    }
+
+    #[test]
+    fn included_secrets_need_the_passphrase_and_restore_as_0600() {
+        let src = TempDir::new().unwrap();
+        seed_install(src.path());
+        let archive = src.path().join("backups/with-secrets.tar.zst");
+        write_archive(
+            &install_root(src.path()),
+            &archive,
+            3,
+            Some("hunter2"),
+            None,
+        )
+        .unwrap();
+
+        let mut unpacked = read_archive(&archive).unwrap();
+        let seal = unpacked.manifest.secrets.as_ref().unwrap();
+        let sealed = unpacked.sealed.take().unwrap();
+        assert!(!unpacked.files.contains_key("install/.secret_key"));
+        assert!(open_secrets(seal, &sealed, "wrong").is_err());
+        unpacked
+            .files
+            .extend(open_secrets(seal, &sealed, "hunter2").unwrap());
+        verify(&unpacked.manifest, &unpacked.files).unwrap();
+
+        let dst = TempDir::new().unwrap();
+        let plan = plan_restore(&unpacked.manifest, &install_root(dst.path())).unwrap();
+        apply_restore(&plan, &unpacked.files).unwrap();
+        let key = dst.path().join(".secret_key");
+        assert_eq!(fs::read_to_string(&key).unwrap(), "0123abcd");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&key).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn plaintext_config_is_treated_as_a_secret() {
+        let src = TempDir::new().unwrap();
+        seed_install(src.path());
+        let config = src.path().join("config.toml");
+        let out = TempDir::new().unwrap();
+
+        let archive = out.path().join("plain.tar.zst");
+        let written =
+            write_archive(&install_root(src.path()), &archive, 3, None, Some(&config)).unwrap();
+        assert!(
+            written
+                .files
+                .iter()
+                .all(|f| f.path != "install/config.toml")
+        );
+
+        let archive = out.path().join("sealed.tar.zst");
+        write_archive(
+            &install_root(src.path()),
+            &archive,
+            3,
+            Some("hunter2"),
+            Some(&config),
+        )
+        .unwrap();
+        let unpacked = read_archive(&archive).unwrap();
+        assert!(!unpacked.files.contains_key("install/config.toml"));
+        let seal = unpacked.manifest.secrets.as_ref().unwrap();
+        let secrets = open_secrets(seal, unpacked.sealed.as_ref().unwrap(), "hunter2").unwrap();
+        assert!(secrets.contains_key("install/config.toml"));
+    }
+
+    #[test]
+    fn live_sqlite_databases_are_snapshotted_without_their_wal() {
+        let src = TempDir::new().unwrap();
+        let db = src.path().join("sessions.db");
+        let conn = rusqlite::Connection::open(&db).unwrap();
+        conn.pragma_update(None, "journal_mode", "WAL").unwrap();
+        conn.execute_batch("CREATE TABLE t (v TEXT); INSERT INTO t VALUES ('kept');")
+            .unwrap();
+        // The open connection keeps the insert in `sessions.db-wal`.
+        assert!(src.path().join("sessions.db-wal").exists());
+
+        let out = TempDir::new().unwrap();
+        let archive = out.path().join("b.tar.zst");
+        let written = write_archive(&install_root(src.path()), &archive, 3, None, None).unwrap();
+        let paths: Vec<&str> = written.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, ["install/sessions.db"]);
+
+        let unpacked = read_archive(&archive).unwrap();
+        verify(&unpacked.manifest, &unpacked.files).unwrap();
+        let dst = TempDir::new().unwrap();
+        let plan = plan_restore(&unpacked.manifest, &install_root(dst.path())).unwrap();
+        apply_restore(&plan, &unpacked.files).unwrap();
+        let restored = rusqlite::Connection::open(dst.path().join("sessions.db")).unwrap();
+        let value: String = restored
+            .query_row("SELECT v FROM t", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(value, "kept");
+        drop(conn);
+    }
+
+    #[test]
+    fn verify_rejects_tampered_and_unlisted_files() {
+        let src = TempDir::new().unwrap();
+        seed_install(src.path());
+        let out = TempDir::new().unwrap();
+        let archive = out.path().join("b.tar.zst");
+        write_archive(&install_root(src.path()), &archive, 3, None, None).unwrap();
+
+        let mut unpacked = read_archive(&archive).unwrap();
+        unpacked
+            .files
+            .insert("install/config.toml".into(), b"tampered".to_vec());
+        assert!(verify(&unpacked.manifest, &unpacked.files).is_err());
+
+        let mut unpacked = read_archive(&archive).unwrap();
+        unpacked
+            .files
+            .insert("install/extra.txt".into(), b"extra".to_vec());
+        assert!(verify(&unpacked.manifest, &unpacked.files).is_err());
+    }
+
+    #[test]
+    fn destination_rejects_paths_outside_their_root() {
+        let roots = install_root(Path::new("/srv/zeroclaw"));
+        assert_eq!(
+            destination("install/config.toml", &roots).unwrap(),
+            Path::new("/srv/zeroclaw/config.toml")
+        );
+        assert!(destination("install/../etc/passwd", &roots).is_err());
+        assert!(destination("install//etc/passwd", &roots).is_err());
+        assert!(destination("elsewhere/config.toml", &roots).is_err());
+    }
+
+    #[test]
+    fn newer_schema_is_refused() {
+        let manifest = Manifest {
+            format_version: FORMAT_VERSION,
+            zeroclaw_version: "99.0.0".into(),
+            config_schema_version: CURRENT_SCHEMA_VERSION + 1,
+            created_at: String::new(),
+            files: Vec::new(),
+            secrets: None,
+        };
+        assert!(check_compatible(&manifest).is_err());
+    }
+}
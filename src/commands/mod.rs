@@ -1,4 +1,6 @@
 #[cfg(feature = "agent-runtime")]
+pub mod backup;
+#[cfg(feature = "agent-runtime")]
 pub mod eval;
 #[cfg(feature = "agent-runtime")]
 pub mod self_test;
@@ -3,6 +3,7 @@
 use anyhow::{Context, Result, bail};
 use sha2::{Digest, Sha256};
 use std::path::{Path, PathBuf};
+use zeroclaw_config::schema::UpdateChannel;
 
 #[cfg(feature = "agent-runtime")]
 use zeroclaw_runtime::i18n::{get_required_cli_string, get_required_cli_string_with_args};
@@ -94,10 +95,48 @@ fn install_dir_not_writable_message(dir: &str, error: &str) -> String {
     }
 }
 
+fn backup_kept_message(path: &str) -> String {
+    #[cfg(feature = "agent-runtime")]
+    {
+        get_required_cli_string_with_args("cli-update-backup-kept", &[("path", path)])
+    }
+
+    #[cfg(not(feature = "agent-runtime"))]
+    {
+        format!("Previous binary kept at {path}; run `zeroclaw update --rollback` to restore it.")
+    }
+}
+
+fn rollback_missing_message(path: &str) -> String {
+    #[cfg(feature = "agent-runtime")]
+    {
+        get_required_cli_string_with_args("cli-update-rollback-missing", &[("path", path)])
+    }
+
+    #[cfg(not(feature = "agent-runtime"))]
+    {
+        format!("no previous binary to roll back to ({path} does not exist)")
+    }
+}
+
+fn rollback_success_message(version: &str) -> String {
+    #[cfg(feature = "agent-runtime")]
+    {
+        get_required_cli_string_with_args("cli-update-rollback-success", &[("version", version)])
+    }
+
+    #[cfg(not(feature = "agent-runtime"))]
+    {
+        format!("Rolled back to the previous binary ({version}).")
+    }
+}
+
 const GITHUB_RELEASES_LATEST_URL: &str =
     "https://api.github.com/repos/zeroclaw-labs/zeroclaw/releases/latest";
 const GITHUB_RELEASES_TAG_URL: &str =
     "https://api.github.com/repos/zeroclaw-labs/zeroclaw/releases/tags";
+const GITHUB_RELEASES_LIST_URL: &str =
+    "https://api.github.com/repos/zeroclaw-labs/zeroclaw/releases?per_page=30";
 
 #[derive(Debug)]
 pub struct UpdateInfo {
@@ -112,11 +151,17 @@ pub struct UpdateInfo {
     pub release_notes: Option<String>,
     /// Release publish timestamp (ISO-8601), as returned by GitHub.
     pub published_at: Option<String>,
+    /// Release track the check followed (`[updater] channel`).
+    pub channel: UpdateChannel,
+    /// SHA-256 the release's SHA256SUMS publishes for this platform's asset.
+    /// `None` when the release has no matching asset or no checksum file.
+    pub expected_sha256: Option<String>,
 }
 
 /// Check for available updates without downloading.
-/// If `target_version` is `Some`, fetch that specific release tag instead of latest.
-pub async fn check(target_version: Option<&str>) -> Result<UpdateInfo> {
+/// If `target_version` is `Some`, fetch that specific release tag; otherwise
+/// pick the newest release on `channel`.
+pub async fn check(target_version: Option<&str>, channel: UpdateChannel) -> Result<UpdateInfo> {
     let current = env!("CARGO_PKG_VERSION").to_string();
 
     let client = reqwest::Client::builder()
@@ -124,29 +169,28 @@ pub async fn check(target_version: Option<&str>) -> Result<UpdateInfo> {
         .timeout(std::time::Duration::from_secs(15))
         .build()?;
 
-    let url = match target_version {
+    let release = match target_version {
         Some(v) => {
             let tag = if v.starts_with('v') {
                 v.to_string()
             } else {
                 format!("v{v}")
             };
-            format!("{GITHUB_RELEASES_TAG_URL}/{tag}")
+            fetch_release_json(&client, &format!("{GITHUB_RELEASES_TAG_URL}/{tag}")).await?
+        }
+        None if channel == UpdateChannel::Stable => {
+            fetch_release_json(&client, GITHUB_RELEASES_LATEST_URL).await?
+        }
+        None => {
+            let releases = fetch_release_json(&client, GITHUB_RELEASES_LIST_URL).await?;
+            select_release_for_channel(releases.as_array().map_or(&[][..], Vec::as_slice), channel)
+                .cloned()
+                .with_context(|| {
+                    format!("no published release on the {} channel", channel.as_str())
+                })?
         }
-        None => GITHUB_RELEASES_LATEST_URL.to_string(),
     };
 
-    let resp = client
-        .get(&url)
-        .send()
-        .await
-        .context("failed to reach GitHub releases API")?;
-
-    if !resp.status().is_success() {
-        bail!("GitHub API returned {}", resp.status());
-    }
-
-    let release: serde_json::Value = resp.json().await?;
     let tag = release["tag_name"]
         .as_str()
         .unwrap_or("unknown")
@@ -164,6 +208,15 @@ pub async fn check(target_version: Option<&str>) -> Result<UpdateInfo> {
         .map(str::to_string);
     let published_at = release["published_at"].as_str().map(str::to_string);
 
+    // Best-effort: surfaces the digest in `--check` output so an operator can
+    // audit it before applying. `run` re-verifies against the same file.
+    let expected_sha256 = match (download_url.as_deref(), sha256sums_url.as_deref()) {
+        (Some(asset_url), Some(sums_url)) => fetch_expected_sha256(&client, asset_url, sums_url)
+            .await
+            .ok(),
+        _ => None,
+    };
+
     Ok(UpdateInfo {
         current_version: current,
         latest_version: tag,
@@ -173,17 +226,97 @@ pub async fn check(target_version: Option<&str>) -> Result<UpdateInfo> {
         release_url,
         release_notes,
         published_at,
+        channel,
+        expected_sha256,
+    })
+}
+
+async fn fetch_release_json(client: &reqwest::Client, url: &str) -> Result<serde_json::Value> {
+    let resp = client
+        .get(url)
+        .send()
+        .await
+        .context("failed to reach GitHub releases API")?;
+
+    if !resp.status().is_success() {
+        bail!("GitHub API returned {}", resp.status());
+    }
+
+    Ok(resp.json().await?)
+}
+
+/// Pick the newest release on `channel` from GitHub's release list, which is
+/// ordered newest first. Drafts are never eligible.
+fn select_release_for_channel(
+    releases: &[serde_json::Value],
+    channel: UpdateChannel,
+) -> Option<&serde_json::Value> {
+    releases.iter().find(|release| {
+        if release["draft"].as_bool().unwrap_or(false) {
+            return false;
+        }
+        let tag = release["tag_name"].as_str().unwrap_or_default();
+        let prerelease = release["prerelease"].as_bool().unwrap_or(false);
+        release_matches_channel(tag, prerelease, channel)
     })
 }
 
-pub async fn run(target_version: Option<&str>, force: bool) -> Result<()> {
+fn release_matches_channel(tag: &str, prerelease: bool, channel: UpdateChannel) -> bool {
+    let suffix = tag.split_once('-').map(|(_, pre)| pre.to_ascii_lowercase());
+    match channel {
+        UpdateChannel::Stable => !prerelease && suffix.is_none(),
+        UpdateChannel::Beta => match suffix {
+            None => !prerelease,
+            Some(pre) => pre.starts_with("beta") || pre.starts_with("rc"),
+        },
+        UpdateChannel::Nightly => true,
+    }
+}
+
+/// Roll back to the binary the last successful `zeroclaw update` kept as
+/// `zeroclaw.bak`. The backup is validated and smoke-tested like a download.
+pub async fn rollback() -> Result<()> {
+    let current_exe =
+        std::env::current_exe().context("cannot determine current executable path")?;
+    let backup_path = backup_path_for(&current_exe);
+    if !backup_path.is_file() {
+        bail!(rollback_missing_message(&backup_path.display().to_string()));
+    }
+
+    ensure_install_dir_writable(&current_exe).await?;
+    validate_binary(&backup_path)
+        .await
+        .context("previous binary failed validation; refusing to restore it")?;
+
+    rollback_binary(&backup_path, &current_exe).await?;
+    let output = tokio::process::Command::new(&current_exe)
+        .arg("--version")
+        .output()
+        .await
+        .context("smoke test: cannot execute restored binary")?;
+    if !output.status.success() {
+        bail!("smoke test: restored binary returned non-zero exit code");
+    }
+    let _ = tokio::fs::remove_file(&backup_path).await;
+
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    println!("{}", rollback_success_message(&version));
+    Ok(())
+}
+
+/// `zeroclaw` -> `zeroclaw.bak`, `zeroclaw.exe` -> `zeroclaw.bak`.
+fn backup_path_for(current_exe: &Path) -> PathBuf {
+    current_exe.with_extension("bak")
+}
+
+pub async fn run(target_version: Option<&str>, force: bool, channel: UpdateChannel) -> Result<()> {
     // Phase 1: Preflight
     ::zeroclaw_log::record!(
         INFO,
         ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
         "Phase 1/6: Preflight checks..."
     );
-    let update_info = check(target_version).await?;
+    let update_info = check(target_version, channel).await?;
 
     if !should_install(update_info.is_newer, force) {
         println!(
@@ -212,6 +345,9 @@ pub async fn run(target_version: Option<&str>, force: bool) -> Result<()> {
     let download_url = update_info
         .download_url
         .context("no suitable binary found for this platform")?;
+    let sha256sums_url = update_info.sha256sums_url.context(
+        "release publishes no SHA256SUMS asset; refusing to install an unverified update",
+    )?;
 
     let current_exe =
         std::env::current_exe().context("cannot determine current executable path")?;
@@ -231,12 +367,7 @@ pub async fn run(target_version: Option<&str>, force: bool) -> Result<()> {
         .tempdir()
         .context("failed to create temp dir")?;
     let staging = temp_dir.path().join("staging");
-    let main_binary = download_release(
-        &download_url,
-        update_info.sha256sums_url.as_deref(),
-        &staging,
-    )
-    .await?;
+    let main_binary = download_release(&download_url, &sha256sums_url, &staging).await?;
 
     // Phase 3: Backup
     ::zeroclaw_log::record!(
@@ -244,7 +375,7 @@ pub async fn run(target_version: Option<&str>, force: bool) -> Result<()> {
         ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note),
         "Phase 3/6: Creating backup..."
     );
-    let backup_path = current_exe.with_extension("bak");
+    let backup_path = backup_path_for(&current_exe);
     tokio::fs::copy(&current_exe, &backup_path)
         .await
         .context("failed to backup current binary")?;
@@ -291,14 +422,17 @@ pub async fn run(target_version: Option<&str>, force: bool) -> Result<()> {
     );
     match smoke_test(&current_exe).await {
         Ok(()) => {
-            // Cleanup backup on success
-            let _ = tokio::fs::remove_file(&backup_path).await;
+            // Keep the backup so `zeroclaw update --rollback` can restore it.
             // Install everything else the archive shipped (the `zerocode`
             // companion, the `web/dist` dashboard bundle, …). Best-effort:
             // the validated main binary is already in place and must not be
             // rolled back if these fail.
             install_companion_artifacts(&staging, &current_exe).await;
             println!("{}", update_success_message(&update_info.latest_version));
+            println!(
+                "{}",
+                backup_kept_message(&backup_path.display().to_string())
+            );
             println!("{}", prebuilt_channel_note_message());
             Ok(())
         }
@@ -400,11 +534,55 @@ fn target_triple_for(os: &str, arch: &str, windows_gnu: bool) -> Option<&'static
     }
 }
 
+/// Compare `X.Y.Z[-pre]` versions. A release outranks its own prereleases;
+/// prerelease identifiers compare numerically when both are numbers.
 fn version_is_newer(current: &str, candidate: &str) -> bool {
-    let parse = |v: &str| -> Vec<u32> { v.split('.').filter_map(|p| p.parse().ok()).collect() };
-    let cur = parse(current);
-    let cand = parse(candidate);
-    cand > cur
+    use std::cmp::Ordering;
+
+    let parse = |v: &str| -> (Vec<u32>, Option<String>) {
+        let v = v.trim_start_matches('v');
+        let (core, pre) = match v.split_once('-') {
+            Some((core, pre)) => (core, Some(pre.to_string())),
+            None => (v, None),
+        };
+        (
+            core.split('.').filter_map(|p| p.parse().ok()).collect(),
+            pre,
+        )
+    };
+    let (cur, cur_pre) = parse(current);
+    let (cand, cand_pre) = parse(candidate);
+
+    match cand.cmp(&cur) {
+        Ordering::Greater => true,
+        Ordering::Less => false,
+        Ordering::Equal => match (cur_pre, cand_pre) {
+            (Some(_), None) => true,
+            (Some(cur_pre), Some(cand_pre)) => {
+                compare_prerelease(&cand_pre, &cur_pre) == Ordering::Greater
+            }
+            _ => false,
+        },
+    }
+}
+
+fn compare_prerelease(a: &str, b: &str) -> std::cmp::Ordering {
+    let mut a_parts = a.split('.');
+    let mut b_parts = b.split('.');
+    loop {
+        let ord = match (a_parts.next(), b_parts.next()) {
+            (None, None) => return std::cmp::Ordering::Equal,
+            (None, Some(_)) => return std::cmp::Ordering::Less,
+            (Some(_), None) => return std::cmp::Ordering::Greater,
+            (Some(x), Some(y)) => match (x.parse::<u64>(), y.parse::<u64>()) {
+                (Ok(x), Ok(y)) => x.cmp(&y),
+                _ => x.cmp(y),
+            },
+        };
+        if ord != std::cmp::Ordering::Equal {
+            return ord;
+        }
+    }
 }
 
 /// Decide whether to proceed with the install. A newer version always installs;
@@ -415,7 +593,8 @@ fn should_install(is_newer: bool, force: bool) -> bool {
     is_newer || force
 }
 
-/// Download a release asset and unpack it into `staging`.
+/// Download a release asset, verify it against the release's SHA256SUMS, and
+/// unpack it into `staging`. Nothing is written on a checksum mismatch.
 ///
 /// `.tar.gz`/`.tgz` (universal) and `.zip` (Windows) archives are unpacked
 /// **wholesale** into `staging` using each archive crate's own `unpack`/`extract`
@@ -427,11 +606,7 @@ fn should_install(is_newer: bool, force: bool) -> bool {
 /// Returns the path to the freshly unpacked main `zeroclaw` (or `zeroclaw.exe`)
 /// binary, which is the only artifact the caller needs by name — everything
 /// else in the archive is installed later by walking `staging` generically.
-async fn download_release(
-    url: &str,
-    sha256sums_url: Option<&str>,
-    staging: &Path,
-) -> Result<PathBuf> {
+async fn download_release(url: &str, sha256sums_url: &str, staging: &Path) -> Result<PathBuf> {
     let client = reqwest::Client::builder()
         .user_agent(format!("zeroclaw/{}", env!("CARGO_PKG_VERSION")))
         .timeout(std::time::Duration::from_mins(5))
@@ -448,16 +623,7 @@ async fn download_release(
 
     let bytes = resp.bytes().await.context("failed to read download body")?;
 
-    if let Some(sums_url) = sha256sums_url {
-        verify_download_checksum(&bytes, url, sums_url, &client).await?;
-    } else {
-        ::zeroclaw_log::record!(
-            WARN,
-            ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Note)
-                .with_outcome(::zeroclaw_log::EventOutcome::Unknown),
-            "No SHA256SUMS asset found; skipping update download checksum verification"
-        );
-    }
+    verify_download_checksum(&bytes, url, sha256sums_url, &client).await?;
 
     tokio::fs::create_dir_all(staging)
         .await
@@ -497,15 +663,7 @@ async fn make_executable(path: &Path) -> Result<()> {
     Ok(())
 }
 
-async fn verify_download_checksum(
-    bytes: &[u8],
-    asset_url: &str,
-    sha256sums_url: &str,
-    client: &reqwest::Client,
-) -> Result<()> {
-    let asset_name = asset_name_from_url(asset_url)
-        .context("cannot derive release asset filename from download URL")?;
-
+async fn fetch_sha256sums(client: &reqwest::Client, sha256sums_url: &str) -> Result<String> {
     let sums_resp = client
         .get(sha256sums_url)
         .send()
@@ -515,10 +673,33 @@ async fn verify_download_checksum(
         bail!("SHA256SUMS fetch returned {}", sums_resp.status());
     }
 
-    let sums_text = sums_resp
+    sums_resp
         .text()
         .await
-        .context("failed to read SHA256SUMS body")?;
+        .context("failed to read SHA256SUMS body")
+}
+
+async fn fetch_expected_sha256(
+    client: &reqwest::Client,
+    asset_url: &str,
+    sha256sums_url: &str,
+) -> Result<String> {
+    let asset_name = asset_name_from_url(asset_url)
+        .context("cannot derive release asset filename from download URL")?;
+    let sums_text = fetch_sha256sums(client, sha256sums_url).await?;
+    Ok(expected_sha256_for_asset(&sums_text, &asset_name)?.to_ascii_lowercase())
+}
+
+async fn verify_download_checksum(
+    bytes: &[u8],
+    asset_url: &str,
+    sha256sums_url: &str,
+    client: &reqwest::Client,
+) -> Result<()> {
+    let asset_name = asset_name_from_url(asset_url)
+        .context("cannot derive release asset filename from download URL")?;
+
+    let sums_text = fetch_sha256sums(client, sha256sums_url).await?;
     verify_checksum_bytes(bytes, &asset_name, &sums_text)?;
 
     ::zeroclaw_log::record!(
@@ -1374,6 +1555,75 @@ mod tests {
         assert!(version_is_newer("1.0.0", "2.0.0"));
     }
 
+    #[test]
+    fn version_comparison_orders_prereleases() {
+        assert!(version_is_newer("0.7.0", "0.8.0-beta.1"));
+        assert!(version_is_newer("0.8.0-beta.1", "0.8.0-beta.2"));
+        assert!(version_is_newer("0.8.0-beta.9", "0.8.0-beta.10"));
+        assert!(version_is_newer("0.8.0-beta.2", "0.8.0"));
+        assert!(!version_is_newer("0.8.0", "0.8.0-beta.2"));
+        assert!(!version_is_newer("0.8.0", "0.7.9-beta.1"));
+    }
+
+    #[test]
+    fn release_channels_filter_by_tag_and_prerelease_flag() {
+        use UpdateChannel::{Beta, Nightly, Stable};
+
+        assert!(release_matches_channel("v0.8.0", false, Stable));
+        assert!(!release_matches_channel("v0.8.0-beta.1", true, Stable));
+        assert!(!release_matches_channel("v0.8.0-beta.1", false, Stable));
+
+        assert!(release_matches_channel("v0.8.0", false, Beta));
+        assert!(release_matches_channel("v0.8.0-beta.1", true, Beta));
+        assert!(release_matches_channel("v0.8.0-rc.1", true, Beta));
+        assert!(!release_matches_channel(
+            "v0.8.0-nightly.20261016",
+            true,
+            Beta
+        ));
+
+        assert!(release_matches_channel(
+            "v0.8.0-nightly.20261016",
+            true,
+            Nightly
+        ));
+        assert!(release_matches_channel("v0.8.0", false, Nightly));
+    }
+
+    #[test]
+    fn select_release_for_channel_skips_drafts_and_other_tracks() {
+        let releases = vec![
+            serde_json::json!({"tag_name": "v0.9.0-beta.1", "draft": true, "prerelease": true}),
+            serde_json::json!({"tag_name": "v0.8.1-nightly.20261016", "prerelease": true}),
+            serde_json::json!({"tag_name": "v0.8.1-beta.2", "prerelease": true}),
+            serde_json::json!({"tag_name": "v0.8.0", "prerelease": false}),
+        ];
+        let tag = |channel| {
+            select_release_for_channel(&releases, channel)
+                .and_then(|r| r["tag_name"].as_str())
+                .map(str::to_string)
+        };
+
+        assert_eq!(tag(UpdateChannel::Stable).as_deref(), Some("v0.8.0"));
+        assert_eq!(tag(UpdateChannel::Beta).as_deref(), Some("v0.8.1-beta.2"));
+        assert_eq!(
+            tag(UpdateChannel::Nightly).as_deref(),
+            Some("v0.8.1-nightly.20261016")
+        );
+    }
+
+    #[test]
+    fn backup_path_replaces_the_executable_extension() {
+        assert_eq!(
+            backup_path_for(Path::new("/usr/local/bin/zeroclaw")),
+            PathBuf::from("/usr/local/bin/zeroclaw.bak")
+        );
+        assert_eq!(
+            backup_path_for(Path::new("C:/zeroclaw/zeroclaw.exe")),
+            PathBuf::from("C:/zeroclaw/zeroclaw.bak")
+        );
+    }
+
     #[test]
     fn current_target_triple_is_not_empty() {
         let triple = current_target_triple().expect("supported test platform");
@@ -1687,7 +1937,7 @@ mod tests {
         let staging = tmp.path().join("staging");
         let binary = download_release(
             &format!("{}/zeroclaw-test.bin", server.uri()),
-            Some(&format!("{}/SHA256SUMS", server.uri())),
+            &format!("{}/SHA256SUMS", server.uri()),
             &staging,
         )
         .await
@@ -1724,7 +1974,7 @@ mod tests {
         let staging = tmp.path().join("staging");
         let err = download_release(
             &format!("{}/zeroclaw-test.bin", server.uri()),
-            Some(&format!("{}/SHA256SUMS", server.uri())),
+            &format!("{}/SHA256SUMS", server.uri()),
             &staging,
         )
         .await
@@ -1736,7 +1986,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn download_release_preserves_missing_checksum_fallback() {
+    async fn download_release_refuses_unreachable_checksum_file() {
         use wiremock::matchers::{method, path};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -1751,15 +2001,43 @@ mod tests {
 
         let tmp = tempfile::tempdir().unwrap();
         let staging = tmp.path().join("staging");
-        let binary = download_release(
+        let err = download_release(
             &format!("{}/zeroclaw-test.bin", server.uri()),
-            None,
+            &format!("{}/SHA256SUMS", server.uri()),
             &staging,
         )
         .await
-        .unwrap();
+        .unwrap_err()
+        .to_string();
 
-        assert_eq!(std::fs::read(&binary).unwrap(), asset);
+        assert!(err.contains("SHA256SUMS fetch returned"), "{err}");
+        assert!(!staging.join("zeroclaw").exists());
+    }
+
+    #[tokio::test]
+    async fn fetch_expected_sha256_reports_the_published_digest() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let digest = "AB".repeat(32);
+        Mock::given(method("GET"))
+            .and(path("/SHA256SUMS"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+                "{digest}  zeroclaw-x86_64-unknown-linux-gnu.tar.gz\n"
+            )))
+            .mount(&server)
+            .await;
+
+        let client = reqwest::Client::new();
+        let expected = fetch_expected_sha256(
+            &client,
+            "https://example.com/download/zeroclaw-x86_64-unknown-linux-gnu.tar.gz",
+            &format!("{}/SHA256SUMS", server.uri()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(expected, "ab".repeat(32));
     }
 
     #[test]
@@ -3,6 +3,7 @@
 //! traits, property helpers) is pulled through here so `crate::config::*`
 //! continues to resolve for callers that predate the crate split.
 
+pub use zeroclaw_config::lint;
 pub use zeroclaw_config::migration;
 pub use zeroclaw_config::providers;
 pub mod schema;
@@ -99,9 +100,11 @@ mod tests {
             draft_update_interval_ms: 1000,
             interrupt_on_new_message: false,
             mention_only: false,
+            allowed_topics: Vec::new(),
             ack_reactions: None,
             proxy_url: None,
             approval_timeout_secs: 120,
+            translate: None,
             excluded_tools: vec![],
             reply_min_interval_secs: 0,
             reply_queue_depth_max: 0,
@@ -116,6 +119,9 @@ mod tests {
             archive: false,
             listen_to_bots: false,
             interrupt_on_new_message: false,
+            respond_to_edits: false,
+            edit_window_secs: 300,
+            reply_context: false,
             mention_only: false,
             slash_command_scope: schema::SlashCommandScope::default(),
             proxy_url: None,
@@ -127,6 +133,7 @@ mod tests {
             intents_mask: None,
             reaction_notifications: zeroclaw_config::schema::DiscordReactionScope::Off,
             approval_timeout_secs: 300,
+            translate: None,
             excluded_tools: vec![],
             reply_min_interval_secs: 0,
             reply_queue_depth_max: 0,
@@ -149,6 +156,7 @@ mod tests {
             ack_reactions: None,
             stream_mode: StreamMode::default(),
             draft_update_interval_ms: 1000,
+            rich_text: Default::default(),
         };
         let nextcloud_talk = NextcloudTalkConfig {
             enabled: true,
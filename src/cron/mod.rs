@@ -39,6 +39,76 @@ fn parse_explicit_rfc3339_utc(raw: &str) -> Result<chrono::DateTime<chrono::Utc>
         })
 }
 
+/// Build the `notify` target from `cron add --notify-*` flags.
+fn notify_from_flags(
+    channel: Option<String>,
+    recipient: Option<String>,
+    on: Option<&str>,
+) -> Result<Option<NotifyConfig>> {
+    let (Some(channel), Some(recipient)) = (channel, recipient) else {
+        return Ok(None);
+    };
+    let on = on
+        .map(NotifyOn::try_from)
+        .transpose()
+        .map_err(anyhow::Error::msg)?
+        .unwrap_or_default();
+    let notify = NotifyConfig {
+        channel,
+        recipient,
+        on,
+    };
+    validate_notify_config(Some(&notify))?;
+    Ok(Some(notify))
+}
+
+fn catch_up_from_flag(catch_up: Option<&str>) -> Result<Option<CatchUpPolicy>> {
+    catch_up
+        .map(CatchUpPolicy::try_from)
+        .transpose()
+        .map_err(anyhow::Error::msg)
+}
+
+fn concurrency_from_flag(concurrency: Option<&str>) -> Result<Option<ConcurrencyPolicy>> {
+    concurrency
+        .map(ConcurrencyPolicy::try_from)
+        .transpose()
+        .map_err(anyhow::Error::msg)
+}
+
+/// Print the `notify`, `catch_up` and `concurrency` settings a job was
+/// created with.
+fn print_job_options(options: &NewJobOptions) {
+    if let Some(notify) = &options.notify {
+        println!(
+            "{}",
+            get_required_cli_string_with_args(
+                "cli-cron-notify",
+                &[
+                    ("channel", &notify.channel),
+                    ("to", &notify.recipient),
+                    ("on", notify.on.as_str()),
+                ]
+            )
+        );
+    }
+    if let Some(catch_up) = options.catch_up {
+        println!(
+            "{}",
+            get_required_cli_string_with_args("cli-cron-catch-up", &[("v", catch_up.as_str())])
+        );
+    }
+    if let Some(concurrency) = options.concurrency {
+        println!(
+            "{}",
+            get_required_cli_string_with_args(
+                "cli-cron-concurrency",
+                &[("v", concurrency.as_str())]
+            )
+        );
+    }
+}
+
 pub fn handle_command(command: crate::CronCommands, config: &Config) -> Result<()> {
     match command {
         crate::CronCommands::List => {
@@ -57,6 +127,8 @@ pub fn handle_command(command: crate::CronCommands, config: &Config) -> Result<(
                     &[("count", &jobs.len().to_string())]
                 )
             );
+            let default_catch_up =
+                CatchUpPolicy::from_startup_default(config.scheduler.catch_up_on_startup);
             for job in jobs {
                 let last_run = job
                     .last_run
@@ -88,25 +160,90 @@ pub fn handle_command(command: crate::CronCommands, config: &Config) -> Result<(
                         )
                     );
                 }
+                if let Some(notify) = &job.notify {
+                    println!(
+                        "{}",
+                        get_required_cli_string_with_args(
+                            "cli-cron-list-notify",
+                            &[
+                                ("channel", &notify.channel),
+                                ("to", &notify.recipient),
+                                ("on", notify.on.as_str()),
+                            ]
+                        )
+                    );
+                }
+                let catch_up = job.catch_up.map_or_else(
+                    || {
+                        get_required_cli_string_with_args(
+                            "cli-cron-list-catch-up-default",
+                            &[("policy", default_catch_up.as_str())],
+                        )
+                    },
+                    |policy| {
+                        get_required_cli_string_with_args(
+                            "cli-cron-list-catch-up",
+                            &[("policy", policy.as_str())],
+                        )
+                    },
+                );
+                println!("{catch_up}");
+                let concurrency_key = if job.running {
+                    "cli-cron-list-concurrency-running"
+                } else {
+                    "cli-cron-list-concurrency"
+                };
+                println!(
+                    "{}",
+                    get_required_cli_string_with_args(
+                        concurrency_key,
+                        &[("policy", job.concurrency.as_str())]
+                    )
+                );
             }
             Ok(())
         }
         crate::CronCommands::Add {
             expression,
+            natural,
             agent_alias,
             tz,
             prompt,
             allowed_tools,
             uses_memory,
+            notify_channel,
+            notify_to,
+            notify_on,
+            catch_up,
+            concurrency,
             command,
         } => {
             require_configured_agent(config, &agent_alias)?;
-            let schedule = Schedule::Cron {
-                expr: expression,
-                tz,
+            let options = NewJobOptions {
+                notify: notify_from_flags(notify_channel, notify_to, notify_on.as_deref())?,
+                catch_up: catch_up_from_flag(catch_up.as_deref())?,
+                concurrency: concurrency_from_flag(concurrency.as_deref())?,
             };
+            let schedule = if natural {
+                let parsed =
+                    parse_natural_schedule(&expression, tz.as_deref(), chrono::Utc::now())?;
+                println!(
+                    "{}",
+                    get_required_cli_string_with_args(
+                        "cli-cron-natural",
+                        &[("summary", &parsed.summary), ("spec", &parsed.spec())]
+                    )
+                );
+                parsed.schedule
+            } else {
+                Schedule::Cron {
+                    expr: expression,
+                    tz,
+                }
+            };
+            let one_shot = matches!(schedule, Schedule::At { .. });
             if prompt {
-                let job = add_agent_job(
+                let job = add_agent_job_with_options(
                     config,
                     &agent_alias,
                     None,
@@ -115,13 +252,14 @@ pub fn handle_command(command: crate::CronCommands, config: &Config) -> Result<(
                     SessionTarget::Isolated,
                     None,
                     None,
-                    false,
+                    one_shot,
                     if allowed_tools.is_empty() {
                         None
                     } else {
                         Some(allowed_tools)
                     },
                     uses_memory.unwrap_or(true),
+                    options.clone(),
                 )?;
                 println!(
                     "{}",
@@ -145,11 +283,19 @@ pub fn handle_command(command: crate::CronCommands, config: &Config) -> Result<(
                         &[("v", job.prompt.as_deref().unwrap_or_default())]
                     )
                 );
+                print_job_options(&options);
             } else {
                 if !allowed_tools.is_empty() {
                     bail!("--allowed-tool is only supported with --prompt cron jobs");
                 }
-                let job = add_shell_job(config, &agent_alias, None, schedule, &command)?;
+                let job = add_shell_job_with_options(
+                    config,
+                    &agent_alias,
+                    None,
+                    schedule,
+                    &command,
+                    options.clone(),
+                )?;
                 println!(
                     "{}",
                     get_required_cli_string_with_args("cli-cron-added", &[("id", &job.id)])
@@ -169,6 +315,7 @@ pub fn handle_command(command: crate::CronCommands, config: &Config) -> Result<(
                     "{}",
                     get_required_cli_string_with_args("cli-cron-cmd", &[("v", &job.command)])
                 );
+                print_job_options(&options);
             }
             Ok(())
         }
@@ -417,14 +564,20 @@ pub fn handle_command(command: crate::CronCommands, config: &Config) -> Result<(
             name,
             allowed_tools,
             uses_memory,
+            catch_up,
+            concurrency,
         } => {
             require_configured_agent(config, &agent_alias)?;
+            let catch_up = catch_up_from_flag(catch_up.as_deref())?;
+            let concurrency = concurrency_from_flag(concurrency.as_deref())?;
             if expression.is_none()
                 && tz.is_none()
                 && command.is_none()
                 && name.is_none()
                 && allowed_tools.is_empty()
                 && uses_memory.is_none()
+                && catch_up.is_none()
+                && concurrency.is_none()
             {
                 bail!("{}", get_required_cli_string("cli-cron-update-no-field"));
             }
@@ -476,6 +629,8 @@ pub fn handle_command(command: crate::CronCommands, config: &Config) -> Result<(
                     Some(allowed_tools)
                 },
                 uses_memory,
+                catch_up,
+                concurrency,
                 ..CronJobPatch::default()
             };
 
@@ -8,10 +8,10 @@ use anyhow::Result;
 use zeroclaw_runtime::i18n::get_required_cli_string;
 
 #[allow(dead_code)]
-pub fn handle_command(cmd: crate::HardwareCommands, _config: &Config) -> Result<()> {
+pub async fn handle_command(cmd: crate::HardwareCommands, config: &Config) -> Result<()> {
     #[cfg(not(feature = "hardware"))]
     {
-        let _ = &cmd;
+        let _ = (&cmd, config);
         println!(
             "{}",
             get_required_cli_string("cli-hardware-feature-required")
@@ -25,7 +25,7 @@ pub fn handle_command(cmd: crate::HardwareCommands, _config: &Config) -> Result<
         not(any(target_os = "linux", target_os = "macos", target_os = "windows"))
     ))]
     {
-        let _ = &cmd;
+        let _ = (&cmd, config);
         println!(
             "{}",
             get_required_cli_string("cli-hardware-unsupported-platform")
@@ -43,6 +43,7 @@ pub fn handle_command(cmd: crate::HardwareCommands, _config: &Config) -> Result<
     ))]
     match cmd {
         crate::HardwareCommands::Discover => run_discover(),
+        crate::HardwareCommands::Scan => run_scan(&config.data_dir.join("state")).await,
         crate::HardwareCommands::Introspect { path } => run_introspect(&path),
         crate::HardwareCommands::Info { chip } => run_info(&chip),
     }
@@ -5,8 +5,15 @@ use crate::config::Config;
 use anyhow::Result;
 
 #[allow(dead_code)]
-pub fn handle_command(command: crate::IntegrationCommands, config: &Config) -> Result<()> {
+pub async fn handle_command(
+    command: crate::IntegrationCommands,
+    config: &mut Config,
+) -> Result<()> {
     match command {
+        crate::IntegrationCommands::List { configured } => list_integrations(config, configured),
         crate::IntegrationCommands::Info { name } => show_integration_info(config, &name),
+        crate::IntegrationCommands::Setup { name, dry_run } => {
+            setup::run(config, &name, dry_run).await
+        }
     }
 }
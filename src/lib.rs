@@ -92,6 +92,8 @@ pub(crate) mod skills;
 #[cfg(feature = "agent-runtime")]
 pub mod sop;
 #[cfg(feature = "agent-runtime")]
+pub mod tasks;
+#[cfg(feature = "agent-runtime")]
 pub mod tools;
 #[cfg(feature = "agent-runtime")]
 pub(crate) mod trust;
@@ -246,7 +248,28 @@ pub enum ChannelCommands {
     /// Start all configured channels (handled in main.rs for async)
     Start,
     /// Run health checks for configured channels (handled in main.rs for async)
-    Doctor,
+    // i18n-exempt: clap derive help — framework requires a compile-time literal
+    #[command(long_about = "\
+Run health checks for configured channels.
+
+Exit codes: 0 when every probed channel is healthy, 2 when any is \
+unhealthy, 3 when any timed out (and none is unhealthy).
+
+Examples:
+  zeroclaw channel doctor
+  zeroclaw channel doctor --json
+  zeroclaw channel doctor --channel telegram.alerts --timeout 30")]
+    Doctor {
+        /// Print a JSON array of {channel, state, latency_ms, hint} entries
+        #[arg(long)]
+        json: bool,
+        /// Probe only this channel (e.g. `telegram` or `telegram.alerts`)
+        #[arg(long)]
+        channel: Option<String>,
+        /// Per-channel health-check timeout in seconds
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+    },
     /// Add a new channel configuration
     // i18n-exempt: clap derive help — framework requires a compile-time literal
     #[command(long_about = "\
@@ -297,6 +320,40 @@ Examples:
         #[arg(long, default_value = "default")]
         alias: String,
     },
+    /// Link a QR-paired channel (WhatsApp Web) to an account
+    // i18n-exempt: clap derive help — framework requires a compile-time literal
+    #[command(long_about = "\
+Link a QR-paired channel to an account without starting it.
+
+Starts a WhatsApp Web session for channels.whatsapp.<alias>, shows \
+the pairing QR code, and waits until it is scanned from WhatsApp > \
+Linked Devices. The session is saved to the alias's session_path \
+with owner-only permissions, so the next channel start resumes it.
+
+If a session is already stored it is checked first; a session \
+WhatsApp no longer accepts is cleared and a new QR code is shown. \
+Stop any running daemon using the same session before pairing.
+
+Examples:
+  zeroclaw channel pair whatsapp
+  zeroclaw channel pair whatsapp --alias ops --png /tmp/whatsapp-qr.png
+  zeroclaw channel pair whatsapp --force")]
+    Pair {
+        /// Channel type to pair (whatsapp)
+        channel: String,
+        /// Channel alias (the `<alias>` in `channels.whatsapp.<alias>`)
+        #[arg(long, default_value = "default")]
+        alias: String,
+        /// Write the QR code to this PNG file instead of the terminal
+        #[arg(long)]
+        png: Option<std::path::PathBuf>,
+        /// Discard the stored session and link a new device
+        #[arg(long)]
+        force: bool,
+        /// Seconds to wait for the QR code to be scanned
+        #[arg(long, default_value_t = 180)]
+        timeout: u64,
+    },
     /// Send a message to a configured channel
     // i18n-exempt: clap derive help — framework requires a compile-time literal
     #[command(long_about = "\
@@ -323,6 +380,49 @@ Examples:
         #[arg(long)]
         recipient: String,
     },
+    /// Inspect replies waiting for redelivery
+    // i18n-exempt: clap derive help — framework requires a compile-time literal
+    #[command(long_about = "\
+Inspect and manage replies waiting for redelivery.
+
+A reply a channel fails to deliver is queued in the outbox \
+([channels.outbox]) and retried by the running daemon with exponential \
+backoff. Replies still undelivered after max_age_secs are dead-lettered \
+and stay listed until retried or dropped.
+
+Examples:
+  zeroclaw channel outbox list
+  zeroclaw channel outbox list --dead
+  zeroclaw channel outbox retry 12
+  zeroclaw channel outbox drop 12")]
+    Outbox {
+        #[command(subcommand)]
+        outbox_command: ChannelOutboxCommands,
+    },
+}
+
+/// Channel outbox subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ChannelOutboxCommands {
+    /// List undelivered replies, oldest first
+    List {
+        /// Show only dead-lettered replies
+        #[arg(long)]
+        dead: bool,
+        /// Maximum number of entries to show
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+    },
+    /// Requeue a pending or dead-lettered reply for immediate delivery
+    Retry {
+        /// Outbox entry id (from `outbox list`)
+        id: i64,
+    },
+    /// Delete an undelivered reply
+    Drop {
+        /// Outbox entry id (from `outbox list`)
+        id: i64,
+    },
 }
 
 /// Alias CRUD for agents (`[agents.<alias>]`). Distinct from the `agent`
@@ -582,7 +682,8 @@ pub enum SkillBundleCommands {
 /// Migration subcommands
 #[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum MigrateCommands {
-    /// Import memory from an `OpenClaw` workspace into this `ZeroClaw` workspace
+    /// Import memory, channel/provider config, and identity files from an
+    /// `OpenClaw` install into this `ZeroClaw` install
     Openclaw {
         /// Optional path to `OpenClaw` workspace (defaults to ~/.openclaw/workspace)
         #[arg(long)]
@@ -595,6 +696,40 @@ pub enum MigrateCommands {
         /// Rebuild backend indexes after importing entries
         #[arg(long)]
         reindex: bool,
+
+        /// Replace config values and workspace files that are already set
+        #[arg(long)]
+        overwrite: bool,
+    },
+}
+
+/// Backup subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BackupCommands {
+    /// Archive config, memory, skills, and transcripts into a `.tar.zst`
+    Create {
+        /// Archive path (defaults to `<install>/backups/zeroclaw-backup-<timestamp>.tar.zst`)
+        #[arg(long, short)]
+        output: Option<std::path::PathBuf>,
+
+        /// Also archive `.secret_key` and auth profiles, encrypted with a
+        /// passphrase (read from `ZEROCLAW_BACKUP_PASSPHRASE` or prompted)
+        #[arg(long)]
+        include_secrets: bool,
+    },
+    /// Validate an archive and restore it over this install, then run any
+    /// pending config and memory migrations
+    Restore {
+        /// Path to a `.tar.zst` written by `zeroclaw backup create`
+        archive: std::path::PathBuf,
+
+        /// Validate the archive and list what would be written, without writing
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Overwrite existing files without asking
+        #[arg(long)]
+        yes: bool,
     },
 }
 
@@ -612,13 +747,25 @@ Uses standard 5-field cron syntax: 'min hour day month weekday'. \
 When --tz is omitted, cron schedules use the runtime local timezone. \
 For user-facing schedules, pass --tz with an explicit IANA timezone.
 
+With --natural the schedule is plain English instead ('every weekday at 9am', \
+'every 2 hours', 'first monday of the month at 10:00', 'tomorrow at 17:30'). \
+The derived cron expression or run time is printed for confirmation, and \
+ambiguous phrases are rejected with suggestions.
+
 Examples:
   zeroclaw cron add '0 9 * * 1-5' 'Good morning' --tz America/New_York --agent
+  zeroclaw cron add --natural 'every weekday at 9am' 'Good morning' --tz America/New_York --prompt -a ops
   zeroclaw cron add '*/30 * * * *' 'Check system health' --agent
-  zeroclaw cron add '*/5 * * * *' 'echo ok'")]
+  zeroclaw cron add '*/5 * * * *' 'echo ok'
+  zeroclaw cron add '0 3 * * *' 'backup.sh' --notify-channel telegram --notify-to 12345 --notify-on failure
+  zeroclaw cron add '0 8 * * *' 'Write the daily report' --prompt -a ops --catch-up once
+  zeroclaw cron add '*/5 * * * *' 'sync.sh' --concurrency queue")]
     Add {
-        /// Cron expression
+        /// Cron expression (or plain-English schedule with --natural)
         expression: String,
+        /// Parse the schedule as natural language, e.g. 'every weekday at 9am'.
+        #[arg(long)]
+        natural: bool,
         /// Configured agent alias the cron job runs as. Required —
         /// there is no default agent.
         #[arg(short = 'a', long = "agent")]
@@ -636,6 +783,23 @@ Examples:
         /// Set to false for stateless digest/report jobs that should not accumulate or consume memory.
         #[arg(long)]
         uses_memory: Option<bool>,
+        /// Channel to send run output to (e.g. `telegram` or `telegram.work`).
+        #[arg(long, requires = "notify_to")]
+        notify_channel: Option<String>,
+        /// Recipient on the notify channel (chat id, user, address).
+        #[arg(long, requires = "notify_channel")]
+        notify_to: Option<String>,
+        /// When to notify: `always` (default) or `failure`.
+        #[arg(long, requires = "notify_channel")]
+        notify_on: Option<String>,
+        /// Missed-run policy after downtime: `none`, `once`, or `all`.
+        /// Defaults to `[scheduler] catch_up_on_startup`.
+        #[arg(long)]
+        catch_up: Option<String>,
+        /// What to do when a run comes due while the previous one is still
+        /// going: `skip` (default), `queue`, or `allow`.
+        #[arg(long)]
+        concurrency: Option<String>,
         /// Command (shell) or prompt (when --prompt) to run
         command: String,
     },
@@ -766,6 +930,12 @@ Examples:
         /// If false, disable memory recall for this agent cron job (default: true).
         #[arg(long)]
         uses_memory: Option<bool>,
+        /// New missed-run policy: `none`, `once`, or `all`.
+        #[arg(long)]
+        catch_up: Option<String>,
+        /// New overlap policy: `skip`, `queue`, or `allow`.
+        #[arg(long)]
+        concurrency: Option<String>,
     },
     /// Pause a scheduled task
     Pause {
@@ -779,6 +949,57 @@ Examples:
     },
 }
 
+/// Background task subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TasksCommands {
+    /// List recent background tasks
+    List {
+        /// Only show tasks run by this agent
+        #[arg(long)]
+        agent: Option<String>,
+        /// Maximum number of tasks to display
+        #[arg(long, default_value = "20")]
+        limit: usize,
+    },
+    /// Show one background task, including its output
+    Show {
+        /// Task ID
+        id: String,
+    },
+    /// Cancel a queued or running background task
+    Cancel {
+        /// Task ID
+        id: String,
+    },
+}
+
+/// Approval queue subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ApprovalsCommands {
+    /// List tool calls waiting for approval
+    List {
+        /// Also show recent decisions, not just pending requests
+        #[arg(long)]
+        all: bool,
+        /// Maximum number of requests to display
+        #[arg(long, default_value = "20")]
+        limit: usize,
+    },
+    /// Approve a queued tool call (talks to the running daemon)
+    Approve {
+        /// Approval request ID
+        id: String,
+    },
+    /// Deny a queued tool call (talks to the running daemon)
+    Deny {
+        /// Approval request ID
+        id: String,
+        /// Reason passed back to the agent and recorded with the decision
+        #[arg(long)]
+        reason: Option<String>,
+    },
+}
+
 /// Memory management subcommands
 #[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum MemoryCommands {
@@ -790,6 +1011,9 @@ pub enum MemoryCommands {
         /// Filter by session ID
         #[arg(long)]
         session: Option<String>,
+        /// Filter by namespace (e.g. default, shared, channel:discord:alice)
+        #[arg(long)]
+        namespace: Option<String>,
         /// Maximum number of entries to display
         #[arg(long, default_value = "50")]
         limit: usize,
@@ -823,16 +1047,51 @@ pub enum MemoryCommands {
     /// entries whose vector is missing. No-op for backends without a
     /// vector index.
     Reindex,
+    /// Compact the memory database and refresh its query statistics.
+    ///
+    /// Reclaims space left by deleted and superseded entries and
+    /// re-optimizes the full-text index. Safe to run while the daemon is
+    /// up, though writes wait until it finishes. No-op for backends
+    /// without a local database file.
+    Vacuum,
 }
 
 /// Integration subcommands
 #[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum IntegrationCommands {
+    /// List integrations, marking the ones that are set up
+    List {
+        /// Only show integrations that are set up
+        #[arg(long)]
+        configured: bool,
+    },
     /// Show details about a specific integration
     Info {
         /// Integration name
         name: String,
     },
+    /// Collect credentials, write config, and verify an integration
+    // i18n-exempt: clap derive help — framework requires a compile-time literal
+    #[command(long_about = "\
+Collect credentials, write config, and verify an integration.
+
+Reads the credential from config, then its environment variable \
+(NOTION_API_KEY, COMPOSIO_API_KEY), then prompts for it. Writes the \
+config sections that register the integration's tool, prints every \
+change, and makes one verification call. GitHub and Google Calendar \
+connect through Composio; Notion uses its API directly.
+
+Examples:
+  zeroclaw integrations setup notion
+  zeroclaw integrations setup github --dry-run
+  zeroclaw integrations setup google-calendar")]
+    Setup {
+        /// Integration name (github, notion, google-calendar)
+        name: String,
+        /// Show the planned config edits without writing or verifying
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 /// Hardware discovery subcommands
@@ -849,6 +1108,18 @@ known development boards (STM32 Nucleo, Arduino, ESP32).
 Examples:
   zeroclaw hardware discover")]
     Discover,
+    /// Detect boards on serial ports by VID/PID and probe their firmware
+    // i18n-exempt: clap derive help — framework requires a compile-time literal
+    #[command(long_about = "\
+Detect boards on serial ports by USB VID/PID and probe their firmware.
+
+Matches each serial port against known board profiles (ST-Link, Arduino, \
+Espressif CP210x/CH340), sends the JSON `capabilities` command, and prints \
+the detection table. Results are cached for `zeroclaw peripheral list`.
+
+Examples:
+  zeroclaw hardware scan")]
+    Scan,
     /// Introspect a device by path (e.g. /dev/ttyACM0)
     // i18n-exempt: clap derive help — framework requires a compile-time literal
     #[command(long_about = "\
@@ -381,6 +381,8 @@ mod skills;
 #[cfg(feature = "agent-runtime")]
 mod sop;
 #[cfg(feature = "agent-runtime")]
+mod tasks;
+#[cfg(feature = "agent-runtime")]
 mod tools;
 #[cfg(feature = "agent-runtime")]
 mod trust;
@@ -395,9 +397,10 @@ use config::Config;
 
 // Re-export so binary modules can use crate::<CommandEnum> while keeping a single source of truth.
 pub use zeroclaw::{
-    AgentsCommands, ChannelCommands, ChannelsCommands, CronCommands, GatewayCommands,
-    HardwareCommands, IntegrationCommands, MigrateCommands, PeripheralCommands, ProvidersCommands,
-    ServiceCommands, SkillBundleCommands, SkillCommands, SopCommands, SopGraphFormat,
+    AgentsCommands, ApprovalsCommands, BackupCommands, ChannelCommands, ChannelOutboxCommands,
+    ChannelsCommands, CronCommands, GatewayCommands, HardwareCommands, IntegrationCommands,
+    MigrateCommands, PeripheralCommands, ProvidersCommands, ServiceCommands, SkillBundleCommands,
+    SkillCommands, SopCommands, SopGraphFormat, TasksCommands,
 };
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
@@ -504,9 +507,11 @@ enum Commands {
     /// Quickstart — create one working agent end-to-end. Replaces the
     /// section-by-section onboarding flow with a single preset-driven
     /// path. Interactive: the flags below pre-seed checklist selectors
-    /// but do not skip them; a terminal is required.
+    /// but do not skip them; a terminal is required. `--from-file`
+    /// applies a provisioning file instead, without prompts.
     Quickstart {
-        /// Provider type (anthropic / openai / openrouter / ollama).
+        /// Provider type (anthropic / openai / openrouter / ollama), or
+        /// `auto` to use the best credential found in the environment.
         #[arg(long)]
         model_provider: Option<String>,
 
@@ -521,6 +526,25 @@ enum Commands {
         /// Alias for the new agent. Defaults to a sanitized provider name.
         #[arg(long)]
         agent: Option<String>,
+
+        /// Use a provider key found in the environment or a vendor CLI file
+        /// without asking. Without this flag a discovered key is only
+        /// offered at the API key prompt.
+        #[arg(long)]
+        use_discovered_key: bool,
+
+        /// Apply a provisioning file (a Quickstart submission in TOML)
+        /// without prompts. `${VAR}` references are read from the environment.
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with_all = ["model_provider", "model", "api_key", "agent", "use_discovered_key"]
+        )]
+        from_file: Option<std::path::PathBuf>,
+
+        /// With `--from-file`: validate and print the changes without writing.
+        #[arg(long, requires = "from_file")]
+        dry_run: bool,
     },
 
     /// Deprecated. Use `zeroclaw quickstart`. Any flags error.
@@ -591,6 +615,8 @@ Use --message for single-shot queries without entering interactive mode.
 Examples:
   zeroclaw agent -a assistant                                          # interactive session
   zeroclaw agent -a assistant -m \"Summarize today's logs\"              # single message
+  zeroclaw agent -a assistant --resume                                 # continue the last session
+  zeroclaw agent -a assistant --list-sessions                          # list saved sessions
   zeroclaw agent -a assistant -p anthropic --model claude-sonnet-4-20250514
   zeroclaw agent -a assistant --peripheral nucleo-f401re:/dev/ttyACM0")]
     Agent {
@@ -607,6 +633,21 @@ Examples:
         #[arg(long)]
         session_state_file: Option<PathBuf>,
 
+        /// Resume a checkpointed interactive session by id, or the most
+        /// recent one when no id (or `last`) is given
+        #[arg(
+            long,
+            value_name = "ID",
+            num_args = 0..=1,
+            default_missing_value = "last",
+            conflicts_with_all = ["message", "session_state_file"]
+        )]
+        resume: Option<String>,
+
+        /// List checkpointed interactive sessions for this agent and exit
+        #[arg(long, conflicts_with_all = ["message", "resume"])]
+        list_sessions: bool,
+
         /// Model provider to use (openrouter, anthropic, openai, openai-codex)
         #[arg(short = 'p', long = "model-provider", alias = "provider")]
         model_provider: Option<String>,
@@ -619,7 +660,7 @@ Examples:
         #[arg(short, long, value_parser = parse_temperature)]
         temperature: Option<f64>,
 
-        /// Attach a peripheral (board:path, e.g. nucleo-f401re:/dev/ttyACM0)
+        /// Attach a peripheral (board:path, e.g. nucleo-f401re:/dev/ttyACM0, or `auto` to detect)
         #[arg(long)]
         peripheral: Vec<String>,
     },
@@ -715,6 +756,10 @@ Examples:
 
     /// Run diagnostics for daemon/scheduler/channel freshness
     Doctor {
+        /// Print results and per-component daemon health as JSON
+        #[arg(long)]
+        json: bool,
+
         #[command(subcommand)]
         doctor_command: Option<DoctorCommands>,
     },
@@ -726,6 +771,23 @@ Examples:
         format: Option<String>,
     },
 
+    /// Daily activity digest
+    // i18n-exempt: clap derive help — framework requires a compile-time literal
+    #[cfg(feature = "agent-runtime")]
+    #[command(long_about = "\
+Daily activity digest.
+
+With [observability.digest] enabled, the daemon counts channel messages, \
+tool calls, errors, and tokens per day and sends a summary of the previous \
+day to the configured channel on the digest's cron schedule.
+
+Examples:
+  zeroclaw digest preview")]
+    Digest {
+        #[command(subcommand)]
+        digest_command: DigestCommands,
+    },
+
     /// Inspect the active security posture derived from local config and host detection
     #[cfg(feature = "agent-runtime")]
     Security {
@@ -779,6 +841,46 @@ Examples:
         cron_command: CronCommands,
     },
 
+    /// Inspect and cancel background agent tasks
+    // i18n-exempt: clap derive help — framework requires a compile-time literal
+    #[command(long_about = "\
+Inspect and cancel background agent tasks.
+
+Agents start background tasks with the task_spawn tool for work that \
+would outlast a channel reply. The daemon runs them, capped by \
+[tasks] max_concurrent, and posts progress back to the originating chat.
+
+Examples:
+  zeroclaw tasks list
+  zeroclaw tasks list --agent default --limit 5
+  zeroclaw tasks show TASK_ID
+  zeroclaw tasks cancel TASK_ID")]
+    Tasks {
+        #[command(subcommand)]
+        tasks_command: TasksCommands,
+    },
+
+    /// Review tool calls waiting in the approval queue
+    // i18n-exempt: clap derive help — framework requires a compile-time literal
+    #[command(long_about = "\
+Review tool calls waiting in the approval queue.
+
+With [approvals] enabled = true, a tool call that needs approval on a \
+channel with no inline approval waits in the daemon's queue instead of \
+being denied. Approve or deny it here; the decision reaches the daemon \
+through the gateway admin API and the call resumes immediately. \
+Unanswered requests are denied after [approvals] timeout_secs.
+
+Examples:
+  zeroclaw approvals list
+  zeroclaw approvals list --all
+  zeroclaw approvals approve APPROVAL_ID
+  zeroclaw approvals deny APPROVAL_ID --reason 'not during the freeze'")]
+    Approvals {
+        #[command(subcommand)]
+        approvals_command: ApprovalsCommands,
+    },
+
     /// Manage model_provider model catalogs
     Models {
         #[command(subcommand)]
@@ -866,6 +968,31 @@ Examples:
         migrate_command: MigrateCommands,
     },
 
+    /// Back up or restore this install (config, memory, skills, transcripts)
+    // i18n-exempt: clap derive help — framework requires a compile-time literal
+    #[command(long_about = "\
+Back up or restore this ZeroClaw install.
+
+`create` writes config, memory databases, skills, transcripts, and \
+other install state to a single .tar.zst with a manifest of versions \
+and SHA-256 file hashes. Secrets (.secret_key, auth profiles) are left \
+out unless --include-secrets is given, in which case they are encrypted \
+with a passphrase from ZEROCLAW_BACKUP_PASSPHRASE or a prompt.
+
+`restore` validates the manifest, lists every file it would create or \
+overwrite, restores secret files with 0600 permissions, and then runs \
+any pending config and memory migrations. Stop the daemon first.
+
+Examples:
+  zeroclaw backup create
+  zeroclaw backup create --output /mnt/usb/zeroclaw.tar.zst --include-secrets
+  zeroclaw backup restore /mnt/usb/zeroclaw.tar.zst --dry-run
+  zeroclaw backup restore /mnt/usb/zeroclaw.tar.zst")]
+    Backup {
+        #[command(subcommand)]
+        backup_command: BackupCommands,
+    },
+
     /// Manage model_provider subscription authentication profiles
     Auth {
         #[command(subcommand)]
@@ -973,16 +1100,25 @@ and smoke test. Automatic rollback on failure.
 Use --check to only check for updates without installing.
 Use --force to skip the confirmation prompt.
 Use --version to target a specific release instead of latest.
+Use --rollback to restore the binary kept by the last update.
+
+Downloads are verified against the release's SHA256SUMS before \
+anything is replaced. The release track comes from \
+`[updater] channel` (stable, beta, or nightly).
 
 Examples:
   zeroclaw update                      # download and install latest
   zeroclaw update --check              # check only, don't install
   zeroclaw update --force              # install without confirmation
-  zeroclaw update --version 0.6.0      # install specific version")]
+  zeroclaw update --version 0.6.0      # install specific version
+  zeroclaw update --rollback           # restore the previous binary")]
     Update {
         /// Only check for updates, don't install
         #[arg(long)]
         check: bool,
+        /// Restore the previous binary kept as `zeroclaw.bak` by the last update
+        #[arg(long, conflicts_with_all = ["check", "force", "version"])]
+        rollback: bool,
         /// Install even if the target is not newer (reinstall or downgrade/pin to --version)
         #[arg(long)]
         force: bool,
@@ -1235,6 +1371,7 @@ async fn run_quickstart_cli(
     model: Option<String>,
     api_key: Option<String>,
     agent: Option<String>,
+    use_discovered_key: bool,
 ) -> anyhow::Result<()> {
     use dialoguer::{Confirm, Editor, FuzzySelect, Input, Password};
     use zeroclaw_config::presets::{
@@ -1277,6 +1414,8 @@ async fn run_quickstart_cli(
         // user has actually opened the selector and left it. Until
         // then the row stays `[ ]` rather than a pre-checked default.
         peer_groups_visited: bool,
+        // "Expose gateway publicly" from the Channels sub-flow.
+        public_tunnel: Option<zeroclaw_config::presets::PublicTunnelChoice>,
         agent: Option<AgentChoice>,
     }
     enum ProviderChoice {
@@ -1361,6 +1500,121 @@ async fn run_quickstart_cli(
 
     let mut form = Form::default();
 
+    // Keys the operator already exported or stored via a vendor CLI. Only
+    // registered families are offered; nothing is persisted until Create.
+    let discovered: Vec<zeroclaw_providers::discovery::DiscoveredCredential> =
+        zeroclaw_providers::discovery::discover_credentials()
+            .into_iter()
+            .filter(|cred| providers.iter().any(|p| p.kind == cred.family))
+            .collect();
+    let display_name_of = |family: &str| -> String {
+        providers
+            .iter()
+            .find(|p| p.kind == family)
+            .map_or_else(|| family.to_string(), |p| p.display_name.clone())
+    };
+    if !discovered.is_empty() {
+        println!();
+        println!(
+            "{}",
+            t(
+                "cli-quickstart-discovered-header",
+                "Found existing provider credentials:"
+            )
+        );
+        for cred in &discovered {
+            println!(
+                "{}",
+                qta(
+                    "cli-quickstart-discovered-row",
+                    &[
+                        ("name", &display_name_of(cred.family)),
+                        ("source", &cred.source.to_string()),
+                        ("key", &cred.redacted_key()),
+                    ],
+                )
+            );
+        }
+    }
+
+    // `--model-provider auto` picks the provider of the best discovered
+    // credential instead of prompting for one.
+    let auto_provider = model_provider
+        .as_deref()
+        .is_some_and(|mp| mp.trim().eq_ignore_ascii_case("auto"));
+    let model_provider = if auto_provider {
+        match zeroclaw_providers::discovery::best_credential(&discovered) {
+            Some(cred) => {
+                println!(
+                    "{}",
+                    qta(
+                        "cli-quickstart-auto-selected",
+                        &[
+                            ("name", &display_name_of(cred.family)),
+                            ("source", &cred.source.to_string()),
+                        ],
+                    )
+                );
+                Some(cred.family.to_string())
+            }
+            None => {
+                eprintln!(
+                    "{}",
+                    t(
+                        "cli-quickstart-auto-none",
+                        "No existing provider credentials found; pick a provider manually."
+                    )
+                );
+                None
+            }
+        }
+    } else {
+        model_provider
+    };
+    // A pre-filled key skips the provider prompts, so a discovered one is
+    // only taken on `--use-discovered-key`; otherwise the API key prompt
+    // offers it.
+    let discovered_key = model_provider
+        .as_deref()
+        .and_then(zeroclaw_runtime::quickstart::resolve_model_provider_type)
+        .filter(|(_, codex_auth)| !codex_auth)
+        .and_then(|(family, _)| zeroclaw_providers::discovery::credential_for(&discovered, family));
+    let api_key = match (api_key, discovered_key) {
+        (None, Some(cred)) if use_discovered_key => {
+            ::zeroclaw_log::record!(
+                INFO,
+                ::zeroclaw_log::Event::new(module_path!(), ::zeroclaw_log::Action::Load)
+                    .with_attrs(::serde_json::json!({
+                        "model_provider": cred.family,
+                        "source": cred.source.to_string(),
+                    })),
+                "quickstart using a discovered provider key"
+            );
+            println!(
+                "{}",
+                qta(
+                    "cli-quickstart-using-discovered-key",
+                    &[
+                        ("name", &display_name_of(cred.family)),
+                        ("source", &cred.source.to_string()),
+                        ("key", &cred.redacted_key()),
+                    ],
+                )
+            );
+            Some(cred.api_key.clone())
+        }
+        (api_key, _) => api_key,
+    };
+    // Auto mode also skips the model prompt when the provider declares a
+    // default model; without one the model picker still asks.
+    let model = match (model, model_provider.as_deref()) {
+        (None, Some(mp)) if auto_provider => field_shape(FieldSection::ModelProvider, mp)
+            .into_iter()
+            .find(|field| field.key == "model")
+            .and_then(|field| field.default),
+        (model, _) => model,
+    };
+
     if let (Some(mp), Some(m)) = (model_provider.as_deref(), model.as_deref())
         && let Some((canonical_provider, codex_auth)) =
             zeroclaw_runtime::quickstart::resolve_model_provider_type(mp)
@@ -1648,10 +1902,14 @@ async fn run_quickstart_cli(
                         }
                     })
                     .collect();
+                // Preselect the best provider with a discovered key.
+                let preselect = zeroclaw_providers::discovery::best_credential(&discovered)
+                    .and_then(|cred| providers.iter().position(|p| p.kind == cred.family))
+                    .unwrap_or(0);
                 let Some(pi) = FuzzySelect::new()
                     .with_prompt(t("cli-quickstart-provider-type-prompt", "Provider type"))
                     .items(&prov_labels)
-                    .default(0)
+                    .default(preselect)
                     .max_length(prov_labels.len().max(1))
                     .interact_opt()?
                 else {
@@ -1691,6 +1949,23 @@ async fn run_quickstart_cli(
                         if skips_api_key {
                             continue;
                         }
+                        if let Some(cred) =
+                            zeroclaw_providers::discovery::credential_for(&discovered, &chosen.kind)
+                            && Confirm::new()
+                                .with_prompt(qta(
+                                    "cli-quickstart-use-discovered-key",
+                                    &[
+                                        ("name", &chosen.display_name),
+                                        ("source", &cred.source.to_string()),
+                                        ("key", &cred.redacted_key()),
+                                    ],
+                                ))
+                                .default(true)
+                                .interact()?
+                        {
+                            field_buf.insert(d.key.clone(), cred.api_key.clone());
+                            continue;
+                        }
                     }
                     // For the model field, upgrade the descriptor with a
                     // live catalog so `prompt_for_field` renders a picker
@@ -1831,6 +2106,16 @@ async fn run_quickstart_cli(
                         })
                         .collect();
                     items.push(t("cli-quickstart-add-channel", "+ Add a channel"));
+                    items.push(match &form.public_tunnel {
+                        Some(tunnel) => qta(
+                            "cli-quickstart-public-tunnel-set",
+                            &[("url", tunnel.public_url.as_deref().unwrap_or("?"))],
+                        ),
+                        None => t(
+                            "cli-quickstart-public-tunnel",
+                            "+ Expose gateway publicly (Cloudflare Tunnel)",
+                        ),
+                    });
                     items.push(t(
                         "cli-quickstart-channels-done",
                         "Done (channels selector counts as visited)",
@@ -1841,7 +2126,7 @@ async fn run_quickstart_cli(
                             "Channels (optional, 0..N)",
                         ))
                         .items(&items)
-                        .default(items.len().saturating_sub(2))
+                        .default(form.channels.len())
                         .max_length(items.len())
                         .interact_opt()?
                     else {
@@ -1851,6 +2136,26 @@ async fn run_quickstart_cli(
                         form.channels.remove(i);
                         continue;
                     }
+                    if i == form.channels.len() + 1 {
+                        let whatsapp_aliases: Vec<String> = form
+                            .channels
+                            .iter()
+                            .filter_map(|c| match c {
+                                ChannelChoice::Fresh { kind, alias, .. } => {
+                                    (kind == "whatsapp").then(|| alias.clone())
+                                }
+                                ChannelChoice::Existing { alias_ref } => {
+                                    alias_ref.strip_prefix("whatsapp.").map(str::to_string)
+                                }
+                            })
+                            .collect();
+                        if let Some(tunnel) =
+                            Box::pin(prompt_public_tunnel(&cfg, &whatsapp_aliases)).await?
+                        {
+                            form.public_tunnel = Some(tunnel);
+                        }
+                        continue;
+                    }
                     if i == form.channels.len() {
                         // Add — pick Existing or Fresh.
                         let mut mode_labels: Vec<String> = Vec::new();
@@ -2368,6 +2673,7 @@ async fn run_quickstart_cli(
         memory,
         channels,
         peer_groups: form.peer_groups,
+        public_tunnel: form.public_tunnel,
         agent: AgentIdentity {
             name: agent_choice.name.clone(),
             system_prompt: agent_choice.system_prompt,
@@ -2390,6 +2696,13 @@ async fn run_quickstart_cli(
             if let Some(auth) = inline_auth {
                 Box::pin(run_inline_provider_auth(auth, &mut cfg)).await;
             }
+            for alias in applied
+                .channels
+                .iter()
+                .filter_map(|reference| reference.strip_prefix("whatsapp."))
+            {
+                Box::pin(offer_whatsapp_web_pairing(&cfg, alias)).await?;
+            }
             println!();
             println!("{}", t("cli-next-steps", "Next steps:"));
             println!(
@@ -2404,36 +2717,243 @@ async fn run_quickstart_cli(
             }
             Ok(())
         }
-        Err(errs) => {
-            eprintln!();
-            eprintln!(
+        Err(errs) => Err(quickstart_errors(&errs)),
+    }
+}
+
+/// Print Quickstart validation errors and build the error that ends the run.
+#[cfg(feature = "agent-runtime")]
+fn quickstart_errors(errs: &[zeroclaw_runtime::quickstart::QuickstartError]) -> anyhow::Error {
+    eprintln!();
+    eprintln!(
+        "{}",
+        t(
+            "cli-agent-not-created",
+            "Your agent was not created — and nothing on disk was changed."
+        )
+    );
+    eprintln!(
+        "{}",
+        t(
+            "cli-quickstart-fix-and-rerun",
+            "Your existing config is untouched. Fix the following and run quickstart again:",
+        )
+    );
+    eprintln!();
+    for e in errs {
+        eprintln!("  • {}: {}", quickstart_step_label(e.step), e.message);
+    }
+    eprintln!();
+    anyhow::anyhow!(
+        "{}",
+        qta(
+            "cli-quickstart-could-not-finish",
+            &[("count", &errs.len().to_string())],
+        )
+    )
+}
+
+/// `zeroclaw quickstart --from-file`: apply a provisioning file without
+/// prompts. Validates first, prints every config value it will write (secrets
+/// masked), and stops there on `--dry-run`.
+#[cfg(feature = "agent-runtime")]
+async fn run_quickstart_from_file(path: &std::path::Path, dry_run: bool) -> anyhow::Result<()> {
+    use zeroclaw_runtime::quickstart::{Surface, apply_with_surface, plan_with_surface, provision};
+
+    let submission = provision::load(path)?;
+    let mut cfg = Box::pin(crate::config::schema::Config::load_or_init()).await?;
+    let plan = plan_with_surface(&submission, &cfg, Surface::Cli)
+        .map_err(|errs| quickstart_errors(&errs))?;
+
+    println!("{}", t("cli-quickstart-plan-header", "Changes:"));
+    for write in &plan {
+        println!("  + {} = {}", write.path, write.value);
+    }
+    println!();
+    if dry_run {
+        println!(
+            "{}",
+            t("cli-quickstart-dry-run", "Dry run — nothing was written.")
+        );
+        return Ok(());
+    }
+
+    let applied = Box::pin(apply_with_surface(submission, &mut cfg, Surface::Cli))
+        .await
+        .map_err(|errs| quickstart_errors(&errs))?;
+    println!(
+        "{}",
+        ta(
+            "cli-quickstart-complete",
+            &[("alias", &applied.alias)],
+            "Quickstart complete."
+        )
+    );
+    println!();
+    println!("{}", t("cli-next-steps", "Next steps:"));
+    println!(
+        "{}",
+        qta(
+            "cli-quickstart-next-agent-command",
+            &[("alias", &applied.alias)]
+        )
+    );
+    Ok(())
+}
+
+/// Quickstart → WhatsApp Web: offer to scan the pairing QR right away, via
+/// the same flow as `zeroclaw channel pair whatsapp`, instead of leaving it
+/// for the first `channel start`. Skipped for Cloud API aliases, builds
+/// without `whatsapp-web`, and sessions that are already linked.
+#[cfg(feature = "agent-runtime")]
+async fn offer_whatsapp_web_pairing(
+    cfg: &crate::config::schema::Config,
+    alias: &str,
+) -> anyhow::Result<()> {
+    use dialoguer::Confirm;
+    use zeroclaw_channels::login_probe::{PersistedLogin, persisted_login};
+
+    let web_backend = cfg
+        .channels
+        .whatsapp
+        .get(alias)
+        .is_some_and(|whatsapp| whatsapp.session_path.is_some());
+    let Some(qr_channel) = zeroclaw_channels::listing::qr_pairing_channel("whatsapp-web") else {
+        return Ok(());
+    };
+    if !web_backend || persisted_login(qr_channel, cfg, alias) == PersistedLogin::Present {
+        return Ok(());
+    }
+
+    println!();
+    let pair_now = Confirm::new()
+        .with_prompt(qta(
+            "cli-quickstart-pair-whatsapp-prompt",
+            &[("alias", alias)],
+        ))
+        .default(true)
+        .interact()?;
+    if pair_now {
+        let options = channels::ChannelPairOptions::default();
+        match Box::pin(channels::pair_channel(cfg, "whatsapp", alias, options)).await {
+            Ok(()) => return Ok(()),
+            Err(e) => eprintln!(
                 "{}",
-                t(
-                    "cli-agent-not-created",
-                    "Your agent was not created — and nothing on disk was changed."
+                qta(
+                    "cli-quickstart-pair-whatsapp-failed",
+                    &[("error", &format!("{e:#}"))],
                 )
-            );
-            eprintln!(
+            ),
+        }
+    }
+    println!(
+        "{}",
+        qta("cli-quickstart-pair-whatsapp-later", &[("alias", alias)])
+    );
+    Ok(())
+}
+
+/// Quickstart Channels → "Expose gateway publicly": take a Cloudflare Tunnel
+/// token, start `cloudflared` once to learn the public URL, and print the
+/// webhook URLs channels should register. `None` when the operator backs out.
+#[cfg(feature = "agent-runtime")]
+async fn prompt_public_tunnel(
+    cfg: &crate::config::schema::Config,
+    whatsapp_aliases: &[String],
+) -> anyhow::Result<Option<zeroclaw_config::presets::PublicTunnelChoice>> {
+    use dialoguer::{Input, Password};
+
+    println!(
+        "{}",
+        t(
+            "cli-quickstart-public-tunnel-hint",
+            "  Needs `cloudflared` on PATH and a tunnel token from Cloudflare Zero Trust → Networks → Tunnels."
+        )
+    );
+    let token = Password::new()
+        .with_prompt(t(
+            "cli-quickstart-public-tunnel-token",
+            "Cloudflare Tunnel token",
+        ))
+        .allow_empty_password(true)
+        .interact()?;
+    let token = token.trim().to_string();
+    if token.is_empty() {
+        return Ok(None);
+    }
+
+    let mut probe_cfg = cfg.clone();
+    probe_cfg.tunnel.tunnel_provider = "cloudflare".into();
+    probe_cfg.tunnel.cloudflare = Some(zeroclaw_config::schema::CloudflareTunnelConfig {
+        token: token.clone(),
+    });
+    println!(
+        "{}",
+        t(
+            "cli-quickstart-public-tunnel-starting",
+            "  Starting the tunnel to learn its public URL…"
+        )
+    );
+    let probed = match zeroclaw_runtime::tunnel::create_gateway_tunnel(&probe_cfg) {
+        Ok(Some(tunnel)) => {
+            zeroclaw_runtime::tunnel::probe_public_url(
+                tunnel.as_ref(),
+                &cfg.gateway.host,
+                cfg.gateway.port,
+            )
+            .await
+        }
+        Ok(None) => Err(anyhow::anyhow!("no tunnel provider")),
+        Err(e) => Err(e),
+    };
+    let public_url = match probed {
+        Ok(url) => url,
+        Err(e) => {
+            println!(
                 "{}",
-                t(
-                    "cli-quickstart-fix-and-rerun",
-                    "Your existing config is untouched. Fix the following and run quickstart again:",
+                qta(
+                    "cli-quickstart-public-tunnel-failed",
+                    &[("error", &format!("{e:#}"))]
                 )
             );
-            eprintln!();
-            for e in &errs {
-                eprintln!("  • {}: {}", quickstart_step_label(e.step), e.message);
-            }
-            eprintln!();
-            anyhow::bail!(
+            Input::<String>::new()
+                .with_prompt(t("cli-quickstart-public-tunnel-url", "Public URL"))
+                .allow_empty(true)
+                .interact_text()?
+        }
+    };
+    let public_url = public_url.trim().trim_end_matches('/').to_string();
+
+    if !public_url.is_empty() {
+        println!(
+            "{}",
+            qta(
+                "cli-quickstart-public-tunnel-url-row",
+                &[("url", &public_url)]
+            )
+        );
+        for alias in whatsapp_aliases {
+            println!(
                 "{}",
                 qta(
-                    "cli-quickstart-could-not-finish",
-                    &[("count", &errs.len().to_string())],
+                    "cli-quickstart-public-tunnel-whatsapp-row",
+                    &[("url", &format!("{public_url}/whatsapp/{alias}"))]
                 )
-            )
+            );
         }
+        println!(
+            "{}",
+            qta(
+                "cli-quickstart-public-tunnel-webhook-row",
+                &[("url", &format!("{public_url}/webhook"))]
+            )
+        );
     }
+
+    Ok(Some(zeroclaw_config::presets::PublicTunnelChoice {
+        token,
+        public_url: (!public_url.is_empty()).then_some(public_url),
+    }))
 }
 
 #[cfg(feature = "agent-runtime")]
@@ -2826,6 +3346,19 @@ enum ConfigCommands {
         /// whole-config schema.
         #[arg(long)]
         path: Option<String>,
+        /// Accepted for symmetry with other `--json` flags; the schema is
+        /// always JSON.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check config.toml for unknown keys and values that fail to deserialize
+    Validate {
+        /// Config file to check instead of the active one.
+        #[arg(long)]
+        file: Option<std::path::PathBuf>,
+        /// Emit a structured JSON envelope ({file, valid, issues}) instead of plain text.
+        #[arg(long)]
+        json: bool,
     },
     /// List all config properties with current values
     List {
@@ -2904,15 +3437,36 @@ enum ConfigCommands {
     },
 }
 
+#[cfg(feature = "agent-runtime")]
+#[derive(Subcommand, Debug)]
+enum DigestCommands {
+    /// Print today's digest so far, without sending it
+    Preview,
+}
+
 #[cfg(feature = "agent-runtime")]
 #[derive(Subcommand, Debug)]
 enum SecurityCommands {
     /// Show security posture for the default or selected agent risk profile
+    #[command(visible_alias = "show")]
     Status {
         /// Agent alias whose effective runtime security posture should be inspected.
         #[arg(long)]
         agent: String,
 
+        /// Emit machine-readable JSON instead of human text.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Explain how an agent's shell policy rates and gates a command
+    Explain {
+        /// Shell command to classify; it is not executed.
+        command: String,
+
+        /// Agent alias whose shell policy should judge the command.
+        #[arg(long)]
+        agent: String,
+
         /// Emit machine-readable JSON instead of human text.
         #[arg(long)]
         json: bool,
@@ -3041,7 +3595,7 @@ enum ModelCommands {
     /// Refresh and cache model_provider models
     Refresh {
         /// ModelProvider name (defaults to configured default model_provider)
-        #[arg(long)]
+        #[arg(long, alias = "provider")]
         model_provider: Option<String>,
 
         /// Refresh all model_providers that support live model discovery
@@ -3069,6 +3623,15 @@ enum ModelCommands {
     },
     /// Show current model configuration and cache status
     Status,
+    /// Show a model's context window, vision and tool support, and pricing
+    Show {
+        /// Model id, e.g. claude-sonnet-4-6
+        model: String,
+
+        /// Provider entry (`type.alias`) whose config overrides apply
+        #[arg(long, alias = "provider")]
+        model_provider: Option<String>,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -3083,6 +3646,16 @@ enum DoctorCommands {
         #[arg(long)]
         use_cache: bool,
     },
+    /// Verify provider API keys and report latency and remaining quota
+    Providers {
+        /// Check a specific model_provider only (default: all known model_providers)
+        #[arg(long)]
+        model_provider: Option<String>,
+
+        /// Print results as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Query runtime trace events (tool diagnostics and model replies)
     Traces {
         /// Show a specific trace event by id
@@ -3141,6 +3714,7 @@ enum MemoryCommands {
         yes: bool,
     },
     Reindex,
+    Vacuum,
 }
 
 /// Bootstrap the value of the global `--config-dir` flag before clap renders
@@ -3170,6 +3744,25 @@ fn probe_config_dir(
         .cloned()
 }
 
+/// The subcommand named on the command line, read the same lenient way as
+/// [`probe_config_dir`].
+#[cfg(feature = "agent-runtime")]
+fn probe_subcommand(
+    command: &clap::Command,
+    args: impl IntoIterator<Item = std::ffi::OsString>,
+) -> Option<String> {
+    let matches = command
+        .clone()
+        .disable_help_flag(true)
+        .disable_help_subcommand(true)
+        .disable_version_flag(true)
+        .ignore_errors(true)
+        .try_get_matches_from(args)
+        .ok()?;
+
+    matches.subcommand_name().map(str::to_string)
+}
+
 fn apply_i18n_to_command(cmd: clap::Command) -> clap::Command {
     #[cfg(feature = "agent-runtime")]
     {
@@ -3348,7 +3941,8 @@ fn main() -> Result<()> {
     // Locale detection runs while clap builds localized help, so expose the CLI
     // override through the bootstrap env before either i18n or Tokio starts.
     // Empty values remain for clap's canonical parse/validation path below.
-    if let Some(config_dir) = probe_config_dir(&command, std::env::args_os())
+    let config_dir = probe_config_dir(&command, std::env::args_os());
+    if let Some(config_dir) = &config_dir
         && !config_dir.trim().is_empty()
     {
         // SAFETY: this synchronous bootstrap runs before the Tokio runtime (and
@@ -3356,7 +3950,22 @@ fn main() -> Result<()> {
         unsafe { std::env::set_var("ZEROCLAW_CONFIG_DIR", config_dir) };
     }
 
-    async_main(command)
+    // A daemon started by the Windows Service Control Manager must hand this
+    // thread to the service dispatcher before the runtime starts.
+    #[cfg(feature = "agent-runtime")]
+    let result = if probe_subcommand(&command, std::env::args_os()).as_deref() == Some("daemon") {
+        let logs_dir = config_dir.map(|dir| PathBuf::from(dir).join("logs"));
+        service::dispatch_daemon(logs_dir, move || async_main(command))
+    } else {
+        async_main(command)
+    };
+    #[cfg(not(feature = "agent-runtime"))]
+    let result = async_main(command);
+
+    // Docker session containers outlive the runtime handles that started
+    // them; remove them once the async runtime has stopped.
+    zeroclaw_config::platform::remove_session_containers();
+    result
 }
 
 #[tokio::main]
@@ -3683,6 +4292,17 @@ async fn async_main(command: clap::Command) -> Result<()> {
                 })
             },
         ));
+
+        // Serial board sessions report connect/detach as `peripheral:<name>`
+        // health components, which `zeroclaw peripheral list` reads back.
+        #[cfg(feature = "hardware")]
+        zeroclaw_hardware::peripherals::boards::set_connection_observer(|board, error| {
+            let component = format!("peripheral:{board}");
+            match error {
+                None => zeroclaw_runtime::health::mark_component_ok(&component),
+                Some(error) => zeroclaw_runtime::health::mark_component_error(&component, error),
+            }
+        });
     }
 
     #[cfg(feature = "agent-runtime")]
@@ -3692,13 +4312,28 @@ async fn async_main(command: clap::Command) -> Result<()> {
         | Commands::MarkdownHelp
         | Commands::MarkdownSchema => unreachable!(),
 
+        Commands::Quickstart {
+            from_file: Some(path),
+            dry_run,
+            ..
+        } => Box::pin(run_quickstart_from_file(&path, dry_run)).await,
+
         Commands::Quickstart {
             model_provider,
             model,
             api_key,
             agent,
+            use_discovered_key,
+            ..
         } => {
-            Box::pin(run_quickstart_cli(model_provider, model, api_key, agent)).await?;
+            Box::pin(run_quickstart_cli(
+                model_provider,
+                model,
+                api_key,
+                agent,
+                use_discovered_key,
+            ))
+            .await?;
             Ok(())
         }
 
@@ -3706,6 +4341,8 @@ async fn async_main(command: clap::Command) -> Result<()> {
             agent: agent_alias,
             message,
             session_state_file,
+            resume,
+            list_sessions,
             model_provider,
             model,
             temperature,
@@ -3720,11 +4357,35 @@ async fn async_main(command: clap::Command) -> Result<()> {
             // Validate up-front: bail with a clear message if the alias
             // isn't configured. The runtime would error too, but this
             // catches typos before any subsystem spins up.
-            if config.agent(&agent_alias).is_none() {
+            let Some(retention) = config
+                .agent(&agent_alias)
+                .map(|agent| agent.cli_session_retention)
+            else {
                 anyhow::bail!(
                     "`zeroclaw agent --agent {agent_alias}` is not configured (no [agents.{agent_alias}] entry)"
                 );
+            };
+
+            use zeroclaw_runtime::agent::cli_sessions;
+            let sessions_dir = cli_sessions::sessions_dir(&config, &agent_alias);
+            if list_sessions {
+                print_cli_sessions(&sessions_dir, &agent_alias)?;
+                return Ok(());
             }
+            let cli_session = match resume {
+                Some(selector) => Some(cli_sessions::CliSession::resume(
+                    sessions_dir,
+                    &selector,
+                    retention,
+                )?),
+                None if message.is_none() && session_state_file.is_none() && retention > 0 => {
+                    Some(cli_sessions::CliSession::create(sessions_dir, retention))
+                }
+                None => None,
+            };
+
+            let peripheral = peripherals::apply_auto_peripherals(&mut config, peripheral).await?;
+            peripherals::attach_peripheral_specs(&mut config, &peripheral)?;
 
             // Wire CLI channel for interactive mode
             zeroclaw_runtime::agent::loop_::register_cli_channel_fn(Box::new(|| {
@@ -3758,7 +4419,10 @@ async fn async_main(command: clap::Command) -> Result<()> {
                 session_state_file,
                 None,
                 zeroclaw_api::ingress::TurnOrigin::Interactive,
-                zeroclaw_runtime::agent::loop_::AgentRunOverrides::default(),
+                zeroclaw_runtime::agent::loop_::AgentRunOverrides {
+                    cli_session,
+                    ..Default::default()
+                },
             ))
             .await
             .map(|_| ())
@@ -4506,6 +5170,26 @@ async fn async_main(command: clap::Command) -> Result<()> {
                     &heartbeat_fallback
                 )
             );
+            let watcher_names: Vec<&str> = config
+                .watchers
+                .iter()
+                .filter(|w| w.enabled)
+                .map(|w| w.name.as_str())
+                .collect();
+            let watchers_value = if watcher_names.is_empty() {
+                t("cli-status-word-none", "(none)")
+            } else {
+                watcher_names.join(", ")
+            };
+            let watchers_fallback = format!("👀 Watchers:       {}", watchers_value);
+            println!(
+                "{}",
+                ta(
+                    "cli-status-watchers",
+                    &[("v", &watchers_value)],
+                    &watchers_fallback
+                )
+            );
             let memory_backend = effective_memory_backend.to_string();
             let memory_auto_save = if config.memory.auto_save {
                 t("cli-status-word-on", "on")
@@ -4527,6 +5211,24 @@ async fn async_main(command: clap::Command) -> Result<()> {
                     &memory_fallback
                 )
             );
+            let today = chrono::Local::now().date_naive();
+            if config.channels.feedback.enabled
+                && let Ok(Some(activity)) =
+                    zeroclaw_runtime::observability::activity::load_day(&config.data_dir, today)
+            {
+                let positive = activity.feedback_positive.to_string();
+                let negative = activity.feedback_negative.to_string();
+                let feedback_fallback =
+                    format!("🗳️  Feedback today: {positive} 👍 · {negative} 👎");
+                println!(
+                    "{}",
+                    ta(
+                        "cli-status-feedback",
+                        &[("positive", &positive), ("negative", &negative)],
+                        &feedback_fallback
+                    )
+                );
+            }
 
             println!();
             // Per-agent security: each enabled agent's risk profile.
@@ -4736,6 +5438,46 @@ async fn async_main(command: clap::Command) -> Result<()> {
                     )
                 );
             }
+            if let Some(details) = doctor::daemon_health_details(&config) {
+                let admission = details.channels;
+                if admission.draining {
+                    println!(
+                        "{}",
+                        ta(
+                            "cli-status-channels-draining",
+                            &[("in_flight", &admission.in_flight.to_string())],
+                            "  Drain mode: on"
+                        )
+                    );
+                }
+                if !admission.paused_channels.is_empty() {
+                    println!(
+                        "{}",
+                        ta(
+                            "cli-status-channels-paused",
+                            &[
+                                ("v", &admission.paused_channels.join(", ")),
+                                ("queued", &admission.queued.to_string()),
+                            ],
+                            "  Paused"
+                        )
+                    );
+                }
+                if !admission.outbox.is_empty() {
+                    let depth = admission
+                        .outbox
+                        .iter()
+                        .map(|(channel, depth)| {
+                            format!("{channel} {}/{}", depth.pending, depth.dead)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    println!(
+                        "{}",
+                        ta("cli-status-channels-outbox", &[("v", &depth)], "  Outbox")
+                    );
+                }
+            }
             println!();
             println!("{}", t("cli-status-peripherals", "Peripherals:"));
             let peripherals_enabled = if config.peripherals.enabled {
@@ -4764,6 +5506,25 @@ async fn async_main(command: clap::Command) -> Result<()> {
             Ok(())
         }
 
+        #[cfg(feature = "agent-runtime")]
+        Commands::Digest { digest_command } => {
+            let DigestCommands::Preview = digest_command;
+            let today = chrono::Local::now().date_naive();
+            let digest = zeroclaw_runtime::observability::digest::build(&config, today, true).await;
+            println!("{}", digest.render());
+            if !config.observability.digest.enabled {
+                println!();
+                println!(
+                    "{}",
+                    t(
+                        "cli-digest-disabled-hint",
+                        "Scheduled digests are off; set [observability.digest] enabled = true."
+                    )
+                );
+            }
+            Ok(())
+        }
+
         #[cfg(feature = "agent-runtime")]
         Commands::Security {
             security_command: SecurityCommands::Status { agent, json },
@@ -4777,6 +5538,24 @@ async fn async_main(command: clap::Command) -> Result<()> {
             Ok(())
         }
 
+        #[cfg(feature = "agent-runtime")]
+        Commands::Security {
+            security_command:
+                SecurityCommands::Explain {
+                    command,
+                    agent,
+                    json,
+                },
+        } => {
+            let report = security_status::explain_command(&config, &agent, &command)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                security_status::print_explain_report(&report);
+            }
+            Ok(())
+        }
+
         Commands::Estop {
             estop_command,
             level,
@@ -4786,6 +5565,12 @@ async fn async_main(command: clap::Command) -> Result<()> {
 
         Commands::Cron { cron_command } => cron::handle_command(cron_command, &config),
 
+        Commands::Tasks { tasks_command } => tasks::handle_command(tasks_command, &config),
+
+        Commands::Approvals { approvals_command } => {
+            approvals_dispatch(approvals_command, &config).await
+        }
+
         Commands::Models { model_command } => {
             #[cfg(feature = "agent-runtime")]
             {
@@ -4861,11 +5646,21 @@ async fn async_main(command: clap::Command) -> Result<()> {
             service::handle_command(&service_command, &config, init_system)
         }
 
-        Commands::Doctor { doctor_command } => match doctor_command {
+        Commands::Doctor {
+            json,
+            doctor_command,
+        } => match doctor_command {
             Some(DoctorCommands::Models {
                 model_provider,
                 use_cache: _,
             }) => doctor::run_configured_models(&config, model_provider.as_deref(), true).await,
+            Some(DoctorCommands::Providers {
+                model_provider,
+                json: providers_json,
+            }) => {
+                doctor::run_providers(&config, model_provider.as_deref(), json || providers_json)
+                    .await
+            }
             Some(DoctorCommands::Traces {
                 id,
                 event,
@@ -4891,6 +5686,7 @@ async fn async_main(command: clap::Command) -> Result<()> {
                 .await?;
                 Ok(())
             }
+            None if json => doctor::run_json(&config).await,
             None => doctor::run(&config).await,
         },
 
@@ -4933,7 +5729,22 @@ async fn async_main(command: clap::Command) -> Result<()> {
                 }
                 result
             }
-            ChannelCommands::Doctor => Box::pin(channels::doctor_channels(config)).await,
+            ChannelCommands::Doctor {
+                json,
+                channel,
+                timeout,
+            } => {
+                let options = channels::ChannelDoctorOptions {
+                    json,
+                    channel,
+                    timeout: std::time::Duration::from_secs(timeout.max(1)),
+                };
+                let report = Box::pin(channels::doctor_channels(config, options)).await?;
+                match report.exit_code() {
+                    0 => Ok(()),
+                    code => std::process::exit(code),
+                }
+            }
             other => Box::pin(channels::handle_command(other, &config)).await,
         },
 
@@ -4946,7 +5757,7 @@ async fn async_main(command: clap::Command) -> Result<()> {
 
         Commands::Integrations {
             integration_command,
-        } => integrations::handle_command(integration_command, &config),
+        } => integrations::handle_command(integration_command, &mut config).await,
 
         Commands::Skills { skill_command } => skills::handle_command(skill_command, &config).await,
 
@@ -4966,6 +5777,10 @@ async fn async_main(command: clap::Command) -> Result<()> {
             migration::handle_command(migrate_command, &config).await
         }
 
+        Commands::Backup { backup_command } => {
+            commands::backup::handle_command(backup_command, &config).await
+        }
+
         Commands::Memory { memory_command } => {
             memory::cli::handle_command(memory_command, &config).await
         }
@@ -4973,7 +5788,7 @@ async fn async_main(command: clap::Command) -> Result<()> {
         Commands::Auth { auth_command } => handle_auth_command(auth_command, &config).await,
 
         Commands::Hardware { hardware_command } => {
-            hardware::handle_command(hardware_command.clone(), &config)
+            hardware::handle_command(hardware_command.clone(), &config).await
         }
 
         Commands::Peripheral { peripheral_command } => {
@@ -5173,12 +5988,16 @@ async fn async_main(command: clap::Command) -> Result<()> {
 
         Commands::Update {
             check,
+            rollback,
             force,
             version,
             json,
         } => {
-            if check {
-                let info = commands::update::check(version.as_deref()).await?;
+            let channel = config.updater.channel;
+            if rollback {
+                commands::update::rollback().await
+            } else if check {
+                let info = commands::update::check(version.as_deref(), channel).await?;
                 if json {
                     // Machine-readable shape consumed by the gateway's
                     // `GET /api/version/check`. Keep field names stable.
@@ -5191,6 +6010,8 @@ async fn async_main(command: clap::Command) -> Result<()> {
                             "release_url": info.release_url,
                             "release_notes": info.release_notes,
                             "published_at": info.published_at,
+                            "channel": info.channel.as_str(),
+                            "expected_sha256": info.expected_sha256,
                         }))?
                     );
                 } else if info.is_newer {
@@ -5215,9 +6036,28 @@ async fn async_main(command: clap::Command) -> Result<()> {
                         )
                     );
                 }
+                if !json {
+                    println!(
+                        "{}",
+                        ta(
+                            "cli-update-channel",
+                            &[("channel", info.channel.as_str())],
+                            "Channel"
+                        )
+                    );
+                    let checksum = info.expected_sha256.as_deref().unwrap_or("unavailable");
+                    println!(
+                        "{}",
+                        ta(
+                            "cli-update-expected-checksum",
+                            &[("sha256", checksum)],
+                            "Expected SHA-256"
+                        )
+                    );
+                }
                 Ok(())
             } else {
-                commands::update::run(version.as_deref(), force).await
+                commands::update::run(version.as_deref(), force, channel).await
             }
         }
 
@@ -5254,7 +6094,7 @@ async fn async_main(command: clap::Command) -> Result<()> {
         },
 
         Commands::Config { config_command } => match config_command {
-            ConfigCommands::Schema { path } => {
+            ConfigCommands::Schema { path, json: _ } => {
                 #[cfg(feature = "schema-export")]
                 {
                     let schema = schemars::schema_for!(config::Config);
@@ -5283,6 +6123,37 @@ async fn async_main(command: clap::Command) -> Result<()> {
                     anyhow::bail!("zeroclaw was built without the 'schema-export' feature")
                 }
             }
+            ConfigCommands::Validate { file, json } => {
+                let path = file.unwrap_or_else(|| config.config_path.clone());
+                let raw = std::fs::read_to_string(&path)
+                    .with_context(|| format!("failed to read {}", path.display()))?;
+                let issues = crate::config::lint::lint_config_toml(&raw);
+                if json {
+                    let envelope = serde_json::json!({
+                        "file": path.display().to_string(),
+                        "valid": issues.is_empty(),
+                        "issues": issues,
+                    });
+                    println!("{}", serde_json::to_string_pretty(&envelope)?);
+                } else if issues.is_empty() {
+                    println!(
+                        "{}",
+                        ta(
+                            "cli-config-valid",
+                            &[("path", &path.display().to_string())],
+                            "No issues found"
+                        )
+                    );
+                } else {
+                    for issue in &issues {
+                        println!("{}: {issue}", path.display());
+                    }
+                }
+                if !issues.is_empty() {
+                    std::process::exit(1);
+                }
+                Ok(())
+            }
             ConfigCommands::List { filter, secrets } => {
                 let entries = config.prop_fields();
                 println!(
@@ -6468,6 +7339,42 @@ fn build_resume_selector(
     Ok(security::ResumeSelector::KillAll)
 }
 
+#[cfg(feature = "agent-runtime")]
+fn print_cli_sessions(dir: &Path, agent_alias: &str) -> Result<()> {
+    let sessions = zeroclaw_runtime::agent::cli_sessions::list_sessions(dir)?;
+    if sessions.is_empty() {
+        println!(
+            "{}",
+            qta("cli-agent-sessions-none", &[("agent", agent_alias)])
+        );
+        return Ok(());
+    }
+    println!(
+        "{}",
+        qta("cli-agent-sessions-header", &[("agent", agent_alias)])
+    );
+    for session in sessions {
+        let updated = session
+            .updated_at
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string();
+        println!(
+            "{}",
+            qta(
+                "cli-agent-sessions-row",
+                &[
+                    ("id", session.id.as_str()),
+                    ("updated", updated.as_str()),
+                    ("turns", session.turns.to_string().as_str()),
+                    ("preview", session.preview.as_str()),
+                ],
+            )
+        );
+    }
+    Ok(())
+}
+
 #[cfg(feature = "agent-runtime")]
 fn print_estop_status(state: &security::EstopState) {
     println!("{}", t("cli-estop-status", "Estop status:"));
@@ -6808,6 +7715,212 @@ async fn sop_admin_post(
     }
 }
 
+/// Dispatch `zeroclaw approvals`. Needs the `agent-runtime` build: the queue
+/// store and the gateway client both live behind it.
+async fn approvals_dispatch(cmd: ApprovalsCommands, config: &crate::config::Config) -> Result<()> {
+    #[cfg(feature = "agent-runtime")]
+    {
+        approvals_request(cmd, config).await
+    }
+    #[cfg(not(feature = "agent-runtime"))]
+    {
+        let _ = (cmd, config);
+        anyhow::bail!("`zeroclaw approvals` requires the agent-runtime build")
+    }
+}
+
+/// `list` reads the queue store directly; `approve`/`deny` post to
+/// `/admin/approvals/*` so the waiting tool call wakes at once. When the
+/// gateway is unreachable the decision is written to the store, where the
+/// daemon's waiter polls for it.
+#[cfg(feature = "agent-runtime")]
+async fn approvals_request(cmd: ApprovalsCommands, config: &crate::config::Config) -> Result<()> {
+    use zeroclaw_runtime::approval::queue::{self, ApprovalDecision, ResolveOutcome};
+
+    let (id, approved, reason) = match cmd {
+        ApprovalsCommands::List { all, limit } => {
+            let approvals = queue::list_approvals(config, !all, limit)?;
+            if approvals.is_empty() {
+                let key = if all {
+                    "cli-approvals-none-all"
+                } else {
+                    "cli-approvals-none"
+                };
+                println!("{}", t(key, "No tool calls waiting for approval."));
+                return Ok(());
+            }
+            println!(
+                "{}",
+                ta(
+                    "cli-approvals-header",
+                    &[("count", &approvals.len().to_string())],
+                    "Approval requests:",
+                )
+            );
+            for approval in approvals {
+                let origin = match &approval.sender {
+                    Some(sender) => format!("{} → {sender}", approval.channel),
+                    None => approval.channel.clone(),
+                };
+                let expires = approval.expires_at.to_rfc3339();
+                println!(
+                    "{}",
+                    ta(
+                        "cli-approvals-row",
+                        &[
+                            ("id", &approval.id),
+                            ("status", approval.status.as_str()),
+                            ("tool", &approval.tool_name),
+                            ("origin", &origin),
+                            ("expires", &expires),
+                        ],
+                        "- (approval)",
+                    )
+                );
+                println!(
+                    "{}",
+                    ta(
+                        "cli-approvals-row-args",
+                        &[("v", &approval.arguments_summary)],
+                        "    args",
+                    )
+                );
+                if !approval.risk_reasons.is_empty() {
+                    println!(
+                        "{}",
+                        ta(
+                            "cli-approvals-row-reasons",
+                            &[("v", &approval.risk_reasons.join("; "))],
+                            "    why",
+                        )
+                    );
+                }
+                if let Some(by) = &approval.decided_by {
+                    let reason = approval
+                        .reason
+                        .as_deref()
+                        .map(|r| format!(" ({r})"))
+                        .unwrap_or_default();
+                    println!(
+                        "{}",
+                        ta(
+                            "cli-approvals-row-decided",
+                            &[
+                                ("by", by),
+                                ("via", approval.decided_via.as_deref().unwrap_or("-")),
+                                ("reason", &reason),
+                            ],
+                            "    decided",
+                        )
+                    );
+                }
+            }
+            return Ok(());
+        }
+        ApprovalsCommands::Approve { id } => (id, true, None),
+        ApprovalsCommands::Deny { id, reason } => (id, false, reason),
+    };
+
+    let operator = std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok();
+    let path = if approved {
+        "/admin/approvals/approve"
+    } else {
+        "/admin/approvals/deny"
+    };
+    let url = gateway_admin_url(
+        &config.gateway.host,
+        config.gateway.port,
+        config.gateway.path_prefix.as_deref(),
+        path,
+    );
+    let sent = reqwest::Client::new()
+        .post(&url)
+        .json(&serde_json::json!({ "id": id, "reason": reason, "operator": operator }))
+        .timeout(std::time::Duration::from_secs(5))
+        .send()
+        .await;
+    let resp = match sent {
+        Ok(resp) => resp,
+        Err(e) if e.is_connect() || e.is_timeout() => {
+            let decided_by = match &operator {
+                Some(operator) => format!("cli ({operator})"),
+                None => "cli".to_string(),
+            };
+            let decision = ApprovalDecision {
+                approved,
+                decided_by,
+                decided_via: "local queue".to_string(),
+                reason,
+            };
+            let outcome = queue::resolve(config, &id, decision)?;
+            let (label, status) = match &outcome {
+                ResolveOutcome::Resolved(a) => ("resolved", a.status.as_str()),
+                ResolveOutcome::AlreadyResolved(a) => ("already_resolved", a.status.as_str()),
+                ResolveOutcome::NotFound => ("not_found", "-"),
+            };
+            if label == "resolved" {
+                println!(
+                    "{}",
+                    t(
+                        "cli-approvals-direct",
+                        "Gateway unreachable; recorded the decision in the queue directly."
+                    )
+                );
+            }
+            return print_approval_outcome(&id, label, status);
+        }
+        Err(e) => anyhow::bail!("Failed to connect to gateway: {e}"),
+    };
+    let status = resp.status();
+    let body: serde_json::Value = resp.json().await.unwrap_or_default();
+    if let Some(label) = body.get("outcome").and_then(|v| v.as_str()) {
+        let approval_status = body.get("status").and_then(|v| v.as_str()).unwrap_or("-");
+        return print_approval_outcome(&id, label, approval_status);
+    }
+    let err = body
+        .get("error")
+        .and_then(|v| v.as_str())
+        .unwrap_or("request failed");
+    anyhow::bail!("Gateway responded {status}: {err}");
+}
+
+/// Report an `/admin/approvals/*` outcome label (`resolved`,
+/// `already_resolved`, `not_found`); anything but `resolved` is an error.
+#[cfg(feature = "agent-runtime")]
+fn print_approval_outcome(id: &str, outcome: &str, status: &str) -> Result<()> {
+    match outcome {
+        "resolved" => {
+            println!(
+                "{}",
+                ta(
+                    "cli-approvals-resolved",
+                    &[("id", id), ("status", status)],
+                    "Approval resolved",
+                )
+            );
+            Ok(())
+        }
+        "already_resolved" => anyhow::bail!(
+            "{}",
+            ta(
+                "cli-approvals-already",
+                &[("id", id), ("status", status)],
+                "Approval already resolved",
+            )
+        ),
+        _ => anyhow::bail!(
+            "{}",
+            ta(
+                "cli-approvals-not-found",
+                &[("id", id)],
+                "No such approval request"
+            )
+        ),
+    }
+}
+
 #[cfg(feature = "agent-runtime")]
 enum PaircodeAction {
     /// GET the current code; do not mint or revoke anything.
@@ -8097,6 +9210,10 @@ async fn dispatch_models_command(model_command: ModelCommands, config: &mut Conf
             doctor::run_models(config, model_provider.as_deref(), false, false).await
         }
         ModelCommands::Set { model } => handle_models_set(config, &model).await,
+        ModelCommands::Show {
+            model,
+            model_provider,
+        } => doctor::run_model_show(config, &model, model_provider.as_deref()),
         ModelCommands::Status => {
             match config
                 .providers
@@ -8190,6 +9307,29 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "agent-runtime")]
+    #[test]
+    fn probe_subcommand_finds_daemon_behind_global_flags() {
+        fn argv(parts: &[&str]) -> Vec<std::ffi::OsString> {
+            parts.iter().map(std::ffi::OsString::from).collect()
+        }
+
+        let command = Cli::command();
+        assert_eq!(
+            probe_subcommand(
+                &command,
+                argv(&["zeroclaw", "--config-dir", "C:\\zc", "daemon"])
+            )
+            .as_deref(),
+            Some("daemon")
+        );
+        assert_eq!(
+            probe_subcommand(&command, argv(&["zeroclaw", "status"])).as_deref(),
+            Some("status")
+        );
+        assert_eq!(probe_subcommand(&command, argv(&["zeroclaw"])), None);
+    }
+
     #[test]
     fn probe_config_dir_follows_clap_token_ownership() {
         fn argv(parts: &[&str]) -> std::vec::IntoIter<std::ffi::OsString> {
@@ -8555,6 +9695,19 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "agent-runtime")]
+    fn digest_preview_cli_parses() {
+        let cli = Cli::try_parse_from(["zeroclaw", "digest", "preview"])
+            .expect("digest preview should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Digest {
+                digest_command: DigestCommands::Preview
+            }
+        ));
+    }
+
     #[test]
     #[cfg(feature = "agent-runtime")]
     fn security_status_cli_requires_agent_and_parses_json_form() {
@@ -8576,6 +9729,35 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "agent-runtime")]
+    fn security_explain_cli_takes_command_and_agent() {
+        let cli = Cli::try_parse_from([
+            "zeroclaw",
+            "security",
+            "explain",
+            "git push origin main",
+            "--agent",
+            "ops",
+        ])
+        .expect("security explain should parse");
+        match cli.command {
+            Commands::Security {
+                security_command:
+                    SecurityCommands::Explain {
+                        command,
+                        agent,
+                        json,
+                    },
+            } => {
+                assert_eq!(command, "git push origin main");
+                assert_eq!(agent, "ops");
+                assert!(!json);
+            }
+            other => panic!("expected security explain command, got {other:?}"),
+        }
+    }
+
     #[test]
     #[cfg(feature = "agent-runtime")]
     fn gateway_get_paircode_rotate_flags_parse_and_conflict() {
@@ -8994,6 +10176,19 @@ mod tests {
         assert_eq!(applied, None);
     }
 
+    #[test]
+    fn cli_parses_models_refresh_provider_alias() {
+        let cli = Cli::try_parse_from(["zeroclaw", "models", "refresh", "--provider", "xai"])
+            .expect("models refresh --provider should parse");
+
+        match cli.command {
+            Commands::Models {
+                model_command: ModelCommands::Refresh { model_provider, .. },
+            } => assert_eq!(model_provider.as_deref(), Some("xai")),
+            other => panic!("expected models refresh command, got {other:?}"),
+        }
+    }
+
     #[test]
     #[cfg(feature = "agent-runtime")]
     fn cli_parses_estop_default_engage() {
@@ -9095,6 +10290,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn agent_command_resume_defaults_to_last() {
+        let cli = Cli::try_parse_from(["zeroclaw", "agent", "--agent", "ops", "--resume"])
+            .expect("bare --resume should parse");
+        match cli.command {
+            Commands::Agent { resume, .. } => assert_eq!(resume.as_deref(), Some("last")),
+            other => panic!("expected agent command, got {other:?}"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "zeroclaw",
+            "agent",
+            "--agent",
+            "ops",
+            "--resume",
+            "20260101-120000-abcdef",
+        ])
+        .expect("--resume <id> should parse");
+        match cli.command {
+            Commands::Agent { resume, .. } => {
+                assert_eq!(resume.as_deref(), Some("20260101-120000-abcdef"));
+            }
+            other => panic!("expected agent command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn agent_command_resume_conflicts_with_single_message() {
+        assert!(
+            Cli::try_parse_from([
+                "zeroclaw", "agent", "--agent", "ops", "--resume", "-m", "hi"
+            ])
+            .is_err()
+        );
+        assert!(
+            Cli::try_parse_from([
+                "zeroclaw",
+                "agent",
+                "--agent",
+                "ops",
+                "--list-sessions",
+                "--resume"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn quickstart_from_file_excludes_interactive_seeds() {
+        assert!(
+            Cli::try_parse_from([
+                "zeroclaw",
+                "quickstart",
+                "--from-file",
+                "p.toml",
+                "--dry-run"
+            ])
+            .is_ok()
+        );
+        assert!(Cli::try_parse_from(["zeroclaw", "quickstart", "--dry-run"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "zeroclaw",
+                "quickstart",
+                "--from-file",
+                "p.toml",
+                "--agent",
+                "ops"
+            ])
+            .is_err()
+        );
+        assert!(
+            Cli::try_parse_from([
+                "zeroclaw",
+                "quickstart",
+                "--from-file",
+                "p.toml",
+                "--use-discovered-key"
+            ])
+            .is_err()
+        );
+    }
+
     #[test]
     #[cfg(feature = "agent-runtime")]
     fn agent_uses_provider_temperature_when_unset() {
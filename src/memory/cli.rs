@@ -43,15 +43,17 @@ pub async fn handle_command(command: crate::MemoryCommands, config: &Config) ->
         crate::MemoryCommands::List {
             category,
             session,
+            namespace,
             limit,
             offset,
-        } => handle_list(config, category, session, limit, offset).await,
+        } => handle_list(config, category, session, namespace, limit, offset).await,
         crate::MemoryCommands::Get { key } => handle_get(config, &key).await,
         crate::MemoryCommands::Stats => handle_stats(config).await,
         crate::MemoryCommands::Clear { key, category, yes } => {
             handle_clear(config, key, category, yes).await
         }
         crate::MemoryCommands::Reindex => handle_reindex(config).await,
+        crate::MemoryCommands::Vacuum => handle_vacuum(config).await,
     }
 }
 
@@ -86,6 +88,26 @@ async fn handle_reindex(config: &Config) -> Result<()> {
     Ok(())
 }
 
+async fn handle_vacuum(config: &Config) -> Result<()> {
+    let mem = create_cli_memory(config)?;
+    println!(
+        "{} {}",
+        style("→").cyan(),
+        mt("cli-memory-vacuuming", "Compacting memory database...")
+    );
+    let reclaimed = mem.vacuum().await?;
+    println!(
+        "{} {}",
+        style("✓").green(),
+        mt_args(
+            "cli-memory-vacuumed",
+            &[("kib", &(reclaimed / 1024).to_string())],
+            "Memory database compacted."
+        )
+    );
+    Ok(())
+}
+
 fn create_cli_memory(config: &Config) -> Result<Box<dyn Memory>> {
     let backend = backend_kind_from_dotted(&config.memory.backend);
 
@@ -101,12 +123,16 @@ async fn handle_list(
     config: &Config,
     category: Option<String>,
     session: Option<String>,
+    namespace: Option<String>,
     limit: usize,
     offset: usize,
 ) -> Result<()> {
     let mem = create_cli_memory(config)?;
     let cat = category.as_deref().map(parse_category);
-    let entries = mem.list(cat.as_ref(), session.as_deref()).await?;
+    let mut entries = mem.list(cat.as_ref(), session.as_deref()).await?;
+    if let Some(namespace) = namespace.as_deref() {
+        entries.retain(|entry| entry.namespace == namespace);
+    }
 
     if entries.is_empty() {
         println!("{}", mt("cli-memory-none", "No memory entries found."));
@@ -138,11 +164,20 @@ async fn handle_list(
     );
 
     for entry in &page {
-        println!(
-            "- {} [{}]",
-            style(&entry.key).white().bold(),
-            entry.category,
-        );
+        if entry.namespace == "default" {
+            println!(
+                "- {} [{}]",
+                style(&entry.key).white().bold(),
+                entry.category,
+            );
+        } else {
+            println!(
+                "- {} [{}] ({})",
+                style(&entry.key).white().bold(),
+                entry.category,
+                style(&entry.namespace).dim(),
+            );
+        }
         println!("    {}", truncate_content(&entry.content, 80));
     }
 
@@ -15,6 +15,10 @@ pub async fn handle_command(command: crate::MigrateCommands, config: &Config) ->
             source,
             dry_run,
             reindex,
-        } => migrate_openclaw_memory(config, source, dry_run, reindex).await,
+            overwrite,
+        } => {
+            migrate_openclaw_memory(config, source.clone(), dry_run, reindex).await?;
+            migrate_openclaw_config(config, source, dry_run, overwrite).await
+        }
     }
 }
@@ -30,11 +30,19 @@ pub async fn handle_command(cmd: crate::PeripheralCommands, config: &Config) ->
                 println!("  path = \"/dev/ttyACM0\""); // i18n-exempt: literal config.toml snippet
             } else {
                 println!("{}", get_required_cli_string("cli-peripherals-configured"));
+                let health = zeroclaw_runtime::doctor::daemon_health_details(config);
                 for b in boards {
                     let path = b.path.as_deref().unwrap_or("(native)");
-                    println!("  {}  {}  {}", b.board, b.transport, path);
+                    let name = b.session_name();
+                    let state = connection_state(health.as_ref(), &name);
+                    println!("  {name}  {}  {}  {path}  {state}", b.board, b.transport);
                 }
             }
+            #[cfg(all(
+                feature = "hardware",
+                any(target_os = "linux", target_os = "macos", target_os = "windows")
+            ))]
+            print_detection_cache(config)?;
         }
         crate::PeripheralCommands::Add { board, path } => {
             let transport = if path == "native" { "native" } else { "serial" };
@@ -68,6 +76,7 @@ pub async fn handle_command(cmd: crate::PeripheralCommands, config: &Config) ->
                 transport: transport.to_string(),
                 path: path_opt,
                 baud: 115_200,
+                name: None,
             });
             Box::pin(cfg.save()).await?;
             println!(
@@ -130,3 +139,164 @@ pub async fn handle_command(cmd: crate::PeripheralCommands, config: &Config) ->
     }
     Ok(())
 }
+
+#[cfg(all(
+    feature = "hardware",
+    any(target_os = "linux", target_os = "macos", target_os = "windows")
+))]
+fn print_detection_cache(config: &Config) -> Result<()> {
+    use zeroclaw_hardware::detect;
+
+    println!();
+    let Some(cache) = detect::load_detection_cache(&config.data_dir.join("state"))? else {
+        println!(
+            "{}",
+            get_required_cli_string("cli-peripherals-detected-none")
+        );
+        return Ok(());
+    };
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    println!(
+        "{}",
+        get_required_cli_string_with_args(
+            "cli-peripherals-detected-header",
+            &[("age", &now.saturating_sub(cache.scanned_at).to_string())],
+        )
+    );
+    detect::print_detection_table(&cache.peripherals);
+    Ok(())
+}
+
+/// Live state of the board session `name` in the running daemon, from its
+/// `peripheral:<name>` health component.
+fn connection_state(
+    health: Option<&zeroclaw_runtime::health::HealthDetails>,
+    name: &str,
+) -> String {
+    let component = health.and_then(|h| h.components.get(&format!("peripheral:{name}")));
+    match component {
+        Some(c) if c.state == "ok" => get_required_cli_string("cli-peripherals-state-connected"),
+        Some(c) => get_required_cli_string_with_args(
+            "cli-peripherals-state-detached",
+            &[("error", c.last_error.as_deref().unwrap_or("-"))],
+        ),
+        None => get_required_cli_string("cli-peripherals-state-no-session"),
+    }
+}
+
+/// Attach explicit `--peripheral board:path` specs as boards on
+/// `config.peripherals`, so each one gets its own named session (`board:path`).
+/// `board:native` attaches a native-transport board. Boards already configured
+/// at the same path are left as they are.
+pub fn attach_peripheral_specs(config: &mut Config, specs: &[String]) -> Result<()> {
+    for spec in specs {
+        let Some((board, path)) = spec
+            .split_once(':')
+            .map(|(board, path)| (board.trim(), path.trim()))
+            .filter(|(board, path)| !board.is_empty() && !path.is_empty())
+        else {
+            anyhow::bail!(
+                "{}",
+                get_required_cli_string_with_args(
+                    "cli-peripherals-spec-invalid",
+                    &[("spec", spec)]
+                )
+            );
+        };
+        let (transport, path) = if path == "native" {
+            ("native", None)
+        } else {
+            ("serial", Some(path.to_string()))
+        };
+        config.peripherals.enabled = true;
+        let already =
+            config.peripherals.boards.iter().any(|b| {
+                b.board == board && (path.is_none() || b.path.as_deref() == path.as_deref())
+            });
+        if !already {
+            config.peripherals.boards.push(PeripheralBoardConfig {
+                board: board.to_string(),
+                transport: transport.to_string(),
+                path,
+                ..PeripheralBoardConfig::default()
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Expand `--peripheral auto` into detected boards on `config.peripherals`.
+/// Returns the remaining explicit `board:path` specs unchanged.
+pub async fn apply_auto_peripherals(
+    config: &mut Config,
+    specs: Vec<String>,
+) -> Result<Vec<String>> {
+    let (auto, rest): (Vec<String>, Vec<String>) = specs
+        .into_iter()
+        .partition(|s| s.eq_ignore_ascii_case("auto"));
+    if auto.is_empty() {
+        return Ok(rest);
+    }
+
+    #[cfg(not(all(
+        feature = "hardware",
+        any(target_os = "linux", target_os = "macos", target_os = "windows")
+    )))]
+    {
+        let _ = config;
+        anyhow::bail!(
+            "{}",
+            get_required_cli_string("cli-hardware-feature-required")
+        );
+    }
+
+    #[cfg(all(
+        feature = "hardware",
+        any(target_os = "linux", target_os = "macos", target_os = "windows")
+    ))]
+    {
+        use std::io::IsTerminal;
+        use zeroclaw_hardware::detect;
+
+        let detected = detect::detect_peripherals().await;
+        detect::save_detection_cache(&config.data_dir.join("state"), &detected)?;
+
+        let mut prompt = detect::prompt_for_board;
+        let chooser: Option<&mut dyn FnMut(&detect::DetectedPeripheral) -> Result<String>> =
+            if std::io::stdin().is_terminal() {
+                Some(&mut prompt)
+            } else {
+                None
+            };
+        let boards = detect::resolve_auto_peripherals(&detected, chooser)?;
+        if boards.is_empty() {
+            detect::print_detection_table(&detected);
+            anyhow::bail!("{}", get_required_cli_string("cli-peripherals-auto-none"));
+        }
+
+        config.peripherals.enabled = true;
+        for board in boards {
+            let path = board.path.clone().unwrap_or_default();
+            if config
+                .peripherals
+                .boards
+                .iter()
+                .any(|b| b.path.as_deref() == Some(path.as_str()))
+            {
+                continue;
+            }
+            println!(
+                "{}",
+                get_required_cli_string_with_args(
+                    "cli-peripherals-auto-attached",
+                    &[("board", &board.board), ("path", &path)],
+                )
+            );
+            config.peripherals.boards.push(board);
+        }
+        Ok(rest)
+    }
+}
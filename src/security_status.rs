@@ -2,7 +2,7 @@ use anyhow::{Context, Result, bail};
 use serde::Serialize;
 use std::collections::BTreeMap;
 use zeroclaw_config::config::CredentialSurfaceClass;
-use zeroclaw_config::policy::SecurityPolicy;
+use zeroclaw_config::policy::{CommandDecision, CommandExplanation, SecurityPolicy};
 use zeroclaw_config::schema::{RiskProfileConfig, SandboxBackend, SandboxConfig};
 
 use crate::config::Config;
@@ -17,6 +17,8 @@ pub struct SecurityStatusReport {
     pub workspace: WorkspaceStatus,
     pub credentials: CredentialStatus,
     pub gateway: GatewayStatus,
+    pub network: NetworkStatus,
+    pub tool_policies: Vec<ToolPolicyStatus>,
     pub warnings: Vec<String>,
 }
 
@@ -32,6 +34,21 @@ pub struct RiskProfileStatus {
     pub excluded_tools_count: usize,
 }
 
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ToolPolicyStatus {
+    pub tool: String,
+    pub permission: String,
+    pub except_paths: Vec<String>,
+    pub except_commands: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct NetworkStatus {
+    pub mode: String,
+    pub allowed_domains: Vec<String>,
+    pub blocked_domains: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 pub struct SandboxStatus {
     pub requested_enabled: Option<bool>,
@@ -198,10 +215,97 @@ pub fn build_report(config: &Config, agent_alias: &str) -> Result<SecurityStatus
             allow_public_bind: config.gateway.allow_public_bind,
             tls_enabled,
         },
+        network: NetworkStatus {
+            mode: resolved.policy.network.mode.as_str().to_string(),
+            allowed_domains: resolved.policy.network.allowed_domains().to_vec(),
+            blocked_domains: resolved.policy.network.blocked_domains().to_vec(),
+        },
+        tool_policies: tool_policy_rows(&resolved.policy),
         warnings,
     })
 }
 
+fn tool_policy_rows(policy: &SecurityPolicy) -> Vec<ToolPolicyStatus> {
+    let mut rows: Vec<ToolPolicyStatus> = policy
+        .tool_policies
+        .iter()
+        .map(|(tool, entry)| ToolPolicyStatus {
+            tool: tool.clone(),
+            permission: entry.permission.as_str().to_string(),
+            except_paths: entry.except_paths.clone(),
+            except_commands: entry.except_commands.clone(),
+        })
+        .collect();
+    rows.sort_by(|a, b| a.tool.cmp(&b.tool));
+    rows
+}
+
+fn print_network(network: &NetworkStatus) {
+    let none = crate::t("cli-security-status-network-domains-none", "none");
+    let list = |domains: &[String]| {
+        if domains.is_empty() {
+            none.clone()
+        } else {
+            domains.join(", ")
+        }
+    };
+    let allowed = list(&network.allowed_domains);
+    let blocked = list(&network.blocked_domains);
+    println!(
+        "{}",
+        crate::ta(
+            "cli-security-status-network",
+            &[
+                ("mode", &network.mode),
+                ("allowed", &allowed),
+                ("blocked", &blocked),
+            ],
+            "Network"
+        )
+    );
+}
+
+fn print_tool_policies(rows: &[ToolPolicyStatus]) {
+    if rows.is_empty() {
+        println!(
+            "{}",
+            crate::t(
+                "cli-security-status-tool-policies-none",
+                "Tool policies: none"
+            )
+        );
+        return;
+    }
+    println!(
+        "{}",
+        crate::t("cli-security-status-tool-policies", "Tool policies:")
+    );
+    for row in rows {
+        let mut exceptions = String::new();
+        if !row.except_paths.is_empty() {
+            exceptions.push_str(&format!(" (except paths: {})", row.except_paths.join(", ")));
+        }
+        if !row.except_commands.is_empty() {
+            exceptions.push_str(&format!(
+                " (except commands: {})",
+                row.except_commands.join(", ")
+            ));
+        }
+        println!(
+            "{}",
+            crate::ta(
+                "cli-security-status-tool-policy-row",
+                &[
+                    ("tool", &row.tool),
+                    ("permission", &row.permission),
+                    ("exceptions", &exceptions),
+                ],
+                "Tool policy"
+            )
+        );
+    }
+}
+
 pub fn print_report(report: &SecurityStatusReport) {
     println!(
         "{}",
@@ -330,6 +434,8 @@ pub fn print_report(report: &SecurityStatusReport) {
             "Gateway"
         )
     );
+    print_network(&report.network);
+    print_tool_policies(&report.tool_policies);
     if report.warnings.is_empty() {
         println!(
             "{}",
@@ -347,6 +453,100 @@ pub fn print_report(report: &SecurityStatusReport) {
     }
 }
 
+/// `zeroclaw security explain`: how an agent's shell policy treats one command.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CommandExplainReport {
+    pub agent: String,
+    pub risk_profile: String,
+    pub autonomy: String,
+    #[serde(flatten)]
+    pub explanation: CommandExplanation,
+}
+
+pub fn explain_command(
+    config: &Config,
+    agent_alias: &str,
+    command: &str,
+) -> Result<CommandExplainReport> {
+    let resolved = resolve_agent_context(config, agent_alias)?;
+    Ok(CommandExplainReport {
+        agent: agent_alias.to_string(),
+        risk_profile: resolved.profile_alias,
+        autonomy: autonomy_level_name(resolved.policy.autonomy).to_string(),
+        explanation: resolved.policy.explain_command(command),
+    })
+}
+
+pub fn print_explain_report(report: &CommandExplainReport) {
+    let explanation = &report.explanation;
+    println!(
+        "{}",
+        crate::ta(
+            "cli-security-explain-command",
+            &[("v", &explanation.command)],
+            "Command"
+        )
+    );
+    println!(
+        "{}",
+        crate::ta(
+            "cli-security-explain-agent",
+            &[
+                ("agent", &report.agent),
+                ("profile", &report.risk_profile),
+                ("autonomy", &report.autonomy),
+            ],
+            "Agent"
+        )
+    );
+    let allowlist = match &explanation.allowlist_violation {
+        Some(violation) => violation.clone(),
+        None => crate::t("cli-security-explain-allowlist-pass", "passes"),
+    };
+    println!(
+        "{}",
+        crate::ta(
+            "cli-security-explain-allowlist",
+            &[("v", &allowlist)],
+            "Allowlist"
+        )
+    );
+    println!(
+        "{}",
+        crate::ta(
+            "cli-security-explain-risk",
+            &[("v", &explanation.risk.to_string())],
+            "Risk"
+        )
+    );
+    if let Some(segment) = &explanation.risk.segment {
+        println!(
+            "{}",
+            crate::ta(
+                "cli-security-explain-segment",
+                &[("v", segment)],
+                "Matched in"
+            )
+        );
+    }
+    let decision = match &explanation.decision {
+        CommandDecision::Allowed => {
+            crate::t("cli-security-explain-decision-allowed", "Decision: allowed")
+        }
+        CommandDecision::RequiresApproval(reason) => crate::ta(
+            "cli-security-explain-decision-approval",
+            &[("reason", reason)],
+            "Decision: requires approval",
+        ),
+        CommandDecision::Blocked(reason) => crate::ta(
+            "cli-security-explain-decision-blocked",
+            &[("reason", reason)],
+            "Decision: blocked",
+        ),
+    };
+    println!("{decision}");
+}
+
 struct ResolvedAgentContext<'a> {
     profile_alias: String,
     _risk_profile: &'a RiskProfileConfig,
@@ -609,6 +809,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn agent_report_lists_tool_policies_sorted_by_tool() {
+        use zeroclaw_config::schema::{ToolPermission, ToolPolicyConfig};
+
+        let mut profile = RiskProfileConfig::default();
+        profile.tools.insert(
+            "shell".to_string(),
+            ToolPolicyConfig {
+                permission: ToolPermission::Deny,
+                except_commands: vec!["git status".into()],
+                ..Default::default()
+            },
+        );
+        profile.tools.insert(
+            "file_write".to_string(),
+            ToolPolicyConfig {
+                permission: ToolPermission::RequireApproval,
+                except_paths: vec!["src".into()],
+                ..Default::default()
+            },
+        );
+        let config = config_with_agent("ops", "ops-risk", profile);
+
+        let report = build_report(&config, "ops").expect("agent report");
+
+        let tools: Vec<&str> = report
+            .tool_policies
+            .iter()
+            .map(|row| row.tool.as_str())
+            .collect();
+        assert_eq!(tools, vec!["file_write", "shell"]);
+        assert_eq!(report.tool_policies[0].permission, "require_approval");
+        assert_eq!(
+            report.tool_policies[0].except_paths,
+            vec!["src".to_string()]
+        );
+        assert_eq!(report.tool_policies[1].permission, "deny");
+    }
+
+    #[test]
+    fn agent_report_shows_network_policy() {
+        use zeroclaw_config::schema::{NetworkMode, NetworkPolicyConfig};
+
+        let profile = RiskProfileConfig {
+            network: NetworkPolicyConfig {
+                mode: NetworkMode::Allowlist,
+                allowed_domains: vec!["*.github.com".into()],
+                ..Default::default()
+            },
+            ..RiskProfileConfig::default()
+        };
+        let config = config_with_agent("ops", "ops-risk", profile);
+
+        let report = build_report(&config, "ops").expect("agent report");
+
+        assert_eq!(report.network.mode, "allowlist");
+        assert_eq!(report.network.allowed_domains, vec!["*.github.com"]);
+        assert!(report.network.blocked_domains.is_empty());
+    }
+
     #[test]
     fn missing_agent_is_an_error() {
         let config = Config::default();
@@ -0,0 +1,83 @@
+pub use zeroclaw_runtime::tasks::*;
+
+use crate::config::Config;
+use anyhow::Result;
+use zeroclaw_runtime::i18n::{get_required_cli_string, get_required_cli_string_with_args};
+
+pub fn handle_command(command: crate::TasksCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::TasksCommands::List { agent, limit } => {
+            let tasks = list_tasks(config, agent.as_deref(), limit)?;
+            if tasks.is_empty() {
+                println!("{}", get_required_cli_string("cli-tasks-none"));
+                return Ok(());
+            }
+
+            println!(
+                "{}",
+                get_required_cli_string_with_args(
+                    "cli-tasks-header",
+                    &[("count", &tasks.len().to_string())]
+                )
+            );
+            for task in tasks {
+                println!(
+                    "- {} | {} | {} | {} | created={}",
+                    task.id,
+                    task.status,
+                    task.agent_alias,
+                    task.description,
+                    task.created_at.to_rfc3339(),
+                );
+            }
+            Ok(())
+        }
+        crate::TasksCommands::Show { id } => {
+            let task = get_task(config, &id)?;
+            let started = task
+                .started_at
+                .map_or_else(|| "-".into(), |d| d.to_rfc3339());
+            let finished = task
+                .finished_at
+                .map_or_else(|| "-".into(), |d| d.to_rfc3339());
+            let reports_to = match (&task.channel, &task.reply_target) {
+                (Some(channel), Some(target)) => format!("{channel} → {target}"),
+                _ => "-".into(),
+            };
+            for (key, value) in [
+                ("cli-tasks-show-id", task.id.as_str()),
+                ("cli-tasks-show-status", task.status.as_str()),
+                ("cli-tasks-show-agent", task.agent_alias.as_str()),
+                ("cli-tasks-show-description", task.description.as_str()),
+                ("cli-tasks-show-reports-to", reports_to.as_str()),
+                ("cli-tasks-show-started", started.as_str()),
+                ("cli-tasks-show-finished", finished.as_str()),
+                ("cli-tasks-show-attempts", &task.attempts.to_string()),
+                ("cli-tasks-show-prompt", task.prompt.as_str()),
+            ] {
+                println!(
+                    "{}",
+                    get_required_cli_string_with_args(key, &[("v", value)])
+                );
+            }
+            if let Some(output) = &task.output {
+                println!("\n{}", get_required_cli_string("cli-tasks-show-output"));
+                println!("{output}");
+            }
+            Ok(())
+        }
+        crate::TasksCommands::Cancel { id } => {
+            let task = request_cancel(config, &id)?;
+            let key = if task.status == TaskStatus::Running {
+                "cli-tasks-cancel-requested"
+            } else {
+                "cli-tasks-cancelled"
+            };
+            println!(
+                "{}",
+                get_required_cli_string_with_args(key, &[("id", &task.id)])
+            );
+            Ok(())
+        }
+    }
+}
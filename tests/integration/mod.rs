@@ -8,6 +8,7 @@ mod hooks;
 mod memory_comparison;
 mod memory_loop_continuity;
 mod memory_restart;
+mod read_only_mode;
 mod report_template_tool_test;
 mod telegram_attachment_fallback;
 mod telegram_finalize_draft;
@@ -0,0 +1,136 @@
+//! Read-only autonomy must hold end-to-end: a canned conversation that asks
+//! for writes, shell mutations, and reads leaves the workspace untouched.
+
+use crate::support::RecordingModelProvider;
+use crate::support::helpers::{text_response, tool_response};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tempfile::TempDir;
+use zeroclaw::agent::agent::Agent;
+use zeroclaw::providers::ToolCall;
+use zeroclaw_config::autonomy::AutonomyLevel;
+use zeroclaw_config::schema::{AliasedAgentConfig, Config, RiskProfileConfig};
+
+fn read_only_config(tmp: &TempDir) -> Config {
+    let mut config = Config {
+        data_dir: tmp.path().join("data"),
+        config_path: tmp.path().join("config.toml"),
+        ..Default::default()
+    };
+    {
+        let entry = config
+            .providers
+            .models
+            .ensure("custom", "default")
+            .expect("custom model_provider type slot");
+        entry.api_key = Some("test-key".to_string());
+        entry.model = Some("test-model".to_string());
+        entry.uri = Some("http://127.0.0.1:9".to_string());
+    }
+    config.memory.backend = "none".to_string();
+    config.memory.auto_save = false;
+    config.risk_profiles.insert(
+        "locked".to_string(),
+        RiskProfileConfig {
+            level: AutonomyLevel::ReadOnly,
+            ..RiskProfileConfig::default()
+        },
+    );
+    config.agents.insert(
+        "reader".to_string(),
+        AliasedAgentConfig {
+            model_provider: "custom.default".into(),
+            risk_profile: "locked".into(),
+            ..AliasedAgentConfig::default()
+        },
+    );
+    config
+}
+
+/// Every file under `dir` with its contents.
+fn snapshot(dir: &Path) -> BTreeMap<String, Vec<u8>> {
+    let mut files = BTreeMap::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        for entry in std::fs::read_dir(&current).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                pending.push(path);
+            } else {
+                let key = path.strip_prefix(dir).unwrap().display().to_string();
+                files.insert(key, std::fs::read(&path).unwrap());
+            }
+        }
+    }
+    files
+}
+
+fn call(id: &str, name: &str, arguments: serde_json::Value) -> ToolCall {
+    ToolCall {
+        id: id.into(),
+        name: name.into(),
+        arguments: arguments.to_string(),
+        extra_content: None,
+    }
+}
+
+#[tokio::test]
+async fn read_only_agent_turn_leaves_workspace_untouched() {
+    let tmp = TempDir::new().unwrap();
+    let config = read_only_config(&tmp);
+    let workspace = config.agent_workspace_dir("reader");
+
+    let mut agent = Agent::from_config(&config, "reader")
+        .await
+        .expect("agent from config");
+    std::fs::write(workspace.join("seed.txt"), "original").unwrap();
+
+    let (model_provider, requests) = RecordingModelProvider::new(vec![
+        tool_response(vec![
+            call(
+                "tc1",
+                "file_write",
+                serde_json::json!({"path": "seed.txt", "content": "overwritten"}),
+            ),
+            call(
+                "tc2",
+                "shell",
+                serde_json::json!({"command": "touch created.txt"}),
+            ),
+            call("tc3", "file_read", serde_json::json!({"path": "seed.txt"})),
+        ]),
+        text_response("Nothing was changed."),
+    ]);
+    agent.set_model_provider(Box::new(model_provider));
+
+    let before = snapshot(&workspace);
+    agent
+        .turn("rewrite seed.txt and create a file")
+        .await
+        .unwrap();
+
+    assert_eq!(snapshot(&workspace), before, "workspace must not change");
+
+    let requests = requests.lock().unwrap();
+    let tool_results: Vec<&str> = requests
+        .last()
+        .expect("model saw the tool results")
+        .iter()
+        .filter(|message| message.role == "tool")
+        .map(|message| message.content.as_str())
+        .collect();
+    assert_eq!(
+        tool_results
+            .iter()
+            .filter(|content| content.contains("read-only mode"))
+            .count(),
+        2,
+        "file_write and the mutating shell command are refused: {tool_results:?}"
+    );
+    assert!(
+        tool_results
+            .iter()
+            .any(|content| content.contains("original")),
+        "file_read still runs: {tool_results:?}"
+    );
+}
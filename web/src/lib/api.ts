@@ -11,6 +11,7 @@ import type {
   HealthSnapshot,
   Session,
   ChannelDetail,
+  DashboardSummary,
   SessionMessagesResponse,
   TuiEntry,
 } from "../types/api";
@@ -2132,6 +2133,12 @@ export function abortSession(id: string): Promise<{ status: string }> {
 // Channels (detailed)
 // ---------------------------------------------------------------------------
 
+/** Health, today's usage, pending approvals, enabled cron jobs and recent
+ * channel messages in one round-trip (Dashboard → Activity). */
+export function getDashboardSummary(): Promise<DashboardSummary> {
+  return apiFetch<DashboardSummary>("/api/dashboard");
+}
+
 export function getChannels(): Promise<ChannelDetail[]> {
   return apiFetch<ChannelDetail[] | { channels: ChannelDetail[] }>(
     "/api/channels",
@@ -1834,6 +1834,21 @@ const translations: Record<Locale, Record<string, string>> = {
     'dashboard.tab_memories': 'Memories',
     'dashboard.tab_health': 'Health',
     'dashboard.tab_cost': 'Cost',
+    'dashboard.tab_activity': 'Activity',
+    'dashboard.activity.usage_today': 'Usage today',
+    'dashboard.activity.requests': 'requests',
+    'dashboard.activity.tokens': 'tokens',
+    'dashboard.activity.cost_tracking_off': 'Cost tracking is off',
+    'dashboard.activity.unhealthy_components': 'Unhealthy components',
+    'dashboard.activity.reporting': 'reporting',
+    'dashboard.activity.pending_approvals': 'Pending approvals',
+    'dashboard.activity.approvals_queue_off': 'Approval queue is off',
+    'dashboard.activity.no_pending_approvals': 'Nothing is waiting for approval',
+    'dashboard.activity.active_cron_jobs': 'Active cron jobs',
+    'dashboard.activity.no_cron_jobs': 'No enabled cron jobs',
+    'dashboard.activity.next_run': 'next',
+    'dashboard.activity.recent_messages': 'Recent messages',
+    'dashboard.activity.messages': 'messages',
     'dashboard.sessions_title': 'Active Sessions',
     'dashboard.no_sessions': 'No active sessions',
     'dashboard.session_id': 'Session ID',
@@ -35,6 +35,7 @@ import type {
   SessionMessageRow,
   ProcessStats,
   TuiEntry,
+  DashboardSummary,
 } from "@/types/api";
 import {
   getStatus,
@@ -50,6 +51,7 @@ import {
   getQuickstartState,
   getTuis,
   listProps,
+  getDashboardSummary,
 } from "@/lib/api";
 import { resolveModelToProviderType } from "@/lib/configuredModels";
 import DoctorFixModal from "@/components/DoctorFixModal";
@@ -168,6 +170,7 @@ import { StatCard, PageHeader, ConfirmDialog } from "@/components/ui";
 
 type TabId =
   | "overview"
+  | "activity"
   | "sessions"
   | "channels"
   | "memories"
@@ -413,6 +416,7 @@ const STATUS_CARDS = [
 
 const TABS: { id: TabId; labelKey: string; icon: typeof LayoutDashboard }[] = [
   { id: "overview", labelKey: "dashboard.tab_overview", icon: LayoutDashboard },
+  { id: "activity", labelKey: "dashboard.tab_activity", icon: Radio },
   { id: "sessions", labelKey: "dashboard.tab_sessions", icon: Users },
   { id: "channels", labelKey: "dashboard.tab_channels", icon: Wifi },
   { id: "memories", labelKey: "dashboard.tab_memories", icon: Brain },
@@ -1651,14 +1655,30 @@ function ChannelsTab() {
             />
           </div>
 
-          {/* Stats. `message_count` / `last_message_at` come back as
-              hardcoded 0 / null from the gateway — drop those rows until the
-              backend wires real counters. Health stays since it reflects the
-              listener supervisor's state. */}
+          {/* Stats. Message counters are summed from the channel's
+              persisted sessions; health reflects the listener supervisor. */}
           <div
             className="pt-3 border-t space-y-2"
             style={{ borderColor: "var(--pc-border)" }}
           >
+            <div className="flex justify-between text-xs">
+              <span style={{ color: "var(--pc-text-muted)" }}>
+                {t("dashboard.channel_messages")}
+              </span>
+              <span style={{ color: "var(--pc-text-primary)" }}>
+                {channel.message_count}
+              </span>
+            </div>
+            <div className="flex justify-between text-xs">
+              <span style={{ color: "var(--pc-text-muted)" }}>
+                {t("dashboard.channel_last_message")}
+              </span>
+              <span style={{ color: "var(--pc-text-primary)" }}>
+                {channel.last_message_at
+                  ? formatRelative(channel.last_message_at)
+                  : t("dashboard.never")}
+              </span>
+            </div>
             {channel.readiness ? (
               <>
                 {CHANNEL_READINESS_ROWS.map(([labelKey, key]) => {
@@ -1721,6 +1741,7 @@ function ChannelsTab() {
 
 const TAB_IDS: TabId[] = [
   "overview",
+  "activity",
   "sessions",
   "channels",
   "memories",
@@ -1876,6 +1897,7 @@ export default function Dashboard() {
           setShowAllChannels={setShowAllChannels}
         />
       )}
+      {activeTab === "activity" && <ActivityTab />}
       {activeTab === "sessions" && <SessionsTab />}
       {activeTab === "channels" && <ChannelsTab />}
       {activeTab === "memories" && <MemoriesTab />}
@@ -1891,6 +1913,280 @@ export default function Dashboard() {
   );
 }
 
+// ---------------------------------------------------------------------------
+// Activity Tab (GET /api/dashboard)
+// ---------------------------------------------------------------------------
+
+function ActivitySection({
+  icon: Icon,
+  title,
+  children,
+}: {
+  icon: typeof Activity;
+  title: string;
+  children: React.ReactNode;
+}) {
+  return (
+    <div className="card p-5 animate-slide-in-up">
+      <div className="flex items-center gap-2 mb-4">
+        <Icon className="h-5 w-5" style={{ color: "var(--pc-accent)" }} />
+        <h2
+          className="text-sm font-semibold uppercase tracking-wider"
+          style={{ color: "var(--pc-text-primary)" }}
+        >
+          {title}
+        </h2>
+      </div>
+      {children}
+    </div>
+  );
+}
+
+function ActivityEmpty({ text }: { text: string }) {
+  return (
+    <p className="text-sm" style={{ color: "var(--pc-text-faint)" }}>
+      {text}
+    </p>
+  );
+}
+
+function ActivityTab() {
+  const [summary, setSummary] = useState<DashboardSummary | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  // Same 5s cadence as the overview cards, so a test message or a listener
+  // going down shows up without a reload.
+  usePolling(
+    (isStale) => {
+      getDashboardSummary()
+        .then((data) => {
+          if (isStale()) return;
+          setSummary(data);
+          setError(null);
+        })
+        .catch((err) => {
+          if (!isStale()) setError(err.message);
+        });
+    },
+    5000,
+    [],
+  );
+
+  if (error && !summary) {
+    return (
+      <div
+        className="rounded-2xl border p-4"
+        style={{
+          background: "var(--color-status-error-alpha-08)",
+          borderColor: "var(--color-status-error-alpha-20)",
+          color: "var(--color-status-error)",
+        }}
+      >
+        {t("dashboard.load_error")}: {error}
+      </div>
+    );
+  }
+  if (!summary) {
+    return (
+      <div className="flex items-center justify-center h-48">
+        <div
+          className="h-6 w-6 border-2 rounded-full animate-spin"
+          style={{
+            borderColor: "var(--pc-border)",
+            borderTopColor: "var(--pc-accent)",
+          }}
+        />
+      </div>
+    );
+  }
+
+  const unhealthy = Object.entries(summary.health.components).filter(
+    ([, comp]) => comp.status !== "ok",
+  );
+  const usage = summary.usage_today;
+
+  return (
+    <div className="space-y-4">
+      {summary.errors.length > 0 && (
+        <p className="text-xs" style={{ color: "var(--color-status-warning)" }}>
+          {summary.errors.join(" · ")}
+        </p>
+      )}
+
+      <div className="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-4 gap-4">
+        <StatCard
+          label={t("dashboard.activity.usage_today")}
+          value={usage ? formatUSD(usage.cost_usd) : "—"}
+          sublabel={
+            usage
+              ? `${usage.request_count} ${t("dashboard.activity.requests")} · ${usage.total_tokens.toLocaleString()} ${t("dashboard.activity.tokens")}`
+              : t("dashboard.activity.cost_tracking_off")
+          }
+          icon={<DollarSign className="h-5 w-5" />}
+        />
+        <StatCard
+          label={t("dashboard.activity.unhealthy_components")}
+          value={unhealthy.length}
+          sublabel={`${Object.keys(summary.health.components).length} ${t("dashboard.activity.reporting")}`}
+          icon={<Heart className="h-5 w-5" />}
+          tone={unhealthy.length > 0 ? "error" : "ok"}
+        />
+        <StatCard
+          label={t("dashboard.activity.pending_approvals")}
+          value={summary.pending_approvals?.length ?? "—"}
+          sublabel={
+            summary.pending_approvals
+              ? undefined
+              : t("dashboard.activity.approvals_queue_off")
+          }
+          icon={<Clock className="h-5 w-5" />}
+          tone={summary.pending_approvals?.length ? "warn" : "neutral"}
+        />
+        <StatCard
+          label={t("dashboard.activity.active_cron_jobs")}
+          value={summary.cron_jobs.length}
+          icon={<ArrowUpDown className="h-5 w-5" />}
+        />
+      </div>
+
+      <ActivitySection
+        icon={MessageSquare}
+        title={t("dashboard.activity.recent_messages")}
+      >
+        {summary.channels.length === 0 ? (
+          <ActivityEmpty text={t("dashboard.no_channels")} />
+        ) : (
+          <div className="space-y-3">
+            {summary.channels.map((channel) => (
+              <div
+                key={channel.name}
+                className="rounded-xl px-3 py-2"
+                style={{
+                  border: `1px solid ${healthBorder(channel.health)}`,
+                  background: healthBg(channel.health),
+                }}
+              >
+                <div className="flex items-center gap-2">
+                  <span
+                    className="status-dot flex-shrink-0"
+                    style={{ background: healthColor(channel.health) }}
+                  />
+                  <span
+                    className="text-sm font-medium font-mono"
+                    style={{ color: "var(--pc-text-primary)" }}
+                  >
+                    {channel.name}
+                  </span>
+                  <span
+                    className="ml-auto text-[11px]"
+                    style={{ color: "var(--pc-text-muted)" }}
+                  >
+                    {channel.message_count} {t("dashboard.activity.messages")}
+                    {channel.last_message_at
+                      ? ` · ${formatRelative(channel.last_message_at)}`
+                      : ""}
+                  </span>
+                </div>
+                {channel.last_error && (
+                  <p
+                    className="text-[11px] mt-1 font-mono break-words"
+                    style={{ color: "var(--color-status-error)" }}
+                  >
+                    ⚠ {channel.last_error}
+                  </p>
+                )}
+                {channel.recent.map((msg) => (
+                  <p
+                    key={msg.session_id}
+                    className="text-xs mt-1 truncate"
+                    style={{ color: "var(--pc-text-secondary)" }}
+                    title={msg.preview}
+                  >
+                    <span style={{ color: "var(--pc-text-muted)" }}>
+                      {msg.sender ?? msg.role} · {formatRelative(msg.at)}:
+                    </span>{" "}
+                    {msg.preview}
+                  </p>
+                ))}
+              </div>
+            ))}
+          </div>
+        )}
+      </ActivitySection>
+
+      <div className="grid grid-cols-1 lg:grid-cols-2 gap-4">
+        <ActivitySection
+          icon={Clock}
+          title={t("dashboard.activity.pending_approvals")}
+        >
+          {!summary.pending_approvals?.length ? (
+            <ActivityEmpty text={t("dashboard.activity.no_pending_approvals")} />
+          ) : (
+            <div className="space-y-2">
+              {summary.pending_approvals.map((approval) => (
+                <div key={approval.id} className="text-xs">
+                  <div className="flex justify-between gap-3">
+                    <span
+                      className="font-mono"
+                      style={{ color: "var(--pc-text-primary)" }}
+                    >
+                      {approval.id.slice(0, 8)} {approval.tool_name}
+                    </span>
+                    <span style={{ color: "var(--pc-text-muted)" }}>
+                      {approval.channel} · {formatRelative(approval.created_at)}
+                    </span>
+                  </div>
+                  <p
+                    className="truncate"
+                    style={{ color: "var(--pc-text-muted)" }}
+                    title={approval.risk_reasons.join("; ")}
+                  >
+                    {approval.arguments_summary}
+                  </p>
+                </div>
+              ))}
+            </div>
+          )}
+        </ActivitySection>
+
+        <ActivitySection
+          icon={ArrowUpDown}
+          title={t("dashboard.activity.active_cron_jobs")}
+        >
+          {summary.cron_jobs.length === 0 ? (
+            <ActivityEmpty text={t("dashboard.activity.no_cron_jobs")} />
+          ) : (
+            <div className="space-y-2">
+              {summary.cron_jobs.map((job) => (
+                <div key={job.id} className="flex justify-between gap-3 text-xs">
+                  <Link
+                    to="/cron"
+                    className="truncate"
+                    style={{ color: "var(--pc-text-primary)" }}
+                  >
+                    {job.name ?? job.id} · {job.agent_alias}
+                  </Link>
+                  <span
+                    className="flex-shrink-0"
+                    style={{
+                      color:
+                        job.last_status === "error"
+                          ? "var(--color-status-error)"
+                          : "var(--pc-text-muted)",
+                    }}
+                  >
+                    {t("dashboard.activity.next_run")} {formatLocalDateTime(job.next_run)}
+                  </span>
+                </div>
+              ))}
+            </div>
+          )}
+        </ActivitySection>
+      </div>
+    </div>
+  );
+}
+
 // ---------------------------------------------------------------------------
 // Health Tab
 // ---------------------------------------------------------------------------
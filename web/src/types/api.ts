@@ -242,6 +242,60 @@ export interface ChannelDetail {
   readiness?: ChannelReadiness;
 }
 
+/** Entry of `[approvals]` queue as `zeroclaw approvals list` shows it. */
+export interface QueuedApproval {
+  id: string;
+  tool_name: string;
+  arguments_summary: string;
+  risk_reasons: string[];
+  agent_alias: string | null;
+  channel: string;
+  sender: string | null;
+  status: string;
+  created_at: string;
+  expires_at: string;
+}
+
+/** Last message of one of a channel's most recently active sessions. */
+export interface DashboardRecentMessage {
+  session_id: string;
+  sender: string | null;
+  role: string;
+  preview: string;
+  at: string;
+}
+
+export interface DashboardChannel {
+  name: string;
+  status: string;
+  health: string;
+  last_error: string | null;
+  session_count: number;
+  message_count: number;
+  last_message_at: string | null;
+  recent: DashboardRecentMessage[];
+}
+
+/** GET /api/dashboard */
+export interface DashboardSummary {
+  generated_at: string;
+  health: HealthSnapshot;
+  channels: DashboardChannel[];
+  /** `null` when cost tracking is off. */
+  usage_today: {
+    cost_usd: number;
+    month_cost_usd: number;
+    total_tokens: number;
+    request_count: number;
+  } | null;
+  /** `null` when `[approvals]` is disabled. */
+  pending_approvals: QueuedApproval[] | null;
+  /** Enabled jobs only. */
+  cron_jobs: CronJob[];
+  /** Sections that failed to load. */
+  errors: string[];
+}
+
 export interface SSEEvent {
   type: string;
   timestamp?: string;